@@ -0,0 +1,102 @@
+/*
+ * The C ABI of glyn, implemented by the glyn_capi crate.
+ *
+ * Values are returned as copies tagged with their type. A value owns its
+ * string, if it has one, until it is freed with glyn_value_free.
+ */
+
+#ifndef GLYN_H
+#define GLYN_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An agent with a realm which the scripts it evaluates share. */
+typedef struct GlynAgent GlynAgent;
+
+/* A UTF-8 string owned by glyn, whose bytes are followed by a NUL byte. */
+typedef struct GlynString GlynString;
+
+typedef enum GlynValueTag {
+    GLYN_UNDEFINED,
+    GLYN_NULL,
+    /* The value is in boolean. */
+    GLYN_BOOLEAN,
+    /* The value is in number. */
+    GLYN_NUMBER,
+    /* The value is in string. */
+    GLYN_STRING,
+    /* The value is shown in string. */
+    GLYN_BIGINT,
+    /* The value is shown in string. */
+    GLYN_SYMBOL,
+    /* The value is shown in string. */
+    GLYN_OBJECT,
+    /* A value was thrown, and its message is in string. */
+    GLYN_EXCEPTION,
+} GlynValueTag;
+
+typedef struct GlynValue {
+    GlynValueTag tag;
+    bool boolean;
+    double number;
+    GlynString *string;
+} GlynValue;
+
+/*
+ * A function which scripts can call. The arguments may be read but not
+ * freed. The returned value is taken by glyn, so its string must be created
+ * with glyn_string_new. Returning a GLYN_EXCEPTION throws its string.
+ */
+typedef GlynValue (*GlynCallback)(const GlynValue *args, size_t count, void *data);
+
+/* Creates an agent, which must be freed with glyn_agent_free. */
+GlynAgent *glyn_agent_new(void);
+
+/*
+ * Frees an agent, along with every value in it. If called by a callback of
+ * the agent, the agent is freed once the script which called back has been
+ * evaluated, and must not be used meanwhile.
+ */
+void glyn_agent_free(GlynAgent *agent);
+
+/*
+ * Evaluates length bytes of UTF-8 source text as a script, returning its
+ * completion value or a GLYN_EXCEPTION with the message of the error. A
+ * GLYN_EXCEPTION is also returned if called by a callback of the agent, or if
+ * the agent panicked, after which it can no longer be used.
+ */
+GlynValue glyn_eval(GlynAgent *agent, const char *source, size_t length);
+
+/*
+ * Defines a global function named name which calls callback with data,
+ * returning false if the name is not valid UTF-8, if called by a callback of
+ * the agent, or if the agent panicked.
+ */
+bool glyn_register_function(GlynAgent *agent, const char *name, size_t length,
+                            GlynCallback callback, void *data);
+
+/* Frees the string of a value, if it has one. */
+void glyn_value_free(GlynValue *value);
+
+/* Creates a string from length bytes of UTF-8, for a callback to return. */
+GlynString *glyn_string_new(const char *data, size_t length);
+
+/* The bytes of the string, which are followed by a NUL byte. */
+const char *glyn_string_data(const GlynString *string);
+
+/* The number of bytes in the string, not counting the NUL byte. */
+size_t glyn_string_length(const GlynString *string);
+
+/* Frees a string which is not owned by a value. */
+void glyn_string_free(GlynString *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
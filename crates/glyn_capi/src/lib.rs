@@ -0,0 +1,395 @@
+//! A C ABI for embedding glyn in programs which are not written in Rust, declared in `include/glyn.h`.
+//!
+//! ```c
+//! GlynAgent *agent = glyn_agent_new();
+//!
+//! const char *source = "[1, 2, 3].length;";
+//! GlynValue value = glyn_eval(agent, source, strlen(source));
+//!
+//! if (value.tag == GLYN_NUMBER) {
+//!     printf("%f\n", value.number);
+//! }
+//!
+//! glyn_value_free(&value);
+//! glyn_agent_free(agent);
+//! ```
+//!
+//! Values cross the boundary as copies, so objects, symbols and BigInts are returned as the string they are shown as in a REPL, and a callback can only return primitives.
+
+use std::{
+    cell::Cell,
+    ffi::{c_char, c_void, CStr},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+
+use glyn_interpreter::{eval_script, JSAgent, JSValue, ThrowCompletion};
+
+/// An agent with a realm which the scripts it evaluates share, handled only through the pointer returned by [`glyn_agent_new`].
+pub struct GlynAgent {
+    agent: JSAgent,
+    /// Whether the agent is being used, during which a callback called by it must not use it too, as that would alias the mutable reference to it.
+    in_use: Cell<bool>,
+    /// Whether a panic unwound out of the agent, which may have left it in an inconsistent state, so that it is not used again.
+    poisoned: Cell<bool>,
+    /// Whether a callback called by the agent freed it, so that it is freed once it is no longer being used.
+    freed: Cell<bool>,
+}
+
+/// The reason an agent could not be used.
+enum AgentError {
+    InUse,
+    Poisoned,
+}
+
+impl AgentError {
+    fn message(&self) -> &'static str {
+        match self {
+            AgentError::InUse => {
+                "Error: the agent is already in use by the script which called back"
+            }
+            AgentError::Poisoned => "Error: the agent panicked and can no longer be used",
+        }
+    }
+}
+
+/// Runs `f` with the agent, unless it is already in use or has panicked, catching any panic so that it does not unwind across the C ABI, and then frees the agent if a callback freed it meanwhile.
+///
+/// # Safety
+///
+/// `agent` must be a pointer returned by [`glyn_agent_new`] which has not been freed, and the result of `f` must not borrow from the agent.
+unsafe fn with_agent<T>(
+    agent: *mut GlynAgent,
+    f: impl FnOnce(&mut JSAgent) -> T,
+) -> Result<T, AgentError> {
+    // NOTE: Only the flags are borrowed, as the agent itself may be mutably borrowed by a script which called back into the C ABI.
+    let in_use = &(*agent).in_use;
+    let poisoned = &(*agent).poisoned;
+
+    if poisoned.get() {
+        return Err(AgentError::Poisoned);
+    }
+
+    if in_use.replace(true) {
+        return Err(AgentError::InUse);
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| f(&mut (*agent).agent)));
+
+    in_use.set(false);
+
+    if result.is_err() {
+        poisoned.set(true);
+    }
+
+    if (*agent).freed.get() {
+        drop(Box::from_raw(agent));
+    }
+
+    result.map_err(|_| AgentError::Poisoned)
+}
+
+/// A UTF-8 string owned by glyn, handled only through its pointer.
+pub struct GlynString {
+    /// The bytes of the string followed by a NUL byte, so that they can be read as a C string if the string has no NUL of its own.
+    bytes: Box<[u8]>,
+}
+
+impl GlynString {
+    fn new(string: &str) -> *mut GlynString {
+        let mut bytes = Vec::with_capacity(string.len() + 1);
+
+        bytes.extend_from_slice(string.as_bytes());
+        bytes.push(0);
+
+        Box::into_raw(Box::new(GlynString {
+            bytes: bytes.into_boxed_slice(),
+        }))
+    }
+
+    fn as_str(&self) -> &str {
+        // NOTE: Every string is created from a str or from bytes converted with from_utf8_lossy.
+        std::str::from_utf8(&self.bytes[..self.bytes.len() - 1]).unwrap_or_default()
+    }
+}
+
+/// The type of a [`GlynValue`], which says which of its fields is set.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlynValueTag {
+    Undefined,
+    Null,
+    /// The value is in `boolean`.
+    Boolean,
+    /// The value is in `number`.
+    Number,
+    /// The value is in `string`.
+    String,
+    /// The value is shown in `string`.
+    BigInt,
+    /// The value is shown in `string`.
+    Symbol,
+    /// The value is shown in `string`.
+    Object,
+    /// A value was thrown, and its message is in `string`.
+    Exception,
+}
+
+/// A copy of a value, which owns its string, if it has one, until it is freed with [`glyn_value_free`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct GlynValue {
+    pub tag: GlynValueTag,
+    pub boolean: bool,
+    pub number: f64,
+    pub string: *mut GlynString,
+}
+
+impl GlynValue {
+    fn new(tag: GlynValueTag) -> Self {
+        GlynValue {
+            tag,
+            boolean: false,
+            number: 0.0,
+            string: ptr::null_mut(),
+        }
+    }
+
+    fn with_string(tag: GlynValueTag, string: &str) -> Self {
+        GlynValue {
+            string: GlynString::new(string),
+            ..GlynValue::new(tag)
+        }
+    }
+
+    fn from_value(value: &JSValue) -> Self {
+        if let Some(number) = value.as_f64() {
+            return GlynValue {
+                number,
+                ..GlynValue::new(GlynValueTag::Number)
+            };
+        }
+
+        if let Some(string) = value.to_rust_string() {
+            return GlynValue::with_string(GlynValueTag::String, &string);
+        }
+
+        let tag = match value {
+            JSValue::Undefined => return GlynValue::new(GlynValueTag::Undefined),
+            JSValue::Null => return GlynValue::new(GlynValueTag::Null),
+            JSValue::Bool(boolean) => {
+                return GlynValue {
+                    boolean: *boolean,
+                    ..GlynValue::new(GlynValueTag::Boolean)
+                }
+            }
+            _ if value.is_big_int() => GlynValueTag::BigInt,
+            _ if value.is_symbol() => GlynValueTag::Symbol,
+            _ => GlynValueTag::Object,
+        };
+
+        // NOTE: Showing a value never runs user code, unlike converting it with ToString.
+        GlynValue::with_string(tag, &value.inspect())
+    }
+
+    /// Converts the value returned by a callback, taking its string.
+    fn into_value(mut self) -> Result<JSValue, ThrowCompletion> {
+        let string = (!self.string.is_null()).then(|| {
+            // SAFETY: The string of a value returned by a callback was created with glyn_string_new.
+            let string = unsafe { Box::from_raw(self.string) };
+
+            self.string = ptr::null_mut();
+
+            string.as_str().to_string()
+        });
+
+        match (self.tag, string) {
+            (GlynValueTag::Undefined, _) => Ok(JSValue::Undefined),
+            (GlynValueTag::Null, _) => Ok(JSValue::Null),
+            (GlynValueTag::Boolean, _) => Ok(JSValue::from(self.boolean)),
+            (GlynValueTag::Number, _) => Ok(JSValue::from(self.number)),
+            (GlynValueTag::String, string) => Ok(JSValue::from(string.unwrap_or_default())),
+            (GlynValueTag::Exception, string) => Err(ThrowCompletion::from(JSValue::from(
+                string.unwrap_or_default(),
+            ))),
+            (tag, _) => Err(ThrowCompletion::type_error(format!(
+                "a callback cannot return a value of type {tag:?}"
+            ))),
+        }
+    }
+}
+
+/// A function which scripts can call, registered with [`glyn_register_function`].
+///
+/// It is called with the arguments, which it may read but not free, and the data it was registered with. The value it returns is taken by glyn, so its string must be created with [`glyn_string_new`] and not be freed by the callback. Returning a value tagged `GLYN_EXCEPTION` throws its string.
+pub type GlynCallback =
+    unsafe extern "C" fn(args: *const GlynValue, count: usize, data: *mut c_void) -> GlynValue;
+
+/// Creates an agent, which must be freed with [`glyn_agent_free`].
+#[no_mangle]
+pub extern "C" fn glyn_agent_new() -> *mut GlynAgent {
+    Box::into_raw(Box::new(GlynAgent {
+        agent: JSAgent::default(),
+        in_use: Cell::new(false),
+        poisoned: Cell::new(false),
+        freed: Cell::new(false),
+    }))
+}
+
+/// Frees an agent, along with every value in it. If it is called by a callback of the agent, the agent is freed once the script which called back has been evaluated, and must not be used meanwhile.
+///
+/// # Safety
+///
+/// `agent` must be null or a pointer returned by [`glyn_agent_new`] which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_agent_free(agent: *mut GlynAgent) {
+    if agent.is_null() {
+        return;
+    }
+
+    // NOTE: The agent is mutably borrowed by the script which called back, so freeing it is left to that script once it has been evaluated.
+    if (*agent).in_use.get() {
+        (*agent).freed.set(true);
+    } else {
+        drop(Box::from_raw(agent));
+    }
+}
+
+/// Evaluates `length` bytes of UTF-8 source text as a script, returning its completion value or an exception with the message of the error it failed with.
+///
+/// A callback of the agent cannot evaluate a script with it, and an agent which panicked cannot evaluate any more scripts, so an exception is returned instead.
+///
+/// # Safety
+///
+/// `agent` must be a pointer returned by [`glyn_agent_new`] which has not been freed, and `source` must point to `length` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_eval(
+    agent: *mut GlynAgent,
+    source: *const c_char,
+    length: usize,
+) -> GlynValue {
+    let Ok(source) = std::str::from_utf8(bytes(source, length)) else {
+        return GlynValue::with_string(
+            GlynValueTag::Exception,
+            "SyntaxError: the source text is not valid UTF-8",
+        );
+    };
+
+    let result = with_agent(agent, |agent| {
+        eval_script(agent, source).map(|value| GlynValue::from_value(&value))
+    });
+
+    match result {
+        Ok(Ok(value)) => value,
+        Ok(Err(error)) => GlynValue::with_string(GlynValueTag::Exception, &error.to_string()),
+        Err(error) => GlynValue::with_string(GlynValueTag::Exception, error.message()),
+    }
+}
+
+/// Defines a global function named `name` which calls `callback` with `data`, returning false if the name is not valid UTF-8, or if the agent cannot be used as it is called by a callback of the agent or the agent panicked.
+///
+/// `length` is the number of arguments the function expects.
+///
+/// # Safety
+///
+/// `agent` must be a pointer returned by [`glyn_agent_new`] which has not been freed, and `name` must be a NUL-terminated string. `data` must stay valid for as long as the agent can call the function.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_register_function(
+    agent: *mut GlynAgent,
+    name: *const c_char,
+    length: usize,
+    callback: GlynCallback,
+    data: *mut c_void,
+) -> bool {
+    let Ok(name) = CStr::from_ptr(name).to_str() else {
+        return false;
+    };
+
+    with_agent(agent, |agent| {
+        agent.register_function(name, length, move |_, args| {
+            let mut args = args.iter().map(GlynValue::from_value).collect::<Vec<_>>();
+
+            // SAFETY: The embedder guarantees that the callback can be called with the data it was registered with.
+            let result = unsafe { callback(args.as_ptr(), args.len(), data) };
+
+            for arg in &mut args {
+                // SAFETY: Each argument owns its string, which the callback does not free.
+                unsafe { glyn_value_free(arg) };
+            }
+
+            result.into_value()
+        })
+    })
+    .is_ok()
+}
+
+/// Frees the string of a value, if it has one, leaving the value without a string.
+///
+/// # Safety
+///
+/// `value` must be null or point to a value returned by glyn, whose string has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_value_free(value: *mut GlynValue) {
+    if let Some(value) = value.as_mut() {
+        glyn_string_free(value.string);
+
+        value.string = ptr::null_mut();
+    }
+}
+
+/// Creates a string from `length` bytes of UTF-8, replacing invalid sequences with U+FFFD (REPLACEMENT CHARACTER), for a callback to return.
+///
+/// # Safety
+///
+/// `data` must point to `length` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_string_new(data: *const c_char, length: usize) -> *mut GlynString {
+    GlynString::new(&String::from_utf8_lossy(bytes(data, length)))
+}
+
+/// The bytes of the string, which are followed by a NUL byte.
+///
+/// # Safety
+///
+/// `string` must be a string created by glyn which has not been freed. The bytes are valid until it is freed.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_string_data(string: *const GlynString) -> *const c_char {
+    let string = &*string;
+
+    string.bytes.as_ptr().cast()
+}
+
+/// The number of bytes in the string, not counting the NUL byte which follows them.
+///
+/// # Safety
+///
+/// `string` must be a string created by glyn which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_string_length(string: *const GlynString) -> usize {
+    let string = &*string;
+
+    string.bytes.len() - 1
+}
+
+/// Frees a string which is not owned by a value.
+///
+/// # Safety
+///
+/// `string` must be null or a string created by glyn which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn glyn_string_free(string: *mut GlynString) {
+    if !string.is_null() {
+        drop(Box::from_raw(string));
+    }
+}
+
+/// # Safety
+///
+/// `data` must point to `length` readable bytes, or may be null if `length` is 0.
+unsafe fn bytes<'a>(data: *const c_char, length: usize) -> &'a [u8] {
+    if length == 0 {
+        return &[];
+    }
+
+    slice::from_raw_parts(data.cast(), length)
+}
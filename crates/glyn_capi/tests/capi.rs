@@ -0,0 +1,248 @@
+use std::{
+    ffi::{c_char, c_void, CStr},
+    ptr, slice,
+};
+
+use glyn_capi::*;
+
+/// Evaluates the script in the agent.
+fn eval(agent: *mut GlynAgent, source: &str) -> GlynValue {
+    unsafe { glyn_eval(agent, source.as_ptr().cast(), source.len()) }
+}
+
+/// The string of the value, which is freed along with it.
+fn take_string(mut value: GlynValue) -> String {
+    let string = unsafe {
+        let bytes = slice::from_raw_parts(
+            glyn_string_data(value.string).cast::<u8>(),
+            glyn_string_length(value.string),
+        );
+
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+
+    unsafe { glyn_value_free(&mut value) };
+
+    assert!(value.string.is_null());
+
+    string
+}
+
+#[test]
+fn values_are_tagged_with_their_type() {
+    let agent = glyn_agent_new();
+
+    assert_eq!(eval(agent, "undefined;").tag, GlynValueTag::Undefined);
+    assert_eq!(eval(agent, "null;").tag, GlynValueTag::Null);
+
+    let boolean = eval(agent, "1 < 2;");
+
+    assert_eq!(
+        (boolean.tag, boolean.boolean),
+        (GlynValueTag::Boolean, true)
+    );
+
+    let number = eval(agent, "[1, 2, 3].length;");
+
+    assert_eq!((number.tag, number.number), (GlynValueTag::Number, 3.0));
+
+    let string = eval(agent, "\"a\" + \"é\";");
+
+    assert_eq!(string.tag, GlynValueTag::String);
+    assert_eq!(take_string(string), "aé");
+
+    let object = eval(agent, "({ a: [1] });");
+
+    assert_eq!(object.tag, GlynValueTag::Object);
+    assert_eq!(take_string(object), "{ a: [ 1 ] }");
+
+    unsafe { glyn_agent_free(agent) };
+}
+
+#[test]
+fn errors_are_returned_as_exceptions() {
+    let agent = glyn_agent_new();
+
+    let thrown = eval(agent, "throw \"boom\";");
+
+    assert_eq!(thrown.tag, GlynValueTag::Exception);
+    assert!(take_string(thrown).starts_with("boom"));
+
+    let syntax_error = eval(agent, "1 +;");
+
+    assert_eq!(syntax_error.tag, GlynValueTag::Exception);
+    assert!(take_string(syntax_error).starts_with("SyntaxError: "));
+
+    let invalid = unsafe { glyn_eval(agent, [0xffu8].as_ptr().cast(), 1) };
+
+    assert_eq!(
+        take_string(invalid),
+        "SyntaxError: the source text is not valid UTF-8"
+    );
+
+    unsafe { glyn_agent_free(agent) };
+}
+
+unsafe extern "C" fn sum(args: *const GlynValue, count: usize, data: *mut c_void) -> GlynValue {
+    let calls = &mut *data.cast::<u32>();
+
+    *calls += 1;
+
+    GlynValue {
+        tag: GlynValueTag::Number,
+        boolean: false,
+        number: slice::from_raw_parts(args, count)
+            .iter()
+            .map(|arg| arg.number)
+            .sum(),
+        string: ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn shout(args: *const GlynValue, count: usize, _data: *mut c_void) -> GlynValue {
+    let (tag, message) = match slice::from_raw_parts(args, count) {
+        [arg] if arg.tag == GlynValueTag::String => (
+            GlynValueTag::String,
+            CStr::from_ptr(glyn_string_data(arg.string))
+                .to_string_lossy()
+                .to_uppercase(),
+        ),
+        _ => (
+            GlynValueTag::Exception,
+            String::from("TypeError: shout expects a string"),
+        ),
+    };
+
+    GlynValue {
+        tag,
+        boolean: false,
+        number: 0.0,
+        string: glyn_string_new(message.as_ptr().cast::<c_char>(), message.len()),
+    }
+}
+
+#[test]
+fn callbacks_can_be_called_by_scripts() {
+    let agent = glyn_agent_new();
+    let mut calls = 0u32;
+
+    unsafe {
+        assert!(glyn_register_function(
+            agent,
+            c"sum".as_ptr(),
+            2,
+            sum,
+            (&raw mut calls).cast()
+        ));
+        assert!(glyn_register_function(
+            agent,
+            c"shout".as_ptr(),
+            1,
+            shout,
+            ptr::null_mut()
+        ));
+    }
+
+    assert_eq!(eval(agent, "sum(1, 2) + sum(3);").number, 6.0);
+    assert_eq!(calls, 2);
+
+    assert_eq!(
+        take_string(eval(agent, "shout(\"hi\") + sum.length;")),
+        "HI2"
+    );
+    assert_eq!(
+        take_string(eval(
+            agent,
+            "let message; try { shout(1); } catch (e) { message = e; } message;"
+        )),
+        "TypeError: shout expects a string"
+    );
+
+    unsafe { glyn_agent_free(agent) };
+}
+
+unsafe extern "C" fn evaluate(
+    args: *const GlynValue,
+    count: usize,
+    data: *mut c_void,
+) -> GlynValue {
+    let agent = data.cast::<GlynAgent>();
+    let source = CStr::from_ptr(glyn_string_data(
+        slice::from_raw_parts(args, count)[0].string,
+    ));
+
+    assert!(!glyn_register_function(
+        agent,
+        c"evaluate".as_ptr(),
+        0,
+        evaluate,
+        data
+    ));
+
+    glyn_eval(agent, source.as_ptr(), source.count_bytes())
+}
+
+#[test]
+fn callbacks_cannot_use_the_agent_which_called_them() {
+    let agent = glyn_agent_new();
+
+    unsafe {
+        assert!(glyn_register_function(
+            agent,
+            c"evaluate".as_ptr(),
+            1,
+            evaluate,
+            agent.cast()
+        ));
+    }
+
+    assert_eq!(
+        take_string(eval(
+            agent,
+            "let message; try { evaluate(\"1;\"); } catch (e) { message = e; } message;"
+        )),
+        "Error: the agent is already in use by the script which called back"
+    );
+    assert_eq!(eval(agent, "1 + 1;").number, 2.0);
+
+    unsafe { glyn_agent_free(agent) };
+}
+
+unsafe extern "C" fn free_agent(
+    _args: *const GlynValue,
+    _count: usize,
+    data: *mut c_void,
+) -> GlynValue {
+    glyn_agent_free(data.cast::<GlynAgent>());
+
+    GlynValue {
+        tag: GlynValueTag::Object,
+        boolean: false,
+        number: 0.0,
+        string: ptr::null_mut(),
+    }
+}
+
+#[test]
+fn callbacks_can_free_the_agent_which_called_them() {
+    let agent = glyn_agent_new();
+
+    unsafe {
+        assert!(glyn_register_function(
+            agent,
+            c"freeAgent".as_ptr(),
+            0,
+            free_agent,
+            agent.cast()
+        ));
+    }
+
+    // NOTE: The agent is freed once the script has been evaluated, so the script carries on after the callback returns.
+    assert_eq!(
+        take_string(eval(
+            agent,
+            "let message; try { freeAgent(); } catch (e) { message = (e instanceof TypeError) + \": \" + e.message; } message;"
+        )),
+        "true: a callback cannot return a value of type Object"
+    );
+}
@@ -0,0 +1,87 @@
+use std::{iter::Peekable, str::Chars};
+
+/// Whether the source ends inside a block, a bracketed expression, a template literal, a comment or a string, so that another line should be read before it is evaluated.
+///
+/// Unbalanced closing brackets and strings which reach a line break which is not escaped are syntax errors whatever follows them, so such source is complete and left for the parser to report.
+pub fn is_incomplete(source: &str) -> bool {
+    // The closing bracket of each construct the source is inside, innermost last, where a backtick stands for the text of a template literal.
+    let mut closers = vec![];
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if closers.last() == Some(&'`') {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '`' => {
+                    closers.pop();
+                }
+                '$' if chars.next_if_eq(&'{').is_some() => closers.push('}'),
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '\'' | '"' => match skip_string(&mut chars, c) {
+                StringEnd::Closed => {}
+                StringEnd::LineBreak => return false,
+                StringEnd::Unterminated => return true,
+            },
+            '`' => closers.push('`'),
+            '/' if chars.next_if_eq(&'/').is_some() => {
+                chars.by_ref().find(|&c| c == '\n');
+            }
+            // NOTE: A block comment which is closed is skipped by the guard.
+            '/' if chars.next_if_eq(&'*').is_some() && !skip_block_comment(&mut chars) => {
+                return true;
+            }
+            '(' => closers.push(')'),
+            '[' => closers.push(']'),
+            '{' => closers.push('}'),
+            // NOTE: A closing bracket which matches the innermost opening bracket is popped by the guard.
+            ')' | ']' | '}' if closers.pop() != Some(c) => return false,
+            _ => {}
+        }
+    }
+
+    !closers.is_empty()
+}
+
+/// How a string literal ends.
+enum StringEnd {
+    Closed,
+    /// The string reaches a line break which is not escaped, which is a syntax error.
+    LineBreak,
+    /// The string reaches the end of the source, so the next line is read as for a template literal or comment, and is a syntax error unless the source ends with a backslash continuing the string onto it.
+    Unterminated,
+}
+
+/// Skips the rest of a string literal opened with the quote.
+fn skip_string(chars: &mut Peekable<Chars>, quote: char) -> StringEnd {
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '\n' => return StringEnd::LineBreak,
+            c if c == quote => return StringEnd::Closed,
+            _ => {}
+        }
+    }
+
+    StringEnd::Unterminated
+}
+
+/// Skips the rest of a block comment, returning whether it was closed.
+fn skip_block_comment(chars: &mut Peekable<Chars>) -> bool {
+    while let Some(c) = chars.next() {
+        if c == '*' && chars.next_if_eq(&'/').is_some() {
+            return true;
+        }
+    }
+
+    false
+}
@@ -0,0 +1,5 @@
+mod continuation;
+mod repl;
+
+pub use continuation::is_incomplete;
+pub use repl::{Repl, ReplOutput};
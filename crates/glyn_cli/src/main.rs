@@ -0,0 +1,121 @@
+use glyn_cli::{Repl, ReplOutput};
+use glyn_interpreter::{compile_script, disassemble, script_to_bytecode_json, OptimizationLevel};
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const HELP: &str = "\
+Glyn JavaScript Interpreter
+
+USAGE:
+    glyn [script...]             Start the REPL, after evaluating each script in its session
+    glyn --file <script>         Evaluate the script, then exit
+    glyn --eval <code>           Evaluate the code, then exit
+    glyn --dump-json <code>      Print the compiled bytecode of the code as JSON
+    glyn --disassemble <code>    Print the compiled bytecode of the code as a listing
+    glyn --help                  Print this help message
+
+OPTIONS:
+    -f, --file <script>          Evaluate the script, then exit
+    -e, --eval <code>            Evaluate the code, then exit
+    -j, --dump-json <code>       Print the compiled bytecode of the code as JSON
+    -d, --disassemble <code>     Print the compiled bytecode of the code as a listing
+    -h, --help                   Print this help message
+
+ENVIRONMENT:
+    GLYN_TRACE                   Trace levels per subsystem when built with the debug feature, e.g. vm=trace,gc=debug";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("--help" | "-h") => println!("{HELP}"),
+        Some(flag) if flag.starts_with('-') => {
+            let output = run_flag(flag, args.get(1));
+
+            let failed = matches!(output, ReplOutput::Error(_));
+
+            print(output);
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        _ => run_repl(&args),
+    }
+}
+
+/// Runs the flag with its argument, without starting the REPL.
+fn run_flag(flag: &str, argument: Option<&String>) -> ReplOutput {
+    let argument =
+        || argument.unwrap_or_else(|| exit_with_usage(&format!("{flag} requires an argument")));
+
+    match flag {
+        "--file" | "-f" => Repl::new().feed(&format!(".load {}", argument())),
+        "--eval" | "-e" => Repl::new().evaluate(argument().clone()),
+        "--dump-json" | "-j" => match script_to_bytecode_json(argument()) {
+            Ok(json) => ReplOutput::Print(json),
+            Err(error) => ReplOutput::Error(error.to_string()),
+        },
+        "--disassemble" | "-d" => match compile_script(argument(), OptimizationLevel::None) {
+            Ok(script) => ReplOutput::Print(disassemble(&script)),
+            Err(error) => ReplOutput::Error(error.to_string()),
+        },
+        _ => exit_with_usage(&format!("Unknown argument '{flag}'")),
+    }
+}
+
+fn run_repl(filenames: &[String]) {
+    let mut repl = Repl::new();
+
+    // NOTE: Each file given as an argument is loaded into the session before any input is read, so that its bindings can be used from the prompt.
+    for filename in filenames {
+        if !print(repl.feed(&format!(".load {filename}"))) {
+            return;
+        }
+    }
+
+    println!("Glyn JavaScript REPL, type .help for the list of commands");
+
+    let mut lines = io::stdin().lock().lines();
+
+    loop {
+        print!("{}", repl.prompt());
+
+        io::stdout().flush().unwrap();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(error)) => {
+                eprintln!("Error reading input: {error}");
+
+                break;
+            }
+            None => break,
+        };
+
+        if !print(repl.feed(&line)) {
+            break;
+        }
+    }
+}
+
+/// Prints the output of the REPL, returning whether it should keep reading input.
+fn print(output: ReplOutput) -> bool {
+    match output {
+        ReplOutput::Continue => {}
+        ReplOutput::Print(text) => println!("{text}"),
+        ReplOutput::Error(error) => eprintln!("{error}"),
+        ReplOutput::Exit => return false,
+    }
+
+    true
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    eprintln!();
+    eprintln!("{HELP}");
+
+    process::exit(1);
+}
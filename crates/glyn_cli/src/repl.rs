@@ -0,0 +1,141 @@
+use std::fs;
+
+use glyn_interpreter::{compile_script, disassemble, eval_script, JSAgent, OptimizationLevel};
+
+use crate::continuation::is_incomplete;
+
+const HELP: &str = "\
+.break                 Discard the input which has not been evaluated yet
+.dump-bytecode [code]  Print the bytecode of the code, or of the last input if none is given
+.exit                  Exit the REPL
+.help                  Print this help message
+.load <file>           Evaluate the file in the current session";
+
+/// What the REPL produced for a line of input.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplOutput {
+    /// The input continues on the next line, so nothing has been evaluated yet.
+    Continue,
+    /// Text to print, such as the value the input evaluated to.
+    Print(String),
+    /// An error to print, such as a syntax error or an uncaught exception.
+    Error(String),
+    /// The REPL should exit.
+    Exit,
+}
+
+/// A read-eval-print loop, which evaluates each input as a script in the same realm, so that the bindings declared by one input can be used by the next.
+pub struct Repl {
+    agent: JSAgent,
+    /// The lines of an input which is not complete yet.
+    pending: String,
+    /// The source of the last input which was evaluated, which `.dump-bytecode` disassembles when it is given no code.
+    last_input: Option<String>,
+}
+
+impl Default for Repl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Repl {
+    pub fn new() -> Self {
+        Self {
+            agent: JSAgent::default(),
+            pending: String::new(),
+            last_input: None,
+        }
+    }
+
+    /// The prompt to show before reading the next line, which shows whether the line continues the input before it.
+    pub fn prompt(&self) -> &'static str {
+        if self.pending.is_empty() {
+            "> "
+        } else {
+            "... "
+        }
+    }
+
+    /// Reads a line of input, evaluating it once it completes the input, or running it as a command if it starts with a dot.
+    pub fn feed(&mut self, line: &str) -> ReplOutput {
+        if self.pending.is_empty() {
+            if let Some(command) = line.trim().strip_prefix('.') {
+                return self.command(command);
+            }
+        } else if line.trim() == ".break" {
+            self.pending.clear();
+
+            return ReplOutput::Continue;
+        }
+
+        if !self.pending.is_empty() {
+            self.pending.push('\n');
+        }
+
+        self.pending.push_str(line);
+
+        if is_incomplete(&self.pending) {
+            return ReplOutput::Continue;
+        }
+
+        let source = std::mem::take(&mut self.pending);
+
+        if source.trim().is_empty() {
+            return ReplOutput::Continue;
+        }
+
+        self.evaluate(source)
+    }
+
+    fn command(&mut self, command: &str) -> ReplOutput {
+        let (name, argument) = command
+            .split_once(char::is_whitespace)
+            .map_or((command, ""), |(name, argument)| (name, argument.trim()));
+
+        match name {
+            "break" => ReplOutput::Continue,
+            "dump-bytecode" => {
+                let source = match (argument, &self.last_input) {
+                    ("", Some(last_input)) => last_input.clone(),
+                    ("", None) => return ReplOutput::Error(String::from("No input to dump")),
+                    (code, _) => code.to_string(),
+                };
+
+                match compile_script(&source, OptimizationLevel::None) {
+                    Ok(script) => ReplOutput::Print(disassemble(&script)),
+                    Err(error) => ReplOutput::Error(error.to_string()),
+                }
+            }
+            "exit" => ReplOutput::Exit,
+            "help" => ReplOutput::Print(String::from(HELP)),
+            "load" if argument.is_empty() => {
+                ReplOutput::Error(String::from(".load requires a file name"))
+            }
+            "load" => match fs::read_to_string(argument) {
+                Ok(source) => self.evaluate(source),
+                Err(error) => ReplOutput::Error(format!("Cannot read '{argument}': {error}")),
+            },
+            _ => ReplOutput::Error(format!(
+                "Unknown command '.{name}', type .help for the list of commands"
+            )),
+        }
+    }
+
+    /// Evaluates the source as a single input, then runs the promise jobs it enqueued.
+    pub fn evaluate(&mut self, source: String) -> ReplOutput {
+        let result = eval_script(&mut self.agent, &source).map_err(|error| error.render(&source));
+
+        self.last_input = Some(source);
+
+        let value = match result {
+            Ok(value) => value,
+            Err(error) => return ReplOutput::Error(error),
+        };
+
+        match self.agent.run_jobs() {
+            Ok(()) => ReplOutput::Print(value.inspect()),
+            Err(error) => ReplOutput::Error(error.to_string()),
+        }
+    }
+}
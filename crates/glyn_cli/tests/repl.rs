@@ -0,0 +1,132 @@
+use glyn_cli::{is_incomplete, Repl, ReplOutput};
+
+fn print(text: &str) -> ReplOutput {
+    ReplOutput::Print(String::from(text))
+}
+
+#[test]
+fn incomplete_input_is_detected() {
+    assert!(is_incomplete("function f() {"));
+    assert!(is_incomplete("f(1,"));
+    assert!(is_incomplete("let a = [1, [2"));
+    assert!(is_incomplete("`abc"));
+    assert!(is_incomplete("`a${ {"));
+    assert!(is_incomplete("/* comment"));
+    assert!(is_incomplete("let s = 'a\\"));
+    assert!(is_incomplete("let s = 'abc"));
+
+    assert!(!is_incomplete("function f() {}"));
+    assert!(!is_incomplete("'{' + \"(\" + `[${1}`"));
+    assert!(!is_incomplete("// {"));
+    assert!(!is_incomplete("/* { */ 1"));
+    assert!(!is_incomplete("let s = 'abc\ndef'"));
+    assert!(!is_incomplete("f())"));
+}
+
+#[test]
+fn bindings_persist_across_inputs() {
+    let mut repl = Repl::new();
+
+    assert_eq!(repl.feed("let a = 1;"), print("undefined"));
+    assert_eq!(
+        repl.feed("function f(x) { return x + a; }"),
+        print("undefined")
+    );
+    assert_eq!(repl.feed("f(2)"), print("3"));
+    assert_eq!(
+        repl.feed("({ a: a, b: [f(1)] })"),
+        print("{ a: 1, b: [ 2 ] }")
+    );
+    assert_eq!(repl.feed(""), ReplOutput::Continue);
+}
+
+#[test]
+fn inputs_continue_over_several_lines() {
+    let mut repl = Repl::new();
+
+    assert_eq!(repl.prompt(), "> ");
+    assert_eq!(repl.feed("function g() {"), ReplOutput::Continue);
+    assert_eq!(repl.prompt(), "... ");
+    assert_eq!(repl.feed("  return 'g';"), ReplOutput::Continue);
+    assert_eq!(repl.feed("}"), print("undefined"));
+    assert_eq!(repl.prompt(), "> ");
+    assert_eq!(repl.feed("g()"), print("'g'"));
+
+    assert_eq!(repl.feed("g(("), ReplOutput::Continue);
+    assert_eq!(repl.feed(".break"), ReplOutput::Continue);
+    assert_eq!(repl.prompt(), "> ");
+}
+
+#[test]
+fn errors_are_reported() {
+    let mut repl = Repl::new();
+
+    assert_eq!(
+        repl.feed("throw 'boom';"),
+        ReplOutput::Error(String::from("boom\n    at 1:1"))
+    );
+    assert!(
+        matches!(repl.feed("let = ;"), ReplOutput::Error(error) if error.starts_with("SyntaxError"))
+    );
+    assert_eq!(repl.feed("'abc"), ReplOutput::Continue);
+    assert!(
+        matches!(repl.feed("def'"), ReplOutput::Error(error) if error.starts_with("SyntaxError: Unterminated string literal"))
+    );
+    assert_eq!(repl.feed("let x = 1;"), print("undefined"));
+    assert!(
+        matches!(repl.feed("let x = 2;"), ReplOutput::Error(error) if error.starts_with("SyntaxError: Identifier 'x' has already been declared"))
+    );
+    assert_eq!(repl.feed("1"), print("1"));
+}
+
+#[test]
+fn commands() {
+    let mut repl = Repl::new();
+
+    assert!(matches!(repl.feed(".help"), ReplOutput::Print(help) if help.contains(".load <file>")));
+    assert_eq!(repl.feed(".exit"), ReplOutput::Exit);
+    assert!(matches!(repl.feed(".nope"), ReplOutput::Error(_)));
+    assert!(matches!(repl.feed(".dump-bytecode"), ReplOutput::Error(_)));
+
+    repl.feed("let x = 1;");
+
+    assert!(
+        matches!(repl.feed(".dump-bytecode"), ReplOutput::Print(listing) if listing.contains("CreateMutableBinding"))
+    );
+    assert!(
+        matches!(repl.feed(".dump-bytecode x + 1"), ReplOutput::Print(listing) if listing.contains("BinAdd"))
+    );
+
+    let path = std::env::temp_dir().join(format!("glyn_repl_{}.js", std::process::id()));
+
+    std::fs::write(&path, "let loaded = 2;\nloaded * 3").unwrap();
+
+    assert_eq!(repl.feed(&format!(".load {}", path.display())), print("6"));
+    assert_eq!(repl.feed("loaded"), print("2"));
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(
+        repl.feed(&format!(".load {}", path.display())),
+        ReplOutput::Error(_)
+    ));
+    assert!(matches!(repl.feed(".load"), ReplOutput::Error(_)));
+}
+
+#[test]
+fn evaluate_runs_source_as_a_single_input() {
+    let mut repl = Repl::new();
+
+    assert!(matches!(
+        repl.evaluate(String::from(".exit")),
+        ReplOutput::Error(_)
+    ));
+    assert_eq!(
+        repl.evaluate(String::from("let b = [1,\n2];\nb")),
+        print("[ 1, 2 ]")
+    );
+    assert!(matches!(
+        repl.evaluate(String::from("b(")),
+        ReplOutput::Error(_)
+    ));
+}
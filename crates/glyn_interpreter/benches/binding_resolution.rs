@@ -0,0 +1,49 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use glyn_interpreter::{eval_script, JSAgent};
+
+/// The number of times the loop of each benchmark resolves its bindings.
+const ITERATIONS: usize = 1000;
+
+/// Scripts which each define a function `run` whose loop over `items` resolves one kind of binding many times over.
+const BENCHMARKS: &[(&str, &str)] = &[
+    (
+        "local",
+        "function run() { let sum = 0; for (let i of items) { sum = sum + i; } return sum; }",
+    ),
+    (
+        "closure",
+        "let step = 1; function run() { let sum = 0; function add() { sum = sum + step; } for (let i of items) { add(); } return sum; }",
+    ),
+    (
+        "global",
+        "let total = 0; function run() { total = 0; for (let i of items) { total = total + i; } return total; }",
+    ),
+    (
+        "block",
+        "function run() { let sum = 0; for (let i of items) { let j = i; { let k = j; sum = sum + k; } } return sum; }",
+    ),
+];
+
+fn binding_resolution(c: &mut Criterion) {
+    let items = (0..ITERATIONS)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut group = c.benchmark_group("binding_resolution");
+
+    for (name, source) in BENCHMARKS {
+        let mut agent = JSAgent::default();
+
+        eval_script(&mut agent, &format!("let items = [{items}]; {source}")).unwrap();
+
+        group.bench_function(*name, |b| {
+            b.iter(|| eval_script(&mut agent, "run()").unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, binding_resolution);
+criterion_main!(benches);
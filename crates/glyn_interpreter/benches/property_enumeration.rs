@@ -0,0 +1,59 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use glyn_interpreter::{eval_script, JSAgent};
+
+/// The number of own properties of the object each benchmark enumerates.
+const PROPERTIES: usize = 1000;
+
+/// The object literal with an own property for each key.
+fn object_literal(keys: impl Iterator<Item = String>) -> String {
+    let properties = keys
+        .enumerate()
+        .map(|(value, key)| format!("{key}: {value}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{{ {properties} }}")
+}
+
+fn property_enumeration(c: &mut Criterion) {
+    let elements = object_literal((0..PROPERTIES).map(|i| i.to_string()));
+    let named = object_literal((0..PROPERTIES).map(|i| format!("p{i}")));
+    let mixed = object_literal((0..PROPERTIES).map(|i| match i % 2 {
+        0 => format!("p{i}"),
+        _ => (PROPERTIES - i).to_string(),
+    }));
+
+    let deletions = (0..PROPERTIES)
+        .step_by(2)
+        .map(|i| format!("delete o.p{i};"))
+        .collect::<String>();
+
+    // Objects which each have many own properties of one kind, which `run` enumerates with a for-in loop.
+    let benchmarks = [
+        ("elements", format!("let o = {elements};")),
+        ("named", format!("let o = {named};")),
+        ("mixed", format!("let o = {mixed};")),
+        ("deleted", format!("let o = {named}; {deletions}")),
+    ];
+
+    let mut group = c.benchmark_group("property_enumeration");
+
+    for (name, source) in benchmarks {
+        let mut agent = JSAgent::default();
+
+        eval_script(
+            &mut agent,
+            &format!("{source} function run() {{ let n = 0; for (let key in o) {{ n = n + 1; }} return n; }}"),
+        )
+        .unwrap();
+
+        group.bench_function(name, |b| {
+            b.iter(|| eval_script(&mut agent, "run()").unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, property_enumeration);
+criterion_main!(benches);
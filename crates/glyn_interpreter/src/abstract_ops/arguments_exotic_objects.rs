@@ -0,0 +1,299 @@
+use crate::{
+    abstract_ops::{
+        object_operations::{
+            create_data_property_or_throw, define_property_or_throw, make_basic_object,
+        },
+        ordinary::ordinary_object_create,
+    },
+    gc::{Trace, Tracer},
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_ITERATOR},
+        completion::CompletionRecord,
+        environment::EnvironmentAddr,
+    },
+    value::{
+        atom::Atom,
+        object::{
+            internal_slots::InternalSlotName,
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectKind, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+// 10.4.4 Arguments Exotic Objects
+// https://262.ecma-international.org/16.0/#sec-arguments-exotic-objects
+
+/// The [[ParameterMap]] of a mapped arguments object.
+///
+/// The specification holds the map as an object whose properties are the accessors created by MakeArgGetter and MakeArgSetter. Those accessors only read and write bindings of the function's Environment Record, so the map is held as the name of the formal parameter each index aliases until it is unmapped.
+#[derive(Debug)]
+pub(crate) struct ParameterMap {
+    env: EnvironmentAddr,
+    names: Vec<Option<Atom>>,
+}
+
+impl Trace for ParameterMap {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.env.trace(tracer);
+    }
+}
+
+/// A property of a mapped arguments object whose value is shared with a binding of a formal parameter.
+pub(crate) struct MappedArgument {
+    env: EnvironmentAddr,
+    name: Atom,
+}
+
+impl MappedArgument {
+    /// 10.4.4.7.1 MakeArgGetter ( name, env )
+    /// https://262.ecma-international.org/16.0/#sec-makearggetter
+    pub(crate) fn get(&self) -> CompletionRecord<JSValue> {
+        // 1. Return env.GetBindingValue(name, false).
+        self.env.get_declarative_binding_value(self.name.clone())
+    }
+
+    /// 10.4.4.7.2 MakeArgSetter ( name, env )
+    /// https://262.ecma-international.org/16.0/#sec-makeargsetter
+    pub(crate) fn set(&self, value: JSValue) -> CompletionRecord {
+        // 1. Return ! env.SetMutableBinding(name, value, false).
+        self.env
+            .set_declarative_mutable_binding(self.name.clone(), value, false)
+    }
+}
+
+/// HasOwnProperty(map, P) for the [[ParameterMap]] of an arguments object, returning the formal parameter the property is mapped to if it is.
+pub(crate) fn mapped_argument(
+    arguments: &impl ObjectMeta,
+    key: &JSObjectPropKey,
+) -> Option<MappedArgument> {
+    let index = key.as_array_index()? as usize;
+
+    let data = arguments.data();
+    let map = data.slots().parameter_map()?;
+
+    map.names.get(index)?.as_ref().map(|name| MappedArgument {
+        env: map.env.clone(),
+        name: name.clone(),
+    })
+}
+
+/// map.[[Delete]](P) for the [[ParameterMap]] of an arguments object, after which the property no longer shares its value with a formal parameter.
+pub(crate) fn unmap_argument(arguments: &impl ObjectMeta, key: &JSObjectPropKey) {
+    let Some(index) = key.as_array_index() else {
+        return;
+    };
+
+    if let Some(name) = arguments
+        .data_mut()
+        .slots_mut()
+        .parameter_map_mut()
+        .and_then(|map| map.names.get_mut(index as usize))
+    {
+        *name = None;
+    }
+}
+
+/// 10.4.4.6 CreateUnmappedArgumentsObject ( argumentsList )
+/// https://262.ecma-international.org/16.0/#sec-createunmappedargumentsobject
+pub(crate) fn create_unmapped_arguments_object(
+    agent: &mut JSAgent,
+    arguments_list: &[JSValue],
+) -> ObjectAddr {
+    let (object_prototype, array_prototype_values, throw_type_error) = {
+        let realm_addr = agent.current_realm();
+        let realm = realm_addr.borrow();
+        let intrinsics = &realm.intrinsics;
+
+        (
+            intrinsics.object_prototype.clone(),
+            intrinsics.array_prototype_values.clone().unwrap(),
+            intrinsics.throw_type_error.clone().unwrap(),
+        )
+    };
+
+    // 1. Let len be the number of elements in argumentsList.
+    let len = arguments_list.len();
+
+    // 2. Let obj be OrdinaryObjectCreate(%Object.prototype%, « [[ParameterMap]] »).
+    // 3. Set obj.[[ParameterMap]] to undefined.
+    // NOTE: The slot is left unset, which distinguishes an unmapped arguments object from an ordinary object.
+    let obj = ordinary_object_create(
+        agent,
+        object_prototype,
+        Some(vec![InternalSlotName::ParameterMap]),
+    );
+
+    // 4. Perform ! DefinePropertyOrThrow(obj, "length", PropertyDescriptor { [[Value]]: 𝔽(len), [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+    define_length_property(&obj, len);
+
+    // 5. Let index be 0.
+    // 6. Repeat, while index < len,
+    define_index_properties(&obj, arguments_list);
+
+    // 7. Perform ! DefinePropertyOrThrow(obj, %Symbol.iterator%, PropertyDescriptor { [[Value]]: %Array.prototype.values%, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+    define_iterator_property(&obj, array_prototype_values);
+
+    // 8. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor { [[Get]]: %ThrowTypeError%, [[Set]]: %ThrowTypeError%, [[Enumerable]]: false, [[Configurable]]: false }).
+    define_property_or_throw(
+        &obj,
+        &JSObjectPropKey::String("callee".into()),
+        JSObjectPropDescriptor {
+            get: Some(JSValue::from(throw_type_error.clone())),
+            set: Some(JSValue::from(throw_type_error)),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+    .unwrap();
+
+    // 9. Return obj.
+    obj
+}
+
+/// 10.4.4.7 CreateMappedArgumentsObject ( func, formals, argumentsList, env )
+/// https://262.ecma-international.org/16.0/#sec-createmappedargumentsobject
+pub(crate) fn create_mapped_arguments_object(
+    agent: &mut JSAgent,
+    function_obj: &ObjectAddr,
+    parameter_names: &[Atom],
+    arguments_list: &[JSValue],
+    env: EnvironmentAddr,
+) -> ObjectAddr {
+    let (object_prototype, array_prototype_values) = {
+        let realm_addr = agent.current_realm();
+        let realm = realm_addr.borrow();
+        let intrinsics = &realm.intrinsics;
+
+        (
+            intrinsics.object_prototype.clone(),
+            intrinsics.array_prototype_values.clone().unwrap(),
+        )
+    };
+
+    // 1. Assert: formals does not contain a rest parameter, any binding patterns, or any initializers. It may contain duplicate identifiers.
+    // 2. Let len be the number of elements in argumentsList.
+    let len = arguments_list.len();
+
+    // 3. Let obj be MakeBasicObject(« [[Prototype]], [[Extensible]], [[ParameterMap]] »).
+    let obj = make_basic_object(agent, vec![InternalSlotName::ParameterMap]);
+
+    // 4. Set obj.[[GetOwnProperty]] as specified in 10.4.4.1.
+    // 5. Set obj.[[DefineOwnProperty]] as specified in 10.4.4.2.
+    // 6. Set obj.[[Get]] as specified in 10.4.4.3.
+    // 7. Set obj.[[Set]] as specified in 10.4.4.4.
+    // 8. Set obj.[[Delete]] as specified in 10.4.4.5.
+    obj.data_mut().set_kind(ObjectKind::Arguments);
+
+    // 9. Set obj.[[Prototype]] to %Object.prototype%.
+    obj.data_mut().set_prototype(object_prototype);
+
+    // 10. Let map be OrdinaryObjectCreate(null).
+    // 11. Set obj.[[ParameterMap]] to map.
+    // NOTE: No index is mapped until the formal parameters are added to the map below.
+    // 12. Let parameterNames be the BoundNames of formals.
+    // 13. Let numberOfParameters be the number of elements in parameterNames.
+    let number_of_parameters = parameter_names.len();
+
+    // 14. Let index be 0.
+    // 15. Repeat, while index < len,
+    define_index_properties(&obj, arguments_list);
+
+    // 16. Perform ! DefinePropertyOrThrow(obj, "length", PropertyDescriptor { [[Value]]: 𝔽(len), [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+    define_length_property(&obj, len);
+
+    // 17. Let mappedNames be a new empty List.
+    let mut names = vec![None; len.min(number_of_parameters)];
+
+    // 18. Let index be numberOfParameters - 1.
+    // 19. Repeat, while index ≥ 0,
+    for (index, name) in parameter_names.iter().enumerate().rev() {
+        // a. Let name be parameterNames[index].
+        // b. If mappedNames does not contain name, then
+        // i. Append name to mappedNames.
+        // NOTE: Only the last of any duplicate parameters is mapped, as it is the one whose binding holds the argument.
+        if parameter_names[index + 1..].contains(name) {
+            continue;
+        }
+
+        // ii. If index < len, then
+        // 1. Let g be MakeArgGetter(name, env).
+        // 2. Let p be MakeArgSetter(name, env).
+        // 3. Perform ! map.[[DefineOwnProperty]](! ToString(𝔽(index)), PropertyDescriptor { [[Set]]: p, [[Get]]: g, [[Enumerable]]: false, [[Configurable]]: true }).
+        if index < len {
+            names[index] = Some(name.clone());
+        }
+
+        // c. Set index to index - 1.
+    }
+
+    obj.data_mut()
+        .slots_mut()
+        .set_parameter_map(ParameterMap { env, names });
+
+    // 20. Perform ! DefinePropertyOrThrow(obj, %Symbol.iterator%, PropertyDescriptor { [[Value]]: %Array.prototype.values%, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+    define_iterator_property(&obj, array_prototype_values);
+
+    // 21. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor { [[Value]]: func, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+    define_property_or_throw(
+        &obj,
+        &JSObjectPropKey::String("callee".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(function_obj.clone())),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+    .unwrap();
+
+    // 22. Return obj.
+    obj
+}
+
+fn define_length_property(obj: &ObjectAddr, len: usize) {
+    define_property_or_throw(
+        obj,
+        &JSObjectPropKey::String("length".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(len as f64)),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+    .unwrap();
+}
+
+fn define_index_properties(obj: &ObjectAddr, arguments_list: &[JSValue]) {
+    for (index, value) in arguments_list.iter().enumerate() {
+        // a. Let val be argumentsList[index].
+        // b. Perform ! CreateDataPropertyOrThrow(obj, ! ToString(𝔽(index)), val).
+        // c. Set index to index + 1.
+        create_data_property_or_throw(
+            obj,
+            &JSObjectPropKey::String(index.to_string().into()),
+            value.clone(),
+        )
+        .unwrap();
+    }
+}
+
+fn define_iterator_property(obj: &ObjectAddr, array_prototype_values: ObjectAddr) {
+    define_property_or_throw(
+        obj,
+        &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(array_prototype_values)),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+    .unwrap();
+}
@@ -0,0 +1,282 @@
+use crate::{
+    abstract_ops::{
+        object_operations::{construct, get, get_function_realm, make_basic_object},
+        ordinary::{ordinary_define_own_property, ordinary_get_own_property},
+        testing_comparison::{is_array, is_constructor, same_value_zero},
+        type_conversion::string_to_number,
+    },
+    runtime::{
+        agent::{range_error, type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_SPECIES},
+        completion::CompletionRecord,
+    },
+    value::{
+        number::JSNumber,
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectEssentialInternalMethods, ObjectKind, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+// 10.4.2 Array Exotic Objects
+// https://262.ecma-international.org/16.0/#sec-array-exotic-objects
+
+/// 10.4.2.2 ArrayCreate ( length [ , proto ] )
+/// https://262.ecma-international.org/16.0/#sec-arraycreate
+pub(crate) fn array_create(
+    agent: &mut JSAgent,
+    length: u64,
+    proto: Option<ObjectAddr>,
+) -> CompletionRecord<ObjectAddr> {
+    // 1. If length > 2**32 - 1, throw a RangeError exception.
+    if length > u32::MAX as u64 {
+        return range_error("Invalid array length");
+    }
+
+    // 2. If proto is not present, set proto to %Array.prototype%.
+    let proto = proto.or_else(|| {
+        agent
+            .current_realm()
+            .borrow()
+            .intrinsics
+            .array_prototype
+            .clone()
+    });
+
+    // 3. Let A be MakeBasicObject(« [[Prototype]], [[Extensible]] »).
+    let a = make_basic_object(agent, vec![]);
+
+    // 4. Set A.[[Prototype]] to proto.
+    a.data_mut().set_prototype(proto);
+
+    // 5. Set A.[[DefineOwnProperty]] as specified in 10.4.2.1.
+    a.data_mut().set_kind(ObjectKind::Array);
+
+    // 6. Perform ! OrdinaryDefineOwnProperty(A, "length", PropertyDescriptor { [[Value]]: 𝔽(length), [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
+    ordinary_define_own_property(
+        &a,
+        &JSObjectPropKey::String("length".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(length as f64)),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+    .unwrap();
+
+    // 7. Return A.
+    Ok(a)
+}
+
+/// 10.4.2.3 ArraySpeciesCreate ( originalArray, length )
+/// https://262.ecma-international.org/16.0/#sec-arrayspeciescreate
+pub(crate) fn array_species_create(
+    agent: &mut JSAgent,
+    original_array: &ObjectAddr,
+    length: u64,
+) -> CompletionRecord<ObjectAddr> {
+    // 1. Let isArray be ? IsArray(originalArray).
+    // 2. If isArray is false, return ? ArrayCreate(length).
+    if !is_array(&JSValue::from(original_array.clone())) {
+        return array_create(agent, length, None);
+    }
+
+    // 3. Let C be ? Get(originalArray, "constructor").
+    let mut c = get(
+        agent,
+        original_array,
+        &JSObjectPropKey::String("constructor".into()),
+    )?;
+
+    // 4. If IsConstructor(C) is true, then
+    if let JSValue::Object(constructor) = &c {
+        if is_constructor(c.clone()) {
+            // a. Let thisRealm be the current Realm Record.
+            let this_realm = agent.current_realm();
+
+            // b. Let realmC be ? GetFunctionRealm(C).
+            let realm_c = get_function_realm(agent, constructor)?;
+
+            // c. If thisRealm and realmC are not the same Realm Record, then
+            // i. If SameValue(C, realmC.[[Intrinsics]].[[%Array%]]) is true, set C to undefined.
+            if this_realm != realm_c
+                && realm_c.borrow().intrinsics.array.as_ref() == Some(constructor)
+            {
+                c = JSValue::Undefined;
+            }
+        }
+    }
+
+    // 5. If C is an Object, then
+    if let JSValue::Object(constructor) = &c {
+        // a. Set C to ? Get(C, %Symbol.species%).
+        c = get(
+            agent,
+            constructor,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_SPECIES),
+        )?;
+
+        // b. If C is null, set C to undefined.
+        if c.is_null() {
+            c = JSValue::Undefined;
+        }
+    }
+
+    // 6. If C is undefined, return ? ArrayCreate(length).
+    if c.is_undefined() {
+        return array_create(agent, length, None);
+    }
+
+    // 7. If IsConstructor(C) is false, throw a TypeError exception.
+    let JSValue::Object(constructor) = &c else {
+        return type_error_for("Array species is not a constructor", &c);
+    };
+
+    if !is_constructor(c.clone()) {
+        return type_error_for("Array species is not a constructor", &c);
+    }
+
+    // 8. Return ? Construct(C, « 𝔽(length) »).
+    construct(
+        agent,
+        constructor,
+        Some(vec![JSValue::from(length as f64)]),
+        None,
+    )
+}
+
+/// 10.4.2.4 ArraySetLength ( A, Desc )
+/// https://262.ecma-international.org/16.0/#sec-arraysetlength
+pub(crate) fn array_set_length(
+    array: &(impl ObjectMeta + ObjectEssentialInternalMethods),
+    descriptor: JSObjectPropDescriptor,
+) -> CompletionRecord<bool> {
+    let length_key = JSObjectPropKey::String("length".into());
+
+    // 1. If Desc does not have a [[Value]] field, then
+    let Some(value) = &descriptor.value else {
+        // a. Return ! OrdinaryDefineOwnProperty(A, "length", Desc).
+        return ordinary_define_own_property(array, &length_key, descriptor);
+    };
+
+    // 2. Let newLenDesc be a copy of Desc.
+    let mut new_len_desc = descriptor.clone();
+
+    // 3. Let newLen be ? ToUint32(Desc.[[Value]]).
+    // 4. Let numberLen be ? ToNumber(Desc.[[Value]]).
+    // NOTE: The internal methods of objects are not passed the agent, so only values which can be converted without calling into user code are accepted.
+    let number_len = match value {
+        JSValue::Number(number) => number.clone(),
+        JSValue::String(string) => string_to_number(string),
+        // TODO: Convert the remaining types of value once internal methods are passed the agent.
+        _ => return range_error("Invalid array length"),
+    };
+
+    let new_len = number_len.to_uint32();
+
+    // 5. If SameValueZero(newLen, numberLen) is false, throw a RangeError exception.
+    if !same_value_zero(&JSValue::from(new_len), &JSValue::Number(number_len)) {
+        return range_error("Invalid array length");
+    }
+
+    // 6. Set newLenDesc.[[Value]] to newLen.
+    new_len_desc.value = Some(JSValue::from(new_len));
+
+    // 7. Let oldLenDesc be OrdinaryGetOwnProperty(A, "length").
+    // 8. Assert: IsDataDescriptor(oldLenDesc) is true.
+    // 9. Assert: oldLenDesc.[[Configurable]] is false.
+    let old_len_desc = ordinary_get_own_property(array, &length_key)?.unwrap();
+
+    // 10. Let oldLen be oldLenDesc.[[Value]].
+    let old_len = array_length(&old_len_desc);
+
+    // 11. If newLen ≥ oldLen, then
+    if new_len >= old_len {
+        // a. Return ! OrdinaryDefineOwnProperty(A, "length", newLenDesc).
+        return ordinary_define_own_property(array, &length_key, new_len_desc);
+    }
+
+    // 12. If oldLenDesc.[[Writable]] is false, return false.
+    if old_len_desc.writable == Some(false) {
+        return Ok(false);
+    }
+
+    // 13. If newLenDesc does not have a [[Writable]] field or newLenDesc.[[Writable]] is true, then
+    // a. Let newWritable be true.
+    // 14. Else,
+    // a. NOTE: Setting the [[Writable]] attribute to false is deferred in case any elements cannot be deleted.
+    // b. Let newWritable be false.
+    // c. Set newLenDesc.[[Writable]] to true.
+    let new_writable = new_len_desc.writable != Some(false);
+
+    if !new_writable {
+        new_len_desc.writable = Some(true);
+    }
+
+    // 15. Let succeeded be ! OrdinaryDefineOwnProperty(A, "length", newLenDesc).
+    // 16. If succeeded is false, return false.
+    if !ordinary_define_own_property(array, &length_key, new_len_desc.clone())? {
+        return Ok(false);
+    }
+
+    // 17. For each own property key P of A such that P is an array index and ! ToUint32(P) ≥ newLen, in descending numeric index order, do
+    let mut indices = array
+        .data()
+        .element_indices()
+        .filter(|index| *index >= new_len)
+        .collect::<Vec<_>>();
+
+    indices.reverse();
+
+    for index in indices {
+        // a. Let deleteSucceeded be ! A.[[Delete]](P).
+        let delete_succeeded = array.delete(&JSObjectPropKey::String(index.into()))?;
+
+        // b. If deleteSucceeded is false, then
+        if !delete_succeeded {
+            // i. Set newLenDesc.[[Value]] to ! ToUint32(P) + 1𝔽.
+            new_len_desc.value = Some(JSValue::from(index + 1));
+
+            // ii. If newWritable is false, set newLenDesc.[[Writable]] to false.
+            if !new_writable {
+                new_len_desc.writable = Some(false);
+            }
+
+            // iii. Perform ! OrdinaryDefineOwnProperty(A, "length", newLenDesc).
+            ordinary_define_own_property(array, &length_key, new_len_desc)?;
+
+            // iv. Return false.
+            return Ok(false);
+        }
+    }
+
+    // 18. If newWritable is false, then
+    if !new_writable {
+        // a. Set succeeded to ! OrdinaryDefineOwnProperty(A, "length", PropertyDescriptor { [[Writable]]: false }).
+        let succeeded = ordinary_define_own_property(
+            array,
+            &length_key,
+            JSObjectPropDescriptor {
+                writable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        )?;
+
+        // b. Assert: succeeded is true.
+        debug_assert!(succeeded);
+    }
+
+    // 19. Return true.
+    Ok(true)
+}
+
+/// The value of the "length" property of an Array, which is always a non-negative integral Number that fits in 32 bits.
+pub(crate) fn array_length(length_desc: &JSObjectPropDescriptor) -> u32 {
+    match &length_desc.value {
+        Some(JSValue::Number(JSNumber(length))) => *length as u32,
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,265 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::call,
+        promise_operations::{perform_promise_then, promise_resolve},
+    },
+    codegen::bytecode::generator::ExecutableFunction,
+    runtime::{
+        agent::JSAgent, completion::ThrowCompletion, execution_context::ExecutionContext,
+        native_function::Args, promise::PromiseCapability,
+    },
+    value::{
+        object::{property::JSObjectPropKey, ObjectAddr},
+        JSValue,
+    },
+    vm::{Completion, VMFrame, VM},
+};
+
+// 27.7.5 Async Functions Abstract Operations
+// https://262.ecma-international.org/16.0/#sec-async-functions-abstract-operations
+
+/// The execution context of an async function body suspended at an await, along with the code evaluation state it is resumed from and the capability of the promise it settles.
+struct AsyncContext {
+    execution_context: ExecutionContext,
+    code: Rc<ExecutableFunction>,
+    frame: VMFrame,
+    promise_capability: PromiseCapability,
+}
+
+/// 27.7.5.1 AsyncFunctionStart ( promiseCapability, asyncFunctionBody )
+/// https://262.ecma-international.org/16.0/#sec-async-functions-abstract-operations-async-function-start
+pub(crate) fn async_function_start(
+    agent: &mut JSAgent,
+    promise_capability: PromiseCapability,
+    async_function_body: Rc<ExecutableFunction>,
+) {
+    // 1. Let runningContext be the running execution context.
+    // 2. Let asyncContext be a copy of runningContext.
+    // 3. NOTE: Copying the execution state is required for AsyncBlockStart to resume its execution. It is ill-defined to resume a currently executing context.
+    let async_context = agent.running_execution_context().clone();
+
+    // 4. Perform AsyncBlockStart(promiseCapability, asyncFunctionBody, asyncContext).
+    async_block_start(
+        agent,
+        promise_capability,
+        async_function_body,
+        async_context,
+    );
+
+    // 5. Return unused.
+}
+
+/// 27.7.5.2 AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )
+/// https://262.ecma-international.org/16.0/#sec-asyncblockstart
+fn async_block_start(
+    agent: &mut JSAgent,
+    promise_capability: PromiseCapability,
+    async_body: Rc<ExecutableFunction>,
+    async_context: ExecutionContext,
+) {
+    // 1. Let runningContext be the running execution context.
+    // 2. Let closure be a new Abstract Closure with no parameters that captures promiseCapability and asyncBody and performs the following steps when called:
+    // NOTE: The steps of the closure are performed by resume_async_body once the evaluation of asyncBody completes.
+    // 3. Set the code evaluation state of asyncContext such that when evaluation is resumed for that execution context, closure will be called with no arguments.
+    // 4. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
+    // 5. Resume the suspended evaluation of asyncContext. Let result be the value returned by the resumed computation.
+    resume_async_body(
+        agent,
+        AsyncContext {
+            execution_context: async_context,
+            code: async_body,
+            frame: VMFrame::default(),
+            promise_capability,
+        },
+        None,
+    );
+
+    // 6. Assert: When we return here, asyncContext has already been removed from the execution context stack and runningContext is the currently running execution context.
+    // 7. Assert: result is a normal completion with a value of unused. The possible sources of this value are Await or, if the async function doesn't await anything, step 2.i above.
+    // 8. Return unused.
+}
+
+/// 27.7.5.3 Await ( value )
+/// https://262.ecma-international.org/16.0/#await
+///
+/// Called once the async function body has suspended at an await, with asyncContext already removed from the execution context stack.
+fn await_value(agent: &mut JSAgent, async_context: AsyncContext, value: JSValue) {
+    // 1. Let asyncContext be the running execution context.
+    // 2. Let promise be ? PromiseResolve(%Promise%, value).
+    let promise_constructor = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .promise
+        .clone()
+        .unwrap();
+
+    let promise = match promise_resolve(agent, &promise_constructor, value) {
+        Ok(promise) => ObjectAddr::try_from(promise).unwrap(),
+        // NOTE: The abrupt completion is the result of the await, so it is thrown into the async function body straight away.
+        Err(error) => {
+            let reason = error.into_value(agent);
+
+            return resume_async_body(agent, async_context, Some(Completion::Throw(reason)));
+        }
+    };
+
+    // NOTE: Only one of the closures is ever called, as the promise settles once.
+    let async_context = Rc::new(RefCell::new(Some(async_context)));
+
+    // 3. Let fulfilledClosure be a new Abstract Closure with parameters (v) that captures asyncContext and performs the following steps when called:
+    let fulfilled_closure = {
+        let async_context = async_context.clone();
+
+        move |agent: &mut JSAgent, args: Args| {
+            // a. Let prevContext be the running execution context.
+            // b. Suspend prevContext.
+            // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
+            // d. Resume the suspended evaluation of asyncContext using NormalCompletion(v) as the result of the operation that suspended it.
+            // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
+            if let Some(async_context) = async_context.take() {
+                resume_async_body(
+                    agent,
+                    async_context,
+                    Some(Completion::Normal(args.get(0).clone())),
+                );
+            }
+
+            // f. Return NormalCompletion(undefined).
+            Ok(JSValue::Undefined)
+        }
+    };
+
+    // 4. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+    let on_fulfilled = create_builtin_function(
+        agent,
+        fulfilled_closure,
+        1,
+        JSObjectPropKey::String("".into()),
+        vec![],
+        None,
+        None,
+        None,
+    );
+
+    // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures asyncContext and performs the following steps when called:
+    let rejected_closure = move |agent: &mut JSAgent, args: Args| {
+        // a. Let prevContext be the running execution context.
+        // b. Suspend prevContext.
+        // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
+        // d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
+        // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
+        if let Some(async_context) = async_context.take() {
+            resume_async_body(
+                agent,
+                async_context,
+                Some(Completion::Throw(args.get(0).clone())),
+            );
+        }
+
+        // f. Return NormalCompletion(undefined).
+        Ok(JSValue::Undefined)
+    };
+
+    // 6. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+    let on_rejected = create_builtin_function(
+        agent,
+        rejected_closure,
+        1,
+        JSObjectPropKey::String("".into()),
+        vec![],
+        None,
+        None,
+        None,
+    );
+
+    // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+    perform_promise_then(
+        agent,
+        &promise,
+        JSValue::from(on_fulfilled),
+        JSValue::from(on_rejected),
+        None,
+    );
+
+    // 8. Remove asyncContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
+    // 9. Let callerContext be the running execution context.
+    // 10. Resume callerContext passing empty. If asyncContext is ever resumed again, let completion be the Completion Record with which it is resumed.
+    // 11. Assert: If control reaches here, then asyncContext is the running execution context again.
+    // 12. Return completion.
+}
+
+/// Evaluates the async function body until it awaits or completes, starting it if there is no completion to resume it with.
+fn resume_async_body(
+    agent: &mut JSAgent,
+    async_context: AsyncContext,
+    completion: Option<Completion>,
+) {
+    let AsyncContext {
+        execution_context,
+        code,
+        frame,
+        promise_capability,
+    } = async_context;
+
+    agent.push_execution_context(execution_context);
+
+    let (result, frame) = {
+        let mut vm = VM::resume(agent, &code.program, frame);
+
+        let result = match completion {
+            // 27.7.5.2 AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )
+            // 2.a. Let acAsyncContext be the running execution context.
+            // b. If asyncBody is a Parse Node, then
+            // i. Let result be Completion(Evaluation of asyncBody).
+            None => vm.evaluate_function_body(),
+            Some(completion) => vm.resume_generator_body(completion),
+        };
+
+        (result, vm.into_suspended_frame())
+    };
+
+    let execution_context = agent.pop_execution_context();
+
+    let Some(frame) = frame else {
+        // 27.7.5.2 AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )
+        // 2.d. Assert: If we return here, the async function either threw an exception or performed an implicit or explicit return; all awaiting is done.
+        // e. Remove acAsyncContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
+        let (function, value) = match result {
+            // f. If result is a normal completion, then
+            // i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            // g. Else if result is a return completion, then
+            // i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result.[[Value]] »).
+            Ok(value) => (promise_capability.resolve, value),
+            // h. Else,
+            // i. Assert: result is a throw completion.
+            // ii. Perform ! Call(promiseCapability.[[Reject]], undefined, « result.[[Value]] »).
+            Err(error) => (
+                promise_capability.reject,
+                ThrowCompletion::from(error).into_value(agent),
+            ),
+        };
+
+        let _ = call(agent, function, &JSValue::Undefined, Some(vec![value]));
+
+        // i. Return unused.
+        return;
+    };
+
+    // NOTE: The body suspended at an await, leaving the awaited value as the result.
+    let value = result.unwrap_or(JSValue::Undefined);
+
+    await_value(
+        agent,
+        AsyncContext {
+            execution_context,
+            code,
+            frame,
+            promise_capability,
+        },
+        value,
+    );
+}
@@ -0,0 +1,842 @@
+use crate::runtime::agent::JSAgent;
+
+// 21.4.1 Overview of Date Objects and Definitions of Abstract Operations
+// https://262.ecma-international.org/16.0/#sec-overview-of-date-objects-and-definitions-of-abstract-operations
+
+// 21.4.1.2 Time-related Constants
+// https://262.ecma-international.org/16.0/#sec-time-related-constants
+pub(crate) const MS_PER_SECOND: f64 = 1000.0;
+pub(crate) const MS_PER_MINUTE: f64 = 60000.0;
+pub(crate) const MS_PER_HOUR: f64 = 3600000.0;
+pub(crate) const MS_PER_DAY: f64 = 86400000.0;
+
+/// The time value is the number of milliseconds since the epoch, which a Date object can represent up to 100,000,000 days either side of.
+const MAX_TIME_VALUE: f64 = 8.64e15;
+
+/// Table 63: Names of days of the week
+/// https://262.ecma-international.org/16.0/#sec-todatestring-day-names
+pub(crate) const DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Table 64: Names of months of the year
+/// https://262.ecma-international.org/16.0/#sec-todatestring-month-names
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The number of days from the start of a year to the start of each month, in a year which is not a leap year.
+const DAYS_BEFORE_MONTH: [f64; 12] = [
+    0.0, 31.0, 59.0, 90.0, 120.0, 151.0, 181.0, 212.0, 243.0, 273.0, 304.0, 334.0,
+];
+
+/// The day within the year which the month starts on, where a leap day is added to the months after February.
+fn month_start_day(month: usize, in_leap_year: bool) -> f64 {
+    let leap_day = if in_leap_year && month >= 2 { 1.0 } else { 0.0 };
+
+    DAYS_BEFORE_MONTH[month] + leap_day
+}
+
+/// 21.4.1.3 Day ( t )
+/// https://262.ecma-international.org/16.0/#sec-day
+pub(crate) fn day(t: f64) -> f64 {
+    // 1. Return 𝔽(floor(ℝ(t / msPerDay))).
+    (t / MS_PER_DAY).floor()
+}
+
+/// 21.4.1.4 TimeWithinDay ( t )
+/// https://262.ecma-international.org/16.0/#sec-timewithinday
+pub(crate) fn time_within_day(t: f64) -> f64 {
+    // 1. Return 𝔽(ℝ(t) modulo ℝ(msPerDay)).
+    t.rem_euclid(MS_PER_DAY)
+}
+
+/// 21.4.1.5 DaysInYear ( y )
+/// https://262.ecma-international.org/16.0/#sec-daysinyear
+fn days_in_year(y: f64) -> f64 {
+    // 1. Let ry be ℝ(y).
+    // 2. If (ry modulo 400) = 0, return 366𝔽.
+    if y.rem_euclid(400.0) == 0.0 {
+        return 366.0;
+    }
+
+    // 3. If (ry modulo 100) = 0, return 365𝔽.
+    if y.rem_euclid(100.0) == 0.0 {
+        return 365.0;
+    }
+
+    // 4. If (ry modulo 4) = 0, return 366𝔽.
+    if y.rem_euclid(4.0) == 0.0 {
+        return 366.0;
+    }
+
+    // 5. Return 365𝔽.
+    365.0
+}
+
+/// 21.4.1.6 DayFromYear ( y )
+/// https://262.ecma-international.org/16.0/#sec-dayfromyear
+fn day_from_year(y: f64) -> f64 {
+    // 1. Let ry be ℝ(y).
+    // 2. NOTE: In the following steps, numYears1, numYears4, numYears100, and numYears400 represent the number of years divisible by 1, 4, 100, and 400, respectively, that occur between the epoch and the start of year y. The number is negative if y is before the epoch.
+    // 3. Let numYears1 be (ry - 1970).
+    let num_years_1 = y - 1970.0;
+
+    // 4. Let numYears4 be floor((ry - 1969) / 4).
+    let num_years_4 = ((y - 1969.0) / 4.0).floor();
+
+    // 5. Let numYears100 be floor((ry - 1901) / 100).
+    let num_years_100 = ((y - 1901.0) / 100.0).floor();
+
+    // 6. Let numYears400 be floor((ry - 1601) / 400).
+    let num_years_400 = ((y - 1601.0) / 400.0).floor();
+
+    // 7. Return 𝔽(365 × numYears1 + numYears4 - numYears100 + numYears400).
+    365.0 * num_years_1 + num_years_4 - num_years_100 + num_years_400
+}
+
+/// 21.4.1.7 TimeFromYear ( y )
+/// https://262.ecma-international.org/16.0/#sec-timefromyear
+fn time_from_year(y: f64) -> f64 {
+    // 1. Return msPerDay × DayFromYear(y).
+    MS_PER_DAY * day_from_year(y)
+}
+
+/// 21.4.1.8 YearFromTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-yearfromtime
+pub(crate) fn year_from_time(t: f64) -> f64 {
+    // 1. Return the largest integral Number y (closest to +∞) such that TimeFromYear(y) ≤ t.
+    // NOTE: The year is estimated from the average length of a year, then corrected by the at most one year it can be off by.
+    let mut y = (t / (MS_PER_DAY * 365.2425)).floor() + 1970.0;
+
+    if time_from_year(y) > t {
+        y -= 1.0;
+    } else if time_from_year(y + 1.0) <= t {
+        y += 1.0;
+    }
+
+    y
+}
+
+/// 21.4.1.9 DayWithinYear ( t )
+/// https://262.ecma-international.org/16.0/#sec-daywithinyear
+fn day_within_year(t: f64) -> f64 {
+    // 1. Return Day(t) - DayFromYear(YearFromTime(t)).
+    day(t) - day_from_year(year_from_time(t))
+}
+
+/// 21.4.1.10 InLeapYear ( t )
+/// https://262.ecma-international.org/16.0/#sec-inleapyear
+fn in_leap_year(t: f64) -> bool {
+    // 1. If DaysInYear(YearFromTime(t)) is 366𝔽, return 1𝔽; else return +0𝔽.
+    days_in_year(year_from_time(t)) == 366.0
+}
+
+/// 21.4.1.11 MonthFromTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-monthfromtime
+pub(crate) fn month_from_time(t: f64) -> f64 {
+    // 1. Let inLeapYear be InLeapYear(t).
+    let in_leap_year = in_leap_year(t);
+
+    // 2. Let dayWithinYear be DayWithinYear(t).
+    let day_within_year = day_within_year(t);
+
+    // 3. If dayWithinYear < 31𝔽, return +0𝔽.
+    // 4. If dayWithinYear < 59𝔽 + inLeapYear, return 1𝔽.
+    // ...
+    // 14. Assert: dayWithinYear < 365𝔽 + inLeapYear.
+    // 15. Return 11𝔽.
+    let month = (1..12)
+        .find(|month| day_within_year < month_start_day(*month, in_leap_year))
+        .unwrap_or(12);
+
+    (month - 1) as f64
+}
+
+/// 21.4.1.12 DateFromTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-datefromtime
+pub(crate) fn date_from_time(t: f64) -> f64 {
+    // 1. Let inLeapYear be InLeapYear(t).
+    // 2. Let dayWithinYear be DayWithinYear(t).
+    // 3. Let month be MonthFromTime(t).
+    // 4. If month is +0𝔽, return dayWithinYear + 1𝔽.
+    // 5. If month is 1𝔽, return dayWithinYear - 30𝔽.
+    // ...
+    // 16. Return dayWithinYear - 333𝔽 - inLeapYear.
+    let month = month_from_time(t) as usize;
+
+    day_within_year(t) - month_start_day(month, in_leap_year(t)) + 1.0
+}
+
+/// 21.4.1.13 WeekDay ( t )
+/// https://262.ecma-international.org/16.0/#sec-weekday
+pub(crate) fn week_day(t: f64) -> f64 {
+    // 1. Return 𝔽(ℝ(Day(t) + 4𝔽) modulo 7).
+    (day(t) + 4.0).rem_euclid(7.0)
+}
+
+/// 21.4.1.14 HourFromTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-hourfromtime
+pub(crate) fn hour_from_time(t: f64) -> f64 {
+    // 1. Return 𝔽(floor(ℝ(t / msPerHour)) modulo HoursPerDay).
+    (t / MS_PER_HOUR).floor().rem_euclid(24.0)
+}
+
+/// 21.4.1.15 MinFromTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-minfromtime
+pub(crate) fn min_from_time(t: f64) -> f64 {
+    // 1. Return 𝔽(floor(ℝ(t / msPerMinute)) modulo MinutesPerHour).
+    (t / MS_PER_MINUTE).floor().rem_euclid(60.0)
+}
+
+/// 21.4.1.16 SecFromTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-secfromtime
+pub(crate) fn sec_from_time(t: f64) -> f64 {
+    // 1. Return 𝔽(floor(ℝ(t / msPerSecond)) modulo SecondsPerMinute).
+    (t / MS_PER_SECOND).floor().rem_euclid(60.0)
+}
+
+/// 21.4.1.17 msFromTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-msfromtime
+pub(crate) fn ms_from_time(t: f64) -> f64 {
+    // 1. Return 𝔽(ℝ(t) modulo ℝ(msPerSecond)).
+    t.rem_euclid(MS_PER_SECOND)
+}
+
+/// 21.4.1.25 LocalTime ( t )
+/// https://262.ecma-international.org/16.0/#sec-localtime
+pub(crate) fn local_time(agent: &JSAgent, t: f64) -> f64 {
+    // 1. Let systemTimeZoneIdentifier be SystemTimeZoneIdentifier().
+    // 2. If IsTimeZoneOffsetString(systemTimeZoneIdentifier) is true, then
+    // a. Let offsetNs be ParseTimeZoneOffsetString(systemTimeZoneIdentifier).
+    // 3. Else,
+    // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(t) × 10**6)).
+    // 4. Let offsetMs be truncate(offsetNs / 10**6).
+    // NOTE: The offset of the system time zone is provided by the clock of the agent.
+    let offset_ms = agent.clock().local_tz_offset(t, true).trunc();
+
+    // 5. Return t + 𝔽(offsetMs).
+    t + offset_ms
+}
+
+/// 21.4.1.26 UTC ( t )
+/// https://262.ecma-international.org/16.0/#sec-utc-t
+pub(crate) fn utc(agent: &JSAgent, t: f64) -> f64 {
+    // 1. If t is not finite, return NaN.
+    if !t.is_finite() {
+        return f64::NAN;
+    }
+
+    // 2. Let systemTimeZoneIdentifier be SystemTimeZoneIdentifier().
+    // 3. If IsTimeZoneOffsetString(systemTimeZoneIdentifier) is true, then
+    // a. Let offsetNs be ParseTimeZoneOffsetString(systemTimeZoneIdentifier).
+    // 4. Else,
+    // a. Let possibleInstants be GetNamedTimeZoneEpochNanoseconds(systemTimeZoneIdentifier, ℝ(YearFromTime(t)), ℝ(MonthFromTime(t)) + 1, ℝ(DateFromTime(t)), ℝ(HourFromTime(t)), ℝ(MinFromTime(t)), ℝ(SecFromTime(t)), ℝ(msFromTime(t)), 0, 0).
+    // ...
+    // 5. Let offsetMs be truncate(offsetNs / 10**6).
+    // NOTE: The offset of the system time zone is provided by the clock of the agent, which disambiguates local times that are skipped or repeated.
+    let offset_ms = agent.clock().local_tz_offset(t, false).trunc();
+
+    // 6. Return t - 𝔽(offsetMs).
+    t - offset_ms
+}
+
+/// 21.4.1.27 MakeTime ( hour, min, sec, ms )
+/// https://262.ecma-international.org/16.0/#sec-maketime
+pub(crate) fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
+    // 1. If hour is not finite, min is not finite, sec is not finite, or ms is not finite, return NaN.
+    if !hour.is_finite() || !min.is_finite() || !sec.is_finite() || !ms.is_finite() {
+        return f64::NAN;
+    }
+
+    // 2. Let h be 𝔽(! ToIntegerOrInfinity(hour)).
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(min)).
+    // 4. Let s be 𝔽(! ToIntegerOrInfinity(sec)).
+    // 5. Let milli be 𝔽(! ToIntegerOrInfinity(ms)).
+    let (h, m, s, milli) = (hour.trunc(), min.trunc(), sec.trunc(), ms.trunc());
+
+    // 6. Let t be ((h × msPerHour + m × msPerMinute) + s × msPerSecond) + milli, performing the arithmetic according to IEEE 754-2019 rules (that is, as if using the ECMAScript operators × and +).
+    // 7. Return t.
+    ((h * MS_PER_HOUR + m * MS_PER_MINUTE) + s * MS_PER_SECOND) + milli
+}
+
+/// 21.4.1.28 MakeDay ( year, month, date )
+/// https://262.ecma-international.org/16.0/#sec-makeday
+pub(crate) fn make_day(year: f64, month: f64, date: f64) -> f64 {
+    // 1. If year is not finite, month is not finite, or date is not finite, return NaN.
+    if !year.is_finite() || !month.is_finite() || !date.is_finite() {
+        return f64::NAN;
+    }
+
+    // 2. Let y be 𝔽(! ToIntegerOrInfinity(year)).
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(month)).
+    // 4. Let dt be 𝔽(! ToIntegerOrInfinity(date)).
+    let (y, m, dt) = (year.trunc(), month.trunc(), date.trunc());
+
+    // 5. Let ym be y + 𝔽(floor(ℝ(m) / 12)).
+    let ym = y + (m / 12.0).floor();
+
+    // 6. If ym is not finite, return NaN.
+    if !ym.is_finite() {
+        return f64::NAN;
+    }
+
+    // 7. Let mn be 𝔽(ℝ(m) modulo 12).
+    let mn = m.rem_euclid(12.0);
+
+    // 8. Find a finite time value t such that YearFromTime(t) is ym, MonthFromTime(t) is mn, and DateFromTime(t) is 1𝔽; but if this is not possible (because some argument is out of range), return NaN.
+    let t =
+        time_from_year(ym) + month_start_day(mn as usize, days_in_year(ym) == 366.0) * MS_PER_DAY;
+
+    if !t.is_finite() {
+        return f64::NAN;
+    }
+
+    // 9. Return Day(t) + dt - 1𝔽.
+    day(t) + dt - 1.0
+}
+
+/// 21.4.1.29 MakeDate ( day, time )
+/// https://262.ecma-international.org/16.0/#sec-makedate
+pub(crate) fn make_date(day: f64, time: f64) -> f64 {
+    // 1. If day is not finite or time is not finite, return NaN.
+    if !day.is_finite() || !time.is_finite() {
+        return f64::NAN;
+    }
+
+    // 2. Let tv be day × msPerDay + time.
+    let tv = day * MS_PER_DAY + time;
+
+    // 3. If tv is not finite, return NaN.
+    if !tv.is_finite() {
+        return f64::NAN;
+    }
+
+    // 4. Return tv.
+    tv
+}
+
+/// 21.4.1.30 MakeFullYear ( year )
+/// https://262.ecma-international.org/16.0/#sec-makefullyear
+pub(crate) fn make_full_year(year: f64) -> f64 {
+    // 1. If year is NaN, return NaN.
+    if year.is_nan() {
+        return f64::NAN;
+    }
+
+    // 2. Let truncated be ! ToIntegerOrInfinity(year).
+    let truncated = year.trunc();
+
+    // 3. If truncated is in the inclusive interval from 0 to 99, return 1900𝔽 + 𝔽(truncated).
+    if (0.0..=99.0).contains(&truncated) {
+        return 1900.0 + truncated;
+    }
+
+    // 4. Return 𝔽(truncated).
+    truncated
+}
+
+/// 21.4.1.31 TimeClip ( time )
+/// https://262.ecma-international.org/16.0/#sec-timeclip
+pub(crate) fn time_clip(time: f64) -> f64 {
+    // 1. If time is not finite, return NaN.
+    if !time.is_finite() {
+        return f64::NAN;
+    }
+
+    // 2. If abs(ℝ(time)) > 8.64 × 10**15, return NaN.
+    if time.abs() > MAX_TIME_VALUE {
+        return f64::NAN;
+    }
+
+    // 3. Return 𝔽(! ToIntegerOrInfinity(time)).
+    // NOTE: Adding zero normalizes -0 to +0, as ToIntegerOrInfinity does.
+    time.trunc() + 0.0
+}
+
+/// ToZeroPaddedDecimalString ( n, minLength )
+/// https://262.ecma-international.org/16.0/#sec-tozeropaddeddecimalstring
+pub(crate) fn to_zero_padded_decimal_string(n: f64, min_length: usize) -> String {
+    // 1. Let S be the String representation of n, formatted as a decimal number.
+    // 2. Return StringPad(S, 𝔽(minLength), "0", start).
+    format!("{:0min_length$}", n as i64)
+}
+
+/// 21.4.4.41.1 TimeString ( tv )
+/// https://262.ecma-international.org/16.0/#sec-timestring
+pub(crate) fn time_string(tv: f64) -> String {
+    // 1. Let hour be ToZeroPaddedDecimalString(ℝ(HourFromTime(tv)), 2).
+    let hour = to_zero_padded_decimal_string(hour_from_time(tv), 2);
+
+    // 2. Let minute be ToZeroPaddedDecimalString(ℝ(MinFromTime(tv)), 2).
+    let minute = to_zero_padded_decimal_string(min_from_time(tv), 2);
+
+    // 3. Let second be ToZeroPaddedDecimalString(ℝ(SecFromTime(tv)), 2).
+    let second = to_zero_padded_decimal_string(sec_from_time(tv), 2);
+
+    // 4. Return the string-concatenation of hour, ":", minute, ":", second, the code unit 0x0020 (SPACE), and "GMT".
+    format!("{hour}:{minute}:{second} GMT")
+}
+
+/// The year of the time value as a sign followed by at least four digits, which DateString and Date.prototype.toUTCString format it as.
+pub(crate) fn padded_year(tv: f64) -> String {
+    // Let yv be YearFromTime(tv).
+    let yv = year_from_time(tv);
+
+    // If yv is +0𝔽 or yv > +0𝔽, let yearSign be the empty String; otherwise, let yearSign be "-".
+    let year_sign = if yv >= 0.0 { "" } else { "-" };
+
+    // Let paddedYear be ToZeroPaddedDecimalString(abs(ℝ(yv)), 4).
+    let padded_year = to_zero_padded_decimal_string(yv.abs(), 4);
+
+    format!("{year_sign}{padded_year}")
+}
+
+/// 21.4.4.41.2 DateString ( tv )
+/// https://262.ecma-international.org/16.0/#sec-datestring
+pub(crate) fn date_string(tv: f64) -> String {
+    // 1. Let weekday be the Name of the entry in Table 63 with the Number WeekDay(tv).
+    let weekday = DAY_NAMES[week_day(tv) as usize];
+
+    // 2. Let month be the Name of the entry in Table 64 with the Number MonthFromTime(tv).
+    let month = MONTH_NAMES[month_from_time(tv) as usize];
+
+    // 3. Let day be ToZeroPaddedDecimalString(ℝ(DateFromTime(tv)), 2).
+    let day = to_zero_padded_decimal_string(date_from_time(tv), 2);
+
+    // 4. Let yv be YearFromTime(tv).
+    // 5. If yv is +0𝔽 or yv > +0𝔽, let yearSign be the empty String; otherwise, let yearSign be "-".
+    // 6. Let paddedYear be ToZeroPaddedDecimalString(abs(ℝ(yv)), 4).
+    let year = padded_year(tv);
+
+    // 7. Return the string-concatenation of weekday, the code unit 0x0020 (SPACE), month, the code unit 0x0020 (SPACE), day, the code unit 0x0020 (SPACE), yearSign, and paddedYear.
+    format!("{weekday} {month} {day} {year}")
+}
+
+/// 21.4.4.41.3 TimeZoneString ( tv )
+/// https://262.ecma-international.org/16.0/#sec-timezoneestring
+pub(crate) fn time_zone_string(agent: &JSAgent, tv: f64) -> String {
+    // 1. Let systemTimeZoneIdentifier be SystemTimeZoneIdentifier().
+    // 2. If IsTimeZoneOffsetString(systemTimeZoneIdentifier) is true, then
+    // a. Let offsetNs be ParseTimeZoneOffsetString(systemTimeZoneIdentifier).
+    // 3. Else,
+    // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(tv) × 10**6)).
+    // 4. Let offset be 𝔽(truncate(offsetNs / 10**6)).
+    let offset = agent.clock().local_tz_offset(tv, true).trunc();
+
+    // 5. If offset is +0𝔽 or offset > +0𝔽, then
+    // a. Let offsetSign be "+".
+    // b. Let absOffset be offset.
+    // 6. Else,
+    // a. Let offsetSign be "-".
+    // b. Let absOffset be -offset.
+    let (offset_sign, abs_offset) = if offset >= 0.0 {
+        ("+", offset)
+    } else {
+        ("-", -offset)
+    };
+
+    // 7. Let offsetMin be ToZeroPaddedDecimalString(ℝ(MinFromTime(absOffset)), 2).
+    let offset_min = to_zero_padded_decimal_string(min_from_time(abs_offset), 2);
+
+    // 8. Let offsetHour be ToZeroPaddedDecimalString(ℝ(HourFromTime(absOffset)), 2).
+    let offset_hour = to_zero_padded_decimal_string(hour_from_time(abs_offset), 2);
+
+    // 9. Let tzName be an implementation-defined string that is either the empty String or the string-concatenation of the code unit 0x0020 (SPACE), the code unit 0x0028 (LEFT PARENTHESIS), an implementation-defined timezone name, and the code unit 0x0029 (RIGHT PARENTHESIS).
+    // NOTE: The clock only provides the offset of the time zone, so it is not named.
+
+    // 10. Return the string-concatenation of offsetSign, offsetHour, offsetMin, and tzName.
+    format!("{offset_sign}{offset_hour}{offset_min}")
+}
+
+/// 21.4.4.41.4 ToDateString ( tv )
+/// https://262.ecma-international.org/16.0/#sec-todatestring
+pub(crate) fn to_date_string(agent: &JSAgent, tv: f64) -> String {
+    // 1. If tv is NaN, return "Invalid Date".
+    if tv.is_nan() {
+        return String::from("Invalid Date");
+    }
+
+    // 2. Let t be LocalTime(tv).
+    let t = local_time(agent, tv);
+
+    // 3. Return the string-concatenation of DateString(t), the code unit 0x0020 (SPACE), TimeString(t), and TimeZoneString(tv).
+    format!(
+        "{} {}{}",
+        date_string(t),
+        time_string(t),
+        time_zone_string(agent, tv)
+    )
+}
+
+/// The date and time of a Date Time String Format string, or of a string produced by Date.prototype.toString or Date.prototype.toUTCString.
+struct DateFields {
+    year: f64,
+    month: f64,
+    day: f64,
+    hour: f64,
+    minute: f64,
+    second: f64,
+    millisecond: f64,
+    /// The offset from UTC in milliseconds, or None if the date and time are in local time.
+    offset: Option<f64>,
+}
+
+impl DateFields {
+    /// The time value of the date and time, or NaN if any of the fields is out of range.
+    fn time_value(&self, agent: &JSAgent) -> f64 {
+        // Illegal values (out-of-bounds as well as syntax errors) in a format string means that the format string is not a valid instance of this format.
+        if !(0.0..12.0).contains(&self.month) {
+            return f64::NAN;
+        }
+
+        let in_leap_year = days_in_year(self.year) == 366.0;
+        let days_in_month = if self.month == 11.0 {
+            31.0
+        } else {
+            month_start_day(self.month as usize + 1, in_leap_year)
+                - month_start_day(self.month as usize, in_leap_year)
+        };
+
+        let is_end_of_day = self.hour == 24.0
+            && self.minute == 0.0
+            && self.second == 0.0
+            && self.millisecond == 0.0;
+
+        if !(1.0..=days_in_month).contains(&self.day)
+            || !(self.hour < 24.0 || is_end_of_day)
+            || self.minute >= 60.0
+            || self.second >= 60.0
+        {
+            return f64::NAN;
+        }
+
+        let date = make_date(
+            make_day(self.year, self.month, self.day),
+            make_time(self.hour, self.minute, self.second, self.millisecond),
+        );
+
+        let tv = match self.offset {
+            Some(offset) => date - offset,
+            None => utc(agent, date),
+        };
+
+        time_clip(tv)
+    }
+}
+
+/// A cursor over the characters of a date string.
+struct DateStringParser<'a> {
+    chars: &'a [u8],
+    position: usize,
+}
+
+impl<'a> DateStringParser<'a> {
+    fn new(string: &'a str) -> Self {
+        Self {
+            chars: string.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.position == self.chars.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn eat(&mut self, char: u8) -> bool {
+        let is_match = self.peek() == Some(char);
+
+        if is_match {
+            self.position += 1;
+        }
+
+        is_match
+    }
+
+    fn expect(&mut self, char: u8) -> Option<()> {
+        self.eat(char).then_some(())
+    }
+
+    /// Parses exactly the given number of decimal digits.
+    fn digits(&mut self, count: usize) -> Option<f64> {
+        let digits = self.chars.get(self.position..self.position + count)?;
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        self.position += count;
+
+        Some(
+            digits
+                .iter()
+                .fold(0.0, |value, digit| value * 10.0 + f64::from(digit - b'0')),
+        )
+    }
+
+    /// Parses one or more decimal digits, returning the value and the number of digits.
+    fn digits_while(&mut self) -> Option<(f64, usize)> {
+        let count = self.chars[self.position..]
+            .iter()
+            .take_while(|char| char.is_ascii_digit())
+            .count();
+
+        (count > 0).then(|| (self.digits(count).unwrap(), count))
+    }
+
+    /// Parses the next word, which runs until a space or the end of the string.
+    fn word(&mut self) -> Option<&'a str> {
+        let start = self.position;
+
+        while self.peek().is_some_and(|char| char != b' ') {
+            self.position += 1;
+        }
+
+        let word = std::str::from_utf8(&self.chars[start..self.position]).ok()?;
+
+        self.eat(b' ');
+
+        (!word.is_empty()).then_some(word)
+    }
+
+    /// 21.4.1.32 Date Time String Format
+    /// https://262.ecma-international.org/16.0/#sec-date-time-string-format
+    fn date_time_string_format(&mut self) -> Option<DateFields> {
+        // YYYY is the year in the proleptic Gregorian calendar as four decimal digits from 0000 to 9999, or as an expanded year of "+" or "-" followed by six decimal digits.
+        let year = match self.peek()? {
+            sign @ (b'+' | b'-') => {
+                self.position += 1;
+
+                let year = self.digits(6)?;
+
+                // The representation of the year 0 as -000000 is invalid.
+                if sign == b'-' && year == 0.0 {
+                    return None;
+                }
+
+                if sign == b'-' {
+                    -year
+                } else {
+                    year
+                }
+            }
+            _ => self.digits(4)?,
+        };
+
+        let mut fields = DateFields {
+            year,
+            month: 0.0,
+            day: 1.0,
+            hour: 0.0,
+            minute: 0.0,
+            second: 0.0,
+            millisecond: 0.0,
+            // When the UTC offset representation is absent, date-only forms are interpreted as a UTC time.
+            offset: Some(0.0),
+        };
+
+        // MM is the month of the year from 01 (January) to 12 (December).
+        if self.eat(b'-') {
+            fields.month = self.digits(2)? - 1.0;
+
+            // DD is the day of the month from 01 to 31.
+            if self.eat(b'-') {
+                fields.day = self.digits(2)?;
+            }
+        }
+
+        // "T" appears literally in the string, to indicate the beginning of the time element.
+        if self.eat(b'T') {
+            // HH is the number of complete hours that have passed since midnight as two decimal digits from 00 to 24.
+            fields.hour = self.digits(2)?;
+
+            // mm is the number of complete minutes since the start of the hour as two decimal digits from 00 to 59.
+            self.expect(b':')?;
+            fields.minute = self.digits(2)?;
+
+            // ss is the number of complete seconds since the start of the minute as two decimal digits from 00 to 59.
+            if self.eat(b':') {
+                fields.second = self.digits(2)?;
+
+                // sss is the number of complete milliseconds since the start of the second as three decimal digits.
+                // NOTE: Fewer or more digits are accepted too, as other implementations do, and any digits after the first three are ignored.
+                if self.eat(b'.') {
+                    let (fraction, count) = self.digits_while()?;
+
+                    fields.millisecond = (fraction * 10f64.powi(3 - count as i32)).trunc();
+                }
+            }
+
+            // Z is the UTC offset representation specified as "Z" (for UTC with no offset) or as either "+" or "-" followed by a time expression HH:mm.
+            fields.offset = match self.peek() {
+                Some(b'Z') => {
+                    self.position += 1;
+
+                    Some(0.0)
+                }
+                Some(sign @ (b'+' | b'-')) => {
+                    self.position += 1;
+
+                    let hours = self.digits(2)?;
+
+                    self.expect(b':')?;
+
+                    let minutes = self.digits(2)?;
+
+                    if hours >= 24.0 || minutes >= 60.0 {
+                        return None;
+                    }
+
+                    let offset = hours * MS_PER_HOUR + minutes * MS_PER_MINUTE;
+
+                    Some(if sign == b'-' { -offset } else { offset })
+                }
+                // When the UTC offset representation is absent, date-time forms are interpreted as a local time.
+                _ => None,
+            };
+        }
+
+        self.is_done().then_some(fields)
+    }
+
+    /// The formats produced by Date.prototype.toString and Date.prototype.toUTCString, which are "Tue Jan 02 2024 03:04:05 GMT+0100" and "Tue, 02 Jan 2024 03:04:05 GMT".
+    fn date_string_output_format(&mut self) -> Option<DateFields> {
+        let weekday = self.word()?;
+
+        let (weekday, month, day) = match weekday.strip_suffix(',') {
+            Some(weekday) => {
+                let day = self.word()?;
+
+                (weekday, self.word()?, day)
+            }
+            None => (weekday, self.word()?, self.word()?),
+        };
+
+        if !DAY_NAMES.contains(&weekday) || day.len() != 2 {
+            return None;
+        }
+
+        let month = MONTH_NAMES.iter().position(|name| *name == month)? as f64;
+        let day = DateStringParser::new(day).digits(2)?;
+
+        let year = self.word()?;
+        let (sign, year) = match year.strip_prefix('-') {
+            Some(year) => (-1.0, year),
+            None => (1.0, year),
+        };
+
+        if year.len() < 4 || !year.bytes().all(|char| char.is_ascii_digit()) {
+            return None;
+        }
+
+        let year = sign * year.parse::<f64>().ok()?;
+
+        let hour = self.digits(2)?;
+        self.expect(b':')?;
+        let minute = self.digits(2)?;
+        self.expect(b':')?;
+        let second = self.digits(2)?;
+        self.expect(b' ')?;
+
+        if !(self.eat(b'G') && self.eat(b'M') && self.eat(b'T')) {
+            return None;
+        }
+
+        let offset = match self.peek() {
+            Some(sign @ (b'+' | b'-')) => {
+                self.position += 1;
+
+                let hours = self.digits(2)?;
+                let minutes = self.digits(2)?;
+                let offset = hours * MS_PER_HOUR + minutes * MS_PER_MINUTE;
+
+                if sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            _ => 0.0,
+        };
+
+        // The time zone may be followed by its name in parentheses, which is ignored.
+        let has_time_zone_name = self.eat(b' ') && self.eat(b'(') && self.chars.ends_with(b")");
+
+        if !self.is_done() && !has_time_zone_name {
+            return None;
+        }
+
+        Some(DateFields {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond: 0.0,
+            offset: Some(offset),
+        })
+    }
+}
+
+/// The implementation-specific heuristics and date formats which Date.parse interprets a String as a time value with.
+///
+/// The Date Time String Format is recognized first, then the formats which Date.prototype.toString and Date.prototype.toUTCString produce. Any other string is NaN.
+pub(crate) fn parse_date_string(agent: &JSAgent, string: &str) -> f64 {
+    let string = string.trim();
+
+    let fields = DateStringParser::new(string)
+        .date_time_string_format()
+        .or_else(|| DateStringParser::new(string).date_string_output_format());
+
+    fields.map_or(f64::NAN, |fields| fields.time_value(agent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_values_round_trip_through_their_fields() {
+        for (year, month, date) in [
+            (1970.0, 0.0, 1.0),
+            (1969.0, 11.0, 31.0),
+            (2000.0, 1.0, 29.0),
+            (2024.0, 11.0, 31.0),
+            (1900.0, 2.0, 1.0),
+            (-1.0, 0.0, 1.0),
+            (275760.0, 8.0, 13.0),
+        ] {
+            let t = make_date(
+                make_day(year, month, date),
+                make_time(23.0, 59.0, 59.0, 999.0),
+            );
+
+            assert_eq!(year_from_time(t), year);
+            assert_eq!(month_from_time(t), month);
+            assert_eq!(date_from_time(t), date);
+            assert_eq!(hour_from_time(t), 23.0);
+            assert_eq!(min_from_time(t), 59.0);
+            assert_eq!(sec_from_time(t), 59.0);
+            assert_eq!(ms_from_time(t), 999.0);
+        }
+    }
+
+    #[test]
+    fn make_day_carries_months_into_years() {
+        assert_eq!(make_day(2023.0, 12.0, 1.0), make_day(2024.0, 0.0, 1.0));
+        assert_eq!(make_day(2024.0, -1.0, 1.0), make_day(2023.0, 11.0, 1.0));
+        assert_eq!(make_day(2024.0, 0.0, 0.0), make_day(2023.0, 11.0, 31.0));
+        assert!(make_day(f64::INFINITY, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn time_clip_limits_the_range() {
+        assert_eq!(time_clip(8.64e15), 8.64e15);
+        assert!(time_clip(8.64e15 + 1.0).is_nan());
+        assert_eq!(time_clip(-0.5).to_bits(), 0f64.to_bits());
+        assert_eq!(week_day(0.0), 4.0);
+    }
+}
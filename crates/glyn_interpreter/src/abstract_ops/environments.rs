@@ -1,27 +1,32 @@
 use crate::{
     gc::Gc,
     runtime::{
+        agent::JSAgent,
         completion::CompletionRecord,
         environment::{
             declarative_environment::DeclarativeEnvironment,
             function_environment::{FunctionEnvironment, ThisBindingStatus},
             global_environment::GlobalEnvironment,
+            module_environment::ModuleEnvironment,
             object_environment::ObjectEnvironment,
             Environment, EnvironmentAddr, EnvironmentMethods,
         },
         reference::{Reference, ReferenceBase, ReferenceName},
     },
     value::{
-        object::{ObjectAddr, ObjectEssentialInternalMethods, ObjectMeta},
-        string::JSString,
+        atom::Atom,
+        object::{
+            internal_slots::ThisMode, ObjectAddr, ObjectEssentialInternalMethods, ObjectMeta,
+        },
     },
 };
 
 /// 9.1.2.1 GetIdentifierReference ( env, name, strict )
 /// https://262.ecma-international.org/16.0/#sec-getidentifierreference
 pub(crate) fn get_identifier_reference(
+    agent: &mut JSAgent,
     env: Option<EnvironmentAddr>,
-    name: &JSString,
+    name: Atom,
     strict: bool,
 ) -> CompletionRecord<Reference> {
     // 1. If env is null, then
@@ -36,7 +41,7 @@ pub(crate) fn get_identifier_reference(
     };
 
     // 2. Let exists be ? env.HasBinding(name).
-    let exists = env.has_binding(name)?;
+    let exists = env.has_binding(agent, name.clone())?;
 
     // 3. If exists is true, then
     if exists {
@@ -54,12 +59,15 @@ pub(crate) fn get_identifier_reference(
     let outer = env.outer();
 
     // b. Return ? GetIdentifierReference(outer, name, strict).
-    get_identifier_reference(outer, name, strict)
+    get_identifier_reference(agent, outer, name, strict)
 }
 
 /// 9.1.2.2 NewDeclarativeEnvironment ( E )
 /// https://262.ecma-international.org/16.0/#sec-newdeclarativeenvironment
-pub(crate) fn new_declarative_environment(outer_env: Option<EnvironmentAddr>) -> EnvironmentAddr {
+pub(crate) fn new_declarative_environment(
+    agent: &mut JSAgent,
+    outer_env: Option<EnvironmentAddr>,
+) -> EnvironmentAddr {
     // 1. Let env be a new Declarative Environment Record containing no bindings.
     let mut env = DeclarativeEnvironment::default();
 
@@ -67,12 +75,16 @@ pub(crate) fn new_declarative_environment(outer_env: Option<EnvironmentAddr>) ->
     env.outer_env = outer_env;
 
     // 3. Return env.
-    Gc::new(Environment::Declarative(env))
+    // NOTE: The environments of blocks and calls are usually short-lived, so they are allocated in the nursery.
+    agent
+        .environment_nursery
+        .allocate(&mut agent.heap, Environment::Declarative(env))
 }
 
 /// 9.1.2.3 NewObjectEnvironment ( O, W, E )
 /// https://262.ecma-international.org/16.0/#sec-newobjectenvironment
 pub(crate) fn new_object_environment(
+    agent: &mut JSAgent,
     binding_object: &(impl ObjectMeta + ObjectEssentialInternalMethods),
     is_with_environment: bool,
     outer_env: Option<EnvironmentAddr>,
@@ -90,12 +102,13 @@ pub(crate) fn new_object_environment(
     };
 
     // 5. Return env.
-    Gc::new(Environment::Object(env))
+    Gc::new(&mut agent.heap, Environment::Object(env))
 }
 
 /// 9.1.2.4 NewFunctionEnvironment ( F, newTarget )
 /// https://262.ecma-international.org/16.0/#sec-newfunctionenvironment
 pub(crate) fn new_function_environment(
+    agent: &mut JSAgent,
     function_obj: &(impl ObjectMeta + ObjectEssentialInternalMethods),
     new_target: Option<ObjectAddr>,
 ) -> EnvironmentAddr {
@@ -105,9 +118,12 @@ pub(crate) fn new_function_environment(
         function_object: Some(function_obj.addr()),
 
         // 3. If F.[[ThisMode]] is lexical, set env.[[ThisBindingStatus]] to lexical.
-        // TODO: Implement this using the function object's [[ThisMode]]
         // 4. Else, set env.[[ThisBindingStatus]] to uninitialized.
-        this_binding_status: ThisBindingStatus::Uninitialized,
+        this_binding_status: if function_obj.data().slots().this_mode() == Some(ThisMode::Lexical) {
+            ThisBindingStatus::Lexical
+        } else {
+            ThisBindingStatus::Uninitialized
+        },
 
         // 5. Set env.[[NewTarget]] to newTarget.
         new_target,
@@ -121,12 +137,15 @@ pub(crate) fn new_function_environment(
     };
 
     // 7. Return env.
-    Gc::new(Environment::Function(env))
+    agent
+        .environment_nursery
+        .allocate(&mut agent.heap, Environment::Function(env))
 }
 
 /// 9.1.2.5 NewGlobalEnvironment ( G, thisValue )
 /// https://262.ecma-international.org/16.0/#sec-newglobalenvironment
 pub(crate) fn new_global_environment(
+    agent: &mut JSAgent,
     global_object: &ObjectAddr,
     this_value: &ObjectAddr,
 ) -> EnvironmentAddr {
@@ -156,5 +175,342 @@ pub(crate) fn new_global_environment(
     };
 
     // 8. Return env.
-    Gc::new(Environment::Global(env))
+    Gc::new(&mut agent.heap, Environment::Global(env))
+}
+
+/// 9.1.2.6 NewModuleEnvironment ( E )
+/// https://262.ecma-international.org/16.0/#sec-newmoduleenvironment
+pub(crate) fn new_module_environment(
+    agent: &mut JSAgent,
+    outer_env: Option<EnvironmentAddr>,
+) -> EnvironmentAddr {
+    // 1. Let env be a new Module Environment Record containing no bindings.
+    // 2. Set env.[[OuterEnv]] to E.
+    let env = ModuleEnvironment {
+        outer_env,
+        ..ModuleEnvironment::default()
+    };
+
+    // 3. Return env.
+    Gc::new(&mut agent.heap, Environment::Module(env))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        abstract_ops::{
+            function_operations::create_builtin_function,
+            object_operations::{create_data_property_or_throw, define_property_or_throw, get},
+            ordinary::ordinary_object_create,
+        },
+        runtime::{
+            agent::WELL_KNOWN_SYMBOLS_UNSCOPABLES,
+            native_function::{Args, NativeFunction},
+        },
+        value::{
+            atom::Atom,
+            number::JSNumber,
+            object::property::{JSObjectPropDescriptor, JSObjectPropKey},
+            JSValue,
+        },
+    };
+
+    fn name(name: &str) -> Atom {
+        Atom::from(name)
+    }
+
+    fn create_object(agent: &mut JSAgent, names: &[&str]) -> ObjectAddr {
+        let object = ordinary_object_create(agent, None, None);
+
+        for binding in names {
+            create_data_property_or_throw(
+                &object,
+                &JSObjectPropKey::from(name(binding)),
+                1.0.into(),
+            )
+            .unwrap();
+        }
+
+        object
+    }
+
+    fn declarative_environment(
+        agent: &mut JSAgent,
+        outer: Option<EnvironmentAddr>,
+        names: &[&str],
+    ) -> EnvironmentAddr {
+        let mut env = new_declarative_environment(agent, outer);
+
+        for binding in names {
+            env.create_mutable_binding(name(binding), false).unwrap();
+        }
+
+        env
+    }
+
+    /// Defines an accessor property on the object whose getter and setter are native functions.
+    fn define_accessor(
+        agent: &mut JSAgent,
+        object: &ObjectAddr,
+        binding: &str,
+        getter: impl NativeFunction,
+        setter: impl NativeFunction,
+    ) {
+        let key = JSObjectPropKey::from(name(binding));
+
+        let getter =
+            create_builtin_function(agent, getter, 0, key.clone(), vec![], None, None, None);
+        let setter =
+            create_builtin_function(agent, setter, 1, key.clone(), vec![], None, None, None);
+
+        define_property_or_throw(
+            object,
+            &key,
+            JSObjectPropDescriptor {
+                get: Some(JSValue::from(getter)),
+                set: Some(JSValue::from(setter)),
+                enumerable: Some(true),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        )
+        .unwrap();
+    }
+
+    fn resolve(env: &EnvironmentAddr, binding: &str, strict: bool) -> Reference {
+        get_identifier_reference(
+            &mut JSAgent::default(),
+            Some(env.clone()),
+            name(binding),
+            strict,
+        )
+        .unwrap()
+    }
+
+    fn assert_resolves_to(reference: &Reference, env: &EnvironmentAddr) {
+        assert!(
+            matches!(&reference.base, ReferenceBase::Environment(base) if base == env),
+            "Expected the reference to resolve to {env:?}, got {reference:?}"
+        );
+    }
+
+    #[test]
+    fn null_environment_is_unresolvable() {
+        let reference =
+            get_identifier_reference(&mut JSAgent::default(), None, name("x"), true).unwrap();
+
+        assert_eq!(
+            reference,
+            Reference {
+                base: ReferenceBase::Unresolvable,
+                referenced_name: ReferenceName::from(name("x")),
+                strict: true,
+                this_value: None,
+            }
+        );
+    }
+
+    #[test]
+    fn declarative_environment_bindings() {
+        let mut agent = JSAgent::default();
+
+        let outer = declarative_environment(&mut agent, None, &["x", "y"]);
+        let inner = declarative_environment(&mut agent, Some(outer.clone()), &["x"]);
+
+        assert_resolves_to(&resolve(&inner, "x", false), &inner);
+        assert_resolves_to(&resolve(&inner, "y", false), &outer);
+        assert_eq!(
+            resolve(&inner, "z", false).base,
+            ReferenceBase::Unresolvable
+        );
+    }
+
+    #[test]
+    fn strict_flag_is_kept_through_the_outer_environments() {
+        let mut agent = JSAgent::default();
+
+        let outer = declarative_environment(&mut agent, None, &["x"]);
+        let inner = declarative_environment(&mut agent, Some(outer.clone()), &[]);
+
+        let reference = resolve(&inner, "x", true);
+        assert_resolves_to(&reference, &outer);
+        assert!(reference.strict);
+
+        assert!(!resolve(&inner, "x", false).strict);
+        assert!(resolve(&inner, "z", true).strict);
+        assert!(!resolve(&inner, "z", false).strict);
+    }
+
+    #[test]
+    fn function_environment_bindings() {
+        let mut agent = JSAgent::default();
+
+        let outer = declarative_environment(&mut agent, None, &["y"]);
+
+        let mut env = Gc::new(
+            &mut agent.heap,
+            Environment::Function(FunctionEnvironment {
+                outer_env: Some(outer.clone()),
+                ..FunctionEnvironment::default()
+            }),
+        );
+        env.create_mutable_binding(name("x"), false).unwrap();
+
+        assert_resolves_to(&resolve(&env, "x", false), &env);
+        assert_resolves_to(&resolve(&env, "y", false), &outer);
+        assert_eq!(resolve(&env, "z", false).base, ReferenceBase::Unresolvable);
+    }
+
+    #[test]
+    fn object_environment_bindings() {
+        let mut agent = JSAgent::default();
+
+        let object = create_object(&mut agent, &["x"]);
+        let outer = declarative_environment(&mut agent, None, &["x", "y"]);
+        let env = new_object_environment(&mut agent, &object, false, Some(outer.clone()));
+
+        assert_resolves_to(&resolve(&env, "x", false), &env);
+        assert_resolves_to(&resolve(&env, "y", false), &outer);
+        assert_eq!(env.with_base_object(), None);
+
+        // A property added after the environment was created is found, as the binding object is searched on each lookup.
+        create_data_property_or_throw(&object, &JSObjectPropKey::from(name("y")), 2.0.into())
+            .unwrap();
+        assert_resolves_to(&resolve(&env, "y", false), &env);
+    }
+
+    #[test]
+    fn with_environment_bindings() {
+        let mut agent = JSAgent::default();
+
+        let object = create_object(&mut agent, &["x", "y"]);
+        let outer = declarative_environment(&mut agent, None, &["y"]);
+        let env = new_object_environment(&mut agent, &object, true, Some(outer.clone()));
+
+        assert_resolves_to(&resolve(&env, "x", false), &env);
+        assert_eq!(env.with_base_object(), Some(object.clone()));
+
+        // Properties blocked by %Symbol.unscopables% fall through to the outer environment.
+        let unscopables = create_object(&mut agent, &["y"]);
+        create_data_property_or_throw(
+            &object,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_UNSCOPABLES),
+            JSValue::from(unscopables),
+        )
+        .unwrap();
+
+        assert_resolves_to(&resolve(&env, "x", false), &env);
+        assert_resolves_to(&resolve(&env, "y", false), &outer);
+    }
+
+    #[test]
+    fn global_environment_bindings() {
+        let mut agent = JSAgent::default();
+
+        let global_object = create_object(&mut agent, &["x"]);
+        let mut env = new_global_environment(&mut agent, &global_object, &global_object);
+        env.create_mutable_binding(name("y"), false).unwrap();
+
+        let inner = declarative_environment(&mut agent, Some(env.clone()), &[]);
+
+        // Both the bindings of the declarative record and the properties of the global object are found in the global environment.
+        assert_resolves_to(&resolve(&inner, "x", false), &env);
+        assert_resolves_to(&resolve(&inner, "y", false), &env);
+        assert_eq!(
+            resolve(&inner, "z", false).base,
+            ReferenceBase::Unresolvable
+        );
+    }
+
+    #[test]
+    fn object_environment_accessors_can_reenter_the_environment() {
+        let mut agent = JSAgent::default();
+        agent.initialize_realm();
+
+        let object = create_object(&mut agent, &["y"]);
+        let mut env = new_object_environment(&mut agent, &object, true, None);
+
+        // The getter deletes the property being read and writes another binding of the same environment.
+        let (getter_env, getter_object) = (env.clone(), object.clone());
+        let getter = move |agent: &mut JSAgent, _: Args| {
+            getter_object.delete(&JSObjectPropKey::from(name("x")))?;
+
+            getter_env
+                .clone()
+                .set_mutable_binding(agent, name("y"), 2.0.into(), false)?;
+
+            getter_env.get_binding_value(agent, name("y"), false)
+        };
+
+        // The setter reads a binding of the same environment before writing it.
+        let setter_env = env.clone();
+        let setter = move |agent: &mut JSAgent, args: Args| {
+            let y =
+                JSNumber::try_from(setter_env.get_binding_value(agent, name("y"), false)?).unwrap();
+            let value = JSNumber::from(args.get_f64(0)?);
+
+            setter_env.clone().set_mutable_binding(
+                agent,
+                name("y"),
+                JSValue::Number(y.add(value)),
+                false,
+            )?;
+
+            Ok(JSValue::Undefined)
+        };
+
+        define_accessor(&mut agent, &object, "x", getter, setter);
+
+        env.set_mutable_binding(&mut agent, name("x"), 3.0.into(), false)
+            .unwrap();
+        assert_eq!(
+            env.get_binding_value(&mut agent, name("y"), false).unwrap(),
+            JSValue::from(4.0)
+        );
+
+        assert_eq!(
+            env.get_binding_value(&mut agent, name("x"), false).unwrap(),
+            JSValue::from(2.0)
+        );
+        assert!(!env.has_binding(&mut agent, name("x")).unwrap());
+        assert_eq!(
+            get(&mut agent, &object, &JSObjectPropKey::from(name("y"))).unwrap(),
+            JSValue::from(2.0)
+        );
+    }
+
+    #[test]
+    fn global_environment_accessors_can_reenter_the_environment() {
+        let mut agent = JSAgent::default();
+        agent.initialize_realm();
+
+        let global_object = create_object(&mut agent, &[]);
+        let mut env = new_global_environment(&mut agent, &global_object, &global_object);
+        env.create_mutable_binding(name("y"), false).unwrap();
+        env.initialize_binding(&mut agent, name("y"), 1.0.into())
+            .unwrap();
+
+        // The getter of a property of the global object writes a lexical binding of the same environment.
+        let getter_env = env.clone();
+        let getter = move |agent: &mut JSAgent, _: Args| {
+            getter_env
+                .clone()
+                .set_mutable_binding(agent, name("y"), 2.0.into(), false)?;
+
+            Ok(JSValue::from(3.0))
+        };
+        let setter = |_: &mut JSAgent, _: Args| Ok(JSValue::Undefined);
+
+        define_accessor(&mut agent, &global_object, "x", getter, setter);
+
+        assert_eq!(
+            env.get_binding_value(&mut agent, name("x"), false).unwrap(),
+            JSValue::from(3.0)
+        );
+        assert_eq!(
+            env.get_binding_value(&mut agent, name("y"), false).unwrap(),
+            JSValue::from(2.0)
+        );
+    }
 }
@@ -2,9 +2,9 @@ use crate::{
     abstract_ops::environments::get_identifier_reference,
     runtime::{
         agent::JSAgent, completion::CompletionRecord, environment::EnvironmentAddr,
-        execution_context::ScriptOrModule, reference::Reference,
+        environment::EnvironmentMethods, execution_context::ScriptOrModule, reference::Reference,
     },
-    value::string::JSString,
+    value::{atom::Atom, object::ObjectAddr, JSValue},
 };
 
 /// 9.4.1 GetActiveScriptOrModule ( )
@@ -29,9 +29,10 @@ pub(crate) fn get_active_script_or_module(agent: &JSAgent) -> Option<&ScriptOrMo
 /// 9.4.2 ResolveBinding ( name [ , env ] )
 /// https://262.ecma-international.org/16.0/#sec-resolvebinding
 pub(crate) fn resolve_binding(
-    agent: &JSAgent,
-    name: &JSString,
+    agent: &mut JSAgent,
+    name: Atom,
     env: Option<EnvironmentAddr>,
+    strict: bool,
 ) -> CompletionRecord<Reference> {
     // 1. If env is not present or env is undefined, then
     let env = match env {
@@ -46,9 +47,68 @@ pub(crate) fn resolve_binding(
 
     // 2. Assert: env is an Environment Record.
     // 3. Let strict be IsStrict(the syntactic production that is being evaluated).
-    // TODO: Grab the strict mode flag from the parser state.
-    let strict = true;
+    // NOTE: Passed in by the caller, as the strictness is a property of the code being evaluated.
 
     // 4. Return ? GetIdentifierReference(env, name, strict).
-    get_identifier_reference(Some(env), name, strict)
+    get_identifier_reference(agent, Some(env), name, strict)
+}
+
+/// 9.4.3 GetThisEnvironment ( )
+/// https://262.ecma-international.org/16.0/#sec-getthisenvironment
+pub(crate) fn get_this_environment(agent: &JSAgent) -> EnvironmentAddr {
+    // 1. Let env be the running execution context's LexicalEnvironment.
+    let mut env = agent
+        .running_execution_context()
+        .lexical_environment
+        .clone()
+        .unwrap();
+
+    // 2. Repeat,
+    loop {
+        // a. Let exists be env.HasThisBinding().
+        // b. If exists is true, return env.
+        if env.has_this_binding() {
+            return env;
+        }
+
+        // c. Let outer be env.[[OuterEnv]].
+        // d. Assert: outer is not null.
+        // e. Set env to outer.
+        env = env.outer().unwrap();
+    }
+}
+
+/// 9.4.4 ResolveThisBinding ( )
+/// https://262.ecma-international.org/16.0/#sec-resolvethisbinding
+pub(crate) fn resolve_this_binding(agent: &JSAgent) -> CompletionRecord<JSValue> {
+    // 1. Let envRec be GetThisEnvironment().
+    let env_rec = get_this_environment(agent);
+
+    // 2. Return ? envRec.GetThisBinding().
+    env_rec.get_this_binding()
+}
+
+/// 9.4.5 GetNewTarget ( )
+/// https://262.ecma-international.org/16.0/#sec-getnewtarget
+pub(crate) fn get_new_target(agent: &JSAgent) -> JSValue {
+    // 1. Let envRec be GetThisEnvironment().
+    let env_rec = get_this_environment(agent);
+
+    // 2. Assert: envRec has a [[NewTarget]] field.
+    // 3. Return envRec.[[NewTarget]].
+    env_rec
+        .new_target()
+        .map_or(JSValue::Undefined, JSValue::from)
+}
+
+/// 9.4.8 GetGlobalObject ( )
+/// https://262.ecma-international.org/16.0/#sec-getglobalobject
+pub(crate) fn get_global_object(agent: &JSAgent) -> ObjectAddr {
+    // 1. Let currentRealm be the current Realm Record.
+    let current_realm = agent.current_realm();
+
+    // 2. Return currentRealm.[[GlobalObject]].
+    let global_object = current_realm.borrow().global_object.clone();
+
+    global_object.unwrap()
 }
@@ -0,0 +1,544 @@
+use std::rc::Rc;
+
+use crate::{
+    abstract_ops::{
+        async_function_operations::async_function_start,
+        environments::new_declarative_environment,
+        function_operations::{
+            function_declaration_instantiation, make_constructor, make_method,
+            ordinary_function_create, set_function_name,
+        },
+        generator_operations::generator_start,
+        object_operations::{call, define_property_or_throw},
+        ordinary::{ordinary_create_from_constructor, ordinary_object_create},
+        promise_operations::new_promise_capability,
+    },
+    codegen::bytecode::generator::{ExecutableFunction, GeneratorKind},
+    runtime::{
+        agent::JSAgent,
+        completion::CompletionRecord,
+        environment::{EnvironmentAddr, EnvironmentMethods},
+        generator::GeneratorState,
+    },
+    value::{
+        object::{
+            internal_slots::InternalSlotName,
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        string::JSString,
+        JSValue,
+    },
+};
+
+/// 8.6.1 Runtime Semantics: InstantiateFunctionObject
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-instantiatefunctionobject
+pub(crate) fn instantiate_function_object(
+    agent: &mut JSAgent,
+    function: Rc<ExecutableFunction>,
+    env: EnvironmentAddr,
+) -> ObjectAddr {
+    match function.generator_kind {
+        // AsyncFunctionDeclaration : async function BindingIdentifier ( FormalParameters ) { AsyncFunctionBody }
+        // 1. Return InstantiateAsyncFunctionObject of AsyncFunctionDeclaration with arguments env and privateEnv.
+        GeneratorKind::NonGenerator if function.is_async => {
+            instantiate_async_function_object(agent, function, env)
+        }
+        // FunctionDeclaration : function BindingIdentifier ( FormalParameters ) { FunctionBody }
+        // 1. Return InstantiateOrdinaryFunctionObject of FunctionDeclaration with arguments env and privateEnv.
+        GeneratorKind::NonGenerator => instantiate_ordinary_function_object(agent, function, env),
+        // GeneratorDeclaration : function * BindingIdentifier ( FormalParameters ) { GeneratorBody }
+        // 1. Return InstantiateGeneratorFunctionObject of GeneratorDeclaration with arguments env and privateEnv.
+        GeneratorKind::Sync => instantiate_generator_function_object(agent, function, env),
+    }
+}
+
+/// 15.2.4 Runtime Semantics: InstantiateOrdinaryFunctionObject
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-instantiateordinaryfunctionobject
+fn instantiate_ordinary_function_object(
+    agent: &mut JSAgent,
+    function: Rc<ExecutableFunction>,
+    env: EnvironmentAddr,
+) -> ObjectAddr {
+    // FunctionDeclaration : function BindingIdentifier ( FormalParameters ) { FunctionBody }
+    // 1. Let name be the StringValue of BindingIdentifier.
+    let name = function.name.clone().unwrap();
+
+    // 2. Let sourceText be the source text matched by FunctionDeclaration.
+    // 3. Let F be OrdinaryFunctionCreate(%Function.prototype%, sourceText, FormalParameters, FunctionBody, non-lexical-this, env, privateEnv).
+    let function_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .function_prototype
+        .clone();
+
+    let function_obj = ordinary_function_create(agent, function_prototype, function, false, env);
+
+    // 4. Perform SetFunctionName(F, name).
+    set_function_name(&function_obj, JSObjectPropKey::from(name), None);
+
+    // 5. Perform MakeConstructor(F).
+    make_constructor(agent, &function_obj);
+
+    // 6. Return F.
+    function_obj
+}
+
+/// 15.2.5 Runtime Semantics: InstantiateOrdinaryFunctionExpression
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-instantiateordinaryfunctionexpression
+pub(crate) fn instantiate_ordinary_function_expression(
+    agent: &mut JSAgent,
+    function: Rc<ExecutableFunction>,
+) -> ObjectAddr {
+    let function_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .function_prototype
+        .clone();
+
+    // 1. Let outerEnv be the LexicalEnvironment of the running execution context.
+    let outer_env = agent
+        .running_execution_context()
+        .lexical_environment
+        .clone()
+        .unwrap();
+
+    let Some(name) = function.name.clone() else {
+        // FunctionExpression : function ( FormalParameters ) { FunctionBody }
+        // 1. If name is not present, set name to "".
+        // 2. Let env be the LexicalEnvironment of the running execution context.
+        // 3. Let privateEnv be the running execution context's PrivateEnvironment.
+        // 4. Let sourceText be the source text matched by FunctionExpression.
+        // 5. Let closure be OrdinaryFunctionCreate(%Function.prototype%, sourceText, FormalParameters, FunctionBody, non-lexical-this, env, privateEnv).
+        let closure =
+            ordinary_function_create(agent, function_prototype, function, false, outer_env);
+
+        // 6. Perform SetFunctionName(closure, name).
+        set_function_name(&closure, JSObjectPropKey::from(JSString::from("")), None);
+
+        // 7. Perform MakeConstructor(closure).
+        make_constructor(agent, &closure);
+
+        // 8. Return closure.
+        return closure;
+    };
+
+    // FunctionExpression : function BindingIdentifier ( FormalParameters ) { FunctionBody }
+    // 1. Assert: name is not present.
+    // 2. Set name to the StringValue of BindingIdentifier.
+    // 3. Let outerEnv be the running execution context's LexicalEnvironment.
+    // 4. Let funcEnv be NewDeclarativeEnvironment(outerEnv).
+    let mut func_env = new_declarative_environment(agent, Some(outer_env));
+
+    // 5. Perform ! funcEnv.CreateImmutableBinding(name, false).
+    let _ = func_env.create_immutable_binding(name.clone(), false);
+
+    // 6. Let privateEnv be the running execution context's PrivateEnvironment.
+    // 7. Let sourceText be the source text matched by FunctionExpression.
+    // 8. Let closure be OrdinaryFunctionCreate(%Function.prototype%, sourceText, FormalParameters, FunctionBody, non-lexical-this, funcEnv, privateEnv).
+    let closure =
+        ordinary_function_create(agent, function_prototype, function, false, func_env.clone());
+
+    // 9. Perform SetFunctionName(closure, name).
+    set_function_name(&closure, JSObjectPropKey::from(name.clone()), None);
+
+    // 10. Perform MakeConstructor(closure).
+    make_constructor(agent, &closure);
+
+    // 11. Perform ! funcEnv.InitializeBinding(name, closure).
+    let _ = func_env.initialize_binding(agent, name, JSValue::from(&closure));
+
+    // 12. Return closure.
+    closure
+}
+
+/// The kind of accessor function defined by a MethodDefinition of the form `get ClassElementName` or `set ClassElementName`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum AccessorKind {
+    Get,
+    Set,
+}
+
+/// 15.4.5 Runtime Semantics: MethodDefinitionEvaluation
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-methoddefinitionevaluation
+///
+/// MethodDefinition : get ClassElementName ( ) { FunctionBody }
+/// MethodDefinition : set ClassElementName ( PropertySetParameterList ) { FunctionBody }
+pub(crate) fn accessor_method_definition_evaluation(
+    agent: &mut JSAgent,
+    object: &ObjectAddr,
+    prop_key: JSObjectPropKey,
+    function: Rc<ExecutableFunction>,
+    kind: AccessorKind,
+    enumerable: bool,
+) -> CompletionRecord {
+    // 1. Let propKey be ? Evaluation of ClassElementName.
+    // 2. Let env be the running execution context's LexicalEnvironment.
+    let env = agent
+        .running_execution_context()
+        .lexical_environment
+        .clone()
+        .unwrap();
+
+    // 3. Let privateEnv be the running execution context's PrivateEnvironment.
+    // 4. Let sourceText be the source text matched by MethodDefinition.
+    // 5. Let formalParameterList be an instance of the production FormalParameters : [empty] .
+    // 6. Let closure be OrdinaryFunctionCreate(%Function.prototype%, sourceText, formalParameterList, FunctionBody, non-lexical-this, env, privateEnv).
+    let function_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .function_prototype
+        .clone();
+
+    let closure = ordinary_function_create(agent, function_prototype, function, false, env);
+
+    // 7. Perform MakeMethod(closure, object).
+    make_method(&closure, object.clone());
+
+    // 8. Perform SetFunctionName(closure, propKey, "get").
+    let prefix = match kind {
+        AccessorKind::Get => "get",
+        AccessorKind::Set => "set",
+    };
+
+    set_function_name(&closure, prop_key.clone(), Some(prefix.to_string()));
+
+    // 9. If propKey is a Private Name, then
+    // NOTE: Private names only occur in class bodies, which are not implemented.
+    // 10. Else,
+    // a. Let desc be the PropertyDescriptor { [[Get]]: closure, [[Enumerable]]: enumerable, [[Configurable]]: true }.
+    let closure = Some(JSValue::from(closure));
+
+    let desc = JSObjectPropDescriptor {
+        get: closure.clone().filter(|_| kind == AccessorKind::Get),
+        set: closure.filter(|_| kind == AccessorKind::Set),
+        enumerable: Some(enumerable),
+        configurable: Some(true),
+        ..JSObjectPropDescriptor::default()
+    };
+
+    // b. Perform ? DefinePropertyOrThrow(object, propKey, desc).
+    define_property_or_throw(object, &prop_key, desc)?;
+
+    // c. Return unused.
+    Ok(())
+}
+
+/// 15.5.2 Runtime Semantics: EvaluateGeneratorBody
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-evaluategeneratorbody
+pub(crate) fn evaluate_generator_body(
+    agent: &mut JSAgent,
+    function_obj: &ObjectAddr,
+    code: Rc<ExecutableFunction>,
+    arguments_list: &[JSValue],
+) -> CompletionRecord<JSValue> {
+    // 1. Perform ? FunctionDeclarationInstantiation(functionObject, argumentsList).
+    function_declaration_instantiation(agent, function_obj, &code, arguments_list)?;
+
+    // 2. Let G be ? OrdinaryCreateFromConstructor(functionObject, "%GeneratorFunction.prototype.prototype%", « [[GeneratorState]], [[GeneratorContext]], [[GeneratorBrand]] »).
+    let generator = ordinary_create_from_constructor(
+        agent,
+        function_obj,
+        |intrinsics| intrinsics.generator_function_prototype_prototype.clone(),
+        Some(vec![
+            InternalSlotName::GeneratorState,
+            InternalSlotName::GeneratorContext,
+            InternalSlotName::GeneratorBrand,
+        ]),
+    )?;
+
+    // 3. Set G.[[GeneratorBrand]] to empty.
+    // 4. Set G.[[GeneratorState]] to suspended-start.
+    generator
+        .data_mut()
+        .slots_mut()
+        .set_generator_state(GeneratorState::SuspendedStart);
+
+    // 5. Perform GeneratorStart(G, FunctionBody).
+    generator_start(agent, &generator, code);
+
+    // 6. Return ReturnCompletion(G).
+    Ok(JSValue::from(generator))
+}
+
+/// 15.5.3 Runtime Semantics: InstantiateGeneratorFunctionObject
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-instantiategeneratorfunctionobject
+fn instantiate_generator_function_object(
+    agent: &mut JSAgent,
+    function: Rc<ExecutableFunction>,
+    env: EnvironmentAddr,
+) -> ObjectAddr {
+    // GeneratorDeclaration : function * BindingIdentifier ( FormalParameters ) { GeneratorBody }
+    // 1. Let name be the StringValue of BindingIdentifier.
+    let name = function.name.clone().unwrap();
+
+    // 2. Let sourceText be the source text matched by GeneratorDeclaration.
+    // 3. Let F be OrdinaryFunctionCreate(%GeneratorFunction.prototype%, sourceText, FormalParameters, GeneratorBody, non-lexical-this, env, privateEnv).
+    let generator_function_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .generator_function_prototype
+        .clone();
+
+    let function_obj =
+        ordinary_function_create(agent, generator_function_prototype, function, false, env);
+
+    // 4. Perform SetFunctionName(F, name).
+    set_function_name(&function_obj, JSObjectPropKey::from(name), None);
+
+    // 5. Let prototype be OrdinaryObjectCreate(%GeneratorFunction.prototype.prototype%).
+    // 6. Perform ! DefinePropertyOrThrow(F, "prototype", PropertyDescriptor { [[Value]]: prototype, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
+    define_generator_prototype(agent, &function_obj);
+
+    // 7. Return F.
+    function_obj
+}
+
+/// 15.5.4 Runtime Semantics: InstantiateGeneratorFunctionExpression
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-instantiategeneratorfunctionexpression
+pub(crate) fn instantiate_generator_function_expression(
+    agent: &mut JSAgent,
+    function: Rc<ExecutableFunction>,
+) -> ObjectAddr {
+    let generator_function_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .generator_function_prototype
+        .clone();
+
+    let outer_env = agent
+        .running_execution_context()
+        .lexical_environment
+        .clone()
+        .unwrap();
+
+    let Some(name) = function.name.clone() else {
+        // GeneratorExpression : function * ( FormalParameters ) { GeneratorBody }
+        // 1. If name is not present, set name to "".
+        // 2. Let env be the LexicalEnvironment of the running execution context.
+        // 3. Let privateEnv be the running execution context's PrivateEnvironment.
+        // 4. Let sourceText be the source text matched by GeneratorExpression.
+        // 5. Let closure be OrdinaryFunctionCreate(%GeneratorFunction.prototype%, sourceText, FormalParameters, GeneratorBody, non-lexical-this, env, privateEnv).
+        let closure = ordinary_function_create(
+            agent,
+            generator_function_prototype,
+            function,
+            false,
+            outer_env,
+        );
+
+        // 6. Perform SetFunctionName(closure, name).
+        set_function_name(&closure, JSObjectPropKey::from(JSString::from("")), None);
+
+        // 7. Let prototype be OrdinaryObjectCreate(%GeneratorFunction.prototype.prototype%).
+        // 8. Perform ! DefinePropertyOrThrow(closure, "prototype", PropertyDescriptor { [[Value]]: prototype, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
+        define_generator_prototype(agent, &closure);
+
+        // 9. Return closure.
+        return closure;
+    };
+
+    // GeneratorExpression : function * BindingIdentifier ( FormalParameters ) { GeneratorBody }
+    // 1. Assert: name is not present.
+    // 2. Set name to the StringValue of BindingIdentifier.
+    // 3. Let outerEnv be the running execution context's LexicalEnvironment.
+    // 4. Let funcEnv be NewDeclarativeEnvironment(outerEnv).
+    let mut func_env = new_declarative_environment(agent, Some(outer_env));
+
+    // 5. Perform ! funcEnv.CreateImmutableBinding(name, false).
+    let _ = func_env.create_immutable_binding(name.clone(), false);
+
+    // 6. Let privateEnv be the running execution context's PrivateEnvironment.
+    // 7. Let sourceText be the source text matched by GeneratorExpression.
+    // 8. Let closure be OrdinaryFunctionCreate(%GeneratorFunction.prototype%, sourceText, FormalParameters, GeneratorBody, non-lexical-this, funcEnv, privateEnv).
+    let closure = ordinary_function_create(
+        agent,
+        generator_function_prototype,
+        function,
+        false,
+        func_env.clone(),
+    );
+
+    // 9. Perform SetFunctionName(closure, name).
+    set_function_name(&closure, JSObjectPropKey::from(name.clone()), None);
+
+    // 10. Let prototype be OrdinaryObjectCreate(%GeneratorFunction.prototype.prototype%).
+    // 11. Perform ! DefinePropertyOrThrow(closure, "prototype", PropertyDescriptor { [[Value]]: prototype, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
+    define_generator_prototype(agent, &closure);
+
+    // 12. Perform ! funcEnv.InitializeBinding(name, closure).
+    let _ = func_env.initialize_binding(agent, name, JSValue::from(&closure));
+
+    // 13. Return closure.
+    closure
+}
+
+/// Defines the "prototype" property of a generator function, whose value is the prototype of the generators it creates.
+fn define_generator_prototype(agent: &mut JSAgent, function_obj: &ObjectAddr) {
+    let generator_function_prototype_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .generator_function_prototype_prototype
+        .clone();
+
+    let prototype = ordinary_object_create(agent, generator_function_prototype_prototype, None);
+
+    let _ = define_property_or_throw(
+        function_obj,
+        &JSObjectPropKey::String("prototype".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(prototype)),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        },
+    );
+}
+
+/// 15.8.2 Runtime Semantics: InstantiateAsyncFunctionObject
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-instantiateasyncfunctionobject
+fn instantiate_async_function_object(
+    agent: &mut JSAgent,
+    function: Rc<ExecutableFunction>,
+    env: EnvironmentAddr,
+) -> ObjectAddr {
+    // AsyncFunctionDeclaration : async function BindingIdentifier ( FormalParameters ) { AsyncFunctionBody }
+    // 1. Let name be the StringValue of BindingIdentifier.
+    let name = function.name.clone().unwrap();
+
+    // 2. Let sourceText be the source text matched by AsyncFunctionDeclaration.
+    // 3. Let F be OrdinaryFunctionCreate(%AsyncFunction.prototype%, sourceText, FormalParameters, AsyncFunctionBody, non-lexical-this, env, privateEnv).
+    let async_function_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .async_function_prototype
+        .clone();
+
+    let function_obj =
+        ordinary_function_create(agent, async_function_prototype, function, false, env);
+
+    // 4. Perform SetFunctionName(F, name).
+    set_function_name(&function_obj, JSObjectPropKey::from(name), None);
+
+    // 5. Return F.
+    function_obj
+}
+
+/// 15.8.3 Runtime Semantics: InstantiateAsyncFunctionExpression
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-instantiateasyncfunctionexpression
+pub(crate) fn instantiate_async_function_expression(
+    agent: &mut JSAgent,
+    function: Rc<ExecutableFunction>,
+) -> ObjectAddr {
+    let async_function_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .async_function_prototype
+        .clone();
+
+    let outer_env = agent
+        .running_execution_context()
+        .lexical_environment
+        .clone()
+        .unwrap();
+
+    let Some(name) = function.name.clone() else {
+        // AsyncFunctionExpression : async function ( FormalParameters ) { AsyncFunctionBody }
+        // 1. If name is not present, set name to "".
+        // 2. Let env be the LexicalEnvironment of the running execution context.
+        // 3. Let privateEnv be the running execution context's PrivateEnvironment.
+        // 4. Let sourceText be the source text matched by AsyncFunctionExpression.
+        // 5. Let closure be OrdinaryFunctionCreate(%AsyncFunction.prototype%, sourceText, FormalParameters, AsyncFunctionBody, non-lexical-this, env, privateEnv).
+        let closure =
+            ordinary_function_create(agent, async_function_prototype, function, false, outer_env);
+
+        // 6. Perform SetFunctionName(closure, name).
+        set_function_name(&closure, JSObjectPropKey::from(JSString::from("")), None);
+
+        // 7. Return closure.
+        return closure;
+    };
+
+    // AsyncFunctionExpression : async function BindingIdentifier ( FormalParameters ) { AsyncFunctionBody }
+    // 1. Assert: name is not present.
+    // 2. Set name to the StringValue of BindingIdentifier.
+    // 3. Let outerEnv be the LexicalEnvironment of the running execution context.
+    // 4. Let funcEnv be NewDeclarativeEnvironment(outerEnv).
+    let mut func_env = new_declarative_environment(agent, Some(outer_env));
+
+    // 5. Perform ! funcEnv.CreateImmutableBinding(name, false).
+    let _ = func_env.create_immutable_binding(name.clone(), false);
+
+    // 6. Let privateEnv be the running execution context's PrivateEnvironment.
+    // 7. Let sourceText be the source text matched by AsyncFunctionExpression.
+    // 8. Let closure be OrdinaryFunctionCreate(%AsyncFunction.prototype%, sourceText, FormalParameters, AsyncFunctionBody, non-lexical-this, funcEnv, privateEnv).
+    let closure = ordinary_function_create(
+        agent,
+        async_function_prototype,
+        function,
+        false,
+        func_env.clone(),
+    );
+
+    // 9. Perform SetFunctionName(closure, name).
+    set_function_name(&closure, JSObjectPropKey::from(name.clone()), None);
+
+    // 10. Perform ! funcEnv.InitializeBinding(name, closure).
+    let _ = func_env.initialize_binding(agent, name, JSValue::from(&closure));
+
+    // 11. Return closure.
+    closure
+}
+
+/// 15.8.4 Runtime Semantics: EvaluateAsyncFunctionBody
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-evaluateasyncfunctionbody
+pub(crate) fn evaluate_async_function_body(
+    agent: &mut JSAgent,
+    function_obj: &ObjectAddr,
+    code: Rc<ExecutableFunction>,
+    arguments_list: &[JSValue],
+) -> CompletionRecord<JSValue> {
+    // 1. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    let promise_constructor = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .promise
+        .clone()
+        .unwrap();
+
+    let promise_capability = new_promise_capability(agent, &JSValue::from(promise_constructor))?;
+
+    // 2. Let declResult be Completion(FunctionDeclarationInstantiation(functionObject, argumentsList)).
+    // 3. If declResult is an abrupt completion, then
+    if let Err(error) =
+        function_declaration_instantiation(agent, function_obj, &code, arguments_list)
+    {
+        let reason = error.into_value(agent);
+
+        // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « declResult.[[Value]] »).
+        let _ = call(
+            agent,
+            promise_capability.reject.clone(),
+            &JSValue::Undefined,
+            Some(vec![reason]),
+        );
+    }
+    // 4. Else,
+    else {
+        // a. Perform AsyncFunctionStart(promiseCapability, FunctionBody).
+        async_function_start(agent, promise_capability.clone(), code);
+    }
+
+    // 5. Return ReturnCompletion(promiseCapability.[[Promise]]).
+    Ok(JSValue::from(promise_capability.promise))
+}
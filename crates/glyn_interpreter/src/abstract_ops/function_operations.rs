@@ -1,18 +1,347 @@
+use std::rc::Rc;
+
+use crate::abstract_ops::arguments_exotic_objects::{
+    create_mapped_arguments_object, create_unmapped_arguments_object,
+};
+use crate::abstract_ops::environments::{new_declarative_environment, new_function_environment};
+use crate::abstract_ops::function_definitions::{
+    evaluate_async_function_body, evaluate_generator_body, instantiate_function_object,
+};
 use crate::abstract_ops::object_operations::{
-    define_property_or_throw, has_property, make_basic_object,
+    create_array_from_list, define_property_or_throw, has_own_property, make_basic_object,
 };
+use crate::abstract_ops::ordinary::ordinary_object_create;
+use crate::abstract_ops::type_conversion::to_object;
+use crate::codegen::bytecode::generator::{ExecutableFunction, GeneratorKind};
 use crate::runtime::agent::JSAgent;
+use crate::runtime::completion::CompletionRecord;
+use crate::runtime::environment::{EnvironmentAddr, EnvironmentMethods};
+use crate::runtime::execution_context::ExecutionContext;
+use crate::runtime::native_function::{Args, NativeFunction};
 use crate::runtime::realm::RealmAddr;
-use crate::value::object::{ObjectEssentialInternalMethods, ObjectMeta};
+use crate::value::object::{ObjectEssentialInternalMethods, ObjectKind, ObjectMeta};
 use crate::value::{
+    atom::Atom,
     object::{
-        internal_slots::{BehaviourFn, InternalSlotName},
+        internal_slots::{BehaviourFn, ConstructorKind, InternalSlotName, ThisMode},
         property::{JSObjectPropDescriptor, JSObjectPropKey},
         ObjectAddr,
     },
     string::JSString,
     JSValue,
 };
+use crate::vm::VM;
+
+/// 10.2.1.1 PrepareForOrdinaryCall ( F, newTarget )
+/// https://262.ecma-international.org/16.0/#sec-prepareforordinarycall
+pub(crate) fn prepare_for_ordinary_call(
+    agent: &mut JSAgent,
+    function_obj: &(impl ObjectMeta + ObjectEssentialInternalMethods),
+    new_target: Option<ObjectAddr>,
+) {
+    // 1. Let callerContext be the running execution context.
+    // 2. Let calleeContext be a new ECMAScript code execution context.
+    // 3. Set the Function of calleeContext to F.
+    // 4. Let calleeRealm be F.[[Realm]].
+    // 5. Set the Realm of calleeContext to calleeRealm.
+    let callee_realm = function_obj.data().slots().realm().cloned();
+
+    // 6. Set the ScriptOrModule of calleeContext to F.[[ScriptOrModule]].
+    // NOTE: The [[ScriptOrModule]] of a function is not tracked yet.
+
+    // 7. Let localEnv be NewFunctionEnvironment(F, newTarget).
+    let local_env = new_function_environment(agent, function_obj, new_target);
+
+    let callee_context = ExecutionContext {
+        function: Some(function_obj.addr()),
+        realm: callee_realm.unwrap_or_else(|| agent.current_realm()),
+        script_or_module: None,
+
+        // 8. Set the LexicalEnvironment of calleeContext to localEnv.
+        lexical_environment: Some(local_env.clone()),
+
+        // 9. Set the VariableEnvironment of calleeContext to localEnv.
+        variable_environment: Some(local_env),
+
+        // 10. Set the PrivateEnvironment of calleeContext to F.[[Environment]].[[PrivateEnvironment]].
+        private_environment: None,
+    };
+
+    if let Some(profiler) = agent.profiler() {
+        profiler.record_call(&function_obj.addr());
+    }
+
+    // 11. If callerContext is not already suspended, suspend callerContext.
+    // 12. Push calleeContext onto the execution context stack; calleeContext is now the running execution context.
+    agent.push_execution_context(callee_context);
+
+    // 13. NOTE: Any exception objects produced after this point are associated with calleeRealm.
+    // 14. Return calleeContext.
+}
+
+/// 10.2.1.2 OrdinaryCallBindThis ( F, calleeContext, thisArgument )
+/// https://262.ecma-international.org/16.0/#sec-ordinarycallbindthis
+pub(crate) fn ordinary_call_bind_this(
+    agent: &mut JSAgent,
+    function_obj: &(impl ObjectMeta + ObjectEssentialInternalMethods),
+    this_argument: &JSValue,
+) {
+    // 1. Let thisMode be F.[[ThisMode]].
+    let this_mode = function_obj.data().slots().this_mode();
+
+    // 2. If thisMode is lexical, return unused.
+    if this_mode == Some(ThisMode::Lexical) {
+        return;
+    }
+
+    // 3. Let calleeRealm be F.[[Realm]].
+    let callee_realm = agent.current_realm();
+
+    // 4. Let localEnv be the LexicalEnvironment of calleeContext.
+    let local_env = agent
+        .running_execution_context()
+        .lexical_environment
+        .clone()
+        .unwrap();
+
+    let this_value = match this_mode {
+        // 5. If thisMode is strict, then
+        // a. Let thisValue be thisArgument.
+        Some(ThisMode::Strict) => this_argument.clone(),
+        // 6. Else,
+        _ => {
+            // a. If thisArgument is either undefined or null, then
+            if this_argument.is_undefined() || this_argument.is_null() {
+                // i. Let globalEnv be calleeRealm.[[GlobalEnv]].
+                let global_env = callee_realm.borrow().global_env.clone().unwrap();
+
+                // ii. Assert: globalEnv is a Global Environment Record.
+                // iii. Let thisValue be globalEnv.[[GlobalThisValue]].
+                global_env.get_this_binding().unwrap_or(JSValue::Undefined)
+            } else {
+                // b. Else,
+                // i. Let thisValue be ! ToObject(thisArgument).
+                // ii. NOTE: ToObject produces wrapper objects using calleeRealm.
+                JSValue::from(to_object(agent, this_argument).unwrap())
+            }
+        }
+    };
+
+    // 7. Assert: localEnv is a Function Environment Record.
+    // 8. Assert: The next step never returns an abrupt completion because localEnv.[[ThisBindingStatus]] is not initialized.
+    // 9. Perform ! localEnv.BindThisValue(thisValue).
+    let _ = local_env.bind_this_value(this_value);
+
+    // 10. Return unused.
+}
+
+/// 10.2.1.4 OrdinaryCallEvaluateBody ( F, argumentsList )
+/// https://262.ecma-international.org/16.0/#sec-ordinarycallevaluatebody
+pub(crate) fn ordinary_call_evaluate_body(
+    agent: &mut JSAgent,
+    function_obj: &(impl ObjectMeta + ObjectEssentialInternalMethods),
+    arguments_list: &[JSValue],
+) -> CompletionRecord<JSValue> {
+    // 1. Return ? EvaluateBody of F.[[ECMAScriptCode]] with arguments F and argumentsList.
+    let code = function_obj.data().slots().ecmascript_code().unwrap();
+
+    // NOTE: 10.2.1.3 Runtime Semantics: EvaluateBody, GeneratorBody : FunctionBody
+    // 1. Return ? EvaluateGeneratorBody of GeneratorBody with arguments functionObject and argumentsList.
+    if code.generator_kind == GeneratorKind::Sync {
+        return evaluate_generator_body(agent, &function_obj.addr(), code, arguments_list);
+    }
+
+    // NOTE: 10.2.1.3 Runtime Semantics: EvaluateBody, AsyncFunctionBody : FunctionBody
+    // 1. Return ? EvaluateAsyncFunctionBody of AsyncFunctionBody with arguments functionObject and argumentsList.
+    if code.is_async {
+        return evaluate_async_function_body(agent, &function_obj.addr(), code, arguments_list);
+    }
+
+    // NOTE: 10.2.1.3 Runtime Semantics: EvaluateBody, FunctionBody : FunctionStatementList
+    // 1. Perform ? FunctionDeclarationInstantiation(functionObject, argumentsList).
+    function_declaration_instantiation(agent, &function_obj.addr(), &code, arguments_list)?;
+
+    // 2. Return ? Evaluation of FunctionStatementList.
+    Ok(VM::new(agent, &code.program).evaluate_function_body()?)
+}
+
+/// 10.2.3 OrdinaryFunctionCreate ( functionPrototype, sourceText, ParameterList, Body, thisMode, env, privateEnv )
+/// https://262.ecma-international.org/16.0/#sec-ordinaryfunctioncreate
+pub(crate) fn ordinary_function_create(
+    agent: &mut JSAgent,
+    function_prototype: Option<ObjectAddr>,
+    code: Rc<ExecutableFunction>,
+    lexical_this: bool,
+    env: EnvironmentAddr,
+) -> ObjectAddr {
+    // 1. Assert: functionPrototype is an Object.
+    // 2. Let internalSlotsList be the internal slots listed in Table 30.
+    let internal_slots_list = vec![
+        InternalSlotName::Environment,
+        InternalSlotName::ECMAScriptCode,
+        InternalSlotName::Realm,
+        InternalSlotName::Strict,
+        InternalSlotName::ThisMode,
+    ];
+
+    // 3. Let F be OrdinaryObjectCreate(functionPrototype, internalSlotsList).
+    let function_obj = ordinary_object_create(agent, function_prototype, Some(internal_slots_list));
+
+    // 4. Set F.[[Call]] to the definition specified in 10.2.1.
+    function_obj.data_mut().set_kind(ObjectKind::Function);
+
+    // 5. Set F.[[SourceText]] to sourceText.
+    // 6. Set F.[[FormalParameters]] to ParameterList.
+    // 7. Set F.[[ECMAScriptCode]] to Body.
+    let formal_parameters_length = code.formal_parameters.expected_argument_count();
+
+    // 8. Let Strict be IsStrict(Body).
+    let strict = code.program.strict;
+
+    function_obj
+        .data_mut()
+        .slots_mut()
+        .set_ecmascript_code(code);
+
+    // 9. Set F.[[Strict]] to Strict.
+    function_obj.data_mut().slots_mut().set_strict(strict);
+
+    let this_mode = if lexical_this {
+        // 10. If thisMode is lexical-this, set F.[[ThisMode]] to lexical.
+        ThisMode::Lexical
+    } else if strict {
+        // 11. Else if Strict is true, set F.[[ThisMode]] to strict.
+        ThisMode::Strict
+    } else {
+        // 12. Else, set F.[[ThisMode]] to global.
+        ThisMode::Global
+    };
+
+    function_obj.data_mut().slots_mut().set_this_mode(this_mode);
+
+    // 13. Set F.[[IsClassConstructor]] to false.
+    // 14. Set F.[[Environment]] to env.
+    function_obj.data_mut().slots_mut().set_environment(env);
+
+    // 15. Set F.[[PrivateEnvironment]] to privateEnv.
+    // 16. Set F.[[ScriptOrModule]] to GetActiveScriptOrModule().
+    // 17. Set F.[[Realm]] to the current Realm Record.
+    let realm = agent.current_realm();
+
+    function_obj.data_mut().slots_mut().set_realm(realm);
+
+    // 18. Set F.[[HomeObject]] to undefined.
+    // 19. Set F.[[Fields]] to a new empty List.
+    // 20. Set F.[[PrivateMethods]] to a new empty List.
+    // 21. Set F.[[ClassFieldInitializerName]] to empty.
+    // 22. Let len be the ExpectedArgumentCount of ParameterList.
+    // 23. Perform SetFunctionLength(F, len).
+    set_function_length(&function_obj, formal_parameters_length);
+
+    // 24. Return F.
+    function_obj
+}
+
+/// 10.2.4 AddRestrictedFunctionProperties ( F, realm )
+/// https://262.ecma-international.org/16.0/#sec-addrestrictedfunctionproperties
+pub(crate) fn add_restricted_function_properties(function_obj: &ObjectAddr, realm: &RealmAddr) {
+    // 1. Assert: realm.[[Intrinsics]].[[%ThrowTypeError%]] exists and has been initialized.
+    // 2. Let thrower be realm.[[Intrinsics]].[[%ThrowTypeError%]].
+    let thrower = JSValue::from(realm.borrow().intrinsics.throw_type_error.clone().unwrap());
+
+    // 3. Perform ! DefinePropertyOrThrow(F, "caller", PropertyDescriptor { [[Get]]: thrower, [[Set]]: thrower, [[Enumerable]]: false, [[Configurable]]: true }).
+    // 4. Perform ! DefinePropertyOrThrow(F, "arguments", PropertyDescriptor { [[Get]]: thrower, [[Set]]: thrower, [[Enumerable]]: false, [[Configurable]]: true }).
+    for name in ["caller", "arguments"] {
+        let _ = define_property_or_throw(
+            function_obj,
+            &JSObjectPropKey::String(name.into()),
+            JSObjectPropDescriptor {
+                get: Some(thrower.clone()),
+                set: Some(thrower.clone()),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+    }
+
+    // 5. Return unused.
+}
+
+/// 10.2.5 MakeConstructor ( F [ , writablePrototype [ , prototype ] ] )
+/// https://262.ecma-international.org/16.0/#sec-makeconstructor
+pub(crate) fn make_constructor(agent: &mut JSAgent, function_obj: &ObjectAddr) {
+    // 1. If F is an ECMAScript function object, then
+    // a. Assert: IsConstructor(F) is false.
+    debug_assert!(!function_obj.is_constructor());
+
+    // b. Assert: F is an extensible object that does not have a "prototype" own property.
+    // c. Set F.[[Construct]] to the definition specified in 10.2.2.
+    // 2. Else,
+    // a. Set F.[[Construct]] to the definition specified in 10.3.2.
+    // 3. Set F.[[ConstructorKind]] to base.
+    function_obj
+        .data_mut()
+        .slots_mut()
+        .set_constructor_kind(ConstructorKind::Base);
+
+    // 4. If writablePrototype is not present, set writablePrototype to true.
+    let writable_prototype = true;
+
+    // 5. If prototype is not present, then
+    // a. Set prototype to OrdinaryObjectCreate(%Object.prototype%).
+    let object_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .object_prototype
+        .clone();
+
+    let prototype = ordinary_object_create(agent, object_prototype, None);
+
+    // b. Perform ! DefinePropertyOrThrow(prototype, "constructor", PropertyDescriptor { [[Value]]: F, [[Writable]]: writablePrototype, [[Enumerable]]: false, [[Configurable]]: true }).
+    let _ = define_property_or_throw(
+        &prototype,
+        &JSObjectPropKey::String("constructor".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(function_obj)),
+            writable: Some(writable_prototype),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    );
+
+    // 6. Perform ! DefinePropertyOrThrow(F, "prototype", PropertyDescriptor { [[Value]]: prototype, [[Writable]]: writablePrototype, [[Enumerable]]: false, [[Configurable]]: false }).
+    let _ = define_property_or_throw(
+        function_obj,
+        &JSObjectPropKey::String("prototype".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(prototype)),
+            writable: Some(writable_prototype),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        },
+    );
+
+    // 7. Return unused.
+}
+
+/// 10.2.7 MakeMethod ( F, homeObject )
+/// https://262.ecma-international.org/16.0/#sec-makemethod
+pub(crate) fn make_method(function_obj: &ObjectAddr, home_object: ObjectAddr) {
+    // 1. Assert: homeObject is an ordinary object.
+    debug_assert!(home_object.kind() == ObjectKind::Ordinary);
+
+    // 2. Set F.[[HomeObject]] to homeObject.
+    function_obj
+        .data_mut()
+        .slots_mut()
+        .set_home_object(home_object);
+
+    // 3. Return unused.
+}
 
 /// 10.2.9 SetFunctionName ( F, name [ , prefix ] )
 /// https://262.ecma-international.org/16.0/#sec-setfunctionname
@@ -23,7 +352,9 @@ pub(crate) fn set_function_name(
 ) {
     // 1. Assert: F is an extensible object that does not have a "name" own property.
     debug_assert!(
-        function_obj.data().extensible && !has_property(function_obj, &name).unwrap_or(true)
+        function_obj.data().extensible
+            && !has_own_property(function_obj, &JSObjectPropKey::String("name".into()))
+                .unwrap_or(true)
     );
 
     let mut name_str = match name {
@@ -44,11 +375,11 @@ pub(crate) fn set_function_name(
             // a. Set name to name.[[Description]].
             todo!()
         }
-        JSObjectPropKey::String(name_str) => name_str,
+        JSObjectPropKey::String(name_str) => name_str.to_js_string(),
     };
 
     // 4. If F has an [[InitialName]] internal slot, then
-    if function_obj.data().slots().initial_name().is_some() {
+    if function_obj.data().slots().has_initial_name() {
         // a. Set F.[[InitialName]] to name.
         function_obj
             .data_mut()
@@ -59,11 +390,9 @@ pub(crate) fn set_function_name(
     // 5. If prefix is present, then
     if let Some(prefix) = opt_prefix {
         // a. Set name to the string-concatenation of prefix, the code unit 0x0020 (SPACE), and name.
-        let new_name = format!("{} {:?}", prefix, name_str);
-
-        name_str = JSString::from(new_name);
+        name_str = JSString::from(format!("{prefix} ")).concat(&name_str);
         // b. If F has an [[InitialName]] internal slot, then
-        if function_obj.data().slots().initial_name().is_some() {
+        if function_obj.data().slots().has_initial_name() {
             // i. Optionally, set F.[[InitialName]] to name.
             function_obj
                 .data_mut()
@@ -99,7 +428,7 @@ pub(crate) fn set_function_length(
     // Assert: F is an extensible object that does not have a "length" own property.
     debug_assert!(
         function_obj.data().extensible
-            && !has_property(function_obj, &length_prop_key).unwrap_or(true)
+            && !has_own_property(function_obj, &length_prop_key).unwrap_or(true)
     );
 
     // 2. Perform ! DefinePropertyOrThrow(F, "length", PropertyDescriptor { [[Value]]: 𝔽(length), [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }).
@@ -122,7 +451,7 @@ pub(crate) fn set_function_length(
 /// https://262.ecma-international.org/16.0/#sec-createbuiltinfunction
 pub(crate) fn create_builtin_function(
     agent: &mut JSAgent,
-    behaviour: BehaviourFn,
+    behaviour: impl NativeFunction,
     length: usize,
     name: JSObjectPropKey,
     additional_internal_slots: Vec<InternalSlotName>,
@@ -143,12 +472,16 @@ pub(crate) fn create_builtin_function(
     internal_slots_list.extend(additional_internal_slots);
 
     // 5. Let func be a new built-in function object that, when called, performs the action described by behaviour using the provided arguments as the values of the corresponding parameters specified by behaviour. The new function object has internal slots whose names are the elements of internalSlotsList, and an [[InitialName]] internal slot.
-    let function_obj = make_basic_object(internal_slots_list);
+    let function_obj = make_basic_object(agent, internal_slots_list);
+
+    function_obj
+        .data_mut()
+        .set_kind(ObjectKind::BuiltinFunction);
 
     function_obj
         .data_mut()
         .slots_mut()
-        .set_behaviour_fn(behaviour);
+        .set_behaviour_fn(BehaviourFn::new(behaviour));
 
     // 6. Set func.[[Prototype]] to prototype.
     function_obj.data_mut().set_prototype(prototype);
@@ -173,3 +506,383 @@ pub(crate) fn create_builtin_function(
     // 13. Return func.
     function_obj
 }
+
+/// 10.2.11 FunctionDeclarationInstantiation ( func, argumentsList )
+/// https://262.ecma-international.org/16.0/#sec-functiondeclarationinstantiation
+pub(crate) fn function_declaration_instantiation(
+    agent: &mut JSAgent,
+    function_obj: &ObjectAddr,
+    code: &ExecutableFunction,
+    arguments_list: &[JSValue],
+) -> CompletionRecord {
+    // 1. Let calleeContext be the running execution context.
+    // 2. Let code be func.[[ECMAScriptCode]].
+    // 3. Let strict be func.[[Strict]].
+    let strict = code.program.strict;
+
+    // 4. Let formals be func.[[FormalParameters]].
+    let formals = &code.formal_parameters;
+
+    // 5. Let parameterNames be the BoundNames of formals.
+    let parameter_names = formals.bound_names();
+
+    // 6. If parameterNames has any duplicate entries, let hasDuplicates be true. Otherwise, let hasDuplicates be false.
+    let has_duplicates = parameter_names
+        .iter()
+        .enumerate()
+        .any(|(index, name)| parameter_names[..index].contains(name));
+
+    // 7. Let simpleParameterList be IsSimpleParameterList of formals.
+    let simple_parameter_list = formals.is_simple_parameter_list();
+
+    // 8. Let hasParameterExpressions be ContainsExpression of formals.
+    let has_parameter_expressions = formals.contains_expression();
+
+    // 9. Let varNames be the VarDeclaredNames of code.
+    // 10. Let varDeclarations be the VarScopedDeclarations of code.
+    // 11. Let lexicalNames be the LexicallyDeclaredNames of code.
+    // 12. Let functionNames be a new empty List.
+    // 13. Let functionsToInitialize be a new empty List.
+    // 14. For each element d of varDeclarations, in reverse List order, do
+    // NOTE: The parser records the function declarations of the body in source order. Var declarations are not supported yet, so the only var scoped names are those of the function declarations.
+    let functions_to_initialize = &code.program.function_declarations;
+
+    let function_names = functions_to_initialize
+        .iter()
+        .map(|index| code.program.functions[*index].name.clone().unwrap())
+        .collect::<Vec<_>>();
+
+    let arguments_name = Atom::from("arguments");
+
+    // 15. Let argumentsObjectNeeded be true.
+    // NOTE: Direct eval is not supported, so the arguments object can only be observed by a function which references it. The parser does not flag a function whose body lexically declares `arguments` and which has no parameter expressions.
+    let arguments_object_needed = code.references_arguments
+        // 16. If func.[[ThisMode]] is lexical, then
+        // a. NOTE: Arrow functions never have an arguments object.
+        // b. Set argumentsObjectNeeded to false.
+        && function_obj.data().slots().this_mode() != Some(ThisMode::Lexical)
+        // 17. Else if parameterNames contains "arguments", then
+        // a. Set argumentsObjectNeeded to false.
+        && !parameter_names.contains(&arguments_name)
+        // 18. Else if hasParameterExpressions is false, then
+        // a. If functionNames contains "arguments" or lexicalNames contains "arguments", then
+        // i. Set argumentsObjectNeeded to false.
+        && (has_parameter_expressions || !function_names.contains(&arguments_name));
+
+    let callee_env = agent
+        .running_execution_context()
+        .lexical_environment
+        .clone()
+        .unwrap();
+
+    // 19. If strict is true or hasParameterExpressions is false, then
+    let mut env = if strict || !has_parameter_expressions {
+        // a. NOTE: Only a single Environment Record is needed for the parameters, since calls to eval in strict mode code cannot create new bindings which are visible outside of the eval.
+        // b. Let env be the LexicalEnvironment of calleeContext.
+        callee_env
+    }
+    // 20. Else,
+    else {
+        // a. NOTE: A separate Environment Record is needed to ensure that bindings created by direct eval calls in the formal parameter list are outside the environment where parameters are declared.
+        // b. Let calleeEnv be the LexicalEnvironment of calleeContext.
+        // c. Let env be NewDeclarativeEnvironment(calleeEnv).
+        let env = new_declarative_environment(agent, Some(callee_env));
+
+        // d. Assert: The VariableEnvironment of calleeContext and calleeEnv are the same Environment Record.
+        // e. Set the LexicalEnvironment of calleeContext to env.
+        agent.running_execution_context_mut().lexical_environment = Some(env.clone());
+
+        env
+    };
+
+    // 21. For each String paramName of parameterNames, do
+    for param_name in &parameter_names {
+        // a. Let alreadyDeclared be ! env.HasBinding(paramName).
+        let already_declared = env.has_binding(agent, param_name.clone())?;
+
+        // b. NOTE: Early errors ensure that duplicate parameter names can only occur in non-strict functions that do not have parameter default values or rest parameters.
+        // c. If alreadyDeclared is false, then
+        if !already_declared {
+            // i. Perform ! env.CreateMutableBinding(paramName, false).
+            env.create_mutable_binding(param_name.clone(), false)?;
+
+            // ii. If hasDuplicates is true, then
+            if has_duplicates {
+                // 1. Perform ! env.InitializeBinding(paramName, undefined).
+                env.initialize_binding(agent, param_name.clone(), JSValue::Undefined)?;
+            }
+        }
+    }
+
+    // 22. If argumentsObjectNeeded is true, then
+    if arguments_object_needed {
+        // a. If strict is true or simpleParameterList is false, then
+        let ao = if strict || !simple_parameter_list {
+            // i. Let ao be CreateUnmappedArgumentsObject(argumentsList).
+            create_unmapped_arguments_object(agent, arguments_list)
+        }
+        // b. Else,
+        else {
+            // i. NOTE: A mapped argument object is only provided for non-strict functions that don't have a rest parameter, any parameter default value initializers, or any destructured parameters.
+            // ii. Let ao be CreateMappedArgumentsObject(func, formals, argumentsList, env).
+            create_mapped_arguments_object(
+                agent,
+                function_obj,
+                &parameter_names,
+                arguments_list,
+                env.clone(),
+            )
+        };
+
+        // c. If strict is true, then
+        if strict {
+            // i. Perform ! env.CreateImmutableBinding("arguments", false).
+            // ii. NOTE: In strict mode code early errors prevent attempting to assign to this binding, so its mutability is not observable.
+            env.create_immutable_binding(arguments_name.clone(), false)?;
+        }
+        // d. Else,
+        else {
+            // i. Perform ! env.CreateMutableBinding("arguments", false).
+            env.create_mutable_binding(arguments_name.clone(), false)?;
+        }
+
+        // e. Perform ! env.InitializeBinding("arguments", ao).
+        env.initialize_binding(agent, arguments_name.clone(), JSValue::from(ao))?;
+
+        // f. Let parameterBindings be the list-concatenation of parameterNames and « "arguments" ».
+    }
+    // 23. Else,
+    // a. Let parameterBindings be parameterNames.
+
+    // 24. Let iteratorRecord be CreateListIteratorRecord(argumentsList).
+    // NOTE: The iterator over the arguments list is not observable, so the arguments are read from the list directly.
+    let mut arguments = arguments_list.iter().cloned();
+
+    // 25. If hasDuplicates is true, then
+    // a. Let usedEnv be undefined.
+    // 26. Else,
+    // a. Let usedEnv be env.
+    // 27. NOTE: The following step cannot return a ReturnCompletion because the only way such a completion can arise in expression position is by use of YieldExpression, which is forbidden in parameter lists by the early error rules in 15.5.1 and 15.6.1.
+    // 28. Perform ? IteratorBindingInitialization of formals with arguments iteratorRecord and usedEnv.
+    for parameter in &formals.parameters {
+        // 8.6.3 Runtime Semantics: IteratorBindingInitialization
+        // SingleNameBinding : BindingIdentifier Initializer
+        // 3. Let v be undefined.
+        // 4. If iteratorRecord.[[Done]] is false, then
+        // a. Let next be ? IteratorStepValue(iteratorRecord).
+        // b. If next is not done, then
+        // i. Set v to next.
+        let mut value = arguments.next().unwrap_or(JSValue::Undefined);
+
+        // 5. If Initializer is present and v is undefined, then
+        if let (Some(initializer), JSValue::Undefined) = (&parameter.initializer, &value) {
+            // b. Else,
+            // i. Let defaultValue be ? Evaluation of Initializer.
+            // ii. Set v to ? GetValue(defaultValue).
+            value = VM::new(agent, initializer).evaluate_function_body()?;
+        }
+
+        initialize_parameter(
+            agent,
+            &mut env,
+            parameter.name.clone(),
+            value,
+            has_duplicates,
+        )?;
+    }
+
+    // FunctionRestParameter : BindingRestElement
+    if let Some(rest) = formals.rest.clone() {
+        // 8.6.3 Runtime Semantics: IteratorBindingInitialization
+        // BindingRestElement : ... BindingIdentifier
+        // 2. Let A be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. Repeat,
+        // a. Let next be done.
+        // b. If iteratorRecord.[[Done]] is false, then
+        // i. Set next to ? IteratorStepValue(iteratorRecord).
+        // c. If next is done, then
+        // i. If environment is undefined, return ? PutValue(lhs, A).
+        // ii. Return ? InitializeReferencedBinding(lhs, A).
+        // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), next).
+        // e. Set n to n + 1.
+        let array = create_array_from_list(agent, arguments.collect());
+
+        initialize_parameter(agent, &mut env, rest, JSValue::from(array), has_duplicates)?;
+    }
+
+    // 29. If hasParameterExpressions is false, then
+    let mut var_env = if !has_parameter_expressions {
+        // a. NOTE: Only a single Environment Record is needed for the parameters and top-level vars.
+        // b. Let instantiatedVarNames be a copy of the List parameterBindings.
+        // d. Let varEnv be env.
+        env
+    }
+    // 30. Else,
+    else {
+        // a. NOTE: A separate Environment Record is needed to ensure that closures created by expressions in the formal parameter list do not have visibility of declarations in the function body.
+        // b. Let varEnv be NewDeclarativeEnvironment(env).
+        let var_env = new_declarative_environment(agent, Some(env));
+
+        // c. Set the VariableEnvironment of calleeContext to varEnv.
+        agent.running_execution_context_mut().variable_environment = Some(var_env.clone());
+
+        // d. Let instantiatedVarNames be a new empty List.
+        var_env
+    };
+
+    // c. For each element n of varNames, do
+    // e. For each element n of varNames, do
+    for name in &function_names {
+        // i. If instantiatedVarNames does not contain n, then
+        if !var_env.has_binding(agent, name.clone())? {
+            // 1. Append n to instantiatedVarNames.
+            // 2. Perform ! varEnv.CreateMutableBinding(n, false).
+            var_env.create_mutable_binding(name.clone(), false)?;
+
+            // 3. Perform ! varEnv.InitializeBinding(n, undefined).
+            // NOTE: The initial value of a function name is always undefined, as it is replaced by the function object below.
+            var_env.initialize_binding(agent, name.clone(), JSValue::Undefined)?;
+        }
+    }
+
+    // 31. NOTE: Annex B.3.2.1 adds additional steps at this point.
+    #[cfg(feature = "annex-b")]
+    if agent.annex_b() {
+        // B.3.2.1 Changes to FunctionDeclarationInstantiation
+        // a. If strict is false, then
+        // i. For each FunctionDeclaration f that is directly contained in the StatementList of a Block, CaseClause, or DefaultClause, do
+        // NOTE: The parser only records the names of the function declarations which Annex B applies to.
+        for name in &code.program.var_scoped_block_function_names {
+            // 2. If initializedBindings does not contain F and F is not "arguments", then
+            if *name != arguments_name && !var_env.has_binding(agent, name.clone())? {
+                // a. Perform ! varEnv.CreateMutableBinding(F, false).
+                var_env.create_mutable_binding(name.clone(), false)?;
+
+                // b. Perform ! varEnv.InitializeBinding(F, undefined).
+                var_env.initialize_binding(agent, name.clone(), JSValue::Undefined)?;
+            }
+        }
+    }
+
+    // 32. If strict is false, then
+    // a. Let lexEnv be NewDeclarativeEnvironment(varEnv).
+    // b. NOTE: Non-strict functions use a separate Environment Record for top-level lexical declarations so that a direct eval can determine whether any var scoped declarations introduced by the eval code conflict with pre-existing top-level lexically scoped declarations. This is not needed for strict functions because a strict direct eval always places all declarations into a new Environment Record.
+    // 33. Else,
+    // a. Let lexEnv be varEnv.
+    // NOTE: Direct eval is not supported, so the top-level lexical declarations share the Environment Record of the vars.
+    let lex_env = var_env.clone();
+
+    // 34. Set the LexicalEnvironment of calleeContext to lexEnv.
+    agent.running_execution_context_mut().lexical_environment = Some(lex_env.clone());
+
+    // 35. Let lexDeclarations be the LexicallyScopedDeclarations of code.
+    // 36. For each element d of lexDeclarations, do
+    // NOTE: Lexical declarations are instantiated by the instructions at the start of the body's bytecode.
+
+    // 37. Let privateEnv be the PrivateEnvironment of calleeContext.
+    // 38. For each Parse Node f of functionsToInitialize, do
+    for (function_index, function_name) in functions_to_initialize.iter().zip(function_names) {
+        let function = code.program.functions[*function_index].clone();
+
+        // a. Let fn be the sole element of the BoundNames of f.
+        // b. Let fo be InstantiateFunctionObject of f with arguments lexEnv and privateEnv.
+        let function_obj = instantiate_function_object(agent, function, lex_env.clone());
+
+        // c. Perform ! varEnv.SetMutableBinding(fn, fo, false).
+        var_env.set_mutable_binding(agent, function_name, JSValue::from(function_obj), false)?;
+    }
+
+    // 39. Return unused.
+    Ok(())
+}
+
+/// 8.6.3 Runtime Semantics: IteratorBindingInitialization
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-iteratorbindinginitialization
+///
+/// Binds a parameter to its value, which is done with PutValue when environment is undefined because there are duplicate parameters, and with InitializeReferencedBinding otherwise.
+fn initialize_parameter(
+    agent: &mut JSAgent,
+    env: &mut EnvironmentAddr,
+    name: Atom,
+    value: JSValue,
+    has_duplicates: bool,
+) -> CompletionRecord {
+    // 6. If environment is undefined, return ? PutValue(lhs, v).
+    if has_duplicates {
+        return env.set_mutable_binding(agent, name, value, false);
+    }
+
+    // 7. Return ? InitializeReferencedBinding(lhs, v).
+    env.initialize_binding(agent, name, value)
+}
+
+/// 10.3.3 BuiltinCallOrConstruct ( F, thisArgument, argumentsList, newTarget )
+/// https://262.ecma-international.org/16.0/#sec-builtincallorconstruct
+pub(crate) fn builtin_call_or_construct(
+    agent: &mut JSAgent,
+    function_obj: &(impl ObjectMeta + ObjectEssentialInternalMethods),
+    this_argument: &JSValue,
+    arguments_list: &[JSValue],
+    new_target: Option<&ObjectAddr>,
+) -> CompletionRecord<JSValue> {
+    // 1. Let callerContext be the running execution context.
+    // NOTE: The call throws rather than overflowing the native stack once the execution context stack is too deep.
+    agent.check_call_depth()?;
+
+    // 2. If callerContext is not already suspended, suspend callerContext.
+    // 3. Let calleeContext be a new execution context.
+    // 4. Set the Function of calleeContext to F.
+    // 5. Let calleeRealm be F.[[Realm]].
+    let callee_realm = function_obj.data().slots().realm().cloned();
+
+    let callee_context = ExecutionContext {
+        function: Some(function_obj.addr()),
+
+        // 6. Set the Realm of calleeContext to calleeRealm.
+        realm: callee_realm.unwrap_or_else(|| agent.current_realm()),
+
+        // 7. Set the ScriptOrModule of calleeContext to null.
+        script_or_module: None,
+
+        // 8. Perform any necessary implementation-defined initialization of calleeContext.
+        lexical_environment: None,
+        variable_environment: None,
+        private_environment: None,
+    };
+
+    if let Some(profiler) = agent.profiler() {
+        profiler.record_call(&function_obj.addr());
+    }
+
+    // 9. Push calleeContext onto the execution context stack; calleeContext is now the running execution context.
+    agent.push_execution_context(callee_context);
+
+    // 10. Let result be the Completion Record that is the result of evaluating F in a manner that conforms to the specification of F. If thisArgument is uninitialized, the this value is uninitialized; otherwise, thisArgument provides the this value. argumentsList provides the named parameters. newTarget provides the NewTarget value.
+    let behaviour = function_obj.data().slots().behaviour_fn().unwrap();
+
+    // NOTE: Errors about the arguments are reported with the name the function is reached by, which for a method of an intrinsic includes the path to the intrinsic.
+    let name = {
+        let data = function_obj.data();
+
+        data.slots()
+            .qualified_name()
+            .or_else(|| data.slots().initial_name())
+            .unwrap_or_else(|| JSString::from(""))
+    };
+
+    let result = behaviour.call(
+        agent,
+        Args::new(&name, this_argument, arguments_list, new_target),
+    );
+
+    // NOTE: An error raised by the engine in F is created while calleeContext is running, so it is created in the realm of F.
+    let result = result.map_err(|error| error.resolve(agent));
+
+    // 11. NOTE: If F is defined in this document, “the specification of F” is the behaviour specified for it via algorithm steps or other means.
+    // 12. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+    agent.pop_execution_context();
+
+    // 13. Return ? result.
+    result
+}
@@ -0,0 +1,249 @@
+use std::rc::Rc;
+
+use crate::{
+    abstract_ops::iterator_operations::create_iter_result_object,
+    codegen::bytecode::generator::ExecutableFunction,
+    runtime::{
+        agent::{type_error, type_error_for, JSAgent},
+        completion::{CompletionRecord, ThrowCompletion},
+        generator::{GeneratorContext, GeneratorState},
+    },
+    value::{
+        object::{ObjectAddr, ObjectMeta},
+        JSValue,
+    },
+    vm::{Completion, VMFrame, VM},
+};
+
+// 27.5.3 Generator Abstract Operations
+// https://262.ecma-international.org/16.0/#sec-generator-abstract-operations
+
+/// 27.5.3.1 GeneratorStart ( generator, generatorBody )
+/// https://262.ecma-international.org/16.0/#sec-generatorstart
+pub(crate) fn generator_start(
+    agent: &JSAgent,
+    generator: &ObjectAddr,
+    generator_body: Rc<ExecutableFunction>,
+) {
+    // 1. Assert: generator.[[GeneratorState]] is suspended-start.
+    debug_assert_eq!(
+        generator.data().slots().generator_state(),
+        Some(GeneratorState::SuspendedStart)
+    );
+
+    // 2. Let genContext be the running execution context.
+    // 3. Set the Generator component of genContext to generator.
+    // NOTE: The running execution context is removed once the generator function returns, so genContext is a copy of it.
+    let execution_context = agent.running_execution_context().clone();
+
+    // 4. Let closure be a new Abstract Closure with no parameters that captures generatorBody and performs the following steps when called:
+    // NOTE: The steps of the closure are performed by resume_generator once the evaluation of generatorBody completes.
+    // 5. Set the code evaluation state of genContext such that when evaluation is resumed for that execution context, closure will be called with no arguments.
+    // 6. Set generator.[[GeneratorContext]] to genContext.
+    generator
+        .data_mut()
+        .slots_mut()
+        .set_generator_context(GeneratorContext {
+            execution_context,
+            code: generator_body,
+            frame: VMFrame::default(),
+        });
+
+    // 7. Return unused.
+}
+
+/// 27.5.3.2 GeneratorValidate ( generator, generatorBrand )
+/// https://262.ecma-international.org/16.0/#sec-generatorvalidate
+pub(crate) fn generator_validate(
+    generator: &JSValue,
+) -> CompletionRecord<(ObjectAddr, GeneratorState)> {
+    // 1. Perform ? RequireInternalSlot(generator, [[GeneratorState]]).
+    // 2. Perform ? RequireInternalSlot(generator, [[GeneratorBrand]]).
+    // 3. If generator.[[GeneratorBrand]] is not generatorBrand, throw a TypeError exception.
+    // NOTE: Only generators with an empty [[GeneratorBrand]] are created so far, so the brand always matches.
+    let state = match generator {
+        JSValue::Object(generator) => generator
+            .data()
+            .slots()
+            .generator_state()
+            .map(|state| (generator.clone(), state)),
+        _ => None,
+    };
+
+    let Some((generator, state)) = state else {
+        return type_error_for(
+            "Generator method called on an incompatible receiver",
+            generator,
+        );
+    };
+
+    // 4. Assert: generator also has a [[GeneratorContext]] internal slot.
+    // 5. Let state be generator.[[GeneratorState]].
+    // 6. If state is executing, throw a TypeError exception.
+    if state == GeneratorState::Executing {
+        return type_error("Generator is already running");
+    }
+
+    // 7. Return state.
+    Ok((generator, state))
+}
+
+/// 27.5.3.3 GeneratorResume ( generator, value, generatorBrand )
+/// https://262.ecma-international.org/16.0/#sec-generatorresume
+pub(crate) fn generator_resume(
+    agent: &mut JSAgent,
+    generator: &JSValue,
+    value: JSValue,
+) -> CompletionRecord<JSValue> {
+    // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+    let (generator, state) = generator_validate(generator)?;
+
+    // 2. If state is completed, return CreateIteratorResultObject(undefined, true).
+    if state == GeneratorState::Completed {
+        return Ok(JSValue::from(create_iter_result_object(
+            agent,
+            JSValue::Undefined,
+            true,
+        )));
+    }
+
+    // 3. Assert: state is either suspended-start or suspended-yield.
+    // 4-11. Resume the suspended evaluation of genContext using NormalCompletion(value) as the result of the operation that suspended it.
+    resume_generator(agent, &generator, state, Completion::Normal(value))
+}
+
+/// 27.5.3.4 GeneratorResumeAbrupt ( generator, abruptCompletion, generatorBrand )
+/// https://262.ecma-international.org/16.0/#sec-generatorresumeabrupt
+pub(crate) fn generator_resume_abrupt(
+    agent: &mut JSAgent,
+    generator: &JSValue,
+    abrupt_completion: Completion,
+) -> CompletionRecord<JSValue> {
+    // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+    let (generator, mut state) = generator_validate(generator)?;
+
+    // 2. If state is suspended-start, then
+    if state == GeneratorState::SuspendedStart {
+        // a. Set generator.[[GeneratorState]] to completed.
+        // b. NOTE: Once a generator enters the completed state it never leaves it and its associated execution context is never resumed. Any execution state associated with generator can be discarded at this point.
+        let mut generator_data = generator.data_mut();
+
+        generator_data
+            .slots_mut()
+            .set_generator_state(GeneratorState::Completed);
+        generator_data.slots_mut().take_generator_context();
+
+        // c. Set state to completed.
+        state = GeneratorState::Completed;
+    }
+
+    // 3. If state is completed, then
+    if state == GeneratorState::Completed {
+        return match abrupt_completion {
+            // a. If abruptCompletion is a return completion, then
+            // i. Return CreateIteratorResultObject(abruptCompletion.[[Value]], true).
+            Completion::Return(value) => {
+                Ok(JSValue::from(create_iter_result_object(agent, value, true)))
+            }
+            // b. Return ? abruptCompletion.
+            Completion::Throw(value) => Err(ThrowCompletion::from(value)),
+            Completion::Normal(_) | Completion::Jump(_) => unreachable!(),
+        };
+    }
+
+    // 4. Assert: state is suspended-yield.
+    // 5-11. Resume the suspended evaluation of genContext using abruptCompletion as the result of the operation that suspended it.
+    resume_generator(agent, &generator, state, abrupt_completion)
+}
+
+/// The steps shared by GeneratorResume and GeneratorResumeAbrupt, which evaluate the generator's body until it yields or completes.
+fn resume_generator(
+    agent: &mut JSAgent,
+    generator: &ObjectAddr,
+    state: GeneratorState,
+    completion: Completion,
+) -> CompletionRecord<JSValue> {
+    // 4. Let genContext be generator.[[GeneratorContext]].
+    let GeneratorContext {
+        execution_context,
+        code,
+        frame,
+    } = generator
+        .data_mut()
+        .slots_mut()
+        .take_generator_context()
+        .unwrap();
+
+    // 5. Let methodContext be the running execution context.
+    // 6. Suspend methodContext.
+    // 7. Set generator.[[GeneratorState]] to executing.
+    generator
+        .data_mut()
+        .slots_mut()
+        .set_generator_state(GeneratorState::Executing);
+
+    // 8. Push genContext onto the execution context stack; genContext is now the running execution context.
+    agent.push_execution_context(execution_context);
+
+    // 9. Resume the suspended evaluation of genContext using completion as the result of the operation that suspended it. Let result be the value returned by the resumed computation.
+    let (result, frame) = {
+        let mut vm = VM::resume(agent, &code.program, frame);
+
+        let result = match state {
+            // NOTE: The closure set up by GeneratorStart ignores the value the generator was first resumed with.
+            GeneratorState::SuspendedStart => vm.evaluate_function_body(),
+            _ => vm.resume_generator_body(completion),
+        };
+
+        (result, vm.into_suspended_frame())
+    };
+
+    // 10. Assert: When we return here, genContext has already been removed from the execution context stack and methodContext is the currently running execution context.
+    let execution_context = agent.pop_execution_context();
+
+    let Some(frame) = frame else {
+        // 27.5.3.1 GeneratorStart ( generator, generatorBody )
+        // 4.f. Set acGenerator.[[GeneratorState]] to completed.
+        // g. NOTE: Once a generator enters the completed state it never leaves it and its associated execution context is never resumed. Any execution state associated with acGenerator can be discarded at this point.
+        generator
+            .data_mut()
+            .slots_mut()
+            .set_generator_state(GeneratorState::Completed);
+
+        // h. If result is a normal completion, then
+        // i. Let resultValue be undefined.
+        // i. Else if result is a return completion, then
+        // i. Let resultValue be result.[[Value]].
+        // j. Else,
+        // i. Assert: result is a throw completion.
+        // ii. Return ? result.
+        let result_value = result?;
+
+        // k. Return CreateIteratorResultObject(resultValue, true).
+        return Ok(JSValue::from(create_iter_result_object(
+            agent,
+            result_value,
+            true,
+        )));
+    };
+
+    // 27.5.3.6 GeneratorYield ( iteratorResult )
+    // 7. Set generator.[[GeneratorState]] to suspended-yield.
+    // 8. Remove genContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
+    let mut generator_data = generator.data_mut();
+
+    generator_data
+        .slots_mut()
+        .set_generator_state(GeneratorState::SuspendedYield);
+    generator_data
+        .slots_mut()
+        .set_generator_context(GeneratorContext {
+            execution_context,
+            code,
+            frame,
+        });
+
+    // 10. Resume callerContext passing NormalCompletion(iteratorResult).
+    // 11. Return ? result.
+    Ok(result?)
+}
@@ -10,10 +10,8 @@ pub(crate) fn set_immutable_prototype(
     let opt_current_addr = object.get_prototype_of();
 
     // 2. If SameValue(V, current) is true, return true.
-    if let (Some(value), Some(current)) = (value_addr, opt_current_addr) {
-        if current == value {
-            return true;
-        }
+    if value_addr == opt_current_addr {
+        return true;
     }
 
     // 3. Return false.
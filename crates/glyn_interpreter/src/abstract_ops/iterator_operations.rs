@@ -0,0 +1,264 @@
+use crate::{
+    abstract_ops::{
+        object_operations::{call, create_data_property_or_throw, get, get_method},
+        ordinary::ordinary_object_create,
+        type_conversion::to_boolean,
+    },
+    runtime::{
+        agent::{type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_ITERATOR},
+        completion::CompletionRecord,
+        iteration::IteratorRecord,
+    },
+    value::{
+        object::{property::JSObjectPropKey, ObjectAddr},
+        JSValue,
+    },
+};
+
+// 7.4 Operations on Iterator Objects
+// https://262.ecma-international.org/16.0/#sec-operations-on-iterator-objects
+
+/// 7.4.2 GetIteratorFromMethod ( obj, method )
+/// https://262.ecma-international.org/16.0/#sec-getiteratorfrommethod
+pub(crate) fn get_iterator_from_method(
+    agent: &mut JSAgent,
+    obj: &JSValue,
+    method: JSValue,
+) -> CompletionRecord<IteratorRecord> {
+    // 1. Let iterator be ? Call(method, obj).
+    let iterator = call(agent, method, obj, None)?;
+
+    // 2. If iterator is not an Object, throw a TypeError exception.
+    let JSValue::Object(iterator) = iterator else {
+        return type_error_for(
+            "Result of the Symbol.iterator method is not an object",
+            &iterator,
+        );
+    };
+
+    // 3. Let nextMethod be ? Get(iterator, "next").
+    let next_method = get(agent, &iterator, &JSObjectPropKey::String("next".into()))?;
+
+    // 4. Let iteratorRecord be the Iterator Record { [[Iterator]]: iterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 5. Return iteratorRecord.
+    Ok(IteratorRecord {
+        iterator,
+        next_method,
+        done: false,
+    })
+}
+
+/// 7.4.3 GetIterator ( obj, kind )
+/// https://262.ecma-international.org/16.0/#sec-getiterator
+pub(crate) fn get_iterator(agent: &mut JSAgent, obj: &JSValue) -> CompletionRecord<IteratorRecord> {
+    // 1. If kind is async, then
+    // TODO: Implement async iteration.
+    // 2. Else,
+    // a. Let method be ? GetMethod(obj, %Symbol.iterator%).
+    let method = get_method(
+        agent,
+        obj,
+        &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+    )?;
+
+    // 3. If method is undefined, throw a TypeError exception.
+    let Some(method) = method else {
+        return type_error_for("Value is not iterable", obj);
+    };
+
+    // 4. Return ? GetIteratorFromMethod(obj, method).
+    get_iterator_from_method(agent, obj, method)
+}
+
+/// 7.4.6 IteratorNext ( iteratorRecord [ , value ] )
+/// https://262.ecma-international.org/16.0/#sec-iteratornext
+pub(crate) fn iterator_next(
+    agent: &mut JSAgent,
+    iterator_record: &mut IteratorRecord,
+    value: Option<JSValue>,
+) -> CompletionRecord<ObjectAddr> {
+    // 1. If value is not present, then
+    // a. Let result be Completion(Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]])).
+    // 2. Else,
+    // a. Let result be Completion(Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]], « value »)).
+    let result = call(
+        agent,
+        iterator_record.next_method.clone(),
+        &JSValue::from(iterator_record.iterator.clone()),
+        value.map(|value| vec![value]),
+    );
+
+    // 3. If result is a throw completion, then
+    // a. Set iteratorRecord.[[Done]] to true.
+    // b. Return ? result.
+    // 4. Set result to ! result.
+    let result = result.inspect_err(|_| iterator_record.done = true)?;
+
+    // 5. If result is not an Object, then
+    let JSValue::Object(result) = result else {
+        // a. Set iteratorRecord.[[Done]] to true.
+        iterator_record.done = true;
+
+        // b. Throw a TypeError exception.
+        return type_error_for("Iterator result is not an object", &result);
+    };
+
+    // 6. Return result.
+    Ok(result)
+}
+
+/// 7.4.7 IteratorComplete ( iteratorResult )
+/// https://262.ecma-international.org/16.0/#sec-iteratorcomplete
+pub(crate) fn iterator_complete(
+    agent: &mut JSAgent,
+    iterator_result: &ObjectAddr,
+) -> CompletionRecord<bool> {
+    // 1. Return ToBoolean(? Get(iteratorResult, "done")).
+    Ok(to_boolean(get(
+        agent,
+        iterator_result,
+        &JSObjectPropKey::String("done".into()),
+    )?))
+}
+
+/// 7.4.8 IteratorValue ( iteratorResult )
+/// https://262.ecma-international.org/16.0/#sec-iteratorvalue
+pub(crate) fn iterator_value(
+    agent: &mut JSAgent,
+    iterator_result: &ObjectAddr,
+) -> CompletionRecord<JSValue> {
+    // 1. Return ? Get(iteratorResult, "value").
+    get(
+        agent,
+        iterator_result,
+        &JSObjectPropKey::String("value".into()),
+    )
+}
+
+/// 7.4.9 IteratorStep ( iteratorRecord )
+/// https://262.ecma-international.org/16.0/#sec-iteratorstep
+///
+/// Returns the next result object produced by the iterator, or `None` once it is done.
+pub(crate) fn iterator_step(
+    agent: &mut JSAgent,
+    iterator_record: &mut IteratorRecord,
+) -> CompletionRecord<Option<ObjectAddr>> {
+    // 1. Let result be ? IteratorNext(iteratorRecord).
+    let result = iterator_next(agent, iterator_record, None)?;
+
+    // 2. Let done be Completion(IteratorComplete(result)).
+    // 3. If done is a throw completion, then
+    // a. Set iteratorRecord.[[Done]] to true.
+    // b. Return ? done.
+    // 4. Set done to ! done.
+    let done = iterator_complete(agent, &result).inspect_err(|_| iterator_record.done = true)?;
+
+    // 5. If done is true, then
+    if done {
+        // a. Set iteratorRecord.[[Done]] to true.
+        iterator_record.done = true;
+
+        // b. Return done.
+        return Ok(None);
+    }
+
+    // 6. Return result.
+    Ok(Some(result))
+}
+
+/// 7.4.10 IteratorStepValue ( iteratorRecord )
+/// https://262.ecma-international.org/16.0/#sec-iteratorstepvalue
+///
+/// Returns the next value produced by the iterator, or `None` once it is done.
+pub(crate) fn iterator_step_value(
+    agent: &mut JSAgent,
+    iterator_record: &mut IteratorRecord,
+) -> CompletionRecord<Option<JSValue>> {
+    // 1. Let result be ? IteratorStep(iteratorRecord).
+    // 2. If result is done, then
+    // a. Return done.
+    let Some(result) = iterator_step(agent, iterator_record)? else {
+        return Ok(None);
+    };
+
+    // 3. Let value be Completion(IteratorValue(result)).
+    // 4. If value is a throw completion, then
+    // a. Set iteratorRecord.[[Done]] to true.
+    // 5. Return ? value.
+    iterator_value(agent, &result)
+        .inspect_err(|_| iterator_record.done = true)
+        .map(Some)
+}
+
+/// 7.4.11 IteratorClose ( iteratorRecord, completion )
+/// https://262.ecma-international.org/16.0/#sec-iteratorclose
+pub(crate) fn iterator_close<T>(
+    agent: &mut JSAgent,
+    iterator_record: &IteratorRecord,
+    completion: CompletionRecord<T>,
+) -> CompletionRecord<T> {
+    // 1. Assert: iteratorRecord.[[Iterator]] is an Object.
+    // 2. Let iterator be iteratorRecord.[[Iterator]].
+    let iterator = JSValue::from(iterator_record.iterator.clone());
+
+    // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+    // 4. If innerResult is a normal completion, then
+    // a. Let return be innerResult.[[Value]].
+    // b. If return is undefined, return ? completion.
+    // c. Set innerResult to Completion(Call(return, iterator)).
+    let inner_result = match get_method(agent, &iterator, &JSObjectPropKey::String("return".into()))
+    {
+        Ok(Some(return_method)) => call(agent, return_method, &iterator, None),
+        Ok(None) => return completion,
+        Err(error) => Err(error),
+    };
+
+    // 5. If completion is a throw completion, return ? completion.
+    let value = completion?;
+
+    // 6. If innerResult is a throw completion, return ? innerResult.
+    // 7. If innerResult.[[Value]] is not an Object, throw a TypeError exception.
+    let inner_result = inner_result?;
+
+    if !inner_result.is_object() {
+        return type_error_for(
+            "Result of the iterator return method is not an object",
+            &inner_result,
+        );
+    }
+
+    // 8. Return ? completion.
+    Ok(value)
+}
+
+/// 7.4.14 CreateIteratorResultObject ( value, done )
+/// https://262.ecma-international.org/16.0/#sec-createiterresultobject
+pub(crate) fn create_iter_result_object(
+    agent: &mut JSAgent,
+    value: JSValue,
+    done: bool,
+) -> ObjectAddr {
+    let object_prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .object_prototype
+        .clone();
+
+    // 1. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+    let obj = ordinary_object_create(agent, object_prototype, None);
+
+    // 2. Perform ! CreateDataPropertyOrThrow(obj, "value", value).
+    create_data_property_or_throw(&obj, &JSObjectPropKey::String("value".into()), value).unwrap();
+
+    // 3. Perform ! CreateDataPropertyOrThrow(obj, "done", done).
+    create_data_property_or_throw(
+        &obj,
+        &JSObjectPropKey::String("done".into()),
+        JSValue::from(done),
+    )
+    .unwrap();
+
+    // 4. Return obj.
+    obj
+}
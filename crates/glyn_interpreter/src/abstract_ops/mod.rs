@@ -1,12 +1,23 @@
+pub(crate) mod arguments_exotic_objects;
+pub(crate) mod array_exotic_objects;
+pub(crate) mod async_function_operations;
+pub(crate) mod date_objects;
 pub(crate) mod environments;
 pub(crate) mod execution_contexts;
+pub(crate) mod function_definitions;
 pub(crate) mod function_operations;
+pub(crate) mod generator_operations;
 pub(crate) mod immutable_prototype_objects;
+pub(crate) mod iterator_operations;
+pub(crate) mod module;
+pub(crate) mod module_namespace_exotic_objects;
 pub(crate) mod object_operations;
 pub(crate) mod ordinary;
+pub(crate) mod promise_operations;
 pub(crate) mod realm;
 pub(crate) mod reference_operations;
 pub(crate) mod runtime_operations;
 pub(crate) mod script;
+pub(crate) mod string_exotic_objects;
 pub(crate) mod testing_comparison;
 pub(crate) mod type_conversion;
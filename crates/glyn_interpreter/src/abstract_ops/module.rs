@@ -0,0 +1,1165 @@
+use std::ops::DerefMut;
+
+use crate::{
+    abstract_ops::{
+        environments::new_module_environment, function_definitions::instantiate_function_object,
+        module_namespace_exotic_objects::module_namespace_create, script::parse_text,
+    },
+    codegen::{
+        bytecode::generator::ExecutableProgram,
+        parser::{ExportEntry, ExportImportName, ImportName, ModuleEntries, ProgramSource},
+    },
+    eval_script::EvalError,
+    gc::Gc,
+    runtime::{
+        agent::{syntax_error, type_error, JSAgent},
+        completion::{CompletionRecord, ThrowCompletion},
+        environment::{module_environment::ModuleEnvironment, EnvironmentAddr, EnvironmentMethods},
+        execution_context::{ExecutionContext, ScriptOrModule},
+        module::{
+            BindingName, ExportResolution, ModuleAddr, ModuleRecord, ModuleStatus, ResolvedBinding,
+        },
+        realm::RealmAddr,
+    },
+    value::{atom::Atom, object::ObjectAddr, string::JSString, JSValue},
+    vm::VM,
+};
+
+/// 16.2.1.6.1 ParseModule ( sourceText, realm, hostDefined )
+/// https://262.ecma-international.org/16.0/#sec-parsemodule
+///
+/// The specifier the module is loaded with is held by the module in place of hostDefined.
+pub(crate) fn parse_module(
+    agent: &mut JSAgent,
+    source_text: &str,
+    realm: RealmAddr,
+    specifier: JSString,
+) -> Result<ModuleAddr, EvalError> {
+    // 1. Let body be ParseText(sourceText, Module).
+    // 2. If body is a List of errors, return body.
+    let (body, entries) = parse_text(
+        source_text,
+        ProgramSource::Module,
+        true,
+        agent.optimization_level,
+        &mut agent.warnings,
+    )?;
+
+    // 3. Let requestedModules be the ModuleRequests of body.
+    // 4. Let importEntries be the ImportEntries of body.
+    let ModuleEntries {
+        requested_modules,
+        import_entries,
+        export_entries,
+        lexical_declarations,
+    } = entries;
+
+    // 5. Let importedBoundNames be ImportedLocalNames(importEntries).
+    // 6. Let indirectExportEntries be a new empty List.
+    let mut indirect_export_entries = vec![];
+
+    // 7. Let localExportEntries be a new empty List.
+    let mut local_export_entries = vec![];
+
+    // 8. Let starExportEntries be a new empty List.
+    let mut star_export_entries = vec![];
+
+    // 9. Let exportEntries be the ExportEntries of body.
+    // 10. For each ExportEntry Record ee of exportEntries, do
+    for export_entry in export_entries {
+        // a. If ee.[[ModuleRequest]] is null, then
+        if export_entry.module_request.is_none() {
+            let import_entry = import_entries.iter().find(|import_entry| {
+                export_entry.local_name.as_ref() == Some(&import_entry.local_name)
+            });
+
+            match import_entry.map(|import_entry| &import_entry.import_name) {
+                // i. If importedBoundNames does not contain ee.[[LocalName]], then
+                // 1. Append ee to localExportEntries.
+                None => local_export_entries.push(export_entry),
+                // ii. Else,
+                // 1. Let ie be the element of importEntries whose [[LocalName]] is ee.[[LocalName]].
+                // 2. If ie.[[ImportName]] is namespace-object, then
+                // a. NOTE: This is a re-export of an imported module namespace object.
+                // b. Append ee to localExportEntries.
+                Some(ImportName::NamespaceObject) => local_export_entries.push(export_entry),
+                // 3. Else,
+                // a. NOTE: This is a re-export of a single name.
+                // b. Append the ExportEntry Record { [[ModuleRequest]]: ie.[[ModuleRequest]], [[ImportName]]: ie.[[ImportName]], [[LocalName]]: null, [[ExportName]]: ee.[[ExportName]] } to indirectExportEntries.
+                Some(ImportName::Name(import_name)) => indirect_export_entries.push(ExportEntry {
+                    module_request: import_entry
+                        .map(|import_entry| import_entry.module_request.clone()),
+                    import_name: Some(ExportImportName::Name(import_name.clone())),
+                    local_name: None,
+                    export_name: export_entry.export_name,
+                }),
+            }
+        }
+        // b. Else if ee.[[ImportName]] is all-but-default, then
+        else if export_entry.import_name == Some(ExportImportName::AllButDefault) {
+            // i. Assert: ee.[[ExportName]] is null.
+            debug_assert!(export_entry.export_name.is_none());
+
+            // ii. Append ee to starExportEntries.
+            star_export_entries.push(export_entry);
+        }
+        // c. Else,
+        else {
+            // i. Append ee to indirectExportEntries.
+            indirect_export_entries.push(export_entry);
+        }
+    }
+
+    // 11. Let async be body Contains await.
+    // NOTE: Top-level await is not supported, so every module is evaluated synchronously.
+
+    // 12. Return Source Text Module Record { [[Realm]]: realm, [[Environment]]: empty, [[Namespace]]: empty, [[CycleRoot]]: empty, [[HasTLA]]: async, [[AsyncEvaluation]]: false, [[TopLevelCapability]]: empty, [[AsyncParentModules]]: « », [[PendingAsyncDependencies]]: empty, [[Status]]: new, [[EvaluationError]]: empty, [[HostDefined]]: hostDefined, [[ECMAScriptCode]]: body, [[Context]]: empty, [[ImportMeta]]: empty, [[RequestedModules]]: requestedModules, [[LoadedModules]]: « », [[ImportEntries]]: importEntries, [[LocalExportEntries]]: localExportEntries, [[IndirectExportEntries]]: indirectExportEntries, [[StarExportEntries]]: starExportEntries, [[DFSIndex]]: empty, [[DFSAncestorIndex]]: empty }.
+    Ok(Gc::new(
+        &mut agent.heap,
+        ModuleRecord {
+            realm,
+            environment: None,
+            namespace: None,
+            specifier,
+            status: ModuleStatus::New,
+            evaluation_error: None,
+            dfs_index: 0,
+            dfs_ancestor_index: 0,
+            requested_modules,
+            loaded_modules: vec![],
+            ecmascript_code: body.into(),
+            import_entries,
+            local_export_entries,
+            indirect_export_entries,
+            star_export_entries,
+            lexical_declarations,
+        },
+    ))
+}
+
+/// 16.2.1.5.1 LoadRequestedModules ( [ hostDefined ] )
+/// https://262.ecma-international.org/16.0/#sec-LoadRequestedModules
+///
+/// Modules are loaded synchronously, so the whole graph has been loaded when this returns rather than a promise being returned which settles once it has been.
+pub(crate) fn load_requested_modules(agent: &mut JSAgent, module: &ModuleAddr) -> CompletionRecord {
+    // 1. If hostDefined is not present, let hostDefined be empty.
+    // 2. Let pc be ! NewPromiseCapability(%Promise%).
+    // 3. Let state be the GraphLoadingState Record { [[IsLoading]]: true, [[PendingModulesCount]]: 1, [[Visited]]: « », [[PromiseCapability]]: pc, [[HostDefined]]: hostDefined }.
+    let mut visited = vec![];
+
+    // 4. Perform InnerModuleLoading(state, module).
+    inner_module_loading(agent, module, &mut visited)?;
+
+    // 16.2.1.5.1.2 ContinueModuleLoading ( state, moduleCompletion )
+    // 2. If moduleCompletion is a normal completion, then
+    // a. Set state.[[PendingModulesCount]] to state.[[PendingModulesCount]] - 1.
+    // b. If state.[[PendingModulesCount]] = 0, then
+    // i. Set state.[[IsLoading]] to false.
+    // ii. For each Cyclic Module Record loaded of state.[[Visited]], do
+    for loaded in visited {
+        let mut loaded = loaded.borrow_mut();
+
+        // 1. If loaded.[[Status]] is new, set loaded.[[Status]] to unlinked.
+        if loaded.status == ModuleStatus::New {
+            loaded.status = ModuleStatus::Unlinked;
+        }
+    }
+
+    // 5. Return pc.[[Promise]].
+    Ok(())
+}
+
+/// 16.2.1.5.1.1 InnerModuleLoading ( state, module )
+/// https://262.ecma-international.org/16.0/#sec-InnerModuleLoading
+fn inner_module_loading(
+    agent: &mut JSAgent,
+    module: &ModuleAddr,
+    visited: &mut Vec<ModuleAddr>,
+) -> CompletionRecord {
+    // 1. Assert: state.[[IsLoading]] is true.
+    // 2. If module is a Cyclic Module Record, module.[[Status]] is new, and state.[[Visited]] does not contain module, then
+    if module.borrow().status != ModuleStatus::New || visited.contains(module) {
+        return Ok(());
+    }
+
+    // a. Append module to state.[[Visited]].
+    visited.push(module.clone());
+
+    // b. Let requestedModulesCount be the number of elements in module.[[RequestedModules]].
+    // c. Set state.[[PendingModulesCount]] to state.[[PendingModulesCount]] + requestedModulesCount.
+    // d. For each ModuleRequest Record request of module.[[RequestedModules]], do
+    let requested_modules = module.borrow().requested_modules.clone();
+
+    for request in requested_modules {
+        // i. If AllImportAttributesSupported(request.[[Attributes]]) is false, then
+        // NOTE: Import attributes are not supported, so a request never has any.
+
+        // ii. Else if module.[[LoadedModules]] contains a LoadedModuleRequest Record record such that ModuleRequestsEqual(record, request) is true, then
+        let loaded_module = loaded_module(module, &request);
+
+        let required_module = match loaded_module {
+            // 1. Perform InnerModuleLoading(state, record.[[Module]]).
+            Some(loaded_module) => loaded_module,
+            // iii. Else,
+            None => {
+                // 1. Perform HostLoadImportedModule(module, request, state.[[HostDefined]], state).
+                let imported_module = host_load_imported_module(agent, module, &request)?;
+
+                // 2. NOTE: HostLoadImportedModule will call FinishLoadingImportedModule, which re-enters the graph loading process through ContinueModuleLoading.
+                finish_loading_imported_module(module, request, &imported_module);
+
+                imported_module
+            }
+        };
+
+        inner_module_loading(agent, &required_module, visited)?;
+
+        // iv. If state.[[IsLoading]] is false, return unused.
+    }
+
+    // 3. Assert: state.[[PendingModulesCount]] ≥ 1.
+    // 4. Set state.[[PendingModulesCount]] to state.[[PendingModulesCount]] - 1.
+    // 5. If state.[[PendingModulesCount]] = 0, then
+    // NOTE: Every requested module has been loaded once the module which the graph was loaded from returns, see [`load_requested_modules`].
+
+    // 6. Return unused.
+    Ok(())
+}
+
+/// The module which a module request of the referrer has been loaded as, if it has been.
+fn loaded_module(referrer: &ModuleAddr, request: &JSString) -> Option<ModuleAddr> {
+    referrer
+        .borrow()
+        .loaded_modules
+        .iter()
+        .find(|(specifier, _)| specifier == request)
+        .map(|(_, module)| module.clone())
+}
+
+/// 16.2.1.7 GetImportedModule ( referrer, request )
+/// https://262.ecma-international.org/16.0/#sec-GetImportedModule
+pub(crate) fn get_imported_module(referrer: &ModuleAddr, request: &JSString) -> ModuleAddr {
+    // 1. Let records be a List consisting of each LoadedModuleRequest Record r of referrer.[[LoadedModules]] such that ModuleRequestsEqual(r, request) is true.
+    // 2. Assert: records has exactly one element, since LoadRequestedModules has completed successfully on referrer prior to invoking this abstract operation.
+    // 3. Let record be the sole element of records.
+    // 4. Return record.[[Module]].
+    loaded_module(referrer, request).unwrap()
+}
+
+/// 16.2.1.8 HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )
+/// https://262.ecma-international.org/16.0/#sec-HostLoadImportedModule
+///
+/// Modules are identified by their specifiers, so a specifier always loads the same module and the source text of each module is only requested from the module loader once.
+pub(crate) fn host_load_imported_module(
+    agent: &mut JSAgent,
+    referrer: &ModuleAddr,
+    specifier: &JSString,
+) -> CompletionRecord<ModuleAddr> {
+    let (referrer_specifier, realm) = {
+        let referrer = referrer.borrow();
+
+        (referrer.specifier.clone(), referrer.realm.clone())
+    };
+
+    let specifier = &JSString::from(agent.with_host_hooks(|hooks| {
+        hooks.resolve_module_specifier(&specifier.to_string(), &referrer_specifier.to_string())
+    }));
+
+    // If this operation is called multiple times with two (referrer, moduleRequest) pairs such that they are the same Module Record, the same specifier and the same attributes, and it performs FinishLoadingImportedModule(referrer, moduleRequest, payload, result) where result is a normal completion, then it must perform FinishLoadingImportedModule(referrer, moduleRequest, payload, result) with the same result each time.
+    if let Some(module) = agent.modules.get(specifier) {
+        return Ok(module.clone());
+    }
+
+    let source_text = match agent.module_loader.as_mut() {
+        Some(module_loader) => {
+            module_loader(&specifier.to_string(), &referrer_specifier.to_string())
+        }
+        None => Err(String::from("No module loader has been set")),
+    };
+
+    let source_text = match source_text {
+        Ok(source_text) => source_text,
+        Err(message) => return type_error(&format!("Cannot load module '{specifier}': {message}")),
+    };
+
+    let module = match parse_module(agent, &source_text, realm, specifier.clone()) {
+        Ok(module) => module,
+        Err(EvalError::SyntaxError(diagnostics)) => return syntax_error(&diagnostics[0].message),
+        Err(error) => return Err(ThrowCompletion::from(JSValue::from(error.to_string()))),
+    };
+
+    agent.modules.insert(specifier.clone(), module.clone());
+
+    Ok(module)
+}
+
+/// 16.2.1.9 FinishLoadingImportedModule ( referrer, moduleRequest, payload, result )
+/// https://262.ecma-international.org/16.0/#sec-FinishLoadingImportedModule
+fn finish_loading_imported_module(referrer: &ModuleAddr, request: JSString, module: &ModuleAddr) {
+    // 1. If result is a normal completion, then
+    // a. If referrer.[[LoadedModules]] contains a LoadedModuleRequest Record record such that ModuleRequestsEqual(record, moduleRequest) is true, then
+    // i. Assert: record.[[Module]] and result.[[Value]] are the same Module Record.
+    // b. Else,
+    // i. Append the LoadedModuleRequest Record { [[Specifier]]: moduleRequest.[[Specifier]], [[Attributes]]: moduleRequest.[[Attributes]], [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
+    referrer
+        .borrow_mut()
+        .loaded_modules
+        .push((request, module.clone()));
+
+    // 2. If payload is a GraphLoadingState Record, then
+    // a. Perform ContinueModuleLoading(payload, result).
+    // NOTE: Loading continues with the next module request once this returns, see [`inner_module_loading`].
+}
+
+/// 16.2.1.5.2 Link ( )
+/// https://262.ecma-international.org/16.0/#sec-moduledeclarationlinking
+pub(crate) fn link(agent: &mut JSAgent, module: &ModuleAddr) -> CompletionRecord {
+    // 1. Assert: module.[[Status]] is one of unlinked, linked, evaluating-async, or evaluated.
+    debug_assert!(matches!(
+        module.borrow().status,
+        ModuleStatus::Unlinked | ModuleStatus::Linked | ModuleStatus::Evaluated
+    ));
+
+    // 2. Let stack be a new empty List.
+    let mut stack = vec![];
+
+    // 3. Let result be Completion(InnerModuleLinking(module, stack, 0)).
+    let result = inner_module_linking(agent, module, &mut stack, 0);
+
+    // 4. If result is an abrupt completion, then
+    if let Err(error) = result {
+        // a. For each Cyclic Module Record m of stack, do
+        for m in stack {
+            let mut m = m.borrow_mut();
+
+            // i. Assert: m.[[Status]] is linking.
+            debug_assert!(m.status == ModuleStatus::Linking);
+
+            // ii. Set m.[[Status]] to unlinked.
+            m.status = ModuleStatus::Unlinked;
+        }
+
+        // b. Assert: module.[[Status]] is unlinked.
+        // c. Return ? result.
+        return Err(error);
+    }
+
+    // 5. Assert: module.[[Status]] is one of linked, evaluating-async, or evaluated.
+    // 6. Assert: stack is empty.
+    debug_assert!(stack.is_empty());
+
+    // 7. Return unused.
+    Ok(())
+}
+
+/// 16.2.1.5.2.1 InnerModuleLinking ( module, stack, index )
+/// https://262.ecma-international.org/16.0/#sec-InnerModuleLinking
+fn inner_module_linking(
+    agent: &mut JSAgent,
+    module: &ModuleAddr,
+    stack: &mut Vec<ModuleAddr>,
+    mut index: usize,
+) -> CompletionRecord<usize> {
+    // 1. If module is not a Cyclic Module Record, then
+    // NOTE: Every module is a Source Text Module Record, which is a Cyclic Module Record.
+
+    // 2. If module.[[Status]] is one of linking, linked, evaluating-async, or evaluated, then
+    // a. Return index.
+    if module.borrow().status != ModuleStatus::Unlinked {
+        return Ok(index);
+    }
+
+    // 3. Assert: module.[[Status]] is unlinked.
+    {
+        let mut module = module.borrow_mut();
+
+        // 4. Set module.[[Status]] to linking.
+        module.status = ModuleStatus::Linking;
+
+        // 5. Let moduleIndex be index.
+        // 6. Set module.[[DFSIndex]] to index.
+        module.dfs_index = index;
+
+        // 7. Set module.[[DFSAncestorIndex]] to index.
+        module.dfs_ancestor_index = index;
+    }
+
+    // 8. Set index to index + 1.
+    index += 1;
+
+    // 9. Append module to stack.
+    stack.push(module.clone());
+
+    // 10. For each ModuleRequest Record required of module.[[RequestedModules]], do
+    let requested_modules = module.borrow().requested_modules.clone();
+
+    for required in requested_modules {
+        // a. Let requiredModule be GetImportedModule(module, required).
+        let required_module = get_imported_module(module, &required);
+
+        // b. Set index to ? InnerModuleLinking(requiredModule, stack, index).
+        index = inner_module_linking(agent, &required_module, stack, index)?;
+
+        // c. If requiredModule is a Cyclic Module Record, then
+        let (required_status, required_ancestor_index) = {
+            let required_module = required_module.borrow();
+
+            (required_module.status, required_module.dfs_ancestor_index)
+        };
+
+        // i. Assert: requiredModule.[[Status]] is one of linking, linked, evaluating-async, or evaluated.
+        // ii. Assert: requiredModule.[[Status]] is linking if and only if stack contains requiredModule.
+        debug_assert!(
+            (required_status == ModuleStatus::Linking) == stack.contains(&required_module)
+        );
+
+        // iii. If requiredModule.[[Status]] is linking, then
+        if required_status == ModuleStatus::Linking {
+            let mut module = module.borrow_mut();
+
+            // 1. Set module.[[DFSAncestorIndex]] to min(module.[[DFSAncestorIndex]], requiredModule.[[DFSAncestorIndex]]).
+            module.dfs_ancestor_index = module.dfs_ancestor_index.min(required_ancestor_index);
+        }
+    }
+
+    // 11. Perform ? module.InitializeEnvironment().
+    initialize_environment(agent, module)?;
+
+    // 12. Assert: module occurs exactly once in stack.
+    // 13. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
+    let (dfs_index, dfs_ancestor_index) = {
+        let module = module.borrow();
+
+        (module.dfs_index, module.dfs_ancestor_index)
+    };
+
+    debug_assert!(dfs_ancestor_index <= dfs_index);
+
+    // 14. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+    if dfs_ancestor_index == dfs_index {
+        // a. Let done be false.
+        // b. Repeat, while done is false,
+        loop {
+            // i. Let requiredModule be the last element of stack.
+            // ii. Remove the last element of stack.
+            let required_module = stack.pop().unwrap();
+
+            // iii. Assert: requiredModule is a Cyclic Module Record.
+            // iv. Set requiredModule.[[Status]] to linked.
+            required_module.borrow_mut().status = ModuleStatus::Linked;
+
+            // v. If requiredModule and module are the same Module Record, set done to true.
+            if required_module == *module {
+                break;
+            }
+        }
+    }
+
+    // 15. Return index.
+    Ok(index)
+}
+
+/// 16.2.1.5.3 Evaluate ( )
+/// https://262.ecma-international.org/16.0/#sec-moduleevaluation
+///
+/// Modules are evaluated synchronously, so the completion of the evaluation is returned rather than a promise which settles with it.
+pub(crate) fn evaluate(agent: &mut JSAgent, module: &ModuleAddr) -> CompletionRecord {
+    // 1. Assert: This call to Evaluate is not happening at the same time as another call to Evaluate within the surrounding agent.
+    // 2. Assert: module.[[Status]] is one of linked, evaluating-async, or evaluated.
+    debug_assert!(matches!(
+        module.borrow().status,
+        ModuleStatus::Linked | ModuleStatus::Evaluated
+    ));
+
+    // 3. If module.[[Status]] is either evaluating-async or evaluated, set module to module.[[CycleRoot]].
+    // NOTE: Every module of a strongly connected component records the same [[EvaluationError]], so the module itself can stand in for its cycle root.
+
+    // 4. If module.[[TopLevelCapability]] is not empty, then
+    // a. Return module.[[TopLevelCapability]].[[Promise]].
+
+    // 5. Let stack be a new empty List.
+    let mut stack = vec![];
+
+    // 6. Let capability be ! NewPromiseCapability(%Promise%).
+    // 7. Set module.[[TopLevelCapability]] to capability.
+    // 8. Let result be Completion(InnerModuleEvaluation(module, stack, 0)).
+    let result = inner_module_evaluation(agent, module, &mut stack, 0);
+
+    // 9. If result is an abrupt completion, then
+    if let Err(error) = result {
+        let error = error.into_value(agent);
+
+        // a. For each Cyclic Module Record m of stack, do
+        for m in stack {
+            let mut m = m.borrow_mut();
+
+            // i. Assert: m.[[Status]] is evaluating.
+            debug_assert!(m.status == ModuleStatus::Evaluating);
+
+            // ii. Set m.[[Status]] to evaluated.
+            m.status = ModuleStatus::Evaluated;
+
+            // iii. Set m.[[EvaluationError]] to result.
+            m.evaluation_error = Some(error.clone());
+        }
+
+        // b. Assert: module.[[Status]] is evaluated.
+        // c. Assert: module.[[EvaluationError]] and result are the same Completion Record.
+        // d. Perform ! Call(capability.[[Reject]], undefined, « result.[[Value]] »).
+        return Err(ThrowCompletion::from(error));
+    }
+
+    // 10. Else,
+    // a. Assert: module.[[Status]] is either evaluating-async or evaluated.
+    // b. If module.[[AsyncEvaluation]] is false, then
+    // i. Assert: module.[[Status]] is evaluated.
+    // ii. Perform ! Call(capability.[[Resolve]], undefined, « undefined »).
+    // c. Assert: stack is empty.
+    debug_assert!(stack.is_empty());
+
+    // 11. Return capability.[[Promise]].
+    Ok(())
+}
+
+/// 16.2.1.5.3.1 InnerModuleEvaluation ( module, stack, index )
+/// https://262.ecma-international.org/16.0/#sec-innermoduleevaluation
+fn inner_module_evaluation(
+    agent: &mut JSAgent,
+    module: &ModuleAddr,
+    stack: &mut Vec<ModuleAddr>,
+    mut index: usize,
+) -> CompletionRecord<usize> {
+    // 1. If module is not a Cyclic Module Record, then
+    // NOTE: Every module is a Source Text Module Record, which is a Cyclic Module Record.
+
+    {
+        let module = module.borrow();
+
+        // 2. If module.[[Status]] is either evaluating-async or evaluated, then
+        if module.status == ModuleStatus::Evaluated {
+            // a. If module.[[EvaluationError]] is empty, return index.
+            // b. Otherwise, return ? module.[[EvaluationError]].
+            return match &module.evaluation_error {
+                None => Ok(index),
+                Some(error) => Err(ThrowCompletion::from(error.clone())),
+            };
+        }
+
+        // 3. If module.[[Status]] is evaluating, return index.
+        if module.status == ModuleStatus::Evaluating {
+            return Ok(index);
+        }
+    }
+
+    {
+        let mut module = module.borrow_mut();
+
+        // 4. Assert: module.[[Status]] is linked.
+        debug_assert!(module.status == ModuleStatus::Linked);
+
+        // 5. Set module.[[Status]] to evaluating.
+        module.status = ModuleStatus::Evaluating;
+
+        // 6. Let moduleIndex be index.
+        // 7. Set module.[[DFSIndex]] to index.
+        module.dfs_index = index;
+
+        // 8. Set module.[[DFSAncestorIndex]] to index.
+        module.dfs_ancestor_index = index;
+
+        // 9. Set module.[[PendingAsyncDependencies]] to 0.
+    }
+
+    // 10. Set index to index + 1.
+    index += 1;
+
+    // 11. Append module to stack.
+    stack.push(module.clone());
+
+    // 12. For each ModuleRequest Record required of module.[[RequestedModules]], do
+    let requested_modules = module.borrow().requested_modules.clone();
+
+    for required in requested_modules {
+        // a. Let requiredModule be GetImportedModule(module, required).
+        let required_module = get_imported_module(module, &required);
+
+        // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
+        index = inner_module_evaluation(agent, &required_module, stack, index)?;
+
+        // c. If requiredModule is a Cyclic Module Record, then
+        let (required_status, required_ancestor_index) = {
+            let required_module = required_module.borrow();
+
+            (required_module.status, required_module.dfs_ancestor_index)
+        };
+
+        // i. Assert: requiredModule.[[Status]] is one of evaluating, evaluating-async, or evaluated.
+        // ii. Assert: requiredModule.[[Status]] is evaluating if and only if stack contains requiredModule.
+        debug_assert!(
+            (required_status == ModuleStatus::Evaluating) == stack.contains(&required_module)
+        );
+
+        // iii. If requiredModule.[[Status]] is evaluating, then
+        if required_status == ModuleStatus::Evaluating {
+            let mut module = module.borrow_mut();
+
+            // 1. Set module.[[DFSAncestorIndex]] to min(module.[[DFSAncestorIndex]], requiredModule.[[DFSAncestorIndex]]).
+            module.dfs_ancestor_index = module.dfs_ancestor_index.min(required_ancestor_index);
+        }
+
+        // iv. Else,
+        // 1. Set requiredModule to requiredModule.[[CycleRoot]].
+        // 2. Assert: requiredModule.[[Status]] is either evaluating-async or evaluated.
+        // 3. If requiredModule.[[EvaluationError]] is not empty, return ? requiredModule.[[EvaluationError]].
+        // NOTE: A module which has been evaluated with an error has already returned it above.
+
+        // v. If requiredModule.[[AsyncEvaluation]] is true, then
+        // NOTE: Top-level await is not supported, so no module is evaluated asynchronously.
+    }
+
+    // 13. If module.[[PendingAsyncDependencies]] > 0 or module.[[HasTLA]] is true, then
+    // 14. Else,
+    // a. Perform ? module.ExecuteModule().
+    execute_module(agent, module)?;
+
+    // 15. Assert: module occurs exactly once in stack.
+    // 16. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
+    let (dfs_index, dfs_ancestor_index) = {
+        let module = module.borrow();
+
+        (module.dfs_index, module.dfs_ancestor_index)
+    };
+
+    debug_assert!(dfs_ancestor_index <= dfs_index);
+
+    // 17. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+    if dfs_ancestor_index == dfs_index {
+        // a. Let done be false.
+        // b. Repeat, while done is false,
+        loop {
+            // i. Let requiredModule be the last element of stack.
+            // ii. Remove the last element of stack.
+            let required_module = stack.pop().unwrap();
+
+            // iii. Assert: requiredModule is a Cyclic Module Record.
+            // iv. If requiredModule.[[AsyncEvaluation]] is false, set requiredModule.[[Status]] to evaluated.
+            required_module.borrow_mut().status = ModuleStatus::Evaluated;
+
+            // v. Otherwise, set requiredModule.[[Status]] to evaluating-async.
+            // vi. If requiredModule and module are the same Module Record, set done to true.
+            // vii. Set requiredModule.[[CycleRoot]] to module.
+            if required_module == *module {
+                break;
+            }
+        }
+    }
+
+    // 18. Return index.
+    Ok(index)
+}
+
+/// 16.2.1.6.2 GetExportedNames ( [ exportStarSet ] )
+/// https://262.ecma-international.org/16.0/#sec-getexportednames
+pub(crate) fn get_exported_names(
+    module: &ModuleAddr,
+    export_star_set: &mut Vec<ModuleAddr>,
+) -> Vec<Atom> {
+    // 1. Assert: module.[[Status]] is not new.
+    // 2. If exportStarSet is not present, set exportStarSet to a new empty List.
+    // 3. If exportStarSet contains module, then
+    if export_star_set.contains(module) {
+        // a. Assert: We've reached the starting point of an export * circularity.
+        // b. Return a new empty List.
+        return vec![];
+    }
+
+    // 4. Append module to exportStarSet.
+    export_star_set.push(module.clone());
+
+    // 5. Let exportedNames be a new empty List.
+    let mut exported_names = vec![];
+
+    let (local_export_entries, indirect_export_entries, star_export_entries) = {
+        let module = module.borrow();
+
+        (
+            module.local_export_entries.clone(),
+            module.indirect_export_entries.clone(),
+            module.star_export_entries.clone(),
+        )
+    };
+
+    // 6. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+    // a. Assert: module provides the direct binding for this export.
+    // b. Assert: e.[[ExportName]] is not null.
+    // c. Append e.[[ExportName]] to exportedNames.
+    // 7. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+    // a. Assert: module imports a specific binding for this export.
+    // b. Assert: e.[[ExportName]] is not null.
+    // c. Append e.[[ExportName]] to exportedNames.
+    exported_names.extend(
+        local_export_entries
+            .into_iter()
+            .chain(indirect_export_entries)
+            .filter_map(|entry| entry.export_name),
+    );
+
+    // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
+    for entry in star_export_entries {
+        // a. Assert: e.[[ModuleRequest]] is not null.
+        // b. Let requestedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+        let requested_module = get_imported_module(module, entry.module_request.as_ref().unwrap());
+
+        // c. Let starNames be requestedModule.GetExportedNames(exportStarSet).
+        let star_names = get_exported_names(&requested_module, export_star_set);
+
+        // d. For each element n of starNames, do
+        for name in star_names {
+            // i. If n is not "default", then
+            // 1. If exportedNames does not contain n, then
+            if name != Atom::from("default") && !exported_names.contains(&name) {
+                // a. Append n to exportedNames.
+                exported_names.push(name);
+            }
+        }
+    }
+
+    // 9. Return exportedNames.
+    exported_names
+}
+
+/// 16.2.1.6.3 ResolveExport ( exportName [ , resolveSet ] )
+/// https://262.ecma-international.org/16.0/#sec-resolveexport
+pub(crate) fn resolve_export(
+    module: &ModuleAddr,
+    export_name: Atom,
+    resolve_set: &mut Vec<(ModuleAddr, Atom)>,
+) -> ExportResolution {
+    // 1. Assert: module.[[Status]] is not new.
+    // 2. If resolveSet is not present, set resolveSet to a new empty List.
+    // 3. For each Record { [[Module]], [[ExportName]] } r of resolveSet, do
+    // a. If module and r.[[Module]] are the same Module Record and exportName is r.[[ExportName]], then
+    if resolve_set.iter().any(|(resolved_module, resolved_name)| {
+        resolved_module == module && *resolved_name == export_name
+    }) {
+        // i. Assert: This is a circular import request.
+        // ii. Return null.
+        return ExportResolution::NotFound;
+    }
+
+    // 4. Append the Record { [[Module]]: module, [[ExportName]]: exportName } to resolveSet.
+    resolve_set.push((module.clone(), export_name.clone()));
+
+    let (local_export_entries, indirect_export_entries, star_export_entries) = {
+        let module = module.borrow();
+
+        (
+            module.local_export_entries.clone(),
+            module.indirect_export_entries.clone(),
+            module.star_export_entries.clone(),
+        )
+    };
+
+    // 5. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+    for entry in local_export_entries {
+        // a. If e.[[ExportName]] is exportName, then
+        if entry.export_name.as_ref() == Some(&export_name) {
+            // i. Assert: module provides the direct binding for this export.
+            // ii. Return ResolvedBinding Record { [[Module]]: module, [[BindingName]]: e.[[LocalName]] }.
+            return ExportResolution::Resolved(ResolvedBinding {
+                module: module.clone(),
+                binding_name: BindingName::Name(entry.local_name.unwrap()),
+            });
+        }
+    }
+
+    // 6. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+    for entry in indirect_export_entries {
+        // a. If e.[[ExportName]] is exportName, then
+        if entry.export_name.as_ref() != Some(&export_name) {
+            continue;
+        }
+
+        // i. Assert: e.[[ModuleRequest]] is not null.
+        // ii. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+        let imported_module = get_imported_module(module, entry.module_request.as_ref().unwrap());
+
+        return match entry.import_name.unwrap() {
+            // iii. If e.[[ImportName]] is all, then
+            // 1. Assert: module does not provide the direct binding for this export.
+            // 2. Return ResolvedBinding Record { [[Module]]: importedModule, [[BindingName]]: namespace }.
+            ExportImportName::All => ExportResolution::Resolved(ResolvedBinding {
+                module: imported_module,
+                binding_name: BindingName::Namespace,
+            }),
+            // iv. Else,
+            // 1. Assert: module imports a specific binding for this export.
+            // 2. Assert: e.[[ImportName]] is a String.
+            // 3. Return importedModule.ResolveExport(e.[[ImportName]], resolveSet).
+            ExportImportName::Name(import_name) => {
+                resolve_export(&imported_module, import_name, resolve_set)
+            }
+            ExportImportName::AllButDefault => unreachable!(),
+        };
+    }
+
+    // 7. If exportName is "default", then
+    if export_name == Atom::from("default") {
+        // a. Assert: A default export was not explicitly defined by this module.
+        // b. Return null.
+        // c. NOTE: A default export cannot be provided by an export * from "mod" declaration.
+        return ExportResolution::NotFound;
+    }
+
+    // 8. Let starResolution be null.
+    let mut star_resolution: Option<ResolvedBinding> = None;
+
+    // 9. For each ExportEntry Record e of module.[[StarExportEntries]], do
+    for entry in star_export_entries {
+        // a. Assert: e.[[ModuleRequest]] is not null.
+        // b. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+        let imported_module = get_imported_module(module, entry.module_request.as_ref().unwrap());
+
+        // c. Let resolution be importedModule.ResolveExport(exportName, resolveSet).
+        let resolution = match resolve_export(&imported_module, export_name.clone(), resolve_set) {
+            // d. If resolution is ambiguous, return ambiguous.
+            ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+            ExportResolution::NotFound => continue,
+            // e. If resolution is not null, then
+            // i. Assert: resolution is a ResolvedBinding Record.
+            ExportResolution::Resolved(resolution) => resolution,
+        };
+
+        match &star_resolution {
+            // ii. If starResolution is null, then
+            // 1. Set starResolution to resolution.
+            None => star_resolution = Some(resolution),
+            // iii. Else,
+            // 1. Assert: There is more than one * import that includes the requested name.
+            Some(star_resolution) => {
+                // 2. If resolution.[[Module]] and starResolution.[[Module]] are not the same Module Record, return ambiguous.
+                // 3. If resolution.[[BindingName]] is not starResolution.[[BindingName]], return ambiguous.
+                if resolution.module != star_resolution.module
+                    || resolution.binding_name != star_resolution.binding_name
+                {
+                    return ExportResolution::Ambiguous;
+                }
+            }
+        }
+    }
+
+    // 10. Return starResolution.
+    star_resolution.map_or(ExportResolution::NotFound, ExportResolution::Resolved)
+}
+
+/// Throws the SyntaxError for an import or indirect export whose name could not be resolved by ResolveExport.
+fn unresolvable_export_error<T>(
+    resolution: &ExportResolution,
+    module_request: &JSString,
+    name: Atom,
+) -> CompletionRecord<T> {
+    match resolution {
+        ExportResolution::Ambiguous => syntax_error(&format!(
+            "The requested module '{module_request}' contains conflicting star exports for name '{name}'"
+        )),
+        _ => syntax_error(&format!(
+            "The requested module '{module_request}' does not provide an export named '{name}'"
+        )),
+    }
+}
+
+/// 16.2.1.6.4 InitializeEnvironment ( )
+/// https://262.ecma-international.org/16.0/#sec-source-text-module-record-initialize-environment
+fn initialize_environment(agent: &mut JSAgent, module: &ModuleAddr) -> CompletionRecord {
+    // 1. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+    let indirect_export_entries = module.borrow().indirect_export_entries.clone();
+
+    for entry in indirect_export_entries {
+        // a. Assert: e.[[ExportName]] is not null.
+        let export_name = entry.export_name.unwrap();
+
+        // b. Let resolution be module.ResolveExport(e.[[ExportName]]).
+        let resolution = resolve_export(module, export_name.clone(), &mut vec![]);
+
+        // c. If resolution is either null or ambiguous, throw a SyntaxError exception.
+        // d. Assert: resolution is a ResolvedBinding Record.
+        if !matches!(resolution, ExportResolution::Resolved(_)) {
+            let import_name = match entry.import_name {
+                Some(ExportImportName::Name(import_name)) => import_name,
+                _ => export_name,
+            };
+
+            return unresolvable_export_error(
+                &resolution,
+                entry.module_request.as_ref().unwrap(),
+                import_name,
+            );
+        }
+
+        // NOTE: The namespace of a module which is exported as a namespace is created here, so that [[Get]] on the namespace object which exports it can read it without an agent.
+        if let ExportResolution::Resolved(ResolvedBinding {
+            module: target_module,
+            binding_name: BindingName::Namespace,
+        }) = resolution
+        {
+            get_module_namespace(agent, &target_module);
+        }
+    }
+
+    // 2. Assert: All named exports from module are resolvable.
+    // 3. Let realm be module.[[Realm]].
+    // 4. Assert: realm is not undefined.
+    let realm = module.borrow().realm.clone();
+
+    // 5. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
+    let global_env = realm.borrow().global_env.clone();
+    let mut env = new_module_environment(agent, global_env);
+
+    // 6. Set module.[[Environment]] to env.
+    module.borrow_mut().environment = Some(env.clone());
+
+    // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+    let import_entries = module.borrow().import_entries.clone();
+
+    for import_entry in import_entries {
+        // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
+        let imported_module = get_imported_module(module, &import_entry.module_request);
+
+        let namespace = match import_entry.import_name {
+            // b. If in.[[ImportName]] is namespace-object, then
+            // i. Let namespace be GetModuleNamespace(importedModule).
+            ImportName::NamespaceObject => get_module_namespace(agent, &imported_module),
+            // c. Else,
+            ImportName::Name(import_name) => {
+                // i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
+                let resolution =
+                    match resolve_export(&imported_module, import_name.clone(), &mut vec![]) {
+                        ExportResolution::Resolved(resolution) => resolution,
+                        resolution => {
+                            return unresolvable_export_error(
+                                &resolution,
+                                &import_entry.module_request,
+                                import_name,
+                            )
+                        }
+                    };
+
+                match resolution.binding_name {
+                    // iii. If resolution.[[BindingName]] is namespace, then
+                    // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
+                    BindingName::Namespace => get_module_namespace(agent, &resolution.module),
+                    // iv. Else,
+                    BindingName::Name(binding_name) => {
+                        let mut env_ref = env.borrow_mut();
+                        let module_env: &mut ModuleEnvironment = env_ref.deref_mut().try_into()?;
+
+                        // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]], resolution.[[BindingName]]).
+                        module_env.create_import_binding(
+                            import_entry.local_name,
+                            resolution.module,
+                            binding_name,
+                        );
+
+                        continue;
+                    }
+                }
+            }
+        };
+
+        // ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+        env.create_immutable_binding(import_entry.local_name.clone(), true)?;
+
+        // iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+        env.initialize_binding(agent, import_entry.local_name, JSValue::from(namespace))?;
+    }
+
+    // 8. Let moduleContext be a new ECMAScript code execution context.
+    let module_context = ExecutionContext {
+        // 9. Set the Function of moduleContext to null.
+        function: None,
+
+        // 10. Assert: module.[[Realm]] is not undefined.
+        // 11. Set the Realm of moduleContext to module.[[Realm]].
+        realm,
+
+        // 12. Set the ScriptOrModule of moduleContext to module.
+        script_or_module: Some(ScriptOrModule::Module(module.clone())),
+
+        // 13. Set the VariableEnvironment of moduleContext to module.[[Environment]].
+        variable_environment: Some(env.clone()),
+
+        // 14. Set the LexicalEnvironment of moduleContext to module.[[Environment]].
+        lexical_environment: Some(env.clone()),
+
+        // 15. Set the PrivateEnvironment of moduleContext to null.
+        private_environment: None,
+    };
+
+    // 16. Set module.[[Context]] to moduleContext.
+    // NOTE: ExecuteModule creates the context again rather than it being held by the module, which it refers to.
+
+    // 17. Push moduleContext onto the execution context stack; moduleContext is now the running execution context.
+    agent.push_execution_context(module_context);
+
+    // 18. Let code be module.[[ECMAScriptCode]].
+    let (code, lexical_declarations) = {
+        let module = module.borrow();
+
+        (
+            module.ecmascript_code.clone(),
+            module.lexical_declarations.clone(),
+        )
+    };
+
+    let result = instantiate_module_declarations(agent, &code, &lexical_declarations, env);
+
+    // 25. Remove moduleContext from the execution context stack.
+    agent.pop_execution_context();
+
+    // 26. Return unused.
+    result
+}
+
+/// Steps 19 to 24 of InitializeEnvironment, which create the bindings of the declarations of the module and instantiate its function declarations.
+fn instantiate_module_declarations(
+    agent: &mut JSAgent,
+    code: &ExecutableProgram,
+    lexical_declarations: &[(Atom, bool)],
+    mut env: EnvironmentAddr,
+) -> CompletionRecord {
+    // 19. Let varDeclarations be the VarScopedDeclarations of code.
+    // 20. Let declaredVarNames be a new empty List.
+    // 21. For each element d of varDeclarations, do
+    // NOTE: Var declarations are not supported yet.
+
+    // 22. Let lexDeclarations be the LexicallyScopedDeclarations of code.
+    // 23. Let privateEnv be null.
+    // 24. For each element d of lexDeclarations, do
+    // a. For each element dn of the BoundNames of d, do
+    for (name, is_constant) in lexical_declarations {
+        // i. If IsConstantDeclaration of d is true, then
+        if *is_constant {
+            // 1. Perform ! env.CreateImmutableBinding(dn, true).
+            env.create_immutable_binding(name.clone(), true)?;
+        }
+        // ii. Else,
+        else {
+            // 1. Perform ! env.CreateMutableBinding(dn, false).
+            env.create_mutable_binding(name.clone(), false)?;
+        }
+    }
+
+    for function_index in &code.function_declarations {
+        let function = code.functions[*function_index].clone();
+        let function_name = function.name.clone().unwrap();
+
+        // ii. Else,
+        // 1. Perform ! env.CreateMutableBinding(dn, false).
+        env.create_mutable_binding(function_name.clone(), false)?;
+
+        // iii. If d is either a FunctionDeclaration, a GeneratorDeclaration, an AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
+        // 1. Let fo be InstantiateFunctionObject of d with arguments env and privateEnv.
+        let function_obj = instantiate_function_object(agent, function, env.clone());
+
+        // 2. Perform ! env.InitializeBinding(dn, fo).
+        env.initialize_binding(agent, function_name, JSValue::from(function_obj))?;
+    }
+
+    Ok(())
+}
+
+/// 16.2.1.6.5 ExecuteModule ( [ capability ] )
+/// https://262.ecma-international.org/16.0/#sec-source-text-module-record-execute-module
+fn execute_module(agent: &mut JSAgent, module: &ModuleAddr) -> CompletionRecord {
+    let (realm, environment, code) = {
+        let module = module.borrow();
+
+        (
+            module.realm.clone(),
+            module.environment.clone(),
+            module.ecmascript_code.clone(),
+        )
+    };
+
+    // 1. Let moduleContext be a new ECMAScript code execution context.
+    let module_context = ExecutionContext {
+        // 2. Set the Function of moduleContext to null.
+        function: None,
+
+        // 3. Set the Realm of moduleContext to module.[[Realm]].
+        realm,
+
+        // 4. Set the ScriptOrModule of moduleContext to module.
+        script_or_module: Some(ScriptOrModule::Module(module.clone())),
+
+        // 5. Assert: module has been linked and declarations in its module environment have been instantiated.
+        // 6. Set the VariableEnvironment of moduleContext to module.[[Environment]].
+        variable_environment: environment.clone(),
+
+        // 7. Set the LexicalEnvironment of moduleContext to module.[[Environment]].
+        lexical_environment: environment,
+
+        private_environment: None,
+    };
+
+    // 8. Suspend the running execution context.
+    // 9. If module.[[HasTLA]] is false, then
+    // a. Assert: capability is not present.
+    // b. Push moduleContext onto the execution context stack; moduleContext is now the running execution context.
+    agent.push_execution_context(module_context);
+
+    // c. Let result be Completion(Evaluation of module.[[ECMAScriptCode]]).
+    let result = VM::new(agent, &code)
+        .evaluate_script()
+        .map_err(ThrowCompletion::from);
+
+    // d. Suspend moduleContext and remove it from the execution context stack.
+    agent.pop_execution_context();
+
+    // e. Resume the context that is now on the top of the execution context stack as the running execution context.
+    // f. If result is an abrupt completion, then
+    // i. Return ? result.
+    result?;
+
+    // 10. Else,
+    // NOTE: Top-level await is not supported.
+
+    // 11. Return unused.
+    Ok(())
+}
+
+/// 16.2.1.10 GetModuleNamespace ( module )
+/// https://262.ecma-international.org/16.0/#sec-getmodulenamespace
+pub(crate) fn get_module_namespace(agent: &mut JSAgent, module: &ModuleAddr) -> ObjectAddr {
+    // 1. Assert: If module is a Cyclic Module Record, then module.[[Status]] is not new or unlinked.
+    // 2. Let namespace be module.[[Namespace]].
+    let namespace = module.borrow().namespace.clone();
+
+    // 3. If namespace is empty, then
+    if let Some(namespace) = namespace {
+        // 4. Return namespace.
+        return namespace;
+    }
+
+    // a. Let exportedNames be module.GetExportedNames().
+    let exported_names = get_exported_names(module, &mut vec![]);
+
+    // b. Let unambiguousNames be a new empty List.
+    // c. For each element name of exportedNames, do
+    // i. Let resolution be module.ResolveExport(name).
+    // ii. If resolution is a ResolvedBinding Record, append name to unambiguousNames.
+    let unambiguous_names = exported_names
+        .into_iter()
+        .filter(|name| {
+            matches!(
+                resolve_export(module, name.clone(), &mut vec![]),
+                ExportResolution::Resolved(_)
+            )
+        })
+        .collect();
+
+    // d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames).
+    // 4. Return namespace.
+    module_namespace_create(agent, module, unambiguous_names)
+}
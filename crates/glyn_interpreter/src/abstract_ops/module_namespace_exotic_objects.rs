@@ -0,0 +1,166 @@
+use crate::{
+    abstract_ops::{
+        module::resolve_export,
+        object_operations::{define_property_or_throw, make_basic_object},
+    },
+    runtime::{
+        agent::{reference_error, JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        completion::CompletionRecord,
+        module::{BindingName, ExportResolution, ModuleAddr},
+    },
+    value::{
+        atom::Atom,
+        object::{
+            internal_slots::InternalSlotName,
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectKind, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+// 10.4.6 Module Namespace Exotic Objects
+// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects
+
+/// [[Module]] and [[Exports]] of a module namespace object.
+#[derive(Debug)]
+pub(crate) struct ModuleNamespace {
+    /// [[Module]]
+    module: ModuleAddr,
+
+    /// [[Exports]]
+    exports: Vec<Atom>,
+}
+
+/// The [[Exports]] of a module namespace object.
+pub(crate) fn namespace_exports(namespace: &impl ObjectMeta) -> Vec<Atom> {
+    namespace
+        .data()
+        .slots()
+        .module_namespace()
+        .map_or_else(Vec::new, |namespace| namespace.exports.clone())
+}
+
+/// The name of the export the key refers to, if [[Exports]] of the module namespace object contains it.
+pub(crate) fn exported_name(namespace: &impl ObjectMeta, key: &JSObjectPropKey) -> Option<Atom> {
+    let JSObjectPropKey::String(name) = key else {
+        return None;
+    };
+
+    namespace
+        .data()
+        .slots()
+        .module_namespace()
+        .is_some_and(|namespace| namespace.exports.contains(name))
+        .then_some(name.clone())
+}
+
+/// 10.4.6.8 [[Get]] ( P, Receiver )
+/// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-get-p-receiver
+///
+/// The value of an export which [[Exports]] of the module namespace object contains.
+pub(crate) fn namespace_binding_value(
+    namespace: &impl ObjectMeta,
+    name: Atom,
+) -> CompletionRecord<JSValue> {
+    // 4. Let m be O.[[Module]].
+    let module = namespace
+        .data()
+        .slots()
+        .module_namespace()
+        .map(|namespace| namespace.module.clone())
+        .unwrap();
+
+    // 5. Let binding be m.ResolveExport(P).
+    // 6. Assert: binding is a ResolvedBinding Record.
+    let ExportResolution::Resolved(binding) = resolve_export(&module, name.clone(), &mut vec![])
+    else {
+        unreachable!()
+    };
+
+    // 7. Let targetModule be binding.[[Module]].
+    // 8. Assert: targetModule is not undefined.
+    let target_module = binding.module;
+
+    let binding_name = match binding.binding_name {
+        // 9. If binding.[[BindingName]] is namespace, then
+        // a. Return GetModuleNamespace(targetModule).
+        // NOTE: The namespace was created when the module which exports it was linked.
+        BindingName::Namespace => {
+            let namespace = target_module.borrow().namespace.clone().unwrap();
+
+            return Ok(JSValue::from(namespace));
+        }
+        BindingName::Name(binding_name) => binding_name,
+    };
+
+    // 10. Let targetEnv be targetModule.[[Environment]].
+    let target_env = target_module.borrow().environment.clone();
+
+    // 11. If targetEnv is empty, throw a ReferenceError exception.
+    let Some(target_env) = target_env else {
+        return reference_error(&format!(
+            "Cannot access '{name}' before its module is linked"
+        ));
+    };
+
+    // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+    target_env.get_declarative_binding_value(binding_name)
+}
+
+/// 10.4.6.12 ModuleNamespaceCreate ( module, exports )
+/// https://262.ecma-international.org/16.0/#sec-modulenamespacecreate
+pub(crate) fn module_namespace_create(
+    agent: &mut JSAgent,
+    module: &ModuleAddr,
+    exports: Vec<Atom>,
+) -> ObjectAddr {
+    // 1. Assert: module.[[Namespace]] is empty.
+    debug_assert!(module.borrow().namespace.is_none());
+
+    // 2. Let internalSlotsList be the internal slots listed in Table 33.
+    // 3. Let M be MakeBasicObject(internalSlotsList).
+    let namespace = make_basic_object(agent, vec![InternalSlotName::ModuleNamespace]);
+
+    // 6. Let sortedExports be a List whose elements are the elements of exports, sorted according to lexicographic code unit order.
+    let mut sorted_exports = exports;
+
+    sorted_exports.sort_by_cached_key(|name| name.to_js_string());
+
+    // 5. Set M.[[Module]] to module.
+    // 7. Set M.[[Exports]] to sortedExports.
+    namespace
+        .data_mut()
+        .slots_mut()
+        .set_module_namespace(ModuleNamespace {
+            module: module.clone(),
+            exports: sorted_exports,
+        });
+
+    // 8. Create own properties of M corresponding to the definitions in 28.3.
+    // 28.3.1 %Symbol.toStringTag%
+    // The initial value of the %Symbol.toStringTag% property is the String value "Module".
+    // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+    define_property_or_throw(
+        &namespace,
+        &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(String::from("Module"))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+    .unwrap();
+
+    // 4. Set M's essential internal methods to the definitions specified in 10.4.6.
+    // NOTE: The internal methods are set once the own properties have been created, as a module namespace object is not extensible.
+    namespace.data_mut().set_kind(ObjectKind::ModuleNamespace);
+
+    // 9. Set module.[[Namespace]] to M.
+    module.borrow_mut().namespace = Some(namespace.clone());
+
+    // 10. Return M.
+    namespace
+}
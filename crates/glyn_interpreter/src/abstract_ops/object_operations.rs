@@ -1,12 +1,18 @@
 use crate::{
-    abstract_ops::{testing_comparison::is_callable, type_conversion::to_object},
-    gc::Gc,
-    runtime::{agent::type_error, completion::CompletionRecord},
+    abstract_ops::{
+        array_exotic_objects::array_create,
+        testing_comparison::is_callable,
+        type_conversion::{to_length, to_object},
+    },
+    runtime::{
+        agent::{type_error, type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_SPECIES},
+        completion::CompletionRecord,
+        realm::RealmAddr,
+    },
     value::{
         object::{
             internal_slots::{InternalSlotName, InternalSlots},
             property::{JSObjectPropDescriptor, JSObjectPropKey},
-            subtypes::FunctionObject,
             ObjectAddr, ObjectData, ObjectEssentialInternalMethods, ObjectExtraInternalMethods,
             ObjectKind, ObjectMeta,
         },
@@ -19,12 +25,16 @@ use crate::{
 
 /// 7.3.1 MakeBasicObject ( internalSlotsList )
 /// https://262.ecma-international.org/16.0/#sec-makebasicobject
-pub(crate) fn make_basic_object(internal_slots_list: Vec<InternalSlotName>) -> ObjectAddr {
+pub(crate) fn make_basic_object(
+    agent: &mut JSAgent,
+    internal_slots_list: Vec<InternalSlotName>,
+) -> ObjectAddr {
     // 1. Set internalSlotsList to the list-concatenation of internalSlotsList and « [[PrivateElements]] ».
     // 2. Let obj be a newly created object with an internal slot for each name in internalSlotsList.
     // 3. NOTE: As described in Object Internal Methods and Internal Slots, the initial value of each such internal slot is undefined unless specified otherwise.
     // 4. Set obj's essential internal methods to the default ordinary object definitions specified in 10.1.
     let mut obj = ObjectData::new(
+        agent,
         ObjectKind::Ordinary,
         InternalSlots::from(internal_slots_list),
     );
@@ -38,44 +48,50 @@ pub(crate) fn make_basic_object(internal_slots_list: Vec<InternalSlotName>) -> O
     obj.extensible = true;
 
     // 9. Return obj.
-    Gc::new(obj)
+    // NOTE: Most objects are short-lived temporaries, like iterator results, so they are allocated in the nursery.
+    ObjectAddr::new(agent.object_nursery.allocate(&mut agent.heap, obj))
 }
 
 /// 7.3.2 Get ( O, P )
 /// https://262.ecma-international.org/16.0/#sec-get-o-p
 pub(crate) fn get(
-    object: &impl ObjectEssentialInternalMethods,
+    agent: &mut JSAgent,
+    object: &(impl ObjectMeta + ObjectEssentialInternalMethods),
     key: &JSObjectPropKey,
-    receiver: &JSValue,
 ) -> CompletionRecord<JSValue> {
     // 1. Return ? O.[[Get]](P, O).
-    object.get(key, receiver)
+    object.get(agent, key, &JSValue::from(object.addr()))
 }
 
 /// 7.3.3 GetV ( V, P )
 /// https://262.ecma-international.org/16.0/#sec-getv
-pub(crate) fn getv(value: &JSValue, key: &JSObjectPropKey) -> CompletionRecord<JSValue> {
+pub(crate) fn getv(
+    agent: &mut JSAgent,
+    value: &JSValue,
+    key: &JSObjectPropKey,
+) -> CompletionRecord<JSValue> {
     // 1. Let O be ? ToObject(V).
-    let object = to_object(value);
+    let object = to_object(agent, value)?;
 
     // 2. Return ? O.[[Get]](P, V).
-    object.get(key, value)
+    object.get(agent, key, value)
 }
 
 /// 7.3.4 Set ( O, P, V, Throw )
 /// https://262.ecma-international.org/16.0/#sec-set-o-p-v-throw
 pub(crate) fn set(
+    agent: &mut JSAgent,
     object: &(impl ObjectMeta + ObjectEssentialInternalMethods),
     key: &JSObjectPropKey,
     value: JSValue,
     throw: bool,
 ) -> CompletionRecord<Option<bool>> {
     // 1. Let success be ? O.[[Set]](P, V, O).
-    let success = object.set(key, value, JSValue::from(object.addr()))?;
+    let success = object.set(agent, key, value, JSValue::from(object.addr()))?;
 
     // 2. If success is false and Throw is true, throw a TypeError exception.
     if !success && throw {
-        type_error("Failed to set property on object");
+        return type_error("Failed to set property on object");
     }
 
     // 3. Return unused.
@@ -114,7 +130,7 @@ pub(crate) fn create_data_property_or_throw(
 
     // 2. If success is false, throw a TypeError exception.
     if !success {
-        type_error("Failed to create data property on object");
+        return type_error("Failed to create data property on object");
     }
 
     // 3. Return unused.
@@ -130,12 +146,7 @@ pub(crate) fn create_non_enumerable_data_property_or_throw(
 ) {
     // 1. Assert: O is an ordinary, extensible object with no non-configurable properties.
     debug_assert!(
-        object.data().extensible
-            && object
-                .data()
-                .values()
-                .iter()
-                .all(|v| v.configurable == Some(true))
+        object.data().extensible && object.data().values().all(|v| v.configurable == Some(true))
     );
 
     // 2. Let newDesc be the PropertyDescriptor { [[Value]]: V, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }.
@@ -165,7 +176,7 @@ pub(crate) fn define_property_or_throw(
 
     // 2. If success is false, throw a TypeError exception.
     if !success {
-        type_error("Failed to define property on object");
+        return type_error("Failed to define property on object");
     }
 
     // 3. Return unused.
@@ -183,7 +194,7 @@ pub(crate) fn delete_property_or_throw(
 
     // 2. If success is false, throw a TypeError exception.
     if !success {
-        type_error("Failed to delete property from object");
+        return type_error("Failed to delete property from object");
     }
 
     // 3. Return unused.
@@ -193,11 +204,12 @@ pub(crate) fn delete_property_or_throw(
 /// 7.3.10 GetMethod ( V, P )
 /// https://262.ecma-international.org/16.0/#sec-getmethod
 pub(crate) fn get_method(
+    agent: &mut JSAgent,
     value: &JSValue,
     key: &JSObjectPropKey,
 ) -> CompletionRecord<Option<JSValue>> {
     // 1. Let func be ? GetV(V, P).
-    let func = getv(value, key)?;
+    let func = getv(agent, value, key)?;
 
     // 2. If func is either undefined or null, return undefined.
     if func.is_undefined() || func.is_null() {
@@ -206,7 +218,7 @@ pub(crate) fn get_method(
 
     // 3. If IsCallable(func) is false, throw a TypeError exception.
     if !is_callable(&func) {
-        type_error("Method is not callable.");
+        return type_error_for("Method is not callable", &func);
     }
 
     // 4. Return func.
@@ -240,6 +252,7 @@ pub(crate) fn has_own_property(
 /// 7.3.13 Call ( F, V [ , argumentsList ] )
 /// https://262.ecma-international.org/16.0/#sec-call
 pub(crate) fn call(
+    agent: &mut JSAgent,
     function_value: JSValue,
     this_value: &JSValue,
     arguments_list: Option<Vec<JSValue>>,
@@ -249,21 +262,20 @@ pub(crate) fn call(
 
     // 2. If IsCallable(F) is false, throw a TypeError exception.
     if !is_callable(&function_value) {
-        type_error("Function cannot be called.");
+        return type_error_for("Function cannot be called", &function_value);
     }
 
     // 3. Return ? F.[[Call]](V, argumentsList).
-    let function_object = FunctionObject::from(&ObjectAddr::try_from(&function_value)?);
-
-    function_object.call(this_value, &args)
+    ObjectAddr::try_from(&function_value)?.call(agent, this_value, &args)
 }
 
 /// 7.3.14 Construct ( F [ , argumentsList [ , newTarget ] ] )
 /// https://262.ecma-international.org/16.0/#sec-construct
 pub(crate) fn construct(
-    function_obj: &FunctionObject,
+    agent: &mut JSAgent,
+    function_obj: &ObjectAddr,
     arguments_list: Option<Vec<JSValue>>,
-    new_target: Option<&FunctionObject>,
+    new_target: Option<&ObjectAddr>,
 ) -> CompletionRecord<ObjectAddr> {
     // 1. If newTarget is not present, set newTarget to F.
     let new_target = new_target.unwrap_or(function_obj);
@@ -272,7 +284,80 @@ pub(crate) fn construct(
     let arguments_list = arguments_list.unwrap_or_default();
 
     // 3. Return ? F.[[Construct]](argumentsList, newTarget).
-    function_obj.construct(&arguments_list, new_target)
+    function_obj.construct(agent, &arguments_list, new_target)
+}
+
+/// 7.3.22 OrdinaryHasInstance ( C, O )
+/// https://262.ecma-international.org/16.0/#sec-ordinaryhasinstance
+pub(crate) fn ordinary_has_instance(
+    agent: &mut JSAgent,
+    constructor: &JSValue,
+    value: &JSValue,
+) -> CompletionRecord<bool> {
+    // 1. If IsCallable(C) is false, return false.
+    if !is_callable(constructor) {
+        return Ok(false);
+    }
+
+    let constructor = ObjectAddr::try_from(constructor)?;
+
+    // 2. If C has a [[BoundTargetFunction]] internal slot, then
+    // a. Let BC be C.[[BoundTargetFunction]].
+    // b. Return ? InstanceofOperator(O, BC).
+    // NOTE: Bound function exotic objects are not supported yet.
+
+    // 3. If O is not an Object, return false.
+    let JSValue::Object(mut object) = value.clone() else {
+        return Ok(false);
+    };
+
+    // 4. Let P be ? Get(C, "prototype").
+    let prototype = get(
+        agent,
+        &constructor,
+        &JSObjectPropKey::String("prototype".into()),
+    )?;
+
+    // 5. If P is not an Object, throw a TypeError exception.
+    let JSValue::Object(prototype) = prototype else {
+        return type_error("Function has non-object prototype in instanceof check");
+    };
+
+    // 6. Repeat,
+    loop {
+        // a. Set O to ? O.[[GetPrototypeOf]]().
+        // b. If O is null, return false.
+        let Some(next) = object.get_prototype_of() else {
+            return Ok(false);
+        };
+
+        // c. If SameValue(P, O) is true, return true.
+        if next == prototype {
+            return Ok(true);
+        }
+
+        object = next;
+    }
+}
+
+/// 7.3.24 GetFunctionRealm ( obj )
+/// https://262.ecma-international.org/16.0/#sec-getfunctionrealm
+pub(crate) fn get_function_realm(
+    agent: &JSAgent,
+    object: &ObjectAddr,
+) -> CompletionRecord<RealmAddr> {
+    // 1. If obj has a [[Realm]] internal slot, then
+    if let Some(realm) = object.data().slots().realm() {
+        // a. Return obj.[[Realm]].
+        return Ok(realm.clone());
+    }
+
+    // 2. If obj is a bound function exotic object, then
+    // 3. If obj is a Proxy exotic object, then
+    // NOTE: Bound function and Proxy exotic objects are not supported yet.
+
+    // 4. Return the current Realm Record.
+    Ok(agent.current_realm())
 }
 
 /// Integrity level for SetIntegrityLevel operation
@@ -400,3 +485,123 @@ pub(crate) fn test_integrity_level(
     // 6. Return true.
     Ok(true)
 }
+
+/// 7.3.17 CreateArrayFromList ( elements )
+/// https://262.ecma-international.org/16.0/#sec-createarrayfromlist
+pub(crate) fn create_array_from_list(agent: &mut JSAgent, elements: Vec<JSValue>) -> ObjectAddr {
+    // 1. Let array be ! ArrayCreate(0).
+    let array = array_create(agent, 0, None).unwrap();
+
+    // 2. Let n be 0.
+    // 3. For each element e of elements, do
+    for (n, element) in elements.into_iter().enumerate() {
+        // a. Perform ! CreateDataPropertyOrThrow(array, ! ToString(𝔽(n)), e).
+        // b. Set n to n + 1.
+        create_data_property_or_throw(
+            &array,
+            &JSObjectPropKey::String(n.to_string().into()),
+            element,
+        )
+        .unwrap();
+    }
+
+    // 4. Return array.
+    array
+}
+
+/// 7.3.18 LengthOfArrayLike ( obj )
+/// https://262.ecma-international.org/16.0/#sec-lengthofarraylike
+pub(crate) fn length_of_array_like(
+    agent: &mut JSAgent,
+    object: &(impl ObjectMeta + ObjectEssentialInternalMethods),
+) -> CompletionRecord<u64> {
+    // 1. Return ℝ(? ToLength(? Get(obj, "length"))).
+    let length = get(agent, object, &JSObjectPropKey::String("length".into()))?;
+
+    Ok(to_length(agent, length)?.0 as u64)
+}
+
+/// 7.3.20 Invoke ( V, P [ , argumentsList ] )
+/// https://262.ecma-international.org/16.0/#sec-invoke
+pub(crate) fn invoke(
+    agent: &mut JSAgent,
+    value: &JSValue,
+    key: &JSObjectPropKey,
+    arguments_list: Option<Vec<JSValue>>,
+) -> CompletionRecord<JSValue> {
+    // 1. If argumentsList is not present, set argumentsList to a new empty List.
+    // 2. Let func be ? GetV(V, P).
+    let func = getv(agent, value, key)?;
+
+    // 3. Return ? Call(func, V, argumentsList).
+    call(agent, func, value, arguments_list)
+}
+
+/// 7.3.22 SpeciesConstructor ( O, defaultConstructor )
+/// https://262.ecma-international.org/16.0/#sec-speciesconstructor
+pub(crate) fn species_constructor(
+    agent: &mut JSAgent,
+    object: &ObjectAddr,
+    default_constructor: ObjectAddr,
+) -> CompletionRecord<ObjectAddr> {
+    // 1. Let C be ? Get(O, "constructor").
+    let constructor = get(
+        agent,
+        object,
+        &JSObjectPropKey::String("constructor".into()),
+    )?;
+
+    // 2. If C is undefined, return defaultConstructor.
+    if constructor.is_undefined() {
+        return Ok(default_constructor);
+    }
+
+    // 3. If C is not an Object, throw a TypeError exception.
+    let JSValue::Object(constructor) = constructor else {
+        return type_error("Object constructor property is not an object");
+    };
+
+    // 4. Let S be ? Get(C, %Symbol.species%).
+    let species = get(
+        agent,
+        &constructor,
+        &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_SPECIES),
+    )?;
+
+    // 5. If S is either undefined or null, return defaultConstructor.
+    if species.is_undefined() || species.is_null() {
+        return Ok(default_constructor);
+    }
+
+    // 6. If IsConstructor(S) is true, return S.
+    if let JSValue::Object(species) = species {
+        if species.is_constructor() {
+            return Ok(species);
+        }
+    }
+
+    // 7. Throw a TypeError exception.
+    type_error("Object constructor species is not a constructor")
+}
+
+/// 7.3.31 PrivateGet ( O, P )
+/// https://262.ecma-international.org/16.0/#sec-privateget
+pub(crate) fn private_get(_object: &ObjectAddr, name: &str) -> CompletionRecord<JSValue> {
+    // 1. Let entry be PrivateElementFind(O, P).
+    // NOTE: Private elements are only added by the fields, methods and accessors of classes, which are not implemented yet, so the entry is always empty.
+    // 2. If entry is empty, throw a TypeError exception.
+    type_error(&format!(
+        "Cannot read private member {name} from an object whose class did not declare it"
+    ))
+}
+
+/// 7.3.32 PrivateSet ( O, P, value )
+/// https://262.ecma-international.org/16.0/#sec-privateset
+pub(crate) fn private_set(_object: &ObjectAddr, name: &str, _value: JSValue) -> CompletionRecord {
+    // 1. Let entry be PrivateElementFind(O, P).
+    // NOTE: Private elements are only added by the fields, methods and accessors of classes, which are not implemented yet, so the entry is always empty.
+    // 2. If entry is empty, throw a TypeError exception.
+    type_error(&format!(
+        "Cannot write private member {name} to an object whose class did not declare it"
+    ))
+}
@@ -1,12 +1,14 @@
 use crate::{
     abstract_ops::{
-        object_operations::{call, create_data_property, make_basic_object},
+        object_operations::{
+            call, create_data_property, get, get_function_realm, make_basic_object,
+        },
         testing_comparison::{is_extensible, same_value},
     },
-    runtime::completion::CompletionRecord,
+    runtime::{agent::JSAgent, completion::CompletionRecord, intrinsics::Intrinsics},
     value::object::{
         internal_slots::InternalSlotName,
-        property::{JSObjectPropDescriptor, JSObjectPropKey},
+        property::{complete_property_descriptor, JSObjectPropDescriptor, JSObjectPropKey},
         ObjectAddr, ObjectEssentialInternalMethods, ObjectMeta,
     },
     JSValue,
@@ -103,10 +105,7 @@ pub(crate) fn ordinary_get_own_property<T: ObjectMeta>(
 
     // 1. If O does not have an own property with key P, return undefined.
     // 3. Let X be O's own property whose key is P.
-    let Some(x) = object_data.find_property_index(key) else {
-        return Ok(None);
-    };
-    let Some(x) = object_data.get_property(x) else {
+    let Some(x) = object_data.get_property(key) else {
         return Ok(None);
     };
 
@@ -156,9 +155,30 @@ pub(crate) fn ordinary_define_own_property<T: ObjectMeta + ObjectEssentialIntern
     let extensible = is_extensible(object);
 
     // 3. Return ValidateAndApplyPropertyDescriptor(O, P, extensible, Desc, current).
-    validate_and_apply_property_descriptor(Some(object), key, extensible, descriptor, current);
+    Ok(validate_and_apply_property_descriptor(
+        Some(object),
+        key,
+        extensible,
+        descriptor,
+        current,
+    ))
+}
 
-    Ok(true)
+/// 10.1.6.2 IsCompatiblePropertyDescriptor ( Extensible, Desc, Current )
+/// https://262.ecma-international.org/16.0/#sec-iscompatiblepropertydescriptor
+pub(crate) fn is_compatible_property_descriptor(
+    extensible: bool,
+    descriptor: JSObjectPropDescriptor,
+    current: Option<JSObjectPropDescriptor>,
+) -> bool {
+    // 1. Return ValidateAndApplyPropertyDescriptor(undefined, "", Extensible, Desc, Current).
+    validate_and_apply_property_descriptor::<ObjectAddr>(
+        None,
+        &JSObjectPropKey::String("".into()),
+        extensible,
+        descriptor,
+        current,
+    )
 }
 
 /// 10.1.6.3 ValidateAndApplyPropertyDescriptor ( O, P, extensible, Desc, current )
@@ -184,33 +204,15 @@ pub(crate) fn validate_and_apply_property_descriptor<T: ObjectMeta>(
         };
 
         // c. If IsAccessorDescriptor(Desc) is true, then
-        if descriptor.is_accessor_descriptor() {
-            // i. Create an own accessor property named P of object O whose [[Get]], [[Set]], [[Enumerable]], and [[Configurable]] attributes are set to the value of the corresponding field in Desc if Desc has that field, or to the attribute's default value otherwise.
-            object.data_mut().set_property(
-                key,
-                JSObjectPropDescriptor {
-                    get: descriptor.get,
-                    set: descriptor.set,
-                    enumerable: descriptor.enumerable,
-                    configurable: descriptor.configurable,
-                    ..JSObjectPropDescriptor::default()
-                },
-            );
-        }
+        // i. Create an own accessor property named P of object O whose [[Get]], [[Set]], [[Enumerable]], and [[Configurable]] attributes are set to the value of the corresponding field in Desc if Desc has that field, or to the attribute's default value otherwise.
         // d. Else,
-        else {
-            // i. Create an own data property named P of object O whose [[Value]], [[Writable]], [[Enumerable]], and [[Configurable]] attributes are set to the value of the corresponding field in Desc if Desc has that field, or to the attribute's default value otherwise.
-            object.data_mut().set_property(
-                key,
-                JSObjectPropDescriptor {
-                    value: descriptor.value,
-                    writable: descriptor.writable,
-                    enumerable: descriptor.enumerable,
-                    configurable: descriptor.configurable,
-                    ..JSObjectPropDescriptor::default()
-                },
-            );
-        }
+        // i. Create an own data property named P of object O whose [[Value]], [[Writable]], [[Enumerable]], and [[Configurable]] attributes are set to the value of the corresponding field in Desc if Desc has that field, or to the attribute's default value otherwise.
+        // NOTE: CompletePropertyDescriptor gives Desc the default value of each attribute of its kind which it has no field for, and a generic descriptor the attributes of a data property.
+        let mut descriptor = descriptor;
+
+        complete_property_descriptor(&mut descriptor);
+
+        object.data_mut().insert_property(key, descriptor);
 
         // e. Return true.
         return true;
@@ -220,7 +222,7 @@ pub(crate) fn validate_and_apply_property_descriptor<T: ObjectMeta>(
     debug_assert!(current.is_fully_populated());
 
     // 4. If Desc does not have any fields, return true.
-    if !descriptor.is_fully_populated() {
+    if descriptor.is_empty() {
         return true;
     }
 
@@ -303,13 +305,13 @@ pub(crate) fn validate_and_apply_property_descriptor<T: ObjectMeta>(
             };
 
             // iii. Replace the property named P of object O with an accessor property whose [[Configurable]] and [[Enumerable]] attributes are set to configurable and enumerable, respectively, and whose [[Get]] and [[Set]] attributes are set to the value of the corresponding field in Desc if Desc has that field, or to the attribute's default value otherwise.
-            object.data_mut().set_property(
+            object.data_mut().update_property(
                 key,
                 JSObjectPropDescriptor {
                     configurable: Some(configurable),
                     enumerable: Some(enumerable),
-                    get: descriptor.get,
-                    set: descriptor.set,
+                    get: descriptor.get.or(Some(JSValue::Undefined)),
+                    set: descriptor.set.or(Some(JSValue::Undefined)),
                     ..JSObjectPropDescriptor::default()
                 },
             );
@@ -331,13 +333,13 @@ pub(crate) fn validate_and_apply_property_descriptor<T: ObjectMeta>(
             };
 
             // iii. Replace the property named P of object O with a data property whose [[Configurable]] and [[Enumerable]] attributes are set to configurable and enumerable, respectively, and whose [[Value]] and [[Writable]] attributes are set to the value of the corresponding field in Desc if Desc has that field, or to the attribute's default value otherwise.
-            object.data_mut().set_property(
+            object.data_mut().update_property(
                 key,
                 JSObjectPropDescriptor {
                     configurable: Some(configurable),
                     enumerable: Some(enumerable),
-                    value: descriptor.value,
-                    writable: descriptor.writable,
+                    value: descriptor.value.or(Some(JSValue::Undefined)),
+                    writable: descriptor.writable.or(Some(false)),
                     ..JSObjectPropDescriptor::default()
                 },
             );
@@ -345,7 +347,9 @@ pub(crate) fn validate_and_apply_property_descriptor<T: ObjectMeta>(
         // c. Else,
         // i. For each field of Desc, set the corresponding attribute of the property named P of object O to the value of the field.
         else {
-            object.data_mut().set_property(key, descriptor);
+            object
+                .data_mut()
+                .update_property(key, descriptor.merge(current));
         }
     }
 
@@ -383,6 +387,7 @@ pub(crate) fn ordinary_has_property<T: ObjectMeta + ObjectEssentialInternalMetho
 /// 10.1.8.1 OrdinaryGet ( O, P, Receiver )
 /// https://262.ecma-international.org/16.0/#sec-ordinaryget
 pub(crate) fn ordinary_get<T: ObjectMeta + ObjectEssentialInternalMethods>(
+    agent: &mut JSAgent,
     object: &T,
     key: &JSObjectPropKey,
     receiver: &JSValue,
@@ -401,7 +406,7 @@ pub(crate) fn ordinary_get<T: ObjectMeta + ObjectEssentialInternalMethods>(
         };
 
         // c. Return ? parent.[[Get]](P, Receiver).
-        return parent.get(key, receiver);
+        return parent.get(agent, key, receiver);
     };
 
     // 3. If IsDataDescriptor(desc) is true, return desc.[[Value]].
@@ -416,17 +421,18 @@ pub(crate) fn ordinary_get<T: ObjectMeta + ObjectEssentialInternalMethods>(
     let getter = desc.get;
 
     // 6. If getter is undefined, return undefined.
-    if getter.is_none() {
+    if matches!(getter, None | Some(JSValue::Undefined)) {
         return Ok(JSValue::Undefined);
     }
 
     // 7. Return ? Call(getter, Receiver).
-    call(getter.unwrap(), receiver, None)
+    call(agent, getter.unwrap(), receiver, None)
 }
 
 /// 10.1.9.1 OrdinarySet ( O, P, V, Receiver )
 /// https://262.ecma-international.org/16.0/#sec-ordinaryset
 pub(crate) fn ordinary_set<T: ObjectMeta + ObjectEssentialInternalMethods>(
+    agent: &mut JSAgent,
     object: &T,
     key: &JSObjectPropKey,
     value: JSValue,
@@ -436,12 +442,13 @@ pub(crate) fn ordinary_set<T: ObjectMeta + ObjectEssentialInternalMethods>(
     let own_desc = object.get_own_property(key)?;
 
     // 2. Return ? OrdinarySetWithOwnDescriptor(O, P, V, Receiver, ownDesc).
-    ordinary_set_with_own_descriptor(object, key, value, receiver, own_desc)
+    ordinary_set_with_own_descriptor(agent, object, key, value, receiver, own_desc)
 }
 
 /// 10.1.9.2 OrdinarySetWithOwnDescriptor ( O, P, V, Receiver, ownDesc )
 /// https://262.ecma-international.org/16.0/#sec-ordinarysetwithowndescriptor
 pub(crate) fn ordinary_set_with_own_descriptor<T: ObjectMeta + ObjectEssentialInternalMethods>(
+    agent: &mut JSAgent,
     object: &T,
     key: &JSObjectPropKey,
     value: JSValue,
@@ -458,7 +465,7 @@ pub(crate) fn ordinary_set_with_own_descriptor<T: ObjectMeta + ObjectEssentialIn
         // b. If parent is not null, then
         if let Some(parent) = opt_parent {
             // i. Return ? parent.[[Set]](P, V, Receiver).
-            return parent.set(key, value, receiver);
+            return parent.set(agent, key, value, receiver);
         }
 
         // c. Else,
@@ -475,7 +482,7 @@ pub(crate) fn ordinary_set_with_own_descriptor<T: ObjectMeta + ObjectEssentialIn
     // 2. If IsDataDescriptor(ownDesc) is true, then
     if own_desc.is_data_descriptor() {
         // a. If ownDesc.[[Writable]] is false, return false.
-        if own_desc.writable == Some(true) {
+        if own_desc.writable == Some(false) {
             return Ok(false);
         }
 
@@ -513,7 +520,7 @@ pub(crate) fn ordinary_set_with_own_descriptor<T: ObjectMeta + ObjectEssentialIn
         // e. Else,
         else {
             // i. Assert: Receiver does not currently have a property P.
-            debug_assert!(!receiver.has_property(key)?);
+            debug_assert!(receiver.get_own_property(key)?.is_none());
 
             // ii. Return ? CreateDataProperty(Receiver, P, V).
             return create_data_property(&receiver, key, value);
@@ -527,12 +534,12 @@ pub(crate) fn ordinary_set_with_own_descriptor<T: ObjectMeta + ObjectEssentialIn
     let setter = own_desc.set;
 
     // 5. If setter is undefined, return false.
-    if setter.is_none() {
+    if matches!(setter, None | Some(JSValue::Undefined)) {
         return Ok(false);
     }
 
     // 6. Perform ? Call(setter, Receiver, « V »).
-    call(setter.unwrap(), &receiver, Some(vec![value]))?;
+    call(agent, setter.unwrap(), &receiver, Some(vec![value]))?;
 
     // 7. Return true.
     Ok(true)
@@ -555,9 +562,7 @@ pub(crate) fn ordinary_delete<T: ObjectMeta + ObjectEssentialInternalMethods>(
     // 3. If desc.[[Configurable]] is true, then
     if desc.configurable.unwrap_or(false) {
         // a. Remove the own property with name P from O.
-        let property = object.data().find_property_index(key).unwrap();
-
-        object.data_mut().delete_property(property);
+        object.data_mut().delete_property(key);
 
         // b. Return true.
         return Ok(true);
@@ -571,21 +576,18 @@ pub(crate) fn ordinary_delete<T: ObjectMeta + ObjectEssentialInternalMethods>(
 /// https://262.ecma-international.org/16.0/#sec-ordinaryownpropertykeys
 pub(crate) fn ordinary_own_property_keys<T: ObjectMeta>(object: &T) -> Vec<JSObjectPropKey> {
     // Let keys be a new empty List.
-    let mut keys: Vec<JSObjectPropKey> = Vec::new();
+    // NOTE: The elements and the named properties are each held in enumeration order, so the keys are copied in one pass without sorting.
+    let mut keys: Vec<JSObjectPropKey> = Vec::with_capacity(object.data().property_count());
 
     // 2. For each own property key P of O such that P is an array index, in ascending numeric index order, do
-    for key in object.data().keys() {
-        if key.is_array_index() {
-            // a. Append P to keys.
-            keys.push(key.clone());
-        }
+    // NOTE: The elements of an object are held in ascending index order, so they need no sorting.
+    for index in object.data().element_indices() {
+        // a. Append P to keys.
+        keys.push(JSObjectPropKey::String(index.into()));
     }
 
-    // Ascending numeric index order.
-    keys.sort_by_key(|key| key.as_array_index().unwrap());
-
     // 3. For each own property key P of O such that P is a String and P is not an array index, in ascending chronological order of property creation, do
-    for key in object.data().keys() {
+    for key in object.data().named_keys() {
         if key.is_string() {
             // a. Append P to keys.
             keys.push(key.clone());
@@ -593,7 +595,7 @@ pub(crate) fn ordinary_own_property_keys<T: ObjectMeta>(object: &T) -> Vec<JSObj
     }
 
     // 4. For each own property key P of O such that P is a Symbol, in ascending chronological order of property creation, do
-    for key in object.data().keys() {
+    for key in object.data().named_keys() {
         if key.is_symbol() {
             // a. Append P to keys.
             keys.push(key.clone());
@@ -607,6 +609,7 @@ pub(crate) fn ordinary_own_property_keys<T: ObjectMeta>(object: &T) -> Vec<JSObj
 /// 10.1.12 OrdinaryObjectCreate ( proto [ , additionalInternalSlotsList ] )
 /// https://262.ecma-international.org/16.0/#sec-ordinaryobjectcreate
 pub(crate) fn ordinary_object_create(
+    agent: &mut JSAgent,
     proto_addr: Option<ObjectAddr>,
     additional_internal_slots: Option<Vec<InternalSlotName>>,
 ) -> ObjectAddr {
@@ -615,7 +618,7 @@ pub(crate) fn ordinary_object_create(
     // 2. If additionalInternalSlotsList is present, set internalSlotsList to the list-concatenation of internalSlotsList and additionalInternalSlotsList.
 
     // 3. Let O be MakeBasicObject(internalSlotsList).
-    let obj = make_basic_object(additional_internal_slots.unwrap_or_default());
+    let obj = make_basic_object(agent, additional_internal_slots.unwrap_or_default());
 
     // 4. Set O.[[Prototype]] to proto.
     obj.data_mut().set_prototype(proto_addr);
@@ -623,3 +626,52 @@ pub(crate) fn ordinary_object_create(
     // 5. Return O.
     obj
 }
+
+/// 10.1.13 OrdinaryCreateFromConstructor ( constructor, intrinsicDefaultProto [ , internalSlotsList ] )
+/// https://262.ecma-international.org/16.0/#sec-ordinarycreatefromconstructor
+pub(crate) fn ordinary_create_from_constructor(
+    agent: &mut JSAgent,
+    constructor: &ObjectAddr,
+    intrinsic_default_proto: fn(&Intrinsics) -> Option<ObjectAddr>,
+    internal_slots_list: Option<Vec<InternalSlotName>>,
+) -> CompletionRecord<ObjectAddr> {
+    // 1. Assert: intrinsicDefaultProto is this specification's name of an intrinsic object. The corresponding object must be an intrinsic that is intended to be used as the [[Prototype]] value of an object.
+    // 2. Let proto be ? GetPrototypeFromConstructor(constructor, intrinsicDefaultProto).
+    let proto = get_prototype_from_constructor(agent, constructor, intrinsic_default_proto)?;
+
+    // 3. If internalSlotsList is present, let slotsList be internalSlotsList.
+    // 4. Else, let slotsList be a new empty List.
+    // 5. Return OrdinaryObjectCreate(proto, slotsList).
+    Ok(ordinary_object_create(agent, proto, internal_slots_list))
+}
+
+/// 10.1.14 GetPrototypeFromConstructor ( constructor, intrinsicDefaultProto )
+/// https://262.ecma-international.org/16.0/#sec-getprototypefromconstructor
+pub(crate) fn get_prototype_from_constructor(
+    agent: &mut JSAgent,
+    constructor: &ObjectAddr,
+    intrinsic_default_proto: fn(&Intrinsics) -> Option<ObjectAddr>,
+) -> CompletionRecord<Option<ObjectAddr>> {
+    // 1. Assert: intrinsicDefaultProto is this specification's name of an intrinsic object. The corresponding object must be an intrinsic that is intended to be used as the [[Prototype]] value of an object.
+    // 2. Let proto be ? Get(constructor, "prototype").
+    let proto = get(
+        agent,
+        constructor,
+        &JSObjectPropKey::String("prototype".into()),
+    )?;
+
+    // 3. If proto is not an Object, then
+    if let JSValue::Object(proto) = proto {
+        // 4. Return proto.
+        return Ok(Some(proto));
+    }
+
+    // a. Let realm be ? GetFunctionRealm(constructor).
+    let realm = get_function_realm(agent, constructor)?;
+
+    // b. Set proto to realm's intrinsic object named intrinsicDefaultProto.
+    let proto = intrinsic_default_proto(&realm.borrow().intrinsics);
+
+    // 4. Return proto.
+    Ok(proto)
+}
@@ -0,0 +1,739 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::{call, construct, get, get_function_realm},
+        testing_comparison::{is_callable, same_value},
+    },
+    runtime::{
+        agent::{type_error, type_error_for, JSAgent},
+        completion::{CompletionRecord, ThrowCompletion},
+        host_hooks::RejectionOperation,
+        job::Job,
+        native_function::Args,
+        promise::{PromiseCapability, PromiseReaction, PromiseReactionType, PromiseState},
+    },
+    value::{
+        object::{property::JSObjectPropKey, ObjectAddr, ObjectMeta},
+        JSValue,
+    },
+};
+
+// 27.2.1 Promise Abstract Operations
+// https://262.ecma-international.org/16.0/#sec-promise-abstract-operations
+
+/// The resolve and reject functions returned by CreateResolvingFunctions.
+pub(crate) struct ResolvingFunctions {
+    /// [[Resolve]]
+    pub(crate) resolve: ObjectAddr,
+
+    /// [[Reject]]
+    pub(crate) reject: ObjectAddr,
+}
+
+/// 27.2.1.1.1 IfAbruptRejectPromise ( value, capability )
+/// https://262.ecma-international.org/16.0/#sec-ifabruptrejectpromise
+///
+/// Called with the thrown value of an abrupt completion, as a normal completion is unwrapped by the caller.
+pub(crate) fn if_abrupt_reject_promise(
+    agent: &mut JSAgent,
+    error: ThrowCompletion,
+    capability: &PromiseCapability,
+) -> CompletionRecord<JSValue> {
+    // 1. Assert: value is a Completion Record.
+    // 2. If value is an abrupt completion, then
+    // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+    let error = error.into_value(agent);
+
+    call(
+        agent,
+        capability.reject.clone(),
+        &JSValue::Undefined,
+        Some(vec![error]),
+    )?;
+
+    // b. Return capability.[[Promise]].
+    Ok(capability.promise.clone().into())
+
+    // 3. Else,
+    // a. Set value to ! value.
+}
+
+/// 27.2.1.3 CreateResolvingFunctions ( promise )
+/// https://262.ecma-international.org/16.0/#sec-createresolvingfunctions
+pub(crate) fn create_resolving_functions(
+    agent: &mut JSAgent,
+    promise: &ObjectAddr,
+) -> ResolvingFunctions {
+    // 1. Let alreadyResolved be the Record { [[Value]]: false }.
+    let already_resolved = Rc::new(Cell::new(false));
+
+    // 2. Let stepsResolve be the algorithm steps defined in Promise Resolve Functions.
+    // 3. Let lengthResolve be the number of non-optional parameters of the function definition in Promise Resolve Functions.
+    // 4. Let resolve be CreateBuiltinFunction(stepsResolve, lengthResolve, "", « [[Promise]], [[AlreadyResolved]] »).
+    // 5. Set resolve.[[Promise]] to promise.
+    // 6. Set resolve.[[AlreadyResolved]] to alreadyResolved.
+    // NOTE: The [[Promise]] and [[AlreadyResolved]] slots are captured by the closure.
+    let resolve = {
+        let promise = promise.clone();
+        let already_resolved = already_resolved.clone();
+
+        create_builtin_function(
+            agent,
+            move |agent, args| {
+                promise_resolve_function(agent, &promise, &already_resolved, args.get(0).clone())
+            },
+            1,
+            JSObjectPropKey::String("".into()),
+            vec![],
+            None,
+            None,
+            None,
+        )
+    };
+
+    // 7. Let stepsReject be the algorithm steps defined in Promise Reject Functions.
+    // 8. Let lengthReject be the number of non-optional parameters of the function definition in Promise Reject Functions.
+    // 9. Let reject be CreateBuiltinFunction(stepsReject, lengthReject, "", « [[Promise]], [[AlreadyResolved]] »).
+    // 10. Set reject.[[Promise]] to promise.
+    // 11. Set reject.[[AlreadyResolved]] to alreadyResolved.
+    let reject = {
+        let promise = promise.clone();
+
+        create_builtin_function(
+            agent,
+            move |agent, args| {
+                promise_reject_function(agent, &promise, &already_resolved, args.get(0).clone())
+            },
+            1,
+            JSObjectPropKey::String("".into()),
+            vec![],
+            None,
+            None,
+            None,
+        )
+    };
+
+    // 12. Return the Record { [[Resolve]]: resolve, [[Reject]]: reject }.
+    ResolvingFunctions { resolve, reject }
+}
+
+/// 27.2.1.3.1 Promise Reject Functions
+/// https://262.ecma-international.org/16.0/#sec-promise-reject-functions
+fn promise_reject_function(
+    agent: &mut JSAgent,
+    promise: &ObjectAddr,
+    already_resolved: &Cell<bool>,
+    reason: JSValue,
+) -> CompletionRecord<JSValue> {
+    // 1. Let F be the active function object.
+    // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+    // 3. Let promise be F.[[Promise]].
+    // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+    // 5. If alreadyResolved.[[Value]] is true, return undefined.
+    if already_resolved.get() {
+        return Ok(JSValue::Undefined);
+    }
+
+    // 6. Set alreadyResolved.[[Value]] to true.
+    already_resolved.set(true);
+
+    // 7. Perform RejectPromise(promise, reason).
+    reject_promise(agent, promise, reason);
+
+    // 8. Return undefined.
+    Ok(JSValue::Undefined)
+}
+
+/// 27.2.1.3.2 Promise Resolve Functions
+/// https://262.ecma-international.org/16.0/#sec-promise-resolve-functions
+fn promise_resolve_function(
+    agent: &mut JSAgent,
+    promise: &ObjectAddr,
+    already_resolved: &Cell<bool>,
+    resolution: JSValue,
+) -> CompletionRecord<JSValue> {
+    // 1. Let F be the active function object.
+    // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+    // 3. Let promise be F.[[Promise]].
+    // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+    // 5. If alreadyResolved.[[Value]] is true, return undefined.
+    if already_resolved.get() {
+        return Ok(JSValue::Undefined);
+    }
+
+    // 6. Set alreadyResolved.[[Value]] to true.
+    already_resolved.set(true);
+
+    // 7. If SameValue(resolution, promise) is true, then
+    if same_value(&resolution, &JSValue::from(promise)) {
+        // a. Let selfResolutionError be a newly created TypeError object.
+        let self_resolution_error =
+            type_error::<()>("Chaining cycle detected for promise").unwrap_err();
+
+        // b. Perform RejectPromise(promise, selfResolutionError).
+        let self_resolution_error = self_resolution_error.into_value(agent);
+
+        reject_promise(agent, promise, self_resolution_error);
+
+        // c. Return undefined.
+        return Ok(JSValue::Undefined);
+    }
+
+    // 8. If resolution is not an Object, then
+    let JSValue::Object(resolution_obj) = &resolution else {
+        // a. Perform FulfillPromise(promise, resolution).
+        fulfill_promise(agent, promise, resolution);
+
+        // b. Return undefined.
+        return Ok(JSValue::Undefined);
+    };
+
+    // 9. Let then be Completion(Get(resolution, "then")).
+    let then = get(
+        agent,
+        resolution_obj,
+        &JSObjectPropKey::String("then".into()),
+    );
+
+    // 10. If then is an abrupt completion, then
+    // 11. Let thenAction be then.[[Value]].
+    let then_action = match then {
+        Ok(then_action) => then_action,
+        Err(error) => {
+            // a. Perform RejectPromise(promise, then.[[Value]]).
+            let error = error.into_value(agent);
+
+            reject_promise(agent, promise, error);
+
+            // b. Return undefined.
+            return Ok(JSValue::Undefined);
+        }
+    };
+
+    // 12. If IsCallable(thenAction) is false, then
+    if !is_callable(&then_action) {
+        // a. Perform FulfillPromise(promise, resolution).
+        fulfill_promise(agent, promise, resolution);
+
+        // b. Return undefined.
+        return Ok(JSValue::Undefined);
+    }
+
+    // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
+    // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
+    let job = new_promise_resolve_thenable_job(agent, promise.clone(), resolution, then_action);
+
+    // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+    agent.host_enqueue_promise_job(job);
+
+    // 16. Return undefined.
+    Ok(JSValue::Undefined)
+}
+
+/// 27.2.1.4 FulfillPromise ( promise, value )
+/// https://262.ecma-international.org/16.0/#sec-fulfillpromise
+pub(crate) fn fulfill_promise(agent: &mut JSAgent, promise: &ObjectAddr, value: JSValue) {
+    let reactions = {
+        let mut promise = promise.data_mut();
+        let slots = promise.slots_mut();
+
+        // 1. Assert: The value of promise.[[PromiseState]] is pending.
+        debug_assert_eq!(slots.promise_state(), Some(PromiseState::Pending));
+
+        // 2. Let reactions be promise.[[PromiseFulfillReactions]].
+        // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+        // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+        let reactions = slots.take_promise_reactions(PromiseReactionType::Fulfill);
+
+        // 3. Set promise.[[PromiseResult]] to value.
+        slots.set_promise_result(value.clone());
+
+        // 6. Set promise.[[PromiseState]] to fulfilled.
+        slots.set_promise_state(PromiseState::Fulfilled);
+
+        reactions
+    };
+
+    // 7. Perform TriggerPromiseReactions(reactions, value).
+    trigger_promise_reactions(agent, reactions, value);
+
+    // 8. Return unused.
+}
+
+/// 27.2.1.5 NewPromiseCapability ( C )
+/// https://262.ecma-international.org/16.0/#sec-newpromisecapability
+pub(crate) fn new_promise_capability(
+    agent: &mut JSAgent,
+    constructor: &JSValue,
+) -> CompletionRecord<PromiseCapability> {
+    // 1. If IsConstructor(C) is false, throw a TypeError exception.
+    let constructor = match constructor {
+        JSValue::Object(constructor) if constructor.is_constructor() => constructor,
+        _ => {
+            return type_error_for(
+                "Promise capability constructor is not a constructor",
+                constructor,
+            )
+        }
+    };
+
+    // 2. NOTE: C is assumed to be a constructor function that supports the parameter conventions of the Promise constructor (see 27.2.3.1).
+    // 3. Let resolvingFunctions be the Record { [[Resolve]]: undefined, [[Reject]]: undefined }.
+    let resolving_functions = Rc::new(RefCell::new((JSValue::Undefined, JSValue::Undefined)));
+
+    // 4. Let executorClosure be a new Abstract Closure with parameters (resolve, reject) that captures resolvingFunctions and performs the following steps when called:
+    let executor_closure = {
+        let resolving_functions = resolving_functions.clone();
+
+        move |_: &mut JSAgent, args: Args| {
+            let mut resolving_functions = resolving_functions.borrow_mut();
+
+            // a. If resolvingFunctions.[[Resolve]] is not undefined, throw a TypeError exception.
+            if !resolving_functions.0.is_undefined() {
+                return type_error("Promise executor has already been invoked");
+            }
+
+            // b. If resolvingFunctions.[[Reject]] is not undefined, throw a TypeError exception.
+            if !resolving_functions.1.is_undefined() {
+                return type_error("Promise executor has already been invoked");
+            }
+
+            // c. Set resolvingFunctions.[[Resolve]] to resolve.
+            // d. Set resolvingFunctions.[[Reject]] to reject.
+            *resolving_functions = (args.get(0).clone(), args.get(1).clone());
+
+            // e. Return NormalCompletion(undefined).
+            Ok(JSValue::Undefined)
+        }
+    };
+
+    // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
+    let executor = create_builtin_function(
+        agent,
+        executor_closure,
+        2,
+        JSObjectPropKey::String("".into()),
+        vec![],
+        None,
+        None,
+        None,
+    );
+
+    // 6. Let promise be ? Construct(C, « executor »).
+    let promise = construct(agent, constructor, Some(vec![executor.into()]), None)?;
+
+    let (resolve, reject) = resolving_functions.borrow().clone();
+
+    // 7. If IsCallable(resolvingFunctions.[[Resolve]]) is false, throw a TypeError exception.
+    if !is_callable(&resolve) {
+        return type_error_for("Promise resolve function is not callable", &resolve);
+    }
+
+    // 8. If IsCallable(resolvingFunctions.[[Reject]]) is false, throw a TypeError exception.
+    if !is_callable(&reject) {
+        return type_error_for("Promise reject function is not callable", &reject);
+    }
+
+    // 9. Return the PromiseCapability Record { [[Promise]]: promise, [[Resolve]]: resolvingFunctions.[[Resolve]], [[Reject]]: resolvingFunctions.[[Reject]] }.
+    Ok(PromiseCapability {
+        promise,
+        resolve,
+        reject,
+    })
+}
+
+/// 27.2.1.6 IsPromise ( x )
+/// https://262.ecma-international.org/16.0/#sec-ispromise
+pub(crate) fn is_promise(value: &JSValue) -> bool {
+    // 1. If x is not an Object, return false.
+    let JSValue::Object(value) = value else {
+        return false;
+    };
+
+    // 2. If x does not have a [[PromiseState]] internal slot, return false.
+    // 3. Return true.
+    value.data().slots().promise_state().is_some()
+}
+
+/// 27.2.1.7 RejectPromise ( promise, reason )
+/// https://262.ecma-international.org/16.0/#sec-rejectpromise
+pub(crate) fn reject_promise(agent: &mut JSAgent, promise: &ObjectAddr, reason: JSValue) {
+    let reactions = {
+        let mut promise = promise.data_mut();
+        let slots = promise.slots_mut();
+
+        // 1. Assert: The value of promise.[[PromiseState]] is pending.
+        debug_assert_eq!(slots.promise_state(), Some(PromiseState::Pending));
+
+        // 2. Let reactions be promise.[[PromiseRejectReactions]].
+        // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+        // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+        let reactions = slots.take_promise_reactions(PromiseReactionType::Reject);
+
+        // 3. Set promise.[[PromiseResult]] to reason.
+        slots.set_promise_result(reason.clone());
+
+        // 6. Set promise.[[PromiseState]] to rejected.
+        slots.set_promise_state(PromiseState::Rejected);
+
+        reactions
+    };
+
+    // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
+    if !promise.data().slots().promise_is_handled() {
+        host_promise_rejection_tracker(agent, promise, RejectionOperation::Reject);
+    }
+
+    // 8. Perform TriggerPromiseReactions(reactions, reason).
+    trigger_promise_reactions(agent, reactions, reason);
+
+    // 9. Return unused.
+}
+
+/// 27.2.1.8 TriggerPromiseReactions ( reactions, argument )
+/// https://262.ecma-international.org/16.0/#sec-triggerpromisereactions
+fn trigger_promise_reactions(
+    agent: &mut JSAgent,
+    reactions: Vec<PromiseReaction>,
+    argument: JSValue,
+) {
+    // 1. For each element reaction of reactions, do
+    for reaction in reactions {
+        // a. Let job be NewPromiseReactionJob(reaction, argument).
+        let job = new_promise_reaction_job(agent, reaction, argument.clone());
+
+        // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+        agent.host_enqueue_promise_job(job);
+    }
+
+    // 2. Return unused.
+}
+
+// 27.2.2 Promise Jobs
+// https://262.ecma-international.org/16.0/#sec-promise-jobs
+
+/// 27.2.2.1 NewPromiseReactionJob ( reaction, argument )
+/// https://262.ecma-international.org/16.0/#sec-newpromisereactionjob
+fn new_promise_reaction_job(
+    agent: &mut JSAgent,
+    reaction: PromiseReaction,
+    argument: JSValue,
+) -> Job {
+    // 2. Let handlerRealm be null.
+    // 3. If reaction.[[Handler]] is not empty, then
+    // a. Let getHandlerRealmResult be Completion(GetFunctionRealm(reaction.[[Handler]].[[Callback]])).
+    // b. If getHandlerRealmResult is a normal completion, set handlerRealm to getHandlerRealmResult.[[Value]].
+    // c. Else, set handlerRealm to the current Realm Record.
+    // d. NOTE: handlerRealm is never null unless the handler is undefined. When the handler is a revoked Proxy and no ECMAScript code runs, handlerRealm is used to create error objects.
+    let handler_realm = match &reaction.handler {
+        Some(JSValue::Object(handler)) => {
+            Some(get_function_realm(agent, handler).unwrap_or_else(|_| agent.current_realm()))
+        }
+        _ => None,
+    };
+
+    // 1. Let job be a new Job Abstract Closure with no parameters that captures reaction and argument and performs the following steps when called:
+    let job = move |agent: &mut JSAgent| {
+        // a. Let promiseCapability be reaction.[[Capability]].
+        // b. Let type be reaction.[[Type]].
+        // c. Let handler be reaction.[[Handler]].
+        let PromiseReaction {
+            capability,
+            reaction_type,
+            handler,
+        } = reaction;
+
+        let handler_result = match handler {
+            // d. If handler is empty, then
+            None => match reaction_type {
+                // i. If type is fulfill, then
+                // 1. Let handlerResult be NormalCompletion(argument).
+                PromiseReactionType::Fulfill => Ok(argument),
+                // ii. Else,
+                // 1. Assert: type is reject.
+                // 2. Let handlerResult be ThrowCompletion(argument).
+                PromiseReactionType::Reject => Err(ThrowCompletion::from(argument)),
+            },
+            // e. Else,
+            // i. Let handlerResult be Completion(HostCallJobCallback(handler, undefined, « argument »)).
+            Some(handler) => {
+                host_call_job_callback(agent, handler, &JSValue::Undefined, vec![argument])
+            }
+        };
+
+        // f. If promiseCapability is undefined, then
+        let Some(capability) = capability else {
+            // i. Assert: handlerResult is not an abrupt completion.
+            // ii. Return empty.
+            // NOTE: Only the reactions of await have no capability, and their handlers do not throw.
+            return handler_result.map(|_| ());
+        };
+
+        // g. Assert: promiseCapability is a PromiseCapability Record.
+        match handler_result {
+            // h. If handlerResult is an abrupt completion, then
+            // i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
+            Err(error) => {
+                let error = error.into_value(agent);
+
+                call(
+                    agent,
+                    capability.reject,
+                    &JSValue::Undefined,
+                    Some(vec![error]),
+                )
+            }
+            // i. Else,
+            // i. Return ? Call(promiseCapability.[[Resolve]], undefined, « handlerResult.[[Value]] »).
+            Ok(value) => call(
+                agent,
+                capability.resolve,
+                &JSValue::Undefined,
+                Some(vec![value]),
+            ),
+        }
+        .map(|_| ())
+    };
+
+    // 4. Return the Record { [[Job]]: job, [[Realm]]: handlerRealm }.
+    Job::new(job, handler_realm)
+}
+
+/// 27.2.2.2 NewPromiseResolveThenableJob ( promiseToResolve, thenable, then )
+/// https://262.ecma-international.org/16.0/#sec-newpromiseresolvethenablejob
+fn new_promise_resolve_thenable_job(
+    agent: &mut JSAgent,
+    promise_to_resolve: ObjectAddr,
+    thenable: JSValue,
+    then: JSValue,
+) -> Job {
+    // 2. Let getThenRealmResult be Completion(GetFunctionRealm(then.[[Callback]])).
+    // 3. If getThenRealmResult is a normal completion, let thenRealm be getThenRealmResult.[[Value]].
+    // 4. Else, let thenRealm be the current Realm Record.
+    // 5. NOTE: thenRealm is never null. When then.[[Callback]] is a revoked Proxy and no code runs, thenRealm is used to create error objects.
+    let then_realm = match &then {
+        JSValue::Object(then) => {
+            get_function_realm(agent, then).unwrap_or_else(|_| agent.current_realm())
+        }
+        _ => agent.current_realm(),
+    };
+
+    // 1. Let job be a new Job Abstract Closure with no parameters that captures promiseToResolve, thenable, and then and performs the following steps when called:
+    let job = move |agent: &mut JSAgent| {
+        // a. Let resolvingFunctions be CreateResolvingFunctions(promiseToResolve).
+        let resolving_functions = create_resolving_functions(agent, &promise_to_resolve);
+
+        // b. Let thenCallResult be Completion(HostCallJobCallback(then, thenable, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)).
+        let then_call_result = host_call_job_callback(
+            agent,
+            then,
+            &thenable,
+            vec![
+                resolving_functions.resolve.into(),
+                resolving_functions.reject.clone().into(),
+            ],
+        );
+
+        // c. If thenCallResult is an abrupt completion, then
+        if let Err(error) = then_call_result {
+            // i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
+            let error = error.into_value(agent);
+
+            return call(
+                agent,
+                resolving_functions.reject.into(),
+                &JSValue::Undefined,
+                Some(vec![error]),
+            )
+            .map(|_| ());
+        }
+
+        // d. Return ! thenCallResult.
+        Ok(())
+    };
+
+    // 6. Return the Record { [[Job]]: job, [[Realm]]: thenRealm }.
+    Job::new(job, Some(then_realm))
+}
+
+/// 27.2.4.7.1 PromiseResolve ( C, x )
+/// https://262.ecma-international.org/16.0/#sec-promise-resolve
+pub(crate) fn promise_resolve(
+    agent: &mut JSAgent,
+    constructor: &ObjectAddr,
+    value: JSValue,
+) -> CompletionRecord<JSValue> {
+    // 1. If IsPromise(x) is true, then
+    if let JSValue::Object(promise) = &value {
+        if is_promise(&value) {
+            // a. Let xConstructor be ? Get(x, "constructor").
+            let value_constructor = get(
+                agent,
+                promise,
+                &JSObjectPropKey::String("constructor".into()),
+            )?;
+
+            // b. If SameValue(xConstructor, C) is true, return x.
+            if same_value(&value_constructor, &JSValue::from(constructor)) {
+                return Ok(value);
+            }
+        }
+    }
+
+    // 2. Let promiseCapability be ? NewPromiseCapability(C).
+    let promise_capability = new_promise_capability(agent, &JSValue::from(constructor))?;
+
+    // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+    call(
+        agent,
+        promise_capability.resolve,
+        &JSValue::Undefined,
+        Some(vec![value]),
+    )?;
+
+    // 4. Return promiseCapability.[[Promise]].
+    Ok(promise_capability.promise.into())
+}
+
+/// 27.2.5.4.1 PerformPromiseThen ( promise, onFulfilled, onRejected [ , resultCapability ] )
+/// https://262.ecma-international.org/16.0/#sec-performpromisethen
+pub(crate) fn perform_promise_then(
+    agent: &mut JSAgent,
+    promise: &ObjectAddr,
+    on_fulfilled: JSValue,
+    on_rejected: JSValue,
+    result_capability: Option<PromiseCapability>,
+) -> JSValue {
+    // 1. Assert: IsPromise(promise) is true.
+    debug_assert!(is_promise(&JSValue::from(promise)));
+
+    // 2. If resultCapability is not present, then
+    // a. Set resultCapability to undefined.
+    let result_promise = result_capability
+        .as_ref()
+        .map(|capability| JSValue::from(capability.promise.clone()));
+
+    // 3. If IsCallable(onFulfilled) is false, then
+    // a. Let onFulfilledJobCallback be empty.
+    // 4. Else,
+    // a. Let onFulfilledJobCallback be HostMakeJobCallback(onFulfilled).
+    let on_fulfilled_job_callback = is_callable(&on_fulfilled).then_some(on_fulfilled);
+
+    // 5. If IsCallable(onRejected) is false, then
+    // a. Let onRejectedJobCallback be empty.
+    // 6. Else,
+    // a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
+    let on_rejected_job_callback = is_callable(&on_rejected).then_some(on_rejected);
+
+    // 7. Let fulfillReaction be the PromiseReaction Record { [[Capability]]: resultCapability, [[Type]]: fulfill, [[Handler]]: onFulfilledJobCallback }.
+    let fulfill_reaction = PromiseReaction {
+        capability: result_capability.clone(),
+        reaction_type: PromiseReactionType::Fulfill,
+        handler: on_fulfilled_job_callback,
+    };
+
+    // 8. Let rejectReaction be the PromiseReaction Record { [[Capability]]: resultCapability, [[Type]]: reject, [[Handler]]: onRejectedJobCallback }.
+    let reject_reaction = PromiseReaction {
+        capability: result_capability,
+        reaction_type: PromiseReactionType::Reject,
+        handler: on_rejected_job_callback,
+    };
+
+    let (state, result) = {
+        let promise = promise.data();
+
+        (
+            promise.slots().promise_state(),
+            promise.slots().promise_result(),
+        )
+    };
+
+    match state {
+        // 9. If promise.[[PromiseState]] is pending, then
+        Some(PromiseState::Pending) => {
+            let mut promise = promise.data_mut();
+
+            // a. Append fulfillReaction to promise.[[PromiseFulfillReactions]].
+            promise.slots_mut().push_promise_reaction(fulfill_reaction);
+
+            // b. Append rejectReaction to promise.[[PromiseRejectReactions]].
+            promise.slots_mut().push_promise_reaction(reject_reaction);
+        }
+        // 10. Else if promise.[[PromiseState]] is fulfilled, then
+        Some(PromiseState::Fulfilled) => {
+            // a. Let value be promise.[[PromiseResult]].
+            // b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
+            let fulfill_job = new_promise_reaction_job(agent, fulfill_reaction, result);
+
+            // c. Perform HostEnqueuePromiseJob(fulfillJob.[[Job]], fulfillJob.[[Realm]]).
+            agent.host_enqueue_promise_job(fulfill_job);
+        }
+        // 11. Else,
+        _ => {
+            // a. Assert: The value of promise.[[PromiseState]] is rejected.
+            // b. Let reason be promise.[[PromiseResult]].
+            // c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+            if !promise.data().slots().promise_is_handled() {
+                host_promise_rejection_tracker(agent, promise, RejectionOperation::Handle);
+            }
+
+            // d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
+            let reject_job = new_promise_reaction_job(agent, reject_reaction, result);
+
+            // e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
+            agent.host_enqueue_promise_job(reject_job);
+        }
+    }
+
+    // 12. Set promise.[[PromiseIsHandled]] to true.
+    promise.data_mut().slots_mut().set_promise_is_handled(true);
+
+    // 13. If resultCapability is undefined, then
+    // a. Return undefined.
+    // 14. Else,
+    // a. Return resultCapability.[[Promise]].
+    result_promise.unwrap_or(JSValue::Undefined)
+}
+
+/// 9.5.3 HostCallJobCallback ( jobCallback, V, argumentsList )
+/// https://262.ecma-international.org/16.0/#sec-hostcalljobcallback
+fn host_call_job_callback(
+    agent: &mut JSAgent,
+    job_callback: JSValue,
+    v: &JSValue,
+    arguments_list: Vec<JSValue>,
+) -> CompletionRecord<JSValue> {
+    // NOTE: The host is notified before the callback is called, which is all a host other than a web browser needs to do.
+    agent.with_host_hooks(|hooks| hooks.call_job_callback(&job_callback, v, &arguments_list));
+
+    // 1. Assert: IsCallable(jobCallback.[[Callback]]) is true.
+    debug_assert!(is_callable(&job_callback));
+
+    // 2. Return ? Call(jobCallback.[[Callback]], V, argumentsList).
+    call(agent, job_callback, v, Some(arguments_list))
+}
+
+/// 27.2.1.9 HostPromiseRejectionTracker ( promise, operation )
+/// https://262.ecma-international.org/16.0/#sec-host-promise-rejection-tracker
+fn host_promise_rejection_tracker(
+    agent: &mut JSAgent,
+    promise: &ObjectAddr,
+    operation: RejectionOperation,
+) {
+    // NOTE: The agent keeps the promises which are still unhandled for the embedder to take, as well as notifying the host hooks.
+    match operation {
+        RejectionOperation::Reject => agent.unhandled_rejections.push(promise.clone()),
+        RejectionOperation::Handle => agent
+            .unhandled_rejections
+            .retain(|rejected| rejected != promise),
+    }
+
+    let promise = JSValue::from(promise.clone());
+
+    agent.with_host_hooks(|hooks| hooks.promise_rejection_tracker(&promise, operation));
+}
@@ -1,7 +1,39 @@
 use crate::{
-    abstract_ops::{environments::new_global_environment, ordinary::ordinary_object_create},
+    abstract_ops::{
+        environments::new_global_environment,
+        function_operations::add_restricted_function_properties,
+        object_operations::define_property_or_throw, ordinary::ordinary_object_create,
+    },
     gc::Gc,
-    intrinsics::{function_prototype::FunctionPrototype, object_prototype::JSObjectPrototype},
+    intrinsics::{
+        array_constructor::ArrayConstructor,
+        array_iterator_prototype::ArrayIteratorPrototype,
+        array_prototype::ArrayPrototype,
+        async_function_prototype::AsyncFunctionPrototype,
+        boolean_prototype::BooleanPrototype,
+        console_object::ConsoleObject,
+        date_constructor::DateConstructor,
+        date_prototype::DatePrototype,
+        error_constructor::ErrorConstructor,
+        error_prototype::ErrorPrototype,
+        for_in_iterator_prototype::ForInIteratorPrototype,
+        function_prototype::FunctionPrototype,
+        generator_function_prototype::GeneratorFunctionPrototype,
+        generator_prototype::GeneratorPrototype,
+        global_object::GlobalObject,
+        iterator_prototype::IteratorPrototype,
+        native_error_constructor::{NativeErrorConstructor, NativeErrorKind},
+        native_error_prototype::NativeErrorPrototype,
+        number_prototype::NumberPrototype,
+        object_constructor::ObjectConstructor,
+        object_prototype::JSObjectPrototype,
+        promise_constructor::PromiseConstructor,
+        promise_prototype::PromisePrototype,
+        string_iterator_prototype::StringIteratorPrototype,
+        string_prototype::StringPrototype,
+        symbol_prototype::SymbolPrototype,
+        throw_type_error::ThrowTypeError,
+    },
     runtime::{
         agent::JSAgent,
         completion::CompletionRecord,
@@ -9,15 +41,21 @@ use crate::{
         intrinsics::Intrinsics,
         realm::{Realm, RealmAddr},
     },
+    value::{
+        object::property::{JSObjectPropDescriptor, JSObjectPropKey},
+        JSValue,
+    },
 };
 
 /// 9.3.1 InitializeHostDefinedRealm ( )
 /// https://262.ecma-international.org/16.0/#sec-initializehostdefinedrealm
-pub(crate) fn initialize_host_defined_realm(agent: &mut JSAgent) -> CompletionRecord {
+///
+/// The realm is returned so that a host creating further realms can pop the execution context which was pushed for it.
+pub(crate) fn initialize_host_defined_realm(agent: &mut JSAgent) -> CompletionRecord<RealmAddr> {
     // 1. Let realm be a new Realm Record.
     let realm = Realm::default();
 
-    let realm_addr = Gc::new(realm);
+    let realm_addr = Gc::new(&mut agent.heap, realm);
 
     // 2. Perform CreateIntrinsics(realm).
     create_intrinsics(agent, realm_addr.clone());
@@ -26,7 +64,7 @@ pub(crate) fn initialize_host_defined_realm(agent: &mut JSAgent) -> CompletionRe
     // Note: AgentSignifier is not implemented in this codebase, so we skip this step.
 
     // 4. Set realm.[[TemplateMap]] to a new empty List.
-    // Note: TemplateMap is not implemented in this codebase, so we skip this step.
+    // NOTE: The [[TemplateMap]] of a new Realm Record is already empty.
 
     // 5. Let newContext be a new execution context.
     let new_context = ExecutionContext {
@@ -54,6 +92,7 @@ pub(crate) fn initialize_host_defined_realm(agent: &mut JSAgent) -> CompletionRe
     // 11. Else,
     // a. Let global be OrdinaryObjectCreate(realm.[[Intrinsics]].[[%Object.prototype%]]).
     let global = ordinary_object_create(
+        agent,
         realm_addr.borrow().intrinsics.object_prototype.clone(),
         None,
     );
@@ -70,40 +109,320 @@ pub(crate) fn initialize_host_defined_realm(agent: &mut JSAgent) -> CompletionRe
     realm_addr.borrow_mut().global_object = Some(global.clone());
 
     // 15. Set realm.[[GlobalEnv]] to NewGlobalEnvironment(global, thisValue).
-    realm_addr.borrow_mut().global_env = Some(new_global_environment(&global, &this_value));
+    realm_addr.borrow_mut().global_env = Some(new_global_environment(agent, &global, &this_value));
 
     // 16. Perform ? SetDefaultGlobalBindings(realm).
     set_default_global_bindings(&realm_addr)?;
 
     // 17. Create any host-defined global object properties on global.
-    // TODO: Implement this step.
+    let console = ConsoleObject::create(agent, realm_addr.clone());
+
+    // NOTE: The console namespace is a property of the global object with the attributes { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }.
+    define_property_or_throw(
+        &global,
+        &JSObjectPropKey::String("console".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(console)),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    )?;
 
     // 18. Return unused.
-    Ok(())
+    Ok(realm_addr)
 }
 
 /// 9.3.2 CreateIntrinsics ( realmRec )
 /// https://262.ecma-international.org/16.0/#sec-createintrinsics
-pub(crate) fn create_intrinsics(agent: &mut JSAgent, realm_addr: RealmAddr) -> Intrinsics {
+pub(crate) fn create_intrinsics(agent: &mut JSAgent, realm_addr: RealmAddr) {
     // 1. Set realmRec.[[Intrinsics]] to a new Record.
-    let intrinsics = Intrinsics {
-        // Iniitalize the base object prototype first so it can be used in other intrinsics.
-        object_prototype: Some(JSObjectPrototype::create()),
+    realm_addr.borrow_mut().intrinsics = Intrinsics::default();
 
-        // 2. Set fields of realmRec.[[Intrinsics]] with the values listed in Table 6. The field names are the names listed in column one of the table. The value of each field is a new object value fully and recursively populated with property values as defined by the specification of each object in clauses 19 through 28. All object property values are newly created object values. All values that are built-in function objects are created by performing CreateBuiltinFunction(steps, length, name, slots, realmRec, prototype) where steps is the definition of that function provided by this specification, name is the initial value of the function's "name" property, length is the initial value of the function's "length" property, slots is a list of the names, if any, of the function's specified internal slots, and prototype is the specified value of the function's [[Prototype]] internal slot. The creation of the intrinsics and their properties must be ordered to avoid any dependencies upon objects that have not yet been created.
-        function_prototype: Some(FunctionPrototype::create(agent, realm_addr)),
+    // 2. Set fields of realmRec.[[Intrinsics]] with the values listed in Table 6. The field names are the names listed in column one of the table. The value of each field is a new object value fully and recursively populated with property values as defined by the specification of each object in clauses 19 through 28. All object property values are newly created object values. All values that are built-in function objects are created by performing CreateBuiltinFunction(steps, length, name, slots, realmRec, prototype) where steps is the definition of that function provided by this specification, name is the initial value of the function's "name" property, length is the initial value of the function's "length" property, slots is a list of the names, if any, of the function's specified internal slots, and prototype is the specified value of the function's [[Prototype]] internal slot. The creation of the intrinsics and their properties must be ordered to avoid any dependencies upon objects that have not yet been created.
+    // NOTE: The base object prototype is initialized first so it can be used in other intrinsics.
+    let object_prototype = JSObjectPrototype::create(agent);
 
-        ..Intrinsics::default()
-    };
+    realm_addr.borrow_mut().intrinsics.object_prototype = Some(object_prototype);
+
+    let function_prototype = FunctionPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.function_prototype = Some(function_prototype.clone());
+
+    JSObjectPrototype::define_methods(agent, realm_addr.clone());
+
+    let throw_type_error = ThrowTypeError::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.throw_type_error = Some(throw_type_error);
+
+    let iterator_prototype = IteratorPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.iterator_prototype = Some(iterator_prototype);
+
+    let for_in_iterator_prototype = ForInIteratorPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.for_in_iterator_prototype = Some(for_in_iterator_prototype);
+
+    let generator_prototype = GeneratorPrototype::create(agent, realm_addr.clone());
+
+    {
+        let mut realm = realm_addr.borrow_mut();
+
+        realm.intrinsics.generator_prototype = Some(generator_prototype.clone());
+        realm.intrinsics.generator_function_prototype_prototype = Some(generator_prototype);
+    }
+
+    let generator_function_prototype =
+        GeneratorFunctionPrototype::create(agent, realm_addr.clone());
+
+    realm_addr
+        .borrow_mut()
+        .intrinsics
+        .generator_function_prototype = Some(generator_function_prototype);
+
+    let array_iterator_prototype = ArrayIteratorPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.array_iterator_prototype = Some(array_iterator_prototype);
+
+    let array_prototype = ArrayPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.array_prototype = Some(array_prototype);
+
+    let array = ArrayConstructor::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.array = Some(array);
+
+    let string_iterator_prototype = StringIteratorPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.string_iterator_prototype = Some(string_iterator_prototype);
+
+    let string_prototype = StringPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.string_prototype = Some(string_prototype);
+
+    let number_prototype = NumberPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.number_prototype = Some(number_prototype);
+
+    let boolean_prototype = BooleanPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.boolean_prototype = Some(boolean_prototype);
+
+    let symbol_prototype = SymbolPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.symbol_prototype = Some(symbol_prototype);
+
+    let promise_prototype = PromisePrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.promise_prototype = Some(promise_prototype);
+
+    let promise = PromiseConstructor::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.promise = Some(promise);
+
+    let date_prototype = DatePrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.date_prototype = Some(date_prototype);
+
+    let date = DateConstructor::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.date = Some(date);
+
+    let error_prototype = ErrorPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.error_prototype = Some(error_prototype);
+
+    let error = ErrorConstructor::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.error = Some(error);
+
+    for kind in NativeErrorKind::ALL {
+        let prototype = NativeErrorPrototype::create(agent, realm_addr.clone(), kind);
+        let constructor =
+            NativeErrorConstructor::create(agent, realm_addr.clone(), kind, &prototype);
+
+        kind.set_intrinsics(
+            &mut realm_addr.borrow_mut().intrinsics,
+            constructor,
+            prototype,
+        );
+    }
+
+    let object = ObjectConstructor::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.object = Some(object);
+
+    let is_finite = GlobalObject::create_function(
+        agent,
+        realm_addr.clone(),
+        "isFinite",
+        1,
+        GlobalObject::is_finite,
+    );
+
+    realm_addr.borrow_mut().intrinsics.is_finite = Some(is_finite);
+
+    let is_nan =
+        GlobalObject::create_function(agent, realm_addr.clone(), "isNaN", 1, GlobalObject::is_nan);
+
+    realm_addr.borrow_mut().intrinsics.is_nan = Some(is_nan);
+
+    let parse_float = GlobalObject::create_function(
+        agent,
+        realm_addr.clone(),
+        "parseFloat",
+        1,
+        GlobalObject::parse_float,
+    );
+
+    realm_addr.borrow_mut().intrinsics.parse_float = Some(parse_float);
+
+    let parse_int = GlobalObject::create_function(
+        agent,
+        realm_addr.clone(),
+        "parseInt",
+        2,
+        GlobalObject::parse_int,
+    );
+
+    realm_addr.borrow_mut().intrinsics.parse_int = Some(parse_int);
+
+    let decode_uri = GlobalObject::create_function(
+        agent,
+        realm_addr.clone(),
+        "decodeURI",
+        1,
+        GlobalObject::decode_uri,
+    );
+
+    realm_addr.borrow_mut().intrinsics.decode_uri = Some(decode_uri);
+
+    let decode_uri_component = GlobalObject::create_function(
+        agent,
+        realm_addr.clone(),
+        "decodeURIComponent",
+        1,
+        GlobalObject::decode_uri_component,
+    );
+
+    realm_addr.borrow_mut().intrinsics.decode_uri_component = Some(decode_uri_component);
+
+    let encode_uri = GlobalObject::create_function(
+        agent,
+        realm_addr.clone(),
+        "encodeURI",
+        1,
+        GlobalObject::encode_uri,
+    );
+
+    realm_addr.borrow_mut().intrinsics.encode_uri = Some(encode_uri);
+
+    let encode_uri_component = GlobalObject::create_function(
+        agent,
+        realm_addr.clone(),
+        "encodeURIComponent",
+        1,
+        GlobalObject::encode_uri_component,
+    );
+
+    realm_addr.borrow_mut().intrinsics.encode_uri_component = Some(encode_uri_component);
+
+    let async_function_prototype = AsyncFunctionPrototype::create(agent, realm_addr.clone());
+
+    realm_addr.borrow_mut().intrinsics.async_function_prototype = Some(async_function_prototype);
 
     // 3. Perform AddRestrictedFunctionProperties(realmRec.[[Intrinsics]].[[%Function.prototype%]], realmRec).
+    add_restricted_function_properties(&function_prototype, &realm_addr);
+
     // 4. Return unused.
-    intrinsics
 }
 
 /// 9.3.3 SetDefaultGlobalBindings ( realm )
 /// https://262.ecma-international.org/16.0/#sec-setdefaultglobalbindings
-fn set_default_global_bindings(_realm: &RealmAddr) -> CompletionRecord {
-    // TODO: Implement
+fn set_default_global_bindings(realm: &RealmAddr) -> CompletionRecord {
+    // 1. Let global be realm.[[GlobalObject]].
+    let global = realm.borrow().global_object.clone().unwrap();
+
+    // 19.1 Value Properties of the Global Object
+    let global_this_value = realm
+        .borrow()
+        .global_env
+        .as_ref()
+        .and_then(|global_env| global_env.get_this_binding().ok());
+
+    let value_properties = [
+        // 19.1.1 globalThis
+        // This property has the attributes { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }.
+        ("globalThis", global_this_value, true),
+        // 19.1.2 Infinity
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        ("Infinity", Some(JSValue::from(f64::INFINITY)), false),
+        // 19.1.3 NaN
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        ("NaN", Some(JSValue::from(f64::NAN)), false),
+        // 19.1.4 undefined
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        ("undefined", Some(JSValue::Undefined), false),
+    ];
+
+    // 19.2 Function Properties of the Global Object
+    // 19.3 Constructor Properties of the Global Object
+    // TODO: Add the remaining properties of the global object as they are implemented.
+    let intrinsics = &realm.borrow().intrinsics;
+
+    let properties = [
+        ("decodeURI", intrinsics.decode_uri.clone()),
+        (
+            "decodeURIComponent",
+            intrinsics.decode_uri_component.clone(),
+        ),
+        ("encodeURI", intrinsics.encode_uri.clone()),
+        (
+            "encodeURIComponent",
+            intrinsics.encode_uri_component.clone(),
+        ),
+        ("isFinite", intrinsics.is_finite.clone()),
+        ("isNaN", intrinsics.is_nan.clone()),
+        ("parseFloat", intrinsics.parse_float.clone()),
+        ("parseInt", intrinsics.parse_int.clone()),
+        ("Array", intrinsics.array.clone()),
+        ("Date", intrinsics.date.clone()),
+        ("Error", intrinsics.error.clone()),
+        ("EvalError", intrinsics.eval_error.clone()),
+        ("Object", intrinsics.object.clone()),
+        ("Promise", intrinsics.promise.clone()),
+        ("RangeError", intrinsics.range_error.clone()),
+        ("ReferenceError", intrinsics.reference_error.clone()),
+        ("SyntaxError", intrinsics.syntax_error.clone()),
+        ("TypeError", intrinsics.type_error.clone()),
+        ("URIError", intrinsics.uri_error.clone()),
+    ]
+    .map(|(name, value)| (name, value.map(JSValue::from), true));
+
+    // 2. For each property of the Global Object specified in clause 19, do
+    for (name, value, is_writable_and_configurable) in
+        value_properties.into_iter().chain(properties)
+    {
+        let Some(value) = value else {
+            continue;
+        };
+
+        // a. Let name be the String value of the property name.
+        // b. Let desc be the fully populated data Property Descriptor for the property, containing the specified attributes for the property. For properties listed in 19.2, 19.3, or 19.4 the value of the [[Value]] attribute is the corresponding intrinsic object from realm.
+        // NOTE: Every other data property described in clauses 19 through 28 and in Annex B.2 has the attributes { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true } unless otherwise specified.
+        let desc = JSObjectPropDescriptor {
+            value: Some(value),
+            writable: Some(is_writable_and_configurable),
+            enumerable: Some(false),
+            configurable: Some(is_writable_and_configurable),
+            ..JSObjectPropDescriptor::default()
+        };
+
+        // c. Perform ? DefinePropertyOrThrow(global, name, desc).
+        define_property_or_throw(&global, &JSObjectPropKey::String(name.into()), desc)?;
+    }
+
+    // 3. Return unused.
     Ok(())
 }
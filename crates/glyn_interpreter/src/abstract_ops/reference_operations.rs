@@ -1,13 +1,30 @@
 use crate::{
+    abstract_ops::{
+        execution_contexts::get_global_object,
+        object_operations::{private_get, private_set, set},
+        type_conversion::{to_object, to_property_key},
+    },
     runtime::{
+        agent::{reference_error, type_error, JSAgent},
         completion::CompletionRecord,
         environment::EnvironmentMethods,
-        reference::{Reference, ReferenceBase},
+        reference::{Reference, ReferenceBase, ReferenceName},
+    },
+    value::{
+        atom::Atom,
+        object::{property::JSObjectPropKey, ObjectEssentialInternalMethods},
     },
-    value::string::JSString,
     JSValue,
 };
 
+/// 6.2.5.1 IsPropertyReference ( V )
+/// https://262.ecma-international.org/16.0/#sec-ispropertyreference
+pub(crate) fn is_property_reference(value: &Reference) -> bool {
+    // 1. If V.[[Base]] is unresolvable, return false.
+    // 2. If V.[[Base]] is an Environment Record, return false; otherwise return true.
+    matches!(value.base, ReferenceBase::Value(_))
+}
+
 /// 6.2.5.2 IsUnresolvableReference ( V )
 /// https://262.ecma-international.org/16.0/#sec-isunresolvablereference
 fn is_unresolvable_reference(value: &Reference) -> bool {
@@ -15,9 +32,167 @@ fn is_unresolvable_reference(value: &Reference) -> bool {
     value.base == ReferenceBase::Unresolvable
 }
 
+/// 6.2.5.3 IsSuperReference ( V )
+/// https://262.ecma-international.org/16.0/#sec-issuperreference
+pub(crate) fn is_super_reference(value: &Reference) -> bool {
+    // 1. If V.[[ThisValue]] is not empty, return true; otherwise return false.
+    value.this_value.is_some()
+}
+
+/// 6.2.5.4 IsPrivateReference ( V )
+/// https://262.ecma-international.org/16.0/#sec-isprivatereference
+pub(crate) fn is_private_reference(value: &Reference) -> bool {
+    // 1. If V.[[ReferencedName]] is a Private Name, return true; otherwise return false.
+    matches!(value.referenced_name, ReferenceName::PrivateName(_))
+}
+
+/// 6.2.5.5 GetValue ( V )
+/// https://262.ecma-international.org/16.0/#sec-getvalue
+pub(crate) fn get_value(agent: &mut JSAgent, reference: Reference) -> CompletionRecord<JSValue> {
+    // 1. If V is not a Reference Record, return V.
+    // NOTE: Handled by the caller, as only Reference Records are passed in.
+
+    // 2. If IsUnresolvableReference(V) is true, throw a ReferenceError exception.
+    if is_unresolvable_reference(&reference) {
+        let name = Atom::try_from(&reference.referenced_name)?;
+
+        return reference_error(&format!("{name} is not defined"));
+    }
+
+    match &reference.base {
+        // 3. If IsPropertyReference(V) is true, then
+        ReferenceBase::Value(base) => {
+            // a. Let baseObj be ? ToObject(V.[[Base]]).
+            let base_obj = to_object(agent, base)?;
+
+            let key = match &reference.referenced_name {
+                // c. If V.[[ReferencedName]] is a Private Name, then
+                // i. Return ? PrivateGet(baseObj, V.[[ReferencedName]]).
+                ReferenceName::PrivateName(name) => return private_get(&base_obj, name),
+                // b. If V.[[ReferencedName]] is neither a String nor a Symbol, then
+                // i. Let referencedName be ? ToPropertyKey(V.[[ReferencedName]]).
+                // ii. Set V.[[ReferencedName]] to referencedName.
+                ReferenceName::Value(referenced_name) => {
+                    to_property_key(agent, referenced_name.clone())?
+                }
+                ReferenceName::Binding(name) => JSObjectPropKey::from(name.clone()),
+            };
+
+            // d. Return ? baseObj.[[Get]](V.[[ReferencedName]], GetThisValue(V)).
+            base_obj.get(agent, &key, &get_this_value(&reference))
+        }
+        // 4. Else,
+        ReferenceBase::Environment(env) => {
+            // a. Let base be V.[[Base]].
+            // b. Assert: base is an Environment Record.
+            // c. Return ? base.GetBindingValue(V.[[ReferencedName]], V.[[Strict]]).
+            env.get_binding_value(
+                agent,
+                Atom::try_from(&reference.referenced_name)?,
+                reference.strict,
+            )
+        }
+        ReferenceBase::Unresolvable => unreachable!(),
+    }
+}
+
+/// 6.2.5.6 PutValue ( V, W )
+/// https://262.ecma-international.org/16.0/#sec-putvalue
+pub(crate) fn put_value(
+    agent: &mut JSAgent,
+    reference: Reference,
+    value: JSValue,
+) -> CompletionRecord {
+    // 1. If V is not a Reference Record, throw a ReferenceError exception.
+    // NOTE: Handled by the parser, as only Reference Records are valid assignment targets.
+
+    match &reference.base {
+        // 2. If IsUnresolvableReference(V) is true, then
+        ReferenceBase::Unresolvable => {
+            let name = Atom::try_from(&reference.referenced_name)?;
+
+            // a. If V.[[Strict]] is true, throw a ReferenceError exception.
+            if reference.strict {
+                return reference_error(&format!("{name} is not defined"));
+            }
+
+            // b. Let globalObj be GetGlobalObject().
+            let global_obj = get_global_object(agent);
+
+            // c. Perform ? Set(globalObj, V.[[ReferencedName]], W, false).
+            set(
+                agent,
+                &global_obj,
+                &JSObjectPropKey::from(name),
+                value,
+                false,
+            )?;
+
+            // d. Return unused.
+            Ok(())
+        }
+        // 3. If IsPropertyReference(V) is true, then
+        ReferenceBase::Value(base) => {
+            // a. Let baseObj be ? ToObject(V.[[Base]]).
+            let base_obj = to_object(agent, base)?;
+
+            let key = match &reference.referenced_name {
+                // c. If V.[[ReferencedName]] is a Private Name, then
+                // i. Return ? PrivateSet(baseObj, V.[[ReferencedName]], W).
+                ReferenceName::PrivateName(name) => return private_set(&base_obj, name, value),
+                // b. If V.[[ReferencedName]] is neither a String nor a Symbol, then
+                // i. Let referencedName be ? ToPropertyKey(V.[[ReferencedName]]).
+                // ii. Set V.[[ReferencedName]] to referencedName.
+                ReferenceName::Value(referenced_name) => {
+                    to_property_key(agent, referenced_name.clone())?
+                }
+                ReferenceName::Binding(name) => JSObjectPropKey::from(name.clone()),
+            };
+
+            // d. Let succeeded be ? baseObj.[[Set]](V.[[ReferencedName]], W, GetThisValue(V)).
+            let succeeded = base_obj.set(agent, &key, value, get_this_value(&reference))?;
+
+            // e. If succeeded is false and V.[[Strict]] is true, throw a TypeError exception.
+            if !succeeded && reference.strict {
+                return type_error("Cannot assign to read only property");
+            }
+
+            // f. Return unused.
+            Ok(())
+        }
+        // 4. Else,
+        ReferenceBase::Environment(env) => {
+            // a. Let base be V.[[Base]].
+            // b. Assert: base is an Environment Record.
+            // c. Return ? base.SetMutableBinding(V.[[ReferencedName]], W, V.[[Strict]]).
+            env.clone().set_mutable_binding(
+                agent,
+                Atom::try_from(&reference.referenced_name)?,
+                value,
+                reference.strict,
+            )
+        }
+    }
+}
+
+/// 6.2.5.7 GetThisValue ( V )
+/// https://262.ecma-international.org/16.0/#sec-getthisvalue
+pub(crate) fn get_this_value(reference: &Reference) -> JSValue {
+    // 1. Assert: IsPropertyReference(V) is true.
+    debug_assert!(is_property_reference(reference));
+
+    // 2. If IsSuperReference(V) is true, return V.[[ThisValue]]; otherwise return V.[[Base]].
+    match (&reference.this_value, &reference.base) {
+        (Some(this_value), _) if is_super_reference(reference) => this_value.clone(),
+        (_, ReferenceBase::Value(base)) => base.clone(),
+        _ => JSValue::Undefined,
+    }
+}
+
 /// 6.2.5.8 InitializeReferencedBinding ( V, W )
 /// https://262.ecma-international.org/16.0/#sec-initializereferencedbinding
-pub(crate) fn initialize_referenced_binding<'a>(
+pub(crate) fn initialize_referenced_binding(
+    agent: &mut JSAgent,
     reference: Reference,
     value: JSValue,
 ) -> CompletionRecord {
@@ -35,5 +210,5 @@ pub(crate) fn initialize_referenced_binding<'a>(
     };
 
     // 4. Return ? base.InitializeBinding(V.[[ReferencedName]], W).
-    env_addr.initialize_binding(&JSString::try_from(&reference.referenced_name)?, value)
+    env_addr.initialize_binding(agent, Atom::try_from(&reference.referenced_name)?, value)
 }
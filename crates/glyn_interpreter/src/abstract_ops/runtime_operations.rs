@@ -1,16 +1,62 @@
+use std::mem::size_of;
+
 use crate::{
     abstract_ops::{
-        testing_comparison::same_type,
-        type_conversion::{to_numeric, to_primitive, to_string, PreferredPrimType},
+        object_operations::{call, get_method, ordinary_has_instance},
+        testing_comparison::{is_callable, same_type},
+        type_conversion::{to_boolean, to_numeric, to_primitive, to_string, PreferredPrimType},
     },
     lexer::Token,
-    runtime::{agent::type_error, completion::CompletionRecord},
-    value::{string::JSString, JSValue},
+    runtime::{
+        agent::{type_error, type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_HAS_INSTANCE},
+        completion::CompletionRecord,
+    },
+    value::{object::property::JSObjectPropKey, JSValue},
 };
 
+/// 13.10.2 InstanceofOperator ( V, target )
+/// https://262.ecma-international.org/16.0/#sec-instanceofoperator
+pub(crate) fn instanceof_operator(
+    agent: &mut JSAgent,
+    value: JSValue,
+    target: JSValue,
+) -> CompletionRecord<bool> {
+    // 1. If target is not an Object, throw a TypeError exception.
+    if !target.is_object() {
+        return type_error_for("Right-hand side of 'instanceof' is not an object", &target);
+    }
+
+    // 2. Let instOfHandler be ? GetMethod(target, %Symbol.hasInstance%).
+    let inst_of_handler = get_method(
+        agent,
+        &target,
+        &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_HAS_INSTANCE),
+    )?;
+
+    // 3. If instOfHandler is not undefined, then
+    if let Some(inst_of_handler) = inst_of_handler {
+        // a. Return ToBoolean(? Call(instOfHandler, target, « V »)).
+        return Ok(to_boolean(call(
+            agent,
+            inst_of_handler,
+            &target,
+            Some(vec![value]),
+        )?));
+    }
+
+    // 4. If IsCallable(target) is false, throw a TypeError exception.
+    if !is_callable(&target) {
+        return type_error_for("Right-hand side of 'instanceof' is not callable", &target);
+    }
+
+    // 5. Return ? OrdinaryHasInstance(target, V).
+    ordinary_has_instance(agent, &target, &value)
+}
+
 /// 13.15.3 ApplyStringOrNumericBinaryOperator ( lval, opText, rval )
 /// https://262.ecma-international.org/16.0/#sec-applystringornumericbinaryoperator
 pub(crate) fn apply_string_or_numeric_binary_operator(
+    agent: &mut JSAgent,
     lval: JSValue,
     rval: JSValue,
 ) -> CompletionRecord<JSValue> {
@@ -18,47 +64,54 @@ pub(crate) fn apply_string_or_numeric_binary_operator(
     // NOTE: Implemented in the VM.
 
     // a. Let lprim be ? ToPrimitive(lval).
-    let lprim = to_primitive(lval, PreferredPrimType::Default)?;
+    let lprim = to_primitive(agent, lval, PreferredPrimType::Default)?;
 
     // b. Let rprim be ? ToPrimitive(rval).
-    let rprim = to_primitive(rval, PreferredPrimType::Default)?;
+    let rprim = to_primitive(agent, rval, PreferredPrimType::Default)?;
 
     // c. If lprim is a String or rprim is a String, then
     if lprim.is_string() || rprim.is_string() {
         // i. Let lstr be ? ToString(lprim).
-        let lstr = to_string(lprim)?;
+        let lstr = to_string(agent, lprim)?;
 
         // ii. Let rstr be ? ToString(rprim).
-        let rstr = to_string(rprim)?;
+        let rstr = to_string(agent, rprim)?;
 
         // iii. Return the string-concatenation of lstr and rstr.
-        return Ok(JSValue::String(JSString::from(lstr.0 + &rstr.0)));
+        let result = lstr.try_concat(&rstr)?;
+
+        // NOTE: A long result is a rope, whose code units are only allocated once it is flattened, so the heap is checked for room for them now rather than when they are read.
+        agent.check_allocation(result.len() * size_of::<u16>())?;
+
+        return Ok(JSValue::String(result));
     }
 
     // d. Set lval to lprim.
     // e. Set rval to rprim.
-    apply_numeric_binary_operator(lprim, Token::Plus, rprim)
+    apply_numeric_binary_operator(agent, lprim, Token::Plus, rprim)
 }
 
 /// 13.15.3 ApplyStringOrNumericBinaryOperator ( lval, opText, rval )
 /// https://262.ecma-international.org/16.0/#sec-applystringornumericbinaryoperator
 pub(crate) fn apply_numeric_binary_operator(
+    agent: &mut JSAgent,
     lval: JSValue,
     op_text: Token,
     rval: JSValue,
 ) -> CompletionRecord<JSValue> {
     // 2. NOTE: At this point, it must be a numeric operation.
     // 3. Let lnum be ? ToNumeric(lval).
-    let lnum = to_numeric(lval)?;
+    let lnum = to_numeric(agent, lval)?;
 
     // 4. Let rnum be ? ToNumeric(rval).
-    let rnum = to_numeric(rval)?;
+    let rnum = to_numeric(agent, rval)?;
 
     // 5. If SameType(lNum, rNum) is false, throw a TypeError exception.
     if !same_type(&lnum, &rnum) {
-        type_error(&format!(
-            "Cannot use {:?} and {:?} in a binary expression",
-            lnum, rnum
+        return type_error(&format!(
+            "Cannot use {} and {} in a binary expression",
+            lnum.preview(),
+            rnum.preview()
         ));
     }
 
@@ -126,3 +179,72 @@ pub(crate) fn apply_numeric_binary_operator(
         Ok(JSValue::Number(op_result))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::{
+        abstract_ops::{
+            function_operations::create_builtin_function,
+            object_operations::define_property_or_throw, ordinary::ordinary_object_create,
+        },
+        runtime::native_function::Args,
+        value::object::property::JSObjectPropDescriptor,
+    };
+
+    #[test]
+    fn instanceof_calls_a_user_defined_has_instance_method() {
+        let mut agent = JSAgent::default();
+        agent.initialize_realm();
+
+        let key = JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_HAS_INSTANCE);
+
+        // The handler records the this value and argument it is called with, and answers whether the argument is a number.
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let handler_calls = calls.clone();
+        let handler = move |_: &mut JSAgent, args: Args| {
+            handler_calls
+                .borrow_mut()
+                .push((args.this().clone(), args.get(0).clone()));
+
+            Ok(JSValue::from(args.get(0).is_number()))
+        };
+        let handler = create_builtin_function(
+            &mut agent,
+            handler,
+            1,
+            key.clone(),
+            vec![],
+            None,
+            None,
+            None,
+        );
+
+        // The target is not callable, so instanceof can only succeed by calling the handler.
+        let target = ordinary_object_create(&mut agent, None, None);
+
+        define_property_or_throw(
+            &target,
+            &key,
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(handler)),
+                ..JSObjectPropDescriptor::default()
+            },
+        )
+        .unwrap();
+
+        let target = JSValue::from(target);
+
+        assert!(instanceof_operator(&mut agent, JSValue::from(1.0), target.clone()).unwrap());
+        assert!(!instanceof_operator(&mut agent, JSValue::from("a"), target.clone()).unwrap());
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                (target.clone(), JSValue::from(1.0)),
+                (target, JSValue::from("a")),
+            ]
+        );
+    }
+}
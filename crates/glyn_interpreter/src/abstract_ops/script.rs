@@ -1,32 +1,93 @@
-use std::ops::DerefMut;
+use std::{ops::DerefMut, rc::Rc};
 
 use crate::{
-    codegen::{bytecode::generator::ExecutableProgram, parser::Parser},
+    abstract_ops::function_definitions::instantiate_function_object,
+    codegen::{
+        bytecode::{generator::ExecutableProgram, optimizer::OptimizationLevel},
+        parser::{ModuleEntries, Parser, ProgramSource},
+        warning::Warning,
+    },
+    eval_script::EvalError,
     lexer::Lexer,
     runtime::{
-        agent::{syntax_error, JSAgent},
-        completion::CompletionRecord,
-        environment::{global_environment::GlobalEnvironment, EnvironmentAddr, EnvironmentMethods},
+        agent::{syntax_error, type_error, JSAgent},
+        completion::{CompletionRecord, ThrowCompletion},
+        environment::{global_environment::GlobalEnvironment, EnvironmentAddr},
         execution_context::{ExecutionContext, ScriptOrModule},
         realm::RealmAddr,
         script::ScriptRecord,
     },
-    value::{string::JSString, JSValue},
+    value::{atom::Atom, JSValue},
     vm::VM,
 };
 
 /// 11.1.6 Static Semantics: ParseText ( sourceText, goalSymbol )
 /// https://262.ecma-international.org/16.0/#sec-parsetext
-pub(crate) fn parse_text(source_text: &str) -> Result<ExecutableProgram, String> {
+///
+/// The import and export entries of the Parse Node are returned alongside it, which are empty unless the goal symbol is Module. Script code is strict mode code from its start if `strict` is true. The bytecode is optimized at the given level, and any warnings reported while parsing are appended to `warnings`, even if parsing fails.
+pub(crate) fn parse_text(
+    source_text: &str,
+    goal_symbol: ProgramSource,
+    strict: bool,
+    optimization_level: OptimizationLevel,
+    warnings: &mut Vec<Warning>,
+) -> Result<(ExecutableProgram, ModuleEntries), EvalError> {
     // 1. Attempt to parse sourceText using goalSymbol as the goal symbol, and analyse the parse result for any early error conditions. Parsing and early error detection may be interleaved in an implementation-defined manner.
-    let lexer = Lexer::new(source_text);
+    let mut lexer = Lexer::new(source_text);
+
+    if matches!(goal_symbol, ProgramSource::Module) {
+        lexer.set_module_goal();
+    }
+
     let mut parser = Parser::new(lexer);
 
-    // 2. If the parse succeeded and no early errors were found, return the Parse Node (an instance of goalSymbol) at the root of the parse tree resulting from the parse.
-    parser.js_parse_script().map_err(|e| e.to_string())?;
-    Ok(parser.program())
+    parser.set_strict(strict);
+    parser.set_optimization_level(optimization_level);
+
+    let result = match goal_symbol {
+        ProgramSource::Script => parser.js_parse_script(),
+        ProgramSource::Module => parser.js_parse_module(),
+    };
+
+    warnings.append(&mut parser.take_warnings());
+
+    let mut errors = parser
+        .lexer_diagnostics()
+        .iter()
+        .map(|diagnostic| (diagnostic.error.to_string(), diagnostic.span))
+        .collect::<Vec<_>>();
+
+    errors.extend(
+        parser
+            .take_errors()
+            .into_iter()
+            .map(|(error, span)| (error.to_string(), span)),
+    );
+
+    if let Err(error) = result {
+        let span = error.span().unwrap_or(parser.current_span());
+
+        errors.push((error.to_string(), span));
+    }
 
     // 3. Otherwise, return a List of one or more SyntaxError objects representing the parsing errors and/or early errors. If more than one parsing error or early error is present, the number and ordering of error objects in the list is implementation-defined, but at least one must be present.
+    // NOTE: The errors are ordered by where they were found in the source text, and the first of them holds the others.
+    if let Some(error) = EvalError::from_syntax_errors(errors) {
+        return Err(error);
+    }
+
+    // 2. If the parse succeeded and no early errors were found, return the Parse Node (an instance of goalSymbol) at the root of the parse tree resulting from the parse.
+    let module_entries = parser.take_module_entries();
+    let program = parser.program();
+
+    trace!(
+        Codegen,
+        Debug,
+        "compiled {goal_symbol}\n{}",
+        program.disassemble()
+    );
+
+    Ok((program, module_entries))
 }
 
 /// 16.1.5 ParseScript ( sourceText, realm, hostDefined )
@@ -35,15 +96,24 @@ pub(crate) fn parse_script(
     source_text: &str,
     realm_addr: RealmAddr,
     host_defined: Option<()>,
-) -> Result<ScriptRecord, String> {
+    strict: bool,
+    optimization_level: OptimizationLevel,
+    warnings: &mut Vec<Warning>,
+) -> Result<ScriptRecord, EvalError> {
     // 1. Let script be ParseText(sourceText, Script)
     // 2. If script is a List of errors, return script.
-    let script = parse_text(source_text)?;
+    let (script, _) = parse_text(
+        source_text,
+        ProgramSource::Script,
+        strict,
+        optimization_level,
+        warnings,
+    )?;
 
     // 3. Return Script Record { [[Realm]]: realm, [[ECMAScriptCode]]: script, [[LoadedModules]]: « », [[HostDefined]]: hostDefined }.
     Ok(ScriptRecord {
         realm: realm_addr,
-        ecmascript_code: script,
+        ecmascript_code: Rc::new(script),
         host_defined,
     })
 }
@@ -86,22 +156,152 @@ pub(crate) fn script_evaluation(
     let script = &script_record.ecmascript_code;
 
     // 12. Let result be Completion(GlobalDeclarationInstantiation(script, globalEnv)).
-    // NOTE: Handled in bytecode at runtime.
+    let result = global_declaration_instantiation(agent, script, global_env.unwrap());
 
     // 13. If result is a normal completion, then
     // a. Set result to Completion(Evaluation of script).
-    let opt_result = VM::new(agent, script).evaluate_script();
-
     // b. If result is a normal completion and result.[[Value]] is empty, then
-    let Ok(result) = opt_result else {
-        // i. Set result to NormalCompletion(undefined).
-        return Ok(JSValue::Undefined);
-    };
+    // i. Set result to NormalCompletion(undefined).
+    // NOTE: The VM produces undefined when the script leaves no value behind.
+    let result = result.and_then(|_| {
+        VM::new(agent, script)
+            .evaluate_script()
+            .map_err(ThrowCompletion::from)
+    });
 
     // 14. Suspend scriptContext and remove it from the execution context stack.
+    agent.pop_execution_context();
+
     // 15. Assert: The execution context stack is not empty.
     // 16. Resume the context that is now on the top of the execution context stack as the running execution context.
 
     // 17. Return ? result.
-    Ok(result)
+    result
+}
+
+/// 16.1.7 GlobalDeclarationInstantiation ( script, env )
+/// https://262.ecma-international.org/16.0/#sec-globaldeclarationinstantiation
+pub(crate) fn global_declaration_instantiation(
+    agent: &mut JSAgent,
+    script: &ExecutableProgram,
+    env: EnvironmentAddr,
+) -> CompletionRecord {
+    // 1. Let lexNames be the LexicallyDeclaredNames of script.
+    // 2. Let varNames be the VarDeclaredNames of script.
+    // 3. For each element name of lexNames, do
+    for name in &script.lexically_declared_names {
+        let mut env_ref = env.borrow_mut();
+        let global_env: &mut GlobalEnvironment = env_ref.deref_mut().try_into()?;
+
+        // a. If HasLexicalDeclaration(env, name) is true, throw a SyntaxError exception.
+        // b. Let hasRestrictedGlobal be ? HasRestrictedGlobalProperty(env, name).
+        // c. NOTE: Global var and function bindings (except those that are introduced by non-strict direct eval) are non-configurable and are therefore restricted global properties.
+        // d. If hasRestrictedGlobal is true, throw a SyntaxError exception.
+        if global_env.has_lexical_declaration(&name)
+            || global_env.has_restricted_global_property(name.clone())?
+        {
+            return syntax_error(&format!("Identifier '{name}' has already been declared"));
+        }
+    }
+    // NOTE: Lexical declarations are instantiated by the instructions at the start of the script's bytecode.
+
+    // 5. Let varDeclarations be the VarScopedDeclarations of script.
+    // 6. Let functionsToInitialize be a new empty List.
+    let mut functions_to_initialize = vec![];
+
+    // 7. Let declaredFunctionNames be a new empty List.
+    let mut declared_function_names: Vec<Atom> = vec![];
+
+    // 8. For each element d of varDeclarations, in reverse List order, do
+    for function_index in script.function_declarations.iter().rev() {
+        let function = script.functions[*function_index].clone();
+
+        // a. If d is not either a VariableDeclaration, a ForBinding, or a BindingIdentifier, then
+        // i. Assert: d is either a FunctionDeclaration, a GeneratorDeclaration, an AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration.
+        // ii. NOTE: If there are multiple function declarations for the same name, the last declaration is used.
+        // iii. Let fn be the sole element of the BoundNames of d.
+        let function_name = function.name.clone().unwrap();
+
+        // iv. If declaredFunctionNames does not contain fn, then
+        if declared_function_names.contains(&function_name) {
+            continue;
+        }
+
+        let mut env_ref = env.borrow_mut();
+        let global_env: &mut GlobalEnvironment = env_ref.deref_mut().try_into()?;
+
+        // 4. For each element name of varNames, do
+        // a. If env.HasLexicalDeclaration(name) is true, throw a SyntaxError exception.
+        if global_env.has_lexical_declaration(&function_name) {
+            return syntax_error(&format!(
+                "Identifier '{function_name}' has already been declared"
+            ));
+        }
+
+        // 1. Let fnDefinable be ? env.CanDeclareGlobalFunction(fn).
+        // 2. If fnDefinable is false, throw a TypeError exception.
+        if !global_env.can_declare_global_function(function_name.clone())? {
+            return type_error(&format!("Cannot declare global function '{function_name}'"));
+        }
+
+        // 3. Append fn to declaredFunctionNames.
+        declared_function_names.push(function_name);
+
+        // 4. Insert d as the first element of functionsToInitialize.
+        functions_to_initialize.insert(0, function);
+    }
+
+    // 9. Let declaredVarNames be a new empty List.
+    // 10. For each element d of varDeclarations, do
+    // NOTE: Var declarations are not supported yet.
+
+    // 13. NOTE: Annex B.3.2.2 adds additional steps at this point.
+    #[cfg(feature = "annex-b")]
+    if agent.annex_b() {
+        // B.3.2.2 Changes to GlobalDeclarationInstantiation
+        // a. Let strict be ScriptIsStrict of script.
+        // b. If strict is false, then
+        // iii. For each FunctionDeclaration f that is directly contained in the StatementList of a Block, CaseClause, or DefaultClause Contained within script, do
+        // NOTE: The parser only records the names of the function declarations which Annex B applies to.
+        for name in &script.var_scoped_block_function_names {
+            let mut env_ref = env.borrow_mut();
+            let global_env: &mut GlobalEnvironment = env_ref.deref_mut().try_into()?;
+
+            // 2. If env.HasLexicalDeclaration(F) is false, then
+            // a. Let fnDefinable be ? env.CanDeclareGlobalVar(F).
+            // b. If fnDefinable is true, then
+            // ii. If declaredFunctionOrVarNames does not contain F, then
+            if !global_env.has_lexical_declaration(&name)
+                && global_env.can_declare_global_var(name.clone())?
+                && !declared_function_names.contains(&name)
+            {
+                // 1. Perform ? env.CreateGlobalVarBinding(F, false).
+                global_env.create_global_var_binding(agent, name.clone(), false)?;
+            }
+        }
+    }
+
+    // 17. For each Parse Node f of functionsToInitialize, do
+    for function in functions_to_initialize {
+        // a. Let fn be the sole element of the BoundNames of f.
+        let function_name = function.name.clone().unwrap();
+
+        // b. Let fo be InstantiateFunctionObject of f with arguments env and privateEnv.
+        let function_obj = instantiate_function_object(agent, function, env.clone());
+
+        // c. Perform ? env.CreateGlobalFunctionBinding(fn, fo, false).
+        let mut env_ref = env.borrow_mut();
+        let global_env: &mut GlobalEnvironment = env_ref.deref_mut().try_into()?;
+
+        global_env.create_global_function_binding(
+            agent,
+            function_name,
+            JSValue::from(function_obj),
+            false,
+        )?;
+    }
+
+    // 18. For each String vn of declaredVarNames, do
+    // 19. Return unused.
+    Ok(())
 }
@@ -0,0 +1,95 @@
+use crate::{
+    abstract_ops::object_operations::{define_property_or_throw, make_basic_object},
+    runtime::agent::JSAgent,
+    value::{
+        object::{
+            internal_slots::InternalSlotName,
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectKind, ObjectMeta,
+        },
+        string::JSString,
+        JSValue,
+    },
+};
+
+// 10.4.3 String Exotic Objects
+// https://262.ecma-international.org/16.0/#sec-string-exotic-objects
+
+/// 10.4.3.4 StringCreate ( value, prototype )
+/// https://262.ecma-international.org/16.0/#sec-stringcreate
+pub(crate) fn string_create(
+    agent: &mut JSAgent,
+    value: JSString,
+    prototype: Option<ObjectAddr>,
+) -> ObjectAddr {
+    // 1. Let S be MakeBasicObject(« [[Prototype]], [[Extensible]], [[StringData]] »).
+    let s = make_basic_object(agent, vec![InternalSlotName::StringData]);
+
+    // 2. Set S.[[Prototype]] to prototype.
+    s.data_mut().set_prototype(prototype);
+
+    // 7. Let length be the length of value.
+    let length = value.len();
+
+    // 3. Set S.[[StringData]] to value.
+    s.data_mut().slots_mut().set_string_data(value);
+
+    // 4. Set S.[[GetOwnProperty]] as specified in 10.4.3.1.
+    // 5. Set S.[[DefineOwnProperty]] as specified in 10.4.3.2.
+    // 6. Set S.[[OwnPropertyKeys]] as specified in 10.4.3.3.
+    s.data_mut().set_kind(ObjectKind::String);
+
+    // 8. Perform ! DefinePropertyOrThrow(S, "length", PropertyDescriptor { [[Value]]: 𝔽(length), [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+    define_property_or_throw(
+        &s,
+        &JSObjectPropKey::String("length".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(length as f64)),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+    .unwrap();
+
+    // 9. Return S.
+    s
+}
+
+/// 10.4.3.5 StringGetOwnProperty ( S, P )
+/// https://262.ecma-international.org/16.0/#sec-stringgetownproperty
+pub(crate) fn string_get_own_property(
+    string_object: &impl ObjectMeta,
+    key: &JSObjectPropKey,
+) -> Option<JSObjectPropDescriptor> {
+    // 1. If P is not a String, return undefined.
+    // 2. Let index be CanonicalNumericIndexString(P).
+    // 3. If index is undefined, return undefined.
+    // 4. If IsIntegralNumber(index) is false, return undefined.
+    // 5. If index is -0𝔽 or index < -0𝔽, return undefined.
+    // NOTE: A String cannot be long enough to have an index which is not an array index, so only array indices are looked up.
+    let index = key.as_array_index()? as usize;
+
+    // 6. Let str be S.[[StringData]].
+    // 7. Assert: str is a String.
+    let str = string_object.data().slots().string_data().unwrap();
+
+    // 8. Let len be the length of str.
+    // 9. If ℝ(index) ≥ len, return undefined.
+    if index >= str.len() {
+        return None;
+    }
+
+    // 10. Let resultStr be the substring of str from ℝ(index) to ℝ(index) + 1.
+    let result_str = str.substring(index, index + 1);
+
+    // 11. Return the PropertyDescriptor { [[Value]]: resultStr, [[Writable]]: false, [[Enumerable]]: true, [[Configurable]]: false }.
+    Some(JSObjectPropDescriptor {
+        value: Some(JSValue::from(result_str)),
+        writable: Some(false),
+        enumerable: Some(true),
+        configurable: Some(false),
+        ..JSObjectPropDescriptor::default()
+    })
+}
@@ -1,10 +1,13 @@
 use crate::{
     abstract_ops::type_conversion::{to_number, to_numeric, to_primitive, PreferredPrimType},
-    runtime::{agent::type_error, completion::CompletionRecord},
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::CompletionRecord,
+    },
     value::{
         big_int::JSBigInt,
         number::JSNumber,
-        object::{ObjectAddr, ObjectMeta},
+        object::{ObjectAddr, ObjectKind, ObjectMeta},
         string::JSString,
     },
     JSValue,
@@ -18,12 +21,27 @@ use crate::{
 pub(crate) fn require_object_coercible(arg: JSValue) -> CompletionRecord<JSValue> {
     //  It throws an error if argument is a value that cannot be converted to an Object using ToObject (e.g. null or undefined).
     if arg.is_null() || arg.is_undefined() {
-        type_error("Cannot convert null or undefined to object");
+        return type_error("Cannot convert null or undefined to object");
     }
 
     Ok(arg)
 }
 
+/// 7.2.2 IsArray ( argument )
+/// https://262.ecma-international.org/16.0/#sec-isarray
+pub(crate) fn is_array(arg: &JSValue) -> bool {
+    // 1. If argument is not an Object, return false.
+    let Ok(object) = ObjectAddr::try_from(arg) else {
+        return false;
+    };
+
+    // 2. If argument is an Array exotic object, return true.
+    // 3. If argument is a Proxy exotic object, then
+    // NOTE: Proxy exotic objects are not implemented yet.
+    // 4. Return false.
+    object.kind() == ObjectKind::Array
+}
+
 /// 7.2.3 IsCallable ( argument )
 /// https://262.ecma-international.org/16.0/#sec-iscallable
 pub(crate) fn is_callable(arg: &JSValue) -> bool {
@@ -98,6 +116,24 @@ pub(crate) fn same_value(x: &JSValue, y: &JSValue) -> bool {
     same_value_non_number(x, y)
 }
 
+/// 7.2.10 SameValueZero ( x, y )
+/// https://262.ecma-international.org/16.0/#sec-samevaluezero
+pub(crate) fn same_value_zero(x: &JSValue, y: &JSValue) -> bool {
+    // 1. If SameType(x, y) is false, return false.
+    if !same_type(x, y) {
+        return false;
+    }
+
+    // 2. If x is a Number, then
+    if let JSValue::Number(x) = x {
+        // a. Return Number::sameValueZero(x, y).
+        return x.same_value_zero(&JSNumber::try_from(y).unwrap());
+    }
+
+    // 3. Return SameValueNonNumber(x, y).
+    same_value_non_number(x, y)
+}
+
 /// 7.2.11 SameValueNonNumber ( x, y )
 /// https://262.ecma-international.org/16.0/#sec-samevaluenonnumber
 fn same_value_non_number(x: &JSValue, y: &JSValue) -> bool {
@@ -133,6 +169,7 @@ fn same_value_non_number(x: &JSValue, y: &JSValue) -> bool {
 /// 7.2.12 IsLessThan ( x, y, LeftFirst )
 /// https://262.ecma-international.org/16.0/#sec-islessthan
 pub(crate) fn is_less_than(
+    agent: &mut JSAgent,
     x: JSValue,
     y: JSValue,
     left_first: bool,
@@ -143,39 +180,39 @@ pub(crate) fn is_less_than(
     // 1. If LeftFirst is true, then
     if left_first {
         // a. Let px be ? ToPrimitive(x, number).
-        px = to_primitive(x, PreferredPrimType::Number)?;
+        px = to_primitive(agent, x, PreferredPrimType::Number)?;
 
         // b. Let py be ? ToPrimitive(y, number).
-        py = to_primitive(y, PreferredPrimType::Number)?;
+        py = to_primitive(agent, y, PreferredPrimType::Number)?;
     }
     // 2. Else,
     else {
         // a. NOTE: The order of evaluation needs to be reversed to preserve left to right evaluation.
         // b. Let py be ? ToPrimitive(y, number).
-        py = to_primitive(y, PreferredPrimType::Number)?;
+        py = to_primitive(agent, y, PreferredPrimType::Number)?;
 
         // c. Let px be ? ToPrimitive(x, number).
-        px = to_primitive(x, PreferredPrimType::Number)?;
+        px = to_primitive(agent, x, PreferredPrimType::Number)?;
     }
 
     // 3. If px is a String and py is a String, then
     if let (Ok(px_str), Ok(py_str)) = (JSString::try_from(&px), JSString::try_from(&py)) {
         // a. Let lx be the length of px.
-        let lx = px_str.utf16_len();
+        let lx = px_str.len();
 
         // b. Let ly be the length of py.
-        let ly = py_str.utf16_len();
+        let ly = py_str.len();
 
-        let px_chars = px_str.0.chars().collect::<Vec<_>>();
-        let py_chars = py_str.0.chars().collect::<Vec<_>>();
+        let px_units = px_str.code_units();
+        let py_units = py_str.code_units();
 
         // c. For each integer i such that 0 ≤ i < min(lx, ly), in ascending order, do
         for i in 0..lx.min(ly) {
             // i. Let cx be the numeric value of the code unit at index i within px.
-            let cx = px_chars[i] as u32;
+            let cx = px_units[i];
 
             // ii. Let cy be the numeric value of the code unit at index i within py.
-            let cy = py_chars[i] as u32;
+            let cy = py_units[i];
 
             // iii. If cx < cy, return true.
             if cx < cy {
@@ -211,10 +248,10 @@ pub(crate) fn is_less_than(
 
         // c. NOTE: Because px and py are primitive values, evaluation order is not important.
         // d. Let nx be ? ToNumeric(px).
-        let nx = to_numeric(px)?;
+        let nx = to_numeric(agent, px)?;
 
         // e. Let ny be ? ToNumeric(py).
-        let ny = to_numeric(py)?;
+        let ny = to_numeric(agent, py)?;
 
         // f. If SameType(nx, ny) is true, then
         if same_type(&nx, &ny) {
@@ -260,7 +297,11 @@ pub(crate) fn is_less_than(
 
 /// 7.2.13 IsLooselyEqual ( x, y )
 /// https://262.ecma-international.org/16.0/#sec-islooselyequal
-pub(crate) fn is_loosely_equal(x: JSValue, y: JSValue) -> CompletionRecord<bool> {
+pub(crate) fn is_loosely_equal(
+    agent: &mut JSAgent,
+    x: JSValue,
+    y: JSValue,
+) -> CompletionRecord<bool> {
     // 1. If SameType(x, y) is true, then
     if same_type(&x, &y) {
         // a. Return IsStrictlyEqual(x, y).
@@ -285,16 +326,16 @@ pub(crate) fn is_loosely_equal(x: JSValue, y: JSValue) -> CompletionRecord<bool>
 
     // 5. If x is a Number and y is a String, return ! IsLooselyEqual(x, ! ToNumber(y)).
     if x.is_number() && y.is_string() {
-        let y_num = to_number(y)?.into();
+        let y_num = to_number(agent, y)?.into();
 
-        return is_loosely_equal(x, y_num);
+        return is_loosely_equal(agent, x, y_num);
     }
 
     // 6. If x is a String and y is a Number, return ! IsLooselyEqual(! ToNumber(x), y).
     if x.is_string() && y.is_number() {
-        let x_num = to_number(x)?.into();
+        let x_num = to_number(agent, x)?.into();
 
-        return is_loosely_equal(x_num, y);
+        return is_loosely_equal(agent, x_num, y);
     }
 
     // 7. If x is a BigInt and y is a String, then
@@ -307,35 +348,35 @@ pub(crate) fn is_loosely_equal(x: JSValue, y: JSValue) -> CompletionRecord<bool>
 
     // 8. If x is a String and y is a BigInt, return ! IsLooselyEqual(y, x).
     if x.is_string() && y.is_big_int() {
-        return is_loosely_equal(y, x);
+        return is_loosely_equal(agent, y, x);
     }
 
     // 9. If x is a Boolean, return ! IsLooselyEqual(! ToNumber(x), y).
     if x.is_boolean() {
-        let x_num = to_number(x)?.into();
+        let x_num = to_number(agent, x)?.into();
 
-        return is_loosely_equal(x_num, y);
+        return is_loosely_equal(agent, x_num, y);
     }
 
     // 10. If y is a Boolean, return ! IsLooselyEqual(x, ! ToNumber(y)).
     if y.is_boolean() {
-        let y_num = to_number(y)?.into();
+        let y_num = to_number(agent, y)?.into();
 
-        return is_loosely_equal(x, y_num);
+        return is_loosely_equal(agent, x, y_num);
     }
 
     // 11. If x is either a String, a Number, a BigInt, or a Symbol and y is an Object, return ! IsLooselyEqual(x, ? ToPrimitive(y)).
     if (x.is_string() || x.is_number() || x.is_big_int() || x.is_symbol()) && y.is_object() {
-        let y_prim = to_primitive(y, PreferredPrimType::Default)?;
+        let y_prim = to_primitive(agent, y, PreferredPrimType::Default)?;
 
-        return is_loosely_equal(x, y_prim);
+        return is_loosely_equal(agent, x, y_prim);
     }
 
     // 12. If x is an Object and y is either a String, a Number, a BigInt, or a Symbol, return ! IsLooselyEqual(? ToPrimitive(x), y).
     if x.is_object() && (y.is_string() || y.is_number() || y.is_big_int() || y.is_symbol()) {
-        let x_prim = to_primitive(x, PreferredPrimType::Default)?;
+        let x_prim = to_primitive(agent, x, PreferredPrimType::Default)?;
 
-        return is_loosely_equal(x_prim, y);
+        return is_loosely_equal(agent, x_prim, y);
     }
 
     // 13. If x is a BigInt and y is a Number, or if x is a Number and y is a BigInt, then
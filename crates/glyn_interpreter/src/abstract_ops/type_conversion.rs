@@ -1,12 +1,18 @@
 use std::cmp::min;
 
-use crate::abstract_ops::object_operations::get_method;
-use crate::runtime::agent::{range_error, type_error, WELL_KNOWN_SYMBOLS_TO_PRIMITIVE};
+use crate::abstract_ops::object_operations::{call, get, get_method};
+use crate::abstract_ops::ordinary::ordinary_object_create;
+use crate::abstract_ops::string_exotic_objects::string_create;
+use crate::abstract_ops::testing_comparison::is_callable;
+use crate::runtime::agent::{
+    range_error, type_error, type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_TO_PRIMITIVE,
+};
 use crate::runtime::completion::CompletionRecord;
 use crate::value::symbol::JSSymbol;
 use crate::value::{
+    atom::Atom,
     number::JSNumber,
-    object::{property::JSObjectPropKey, ObjectAddr},
+    object::{internal_slots::InternalSlotName, property::JSObjectPropKey, ObjectAddr, ObjectMeta},
     string::JSString,
     JSValue,
 };
@@ -23,6 +29,7 @@ pub(crate) enum PreferredPrimType {
 /// 7.1.1 ToPrimitive ( input [ , preferredType ] )
 /// https://262.ecma-international.org/16.0/#sec-toprimitive
 pub(crate) fn to_primitive(
+    agent: &mut JSAgent,
     input: JSValue,
     preferred_type: PreferredPrimType,
 ) -> CompletionRecord<JSValue> {
@@ -30,32 +37,43 @@ pub(crate) fn to_primitive(
 
     // 1. If input is an Object, then
     if let Ok(object) = ObjectAddr::try_from(&input) {
-        // a. Let exoticToPrim be ? GetMethod(input, @@toPrimitive).
+        // a. Let exoticToPrim be ? GetMethod(input, %Symbol.toPrimitive%).
         let exotic_to_prim = get_method(
+            agent,
             &input,
             &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_PRIMITIVE),
         )?;
 
         // b. If exoticToPrim is not undefined, then
         if let Some(exotic_to_prim) = exotic_to_prim {
-            preferred_type = match preferred_type {
+            let hint = match preferred_type {
                 // i. If preferredType is not present, then
                 // 1. Let hint be "default".
-                PreferredPrimType::Default => PreferredPrimType::Default,
+                PreferredPrimType::Default => "default",
                 // ii. Else if preferredType is string, then
                 // 1. Let hint be "string".
-                PreferredPrimType::String => PreferredPrimType::String,
+                PreferredPrimType::String => "string",
                 // iii. Else,
                 // 1. Assert: preferredType is number.
                 // 2. Let hint be "number".
-                PreferredPrimType::Number => PreferredPrimType::Number,
+                PreferredPrimType::Number => "number",
             };
 
-            todo!();
-
             // iv. Let result be ? Call(exoticToPrim, input, « hint »).
+            let result = call(
+                agent,
+                exotic_to_prim,
+                &input,
+                Some(vec![JSValue::from(hint.to_string())]),
+            )?;
+
             // v. If result is not an Object, return result.
-            // vi. Throw a TypeError exception
+            if !result.is_object() {
+                return Ok(result);
+            }
+
+            // vi. Throw a TypeError exception.
+            return type_error("Cannot convert object to primitive value");
         }
 
         // c. If preferredType is not present, let preferredType be number.
@@ -64,13 +82,50 @@ pub(crate) fn to_primitive(
         }
 
         // d. Return ? OrdinaryToPrimitive(input, preferredType).
-        todo!()
+        return ordinary_to_primitive(agent, &object, preferred_type);
     }
 
     // 2. Return input.
     Ok(input)
 }
 
+/// 7.1.1.1 OrdinaryToPrimitive ( O, hint )
+/// https://262.ecma-international.org/16.0/#sec-ordinarytoprimitive
+pub(crate) fn ordinary_to_primitive(
+    agent: &mut JSAgent,
+    object: &ObjectAddr,
+    hint: PreferredPrimType,
+) -> CompletionRecord<JSValue> {
+    let method_names = match hint {
+        // 1. If hint is string, then
+        // a. Let methodNames be « "toString", "valueOf" ».
+        PreferredPrimType::String => ["toString", "valueOf"],
+        // 2. Else,
+        // a. Let methodNames be « "valueOf", "toString" ».
+        _ => ["valueOf", "toString"],
+    };
+
+    // 3. For each element name of methodNames, do
+    for name in method_names {
+        // a. Let method be ? Get(O, name).
+        let method = get(agent, object, &JSObjectPropKey::String(name.into()))?;
+
+        // b. If IsCallable(method) is true, then
+        if is_callable(&method) {
+            // i. Let result be ? Call(method, O).
+            let result = call(agent, method, &JSValue::from(object.clone()), None)?;
+
+            // ii. If result is not an Object, return result.
+            if !result.is_object() {
+                return Ok(result);
+            }
+        }
+    }
+
+    // 4. Throw a TypeError exception.
+    type_error("Cannot convert object to primitive value")
+}
+
 /// 7.1.2 ToBoolean ( argument )
 /// https://262.ecma-international.org/16.0/#sec-toboolean
 pub(crate) fn to_boolean(arg: JSValue) -> bool {
@@ -97,9 +152,9 @@ pub(crate) fn to_boolean(arg: JSValue) -> bool {
 
 /// 7.1.3 ToNumeric ( value )
 /// https://262.ecma-international.org/16.0/#sec-tonumeric
-pub(crate) fn to_numeric(value: JSValue) -> CompletionRecord<JSValue> {
+pub(crate) fn to_numeric(agent: &mut JSAgent, value: JSValue) -> CompletionRecord<JSValue> {
     // 1. Let primValue be ? ToPrimitive(value, number).
-    let prim_value = to_primitive(value, PreferredPrimType::Number)?;
+    let prim_value = to_primitive(agent, value, PreferredPrimType::Number)?;
 
     // 2. If primValue is a BigInt, return primValue.
     if prim_value.is_big_int() {
@@ -107,18 +162,18 @@ pub(crate) fn to_numeric(value: JSValue) -> CompletionRecord<JSValue> {
     }
 
     // 3. Return ? ToNumber(primValue).
-    Ok(JSValue::Number(to_number(prim_value)?))
+    Ok(JSValue::Number(to_number(agent, prim_value)?))
 }
 
 /// 7.1.4 ToNumber ( argument )
 /// https://262.ecma-international.org/16.0/#sec-tonumber
-pub(crate) fn to_number(arg: JSValue) -> CompletionRecord<JSNumber> {
+pub(crate) fn to_number(agent: &mut JSAgent, arg: JSValue) -> CompletionRecord<JSNumber> {
     match arg {
         // 1. If argument is a Number, return argument.
         JSValue::Number(number) => return Ok(number.clone()),
         // 2. If argument is either a Symbol or a BigInt, throw a TypeError exception.
-        JSValue::Symbol(_) => type_error("Cannot convert Symbol to JSNumber"),
-        JSValue::BigInt(_) => type_error("Cannot convert BigInt to JSNumber"),
+        JSValue::Symbol(_) => return type_error_for("Cannot convert Symbol to JSNumber", &arg),
+        JSValue::BigInt(_) => return type_error("Cannot convert BigInt to JSNumber"),
         // 3. If argument is undefined, return NaN.
         JSValue::Undefined => return Ok(JSNumber::NAN),
         // 4. If argument is either null or false, return +0𝔽.
@@ -134,13 +189,13 @@ pub(crate) fn to_number(arg: JSValue) -> CompletionRecord<JSNumber> {
     debug_assert!(arg.is_object());
 
     // 8. Let primValue be ? ToPrimitive(argument, number).
-    let prim_value = to_primitive(arg, PreferredPrimType::Number)?;
+    let prim_value = to_primitive(agent, arg, PreferredPrimType::Number)?;
 
     // 9. Assert: primValue is not an Object.
     debug_assert!(!prim_value.is_object());
 
     // 10. Return ? ToNumber(primValue).
-    to_number(prim_value)
+    to_number(agent, prim_value)
 }
 
 /// 7.1.4.1.1 StringToNumber ( str )
@@ -148,21 +203,168 @@ pub(crate) fn to_number(arg: JSValue) -> CompletionRecord<JSNumber> {
 pub(crate) fn string_to_number(str: &JSString) -> JSNumber {
     // 1. Let text be StringToCodePoints(str).
     // 2. Let literal be ParseText(text, StringNumericLiteral).
-    // TODO Implement the below exactly.
-    let literal = str.0.parse::<f64>();
+    // StringNumericLiteral ::: StrWhiteSpace[opt] StrNumericLiteral StrWhiteSpace[opt]
+    let text = str.to_string();
+    let text = text.trim_matches(is_str_white_space_char);
+
+    // 7.1.4.1.2 Runtime Semantics: StringNumericValue
+    // StringNumericLiteral ::: StrWhiteSpace[opt]
+    // 1. Return +0𝔽.
+    if text.is_empty() {
+        return JSNumber::ZERO;
+    }
 
+    // StrNumericLiteral ::: NonDecimalIntegerLiteral
+    for (prefix, radix) in [
+        ("0b", 2),
+        ("0B", 2),
+        ("0o", 8),
+        ("0O", 8),
+        ("0x", 16),
+        ("0X", 16),
+    ] {
+        if let Some(digits) = text.strip_prefix(prefix) {
+            // 3. If literal is a List of errors, return NaN.
+            if digits.is_empty() {
+                return JSNumber::NAN;
+            }
+
+            // 4. Return StringNumericValue of literal.
+            return non_decimal_integer_value(digits, radix).map_or(JSNumber::NAN, JSNumber);
+        }
+    }
+
+    // StrNumericLiteral ::: StrDecimalLiteral
     // 3. If literal is a List of errors, return NaN.
-    let Ok(literal) = literal else {
-        return JSNumber::NAN;
+    match str_decimal_literal_prefix(text) {
+        // 4. Return StringNumericValue of literal.
+        Some(literal) if literal.len() == text.len() => str_decimal_literal_value(literal),
+        _ => JSNumber::NAN,
+    }
+}
+
+/// The value of the digits of a NonDecimalIntegerLiteral in a radix of 2, 8 or 16, rounded to the nearest Number with ties to even, if they are all digits of the radix.
+///
+/// NOTE: Accumulating the value digit by digit in a float would round it at each digit past the precision of a double, so the bits of the digits are rounded once instead.
+fn non_decimal_integer_value(digits: &str, radix: u32) -> Option<f64> {
+    let bits_per_digit = radix.trailing_zeros();
+
+    let mut bits = vec![];
+
+    for char in digits.chars() {
+        let digit = char.to_digit(radix)?;
+
+        bits.extend(
+            (0..bits_per_digit)
+                .rev()
+                .map(|shift| digit >> shift & 1 == 1),
+        );
+    }
+
+    let leading_zeros = bits.iter().take_while(|bit| !**bit).count();
+    let bits = &bits[leading_zeros..];
+
+    let to_integer = |bits: &[bool]| {
+        bits.iter()
+            .fold(0u64, |integer, bit| integer << 1 | u64::from(*bit))
     };
 
-    // 4. Return StringNumericValue of literal.
-    JSNumber::from(literal)
+    // Every integer of up to 53 bits is a Number.
+    if bits.len() <= 53 {
+        return Some(to_integer(bits) as f64);
+    }
+
+    let mut mantissa = to_integer(&bits[..53]);
+
+    let round = bits[53];
+    let sticky = bits[54..].iter().any(|bit| *bit);
+
+    if round && (sticky || mantissa & 1 == 1) {
+        mantissa += 1;
+    }
+
+    Some(mantissa as f64 * 2f64.powi((bits.len() - 53) as i32))
 }
+
+/// The longest prefix of the text which satisfies the syntax of a StrDecimalLiteral, if there is one.
+///
+/// StrDecimalLiteral ::: StrUnsignedDecimalLiteral, + StrUnsignedDecimalLiteral, - StrUnsignedDecimalLiteral
+/// StrUnsignedDecimalLiteral ::: Infinity, DecimalDigits . DecimalDigits[opt] ExponentPart[opt], . DecimalDigits ExponentPart[opt], DecimalDigits ExponentPart[opt]
+pub(crate) fn str_decimal_literal_prefix(text: &str) -> Option<&str> {
+    let bytes = text.as_bytes();
+    let digits_end = |start: usize| {
+        start
+            + bytes[start..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count()
+    };
+
+    let sign_len = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+
+    if text[sign_len..].starts_with("Infinity") {
+        return Some(&text[..sign_len + "Infinity".len()]);
+    }
+
+    let integer_end = digits_end(sign_len);
+    let has_decimal_point = bytes.get(integer_end) == Some(&b'.');
+    let mut end = if has_decimal_point {
+        digits_end(integer_end + 1)
+    } else {
+        integer_end
+    };
+
+    // There is at least one digit, either before or after the decimal point.
+    if end - sign_len - usize::from(has_decimal_point) == 0 {
+        return None;
+    }
+
+    // ExponentPart ::: ExponentIndicator SignedInteger
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let exponent_sign_len = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent_start = end + 1 + exponent_sign_len;
+        let exponent_end = digits_end(exponent_start);
+
+        if exponent_end > exponent_start {
+            end = exponent_end;
+        }
+    }
+
+    Some(&text[..end])
+}
+
+/// 7.1.4.1.2 Runtime Semantics: StringNumericValue
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-stringnumericvalue
+///
+/// The value of a string which satisfies the syntax of a StrDecimalLiteral.
+pub(crate) fn str_decimal_literal_value(literal: &str) -> JSNumber {
+    match literal.strip_prefix(['+', '-']).unwrap_or(literal) {
+        // StrUnsignedDecimalLiteral ::: Infinity
+        // 1. Return +∞𝔽.
+        "Infinity" if literal.starts_with('-') => JSNumber(f64::NEG_INFINITY),
+        "Infinity" => JSNumber(f64::INFINITY),
+        // NOTE: The float parser accepts every other form of StrDecimalLiteral, and rounds its value as RoundMVResult does.
+        _ => literal.parse::<f64>().map_or(JSNumber::NAN, JSNumber::from),
+    }
+}
+
+/// 7.1.4.1 ToNumber Applied to the String Type
+/// https://262.ecma-international.org/16.0/#prod-StrWhiteSpaceChar
+///
+/// StrWhiteSpaceChar ::: WhiteSpace LineTerminator
+pub(crate) fn is_str_white_space_char(char: char) -> bool {
+    // NOTE: The Unicode White_Space property matches WhiteSpace and LineTerminator, except that it includes U+0085 (NEXT LINE) and excludes U+FEFF (ZERO WIDTH NO-BREAK SPACE).
+    (char.is_whitespace() && char != '\u{85}') || char == '\u{FEFF}'
+}
+
+/// 7.1.5 ToIntegerOrInfinity ( argument )
 /// https://262.ecma-international.org/16.0/#sec-tointegerorinfinity
-pub(crate) fn to_integer_or_infinity(argument: JSValue) -> CompletionRecord<JSNumber> {
+pub(crate) fn to_integer_or_infinity(
+    agent: &mut JSAgent,
+    argument: JSValue,
+) -> CompletionRecord<JSNumber> {
     // 1. Let number be ? ToNumber(argument).
-    let number = to_number(argument)?;
+    let number = to_number(agent, argument)?;
 
     // 2. If number is one of NaN, +0𝔽, or -0𝔽, return 0.
     if number.is_nan() || number.is_zero() {
@@ -185,33 +387,33 @@ pub(crate) fn to_integer_or_infinity(argument: JSValue) -> CompletionRecord<JSNu
 
 /// 7.1.6 ToInt32 ( argument )
 /// https://262.ecma-international.org/16.0/#sec-toint32
-pub(crate) fn to_int32(argument: JSValue) -> CompletionRecord<JSNumber> {
+pub(crate) fn to_int32(agent: &mut JSAgent, argument: JSValue) -> CompletionRecord<JSNumber> {
     // 1. Let number be ? ToNumber(argument).
-    let number = to_number(argument)?;
+    let number = to_number(agent, argument)?;
 
     // 2. If number is not finite or number is either +0𝔽 or -0𝔽, return +0𝔽.
     // 3. Let int be truncate(ℝ(number)).
     // 4. Let int32bit be int modulo 2^32.
     // 5. If int32bit ≥ 2^31, return 𝔽(int32bit - 2^32); otherwise return 𝔽(int32bit).
-    Ok(JSNumber(number.0 as i32 as f64))
+    Ok(JSNumber(number.to_int32() as f64))
 }
 
 /// 7.1.7 ToUint32 ( argument )
 /// https://262.ecma-international.org/16.0/#sec-touint32
-pub(crate) fn to_uint32(argument: JSValue) -> CompletionRecord<JSNumber> {
+pub(crate) fn to_uint32(agent: &mut JSAgent, argument: JSValue) -> CompletionRecord<JSNumber> {
     // 1. Let number be ? ToNumber(argument).
-    let number = to_number(argument)?;
+    let number = to_number(agent, argument)?;
 
     // 2. If number is not finite or number is either +0𝔽 or -0𝔽, return +0𝔽.
     // 3. Let int be truncate(ℝ(number)).
     // 4. Let int32bit be int modulo 2^32.
     // 5. Return 𝔽(int32bit).
-    Ok(JSNumber(number.0 as u32 as f64))
+    Ok(JSNumber(number.to_uint32() as f64))
 }
 
 /// 7.1.17 ToString ( argument )
 /// https://262.ecma-international.org/16.0/#sec-tostring
-pub(crate) fn to_string(argument: JSValue) -> CompletionRecord<JSString> {
+pub(crate) fn to_string(agent: &mut JSAgent, argument: JSValue) -> CompletionRecord<JSString> {
     // 1. If argument is a String, return argument.
     if let Ok(string) = JSString::try_from(&argument) {
         return Ok(string);
@@ -219,7 +421,7 @@ pub(crate) fn to_string(argument: JSValue) -> CompletionRecord<JSString> {
 
     // 2. If argument is a Symbol, throw a TypeError exception.
     if argument.is_symbol() {
-        type_error("Cannot convert Symbol to string");
+        return type_error_for("Cannot convert Symbol to string", &argument);
     }
 
     // 3. If argument is undefined, return "undefined".
@@ -256,47 +458,110 @@ pub(crate) fn to_string(argument: JSValue) -> CompletionRecord<JSString> {
     debug_assert!(argument.is_object());
 
     // 10. Let primValue be ? ToPrimitive(argument, string).
-    let prim_value = to_primitive(argument, PreferredPrimType::String)?;
+    let prim_value = to_primitive(agent, argument, PreferredPrimType::String)?;
 
     // 11. Assert: primValue is not an Object.
     debug_assert!(!prim_value.is_object());
 
     // 12. Return ? ToString(primValue).
-    to_string(prim_value)
+    to_string(agent, prim_value)
 }
 
 /// 7.1.18 ToObject ( argument )
 /// https://262.ecma-international.org/16.0/#sec-toobject
-pub(crate) fn to_object(arg: &JSValue) -> ObjectAddr {
+pub(crate) fn to_object(agent: &mut JSAgent, arg: &JSValue) -> CompletionRecord<ObjectAddr> {
     match arg {
         JSValue::Undefined => {
             // Throw a TypeError exception.
-            type_error("Cannot convert undefined to object");
+            type_error("Cannot convert undefined to object")
         }
         JSValue::Null => {
             // Throw a TypeError exception.
-            type_error("Cannot convert null to object");
+            type_error("Cannot convert null to object")
         }
         // Return a new Boolean object whose [[BooleanData]] internal slot is set to argument.
-        JSValue::Bool(_value) => todo!(),
+        JSValue::Bool(value) => {
+            let boolean_prototype = agent
+                .current_realm()
+                .borrow()
+                .intrinsics
+                .boolean_prototype
+                .clone();
+
+            let boolean = ordinary_object_create(
+                agent,
+                boolean_prototype,
+                Some(vec![InternalSlotName::BooleanData]),
+            );
+
+            boolean.data_mut().slots_mut().set_boolean_data(*value);
+
+            Ok(boolean)
+        }
         // Return a new Number object whose [[NumberData]] internal slot is set to argument.
-        JSValue::Number(_value) => todo!(),
+        JSValue::Number(value) => {
+            let number_prototype = agent
+                .current_realm()
+                .borrow()
+                .intrinsics
+                .number_prototype
+                .clone();
+
+            let number = ordinary_object_create(
+                agent,
+                number_prototype,
+                Some(vec![InternalSlotName::NumberData]),
+            );
+
+            number.data_mut().slots_mut().set_number_data(value.clone());
+
+            Ok(number)
+        }
         // Return a new String object whose [[StringData]] internal slot is set to argument.
-        JSValue::String(_value) => todo!(),
+        JSValue::String(value) => {
+            let string_prototype = agent
+                .current_realm()
+                .borrow()
+                .intrinsics
+                .string_prototype
+                .clone();
+
+            Ok(string_create(agent, value.clone(), string_prototype))
+        }
         // Return a new Symbol object whose [[SymbolData]] internal slot is set to argument.
-        JSValue::Symbol(_) => todo!(),
+        JSValue::Symbol(value) => {
+            let symbol_prototype = agent
+                .current_realm()
+                .borrow()
+                .intrinsics
+                .symbol_prototype
+                .clone();
+
+            let symbol = ordinary_object_create(
+                agent,
+                symbol_prototype,
+                Some(vec![InternalSlotName::SymbolData]),
+            );
+
+            symbol.data_mut().slots_mut().set_symbol_data(value.clone());
+
+            Ok(symbol)
+        }
         // Return a new BigInt object whose [[BigIntData]] internal slot is set to argument.
         JSValue::BigInt(_value) => todo!(),
         // If argument is an Object, return argument.
-        JSValue::Object(addr) => addr.clone(),
+        JSValue::Object(addr) => Ok(addr.clone()),
     }
 }
 
 /// 7.1.19 ToPropertyKey ( argument )
 /// https://262.ecma-international.org/16.0/#sec-topropertykey
-pub(crate) fn to_property_key(argument: JSValue) -> CompletionRecord<JSObjectPropKey> {
+pub(crate) fn to_property_key(
+    agent: &mut JSAgent,
+    argument: JSValue,
+) -> CompletionRecord<JSObjectPropKey> {
     // 1. Let key be ? ToPrimitive(argument, string).
-    let key = to_primitive(argument, PreferredPrimType::String)?;
+    let key = to_primitive(agent, argument, PreferredPrimType::String)?;
 
     // 2. If key is a Symbol, then
     if let Ok(key) = JSSymbol::try_from(&key) {
@@ -305,14 +570,14 @@ pub(crate) fn to_property_key(argument: JSValue) -> CompletionRecord<JSObjectPro
     }
 
     // 3. Return ! ToString(key).
-    Ok(JSObjectPropKey::String(to_string(key)?))
+    Ok(JSObjectPropKey::String(Atom::from(to_string(agent, key)?)))
 }
 
 /// 7.1.20 ToLength ( argument )
 /// https://262.ecma-international.org/16.0/#sec-tolength
-pub(crate) fn to_length(argument: JSValue) -> CompletionRecord<JSNumber> {
+pub(crate) fn to_length(agent: &mut JSAgent, argument: JSValue) -> CompletionRecord<JSNumber> {
     // 1. Let len be ? ToIntegerOrInfinity(argument).
-    let len = to_integer_or_infinity(argument)?;
+    let len = to_integer_or_infinity(agent, argument)?;
 
     // 2. If len ≤ 0, return +0𝔽.
     if len.lt(&JSNumber::ZERO) {
@@ -327,19 +592,22 @@ pub(crate) fn to_length(argument: JSValue) -> CompletionRecord<JSNumber> {
 
 /// 7.1.21 CanonicalNumericIndexString ( argument )
 /// https://262.ecma-international.org/16.0/#sec-canonicalnumericindexstring
-pub(crate) fn canonical_numeric_index_string(argument: &JSString) -> Option<JSNumber> {
+pub(crate) fn canonical_numeric_index_string(
+    agent: &mut JSAgent,
+    argument: &JSString,
+) -> Option<JSNumber> {
     // 1. If argument is "-0", return -0𝔽.
-    if argument.0 == "-0" {
+    if *argument == "-0" {
         return Some(JSNumber::NEG_ZERO);
     }
 
     // 2. Let n be ! ToNumber(argument).
-    let Ok(n) = to_number(JSValue::from(argument.clone())) else {
+    let Ok(n) = to_number(agent, JSValue::from(argument.clone())) else {
         return None;
     };
 
     // 3. If ! ToString(n) is argument, return n.
-    let Ok(string) = to_string(JSValue::from(n.clone())) else {
+    let Ok(string) = to_string(agent, JSValue::from(n.clone())) else {
         return None;
     };
 
@@ -353,13 +621,13 @@ pub(crate) fn canonical_numeric_index_string(argument: &JSString) -> Option<JSNu
 
 /// 7.1.22 ToIndex ( value )
 /// https://262.ecma-international.org/16.0/#sec-toindex
-pub(crate) fn to_index(value: JSValue) -> CompletionRecord<JSNumber> {
+pub(crate) fn to_index(agent: &mut JSAgent, value: JSValue) -> CompletionRecord<JSNumber> {
     // 1. Let integer be ? ToIntegerOrInfinity(value).
-    let integer = to_integer_or_infinity(value)?;
+    let integer = to_integer_or_infinity(agent, value)?;
 
     // 2. If integer is not in the inclusive interval from 0 to 2^53 - 1, throw a RangeError exception.
     if integer < JSNumber::ZERO || integer > JSNumber::from(JSNumber::MAX_SAFE_INTEGER as f64) {
-        range_error("Index must be in the range 0 - 2^53-1");
+        return range_error("Index must be in the range 0 - 2^53-1");
     }
 
     // 3. Return integer.
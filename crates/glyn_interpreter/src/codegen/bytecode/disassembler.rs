@@ -0,0 +1,169 @@
+use std::{fmt::Write, ops::Range};
+
+use crate::{
+    abstract_ops::script::parse_text,
+    codegen::{
+        bytecode::{
+            generator::ExecutableProgram, instruction::Instruction, optimizer::OptimizationLevel,
+        },
+        parser::ProgramSource,
+    },
+    eval_script::EvalError,
+    lexer::SourcePosition,
+};
+
+/// A script compiled to bytecode, which can be inspected by tooling such as bytecode viewers without being evaluated.
+#[derive(Clone, Debug)]
+pub struct CompiledScript {
+    program: ExecutableProgram,
+}
+
+/// An instruction of a [`CompiledScript`], along with the values of its operands and the source text it was compiled from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedInstruction {
+    /// The byte offset of the instruction, which is the offset of its Wide prefix if it has one.
+    pub offset: usize,
+    /// The name of the instruction, like `Const` or `Jump`.
+    pub opcode: String,
+    /// The value of each operand, which is an index into the constants, identifiers or functions of the script, a count of arguments, or the offset of the target of a jump.
+    pub operands: Vec<u32>,
+    /// The range of byte offsets of the source text the instruction was compiled from, if it was compiled from a statement or declaration rather than from setting up the script.
+    pub source_span: Option<Range<usize>>,
+    /// The line and column at which the source text the instruction was compiled from starts.
+    pub source_position: Option<SourcePosition>,
+}
+
+impl CompiledScript {
+    /// Decodes the instructions of the script in order, merging each Wide prefix into the instruction it widens.
+    pub fn instructions(&self) -> impl Iterator<Item = DecodedInstruction> + '_ {
+        let mut wide_offset = None;
+
+        self.program
+            .decode()
+            .filter_map(move |(offset, instruction, operands)| {
+                if matches!(instruction, Instruction::Wide) {
+                    wide_offset = Some(offset);
+
+                    return None;
+                }
+
+                let wide = wide_offset.is_some();
+                let offset = wide_offset.take().unwrap_or(offset);
+
+                // NOTE: Operands which are widened or are jump targets take four bytes each, and every other operand takes a single byte.
+                let operands = if wide || instruction.operand_size(false) == 4 {
+                    operands
+                        .chunks(4)
+                        .map(|operand| Some(u32::from_le_bytes(operand.try_into().ok()?)))
+                        .collect::<Option<Vec<_>>>()?
+                } else {
+                    operands.iter().map(|operand| *operand as u32).collect()
+                };
+
+                let entry = self.program.span_entry(offset);
+
+                Some(DecodedInstruction {
+                    offset,
+                    opcode: instruction.to_string(),
+                    operands,
+                    source_span: entry.map(|entry| entry.span.start..entry.span.end),
+                    source_position: entry.map(|entry| entry.position),
+                })
+            })
+    }
+
+    /// The functions defined in the script, in the order of the indices which CreateClosure operands refer to, each along with its name if it has one.
+    pub fn functions(&self) -> impl Iterator<Item = (Option<String>, CompiledScript)> + '_ {
+        self.program.functions.iter().map(|function| {
+            (
+                function.name.clone().map(|name| name.to_string()),
+                CompiledScript {
+                    program: function.program.clone(),
+                },
+            )
+        })
+    }
+}
+
+/// Compiles a script to bytecode without evaluating it, optimizing the bytecode at the given level.
+pub fn compile_script(
+    source_text: &str,
+    optimization_level: OptimizationLevel,
+) -> Result<CompiledScript, EvalError> {
+    let (program, _) = parse_text(
+        source_text,
+        ProgramSource::Script,
+        false,
+        optimization_level,
+        &mut vec![],
+    )?;
+
+    Ok(CompiledScript { program })
+}
+
+/// Renders the compiled script as a human-readable listing, with one instruction per line followed by the constants and identifiers its operands index into, and then the listing of each function defined in it.
+pub fn disassemble(script: &CompiledScript) -> String {
+    script.program.disassemble()
+}
+
+impl ExecutableProgram {
+    /// Renders the program as a human-readable listing, with one instruction per line followed by the constant pool and identifier table its operands index into, and then the listing of each nested function. The instructions compiled from each statement or declaration are preceded by the line and column it starts at.
+    ///
+    /// ```text
+    /// ; 1:1
+    /// 0000  Const 0
+    /// 0002  ResolveBinding 0
+    /// constants: [Number(1.0)]
+    /// identifiers: ["x"]
+    /// ```
+    pub(crate) fn disassemble(&self) -> String {
+        let mut listing = String::new();
+        let mut spans = self.spans.iter().peekable();
+
+        for (offset, instruction, operands) in self.decode() {
+            // NOTE: Span entries which start at the same offset are of constructs which compiled to no instructions of their own, so only the innermost of them is shown.
+            let mut position = None;
+
+            while let Some(entry) = spans.next_if(|entry| entry.offset <= offset) {
+                position = Some(entry.position);
+            }
+
+            if let Some(position) = position {
+                let _ = writeln!(listing, "; {position}");
+            }
+
+            let _ = write!(listing, "{offset:04}  {instruction}");
+
+            for operand in operands {
+                let _ = write!(listing, " {operand}");
+            }
+
+            listing.push('\n');
+        }
+
+        let _ = writeln!(listing, "constants: {:?}", self.constants);
+        let _ = write!(
+            listing,
+            "identifiers: {:?}",
+            self.identifiers
+                .iter()
+                .map(|identifier| identifier.to_string())
+                .collect::<Vec<_>>()
+        );
+
+        for function in &self.functions {
+            let name = function
+                .name
+                .as_ref()
+                .map_or_else(|| String::from("anonymous"), |name| name.to_string());
+
+            let _ = write!(
+                listing,
+                "\n\nfunction {name}\n{}",
+                function.program.disassemble()
+            );
+        }
+
+        listing
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    abstract_ops::testing_comparison::{is_strictly_equal, same_type},
+    codegen::bytecode::instruction::Instruction,
+    value::JSValue,
+};
+
+/// Evaluates the unary operator on a constant at compile time, if doing so cannot run any code or throw.
+pub(crate) fn fold_unary(instruction: Instruction, operand: &JSValue) -> Option<JSValue> {
+    let JSValue::Number(number) = operand else {
+        return None;
+    };
+
+    match instruction {
+        // 13.5.4.1 Runtime Semantics: Evaluation
+        // https://262.ecma-international.org/16.0/#sec-unary-plus-operator-runtime-semantics-evaluation
+        Instruction::Plus => Some(operand.clone()),
+        // 13.5.5.1 Runtime Semantics: Evaluation
+        // https://262.ecma-international.org/16.0/#sec-unary-minus-operator-runtime-semantics-evaluation
+        Instruction::Minus => Some(JSValue::Number(number.unary_minus())),
+        _ => None,
+    }
+}
+
+/// Evaluates the binary operator on two constants at compile time, if doing so cannot run any code or throw.
+///
+/// NOTE: Only Numbers, and Strings joined to Strings or Numbers, are folded, as converting any other constant would need the conversions of the running agent.
+pub(crate) fn fold_binary(
+    instruction: Instruction,
+    lval: &JSValue,
+    rval: &JSValue,
+) -> Option<JSValue> {
+    match instruction {
+        Instruction::StrictEqual => Some(JSValue::from(is_strictly_equal(lval, rval))),
+        Instruction::StrictNotEqual => Some(JSValue::from(!is_strictly_equal(lval, rval))),
+        // NOTE: IsLooselyEqual is IsStrictlyEqual when both values are of the same type.
+        Instruction::Equal if same_type(lval, rval) => {
+            Some(JSValue::from(is_strictly_equal(lval, rval)))
+        }
+        Instruction::NotEqual if same_type(lval, rval) => {
+            Some(JSValue::from(!is_strictly_equal(lval, rval)))
+        }
+        Instruction::LessThan => is_less_than(lval, rval).map(|r| JSValue::from(r == Some(true))),
+        Instruction::GreaterThan => {
+            is_less_than(rval, lval).map(|r| JSValue::from(r == Some(true)))
+        }
+        Instruction::LessThanOrEqual => {
+            is_less_than(rval, lval).map(|r| JSValue::from(r == Some(false)))
+        }
+        Instruction::GreaterThanOrEqual => {
+            is_less_than(lval, rval).map(|r| JSValue::from(r == Some(false)))
+        }
+        Instruction::BinAdd => match (lval, rval) {
+            (JSValue::String(lstr), JSValue::String(rstr)) => {
+                Some(JSValue::String(lstr.concat(rstr)))
+            }
+            (JSValue::String(lstr), JSValue::Number(rnum)) => {
+                Some(JSValue::String(lstr.concat(&rnum.to_string(10))))
+            }
+            (JSValue::Number(lnum), JSValue::String(rstr)) => {
+                Some(JSValue::String(lnum.to_string(10).concat(rstr)))
+            }
+            (JSValue::Number(lnum), JSValue::Number(rnum)) => {
+                Some(JSValue::Number(lnum.clone().add(rnum.clone())))
+            }
+            _ => None,
+        },
+        _ => {
+            let (JSValue::Number(lnum), JSValue::Number(rnum)) = (lval, rval) else {
+                return None;
+            };
+
+            let (lnum, rnum) = (lnum.clone(), rnum.clone());
+
+            let result = match instruction {
+                Instruction::BinSubtract => lnum.subtract(rnum),
+                Instruction::BinMultiply => lnum.multiply(rnum),
+                Instruction::BinDivide => lnum.divide(rnum),
+                Instruction::BinModulo => lnum.remainder(rnum),
+                Instruction::BinExponent => lnum.exponentiate(&rnum),
+                Instruction::BitAnd => lnum.bitwise_and(rnum),
+                Instruction::BitOr => lnum.bitwise_or(rnum),
+                Instruction::BitXor => lnum.bitwise_xor(rnum),
+                Instruction::BitShiftLeft => lnum.left_shift(rnum),
+                Instruction::BitShiftRight => lnum.signed_right_shift(rnum),
+                Instruction::BitShiftRightUnsigned => lnum.unsigned_right_shift(rnum),
+                _ => return None,
+            };
+
+            Some(JSValue::Number(result))
+        }
+    }
+}
+
+/// 7.2.12 IsLessThan ( x, y, LeftFirst )
+/// https://262.ecma-international.org/16.0/#sec-islessthan
+///
+/// The result of IsLessThan for two Numbers or two Strings, for which ToPrimitive returns the value itself.
+fn is_less_than(x: &JSValue, y: &JSValue) -> Option<Option<bool>> {
+    match (x, y) {
+        (JSValue::Number(x), JSValue::Number(y)) => Some(x.less_than(y)),
+        (JSValue::String(x), JSValue::String(y)) => Some(Some(x < y)),
+        _ => None,
+    }
+}
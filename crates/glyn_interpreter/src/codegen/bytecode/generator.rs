@@ -1,21 +1,261 @@
+use std::rc::Rc;
+
 use crate::{
-    codegen::bytecode::instruction::Instruction,
-    value::{string::JSString, JSValue},
+    abstract_ops::testing_comparison::same_value,
+    codegen::bytecode::{
+        folding::{fold_binary, fold_unary},
+        instruction::Instruction,
+        optimizer::{optimize, OptimizationLevel},
+    },
+    lexer::{SourcePosition, Span},
+    value::{atom::Atom, string::JSString, JSValue},
 };
 
+/// Associates the instructions starting at `offset` with the source text they were compiled from, and with the line and column that source text starts at.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SpanEntry {
+    pub(crate) offset: usize,
+    pub(crate) span: Span,
+    pub(crate) position: SourcePosition,
+}
+
+/// The span entry of the innermost statement or declaration which the instruction at the offset was compiled from.
+///
+/// NOTE: Span entries are ordered by offset, so the last one starting at or before the instruction is that of the innermost construct it was compiled from.
+pub(crate) fn find_span_entry(spans: &[SpanEntry], offset: usize) -> Option<&SpanEntry> {
+    spans[..spans.partition_point(|entry| entry.offset <= offset)].last()
+}
+
 #[derive(Clone, Debug, Default)]
 pub(crate) struct ExecutableProgram {
     pub(crate) instructions: Vec<u8>,
     pub(crate) constants: Vec<JSValue>,
-    pub(crate) identifiers: Vec<JSString>,
+    pub(crate) identifiers: Vec<Atom>,
+    pub(crate) spans: Rc<[SpanEntry]>,
+    pub(crate) functions: Vec<Rc<ExecutableFunction>>,
+    /// Indices into `functions` of the function declarations which are instantiated before the program is evaluated.
+    pub(crate) function_declarations: Vec<usize>,
+    /// The LexicallyDeclaredNames of the program if it is a script, which must not already be declared in the global environment.
+    pub(crate) lexically_declared_names: Vec<Atom>,
+    /// Indices into `functions` of the function declarations of each block, in the order of the indices which InstantiateBlockFunctions operands refer to.
+    pub(crate) block_function_declarations: Vec<Vec<usize>>,
+    /// The names of the function declarations in blocks which Annex B also binds in the VariableEnvironment, initialized to undefined before the program is evaluated.
+    #[cfg(feature = "annex-b")]
+    pub(crate) var_scoped_block_function_names: Vec<Atom>,
+    /// The tagged templates of the program, in the order of the indices which GetTemplateObject operands refer to.
+    pub(crate) templates: Vec<Rc<TemplateSite>>,
+    /// Whether the program is strict mode code.
+    pub(crate) strict: bool,
+}
+
+impl ExecutableProgram {
+    /// The span entry of the innermost statement or declaration which the instruction at the offset was compiled from, if it was not compiled from setting up the program.
+    pub(crate) fn span_entry(&self, offset: usize) -> Option<&SpanEntry> {
+        find_span_entry(&self.spans, offset)
+    }
+
+    /// Decodes the instruction stream into the byte offset of each instruction along with the raw operand bytes which follow it. A Wide prefix is decoded as an instruction of its own, which widens the operand of the instruction after it.
+    pub(crate) fn decode(&self) -> impl Iterator<Item = (usize, Instruction, &[u8])> {
+        let mut offset = 0;
+        let mut wide = false;
+
+        std::iter::from_fn(move || {
+            let instruction = Instruction::from(*self.instructions.get(offset)?);
+
+            let operands_end =
+                (offset + 1 + instruction.operand_size(wide)).min(self.instructions.len());
+
+            wide = matches!(instruction, Instruction::Wide);
+
+            let decoded = (
+                offset,
+                instruction,
+                &self.instructions[offset + 1..operands_end],
+            );
+
+            offset = operands_end;
+
+            Some(decoded)
+        })
+    }
+}
+
+/// A function body compiled into its own program, along with the static semantics needed to instantiate it.
+#[derive(Debug, Default)]
+pub(crate) struct ExecutableFunction {
+    pub(crate) name: Option<Atom>,
+    pub(crate) formal_parameters: FormalParameters,
+    pub(crate) program: ExecutableProgram,
+    pub(crate) generator_kind: GeneratorKind,
+    /// Whether the function is an async function, whose body is evaluated by AsyncFunctionStart and may contain AwaitExpressions.
+    pub(crate) is_async: bool,
+    /// Whether the formal parameters or body of the function reference `arguments`, in which case FunctionDeclarationInstantiation creates an arguments object for it.
+    pub(crate) references_arguments: bool,
+}
+
+/// The strings of a TemplateLiteral which is the template of a tagged template.
+///
+/// NOTE: A site is shared through its Rc by every evaluation of the TemplateLiteral, as the Parse Node which the [[TemplateMap]] of a realm identifies it by.
+#[derive(Debug, Default)]
+pub(crate) struct TemplateSite {
+    /// 13.2.8.2 Static Semantics: TemplateStrings, with raw true.
+    pub(crate) raw: Vec<JSString>,
+    /// 13.2.8.2 Static Semantics: TemplateStrings, with raw false, where a string containing a NotEscapeSequence is undefined.
+    pub(crate) cooked: Vec<Option<JSString>>,
+}
+
+/// 15.1 Parameter Lists
+/// https://262.ecma-international.org/16.0/#prod-FormalParameters
+#[derive(Debug, Default)]
+pub(crate) struct FormalParameters {
+    pub(crate) parameters: Vec<FormalParameter>,
+    /// The BindingIdentifier of the FunctionRestParameter, if there is one.
+    pub(crate) rest: Option<Atom>,
+}
+
+/// A FormalParameter whose BindingElement is a SingleNameBinding, along with its Initializer compiled into its own program which returns the default value.
+#[derive(Debug)]
+pub(crate) struct FormalParameter {
+    pub(crate) name: Atom,
+    pub(crate) initializer: Option<ExecutableProgram>,
+}
+
+impl FormalParameters {
+    /// 8.2.1 Static Semantics: BoundNames
+    /// https://262.ecma-international.org/16.0/#sec-static-semantics-boundnames
+    pub(crate) fn bound_names(&self) -> Vec<Atom> {
+        self.parameters
+            .iter()
+            .map(|parameter| parameter.name.clone())
+            .chain(self.rest.clone())
+            .collect()
+    }
+
+    /// 15.1.2 Static Semantics: ContainsExpression
+    /// https://262.ecma-international.org/16.0/#sec-static-semantics-containsexpression
+    pub(crate) fn contains_expression(&self) -> bool {
+        self.parameters
+            .iter()
+            .any(|parameter| parameter.initializer.is_some())
+    }
+
+    /// 15.1.3 Static Semantics: IsSimpleParameterList
+    /// https://262.ecma-international.org/16.0/#sec-static-semantics-issimpleparameterlist
+    pub(crate) fn is_simple_parameter_list(&self) -> bool {
+        self.rest.is_none() && !self.contains_expression()
+    }
+
+    /// 15.1.5 Static Semantics: ExpectedArgumentCount
+    /// https://262.ecma-international.org/16.0/#sec-static-semantics-expectedargumentcount
+    pub(crate) fn expected_argument_count(&self) -> usize {
+        // NOTE: The count stops at the first parameter with an Initializer, and does not include a FunctionRestParameter.
+        self.parameters
+            .iter()
+            .take_while(|parameter| parameter.initializer.is_none())
+            .count()
+    }
+}
+
+/// 27.5.3.5 GetGeneratorKind ( )
+/// https://262.ecma-international.org/16.0/#sec-getgeneratorkind
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum GeneratorKind {
+    #[default]
+    NonGenerator,
+    Sync,
+}
+
+/// A constant pushed by one of the instructions at the end of the program, which an operator emitted straight after it may be folded into.
+#[derive(Debug)]
+struct FoldableConstant {
+    /// The offsets of the start and end of the instruction which pushes the constant.
+    offset: usize,
+    end: usize,
+    value: JSValue,
+    /// Whether the constant was added to the constants by the instruction, so that it can be removed along with it.
+    added: bool,
+}
+
+/// An identifier reference at the end of the program which resolves statically to a binding of a block, so that reading or assigning it can index that binding directly.
+#[derive(Debug)]
+struct ScopedReference {
+    /// The offsets of the start and end of the ResolveBinding instruction which pushes the Reference Record.
+    offset: usize,
+    end: usize,
+    /// The number of environments outwards from the running execution context's LexicalEnvironment which hold the binding.
+    depth: u32,
+    /// The position of the binding among the bindings of its environment, in the order they were created.
+    slot: u32,
+}
+
+/// A statement which break and continue statements can target, which is a labelled statement or an iteration statement.
+#[derive(Debug)]
+struct JumpTarget {
+    /// The label set of the statement, which is empty for an iteration statement without labels.
+    labels: Vec<Atom>,
+    /// The offset a continue statement targeting the statement jumps to, along with the number of exits it stays within, which only an iteration statement has.
+    continue_target: Option<(usize, usize)>,
+    /// The operands of the jumps of the break statements targeting the statement, which are patched to the end of the statement.
+    breaks: Vec<usize>,
+    /// The number of exits and environments when the statement was entered, which a break statement targeting it leaves.
+    exit_depth: usize,
+    environment_depth: usize,
+}
+
+/// Something a break or continue statement leaves at runtime on the way to the statement it targets.
+#[derive(Debug)]
+enum Exit {
+    /// The iterator of a for-in or for-of loop, which is closed.
+    Iterator,
+    /// The catch handler of a try statement whose block is being evaluated, which is popped.
+    CatchHandler,
+    /// The finally handler of a try statement whose block or catch clause is being evaluated, which is entered, along with the jumps which entered it.
+    FinallyHandler(Vec<PendingJump>),
+    /// The completion of a try statement whose finally clause is being evaluated, which is discarded, along with the jumps which entered the finally clause.
+    Completion(Vec<PendingJump>),
+}
+
+/// A break or continue statement which enters a finally clause, and which continues to the statement it targets once the finally clause completes.
+#[derive(Debug)]
+struct PendingJump {
+    /// The operand of the JumpThroughFinally instruction, which is patched to the code continuing the jump after the finally clause.
+    operand: usize,
+    /// The index of the statement the jump targets.
+    target: usize,
+    is_continue: bool,
+}
+
+/// The number of jump targets and exits, which are returned to when a statement fails to parse.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct JumpTargetDepth {
+    jump_targets: usize,
+    exits: usize,
 }
 
 #[derive(Debug, Default)]
 pub(crate) struct BytecodeGenerator {
     instructions: Vec<u8>,
     constants: Vec<JSValue>,
-    identifiers: Vec<JSString>,
-    scope_depth: u8,
+    identifiers: Vec<Atom>,
+    spans: Vec<SpanEntry>,
+    functions: Vec<Rc<ExecutableFunction>>,
+    function_declarations: Vec<usize>,
+    lexically_declared_names: Vec<Atom>,
+    block_function_declarations: Vec<Vec<usize>>,
+    #[cfg(feature = "annex-b")]
+    var_scoped_block_function_names: Vec<Atom>,
+    templates: Vec<Rc<TemplateSite>>,
+    strict: bool,
+    /// The constants pushed by the last run of instructions which push constants, which is broken by any other instruction or by a jump target.
+    foldable: Vec<FoldableConstant>,
+    /// The names of the bindings of each environment which the instructions generated so far have entered, innermost last, in the order they are created. An object environment has no names, as the properties of its object are only known at runtime.
+    environments: Vec<Option<Vec<Atom>>>,
+    /// The identifier reference which was the last instruction emitted, if it resolves statically.
+    scoped_reference: Option<ScopedReference>,
+    /// The statements enclosing the instructions generated next which break and continue statements can target, innermost last.
+    jump_targets: Vec<JumpTarget>,
+    /// The iterators, handlers and completions a jump out of the instructions generated next has to leave, innermost last.
+    exits: Vec<Exit>,
 }
 
 impl BytecodeGenerator {
@@ -24,56 +264,589 @@ impl BytecodeGenerator {
             instructions: self.instructions,
             constants: self.constants,
             identifiers: self.identifiers,
+            spans: self.spans.into(),
+            functions: self.functions,
+            function_declarations: self.function_declarations,
+            lexically_declared_names: self.lexically_declared_names,
+            block_function_declarations: self.block_function_declarations,
+            #[cfg(feature = "annex-b")]
+            var_scoped_block_function_names: self.var_scoped_block_function_names,
+            templates: self.templates,
+            strict: self.strict,
+        }
+    }
+
+    /// Optimizes the instructions generated so far at the given level, which must only be done once every jump has been patched.
+    pub(crate) fn optimize(&mut self, level: OptimizationLevel) {
+        if level == OptimizationLevel::None {
+            return;
         }
+
+        self.instructions = optimize(&self.instructions, &mut self.spans);
+
+        self.foldable.clear();
+    }
+
+    /// Opens a span entry for the instructions emitted from this point onwards, returning its index so the end of the span can be filled in once the source construct has been fully parsed.
+    pub(crate) fn begin_span(&mut self, start: usize, position: SourcePosition) -> usize {
+        self.foldable.clear();
+        self.scoped_reference = None;
+
+        self.spans.push(SpanEntry {
+            offset: self.instructions.len(),
+            span: Span::new(start, start),
+            position,
+        });
+
+        self.spans.len() - 1
+    }
+
+    pub(crate) fn end_span(&mut self, index: usize, end: usize) {
+        self.spans[index].span.end = end;
     }
 
     fn push(&mut self, instruction: u8) {
         self.instructions.push(instruction);
     }
 
-    pub(crate) fn add_identifier(&mut self, identifier: JSString) -> u8 {
+    /// Pushes the instruction along with its operand, preceded by a Wide prefix if the operand does not fit in a single byte.
+    fn push_with_operand(&mut self, instruction: Instruction, operand: u32) {
+        match u8::try_from(operand) {
+            Ok(operand) => {
+                self.push(instruction as u8);
+                self.push(operand);
+            }
+            Err(_) => {
+                self.push(Instruction::Wide as u8);
+                self.push(instruction as u8);
+                self.instructions.extend_from_slice(&operand.to_le_bytes());
+            }
+        }
+    }
+
+    /// Pushes the instruction along with its two operands, preceded by a Wide prefix if either operand does not fit in a single byte.
+    fn push_with_operands(&mut self, instruction: Instruction, operands: [u32; 2]) {
+        match operands.map(u8::try_from) {
+            [Ok(first), Ok(second)] => {
+                self.push(instruction as u8);
+                self.push(first);
+                self.push(second);
+            }
+            _ => {
+                self.push(Instruction::Wide as u8);
+                self.push(instruction as u8);
+
+                for operand in operands {
+                    self.instructions.extend_from_slice(&operand.to_le_bytes());
+                }
+            }
+        }
+    }
+
+    /// Adds the identifier to the program, reusing the index of an identical identifier if it has already been added.
+    pub(crate) fn add_identifier(&mut self, identifier: Atom) -> u32 {
+        if let Some(index) = self
+            .identifiers
+            .iter()
+            .position(|other| *other == identifier)
+        {
+            return index as u32;
+        }
+
         self.identifiers.push(identifier);
 
-        (self.identifiers.len() - 1) as u8
+        (self.identifiers.len() - 1) as u32
     }
 
-    pub(crate) fn add_constant(&mut self, constant: JSValue) {
+    /// Adds the constant to the program, reusing the index of an identical constant if it has already been added.
+    pub(crate) fn add_constant(&mut self, constant: JSValue) -> u32 {
+        // NOTE: Constants are compared with SameValue, so that +0 and -0 are kept apart.
+        if let Some(index) = self
+            .constants
+            .iter()
+            .position(|other| same_value(other, &constant))
+        {
+            return index as u32;
+        }
+
         self.constants.push(constant);
+
+        (self.constants.len() - 1) as u32
+    }
+
+    pub(crate) fn add_function(&mut self, function: ExecutableFunction) -> u32 {
+        self.functions.push(Rc::new(function));
+
+        (self.functions.len() - 1) as u32
     }
 
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    pub(crate) fn declare_function(&mut self, function_index: u32) {
+        self.function_declarations.push(function_index as usize);
+    }
+
+    pub(crate) fn set_lexically_declared_names(&mut self, names: Vec<Atom>) {
+        self.lexically_declared_names = names;
+    }
+
+    /// Emits an InstantiateBlockFunctions for a block whose function declarations are added once they have been parsed with [`BytecodeGenerator::declare_block_function`], returning the index of its function declarations.
+    pub(crate) fn emit_instantiate_block_functions(&mut self) -> u32 {
+        self.block_function_declarations.push(vec![]);
+
+        let block_index = (self.block_function_declarations.len() - 1) as u32;
+
+        self.push_with_operand(Instruction::InstantiateBlockFunctions, block_index);
+
+        block_index
+    }
+
+    pub(crate) fn declare_block_function(&mut self, block_index: u32, function_index: u32) {
+        self.block_function_declarations[block_index as usize].push(function_index as usize);
+    }
+
+    /// Emits a SetVariableBinding, which assigns the value on top of the stack to the binding of the function declaration in the VariableEnvironment, and records the name so that the binding is created before the program is evaluated.
+    #[cfg(feature = "annex-b")]
+    pub(crate) fn emit_set_variable_binding(&mut self, name: Atom) {
+        if !self.var_scoped_block_function_names.contains(&name) {
+            self.var_scoped_block_function_names.push(name.clone());
+        }
+
+        let binding_index = self.add_identifier(name);
+
+        self.push_with_operand(Instruction::SetVariableBinding, binding_index);
+    }
+
+    /// The BoundNames of the function declarations declared so far.
+    pub(crate) fn declared_function_names(&self) -> Vec<Atom> {
+        self.function_declarations
+            .iter()
+            .filter_map(|index| self.functions[*index].name.clone())
+            .collect()
+    }
+
+    /// Emits the instruction, or the constant it evaluates to if it is an operator whose operands are all constants.
     pub(crate) fn emit_instruction(&mut self, instruction: Instruction) {
+        if let Some(value) = self.fold(instruction) {
+            self.emit_constant(value);
+
+            return;
+        }
+
+        match instruction {
+            Instruction::PushDeclarativeEnvironment => self.environments.push(Some(vec![])),
+            Instruction::PushWithEnvironment => self.environments.push(None),
+            Instruction::PopEnvironment => {
+                self.environments.pop();
+            }
+            Instruction::PopExceptionHandler => {
+                self.exits.pop();
+            }
+            // NOTE: The jumps which entered the finally clause are continued once it completes, by BytecodeGenerator::emit_end_finally.
+            Instruction::EnterFinally => {
+                if let Some(Exit::FinallyHandler(pending)) = self.exits.pop() {
+                    self.exits.push(Exit::Completion(pending));
+                }
+            }
+            // NOTE: Reading an identifier reference which resolves statically indexes its binding directly, rather than resolving it by name.
+            Instruction::GetValue => {
+                if let Some((depth, slot)) = self.take_scoped_reference() {
+                    self.push_with_operands(Instruction::GetScopedLocal, [depth, slot]);
+
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        let offset = self.instructions.len();
+
         self.push(instruction as u8);
+
+        let value = match instruction {
+            Instruction::True => JSValue::from(true),
+            Instruction::False => JSValue::from(false),
+            Instruction::Null => JSValue::Null,
+            _ => return,
+        };
+
+        self.push_foldable(offset, value, false);
     }
 
     pub(crate) fn emit_constant(&mut self, value: JSValue) {
-        self.add_constant(value);
+        let offset = self.instructions.len();
+        let constants_length = self.constants.len();
+
+        let index = self.add_constant(value.clone());
+
+        self.push_with_operand(Instruction::Const, index);
+
+        self.push_foldable(offset, value, index as usize == constants_length);
+    }
+
+    /// Records the constant pushed by the instruction just emitted at the offset, starting a new run if any other instruction was emitted since the last one.
+    fn push_foldable(&mut self, offset: usize, value: JSValue, added: bool) {
+        if self
+            .foldable
+            .last()
+            .is_some_and(|constant| constant.end != offset)
+        {
+            self.foldable.clear();
+        }
+
+        self.foldable.push(FoldableConstant {
+            offset,
+            end: self.instructions.len(),
+            value,
+            added,
+        });
+    }
+
+    /// Evaluates the operator at compile time if its operands were pushed by the constants at the end of the program, removing the instructions which pushed them.
+    fn fold(&mut self, instruction: Instruction) -> Option<JSValue> {
+        let operand_count = match instruction {
+            Instruction::Plus | Instruction::Minus => 1,
+            _ => 2,
+        };
+
+        // NOTE: The operands can only be folded if the constants were the last instructions emitted.
+        if self
+            .foldable
+            .last()
+            .is_none_or(|constant| constant.end != self.instructions.len())
+        {
+            return None;
+        }
+
+        let start = self.foldable.len().checked_sub(operand_count)?;
+
+        let value = match &self.foldable[start..] {
+            [operand] => fold_unary(instruction, &operand.value),
+            [lval, rval] => fold_binary(instruction, &lval.value, &rval.value),
+            _ => None,
+        }?;
+
+        for constant in self.foldable.drain(start..).rev() {
+            self.instructions.truncate(constant.offset);
+
+            if constant.added {
+                self.constants.pop();
+            }
+        }
+
+        Some(value)
+    }
+
+    pub(crate) fn emit_resolve_binding(&mut self, identifier_index: u32) {
+        let offset = self.instructions.len();
 
-        self.push(Instruction::Const as u8);
+        self.push_with_operand(Instruction::ResolveBinding, identifier_index);
 
-        self.push(self.constants.len() as u8 - 1);
+        self.scoped_reference = self
+            .resolve_scoped_binding(&self.identifiers[identifier_index as usize])
+            .map(|(depth, slot)| ScopedReference {
+                offset,
+                end: self.instructions.len(),
+                depth,
+                slot,
+            });
     }
 
-    pub(crate) fn emit_resolve_binding(&mut self, identifier_index: u8) {
-        self.push(Instruction::ResolveBinding as u8);
+    /// The depth and slot of the binding the name resolves to, if it is a binding of a block which the instructions generated so far have entered.
+    ///
+    /// NOTE: The bindings of function, global and module environments are created at runtime, and direct eval may add var bindings to them, so a name which is not bound by an enclosing block is resolved by name. Direct eval can never add a binding to the environment of a block, as its var declarations are hoisted past them, and its lexical declarations are bound in an environment of its own.
+    fn resolve_scoped_binding(&self, name: &Atom) -> Option<(u32, u32)> {
+        for (depth, environment) in self.environments.iter().rev().enumerate() {
+            // NOTE: The binding may be a property of the object of a with statement, so it can only be resolved by name.
+            let names = environment.as_ref()?;
 
-        self.push(identifier_index);
+            if let Some(slot) = names.iter().position(|other| other == name) {
+                return Some((depth as u32, slot as u32));
+            }
+        }
+
+        None
+    }
+
+    /// Removes the identifier reference which was the last instruction emitted, if it resolves statically, returning the depth and slot of its binding so that it can be accessed directly instead.
+    pub(crate) fn take_scoped_reference(&mut self) -> Option<(u32, u32)> {
+        let reference = self
+            .scoped_reference
+            .take()
+            .filter(|reference| reference.end == self.instructions.len())?;
+
+        self.instructions.truncate(reference.offset);
+
+        Some((reference.depth, reference.slot))
+    }
+
+    /// Emits a SetScopedLocal, which assigns the value on top of the stack to the binding at the depth and slot taken by [`BytecodeGenerator::take_scoped_reference`].
+    pub(crate) fn emit_set_scoped_local(&mut self, depth: u32, slot: u32) {
+        self.push_with_operands(Instruction::SetScopedLocal, [depth, slot]);
     }
 
-    pub(crate) fn emit_create_mutable_binding(&mut self, binding_index: u8) {
-        self.push(Instruction::CreateMutableBinding as u8);
+    pub(crate) fn emit_create_mutable_binding(&mut self, binding_index: u32) {
+        self.declare_scoped_binding(binding_index);
+
+        self.push_with_operand(Instruction::CreateMutableBinding, binding_index);
+    }
 
-        self.push(binding_index);
+    pub(crate) fn emit_create_immutable_binding(&mut self, binding_index: u32) {
+        self.declare_scoped_binding(binding_index);
 
-        self.push(self.scope_depth);
+        self.push_with_operand(Instruction::CreateImmutableBinding, binding_index);
+    }
+
+    /// Records the binding as the next slot of the innermost block environment, as CreateMutableBinding and CreateImmutableBinding add it to the running execution context's LexicalEnvironment. Bindings created in a function, global or module environment are not recorded, as that environment is not entered by the instructions generated so far.
+    fn declare_scoped_binding(&mut self, binding_index: u32) {
+        if let Some(Some(names)) = self.environments.last_mut() {
+            names.push(self.identifiers[binding_index as usize].clone());
+        }
     }
 
     pub(crate) fn emit_initialize_referenced_binding(&mut self) {
         self.push(Instruction::InitializeReferencedBinding as u8);
     }
 
-    pub(crate) fn emit_call(&mut self, args_length: u8) {
-        self.push(Instruction::Call as u8);
+    pub(crate) fn emit_call(&mut self, args_length: u32) {
+        self.push_with_operand(Instruction::Call, args_length);
+    }
+
+    pub(crate) fn emit_new(&mut self, args_length: u32) {
+        self.push_with_operand(Instruction::New, args_length);
+    }
+
+    pub(crate) fn emit_collect_arguments(&mut self, args_length: u32) {
+        self.push_with_operand(Instruction::CollectArguments, args_length);
+    }
+
+    /// The offset at which the next instruction will be emitted, for use as the target of a jump.
+    pub(crate) fn current_offset(&mut self) -> usize {
+        self.foldable.clear();
+        self.scoped_reference = None;
+
+        self.instructions.len()
+    }
+
+    /// Emits a jump instruction whose target is not yet known, returning the position of its operand so that it can be filled in by [`BytecodeGenerator::patch_jump`].
+    pub(crate) fn emit_jump(&mut self, instruction: Instruction) -> usize {
+        match instruction {
+            Instruction::PushCatchHandler => self.exits.push(Exit::CatchHandler),
+            Instruction::PushFinallyHandler => self.exits.push(Exit::FinallyHandler(vec![])),
+            _ => {}
+        }
+
+        self.push(instruction as u8);
+
+        self.instructions.extend_from_slice(&[0; 4]);
+
+        self.instructions.len() - 4
+    }
+
+    /// Sets the target of the jump with the operand at the given position to the next instruction to be emitted.
+    pub(crate) fn patch_jump(&mut self, operand_position: usize) {
+        self.foldable.clear();
+
+        let target = (self.instructions.len() as u32).to_le_bytes();
+
+        self.instructions[operand_position..operand_position + 4].copy_from_slice(&target);
+    }
+
+    /// Emits a jump back to the start of a loop.
+    pub(crate) fn emit_loop(&mut self, loop_start: usize) {
+        self.push(Instruction::Jump as u8);
+
+        self.instructions
+            .extend_from_slice(&(loop_start as u32).to_le_bytes());
+    }
+
+    pub(crate) fn emit_create_closure(&mut self, function_index: u32) {
+        self.push_with_operand(Instruction::CreateClosure, function_index);
+    }
+
+    /// Emits an instruction which defines the function as an accessor of the property whose key is on top of the stack, like DefineGetter.
+    pub(crate) fn emit_define_accessor(&mut self, instruction: Instruction, function_index: u32) {
+        self.push_with_operand(instruction, function_index);
+    }
+
+    /// Emits a GetTemplateObject for a template whose strings are given once it has been fully parsed with [`BytecodeGenerator::define_template`], returning the index of its site.
+    pub(crate) fn emit_get_template_object(&mut self) -> u32 {
+        self.templates.push(Rc::default());
+
+        let template_index = (self.templates.len() - 1) as u32;
+
+        self.push_with_operand(Instruction::GetTemplateObject, template_index);
+
+        template_index
+    }
+
+    pub(crate) fn define_template(&mut self, template_index: u32, site: TemplateSite) {
+        self.templates[template_index as usize] = Rc::new(site);
+    }
+
+    pub(crate) fn function(&self, function_index: u32) -> &ExecutableFunction {
+        &self.functions[function_index as usize]
+    }
+
+    /// Emits an EndFinally, followed by the code which continues each break and continue statement that entered the finally clause once it completes normally.
+    pub(crate) fn emit_end_finally(&mut self) {
+        let pending = match self.exits.pop() {
+            Some(Exit::Completion(pending)) => pending,
+            _ => vec![],
+        };
+
+        self.emit_instruction(Instruction::EndFinally);
+
+        if pending.is_empty() {
+            return;
+        }
+
+        let end_jump = self.emit_jump(Instruction::Jump);
+
+        for jump in pending {
+            self.patch_jump(jump.operand);
+
+            self.emit_jump_to_target(jump.target, jump.is_continue);
+        }
+
+        self.patch_jump(end_jump);
+    }
+
+    /// The number of jump targets and exits enclosing the instructions generated next.
+    pub(crate) fn jump_target_depth(&self) -> JumpTargetDepth {
+        JumpTargetDepth {
+            jump_targets: self.jump_targets.len(),
+            exits: self.exits.len(),
+        }
+    }
+
+    /// Leaves the jump targets and exits entered since the depth, without patching the jumps which target them.
+    pub(crate) fn truncate_jump_targets(&mut self, depth: JumpTargetDepth) {
+        self.jump_targets.truncate(depth.jump_targets);
+        self.exits.truncate(depth.exits);
+    }
+
+    /// Whether the label is in the label set of a statement enclosing the instructions generated next.
+    pub(crate) fn has_label(&self, label: &Atom) -> bool {
+        self.jump_targets
+            .iter()
+            .any(|jump_target| jump_target.labels.contains(label))
+    }
+
+    /// The index of the innermost enclosing statement with the label, or of the innermost enclosing iteration statement if there is no label.
+    pub(crate) fn jump_target(&self, label: Option<&Atom>) -> Option<usize> {
+        self.jump_targets
+            .iter()
+            .rposition(|jump_target| match label {
+                Some(label) => jump_target.labels.contains(label),
+                None => jump_target.continue_target.is_some(),
+            })
+    }
+
+    /// Whether the statement at the index is an iteration statement, which continue statements can target.
+    pub(crate) fn is_iteration_target(&self, target: usize) -> bool {
+        self.jump_targets[target].continue_target.is_some()
+    }
 
-        self.push(args_length);
+    /// Enters a for-in or for-of loop with the label set whose iterator is on the stack, where the offset is the start of each iteration.
+    pub(crate) fn enter_iteration(&mut self, labels: Vec<Atom>, loop_start: usize) {
+        let exit_depth = self.exits.len();
+
+        self.exits.push(Exit::Iterator);
+
+        self.jump_targets.push(JumpTarget {
+            labels,
+            continue_target: Some((loop_start, exit_depth + 1)),
+            breaks: vec![],
+            exit_depth,
+            environment_depth: self.environments.len(),
+        });
+    }
+
+    /// Enters a labelled statement which is not an iteration statement, with the label set.
+    pub(crate) fn enter_labelled_statement(&mut self, labels: Vec<Atom>) {
+        self.jump_targets.push(JumpTarget {
+            labels,
+            continue_target: None,
+            breaks: vec![],
+            exit_depth: self.exits.len(),
+            environment_depth: self.environments.len(),
+        });
+    }
+
+    /// Leaves the innermost statement entered with [`BytecodeGenerator::enter_iteration`] or [`BytecodeGenerator::enter_labelled_statement`], patching the break statements which target it to the next instruction.
+    pub(crate) fn exit_jump_target(&mut self) {
+        let Some(jump_target) = self.jump_targets.pop() else {
+            return;
+        };
+
+        self.exits.truncate(jump_target.exit_depth);
+
+        for operand in jump_target.breaks {
+            self.patch_jump(operand);
+        }
+    }
+
+    /// Emits a break statement which targets the statement at the index.
+    pub(crate) fn emit_break(&mut self, target: usize) {
+        self.emit_jump_to_target(target, false);
+    }
+
+    /// Emits a continue statement which targets the iteration statement at the index.
+    pub(crate) fn emit_continue(&mut self, target: usize) {
+        self.emit_jump_to_target(target, true);
+    }
+
+    /// Emits the instructions which leave each exit between the instructions generated next and the statement at the index, followed by the jump to the end of the statement, or to the start of its next iteration if it is continued.
+    ///
+    /// NOTE: A jump which leaves the block or catch clause of a try statement with a finally clause enters the finally clause, and continues from the end of it.
+    fn emit_jump_to_target(&mut self, target: usize, is_continue: bool) {
+        let jump_target = &self.jump_targets[target];
+
+        let (loop_start, exit_depth) = match jump_target.continue_target {
+            Some((loop_start, exit_depth)) if is_continue => (Some(loop_start), exit_depth),
+            _ => (None, jump_target.exit_depth),
+        };
+
+        let environment_depth = jump_target.environment_depth;
+
+        for index in (exit_depth..self.exits.len()).rev() {
+            match self.exits[index] {
+                Exit::Iterator => self.push(Instruction::IteratorClose as u8),
+                Exit::CatchHandler => self.push(Instruction::PopExceptionHandler as u8),
+                Exit::Completion(_) => self.push(Instruction::PopCompletion as u8),
+                Exit::FinallyHandler(_) => {
+                    let operand = self.emit_jump(Instruction::JumpThroughFinally);
+
+                    let Exit::FinallyHandler(pending) = &mut self.exits[index] else {
+                        unreachable!()
+                    };
+
+                    pending.push(PendingJump {
+                        operand,
+                        target,
+                        is_continue,
+                    });
+
+                    return;
+                }
+            }
+        }
+
+        // NOTE: The environments are popped directly, as the instructions after the jump are still within them.
+        for _ in environment_depth..self.environments.len() {
+            self.push(Instruction::PopEnvironment as u8);
+        }
+
+        match loop_start {
+            Some(loop_start) => self.emit_loop(loop_start),
+            None => {
+                let operand = self.emit_jump(Instruction::Jump);
+
+                self.jump_targets[target].breaks.push(operand);
+            }
+        }
     }
 }
@@ -1,8 +1,12 @@
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 #[repr(u8)]
 pub(crate) enum Instruction {
+    AppendElement,
+    AppendElision,
+    ArraySpread,
+    Await,
     BinAdd,
     BinDivide,
     BinExponent,
@@ -16,37 +20,147 @@ pub(crate) enum Instruction {
     BitShiftRightUnsigned,
     BitXor,
     Call,
+    CollectArguments,
     Const,
+    CreateArray,
+    CreateClosure,
+    CreateForInIterator,
+    CreateImmutableBinding,
     CreateMutableBinding,
-    Decrement,
+    CreateObject,
+    DefineGetter,
+    DefineProperty,
+    DefineSetter,
+    Delete,
+    EndFinally,
+    EnterFinally,
     Equal,
     False,
-    GetLocal,
+    GetCallee,
+    GetIterator,
+    /// Pushes the value of the binding at the slot of the environment the depth outwards from the running execution context's LexicalEnvironment, which is an identifier reference the bytecode generator resolved statically, followed by GetValue.
+    GetScopedLocal,
+    /// Pushes the template object of the tagged template at the index of the program's templates, which is created once per realm.
+    GetTemplateObject,
+    GetValue,
     GreaterThan,
     GreaterThanOrEqual,
-    Halt,
-    Increment,
+    In,
     InitializeReferencedBinding,
+    InstanceOf,
+    /// Instantiates the function declarations of the block at the index of the program's block function declarations in the running execution context's LexicalEnvironment, initializing the bindings BlockDeclarationInstantiation created for them.
+    InstantiateBlockFunctions,
+    /// Removes the innermost iterator of a for-in or for-of loop from the stack, along with everything above it, closing it if it is the iterator of a for-of loop which is not done.
+    IteratorClose,
+    /// Collects the remaining values of the innermost iterator on the stack into a new array, which is the value of the rest element of an array binding pattern.
+    IteratorRest,
+    /// Pushes the next value of the innermost iterator on the stack, or undefined once it is done, which is the value of an element of an array binding pattern.
+    IteratorStepElement,
+    IteratorStepValue,
     Jump,
-    JumpIfFalse,
-    JumpIfTrue,
+    /// Jumps to the target if the value on top of the stack is not undefined, or pops it otherwise, so that the Initializer of a binding element is only evaluated for an undefined value.
+    JumpIfNotUndefined,
+    JumpIfNullish,
+    /// Enters the innermost finally handler with a completion which continues at the target once the finally clause completes normally, which is how a break or continue statement leaves a try statement.
+    JumpThroughFinally,
     LessThan,
     LessThanOrEqual,
     LogicalAnd,
     LogicalOr,
     Minus,
+    New,
+    NewTarget,
     Not,
     NotEqual,
     Null,
     Plus,
     Pop,
-    Print,
+    /// Removes the innermost completion of a try statement from the stack, along with everything above it, which is how a break or continue statement leaves a finally clause.
+    PopCompletion,
+    PopEnvironment,
+    PopExceptionHandler,
+    PropertyAccess,
+    PushCatchHandler,
+    PushDeclarativeEnvironment,
+    PushFinallyHandler,
+    PushWithEnvironment,
+    PutValue,
     ResolveBinding,
     Return,
+    /// Assigns the value on top of the stack to the binding at the slot of the environment the depth outwards from the running execution context's LexicalEnvironment, which is an assignment to an identifier reference the bytecode generator resolved statically, leaving the value on the stack.
+    SetScopedLocal,
+    SetPrototype,
+    /// Assigns the value on top of the stack to the binding of the identifier in the running execution context's VariableEnvironment, popping it, which is how Annex B makes a function declared in a block available outside of it.
+    #[cfg(feature = "annex-b")]
+    SetVariableBinding,
+    SpreadCall,
+    SpreadNew,
     StrictEqual,
     StrictNotEqual,
+    /// Replaces the property name on top of the stack with the Reference Record of that property of the prototype of the running method's [[HomeObject]], which is accessed with the method's this value.
+    SuperProperty,
+    Swap,
+    This,
+    Throw,
+    ToString,
     True,
+    TypeOf,
     Undefined,
+    Wide,
+    Yield,
+    YieldDelegate,
+}
+
+impl Instruction {
+    /// The number of bytes of operands which follow the opcode in the instruction stream.
+    ///
+    /// Jump targets are absolute offsets encoded as four little-endian bytes. Every other operand is a single byte, unless the instruction follows a [`Instruction::Wide`] prefix, which widens it to four little-endian bytes. [`Instruction::GetScopedLocal`] and [`Instruction::SetScopedLocal`] take two operands, the depth and the slot, which are widened together.
+    pub(crate) fn operand_size(&self, wide: bool) -> usize {
+        match self {
+            Instruction::CreateForInIterator
+            | Instruction::IteratorStepValue
+            | Instruction::Jump
+            | Instruction::JumpIfNotUndefined
+            | Instruction::JumpIfNullish
+            | Instruction::JumpThroughFinally
+            | Instruction::PushCatchHandler
+            | Instruction::PushFinallyHandler => 4,
+            Instruction::Call
+            | Instruction::CollectArguments
+            | Instruction::Const
+            | Instruction::CreateClosure
+            | Instruction::CreateImmutableBinding
+            | Instruction::CreateMutableBinding
+            | Instruction::DefineGetter
+            | Instruction::DefineSetter
+            | Instruction::GetTemplateObject
+            | Instruction::InstantiateBlockFunctions
+            | Instruction::New
+            | Instruction::ResolveBinding => {
+                if wide {
+                    4
+                } else {
+                    1
+                }
+            }
+            #[cfg(feature = "annex-b")]
+            Instruction::SetVariableBinding => {
+                if wide {
+                    4
+                } else {
+                    1
+                }
+            }
+            Instruction::GetScopedLocal | Instruction::SetScopedLocal => {
+                if wide {
+                    8
+                } else {
+                    2
+                }
+            }
+            _ => 0,
+        }
+    }
 }
 
 impl From<u8> for Instruction {
@@ -0,0 +1,186 @@
+use std::fmt::Write;
+
+use crate::{
+    abstract_ops::script::parse_text,
+    codegen::{
+        bytecode::{generator::ExecutableProgram, optimizer::OptimizationLevel},
+        parser::ProgramSource,
+    },
+    eval_script::EvalError,
+    value::JSValue,
+};
+
+/// The version of the JSON schema produced by [`ExecutableProgram::to_json`]. Bump this whenever a field is added, removed or changes meaning.
+pub(crate) const BYTECODE_JSON_SCHEMA_VERSION: u32 = 1;
+
+impl ExecutableProgram {
+    /// Serializes the program into a JSON document for external tooling such as visualizers and differs.
+    ///
+    /// ```json
+    /// {
+    ///   "version": 1,
+    ///   "instructions": [{ "offset": 0, "opcode": "Const", "operands": [0] }],
+    ///   "constants": [{ "type": "number", "value": 1 }],
+    ///   "identifiers": ["x"],
+    ///   "spans": [{ "offset": 0, "start": 0, "end": 5 }],
+    ///   "functions": []
+    /// }
+    /// ```
+    ///
+    /// - `instructions`: the decoded instruction stream. `offset` is the byte offset of the opcode and `operands` holds the raw operand bytes which follow it.
+    /// - `constants`: the constant pool, indexed by the `Const` operand. `type` is one of `undefined`, `null`, `boolean`, `string`, `number`, `bigint`, `symbol` or `object`. Non-finite numbers are encoded as the strings `"NaN"`, `"Infinity"` and `"-Infinity"`.
+    /// - `identifiers`: the identifier table, indexed by the binding operands.
+    /// - `spans`: the byte range of source text each run of instructions starting at `offset` was compiled from, ordered by `offset`.
+    /// - `functions`: the nested function units, each serialized with this same schema.
+    pub(crate) fn to_json(&self) -> String {
+        let mut json = String::new();
+
+        let _ = write!(json, "{{\"version\":{BYTECODE_JSON_SCHEMA_VERSION}");
+
+        json.push_str(",\"instructions\":[");
+
+        for (index, (offset, instruction, operands)) in self.decode().enumerate() {
+            let operands = operands
+                .iter()
+                .map(|operand| operand.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            if index > 0 {
+                json.push(',');
+            }
+
+            let _ = write!(
+                json,
+                "{{\"offset\":{offset},\"opcode\":\"{instruction}\",\"operands\":[{operands}]}}"
+            );
+        }
+
+        json.push_str("],\"constants\":[");
+
+        for (index, constant) in self.constants.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+
+            write_constant(&mut json, constant);
+        }
+
+        json.push_str("],\"identifiers\":[");
+
+        for (index, identifier) in self.identifiers.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+
+            write_string(&mut json, &identifier.to_string());
+        }
+
+        json.push_str("],\"spans\":[");
+
+        for (index, entry) in self.spans.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+
+            let _ = write!(
+                json,
+                "{{\"offset\":{},\"start\":{},\"end\":{}}}",
+                entry.offset, entry.span.start, entry.span.end
+            );
+        }
+
+        json.push_str("],\"functions\":[");
+
+        for (index, function) in self.functions.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+
+            json.push_str(&function.program.to_json());
+        }
+
+        json.push_str("]}");
+
+        json
+    }
+}
+
+fn write_constant(json: &mut String, constant: &JSValue) {
+    match constant {
+        JSValue::Undefined => json.push_str("{\"type\":\"undefined\"}"),
+        JSValue::Null => json.push_str("{\"type\":\"null\"}"),
+        JSValue::Bool(value) => {
+            let _ = write!(json, "{{\"type\":\"boolean\",\"value\":{value}}}");
+        }
+        JSValue::String(value) => {
+            json.push_str("{\"type\":\"string\",\"value\":");
+
+            write_string(json, &value.to_string());
+
+            json.push('}');
+        }
+        JSValue::Number(value) => {
+            json.push_str("{\"type\":\"number\",\"value\":");
+
+            if value.0.is_nan() {
+                json.push_str("\"NaN\"");
+            } else if value.0.is_infinite() {
+                json.push_str(if value.0 > 0.0 {
+                    "\"Infinity\""
+                } else {
+                    "\"-Infinity\""
+                });
+            } else {
+                let _ = write!(json, "{}", value.0);
+            }
+
+            json.push('}');
+        }
+        JSValue::BigInt(_) => json.push_str("{\"type\":\"bigint\"}"),
+        JSValue::Symbol(symbol) => {
+            json.push_str("{\"type\":\"symbol\",\"description\":");
+
+            match &symbol.description {
+                Some(description) => write_string(json, description),
+                None => json.push_str("null"),
+            }
+
+            json.push('}');
+        }
+        JSValue::Object(_) => json.push_str("{\"type\":\"object\"}"),
+    }
+}
+
+fn write_string(json: &mut String, value: &str) {
+    json.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", ch as u32);
+            }
+            ch => json.push(ch),
+        }
+    }
+
+    json.push('"');
+}
+
+/// Compiles a script and returns its bytecode as JSON, using the schema documented on [`ExecutableProgram::to_json`].
+pub fn script_to_bytecode_json(source_text: &str) -> Result<String, EvalError> {
+    let (program, _) = parse_text(
+        source_text,
+        ProgramSource::Script,
+        false,
+        OptimizationLevel::None,
+        &mut vec![],
+    )?;
+
+    Ok(program.to_json())
+}
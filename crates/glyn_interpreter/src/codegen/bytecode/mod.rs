@@ -1,2 +1,6 @@
+pub(crate) mod disassembler;
+pub(crate) mod folding;
 pub(crate) mod generator;
 pub(crate) mod instruction;
+pub(crate) mod json;
+pub(crate) mod optimizer;
@@ -0,0 +1,303 @@
+use crate::codegen::bytecode::{generator::SpanEntry, instruction::Instruction};
+
+/// How much the bytecode of a program is optimized once it has been generated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// The bytecode is run as it was generated.
+    #[default]
+    None,
+    /// The bytecode is rewritten by peephole optimizations, which remove values pushed only to be popped, thread jumps to jumps straight to their final target, and strip instructions which can never be reached.
+    Peephole,
+}
+
+/// An instruction decoded from the instruction stream, along with its operand.
+struct Op {
+    instruction: Instruction,
+    /// Whether the instruction follows a Wide prefix.
+    wide: bool,
+    /// The operand bytes of an instruction which does not jump.
+    operands: Vec<u8>,
+    /// The index of the op which an instruction that jumps targets, which is the number of ops if it targets the end of the program.
+    target: Option<usize>,
+    removed: bool,
+}
+
+impl Op {
+    /// Whether execution never continues to the next instruction after this one.
+    fn is_terminal(&self) -> bool {
+        matches!(
+            self.instruction,
+            Instruction::Jump
+                | Instruction::JumpThroughFinally
+                | Instruction::Return
+                | Instruction::Throw
+        )
+    }
+
+    /// Whether the instruction only pushes a value onto the stack, which cannot run any code or throw.
+    fn is_pure_push(&self) -> bool {
+        matches!(
+            self.instruction,
+            Instruction::Const
+                | Instruction::True
+                | Instruction::False
+                | Instruction::Null
+                | Instruction::Undefined
+        )
+    }
+}
+
+/// Runs the peephole optimizations over the instructions until none of them applies, moving the offsets of the span entries along with the instructions they point to.
+///
+/// NOTE: Comparisons are not fused with conditional jumps, as no conditional jump tests the result of a comparison. The parser has no conditional operator, `??` or if statement yet, so there is no instruction which jumps on a boolean, and the only conditional jumps test for nullish values, undefined values and finished iterators.
+pub(crate) fn optimize(instructions: &[u8], spans: &mut [SpanEntry]) -> Vec<u8> {
+    let (mut ops, offsets) = decode(instructions);
+
+    while thread_jumps(&mut ops)
+        | remove_jumps_to_next(&mut ops)
+        | remove_push_pop_pairs(&mut ops)
+        | remove_unreachable(&mut ops)
+    {}
+
+    let (optimized, new_offsets) = encode(&ops);
+
+    for entry in spans.iter_mut() {
+        let index = offsets.partition_point(|&offset| offset < entry.offset);
+
+        entry.offset = new_offsets[index];
+    }
+
+    optimized
+}
+
+/// Decodes the instructions into ops, along with the byte offset at which each of them starts.
+fn decode(instructions: &[u8]) -> (Vec<Op>, Vec<usize>) {
+    let mut ops = vec![];
+    let mut offsets = vec![];
+    let mut targets = vec![];
+    let mut offset = 0;
+
+    while offset < instructions.len() {
+        let start = offset;
+        let mut instruction = Instruction::from(instructions[offset]);
+        let wide = matches!(instruction, Instruction::Wide);
+
+        if wide {
+            offset += 1;
+            instruction = Instruction::from(instructions[offset]);
+        }
+
+        let size = instruction.operand_size(wide);
+        let operands = instructions[offset + 1..offset + 1 + size].to_vec();
+
+        offset += 1 + size;
+
+        let target = is_jump(instruction).then(|| {
+            targets.push(ops.len());
+
+            u32::from_le_bytes(operands[..4].try_into().unwrap()) as usize
+        });
+
+        offsets.push(start);
+        ops.push(Op {
+            instruction,
+            wide,
+            operands,
+            target,
+            removed: false,
+        });
+    }
+
+    // NOTE: Targets are converted from byte offsets to indices once every op is decoded, as a jump may target an instruction after it.
+    for index in targets {
+        let target = ops[index].target.unwrap();
+
+        ops[index].target = Some(offsets.partition_point(|&offset| offset < target));
+    }
+
+    (ops, offsets)
+}
+
+/// Encodes the ops which have not been removed, along with the new offset of each op, which is the offset of the next op kept if it was removed.
+fn encode(ops: &[Op]) -> (Vec<u8>, Vec<usize>) {
+    let mut new_offsets = Vec::with_capacity(ops.len() + 1);
+    let mut offset = 0;
+
+    for op in ops {
+        new_offsets.push(offset);
+
+        if !op.removed {
+            offset += usize::from(op.wide) + 1 + op.instruction.operand_size(op.wide);
+        }
+    }
+
+    new_offsets.push(offset);
+
+    let mut instructions = Vec::with_capacity(offset);
+
+    for op in ops.iter().filter(|op| !op.removed) {
+        if op.wide {
+            instructions.push(Instruction::Wide as u8);
+        }
+
+        instructions.push(op.instruction as u8);
+
+        match op.target {
+            Some(target) => {
+                instructions.extend_from_slice(&(new_offsets[target] as u32).to_le_bytes())
+            }
+            None => instructions.extend_from_slice(&op.operands),
+        }
+    }
+
+    (instructions, new_offsets)
+}
+
+fn is_jump(instruction: Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::CreateForInIterator
+            | Instruction::IteratorStepValue
+            | Instruction::Jump
+            | Instruction::JumpIfNotUndefined
+            | Instruction::JumpIfNullish
+            | Instruction::JumpThroughFinally
+            | Instruction::PushCatchHandler
+            | Instruction::PushFinallyHandler
+    )
+}
+
+/// The index of the first op at or after the index which has not been removed, which is where execution continues at the index.
+fn resolve(ops: &[Op], index: usize) -> usize {
+    (index..ops.len())
+        .find(|&index| !ops[index].removed)
+        .unwrap_or(ops.len())
+}
+
+/// Whether each op is jumped to by any op which has not been removed.
+///
+/// NOTE: The targets are found once for each optimization, as an op which is no longer jumped to once the optimization has removed other ops only misses being optimized until the next round.
+fn targets(ops: &[Op]) -> Vec<bool> {
+    let mut targets = vec![false; ops.len() + 1];
+
+    for target in ops
+        .iter()
+        .filter(|op| !op.removed)
+        .filter_map(|op| op.target)
+    {
+        targets[resolve(ops, target)] = true;
+    }
+
+    targets
+}
+
+/// Retargets each jump to a Jump to the target of that Jump.
+fn thread_jumps(ops: &mut [Op]) -> bool {
+    let mut changed = false;
+
+    for index in 0..ops.len() {
+        let Some(target) = ops[index].target.filter(|_| !ops[index].removed) else {
+            continue;
+        };
+
+        let mut new_target = resolve(ops, target);
+        let mut followed = vec![index];
+
+        // NOTE: Jumps already followed are not followed again, as a loop of Jumps which only jump to each other would never end.
+        while let Some(Op {
+            instruction: Instruction::Jump,
+            target: Some(next),
+            ..
+        }) = ops.get(new_target)
+        {
+            if followed.contains(&new_target) {
+                break;
+            }
+
+            followed.push(new_target);
+
+            new_target = resolve(ops, *next);
+        }
+
+        if new_target != resolve(ops, target) {
+            ops[index].target = Some(new_target);
+
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+/// Removes each Jump to the instruction straight after it.
+fn remove_jumps_to_next(ops: &mut [Op]) -> bool {
+    let mut changed = false;
+
+    for index in 0..ops.len() {
+        let op = &ops[index];
+
+        if op.removed || !matches!(op.instruction, Instruction::Jump) {
+            continue;
+        }
+
+        if resolve(ops, op.target.unwrap()) == resolve(ops, index + 1) {
+            ops[index].removed = true;
+
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+/// Removes each instruction which only pushes a value that the instruction after it pops.
+fn remove_push_pop_pairs(ops: &mut [Op]) -> bool {
+    let targets = targets(ops);
+    let mut changed = false;
+
+    for index in 0..ops.len() {
+        if ops[index].removed || !ops[index].is_pure_push() {
+            continue;
+        }
+
+        let next = resolve(ops, index + 1);
+
+        if ops
+            .get(next)
+            .is_some_and(|op| matches!(op.instruction, Instruction::Pop))
+            && !targets[next]
+        {
+            ops[index].removed = true;
+            ops[next].removed = true;
+
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+/// Removes the instructions after a Jump, Return or Throw up to the next instruction which is jumped to.
+fn remove_unreachable(ops: &mut [Op]) -> bool {
+    let targets = targets(ops);
+    let mut changed = false;
+    let mut reachable = true;
+
+    for index in 0..ops.len() {
+        if ops[index].removed {
+            continue;
+        }
+
+        if !reachable && !targets[index] {
+            ops[index].removed = true;
+
+            changed = true;
+
+            continue;
+        }
+
+        reachable = !ops[index].is_terminal();
+    }
+
+    changed
+}
@@ -1,16 +1,195 @@
 use std::fmt::Display;
 
+use crate::lexer::{Span, TokenKind};
+
 #[derive(Debug)]
 pub(crate) enum CodeGenError {
-    UnexpectedToken,
+    /// A token which the grammar does not allow where it was found.
+    UnexpectedToken {
+        /// The tokens which would have been allowed instead, if the parser was expecting particular ones.
+        expected: Vec<TokenKind>,
+        found: TokenKind,
+        span: Span,
+    },
     InvalidInteger64Literal,
+    IllegalReturnStatement,
+    IllegalBreakStatement,
+    IllegalContinueStatement,
+    ContinueToNonIterationLabel,
+    DuplicateLabel,
+    UndefinedLabel,
+    LabelledFunctionDeclaration,
+    DuplicateDeclaration,
+    DuplicateParameter,
+    DuplicateExport,
+    GetterParameters,
+    InvalidAssignmentTarget,
+    UndeclaredExport,
+    InvalidOptionalChainFromNewExpression,
+    InvalidTemplateEscapeSequence,
+    TaggedTemplateInOptionalChain,
+    LexicallyBoundLet,
+    NewTargetOutsideFunction,
+    MissingCatchOrFinally,
+    MissingConstInitializer,
+    MissingDestructuringInitializer,
+    /// Expressions, statements or patterns nested more deeply than [`MAX_NESTING_DEPTH`](crate::codegen::parser::MAX_NESTING_DEPTH), which bounds the stack the parser grows to parse them.
+    NestingTooDeep,
+    RestParameterInitializer,
+    RestParameterNotLast,
+    SetterParameters,
+    StrictModeDelete,
+    StrictModeOctalEscapeSequence,
+    StrictModeOctalLiteral,
+    StrictModeNonSimpleParameters,
+    StrictModeReservedWord,
+    StrictModeWith,
+    AwaitInFormalParameters,
+    YieldInFormalParameters,
+    UnparenthesizedUnaryExponentiation,
+    UnexpectedSuper,
+    UnsupportedImportCall,
+    UnsupportedImportMeta,
+    UnsupportedObjectBindingPattern,
 }
 
 impl Display for CodeGenError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CodeGenError::UnexpectedToken => write!(f, "Unexpected token"),
+            CodeGenError::UnexpectedToken {
+                expected, found, ..
+            } => {
+                match found {
+                    TokenKind::Keyword(_) | TokenKind::Punctuator(_) => {
+                        write!(f, "Unexpected token {found}")?
+                    }
+                    _ => write!(f, "Unexpected {found}")?,
+                }
+
+                match expected.split_last() {
+                    None => Ok(()),
+                    Some((last, [])) => write!(f, ", expected {last}"),
+                    Some((last, rest)) => {
+                        let rest = rest
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        write!(f, ", expected {rest} or {last}")
+                    }
+                }
+            }
             CodeGenError::InvalidInteger64Literal => write!(f, "Invalid integer64 literal"),
+            CodeGenError::IllegalReturnStatement => write!(f, "Illegal return statement"),
+            CodeGenError::IllegalBreakStatement => write!(f, "Illegal break statement"),
+            CodeGenError::IllegalContinueStatement => write!(
+                f,
+                "Illegal continue statement: no surrounding iteration statement"
+            ),
+            CodeGenError::ContinueToNonIterationLabel => write!(
+                f,
+                "Illegal continue statement: label does not denote an iteration statement"
+            ),
+            CodeGenError::DuplicateLabel => write!(f, "Label has already been declared"),
+            CodeGenError::UndefinedLabel => write!(f, "Undefined label"),
+            CodeGenError::LabelledFunctionDeclaration => write!(
+                f,
+                "Functions can only be labelled in sloppy mode, and generator and async functions cannot be labelled"
+            ),
+            CodeGenError::DuplicateDeclaration => {
+                write!(f, "Identifier has already been declared")
+            }
+            CodeGenError::DuplicateParameter => {
+                write!(f, "Duplicate parameter name not allowed in this context")
+            }
+            CodeGenError::DuplicateExport => write!(f, "Duplicate export of a name"),
+            CodeGenError::GetterParameters => {
+                write!(f, "Getter must not have any formal parameters")
+            }
+            CodeGenError::UndeclaredExport => {
+                write!(f, "Export of a name which is not declared in the module")
+            }
+            CodeGenError::InvalidAssignmentTarget => {
+                write!(f, "Invalid left-hand side in assignment")
+            }
+            CodeGenError::InvalidOptionalChainFromNewExpression => {
+                write!(f, "Invalid optional chain from new expression")
+            }
+            CodeGenError::LexicallyBoundLet => {
+                write!(f, "let is disallowed as a lexically bound name")
+            }
+            CodeGenError::MissingCatchOrFinally => write!(f, "Missing catch or finally after try"),
+            CodeGenError::MissingConstInitializer => {
+                write!(f, "Missing initializer in const declaration")
+            }
+            CodeGenError::MissingDestructuringInitializer => {
+                write!(f, "Missing initializer in destructuring declaration")
+            }
+            CodeGenError::NestingTooDeep => write!(f, "Maximum nesting depth exceeded"),
+            CodeGenError::RestParameterInitializer => {
+                write!(f, "Rest parameter may not have a default initializer")
+            }
+            CodeGenError::RestParameterNotLast => {
+                write!(f, "Rest parameter must be last formal parameter")
+            }
+            CodeGenError::SetterParameters => {
+                write!(f, "Setter must have exactly one formal parameter")
+            }
+            CodeGenError::StrictModeDelete => {
+                write!(f, "Delete of an unqualified identifier in strict mode")
+            }
+            CodeGenError::StrictModeOctalEscapeSequence => {
+                write!(f, "Octal escape sequences are not allowed in strict mode")
+            }
+            CodeGenError::StrictModeOctalLiteral => {
+                write!(f, "Octal literals are not allowed in strict mode")
+            }
+            CodeGenError::StrictModeNonSimpleParameters => write!(
+                f,
+                "Illegal 'use strict' directive in function with non-simple parameter list"
+            ),
+            CodeGenError::StrictModeReservedWord => {
+                write!(f, "Unexpected strict mode reserved word")
+            }
+            CodeGenError::NewTargetOutsideFunction => {
+                write!(f, "new.target expression is not allowed here")
+            }
+            CodeGenError::InvalidTemplateEscapeSequence => {
+                write!(f, "Invalid escape sequence in template")
+            }
+            CodeGenError::TaggedTemplateInOptionalChain => {
+                write!(f, "Tagged template cannot be used in optional chain")
+            }
+            CodeGenError::StrictModeWith => {
+                write!(f, "Strict mode code may not include a with statement")
+            }
+            CodeGenError::AwaitInFormalParameters => {
+                write!(f, "Illegal await-expression in formal parameters")
+            }
+            CodeGenError::YieldInFormalParameters => {
+                write!(f, "Illegal yield-expression in formal parameters")
+            }
+            CodeGenError::UnparenthesizedUnaryExponentiation => write!(
+                f,
+                "Unary operator used immediately before exponentiation expression. Parenthesis must be used to disambiguate operator precedence"
+            ),
+            CodeGenError::UnexpectedSuper => write!(f, "'super' keyword unexpected here"),
+            CodeGenError::UnsupportedImportCall => write!(f, "import() is not supported"),
+            CodeGenError::UnsupportedImportMeta => write!(f, "import.meta is not supported"),
+            CodeGenError::UnsupportedObjectBindingPattern => {
+                write!(f, "Object binding patterns are not supported")
+            }
+        }
+    }
+}
+
+impl CodeGenError {
+    /// The span of the source text the error was found in, if the error records one.
+    pub(crate) fn span(&self) -> Option<Span> {
+        match self {
+            CodeGenError::UnexpectedToken { span, .. } => Some(*span),
+            _ => None,
         }
     }
 }
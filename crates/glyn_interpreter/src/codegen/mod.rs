@@ -1,3 +1,4 @@
 pub(crate) mod bytecode;
 pub(crate) mod error;
 pub(crate) mod parser;
+pub(crate) mod warning;
@@ -1,14 +1,26 @@
+use std::{iter::Peekable, str::Chars};
+
 use crate::{
+    abstract_ops::function_definitions::AccessorKind,
     codegen::{
-        bytecode::instruction::Instruction,
+        bytecode::{generator::TemplateSite, instruction::Instruction},
         error::{CodeGenError, CodeGenResult},
         parser::Parser,
+        warning::WarningKind,
     },
     lexer::{BinOpPrecedence, Keyword, Token},
-    value::string::JSString,
+    value::{atom::Atom, string::JSString},
     JSValue,
 };
 
+/// How the evaluated arguments of a call are left on the stack.
+enum ArgumentList {
+    /// Each argument is a value on the stack.
+    Values(u32),
+    /// An argument is spread, so the arguments are collected into an array.
+    Spread,
+}
+
 /// 13 ECMAScript Language: Expressions
 /// https://262.ecma-international.org/16.0/#sec-ecmascript-language-expressions
 impl<'a> Parser<'a> {
@@ -17,9 +29,22 @@ impl<'a> Parser<'a> {
     pub(crate) fn js_parse_identifier_reference(&mut self) -> CodeGenResult {
         let identifier_reference = self.current_token.to_string();
 
-        if self.current_token.is_identifier_reference() {
+        if self.current_token.is_identifier_reference()
+            && !self.is_yield_expression_start()
+            && !self.is_await_expression_start()
+        {
+            // It is a Syntax Error if this phrase is contained in strict mode code and the StringValue of IdentifierName is one of "implements", "interface", "let", "package", "private", "protected", "public", "static", or "yield".
+            if self.strict && self.current_token.is_strict_mode_reserved_word() {
+                return self.error(CodeGenError::StrictModeReservedWord);
+            }
+
             self.advance(); // Eat binding identifier token.
 
+            // NOTE: The arguments object is only created for functions which can observe it.
+            if identifier_reference == "arguments" {
+                self.references_arguments = true;
+            }
+
             // IdentifierReference : Identifier
             // 1. Return ? ResolveBinding(StringValue of Identifier).
             // IdentifierReference : yield
@@ -28,45 +53,133 @@ impl<'a> Parser<'a> {
             // 1. Return ? ResolveBinding("await").
             let identifier_reference_index = self
                 .bytecode
-                .add_identifier(JSString::from(identifier_reference));
+                .add_identifier(Atom::from(identifier_reference));
 
             self.bytecode
                 .emit_resolve_binding(identifier_reference_index);
         } else {
-            return self.error(CodeGenError::UnexpectedToken);
+            return self.unexpected_token(&[]);
         }
 
         Ok(())
     }
 
     /// https://262.ecma-international.org/16.0/#prod-BindingIdentifier
-    pub(crate) fn js_parse_binding_identifier(&mut self) -> CodeGenResult<JSString> {
+    pub(crate) fn js_parse_binding_identifier(&mut self) -> CodeGenResult<Atom> {
         let binding_identifier = self.current_token.to_string();
 
-        if self.current_token.is_binding_identifier() {
+        // It is a Syntax Error if this production has a [Yield] parameter and StringValue of Identifier is "yield".
+        // It is a Syntax Error if this production has an [Await] parameter and StringValue of Identifier is "await".
+        if self.current_token.is_binding_identifier()
+            && !self.is_yield_expression_start()
+            && !self.is_await_expression_start()
+        {
+            // It is a Syntax Error if this phrase is contained in strict mode code and the StringValue of IdentifierName is one of "implements", "interface", "let", "package", "private", "protected", "public", "static", or "yield".
+            if self.strict && self.current_token.is_strict_mode_reserved_word() {
+                return self.error(CodeGenError::StrictModeReservedWord);
+            }
+
             self.advance(); // Eat binding identifier token.
         } else {
-            return self.error(CodeGenError::UnexpectedToken);
+            return self.unexpected_token(&[]);
         }
 
         Ok(binding_identifier.into())
     }
 
+    /// https://262.ecma-international.org/16.0/#prod-LabelIdentifier
+    pub(crate) fn js_parse_label_identifier(&mut self) -> CodeGenResult<Atom> {
+        let label_identifier = self.current_token.to_string();
+
+        // It is a Syntax Error if this production has a [Yield] parameter and StringValue of Identifier is "yield".
+        // It is a Syntax Error if this production has an [Await] parameter and StringValue of Identifier is "await".
+        if self.current_token.is_identifier_reference()
+            && !self.is_yield_expression_start()
+            && !self.is_await_expression_start()
+        {
+            // It is a Syntax Error if this phrase is contained in strict mode code and the StringValue of IdentifierName is one of "implements", "interface", "let", "package", "private", "protected", "public", "static", or "yield".
+            if self.strict && self.current_token.is_strict_mode_reserved_word() {
+                return self.error(CodeGenError::StrictModeReservedWord);
+            }
+
+            self.advance(); // Eat label identifier token.
+        } else {
+            return self.unexpected_token(&[]);
+        }
+
+        Ok(label_identifier.into())
+    }
+
     /// 13.15 Assignment Operators
     /// https://262.ecma-international.org/16.0/#prod-AssignmentExpression
     pub(crate) fn js_parse_assignment_expression(&mut self) -> CodeGenResult {
-        self.js_parse_conditional_expression()?;
+        self.nested(Self::js_parse_assignment_expression_unnested)
+    }
 
-        if self.current_token.is_assignment_operator() {
-            self.advance(); // Eat the assignment operator token.
+    /// Parses an AssignmentExpression which is not nested too deeply to be parsed, as checked by [`Parser::js_parse_assignment_expression`].
+    fn js_parse_assignment_expression_unnested(&mut self) -> CodeGenResult {
+        // AssignmentExpression : YieldExpression
+        if self.is_yield_expression_start() {
+            // It is a Syntax Error if FormalParameters Contains YieldExpression is true.
+            if self.in_formal_parameters {
+                return self.error(CodeGenError::YieldInFormalParameters);
+            }
+
+            return self.js_parse_yield_expression();
+        }
+
+        let identifier_target = (self.current_token.is_identifier_reference()
+            && self.peek() == Some(&Token::Assign))
+        .then(|| Atom::from(self.current_token.to_string()));
+
+        let is_reference = self.js_parse_conditional_expression()?;
+
+        if !self.current_token.is_assignment_operator() {
+            self.emit_get_value(is_reference);
 
-            self.current_token.clone()
-        } else {
             return Ok(());
-        };
+        }
+
+        // AssignmentExpression : LeftHandSideExpression = AssignmentExpression
+        // TODO: Implement compound and logical assignment operators.
+        if self.current_token != Token::Assign {
+            return self.unexpected_token(&[]);
+        }
+
+        // 1. If LeftHandSideExpression is neither an ObjectLiteral nor an ArrayLiteral, then
+        // a. Let lref be ? Evaluation of LeftHandSideExpression.
+        // NOTE: It is an early error if the AssignmentTargetType of LeftHandSideExpression is not simple, so the Reference Record is left on the stack for PutValue.
+        if !is_reference || self.is_optional_expression_end() {
+            return self.error(CodeGenError::InvalidAssignmentTarget);
+        }
+
+        // NOTE: PutValue throws a TypeError when the target is a const binding, which is only warned about if the target statically resolves to one, as the assignment may never run.
+        if identifier_target
+            .as_ref()
+            .is_some_and(|name| self.is_constant_binding(name))
+        {
+            self.warn(WarningKind::ConstAssignment, self.previous_span.start);
+        }
 
+        // NOTE: An identifier which resolves statically to a binding of a block is assigned directly, rather than through its Reference Record.
+        let scoped_target = identifier_target.and_then(|_| self.bytecode.take_scoped_reference());
+
+        self.advance(); // Eat '=' token.
+
+        // b. If IsAnonymousFunctionDefinition(AssignmentExpression) and IsIdentifierRef of LeftHandSideExpression are both true, then
+        // TODO: Name anonymous functions after their assignment target.
+        // c. Else,
+        // i. Let rref be ? Evaluation of AssignmentExpression.
+        // ii. Let rval be ? GetValue(rref).
         self.js_parse_assignment_expression()?;
 
+        // d. Perform ? PutValue(lref, rval).
+        // e. Return rval.
+        match scoped_target {
+            Some((depth, slot)) => self.bytecode.emit_set_scoped_local(depth, slot),
+            None => self.bytecode.emit_instruction(Instruction::PutValue),
+        }
+
         Ok(())
     }
 
@@ -78,18 +191,221 @@ impl<'a> Parser<'a> {
 
     /// 13.2 Primary Expressions
     /// https://262.ecma-international.org/16.0/#prod-PrimaryExpression
-    fn js_parse_primary_expression(&mut self) -> CodeGenResult {
+    ///
+    /// Returns whether the expression left a Reference Record on the stack rather than a value.
+    fn js_parse_primary_expression(&mut self) -> CodeGenResult<bool> {
         match &self.current_token {
-            token if token.is_identifier_reference() => self.js_parse_identifier_reference(),
-            _ => self.js_parse_literal(),
+            Token::Keyword(Keyword::Async) if self.is_async_function_start() => {
+                let function_index = self.js_parse_function()?;
+
+                self.bytecode.emit_create_closure(function_index);
+            }
+            token if token.is_identifier_reference() => {
+                self.js_parse_identifier_reference()?;
+
+                return Ok(true);
+            }
+            Token::Keyword(Keyword::This) => {
+                self.advance(); // Eat 'this' token.
+
+                self.bytecode.emit_instruction(Instruction::This);
+            }
+            Token::Keyword(Keyword::Function) => {
+                let function_index = self.js_parse_function()?;
+
+                self.bytecode.emit_create_closure(function_index);
+            }
+            Token::LeftBracket => self.js_parse_array_literal()?,
+            Token::LeftBrace => self.js_parse_object_literal()?,
+            Token::LeftParen => self.js_parse_parenthesized_expression()?,
+            token if token.is_template_start() => self.js_parse_template_literal()?,
+            _ => self.js_parse_literal()?,
+        };
+
+        Ok(false)
+    }
+
+    /// 13.2.1 Parenthesized Expression
+    /// https://262.ecma-international.org/16.0/#prod-ParenthesizedExpression
+    fn js_parse_parenthesized_expression(&mut self) -> CodeGenResult {
+        self.expect(Token::LeftParen)?;
+
+        self.js_parse_expression()?;
+
+        self.expect(Token::RightParen)?;
+
+        Ok(())
+    }
+
+    /// 13.2.4 Array Initializer
+    /// https://262.ecma-international.org/16.0/#prod-ArrayLiteral
+    fn js_parse_array_literal(&mut self) -> CodeGenResult {
+        self.expect(Token::LeftBracket)?;
+
+        // 1. Let array be ! ArrayCreate(0).
+        self.bytecode.emit_instruction(Instruction::CreateArray);
+
+        while self.current_token != Token::RightBracket {
+            // Elision : ,
+            if self.current_token == Token::Comma {
+                self.advance(); // Eat ',' token.
+
+                self.bytecode.emit_instruction(Instruction::AppendElision);
+
+                continue;
+            }
+
+            // ElementList : SpreadElement
+            if self.current_token == Token::Spread {
+                self.advance(); // Eat '...' token.
+
+                self.js_parse_assignment_expression()?;
+
+                self.bytecode.emit_instruction(Instruction::ArraySpread);
+            }
+            // ElementList : AssignmentExpression
+            else {
+                self.js_parse_assignment_expression()?;
+
+                self.bytecode.emit_instruction(Instruction::AppendElement);
+            }
+
+            if self.current_token != Token::Comma {
+                break;
+            }
+
+            self.advance(); // Eat ',' token.
+        }
+
+        self.expect(Token::RightBracket)?;
+
+        Ok(())
+    }
+
+    /// 13.2.5 Object Initializer
+    /// https://262.ecma-international.org/16.0/#prod-ObjectLiteral
+    fn js_parse_object_literal(&mut self) -> CodeGenResult {
+        self.expect(Token::LeftBrace)?;
+
+        self.bytecode.emit_instruction(Instruction::CreateObject);
+
+        let mut has_proto_setter = false;
+
+        while self.current_token != Token::RightBrace {
+            // PropertyDefinition : IdentifierReference
+            if self.current_token.is_identifier_reference()
+                && matches!(self.peek(), Some(Token::Comma | Token::RightBrace))
+            {
+                let property_name = self.current_token.to_string();
+
+                self.bytecode.emit_constant(JSValue::from(property_name));
+
+                self.js_parse_identifier_reference()?;
+
+                self.bytecode.emit_instruction(Instruction::GetValue);
+
+                self.bytecode.emit_instruction(Instruction::DefineProperty);
+            }
+            // PropertyDefinition : MethodDefinition
+            // MethodDefinition : get ClassElementName ( ) { FunctionBody }
+            // MethodDefinition : set ClassElementName ( PropertySetParameterList ) { FunctionBody }
+            else if matches!(
+                self.current_token,
+                Token::Keyword(Keyword::Get | Keyword::Set)
+            ) && !matches!(
+                self.peek(),
+                Some(Token::Colon | Token::Comma | Token::RightBrace | Token::LeftParen)
+            ) {
+                let (kind, instruction) = if self.current_token == Token::Keyword(Keyword::Get) {
+                    (AccessorKind::Get, Instruction::DefineGetter)
+                } else {
+                    (AccessorKind::Set, Instruction::DefineSetter)
+                };
+
+                self.advance(); // Eat 'get' or 'set' token.
+
+                if let Some(property_name) = self.js_parse_property_name()? {
+                    self.bytecode.emit_constant(JSValue::from(property_name));
+                }
+
+                let function_index = self.js_parse_accessor_method(kind)?;
+
+                self.bytecode
+                    .emit_define_accessor(instruction, function_index);
+            }
+            // PropertyDefinition : PropertyName : AssignmentExpression
+            else {
+                let property_name = self.js_parse_property_name()?;
+
+                self.expect(Token::Colon)?;
+
+                // It is a Syntax Error if PropertyNameList of PropertyDefinitionList contains any duplicate entries for "__proto__" and at least two of those entries were obtained from productions of the form PropertyDefinition : PropertyName : AssignmentExpression.
+                if property_name.as_deref() == Some("__proto__") {
+                    if has_proto_setter {
+                        return self.unexpected_token(&[]);
+                    }
+
+                    has_proto_setter = true;
+
+                    self.js_parse_assignment_expression()?;
+
+                    self.bytecode.emit_instruction(Instruction::SetPrototype);
+                } else {
+                    if let Some(property_name) = property_name {
+                        self.bytecode.emit_constant(JSValue::from(property_name));
+                    }
+
+                    self.js_parse_assignment_expression()?;
+
+                    self.bytecode.emit_instruction(Instruction::DefineProperty);
+                }
+            }
+
+            if self.current_token != Token::Comma {
+                break;
+            }
+
+            self.advance(); // Eat ',' token.
         }
+
+        self.expect(Token::RightBrace)?;
+
+        Ok(())
+    }
+
+    /// 13.2.5 Object Initializer
+    /// https://262.ecma-international.org/16.0/#prod-PropertyName
+    ///
+    /// Returns the value of a LiteralPropertyName, or emits the key and returns None for a ComputedPropertyName.
+    fn js_parse_property_name(&mut self) -> CodeGenResult<Option<String>> {
+        let property_name = match self.current_token {
+            // ComputedPropertyName : [ AssignmentExpression ]
+            Token::LeftBracket => {
+                self.advance(); // Eat '[' token.
+
+                self.js_parse_assignment_expression()?;
+
+                self.expect(Token::RightBracket)?;
+
+                return Ok(None);
+            }
+            // LiteralPropertyName : StringLiteral
+            Token::String(value) => string_literal_value(value),
+            // LiteralPropertyName : NumericLiteral
+            Token::Int64(value) => self.js_parse_numeric_literal_value(value)?.to_string(),
+            // LiteralPropertyName : IdentifierName
+            ref token if token.is_identifier_name() => token.to_string(),
+            _ => return self.unexpected_token(&[]),
+        };
+
+        self.advance(); // Eat the property name token.
+
+        Ok(Some(property_name))
     }
 
     /// 13.2.3 Literals
     /// https://262.ecma-international.org/16.0/#prod-Literal
     fn js_parse_literal(&mut self) -> CodeGenResult {
-        use crate::value::JSValue;
-
         match self.current_token {
             Token::Keyword(Keyword::True) => {
                 self.advance(); // Eat the literal token.
@@ -107,54 +423,471 @@ impl<'a> Parser<'a> {
                 self.bytecode.emit_instruction(Instruction::Null);
             }
             Token::Int64(value) => {
-                let f64_value = value
-                    .parse::<f64>()
-                    .map_err(|_| CodeGenError::InvalidInteger64Literal)?;
+                let f64_value = self.js_parse_numeric_literal_value(value)?;
 
                 self.advance(); // Eat the literal token.
 
                 self.bytecode.emit_constant(JSValue::from(f64_value));
             }
             Token::String(value) => {
-                let string_value = value.to_string();
+                // B.1.2 String Literals
+                // https://262.ecma-international.org/16.0/#sec-additional-syntax-string-literals
+                if has_legacy_octal_escape_sequence(value) {
+                    // It is a Syntax Error if the source text matched by this production is strict mode code.
+                    if self.strict {
+                        return self.error(CodeGenError::StrictModeOctalEscapeSequence);
+                    }
+
+                    self.warn(
+                        WarningKind::LegacyOctalEscapeSequence,
+                        self.current_span.start,
+                    );
+                }
+
+                let string_value = string_literal_value(value);
 
                 self.advance(); // Eat the literal token.
 
                 self.bytecode.emit_constant(JSValue::from(string_value));
             }
-            _ => self.error(CodeGenError::UnexpectedToken)?,
+            _ => self.unexpected_token(&[])?,
         };
 
         Ok(())
     }
 
+    /// 13.2.8 Template Literals
+    /// https://262.ecma-international.org/16.0/#prod-TemplateLiteral
+    fn js_parse_template_literal(&mut self) -> CodeGenResult {
+        // 13.2.8.6 Runtime Semantics: Evaluation
+        // TemplateLiteral : NoSubstitutionTemplate
+        // 1. Return the TV of NoSubstitutionTemplate.
+        // SubstitutionTemplate : TemplateHead Expression TemplateSpans
+        // 1. Let head be the TV of TemplateHead.
+        // 2. Let subRef be ? Evaluation of Expression.
+        // 3. Let sub be ? GetValue(subRef).
+        // 4. Let middle be ? ToString(sub).
+        // 5. Let tail be ? Evaluation of TemplateSpans.
+        // 6. Return the string-concatenation of head, middle, and tail.
+        // NOTE: Each string is concatenated onto the strings before it once it is evaluated, which gives the same string as concatenating them at the end.
+        let mut is_head = true;
+
+        loop {
+            let (characters, is_tail) = self.template_characters()?;
+
+            // It is a Syntax Error if the [Tagged] parameter was not set and NotEscapeSequence is a part of the TemplateLiteral.
+            let Some(cooked) = template_value(characters) else {
+                return self.error(CodeGenError::InvalidTemplateEscapeSequence);
+            };
+
+            self.advance(); // Eat the template token.
+
+            self.bytecode
+                .emit_constant(JSValue::from(JSString::from(cooked)));
+
+            if !is_head {
+                self.bytecode.emit_instruction(Instruction::BinAdd);
+            }
+
+            if is_tail {
+                return Ok(());
+            }
+
+            self.js_parse_expression()?;
+
+            self.bytecode.emit_instruction(Instruction::ToString);
+            self.bytecode.emit_instruction(Instruction::BinAdd);
+
+            self.js_parse_template_substitution_end()?;
+
+            is_head = false;
+        }
+    }
+
+    /// The TemplateCharacters of the current template token, along with whether the token ends the template.
+    fn template_characters(&self) -> CodeGenResult<(&'a str, bool)> {
+        match self.current_token {
+            Token::TemplateNoSubstitution(characters) | Token::TemplateTail(characters) => {
+                Ok((characters, true))
+            }
+            Token::TemplateHead(characters) | Token::TemplateMiddle(characters) => {
+                Ok((characters, false))
+            }
+            _ => self.unexpected_token(&[]),
+        }
+    }
+
+    /// Expects the '}' which ends a substitution of a template, which is lexed again as the TemplateMiddle or TemplateTail it starts.
+    fn js_parse_template_substitution_end(&mut self) -> CodeGenResult {
+        if self.current_token != Token::RightBrace {
+            return self.unexpected_token(&[Token::RightBrace]);
+        }
+
+        self.rescan_template_continuation();
+
+        Ok(())
+    }
+
     /// 13.3 Left-Hand-Side Expressions
     /// https://262.ecma-international.org/16.0/#prod-MemberExpression
-    fn js_parse_member_expression(&mut self) -> CodeGenResult {
-        self.js_parse_primary_expression()
+    fn js_parse_member_expression(&mut self) -> CodeGenResult<bool> {
+        let mut is_reference = if self.current_token == Token::Keyword(Keyword::New)
+            && self.peek() == Some(&Token::Dot)
+        {
+            self.js_parse_new_target()?;
+
+            false
+        } else if self.current_token == Token::Keyword(Keyword::New) {
+            self.nested(Self::js_parse_new_expression)?;
+
+            false
+        } else if self.current_token == Token::Keyword(Keyword::Super)
+            && matches!(self.peek(), Some(Token::Dot | Token::LeftBracket))
+        {
+            self.js_parse_super_property()?
+        } else {
+            self.js_parse_primary_expression()?
+        };
+
+        loop {
+            match self.current_token {
+                Token::Dot | Token::LeftBracket => {
+                    is_reference = self.js_parse_property_access(is_reference)?;
+                }
+                // MemberExpression : MemberExpression TemplateLiteral
+                _ if self.current_token.is_template_start() => {
+                    self.js_parse_tagged_template()?;
+
+                    is_reference = false;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(is_reference)
+    }
+
+    /// 13.3.2 Property Accessors
+    /// https://262.ecma-international.org/16.0/#sec-property-accessors
+    fn js_parse_property_access(&mut self, is_reference: bool) -> CodeGenResult<bool> {
+        // 1. Let baseReference be ? Evaluation of MemberExpression.
+        // 2. Let baseValue be ? GetValue(baseReference).
+        self.emit_get_value(is_reference);
+
+        // OptionalChain : ?. [ Expression ]
+        if self.current_token == Token::OptionalChaining && self.peek() == Some(&Token::LeftBracket)
+        {
+            self.advance(); // Eat '?.' token.
+        }
+
+        match self.current_token {
+            // MemberExpression : MemberExpression . IdentifierName
+            // OptionalChain : ?. IdentifierName
+            Token::Dot | Token::OptionalChaining => {
+                self.advance(); // Eat '.' or '?.' token.
+
+                if !self.current_token.is_identifier_name() {
+                    return self.unexpected_token(&[]);
+                }
+
+                let identifier_name = self.current_token.to_string();
+
+                self.advance(); // Eat the identifier name token.
+
+                // 3. If the source text matched by this MemberExpression is strict mode code, let strict be true; else let strict be false.
+                // 4. Return EvaluatePropertyAccessWithIdentifierKey(baseValue, IdentifierName, strict).
+                self.bytecode.emit_constant(JSValue::from(identifier_name));
+            }
+            // MemberExpression : MemberExpression [ Expression ]
+            _ => {
+                self.expect(Token::LeftBracket)?;
+
+                // 3. If the source text matched by this MemberExpression is strict mode code, let strict be true; else let strict be false.
+                // 4. Return ? EvaluatePropertyAccessWithExpressionKey(baseValue, Expression, strict).
+                self.js_parse_expression()?;
+
+                self.expect(Token::RightBracket)?;
+            }
+        }
+
+        self.bytecode.emit_instruction(Instruction::PropertyAccess);
+
+        Ok(true)
+    }
+
+    /// 13.3.7 The super Keyword
+    /// https://262.ecma-international.org/16.0/#prod-SuperProperty
+    fn js_parse_super_property(&mut self) -> CodeGenResult<bool> {
+        // It is a Syntax Error if FunctionBody Contains SuperProperty is true.
+        // NOTE: A SuperProperty is only valid within a method, which is the only kind of function with a [[HomeObject]].
+        if !self.in_method {
+            return self.error(CodeGenError::UnexpectedSuper);
+        }
+
+        self.expect(Token::Keyword(Keyword::Super))?;
+
+        match self.current_token {
+            // SuperProperty : super . IdentifierName
+            Token::Dot => {
+                self.advance(); // Eat '.' token.
+
+                if !self.current_token.is_identifier_name() {
+                    return self.unexpected_token(&[]);
+                }
+
+                // 1. Let env be GetThisEnvironment().
+                // 2. Let actualThis be ? env.GetThisBinding().
+                // 3. Let propertyKey be the StringValue of IdentifierName.
+                let identifier_name = self.current_token.to_string();
+
+                self.advance(); // Eat the identifier name token.
+
+                self.bytecode.emit_constant(JSValue::from(identifier_name));
+            }
+            // SuperProperty : super [ Expression ]
+            _ => {
+                self.expect(Token::LeftBracket)?;
+
+                // 1. Let env be GetThisEnvironment().
+                // 2. Let actualThis be ? env.GetThisBinding().
+                // 3. Let propertyNameReference be ? Evaluation of Expression.
+                // 4. Let propertyNameValue be ? GetValue(propertyNameReference).
+                self.js_parse_expression()?;
+
+                self.expect(Token::RightBracket)?;
+            }
+        }
+
+        self.bytecode.emit_instruction(Instruction::SuperProperty);
+
+        Ok(true)
+    }
+
+    /// 13.3.5 The new Operator
+    /// https://262.ecma-international.org/16.0/#prod-NewExpression
+    fn js_parse_new_expression(&mut self) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::New))?;
+
+        // 1. Let ref be ? Evaluation of constructExpr.
+        // 2. Let constructor be ? GetValue(ref).
+        let is_reference = self.js_parse_member_expression()?;
+
+        // NOTE: An OptionalChain can't follow a NewExpression without Arguments, as it isn't a MemberExpression.
+        if self.current_token == Token::OptionalChaining {
+            return self.error(CodeGenError::InvalidOptionalChainFromNewExpression);
+        }
+
+        self.emit_get_value(is_reference);
+
+        // NewExpression : new NewExpression
+        // 1. Return ? EvaluateNew(NewExpression, empty).
+        // MemberExpression : new MemberExpression Arguments
+        // 1. Return ? EvaluateNew(MemberExpression, Arguments).
+        let arguments = if self.current_token == Token::LeftParen {
+            self.js_parse_arguments()?
+        } else {
+            ArgumentList::Values(0)
+        };
+
+        match arguments {
+            ArgumentList::Values(args_length) => self.bytecode.emit_new(args_length),
+            ArgumentList::Spread => self.bytecode.emit_instruction(Instruction::SpreadNew),
+        }
+
+        Ok(())
+    }
+
+    /// 13.3.12 Meta Properties
+    /// https://262.ecma-international.org/16.0/#prod-NewTarget
+    fn js_parse_new_target(&mut self) -> CodeGenResult {
+        // It is a Syntax Error if StatementList Contains NewTarget, unless the source text containing NewTarget is within a function.
+        // NOTE: The formal parameters of a function are within it, as well as its body.
+        if !self.in_function_body && !self.in_formal_parameters {
+            return self.error(CodeGenError::NewTargetOutsideFunction);
+        }
+
+        self.expect(Token::Keyword(Keyword::New))?;
+        self.expect(Token::Dot)?;
+        self.expect(Token::Keyword(Keyword::Target))?;
+
+        // 13.3.12.1 Runtime Semantics: Evaluation
+        // NewTarget : new . target
+        // 1. Return GetNewTarget().
+        self.bytecode.emit_instruction(Instruction::NewTarget);
+
+        Ok(())
     }
 
     ///13.3 Left-Hand-Side Expressions
     /// https://262.ecma-international.org/16.0/#prod-CallExpression
-    fn js_parse_call_expression(&mut self) -> CodeGenResult {
-        self.js_parse_member_expression()?;
+    ///
+    /// 13.3.9 Optional Chains
+    /// https://262.ecma-international.org/16.0/#prod-OptionalExpression
+    fn js_parse_call_expression(&mut self) -> CodeGenResult<bool> {
+        let mut is_reference = self.js_parse_member_expression()?;
+
+        // The jumps taken when the base of an OptionalChain is undefined or null, which skip the rest of the chain.
+        let mut short_circuits = vec![];
+
+        loop {
+            match self.current_token {
+                // When processing an instance of the production
+                // CallExpression : CoverCallExpressionAndAsyncArrowHead
+                // the interpretation of CoverCallExpressionAndAsyncArrowHead is refined using the following grammar:
+                // CallMemberExpression : MemberExpression Arguments
+                // NOTE: The callee is left as a Reference Record so the call can determine its this value.
+                Token::LeftParen => {
+                    self.js_parse_call()?;
+
+                    is_reference = false;
+                }
+                Token::Dot | Token::LeftBracket => {
+                    is_reference = self.js_parse_property_access(is_reference)?;
+                }
+                // CallExpression : CallExpression TemplateLiteral
+                _ if self.current_token.is_template_start() => {
+                    // OptionalChain : ?. TemplateLiteral
+                    // OptionalChain : OptionalChain TemplateLiteral
+                    // It is a Syntax Error if any source text is matched by this production.
+                    if !short_circuits.is_empty() {
+                        return self.error(CodeGenError::TaggedTemplateInOptionalChain);
+                    }
+
+                    self.js_parse_tagged_template()?;
+
+                    is_reference = false;
+                }
+                // 13.3.9.1 Runtime Semantics: Evaluation
+                // OptionalExpression : MemberExpression OptionalChain
+                // OptionalExpression : CallExpression OptionalChain
+                // OptionalExpression : OptionalExpression OptionalChain
+                Token::OptionalChaining => {
+                    // 1. Let baseReference be ? Evaluation of MemberExpression.
+                    // 2. Let baseValue be ? GetValue(baseReference).
+                    // NOTE: An optional call needs the this value of its callee as well as its value.
+                    let is_call = self.peek() == Some(&Token::LeftParen);
+
+                    if !is_call {
+                        self.emit_get_value(is_reference);
+                    } else if is_reference {
+                        self.bytecode.emit_instruction(Instruction::GetCallee);
+                    }
+
+                    // 3. If baseValue is either undefined or null, then
+                    // a. Return undefined.
+                    short_circuits.push(self.bytecode.emit_jump(Instruction::JumpIfNullish));
+
+                    // 4. Return ? ChainEvaluation of OptionalChain with arguments baseValue and baseReference.
+                    if is_call {
+                        // OptionalChain : ?. Arguments
+                        self.advance(); // Eat '?.' token.
+
+                        self.js_parse_call()?;
+
+                        is_reference = false;
+                    } else {
+                        // OptionalChain : ?. [ Expression ]
+                        // OptionalChain : ?. IdentifierName
+                        is_reference = self.js_parse_property_access(false)?;
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        // NOTE: Every OptionalChain in the expression is short-circuited at once, which leaves undefined in place of the Reference Record the rest of the chain evaluates to.
+        if !short_circuits.is_empty() {
+            for short_circuit in short_circuits {
+                self.bytecode.patch_jump(short_circuit);
+            }
+
+            self.optional_expression_end = Some(self.previous_span);
+        }
 
-        // When processing an instance of the production
-        // CallExpression : CoverCallExpressionAndAsyncArrowHead
-        // the interpretation of CoverCallExpressionAndAsyncArrowHead is refined using the following grammar:
-        // CallMemberExpression : MemberExpression Arguments
-        if self.current_token == Token::LeftParen {
-            let args_length = self.js_parse_arguments()?;
+        Ok(is_reference)
+    }
 
-            self.bytecode.emit_call(args_length);
+    /// 13.3.6.2 EvaluateCall ( func, ref, arguments, tailPosition )
+    /// https://262.ecma-international.org/16.0/#sec-evaluatecall
+    fn js_parse_call(&mut self) -> CodeGenResult {
+        match self.js_parse_arguments()? {
+            ArgumentList::Values(args_length) => self.bytecode.emit_call(args_length),
+            ArgumentList::Spread => self.bytecode.emit_instruction(Instruction::SpreadCall),
         }
 
         Ok(())
     }
 
+    /// 13.3.11 Tagged Templates
+    /// https://262.ecma-international.org/16.0/#sec-tagged-templates
+    fn js_parse_tagged_template(&mut self) -> CodeGenResult {
+        // 13.3.11.1 Runtime Semantics: Evaluation
+        // MemberExpression : MemberExpression TemplateLiteral
+        // 1. Let tagRef be ? Evaluation of MemberExpression.
+        // 2. Let tagFunc be ? GetValue(tagRef).
+        // 3. Let thisCall be this MemberExpression.
+        // 4. Let tailCall be IsInTailPosition(thisCall).
+        // 5. Return ? EvaluateCall(tagFunc, tagRef, TemplateLiteral, tailCall).
+        // NOTE: The tag is left as a Reference Record, as the callee of a call is, so the call can determine its this value.
+
+        // 13.3.8.1 Runtime Semantics: ArgumentListEvaluation
+        // TemplateLiteral : NoSubstitutionTemplate
+        // 1. Let templateLiteral be this TemplateLiteral.
+        // 2. Let siteObj be GetTemplateObject(templateLiteral).
+        // 3. Return « siteObj ».
+        // TemplateLiteral : SubstitutionTemplate
+        // 1. Let templateLiteral be this TemplateLiteral.
+        // 2. Let siteObj be GetTemplateObject(templateLiteral).
+        // 3. Let remaining be ? ArgumentListEvaluation of SubstitutionTemplate.
+        // 4. Return the list-concatenation of « siteObj » and remaining.
+        // NOTE: The strings of the site are only known once the whole template has been parsed, so they are defined after the substitutions are.
+        let template_index = self.bytecode.emit_get_template_object();
+
+        let mut site = TemplateSite::default();
+        let mut args_length = 1;
+
+        loop {
+            let (characters, is_tail) = self.template_characters()?;
+
+            // 13.2.8.3 Static Semantics: TemplateString ( templateToken, raw )
+            // 1. If raw is true, then
+            // a. Let string be the TRV of templateToken.
+            // 2. Else,
+            // a. Let string be the TV of templateToken.
+            site.raw
+                .push(JSString::from(template_raw_value(characters)));
+            site.cooked
+                .push(template_value(characters).map(JSString::from));
+
+            self.advance(); // Eat the template token.
+
+            if is_tail {
+                break;
+            }
+
+            // SubstitutionTemplate : TemplateHead Expression TemplateSpans
+            // 1. Let firstSubRef be ? Evaluation of Expression.
+            // 2. Let firstSub be ? GetValue(firstSubRef).
+            // 3. Let restSub be ? SubstitutionEvaluation of TemplateSpans.
+            // 4. Assert: restSub is a possibly empty List.
+            // 5. Return the list-concatenation of « firstSub » and restSub.
+            self.js_parse_expression()?;
+
+            args_length += 1;
+
+            self.js_parse_template_substitution_end()?;
+        }
+
+        self.bytecode.define_template(template_index, site);
+
+        self.bytecode.emit_call(args_length);
+
+        Ok(())
+    }
+
     /// 13.3 Left-Hand-Side Expressions
     /// https://262.ecma-international.org/16.0/#prod-LeftHandSideExpression
-    fn js_parse_left_hand_side_expression(&mut self) -> CodeGenResult {
+    pub(crate) fn js_parse_left_hand_side_expression(&mut self) -> CodeGenResult<bool> {
         let current_token = &self.current_token.clone();
 
         let Some(peek_token) = &self.peek() else {
@@ -162,35 +895,19 @@ impl<'a> Parser<'a> {
         };
 
         match (current_token, peek_token) {
-            // `super . IdentifierName`.
-            // `super [ Expression ]`.
-            (Token::Keyword(Keyword::Super), Token::LeftBracket | Token::Dot) => {
-                // self.js_parse_super_property()
-                todo!();
-            }
-            // `new.target`.
-            (Token::Keyword(Keyword::New), Token::Dot) => {
-                // self.js_parse_new_target()
-                todo!();
-            }
             // `import.meta`.
             (Token::Keyword(Keyword::Import), Token::Dot) => {
-                // self.js_parse_import_meta()
-                todo!();
+                self.error(CodeGenError::UnsupportedImportMeta)
             }
             // `super Arguments`.
+            // It is a Syntax Error if the source text matched by a SuperCall is not nested within the constructor of a class with a heritage.
+            // NOTE: Classes are not supported yet, so a SuperCall can never be valid.
             (Token::Keyword(Keyword::Super), Token::LeftParen) => {
-                // self.js_parse_super_call()
-                todo!();
+                self.error(CodeGenError::UnexpectedSuper)
             }
             // `import ( AssignmentExpression )`.
             (Token::Keyword(Keyword::Import), Token::LeftParen) => {
-                // self.js_parse_import_call()
-                todo!();
-            }
-            (Token::Keyword(Keyword::New), _) => {
-                // self.js_parse_new_expression()
-                todo!();
+                self.error(CodeGenError::UnsupportedImportCall)
             }
             _ => self.js_parse_call_expression(),
         }
@@ -198,57 +915,139 @@ impl<'a> Parser<'a> {
 
     /// 13.4 Update Expressions
     /// https://262.ecma-international.org/16.0/#prod-UpdateExpression
-    fn js_parse_update_expression(&mut self) -> CodeGenResult {
+    fn js_parse_update_expression(&mut self) -> CodeGenResult<bool> {
         self.js_parse_left_hand_side_expression()
     }
 
     /// 13.5 Unary Operators
     /// https://262.ecma-international.org/16.0/#prod-UnaryExpression
-    fn js_parse_unary_expression(&mut self) -> CodeGenResult {
+    fn js_parse_unary_expression(&mut self) -> CodeGenResult<bool> {
         match self.current_token {
             Token::Plus | Token::Minus => {
                 let operation = self.current_token.clone();
 
                 self.advance(); // Eat the unary operator token.
 
-                self.js_parse_unary_expression()?;
+                let is_reference = self.nested(Self::js_parse_unary_expression)?;
+
+                self.emit_get_value(is_reference);
 
                 let instruction = match operation {
                     Token::Plus => Instruction::Plus,
                     Token::Minus => Instruction::Minus,
                     Token::Not => Instruction::Not,
-                    _ => return Err(CodeGenError::UnexpectedToken),
+                    _ => return self.unexpected_token(&[]),
                 };
 
                 self.bytecode.emit_instruction(instruction);
 
-                Ok(())
+                Ok(false)
+            }
+            Token::Keyword(Keyword::Delete) => {
+                self.advance(); // Eat 'delete' token.
+
+                let operand_span = self.current_span;
+                let is_identifier_reference = self.current_token.is_identifier_reference();
+
+                self.nested(Self::js_parse_unary_expression)?;
+
+                // It is a Syntax Error if the UnaryExpression is contained in strict mode code and the derived UnaryExpression is PrimaryExpression : IdentifierReference.
+                if self.strict && is_identifier_reference && self.previous_span == operand_span {
+                    return self.error(CodeGenError::StrictModeDelete);
+                }
+
+                // NOTE: The operand is left on the stack as is, as the result depends on whether it is a Reference Record.
+                self.bytecode.emit_instruction(Instruction::Delete);
+
+                Ok(false)
+            }
+            Token::Keyword(Keyword::Typeof) => {
+                self.advance(); // Eat 'typeof' token.
+
+                // 1. Let val be ? Evaluation of UnaryExpression.
+                // NOTE: The operand is left on the stack as is, as an unresolvable reference evaluates to "undefined" rather than throwing.
+                self.nested(Self::js_parse_unary_expression)?;
+
+                self.bytecode.emit_instruction(Instruction::TypeOf);
+
+                Ok(false)
+            }
+            // 15.8 Async Function Definitions
+            // https://262.ecma-international.org/16.0/#prod-AwaitExpression
+            Token::Keyword(Keyword::Await) if self.is_await_expression_start() => {
+                // It is a Syntax Error if FormalParameters Contains AwaitExpression is true.
+                if self.in_formal_parameters {
+                    return self.error(CodeGenError::AwaitInFormalParameters);
+                }
+
+                self.advance(); // Eat 'await' token.
+
+                // 1. Let exprRef be ? Evaluation of UnaryExpression.
+                // 2. Let value be ? GetValue(exprRef).
+                let is_reference = self.nested(Self::js_parse_unary_expression)?;
+
+                self.emit_get_value(is_reference);
+
+                // 3. Return ? Await(value).
+                self.bytecode.emit_instruction(Instruction::Await);
+
+                Ok(false)
             }
             _ => self.js_parse_update_expression(),
         }
     }
 
-    /// https://tc39.es/ecma262/#prod-Arguments
-    /// https://tc39.es/ecma262/#prod-ArgumentList
-    fn js_parse_arguments(&mut self) -> CodeGenResult<u8> {
+    /// 13.3.8.1 Runtime Semantics: ArgumentListEvaluation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-argumentlistevaluation
+    fn js_parse_arguments(&mut self) -> CodeGenResult<ArgumentList> {
         self.expect(Token::LeftParen)?;
 
-        let mut args_length: u8 = 0;
+        // Arguments : ( )
+        // 1. Return a new empty List.
+        let mut arguments = ArgumentList::Values(0);
 
         while self.current_token != Token::RightParen {
+            // ArgumentList : ... AssignmentExpression
+            // ArgumentList : ArgumentList , ... AssignmentExpression
             if self.current_token == Token::Spread {
                 self.advance(); // Eat '...' token.
-            }
 
-            self.js_parse_assignment_expression()?;
+                // NOTE: The number of arguments is only known at runtime once an argument is spread, so the arguments evaluated so far are collected into an array which the rest are appended to.
+                if let ArgumentList::Values(args_length) = arguments {
+                    self.bytecode.emit_collect_arguments(args_length);
 
-            args_length += 1;
+                    arguments = ArgumentList::Spread;
+                }
 
-            if self.current_token != Token::Comma {
-                break;
+                // 1. Let list be a new empty List.
+                // 2. Let spreadRef be ? Evaluation of AssignmentExpression.
+                // 3. Let spreadObj be ? GetValue(spreadRef).
+                self.js_parse_assignment_expression()?;
+
+                // 4. Let iteratorRecord be ? GetIterator(spreadObj, sync).
+                // 5. Repeat,
+                // a. Let next be ? IteratorStepValue(iteratorRecord).
+                // b. If next is done, return list.
+                // c. Append next to list.
+                self.bytecode.emit_instruction(Instruction::ArraySpread);
+            }
+            // ArgumentList : AssignmentExpression
+            // ArgumentList : ArgumentList , AssignmentExpression
+            else {
+                // 1. Let ref be ? Evaluation of AssignmentExpression.
+                // 2. Let arg be ? GetValue(ref).
+                self.js_parse_assignment_expression()?;
+
+                // 3. Return « arg ».
+                match &mut arguments {
+                    ArgumentList::Values(args_length) => *args_length += 1,
+                    ArgumentList::Spread => {
+                        self.bytecode.emit_instruction(Instruction::AppendElement)
+                    }
+                }
             }
 
-            if self.current_token == Token::RightParen {
+            if self.current_token != Token::Comma {
                 break;
             }
 
@@ -257,7 +1056,7 @@ impl<'a> Parser<'a> {
 
         self.expect(Token::RightParen)?;
 
-        Ok(args_length)
+        Ok(arguments)
     }
 
     /// 13.6 Exponentiation Operator
@@ -289,18 +1088,33 @@ impl<'a> Parser<'a> {
     ///
     /// 13.14 Conditional Operator ( ? : )
     /// https://262.ecma-international.org/16.0/#prod-ConditionalExpression
-    fn js_parse_conditional_expression(&mut self) -> CodeGenResult {
-        self.js_parse_binary_expression(BinOpPrecedence::Lowest)
+    fn js_parse_conditional_expression(&mut self) -> CodeGenResult<bool> {
+        // NOTE: Only operators which bind more tightly than assignment are parsed, so a comma ends the expression.
+        self.js_parse_binary_expression(BinOpPrecedence::Assignment)
     }
 
-    fn js_parse_binary_expression(&mut self, precedence: BinOpPrecedence) -> CodeGenResult {
-        self.js_parse_unary_expression()?;
+    fn js_parse_binary_expression(&mut self, precedence: BinOpPrecedence) -> CodeGenResult<bool> {
+        let is_unary_operation = self.is_unary_operator_start();
+
+        let is_reference = self.js_parse_unary_expression()?;
+
+        // ExponentiationExpression : UpdateExpression ** ExponentiationExpression
+        // NOTE: The base of an exponentiation is an UpdateExpression, so a UnaryExpression with an operator, as in -a ** b, must be parenthesized to show which of the operators applies first.
+        if is_unary_operation && self.current_token == Token::Exponent {
+            return self.error(CodeGenError::UnparenthesizedUnaryExponentiation);
+        }
 
         if !self.current_token.is_binary_operator() {
-            return Ok(());
+            return Ok(is_reference);
         }
 
-        self.js_parse_binary_expression_rest(precedence)
+        // 1. Let lref be ? Evaluation of the left operand.
+        // 2. Let lval be ? GetValue(lref).
+        self.emit_get_value(is_reference);
+
+        self.js_parse_binary_expression_rest(precedence)?;
+
+        Ok(false)
     }
 
     fn js_parse_binary_expression_rest(&mut self, precedence: BinOpPrecedence) -> CodeGenResult {
@@ -321,7 +1135,20 @@ impl<'a> Parser<'a> {
 
             self.advance(); // Eat the binary operator token.
 
-            self.js_parse_binary_expression(new_precedence)?;
+            // 3. Let rref be ? Evaluation of the right operand.
+            // 4. Let rval be ? GetValue(rref).
+            // NOTE: Only a right-associative operator nests its right operand within itself, as the right operand of any other operator can only contain operators which bind more tightly.
+            let is_right_associative = new_precedence.is_right_associative();
+
+            let parse_right = |parser: &mut Self| parser.js_parse_binary_expression(new_precedence);
+
+            let is_reference = if is_right_associative {
+                self.nested(parse_right)?
+            } else {
+                self.deeper(parse_right)?
+            };
+
+            self.emit_get_value(is_reference);
 
             let instruction = match operator {
                 Token::Plus => Instruction::BinAdd,
@@ -343,10 +1170,12 @@ impl<'a> Parser<'a> {
                 Token::BitXor => Instruction::BitXor,
                 Token::LeftShift => Instruction::BitShiftLeft,
                 Token::RightShift => Instruction::BitShiftRight,
-                Token::UnsignedRightShift => Instruction::BitShiftRight,
+                Token::UnsignedRightShift => Instruction::BitShiftRightUnsigned,
+                Token::Keyword(Keyword::In) => Instruction::In,
+                Token::Keyword(Keyword::Instanceof) => Instruction::InstanceOf,
                 Token::LogicalAnd => Instruction::LogicalAnd,
                 Token::LogicalOr => Instruction::LogicalOr,
-                _ => return Err(CodeGenError::UnexpectedToken),
+                _ => return self.unexpected_token(&[]),
             };
 
             self.bytecode.emit_instruction(instruction);
@@ -354,4 +1183,170 @@ impl<'a> Parser<'a> {
 
         Ok(())
     }
+
+    /// 12.9.3.2 Static Semantics: MV
+    /// https://262.ecma-international.org/16.0/#sec-static-semantics-mv
+    fn js_parse_numeric_literal_value(&mut self, value: &str) -> CodeGenResult<f64> {
+        // B.1.1 Numeric Literals
+        // https://262.ecma-international.org/16.0/#sec-additional-syntax-numeric-literals
+        if value.len() > 1 && value.starts_with('0') {
+            // It is a Syntax Error if the source text matched by this production is strict mode code.
+            if self.strict {
+                return self.error(CodeGenError::StrictModeOctalLiteral);
+            }
+
+            self.warn(WarningKind::LegacyOctalLiteral, self.current_span.start);
+
+            // LegacyOctalIntegerLiteral
+            if value.bytes().all(|byte| (b'0'..=b'7').contains(&byte)) {
+                return u64::from_str_radix(value, 8)
+                    .map(|value| value as f64)
+                    .map_err(|_| CodeGenError::InvalidInteger64Literal);
+            }
+
+            // NonOctalDecimalIntegerLiteral
+        }
+
+        value
+            .parse::<f64>()
+            .map_err(|_| CodeGenError::InvalidInteger64Literal)
+    }
+
+    /// Emits a GetValue for an expression which left a Reference Record on the stack, so that its value can be used.
+    fn emit_get_value(&mut self, is_reference: bool) {
+        if is_reference {
+            self.bytecode.emit_instruction(Instruction::GetValue);
+        }
+    }
+}
+
+/// 12.9.4.1 Static Semantics: SV
+/// https://262.ecma-international.org/16.0/#sec-static-semantics-sv
+pub(crate) fn string_literal_value(value: &str) -> String {
+    // NOTE: The lexer includes the enclosing quotes in the token.
+    value[1..value.len() - 1].to_string()
+}
+
+/// 12.9.6.1 Static Semantics: TV
+/// https://262.ecma-international.org/16.0/#sec-static-semantics-tv
+///
+/// The TV of TemplateCharacters which contain a NotEscapeSequence is undefined, which is returned as None.
+fn template_value(characters: &str) -> Option<Vec<u16>> {
+    let mut value = vec![];
+    let mut chars = characters.chars().peekable();
+    let mut buffer = [0; 2];
+
+    while let Some(ch) = chars.next() {
+        let escape = match ch {
+            // The TV of LineTerminatorSequence :: <CR><LF> is the sequence consisting of the code unit 0x000A (LINE FEED).
+            // The TV of LineTerminatorSequence :: <CR> is the sequence consisting of the code unit 0x000A (LINE FEED).
+            '\r' => {
+                chars.next_if_eq(&'\n');
+
+                value.push(0x000A);
+
+                continue;
+            }
+            '\\' => chars.next()?,
+            ch => {
+                value.extend_from_slice(ch.encode_utf16(&mut buffer));
+
+                continue;
+            }
+        };
+
+        match escape {
+            // The TV of LineContinuation :: \ LineTerminatorSequence is the empty sequence.
+            '\r' => {
+                chars.next_if_eq(&'\n');
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            // The TV of SingleEscapeCharacter is the code unit whose value is determined by the SingleEscapeCharacter according to Table 35.
+            'b' => value.push(0x0008),
+            't' => value.push(0x0009),
+            'n' => value.push(0x000A),
+            'v' => value.push(0x000B),
+            'f' => value.push(0x000C),
+            'r' => value.push(0x000D),
+            // The TV of TemplateEscapeSequence :: 0 [lookahead ∉ DecimalDigit] is the code unit 0x0000 (NULL).
+            '0' if !chars.peek().is_some_and(char::is_ascii_digit) => value.push(0x0000),
+            // NotEscapeSequence :: 0 DecimalDigit
+            // NotEscapeSequence :: DecimalDigit but not 0
+            '0'..='9' => return None,
+            // The TV of HexEscapeSequence :: x HexDigit HexDigit is the code unit whose value is the MV of HexEscapeSequence.
+            'x' => value.push(hex_digits(&mut chars, 2)? as u16),
+            // The TV of UnicodeEscapeSequence :: u{ CodePoint } is UTF16EncodeCodePoint(the MV of CodePoint).
+            'u' if chars.next_if_eq(&'{').is_some() => {
+                let mut code_point = 0;
+                let mut has_digits = false;
+
+                while let Some(digit) = chars.next_if(char::is_ascii_hexdigit) {
+                    code_point = code_point * 16 + digit.to_digit(16)?;
+                    has_digits = true;
+
+                    // NotCodePoint :: HexDigits but only if the MV of HexDigits > 0x10FFFF
+                    if code_point > 0x10FFFF {
+                        return None;
+                    }
+                }
+
+                chars.next_if_eq(&'}')?;
+
+                if !has_digits {
+                    return None;
+                }
+
+                match char::from_u32(code_point) {
+                    Some(ch) => value.extend_from_slice(ch.encode_utf16(&mut buffer)),
+                    // NOTE: A surrogate is a code point which is encoded as a single code unit of its own.
+                    None => value.push(code_point as u16),
+                }
+            }
+            // The TV of UnicodeEscapeSequence :: u Hex4Digits is the code unit whose value is the MV of Hex4Digits.
+            'u' => value.push(hex_digits(&mut chars, 4)? as u16),
+            // The TV of NonEscapeCharacter is the code unit of the character.
+            ch => value.extend_from_slice(ch.encode_utf16(&mut buffer)),
+        }
+    }
+
+    Some(value)
+}
+
+/// The MV of the given number of HexDigits at the start of the characters, if they are all hexadecimal digits.
+fn hex_digits(chars: &mut Peekable<Chars>, count: usize) -> Option<u32> {
+    (0..count).try_fold(0, |value, _| {
+        let digit = chars.next_if(char::is_ascii_hexdigit)?;
+
+        Some(value * 16 + digit.to_digit(16)?)
+    })
+}
+
+/// 12.9.6.2 Static Semantics: TRV
+/// https://262.ecma-international.org/16.0/#sec-static-semantics-trv
+fn template_raw_value(characters: &str) -> String {
+    // The TRV of LineTerminatorSequence :: <CR><LF> is the sequence consisting of the code unit 0x000A (LINE FEED).
+    // The TRV of LineTerminatorSequence :: <CR> is the sequence consisting of the code unit 0x000A (LINE FEED).
+    // NOTE: The TRV of every other part of the TemplateCharacters is the source text of that part.
+    characters.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// B.1.2 String Literals
+/// https://262.ecma-international.org/16.0/#prod-annexB-LegacyOctalEscapeSequence
+fn has_legacy_octal_escape_sequence(value: &str) -> bool {
+    let mut chars = value.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            continue;
+        }
+
+        match chars.next() {
+            // \0 is only a legacy escape when followed by a decimal digit.
+            Some('0') if chars.peek().is_some_and(|ch| ch.is_ascii_digit()) => return true,
+            Some('1'..='9') => return true,
+            _ => {}
+        }
+    }
+
+    false
 }
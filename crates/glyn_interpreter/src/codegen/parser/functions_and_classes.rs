@@ -0,0 +1,387 @@
+use crate::{
+    abstract_ops::function_definitions::AccessorKind,
+    codegen::{
+        bytecode::{
+            generator::{
+                ExecutableFunction, ExecutableProgram, FormalParameter, FormalParameters,
+                GeneratorKind,
+            },
+            instruction::Instruction,
+        },
+        error::CodeGenError,
+        parser::{CodeGenResult, Parser},
+    },
+    lexer::{Keyword, Token},
+    value::atom::Atom,
+};
+
+/// 15 ECMAScript Language: Functions and Classes
+/// https://262.ecma-international.org/16.0/#sec-ecmascript-language-functions-and-classes
+impl<'a> Parser<'a> {
+    /// 15.2 Function Definitions
+    /// https://262.ecma-international.org/16.0/#prod-FunctionDeclaration
+    /// 15.5 Generator Function Definitions
+    /// https://262.ecma-international.org/16.0/#prod-GeneratorDeclaration
+    /// 15.8 Async Function Definitions
+    /// https://262.ecma-international.org/16.0/#prod-AsyncFunctionDeclaration
+    ///
+    /// Compiles the function into its own program and returns its index in the enclosing program's function table.
+    pub(crate) fn js_parse_function(&mut self) -> CodeGenResult<u32> {
+        let is_async = self.current_token == Token::Keyword(Keyword::Async);
+
+        if is_async {
+            self.advance(); // Eat 'async' token.
+        }
+
+        self.expect(Token::Keyword(Keyword::Function))?;
+
+        let generator_kind = if self.current_token == Token::Multiply {
+            // TODO: Implement async generator functions.
+            if is_async {
+                return self.unexpected_token(&[]);
+            }
+
+            self.advance(); // Eat '*' token.
+
+            GeneratorKind::Sync
+        } else {
+            GeneratorKind::NonGenerator
+        };
+
+        let name = if self.current_token.is_binding_identifier() {
+            Some(self.js_parse_binding_identifier()?)
+        } else {
+            None
+        };
+
+        self.js_parse_function_parameters_and_body(name, generator_kind, is_async, false)
+    }
+
+    /// 15.4 Method Definitions
+    /// https://262.ecma-international.org/16.0/#prod-MethodDefinition
+    ///
+    /// Compiles the parameters and body of a `get` or `set` MethodDefinition, the property name of which has already been parsed, and returns its index in the enclosing program's function table.
+    pub(crate) fn js_parse_accessor_method(&mut self, kind: AccessorKind) -> CodeGenResult<u32> {
+        let function_index = self.js_parse_function_parameters_and_body(
+            None,
+            GeneratorKind::NonGenerator,
+            false,
+            true,
+        )?;
+
+        let formal_parameters = &self.bytecode.function(function_index).formal_parameters;
+
+        match kind {
+            // MethodDefinition : get ClassElementName ( ) { FunctionBody }
+            AccessorKind::Get
+                if !formal_parameters.parameters.is_empty() || formal_parameters.rest.is_some() =>
+            {
+                self.error(CodeGenError::GetterParameters)
+            }
+            // PropertySetParameterList : FormalParameter
+            AccessorKind::Set
+                if formal_parameters.parameters.len() != 1 || formal_parameters.rest.is_some() =>
+            {
+                self.error(CodeGenError::SetterParameters)
+            }
+            _ => Ok(function_index),
+        }
+    }
+
+    /// Compiles the formal parameters and body of a function into its own program and returns its index in the enclosing program's function table.
+    ///
+    /// `is_method` is whether the function is a MethodDefinition, within which super property references may be used.
+    fn js_parse_function_parameters_and_body(
+        &mut self,
+        name: Option<Atom>,
+        generator_kind: GeneratorKind,
+        is_async: bool,
+        is_method: bool,
+    ) -> CodeGenResult<u32> {
+        trace!(
+            Parser,
+            Debug,
+            "function {:?} at {}",
+            name.as_ref().map(|name| name.to_string()),
+            self.current_span.start
+        );
+
+        // NOTE: The formal parameters and body of a generator are parsed with the [Yield] parameter, those of an async function with the [Await] parameter, and those of any other function without either.
+        let enclosing_in_generator_body = std::mem::replace(
+            &mut self.in_generator_body,
+            generator_kind == GeneratorKind::Sync,
+        );
+        let enclosing_in_async_body = std::mem::replace(&mut self.in_async_body, is_async);
+        let enclosing_in_method = std::mem::replace(&mut self.in_method, is_method);
+        let enclosing_references_arguments =
+            std::mem::replace(&mut self.references_arguments, false);
+        let enclosing_var_scope_depth =
+            std::mem::replace(&mut self.var_scope_depth, self.scopes.len());
+        let enclosing_block_functions = self.block_functions.take();
+
+        let formal_parameters = self.js_parse_formal_parameters().inspect_err(|_| {
+            self.in_generator_body = enclosing_in_generator_body;
+            self.in_async_body = enclosing_in_async_body;
+            self.in_method = enclosing_in_method;
+            self.references_arguments = enclosing_references_arguments;
+            self.var_scope_depth = enclosing_var_scope_depth;
+            self.block_functions = enclosing_block_functions;
+        })?;
+
+        let parameter_names = formal_parameters.bound_names();
+
+        let enclosing_bytecode = std::mem::take(&mut self.bytecode);
+        let enclosing_in_function_body = std::mem::replace(&mut self.in_function_body, true);
+        let enclosing_in_formal_parameters =
+            std::mem::replace(&mut self.in_formal_parameters, false);
+
+        // NOTE: Function code is strict if it is contained in strict mode code, or if its body begins with a Use Strict Directive.
+        let enclosing_strict = self.strict;
+
+        let function_body = self.js_parse_function_body(&formal_parameters);
+
+        self.bytecode.set_strict(self.strict);
+
+        let mut function_bytecode = std::mem::replace(&mut self.bytecode, enclosing_bytecode);
+        let strict = std::mem::replace(&mut self.strict, enclosing_strict);
+        let references_arguments = std::mem::replace(
+            &mut self.references_arguments,
+            enclosing_references_arguments,
+        );
+        self.in_function_body = enclosing_in_function_body;
+        self.in_formal_parameters = enclosing_in_formal_parameters;
+        self.in_generator_body = enclosing_in_generator_body;
+        self.in_async_body = enclosing_in_async_body;
+        self.in_method = enclosing_in_method;
+        self.var_scope_depth = enclosing_var_scope_depth;
+        self.block_functions = enclosing_block_functions;
+
+        let contains_use_strict = function_body?;
+
+        // It is a Syntax Error if FunctionBodyContainsUseStrict of FunctionBody is true and IsSimpleParameterList of FormalParameters is false.
+        if contains_use_strict && !formal_parameters.is_simple_parameter_list() {
+            return self.error(CodeGenError::StrictModeNonSimpleParameters);
+        }
+
+        // It is a Syntax Error if IsStrict(FormalParameters) is true and BoundNames of FormalParameters contains any duplicate elements.
+        // It is a Syntax Error if IsSimpleParameterList of FormalParameterList is false and BoundNames of FormalParameterList contains any duplicate elements.
+        if (strict || !formal_parameters.is_simple_parameter_list())
+            && has_duplicates(&parameter_names)
+        {
+            return self.error(CodeGenError::DuplicateParameter);
+        }
+
+        function_bytecode.optimize(self.optimization_level);
+
+        Ok(self.bytecode.add_function(ExecutableFunction {
+            name,
+            formal_parameters,
+            program: function_bytecode.program(),
+            generator_kind,
+            is_async,
+            references_arguments,
+        }))
+    }
+
+    /// 15.1 Parameter Lists
+    /// https://262.ecma-international.org/16.0/#prod-FormalParameters
+    fn js_parse_formal_parameters(&mut self) -> CodeGenResult<FormalParameters> {
+        self.expect(Token::LeftParen)?;
+
+        let enclosing_in_formal_parameters =
+            std::mem::replace(&mut self.in_formal_parameters, true);
+
+        let formal_parameters = self.js_parse_formal_parameter_list();
+
+        self.in_formal_parameters = enclosing_in_formal_parameters;
+
+        let formal_parameters = formal_parameters?;
+
+        self.expect(Token::RightParen)?;
+
+        Ok(formal_parameters)
+    }
+
+    /// 15.1 Parameter Lists
+    /// https://262.ecma-international.org/16.0/#prod-FormalParameterList
+    fn js_parse_formal_parameter_list(&mut self) -> CodeGenResult<FormalParameters> {
+        let mut formal_parameters = FormalParameters::default();
+
+        while self.current_token != Token::RightParen {
+            // FunctionRestParameter : BindingRestElement
+            if self.current_token == Token::Spread {
+                self.advance(); // Eat '...' token.
+
+                formal_parameters.rest = Some(self.js_parse_binding_identifier()?);
+
+                if self.current_token == Token::Assign {
+                    return self.error(CodeGenError::RestParameterInitializer);
+                }
+
+                // NOTE: A FunctionRestParameter may not be followed by another parameter or a trailing comma.
+                if self.current_token != Token::RightParen {
+                    return self.error(CodeGenError::RestParameterNotLast);
+                }
+
+                break;
+            }
+
+            // FormalParameter : BindingElement
+            // BindingElement : SingleNameBinding
+            // SingleNameBinding : BindingIdentifier Initializer?
+            let name = self.js_parse_binding_identifier()?;
+
+            let initializer = if self.current_token == Token::Assign {
+                self.advance(); // Eat '=' token.
+
+                Some(self.js_parse_parameter_initializer()?)
+            } else {
+                None
+            };
+
+            formal_parameters
+                .parameters
+                .push(FormalParameter { name, initializer });
+
+            if self.current_token != Token::Comma {
+                break;
+            }
+
+            self.advance(); // Eat ',' token.
+        }
+
+        Ok(formal_parameters)
+    }
+
+    /// 15.1 Parameter Lists
+    /// https://262.ecma-international.org/16.0/#prod-Initializer
+    ///
+    /// Compiles the Initializer of a parameter into its own program, which returns the value of its AssignmentExpression when it is evaluated by FunctionDeclarationInstantiation.
+    fn js_parse_parameter_initializer(&mut self) -> CodeGenResult<ExecutableProgram> {
+        let enclosing_bytecode = std::mem::take(&mut self.bytecode);
+
+        let span_index = self.begin_span();
+
+        // SingleNameBinding : BindingIdentifier Initializer
+        // 5. a. Let defaultValue be ? Evaluation of Initializer.
+        // 5. b. Set v to ? GetValue(defaultValue).
+        // TODO: Name anonymous functions after their parameter, as IsAnonymousFunctionDefinition(Initializer) requires.
+        let initializer = self.js_parse_assignment_expression();
+
+        self.bytecode.end_span(span_index, self.previous_span.end);
+        self.bytecode.emit_instruction(Instruction::Return);
+        self.bytecode.set_strict(self.strict);
+
+        let mut initializer_bytecode = std::mem::replace(&mut self.bytecode, enclosing_bytecode);
+
+        initializer?;
+
+        initializer_bytecode.optimize(self.optimization_level);
+
+        Ok(initializer_bytecode.program())
+    }
+
+    /// 15.2 Function Definitions
+    /// https://262.ecma-international.org/16.0/#prod-FunctionBody
+    ///
+    /// Returns whether the body begins with a Use Strict Directive.
+    fn js_parse_function_body(
+        &mut self,
+        formal_parameters: &FormalParameters,
+    ) -> CodeGenResult<bool> {
+        self.expect(Token::LeftBrace)?;
+
+        // 10.2.11 FunctionDeclarationInstantiation ( func, argumentsList )
+        // 34. For each element d of lexDeclarations, do
+        // It is a Syntax Error if any element of the BoundNames of FormalParameters also occurs in the LexicallyDeclaredNames of FunctionBody.
+        self.js_instantiate_lexical_declarations(&formal_parameters.bound_names())?;
+
+        let contains_use_strict = self.js_parse_directive_prologue()?;
+
+        self.js_parse_braced_statement_list();
+
+        self.expect(Token::RightBrace)?;
+
+        // 10.2.11 FunctionDeclarationInstantiation ( func, argumentsList )
+        // 18. Else if hasParameterExpressions is false, then
+        // a. If functionNames contains "arguments" or lexicalNames contains "arguments", then
+        // i. Set argumentsObjectNeeded to false.
+        // NOTE: The lexically declared names of the body are only known to the parser, so a function which declares `arguments` is not flagged as referencing it.
+        if !formal_parameters.contains_expression()
+            && self.scopes.last().is_some_and(|scope| {
+                scope
+                    .iter()
+                    .any(|(name, _)| *name == Atom::from("arguments"))
+            })
+        {
+            self.references_arguments = false;
+        }
+
+        self.scopes.pop();
+
+        // 15.2.3 Runtime Semantics: EvaluateFunctionBody
+        // NOTE: A body which completes normally returns undefined.
+        self.bytecode.emit_instruction(Instruction::Undefined);
+        self.bytecode.emit_instruction(Instruction::Return);
+
+        Ok(contains_use_strict)
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// 15.5 Generator Function Definitions
+    /// https://262.ecma-international.org/16.0/#prod-YieldExpression
+    pub(crate) fn js_parse_yield_expression(&mut self) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::Yield))?;
+
+        // YieldExpression : yield * AssignmentExpression
+        if self.current_token == Token::Multiply {
+            self.advance(); // Eat '*' token.
+
+            // 1. Let generatorKind be GetGeneratorKind().
+            // 2. Assert: generatorKind is either sync or async.
+            // 3. Let exprRef be ? Evaluation of AssignmentExpression.
+            // 4. Let value be ? GetValue(exprRef).
+            self.js_parse_assignment_expression()?;
+
+            // 5. Let iteratorRecord be ? GetIterator(value, generatorKind).
+            // 7. Repeat,
+            self.bytecode.emit_instruction(Instruction::YieldDelegate);
+
+            return Ok(());
+        }
+
+        // YieldExpression : yield
+        // 1. Return ? Yield(undefined).
+        if matches!(
+            self.current_token,
+            Token::RightParen
+                | Token::RightBracket
+                | Token::RightBrace
+                | Token::Comma
+                | Token::Semicolon
+                | Token::Colon
+                | Token::Eof
+        ) {
+            self.bytecode.emit_instruction(Instruction::Undefined);
+        }
+        // YieldExpression : yield AssignmentExpression
+        else {
+            // 1. Let exprRef be ? Evaluation of AssignmentExpression.
+            // 2. Let value be ? GetValue(exprRef).
+            self.js_parse_assignment_expression()?;
+        }
+
+        // 3. Return ? Yield(value).
+        self.bytecode.emit_instruction(Instruction::Yield);
+
+        Ok(())
+    }
+}
+
+fn has_duplicates(formal_parameters: &[Atom]) -> bool {
+    formal_parameters
+        .iter()
+        .enumerate()
+        .any(|(index, name)| formal_parameters[..index].contains(name))
+}
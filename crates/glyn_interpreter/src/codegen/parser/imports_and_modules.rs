@@ -1,20 +1,562 @@
-use crate::codegen::parser::{CodeGenResult, Parser};
+use crate::{
+    codegen::{
+        error::CodeGenError,
+        parser::{expression::string_literal_value, CodeGenResult, Parser},
+    },
+    lexer::{Keyword, Token},
+    value::{atom::Atom, string::JSString},
+};
 
 /// 16 ECMAScript Language: Scripts and Modules
 /// https://262.ecma-international.org/16.0/#sec-ecmascript-language-scripts-and-modules
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum ProgramSource {
     Script,
     Module,
 }
 
+impl std::fmt::Display for ProgramSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProgramSource::Script => write!(f, "script"),
+            ProgramSource::Module => write!(f, "module"),
+        }
+    }
+}
+
+/// The name a module binds to `default` when it is exported by `export default` followed by an expression.
+const DEFAULT_EXPORT_BINDING_NAME: &str = "*default*";
+
+/// 16.2.1.6 Source Text Module Records
+/// https://262.ecma-international.org/16.0/#importentry-record
+#[derive(Clone, Debug)]
+pub(crate) struct ImportEntry {
+    /// [[ModuleRequest]]
+    pub(crate) module_request: JSString,
+
+    /// [[ImportName]]
+    pub(crate) import_name: ImportName,
+
+    /// [[LocalName]]
+    pub(crate) local_name: Atom,
+}
+
+/// The [[ImportName]] of an ImportEntry Record.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ImportName {
+    Name(Atom),
+    /// The import binds the module namespace object of the imported module.
+    NamespaceObject,
+}
+
+/// 16.2.1.6 Source Text Module Records
+/// https://262.ecma-international.org/16.0/#exportentry-record
+#[derive(Clone, Debug)]
+pub(crate) struct ExportEntry {
+    /// [[ExportName]]
+    pub(crate) export_name: Option<Atom>,
+
+    /// [[ModuleRequest]]
+    pub(crate) module_request: Option<JSString>,
+
+    /// [[ImportName]]
+    pub(crate) import_name: Option<ExportImportName>,
+
+    /// [[LocalName]]
+    pub(crate) local_name: Option<Atom>,
+}
+
+/// The [[ImportName]] of an ExportEntry Record.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ExportImportName {
+    Name(Atom),
+    /// `export * as ns from "mod"`, which exports the module namespace object of the requested module.
+    All,
+    /// `export * from "mod"`, which exports every name the requested module exports other than `default`.
+    AllButDefault,
+}
+
+/// The static semantics of a Module which ParseModule records in its Source Text Module Record.
+#[derive(Debug, Default)]
+pub(crate) struct ModuleEntries {
+    /// ModuleRequests, in source text order and without duplicates.
+    pub(crate) requested_modules: Vec<JSString>,
+
+    /// ImportEntries
+    pub(crate) import_entries: Vec<ImportEntry>,
+
+    /// ExportEntries
+    pub(crate) export_entries: Vec<ExportEntry>,
+
+    /// The bound names of the let and const declarations of the module along with whether each is a constant declaration, which InitializeEnvironment creates bindings for.
+    pub(crate) lexical_declarations: Vec<(Atom, bool)>,
+}
+
 impl<'a> Parser<'a> {
     /// 16.1 Scripts
     /// https://262.ecma-international.org/16.0/#prod-Script
     pub(crate) fn js_parse_script(&mut self) -> CodeGenResult {
-        // TODO Parse prologue if present.
+        // 16.1.7 GlobalDeclarationInstantiation ( script, env )
+        // 15. For each element d of lexDeclarations, do
+        let lexically_declared_names = self.js_instantiate_lexical_declarations(&[])?;
+
+        self.bytecode
+            .set_lexically_declared_names(lexically_declared_names);
+
+        self.js_parse_directive_prologue()?;
 
         self.js_parse_statement_list()?;
 
+        self.scopes.pop();
+
+        self.bytecode.set_strict(self.strict);
+
+        Ok(())
+    }
+
+    /// 16.2 Modules
+    /// https://262.ecma-international.org/16.0/#prod-Module
+    pub(crate) fn js_parse_module(&mut self) -> CodeGenResult {
+        // 11.2.2 Strict Mode Code
+        // Module code is always strict mode code.
+        self.strict = true;
+
+        // NOTE: The bindings of a module are created by InitializeEnvironment when the module is linked, before any module of the graph is evaluated, rather than by instructions at the start of its bytecode.
+        (self.module.lexical_declarations, _) = self.js_declare_lexical_declarations(&[], false)?;
+
+        // ModuleItem : ImportDeclaration | ExportDeclaration | StatementListItem
+        while !self.is_eof() {
+            let recovery_point = self.recovery_point(false);
+
+            // NOTE: An ImportCall or ImportMeta begins an ExpressionStatement rather than an ImportDeclaration.
+            let is_import_declaration = self.current_token == Token::Keyword(Keyword::Import)
+                && !matches!(self.peek(), Some(Token::LeftParen | Token::Dot));
+
+            let result = match self.current_token {
+                Token::Keyword(Keyword::Import) if is_import_declaration => {
+                    self.js_parse_import_declaration()
+                }
+                Token::Keyword(Keyword::Export) => self.js_parse_export_declaration(),
+                _ => self.js_parse_statement(),
+            };
+
+            if let Err(error) = result {
+                self.recover(recovery_point, error);
+            }
+        }
+
+        self.scopes.pop();
+
+        // 16.2.1.1 Static Semantics: Early Errors
+        // It is a Syntax Error if the ExportedNames of ModuleItemList contains any duplicate entries.
+        let exported_names = self
+            .module
+            .export_entries
+            .iter()
+            .filter_map(|entry| entry.export_name.clone())
+            .collect::<Vec<_>>();
+
+        if has_duplicates(&exported_names) {
+            return self.error(CodeGenError::DuplicateExport);
+        }
+
+        // It is a Syntax Error if the LexicallyDeclaredNames of ModuleItemList contains any duplicate entries.
+        let lexically_declared_names = self
+            .module
+            .lexical_declarations
+            .iter()
+            .map(|(name, _)| name.clone())
+            .chain(
+                self.module
+                    .import_entries
+                    .iter()
+                    .map(|entry| entry.local_name.clone()),
+            )
+            .chain(self.bytecode.declared_function_names())
+            .collect::<Vec<_>>();
+
+        if has_duplicates(&lexically_declared_names) {
+            return self.error(CodeGenError::DuplicateDeclaration);
+        }
+
+        // It is a Syntax Error if any element of the ExportedBindings of ModuleItemList does not also occur in either the VarDeclaredNames of ModuleItemList, or the LexicallyDeclaredNames of ModuleItemList.
+        let has_undeclared_export = self.module.export_entries.iter().any(|entry| {
+            entry.module_request.is_none()
+                && entry
+                    .local_name
+                    .as_ref()
+                    .is_some_and(|name| !lexically_declared_names.contains(name))
+        });
+
+        if has_undeclared_export {
+            return self.error(CodeGenError::UndeclaredExport);
+        }
+
+        self.bytecode.set_strict(true);
+
+        Ok(())
+    }
+
+    /// Takes the import and export entries of the module which was parsed.
+    pub(crate) fn take_module_entries(&mut self) -> ModuleEntries {
+        std::mem::take(&mut self.module)
+    }
+
+    /// 16.2.2 Imports
+    /// https://262.ecma-international.org/16.0/#prod-ImportDeclaration
+    fn js_parse_import_declaration(&mut self) -> CodeGenResult {
+        self.advance(); // Eat 'import' token.
+
+        // ImportDeclaration : import ModuleSpecifier ;
+        if let Token::String(_) = self.current_token {
+            self.js_parse_module_specifier()?;
+
+            self.optional(Token::Semicolon);
+
+            return Ok(());
+        }
+
+        // ImportDeclaration : import ImportClause FromClause ;
+        let mut bindings = vec![];
+
+        // ImportClause : ImportedDefaultBinding
+        if self.current_token.is_binding_identifier() {
+            let local_name = self.js_parse_binding_identifier()?;
+
+            bindings.push((ImportName::Name(Atom::from("default")), local_name));
+
+            // ImportClause : ImportedDefaultBinding , NameSpaceImport
+            // ImportClause : ImportedDefaultBinding , NamedImports
+            if self.current_token == Token::Comma {
+                self.advance(); // Eat ',' token.
+
+                self.js_parse_import_bindings(&mut bindings)?;
+            }
+        } else {
+            self.js_parse_import_bindings(&mut bindings)?;
+        }
+
+        // FromClause : from ModuleSpecifier
+        self.expect(Token::Keyword(Keyword::From))?;
+
+        let module_request = self.js_parse_module_specifier()?;
+
+        self.optional(Token::Semicolon);
+
+        // NOTE: The bindings of imports are immutable, so assigning to them is reported early like assigning to a constant.
+        for (import_name, local_name) in bindings {
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.push((local_name.clone(), true));
+            }
+
+            self.module.import_entries.push(ImportEntry {
+                module_request: module_request.clone(),
+                import_name,
+                local_name,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// 16.2.2 Imports
+    /// https://262.ecma-international.org/16.0/#prod-NameSpaceImport
+    /// https://262.ecma-international.org/16.0/#prod-NamedImports
+    fn js_parse_import_bindings(
+        &mut self,
+        bindings: &mut Vec<(ImportName, Atom)>,
+    ) -> CodeGenResult {
+        // NameSpaceImport : * as ImportedBinding
+        if self.current_token == Token::Multiply {
+            self.advance(); // Eat '*' token.
+
+            self.expect(Token::Keyword(Keyword::As))?;
+
+            bindings.push((
+                ImportName::NamespaceObject,
+                self.js_parse_binding_identifier()?,
+            ));
+
+            return Ok(());
+        }
+
+        // NamedImports : { ImportsList , }
+        self.expect(Token::LeftBrace)?;
+
+        while self.current_token != Token::RightBrace {
+            // ImportSpecifier : ModuleExportName as ImportedBinding
+            if self.peek() == Some(&Token::Keyword(Keyword::As))
+                || matches!(self.current_token, Token::String(_))
+            {
+                let import_name = self.js_parse_module_export_name()?;
+
+                self.expect(Token::Keyword(Keyword::As))?;
+
+                bindings.push((
+                    ImportName::Name(import_name),
+                    self.js_parse_binding_identifier()?,
+                ));
+            }
+            // ImportSpecifier : ImportedBinding
+            else {
+                let local_name = self.js_parse_binding_identifier()?;
+
+                bindings.push((ImportName::Name(local_name.clone()), local_name));
+            }
+
+            if self.current_token != Token::Comma {
+                break;
+            }
+
+            self.advance(); // Eat ',' token.
+        }
+
+        self.expect(Token::RightBrace)
+    }
+
+    /// 16.2.3 Exports
+    /// https://262.ecma-international.org/16.0/#prod-ExportDeclaration
+    fn js_parse_export_declaration(&mut self) -> CodeGenResult {
+        let span_index = self.begin_span();
+
+        self.advance(); // Eat 'export' token.
+
+        match self.current_token {
+            // ExportDeclaration : export ExportFromClause FromClause ;
+            Token::Multiply => {
+                self.advance(); // Eat '*' token.
+
+                // ExportFromClause : * as ModuleExportName
+                let (export_name, import_name) =
+                    if self.current_token == Token::Keyword(Keyword::As) {
+                        self.advance(); // Eat 'as' token.
+
+                        (
+                            Some(self.js_parse_module_export_name()?),
+                            ExportImportName::All,
+                        )
+                    }
+                    // ExportFromClause : *
+                    else {
+                        (None, ExportImportName::AllButDefault)
+                    };
+
+                self.expect(Token::Keyword(Keyword::From))?;
+
+                let module_request = self.js_parse_module_specifier()?;
+
+                self.module.export_entries.push(ExportEntry {
+                    export_name,
+                    module_request: Some(module_request),
+                    import_name: Some(import_name),
+                    local_name: None,
+                });
+            }
+            // ExportDeclaration : export NamedExports ;
+            // ExportDeclaration : export ExportFromClause FromClause ;
+            Token::LeftBrace => self.js_parse_named_exports()?,
+            // ExportDeclaration : export Declaration
+            Token::Keyword(Keyword::Let | Keyword::Const) => {
+                for name in self.js_parse_lexical_declaration()? {
+                    self.add_local_export(name.clone(), name);
+                }
+            }
+            // ExportDeclaration : export HoistableDeclaration
+            Token::Keyword(Keyword::Function) => self.js_parse_exported_function_declaration()?,
+            Token::Keyword(Keyword::Async) if self.is_async_function_start() => {
+                self.js_parse_exported_function_declaration()?
+            }
+            // ExportDeclaration : export default HoistableDeclaration
+            // ExportDeclaration : export default AssignmentExpression ;
+            Token::Keyword(Keyword::Default) => self.js_parse_default_export()?,
+            _ => return self.unexpected_token(&[]),
+        }
+
+        self.optional(Token::Semicolon);
+
+        self.bytecode.end_span(span_index, self.previous_span.end);
+
+        Ok(())
+    }
+
+    /// 16.2.3 Exports
+    /// https://262.ecma-international.org/16.0/#prod-NamedExports
+    fn js_parse_named_exports(&mut self) -> CodeGenResult {
+        self.expect(Token::LeftBrace)?;
+
+        // NOTE: A local name which is a string literal is only allowed if the names are re-exported from another module.
+        let mut specifiers = vec![];
+
+        // ExportsList : ExportsList , ExportSpecifier
+        while self.current_token != Token::RightBrace {
+            let is_string_literal = matches!(self.current_token, Token::String(_));
+
+            // ExportSpecifier : ModuleExportName
+            let local_name = self.js_parse_module_export_name()?;
+
+            // ExportSpecifier : ModuleExportName as ModuleExportName
+            let export_name = if self.current_token == Token::Keyword(Keyword::As) {
+                self.advance(); // Eat 'as' token.
+
+                self.js_parse_module_export_name()?
+            } else {
+                local_name.clone()
+            };
+
+            specifiers.push((local_name, export_name, is_string_literal));
+
+            if self.current_token != Token::Comma {
+                break;
+            }
+
+            self.advance(); // Eat ',' token.
+        }
+
+        self.expect(Token::RightBrace)?;
+
+        // ExportDeclaration : export ExportFromClause FromClause ;
+        if self.current_token == Token::Keyword(Keyword::From) {
+            self.advance(); // Eat 'from' token.
+
+            let module_request = self.js_parse_module_specifier()?;
+
+            for (import_name, export_name, _) in specifiers {
+                self.module.export_entries.push(ExportEntry {
+                    export_name: Some(export_name),
+                    module_request: Some(module_request.clone()),
+                    import_name: Some(ExportImportName::Name(import_name)),
+                    local_name: None,
+                });
+            }
+
+            return Ok(());
+        }
+
+        // ExportDeclaration : export NamedExports ;
+        for (local_name, export_name, is_string_literal) in specifiers {
+            // It is a Syntax Error if ReferencedBindings of NamedExports contains any StringLiterals.
+            if is_string_literal {
+                return self.unexpected_token(&[]);
+            }
+
+            self.add_local_export(local_name, export_name);
+        }
+
         Ok(())
     }
+
+    /// ExportDeclaration : export HoistableDeclaration
+    fn js_parse_exported_function_declaration(&mut self) -> CodeGenResult {
+        let Some(name) = self.function_declaration_name() else {
+            return self.unexpected_token(&[]);
+        };
+
+        self.js_parse_function_declaration()?;
+
+        self.add_local_export(name.clone(), name);
+
+        Ok(())
+    }
+
+    /// ExportDeclaration : export default HoistableDeclaration
+    /// ExportDeclaration : export default AssignmentExpression ;
+    fn js_parse_default_export(&mut self) -> CodeGenResult {
+        self.advance(); // Eat 'default' token.
+
+        let is_function_declaration = self.current_token == Token::Keyword(Keyword::Function)
+            || self.is_async_function_start();
+
+        // ExportDeclaration : export default HoistableDeclaration
+        if is_function_declaration {
+            if let Some(name) = self.function_declaration_name() {
+                self.js_parse_function_declaration()?;
+
+                self.add_local_export(name, Atom::from("default"));
+
+                return Ok(());
+            }
+        }
+
+        // ExportDeclaration : export default AssignmentExpression ;
+        // NOTE: An anonymous default function declaration is evaluated where it appears rather than being hoisted, like an AssignmentExpression.
+        let local_name = Atom::from(DEFAULT_EXPORT_BINDING_NAME);
+
+        self.module
+            .lexical_declarations
+            .push((local_name.clone(), false));
+
+        self.add_local_export(local_name.clone(), Atom::from("default"));
+
+        // 16.2.3.7 Runtime Semantics: Evaluation
+        // 1. If IsAnonymousFunctionDefinition(AssignmentExpression) is true, then
+        // a. Let value be ? NamedEvaluation of AssignmentExpression with argument "default".
+        // TODO: Name anonymous functions after their assignment target.
+        // 2. Else,
+        // a. Let rhs be ? Evaluation of AssignmentExpression.
+        // b. Let value be ? GetValue(rhs).
+        let binding_index = self.bytecode.add_identifier(local_name);
+
+        self.bytecode.emit_resolve_binding(binding_index);
+
+        self.js_parse_assignment_expression()?;
+
+        // 3. Let env be the running execution context's LexicalEnvironment.
+        // 4. Perform ? InitializeBoundName("*default*", value, env).
+        self.bytecode.emit_initialize_referenced_binding();
+
+        // 5. Return empty.
+        Ok(())
+    }
+
+    /// 16.2.2 Imports
+    /// https://262.ecma-international.org/16.0/#prod-ModuleSpecifier
+    ///
+    /// Returns the StringValue of the specifier, which is also added to the ModuleRequests of the module.
+    fn js_parse_module_specifier(&mut self) -> CodeGenResult<JSString> {
+        let Token::String(value) = self.current_token else {
+            return self.unexpected_token(&[]);
+        };
+
+        let specifier = JSString::from(string_literal_value(value));
+
+        self.advance(); // Eat the specifier token.
+
+        if !self.module.requested_modules.contains(&specifier) {
+            self.module.requested_modules.push(specifier.clone());
+        }
+
+        Ok(specifier)
+    }
+
+    /// 16.2.2 Imports
+    /// https://262.ecma-international.org/16.0/#prod-ModuleExportName
+    fn js_parse_module_export_name(&mut self) -> CodeGenResult<Atom> {
+        let name = match self.current_token {
+            // ModuleExportName : StringLiteral
+            Token::String(value) => string_literal_value(value),
+            // ModuleExportName : IdentifierName
+            ref token if token.is_identifier_name() => token.to_string(),
+            _ => return self.unexpected_token(&[]),
+        };
+
+        self.advance(); // Eat the name token.
+
+        Ok(Atom::from(name))
+    }
+
+    fn add_local_export(&mut self, local_name: Atom, export_name: Atom) {
+        self.module.export_entries.push(ExportEntry {
+            export_name: Some(export_name),
+            module_request: None,
+            import_name: None,
+            local_name: Some(local_name),
+        });
+    }
+}
+
+fn has_duplicates(names: &[Atom]) -> bool {
+    names
+        .iter()
+        .enumerate()
+        .any(|(index, name)| names[..index].contains(name))
 }
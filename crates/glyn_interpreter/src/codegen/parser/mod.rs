@@ -1,52 +1,447 @@
 mod expression;
+mod functions_and_classes;
 mod imports_and_modules;
 mod statement;
 
-use std::iter::Peekable;
+pub(crate) use imports_and_modules::{
+    ExportEntry, ExportImportName, ImportEntry, ImportName, ModuleEntries, ProgramSource,
+};
 
 use crate::{
     codegen::{
-        bytecode::generator::{BytecodeGenerator, ExecutableProgram},
+        bytecode::{
+            generator::{BytecodeGenerator, ExecutableProgram, JumpTargetDepth},
+            optimizer::OptimizationLevel,
+        },
         error::{CodeGenError, CodeGenResult},
+        warning::{Warning, WarningKind},
     },
-    lexer::{Lexer, Token},
+    lexer::{Keyword, Lexer, LexerDiagnostic, Span, Token, TokenKind},
+    value::atom::Atom,
 };
 
+/// The maximum number of expressions, statements and patterns which can be nested within each other.
+pub(crate) const MAX_NESTING_DEPTH: usize = 1000;
+
+/// The amount of stack left below which the parser grows the stack before parsing a nested construct, which is more than a construct takes up until its nested constructs are parsed, even in a debug build.
+#[cfg(not(target_arch = "wasm32"))]
+const STACK_RED_ZONE: usize = 256 * 1024;
+
+/// The size of each stack segment the parser grows the stack by.
+#[cfg(not(target_arch = "wasm32"))]
+const STACK_SEGMENT_SIZE: usize = 4 * 1024 * 1024;
+
+/// Runs the function on a new stack segment if less than [`STACK_RED_ZONE`] of the current one is left.
+#[cfg(not(target_arch = "wasm32"))]
+fn grow_stack<T>(function: impl FnOnce() -> T) -> T {
+    stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT_SIZE, function)
+}
+
+/// Runs the function on the current stack, as the stack of WebAssembly can't be grown.
+#[cfg(target_arch = "wasm32")]
+fn grow_stack<T>(function: impl FnOnce() -> T) -> T {
+    function()
+}
+
 pub(crate) struct Parser<'a> {
     bytecode: BytecodeGenerator,
-    lexer: Peekable<Lexer<'a>>,
+    lexer: Lexer<'a>,
+    current_token: Token<'a>,
+    current_span: Span,
+    previous_span: Span,
+    next: Option<(Token<'a>, Span)>,
+    in_function_body: bool,
+    /// Whether the code being parsed is the body of a generator function, where `yield` is a YieldExpression rather than an identifier.
+    in_generator_body: bool,
+    /// Whether the code being parsed is the body of an async function, where `await` is an AwaitExpression rather than an identifier.
+    in_async_body: bool,
+    /// Whether the code being parsed is a formal parameter list, which may not contain a YieldExpression or an AwaitExpression.
+    in_formal_parameters: bool,
+    /// Whether the code being parsed is within the formal parameters or body of a method, where `super` may be used to reference the properties of its home object's prototype.
+    in_method: bool,
+    /// Whether the code of the innermost enclosing function references `arguments`.
+    references_arguments: bool,
+    /// Whether the code being parsed is strict mode code.
+    strict: bool,
+    /// The lexically declared names of each enclosing scope, innermost last, along with whether they are constant.
+    scopes: Vec<Vec<(Atom, bool)>>,
+    /// The number of enclosing scopes when the body of the innermost enclosing function or the script was entered, whose scope is the first of the VariableEnvironment.
+    var_scope_depth: usize,
+    /// The index of the function declarations of the block whose statement list is being parsed, if it declares any functions, which are instantiated when the block is entered.
+    block_functions: Option<u32>,
+    /// The number of enclosing scopes when the innermost enclosing with statement was entered, as identifiers within it may resolve to properties of its object rather than to the bindings of those scopes.
+    with_scope_depth: usize,
+    /// The span of the last token of the most recently parsed OptionalExpression, which evaluates to a Reference Record but is not a valid assignment target.
+    optional_expression_end: Option<Span>,
+    /// The import and export entries of the module being parsed, if the code being parsed is a Module.
+    module: ModuleEntries,
+    warnings: Vec<Warning>,
+    /// The errors of the statements which failed to parse, along with the span each was found at, which the parser recovered from by skipping to the end of the statement.
+    errors: Vec<(CodeGenError, Span)>,
+    /// The level at which the program and each function in it are optimized once they have been generated.
+    optimization_level: OptimizationLevel,
+    /// The number of expressions, statements and patterns being parsed which enclose the current token.
+    nesting_depth: usize,
+}
+
+/// The position of a parser in the token stream, which can be returned to with [`Parser::restore`].
+struct Checkpoint<'a> {
+    lexer_position: usize,
     current_token: Token<'a>,
+    current_span: Span,
+    previous_span: Span,
+    next: Option<(Token<'a>, Span)>,
+}
+
+/// The state of a parser at the start of a statement, which is returned to if the statement fails to parse so that the statements after it can still be parsed.
+struct RecoveryPoint<'a> {
+    checkpoint: Checkpoint<'a>,
+    in_function_body: bool,
+    in_generator_body: bool,
+    in_async_body: bool,
+    in_formal_parameters: bool,
+    in_method: bool,
+    strict: bool,
+    scope_depth: usize,
+    block_functions: Option<u32>,
+    with_scope_depth: usize,
+    jump_target_depth: JumpTargetDepth,
+    /// Whether the statement is within the braces of a block or function body, whose closing brace ends the statement without being skipped past.
+    within_braces: bool,
 }
 
 impl<'a> Parser<'a> {
-    pub(crate) fn new(lexer: Lexer<'a>) -> Self {
-        let mut lexer = lexer.peekable();
+    pub(crate) fn new(mut lexer: Lexer<'a>) -> Self {
+        let (current_token, current_span) = lexer
+            .next_token()
+            .unwrap_or((Token::Illegal, Span::default()));
 
-        let current_token = lexer.next().unwrap_or(Token::Illegal);
+        let next = lexer.next_token();
 
         Self {
             current_token,
+            current_span,
+            previous_span: Span::default(),
+            next,
             lexer,
             bytecode: BytecodeGenerator::default(),
+            in_function_body: false,
+            in_generator_body: false,
+            in_async_body: false,
+            in_formal_parameters: false,
+            in_method: false,
+            references_arguments: false,
+            strict: false,
+            scopes: vec![],
+            var_scope_depth: 0,
+            block_functions: None,
+            with_scope_depth: 0,
+            optional_expression_end: None,
+            module: ModuleEntries::default(),
+            warnings: vec![],
+            errors: vec![],
+            optimization_level: OptimizationLevel::default(),
+            nesting_depth: 0,
         }
     }
 
-    pub(crate) fn program(self) -> ExecutableProgram {
+    /// Sets whether the code is strict mode code from its start, before any Use Strict Directive.
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    pub(crate) fn set_optimization_level(&mut self, level: OptimizationLevel) {
+        self.optimization_level = level;
+    }
+
+    pub(crate) fn program(mut self) -> ExecutableProgram {
+        self.bytecode.optimize(self.optimization_level);
+
         self.bytecode.program()
     }
 
+    /// Takes the warnings reported so far, including those reported before a parse error.
+    pub(crate) fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Takes the errors of the statements the parser recovered from, along with the span each was found at.
+    pub(crate) fn take_errors(&mut self) -> Vec<(CodeGenError, Span)> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// The span of the current token, which is where the parser stopped if it returned an error.
+    pub(crate) fn current_span(&self) -> Span {
+        self.current_span
+    }
+
+    /// The errors the lexer found in the source text read so far, which the parser carries on past as the characters they were found at are skipped.
+    pub(crate) fn lexer_diagnostics(&self) -> &[LexerDiagnostic] {
+        self.lexer.diagnostics()
+    }
+
+    /// Opens a span entry at the current token for the instructions emitted from this point onwards, returning its index so the end of the span can be filled in once the source construct has been fully parsed.
+    fn begin_span(&mut self) -> usize {
+        let start = self.current_span.start;
+
+        self.bytecode
+            .begin_span(start, self.lexer.source_position(start))
+    }
+
     fn error<T>(&self, error: CodeGenError) -> CodeGenResult<T> {
         Err(error)
     }
 
+    /// Fails at the current token, which is not one of the expected tokens, or is not allowed where it was found if none are given.
+    fn unexpected_token<T>(&self, expected: &[Token]) -> CodeGenResult<T> {
+        self.error(CodeGenError::UnexpectedToken {
+            expected: expected.iter().map(TokenKind::from).collect(),
+            found: TokenKind::from(&self.current_token),
+            span: self.current_span,
+        })
+    }
+
+    /// Parses a construct which can contain itself, failing instead if it is nested more deeply than [`MAX_NESTING_DEPTH`].
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> CodeGenResult<T>) -> CodeGenResult<T> {
+        if self.nesting_depth >= MAX_NESTING_DEPTH {
+            return self.error(CodeGenError::NestingTooDeep);
+        }
+
+        self.nesting_depth += 1;
+
+        let result = self.deeper(parse);
+
+        self.nesting_depth -= 1;
+
+        result
+    }
+
+    /// Parses part of the construct being parsed which can recurse without being nested in the source text, such as the right operand of a binary operator, growing the stack first if too little of it is left.
+    ///
+    /// NOTE: The stack is grown rather than the depth limited to fit it, as each nested construct takes up more than 10 KiB of the stack in a debug build.
+    fn deeper<T>(&mut self, parse: impl FnOnce(&mut Self) -> CodeGenResult<T>) -> CodeGenResult<T> {
+        grow_stack(|| parse(self))
+    }
+
+    /// Reports a warning for the construct spanning from `start` to the end of the current token.
+    fn warn(&mut self, kind: WarningKind, start: usize) {
+        self.warnings.push(Warning {
+            kind,
+            start,
+            end: self.current_span.end,
+        });
+    }
+
     fn advance(&mut self) -> &Token {
-        self.current_token = self.lexer.next().unwrap_or(Token::Eof);
+        let end = Span::new(self.current_span.end, self.current_span.end);
+
+        let (token, span) = self.next.take().unwrap_or((Token::Eof, end));
+
+        self.previous_span = self.current_span;
+        self.current_token = token;
+        self.current_span = span;
+
+        self.next = self.lexer.next_token();
 
         &self.current_token
     }
 
+    /// Lexes the current '}' token again as the TemplateMiddle or TemplateTail it starts, as it ends a substitution of a template.
+    fn rescan_template_continuation(&mut self) {
+        let end = Span::new(self.current_span.end, self.current_span.end);
+
+        let (token, span) = self
+            .lexer
+            .next_template_continuation(self.current_span.start)
+            .unwrap_or((Token::Eof, end));
+
+        self.current_token = token;
+        self.current_span = span;
+
+        self.next = self.lexer.next_token();
+    }
+
+    fn checkpoint(&self) -> Checkpoint<'a> {
+        Checkpoint {
+            lexer_position: self.lexer.position(),
+            current_token: self.current_token.clone(),
+            current_span: self.current_span,
+            previous_span: self.previous_span,
+            next: self.next.clone(),
+        }
+    }
+
+    /// Returns to a checkpoint, so that the tokens after it are parsed again.
+    fn restore(&mut self, checkpoint: Checkpoint<'a>) {
+        self.lexer.rewind(checkpoint.lexer_position);
+
+        self.current_token = checkpoint.current_token;
+        self.current_span = checkpoint.current_span;
+        self.previous_span = checkpoint.previous_span;
+        self.next = checkpoint.next;
+    }
+
+    fn recovery_point(&self, within_braces: bool) -> RecoveryPoint<'a> {
+        RecoveryPoint {
+            checkpoint: self.checkpoint(),
+            in_function_body: self.in_function_body,
+            in_generator_body: self.in_generator_body,
+            in_async_body: self.in_async_body,
+            in_formal_parameters: self.in_formal_parameters,
+            in_method: self.in_method,
+            strict: self.strict,
+            scope_depth: self.scopes.len(),
+            block_functions: self.block_functions,
+            with_scope_depth: self.with_scope_depth,
+            jump_target_depth: self.bytecode.jump_target_depth(),
+            within_braces,
+        }
+    }
+
+    /// Records the error of a statement which failed to parse, then returns to the start of the statement and skips past the token the error was found at, up to the end of the statement.
+    ///
+    /// The statement ends at a semicolon or a line break which is not within brackets, at the closing brace of a block, or after a closing bracket which was never opened. The closing brace of the block or function body the statement is within is left for it to be closed by. The bytecode generated for the statement is left as it is, as no program is generated from source text containing errors.
+    fn recover(&mut self, recovery_point: RecoveryPoint<'a>, error: CodeGenError) {
+        let span = error.span().unwrap_or(self.current_span);
+
+        self.errors.push((error, span));
+
+        self.restore(recovery_point.checkpoint);
+
+        self.in_function_body = recovery_point.in_function_body;
+        self.in_generator_body = recovery_point.in_generator_body;
+        self.in_async_body = recovery_point.in_async_body;
+        self.in_formal_parameters = recovery_point.in_formal_parameters;
+        self.in_method = recovery_point.in_method;
+        self.strict = recovery_point.strict;
+        self.scopes.truncate(recovery_point.scope_depth);
+        self.block_functions = recovery_point.block_functions;
+        self.with_scope_depth = recovery_point.with_scope_depth;
+        self.bytecode
+            .truncate_jump_targets(recovery_point.jump_target_depth);
+        self.optional_expression_end = None;
+
+        // The brackets opened since the start of the statement which are still open, along with whether each was opened before the token the error was found at.
+        let mut brackets = vec![];
+
+        while !self.is_eof() {
+            let is_at_error = self.current_span.start >= span.start;
+
+            if self.current_span.start > span.start
+                && brackets.is_empty()
+                && self
+                    .lexer
+                    .has_line_terminator(self.previous_span.end, self.current_span.start)
+            {
+                return;
+            }
+
+            match self.current_token {
+                Token::Semicolon if is_at_error && brackets.is_empty() => {
+                    self.advance();
+
+                    return;
+                }
+                Token::LeftBrace => brackets.push((Token::RightBrace, !is_at_error)),
+                Token::LeftParen => brackets.push((Token::RightParen, !is_at_error)),
+                Token::LeftBracket => brackets.push((Token::RightBracket, !is_at_error)),
+                Token::RightBrace | Token::RightParen | Token::RightBracket => {
+                    // NOTE: A closing bracket also closes any brackets opened within its pair which were left open.
+                    let Some(index) = brackets
+                        .iter()
+                        .rposition(|(bracket, _)| *bracket == self.current_token)
+                    else {
+                        if !(recovery_point.within_braces
+                            && self.current_token == Token::RightBrace)
+                        {
+                            self.advance();
+                        }
+
+                        return;
+                    };
+
+                    let (_, opened_before_error) = brackets[index];
+
+                    brackets.truncate(index);
+
+                    // NOTE: A brace opened at or after the error, like that of an object literal, closes only itself rather than the statement.
+                    if is_at_error
+                        && opened_before_error
+                        && brackets.is_empty()
+                        && self.current_token == Token::RightBrace
+                    {
+                        self.advance();
+
+                        return;
+                    }
+                }
+                _ => {}
+            }
+
+            self.advance();
+        }
+    }
+
+    /// Scans ahead over the tokens from the current token onwards, without consuming them, so the lexer must be rewound to its position beforehand once the scan is done.
+    ///
+    /// NOTE: The '}' ending a substitution of a template is lexed again as the TemplateMiddle or TemplateTail it starts, as the parser does, so that the rest of the template is not scanned as tokens.
+    fn scan_ahead(&mut self) -> impl Iterator<Item = Token<'a>> + '_ {
+        let mut pending = [(self.current_token.clone(), self.current_span)]
+            .into_iter()
+            .chain(self.next.clone());
+        let lexer = &mut self.lexer;
+
+        let mut depth = 0usize;
+        // The number of brackets open when each enclosing substitution of a template was entered, innermost last.
+        let mut templates: Vec<usize> = vec![];
+
+        std::iter::from_fn(move || {
+            let (mut token, span) = pending.next().or_else(|| lexer.next_token())?;
+
+            if token == Token::RightBrace && templates.last() == Some(&depth) {
+                (token, _) = lexer.next_template_continuation(span.start)?;
+            }
+
+            match token {
+                Token::TemplateHead(_) => templates.push(depth),
+                Token::TemplateTail(_) => {
+                    templates.pop();
+                }
+                Token::LeftBrace | Token::LeftParen | Token::LeftBracket => depth += 1,
+                Token::RightBrace | Token::RightParen | Token::RightBracket => {
+                    depth = depth.saturating_sub(1)
+                }
+                _ => {}
+            }
+
+            Some(token)
+        })
+    }
+
+    /// Skips the current token along with everything up to its matching closing bracket, if it is an opening bracket.
+    fn skip_balanced(&mut self) {
+        let mut depth = 0usize;
+
+        loop {
+            match self.current_token {
+                Token::LeftBrace | Token::LeftParen | Token::LeftBracket => depth += 1,
+                Token::RightBrace | Token::RightParen | Token::RightBracket => {
+                    depth = depth.saturating_sub(1)
+                }
+                Token::Eof => return,
+                _ => {}
+            }
+
+            self.advance();
+
+            if depth == 0 {
+                return;
+            }
+        }
+    }
+
     pub(crate) fn peek(&mut self) -> Option<&Token> {
-        self.lexer.peek()
+        self.next.as_ref().map(|(token, _)| token)
     }
 
     fn optional(&mut self, expected_token: Token) {
@@ -57,7 +452,7 @@ impl<'a> Parser<'a> {
 
     fn expect(&mut self, expected_token: Token) -> CodeGenResult {
         if self.current_token != expected_token {
-            return self.error(CodeGenError::UnexpectedToken);
+            return self.unexpected_token(&[expected_token]);
         }
 
         self.advance();
@@ -67,7 +462,7 @@ impl<'a> Parser<'a> {
 
     fn expect_one_of(&mut self, expected_tokens: Vec<Token>) -> CodeGenResult {
         if !expected_tokens.contains(&self.current_token) {
-            return self.error(CodeGenError::UnexpectedToken);
+            return self.unexpected_token(&expected_tokens);
         }
 
         self.advance();
@@ -75,6 +470,63 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    /// Whether the expression which was just parsed is an OptionalExpression.
+    fn is_optional_expression_end(&self) -> bool {
+        self.optional_expression_end == Some(self.previous_span)
+    }
+
+    /// Whether the identifier statically resolves to a const declaration in an enclosing scope.
+    fn is_constant_binding(&self, name: &Atom) -> bool {
+        self.scopes[self.with_scope_depth.min(self.scopes.len())..]
+            .iter()
+            .rev()
+            .find_map(|scope| {
+                scope
+                    .iter()
+                    .find(|(binding_name, _)| binding_name == name)
+                    .map(|(_, is_constant)| *is_constant)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Whether the current token is `yield` within a generator body, where it begins a YieldExpression rather than being an identifier.
+    fn is_yield_expression_start(&self) -> bool {
+        self.in_generator_body && self.current_token == Token::Keyword(Keyword::Yield)
+    }
+
+    /// Whether the current token is `await` within an async function body, where it begins an AwaitExpression rather than being an identifier.
+    fn is_await_expression_start(&self) -> bool {
+        self.in_async_body && self.current_token == Token::Keyword(Keyword::Await)
+    }
+
+    /// Whether the current token is an operator which begins a UnaryExpression, rather than an UpdateExpression.
+    fn is_unary_operator_start(&self) -> bool {
+        matches!(
+            self.current_token,
+            Token::Plus
+                | Token::Minus
+                | Token::Keyword(Keyword::Delete)
+                | Token::Keyword(Keyword::Typeof)
+        ) || self.is_await_expression_start()
+    }
+
+    /// Whether the current token is `async` followed by `function` with no LineTerminator in between, where it begins an async function rather than being an identifier.
+    fn is_async_function_start(&self) -> bool {
+        let Some((Token::Keyword(Keyword::Function), next_span)) = &self.next else {
+            return false;
+        };
+
+        self.current_token == Token::Keyword(Keyword::Async)
+            && !self
+                .lexer
+                .has_line_terminator(self.current_span.end, next_span.start)
+    }
+
+    /// Whether the current token is a LabelIdentifier followed by a colon, which begins a LabelledStatement.
+    fn is_labelled_statement_start(&self) -> bool {
+        self.current_token.is_identifier_reference() && matches!(self.next, Some((Token::Colon, _)))
+    }
+
     fn is_eof(&self) -> bool {
         self.current_token == Token::Eof
     }
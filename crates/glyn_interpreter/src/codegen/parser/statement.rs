@@ -1,18 +1,45 @@
+use std::iter::Peekable;
+
 use crate::{
     codegen::{
         bytecode::instruction::Instruction,
         error::CodeGenError,
         parser::{CodeGenResult, Parser},
+        warning::WarningKind,
     },
     lexer::{Keyword, Token},
+    runtime::iteration::IterationKind,
+    value::atom::Atom,
 };
 
+#[cfg(feature = "annex-b")]
+use crate::codegen::bytecode::generator::GeneratorKind;
+
+/// The bound names of the let and const declarations of a statement list along with whether each is a constant declaration, and the names of its function declarations which are lexically scoped.
+pub(crate) type LexicalDeclarations = (Vec<(Atom, bool)>, Vec<Atom>);
+
 /// 14 ECMAScript Language: Statements and Declarations
 /// https://262.ecma-international.org/16.0/#prod-Statement
 impl<'a> Parser<'a> {
     /// 14 ECMAScript Language: Statements and Declarations
     /// https://262.ecma-international.org/16.0/#prod-Statement
-    fn js_parse_statement(&mut self) -> CodeGenResult {
+    pub(crate) fn js_parse_statement(&mut self) -> CodeGenResult {
+        self.nested(Self::js_parse_statement_unnested)
+    }
+
+    /// Parses a statement which is not nested too deeply to be parsed, as checked by [`Parser::js_parse_statement`].
+    fn js_parse_statement_unnested(&mut self) -> CodeGenResult {
+        trace!(
+            Parser,
+            Trace,
+            "statement at {}: {}",
+            self.current_span.start,
+            self.current_token
+        );
+
+        let span_index = self.begin_span();
+
+        let is_labelled_statement = self.is_labelled_statement_start();
         let current_token = self.current_token.clone();
         let peek_token = self.peek();
 
@@ -20,45 +47,425 @@ impl<'a> Parser<'a> {
             Token::Keyword(Keyword::Let)
                 if peek_token.is_some_and(|token| token.is_lexical_binding_start()) =>
             {
-                self.js_parse_let_declaration()
+                self.js_parse_lexical_declaration().map(drop)
+            }
+            Token::Keyword(Keyword::Const) => self.js_parse_lexical_declaration().map(drop),
+            Token::LeftBrace => self.js_parse_block_statement(&[]),
+            Token::Keyword(Keyword::For) => self.js_parse_for_in_of_statement(vec![]),
+            Token::Keyword(Keyword::Function) => self.js_parse_function_declaration(),
+            Token::Keyword(Keyword::Async) if self.is_async_function_start() => {
+                self.js_parse_function_declaration()
             }
+            Token::Keyword(Keyword::Break) => self.js_parse_break_statement(),
+            Token::Keyword(Keyword::Continue) => self.js_parse_continue_statement(),
+            Token::Keyword(Keyword::Return) => self.js_parse_return_statement(),
+            Token::Keyword(Keyword::Throw) => self.js_parse_throw_statement(),
+            Token::Keyword(Keyword::Try) => self.js_parse_try_statement(),
+            Token::Keyword(Keyword::With) => self.js_parse_with_statement(),
+            _ if is_labelled_statement => self.js_parse_labelled_statement(vec![]),
             _ => self.js_parse_expression(),
         }?;
 
         self.optional(Token::Semicolon);
 
+        self.bytecode.end_span(span_index, self.previous_span.end);
+
         Ok(())
     }
 
+    /// 11.2.1 Directive Prologues and the Use Strict Directive
+    /// https://262.ecma-international.org/16.0/#sec-directive-prologues-and-the-use-strict-directive
+    ///
+    /// Returns whether the Directive Prologue contains a Use Strict Directive.
+    pub(crate) fn js_parse_directive_prologue(&mut self) -> CodeGenResult<bool> {
+        let mut contains_use_strict = false;
+
+        // A Directive Prologue is the longest sequence of ExpressionStatements occurring as the initial StatementListItems or ModuleItems of a FunctionBody, a ScriptBody, or a ModuleBody and where each ExpressionStatement in the sequence consists entirely of a StringLiteral token followed by a semicolon.
+        // NOTE: Line terminators are not tracked by the lexer, so directives relying on automatic semicolon insertion are only recognised at the end of a body.
+        while let Token::String(value) = self.current_token {
+            if !matches!(
+                self.peek(),
+                None | Some(Token::Semicolon | Token::RightBrace | Token::Eof)
+            ) {
+                break;
+            }
+
+            // A Use Strict Directive is an ExpressionStatement in a Directive Prologue whose StringLiteral is either of the exact code point sequences "use strict" or 'use strict'. A Use Strict Directive may not contain an EscapeSequence or LineContinuation.
+            if value == "\"use strict\"" || value == "'use strict'" {
+                self.strict = true;
+                contains_use_strict = true;
+            }
+
+            self.js_parse_statement()?;
+        }
+
+        Ok(contains_use_strict)
+    }
+
     /// 14.2 Block
     /// https://262.ecma-international.org/16.0/#prod-StatementList
+    ///
+    /// A statement which fails to parse is recovered from, so that the errors of the statements after it are found too.
     pub(crate) fn js_parse_statement_list(&mut self) -> CodeGenResult {
         while !self.is_eof() {
-            self.js_parse_statement()?;
+            let recovery_point = self.recovery_point(false);
+
+            if let Err(error) = self.js_parse_statement() {
+                self.recover(recovery_point, error);
+            }
         }
 
         Ok(())
     }
 
+    /// 14.2 Block
+    /// https://262.ecma-international.org/16.0/#prod-StatementList
+    ///
+    /// The statements of a block or function body up to its closing brace. A statement which fails to parse is recovered from without skipping past the closing brace, so that the statements after the block are parsed as they would be if it had no errors.
+    pub(crate) fn js_parse_braced_statement_list(&mut self) {
+        while self.current_token != Token::RightBrace && !self.is_eof() {
+            let recovery_point = self.recovery_point(true);
+
+            if let Err(error) = self.js_parse_statement() {
+                self.recover(recovery_point, error);
+            }
+        }
+    }
+
+    /// 14.2 Block
+    /// https://262.ecma-international.org/16.0/#prod-BlockStatement
+    ///
+    /// The bound names of a catch parameter enclosing the block must not be redeclared by it.
+    fn js_parse_block_statement(&mut self, bound_names: &[Atom]) -> CodeGenResult {
+        self.expect(Token::LeftBrace)?;
+
+        // 14.2.2 Runtime Semantics: Evaluation
+        // Block : { StatementList }
+        // 1. Let oldEnv be the running execution context's LexicalEnvironment.
+        // 2. Let blockEnv be NewDeclarativeEnvironment(oldEnv).
+        // 4. Set the running execution context's LexicalEnvironment to blockEnv.
+        self.bytecode
+            .emit_instruction(Instruction::PushDeclarativeEnvironment);
+
+        // 3. Perform BlockDeclarationInstantiation(StatementList, blockEnv).
+        let block_functions = self.js_instantiate_block_declarations(bound_names)?;
+
+        // 5. Let blockValue be Completion(Evaluation of StatementList).
+        let enclosing_block_functions =
+            std::mem::replace(&mut self.block_functions, block_functions);
+
+        self.js_parse_braced_statement_list();
+
+        self.block_functions = enclosing_block_functions;
+
+        self.expect(Token::RightBrace)?;
+
+        // 6. Set the running execution context's LexicalEnvironment to oldEnv.
+        self.bytecode.emit_instruction(Instruction::PopEnvironment);
+
+        self.scopes.pop();
+
+        // 7. Return ? blockValue.
+        Ok(())
+    }
+
+    /// 14.2.3 BlockDeclarationInstantiation ( code, env )
+    /// https://262.ecma-international.org/16.0/#sec-blockdeclarationinstantiation
+    ///
+    /// Emits the bindings for the lexical declarations of the statement list starting at the current token, so that they exist in the running execution context's LexicalEnvironment, uninitialized, before any of its statements are evaluated, returning their bound names. The names of the parameters of an enclosing function body must not be redeclared.
+    pub(crate) fn js_instantiate_lexical_declarations(
+        &mut self,
+        parameter_names: &[Atom],
+    ) -> CodeGenResult<Vec<Atom>> {
+        // 1. Let declarations be the LexicallyScopedDeclarations of code.
+        let (declarations, _) = self.js_declare_lexical_declarations(parameter_names, false)?;
+
+        self.js_create_lexical_bindings(&declarations);
+
+        Ok(declarations.into_iter().map(|(name, _)| name).collect())
+    }
+
+    /// 14.2.3 BlockDeclarationInstantiation ( code, env )
+    /// https://262.ecma-international.org/16.0/#sec-blockdeclarationinstantiation
+    ///
+    /// Emits the bindings for the lexical declarations and function declarations of the block starting at the current token, and the instructions which initialize the bindings of the function declarations, returning the index of the block's function declarations if it has any. The bound names of a catch parameter enclosing the block must not be redeclared.
+    fn js_instantiate_block_declarations(
+        &mut self,
+        bound_names: &[Atom],
+    ) -> CodeGenResult<Option<u32>> {
+        // 1. Let declarations be the LexicallyScopedDeclarations of code.
+        let (declarations, function_names) =
+            self.js_declare_lexical_declarations(bound_names, true)?;
+
+        self.js_create_lexical_bindings(&declarations);
+
+        for (index, name) in function_names.iter().enumerate() {
+            // NOTE: Annex B allows a block to declare the same function more than once, in which case a single binding is created.
+            if function_names[..index].contains(name) {
+                continue;
+            }
+
+            // ii. Else,
+            // 1. Perform ! env.CreateMutableBinding(dn, false).
+            let binding_index = self.bytecode.add_identifier(name.clone());
+
+            self.bytecode.emit_create_mutable_binding(binding_index);
+        }
+
+        // b. If d is either a FunctionDeclaration, a GeneratorDeclaration, an AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
+        // NOTE: The function declarations are added to the block's function declarations once they have been parsed.
+        if function_names.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(self.bytecode.emit_instantiate_block_functions()))
+    }
+
+    /// 14.2.3 BlockDeclarationInstantiation ( code, env )
+    /// https://262.ecma-international.org/16.0/#sec-blockdeclarationinstantiation
+    fn js_create_lexical_bindings(&mut self, declarations: &[(Atom, bool)]) {
+        // 3. For each element d of declarations, do
+        for (name, is_constant) in declarations {
+            let binding_index = self.bytecode.add_identifier(name.clone());
+
+            // a. For each element dn of the BoundNames of d, do
+            // i. If IsConstantDeclaration of d is true, then
+            if *is_constant {
+                // 1. Perform ! env.CreateImmutableBinding(dn, true).
+                self.bytecode.emit_create_immutable_binding(binding_index);
+            }
+            // ii. Else,
+            else {
+                // 1. Perform ! env.CreateMutableBinding(dn, false).
+                self.bytecode.emit_create_mutable_binding(binding_index);
+            }
+        }
+    }
+
+    /// Enters the scope of the statement list starting at the current token, returning the bound names of its lexical declarations along with whether each is a constant declaration, and the names of its function declarations if it is the statement list of a block. The names of the parameters of an enclosing function body must not be redeclared.
+    pub(crate) fn js_declare_lexical_declarations(
+        &mut self,
+        parameter_names: &[Atom],
+        is_block: bool,
+    ) -> CodeGenResult<LexicalDeclarations> {
+        let (declarations, mut function_names) = self.lexically_scoped_declarations();
+
+        // NOTE: The function declarations at the top level of a function or script are var scoped, rather than lexically scoped.
+        if !is_block {
+            function_names.clear();
+        }
+
+        // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries.
+        if declarations.iter().enumerate().any(|(index, (name, _))| {
+            parameter_names.contains(name)
+                || function_names.contains(name)
+                || declarations[..index].iter().any(|(other, _)| other == name)
+        }) || function_names
+            .iter()
+            .any(|name| parameter_names.contains(name))
+        {
+            return self.error(CodeGenError::DuplicateDeclaration);
+        }
+
+        // B.3.2.4 Changes to Block Static Semantics: Early Errors
+        // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries, unless the source text matched by this production is not strict mode code and the duplicate entries are only bound by FunctionDeclarations.
+        if (self.strict || !cfg!(feature = "annex-b"))
+            && function_names
+                .iter()
+                .enumerate()
+                .any(|(index, name)| function_names[..index].contains(name))
+        {
+            return self.error(CodeGenError::DuplicateDeclaration);
+        }
+
+        // NOTE: The scope is tracked so that assignments to constants can be warned about, and the parameters are included as they shadow any outer declarations.
+        let mut scope: Vec<(Atom, bool)> = parameter_names
+            .iter()
+            .map(|name| (name.clone(), false))
+            .collect();
+
+        scope.extend(declarations.iter().cloned());
+        scope.extend(function_names.iter().map(|name| (name.clone(), false)));
+
+        self.scopes.push(scope);
+
+        Ok((declarations, function_names))
+    }
+
+    /// 8.2.5 Static Semantics: LexicallyScopedDeclarations
+    /// https://262.ecma-international.org/16.0/#sec-static-semantics-lexicallyscopeddeclarations
+    ///
+    /// Scans ahead over the statement list starting at the current token, up to its closing brace, and returns the bound names of its let and const declarations along with whether each is a constant declaration, and the names of the function declarations which are its items or the items of its labelled statements. The lexer is rewound afterwards, so no tokens are consumed.
+    fn lexically_scoped_declarations(&mut self) -> LexicalDeclarations {
+        let position = self.lexer.position();
+
+        let mut tokens = self.scan_ahead().peekable();
+
+        let mut declarations = vec![];
+        let mut function_names = vec![];
+        let mut depth = 0usize;
+        // Whether the declaration list of a let or const declaration is being scanned, and if so whether it is constant.
+        let mut declaration: Option<bool> = None;
+        // The last token outside of any brackets, along with whether a statement can start straight after it.
+        let mut previous: Option<(Token, bool)> = None;
+        // Whether the last token outside of any brackets started a statement, as the `async` token of an async function declaration and the LabelIdentifier of a LabelledStatement do.
+        let mut previous_starts_statement = true;
+
+        while let Some(token) = tokens.next() {
+            let starts_statement = previous.as_ref().is_none_or(|(_, precedes)| *precedes);
+
+            match token {
+                Token::Eof => break,
+                Token::LeftBrace | Token::LeftParen | Token::LeftBracket => depth += 1,
+                Token::RightBrace | Token::RightParen | Token::RightBracket => {
+                    if depth == 0 {
+                        break;
+                    }
+
+                    depth -= 1;
+                }
+                _ if depth > 0 => continue,
+                Token::Semicolon => declaration = None,
+                // NOTE: The `function` token of an async function declaration follows the `async` token which starts the statement.
+                Token::Keyword(Keyword::Function)
+                    if match &previous {
+                        Some((Token::Keyword(Keyword::Async), _)) => previous_starts_statement,
+                        _ => starts_statement,
+                    } =>
+                {
+                    tokens.next_if_eq(&Token::Multiply);
+
+                    if let Some(name) = tokens.next_if(|token| token.is_binding_identifier()) {
+                        function_names.push(Atom::from(name.to_string()));
+                    }
+
+                    previous = None;
+
+                    continue;
+                }
+                Token::Keyword(Keyword::Let | Keyword::Const) | Token::Comma
+                    if token != Token::Comma || declaration.is_some() =>
+                {
+                    let is_constant = match token {
+                        Token::Keyword(Keyword::Let) => false,
+                        Token::Keyword(Keyword::Const) => true,
+                        _ => declaration.unwrap_or_default(),
+                    };
+
+                    // NOTE: A let which isn't followed by a binding identifier or an array binding pattern is an identifier reference, and object binding patterns are not supported yet.
+                    if let Some(name) = tokens.next_if(|token| token.is_binding_identifier()) {
+                        declarations.push((Atom::from(name.to_string()), is_constant));
+
+                        declaration = Some(is_constant);
+                        previous = Some((name, true));
+
+                        continue;
+                    } else if tokens.next_if_eq(&Token::LeftBracket).is_some() {
+                        let mut names = vec![];
+
+                        scan_array_binding_pattern(&mut tokens, &mut names);
+
+                        declarations.extend(names.into_iter().map(|name| (name, is_constant)));
+
+                        declaration = Some(is_constant);
+                        previous = Some((Token::RightBracket, true));
+
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            // NOTE: The colon of a LabelledStatement is followed by a statement, whereas that of a ConditionalExpression is followed by an expression.
+            let precedes_statement = match (&token, &previous) {
+                (Token::Colon, Some((label, _))) => {
+                    previous_starts_statement && label.is_identifier_reference()
+                }
+                (Token::Colon, None) => false,
+                _ => precedes_statement(&token),
+            };
+
+            previous_starts_statement = starts_statement;
+
+            previous = Some((token, precedes_statement));
+        }
+
+        drop(tokens);
+
+        self.lexer.rewind(position);
+
+        (declarations, function_names)
+    }
+
+    /// 8.2.1 Static Semantics: BoundNames
+    /// https://262.ecma-international.org/16.0/#sec-static-semantics-boundnames
+    ///
+    /// Scans ahead over the array binding pattern starting at the current token, returning the names it binds. The lexer is rewound afterwards, so no tokens are consumed.
+    fn array_binding_pattern_names(&mut self) -> Vec<Atom> {
+        let position = self.lexer.position();
+
+        let mut tokens = self.scan_ahead().skip(1).peekable();
+
+        let mut names = vec![];
+
+        scan_array_binding_pattern(&mut tokens, &mut names);
+
+        drop(tokens);
+
+        self.lexer.rewind(position);
+
+        names
+    }
+
+    /// 14.3.1 Let and Const Declarations
+    /// https://262.ecma-international.org/16.0/#prod-LexicalDeclaration
+    ///
+    /// Returns the BoundNames of the declaration.
+    pub(crate) fn js_parse_lexical_declaration(&mut self) -> CodeGenResult<Vec<Atom>> {
+        // NOTE: The bindings have already been created by the enclosing BlockDeclarationInstantiation, FunctionDeclarationInstantiation or GlobalDeclarationInstantiation.
+        let is_constant = self.current_token == Token::Keyword(Keyword::Const);
+
+        self.expect_one_of(vec![
+            Token::Keyword(Keyword::Let),
+            Token::Keyword(Keyword::Const),
+        ])?;
+
+        let mut bound_names = vec![];
+
+        // BindingList : BindingList , LexicalBinding
+        loop {
+            bound_names.extend(self.js_parse_lexical_binding(is_constant)?);
+
+            if self.current_token != Token::Comma {
+                break;
+            }
+
+            self.advance(); // Eat ',' token.
+        }
+
+        Ok(bound_names)
+    }
+
     /// 14.3.1 Let and Const Declarations
     /// https://262.ecma-international.org/16.0/#prod-LexicalBinding
-    fn js_parse_let_declaration(&mut self) -> CodeGenResult {
-        self.expect(Token::Keyword(Keyword::Let))?;
+    ///
+    /// Returns the BoundNames of the binding.
+    fn js_parse_lexical_binding(&mut self, is_constant: bool) -> CodeGenResult<Vec<Atom>> {
+        // 14.3.1.1 Static Semantics: Early Errors
+        // It is a Syntax Error if the BoundNames of BindingList contains "let".
+        if self.current_token == Token::Keyword(Keyword::Let) {
+            return self.error(CodeGenError::LexicallyBoundLet);
+        }
 
         let binding_identifier = match self.current_token.clone() {
             token_kind if token_kind.is_binding_identifier() => self.js_parse_binding_identifier(),
-            Token::LeftBrace => todo!(),
-            Token::LeftBracket => todo!(),
-            _ => self.error(CodeGenError::UnexpectedToken),
+            Token::LeftBrace => self.error(CodeGenError::UnsupportedObjectBindingPattern),
+            Token::LeftBracket => return self.js_parse_lexical_binding_pattern(),
+            _ => self.unexpected_token(&[]),
         }?;
 
         // 1. Let bindingId be the StringValue of BindingIdentifier.
-        let binding_index = self.bytecode.add_identifier(binding_identifier);
-
-        // 16.1.7 GlobalDeclarationInstantiation ( script, env )
-        // 1. Perform ? env.CreateMutableBinding(dn, false).
-        // TODO Implement correct scope depth
-        self.bytecode.emit_create_mutable_binding(binding_index);
+        let binding_index = self.bytecode.add_identifier(binding_identifier.clone());
 
         // 2. Let lhs be ! ResolveBinding(bindingId).
         self.bytecode.emit_resolve_binding(binding_index);
@@ -77,6 +484,11 @@ impl<'a> Parser<'a> {
         }
         // LexicalBinding : BindingIdentifier
         else {
+            // It is a Syntax Error if Initializer is not present and IsConstantDeclaration of the LexicalDeclaration containing this LexicalBinding is true.
+            if is_constant {
+                return self.error(CodeGenError::MissingConstInitializer);
+            }
+
             // 2. Perform ! InitializeReferencedBinding(lhs, undefined).
             self.bytecode.emit_instruction(Instruction::Undefined);
         }
@@ -84,6 +496,1077 @@ impl<'a> Parser<'a> {
         // 5. Perform ! InitializeReferencedBinding(lhs, value).
         self.bytecode.emit_initialize_referenced_binding();
 
+        Ok(vec![binding_identifier])
+    }
+
+    /// 14.3.1 Let and Const Declarations
+    /// https://262.ecma-international.org/16.0/#prod-LexicalBinding
+    ///
+    /// LexicalBinding : BindingPattern Initializer
+    fn js_parse_lexical_binding_pattern(&mut self) -> CodeGenResult<Vec<Atom>> {
+        // NOTE: The Initializer is evaluated before the bindings of the pattern are initialized, so the tokens of the pattern are skipped here and parsed again once the code for the Initializer has been emitted.
+        let pattern = self.checkpoint();
+
+        self.skip_balanced();
+
+        // 14.3.1.1 Static Semantics: Early Errors
+        // NOTE: The grammar requires an Initializer for a LexicalBinding with a BindingPattern.
+        if self.current_token != Token::Assign {
+            return self.error(CodeGenError::MissingDestructuringInitializer);
+        }
+
+        self.advance(); // Eat '=' token.
+
+        // 14.3.1.2 Runtime Semantics: Evaluation
+        // 1. Let rhs be ? Evaluation of Initializer.
+        // 2. Let value be ? GetValue(rhs).
+        self.js_parse_assignment_expression()?;
+
+        let end = self.checkpoint();
+
+        self.restore(pattern);
+
+        // 3. Let env be the running execution context's LexicalEnvironment.
+        // 4. Return ? BindingInitialization of BindingPattern with arguments value and env.
+        let mut bound_names = vec![];
+
+        self.js_parse_array_binding_pattern(&mut bound_names)?;
+
+        self.restore(end);
+
+        Ok(bound_names)
+    }
+
+    /// 14.3.3 Destructuring Binding Patterns
+    /// https://262.ecma-international.org/16.0/#prod-ArrayBindingPattern
+    ///
+    /// Initializes the bindings of the pattern from the value on top of the stack, adding them to the bound names.
+    fn js_parse_array_binding_pattern(&mut self, bound_names: &mut Vec<Atom>) -> CodeGenResult {
+        self.nested(|parser| parser.js_parse_array_binding_pattern_unnested(bound_names))
+    }
+
+    /// Parses an ArrayBindingPattern which is not nested too deeply to be parsed, as checked by [`Parser::js_parse_array_binding_pattern`].
+    fn js_parse_array_binding_pattern_unnested(
+        &mut self,
+        bound_names: &mut Vec<Atom>,
+    ) -> CodeGenResult {
+        self.expect(Token::LeftBracket)?;
+
+        // 8.6.2 Runtime Semantics: BindingInitialization
+        // BindingPattern : ArrayBindingPattern
+        // 1. Let iteratorRecord be ? GetIterator(value, sync).
+        // NOTE: GetIterator also pushes the initial value of a for-of loop, which a pattern does not have.
+        self.bytecode.emit_instruction(Instruction::GetIterator);
+        self.bytecode.emit_instruction(Instruction::Pop);
+
+        // 2. Let result be Completion(IteratorBindingInitialization of ArrayBindingPattern with arguments iteratorRecord and environment).
+        loop {
+            match self.current_token {
+                Token::RightBracket => break,
+                // 8.6.3 Runtime Semantics: IteratorBindingInitialization
+                // Elision : ,
+                // 1. If iteratorRecord.[[Done]] is false, then
+                // a. Perform ? IteratorStep(iteratorRecord).
+                Token::Comma => {
+                    self.advance(); // Eat ',' token.
+
+                    self.bytecode
+                        .emit_instruction(Instruction::IteratorStepElement);
+                    self.bytecode.emit_instruction(Instruction::Pop);
+                }
+                // ArrayBindingPattern : [ Elision_opt BindingRestElement ]
+                Token::Spread => {
+                    self.advance(); // Eat '...' token.
+
+                    self.js_parse_binding_rest_element(bound_names)?;
+
+                    break;
+                }
+                _ => {
+                    self.js_parse_binding_element(bound_names)?;
+
+                    if self.current_token != Token::RightBracket {
+                        self.expect(Token::Comma)?;
+                    }
+                }
+            }
+        }
+
+        self.expect(Token::RightBracket)?;
+
+        // 3. If iteratorRecord.[[Done]] is false, return ? IteratorClose(iteratorRecord, result).
+        // NOTE: An abrupt completion closes the iterator as it propagates out of the pattern, as the VM closes the iterators it leaves.
+        self.bytecode.emit_instruction(Instruction::IteratorClose);
+
+        Ok(())
+    }
+
+    /// 14.3.3 Destructuring Binding Patterns
+    /// https://262.ecma-international.org/16.0/#prod-BindingElement
+    fn js_parse_binding_element(&mut self, bound_names: &mut Vec<Atom>) -> CodeGenResult {
+        match self.current_token {
+            // BindingElement : BindingPattern Initializer_opt
+            Token::LeftBracket => {
+                // NOTE: The value of the element and its Initializer are evaluated before the bindings of the pattern are initialized.
+                let pattern = self.checkpoint();
+
+                self.skip_balanced();
+
+                // 1. Let v be undefined.
+                // 2. If iteratorRecord.[[Done]] is false, then
+                // a. Let next be ? IteratorStepValue(iteratorRecord).
+                // b. If next is not done, then
+                // i. Set v to next.
+                self.bytecode
+                    .emit_instruction(Instruction::IteratorStepElement);
+
+                // 3. If Initializer is present and v is undefined, then
+                // a. Let defaultValue be ? Evaluation of Initializer.
+                // b. Set v to ? GetValue(defaultValue).
+                self.js_parse_binding_element_initializer()?;
+
+                let end = self.checkpoint();
+
+                self.restore(pattern);
+
+                // 4. Return ? BindingInitialization of BindingPattern with arguments v and environment.
+                self.js_parse_array_binding_pattern(bound_names)?;
+
+                self.restore(end);
+
+                Ok(())
+            }
+            // TODO: Implement object binding patterns.
+            Token::LeftBrace => self.unexpected_token(&[]),
+            // SingleNameBinding : BindingIdentifier Initializer_opt
+            _ => {
+                // 14.3.1.1 Static Semantics: Early Errors
+                // It is a Syntax Error if the BoundNames of BindingList contains "let".
+                if self.current_token == Token::Keyword(Keyword::Let) {
+                    return self.error(CodeGenError::LexicallyBoundLet);
+                }
+
+                // 1. Let bindingId be the StringValue of BindingIdentifier.
+                let binding_identifier = self.js_parse_binding_identifier()?;
+
+                bound_names.push(binding_identifier.clone());
+
+                let binding_index = self.bytecode.add_identifier(binding_identifier);
+
+                // 2. Let lhs be ? ResolveBinding(bindingId, environment).
+                self.bytecode.emit_resolve_binding(binding_index);
+
+                // 3. Let v be undefined.
+                // 4. If iteratorRecord.[[Done]] is false, then
+                // a. Let next be ? IteratorStepValue(iteratorRecord).
+                // b. If next is not done, then
+                // i. Set v to next.
+                self.bytecode
+                    .emit_instruction(Instruction::IteratorStepElement);
+
+                // 5. If Initializer is present and v is undefined, then
+                // a. If IsAnonymousFunctionDefinition(Initializer) is true, then
+                // i. Set v to ? NamedEvaluation of Initializer with argument bindingId.
+                // TODO: Implement the above.
+                // b. Else,
+                // i. Let defaultValue be ? Evaluation of Initializer.
+                // ii. Set v to ? GetValue(defaultValue).
+                self.js_parse_binding_element_initializer()?;
+
+                // 6. If environment is undefined, return ? PutValue(lhs, v).
+                // 7. Return ? InitializeReferencedBinding(lhs, v).
+                self.bytecode.emit_initialize_referenced_binding();
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Parses the optional Initializer of a binding element, which replaces the value on top of the stack when it is undefined.
+    fn js_parse_binding_element_initializer(&mut self) -> CodeGenResult {
+        if self.current_token != Token::Assign {
+            return Ok(());
+        }
+
+        self.advance(); // Eat '=' token.
+
+        let skip_jump = self.bytecode.emit_jump(Instruction::JumpIfNotUndefined);
+
+        self.js_parse_assignment_expression()?;
+
+        self.bytecode.patch_jump(skip_jump);
+
+        Ok(())
+    }
+
+    /// 14.3.3 Destructuring Binding Patterns
+    /// https://262.ecma-international.org/16.0/#prod-BindingRestElement
+    fn js_parse_binding_rest_element(&mut self, bound_names: &mut Vec<Atom>) -> CodeGenResult {
+        // BindingRestElement : ... BindingPattern
+        if self.current_token == Token::LeftBracket {
+            // 1. Let A be ! ArrayCreate(0).
+            // 2. Let n be 0.
+            // 3. Repeat,
+            // c. If next is done, then
+            // i. Return ? BindingInitialization of BindingPattern with arguments A and environment.
+            self.bytecode.emit_instruction(Instruction::IteratorRest);
+
+            return self.js_parse_array_binding_pattern(bound_names);
+        }
+
+        // BindingRestElement : ... BindingIdentifier
+        if self.current_token == Token::Keyword(Keyword::Let) {
+            return self.error(CodeGenError::LexicallyBoundLet);
+        }
+
+        let binding_identifier = self.js_parse_binding_identifier()?;
+
+        bound_names.push(binding_identifier.clone());
+
+        let binding_index = self.bytecode.add_identifier(binding_identifier);
+
+        // 1. Let lhs be ? ResolveBinding(StringValue of BindingIdentifier, environment).
+        self.bytecode.emit_resolve_binding(binding_index);
+
+        // 2. Let A be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. Repeat,
+        // c. If next is done, then
+        // ii. Return ? InitializeReferencedBinding(lhs, A).
+        self.bytecode.emit_instruction(Instruction::IteratorRest);
+        self.bytecode.emit_initialize_referenced_binding();
+
+        Ok(())
+    }
+
+    /// 14.7.5 The for-in, for-of, and for-await-of Statements
+    /// https://262.ecma-international.org/16.0/#prod-ForInOfStatement
+    ///
+    /// The label set holds the labels of the labelled statements the loop is the item of.
+    fn js_parse_for_in_of_statement(&mut self, labels: Vec<Atom>) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::For))?;
+        self.expect(Token::LeftParen)?;
+
+        let is_declaration = match self.current_token {
+            Token::Keyword(Keyword::Let) => self
+                .peek()
+                .is_some_and(|token| token.is_lexical_binding_start()),
+            Token::Keyword(Keyword::Const) => true,
+            _ => false,
+        };
+
+        // ForInOfStatement : for ( ForDeclaration in Expression ) Statement
+        if is_declaration {
+            return self.js_parse_for_in_declaration_statement(labels);
+        }
+
+        // ForInOfStatement : for ( LeftHandSideExpression in Expression ) Statement
+        // NOTE: The LeftHandSideExpression is evaluated on each iteration, after the Expression, so its tokens are skipped here and parsed again once the code for the loop head has been emitted.
+        let lhs = self.checkpoint();
+
+        // ForInOfStatement : for ( [lookahead ∉ { let, async of }] LeftHandSideExpression of AssignmentExpression ) Statement
+        if self.current_token == Token::Keyword(Keyword::Async)
+            && self.peek() == Some(&Token::Keyword(Keyword::Of))
+        {
+            return self.unexpected_token(&[]);
+        }
+
+        // NOTE: The first token always belongs to the LeftHandSideExpression, so an `of` identifier isn't mistaken for the separator.
+        if self.current_token == Token::Keyword(Keyword::Of) {
+            self.advance(); // Eat 'of' token.
+        }
+
+        let mut depth = 0usize;
+
+        while depth > 0 || !Self::is_for_in_of_separator(&self.current_token) {
+            match self.current_token {
+                Token::LeftBrace | Token::LeftParen | Token::LeftBracket => depth += 1,
+                Token::RightBrace | Token::RightParen | Token::RightBracket if depth > 0 => {
+                    depth -= 1
+                }
+                // TODO: Implement the for statement.
+                Token::Eof | Token::Semicolon | Token::RightParen | Token::RightBrace => {
+                    return self.unexpected_token(&[]);
+                }
+                _ => {}
+            }
+
+            self.advance();
+        }
+
+        // 14.7.5.5 Runtime Semantics: ForInOfLoopEvaluation
+        // ForInOfStatement : for ( LeftHandSideExpression in Expression ) Statement
+        // 1. Let keyResult be ? ForIn/OfHeadEvaluation(« », Expression, enumerate).
+        // ForInOfStatement : for ( LeftHandSideExpression of AssignmentExpression ) Statement
+        // 1. Let keyResult be ? ForIn/OfHeadEvaluation(« », AssignmentExpression, iterate).
+        let iteration_kind = self.js_parse_for_in_of_head_expression()?;
+
+        self.expect(Token::RightParen)?;
+
+        let exit_jump = self.emit_for_in_of_iterator(iteration_kind);
+
+        // 2. Return ? ForIn/OfBodyEvaluation(LeftHandSideExpression, Statement, keyResult, iterationKind, assignment, labelSet).
+        let loop_start = self.bytecode.current_offset();
+
+        let done_jump = self.bytecode.emit_jump(Instruction::IteratorStepValue);
+
+        self.bytecode.enter_iteration(labels, loop_start);
+
+        let body = self.checkpoint();
+
+        self.restore(lhs);
+
+        // g. If lhsKind is either assignment or varBinding, then
+        // ii. Else,
+        // 1. Let lhsRef be Completion(Evaluation of lhs). (It may be evaluated repeatedly.)
+        // TODO: Implement destructuring assignment targets.
+        // It is a Syntax Error if the AssignmentTargetType of LeftHandSideExpression is not simple.
+        if !self.js_parse_left_hand_side_expression()? || self.is_optional_expression_end() {
+            return self.error(CodeGenError::InvalidAssignmentTarget);
+        }
+
+        if !Self::is_for_in_of_separator(&self.current_token) {
+            return self.unexpected_token(&[]);
+        }
+
+        // NOTE: PutValue throws a TypeError when the target is a const binding, which is not reported early, as the body of the loop may never run.
+        self.restore(body);
+
+        // i. If destructuring is false, then
+        // iii. Else, let status be Completion(PutValue(lhsRef, nextValue)).
+        self.bytecode.emit_instruction(Instruction::Swap);
+        self.bytecode.emit_instruction(Instruction::PutValue);
+        self.bytecode.emit_instruction(Instruction::Pop);
+
+        // l. Let result be Completion(Evaluation of stmt).
+        self.js_parse_statement()?;
+
+        self.bytecode.emit_loop(loop_start);
+
+        if let Some(exit_jump) = exit_jump {
+            self.bytecode.patch_jump(exit_jump);
+        }
+
+        self.bytecode.patch_jump(done_jump);
+
+        self.bytecode.exit_jump_target();
+
+        Ok(())
+    }
+
+    /// 14.7.5 The for-in, for-of, and for-await-of Statements
+    /// https://262.ecma-international.org/16.0/#prod-ForDeclaration
+    fn js_parse_for_in_declaration_statement(&mut self, labels: Vec<Atom>) -> CodeGenResult {
+        let is_constant = self.current_token == Token::Keyword(Keyword::Const);
+
+        self.advance(); // Eat 'let' or 'const' token.
+
+        // 14.7.5.1 Static Semantics: Early Errors
+        // It is a Syntax Error if the BoundNames of ForDeclaration contains "let".
+        if self.current_token == Token::Keyword(Keyword::Let) {
+            return self.error(CodeGenError::LexicallyBoundLet);
+        }
+
+        // NOTE: The bindings of a pattern are initialized on each iteration, after the Expression, so its tokens are skipped here and parsed again once the code for the loop head has been emitted.
+        let binding = self.checkpoint();
+
+        let destructuring = self.current_token == Token::LeftBracket;
+
+        let bound_names = if destructuring {
+            let bound_names = self.array_binding_pattern_names();
+
+            // It is a Syntax Error if the BoundNames of ForDeclaration contains any duplicate entries.
+            if bound_names
+                .iter()
+                .enumerate()
+                .any(|(index, name)| bound_names[..index].contains(name))
+            {
+                return self.error(CodeGenError::DuplicateDeclaration);
+            }
+
+            self.skip_balanced();
+
+            bound_names
+        } else {
+            vec![self.js_parse_binding_identifier()?]
+        };
+
+        // TODO: Implement the for statement.
+        if !Self::is_for_in_of_separator(&self.current_token) {
+            return self.unexpected_token(&[]);
+        }
+
+        let binding_indices: Vec<u32> = bound_names
+            .iter()
+            .map(|name| self.bytecode.add_identifier(name.clone()))
+            .collect();
+
+        // 14.7.5.5 Runtime Semantics: ForInOfLoopEvaluation
+        // 1. Let keyResult be ? ForIn/OfHeadEvaluation(BoundNames of ForDeclaration, Expression, iterationKind).
+        // 14.7.5.6 ForIn/OfHeadEvaluation ( uninitializedBoundNames, expr, iterationKind )
+        // 2. If uninitializedBoundNames is not empty, then
+        // b. Let newEnv be NewDeclarativeEnvironment(oldEnv).
+        // d. Set the running execution context's LexicalEnvironment to newEnv.
+        self.bytecode
+            .emit_instruction(Instruction::PushDeclarativeEnvironment);
+
+        // c. For each String name of uninitializedBoundNames, do
+        // i. Perform ! newEnv.CreateMutableBinding(name, false).
+        for &binding_index in &binding_indices {
+            self.bytecode.emit_create_mutable_binding(binding_index);
+        }
+
+        // 3. Let exprRef be Completion(Evaluation of expr).
+        let iteration_kind = self.js_parse_for_in_of_head_expression()?;
+
+        // 4. Set the running execution context's LexicalEnvironment to oldEnv.
+        self.bytecode.emit_instruction(Instruction::PopEnvironment);
+
+        self.expect(Token::RightParen)?;
+
+        let exit_jump = self.emit_for_in_of_iterator(iteration_kind);
+
+        // 2. Return ? ForIn/OfBodyEvaluation(ForDeclaration, Statement, keyResult, iterationKind, lexicalBinding, labelSet).
+        let loop_start = self.bytecode.current_offset();
+
+        let done_jump = self.bytecode.emit_jump(Instruction::IteratorStepValue);
+
+        self.bytecode.enter_iteration(labels, loop_start);
+
+        // h. Else,
+        // ii. Let iterationEnv be NewDeclarativeEnvironment(oldEnv).
+        // iv. Set the running execution context's LexicalEnvironment to iterationEnv.
+        self.bytecode
+            .emit_instruction(Instruction::PushDeclarativeEnvironment);
+
+        // iii. Perform ForDeclarationBindingInstantiation of lhs with argument iterationEnv.
+        for &binding_index in &binding_indices {
+            if is_constant {
+                self.bytecode.emit_create_immutable_binding(binding_index);
+            } else {
+                self.bytecode.emit_create_mutable_binding(binding_index);
+            }
+        }
+
+        // v. If destructuring is false, then
+        if !destructuring {
+            let binding_index = binding_indices[0];
+
+            // 1. Assert: lhs binds a single name.
+            // 2. Let lhsName be the sole element of the BoundNames of lhs.
+            // 3. Let lhsRef be ! ResolveBinding(lhsName).
+            self.bytecode.emit_resolve_binding(binding_index);
+
+            // i. If destructuring is false, then
+            // ii. Else if lhsKind is lexicalBinding, then
+            // 1. Let status be Completion(InitializeReferencedBinding(lhsRef, nextValue)).
+            self.bytecode.emit_instruction(Instruction::Swap);
+            self.bytecode.emit_initialize_referenced_binding();
+        }
+        // j. Else,
+        else {
+            let body = self.checkpoint();
+
+            self.restore(binding);
+
+            // iii. Else,
+            // 1. Assert: lhsKind is lexicalBinding.
+            // 2. Assert: lhs is a ForDeclaration.
+            // 3. Let status be Completion(ForDeclarationBindingInitialization of lhs with arguments nextValue and iterationEnv).
+            self.js_parse_array_binding_pattern(&mut vec![])?;
+
+            self.restore(body);
+        }
+
+        self.scopes.push(
+            bound_names
+                .iter()
+                .map(|name| (name.clone(), is_constant))
+                .collect(),
+        );
+
+        // l. Let result be Completion(Evaluation of stmt).
+        self.js_parse_statement()?;
+
+        self.scopes.pop();
+
+        // m. Set the running execution context's LexicalEnvironment to oldEnv.
+        self.bytecode.emit_instruction(Instruction::PopEnvironment);
+
+        self.bytecode.emit_loop(loop_start);
+
+        if let Some(exit_jump) = exit_jump {
+            self.bytecode.patch_jump(exit_jump);
+        }
+
+        self.bytecode.patch_jump(done_jump);
+
+        self.bytecode.exit_jump_target();
+
+        Ok(())
+    }
+
+    fn is_for_in_of_separator(token: &Token) -> bool {
+        matches!(token, Token::Keyword(Keyword::In | Keyword::Of))
+    }
+
+    /// 14.7.5 The for-in, for-of, and for-await-of Statements
+    /// https://262.ecma-international.org/16.0/#prod-ForInOfStatement
+    ///
+    /// Parses the `in` or `of` keyword and the expression following it, returning whether the loop enumerates the keys of the value or iterates it.
+    fn js_parse_for_in_of_head_expression(&mut self) -> CodeGenResult<IterationKind> {
+        if self.current_token == Token::Keyword(Keyword::In) {
+            self.advance(); // Eat 'in' token.
+
+            self.js_parse_expression()?;
+
+            return Ok(IterationKind::Enumerate);
+        }
+
+        self.expect(Token::Keyword(Keyword::Of))?;
+
+        self.js_parse_assignment_expression()?;
+
+        Ok(IterationKind::Iterate)
+    }
+
+    /// 14.7.5.6 ForIn/OfHeadEvaluation ( uninitializedBoundNames, expr, iterationKind )
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-forinofheadevaluation
+    ///
+    /// Returns the operand of the jump which exits a for-in loop over undefined or null, as a for-of loop has none.
+    fn emit_for_in_of_iterator(&mut self, iteration_kind: IterationKind) -> Option<usize> {
+        match iteration_kind {
+            // 6. If iterationKind is enumerate, then
+            IterationKind::Enumerate => {
+                Some(self.bytecode.emit_jump(Instruction::CreateForInIterator))
+            }
+            // 7. Else,
+            IterationKind::Iterate => {
+                self.bytecode.emit_instruction(Instruction::GetIterator);
+
+                None
+            }
+        }
+    }
+
+    /// 14.8 The continue Statement
+    /// https://262.ecma-international.org/16.0/#prod-ContinueStatement
+    fn js_parse_continue_statement(&mut self) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::Continue))?;
+
+        let label = self.js_parse_jump_label()?;
+
+        // 14.8.1 Static Semantics: Early Errors
+        // It is a Syntax Error if this ContinueStatement is not nested, directly or indirectly (but not crossing function or static initialization block boundaries), within an IterationStatement.
+        // NOTE: ContainsUndefinedContinueTarget is checked here for each continue statement with a label, rather than for each statement list.
+        let Some(target) = self.bytecode.jump_target(label.as_ref()) else {
+            return self.error(match label {
+                Some(_) => CodeGenError::UndefinedLabel,
+                None => CodeGenError::IllegalContinueStatement,
+            });
+        };
+
+        if !self.bytecode.is_iteration_target(target) {
+            return self.error(CodeGenError::ContinueToNonIterationLabel);
+        }
+
+        // 14.8.2 Runtime Semantics: Evaluation
+        // ContinueStatement : continue ;
+        // 1. Return Completion Record { [[Type]]: continue, [[Value]]: empty, [[Target]]: empty }.
+        // ContinueStatement : continue LabelIdentifier ;
+        // 1. Let label be the StringValue of LabelIdentifier.
+        // 2. Return Completion Record { [[Type]]: continue, [[Value]]: empty, [[Target]]: label }.
+        self.bytecode.emit_continue(target);
+
+        Ok(())
+    }
+
+    /// 14.9 The break Statement
+    /// https://262.ecma-international.org/16.0/#prod-BreakStatement
+    fn js_parse_break_statement(&mut self) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::Break))?;
+
+        let label = self.js_parse_jump_label()?;
+
+        // 14.9.1 Static Semantics: Early Errors
+        // It is a Syntax Error if this BreakStatement is not nested, directly or indirectly (but not crossing function or static initialization block boundaries), within an IterationStatement or a SwitchStatement.
+        // NOTE: ContainsUndefinedBreakTarget is checked here for each break statement with a label, rather than for each statement list.
+        let Some(target) = self.bytecode.jump_target(label.as_ref()) else {
+            return self.error(match label {
+                Some(_) => CodeGenError::UndefinedLabel,
+                None => CodeGenError::IllegalBreakStatement,
+            });
+        };
+
+        // 14.9.2 Runtime Semantics: Evaluation
+        // BreakStatement : break ;
+        // 1. Return Completion Record { [[Type]]: break, [[Value]]: empty, [[Target]]: empty }.
+        // BreakStatement : break LabelIdentifier ;
+        // 1. Let label be the StringValue of LabelIdentifier.
+        // 2. Return Completion Record { [[Type]]: break, [[Value]]: empty, [[Target]]: label }.
+        self.bytecode.emit_break(target);
+
+        Ok(())
+    }
+
+    /// Parses the LabelIdentifier of a break or continue statement, which is only part of the statement when it is on the same line as the keyword.
+    fn js_parse_jump_label(&mut self) -> CodeGenResult<Option<Atom>> {
+        // BreakStatement : break [no LineTerminator here] LabelIdentifier ;
+        // ContinueStatement : continue [no LineTerminator here] LabelIdentifier ;
+        if !self.current_token.is_identifier_reference()
+            || self
+                .lexer
+                .has_line_terminator(self.previous_span.end, self.current_span.start)
+        {
+            return Ok(None);
+        }
+
+        self.js_parse_label_identifier().map(Some)
+    }
+
+    /// 14.10 The return Statement
+    /// https://262.ecma-international.org/16.0/#prod-ReturnStatement
+    fn js_parse_return_statement(&mut self) -> CodeGenResult {
+        // It is a Syntax Error if a ReturnStatement is not contained within a FunctionBody.
+        if !self.in_function_body {
+            return self.error(CodeGenError::IllegalReturnStatement);
+        }
+
+        self.expect(Token::Keyword(Keyword::Return))?;
+
+        // ReturnStatement : return ;
+        // 1. Return ReturnCompletion(undefined).
+        if matches!(
+            self.current_token,
+            Token::Semicolon | Token::RightBrace | Token::Eof
+        ) {
+            self.bytecode.emit_instruction(Instruction::Undefined);
+        }
+        // ReturnStatement : return Expression ;
+        else {
+            // 1. Let exprRef be ? Evaluation of Expression.
+            // 2. Let exprValue be ? GetValue(exprRef).
+            self.js_parse_expression()?;
+        }
+
+        // 3. Return ReturnCompletion(exprValue).
+        self.bytecode.emit_instruction(Instruction::Return);
+
+        Ok(())
+    }
+
+    /// 14.13 Labelled Statements
+    /// https://262.ecma-international.org/16.0/#prod-LabelledStatement
+    ///
+    /// The label set holds the labels of the enclosing labelled statements this one is the item of.
+    fn js_parse_labelled_statement(&mut self, mut labels: Vec<Atom>) -> CodeGenResult {
+        let label = self.js_parse_label_identifier()?;
+
+        // 14.13.1 Static Semantics: Early Errors
+        // NOTE: ContainsDuplicateLabels is checked here for each labelled statement, against the labels of the statements enclosing it.
+        if labels.contains(&label) || self.bytecode.has_label(&label) {
+            return self.error(CodeGenError::DuplicateLabel);
+        }
+
+        self.expect(Token::Colon)?;
+
+        // 14.13.4 Runtime Semantics: LabelledEvaluation
+        // LabelledStatement : LabelIdentifier : LabelledItem
+        // 1. Let label be the StringValue of LabelIdentifier.
+        // 2. Let newLabelSet be the list-concatenation of labelSet and « label ».
+        labels.push(label);
+
+        // 3. Let stmtResult be Completion(LabelledEvaluation of LabelledItem with argument newLabelSet).
+        match self.current_token {
+            // LabelledItem : Statement
+            // 1. If Statement is either a LabelledStatement or a BreakableStatement, then
+            // a. Return ? LabelledEvaluation of Statement with argument labelSet.
+            Token::Keyword(Keyword::For) => return self.js_parse_for_in_of_statement(labels),
+            _ if self.is_labelled_statement_start() => {
+                return self.nested(|parser| parser.js_parse_labelled_statement(labels));
+            }
+            // It is a Syntax Error if any source text is matched by this production.
+            // NOTE: Annex B allows a FunctionDeclaration as a LabelledItem in non-strict code, but not a generator or async function declaration.
+            Token::Keyword(Keyword::Function)
+                if self.strict || matches!(self.next, Some((Token::Multiply, _))) =>
+            {
+                return self.error(CodeGenError::LabelledFunctionDeclaration);
+            }
+            Token::Keyword(Keyword::Async) if self.is_async_function_start() => {
+                return self.error(CodeGenError::LabelledFunctionDeclaration);
+            }
+            _ => {}
+        }
+
+        // 2. Return ? Evaluation of Statement.
+        self.bytecode.enter_labelled_statement(labels);
+
+        self.js_parse_statement()?;
+
+        // 4. If stmtResult is a break completion and stmtResult.[[Target]] is label, then
+        // a. Set stmtResult to NormalCompletion(stmtResult.[[Value]]).
+        self.bytecode.exit_jump_target();
+
+        Ok(())
+    }
+
+    /// 14.14 The throw Statement
+    /// https://262.ecma-international.org/16.0/#prod-ThrowStatement
+    fn js_parse_throw_statement(&mut self) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::Throw))?;
+
+        // 1. Let exprRef be ? Evaluation of Expression.
+        // 2. Let exprValue be ? GetValue(exprRef).
+        self.js_parse_expression()?;
+
+        // 3. Return ThrowCompletion(exprValue).
+        self.bytecode.emit_instruction(Instruction::Throw);
+
+        Ok(())
+    }
+
+    /// 14.15 The try Statement
+    /// https://262.ecma-international.org/16.0/#prod-TryStatement
+    fn js_parse_try_statement(&mut self) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::Try))?;
+
+        let (has_catch, has_finally) = self.try_statement_clauses();
+
+        if !has_catch && !has_finally {
+            return self.error(CodeGenError::MissingCatchOrFinally);
+        }
+
+        // NOTE: The handler for the finally clause is registered first, so that it also handles abrupt completions of the catch clause.
+        let finally_handler =
+            has_finally.then(|| self.bytecode.emit_jump(Instruction::PushFinallyHandler));
+
+        let catch_handler =
+            has_catch.then(|| self.bytecode.emit_jump(Instruction::PushCatchHandler));
+
+        // NOTE: The block and the catch clause each produce undefined when their statement list is empty, which is UpdateEmpty(C, undefined).
+        self.bytecode.emit_instruction(Instruction::Undefined);
+
+        // 1. Let B be Completion(Evaluation of Block).
+        self.js_parse_block_statement(&[])?;
+
+        // TryStatement : try Block Catch
+        if let Some(catch_handler) = catch_handler {
+            self.bytecode
+                .emit_instruction(Instruction::PopExceptionHandler);
+
+            let end_jump = self.bytecode.emit_jump(Instruction::Jump);
+
+            // 2. If B is a throw completion, let C be Completion(CatchClauseEvaluation of Catch with argument B.[[Value]]).
+            self.bytecode.patch_jump(catch_handler);
+
+            self.js_parse_catch()?;
+
+            // 3. Else, let C be B.
+            self.bytecode.patch_jump(end_jump);
+        }
+
+        // TryStatement : try Block Finally
+        // TryStatement : try Block Catch Finally
+        if let Some(finally_handler) = finally_handler {
+            self.expect(Token::Keyword(Keyword::Finally))?;
+
+            self.bytecode.emit_instruction(Instruction::EnterFinally);
+
+            self.bytecode.patch_jump(finally_handler);
+
+            // 2. Let F be Completion(Evaluation of Finally).
+            self.js_parse_block_statement(&[])?;
+
+            // 3. If F is a normal completion, set F to C.
+            // 4. Return ? UpdateEmpty(F, undefined).
+            self.bytecode.emit_end_finally();
+        }
+
+        Ok(())
+    }
+
+    /// Scans ahead over the block of a try statement, starting at the current token, and returns whether it is followed by a catch clause and a finally clause. The parser is restored afterwards, so no tokens are consumed.
+    fn try_statement_clauses(&mut self) -> (bool, bool) {
+        let checkpoint = self.checkpoint();
+
+        self.skip_balanced();
+
+        let has_catch = self.current_token == Token::Keyword(Keyword::Catch);
+
+        if has_catch {
+            self.advance(); // Eat 'catch' token.
+
+            if self.current_token == Token::LeftParen {
+                self.skip_balanced();
+            }
+
+            self.skip_balanced();
+        }
+
+        let has_finally = self.current_token == Token::Keyword(Keyword::Finally);
+
+        self.restore(checkpoint);
+
+        (has_catch, has_finally)
+    }
+
+    /// 14.15 The try Statement
+    /// https://262.ecma-international.org/16.0/#prod-Catch
+    fn js_parse_catch(&mut self) -> CodeGenResult {
+        self.expect(Token::Keyword(Keyword::Catch))?;
+
+        // Catch : catch Block
+        if self.current_token != Token::LeftParen {
+            // 1. Return ? Evaluation of Block.
+            // NOTE: The thrown value is discarded, as there is no parameter to bind it to.
+            self.bytecode.emit_instruction(Instruction::Pop);
+
+            self.bytecode.emit_instruction(Instruction::Undefined);
+
+            return self.js_parse_block_statement(&[]);
+        }
+
+        // Catch : catch ( CatchParameter ) Block
+        self.advance(); // Eat '(' token.
+
+        // TODO: Implement binding patterns.
+        let parameter = self.js_parse_binding_identifier()?;
+
+        self.expect(Token::RightParen)?;
+
+        let parameter_index = self.bytecode.add_identifier(parameter.clone());
+
+        // 14.15.2 Runtime Semantics: CatchClauseEvaluation
+        // 1. Let oldEnv be the running execution context's LexicalEnvironment.
+        // 2. Let catchEnv be NewDeclarativeEnvironment(oldEnv).
+        // 4. Set the running execution context's LexicalEnvironment to catchEnv.
+        self.bytecode
+            .emit_instruction(Instruction::PushDeclarativeEnvironment);
+
+        // 3. For each element argName of the BoundNames of CatchParameter, do
+        // a. Perform ! catchEnv.CreateMutableBinding(argName, false).
+        self.bytecode.emit_create_mutable_binding(parameter_index);
+
+        // 5. Let status be Completion(BindingInitialization of CatchParameter with arguments thrownValue and catchEnv).
+        self.bytecode.emit_resolve_binding(parameter_index);
+        self.bytecode.emit_instruction(Instruction::Swap);
+        self.bytecode.emit_initialize_referenced_binding();
+
+        self.bytecode.emit_instruction(Instruction::Undefined);
+
+        // 7. Let B be Completion(Evaluation of Block).
+        // It is a Syntax Error if any element of the BoundNames of CatchParameter also occurs in the LexicallyDeclaredNames of Block.
+        self.js_parse_block_statement(&[parameter])?;
+
+        // 8. Set the running execution context's LexicalEnvironment to oldEnv.
+        self.bytecode.emit_instruction(Instruction::PopEnvironment);
+
+        // 9. Return ? B.
         Ok(())
     }
+
+    /// 15.2 Function Definitions
+    /// https://262.ecma-international.org/16.0/#prod-FunctionDeclaration
+    pub(crate) fn js_parse_function_declaration(&mut self) -> CodeGenResult {
+        // It is a Syntax Error if a FunctionDeclaration, GeneratorDeclaration or AsyncFunctionDeclaration without a BindingIdentifier is not the default export.
+        if self.function_declaration_name().is_none() {
+            return self.unexpected_token(&[]);
+        }
+
+        let function_index = self.js_parse_function()?;
+
+        // NOTE: Function declarations are instantiated by FunctionDeclarationInstantiation, GlobalDeclarationInstantiation or BlockDeclarationInstantiation, so no instructions are emitted here unless Annex B applies.
+        let Some(block_index) = self.block_functions else {
+            self.bytecode.declare_function(function_index);
+
+            return Ok(());
+        };
+
+        self.bytecode
+            .declare_block_function(block_index, function_index);
+
+        #[cfg(feature = "annex-b")]
+        self.js_copy_block_function_to_var_scope(function_index);
+
+        Ok(())
+    }
+
+    /// B.3.2.1 Changes to FunctionDeclarationInstantiation
+    /// https://262.ecma-international.org/16.0/#sec-web-compat-functiondeclarationinstantiation
+    /// B.3.2.2 Changes to GlobalDeclarationInstantiation
+    /// https://262.ecma-international.org/16.0/#sec-web-compat-globaldeclarationinstantiation
+    ///
+    /// Emits the instructions which assign the function declared in a block of non-strict code to its var scoped binding, where the function declaration is evaluated.
+    #[cfg(feature = "annex-b")]
+    fn js_copy_block_function_to_var_scope(&mut self, function_index: u32) {
+        let function = self.bytecode.function(function_index);
+
+        // For each FunctionDeclaration f that is directly contained in the StatementList of a Block, CaseClause, or DefaultClause, do
+        // NOTE: Generator and async function declarations are never var scoped.
+        if self.strict
+            || function.generator_kind != GeneratorKind::NonGenerator
+            || function.is_async
+        {
+            return;
+        }
+
+        // a. Let F be StringValue of the BindingIdentifier of f.
+        let Some(name) = function.name.clone() else {
+            return;
+        };
+
+        // b. If replacing the FunctionDeclaration f with a VariableStatement that has F as a BindingIdentifier would not produce any Early Errors for func and parameterNames does not contain F, then
+        // NOTE: A var declaration would conflict with a lexical declaration or parameter of any scope enclosing the block, up to the function or script.
+        let scopes = &self.scopes[self.var_scope_depth.min(self.scopes.len())..];
+
+        if scopes[..scopes.len().saturating_sub(1)]
+            .iter()
+            .any(|scope| scope.iter().any(|(binding_name, _)| *binding_name == name))
+        {
+            return;
+        }
+
+        // When the FunctionDeclaration f is evaluated, perform the following steps in place of the FunctionDeclaration Evaluation algorithm provided in 15.2.6:
+        // i. Let fenv be the running execution context's VariableEnvironment.
+        // ii. Let benv be the running execution context's LexicalEnvironment.
+        // iii. Let fobj be ! benv.GetBindingValue(F, false).
+        let binding_index = self.bytecode.add_identifier(name.clone());
+
+        self.bytecode.emit_resolve_binding(binding_index);
+        self.bytecode.emit_instruction(Instruction::GetValue);
+
+        // iv. Perform ? fenv.SetMutableBinding(F, fobj, false).
+        self.bytecode.emit_set_variable_binding(name);
+    }
+
+    /// The BindingIdentifier of the function declaration starting at the current token, if it has one. No tokens are consumed.
+    pub(crate) fn function_declaration_name(&mut self) -> Option<Atom> {
+        let checkpoint = self.checkpoint();
+
+        self.optional(Token::Keyword(Keyword::Async));
+        self.advance(); // Eat 'function' token.
+        self.optional(Token::Multiply);
+
+        let name = self
+            .current_token
+            .is_binding_identifier()
+            .then(|| Atom::from(self.current_token.to_string()));
+
+        self.restore(checkpoint);
+
+        name
+    }
+
+    /// 14.11 The with Statement
+    /// https://262.ecma-international.org/16.0/#prod-WithStatement
+    fn js_parse_with_statement(&mut self) -> CodeGenResult {
+        // It is a Syntax Error if the source text matched by this production is contained in strict mode code.
+        if self.strict {
+            return self.error(CodeGenError::StrictModeWith);
+        }
+
+        self.warn(WarningKind::WithStatement, self.current_span.start);
+
+        self.advance(); // Eat 'with' token.
+
+        self.expect(Token::LeftParen)?;
+
+        // 1. Let val be ? Evaluation of Expression.
+        self.js_parse_expression()?;
+
+        self.expect(Token::RightParen)?;
+
+        // 2. Let obj be ? ToObject(? GetValue(val)).
+        // 3. Let oldEnv be the running execution context's LexicalEnvironment.
+        // 4. Let newEnv be NewObjectEnvironment(obj, true, oldEnv).
+        // 5. Set the running execution context's LexicalEnvironment to newEnv.
+        self.bytecode
+            .emit_instruction(Instruction::PushWithEnvironment);
+
+        // NOTE: The statement produces undefined when it is empty, which is UpdateEmpty(C, undefined).
+        self.bytecode.emit_instruction(Instruction::Undefined);
+
+        // 6. Let C be Completion(Evaluation of Statement).
+        let enclosing_with_scope_depth =
+            std::mem::replace(&mut self.with_scope_depth, self.scopes.len());
+
+        let statement = self.js_parse_statement();
+
+        self.with_scope_depth = enclosing_with_scope_depth;
+
+        statement?;
+
+        // 7. Set the running execution context's LexicalEnvironment to oldEnv.
+        self.bytecode.emit_instruction(Instruction::PopEnvironment);
+
+        // 8. Return ? UpdateEmpty(C, undefined).
+        Ok(())
+    }
+}
+
+/// 8.2.1 Static Semantics: BoundNames
+/// https://262.ecma-international.org/16.0/#sec-static-semantics-boundnames
+///
+/// Scans the tokens of an array binding pattern after its opening bracket, up to and including its closing bracket, adding the names it binds.
+/// Whether a statement can start straight after the token, as it ends a statement or an expression, which the parser inserts a semicolon after if the next token cannot continue it.
+fn precedes_statement(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Semicolon
+            | Token::RightBrace
+            | Token::RightParen
+            | Token::RightBracket
+            | Token::Increment
+            | Token::Decrement
+            | Token::String(_)
+            | Token::Int64(_)
+            | Token::Float64(_)
+            | Token::BigIntLiteral(_)
+            | Token::RegularExpressionLiteral(_)
+            | Token::TemplateNoSubstitution(_)
+            | Token::TemplateTail(_)
+            | Token::Keyword(
+                Keyword::This | Keyword::Null | Keyword::True | Keyword::False | Keyword::Super
+            )
+    ) || token.is_identifier()
+}
+
+fn scan_array_binding_pattern<'a>(
+    tokens: &mut Peekable<impl Iterator<Item = Token<'a>>>,
+    names: &mut Vec<Atom>,
+) {
+    // The pattern being scanned and the patterns enclosing it, innermost last, each with the number of brackets opened within an Initializer, whose tokens bind no names, and whether the next token starts a binding element, rather than being part of an Initializer.
+    // NOTE: The nested patterns are kept on a stack rather than scanned recursively, so that a deeply nested pattern can't overflow the stack.
+    let mut patterns = vec![(0usize, true)];
+
+    while let Some((depth, element_start)) = patterns.last_mut() {
+        let Some(token) = tokens.next() else {
+            return;
+        };
+
+        match token {
+            Token::Eof => return,
+            Token::LeftBracket if *depth == 0 && *element_start => {
+                *element_start = false;
+
+                patterns.push((0, true));
+            }
+            Token::LeftBrace | Token::LeftParen | Token::LeftBracket => *depth += 1,
+            Token::RightBrace | Token::RightParen | Token::RightBracket if *depth > 0 => {
+                *depth -= 1
+            }
+            Token::RightBracket => {
+                patterns.pop();
+            }
+            _ if *depth > 0 => {}
+            Token::Comma => *element_start = true,
+            Token::Spread => {}
+            token if *element_start && token.is_binding_identifier() => {
+                names.push(Atom::from(token.to_string()));
+
+                *element_start = false;
+            }
+            _ => *element_start = false,
+        }
+    }
 }
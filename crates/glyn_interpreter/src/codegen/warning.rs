@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+/// A non-fatal diagnostic for a deprecated or suspicious construct, reported while compiling a script which is still evaluated as normal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warning {
+    pub kind: WarningKind,
+    /// The byte offset in the source text where the construct starts.
+    pub start: usize,
+    /// The byte offset in the source text where the construct ends.
+    pub end: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WarningKind {
+    /// B.1.1 Numeric literals such as `017` or `019`, which are only permitted in sloppy mode.
+    LegacyOctalLiteral,
+    /// B.1.2 String escapes such as `"\01"`, which are only permitted in sloppy mode.
+    LegacyOctalEscapeSequence,
+    /// 14.11 The with Statement, which is deprecated and a SyntaxError in strict mode.
+    WithStatement,
+    /// An assignment to an identifier which resolves to a const declaration, which throws a TypeError if it runs.
+    ConstAssignment,
+}
+
+impl Display for WarningKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WarningKind::LegacyOctalLiteral => write!(f, "Legacy octal literals are deprecated"),
+            WarningKind::LegacyOctalEscapeSequence => {
+                write!(f, "Legacy octal escape sequences are deprecated")
+            }
+            WarningKind::WithStatement => write!(f, "The with statement is deprecated"),
+            WarningKind::ConstAssignment => write!(f, "Assignment to constant variable"),
+        }
+    }
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}..{})", self.kind, self.start, self.end)
+    }
+}
@@ -1,35 +1,281 @@
+use std::fmt::Display;
+
 use crate::{
     abstract_ops::{
-        realm::initialize_host_defined_realm,
+        module::{evaluate, get_module_namespace, link, load_requested_modules, parse_module},
         script::{parse_script, script_evaluation},
+        testing_comparison::same_value,
+        type_conversion::to_string,
+    },
+    lexer::{Lexer, SourcePosition, Span},
+    runtime::{
+        agent::JSAgent,
+        completion::ThrowCompletion,
+        realm::{JSRealm, RealmAddr},
     },
-    runtime::agent::JSAgent,
-    value::JSValue,
+    value::{string::JSString, JSValue},
 };
 
+/// A syntax error found in the source text, along with the span of the token it was found at.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxDiagnostic {
+    pub message: String,
+    /// The byte offset in the source text where the token the error was found at starts.
+    pub start: usize,
+    /// The byte offset in the source text where the token the error was found at ends.
+    pub end: usize,
+}
+
+impl SyntaxDiagnostic {
+    /// Renders the error for the source text it was found in, showing the line it is on with its span underlined.
+    fn render(&self, source_text: &str) -> String {
+        let lexer = Lexer::new(source_text);
+        let position = lexer.source_position(self.start);
+        let line_text = lexer.line_text(position.line);
+
+        let gutter = " ".repeat(position.line.to_string().len());
+        let indent = " ".repeat(position.column - 1);
+
+        // NOTE: A span which runs past the end of its line is underlined up to the end of the line, and an empty span, such as the end of the input, is underlined by a single caret.
+        let underline = "^".repeat(
+            source_text[self.start..self.end]
+                .chars()
+                .count()
+                .min(
+                    line_text
+                        .chars()
+                        .count()
+                        .saturating_sub(position.column - 1),
+                )
+                .max(1),
+        );
+
+        format!(
+            "{self}\n{gutter}--> {position}\n{gutter} |\n{} | {line_text}\n{gutter} | {indent}{underline}",
+            position.line
+        )
+    }
+}
+
+impl Display for SyntaxDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SyntaxError: {}", self.message)
+    }
+}
+
+/// The reason a script, module or job could not be evaluated to completion.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvalError {
+    /// The source text is not a valid script or module, or breaks one of the early error rules, with every syntax error found in it in the order they appear, of which there is at least one.
+    SyntaxError(Vec<SyntaxDiagnostic>),
+    /// A value was thrown and not caught, along with the message it converts to with ToString, which for errors raised by the engine is like `TypeError: …`.
+    UncaughtException {
+        value: JSValue,
+        message: String,
+        /// The line and column of the statement the value was thrown from, if it was thrown while evaluating one.
+        position: Option<SourcePosition>,
+        /// The stack trace of the calls the value was thrown in, from the innermost outwards, with each line starting with a line break like `\n    at f (2:3)`. It is empty if the value was not thrown while evaluating a statement.
+        stack: String,
+    },
+    /// The VM reached a state which no bytecode should lead it to, which is a bug in the interpreter rather than in the script.
+    Internal(String),
+    /// The fuel set by [`JSAgent::set_fuel`] ran out, and running out of fuel was not made catchable.
+    FuelExhausted,
+    /// The heap grew beyond the limit set by [`JSAgent::set_heap_limit`], even after the objects which were no longer referenced were freed.
+    OutOfMemory,
+}
+
+impl EvalError {
+    /// Converts the messages and spans of the syntax errors found in the source text into a `SyntaxError` listing them in the order they appear, or `None` if there are none.
+    pub(crate) fn from_syntax_errors(mut errors: Vec<(String, Span)>) -> Option<Self> {
+        if errors.is_empty() {
+            return None;
+        }
+
+        errors.sort_by_key(|(_, span)| span.start);
+
+        Some(EvalError::SyntaxError(
+            errors
+                .into_iter()
+                .map(|(message, span)| SyntaxDiagnostic {
+                    message,
+                    start: span.start,
+                    end: span.end,
+                })
+                .collect(),
+        ))
+    }
+
+    /// Converts a value thrown out of a script or job into the error reported to the embedder, which is an internal error if the VM failed while evaluating it.
+    pub(crate) fn from_throw_completion(agent: &mut JSAgent, error: ThrowCompletion) -> Self {
+        if let Some(message) = agent.internal_error.take() {
+            return EvalError::Internal(message);
+        }
+
+        if let Some(error) = agent.abort_error() {
+            return error;
+        }
+
+        let value = error.into_value(agent);
+
+        let message = to_string(agent, value.clone()).map_or_else(
+            |_| String::from("Uncaught exception"),
+            |message| message.to_string(),
+        );
+
+        let (position, stack) = agent
+            .thrown_at
+            .take()
+            .filter(|(thrown, ..)| same_value(thrown, &value))
+            .map_or((None, String::new()), |(_, position, stack)| {
+                (Some(position), stack)
+            });
+
+        EvalError::UncaughtException {
+            value,
+            message,
+            position,
+            stack,
+        }
+    }
+
+    /// Renders the error for the source text it was found in, showing the line each syntax error is on with the span of the error underlined.
+    ///
+    /// ```text
+    /// SyntaxError: Unexpected token ')'
+    ///  --> 2:5
+    ///   |
+    /// 2 | x = ) 2;
+    ///   |     ^
+    /// ```
+    ///
+    /// Any other error is rendered as it is displayed.
+    pub fn render(&self, source_text: &str) -> String {
+        let EvalError::SyntaxError(diagnostics) = self else {
+            return self.to_string();
+        };
+
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.render(source_text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::SyntaxError(diagnostics) => {
+                let diagnostics = diagnostics.iter().map(ToString::to_string);
+
+                f.write_str(&diagnostics.collect::<Vec<_>>().join("\n"))
+            }
+            EvalError::UncaughtException { message, stack, .. } if !stack.is_empty() => {
+                write!(f, "{message}{stack}")
+            }
+            EvalError::UncaughtException {
+                message,
+                position: Some(position),
+                ..
+            } => write!(f, "{message}\n    at {position}"),
+            EvalError::UncaughtException { message, .. } => f.write_str(message),
+            EvalError::Internal(message) => write!(f, "InternalError: {message}"),
+            EvalError::FuelExhausted => f.write_str("RangeError: fuel exhausted"),
+            EvalError::OutOfMemory => f.write_str("RangeError: out of memory"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
 /// https://github.com/tc39/test262/blob/main/INTERPRETING.md
-pub fn eval_script(agent: &mut JSAgent, script_str: &str) -> Result<JSValue, String> {
+///
+/// Warnings reported while compiling the script can be retrieved afterwards with [`JSAgent::take_warnings`].
+pub fn eval_script(agent: &mut JSAgent, script_str: &str) -> Result<JSValue, EvalError> {
     // 1. Let hostDefined be any host-defined values for the provided sourceText (obtained in an implementation dependent manner)
     let host_defined = None;
 
     // 2. Let realm be the current Realm Record.
-    let _ = initialize_host_defined_realm(agent);
+    // NOTE: The realm is only created for the first script, so that later scripts share its global environment.
+    agent.initialize_realm();
 
     let realm = agent.current_realm();
 
+    evaluate_script(agent, realm, script_str, host_defined)
+}
+
+/// Evaluates the source text as a script in a realm created with [`JSAgent::create_realm`], using the global object and intrinsics of that realm rather than those of the agent.
+pub fn eval_script_in_realm(
+    agent: &mut JSAgent,
+    realm: &JSRealm,
+    script_str: &str,
+) -> Result<JSValue, EvalError> {
+    evaluate_script(agent, realm.0.clone(), script_str, None)
+}
+
+fn evaluate_script(
+    agent: &mut JSAgent,
+    realm: RealmAddr,
+    script_str: &str,
+    host_defined: Option<()>,
+) -> Result<JSValue, EvalError> {
     // 3. Let s be ParseScript(sourceText, realm, hostDefined).
-    let s = parse_script(script_str, realm, host_defined)?;
+    let s = parse_script(
+        script_str,
+        realm,
+        host_defined,
+        agent.strict_only,
+        agent.optimization_level,
+        &mut agent.warnings,
+    )?;
 
     // 4. If s is a List of errors, then
     // a. Let error be the first element of s.
     // b. Return Completion{[[Type]]: throw, [[Value]]: error, [[Target]]: empty}.
     // 5. Let status be ScriptEvaluation(s).
+    agent.begin_evaluation();
+
     let status = script_evaluation(agent, &s);
 
     // 6. Return Completion(status).
-    // NOTE: We only return JSValue to avoid needing to expose additional types.
-    match status {
-        Ok(value) => Ok(value),
-        Err(err) => Err(format!("Script parsing error: {err:?}")),
+    status.map_err(|error| EvalError::from_throw_completion(agent, error))
+}
+
+/// Evaluates the source text as the module with the specifier, loading the modules it imports with the module loader set by [`JSAgent::set_module_loader`], and returns its module namespace object.
+///
+/// The specifier must not already have been loaded, either as a module which was evaluated or as one which was imported.
+pub fn eval_module(
+    agent: &mut JSAgent,
+    specifier: &str,
+    source_text: &str,
+) -> Result<JSValue, EvalError> {
+    agent.initialize_realm();
+
+    let specifier = JSString::from(specifier);
+
+    if agent.modules.contains_key(&specifier) {
+        let error =
+            ThrowCompletion::type_error(format!("Module '{specifier}' has already been loaded"));
+
+        return Err(EvalError::from_throw_completion(agent, error));
     }
+
+    let realm = agent.current_realm();
+
+    let module = parse_module(agent, source_text, realm, specifier.clone())?;
+
+    agent.modules.insert(specifier, module.clone());
+
+    agent.begin_evaluation();
+
+    // 16.2.1.5 Cyclic Module Records
+    // https://262.ecma-international.org/16.0/#sec-cyclic-module-records
+    // A host loads the graph of a module with LoadRequestedModules, then links it with Link and evaluates it with Evaluate.
+    load_requested_modules(agent, &module)
+        .and_then(|_| link(agent, &module))
+        .and_then(|_| evaluate(agent, &module))
+        .map_err(|error| EvalError::from_throw_completion(agent, error))?;
+
+    Ok(JSValue::from(get_module_namespace(agent, &module)))
 }
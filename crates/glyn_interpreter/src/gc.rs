@@ -1,30 +1,709 @@
-use std::{cell::RefCell, ops::Deref, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    mem::{size_of, ManuallyDrop},
+    ops::Deref,
+    rc::{Rc, Weak},
+};
 
-#[derive(Debug)]
-pub(crate) struct Gc<T>(Rc<RefCell<T>>);
+/// The size the heap must grow to before it is first collected, below which collecting it is not worth the time it takes.
+const MIN_COLLECTION_THRESHOLD: usize = 1024 * 1024;
 
-impl<T> Gc<T> {
-    pub(crate) fn new(value: T) -> Self {
-        Gc(Rc::new(RefCell::new(value)))
+/// The number of values the heap holds before the values which have been freed are first removed from it.
+const MIN_PRUNE_THRESHOLD: usize = 1024;
+
+thread_local! {
+    /// The size of the heap of the agent which is running a program on the thread, which the strings created while it runs are counted towards, as strings are created where there is no agent to hand.
+    static STRING_HEAP: RefCell<Option<HeapSize>> = const { RefCell::new(None) };
+}
+
+/// Counts the strings created on the thread from here on towards `heap`, returning the heap they were counted towards before.
+pub(crate) fn charge_strings_to(heap: Option<HeapSize>) -> Option<HeapSize> {
+    STRING_HEAP.with(|current| current.replace(heap))
+}
+
+/// Records the bytes taken up by a string, or by an interned string, as allocated on the heap of the agent which is running a program, returning that heap for the bytes to be freed from.
+pub(crate) fn record_string_allocation(bytes: usize) -> Option<HeapSize> {
+    let heap = STRING_HEAP.with(|heap| heap.borrow().clone());
+
+    if let Some(heap) = &heap {
+        heap.record_allocation(bytes);
     }
+
+    heap
 }
 
-impl<T> Clone for Gc<T> {
+/// The number of bytes allocated on a heap which have not been freed, which the heap shares with the values on it so that they can record the bytes they take up as they grow and are freed.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HeapSize(Rc<Cell<usize>>);
+
+impl HeapSize {
+    pub(crate) fn get(&self) -> usize {
+        self.0.get()
+    }
+
+    pub(crate) fn record_allocation(&self, bytes: usize) {
+        self.0.set(self.0.get() + bytes);
+    }
+
+    pub(crate) fn record_free(&self, bytes: usize) {
+        self.0.set(self.0.get().saturating_sub(bytes));
+    }
+
+    /// Records the change in the bytes taken up by a buffer which took up `before` bytes and now takes up `after`.
+    pub(crate) fn record_growth(&self, before: usize, after: usize) {
+        if after > before {
+            self.record_allocation(after - before);
+        } else {
+            self.record_free(before - after);
+        }
+    }
+}
+
+/// A value which holds references to values on the heap, which the collector follows to find the values that are reachable.
+pub(crate) trait Trace {
+    /// Reports each reference the value holds to a value on the heap, as many times as it holds it.
+    ///
+    /// A reference which is not reported keeps the value it refers to alive, while a reference which is reported but not held would let a reachable value be freed, so a reference which is shared with other values, like one held by a closure or behind an [`Rc`], must not be reported.
+    fn trace(&self, tracer: &mut Tracer);
+}
+
+/// A value which can be allocated on the heap, and so freed by the collector once it is unreachable.
+pub(crate) trait Collect: Trace + 'static {
+    /// Drops the references the value holds to other values on the heap, which breaks the cycles an unreachable value is part of.
+    fn clear(&mut self);
+
+    /// The bytes taken up by the buffers the value holds, like the lists of its properties, which are accounted for along with it.
+    fn buffer_size(&self) -> usize {
+        0
+    }
+}
+
+/// The references reported by tracing a value.
+#[derive(Default)]
+pub(crate) struct Tracer {
+    edges: Vec<*const ()>,
+}
+
+impl<T: Collect> Trace for Gc<T> {
+    fn trace(&self, tracer: &mut Tracer) {
+        tracer.edges.push(Rc::as_ptr(&self.0).cast());
+    }
+}
+
+impl<T: Trace> Trace for Option<T> {
+    fn trace(&self, tracer: &mut Tracer) {
+        if let Some(value) = self {
+            value.trace(tracer);
+        }
+    }
+}
+
+impl<T: Trace> Trace for Vec<T> {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.iter().for_each(|value| value.trace(tracer));
+    }
+}
+
+/// A value on the heap, whatever its type, as the collector sees it.
+trait HeapValue {
+    /// Traces the value, unless it is mutably borrowed, in which case it returns false.
+    fn trace(&self, tracer: &mut Tracer) -> bool;
+
+    fn clear(&self);
+}
+
+/// Every value allocated by an agent, which the collector finds the unreachable values among.
+pub(crate) struct Heap {
+    values: Vec<Weak<dyn HeapValue>>,
+    /// The number of values the heap holds before the values which have been freed are next removed from it.
+    next_prune: usize,
+    size: HeapSize,
+    /// The number of bytes the heap can grow to before evaluation runs out of memory.
+    limit: Option<usize>,
+    /// The size the heap must grow to before it is next collected.
+    next_collection: usize,
+}
+
+impl Default for Heap {
+    fn default() -> Self {
+        Self {
+            values: vec![],
+            next_prune: 0,
+            size: HeapSize::default(),
+            limit: None,
+            next_collection: MIN_COLLECTION_THRESHOLD,
+        }
+    }
+}
+
+impl Heap {
+    fn register(&mut self, value: Weak<dyn HeapValue>) {
+        self.values.push(value);
+
+        // NOTE: The allocation of a freed value is only released once its entry is removed, so the entries are removed as often as the heap doubles.
+        if self.values.len() >= self.next_prune.max(MIN_PRUNE_THRESHOLD) {
+            self.prune();
+        }
+    }
+
+    fn prune(&mut self) {
+        self.values.retain(|value| value.strong_count() > 0);
+
+        self.next_prune = self.values.len() * 2;
+    }
+
+    /// The number of bytes allocated on the heap which have not been freed.
+    pub(crate) fn size(&self) -> usize {
+        self.size.get()
+    }
+
+    /// The size of the heap, which a value on it records the growth of the buffers it holds in.
+    pub(crate) fn size_handle(&self) -> HeapSize {
+        self.size.clone()
+    }
+
+    pub(crate) fn set_limit(&mut self, limit: Option<usize>) {
+        self.limit = limit;
+    }
+
+    /// Whether the heap has grown beyond its limit.
+    pub(crate) fn is_over_limit(&self) -> bool {
+        self.would_exceed_limit(0)
+    }
+
+    /// Whether allocating the bytes would take the heap beyond its limit.
+    pub(crate) fn would_exceed_limit(&self, bytes: usize) -> bool {
+        self.limit
+            .is_some_and(|limit| self.size().saturating_add(bytes) > limit)
+    }
+
+    /// Whether the heap has grown enough since it was last collected to be collected again.
+    pub(crate) fn is_collection_due(&self) -> bool {
+        self.size() >= self.next_collection
+    }
+
+    /// Frees the values on the heap which are unreachable, returning how many there were.
+    ///
+    /// The values which are referenced from outside the heap, like by the execution contexts of an agent, the stack of a running VM or a value held by the embedder, are the roots. They are found by subtracting the references each value on the heap holds from the reference counts of the values they refer to, which leaves the references from outside the heap. Every value which cannot be reached from a root is part of a cycle of unreachable values, which are cleared to break the cycle.
+    ///
+    /// This is trial deletion over the reference counts rather than a mark-and-sweep collector: a value is still freed as soon as its last reference is dropped, and the collector only finds and breaks the cycles which reference counting cannot free.
+    ///
+    /// The nurseries hold references to young values, so they must have been released beforehand for the young values to be collected.
+    pub(crate) fn collect(&mut self) -> usize {
+        trace!(Gc, Debug, "collecting a heap of {} bytes", self.size());
+
+        self.prune();
+
+        let values: Vec<Rc<dyn HeapValue>> = self.values.iter().filter_map(Weak::upgrade).collect();
+
+        let indices: HashMap<*const (), usize> = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Rc::as_ptr(value).cast::<()>(), index))
+            .collect();
+
+        // NOTE: The reference held by the list of values is not counted.
+        let mut external_references: Vec<usize> = values
+            .iter()
+            .map(|value| Rc::strong_count(value) - 1)
+            .collect();
+
+        let mut edges: Vec<Option<Vec<usize>>> = Vec::with_capacity(values.len());
+
+        for value in &values {
+            let mut tracer = Tracer::default();
+
+            // NOTE: A value which is mutably borrowed is being changed by the code the collection was started from, so it is treated as a root, as are the values it refers to, whose references from it were not subtracted.
+            if !value.trace(&mut tracer) {
+                edges.push(None);
+
+                continue;
+            }
+
+            let value_edges: Vec<usize> = tracer
+                .edges
+                .iter()
+                .filter_map(|edge| indices.get(edge).copied())
+                .collect();
+
+            for &edge in &value_edges {
+                debug_assert!(
+                    external_references[edge] > 0,
+                    "a value reported a reference it does not hold"
+                );
+
+                external_references[edge] = external_references[edge].saturating_sub(1);
+            }
+
+            edges.push(Some(value_edges));
+        }
+
+        let mut reachable: Vec<bool> = external_references
+            .iter()
+            .zip(&edges)
+            .map(|(&external_references, edges)| external_references > 0 || edges.is_none())
+            .collect();
+
+        let mut pending: Vec<usize> = (0..values.len())
+            .filter(|&index| reachable[index])
+            .collect();
+
+        while let Some(index) = pending.pop() {
+            for &edge in edges[index].iter().flatten() {
+                if !reachable[edge] {
+                    reachable[edge] = true;
+
+                    pending.push(edge);
+                }
+            }
+        }
+
+        let mut freed = 0;
+
+        for (value, reachable) in values.iter().zip(&reachable) {
+            if !reachable {
+                value.clear();
+
+                freed += 1;
+            }
+        }
+
+        // NOTE: The unreachable values are only freed once the list of values no longer references them, so none is freed while another is being cleared.
+        drop(values);
+
+        self.next_collection = (self.size() * 2).max(MIN_COLLECTION_THRESHOLD);
+
+        trace!(
+            Gc,
+            Debug,
+            "freed {freed} values, leaving {} bytes",
+            self.size()
+        );
+
+        freed
+    }
+}
+
+/// A value on the heap, which records the bytes it takes up as allocated until it is freed.
+pub(crate) struct GcBox<T: Collect> {
+    heap_size: HeapSize,
+    value: RefCell<T>,
+}
+
+impl<T: Collect> GcBox<T> {
+    /// The bytes taken up by the allocation, including the reference counts.
+    const SIZE: usize = size_of::<Self>() + 2 * size_of::<usize>();
+}
+
+impl<T: Collect> GcBox<T> {
+    fn new(heap: &mut Heap, value: T) -> Rc<Self> {
+        heap.size.record_allocation(Self::SIZE);
+
+        let value = Rc::new(Self {
+            heap_size: heap.size.clone(),
+            value: RefCell::new(value),
+        });
+
+        heap.register(Rc::downgrade(&value) as Weak<GcBox<T>>);
+
+        value
+    }
+}
+
+impl<T: Collect> HeapValue for GcBox<T> {
+    fn trace(&self, tracer: &mut Tracer) -> bool {
+        let Ok(value) = self.value.try_borrow() else {
+            return false;
+        };
+
+        value.trace(tracer);
+
+        true
+    }
+
+    fn clear(&self) {
+        if let Ok(mut value) = self.value.try_borrow_mut() {
+            let size = value.buffer_size();
+
+            value.clear();
+
+            self.heap_size.record_growth(size, value.buffer_size());
+        }
+    }
+}
+
+impl<T: Collect> Drop for GcBox<T> {
+    fn drop(&mut self) {
+        self.heap_size
+            .record_free(Self::SIZE + self.value.get_mut().buffer_size());
+    }
+}
+
+/// A shared reference to a value on the heap.
+///
+/// A borrow of the value must be released before calling anything which can evaluate user code, like a getter, a setter or a host function, as that code can reach the same value and borrow it again. Copy what is needed out of the value first, or take it out of the value for the duration of the call and put it back afterwards.
+pub(crate) struct Gc<T: Collect>(ManuallyDrop<Rc<GcBox<T>>>);
+
+/// A value on the heap which can be described without following the references it holds.
+///
+/// Values on the heap reference each other in cycles, like a constructor and its prototype, so a reference to one is printed as this description and its address rather than as its contents.
+pub(crate) trait Summarize {
+    fn summarize(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+impl<T: Collect + Summarize> Debug for Gc<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // NOTE: The value is being mutated if it can't be borrowed, as happens when printing from within a method of the value.
+        match self.0.value.try_borrow() {
+            Ok(value) => value.summarize(f)?,
+            Err(_) => f.write_str("<borrowed>")?,
+        }
+
+        write!(f, " @ {:p}", Rc::as_ptr(&self.0))
+    }
+}
+
+impl<T: Collect> Gc<T> {
+    pub(crate) fn new(heap: &mut Heap, value: T) -> Self {
+        Gc(ManuallyDrop::new(GcBox::new(heap, value)))
+    }
+}
+
+impl<T: Collect> Clone for Gc<T> {
     fn clone(&self) -> Self {
         Gc(self.0.clone())
     }
 }
 
-impl<T> PartialEq for Gc<T> {
+thread_local! {
+    /// The values whose last reference was dropped while another value was being freed, which are freed one after the other rather than from within the value which referenced them. This is None unless a value is being freed.
+    static PENDING_FREES: RefCell<Option<Vec<Rc<dyn HeapValue>>>> = const { RefCell::new(None) };
+}
+
+impl<T: Collect> Drop for Gc<T> {
+    fn drop(&mut self) {
+        // Safety: The reference is taken out of the Gc as it is being dropped, so it is never used again.
+        let value: Rc<GcBox<T>> = unsafe { ManuallyDrop::take(&mut self.0) };
+
+        if Rc::strong_count(&value) > 1 {
+            return;
+        }
+
+        // NOTE: Freeing a value drops the references it holds, which would free the values only it referenced from within its own drop, so a long chain of values, like a linked list, would overflow the stack. A value freed while another is being freed is instead queued, and freed by the outermost drop once the value it was referenced from has been.
+        let mut value = Some(value as Rc<dyn HeapValue>);
+
+        let is_freeing = PENDING_FREES
+            .try_with(|pending| {
+                let mut pending = pending.borrow_mut();
+
+                match pending.as_mut() {
+                    Some(pending) => {
+                        pending.extend(value.take());
+
+                        true
+                    }
+                    None => {
+                        *pending = Some(vec![]);
+
+                        false
+                    }
+                }
+            })
+            .unwrap_or(false);
+
+        if is_freeing {
+            return;
+        }
+
+        drop(value);
+
+        // NOTE: The thread-local is only missing while the thread is exiting, in which case the value has been freed in place.
+        while let Ok(Some(next)) = PENDING_FREES.try_with(|pending| {
+            let mut pending = pending.borrow_mut();
+
+            match pending.as_mut().and_then(Vec::pop) {
+                Some(next) => Some(next),
+                None => {
+                    *pending = None;
+
+                    None
+                }
+            }
+        }) {
+            drop(next);
+        }
+    }
+}
+
+impl<T: Collect> PartialEq for Gc<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.0.as_ptr() == other.0.as_ptr()
+        Rc::ptr_eq(&self.0, &other.0)
     }
 }
 
-impl<T> Deref for Gc<T> {
+impl<T: Collect> Eq for Gc<T> {}
+
+impl<T: Collect> Hash for Gc<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
+impl<T: Collect> Deref for Gc<T> {
     type Target = RefCell<T>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.0.value
+    }
+}
+
+/// The number of young values a nursery holds before the oldest is either reclaimed or promoted.
+const NURSERY_CAPACITY: usize = 256;
+
+/// A value whose allocation can be reused for a new value of the same type once it is no longer referenced.
+pub(crate) trait Reclaim: Collect + Sized {
+    /// Replaces the contents with the new value, keeping any buffers the old contents allocated so the new value can grow into them.
+    fn reclaim(&mut self, value: Self);
+}
+
+/// Counts of the allocations made by a nursery, which show how much of the heap was reclaimed without freeing and reallocating it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HeapStats {
+    /// The number of values allocated in the nursery.
+    pub allocated: usize,
+    /// The number of allocations which reused a young value that was no longer referenced.
+    pub reclaimed: usize,
+    /// The number of young values which were still referenced when the nursery needed their slot, and so were left to be freed when their last reference is dropped.
+    pub promoted: usize,
+}
+
+/// A ring of the most recent allocations of a type, which lets short-lived values be reclaimed cheaply.
+///
+/// The nursery holds a reference to each young value. Each allocation takes the slot of the oldest young value: if nothing else references that value it is dead and its allocation is reused for the new value, otherwise it has survived long enough to be promoted and the nursery drops its reference. A dead value is therefore kept alive for at most [`NURSERY_CAPACITY`] allocations.
+pub(crate) struct Nursery<T: Collect> {
+    young: Vec<Rc<GcBox<T>>>,
+    /// The index of the oldest young value, which the next allocation takes the slot of once the nursery is full.
+    oldest: usize,
+    stats: HeapStats,
+}
+
+impl<T: Collect> Default for Nursery<T> {
+    fn default() -> Self {
+        Self {
+            young: Vec::with_capacity(NURSERY_CAPACITY),
+            oldest: 0,
+            stats: HeapStats::default(),
+        }
+    }
+}
+
+impl<T: Reclaim> Nursery<T> {
+    /// Allocates the value on the heap, reusing the allocation of a young value which is no longer referenced if there is one.
+    ///
+    /// Use this rather than [`Gc::new`] for values which are likely to be short-lived, like iterator results and other temporaries.
+    pub(crate) fn allocate(&mut self, heap: &mut Heap, value: T) -> Gc<T> {
+        self.stats.allocated += 1;
+
+        if self.young.len() < NURSERY_CAPACITY {
+            let young = GcBox::new(heap, value);
+
+            self.young.push(young.clone());
+
+            return Gc(ManuallyDrop::new(young));
+        }
+
+        let slot = &mut self.young[self.oldest];
+
+        self.oldest = (self.oldest + 1) % NURSERY_CAPACITY;
+
+        // NOTE: Only the nursery references the value, so it is unreachable and nothing can observe its allocation being reused.
+        if Rc::strong_count(slot) == 1 {
+            trace!(
+                Gc,
+                Trace,
+                "reclaimed a young {}",
+                std::any::type_name::<T>()
+            );
+
+            self.stats.reclaimed += 1;
+
+            let mut young = slot.value.borrow_mut();
+            let size = young.buffer_size();
+
+            young.reclaim(value);
+
+            slot.heap_size.record_growth(size, young.buffer_size());
+
+            drop(young);
+
+            return Gc(ManuallyDrop::new(slot.clone()));
+        }
+
+        trace!(Gc, Trace, "promoted a young {}", std::any::type_name::<T>());
+
+        self.stats.promoted += 1;
+
+        *slot = GcBox::new(heap, value);
+
+        Gc(ManuallyDrop::new(slot.clone()))
+    }
+
+    /// Drops the references the nursery holds to its young values, freeing those which are no longer referenced and promoting the rest, so that the collector sees only the references from elsewhere.
+    pub(crate) fn release(&mut self) {
+        self.young.clear();
+
+        self.oldest = 0;
+    }
+
+    pub(crate) fn stats(&self) -> HeapStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Young(Vec<usize>);
+
+    impl Reclaim for Young {
+        fn reclaim(&mut self, value: Self) {
+            self.0.clear();
+            self.0.extend(value.0);
+        }
+    }
+
+    impl Trace for Young {
+        fn trace(&self, _tracer: &mut Tracer) {}
+    }
+
+    impl Collect for Young {
+        fn clear(&mut self) {}
+    }
+
+    #[derive(Debug, Default)]
+    struct Node(Vec<Gc<Node>>);
+
+    impl Trace for Node {
+        fn trace(&self, tracer: &mut Tracer) {
+            self.0.trace(tracer);
+        }
+    }
+
+    impl Collect for Node {
+        fn clear(&mut self) {
+            self.0.clear();
+        }
+    }
+
+    impl Summarize for Node {
+        fn summarize(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "Node({})", self.0.len())
+        }
+    }
+
+    fn fill_nursery(heap: &mut Heap, nursery: &mut Nursery<Young>) -> Vec<Gc<Young>> {
+        (0..NURSERY_CAPACITY)
+            .map(|index| nursery.allocate(heap, Young(vec![index])))
+            .collect()
+    }
+
+    #[test]
+    fn reclaims_unreferenced_values() {
+        let mut heap = Heap::default();
+        let mut nursery = Nursery::default();
+
+        let mut young = fill_nursery(&mut heap, &mut nursery);
+
+        let oldest = young.remove(0);
+        let ptr = oldest.as_ptr();
+
+        drop(oldest);
+
+        let value = nursery.allocate(&mut heap, Young(vec![1, 2]));
+
+        assert_eq!(value.as_ptr(), ptr);
+        assert_eq!(value.borrow().0, vec![1, 2]);
+
+        let stats = nursery.stats();
+
+        assert_eq!(stats.allocated, NURSERY_CAPACITY + 1);
+        assert_eq!(stats.reclaimed, 1);
+        assert_eq!(stats.promoted, 0);
+    }
+
+    #[test]
+    fn promotes_referenced_values() {
+        let mut heap = Heap::default();
+        let mut nursery = Nursery::default();
+
+        let young = fill_nursery(&mut heap, &mut nursery);
+
+        let value = nursery.allocate(&mut heap, Young(vec![1, 2]));
+
+        assert!(young.iter().all(|young| *young != value));
+        assert_eq!(young[0].borrow().0, vec![0]);
+        assert_eq!(Rc::strong_count(&young[0].0), 1);
+
+        let stats = nursery.stats();
+
+        assert_eq!(stats.reclaimed, 0);
+        assert_eq!(stats.promoted, 1);
+    }
+
+    #[test]
+    fn formats_cycles_without_following_them() {
+        let a = Gc::new(&mut Heap::default(), Node::default());
+
+        a.borrow_mut().0.push(a.clone());
+
+        assert_eq!(
+            format!("{a:?}"),
+            format!("Node(1) @ {:p}", Rc::as_ptr(&a.0))
+        );
+
+        a.borrow_mut().0.clear();
+    }
+
+    #[test]
+    fn collects_unreachable_cycles() {
+        let mut heap = Heap::default();
+
+        let a = Gc::new(&mut heap, Node::default());
+        let b = Gc::new(&mut heap, Node(vec![a.clone()]));
+
+        a.borrow_mut().0.push(b.clone());
+
+        let weak_a = Rc::downgrade(&a.0);
+        let weak_b = Rc::downgrade(&b.0);
+
+        drop(a);
+        drop(b);
+
+        assert_eq!(heap.collect(), 2);
+        assert_eq!(weak_a.strong_count(), 0);
+        assert_eq!(weak_b.strong_count(), 0);
+    }
+
+    #[test]
+    fn keeps_values_reachable_from_outside_the_heap() {
+        let mut heap = Heap::default();
+
+        let root = Gc::new(&mut heap, Node::default());
+        let child = Gc::new(&mut heap, Node(vec![root.clone()]));
+
+        root.borrow_mut().0.push(child.clone());
+        root.borrow_mut().0.push(child.clone());
+
+        let weak_child = Rc::downgrade(&child.0);
+
+        drop(child);
+
+        assert_eq!(heap.collect(), 0);
+        assert_eq!(weak_child.strong_count(), 2);
     }
 }
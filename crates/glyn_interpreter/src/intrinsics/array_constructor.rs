@@ -0,0 +1,464 @@
+use crate::{
+    abstract_ops::{
+        array_exotic_objects::array_create,
+        function_operations::create_builtin_function,
+        iterator_operations::{get_iterator_from_method, iterator_close, iterator_step_value},
+        object_operations::{
+            call, construct, create_data_property_or_throw, define_property_or_throw, get,
+            get_method, length_of_array_like, set,
+        },
+        ordinary::get_prototype_from_constructor,
+        testing_comparison::{is_array, is_callable, is_constructor, same_value_zero},
+        type_conversion::{to_object, to_uint32},
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{
+            range_error, type_error, type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_ITERATOR,
+            WELL_KNOWN_SYMBOLS_SPECIES,
+        },
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            internal_slots::{ConstructorKind, InternalSlotName},
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// 23.1.1 The Array Constructor
+/// https://262.ecma-international.org/16.0/#sec-array-constructor
+#[derive(Debug)]
+pub(crate) struct ArrayConstructor;
+
+impl ArrayConstructor {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Array%.
+        // is the initial value of the "Array" property of the global object.
+        // has a [[Prototype]] internal slot whose value is %Function.prototype%.
+        // has a "length" property whose value is 1𝔽.
+        let array = create_builtin_function(
+            agent,
+            Self::array,
+            1,
+            JSObjectPropKey::String("Array".into()),
+            vec![InternalSlotName::ConstructorKind],
+            Some(realm_addr.clone()),
+            None,
+            None,
+        );
+
+        // may be used as the value of an extends clause of a class definition.
+        array
+            .data_mut()
+            .slots_mut()
+            .set_constructor_kind(ConstructorKind::Base);
+
+        define_builtin_method(agent, &realm_addr, &array, "Array", "from", 1, Self::from);
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array,
+            "Array",
+            "isArray",
+            1,
+            Self::is_array,
+        );
+        define_builtin_method(agent, &realm_addr, &array, "Array", "of", 0, Self::of);
+
+        // 23.1.2.4 Array.prototype
+        // The value of Array.prototype is the Array prototype object.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        let array_prototype = realm_addr
+            .borrow()
+            .intrinsics
+            .array_prototype
+            .clone()
+            .unwrap();
+
+        let _ = define_property_or_throw(
+            &array,
+            &JSObjectPropKey::String("prototype".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(array_prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 23.1.3.3 Array.prototype.constructor
+        // The initial value of Array.prototype.constructor is %Array%.
+        let _ = define_property_or_throw(
+            &array_prototype,
+            &JSObjectPropKey::String("constructor".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(array.clone())),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 23.1.2.5 get Array [ %Symbol.species% ]
+        // Array[%Symbol.species%] is an accessor property whose set accessor function is undefined.
+        let species_getter = create_builtin_function(
+            agent,
+            Self::get_species,
+            0,
+            JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_SPECIES),
+            vec![],
+            Some(realm_addr.clone()),
+            None,
+            Some("get".to_string()),
+        );
+
+        let _ = define_property_or_throw(
+            &array,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_SPECIES),
+            JSObjectPropDescriptor {
+                get: Some(JSValue::from(species_getter)),
+                set: Some(JSValue::Undefined),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        array
+    }
+
+    /// 23.1.1.1 Array ( ...values )
+    /// https://262.ecma-international.org/16.0/#sec-array
+    fn array(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        let active_function = agent.current_realm().borrow().intrinsics.array.clone();
+
+        let new_target = match args.new_target() {
+            Some(new_target) => new_target.clone(),
+            None => active_function.unwrap(),
+        };
+
+        // 2. Let proto be ? GetPrototypeFromConstructor(newTarget, "%Array.prototype%").
+        let proto = get_prototype_from_constructor(agent, &new_target, |intrinsics| {
+            intrinsics.array_prototype.clone()
+        })?;
+
+        // 3. Let numberOfArgs be the number of elements in values.
+        match args.len() {
+            // 4. If numberOfArgs = 0, then
+            // a. Return ! ArrayCreate(0, proto).
+            0 => Ok(JSValue::from(array_create(agent, 0, proto).unwrap())),
+            // 5. Else if numberOfArgs = 1, then
+            1 => {
+                // a. Let len be values[0].
+                let len = args.get(0);
+
+                // b. Let array be ! ArrayCreate(0, proto).
+                let array = array_create(agent, 0, proto).unwrap();
+
+                // c. If len is not a Number, then
+                let int_len = if !len.is_number() {
+                    // i. Perform ! CreateDataPropertyOrThrow(array, "0", len).
+                    create_data_property_or_throw(
+                        &array,
+                        &JSObjectPropKey::String("0".into()),
+                        len.clone(),
+                    )
+                    .unwrap();
+
+                    // ii. Let intLen be 1𝔽.
+                    JSValue::from(1)
+                }
+                // d. Else,
+                else {
+                    // i. Let intLen be ! ToUint32(len).
+                    let int_len = JSValue::from(to_uint32(agent, len.clone())?);
+
+                    // ii. If SameValueZero(intLen, len) is false, throw a RangeError exception.
+                    if !same_value_zero(&int_len, len) {
+                        return range_error("Invalid array length");
+                    }
+
+                    int_len
+                };
+
+                // e. Perform ! Set(array, "length", intLen, true).
+                set(
+                    agent,
+                    &array,
+                    &JSObjectPropKey::String("length".into()),
+                    int_len,
+                    true,
+                )?;
+
+                // f. Return array.
+                Ok(JSValue::from(array))
+            }
+            // 6. Else,
+            number_of_args => {
+                // a. Assert: numberOfArgs ≥ 2.
+                // b. Let array be ? ArrayCreate(numberOfArgs, proto).
+                let array = array_create(agent, number_of_args as u64, proto)?;
+
+                // c. Let k be 0.
+                // d. Repeat, while k < numberOfArgs,
+                for (k, item_k) in args.iter().enumerate() {
+                    // i. Let Pk be ! ToString(𝔽(k)).
+                    // ii. Let itemK be values[k].
+                    // iii. Perform ! CreateDataPropertyOrThrow(array, Pk, itemK).
+                    // iv. Set k to k + 1.
+                    create_data_property_or_throw(
+                        &array,
+                        &JSObjectPropKey::String(k.to_string().into()),
+                        item_k.clone(),
+                    )
+                    .unwrap();
+                }
+
+                // e. Assert: The mathematical value of array's "length" property is numberOfArgs.
+                // f. Return array.
+                Ok(JSValue::from(array))
+            }
+        }
+    }
+
+    /// Creates the array which Array.from and Array.of populate, which is an instance of the this value when it is a constructor.
+    fn create_from_this(
+        agent: &mut JSAgent,
+        this: &JSValue,
+        len: Option<u64>,
+    ) -> CompletionRecord<ObjectAddr> {
+        // If IsConstructor(C) is true, then
+        if is_constructor(this.clone()) {
+            // Let A be ? Construct(C, « 𝔽(len) »).
+            let arguments_list = len.map(|len| vec![JSValue::from(len as f64)]);
+
+            return construct(agent, &ObjectAddr::try_from(this)?, arguments_list, None);
+        }
+
+        // Else,
+        // Let A be ? ArrayCreate(len).
+        array_create(agent, len.unwrap_or(0), None)
+    }
+
+    /// 23.1.2.1 Array.from ( items [ , mapper [ , thisArg ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.from
+    fn from(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let items = args.get(0);
+        let mapper = args.get(1);
+        let this_arg = args.get(2);
+
+        // 1. Let C be the this value.
+        let c = args.this();
+
+        // 2. If mapper is undefined, then
+        // a. Let mapping be false.
+        // 3. Else,
+        // a. If IsCallable(mapper) is false, throw a TypeError exception.
+        // b. Let mapping be true.
+        let mapping = !mapper.is_undefined();
+
+        if mapping && !is_callable(mapper) {
+            return type_error_for("Array.from: the mapper is not a function", mapper);
+        }
+
+        // 4. Let usingIterator be ? GetMethod(items, %Symbol.iterator%).
+        let using_iterator = get_method(
+            agent,
+            items,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+        )?;
+
+        // 5. If usingIterator is not undefined, then
+        if let Some(using_iterator) = using_iterator {
+            // a. If IsConstructor(C) is true, then
+            // i. Let A be ? Construct(C).
+            // b. Else,
+            // i. Let A be ! ArrayCreate(0).
+            let a = Self::create_from_this(agent, c, None)?;
+
+            // c. Let iteratorRecord be ? GetIteratorFromMethod(items, usingIterator).
+            let mut iterator_record = get_iterator_from_method(agent, items, using_iterator)?;
+
+            // d. Let k be 0.
+            let mut k = 0u64;
+
+            // e. Repeat,
+            loop {
+                // i. If k ≥ 2**53 - 1, then
+                if k >= (1 << 53) - 1 {
+                    // 1. Let error be ThrowCompletion(a newly created TypeError object).
+                    let error = type_error("Array.from: too many elements");
+
+                    // 2. Return ? IteratorClose(iteratorRecord, error).
+                    return iterator_close(agent, &iterator_record, error);
+                }
+
+                // ii. Let Pk be ! ToString(𝔽(k)).
+                let pk = JSObjectPropKey::String(k.to_string().into());
+
+                // iii. Let next be ? IteratorStepValue(iteratorRecord).
+                // iv. If next is done, then
+                let Some(next) = iterator_step_value(agent, &mut iterator_record)? else {
+                    // 1. Perform ? Set(A, "length", 𝔽(k), true).
+                    set(
+                        agent,
+                        &a,
+                        &JSObjectPropKey::String("length".into()),
+                        JSValue::from(k as f64),
+                        true,
+                    )?;
+
+                    // 2. Return A.
+                    return Ok(JSValue::from(a));
+                };
+
+                // v. If mapping is true, then
+                let mapped_value = if mapping {
+                    // 1. Let mappedValue be Completion(Call(mapper, thisArg, « next, 𝔽(k) »)).
+                    // 2. IfAbruptCloseIterator(mappedValue, iteratorRecord).
+                    match call(
+                        agent,
+                        mapper.clone(),
+                        this_arg,
+                        Some(vec![next, JSValue::from(k as f64)]),
+                    ) {
+                        Ok(mapped_value) => mapped_value,
+                        Err(error) => return iterator_close(agent, &iterator_record, Err(error)),
+                    }
+                }
+                // vi. Else,
+                else {
+                    // 1. Let mappedValue be next.
+                    next
+                };
+
+                // vii. Let defineStatus be Completion(CreateDataPropertyOrThrow(A, Pk, mappedValue)).
+                // viii. IfAbruptCloseIterator(defineStatus, iteratorRecord).
+                if let Err(error) = create_data_property_or_throw(&a, &pk, mapped_value) {
+                    return iterator_close(agent, &iterator_record, Err(error));
+                }
+
+                // ix. Set k to k + 1.
+                k += 1;
+            }
+        }
+
+        // 6. NOTE: items is not an Iterable so assume it is an array-like object.
+        // 7. Let arrayLike be ! ToObject(items).
+        let array_like = to_object(agent, items)?;
+
+        // 8. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = length_of_array_like(agent, &array_like)?;
+
+        // 9. If IsConstructor(C) is true, then
+        // a. Let A be ? Construct(C, « 𝔽(len) »).
+        // 10. Else,
+        // a. Let A be ? ArrayCreate(len).
+        let a = Self::create_from_this(agent, c, Some(len))?;
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = JSObjectPropKey::String(k.to_string().into());
+
+            // b. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = get(agent, &array_like, &pk)?;
+
+            // c. If mapping is true, then
+            // i. Let mappedValue be ? Call(mapper, thisArg, « kValue, 𝔽(k) »).
+            // d. Else,
+            // i. Let mappedValue be kValue.
+            let mapped_value = if mapping {
+                call(
+                    agent,
+                    mapper.clone(),
+                    this_arg,
+                    Some(vec![k_value, JSValue::from(k as f64)]),
+                )?
+            } else {
+                k_value
+            };
+
+            // e. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+            // f. Set k to k + 1.
+            create_data_property_or_throw(&a, &pk, mapped_value)?;
+        }
+
+        // 13. Perform ? Set(A, "length", 𝔽(len), true).
+        set(
+            agent,
+            &a,
+            &JSObjectPropKey::String("length".into()),
+            JSValue::from(len as f64),
+            true,
+        )?;
+
+        // 14. Return A.
+        Ok(JSValue::from(a))
+    }
+
+    /// 23.1.2.2 Array.isArray ( arg )
+    /// https://262.ecma-international.org/16.0/#sec-array.isarray
+    fn is_array(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? IsArray(arg).
+        Ok(JSValue::from(is_array(args.get(0))))
+    }
+
+    /// 23.1.2.3 Array.of ( ...items )
+    /// https://262.ecma-international.org/16.0/#sec-array.of
+    fn of(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let len be the number of elements in items.
+        let len = args.len() as u64;
+
+        // 2. Let lenNumber be 𝔽(len).
+        // 3. Let C be the this value.
+        // 4. If IsConstructor(C) is true, then
+        // a. Let A be ? Construct(C, « lenNumber »).
+        // 5. Else,
+        // a. Let A be ? ArrayCreate(len).
+        let a = Self::create_from_this(agent, args.this(), Some(len))?;
+
+        // 6. Let k be 0.
+        // 7. Repeat, while k < len,
+        for (k, k_value) in args.iter().enumerate() {
+            // a. Let kValue be items[k].
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Perform ? CreateDataPropertyOrThrow(A, Pk, kValue).
+            // d. Set k to k + 1.
+            create_data_property_or_throw(
+                &a,
+                &JSObjectPropKey::String(k.to_string().into()),
+                k_value.clone(),
+            )?;
+        }
+
+        // 8. Perform ? Set(A, "length", lenNumber, true).
+        set(
+            agent,
+            &a,
+            &JSObjectPropKey::String("length".into()),
+            JSValue::from(len as f64),
+            true,
+        )?;
+
+        // 9. Return A.
+        Ok(JSValue::from(a))
+    }
+
+    /// 23.1.2.5 get Array [ %Symbol.species% ]
+    /// https://262.ecma-international.org/16.0/#sec-get-array-%symbol.species%
+    fn get_species(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return the this value.
+        Ok(args.this().clone())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        iterator_operations::create_iter_result_object,
+        object_operations::define_property_or_throw, ordinary::ordinary_object_create,
+    },
+    runtime::{
+        agent::{type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// 23.1.5.2 The %ArrayIteratorPrototype% Object
+/// https://262.ecma-international.org/16.0/#sec-%arrayiteratorprototype%-object
+#[derive(Debug)]
+pub(crate) struct ArrayIteratorPrototype;
+
+impl ArrayIteratorPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // has properties that are inherited by all Array Iterator objects.
+        // is an ordinary object.
+        // has a [[Prototype]] internal slot whose value is %Iterator.prototype%.
+        let array_iterator_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.iterator_prototype.clone(),
+            None,
+        );
+
+        let next = create_builtin_function(
+            agent,
+            Self::next,
+            0,
+            JSObjectPropKey::String("next".into()),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        );
+
+        let _ = define_property_or_throw(
+            &array_iterator_prototype,
+            &JSObjectPropKey::String("next".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(next)),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 23.1.5.2.2 %ArrayIteratorPrototype% [ %Symbol.toStringTag% ]
+        // The initial value of the %Symbol.toStringTag% property is the String value "Array Iterator".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &array_iterator_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from("Array Iterator".to_string())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        array_iterator_prototype
+    }
+
+    /// 23.1.5.2.1 %ArrayIteratorPrototype%.next ( )
+    /// https://262.ecma-international.org/16.0/#sec-%arrayiteratorprototype%.next
+    fn next(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? GeneratorResume(this value, empty, "%ArrayIteratorPrototype%").
+        let object = ObjectAddr::try_from(args.this())?;
+
+        // NOTE: The closure state is taken out of the iterator while it is advanced, so a value without the state, or an iterator which is already executing, is rejected as GeneratorValidate would.
+        let Some(mut iterator) = object.data_mut().slots_mut().take_array_iterator() else {
+            return type_error_for(
+                "Array Iterator next called on an incompatible receiver",
+                args.this(),
+            );
+        };
+
+        let value = iterator.next(agent);
+
+        object.data_mut().slots_mut().set_array_iterator(iterator);
+
+        let result = match value? {
+            Some(value) => create_iter_result_object(agent, value, false),
+            None => create_iter_result_object(agent, JSValue::Undefined, true),
+        };
+
+        Ok(JSValue::from(result))
+    }
+}
@@ -0,0 +1,1508 @@
+use std::{cmp::Ordering, mem::size_of};
+
+use crate::{
+    abstract_ops::{
+        array_exotic_objects::{array_create, array_species_create},
+        object_operations::{
+            call, create_data_property_or_throw, define_property_or_throw,
+            delete_property_or_throw, get, has_property, length_of_array_like, set,
+        },
+        ordinary::ordinary_object_create,
+        testing_comparison::{is_array, is_callable, is_strictly_equal, same_value_zero},
+        type_conversion::{to_boolean, to_integer_or_infinity, to_number, to_object, to_string},
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{
+            range_error, type_error, JSAgent, WELL_KNOWN_SYMBOLS_ITERATOR,
+            WELL_KNOWN_SYMBOLS_UNSCOPABLES,
+        },
+        completion::CompletionRecord,
+        iteration::{create_array_iterator, ArrayIteratorKind},
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        string::{JSString, MAX_STRING_LENGTH},
+        JSValue,
+    },
+};
+
+/// 23.1.3 Properties of the Array Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-array-prototype-object
+#[derive(Debug)]
+pub(crate) struct ArrayPrototype;
+
+impl ArrayPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Array.prototype%.
+        // is an Array exotic object and has the internal methods specified for such objects.
+        // has a "length" property whose initial value is +0𝔽 and whose attributes are { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        let array_prototype = array_create(
+            agent,
+            0,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+        )
+        .unwrap();
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "at",
+            1,
+            Self::at,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "copyWithin",
+            2,
+            Self::copy_within,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "entries",
+            0,
+            Self::entries,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "fill",
+            1,
+            Self::fill,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "findLast",
+            1,
+            Self::find_last,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "findLastIndex",
+            1,
+            Self::find_last_index,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "flat",
+            0,
+            Self::flat,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "flatMap",
+            1,
+            Self::flat_map,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "includes",
+            1,
+            Self::includes,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "join",
+            1,
+            Self::join,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "keys",
+            0,
+            Self::keys,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "lastIndexOf",
+            1,
+            Self::last_index_of,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "reverse",
+            0,
+            Self::reverse,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "sort",
+            1,
+            Self::sort,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "toReversed",
+            0,
+            Self::to_reversed,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "toSorted",
+            1,
+            Self::to_sorted,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "toSpliced",
+            2,
+            Self::to_spliced,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "toString",
+            0,
+            Self::to_string,
+        );
+
+        let values = define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "values",
+            0,
+            Self::values,
+        );
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &array_prototype,
+            "Array.prototype",
+            "with",
+            2,
+            Self::with,
+        );
+
+        // 23.1.3.40 Array.prototype [ %Symbol.iterator% ] ( )
+        // The initial value of the %Symbol.iterator% property is %Array.prototype.values%, defined in 23.1.3.38.
+        let _ = define_property_or_throw(
+            &array_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(values.clone())),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 23.1.3.41 Array.prototype [ %Symbol.unscopables% ]
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &array_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_UNSCOPABLES),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(Self::unscopables(agent))),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        realm_addr.borrow_mut().intrinsics.array_prototype_values = Some(values);
+
+        array_prototype
+    }
+
+    /// 23.1.3.41 Array.prototype [ %Symbol.unscopables% ]
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype-%symbol.unscopables%
+    fn unscopables(agent: &mut JSAgent) -> ObjectAddr {
+        // 1. Let unscopableList be OrdinaryObjectCreate(null).
+        let unscopable_list = ordinary_object_create(agent, None, None);
+
+        // 2. Perform ! CreateDataPropertyOrThrow(unscopableList, "at", true).
+        // 3. Perform ! CreateDataPropertyOrThrow(unscopableList, "copyWithin", true).
+        // 4. Perform ! CreateDataPropertyOrThrow(unscopableList, "entries", true).
+        // 5. Perform ! CreateDataPropertyOrThrow(unscopableList, "fill", true).
+        // 6. Perform ! CreateDataPropertyOrThrow(unscopableList, "find", true).
+        // 7. Perform ! CreateDataPropertyOrThrow(unscopableList, "findIndex", true).
+        // 8. Perform ! CreateDataPropertyOrThrow(unscopableList, "findLast", true).
+        // 9. Perform ! CreateDataPropertyOrThrow(unscopableList, "findLastIndex", true).
+        // 10. Perform ! CreateDataPropertyOrThrow(unscopableList, "flat", true).
+        // 11. Perform ! CreateDataPropertyOrThrow(unscopableList, "flatMap", true).
+        // 12. Perform ! CreateDataPropertyOrThrow(unscopableList, "includes", true).
+        // 13. Perform ! CreateDataPropertyOrThrow(unscopableList, "keys", true).
+        // 14. Perform ! CreateDataPropertyOrThrow(unscopableList, "toReversed", true).
+        // 15. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSorted", true).
+        // 16. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSpliced", true).
+        // 17. Perform ! CreateDataPropertyOrThrow(unscopableList, "values", true).
+        for name in [
+            "at",
+            "copyWithin",
+            "entries",
+            "fill",
+            "find",
+            "findIndex",
+            "findLast",
+            "findLastIndex",
+            "flat",
+            "flatMap",
+            "includes",
+            "keys",
+            "toReversed",
+            "toSorted",
+            "toSpliced",
+            "values",
+        ] {
+            let _ = create_data_property_or_throw(
+                &unscopable_list,
+                &JSObjectPropKey::String(name.into()),
+                JSValue::from(true),
+            );
+        }
+
+        // 18. Return unscopableList.
+        unscopable_list
+    }
+
+    /// 23.1.3.1 Array.prototype.at ( index )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.at
+    fn at(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = to_integer_or_infinity(agent, args.get(0).clone())?.0;
+
+        // 4. If relativeIndex ≥ 0, then
+        // a. Let k be relativeIndex.
+        // 5. Else,
+        // a. Let k be len + relativeIndex.
+        let k = if relative_index >= 0.0 {
+            relative_index
+        } else {
+            len as f64 + relative_index
+        };
+
+        // 6. If k < 0 or k ≥ len, return undefined.
+        if k < 0.0 || k >= len as f64 {
+            return Ok(JSValue::Undefined);
+        }
+
+        // 7. Return ? Get(O, ! ToString(𝔽(k))).
+        get(
+            agent,
+            &o,
+            &JSObjectPropKey::String((k as u64).to_string().into()),
+        )
+    }
+
+    /// 23.1.3.4 Array.prototype.copyWithin ( target, start [ , end ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.copywithin
+    fn copy_within(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let relativeTarget be ? ToIntegerOrInfinity(target).
+        // 4. If relativeTarget = -∞, let to be 0.
+        // 5. Else if relativeTarget < 0, let to be max(len + relativeTarget, 0).
+        // 6. Else, let to be min(relativeTarget, len).
+        let relative_target = to_integer_or_infinity(agent, args.get(0).clone())?.0;
+        let mut to = relative_to_index(relative_target, len);
+
+        // 7. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 8. If relativeStart = -∞, let from be 0.
+        // 9. Else if relativeStart < 0, let from be max(len + relativeStart, 0).
+        // 10. Else, let from be min(relativeStart, len).
+        let relative_start = to_integer_or_infinity(agent, args.get(1).clone())?.0;
+        let mut from = relative_to_index(relative_start, len);
+
+        // 11. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        // 12. If relativeEnd = -∞, let final be 0.
+        // 13. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+        // 14. Else, let final be min(relativeEnd, len).
+        let r#final = Self::relative_end(agent, args.get(2), len)?;
+
+        // 15. Let count be min(final - from, len - to).
+        let mut count = r#final.saturating_sub(from).min(len - to);
+
+        // 16. If from < to and to < from + count, then
+        // a. Let direction be -1.
+        // b. Set from to from + count - 1.
+        // c. Set to to to + count - 1.
+        // 17. Else,
+        // a. Let direction be 1.
+        let backwards = from < to && to < from + count;
+
+        if backwards {
+            from += count - 1;
+            to += count - 1;
+        }
+
+        // 18. Repeat, while count > 0,
+        while count > 0 {
+            // a. Let fromKey be ! ToString(𝔽(from)).
+            let from_key = JSObjectPropKey::String(from.to_string().into());
+
+            // b. Let toKey be ! ToString(𝔽(to)).
+            let to_key = JSObjectPropKey::String(to.to_string().into());
+
+            // c. Let fromPresent be ? HasProperty(O, fromKey).
+            // d. If fromPresent is true, then
+            if has_property(&o, &from_key)? {
+                // i. Let fromValue be ? Get(O, fromKey).
+                let from_value = get(agent, &o, &from_key)?;
+
+                // ii. Perform ? Set(O, toKey, fromValue, true).
+                set(agent, &o, &to_key, from_value, true)?;
+            }
+            // e. Else,
+            else {
+                // i. Assert: fromPresent is false.
+                // ii. Perform ? DeletePropertyOrThrow(O, toKey).
+                delete_property_or_throw(&o, &to_key)?;
+            }
+
+            // f. Set from to from + direction.
+            // g. Set to to to + direction.
+            // NOTE: The indices only go below 0 once the last element has been copied, so they saturate at 0 instead.
+            if backwards {
+                from = from.saturating_sub(1);
+                to = to.saturating_sub(1);
+            } else {
+                from += 1;
+                to += 1;
+            }
+
+            // h. Set count to count - 1.
+            count -= 1;
+        }
+
+        // 19. Return O.
+        Ok(JSValue::from(o))
+    }
+
+    /// The index an optional end argument refers to, which is the length of the array when it is undefined, and counts back from the length when it is negative.
+    fn relative_end(agent: &mut JSAgent, end: &JSValue, len: u64) -> CompletionRecord<u64> {
+        // If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        if end.is_undefined() {
+            return Ok(len);
+        }
+
+        let relative_end = to_integer_or_infinity(agent, end.clone())?.0;
+
+        // If relativeEnd = -∞, let final be 0.
+        // Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+        // Else, let final be min(relativeEnd, len).
+        Ok(relative_to_index(relative_end, len))
+    }
+
+    /// 23.1.3.5 Array.prototype.entries ( )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.entries
+    fn entries(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Return CreateArrayIterator(O, key+value).
+        Ok(JSValue::from(create_array_iterator(
+            agent,
+            o,
+            ArrayIteratorKind::KeyValue,
+        )))
+    }
+
+    /// 23.1.3.7 Array.prototype.fill ( value [ , start [ , end ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.fill
+    fn fill(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let value = args.get(0);
+
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 4. If relativeStart = -∞, let k be 0.
+        // 5. Else if relativeStart < 0, let k be max(len + relativeStart, 0).
+        // 6. Else, let k be min(relativeStart, len).
+        let relative_start = to_integer_or_infinity(agent, args.get(1).clone())?.0;
+        let k = relative_to_index(relative_start, len);
+
+        // 7. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        // 8. If relativeEnd = -∞, let final be 0.
+        // 9. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+        // 10. Else, let final be min(relativeEnd, len).
+        let r#final = Self::relative_end(agent, args.get(2), len)?;
+
+        // 11. Repeat, while k < final,
+        for k in k..r#final {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Perform ? Set(O, Pk, value, true).
+            // c. Set k to k + 1.
+            set(
+                agent,
+                &o,
+                &JSObjectPropKey::String(k.to_string().into()),
+                value.clone(),
+                true,
+            )?;
+        }
+
+        // 12. Return O.
+        Ok(JSValue::from(o))
+    }
+
+    /// 23.1.3.11 Array.prototype.findLast ( predicate [ , thisArg ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.findlast
+    fn find_last(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let findRec be ? FindViaPredicate(O, len, descending, predicate, thisArg).
+        let (_, value) = Self::find_via_predicate(agent, &o, (0..len).rev(), &args)?;
+
+        // 4. Return findRec.[[Value]].
+        Ok(value)
+    }
+
+    /// 23.1.3.12 Array.prototype.findLastIndex ( predicate [ , thisArg ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.findlastindex
+    fn find_last_index(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let findRec be ? FindViaPredicate(O, len, descending, predicate, thisArg).
+        let (index, _) = Self::find_via_predicate(agent, &o, (0..len).rev(), &args)?;
+
+        // 4. Return findRec.[[Index]].
+        Ok(JSValue::from(index))
+    }
+
+    /// 23.1.3.12.1 FindViaPredicate ( O, len, direction, predicate, thisArg )
+    /// https://262.ecma-international.org/16.0/#sec-findviapredicate
+    ///
+    /// The direction is given by the order of the indices, and the predicate and thisArg are the first two arguments of the method. Returns the index and value of the element the predicate returns true for.
+    fn find_via_predicate(
+        agent: &mut JSAgent,
+        o: &ObjectAddr,
+        indices: impl Iterator<Item = u64>,
+        args: &Args,
+    ) -> CompletionRecord<(f64, JSValue)> {
+        let this_arg = args.get(1);
+
+        // 1. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = args.get_function(0)?;
+
+        // 2. If direction is ascending, then
+        // a. Let indices be a List of the integers in the interval from 0 (inclusive) to len (exclusive), in ascending order.
+        // 3. Else,
+        // a. Let indices be a List of the integers in the interval from 0 (inclusive) to len (exclusive), in descending order.
+        // 4. For each integer k of indices, do
+        for k in indices {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. NOTE: If O is a TypedArray, the following invocation of Get will return a normal completion.
+            // c. Let kValue be ? Get(O, Pk).
+            let k_value = get(agent, o, &JSObjectPropKey::String(k.to_string().into()))?;
+
+            // d. Let testResult be ? Call(predicate, thisArg, « kValue, 𝔽(k), O »).
+            let test_result = call(
+                agent,
+                predicate.clone(),
+                this_arg,
+                Some(vec![
+                    k_value.clone(),
+                    JSValue::from(k as f64),
+                    JSValue::from(o.clone()),
+                ]),
+            )?;
+
+            // e. If ToBoolean(testResult) is true, return the Record { [[Index]]: 𝔽(k), [[Value]]: kValue }.
+            if to_boolean(test_result) {
+                return Ok((k as f64, k_value));
+            }
+        }
+
+        // 5. Return the Record { [[Index]]: -1𝔽, [[Value]]: undefined }.
+        Ok((-1.0, JSValue::Undefined))
+    }
+
+    /// 23.1.3.13 Array.prototype.flat ( [ depth ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.flat
+    fn flat(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let depth = args.get(0);
+
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let sourceLen be ? LengthOfArrayLike(O).
+        let source_len = length_of_array_like(agent, &o)?;
+
+        // 3. Let depthNum be 1.
+        // 4. If depth is not undefined, then
+        // a. Set depthNum to ? ToIntegerOrInfinity(depth).
+        // b. If depthNum < 0, set depthNum to 0.
+        let depth_num = if depth.is_undefined() {
+            1.0
+        } else {
+            to_integer_or_infinity(agent, depth.clone())?.0.max(0.0)
+        };
+
+        // 5. Let A be ? ArraySpeciesCreate(O, 0).
+        let a = array_species_create(agent, &o, 0)?;
+
+        // 6. Perform ? FlattenIntoArray(A, O, sourceLen, 0, depthNum).
+        Self::flatten_into_array(agent, &a, &o, source_len, 0, depth_num, None)?;
+
+        // 7. Return A.
+        Ok(JSValue::from(a))
+    }
+
+    /// 23.1.3.13.1 FlattenIntoArray ( target, source, sourceLen, start, depth [ , mapperFunction [ , thisArg ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-flattenintoarray
+    ///
+    /// The mapper function is given together with its thisArg.
+    fn flatten_into_array(
+        agent: &mut JSAgent,
+        target: &ObjectAddr,
+        source: &ObjectAddr,
+        source_len: u64,
+        start: u64,
+        depth: f64,
+        mapper: Option<(&JSValue, &JSValue)>,
+    ) -> CompletionRecord<u64> {
+        // 1. Assert: If mapperFunction is present, then IsCallable(mapperFunction) is true, thisArg is present, and depth is 1.
+        // 2. Let targetIndex be start.
+        let mut target_index = start;
+
+        // 3. Let sourceIndex be +0𝔽.
+        // 4. Repeat, while ℝ(sourceIndex) < sourceLen,
+        for source_index in 0..source_len {
+            // a. Let P be ! ToString(sourceIndex).
+            let p = JSObjectPropKey::String(source_index.to_string().into());
+
+            // b. Let exists be ? HasProperty(source, P).
+            // c. If exists is true, then
+            if !has_property(source, &p)? {
+                continue;
+            }
+
+            // i. Let element be ? Get(source, P).
+            let mut element = get(agent, source, &p)?;
+
+            // ii. If mapperFunction is present, then
+            if let Some((mapper_function, this_arg)) = mapper {
+                // 1. Set element to ? Call(mapperFunction, thisArg, « element, sourceIndex, source »).
+                element = call(
+                    agent,
+                    mapper_function.clone(),
+                    this_arg,
+                    Some(vec![
+                        element,
+                        JSValue::from(source_index as f64),
+                        JSValue::from(source.clone()),
+                    ]),
+                )?;
+            }
+
+            // iii. Let shouldFlatten be false.
+            // iv. If depth > 0, then
+            // 1. Set shouldFlatten to ? IsArray(element).
+            let should_flatten = depth > 0.0 && is_array(&element);
+
+            // v. If shouldFlatten is true, then
+            if should_flatten {
+                // 1. If depth = +∞, let newDepth be +∞.
+                // 2. Else, let newDepth be depth - 1.
+                // NOTE: Subtracting from +∞ leaves it unchanged.
+                let new_depth = depth - 1.0;
+
+                // 3. Let elementLen be ? LengthOfArrayLike(element).
+                let element = ObjectAddr::try_from(&element)?;
+                let element_len = length_of_array_like(agent, &element)?;
+
+                // 4. Set targetIndex to ? FlattenIntoArray(target, element, elementLen, targetIndex, newDepth).
+                target_index = Self::flatten_into_array(
+                    agent,
+                    target,
+                    &element,
+                    element_len,
+                    target_index,
+                    new_depth,
+                    None,
+                )?;
+            }
+            // vi. Else,
+            else {
+                // 1. If targetIndex ≥ 2**53 - 1, throw a TypeError exception.
+                if target_index >= (1 << 53) - 1 {
+                    return type_error("Array.prototype.flat: too many elements");
+                }
+
+                // 2. Perform ? CreateDataPropertyOrThrow(target, ! ToString(𝔽(targetIndex)), element).
+                create_data_property_or_throw(
+                    target,
+                    &JSObjectPropKey::String(target_index.to_string().into()),
+                    element,
+                )?;
+
+                // 3. Set targetIndex to targetIndex + 1.
+                target_index += 1;
+            }
+
+            // d. Set sourceIndex to sourceIndex + 1𝔽.
+        }
+
+        // 5. Return targetIndex.
+        Ok(target_index)
+    }
+
+    /// 23.1.3.14 Array.prototype.flatMap ( mapperFunction [ , thisArg ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.flatmap
+    fn flat_map(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let sourceLen be ? LengthOfArrayLike(O).
+        let source_len = length_of_array_like(agent, &o)?;
+
+        // 3. If IsCallable(mapperFunction) is false, throw a TypeError exception.
+        let mapper_function = args.get_function(0)?;
+
+        // 4. Let A be ? ArraySpeciesCreate(O, 0).
+        let a = array_species_create(agent, &o, 0)?;
+
+        // 5. Perform ? FlattenIntoArray(A, O, sourceLen, 0, 1, mapperFunction, thisArg).
+        Self::flatten_into_array(
+            agent,
+            &a,
+            &o,
+            source_len,
+            0,
+            1.0,
+            Some((mapper_function, args.get(1))),
+        )?;
+
+        // 6. Return A.
+        Ok(JSValue::from(a))
+    }
+
+    /// 23.1.3.16 Array.prototype.includes ( searchElement [ , fromIndex ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.includes
+    fn includes(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let search_element = args.get(0);
+
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. If len = 0, return false.
+        if len == 0 {
+            return Ok(JSValue::from(false));
+        }
+
+        // 4. Let n be ? ToIntegerOrInfinity(fromIndex).
+        // 5. Assert: If fromIndex is undefined, then n is 0.
+        let n = to_integer_or_infinity(agent, args.get(1).clone())?;
+
+        // 6. If n = +∞, return false.
+        if n.is_pos_infinite() {
+            return Ok(JSValue::from(false));
+        }
+
+        // 7. Else if n = -∞, set n to 0.
+        // 8. If n ≥ 0, then
+        // a. Let k be n.
+        // 9. Else,
+        // a. Let k be len + n.
+        // b. If k < 0, set k to 0.
+        // NOTE: Casting to u64 saturates, so a negative k and -∞ both become 0.
+        let k = if n.0 >= 0.0 {
+            n.0 as u64
+        } else {
+            (len as f64 + n.0) as u64
+        };
+
+        // 10. Repeat, while k < len,
+        for k in k..len {
+            // a. Let elementK be ? Get(O, ! ToString(𝔽(k))).
+            let element_k = get(agent, &o, &JSObjectPropKey::String(k.to_string().into()))?;
+
+            // b. If SameValueZero(searchElement, elementK) is true, return true.
+            if same_value_zero(search_element, &element_k) {
+                return Ok(JSValue::from(true));
+            }
+
+            // c. Set k to k + 1.
+        }
+
+        // 11. Return false.
+        Ok(JSValue::from(false))
+    }
+
+    /// 23.1.3.18 Array.prototype.join ( separator )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.join
+    fn join(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        let sep = match args.get(0) {
+            // 3. If separator is undefined, let sep be ",".
+            JSValue::Undefined => JSString::from(","),
+            // 4. Else, let sep be ? ToString(separator).
+            separator => to_string(agent, separator.clone())?,
+        };
+
+        // 5. Let R be the empty String.
+        let mut r: Vec<u16> = vec![];
+
+        // 6. Let k be 0.
+        // 7. Repeat, while k < len,
+        for k in 0..len {
+            // a. If k > 0, set R to the string-concatenation of R and sep.
+            if k > 0 {
+                push_code_units(agent, &mut r, &sep)?;
+            }
+
+            // b. Let element be ? Get(O, ! ToString(𝔽(k))).
+            let element = get(agent, &o, &JSObjectPropKey::String(k.to_string().into()))?;
+
+            // c. If element is either undefined or null, let next be the empty String; otherwise, let next be ? ToString(element).
+            // d. Set R to the string-concatenation of R and next.
+            if !element.is_undefined() && !element.is_null() {
+                let next = to_string(agent, element)?;
+
+                push_code_units(agent, &mut r, &next)?;
+            }
+
+            // e. Set k to k + 1.
+        }
+
+        // 8. Return R.
+        Ok(JSValue::String(JSString::from(r)))
+    }
+
+    /// 23.1.3.19 Array.prototype.keys ( )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.keys
+    fn keys(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Return CreateArrayIterator(O, key).
+        Ok(JSValue::from(create_array_iterator(
+            agent,
+            o,
+            ArrayIteratorKind::Key,
+        )))
+    }
+
+    /// 23.1.3.20 Array.prototype.lastIndexOf ( searchElement [ , fromIndex ] )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.lastindexof
+    fn last_index_of(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let search_element = args.get(0);
+
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. If len = 0, return -1𝔽.
+        if len == 0 {
+            return Ok(JSValue::from(-1));
+        }
+
+        // 4. If fromIndex is present, let n be ? ToIntegerOrInfinity(fromIndex); else let n be len - 1.
+        let n = if args.len() > 1 {
+            to_integer_or_infinity(agent, args.get(1).clone())?.0
+        } else {
+            len as f64 - 1.0
+        };
+
+        // 5. If n = -∞, return -1𝔽.
+        // 6. If n ≥ 0, then
+        // a. Let k be min(n, len - 1).
+        // 7. Else,
+        // a. Let k be len + n.
+        let k = if n >= 0.0 {
+            n.min(len as f64 - 1.0)
+        } else {
+            len as f64 + n
+        };
+
+        if k < 0.0 {
+            return Ok(JSValue::from(-1));
+        }
+
+        // 8. Repeat, while k ≥ 0,
+        for k in (0..=k as u64).rev() {
+            let pk = JSObjectPropKey::String(k.to_string().into());
+
+            // a. Let kPresent be ? HasProperty(O, ! ToString(𝔽(k))).
+            // b. If kPresent is true, then
+            if has_property(&o, &pk)? {
+                // i. Let elementK be ? Get(O, ! ToString(𝔽(k))).
+                let element_k = get(agent, &o, &pk)?;
+
+                // ii. If IsStrictlyEqual(searchElement, elementK) is true, return 𝔽(k).
+                if is_strictly_equal(search_element, &element_k) {
+                    return Ok(JSValue::from(k as f64));
+                }
+            }
+
+            // c. Set k to k - 1.
+        }
+
+        // 9. Return -1𝔽.
+        Ok(JSValue::from(-1))
+    }
+
+    /// 23.1.3.26 Array.prototype.reverse ( )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.reverse
+    fn reverse(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let middle be floor(len / 2).
+        let middle = len / 2;
+
+        // 4. Let lower be 0.
+        // 5. Repeat, while lower ≠ middle,
+        for lower in 0..middle {
+            // a. Let upper be len - lower - 1.
+            let upper = len - lower - 1;
+
+            // b. Let upperP be ! ToString(𝔽(upper)).
+            let upper_p = JSObjectPropKey::String(upper.to_string().into());
+
+            // c. Let lowerP be ! ToString(𝔽(lower)).
+            let lower_p = JSObjectPropKey::String(lower.to_string().into());
+
+            // d. Let lowerExists be ? HasProperty(O, lowerP).
+            // e. If lowerExists is true, then
+            // i. Let lowerValue be ? Get(O, lowerP).
+            let lower_value = if has_property(&o, &lower_p)? {
+                Some(get(agent, &o, &lower_p)?)
+            } else {
+                None
+            };
+
+            // f. Let upperExists be ? HasProperty(O, upperP).
+            // g. If upperExists is true, then
+            // i. Let upperValue be ? Get(O, upperP).
+            let upper_value = if has_property(&o, &upper_p)? {
+                Some(get(agent, &o, &upper_p)?)
+            } else {
+                None
+            };
+
+            match (lower_value, upper_value) {
+                // h. If lowerExists is true and upperExists is true, then
+                (Some(lower_value), Some(upper_value)) => {
+                    // i. Perform ? Set(O, lowerP, upperValue, true).
+                    set(agent, &o, &lower_p, upper_value, true)?;
+
+                    // ii. Perform ? Set(O, upperP, lowerValue, true).
+                    set(agent, &o, &upper_p, lower_value, true)?;
+                }
+                // i. Else if lowerExists is false and upperExists is true, then
+                (None, Some(upper_value)) => {
+                    // i. Perform ? Set(O, lowerP, upperValue, true).
+                    set(agent, &o, &lower_p, upper_value, true)?;
+
+                    // ii. Perform ? DeletePropertyOrThrow(O, upperP).
+                    delete_property_or_throw(&o, &upper_p)?;
+                }
+                // j. Else if lowerExists is true and upperExists is false, then
+                (Some(lower_value), None) => {
+                    // i. Perform ? DeletePropertyOrThrow(O, lowerP).
+                    delete_property_or_throw(&o, &lower_p)?;
+
+                    // ii. Perform ? Set(O, upperP, lowerValue, true).
+                    set(agent, &o, &upper_p, lower_value, true)?;
+                }
+                // k. Else,
+                // i. Assert: lowerExists and upperExists are both false.
+                // ii. NOTE: No action is required.
+                (None, None) => {}
+            }
+
+            // l. Set lower to lower + 1.
+        }
+
+        // 6. Return O.
+        Ok(JSValue::from(o))
+    }
+
+    /// 23.1.3.30 Array.prototype.sort ( comparator )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.sort
+    fn sort(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let comparator = args.get(0);
+
+        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
+        if !comparator.is_undefined() {
+            args.get_function(0)?;
+        }
+
+        // 2. Let obj be ? ToObject(this value).
+        let obj = to_object(agent, args.this())?;
+
+        // 3. Let len be ? LengthOfArrayLike(obj).
+        let len = length_of_array_like(agent, &obj)?;
+
+        // 4. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparator and performs the following steps when called:
+        // a. Return ? CompareArrayElements(x, y, comparator).
+        // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, skip-holes).
+        let sorted_list =
+            Self::sort_indexed_properties(agent, &obj, len, comparator, Holes::SkipHoles)?;
+
+        // 6. Let itemCount be the number of elements in sortedList.
+        let item_count = sorted_list.len() as u64;
+
+        // 7. Let j be 0.
+        // 8. Repeat, while j < itemCount,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ? Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+            // b. Set j to j + 1.
+            set(
+                agent,
+                &obj,
+                &JSObjectPropKey::String(j.to_string().into()),
+                item,
+                true,
+            )?;
+        }
+
+        // 9. NOTE: The call to SortIndexedProperties in step 5 uses skip-holes. The remaining indices are deleted to preserve the number of holes that were detected and excluded from the sort.
+        // 10. Repeat, while j < len,
+        for j in item_count..len {
+            // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
+            // b. Set j to j + 1.
+            delete_property_or_throw(&obj, &JSObjectPropKey::String(j.to_string().into()))?;
+        }
+
+        // 11. Return obj.
+        Ok(JSValue::from(obj))
+    }
+
+    /// 23.1.3.30.1 SortIndexedProperties ( obj, len, SortCompare, holes )
+    /// https://262.ecma-international.org/16.0/#sec-sortindexedproperties
+    ///
+    /// The SortCompare closure is always CompareArrayElements with the comparator, so the comparator is passed in its place.
+    fn sort_indexed_properties(
+        agent: &mut JSAgent,
+        obj: &ObjectAddr,
+        len: u64,
+        comparator: &JSValue,
+        holes: Holes,
+    ) -> CompletionRecord<Vec<JSValue>> {
+        // 1. Let items be a new empty List.
+        let mut items = vec![];
+
+        // 2. Let k be 0.
+        // 3. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = JSObjectPropKey::String(k.to_string().into());
+
+            // b. If holes is skip-holes, then
+            // i. Let kRead be ? HasProperty(obj, Pk).
+            // c. Else,
+            // i. Assert: holes is read-through-holes.
+            // ii. Let kRead be true.
+            let k_read = match holes {
+                Holes::SkipHoles => has_property(obj, &pk)?,
+                Holes::ReadThroughHoles => true,
+            };
+
+            // d. If kRead is true, then
+            if k_read {
+                // i. Let kValue be ? Get(obj, Pk).
+                // ii. Append kValue to items.
+                items.push(get(agent, obj, &pk)?);
+            }
+
+            // e. Set k to k + 1.
+        }
+
+        // 4. Sort items using an implementation-defined sequence of calls to SortCompare. If any such call returns an abrupt completion, stop before performing any further calls to SortCompare and return that Completion Record.
+        // NOTE: A merge sort is used as the sort must be stable, and the comparator can return an abrupt completion, which the sorts of the standard library cannot stop at.
+        // 5. Return items.
+        merge_sort(items, |x, y| {
+            Self::compare_array_elements(agent, x, y, comparator)
+        })
+    }
+
+    /// 23.1.3.30.2 CompareArrayElements ( x, y, comparator )
+    /// https://262.ecma-international.org/16.0/#sec-comparearrayelements
+    fn compare_array_elements(
+        agent: &mut JSAgent,
+        x: &JSValue,
+        y: &JSValue,
+        comparator: &JSValue,
+    ) -> CompletionRecord<Ordering> {
+        match (x, y) {
+            // 1. If x and y are both undefined, return +0𝔽.
+            (JSValue::Undefined, JSValue::Undefined) => return Ok(Ordering::Equal),
+            // 2. If x is undefined, return 1𝔽.
+            (JSValue::Undefined, _) => return Ok(Ordering::Greater),
+            // 3. If y is undefined, return -1𝔽.
+            (_, JSValue::Undefined) => return Ok(Ordering::Less),
+            _ => {}
+        }
+
+        // 4. If comparator is not undefined, then
+        if !comparator.is_undefined() {
+            // a. Let v be ? ToNumber(? Call(comparator, undefined, « x, y »)).
+            let result = call(
+                agent,
+                comparator.clone(),
+                &JSValue::Undefined,
+                Some(vec![x.clone(), y.clone()]),
+            )?;
+
+            let v = to_number(agent, result)?;
+
+            // b. If v is NaN, return +0𝔽.
+            // c. Return v.
+            return Ok(v.0.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+
+        // 5. Let xString be ? ToString(x).
+        let x_string = to_string(agent, x.clone())?;
+
+        // 6. Let yString be ? ToString(y).
+        let y_string = to_string(agent, y.clone())?;
+
+        // 7. Let xSmaller be ! IsLessThan(xString, yString, true).
+        // 8. If xSmaller is true, return -1𝔽.
+        // 9. Let ySmaller be ! IsLessThan(yString, xString, true).
+        // 10. If ySmaller is true, return 1𝔽.
+        // 11. Return +0𝔽.
+        // NOTE: IsLessThan compares Strings by their code units, as the ordering of JSString does.
+        Ok(x_string.cmp(&y_string))
+    }
+
+    /// 23.1.3.33 Array.prototype.toReversed ( )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.toreversed
+    fn to_reversed(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let A be ? ArrayCreate(len).
+        let a = array_create(agent, len, None)?;
+
+        // 4. Let k be 0.
+        // 5. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let from be ! ToString(𝔽(len - k - 1)).
+            let from = JSObjectPropKey::String((len - k - 1).to_string().into());
+
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = get(agent, &o, &from)?;
+
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            // e. Set k to k + 1.
+            create_data_property_or_throw(
+                &a,
+                &JSObjectPropKey::String(k.to_string().into()),
+                from_value,
+            )
+            .unwrap();
+        }
+
+        // 6. Return A.
+        Ok(JSValue::from(a))
+    }
+
+    /// 23.1.3.34 Array.prototype.toSorted ( comparator )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.tosorted
+    fn to_sorted(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let comparator = args.get(0);
+
+        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
+        if !comparator.is_undefined() {
+            args.get_function(0)?;
+        }
+
+        // 2. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 3. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 4. Let A be ? ArrayCreate(len).
+        let a = array_create(agent, len, None)?;
+
+        // 5. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparator and performs the following steps when called:
+        // a. Return ? CompareArrayElements(x, y, comparator).
+        // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted_list =
+            Self::sort_indexed_properties(agent, &o, len, comparator, Holes::ReadThroughHoles)?;
+
+        // 7. Let j be 0.
+        // 8. Repeat, while j < len,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(j)), sortedList[j]).
+            // b. Set j to j + 1.
+            create_data_property_or_throw(&a, &JSObjectPropKey::String(j.to_string().into()), item)
+                .unwrap();
+        }
+
+        // 9. Return A.
+        Ok(JSValue::from(a))
+    }
+
+    /// 23.1.3.35 Array.prototype.toSpliced ( start, skipCount, ...items )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.tospliced
+    fn to_spliced(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 4. If relativeStart = -∞, let actualStart be 0.
+        // 5. Else if relativeStart < 0, let actualStart be max(len + relativeStart, 0).
+        // 6. Else, let actualStart be min(relativeStart, len).
+        let relative_start = to_integer_or_infinity(agent, args.get(0).clone())?.0;
+        let actual_start = relative_to_index(relative_start, len);
+
+        // 7. Let insertCount be the number of elements in items.
+        let items = args.rest(2);
+        let insert_count = items.len() as u64;
+
+        let actual_skip_count = match args.len() {
+            // 8. If start is not present, then
+            // a. Let actualSkipCount be 0.
+            0 => 0,
+            // 9. Else if skipCount is not present, then
+            // a. Let actualSkipCount be len - actualStart.
+            1 => len - actual_start,
+            // 10. Else,
+            _ => {
+                // a. Let sc be ? ToIntegerOrInfinity(skipCount).
+                let sc = to_integer_or_infinity(agent, args.get(1).clone())?.0;
+
+                // b. Let actualSkipCount be the result of clamping sc between 0 and len - actualStart.
+                sc.clamp(0.0, (len - actual_start) as f64) as u64
+            }
+        };
+
+        // 11. Let newLen be len + insertCount - actualSkipCount.
+        let new_len = len + insert_count - actual_skip_count;
+
+        // 12. If newLen > 2**53 - 1, throw a TypeError exception.
+        if new_len > (1 << 53) - 1 {
+            return type_error("Array.prototype.toSpliced: the new length is too large");
+        }
+
+        // 13. Let A be ? ArrayCreate(newLen).
+        let a = array_create(agent, new_len, None)?;
+
+        // 14. Let i be 0.
+        let mut i = 0;
+
+        // 15. Let r be actualStart + actualSkipCount.
+        let mut r = actual_start + actual_skip_count;
+
+        // 16. Repeat, while i < actualStart,
+        while i < actual_start {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            let pi = JSObjectPropKey::String(i.to_string().into());
+
+            // b. Let iValue be ? Get(O, Pi).
+            let i_value = get(agent, &o, &pi)?;
+
+            // c. Perform ! CreateDataPropertyOrThrow(A, Pi, iValue).
+            create_data_property_or_throw(&a, &pi, i_value).unwrap();
+
+            // d. Set i to i + 1.
+            i += 1;
+        }
+
+        // 17. For each element E of items, do
+        for e in items {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Perform ! CreateDataPropertyOrThrow(A, Pi, E).
+            create_data_property_or_throw(
+                &a,
+                &JSObjectPropKey::String(i.to_string().into()),
+                e.clone(),
+            )
+            .unwrap();
+
+            // c. Set i to i + 1.
+            i += 1;
+        }
+
+        // 18. Repeat, while i < newLen,
+        while i < new_len {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            let pi = JSObjectPropKey::String(i.to_string().into());
+
+            // b. Let from be ! ToString(𝔽(r)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = get(agent, &o, &JSObjectPropKey::String(r.to_string().into()))?;
+
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pi, fromValue).
+            create_data_property_or_throw(&a, &pi, from_value).unwrap();
+
+            // e. Set i to i + 1.
+            // f. Set r to r + 1.
+            i += 1;
+            r += 1;
+        }
+
+        // 19. Return A.
+        Ok(JSValue::from(a))
+    }
+
+    /// 23.1.3.36 Array.prototype.toString ( )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.tostring
+    fn to_string(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let array be ? ToObject(this value).
+        let array = to_object(agent, args.this())?;
+
+        // 2. Let func be ? Get(array, "join").
+        let mut func = get(agent, &array, &JSObjectPropKey::String("join".into()))?;
+
+        // 3. If IsCallable(func) is false, set func to the intrinsic function %Object.prototype.toString%.
+        if !is_callable(&func) {
+            let object_prototype_to_string = agent
+                .current_realm()
+                .borrow()
+                .intrinsics
+                .object_prototype_to_string
+                .clone()
+                .unwrap();
+
+            func = JSValue::from(object_prototype_to_string);
+        }
+
+        // 4. Return ? Call(func, array).
+        call(agent, func, &JSValue::from(array), None)
+    }
+
+    /// 23.1.3.38 Array.prototype.values ( )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.values
+    fn values(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Return CreateArrayIterator(O, value).
+        Ok(JSValue::from(create_array_iterator(
+            agent,
+            o,
+            ArrayIteratorKind::Value,
+        )))
+    }
+    /// 23.1.3.39 Array.prototype.with ( index, value )
+    /// https://262.ecma-international.org/16.0/#sec-array.prototype.with
+    fn with(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let value = args.get(1);
+
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = length_of_array_like(agent, &o)?;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = to_integer_or_infinity(agent, args.get(0).clone())?.0;
+
+        // 4. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+        // 5. Else, let actualIndex be len + relativeIndex.
+        let actual_index = if relative_index >= 0.0 {
+            relative_index
+        } else {
+            len as f64 + relative_index
+        };
+
+        // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
+        if actual_index >= len as f64 || actual_index < 0.0 {
+            return range_error("Invalid array index");
+        }
+
+        // 7. Let A be ? ArrayCreate(len).
+        let a = array_create(agent, len, None)?;
+
+        // 8. Let k be 0.
+        // 9. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = JSObjectPropKey::String(k.to_string().into());
+
+            // b. If k = actualIndex, let fromValue be value.
+            // c. Else, let fromValue be ? Get(O, Pk).
+            let from_value = if k == actual_index as u64 {
+                value.clone()
+            } else {
+                get(agent, &o, &pk)?
+            };
+
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            // e. Set k to k + 1.
+            create_data_property_or_throw(&a, &pk, from_value).unwrap();
+        }
+
+        // 10. Return A.
+        Ok(JSValue::from(a))
+    }
+}
+
+/// Whether SortIndexedProperties skips the indices an object has no property for, or reads them as undefined.
+#[derive(Debug, Clone, Copy)]
+enum Holes {
+    SkipHoles,
+    ReadThroughHoles,
+}
+
+/// Resolves a relative index, which counts back from the length when it is negative, clamping it between 0 and the length.
+fn relative_to_index(relative: f64, len: u64) -> u64 {
+    // NOTE: Adding -∞ to the length gives -∞, which is clamped to 0 like any other index before the start.
+    if relative < 0.0 {
+        (len as f64 + relative).max(0.0) as u64
+    } else {
+        relative.min(len as f64) as u64
+    }
+}
+
+/// Appends the code units of the string to those of the string being built, throwing a RangeError if the result would be longer than the maximum length of a string, or if there is no room for it on the heap.
+fn push_code_units(
+    agent: &mut JSAgent,
+    units: &mut Vec<u16>,
+    string: &JSString,
+) -> CompletionRecord {
+    let len = units.len() + string.len();
+
+    if len > MAX_STRING_LENGTH {
+        return range_error("Invalid string length");
+    }
+
+    agent.check_allocation(len * size_of::<u16>())?;
+
+    units.extend_from_slice(string.code_units());
+
+    Ok(())
+}
+
+/// Sorts the items with a stable, bottom-up merge sort, stopping at the first abrupt completion returned by the comparison.
+fn merge_sort(
+    mut items: Vec<JSValue>,
+    mut compare: impl FnMut(&JSValue, &JSValue) -> CompletionRecord<Ordering>,
+) -> CompletionRecord<Vec<JSValue>> {
+    let len = items.len();
+
+    let mut merged = Vec::with_capacity(len);
+
+    let mut width = 1;
+
+    while width < len {
+        for start in (0..len).step_by(2 * width) {
+            let middle = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+
+            let (mut left, mut right) = (start, middle);
+
+            while left < middle && right < end {
+                // NOTE: An item of the right run is only taken first when it is strictly smaller, which keeps equal items in their original order.
+                if compare(&items[left], &items[right])? == Ordering::Greater {
+                    merged.push(items[right].clone());
+                    right += 1;
+                } else {
+                    merged.push(items[left].clone());
+                    left += 1;
+                }
+            }
+
+            merged.extend_from_slice(&items[left..middle]);
+            merged.extend_from_slice(&items[right..end]);
+        }
+
+        std::mem::swap(&mut items, &mut merged);
+
+        merged.clear();
+
+        width *= 2;
+    }
+
+    Ok(items)
+}
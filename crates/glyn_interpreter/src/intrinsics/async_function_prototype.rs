@@ -0,0 +1,53 @@
+use crate::{
+    abstract_ops::{object_operations::define_property_or_throw, ordinary::ordinary_object_create},
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        JSValue,
+    },
+};
+
+/// 27.7.3 Properties of the AsyncFunction Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-async-function-prototype-properties
+#[derive(Debug)]
+pub(crate) struct AsyncFunctionPrototype;
+
+impl AsyncFunctionPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %AsyncFunction.prototype%.
+        // is an ordinary object.
+        // is not a function object and does not have an [[ECMAScriptCode]] internal slot or any other of the internal slots listed in Table 30.
+        // has a [[Prototype]] internal slot whose value is %Function.prototype%.
+        let async_function_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.function_prototype.clone(),
+            None,
+        );
+
+        // 27.7.3.1 AsyncFunction.prototype.constructor
+        // TODO: Implement the %AsyncFunction% constructor.
+
+        // 27.7.3.2 AsyncFunction.prototype [ %Symbol.toStringTag% ]
+        // The initial value of the %Symbol.toStringTag% property is the String value "AsyncFunction".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &async_function_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from("AsyncFunction".to_string())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        async_function_prototype
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    abstract_ops::ordinary::ordinary_object_create,
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{type_error_for, JSAgent},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{internal_slots::InternalSlotName, ObjectAddr, ObjectMeta},
+        JSValue,
+    },
+};
+
+/// 20.3.3 Properties of the Boolean Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-boolean-prototype-object
+#[derive(Debug)]
+pub(crate) struct BooleanPrototype;
+
+impl BooleanPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Boolean.prototype%.
+        // is itself a Boolean object; it has a [[BooleanData]] internal slot with the value false.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        let boolean_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            Some(vec![InternalSlotName::BooleanData]),
+        );
+
+        boolean_prototype
+            .data_mut()
+            .slots_mut()
+            .set_boolean_data(false);
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &boolean_prototype,
+            "Boolean.prototype",
+            "toString",
+            0,
+            Self::to_string,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &boolean_prototype,
+            "Boolean.prototype",
+            "valueOf",
+            0,
+            Self::value_of,
+        );
+
+        boolean_prototype
+    }
+
+    /// 20.3.3.2 Boolean.prototype.toString ( )
+    /// https://262.ecma-international.org/16.0/#sec-boolean.prototype.tostring
+    fn to_string(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let b be ? ThisBooleanValue(this value).
+        let b = this_boolean_value(&args)?;
+
+        // 2. If b is true, return "true"; else return "false".
+        Ok(JSValue::from(if b { "true" } else { "false" }))
+    }
+
+    /// 20.3.3.3 Boolean.prototype.valueOf ( )
+    /// https://262.ecma-international.org/16.0/#sec-boolean.prototype.valueof
+    fn value_of(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? ThisBooleanValue(this value).
+        Ok(JSValue::Bool(this_boolean_value(&args)?))
+    }
+}
+
+/// 20.3.3.3.1 ThisBooleanValue ( value )
+/// https://262.ecma-international.org/16.0/#sec-thisbooleanvalue
+fn this_boolean_value(args: &Args) -> CompletionRecord<bool> {
+    let boolean = match args.this() {
+        // 1. If value is a Boolean, return value.
+        JSValue::Bool(value) => Some(*value),
+        // 2. If value is an Object and value has a [[BooleanData]] internal slot, then
+        // a. Let b be value.[[BooleanData]].
+        // b. Assert: b is a Boolean.
+        // c. Return b.
+        JSValue::Object(object) => object.data().slots().boolean_data(),
+        _ => None,
+    };
+
+    match boolean {
+        Some(boolean) => Ok(boolean),
+        // 3. Throw a TypeError exception.
+        None => type_error_for(
+            &format!(
+                "{} called on an incompatible receiver",
+                args.function_name()
+            ),
+            args.this(),
+        ),
+    }
+}
@@ -0,0 +1,360 @@
+use crate::{
+    abstract_ops::{
+        object_operations::call,
+        ordinary::ordinary_object_create,
+        type_conversion::{to_boolean, to_string},
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::JSAgent, completion::CompletionRecord, console::LogLevel, native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{object::ObjectAddr, JSValue},
+};
+
+/// The label of a timer started without one.
+const DEFAULT_LABEL: &str = "default";
+
+/// The console namespace object, which is a host-defined property of the global object.
+/// https://console.spec.whatwg.org/#console-namespace
+///
+/// Every message is written to the sink set with [`JSAgent::set_console`], which is shared by the console objects of every realm of the agent, as is the table of timers.
+#[derive(Debug)]
+pub(crate) struct ConsoleObject;
+
+impl ConsoleObject {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // The [[Prototype]] of a namespace object is %Object.prototype%.
+        let console = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            None,
+        );
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &console,
+            "console",
+            "assert",
+            0,
+            Self::assert,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &console,
+            "console",
+            "debug",
+            0,
+            Self::debug,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &console,
+            "console",
+            "error",
+            0,
+            Self::error,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &console,
+            "console",
+            "info",
+            0,
+            Self::info,
+        );
+        define_builtin_method(agent, &realm_addr, &console, "console", "log", 0, Self::log);
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &console,
+            "console",
+            "warn",
+            0,
+            Self::warn,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &console,
+            "console",
+            "time",
+            0,
+            Self::time,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &console,
+            "console",
+            "timeEnd",
+            0,
+            Self::time_end,
+        );
+
+        console
+    }
+
+    /// 1.1.1 assert(condition, ...data)
+    /// https://console.spec.whatwg.org/#assert
+    fn assert(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. If condition is true, return.
+        if to_boolean(args.get(0).clone()) {
+            return Ok(JSValue::Undefined);
+        }
+
+        // 2. Let message be a string without any formatting specifiers indicating generically an assertion failure (such as "Assertion failed").
+        let message = "Assertion failed";
+
+        let mut data = args.rest(1).to_vec();
+
+        match data.first() {
+            // 3. If data is empty, append message to data.
+            None => data.push(JSValue::from(message)),
+            // 4. Otherwise:
+            // a. Let first be data[0].
+            // c. Otherwise:
+            // i. Let concat be the concatenation of message, U+003A (:), U+0020 SPACE, and first.
+            // ii. Set data[0] to concat.
+            Some(JSValue::String(first)) => data[0] = JSValue::from(format!("{message}: {first}")),
+            // b. If first is not a String, then prepend message to data.
+            Some(_) => data.insert(0, JSValue::from(message)),
+        }
+
+        // 5. Perform Logger("assert", data).
+        logger(agent, LogLevel::Error, &data)?;
+
+        Ok(JSValue::Undefined)
+    }
+
+    /// 1.1.3 debug(...data)
+    /// https://console.spec.whatwg.org/#debug
+    fn debug(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Perform Logger("debug", data).
+        logger(agent, LogLevel::Debug, args.rest(0))?;
+
+        Ok(JSValue::Undefined)
+    }
+
+    /// 1.1.4 error(...data)
+    /// https://console.spec.whatwg.org/#error
+    fn error(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Perform Logger("error", data).
+        logger(agent, LogLevel::Error, args.rest(0))?;
+
+        Ok(JSValue::Undefined)
+    }
+
+    /// 1.1.5 info(...data)
+    /// https://console.spec.whatwg.org/#info
+    fn info(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Perform Logger("info", data).
+        logger(agent, LogLevel::Info, args.rest(0))?;
+
+        Ok(JSValue::Undefined)
+    }
+
+    /// 1.1.6 log(...data)
+    /// https://console.spec.whatwg.org/#log
+    fn log(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Perform Logger("log", data).
+        logger(agent, LogLevel::Log, args.rest(0))?;
+
+        Ok(JSValue::Undefined)
+    }
+
+    /// 1.1.9 warn(...data)
+    /// https://console.spec.whatwg.org/#warn
+    fn warn(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Perform Logger("warn", data).
+        logger(agent, LogLevel::Warn, args.rest(0))?;
+
+        Ok(JSValue::Undefined)
+    }
+
+    /// 1.4.1 time(label)
+    /// https://console.spec.whatwg.org/#time
+    fn time(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let label = timer_label(agent, &args)?;
+
+        // 1. If the associated timer table contains an entry with key label, return, optionally reporting a warning to the console indicating that a timer with label label has already been started.
+        if agent.console_timers.contains_key(&label) {
+            agent.write_console(
+                LogLevel::Warn,
+                &format!("Warning: Label '{label}' already exists for console.time()"),
+            );
+
+            return Ok(JSValue::Undefined);
+        }
+
+        // 2. Otherwise, set the value of the entry with key label in the associated timer table to the current time.
+        let now = agent.clock().now();
+
+        agent.console_timers.insert(label, now);
+
+        Ok(JSValue::Undefined)
+    }
+
+    /// 1.4.3 timeEnd(label)
+    /// https://console.spec.whatwg.org/#timeend
+    fn time_end(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let label = timer_label(agent, &args)?;
+
+        // 1. Let timerTable be the associated timer table.
+        // 2. Let startTime be timerTable[label].
+        // 3. Remove timerTable[label].
+        // NOTE: A timer which was never started is reported as a warning, like other consoles do.
+        let Some(start_time) = agent.console_timers.remove(&label) else {
+            agent.write_console(
+                LogLevel::Warn,
+                &format!("Warning: No such label '{label}' for console.timeEnd()"),
+            );
+
+            return Ok(JSValue::Undefined);
+        };
+
+        // 4. Let duration be a string representing the difference between the current time and startTime, in an implementation-defined format.
+        let duration = format!("{}ms", agent.clock().now() - start_time);
+
+        // 5. Let concat be the concatenation of label, U+003A (:), U+0020 SPACE, and duration.
+        // 6. Perform Printer("timeEnd", « concat »).
+        agent.write_console(LogLevel::Info, &format!("{label}: {duration}"));
+
+        Ok(JSValue::Undefined)
+    }
+}
+
+/// 2.1 Logger ( logLevel, args )
+/// https://console.spec.whatwg.org/#logger
+fn logger(agent: &mut JSAgent, level: LogLevel, args: &[JSValue]) -> CompletionRecord {
+    // 1. If args is empty, return.
+    // 2. Let first be args[0].
+    // 3. Let rest be all elements following first in args.
+    let Some((first, rest)) = args.split_first() else {
+        return Ok(());
+    };
+
+    match first {
+        // 6. Otherwise, perform Printer(logLevel, Formatter(args)).
+        // NOTE: Only a string can hold format specifiers, and Formatter leaves a string without any of them as it is.
+        JSValue::String(target) if !rest.is_empty() => {
+            let args = formatter(agent, &target.to_string(), rest)?;
+
+            printer(agent, level, &args);
+        }
+        // 4. If rest is empty, perform Printer(logLevel, « first ») and return.
+        // 5. If first does not contain any format specifiers, perform Printer(logLevel, args).
+        _ => printer(agent, level, args),
+    }
+
+    Ok(())
+}
+
+/// 2.2 Formatter ( args )
+/// https://console.spec.whatwg.org/#formatter
+///
+/// The format specifiers of the target are replaced from left to right by the arguments after it, until none are left. The arguments which no specifier takes follow the target.
+fn formatter(
+    agent: &mut JSAgent,
+    target: &str,
+    args: &[JSValue],
+) -> CompletionRecord<Vec<JSValue>> {
+    let mut args = args.iter();
+    let mut result = String::with_capacity(target.len());
+    let mut chars = target.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let specifier = chars
+            .peek()
+            .copied()
+            .filter(|specifier| c == '%' && "sdifoOc".contains(*specifier));
+
+        let (Some(specifier), Some(current)) = (specifier, args.as_slice().first().cloned()) else {
+            result.push(c);
+
+            continue;
+        };
+
+        chars.next();
+        args.next();
+
+        let converted = match specifier {
+            // %s: Let converted be the result of Call(%String%, undefined, « current »).
+            's' => current.to_display_string(agent)?,
+            // %d or %i: If current is a Symbol, let converted be NaN. Otherwise, let converted be the result of Call(%parseInt%, undefined, « current, 10 »).
+            'd' | 'i' if current.is_symbol() => String::from("NaN"),
+            'd' | 'i' => {
+                let parse_int = agent.current_realm().borrow().intrinsics.parse_int.clone();
+
+                call(
+                    agent,
+                    JSValue::from(parse_int.unwrap()),
+                    &JSValue::Undefined,
+                    Some(vec![current, JSValue::from(10.0)]),
+                )?
+                .to_display_string(agent)?
+            }
+            // %f: If current is a Symbol, let converted be NaN. Otherwise, let converted be the result of Call(%parseFloat%, undefined, « current »).
+            'f' if current.is_symbol() => String::from("NaN"),
+            'f' => {
+                let parse_float = agent
+                    .current_realm()
+                    .borrow()
+                    .intrinsics
+                    .parse_float
+                    .clone();
+
+                call(
+                    agent,
+                    JSValue::from(parse_float.unwrap()),
+                    &JSValue::Undefined,
+                    Some(vec![current]),
+                )?
+                .to_display_string(agent)?
+            }
+            // %o: Optionally, let converted be current with optimally useful formatting applied.
+            // %O: Optionally, let converted be current with generic JavaScript object formatting applied.
+            'o' | 'O' => current.inspect(),
+            // %c: Optionally, let converted be current with the CSS it holds applied.
+            // NOTE: Messages are written as plain text, so the CSS is dropped.
+            _ => String::new(),
+        };
+
+        // If any of the previous steps set converted, replace specifier in target with converted.
+        result.push_str(&converted);
+    }
+
+    // Let result be a list containing target together with the elements of args starting from the third onward.
+    Ok(std::iter::once(JSValue::from(result))
+        .chain(args.cloned())
+        .collect())
+}
+
+/// 2.3 Printer ( logLevel, args [, options] )
+/// https://console.spec.whatwg.org/#printer
+///
+/// The values are printed separated by spaces: strings by their contents, and every other value as [`JSValue::inspect`] renders it.
+fn printer(agent: &mut JSAgent, level: LogLevel, args: &[JSValue]) {
+    let message = args
+        .iter()
+        .map(|value| match value {
+            JSValue::String(string) => string.to_string(),
+            value => value.inspect(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    agent.write_console(level, &message);
+}
+
+/// The label of a timer, which is "default" if none is given, and is otherwise converted to a string.
+fn timer_label(agent: &mut JSAgent, args: &Args) -> CompletionRecord<String> {
+    match args.get(0) {
+        JSValue::Undefined => Ok(String::from(DEFAULT_LABEL)),
+        label => Ok(to_string(agent, label.clone())?.to_string()),
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    abstract_ops::{
+        date_objects::{
+            make_date, make_day, make_full_year, make_time, parse_date_string, time_clip,
+            to_date_string, utc,
+        },
+        function_operations::create_builtin_function,
+        object_operations::define_property_or_throw,
+        ordinary::ordinary_create_from_constructor,
+        type_conversion::{to_number, to_primitive, to_string, PreferredPrimType},
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::JSAgent, completion::CompletionRecord, native_function::Args, realm::RealmAddr,
+    },
+    value::{
+        object::{
+            internal_slots::{ConstructorKind, InternalSlotName},
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// 21.4.2 The Date Constructor
+/// https://262.ecma-international.org/16.0/#sec-date-constructor
+#[derive(Debug)]
+pub(crate) struct DateConstructor;
+
+impl DateConstructor {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Date%.
+        // is the initial value of the "Date" property of the global object.
+        // has a [[Prototype]] internal slot whose value is %Function.prototype%.
+        // has a "length" property whose value is 7𝔽.
+        let date = create_builtin_function(
+            agent,
+            Self::date,
+            7,
+            JSObjectPropKey::String("Date".into()),
+            vec![InternalSlotName::ConstructorKind],
+            Some(realm_addr.clone()),
+            None,
+            None,
+        );
+
+        // may be used as the value of an extends clause of a class definition.
+        date.data_mut()
+            .slots_mut()
+            .set_constructor_kind(ConstructorKind::Base);
+
+        define_builtin_method(agent, &realm_addr, &date, "Date", "now", 0, Self::now);
+        define_builtin_method(agent, &realm_addr, &date, "Date", "parse", 1, Self::parse);
+        define_builtin_method(agent, &realm_addr, &date, "Date", "UTC", 7, Self::utc);
+
+        // 21.4.3.3 Date.prototype
+        // The initial value of Date.prototype is the Date prototype object.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        let date_prototype = realm_addr
+            .borrow()
+            .intrinsics
+            .date_prototype
+            .clone()
+            .unwrap();
+
+        let _ = define_property_or_throw(
+            &date,
+            &JSObjectPropKey::String("prototype".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(date_prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 21.4.4.1 Date.prototype.constructor
+        // The initial value of Date.prototype.constructor is %Date%.
+        let _ = define_property_or_throw(
+            &date_prototype,
+            &JSObjectPropKey::String("constructor".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(date.clone())),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        date
+    }
+
+    /// The time value of the date and time given by the arguments from year onwards, which are passed to the Date constructor and to Date.UTC.
+    ///
+    /// The month defaults to +0𝔽, the date to 1𝔽 and the remaining fields to +0𝔽 when they are not present.
+    fn date_from_fields(agent: &mut JSAgent, args: &Args) -> CompletionRecord<f64> {
+        let mut fields = [f64::NAN, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+
+        for (index, field) in fields.iter_mut().enumerate() {
+            if index == 0 || index < args.len() {
+                *field = to_number(agent, args.get(index).clone())?.0;
+            }
+        }
+
+        let [y, m, dt, h, min, s, milli] = fields;
+
+        // Let yr be MakeFullYear(y).
+        let yr = make_full_year(y);
+
+        // Return MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli)).
+        Ok(make_date(make_day(yr, m, dt), make_time(h, min, s, milli)))
+    }
+
+    /// 21.4.2.1 Date ( ...values )
+    /// https://262.ecma-international.org/16.0/#sec-date
+    fn date(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. If NewTarget is undefined, then
+        let Some(new_target) = args.new_target() else {
+            // a. Let now be the time value (UTC) identifying the current time.
+            let now = agent.clock().now();
+
+            // b. Return ToDateString(now).
+            return Ok(JSValue::from(to_date_string(agent, now)));
+        };
+
+        // 2. Let numberOfArgs be the number of elements in values.
+        let dv = match args.len() {
+            // 3. If numberOfArgs = 0, then
+            // a. Let dv be the time value (UTC) identifying the current time.
+            0 => agent.clock().now(),
+            // 4. Else if numberOfArgs = 1, then
+            1 => {
+                // a. Let value be values[0].
+                let value = args.get(0);
+
+                // b. If value is an Object and value has a [[DateValue]] internal slot, then
+                // i. Let tv be value.[[DateValue]].
+                let date_value = match value {
+                    JSValue::Object(object) => object.data().slots().date_value(),
+                    _ => None,
+                };
+
+                let tv = match date_value {
+                    Some(tv) => tv,
+                    // c. Else,
+                    None => {
+                        // i. Let v be ? ToPrimitive(value).
+                        let v = to_primitive(agent, value.clone(), PreferredPrimType::Default)?;
+
+                        // ii. If v is a String, then
+                        if v.is_string() {
+                            // 1. Assert: The next step never returns an abrupt completion because v is a String.
+                            // 2. Let tv be the result of parsing v as a date, in exactly the same manner as for the parse method (21.4.3.2).
+                            let v = to_string(agent, v)?;
+
+                            parse_date_string(agent, &v.to_string())
+                        }
+                        // iii. Else,
+                        else {
+                            // 1. Let tv be ? ToNumber(v).
+                            to_number(agent, v)?.0
+                        }
+                    }
+                };
+
+                // d. Let dv be TimeClip(tv).
+                time_clip(tv)
+            }
+            // 5. Else,
+            _ => {
+                // a. Assert: numberOfArgs ≥ 2.
+                // b. Let y be ? ToNumber(values[0]).
+                // c. Let m be ? ToNumber(values[1]).
+                // d. If numberOfArgs > 2, let dt be ? ToNumber(values[2]); else let dt be 1𝔽.
+                // e. If numberOfArgs > 3, let h be ? ToNumber(values[3]); else let h be +0𝔽.
+                // f. If numberOfArgs > 4, let min be ? ToNumber(values[4]); else let min be +0𝔽.
+                // g. If numberOfArgs > 5, let s be ? ToNumber(values[5]); else let s be +0𝔽.
+                // h. If numberOfArgs > 6, let milli be ? ToNumber(values[6]); else let milli be +0𝔽.
+                // i. Let yr be MakeFullYear(y).
+                // j. Let finalDate be MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli)).
+                let final_date = Self::date_from_fields(agent, &args)?;
+
+                // k. Let dv be TimeClip(UTC(finalDate)).
+                time_clip(utc(agent, final_date))
+            }
+        };
+
+        // 6. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%Date.prototype%", « [[DateValue]] »).
+        let o = ordinary_create_from_constructor(
+            agent,
+            new_target,
+            |intrinsics| intrinsics.date_prototype.clone(),
+            Some(vec![InternalSlotName::DateValue]),
+        )?;
+
+        // 7. Set O.[[DateValue]] to dv.
+        o.data_mut().slots_mut().set_date_value(dv);
+
+        // 8. Return O.
+        Ok(JSValue::from(o))
+    }
+
+    /// 21.4.3.1 Date.now ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.now
+    fn now(agent: &mut JSAgent, _: Args) -> CompletionRecord<JSValue> {
+        // This function returns the time value designating the UTC date and time of the occurrence of the call to it.
+        Ok(JSValue::from(agent.clock().now().floor()))
+    }
+
+    /// 21.4.3.2 Date.parse ( string )
+    /// https://262.ecma-international.org/16.0/#sec-date.parse
+    fn parse(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // This function applies the ToString operator to its argument. If ToString results in an abrupt completion the Completion Record is immediately returned. Otherwise, this function interprets the resulting String as a date and time; it returns a Number, the UTC time value corresponding to the date and time.
+        let string = to_string(agent, args.get(0).clone())?;
+
+        Ok(JSValue::from(parse_date_string(agent, &string.to_string())))
+    }
+
+    /// 21.4.3.4 Date.UTC ( year [ , month [ , date [ , hours [ , minutes [ , seconds [ , ms ] ] ] ] ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.utc
+    fn utc(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let y be ? ToNumber(year).
+        // 2. If month is present, let m be ? ToNumber(month); else let m be +0𝔽.
+        // 3. If date is present, let dt be ? ToNumber(date); else let dt be 1𝔽.
+        // 4. If hours is present, let h be ? ToNumber(hours); else let h be +0𝔽.
+        // 5. If minutes is present, let min be ? ToNumber(minutes); else let min be +0𝔽.
+        // 6. If seconds is present, let s be ? ToNumber(seconds); else let s be +0𝔽.
+        // 7. If ms is present, let milli be ? ToNumber(ms); else let milli be +0𝔽.
+        // 8. Let yr be MakeFullYear(y).
+        // 9. Return TimeClip(MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli))).
+        let date = Self::date_from_fields(agent, &args)?;
+
+        Ok(JSValue::from(time_clip(date)))
+    }
+}
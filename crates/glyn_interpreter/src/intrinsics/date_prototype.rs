@@ -0,0 +1,766 @@
+use crate::{
+    abstract_ops::{
+        date_objects::{
+            date_from_time, date_string, day, hour_from_time, local_time, make_date, make_day,
+            make_time, min_from_time, month_from_time, ms_from_time, padded_year, sec_from_time,
+            time_clip, time_string, time_within_day, time_zone_string, to_date_string,
+            to_zero_padded_decimal_string, utc, week_day, year_from_time, DAY_NAMES, MONTH_NAMES,
+            MS_PER_MINUTE,
+        },
+        function_operations::create_builtin_function,
+        object_operations::{define_property_or_throw, invoke},
+        ordinary::ordinary_object_create,
+        type_conversion::{
+            ordinary_to_primitive, to_number, to_object, to_primitive, PreferredPrimType,
+        },
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{
+            range_error, type_error, type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_TO_PRIMITIVE,
+        },
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// The steps of a method of the Date prototype object.
+type DateMethod = fn(&mut JSAgent, Args) -> CompletionRecord<JSValue>;
+
+/// 21.4.4 Properties of the Date Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-date-prototype-object
+#[derive(Debug)]
+pub(crate) struct DatePrototype;
+
+impl DatePrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Date.prototype%.
+        // is itself an ordinary object.
+        // is not a Date instance and does not have a [[DateValue]] internal slot.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        let date_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            None,
+        );
+
+        let methods: [(&str, usize, DateMethod); 44] = [
+            ("getDate", 0, Self::get_date),
+            ("getDay", 0, Self::get_day),
+            ("getFullYear", 0, Self::get_full_year),
+            ("getHours", 0, Self::get_hours),
+            ("getMilliseconds", 0, Self::get_milliseconds),
+            ("getMinutes", 0, Self::get_minutes),
+            ("getMonth", 0, Self::get_month),
+            ("getSeconds", 0, Self::get_seconds),
+            ("getTime", 0, Self::get_time),
+            ("getTimezoneOffset", 0, Self::get_timezone_offset),
+            ("getUTCDate", 0, Self::get_utc_date),
+            ("getUTCDay", 0, Self::get_utc_day),
+            ("getUTCFullYear", 0, Self::get_utc_full_year),
+            ("getUTCHours", 0, Self::get_utc_hours),
+            ("getUTCMilliseconds", 0, Self::get_utc_milliseconds),
+            ("getUTCMinutes", 0, Self::get_utc_minutes),
+            ("getUTCMonth", 0, Self::get_utc_month),
+            ("getUTCSeconds", 0, Self::get_utc_seconds),
+            ("getYear", 0, Self::get_year),
+            ("setDate", 1, Self::set_date),
+            ("setFullYear", 3, Self::set_full_year),
+            ("setHours", 4, Self::set_hours),
+            ("setMilliseconds", 1, Self::set_milliseconds),
+            ("setMinutes", 3, Self::set_minutes),
+            ("setMonth", 2, Self::set_month),
+            ("setSeconds", 2, Self::set_seconds),
+            ("setTime", 1, Self::set_time),
+            ("setUTCDate", 1, Self::set_utc_date),
+            ("setUTCFullYear", 3, Self::set_utc_full_year),
+            ("setUTCHours", 4, Self::set_utc_hours),
+            ("setUTCMilliseconds", 1, Self::set_utc_milliseconds),
+            ("setUTCMinutes", 3, Self::set_utc_minutes),
+            ("setUTCMonth", 2, Self::set_utc_month),
+            ("setUTCSeconds", 2, Self::set_utc_seconds),
+            ("toDateString", 0, Self::to_date_string),
+            ("toISOString", 0, Self::to_iso_string),
+            ("toJSON", 1, Self::to_json),
+            ("toLocaleDateString", 0, Self::to_date_string),
+            ("toLocaleString", 0, Self::to_string),
+            ("toLocaleTimeString", 0, Self::to_time_string),
+            ("toString", 0, Self::to_string),
+            ("toTimeString", 0, Self::to_time_string),
+            ("toUTCString", 0, Self::to_utc_string),
+            ("valueOf", 0, Self::value_of),
+        ];
+
+        for (name, length, method) in methods {
+            define_builtin_method(
+                agent,
+                &realm_addr,
+                &date_prototype,
+                "Date.prototype",
+                name,
+                length,
+                method,
+            );
+        }
+
+        // 21.4.4.45 Date.prototype [ %Symbol.toPrimitive% ] ( hint )
+        // The value of the "name" property of this method is "[Symbol.toPrimitive]".
+        let to_primitive = create_builtin_function(
+            agent,
+            Self::to_primitive,
+            1,
+            JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_PRIMITIVE),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        );
+
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &date_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_PRIMITIVE),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(to_primitive)),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        date_prototype
+    }
+
+    /// The Date object the method was called on and its [[DateValue]].
+    fn this_time_value(args: &Args) -> CompletionRecord<(ObjectAddr, f64)> {
+        // Let dateObject be the this value.
+        // Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // Let t be dateObject.[[DateValue]].
+        let date_value = match args.this() {
+            JSValue::Object(date_object) => date_object
+                .data()
+                .slots()
+                .date_value()
+                .map(|t| (date_object.clone(), t)),
+            _ => None,
+        };
+
+        match date_value {
+            Some(date_value) => Ok(date_value),
+            None => type_error_for(
+                &format!(
+                    "{} called on an incompatible receiver",
+                    args.function_name()
+                ),
+                args.this(),
+            ),
+        }
+    }
+
+    /// The steps shared by the getters of the fields of a date in local time, like Date.prototype.getDate.
+    fn get_local_field(
+        agent: &mut JSAgent,
+        args: Args,
+        field: fn(f64) -> f64,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        let (_, t) = Self::this_time_value(&args)?;
+
+        // 4. If t is NaN, return NaN.
+        if t.is_nan() {
+            return Ok(JSValue::from(f64::NAN));
+        }
+
+        // 5. Return DateFromTime(LocalTime(t)).
+        Ok(JSValue::from(field(local_time(agent, t))))
+    }
+
+    /// The steps shared by the getters of the fields of a date in UTC, like Date.prototype.getUTCDate.
+    fn get_utc_field(args: Args, field: fn(f64) -> f64) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        let (_, t) = Self::this_time_value(&args)?;
+
+        // 4. If t is NaN, return NaN.
+        if t.is_nan() {
+            return Ok(JSValue::from(f64::NAN));
+        }
+
+        // 5. Return DateFromTime(t).
+        Ok(JSValue::from(field(t)))
+    }
+
+    /// 21.4.4.2 Date.prototype.getDate ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getdate
+    fn get_date(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, date_from_time)
+    }
+
+    /// 21.4.4.3 Date.prototype.getDay ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getday
+    fn get_day(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, week_day)
+    }
+
+    /// 21.4.4.4 Date.prototype.getFullYear ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getfullyear
+    fn get_full_year(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, year_from_time)
+    }
+
+    /// 21.4.4.5 Date.prototype.getHours ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.gethours
+    fn get_hours(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, hour_from_time)
+    }
+
+    /// 21.4.4.6 Date.prototype.getMilliseconds ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getmilliseconds
+    fn get_milliseconds(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, ms_from_time)
+    }
+
+    /// 21.4.4.7 Date.prototype.getMinutes ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getminutes
+    fn get_minutes(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, min_from_time)
+    }
+
+    /// 21.4.4.8 Date.prototype.getMonth ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getmonth
+    fn get_month(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, month_from_time)
+    }
+
+    /// 21.4.4.9 Date.prototype.getSeconds ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getseconds
+    fn get_seconds(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_local_field(agent, args, sec_from_time)
+    }
+
+    /// 21.4.4.10 Date.prototype.getTime ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.gettime
+    fn get_time(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Return dateObject.[[DateValue]].
+        let (_, t) = Self::this_time_value(&args)?;
+
+        Ok(JSValue::from(t))
+    }
+
+    /// 21.4.4.11 Date.prototype.getTimezoneOffset ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.gettimezoneoffset
+    fn get_timezone_offset(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        let (_, t) = Self::this_time_value(&args)?;
+
+        // 4. If t is NaN, return NaN.
+        if t.is_nan() {
+            return Ok(JSValue::from(f64::NAN));
+        }
+
+        // 5. Return (t - LocalTime(t)) / msPerMinute.
+        Ok(JSValue::from((t - local_time(agent, t)) / MS_PER_MINUTE))
+    }
+
+    /// 21.4.4.12 Date.prototype.getUTCDate ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutcdate
+    fn get_utc_date(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, date_from_time)
+    }
+
+    /// 21.4.4.13 Date.prototype.getUTCDay ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutcday
+    fn get_utc_day(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, week_day)
+    }
+
+    /// 21.4.4.14 Date.prototype.getUTCFullYear ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutcfullyear
+    fn get_utc_full_year(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, year_from_time)
+    }
+
+    /// 21.4.4.15 Date.prototype.getUTCHours ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutchours
+    fn get_utc_hours(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, hour_from_time)
+    }
+
+    /// 21.4.4.16 Date.prototype.getUTCMilliseconds ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutcmilliseconds
+    fn get_utc_milliseconds(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, ms_from_time)
+    }
+
+    /// 21.4.4.17 Date.prototype.getUTCMinutes ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutcminutes
+    fn get_utc_minutes(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, min_from_time)
+    }
+
+    /// 21.4.4.18 Date.prototype.getUTCMonth ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutcmonth
+    fn get_utc_month(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, month_from_time)
+    }
+
+    /// 21.4.4.19 Date.prototype.getUTCSeconds ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getutcseconds
+    fn get_utc_seconds(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::get_utc_field(args, sec_from_time)
+    }
+
+    /// B.2.3.1 Date.prototype.getYear ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.getyear
+    fn get_year(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        // 4. If t is NaN, return NaN.
+        // 5. Return YearFromTime(LocalTime(t)) - 1900𝔽.
+        Self::get_local_field(agent, args, |t| year_from_time(t) - 1900.0)
+    }
+
+    /// The arguments of a setter converted to Numbers, where the fields the setter sets are taken from its arguments, in order, starting with the field at the index of the first. The first argument is always converted, even if it is not present, and the fields which the remaining arguments are not present for are None.
+    fn setter_arguments<const N: usize>(
+        agent: &mut JSAgent,
+        args: &Args,
+        first: usize,
+    ) -> CompletionRecord<[Option<f64>; N]> {
+        let mut fields = [None; N];
+
+        for (index, field) in fields.iter_mut().enumerate().skip(first) {
+            let position = index - first;
+
+            if position == 0 || position < args.len() {
+                *field = Some(to_number(agent, args.get(position).clone())?.0);
+            }
+        }
+
+        Ok(fields)
+    }
+
+    /// The steps shared by the setters of the fields of a time, which are the hours, minutes, seconds and milliseconds, in order, like Date.prototype.setHours.
+    ///
+    /// The setter sets the fields from the one at the index of the first onwards.
+    fn set_time_fields(
+        agent: &mut JSAgent,
+        args: Args,
+        first: usize,
+        is_local: bool,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        let (date_object, t) = Self::this_time_value(&args)?;
+
+        // 4. Let h be ? ToNumber(hour).
+        // 5. If min is present, let m be ? ToNumber(min).
+        // 6. If sec is present, let s be ? ToNumber(sec).
+        // 7. If ms is present, let milli be ? ToNumber(ms).
+        let [h, m, s, milli] = Self::setter_arguments(agent, &args, first)?;
+
+        // 8. If t is NaN, return NaN.
+        if t.is_nan() {
+            return Ok(JSValue::from(f64::NAN));
+        }
+
+        // 9. Set t to LocalTime(t).
+        let t = if is_local { local_time(agent, t) } else { t };
+
+        // 10. If min is not present, let m be MinFromTime(t).
+        // 11. If sec is not present, let s be SecFromTime(t).
+        // 12. If ms is not present, let milli be msFromTime(t).
+        let h = h.unwrap_or_else(|| hour_from_time(t));
+        let m = m.unwrap_or_else(|| min_from_time(t));
+        let s = s.unwrap_or_else(|| sec_from_time(t));
+        let milli = milli.unwrap_or_else(|| ms_from_time(t));
+
+        // 13. Let date be MakeDate(Day(t), MakeTime(h, m, s, milli)).
+        let date = make_date(day(t), make_time(h, m, s, milli));
+
+        // 14. Let u be TimeClip(UTC(date)).
+        let u = time_clip(if is_local { utc(agent, date) } else { date });
+
+        // 15. Set dateObject.[[DateValue]] to u.
+        date_object.data_mut().slots_mut().set_date_value(u);
+
+        // 16. Return u.
+        Ok(JSValue::from(u))
+    }
+
+    /// The steps shared by the setters of the fields of a date, which are the year, month and date, in order, like Date.prototype.setMonth.
+    ///
+    /// The setter sets the fields from the one at the index of the first onwards.
+    fn set_date_fields(
+        agent: &mut JSAgent,
+        args: Args,
+        first: usize,
+        is_local: bool,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        let (date_object, t) = Self::this_time_value(&args)?;
+
+        // 4. Let m be ? ToNumber(month).
+        // 5. If date is present, let dt be ? ToNumber(date).
+        let [y, m, dt] = Self::setter_arguments(agent, &args, first)?;
+
+        let t = if t.is_nan() {
+            // NOTE: Only setFullYear and setUTCFullYear set a date which is NaN, which they treat as +0𝔽.
+            if first != 0 {
+                // 6. If t is NaN, return NaN.
+                return Ok(JSValue::from(f64::NAN));
+            }
+
+            0.0
+        } else if is_local {
+            // 7. Set t to LocalTime(t).
+            local_time(agent, t)
+        } else {
+            t
+        };
+
+        // 8. If date is not present, let dt be DateFromTime(t).
+        let y = y.unwrap_or_else(|| year_from_time(t));
+        let m = m.unwrap_or_else(|| month_from_time(t));
+        let dt = dt.unwrap_or_else(|| date_from_time(t));
+
+        // 9. Let newDate be MakeDate(MakeDay(YearFromTime(t), m, dt), TimeWithinDay(t)).
+        let new_date = make_date(make_day(y, m, dt), time_within_day(t));
+
+        // 10. Let u be TimeClip(UTC(newDate)).
+        let u = time_clip(if is_local {
+            utc(agent, new_date)
+        } else {
+            new_date
+        });
+
+        // 11. Set dateObject.[[DateValue]] to u.
+        date_object.data_mut().slots_mut().set_date_value(u);
+
+        // 12. Return u.
+        Ok(JSValue::from(u))
+    }
+
+    /// 21.4.4.20 Date.prototype.setDate ( date )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setdate
+    fn set_date(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_date_fields(agent, args, 2, true)
+    }
+
+    /// 21.4.4.21 Date.prototype.setFullYear ( year [ , month [ , date ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setfullyear
+    fn set_full_year(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_date_fields(agent, args, 0, true)
+    }
+
+    /// 21.4.4.22 Date.prototype.setHours ( hour [ , min [ , sec [ , ms ] ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.sethours
+    fn set_hours(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 0, true)
+    }
+
+    /// 21.4.4.23 Date.prototype.setMilliseconds ( ms )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setmilliseconds
+    fn set_milliseconds(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 3, true)
+    }
+
+    /// 21.4.4.24 Date.prototype.setMinutes ( min [ , sec [ , ms ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setminutes
+    fn set_minutes(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 1, true)
+    }
+
+    /// 21.4.4.25 Date.prototype.setMonth ( month [ , date ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setmonth
+    fn set_month(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_date_fields(agent, args, 1, true)
+    }
+
+    /// 21.4.4.26 Date.prototype.setSeconds ( sec [ , ms ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setseconds
+    fn set_seconds(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 2, true)
+    }
+
+    /// 21.4.4.27 Date.prototype.setTime ( time )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.settime
+    fn set_time(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let (date_object, _) = Self::this_time_value(&args)?;
+
+        // 3. Let t be ? ToNumber(time).
+        let t = to_number(agent, args.get(0).clone())?.0;
+
+        // 4. Let v be TimeClip(t).
+        let v = time_clip(t);
+
+        // 5. Set dateObject.[[DateValue]] to v.
+        date_object.data_mut().slots_mut().set_date_value(v);
+
+        // 6. Return v.
+        Ok(JSValue::from(v))
+    }
+
+    /// 21.4.4.28 Date.prototype.setUTCDate ( date )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setutcdate
+    fn set_utc_date(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_date_fields(agent, args, 2, false)
+    }
+
+    /// 21.4.4.29 Date.prototype.setUTCFullYear ( year [ , month [ , date ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setutcfullyear
+    fn set_utc_full_year(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_date_fields(agent, args, 0, false)
+    }
+
+    /// 21.4.4.30 Date.prototype.setUTCHours ( hour [ , min [ , sec [ , ms ] ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setutchours
+    fn set_utc_hours(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 0, false)
+    }
+
+    /// 21.4.4.31 Date.prototype.setUTCMilliseconds ( ms )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setutcmilliseconds
+    fn set_utc_milliseconds(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 3, false)
+    }
+
+    /// 21.4.4.32 Date.prototype.setUTCMinutes ( min [ , sec [ , ms ] ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setutcminutes
+    fn set_utc_minutes(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 1, false)
+    }
+
+    /// 21.4.4.33 Date.prototype.setUTCMonth ( month [ , date ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setutcmonth
+    fn set_utc_month(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_date_fields(agent, args, 1, false)
+    }
+
+    /// 21.4.4.34 Date.prototype.setUTCSeconds ( sec [ , ms ] )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.setutcseconds
+    fn set_utc_seconds(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::set_time_fields(agent, args, 2, false)
+    }
+
+    /// 21.4.4.35 Date.prototype.toDateString ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.todatestring
+    ///
+    /// Date.prototype.toLocaleDateString is the same method, as ECMA-402 is not implemented.
+    fn to_date_string(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let tv be dateObject.[[DateValue]].
+        let (_, tv) = Self::this_time_value(&args)?;
+
+        // 4. If tv is NaN, return "Invalid Date".
+        if tv.is_nan() {
+            return Ok(JSValue::from(String::from("Invalid Date")));
+        }
+
+        // 5. Let t be LocalTime(tv).
+        let t = local_time(agent, tv);
+
+        // 6. Return DateString(t).
+        Ok(JSValue::from(date_string(t)))
+    }
+
+    /// 21.4.4.36 Date.prototype.toISOString ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.toisostring
+    fn to_iso_string(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let tv be dateObject.[[DateValue]].
+        let (_, tv) = Self::this_time_value(&args)?;
+
+        // 4. If tv is not finite, throw a RangeError exception.
+        if !tv.is_finite() {
+            return range_error("Invalid time value");
+        }
+
+        // 5. Assert: tv is an integral Number.
+        // 6. If tv corresponds with a year that cannot be represented in the Date Time String Format, throw a RangeError exception.
+        // NOTE: Every year within the range of time values can be represented as an expanded year.
+        let year = year_from_time(tv);
+
+        let year = if (0.0..=9999.0).contains(&year) {
+            to_zero_padded_decimal_string(year, 4)
+        } else {
+            let sign = if year < 0.0 { "-" } else { "+" };
+
+            format!("{sign}{}", to_zero_padded_decimal_string(year.abs(), 6))
+        };
+
+        // 7. Return a String representation of tv in the Date Time String Format on the UTC time scale, including all format elements and the UTC offset representation "Z".
+        Ok(JSValue::from(format!(
+            "{year}-{}-{}T{}:{}:{}.{}Z",
+            to_zero_padded_decimal_string(month_from_time(tv) + 1.0, 2),
+            to_zero_padded_decimal_string(date_from_time(tv), 2),
+            to_zero_padded_decimal_string(hour_from_time(tv), 2),
+            to_zero_padded_decimal_string(min_from_time(tv), 2),
+            to_zero_padded_decimal_string(sec_from_time(tv), 2),
+            to_zero_padded_decimal_string(ms_from_time(tv), 3),
+        )))
+    }
+
+    /// 21.4.4.37 Date.prototype.toJSON ( key )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.tojson
+    fn to_json(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = JSValue::from(to_object(agent, args.this())?);
+
+        // 2. Let tv be ? ToPrimitive(O, number).
+        let tv = to_primitive(agent, o.clone(), PreferredPrimType::Number)?;
+
+        // 3. If tv is a Number and tv is not finite, return null.
+        if let JSValue::Number(number) = &tv {
+            if !number.is_finite() {
+                return Ok(JSValue::Null);
+            }
+        }
+
+        // 4. Return ? Invoke(O, "toISOString").
+        invoke(
+            agent,
+            &o,
+            &JSObjectPropKey::String("toISOString".into()),
+            None,
+        )
+    }
+
+    /// 21.4.4.41 Date.prototype.toString ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.tostring
+    ///
+    /// Date.prototype.toLocaleString is the same method, as ECMA-402 is not implemented.
+    fn to_string(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let tv be dateObject.[[DateValue]].
+        let (_, tv) = Self::this_time_value(&args)?;
+
+        // 4. Return ToDateString(tv).
+        Ok(JSValue::from(to_date_string(agent, tv)))
+    }
+
+    /// 21.4.4.42 Date.prototype.toTimeString ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.totimestring
+    ///
+    /// Date.prototype.toLocaleTimeString is the same method, as ECMA-402 is not implemented.
+    fn to_time_string(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let tv be dateObject.[[DateValue]].
+        let (_, tv) = Self::this_time_value(&args)?;
+
+        // 4. If tv is NaN, return "Invalid Date".
+        if tv.is_nan() {
+            return Ok(JSValue::from(String::from("Invalid Date")));
+        }
+
+        // 5. Let t be LocalTime(tv).
+        let t = local_time(agent, tv);
+
+        // 6. Return the string-concatenation of TimeString(t) and TimeZoneString(tv).
+        Ok(JSValue::from(format!(
+            "{}{}",
+            time_string(t),
+            time_zone_string(agent, tv)
+        )))
+    }
+
+    /// 21.4.4.43 Date.prototype.toUTCString ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.toutcstring
+    fn to_utc_string(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let tv be dateObject.[[DateValue]].
+        let (_, tv) = Self::this_time_value(&args)?;
+
+        // 4. If tv is NaN, return "Invalid Date".
+        if tv.is_nan() {
+            return Ok(JSValue::from(String::from("Invalid Date")));
+        }
+
+        // 5. Let weekday be the Name of the entry in Table 63 with the Number WeekDay(tv).
+        let weekday = DAY_NAMES[week_day(tv) as usize];
+
+        // 6. Let month be the Name of the entry in Table 64 with the Number MonthFromTime(tv).
+        let month = MONTH_NAMES[month_from_time(tv) as usize];
+
+        // 7. Let day be ToZeroPaddedDecimalString(ℝ(DateFromTime(tv)), 2).
+        let day = to_zero_padded_decimal_string(date_from_time(tv), 2);
+
+        // 8. Let yv be YearFromTime(tv).
+        // 9. If yv is +0𝔽 or yv > +0𝔽, let yearSign be the empty String; otherwise, let yearSign be "-".
+        // 10. Let paddedYear be ToZeroPaddedDecimalString(abs(ℝ(yv)), 4).
+        let year = padded_year(tv);
+
+        // 11. Return the string-concatenation of weekday, ",", the code unit 0x0020 (SPACE), day, the code unit 0x0020 (SPACE), month, the code unit 0x0020 (SPACE), yearSign, paddedYear, the code unit 0x0020 (SPACE), and TimeString(tv).
+        Ok(JSValue::from(format!(
+            "{weekday}, {day} {month} {year} {}",
+            time_string(tv)
+        )))
+    }
+
+    /// 21.4.4.44 Date.prototype.valueOf ( )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype.valueof
+    fn value_of(_: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Return dateObject.[[DateValue]].
+        let (_, t) = Self::this_time_value(&args)?;
+
+        Ok(JSValue::from(t))
+    }
+
+    /// 21.4.4.45 Date.prototype [ %Symbol.toPrimitive% ] ( hint )
+    /// https://262.ecma-international.org/16.0/#sec-date.prototype-%symbol.toprimitive%
+    fn to_primitive(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let JSValue::Object(o) = args.this() else {
+            return type_error_for(
+                "Date.prototype[Symbol.toPrimitive] called on a non-object",
+                args.this(),
+            );
+        };
+
+        let try_first = match args.get(0) {
+            // 3. If hint is either "string" or "default", then
+            // a. Let tryFirst be string.
+            JSValue::String(hint) if *hint == "string" || *hint == "default" => {
+                PreferredPrimType::String
+            }
+            // 4. Else if hint is "number", then
+            // a. Let tryFirst be number.
+            JSValue::String(hint) if *hint == "number" => PreferredPrimType::Number,
+            // 5. Else,
+            // a. Throw a TypeError exception.
+            _ => return type_error("Invalid hint for Date.prototype[Symbol.toPrimitive]"),
+        };
+
+        // 6. Return ? OrdinaryToPrimitive(O, tryFirst).
+        ordinary_to_primitive(agent, o, try_first)
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::{
+            create_non_enumerable_data_property_or_throw, define_property_or_throw, get,
+            has_property,
+        },
+        ordinary::ordinary_create_from_constructor,
+        type_conversion::to_string,
+    },
+    intrinsics::{define_builtin_method, error_prototype::ErrorPrototype},
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+        stack_trace::capture_stack_trace,
+    },
+    value::{
+        object::{
+            internal_slots::{ConstructorKind, InternalSlotName},
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        string::JSString,
+        JSValue,
+    },
+};
+
+/// 20.5.1 The Error Constructor
+/// https://262.ecma-international.org/16.0/#sec-error-constructor
+#[derive(Debug)]
+pub(crate) struct ErrorConstructor;
+
+impl ErrorConstructor {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Error%.
+        // is the initial value of the "Error" property of the global object.
+        // has a [[Prototype]] internal slot whose value is %Function.prototype%.
+        // has a "length" property whose value is 1𝔽.
+        let error = create_builtin_function(
+            agent,
+            Self::error,
+            1,
+            JSObjectPropKey::String("Error".into()),
+            vec![InternalSlotName::ConstructorKind],
+            Some(realm_addr.clone()),
+            None,
+            None,
+        );
+
+        // may be used as the value of an extends clause of a class definition.
+        error
+            .data_mut()
+            .slots_mut()
+            .set_constructor_kind(ConstructorKind::Base);
+
+        // NOTE: Error.captureStackTrace is not part of ECMAScript, but is provided by most engines for libraries which create their own error objects.
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &error,
+            "Error",
+            "captureStackTrace",
+            1,
+            Self::capture_stack_trace,
+        );
+
+        // 20.5.2.1 Error.prototype
+        // The initial value of Error.prototype is the Error prototype object.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        let error_prototype = realm_addr
+            .borrow()
+            .intrinsics
+            .error_prototype
+            .clone()
+            .unwrap();
+
+        let _ = define_property_or_throw(
+            &error,
+            &JSObjectPropKey::String("prototype".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(error_prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 20.5.3.1 Error.prototype.constructor
+        // The initial value of Error.prototype.constructor is %Error%.
+        let _ = define_property_or_throw(
+            &error_prototype,
+            &JSObjectPropKey::String("constructor".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(error.clone())),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        error
+    }
+
+    /// 20.5.1.1 Error ( message [ , options ] )
+    /// https://262.ecma-international.org/16.0/#sec-error-message
+    fn error(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let message = args.get(0);
+        let options = args.get(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        let new_target = match args.new_target() {
+            Some(new_target) => new_target.clone(),
+            None => agent.running_execution_context().function.clone().unwrap(),
+        };
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%Error.prototype%", « [[ErrorData]] »).
+        let o = ordinary_create_from_constructor(
+            agent,
+            &new_target,
+            |intrinsics| intrinsics.error_prototype.clone(),
+            Some(vec![InternalSlotName::ErrorData]),
+        )?;
+
+        // 3. If message is not undefined, then
+        if !message.is_undefined() {
+            // a. Let msg be ? ToString(message).
+            let msg = to_string(agent, message.clone())?;
+
+            // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
+            create_non_enumerable_data_property_or_throw(
+                &o,
+                &JSObjectPropKey::String("message".into()),
+                JSValue::String(msg),
+            );
+        }
+
+        // 4. Perform ? InstallErrorCause(O, options).
+        install_error_cause(agent, &o, options)?;
+
+        // NOTE: The stack trace is captured when the error is created, rather than when it is thrown, as in other engines.
+        install_stack(agent, &o)?;
+
+        // 5. Return O.
+        Ok(JSValue::from(o))
+    }
+
+    /// Error.captureStackTrace ( targetObject )
+    ///
+    /// Defines a "stack" property on the target object, describing it like an error along with the stack trace of the call.
+    fn capture_stack_trace(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let JSValue::Object(target_object) = args.get(0) else {
+            return type_error(&format!(
+                "Error.captureStackTrace: argument 1 must be an object, but got {}",
+                args.get(0).preview()
+            ));
+        };
+
+        install_stack(agent, target_object)?;
+
+        Ok(JSValue::Undefined)
+    }
+}
+
+/// 20.5.8.1 InstallErrorCause ( O, options )
+/// https://262.ecma-international.org/16.0/#sec-installerrorcause
+pub(crate) fn install_error_cause(
+    agent: &mut JSAgent,
+    o: &ObjectAddr,
+    options: &JSValue,
+) -> CompletionRecord {
+    // 1. If options is an Object and ? HasProperty(options, "cause") is true, then
+    if let JSValue::Object(options) = options {
+        let key = JSObjectPropKey::String("cause".into());
+
+        if has_property(options, &key)? {
+            // a. Let cause be ? Get(options, "cause").
+            let cause = get(agent, options, &key)?;
+
+            // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "cause", cause).
+            create_non_enumerable_data_property_or_throw(o, &key, cause);
+        }
+    }
+
+    // 2. Return unused.
+    Ok(())
+}
+
+/// Defines the "stack" property of the object, which describes it as Error.prototype.toString does followed by the stack trace of the calls being evaluated.
+pub(crate) fn install_stack(agent: &mut JSAgent, o: &ObjectAddr) -> CompletionRecord {
+    let description = ErrorPrototype::describe(agent, &JSValue::from(o.clone()))?;
+    let stack = description.concat(&JSString::from(capture_stack_trace(agent)));
+
+    define_property_or_throw(
+        o,
+        &JSObjectPropKey::String("stack".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::String(stack)),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    )
+}
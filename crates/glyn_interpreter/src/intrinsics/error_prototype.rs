@@ -0,0 +1,117 @@
+use crate::{
+    abstract_ops::{
+        object_operations::{define_property_or_throw, get},
+        ordinary::ordinary_object_create,
+        type_conversion::to_string,
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{type_error_for, JSAgent},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        string::JSString,
+        JSValue,
+    },
+};
+
+/// 20.5.3 Properties of the Error Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-error-prototype-object
+#[derive(Debug)]
+pub(crate) struct ErrorPrototype;
+
+impl ErrorPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Error.prototype%.
+        // is an ordinary object.
+        // is not an Error instance and does not have an [[ErrorData]] internal slot.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        let error_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            None,
+        );
+
+        // 20.5.3.2 Error.prototype.message
+        // The initial value of Error.prototype.message is the empty String.
+        // 20.5.3.3 Error.prototype.name
+        // The initial value of Error.prototype.name is "Error".
+        for (name, value) in [("message", ""), ("name", "Error")] {
+            let _ = define_property_or_throw(
+                &error_prototype,
+                &JSObjectPropKey::String(name.into()),
+                JSObjectPropDescriptor {
+                    value: Some(JSValue::from(value)),
+                    writable: Some(true),
+                    enumerable: Some(false),
+                    configurable: Some(true),
+                    ..JSObjectPropDescriptor::default()
+                },
+            );
+        }
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &error_prototype,
+            "Error.prototype",
+            "toString",
+            0,
+            Self::to_string,
+        );
+
+        error_prototype
+    }
+
+    /// 20.5.3.4 Error.prototype.toString ( )
+    /// https://262.ecma-international.org/16.0/#sec-error.prototype.tostring
+    fn to_string(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Ok(JSValue::String(Self::describe(agent, args.this())?))
+    }
+
+    /// The steps of Error.prototype.toString, which describe the error by its name and message, like `TypeError: x is not a function`.
+    pub(crate) fn describe(agent: &mut JSAgent, this: &JSValue) -> CompletionRecord<JSString> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let JSValue::Object(o) = this else {
+            return type_error_for("Error.prototype.toString called on a non-object", this);
+        };
+
+        // 3. Let name be ? Get(O, "name").
+        let name = get(agent, o, &JSObjectPropKey::String("name".into()))?;
+
+        // 4. If name is undefined, set name to "Error"; otherwise set name to ? ToString(name).
+        let name = match name {
+            JSValue::Undefined => JSString::from("Error"),
+            name => to_string(agent, name)?,
+        };
+
+        // 5. Let msg be ? Get(O, "message").
+        let msg = get(agent, o, &JSObjectPropKey::String("message".into()))?;
+
+        // 6. If msg is undefined, set msg to the empty String; otherwise set msg to ? ToString(msg).
+        let msg = match msg {
+            JSValue::Undefined => JSString::from(""),
+            msg => to_string(agent, msg)?,
+        };
+
+        // 7. If name is the empty String, return msg.
+        if name.is_empty() {
+            return Ok(msg);
+        }
+
+        // 8. If msg is the empty String, return name.
+        if msg.is_empty() {
+            return Ok(name);
+        }
+
+        // 9. Return the string-concatenation of name, the code unit 0x003A (COLON), the code unit 0x0020 (SPACE), and msg.
+        Ok(name.concat(&JSString::from(": ")).concat(&msg))
+    }
+}
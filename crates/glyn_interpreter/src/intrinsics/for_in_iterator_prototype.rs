@@ -0,0 +1,89 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        iterator_operations::create_iter_result_object,
+        object_operations::define_property_or_throw, ordinary::ordinary_object_create,
+    },
+    runtime::{
+        agent::JSAgent, completion::CompletionRecord, native_function::Args, realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// 14.7.5.10.2 The %ForInIteratorPrototype% Object
+/// https://262.ecma-international.org/16.0/#sec-%foriniteratorprototype%-object
+#[derive(Debug)]
+pub(crate) struct ForInIteratorPrototype;
+
+impl ForInIteratorPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // has properties that are inherited by all For-In Iterator Objects.
+        // is an ordinary object.
+        // has a [[Prototype]] internal slot whose value is %Iterator.prototype%.
+        // is never directly accessible to ECMAScript code.
+        let for_in_iterator_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.iterator_prototype.clone(),
+            None,
+        );
+
+        let next = create_builtin_function(
+            agent,
+            Self::next,
+            0,
+            JSObjectPropKey::String("next".into()),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        );
+
+        let _ = define_property_or_throw(
+            &for_in_iterator_prototype,
+            &JSObjectPropKey::String("next".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(next)),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        for_in_iterator_prototype
+    }
+
+    /// 14.7.5.10.2.1 %ForInIteratorPrototype%.next ( )
+    /// https://262.ecma-international.org/16.0/#sec-%foriniteratorprototype%.next
+    fn next(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be the this value.
+        // 2. Assert: O is an Object.
+        let object = ObjectAddr::try_from(args.this())?;
+
+        // 3. Assert: O has all of the internal slots of a For-In Iterator Instance (14.7.5.10.3).
+        // NOTE: The slots are taken out of the object while the iterator is advanced, so that it is not borrowed while the keys of the enumerated objects are read.
+        let mut iterator = object
+            .data_mut()
+            .slots_mut()
+            .take_for_in_iterator()
+            .unwrap();
+
+        // NOTE: The remaining steps are implemented by ForInIterator::next, which returns the key the iterator result object is created for.
+        let key = iterator.next();
+
+        object.data_mut().slots_mut().set_for_in_iterator(iterator);
+
+        let result = match key? {
+            Some(key) => create_iter_result_object(agent, JSValue::from(key), false),
+            None => create_iter_result_object(agent, JSValue::Undefined, true),
+        };
+
+        Ok(JSValue::from(result))
+    }
+}
@@ -1,8 +1,19 @@
 use crate::{
-    abstract_ops::function_operations::create_builtin_function,
-    runtime::{agent::JSAgent, realm::RealmAddr},
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::{define_property_or_throw, ordinary_has_instance},
+    },
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_HAS_INSTANCE},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
     value::{
-        object::{property::JSObjectPropKey, ObjectAddr},
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
         JSValue,
     },
 };
@@ -15,10 +26,10 @@ pub(crate) struct FunctionPrototype;
 impl FunctionPrototype {
     pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
         // accepts any arguments and returns undefined when invoked.
-        let behaviour_fn = |_args: Vec<JSValue>| JSValue::Undefined;
+        let behaviour_fn = |_: &mut JSAgent, _: Args| Ok(JSValue::Undefined);
 
         // is itself a built-in function object.
-        create_builtin_function(
+        let function_prototype = create_builtin_function(
             agent,
             behaviour_fn,
             // has a "length" property whose value is +0𝔽.
@@ -31,6 +42,47 @@ impl FunctionPrototype {
             // has a [[Prototype]] internal slot whose value is %Object.prototype%.
             realm_addr.borrow().intrinsics.object_prototype.clone(),
             None,
-        )
+        );
+
+        // 20.2.3.6 Function.prototype [ %Symbol.hasInstance% ] ( V )
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        let has_instance = create_builtin_function(
+            agent,
+            Self::has_instance,
+            1,
+            JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_HAS_INSTANCE),
+            vec![],
+            Some(realm_addr),
+            Some(function_prototype.clone()),
+            None,
+        );
+
+        let _ = define_property_or_throw(
+            &function_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_HAS_INSTANCE),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(has_instance)),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        function_prototype
+    }
+
+    /// 20.2.3.6 Function.prototype [ %Symbol.hasInstance% ] ( V )
+    /// https://262.ecma-international.org/16.0/#sec-function.prototype-%symbol.hasinstance%
+    fn has_instance(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let value = args.get(0);
+
+        // 1. Let F be the this value.
+        // 2. Return ? OrdinaryHasInstance(F, V).
+        Ok(JSValue::from(ordinary_has_instance(
+            agent,
+            args.this(),
+            value,
+        )?))
     }
 }
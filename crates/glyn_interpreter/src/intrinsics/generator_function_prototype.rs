@@ -0,0 +1,90 @@
+use crate::{
+    abstract_ops::{object_operations::define_property_or_throw, ordinary::ordinary_object_create},
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        JSValue,
+    },
+};
+
+/// 27.3.3 Properties of the GeneratorFunction Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-generatorfunction-prototype-object
+#[derive(Debug)]
+pub(crate) struct GeneratorFunctionPrototype;
+
+impl GeneratorFunctionPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %GeneratorFunction.prototype%.
+        // is an ordinary object.
+        // is not a function object and does not have an [[ECMAScriptCode]] internal slot or any other of the internal slots listed in Table 30 or Table 91.
+        // has a [[Prototype]] internal slot whose value is %Function.prototype%.
+        let generator_function_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.function_prototype.clone(),
+            None,
+        );
+
+        let generator_prototype = realm_addr
+            .borrow()
+            .intrinsics
+            .generator_prototype
+            .clone()
+            .unwrap();
+
+        // 27.3.3.1 GeneratorFunction.prototype.constructor
+        // TODO: Implement the %GeneratorFunction% constructor.
+
+        // 27.3.3.2 GeneratorFunction.prototype.prototype
+        // The initial value of GeneratorFunction.prototype.prototype is %GeneratorPrototype%.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &generator_function_prototype,
+            &JSObjectPropKey::String("prototype".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(generator_prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 27.5.1.1 %GeneratorPrototype%.constructor
+        // The initial value of %GeneratorPrototype%.constructor is %GeneratorFunction.prototype%.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &generator_prototype,
+            &JSObjectPropKey::String("constructor".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(generator_function_prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 27.3.3.3 GeneratorFunction.prototype [ %Symbol.toStringTag% ]
+        // The initial value of the %Symbol.toStringTag% property is the String value "GeneratorFunction".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &generator_function_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from("GeneratorFunction".to_string())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        generator_function_prototype
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    abstract_ops::{
+        generator_operations::{generator_resume, generator_resume_abrupt},
+        object_operations::define_property_or_throw,
+        ordinary::ordinary_object_create,
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        JSValue,
+    },
+    vm::Completion,
+};
+
+/// 27.5.1 The %GeneratorPrototype% Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-generator-prototype
+#[derive(Debug)]
+pub(crate) struct GeneratorPrototype;
+
+impl GeneratorPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %GeneratorFunction.prototype.prototype%.
+        // is an ordinary object.
+        // is not a Generator instance and does not have a [[GeneratorState]] internal slot.
+        // has a [[Prototype]] internal slot whose value is %Iterator.prototype%.
+        let generator_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.iterator_prototype.clone(),
+            None,
+        );
+
+        let next = define_builtin_method(
+            agent,
+            &realm_addr,
+            &generator_prototype,
+            "%GeneratorPrototype%",
+            "next",
+            1,
+            Self::next,
+        );
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &generator_prototype,
+            "%GeneratorPrototype%",
+            "return",
+            1,
+            Self::r#return,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &generator_prototype,
+            "%GeneratorPrototype%",
+            "throw",
+            1,
+            Self::throw,
+        );
+
+        // 27.5.1.5 %GeneratorPrototype% [ %Symbol.toStringTag% ]
+        // The initial value of the %Symbol.toStringTag% property is the String value "Generator".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &generator_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from("Generator".to_string())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        realm_addr
+            .borrow_mut()
+            .intrinsics
+            .generator_function_prototype_prototype_next = Some(next);
+
+        generator_prototype
+    }
+
+    /// 27.5.1.2 %GeneratorPrototype%.next ( value )
+    /// https://262.ecma-international.org/16.0/#sec-generator.prototype.next
+    fn next(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? GeneratorResume(this value, value, empty).
+        generator_resume(agent, args.this(), args.get(0).clone())
+    }
+
+    /// 27.5.1.3 %GeneratorPrototype%.return ( value )
+    /// https://262.ecma-international.org/16.0/#sec-generator.prototype.return
+    fn r#return(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be ReturnCompletion(value).
+        let completion = Completion::Return(args.get(0).clone());
+
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        generator_resume_abrupt(agent, args.this(), completion)
+    }
+
+    /// 27.5.1.4 %GeneratorPrototype%.throw ( exception )
+    /// https://262.ecma-international.org/16.0/#sec-generator.prototype.throw
+    fn throw(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be ThrowCompletion(exception).
+        let completion = Completion::Throw(args.get(0).clone());
+
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        generator_resume_abrupt(agent, args.this(), completion)
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        type_conversion::{
+            is_str_white_space_char, str_decimal_literal_prefix, str_decimal_literal_value,
+            to_int32, to_number, to_string,
+        },
+    },
+    runtime::{
+        agent::{uri_error, JSAgent},
+        completion::CompletionRecord,
+        native_function::{Args, NativeFunction},
+        realm::RealmAddr,
+    },
+    value::{object::property::JSObjectPropKey, object::ObjectAddr, string::JSString, JSValue},
+};
+
+/// The characters which are never escaped by Encode, which are the ASCII word characters and "-.!~*'()".
+const ALWAYS_UNESCAPED: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_-.!~*'()";
+
+/// The characters of a URI which have a reserved meaning, which encodeURI leaves unescaped and decodeURI does not unescape.
+const URI_RESERVED: &str = ";/?:@&=+$,#";
+
+/// 19.2 Function Properties of the Global Object
+/// https://262.ecma-international.org/16.0/#sec-function-properties-of-the-global-object
+#[derive(Debug)]
+pub(crate) struct GlobalObject;
+
+impl GlobalObject {
+    /// Creates the built-in function object of one of the function properties of the global object, like %isNaN%.
+    pub(crate) fn create_function(
+        agent: &mut JSAgent,
+        realm_addr: RealmAddr,
+        name: &str,
+        length: usize,
+        behaviour: impl NativeFunction,
+    ) -> ObjectAddr {
+        create_builtin_function(
+            agent,
+            behaviour,
+            length,
+            JSObjectPropKey::String(name.into()),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        )
+    }
+
+    /// 19.2.2 isFinite ( number )
+    /// https://262.ecma-international.org/16.0/#sec-isfinite-number
+    pub(crate) fn is_finite(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let num be ? ToNumber(number).
+        let num = to_number(agent, args.get(0).clone())?;
+
+        // 2. If num is not finite, return false.
+        // 3. Otherwise, return true.
+        Ok(JSValue::from(num.0.is_finite()))
+    }
+
+    /// 19.2.3 isNaN ( number )
+    /// https://262.ecma-international.org/16.0/#sec-isnan-number
+    pub(crate) fn is_nan(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let num be ? ToNumber(number).
+        let num = to_number(agent, args.get(0).clone())?;
+
+        // 2. If num is NaN, return true.
+        // 3. Otherwise, return false.
+        Ok(JSValue::from(num.is_nan()))
+    }
+
+    /// 19.2.4 parseFloat ( string )
+    /// https://262.ecma-international.org/16.0/#sec-parsefloat-string
+    pub(crate) fn parse_float(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let inputString be ? ToString(string).
+        let input_string = to_string(agent, args.get(0).clone())?;
+
+        // 2. Let trimmedString be ! TrimString(inputString, start).
+        // 3. Let trimmed be StringToCodePoints(trimmedString).
+        let input_string = input_string.to_string();
+        let trimmed = input_string.trim_start_matches(is_str_white_space_char);
+
+        // 4. Let trimmedPrefix be the longest prefix of trimmed that satisfies the syntax of a StrDecimalLiteral, which might be trimmed itself. If there is no such prefix, return NaN.
+        let Some(trimmed_prefix) = str_decimal_literal_prefix(trimmed) else {
+            return Ok(JSValue::from(f64::NAN));
+        };
+
+        // 5. Let parsedNumber be ParseText(trimmedPrefix, StrDecimalLiteral).
+        // 6. Assert: parsedNumber is a Parse Node.
+        // 7. Return the StringNumericValue of parsedNumber.
+        Ok(JSValue::from(str_decimal_literal_value(trimmed_prefix).0))
+    }
+
+    /// 19.2.5 parseInt ( string, radix )
+    /// https://262.ecma-international.org/16.0/#sec-parseint-string-radix
+    pub(crate) fn parse_int(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let inputString be ? ToString(string).
+        let input_string = to_string(agent, args.get(0).clone())?;
+
+        // 2. Let S be ! TrimString(inputString, start).
+        let input_string = input_string.to_string();
+        let mut s = input_string.trim_start_matches(is_str_white_space_char);
+
+        // 3. Let sign be 1.
+        // 4. If S is not empty and the first code unit of S is the code unit 0x002D (HYPHEN-MINUS), set sign to -1.
+        let sign = if s.starts_with('-') { -1.0 } else { 1.0 };
+
+        // 5. If S is not empty and the first code unit of S is either the code unit 0x002B (PLUS SIGN) or the code unit 0x002D (HYPHEN-MINUS), set S to the substring of S from index 1.
+        s = s.strip_prefix(['+', '-']).unwrap_or(s);
+
+        // 6. Let R be ℝ(? ToInt32(radix)).
+        let mut r = to_int32(agent, args.get(1).clone())?.0 as i32;
+
+        // 7. Let stripPrefix be true.
+        let mut strip_prefix = true;
+
+        // 8. If R ≠ 0, then
+        if r != 0 {
+            // a. If R < 2 or R > 36, return NaN.
+            if !(2..=36).contains(&r) {
+                return Ok(JSValue::from(f64::NAN));
+            }
+
+            // b. If R ≠ 16, set stripPrefix to false.
+            if r != 16 {
+                strip_prefix = false;
+            }
+        }
+        // 9. Else,
+        else {
+            // a. Set R to 10.
+            r = 10;
+        }
+
+        // 10. If stripPrefix is true, then
+        if strip_prefix {
+            // a. If the length of S is at least 2 and the first two code units of S are either "0x" or "0X", then
+            if let Some(rest) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                // i. Set S to the substring of S from index 2.
+                s = rest;
+
+                // ii. Set R to 16.
+                r = 16;
+            }
+        }
+
+        let radix = r as u32;
+
+        // 11. If S contains a code unit that is not a radix-R digit, let end be the index within S of the first such code unit; otherwise, let end be the length of S.
+        let end = s
+            .find(|char: char| !char.is_digit(radix))
+            .unwrap_or(s.len());
+
+        // 12. Let Z be the substring of S from 0 to end.
+        let z = &s[..end];
+
+        // 13. If Z is empty, return NaN.
+        if z.is_empty() {
+            return Ok(JSValue::from(f64::NAN));
+        }
+
+        // 14. Let mathInt be the integer value that is represented by Z in radix-R notation, using the letters A through Z and a through z for digits with values 10 through 35. (However, if R = 10 and Z contains more than 20 significant digits, every significant digit after the 20th may be replaced by a 0 digit, at the option of the implementation; and if R is not one of 2, 4, 8, 10, 16, or 32, then mathInt may be an implementation-approximated integer representing the integer value denoted by Z in radix-R notation.)
+        let math_int = match radix {
+            // NOTE: The float parser rounds the value of a decimal integer to the nearest Number.
+            10 => z.parse::<f64>().unwrap_or(f64::NAN),
+            2 | 4 | 8 | 16 | 32 => power_of_two_radix_value(z, radix),
+            _ => z.chars().fold(0.0, |value, char| {
+                value * radix as f64 + char.to_digit(radix).unwrap_or(0) as f64
+            }),
+        };
+
+        // 15. If mathInt = 0, then
+        // a. If sign = -1, return -0𝔽.
+        // b. Return +0𝔽.
+        // 16. Return 𝔽(sign × mathInt).
+        Ok(JSValue::from(sign * math_int))
+    }
+
+    /// 19.2.6.1 decodeURI ( encodedURI )
+    /// https://262.ecma-international.org/16.0/#sec-decodeuri-encodeduri
+    pub(crate) fn decode_uri(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let uriString be ? ToString(encodedURI).
+        let uri_string = to_string(agent, args.get(0).clone())?;
+
+        // 2. Let preserveEscapeSet be ";/?:@&=+$,#".
+        // 3. Return ? Decode(uriString, preserveEscapeSet).
+        Ok(JSValue::from(decode(
+            &uri_string.to_string(),
+            URI_RESERVED,
+        )?))
+    }
+
+    /// 19.2.6.2 decodeURIComponent ( encodedURIComponent )
+    /// https://262.ecma-international.org/16.0/#sec-decodeuricomponent-encodeduricomponent
+    pub(crate) fn decode_uri_component(
+        agent: &mut JSAgent,
+        args: Args,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let componentString be ? ToString(encodedURIComponent).
+        let component_string = to_string(agent, args.get(0).clone())?;
+
+        // 2. Let preserveEscapeSet be the empty String.
+        // 3. Return ? Decode(componentString, preserveEscapeSet).
+        Ok(JSValue::from(decode(&component_string.to_string(), "")?))
+    }
+
+    /// 19.2.6.3 encodeURI ( uri )
+    /// https://262.ecma-international.org/16.0/#sec-encodeuri-uri
+    pub(crate) fn encode_uri(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let uriString be ? ToString(uri).
+        let uri_string = to_string(agent, args.get(0).clone())?;
+
+        // 2. Let extraUnescaped be ";/?:@&=+$,#".
+        // 3. Return ? Encode(uriString, extraUnescaped).
+        Ok(JSValue::from(encode(&uri_string, URI_RESERVED)?))
+    }
+
+    /// 19.2.6.4 encodeURIComponent ( uriComponent )
+    /// https://262.ecma-international.org/16.0/#sec-encodeuricomponent-uricomponent
+    pub(crate) fn encode_uri_component(
+        agent: &mut JSAgent,
+        args: Args,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let componentString be ? ToString(uriComponent).
+        let component_string = to_string(agent, args.get(0).clone())?;
+
+        // 2. Let extraUnescaped be the empty String.
+        // 3. Return ? Encode(componentString, extraUnescaped).
+        Ok(JSValue::from(encode(&component_string, "")?))
+    }
+}
+
+/// The value of the digits in a radix which is a power of two, rounded to the nearest Number, with ties to even.
+fn power_of_two_radix_value(digits: &str, radix: u32) -> f64 {
+    let bits_per_digit = radix.trailing_zeros();
+
+    let bits: Vec<bool> = digits
+        .chars()
+        .filter_map(|char| char.to_digit(radix))
+        .flat_map(|digit| {
+            (0..bits_per_digit)
+                .rev()
+                .map(move |bit| digit >> bit & 1 == 1)
+        })
+        .skip_while(|bit| !bit)
+        .collect();
+
+    // A Number has 53 significant bits, beyond which the value is rounded.
+    let significant_bits = bits.len().min(53);
+
+    let mut significand = bits[..significant_bits]
+        .iter()
+        .fold(0u64, |value, bit| value << 1 | u64::from(*bit));
+
+    if let Some((round_bit, rest)) = bits[significant_bits..].split_first() {
+        let is_tie = !rest.iter().any(|bit| *bit);
+
+        if *round_bit && (!is_tie || significand & 1 == 1) {
+            significand += 1;
+        }
+    }
+
+    significand as f64 * 2f64.powi((bits.len() - significant_bits) as i32)
+}
+
+/// 19.2.6.5 Encode ( string, extraUnescaped )
+/// https://262.ecma-international.org/16.0/#sec-encode
+fn encode(string: &JSString, extra_unescaped: &str) -> CompletionRecord<String> {
+    // 1. Let len be the length of string.
+    let len = string.len();
+
+    // 2. Let R be the empty String.
+    let mut r = String::with_capacity(len);
+
+    // 3. Let alwaysUnescaped be the string-concatenation of the ASCII word characters and "-.!~*'()".
+    // 4. Let unescapedSet be the string-concatenation of alwaysUnescaped and extraUnescaped.
+    let is_unescaped = |unit: u16| {
+        char::from_u32(unit as u32).is_some_and(|char| {
+            char.is_ascii() && (ALWAYS_UNESCAPED.contains(char) || extra_unescaped.contains(char))
+        })
+    };
+
+    // 5. Let k be 0.
+    let mut k = 0;
+
+    // 6. Repeat, while k < len,
+    while k < len {
+        // a. Let C be the code unit at index k within string.
+        let c = string.code_units()[k];
+
+        // b. If unescapedSet contains C, then
+        if is_unescaped(c) {
+            // i. Set k to k + 1.
+            k += 1;
+
+            // ii. Set R to the string-concatenation of R and C.
+            r.push(c as u8 as char);
+        }
+        // c. Else,
+        else {
+            // i. Let cp be CodePointAt(string, k).
+            let (code_point, code_unit_count, is_unpaired_surrogate) = string.code_point_at(k);
+
+            // ii. If cp.[[IsUnpairedSurrogate]] is true, throw a URIError exception.
+            if is_unpaired_surrogate {
+                return uri_error("URI malformed");
+            }
+
+            // iii. Set k to k + cp.[[CodeUnitCount]].
+            k += code_unit_count;
+
+            // iv. Let Octets be the List of octets resulting by applying the UTF-8 transformation to cp.[[CodePoint]].
+            let mut octets = [0; 4];
+            let char = char::from_u32(code_point).unwrap();
+
+            // v. For each element octet of Octets, do
+            for octet in char.encode_utf8(&mut octets).bytes() {
+                // 1. Let hex be the String representation of octet, formatted as an uppercase hexadecimal number.
+                // 2. Set R to the string-concatenation of R, "%", and StringPad(hex, 2, "0", start).
+                r.push_str(&format!("%{octet:02X}"));
+            }
+        }
+    }
+
+    // 7. Return R.
+    Ok(r)
+}
+
+/// 19.2.6.6 Decode ( string, preserveEscapeSet )
+/// https://262.ecma-international.org/16.0/#sec-decode
+fn decode(string: &str, preserve_escape_set: &str) -> CompletionRecord<String> {
+    let string = string.as_bytes();
+
+    // 1. Let len be the length of string.
+    let len = string.len();
+
+    // 2. Let R be the empty String.
+    // NOTE: R is built up as UTF-8, and only valid UTF-8 encodings of code points are appended to it.
+    let mut r = Vec::with_capacity(len);
+
+    // 3. Let k be 0.
+    let mut k = 0;
+
+    // 4. Repeat, while k < len,
+    while k < len {
+        // a. Let C be the code unit at index k within string.
+        let c = string[k];
+
+        // b. Let S be C.
+        // c. If C is the code unit 0x0025 (PERCENT SIGN), then
+        if c == b'%' {
+            // i. If k + 3 > len, throw a URIError exception.
+            // ii. Let escape be the substring of string from k to k + 3.
+            // iii. Let B be ParseHexOctet(string, k + 1).
+            // iv. If B is not an integer, throw a URIError exception.
+            let b = parse_hex_octet(string, k + 1)?;
+            let escape = &string[k..k + 3];
+
+            // v. Set k to k + 2.
+            k += 2;
+
+            // vi. Let n be the number of leading 1 bits in B.
+            let n = b.leading_ones() as usize;
+
+            // vii. If n = 0, then
+            if n == 0 {
+                // 1. Let asciiChar be the code unit whose numeric value is B.
+                // 2. If preserveEscapeSet contains asciiChar, set S to escape. Otherwise, set S to asciiChar.
+                if preserve_escape_set.contains(b as char) {
+                    r.extend_from_slice(escape);
+                } else {
+                    r.push(b);
+                }
+            }
+            // viii. Else,
+            else {
+                // 1. If n = 1 or n > 4, throw a URIError exception.
+                if n == 1 || n > 4 {
+                    return uri_error("URI malformed");
+                }
+
+                // 2. Let Octets be « B ».
+                let mut octets = vec![b];
+
+                // 3. Let j be 1.
+                // 4. Repeat, while j < n,
+                for _ in 1..n {
+                    // a. Set k to k + 1.
+                    k += 1;
+
+                    // b. If k + 3 > len, throw a URIError exception.
+                    // c. If the code unit at index k within string is not the code unit 0x0025 (PERCENT SIGN), throw a URIError exception.
+                    if string.get(k) != Some(&b'%') {
+                        return uri_error("URI malformed");
+                    }
+
+                    // d. Let continuationByte be ParseHexOctet(string, k + 1).
+                    // e. If continuationByte is not an integer, throw a URIError exception.
+                    // f. Append continuationByte to Octets.
+                    octets.push(parse_hex_octet(string, k + 1)?);
+
+                    // g. Set k to k + 2.
+                    // h. Set j to j + 1.
+                    k += 2;
+                }
+
+                // 5. Assert: The length of Octets is n.
+                debug_assert_eq!(octets.len(), n);
+
+                // 6. If Octets does not contain a valid UTF-8 encoding of a Unicode code point, throw a URIError exception.
+                if std::str::from_utf8(&octets).is_err() {
+                    return uri_error("URI malformed");
+                }
+
+                // 7. Let V be the code point obtained by applying the UTF-8 transformation to Octets, that is, from a List of octets into a 21-bit value.
+                // 8. Set S to UTF16EncodeCodePoint(V).
+                r.extend_from_slice(&octets);
+            }
+        } else {
+            r.push(c);
+        }
+
+        // d. Set R to the string-concatenation of R and S.
+        // e. Set k to k + 1.
+        k += 1;
+    }
+
+    // 5. Return R.
+    Ok(String::from_utf8(r).expect("Decode only appends valid UTF-8"))
+}
+
+/// 19.2.6.7 ParseHexOctet ( string, position )
+/// https://262.ecma-international.org/16.0/#sec-parsehexoctet
+///
+/// Throws a URIError if the two code units at the position are not hexadecimal digits, which is what the callers do with the errors returned by the parse.
+fn parse_hex_octet(string: &[u8], position: usize) -> CompletionRecord<u8> {
+    // 1. Let len be the length of string.
+    // 2. Assert: position + 2 ≤ len.
+    // 3. Let hexDigits be the substring of string from position to position + 2.
+    let Some(hex_digits) = string.get(position..position + 2) else {
+        return uri_error("URI malformed");
+    };
+
+    // 4. Let parseResult be ParseText(hexDigits, HexDigits[~Sep]).
+    // 5. If parseResult is not a Parse Node, return parseResult.
+    // 6. Let n be the MV of parseResult.
+    // 7. Assert: n is in the inclusive interval from 0 to 255.
+    // 8. Return n.
+    hex_digits
+        .iter()
+        .try_fold(0u8, |n, digit| {
+            (*digit as char)
+                .to_digit(16)
+                .map(|digit| n << 4 | digit as u8)
+        })
+        .map_or_else(|| uri_error("URI malformed"), Ok)
+}
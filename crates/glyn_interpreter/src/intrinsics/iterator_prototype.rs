@@ -0,0 +1,70 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function, object_operations::define_property_or_throw,
+        ordinary::ordinary_object_create,
+    },
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_ITERATOR},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        JSValue,
+    },
+};
+
+/// 27.1.4 Properties of the Iterator Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-iterator-prototype-object
+#[derive(Debug)]
+pub(crate) struct IteratorPrototype;
+
+impl IteratorPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Iterator.prototype%.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        // is an ordinary object.
+        // TODO: Implement the iterator helper methods.
+        let iterator_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            None,
+        );
+
+        let iterator = create_builtin_function(
+            agent,
+            Self::iterator,
+            0,
+            JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        );
+
+        let _ = define_property_or_throw(
+            &iterator_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(iterator)),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        iterator_prototype
+    }
+
+    /// 27.1.4.13 Iterator.prototype [ %Symbol.iterator% ] ( )
+    /// https://262.ecma-international.org/16.0/#sec-%iterator.prototype%-%symbol.iterator%
+    fn iterator(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return the this value.
+        Ok(args.this().clone())
+    }
+}
@@ -1,2 +1,135 @@
+pub(crate) mod array_constructor;
+pub(crate) mod array_iterator_prototype;
+pub(crate) mod array_prototype;
+pub(crate) mod async_function_prototype;
+pub(crate) mod boolean_prototype;
+pub(crate) mod console_object;
+pub(crate) mod date_constructor;
+pub(crate) mod date_prototype;
+pub(crate) mod error_constructor;
+pub(crate) mod error_prototype;
+pub(crate) mod for_in_iterator_prototype;
 pub(crate) mod function_prototype;
+pub(crate) mod generator_function_prototype;
+pub(crate) mod generator_prototype;
+pub(crate) mod global_object;
+pub(crate) mod iterator_prototype;
+pub(crate) mod native_error_constructor;
+pub(crate) mod native_error_prototype;
+pub(crate) mod number_prototype;
+pub(crate) mod object_constructor;
 pub(crate) mod object_prototype;
+pub(crate) mod promise_constructor;
+pub(crate) mod promise_prototype;
+pub(crate) mod string_iterator_prototype;
+pub(crate) mod string_prototype;
+pub(crate) mod symbol_prototype;
+pub(crate) mod throw_type_error;
+
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function, object_operations::define_property_or_throw,
+    },
+    runtime::{agent::JSAgent, native_function::NativeFunction, realm::RealmAddr},
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        string::JSString,
+        JSValue,
+    },
+};
+
+/// Creates a built-in function and defines it as a method of an intrinsic object.
+///
+/// The owner is the path the object is reached by from the global object, like `Array.prototype`, so that errors about the arguments of the method name it in full.
+pub(crate) fn define_builtin_method(
+    agent: &mut JSAgent,
+    realm_addr: &RealmAddr,
+    object: &ObjectAddr,
+    owner: &str,
+    name: &str,
+    length: usize,
+    behaviour: impl NativeFunction,
+) -> ObjectAddr {
+    let method = create_builtin_function(
+        agent,
+        behaviour,
+        length,
+        JSObjectPropKey::String(name.into()),
+        vec![],
+        Some(realm_addr.clone()),
+        None,
+        None,
+    );
+
+    method
+        .data_mut()
+        .slots_mut()
+        .set_qualified_name(JSString::from(format!("{owner}.{name}")));
+
+    // Every other data property described in clauses 19 through 28 and in Annex B.2 has the attributes { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true } unless otherwise specified.
+    let _ = define_property_or_throw(
+        object,
+        &JSObjectPropKey::String(name.into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(method.clone())),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    );
+
+    method
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        abstract_ops::{
+            object_operations::call, ordinary::ordinary_object_create, type_conversion::to_string,
+        },
+        runtime::native_function::Args,
+    };
+
+    #[test]
+    fn argument_errors_name_the_method_in_full() {
+        let mut agent = JSAgent::default();
+        agent.initialize_realm();
+
+        let realm_addr = agent.current_realm();
+        let object = ordinary_object_create(&mut agent, None, None);
+
+        let method = define_builtin_method(
+            &mut agent,
+            &realm_addr,
+            &object,
+            "Array.prototype",
+            "slice",
+            2,
+            |_: &mut JSAgent, args: Args| args.get_f64(0).map(JSValue::from),
+        );
+
+        let symbol = JSValue::Symbol(String::from("tag").into());
+
+        let Err(error) = call(
+            &mut agent,
+            JSValue::from(method),
+            &JSValue::from(object),
+            Some(vec![symbol]),
+        ) else {
+            panic!("Expected the call to throw");
+        };
+
+        let error = error.into_value(&mut agent);
+
+        assert!(error.is_object());
+        assert_eq!(
+            to_string(&mut agent, error).unwrap(),
+            "TypeError: Array.prototype.slice: argument 1 must be a number, but got a symbol (Symbol(tag))"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::{
+            create_non_enumerable_data_property_or_throw, define_property_or_throw,
+        },
+        ordinary::{ordinary_create_from_constructor, ordinary_object_create},
+        type_conversion::to_string,
+    },
+    intrinsics::error_constructor::{install_error_cause, install_stack},
+    runtime::{
+        agent::JSAgent, completion::CompletionRecord, intrinsics::Intrinsics,
+        native_function::Args, realm::RealmAddr,
+    },
+    value::{
+        object::{
+            internal_slots::{ConstructorKind, InternalSlotName},
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// 20.5.5 Native Error Types Used in This Standard
+/// https://262.ecma-international.org/16.0/#sec-native-error-types-used-in-this-standard
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum NativeErrorKind {
+    Eval,
+    Range,
+    Reference,
+    Syntax,
+    Type,
+    Uri,
+}
+
+impl NativeErrorKind {
+    pub(crate) const ALL: [NativeErrorKind; 6] = [
+        NativeErrorKind::Eval,
+        NativeErrorKind::Range,
+        NativeErrorKind::Reference,
+        NativeErrorKind::Syntax,
+        NativeErrorKind::Type,
+        NativeErrorKind::Uri,
+    ];
+
+    /// The name of the constructor, which is used instead of NativeError.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            NativeErrorKind::Eval => "EvalError",
+            NativeErrorKind::Range => "RangeError",
+            NativeErrorKind::Reference => "ReferenceError",
+            NativeErrorKind::Syntax => "SyntaxError",
+            NativeErrorKind::Type => "TypeError",
+            NativeErrorKind::Uri => "URIError",
+        }
+    }
+
+    /// The kind of the NativeError constructor with the given name.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// The %NativeError.prototype% intrinsic of the kind.
+    pub(crate) fn prototype(self) -> fn(&Intrinsics) -> Option<ObjectAddr> {
+        match self {
+            NativeErrorKind::Eval => |intrinsics| intrinsics.eval_error_prototype.clone(),
+            NativeErrorKind::Range => |intrinsics| intrinsics.range_error_prototype.clone(),
+            NativeErrorKind::Reference => |intrinsics| intrinsics.reference_error_prototype.clone(),
+            NativeErrorKind::Syntax => |intrinsics| intrinsics.syntax_error_prototype.clone(),
+            NativeErrorKind::Type => |intrinsics| intrinsics.type_error_prototype.clone(),
+            NativeErrorKind::Uri => |intrinsics| intrinsics.uri_error_prototype.clone(),
+        }
+    }
+
+    /// Records the %NativeError% and %NativeError.prototype% intrinsics of the kind.
+    pub(crate) fn set_intrinsics(
+        self,
+        intrinsics: &mut Intrinsics,
+        constructor: ObjectAddr,
+        prototype: ObjectAddr,
+    ) {
+        let (constructor_slot, prototype_slot) = match self {
+            NativeErrorKind::Eval => (
+                &mut intrinsics.eval_error,
+                &mut intrinsics.eval_error_prototype,
+            ),
+            NativeErrorKind::Range => (
+                &mut intrinsics.range_error,
+                &mut intrinsics.range_error_prototype,
+            ),
+            NativeErrorKind::Reference => (
+                &mut intrinsics.reference_error,
+                &mut intrinsics.reference_error_prototype,
+            ),
+            NativeErrorKind::Syntax => (
+                &mut intrinsics.syntax_error,
+                &mut intrinsics.syntax_error_prototype,
+            ),
+            NativeErrorKind::Type => (
+                &mut intrinsics.type_error,
+                &mut intrinsics.type_error_prototype,
+            ),
+            NativeErrorKind::Uri => (
+                &mut intrinsics.uri_error,
+                &mut intrinsics.uri_error_prototype,
+            ),
+        };
+
+        *constructor_slot = Some(constructor);
+        *prototype_slot = Some(prototype);
+    }
+}
+
+/// 20.5.6 NativeError Object Structure
+/// https://262.ecma-international.org/16.0/#sec-nativeerror-object-structure
+#[derive(Debug)]
+pub(crate) struct NativeErrorConstructor;
+
+impl NativeErrorConstructor {
+    pub(crate) fn create(
+        agent: &mut JSAgent,
+        realm_addr: RealmAddr,
+        kind: NativeErrorKind,
+        prototype: &ObjectAddr,
+    ) -> ObjectAddr {
+        // 20.5.6.2 Properties of the NativeError Constructors
+        // has a [[Prototype]] internal slot whose value is %Error%.
+        // has a "name" property whose value is the String value "NativeError".
+        // has a "length" property whose value is 1𝔽.
+        let error = realm_addr.borrow().intrinsics.error.clone();
+
+        let constructor = create_builtin_function(
+            agent,
+            move |agent: &mut JSAgent, args: Args| Self::native_error(agent, args, kind),
+            1,
+            JSObjectPropKey::String(kind.name().into()),
+            vec![InternalSlotName::ConstructorKind],
+            Some(realm_addr),
+            error,
+            None,
+        );
+
+        // may be used as the value of an extends clause of a class definition.
+        constructor
+            .data_mut()
+            .slots_mut()
+            .set_constructor_kind(ConstructorKind::Base);
+
+        // 20.5.6.2.1 NativeError.prototype
+        // The initial value of NativeError.prototype is a NativeError prototype object. Each NativeError constructor has a distinct prototype object.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        let _ = define_property_or_throw(
+            &constructor,
+            &JSObjectPropKey::String("prototype".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 20.5.6.3.1 NativeError.prototype.constructor
+        // The initial value of the "constructor" property of the prototype for a given NativeError constructor is the constructor itself.
+        let _ = define_property_or_throw(
+            prototype,
+            &JSObjectPropKey::String("constructor".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(constructor.clone())),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        constructor
+    }
+
+    /// 20.5.6.1.1 NativeError ( message [ , options ] )
+    /// https://262.ecma-international.org/16.0/#sec-nativeerror
+    fn native_error(
+        agent: &mut JSAgent,
+        args: Args,
+        kind: NativeErrorKind,
+    ) -> CompletionRecord<JSValue> {
+        let message = args.get(0);
+        let options = args.get(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        let new_target = match args.new_target() {
+            Some(new_target) => new_target.clone(),
+            None => agent.running_execution_context().function.clone().unwrap(),
+        };
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%NativeError.prototype%", « [[ErrorData]] »).
+        let o = ordinary_create_from_constructor(
+            agent,
+            &new_target,
+            kind.prototype(),
+            Some(vec![InternalSlotName::ErrorData]),
+        )?;
+
+        // 3. If message is not undefined, then
+        if !message.is_undefined() {
+            // a. Let msg be ? ToString(message).
+            let msg = to_string(agent, message.clone())?;
+
+            // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
+            create_non_enumerable_data_property_or_throw(
+                &o,
+                &JSObjectPropKey::String("message".into()),
+                JSValue::String(msg),
+            );
+        }
+
+        // 4. Perform ? InstallErrorCause(O, options).
+        install_error_cause(agent, &o, options)?;
+
+        install_stack(agent, &o)?;
+
+        // 5. Return O.
+        Ok(JSValue::from(o))
+    }
+}
+
+/// Creates the error object of an error raised by the engine, from the NativeError prototype of the current realm, as `new TypeError(message)` would.
+///
+/// The error is only described by a String if it can't be created, because there is no realm yet or evaluation has run out of memory.
+pub(crate) fn create_native_error(
+    agent: &mut JSAgent,
+    kind: NativeErrorKind,
+    message: &str,
+) -> JSValue {
+    let prototype = if agent.execution_contexts.is_empty() || agent.abort_error().is_some() {
+        None
+    } else {
+        (kind.prototype())(&agent.current_realm().borrow().intrinsics)
+    };
+
+    let Some(prototype) = prototype else {
+        return JSValue::from(format!("{}: {message}", kind.name()));
+    };
+
+    let o = ordinary_object_create(
+        agent,
+        Some(prototype),
+        Some(vec![InternalSlotName::ErrorData]),
+    );
+
+    create_non_enumerable_data_property_or_throw(
+        &o,
+        &JSObjectPropKey::String("message".into()),
+        JSValue::from(message),
+    );
+
+    // NOTE: A script can replace the name of the prototype with a getter which throws, in which case the error is raised without a stack.
+    let _ = install_stack(agent, &o);
+
+    JSValue::from(o)
+}
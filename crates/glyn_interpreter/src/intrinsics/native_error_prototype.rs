@@ -0,0 +1,54 @@
+use crate::{
+    abstract_ops::{object_operations::define_property_or_throw, ordinary::ordinary_object_create},
+    intrinsics::native_error_constructor::NativeErrorKind,
+    runtime::{agent::JSAgent, realm::RealmAddr},
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        JSValue,
+    },
+};
+
+/// 20.5.6.3 Properties of the NativeError Prototype Objects
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-nativeerror-prototype-objects
+#[derive(Debug)]
+pub(crate) struct NativeErrorPrototype;
+
+impl NativeErrorPrototype {
+    pub(crate) fn create(
+        agent: &mut JSAgent,
+        realm_addr: RealmAddr,
+        kind: NativeErrorKind,
+    ) -> ObjectAddr {
+        // is an ordinary object.
+        // is not an Error instance and does not have an [[ErrorData]] internal slot.
+        // has a [[Prototype]] internal slot whose value is %Error.prototype%.
+        let prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.error_prototype.clone(),
+            None,
+        );
+
+        // 20.5.6.3.2 NativeError.prototype.message
+        // The initial value of the "message" property of the prototype for a given NativeError constructor is the empty String.
+        // 20.5.6.3.3 NativeError.prototype.name
+        // The initial value of the "name" property of the prototype for a given NativeError constructor is the String value consisting of the name of the constructor (the name used instead of NativeError).
+        for (name, value) in [("message", ""), ("name", kind.name())] {
+            let _ = define_property_or_throw(
+                &prototype,
+                &JSObjectPropKey::String(name.into()),
+                JSObjectPropDescriptor {
+                    value: Some(JSValue::from(value)),
+                    writable: Some(true),
+                    enumerable: Some(false),
+                    configurable: Some(true),
+                    ..JSObjectPropDescriptor::default()
+                },
+            );
+        }
+
+        prototype
+    }
+}
@@ -0,0 +1,469 @@
+use crate::{
+    abstract_ops::{ordinary::ordinary_object_create, type_conversion::to_integer_or_infinity},
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{range_error, type_error_for, JSAgent},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        number::JSNumber,
+        object::{internal_slots::InternalSlotName, ObjectAddr, ObjectMeta},
+        JSValue,
+    },
+};
+
+/// 21.1.3 Properties of the Number Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-number-prototype-object
+#[derive(Debug)]
+pub(crate) struct NumberPrototype;
+
+impl NumberPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Number.prototype%.
+        // is itself a Number object; it has a [[NumberData]] internal slot with the value +0𝔽.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        let number_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            Some(vec![InternalSlotName::NumberData]),
+        );
+
+        number_prototype
+            .data_mut()
+            .slots_mut()
+            .set_number_data(JSNumber::POS_ZERO);
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &number_prototype,
+            "Number.prototype",
+            "toExponential",
+            1,
+            Self::to_exponential,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &number_prototype,
+            "Number.prototype",
+            "toFixed",
+            1,
+            Self::to_fixed,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &number_prototype,
+            "Number.prototype",
+            "toPrecision",
+            1,
+            Self::to_precision,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &number_prototype,
+            "Number.prototype",
+            "toString",
+            1,
+            Self::to_string,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &number_prototype,
+            "Number.prototype",
+            "valueOf",
+            0,
+            Self::value_of,
+        );
+
+        number_prototype
+    }
+
+    /// 21.1.3.2 Number.prototype.toExponential ( fractionDigits )
+    /// https://262.ecma-international.org/16.0/#sec-number.prototype.toexponential
+    fn to_exponential(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let fraction_digits = args.get(0);
+
+        // 1. Let x be ? ThisNumberValue(this value).
+        let x = this_number_value(&args)?;
+
+        // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
+        // 3. Assert: If fractionDigits is undefined, then f is 0.
+        let f = to_integer_or_infinity(agent, fraction_digits.clone())?.0;
+
+        // 4. If x is not finite, return Number::toString(x, 10).
+        if !x.0.is_finite() {
+            return Ok(JSValue::from(x.to_string(10)));
+        }
+
+        // 5. If f < 0 or f > 100, throw a RangeError exception.
+        if !(0.0..=100.0).contains(&f) {
+            return range_error("toExponential() argument must be between 0 and 100");
+        }
+
+        let f = f as usize;
+
+        // 6. Set x to ℝ(x).
+        // 7. Let s be the empty String.
+        // 8. If x < 0, then
+        // a. Set s to "-".
+        // b. Set x to -x.
+        let (s, x) = sign_and_magnitude(x.0);
+
+        // 9. If x = 0, then
+        // a. Let m be the String value consisting of f + 1 occurrences of the code unit 0x0030 (DIGIT ZERO).
+        // b. Let e be 0.
+        let (mut m, e) = if x == 0.0 {
+            ("0".repeat(f + 1), 0)
+        }
+        // 10. Else,
+        // a. If fractionDigits is not undefined, then
+        // i. Let e and n be integers such that 10**f ≤ n < 10**(f + 1) and for which n × 10**(e - f) - x is as close to zero as possible. If there are two such sets of e and n, pick the e and n for which n × 10**(e - f) is larger.
+        else if !fraction_digits.is_undefined() {
+            let (digits, e) = exact_digits(x);
+
+            round_to_significant_digits(&digits, e, f + 1)
+        }
+        // b. Else,
+        // i. Let e, n, and f be integers such that f ≥ 0, 10**f ≤ n < 10**(f + 1), 𝔽(n × 10**(e - f)) is 𝔽(x), and f is as small as possible. Note that the decimal representation of n has f + 1 digits, n is not divisible by 10, and the least significant digit of n is not necessarily uniquely determined by these criteria.
+        // NOTE: Rust formats the shortest digits which round trip to the same value in scientific notation, as Number::toString does.
+        else {
+            let scientific = format!("{x:e}");
+            let (mantissa, exponent) = scientific.split_once('e').unwrap();
+
+            (mantissa.replace('.', ""), exponent.parse::<i32>().unwrap())
+        };
+
+        // c. Let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+        // 11. If f ≠ 0, then
+        // a. Let a be the first code unit of m.
+        // b. Let b be the remaining f code units of m.
+        // c. Set m to the string-concatenation of a, ".", and b.
+        if m.len() > 1 {
+            m.insert(1, '.');
+        }
+
+        // 12. If e = 0, then
+        // a. Let c be "+".
+        // b. Let d be "0".
+        // 13. Else,
+        // a. If e > 0, then
+        // i. Let c be "+".
+        // b. Else,
+        // i. Assert: e < 0.
+        // ii. Let c be "-".
+        // iii. Set e to -e.
+        // c. Let d be the String value consisting of the digits of the decimal representation of e (in order, with no leading zeroes).
+        let c = if e < 0 { '-' } else { '+' };
+        let d = e.abs();
+
+        // 14. Set m to the string-concatenation of m, "e", c, and d.
+        // 15. Return the string-concatenation of s and m.
+        Ok(JSValue::from(format!("{s}{m}e{c}{d}")))
+    }
+
+    /// 21.1.3.3 Number.prototype.toFixed ( fractionDigits )
+    /// https://262.ecma-international.org/16.0/#sec-number.prototype.tofixed
+    fn to_fixed(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let x be ? ThisNumberValue(this value).
+        let x = this_number_value(&args)?;
+
+        // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
+        // 3. Assert: If fractionDigits is undefined, then f is 0.
+        let f = to_integer_or_infinity(agent, args.get(0).clone())?.0;
+
+        // 4. If f is not finite, throw a RangeError exception.
+        // 5. If f < 0 or f > 100, throw a RangeError exception.
+        if !(0.0..=100.0).contains(&f) {
+            return range_error("toFixed() digits argument must be between 0 and 100");
+        }
+
+        let f = f as usize;
+
+        // 6. If x is not finite, return Number::toString(x, 10).
+        if !x.0.is_finite() {
+            return Ok(JSValue::from(x.to_string(10)));
+        }
+
+        // 7. Set x to ℝ(x).
+        // 8. Let s be the empty String.
+        // 9. If x < 0, then
+        // a. Set s to "-".
+        // b. Set x to -x.
+        let (s, x) = sign_and_magnitude(x.0);
+
+        // 10. If x ≥ 10**21, then
+        // a. Let m be ! ToString(𝔽(x)).
+        if x >= 1e21 {
+            return Ok(JSValue::from(format!(
+                "{s}{}",
+                JSNumber::from(x).to_string(10)
+            )));
+        }
+
+        // 11. Else,
+        // a. Let n be an integer for which n / 10**f - x is as close to zero as possible. If there are two such n, pick the larger n.
+        // NOTE: n has as many digits as x has before the decimal point, plus f digits after it.
+        let (digits, e) = exact_digits(x);
+
+        let count = e + 1 + f as i32;
+
+        let n = if count < 0 {
+            vec![]
+        } else {
+            round_half_up(&digits, count as usize)
+        };
+
+        // b. If n = 0, let m be "0". Otherwise, let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+        let mut m: String = n
+            .iter()
+            .skip_while(|digit| **digit == 0)
+            .map(|digit| char::from(b'0' + digit))
+            .collect();
+
+        if m.is_empty() {
+            m = String::from("0");
+        }
+
+        // c. If f ≠ 0, then
+        if f != 0 {
+            // i. Let k be the length of m.
+            // ii. If k ≤ f, then
+            if m.len() <= f {
+                // 1. Let z be the String value consisting of f + 1 - k occurrences of the code unit 0x0030 (DIGIT ZERO).
+                // 2. Set m to the string-concatenation of z and m.
+                // 3. Set k to f + 1.
+                m = format!("{}{m}", "0".repeat(f + 1 - m.len()));
+            }
+
+            // iii. Let a be the first k - f code units of m.
+            // iv. Let b be the other f code units of m.
+            // v. Set m to the string-concatenation of a, ".", and b.
+            m.insert(m.len() - f, '.');
+        }
+
+        // 12. Return the string-concatenation of s and m.
+        Ok(JSValue::from(format!("{s}{m}")))
+    }
+
+    /// 21.1.3.5 Number.prototype.toPrecision ( precision )
+    /// https://262.ecma-international.org/16.0/#sec-number.prototype.toprecision
+    fn to_precision(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let precision = args.get(0);
+
+        // 1. Let x be ? ThisNumberValue(this value).
+        let x = this_number_value(&args)?;
+
+        // 2. If precision is undefined, return ! ToString(x).
+        if precision.is_undefined() {
+            return Ok(JSValue::from(x.to_string(10)));
+        }
+
+        // 3. Let p be ? ToIntegerOrInfinity(precision).
+        let p = to_integer_or_infinity(agent, precision.clone())?.0;
+
+        // 4. If x is not finite, return Number::toString(x, 10).
+        if !x.0.is_finite() {
+            return Ok(JSValue::from(x.to_string(10)));
+        }
+
+        // 5. If p < 1 or p > 100, throw a RangeError exception.
+        if !(1.0..=100.0).contains(&p) {
+            return range_error("toPrecision() argument must be between 1 and 100");
+        }
+
+        let p = p as usize;
+
+        // 6. Set x to ℝ(x).
+        // 7. Let s be the empty String.
+        // 8. If x < 0, then
+        // a. Set s to the code unit 0x002D (HYPHEN-MINUS).
+        // b. Set x to -x.
+        let (s, x) = sign_and_magnitude(x.0);
+
+        // 9. If x = 0, then
+        // a. Let m be the String value consisting of p occurrences of the code unit 0x0030 (DIGIT ZERO).
+        // b. Let e be 0.
+        let (mut m, e) = if x == 0.0 {
+            ("0".repeat(p), 0)
+        }
+        // 10. Else,
+        // a. Let e and n be integers such that 10**(p - 1) ≤ n < 10**p and for which n × 10**(e - p + 1) - x is as close to zero as possible. If there are two such sets of e and n, pick the e and n for which n × 10**(e - p + 1) is larger.
+        // b. Let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+        else {
+            let (digits, e) = exact_digits(x);
+
+            round_to_significant_digits(&digits, e, p)
+        };
+
+        // c. If e < -6 or e ≥ p, then
+        if e < -6 || e >= p as i32 {
+            // i. Assert: e ≠ 0.
+            // ii. If p ≠ 1, then
+            // 1. Let a be the first code unit of m.
+            // 2. Let b be the remaining p - 1 code units of m.
+            // 3. Set m to the string-concatenation of a, ".", and b.
+            if p != 1 {
+                m.insert(1, '.');
+            }
+
+            // iii. If e > 0, then
+            // 1. Let c be the code unit 0x002B (PLUS SIGN).
+            // iv. Else,
+            // 1. Assert: e < 0.
+            // 2. Let c be the code unit 0x002D (HYPHEN-MINUS).
+            // 3. Set e to -e.
+            // v. Let d be the String value consisting of the digits of the decimal representation of e (in order, with no leading zeroes).
+            let c = if e > 0 { '+' } else { '-' };
+            let d = e.abs();
+
+            // vi. Return the string-concatenation of s, m, the code unit 0x0065 (LATIN SMALL LETTER E), c, and d.
+            return Ok(JSValue::from(format!("{s}{m}e{c}{d}")));
+        }
+
+        // 11. If e = p - 1, return the string-concatenation of s and m.
+        if e == p as i32 - 1 {
+            return Ok(JSValue::from(format!("{s}{m}")));
+        }
+
+        // 12. If e ≥ 0, then
+        if e >= 0 {
+            // a. Set m to the string-concatenation of the first e + 1 code units of m, the code unit 0x002E (FULL STOP), and the remaining p - (e + 1) code units of m.
+            m.insert(e as usize + 1, '.');
+        }
+        // 13. Else,
+        else {
+            // a. Set m to the string-concatenation of the code unit 0x0030 (DIGIT ZERO), the code unit 0x002E (FULL STOP), -(e + 1) occurrences of the code unit 0x0030 (DIGIT ZERO), and the String m.
+            m = format!("0.{}{m}", "0".repeat(-(e + 1) as usize));
+        }
+
+        // 14. Return the string-concatenation of s and m.
+        Ok(JSValue::from(format!("{s}{m}")))
+    }
+
+    /// 21.1.3.6 Number.prototype.toString ( [ radix ] )
+    /// https://262.ecma-international.org/16.0/#sec-number.prototype.tostring
+    fn to_string(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let x be ? ThisNumberValue(this value).
+        let x = this_number_value(&args)?;
+
+        let radix_mv = match args.get(0) {
+            // 2. If radix is undefined, let radixMV be 10.
+            JSValue::Undefined => 10.0,
+            // 3. Else, let radixMV be ? ToIntegerOrInfinity(radix).
+            radix => to_integer_or_infinity(agent, radix.clone())?.0,
+        };
+
+        // 4. If radixMV is not in the inclusive interval from 2 to 36, throw a RangeError exception.
+        if !(2.0..=36.0).contains(&radix_mv) {
+            return range_error("toString() radix must be between 2 and 36");
+        }
+
+        // 5. Return Number::toString(x, radixMV).
+        Ok(JSValue::from(x.to_string(radix_mv as u32)))
+    }
+
+    /// 21.1.3.7 Number.prototype.valueOf ( )
+    /// https://262.ecma-international.org/16.0/#sec-number.prototype.valueof
+    fn value_of(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? ThisNumberValue(this value).
+        Ok(JSValue::Number(this_number_value(&args)?))
+    }
+}
+
+/// 21.1.3.7.1 ThisNumberValue ( value )
+/// https://262.ecma-international.org/16.0/#sec-thisnumbervalue
+fn this_number_value(args: &Args) -> CompletionRecord<JSNumber> {
+    let number = match args.this() {
+        // 1. If value is a Number, return value.
+        JSValue::Number(number) => Some(number.clone()),
+        // 2. If value is an Object and value has a [[NumberData]] internal slot, then
+        // a. Let n be value.[[NumberData]].
+        // b. Assert: n is a Number.
+        // c. Return n.
+        JSValue::Object(object) => object.data().slots().number_data(),
+        _ => None,
+    };
+
+    match number {
+        Some(number) => Ok(number),
+        // 3. Throw a TypeError exception.
+        None => type_error_for(
+            &format!(
+                "{} called on an incompatible receiver",
+                args.function_name()
+            ),
+            args.this(),
+        ),
+    }
+}
+
+/// The sign of the number as the prefix of its string, and its magnitude, where -0 has no sign.
+fn sign_and_magnitude(x: f64) -> (&'static str, f64) {
+    if x < 0.0 {
+        ("-", -x)
+    } else {
+        ("", x)
+    }
+}
+
+/// The digits of the exact decimal expansion of a positive finite number, and the exponent of its most significant digit.
+fn exact_digits(x: f64) -> (Vec<u8>, i32) {
+    // NOTE: Rust formats the exact value of a number when it is given enough precision, and the expansion of a double has at most 767 significant digits.
+    let scientific = format!("{x:.800e}");
+
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+
+    let digits = mantissa
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|digit| digit - b'0')
+        .collect();
+
+    (digits, exponent.parse().unwrap())
+}
+
+/// The most significant digits of an exact decimal expansion, with the last one rounded half up, which picks the larger of two integers which are as close to the number as each other.
+///
+/// A carry out of the most significant digit adds a digit to the front, so there can be one more digit than the count.
+fn round_half_up(digits: &[u8], count: usize) -> Vec<u8> {
+    let mut n: Vec<u8> = (0..count)
+        .map(|index| digits.get(index).copied().unwrap_or(0))
+        .collect();
+
+    if digits.get(count).is_some_and(|digit| *digit >= 5) {
+        let carried = n.iter_mut().rev().all(|digit| {
+            *digit = (*digit + 1) % 10;
+
+            *digit == 0
+        });
+
+        if carried {
+            n.insert(0, 1);
+        }
+    }
+
+    n
+}
+
+/// Rounds an exact decimal expansion half up to the count of significant digits, returning the digits and the exponent of the most significant one, which is one more than before when rounding carries into a new digit.
+fn round_to_significant_digits(digits: &[u8], e: i32, count: usize) -> (String, i32) {
+    let mut n = round_half_up(digits, count);
+
+    let e = if n.len() > count {
+        n.truncate(count);
+
+        e + 1
+    } else {
+        e
+    };
+
+    let m = n.iter().map(|digit| char::from(b'0' + digit)).collect();
+
+    (m, e)
+}
@@ -0,0 +1,200 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::define_property_or_throw,
+        ordinary::{ordinary_create_from_constructor, ordinary_object_create},
+        testing_comparison::same_value,
+        type_conversion::{to_object, to_property_key},
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            internal_slots::{ConstructorKind, InternalSlotName},
+            property::{
+                from_property_descriptor, to_property_descriptor, JSObjectPropDescriptor,
+                JSObjectPropKey,
+            },
+            ObjectAddr, ObjectEssentialInternalMethods, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// 20.1.1 The Object Constructor
+/// https://262.ecma-international.org/16.0/#sec-object-constructor
+#[derive(Debug)]
+pub(crate) struct ObjectConstructor;
+
+impl ObjectConstructor {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Object%.
+        // is the initial value of the "Object" property of the global object.
+        // has a [[Prototype]] internal slot whose value is %Function.prototype%.
+        // has a "length" property whose value is 1𝔽.
+        let object = create_builtin_function(
+            agent,
+            Self::object,
+            1,
+            JSObjectPropKey::String("Object".into()),
+            vec![InternalSlotName::ConstructorKind],
+            Some(realm_addr.clone()),
+            None,
+            None,
+        );
+
+        // may be used as the value of an extends clause of a class definition.
+        object
+            .data_mut()
+            .slots_mut()
+            .set_constructor_kind(ConstructorKind::Base);
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &object,
+            "Object",
+            "defineProperty",
+            3,
+            Self::define_property,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &object,
+            "Object",
+            "getOwnPropertyDescriptor",
+            2,
+            Self::get_own_property_descriptor,
+        );
+        define_builtin_method(agent, &realm_addr, &object, "Object", "is", 2, Self::is);
+
+        // 20.1.2.21 Object.prototype
+        // The initial value of Object.prototype is the Object prototype object.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        let object_prototype = realm_addr
+            .borrow()
+            .intrinsics
+            .object_prototype
+            .clone()
+            .unwrap();
+
+        let _ = define_property_or_throw(
+            &object,
+            &JSObjectPropKey::String("prototype".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(object_prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 20.1.3.1 Object.prototype.constructor
+        // The initial value of Object.prototype.constructor is %Object%.
+        let _ = define_property_or_throw(
+            &object_prototype,
+            &JSObjectPropKey::String("constructor".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(object.clone())),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        object
+    }
+
+    /// 20.1.1.1 Object ( [ value ] )
+    /// https://262.ecma-international.org/16.0/#sec-object-value
+    fn object(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let value = args.get(0);
+
+        // 1. If NewTarget is neither undefined nor the active function object, then
+        let active_function = agent.current_realm().borrow().intrinsics.object.clone();
+
+        if let Some(new_target) = args.new_target() {
+            if active_function.as_ref() != Some(new_target) {
+                // a. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Object.prototype%").
+                let object = ordinary_create_from_constructor(
+                    agent,
+                    new_target,
+                    |intrinsics| intrinsics.object_prototype.clone(),
+                    None,
+                )?;
+
+                return Ok(JSValue::from(object));
+            }
+        }
+
+        // 2. If value is either undefined or null, return OrdinaryObjectCreate(%Object.prototype%).
+        if value.is_undefined() || value.is_null() {
+            let object_prototype = agent
+                .current_realm()
+                .borrow()
+                .intrinsics
+                .object_prototype
+                .clone();
+
+            return Ok(JSValue::from(ordinary_object_create(
+                agent,
+                object_prototype,
+                None,
+            )));
+        }
+
+        // 3. Return ! ToObject(value).
+        Ok(JSValue::from(to_object(agent, value)?))
+    }
+
+    /// 20.1.2.4 Object.defineProperty ( O, P, Attributes )
+    /// https://262.ecma-international.org/16.0/#sec-object.defineproperty
+    fn define_property(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. If O is not an Object, throw a TypeError exception.
+        let Ok(o) = ObjectAddr::try_from(args.get(0)) else {
+            return type_error("Object.defineProperty called on non-object");
+        };
+
+        // 2. Let key be ? ToPropertyKey(P).
+        let key = to_property_key(agent, args.get(1).clone())?;
+
+        // 3. Let desc be ? ToPropertyDescriptor(Attributes).
+        let desc = to_property_descriptor(agent, args.get(2))?;
+
+        // 4. Perform ? DefinePropertyOrThrow(O, key, desc).
+        define_property_or_throw(&o, &key, desc)?;
+
+        // 5. Return O.
+        Ok(JSValue::from(o))
+    }
+
+    /// 20.1.2.8 Object.getOwnPropertyDescriptor ( O, P )
+    /// https://262.ecma-international.org/16.0/#sec-object.getownpropertydescriptor
+    fn get_own_property_descriptor(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let obj be ? ToObject(O).
+        let obj = to_object(agent, args.get(0))?;
+
+        // 2. Let key be ? ToPropertyKey(P).
+        let key = to_property_key(agent, args.get(1).clone())?;
+
+        // 3. Let desc be ? obj.[[GetOwnProperty]](key).
+        let desc = obj.get_own_property(&key)?;
+
+        // 4. Return FromPropertyDescriptor(desc).
+        Ok(from_property_descriptor(agent, desc))
+    }
+    /// 20.1.2.15 Object.is ( value1, value2 )
+    /// https://262.ecma-international.org/16.0/#sec-object.is
+    fn is(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return SameValue(value1, value2).
+        Ok(JSValue::from(same_value(args.get(0), args.get(1))))
+    }
+}
@@ -1,6 +1,33 @@
 use crate::{
+    abstract_ops::{
+        object_operations::get, testing_comparison::is_array, type_conversion::to_object,
+    },
     gc::Gc,
-    value::object::{internal_slots::InternalSlots, ObjectAddr, ObjectData, ObjectKind},
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            internal_slots::{InternalSlotName, InternalSlots},
+            property::JSObjectPropKey,
+            ObjectAddr, ObjectData, ObjectKind, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+#[cfg(feature = "annex-b")]
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function, object_operations::define_property_or_throw,
+        testing_comparison::require_object_coercible, type_conversion::to_property_key,
+    },
+    runtime::agent::type_error,
+    value::object::{property::JSObjectPropDescriptor, ObjectEssentialInternalMethods},
 };
 
 /// 20.1.3 Properties of the Object Prototype Object
@@ -9,14 +36,376 @@ use crate::{
 pub(crate) struct JSObjectPrototype;
 
 impl JSObjectPrototype {
-    pub(crate) fn create() -> ObjectAddr {
+    pub(crate) fn create(agent: &mut JSAgent) -> ObjectAddr {
         // is %Object.prototype%.
         // has an [[Extensible]] internal slot whose value is true.
         // has the internal methods defined for ordinary objects, except for the [[SetPrototypeOf]] method, which is as defined in 10.4.7.1. (Thus, it is an immutable prototype exotic object.)
         // has a [[Prototype]] internal slot whose value is null.
-        Gc::new(ObjectData::new(
+        let object = ObjectData::new(
+            agent,
             ObjectKind::ImmutablePrototype,
             InternalSlots::default(),
-        ))
+        );
+
+        ObjectAddr::new(Gc::new(&mut agent.heap, object))
+    }
+
+    /// Defines the methods of %Object.prototype%, which can only be created once %Function.prototype% exists, as built-in functions have it as their prototype.
+    pub(crate) fn define_methods(agent: &mut JSAgent, realm_addr: RealmAddr) {
+        let object_prototype = realm_addr
+            .borrow()
+            .intrinsics
+            .object_prototype
+            .clone()
+            .unwrap();
+
+        let to_string = define_builtin_method(
+            agent,
+            &realm_addr,
+            &object_prototype,
+            "Object.prototype",
+            "toString",
+            0,
+            Self::to_string,
+        );
+
+        let value_of = define_builtin_method(
+            agent,
+            &realm_addr,
+            &object_prototype,
+            "Object.prototype",
+            "valueOf",
+            0,
+            Self::value_of,
+        );
+
+        #[cfg(feature = "annex-b")]
+        if agent.annex_b() {
+            Self::define_legacy_methods(agent, &realm_addr, &object_prototype);
+        }
+
+        let mut realm = realm_addr.borrow_mut();
+
+        realm.intrinsics.object_prototype_to_string = Some(to_string);
+        realm.intrinsics.object_prototype_value_of = Some(value_of);
+    }
+
+    /// 20.1.3.6 Object.prototype.toString ( )
+    /// https://262.ecma-international.org/16.0/#sec-object.prototype.tostring
+    fn to_string(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        let this_value = args.this();
+
+        // 1. If the this value is undefined, return "[object Undefined]".
+        if this_value.is_undefined() {
+            return Ok(JSValue::from("[object Undefined]"));
+        }
+
+        // 2. If the this value is null, return "[object Null]".
+        if this_value.is_null() {
+            return Ok(JSValue::from("[object Null]"));
+        }
+
+        // 3. Let O be ! ToObject(this value).
+        let o = to_object(agent, this_value)?;
+
+        // 4. Let isArray be ? IsArray(O).
+        let is_array = is_array(&JSValue::from(o.clone()));
+
+        let is_callable = o.is_callable();
+
+        let builtin_tag = {
+            let data = o.data();
+            let slots = data.slots();
+
+            // 5. If isArray is true, let builtinTag be "Array".
+            if is_array {
+                "Array"
+            }
+            // 6. Else if O has a [[ParameterMap]] internal slot, let builtinTag be "Arguments".
+            else if slots.contains(&InternalSlotName::ParameterMap) {
+                "Arguments"
+            }
+            // 7. Else if O has a [[Call]] internal method, let builtinTag be "Function".
+            else if is_callable {
+                "Function"
+            }
+            // 8. Else if O has an [[ErrorData]] internal slot, let builtinTag be "Error".
+            else if slots.contains(&InternalSlotName::ErrorData) {
+                "Error"
+            }
+            // 9. Else if O has a [[BooleanData]] internal slot, let builtinTag be "Boolean".
+            else if slots.contains(&InternalSlotName::BooleanData) {
+                "Boolean"
+            }
+            // 10. Else if O has a [[NumberData]] internal slot, let builtinTag be "Number".
+            else if slots.contains(&InternalSlotName::NumberData) {
+                "Number"
+            }
+            // 11. Else if O has a [[StringData]] internal slot, let builtinTag be "String".
+            else if slots.contains(&InternalSlotName::StringData) {
+                "String"
+            }
+            // 12. Else if O has a [[DateValue]] internal slot, let builtinTag be "Date".
+            else if slots.contains(&InternalSlotName::DateValue) {
+                "Date"
+            }
+            // 13. Else if O has a [[RegExpMatcher]] internal slot, let builtinTag be "RegExp".
+            // NOTE: RegExp objects are not implemented yet.
+            // 14. Else, let builtinTag be "Object".
+            else {
+                "Object"
+            }
+        };
+
+        // 15. Let tag be ? Get(O, %Symbol.toStringTag%).
+        let tag = get(
+            agent,
+            &o,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+        )?;
+
+        // 16. If tag is not a String, set tag to builtinTag.
+        let tag = match tag {
+            JSValue::String(tag) => tag.to_string(),
+            _ => builtin_tag.to_string(),
+        };
+
+        // 17. Return the string-concatenation of "[object ", tag, and "]".
+        Ok(JSValue::from(format!("[object {tag}]")))
+    }
+
+    /// 20.1.3.7 Object.prototype.valueOf ( )
+    /// https://262.ecma-international.org/16.0/#sec-object.prototype.valueof
+    fn value_of(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? ToObject(this value).
+        Ok(JSValue::from(to_object(agent, args.this())?))
+    }
+}
+
+/// B.2.2 Additional Properties of the Object.prototype Object
+/// https://262.ecma-international.org/16.0/#sec-additional-properties-of-the-object.prototype-object
+#[cfg(feature = "annex-b")]
+impl JSObjectPrototype {
+    fn define_legacy_methods(
+        agent: &mut JSAgent,
+        realm_addr: &RealmAddr,
+        object_prototype: &ObjectAddr,
+    ) {
+        define_builtin_method(
+            agent,
+            realm_addr,
+            object_prototype,
+            "Object.prototype",
+            "__defineGetter__",
+            2,
+            Self::define_getter,
+        );
+        define_builtin_method(
+            agent,
+            realm_addr,
+            object_prototype,
+            "Object.prototype",
+            "__defineSetter__",
+            2,
+            Self::define_setter,
+        );
+        define_builtin_method(
+            agent,
+            realm_addr,
+            object_prototype,
+            "Object.prototype",
+            "__lookupGetter__",
+            1,
+            Self::lookup_getter,
+        );
+        define_builtin_method(
+            agent,
+            realm_addr,
+            object_prototype,
+            "Object.prototype",
+            "__lookupSetter__",
+            1,
+            Self::lookup_setter,
+        );
+
+        // B.2.2.1 Object.prototype.__proto__
+        // Object.prototype.__proto__ is an accessor property with attributes { [[Enumerable]]: false, [[Configurable]]: true }.
+        let proto_key = JSObjectPropKey::String("__proto__".into());
+
+        let getter = create_builtin_function(
+            agent,
+            Self::get_proto,
+            0,
+            proto_key.clone(),
+            vec![],
+            Some(realm_addr.clone()),
+            None,
+            Some("get".to_string()),
+        );
+
+        let setter = create_builtin_function(
+            agent,
+            Self::set_proto,
+            1,
+            proto_key.clone(),
+            vec![],
+            Some(realm_addr.clone()),
+            None,
+            Some("set".to_string()),
+        );
+
+        let _ = define_property_or_throw(
+            object_prototype,
+            &proto_key,
+            JSObjectPropDescriptor {
+                get: Some(JSValue::from(getter)),
+                set: Some(JSValue::from(setter)),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+    }
+
+    /// B.2.2.1.1 get Object.prototype.__proto__
+    /// https://262.ecma-international.org/16.0/#sec-get-object.prototype.__proto__
+    fn get_proto(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. Return ? O.[[GetPrototypeOf]]().
+        Ok(o.get_prototype_of().map_or(JSValue::Null, JSValue::from))
+    }
+
+    /// B.2.2.1.2 set Object.prototype.__proto__
+    /// https://262.ecma-international.org/16.0/#sec-set-object.prototype.__proto__
+    fn set_proto(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = require_object_coercible(args.this().clone())?;
+
+        // 2. If proto is not an Object and proto is not null, return undefined.
+        let proto = match args.get(0) {
+            JSValue::Object(proto) => Some(proto.clone()),
+            JSValue::Null => None,
+            _ => return Ok(JSValue::Undefined),
+        };
+
+        // 3. If O is not an Object, return undefined.
+        let JSValue::Object(o) = o else {
+            return Ok(JSValue::Undefined);
+        };
+
+        // 4. Let status be ? O.[[SetPrototypeOf]](proto).
+        let status = o.set_prototype_of(proto);
+
+        // 5. If status is false, throw a TypeError exception.
+        if !status {
+            return type_error("Object.prototype.__proto__: cannot set the prototype");
+        }
+
+        // 6. Return undefined.
+        Ok(JSValue::Undefined)
+    }
+
+    /// B.2.2.2 Object.prototype.__defineGetter__ ( P, getter )
+    /// https://262.ecma-international.org/16.0/#sec-object.prototype.__defineGetter__
+    fn define_getter(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. If IsCallable(getter) is false, throw a TypeError exception.
+        let getter = args.get_function(1)?;
+
+        // 3. Let desc be PropertyDescriptor { [[Get]]: getter, [[Enumerable]]: true, [[Configurable]]: true }.
+        let desc = JSObjectPropDescriptor {
+            get: Some(getter.clone()),
+            enumerable: Some(true),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        };
+
+        // 4. Let key be ? ToPropertyKey(P).
+        let key = to_property_key(agent, args.get(0).clone())?;
+
+        // 5. Perform ? DefinePropertyOrThrow(O, key, desc).
+        define_property_or_throw(&o, &key, desc)?;
+
+        // 6. Return undefined.
+        Ok(JSValue::Undefined)
+    }
+
+    /// B.2.2.3 Object.prototype.__defineSetter__ ( P, setter )
+    /// https://262.ecma-international.org/16.0/#sec-object.prototype.__defineSetter__
+    fn define_setter(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, args.this())?;
+
+        // 2. If IsCallable(setter) is false, throw a TypeError exception.
+        let setter = args.get_function(1)?;
+
+        // 3. Let desc be PropertyDescriptor { [[Set]]: setter, [[Enumerable]]: true, [[Configurable]]: true }.
+        let desc = JSObjectPropDescriptor {
+            set: Some(setter.clone()),
+            enumerable: Some(true),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        };
+
+        // 4. Let key be ? ToPropertyKey(P).
+        let key = to_property_key(agent, args.get(0).clone())?;
+
+        // 5. Perform ? DefinePropertyOrThrow(O, key, desc).
+        define_property_or_throw(&o, &key, desc)?;
+
+        // 6. Return undefined.
+        Ok(JSValue::Undefined)
+    }
+
+    /// B.2.2.4 Object.prototype.__lookupGetter__ ( P )
+    /// https://262.ecma-international.org/16.0/#sec-object.prototype.__lookupGetter__
+    fn lookup_getter(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        lookup_accessor(agent, &args, |desc| desc.get)
+    }
+
+    /// B.2.2.5 Object.prototype.__lookupSetter__ ( P )
+    /// https://262.ecma-international.org/16.0/#sec-object.prototype.__lookupSetter__
+    fn lookup_setter(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        lookup_accessor(agent, &args, |desc| desc.set)
+    }
+}
+
+/// The shared steps of __lookupGetter__ and __lookupSetter__, which only differ in the accessor field they return.
+#[cfg(feature = "annex-b")]
+fn lookup_accessor(
+    agent: &mut JSAgent,
+    args: &Args,
+    accessor: fn(JSObjectPropDescriptor) -> Option<JSValue>,
+) -> CompletionRecord<JSValue> {
+    // 1. Let O be ? ToObject(this value).
+    let mut o = to_object(agent, args.this())?;
+
+    // 2. Let key be ? ToPropertyKey(P).
+    let key = to_property_key(agent, args.get(0).clone())?;
+
+    // 3. Repeat,
+    loop {
+        // a. Let desc be ? O.[[GetOwnProperty]](key).
+        // b. If desc is not undefined, then
+        if let Some(desc) = o.get_own_property(&key)? {
+            // i. If IsAccessorDescriptor(desc) is true, return desc.[[Get]].
+            if desc.is_accessor_descriptor() {
+                return Ok(accessor(desc).unwrap_or(JSValue::Undefined));
+            }
+
+            // ii. Return undefined.
+            return Ok(JSValue::Undefined);
+        }
+
+        // c. Set O to ? O.[[GetPrototypeOf]]().
+        // d. If O is null, return undefined.
+        match o.get_prototype_of() {
+            Some(proto) => o = proto,
+            None => return Ok(JSValue::Undefined),
+        }
     }
 }
@@ -0,0 +1,910 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        iterator_operations::{get_iterator, iterator_close, iterator_step_value},
+        object_operations::{
+            call, create_array_from_list, create_data_property_or_throw, define_property_or_throw,
+            get, invoke,
+        },
+        ordinary::{ordinary_create_from_constructor, ordinary_object_create},
+        promise_operations::{
+            create_resolving_functions, if_abrupt_reject_promise, new_promise_capability,
+            promise_resolve,
+        },
+        testing_comparison::is_callable,
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{type_error, type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_SPECIES},
+        completion::{CompletionRecord, ThrowCompletion},
+        iteration::IteratorRecord,
+        native_function::{Args, NativeFunction},
+        promise::{PromiseCapability, PromiseState},
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            internal_slots::{ConstructorKind, InternalSlotName},
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// The steps of PerformPromiseAll, PerformPromiseAllSettled, PerformPromiseAny or PerformPromiseRace, called with iteratorRecord, C, promiseCapability and promiseResolve.
+type PerformPromiseCombinator = fn(
+    &mut JSAgent,
+    &mut IteratorRecord,
+    &ObjectAddr,
+    &PromiseCapability,
+    JSValue,
+) -> CompletionRecord<JSValue>;
+
+/// 27.2.3 The Promise Constructor
+/// https://262.ecma-international.org/16.0/#sec-promise-constructor
+#[derive(Debug)]
+pub(crate) struct PromiseConstructor;
+
+impl PromiseConstructor {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Promise%.
+        // is the initial value of the "Promise" property of the global object.
+        // has a [[Prototype]] internal slot whose value is %Function.prototype%.
+        let promise = create_builtin_function(
+            agent,
+            Self::promise,
+            1,
+            JSObjectPropKey::String("Promise".into()),
+            vec![InternalSlotName::ConstructorKind],
+            Some(realm_addr.clone()),
+            None,
+            None,
+        );
+
+        // may be used as the value in an extends clause of a class definition.
+        promise
+            .data_mut()
+            .slots_mut()
+            .set_constructor_kind(ConstructorKind::Base);
+
+        define_builtin_method(agent, &realm_addr, &promise, "Promise", "all", 1, Self::all);
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &promise,
+            "Promise",
+            "allSettled",
+            1,
+            Self::all_settled,
+        );
+        define_builtin_method(agent, &realm_addr, &promise, "Promise", "any", 1, Self::any);
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &promise,
+            "Promise",
+            "race",
+            1,
+            Self::race,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &promise,
+            "Promise",
+            "reject",
+            1,
+            Self::reject,
+        );
+
+        let resolve = define_builtin_method(
+            agent,
+            &realm_addr,
+            &promise,
+            "Promise",
+            "resolve",
+            1,
+            Self::resolve,
+        );
+
+        // 27.2.4.4 Promise.prototype
+        // The initial value of Promise.prototype is the Promise prototype object.
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        let promise_prototype = realm_addr
+            .borrow()
+            .intrinsics
+            .promise_prototype
+            .clone()
+            .unwrap();
+
+        let _ = define_property_or_throw(
+            &promise,
+            &JSObjectPropKey::String("prototype".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(promise_prototype.clone())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 27.2.5.2 Promise.prototype.constructor
+        // The initial value of Promise.prototype.constructor is %Promise%.
+        let _ = define_property_or_throw(
+            &promise_prototype,
+            &JSObjectPropKey::String("constructor".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(promise.clone())),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 27.2.4.8 get Promise [ %Symbol.species% ]
+        // Promise[%Symbol.species%] is an accessor property whose set accessor function is undefined.
+        let species_getter = create_builtin_function(
+            agent,
+            Self::get_species,
+            0,
+            JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_SPECIES),
+            vec![],
+            Some(realm_addr.clone()),
+            None,
+            Some("get".to_string()),
+        );
+
+        let _ = define_property_or_throw(
+            &promise,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_SPECIES),
+            JSObjectPropDescriptor {
+                get: Some(JSValue::from(species_getter)),
+                set: Some(JSValue::Undefined),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        realm_addr.borrow_mut().intrinsics.promise_resolve = Some(resolve);
+
+        promise
+    }
+
+    /// 27.2.3.1 Promise ( executor )
+    /// https://262.ecma-international.org/16.0/#sec-promise-executor
+    fn promise(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = args.new_target() else {
+            return type_error("Promise constructor cannot be invoked without 'new'");
+        };
+
+        // 2. If IsCallable(executor) is false, throw a TypeError exception.
+        let executor = args.get_function(0)?;
+
+        // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
+        let promise = ordinary_create_from_constructor(
+            agent,
+            new_target,
+            |intrinsics| intrinsics.promise_prototype.clone(),
+            Some(vec![
+                InternalSlotName::PromiseState,
+                InternalSlotName::PromiseResult,
+                InternalSlotName::PromiseFulfillReactions,
+                InternalSlotName::PromiseRejectReactions,
+                InternalSlotName::PromiseIsHandled,
+            ]),
+        )?;
+
+        {
+            let mut promise = promise.data_mut();
+
+            // 4. Set promise.[[PromiseState]] to pending.
+            promise.slots_mut().set_promise_state(PromiseState::Pending);
+
+            // 5. Set promise.[[PromiseFulfillReactions]] to a new empty List.
+            // 6. Set promise.[[PromiseRejectReactions]] to a new empty List.
+            // NOTE: Reactions are appended to the lists as they are created.
+
+            // 7. Set promise.[[PromiseIsHandled]] to false.
+            promise.slots_mut().set_promise_is_handled(false);
+        }
+
+        // 8. Let resolvingFunctions be CreateResolvingFunctions(promise).
+        let resolving_functions = create_resolving_functions(agent, &promise);
+
+        // 9. Let completion be Completion(Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)).
+        let completion = call(
+            agent,
+            executor.clone(),
+            &JSValue::Undefined,
+            Some(vec![
+                resolving_functions.resolve.into(),
+                resolving_functions.reject.clone().into(),
+            ]),
+        );
+
+        // 10. If completion is an abrupt completion, then
+        if let Err(error) = completion {
+            // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
+            let error = error.into_value(agent);
+
+            call(
+                agent,
+                resolving_functions.reject.into(),
+                &JSValue::Undefined,
+                Some(vec![error]),
+            )?;
+        }
+
+        // 11. Return promise.
+        Ok(promise.into())
+    }
+
+    /// 27.2.4.1 Promise.all ( iterable )
+    /// https://262.ecma-international.org/16.0/#sec-promise.all
+    fn all(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::combinator(agent, args, Self::perform_promise_all)
+    }
+
+    /// 27.2.4.1.2 PerformPromiseAll ( iteratorRecord, constructor, resultCapability, promiseResolve )
+    /// https://262.ecma-international.org/16.0/#sec-performpromiseall
+    fn perform_promise_all(
+        agent: &mut JSAgent,
+        iterator_record: &mut IteratorRecord,
+        constructor: &ObjectAddr,
+        result_capability: &PromiseCapability,
+        promise_resolve: JSValue,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let values be a new empty List.
+        let values = Rc::new(RefCell::new(vec![]));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Rc::new(Cell::new(1usize));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be ? IteratorStepValue(iteratorRecord).
+            // b. If next is done, then
+            let Some(next) = iterator_step_value(agent, iterator_record)? else {
+                // i. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                remaining_elements_count.set(remaining_elements_count.get() - 1);
+
+                // ii. If remainingElementsCount.[[Value]] = 0, then
+                if remaining_elements_count.get() == 0 {
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array = create_array_from_list(agent, values.take());
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    call(
+                        agent,
+                        result_capability.resolve.clone(),
+                        &JSValue::Undefined,
+                        Some(vec![values_array.into()]),
+                    )?;
+                }
+
+                // iii. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // c. Append undefined to values.
+            values.borrow_mut().push(JSValue::Undefined);
+
+            // d. Let nextPromise be ? Call(promiseResolve, constructor, « next »).
+            let next_promise = call(
+                agent,
+                promise_resolve.clone(),
+                &JSValue::from(constructor),
+                Some(vec![next]),
+            )?;
+
+            // e. Let steps be the algorithm steps defined in Promise.all Resolve Element Functions.
+            // f. Let length be the number of non-optional parameters of the function definition in Promise.all Resolve Element Functions.
+            // g. Let onFulfilled be CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // h. Set onFulfilled.[[AlreadyCalled]] to false.
+            // i. Set onFulfilled.[[Index]] to index.
+            // j. Set onFulfilled.[[Values]] to values.
+            // k. Set onFulfilled.[[Capability]] to resultCapability.
+            // l. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+            // NOTE: The internal slots of the element functions are captured by their closures.
+            let on_fulfilled = {
+                let already_called = Cell::new(false);
+                let values = values.clone();
+                let capability = result_capability.clone();
+                let remaining_elements = remaining_elements_count.clone();
+
+                // 27.2.4.1.3 Promise.all Resolve Element Functions
+                // https://262.ecma-international.org/16.0/#sec-promise.all-resolve-element-functions
+                let steps = move |agent: &mut JSAgent, args: Args| {
+                    // 1. Let F be the active function object.
+                    // 2. If F.[[AlreadyCalled]] is true, return undefined.
+                    // 3. Set F.[[AlreadyCalled]] to true.
+                    if already_called.replace(true) {
+                        return Ok(JSValue::Undefined);
+                    }
+
+                    // 4. Let index be F.[[Index]].
+                    // 5. Let values be F.[[Values]].
+                    // 6. Let promiseCapability be F.[[Capability]].
+                    // 7. Let remainingElementsCount be F.[[RemainingElements]].
+                    // 8. Set values[index] to x.
+                    values.borrow_mut()[index] = args.get(0).clone();
+
+                    // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                    remaining_elements.set(remaining_elements.get() - 1);
+
+                    // 10. If remainingElementsCount.[[Value]] = 0, then
+                    if remaining_elements.get() == 0 {
+                        // a. Let valuesArray be CreateArrayFromList(values).
+                        let values_array = create_array_from_list(agent, values.take());
+
+                        // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                        return call(
+                            agent,
+                            capability.resolve.clone(),
+                            &JSValue::Undefined,
+                            Some(vec![values_array.into()]),
+                        );
+                    }
+
+                    // 11. Return undefined.
+                    Ok(JSValue::Undefined)
+                };
+
+                Self::element_function(agent, steps)
+            };
+
+            // m. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            remaining_elements_count.set(remaining_elements_count.get() + 1);
+
+            // n. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
+            invoke(
+                agent,
+                &next_promise,
+                &JSObjectPropKey::String("then".into()),
+                Some(vec![on_fulfilled.into(), result_capability.reject.clone()]),
+            )?;
+
+            // o. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// 27.2.4.2 Promise.allSettled ( iterable )
+    /// https://262.ecma-international.org/16.0/#sec-promise.allsettled
+    fn all_settled(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::combinator(agent, args, Self::perform_promise_all_settled)
+    }
+
+    /// 27.2.4.2.1 PerformPromiseAllSettled ( iteratorRecord, constructor, resultCapability, promiseResolve )
+    /// https://262.ecma-international.org/16.0/#sec-performpromiseallsettled
+    fn perform_promise_all_settled(
+        agent: &mut JSAgent,
+        iterator_record: &mut IteratorRecord,
+        constructor: &ObjectAddr,
+        result_capability: &PromiseCapability,
+        promise_resolve: JSValue,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let values be a new empty List.
+        let values = Rc::new(RefCell::new(vec![]));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Rc::new(Cell::new(1usize));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be ? IteratorStepValue(iteratorRecord).
+            // b. If next is done, then
+            let Some(next) = iterator_step_value(agent, iterator_record)? else {
+                // i. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                remaining_elements_count.set(remaining_elements_count.get() - 1);
+
+                // ii. If remainingElementsCount.[[Value]] = 0, then
+                if remaining_elements_count.get() == 0 {
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array = create_array_from_list(agent, values.take());
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    call(
+                        agent,
+                        result_capability.resolve.clone(),
+                        &JSValue::Undefined,
+                        Some(vec![values_array.into()]),
+                    )?;
+                }
+
+                // iii. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // c. Append undefined to values.
+            values.borrow_mut().push(JSValue::Undefined);
+
+            // d. Let nextPromise be ? Call(promiseResolve, constructor, « next »).
+            let next_promise = call(
+                agent,
+                promise_resolve.clone(),
+                &JSValue::from(constructor),
+                Some(vec![next]),
+            )?;
+
+            // e. Let stepsFulfilled be the algorithm steps defined in Promise.allSettled Resolve Element Functions.
+            // f. Let lengthFulfilled be the number of non-optional parameters of the function definition in Promise.allSettled Resolve Element Functions.
+            // g. Let onFulfilled be CreateBuiltinFunction(stepsFulfilled, lengthFulfilled, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // h. Let alreadyCalled be the Record { [[Value]]: false }.
+            // i. Set onFulfilled.[[AlreadyCalled]] to alreadyCalled.
+            // j. Set onFulfilled.[[Index]] to index.
+            // k. Set onFulfilled.[[Values]] to values.
+            // l. Set onFulfilled.[[Capability]] to resultCapability.
+            // m. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+            // n. Let stepsRejected be the algorithm steps defined in Promise.allSettled Reject Element Functions.
+            // o. Let lengthRejected be the number of non-optional parameters of the function definition in Promise.allSettled Reject Element Functions.
+            // p. Let onRejected be CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // q. Set onRejected.[[AlreadyCalled]] to alreadyCalled.
+            // r. Set onRejected.[[Index]] to index.
+            // s. Set onRejected.[[Values]] to values.
+            // t. Set onRejected.[[Capability]] to resultCapability.
+            // u. Set onRejected.[[RemainingElements]] to remainingElementsCount.
+            let already_called = Rc::new(Cell::new(false));
+
+            let [on_fulfilled, on_rejected] =
+                [("fulfilled", "value"), ("rejected", "reason")].map(|(status, key)| {
+                    let already_called = already_called.clone();
+                    let values = values.clone();
+                    let capability = result_capability.clone();
+                    let remaining_elements = remaining_elements_count.clone();
+
+                    // 27.2.4.2.2 Promise.allSettled Resolve Element Functions
+                    // https://262.ecma-international.org/16.0/#sec-promise.allsettled-resolve-element-functions
+                    // 27.2.4.2.3 Promise.allSettled Reject Element Functions
+                    // https://262.ecma-international.org/16.0/#sec-promise.allsettled-reject-element-functions
+                    let steps = move |agent: &mut JSAgent, args: Args| {
+                        // 1. Let F be the active function object.
+                        // 2. Let alreadyCalled be F.[[AlreadyCalled]].
+                        // 3. If alreadyCalled.[[Value]] is true, return undefined.
+                        // 4. Set alreadyCalled.[[Value]] to true.
+                        if already_called.replace(true) {
+                            return Ok(JSValue::Undefined);
+                        }
+
+                        // 5. Let index be F.[[Index]].
+                        // 6. Let values be F.[[Values]].
+                        // 7. Let promiseCapability be F.[[Capability]].
+                        // 8. Let remainingElementsCount be F.[[RemainingElements]].
+                        // 9. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+                        let object_prototype = agent
+                            .current_realm()
+                            .borrow()
+                            .intrinsics
+                            .object_prototype
+                            .clone();
+
+                        let obj = ordinary_object_create(agent, object_prototype, None);
+
+                        // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "fulfilled").
+                        create_data_property_or_throw(
+                            &obj,
+                            &JSObjectPropKey::String("status".into()),
+                            JSValue::from(status.to_string()),
+                        )
+                        .unwrap();
+
+                        // 11. Perform ! CreateDataPropertyOrThrow(obj, "value", x).
+                        create_data_property_or_throw(
+                            &obj,
+                            &JSObjectPropKey::String(key.into()),
+                            args.get(0).clone(),
+                        )
+                        .unwrap();
+
+                        // 12. Set values[index] to obj.
+                        values.borrow_mut()[index] = obj.into();
+
+                        // 13. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                        remaining_elements.set(remaining_elements.get() - 1);
+
+                        // 14. If remainingElementsCount.[[Value]] = 0, then
+                        if remaining_elements.get() == 0 {
+                            // a. Let valuesArray be CreateArrayFromList(values).
+                            let values_array = create_array_from_list(agent, values.take());
+
+                            // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                            return call(
+                                agent,
+                                capability.resolve.clone(),
+                                &JSValue::Undefined,
+                                Some(vec![values_array.into()]),
+                            );
+                        }
+
+                        // 15. Return undefined.
+                        Ok(JSValue::Undefined)
+                    };
+
+                    Self::element_function(agent, steps)
+                });
+
+            // v. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            remaining_elements_count.set(remaining_elements_count.get() + 1);
+
+            // w. Perform ? Invoke(nextPromise, "then", « onFulfilled, onRejected »).
+            invoke(
+                agent,
+                &next_promise,
+                &JSObjectPropKey::String("then".into()),
+                Some(vec![on_fulfilled.into(), on_rejected.into()]),
+            )?;
+
+            // x. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// 27.2.4.3 Promise.any ( iterable )
+    /// https://262.ecma-international.org/16.0/#sec-promise.any
+    fn any(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::combinator(agent, args, Self::perform_promise_any)
+    }
+
+    /// 27.2.4.3.1 PerformPromiseAny ( iteratorRecord, constructor, resultCapability, promiseResolve )
+    /// https://262.ecma-international.org/16.0/#sec-performpromiseany
+    fn perform_promise_any(
+        agent: &mut JSAgent,
+        iterator_record: &mut IteratorRecord,
+        constructor: &ObjectAddr,
+        result_capability: &PromiseCapability,
+        promise_resolve: JSValue,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let errors be a new empty List.
+        let errors = Rc::new(RefCell::new(vec![]));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Rc::new(Cell::new(1usize));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be ? IteratorStepValue(iteratorRecord).
+            // b. If next is done, then
+            let Some(next) = iterator_step_value(agent, iterator_record)? else {
+                // i. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                remaining_elements_count.set(remaining_elements_count.get() - 1);
+
+                // ii. If remainingElementsCount.[[Value]] = 0, then
+                if remaining_elements_count.get() == 0 {
+                    // 1. Let aggregateError be a newly created AggregateError object.
+                    // 2. Perform ! DefinePropertyOrThrow(aggregateError, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                    // 3. Return ThrowCompletion(aggregateError).
+                    return Err(ThrowCompletion::from(Self::aggregate_error(
+                        agent,
+                        errors.take(),
+                    )));
+                }
+
+                // iii. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // c. Append undefined to errors.
+            errors.borrow_mut().push(JSValue::Undefined);
+
+            // d. Let nextPromise be ? Call(promiseResolve, constructor, « next »).
+            let next_promise = call(
+                agent,
+                promise_resolve.clone(),
+                &JSValue::from(constructor),
+                Some(vec![next]),
+            )?;
+
+            // e. Let stepsRejected be the algorithm steps defined in Promise.any Reject Element Functions.
+            // f. Let lengthRejected be the number of non-optional parameters of the function definition in Promise.any Reject Element Functions.
+            // g. Let onRejected be CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Errors]], [[Capability]], [[RemainingElements]] »).
+            // h. Set onRejected.[[AlreadyCalled]] to false.
+            // i. Set onRejected.[[Index]] to index.
+            // j. Set onRejected.[[Errors]] to errors.
+            // k. Set onRejected.[[Capability]] to resultCapability.
+            // l. Set onRejected.[[RemainingElements]] to remainingElementsCount.
+            let on_rejected = {
+                let already_called = Cell::new(false);
+                let errors = errors.clone();
+                let capability = result_capability.clone();
+                let remaining_elements = remaining_elements_count.clone();
+
+                // 27.2.4.3.2 Promise.any Reject Element Functions
+                // https://262.ecma-international.org/16.0/#sec-promise.any-reject-element-functions
+                let steps = move |agent: &mut JSAgent, args: Args| {
+                    // 1. Let F be the active function object.
+                    // 2. If F.[[AlreadyCalled]] is true, return undefined.
+                    // 3. Set F.[[AlreadyCalled]] to true.
+                    if already_called.replace(true) {
+                        return Ok(JSValue::Undefined);
+                    }
+
+                    // 4. Let index be F.[[Index]].
+                    // 5. Let errors be F.[[Errors]].
+                    // 6. Let promiseCapability be F.[[Capability]].
+                    // 7. Let remainingElementsCount be F.[[RemainingElements]].
+                    // 8. Set errors[index] to x.
+                    errors.borrow_mut()[index] = args.get(0).clone();
+
+                    // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                    remaining_elements.set(remaining_elements.get() - 1);
+
+                    // 10. If remainingElementsCount.[[Value]] = 0, then
+                    if remaining_elements.get() == 0 {
+                        // a. Let error be a newly created AggregateError object.
+                        // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                        let error = Self::aggregate_error(agent, errors.take());
+
+                        // c. Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+                        return call(
+                            agent,
+                            capability.reject.clone(),
+                            &JSValue::Undefined,
+                            Some(vec![error]),
+                        );
+                    }
+
+                    // 11. Return undefined.
+                    Ok(JSValue::Undefined)
+                };
+
+                Self::element_function(agent, steps)
+            };
+
+            // m. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            remaining_elements_count.set(remaining_elements_count.get() + 1);
+
+            // n. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], onRejected »).
+            invoke(
+                agent,
+                &next_promise,
+                &JSObjectPropKey::String("then".into()),
+                Some(vec![result_capability.resolve.clone(), on_rejected.into()]),
+            )?;
+
+            // o. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// 27.2.4.5 Promise.race ( iterable )
+    /// https://262.ecma-international.org/16.0/#sec-promise.race
+    fn race(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        Self::combinator(agent, args, Self::perform_promise_race)
+    }
+
+    /// 27.2.4.5.1 PerformPromiseRace ( iteratorRecord, constructor, resultCapability, promiseResolve )
+    /// https://262.ecma-international.org/16.0/#sec-performpromiserace
+    fn perform_promise_race(
+        agent: &mut JSAgent,
+        iterator_record: &mut IteratorRecord,
+        constructor: &ObjectAddr,
+        result_capability: &PromiseCapability,
+        promise_resolve: JSValue,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Repeat,
+        loop {
+            // a. Let next be ? IteratorStepValue(iteratorRecord).
+            // b. If next is done, then
+            let Some(next) = iterator_step_value(agent, iterator_record)? else {
+                // i. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // c. Let nextPromise be ? Call(promiseResolve, constructor, « next »).
+            let next_promise = call(
+                agent,
+                promise_resolve.clone(),
+                &JSValue::from(constructor),
+                Some(vec![next]),
+            )?;
+
+            // d. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], resultCapability.[[Reject]] »).
+            invoke(
+                agent,
+                &next_promise,
+                &JSObjectPropKey::String("then".into()),
+                Some(vec![
+                    result_capability.resolve.clone(),
+                    result_capability.reject.clone(),
+                ]),
+            )?;
+        }
+    }
+
+    /// 27.2.4.6 Promise.reject ( r )
+    /// https://262.ecma-international.org/16.0/#sec-promise.reject
+    fn reject(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = new_promise_capability(agent, args.this())?;
+
+        // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        call(
+            agent,
+            promise_capability.reject,
+            &JSValue::Undefined,
+            Some(vec![args.get(0).clone()]),
+        )?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise.into())
+    }
+
+    /// 27.2.4.7 Promise.resolve ( x )
+    /// https://262.ecma-international.org/16.0/#sec-promise.resolve
+    fn resolve(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let C be the this value.
+        // 2. If C is not an Object, throw a TypeError exception.
+        let JSValue::Object(constructor) = args.this() else {
+            return type_error_for("Promise.resolve called on a non-object", args.this());
+        };
+
+        // 3. Return ? PromiseResolve(C, x).
+        promise_resolve(agent, constructor, args.get(0).clone())
+    }
+
+    /// 27.2.4.8 get Promise [ %Symbol.species% ]
+    /// https://262.ecma-international.org/16.0/#sec-get-promise-%symbol.species%
+    fn get_species(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return the this value.
+        Ok(args.this().clone())
+    }
+
+    /// The steps shared by Promise.all, Promise.allSettled, Promise.any and Promise.race, which differ only in the operation performed for the iterable.
+    fn combinator(
+        agent: &mut JSAgent,
+        args: Args,
+        perform: PerformPromiseCombinator,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let C be the this value.
+        let constructor = args.this();
+
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = new_promise_capability(agent, constructor)?;
+
+        let JSValue::Object(constructor) = constructor else {
+            unreachable!();
+        };
+
+        // 3. Let promiseResolve be Completion(GetPromiseResolve(C)).
+        // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
+        let promise_resolve = match Self::get_promise_resolve(agent, constructor) {
+            Ok(promise_resolve) => promise_resolve,
+            Err(error) => return if_abrupt_reject_promise(agent, error, &promise_capability),
+        };
+
+        // 5. Let iteratorRecord be Completion(GetIterator(iterable, sync)).
+        // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
+        let mut iterator_record = match get_iterator(agent, args.get(0)) {
+            Ok(iterator_record) => iterator_record,
+            Err(error) => return if_abrupt_reject_promise(agent, error, &promise_capability),
+        };
+
+        // 7. Let result be Completion(PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve)).
+        let mut result = perform(
+            agent,
+            &mut iterator_record,
+            constructor,
+            &promise_capability,
+            promise_resolve,
+        );
+
+        // 8. If result is an abrupt completion, then
+        if result.is_err() {
+            // a. If iteratorRecord.[[Done]] is false, set result to Completion(IteratorClose(iteratorRecord, result)).
+            if !iterator_record.done {
+                result = iterator_close(agent, &iterator_record, result);
+            }
+
+            // b. IfAbruptRejectPromise(result, promiseCapability).
+            if let Err(error) = result {
+                return if_abrupt_reject_promise(agent, error, &promise_capability);
+            }
+        }
+
+        // 9. Return ? result.
+        result
+    }
+
+    /// 27.2.4.1.1 GetPromiseResolve ( promiseConstructor )
+    /// https://262.ecma-international.org/16.0/#sec-getpromiseresolve
+    fn get_promise_resolve(
+        agent: &mut JSAgent,
+        promise_constructor: &ObjectAddr,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let promiseResolve be ? Get(promiseConstructor, "resolve").
+        let promise_resolve = get(
+            agent,
+            promise_constructor,
+            &JSObjectPropKey::String("resolve".into()),
+        )?;
+
+        // 2. If IsCallable(promiseResolve) is false, throw a TypeError exception.
+        if !is_callable(&promise_resolve) {
+            return type_error_for("Promise resolve is not a function", &promise_resolve);
+        }
+
+        // 3. Return promiseResolve.
+        Ok(promise_resolve)
+    }
+
+    /// Creates an anonymous built-in function with one parameter for the steps of an element function.
+    fn element_function(agent: &mut JSAgent, steps: impl NativeFunction) -> ObjectAddr {
+        create_builtin_function(
+            agent,
+            steps,
+            1,
+            JSObjectPropKey::String("".into()),
+            vec![],
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// A newly created AggregateError object with an "errors" property holding the errors.
+    ///
+    /// NOTE: Error objects are not implemented yet, so the error is an ordinary object with the "name" and "message" of an AggregateError.
+    fn aggregate_error(agent: &mut JSAgent, errors: Vec<JSValue>) -> JSValue {
+        let object_prototype = agent
+            .current_realm()
+            .borrow()
+            .intrinsics
+            .object_prototype
+            .clone();
+
+        let error = ordinary_object_create(agent, object_prototype, None);
+
+        for (key, value) in [
+            ("name", "AggregateError"),
+            ("message", "All promises were rejected"),
+        ] {
+            let _ = define_property_or_throw(
+                &error,
+                &JSObjectPropKey::String(key.into()),
+                JSObjectPropDescriptor {
+                    value: Some(JSValue::from(value.to_string())),
+                    writable: Some(true),
+                    enumerable: Some(false),
+                    configurable: Some(true),
+                    ..JSObjectPropDescriptor::default()
+                },
+            );
+        }
+
+        let errors = create_array_from_list(agent, errors);
+
+        let _ = define_property_or_throw(
+            &error,
+            &JSObjectPropKey::String("errors".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(errors)),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        error.into()
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::{call, define_property_or_throw, invoke, species_constructor},
+        ordinary::ordinary_object_create,
+        promise_operations::{
+            is_promise, new_promise_capability, perform_promise_then, promise_resolve,
+        },
+        testing_comparison::is_callable,
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        completion::{CompletionRecord, ThrowCompletion},
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        JSValue,
+    },
+};
+
+/// 27.2.5 Properties of the Promise Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-promise-prototype-object
+#[derive(Debug)]
+pub(crate) struct PromisePrototype;
+
+impl PromisePrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Promise.prototype%.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        // is an ordinary object.
+        // does not have a [[PromiseState]] internal slot or any of the other internal slots of Promise instances.
+        let promise_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            None,
+        );
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &promise_prototype,
+            "Promise.prototype",
+            "catch",
+            1,
+            Self::catch,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &promise_prototype,
+            "Promise.prototype",
+            "finally",
+            1,
+            Self::finally,
+        );
+
+        let then = define_builtin_method(
+            agent,
+            &realm_addr,
+            &promise_prototype,
+            "Promise.prototype",
+            "then",
+            2,
+            Self::then,
+        );
+
+        // 27.2.5.5 Promise.prototype [ %Symbol.toStringTag% ]
+        // The initial value of the %Symbol.toStringTag% property is the String value "Promise".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &promise_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from("Promise".to_string())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        realm_addr.borrow_mut().intrinsics.promise_prototype_then = Some(then);
+
+        promise_prototype
+    }
+
+    /// 27.2.5.1 Promise.prototype.catch ( onRejected )
+    /// https://262.ecma-international.org/16.0/#sec-promise.prototype.catch
+    fn catch(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let promise be the this value.
+        // 2. Return ? Invoke(promise, "then", « undefined, onRejected »).
+        invoke(
+            agent,
+            args.this(),
+            &JSObjectPropKey::String("then".into()),
+            Some(vec![JSValue::Undefined, args.get(0).clone()]),
+        )
+    }
+
+    /// 27.2.5.3 Promise.prototype.finally ( onFinally )
+    /// https://262.ecma-international.org/16.0/#sec-promise.prototype.finally
+    fn finally(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let promise be the this value.
+        // 2. If promise is not an Object, throw a TypeError exception.
+        let JSValue::Object(promise) = args.this() else {
+            return type_error_for(
+                "Promise.prototype.finally called on a non-object",
+                args.this(),
+            );
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let default_constructor = Self::intrinsic_promise(agent);
+        let constructor = species_constructor(agent, promise, default_constructor)?;
+
+        // 4. Assert: IsConstructor(C) is true.
+        let on_finally = args.get(0).clone();
+
+        // 5. If IsCallable(onFinally) is false, then
+        let (then_finally, catch_finally) = if !is_callable(&on_finally) {
+            // a. Let thenFinally be onFinally.
+            // b. Let catchFinally be onFinally.
+            (on_finally.clone(), on_finally)
+        }
+        // 6. Else,
+        else {
+            // a. Let thenFinallyClosure be a new Abstract Closure with parameters (value) that captures onFinally and C and performs the following steps when called:
+            let then_finally_closure = {
+                let on_finally = on_finally.clone();
+                let constructor = constructor.clone();
+
+                move |agent: &mut JSAgent, args: Args| {
+                    let value = args.get(0).clone();
+
+                    // i. Let result be ? Call(onFinally, undefined).
+                    let result = call(agent, on_finally.clone(), &JSValue::Undefined, None)?;
+
+                    // ii. Let p be ? PromiseResolve(C, result).
+                    let promise = promise_resolve(agent, &constructor, result)?;
+
+                    // iii. Let returnValue be a new Abstract Closure with no parameters that captures value and performs the following steps when called:
+                    // 1. Return NormalCompletion(value).
+                    let return_value = move |_: &mut JSAgent, _: Args| Ok(value.clone());
+
+                    // iv. Let valueThunk be CreateBuiltinFunction(returnValue, 0, "", « »).
+                    let value_thunk = create_builtin_function(
+                        agent,
+                        return_value,
+                        0,
+                        JSObjectPropKey::String("".into()),
+                        vec![],
+                        None,
+                        None,
+                        None,
+                    );
+
+                    // v. Return ? Invoke(p, "then", « valueThunk »).
+                    invoke(
+                        agent,
+                        &promise,
+                        &JSObjectPropKey::String("then".into()),
+                        Some(vec![value_thunk.into()]),
+                    )
+                }
+            };
+
+            // b. Let thenFinally be CreateBuiltinFunction(thenFinallyClosure, 1, "", « »).
+            let then_finally = create_builtin_function(
+                agent,
+                then_finally_closure,
+                1,
+                JSObjectPropKey::String("".into()),
+                vec![],
+                None,
+                None,
+                None,
+            );
+
+            // c. Let catchFinallyClosure be a new Abstract Closure with parameters (reason) that captures onFinally and C and performs the following steps when called:
+            let catch_finally_closure = move |agent: &mut JSAgent, args: Args| {
+                let reason = args.get(0).clone();
+
+                // i. Let result be ? Call(onFinally, undefined).
+                let result = call(agent, on_finally.clone(), &JSValue::Undefined, None)?;
+
+                // ii. Let p be ? PromiseResolve(C, result).
+                let promise = promise_resolve(agent, &constructor, result)?;
+
+                // iii. Let throwReason be a new Abstract Closure with no parameters that captures reason and performs the following steps when called:
+                // 1. Return ThrowCompletion(reason).
+                let throw_reason =
+                    move |_: &mut JSAgent, _: Args| Err(ThrowCompletion::from(reason.clone()));
+
+                // iv. Let thrower be CreateBuiltinFunction(throwReason, 0, "", « »).
+                let thrower = create_builtin_function(
+                    agent,
+                    throw_reason,
+                    0,
+                    JSObjectPropKey::String("".into()),
+                    vec![],
+                    None,
+                    None,
+                    None,
+                );
+
+                // v. Return ? Invoke(p, "then", « thrower »).
+                invoke(
+                    agent,
+                    &promise,
+                    &JSObjectPropKey::String("then".into()),
+                    Some(vec![thrower.into()]),
+                )
+            };
+
+            // d. Let catchFinally be CreateBuiltinFunction(catchFinallyClosure, 1, "", « »).
+            let catch_finally = create_builtin_function(
+                agent,
+                catch_finally_closure,
+                1,
+                JSObjectPropKey::String("".into()),
+                vec![],
+                None,
+                None,
+                None,
+            );
+
+            (then_finally.into(), catch_finally.into())
+        };
+
+        // 7. Return ? Invoke(promise, "then", « thenFinally, catchFinally »).
+        invoke(
+            agent,
+            args.this(),
+            &JSObjectPropKey::String("then".into()),
+            Some(vec![then_finally, catch_finally]),
+        )
+    }
+
+    /// 27.2.5.4 Promise.prototype.then ( onFulfilled, onRejected )
+    /// https://262.ecma-international.org/16.0/#sec-promise.prototype.then
+    fn then(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let promise be the this value.
+        let promise = args.this();
+
+        // 2. If IsPromise(promise) is false, throw a TypeError exception.
+        let (true, JSValue::Object(promise)) = (is_promise(promise), promise) else {
+            return type_error_for(
+                "Promise.prototype.then called on an incompatible receiver",
+                args.this(),
+            );
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let default_constructor = Self::intrinsic_promise(agent);
+        let constructor = species_constructor(agent, promise, default_constructor)?;
+
+        // 4. Let resultCapability be ? NewPromiseCapability(C).
+        let result_capability = new_promise_capability(agent, &JSValue::from(constructor))?;
+
+        // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        Ok(perform_promise_then(
+            agent,
+            promise,
+            args.get(0).clone(),
+            args.get(1).clone(),
+            Some(result_capability),
+        ))
+    }
+
+    /// %Promise% of the current realm.
+    fn intrinsic_promise(agent: &JSAgent) -> ObjectAddr {
+        agent
+            .current_realm()
+            .borrow()
+            .intrinsics
+            .promise
+            .clone()
+            .unwrap()
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        iterator_operations::create_iter_result_object,
+        object_operations::define_property_or_throw, ordinary::ordinary_object_create,
+    },
+    runtime::{
+        agent::{type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_TO_STRING_TAG},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// 22.1.5.1 The %StringIteratorPrototype% Object
+/// https://262.ecma-international.org/16.0/#sec-%stringiteratorprototype%-object
+#[derive(Debug)]
+pub(crate) struct StringIteratorPrototype;
+
+impl StringIteratorPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // has properties that are inherited by all String Iterator objects.
+        // is an ordinary object.
+        // has a [[Prototype]] internal slot whose value is %Iterator.prototype%.
+        let string_iterator_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.iterator_prototype.clone(),
+            None,
+        );
+
+        let next = create_builtin_function(
+            agent,
+            Self::next,
+            0,
+            JSObjectPropKey::String("next".into()),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        );
+
+        let _ = define_property_or_throw(
+            &string_iterator_prototype,
+            &JSObjectPropKey::String("next".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(next)),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 22.1.5.1.2 %StringIteratorPrototype% [ %Symbol.toStringTag% ]
+        // The initial value of the %Symbol.toStringTag% property is the String value "String Iterator".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &string_iterator_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from("String Iterator".to_string())),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        string_iterator_prototype
+    }
+
+    /// 22.1.5.1.1 %StringIteratorPrototype%.next ( )
+    /// https://262.ecma-international.org/16.0/#sec-%stringiteratorprototype%.next
+    fn next(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? GeneratorResume(this value, empty, "%StringIteratorPrototype%").
+        let object = ObjectAddr::try_from(args.this())?;
+
+        let Some(mut iterator) = object.data_mut().slots_mut().take_string_iterator() else {
+            return type_error_for(
+                "String Iterator next called on an incompatible receiver",
+                args.this(),
+            );
+        };
+
+        let value = iterator.next();
+
+        object.data_mut().slots_mut().set_string_iterator(iterator);
+
+        let result = match value {
+            Some(value) => create_iter_result_object(agent, JSValue::from(value), false),
+            None => create_iter_result_object(agent, JSValue::Undefined, true),
+        };
+
+        Ok(JSValue::from(result))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function, object_operations::define_property_or_throw,
+        string_exotic_objects::string_create, testing_comparison::require_object_coercible,
+        type_conversion::to_string,
+    },
+    runtime::{
+        agent::{JSAgent, WELL_KNOWN_SYMBOLS_ITERATOR},
+        completion::CompletionRecord,
+        iteration::create_string_iterator,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr,
+        },
+        JSValue,
+    },
+};
+
+/// 22.1.3 Properties of the String Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-string-prototype-object
+#[derive(Debug)]
+pub(crate) struct StringPrototype;
+
+impl StringPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %String.prototype%.
+        // is a String exotic object and has the internal methods specified for such objects.
+        // has a [[StringData]] internal slot whose value is the empty String.
+        // has a "length" property whose initial value is +0𝔽 and whose attributes are { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        let string_prototype = string_create(
+            agent,
+            "".into(),
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+        );
+
+        let iterator = create_builtin_function(
+            agent,
+            Self::iterator,
+            0,
+            JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        );
+
+        let _ = define_property_or_throw(
+            &string_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_ITERATOR),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(iterator)),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        string_prototype
+    }
+
+    /// 22.1.3.36 String.prototype [ %Symbol.iterator% ] ( )
+    /// https://262.ecma-international.org/16.0/#sec-string.prototype-%symbol.iterator%
+    fn iterator(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = require_object_coercible(args.this().clone())?;
+
+        // 2. Let s be ? ToString(O).
+        let s = to_string(agent, o)?;
+
+        // 3. Let closure be a new Abstract Closure with no parameters that captures s and performs the following steps when called:
+        // 4. Return CreateIteratorFromClosure(closure, "%StringIteratorPrototype%", %StringIteratorPrototype%).
+        Ok(JSValue::from(create_string_iterator(agent, s)))
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function, object_operations::define_property_or_throw,
+        ordinary::ordinary_object_create,
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{
+            type_error_for, JSAgent, WELL_KNOWN_SYMBOLS_TO_PRIMITIVE,
+            WELL_KNOWN_SYMBOLS_TO_STRING_TAG,
+        },
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        symbol::JSSymbol,
+        JSValue,
+    },
+};
+
+/// 20.4.3 Properties of the Symbol Prototype Object
+/// https://262.ecma-international.org/16.0/#sec-properties-of-the-symbol-prototype-object
+#[derive(Debug)]
+pub(crate) struct SymbolPrototype;
+
+impl SymbolPrototype {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is %Symbol.prototype%.
+        // is an ordinary object.
+        // is not a Symbol instance and does not have a [[SymbolData]] internal slot.
+        // has a [[Prototype]] internal slot whose value is %Object.prototype%.
+        let symbol_prototype = ordinary_object_create(
+            agent,
+            realm_addr.borrow().intrinsics.object_prototype.clone(),
+            None,
+        );
+
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &symbol_prototype,
+            "Symbol.prototype",
+            "toString",
+            0,
+            Self::to_string,
+        );
+        define_builtin_method(
+            agent,
+            &realm_addr,
+            &symbol_prototype,
+            "Symbol.prototype",
+            "valueOf",
+            0,
+            Self::value_of,
+        );
+
+        // 20.4.3.2 get Symbol.prototype.description
+        // Symbol.prototype.description is an accessor property whose set accessor function is undefined.
+        let description_getter = create_builtin_function(
+            agent,
+            Self::get_description,
+            0,
+            JSObjectPropKey::String("description".into()),
+            vec![],
+            Some(realm_addr.clone()),
+            None,
+            Some("get".to_string()),
+        );
+
+        let _ = define_property_or_throw(
+            &symbol_prototype,
+            &JSObjectPropKey::String("description".into()),
+            JSObjectPropDescriptor {
+                get: Some(JSValue::from(description_getter)),
+                set: Some(JSValue::Undefined),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 20.4.3.5 Symbol.prototype [ %Symbol.toPrimitive% ] ( hint )
+        // The value of the "name" property of this method is "[Symbol.toPrimitive]".
+        let to_primitive = create_builtin_function(
+            agent,
+            Self::to_primitive,
+            1,
+            JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_PRIMITIVE),
+            vec![],
+            Some(realm_addr.clone()),
+            None,
+            None,
+        );
+
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &symbol_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_PRIMITIVE),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(to_primitive)),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        // 20.4.3.6 Symbol.prototype [ %Symbol.toStringTag% ]
+        // The initial value of the %Symbol.toStringTag% property is the String value "Symbol".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }.
+        let _ = define_property_or_throw(
+            &symbol_prototype,
+            &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_TO_STRING_TAG),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from("Symbol")),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..JSObjectPropDescriptor::default()
+            },
+        );
+
+        symbol_prototype
+    }
+
+    /// 20.4.3.2 get Symbol.prototype.description
+    /// https://262.ecma-international.org/16.0/#sec-symbol.prototype.description
+    fn get_description(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let s be the this value.
+        // 2. Let sym be ? ThisSymbolValue(s).
+        let sym = this_symbol_value(&args)?;
+
+        // 3. Return sym.[[Description]].
+        Ok(sym.description.map_or(JSValue::Undefined, JSValue::from))
+    }
+
+    /// 20.4.3.3 Symbol.prototype.toString ( )
+    /// https://262.ecma-international.org/16.0/#sec-symbol.prototype.tostring
+    fn to_string(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Let sym be ? ThisSymbolValue(this value).
+        let sym = this_symbol_value(&args)?;
+
+        // 2. Return SymbolDescriptiveString(sym).
+        Ok(JSValue::from(sym.descriptive_string()))
+    }
+
+    /// 20.4.3.4 Symbol.prototype.valueOf ( )
+    /// https://262.ecma-international.org/16.0/#sec-symbol.prototype.valueof
+    fn value_of(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // 1. Return ? ThisSymbolValue(this value).
+        Ok(JSValue::Symbol(this_symbol_value(&args)?))
+    }
+
+    /// 20.4.3.5 Symbol.prototype [ %Symbol.toPrimitive% ] ( hint )
+    /// https://262.ecma-international.org/16.0/#sec-symbol.prototype-%symbol.toprimitive%
+    fn to_primitive(_agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        // This method is called by ECMAScript language operators to convert a Symbol object to a primitive value.
+        // 1. Return ? ThisSymbolValue(this value).
+        // NOTE: The argument is ignored.
+        Ok(JSValue::Symbol(this_symbol_value(&args)?))
+    }
+}
+
+/// 20.4.3.4.1 ThisSymbolValue ( value )
+/// https://262.ecma-international.org/16.0/#sec-thissymbolvalue
+fn this_symbol_value(args: &Args) -> CompletionRecord<JSSymbol> {
+    let symbol = match args.this() {
+        // 1. If value is a Symbol, return value.
+        JSValue::Symbol(symbol) => Some(symbol.clone()),
+        // 2. If value is an Object and value has a [[SymbolData]] internal slot, then
+        // a. Let s be value.[[SymbolData]].
+        // b. Assert: s is a Symbol.
+        // c. Return s.
+        JSValue::Object(object) => object.data().slots().symbol_data(),
+        _ => None,
+    };
+
+    match symbol {
+        Some(symbol) => Ok(symbol),
+        // 3. Throw a TypeError exception.
+        None => type_error_for(
+            &format!(
+                "{} called on an incompatible receiver",
+                args.function_name()
+            ),
+            args.this(),
+        ),
+    }
+}
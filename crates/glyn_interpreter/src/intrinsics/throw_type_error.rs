@@ -0,0 +1,62 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function, object_operations::define_property_or_throw,
+    },
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::CompletionRecord,
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectEssentialInternalMethods,
+        },
+        JSValue,
+    },
+};
+
+/// 10.2.4.1 %ThrowTypeError% ( )
+/// https://262.ecma-international.org/16.0/#sec-%throwtypeerror%
+#[derive(Debug)]
+pub(crate) struct ThrowTypeError;
+
+impl ThrowTypeError {
+    pub(crate) fn create(agent: &mut JSAgent, realm_addr: RealmAddr) -> ObjectAddr {
+        // is an anonymous built-in function object that is defined once for each realm.
+        let throw_type_error = create_builtin_function(
+            agent,
+            Self::behaviour,
+            0,
+            JSObjectPropKey::String("".into()),
+            vec![],
+            Some(realm_addr),
+            None,
+            None,
+        );
+
+        // The "length" property of this function has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        // The "name" property of this function has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        for name in ["length", "name"] {
+            let _ = define_property_or_throw(
+                &throw_type_error,
+                &JSObjectPropKey::String(name.into()),
+                JSObjectPropDescriptor {
+                    configurable: Some(false),
+                    ..JSObjectPropDescriptor::default()
+                },
+            );
+        }
+
+        // The value of the [[Extensible]] internal slot of this function is false.
+        throw_type_error.prevent_extensions();
+
+        throw_type_error
+    }
+
+    fn behaviour(_: &mut JSAgent, _: Args) -> CompletionRecord<JSValue> {
+        // 1. Throw a TypeError exception.
+        type_error("'caller', 'callee', and 'arguments' properties may not be accessed on strict mode functions or the arguments objects for calls to them")
+    }
+}
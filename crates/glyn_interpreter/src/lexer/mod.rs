@@ -5,12 +5,15 @@ use core::fmt;
 
 use glyn_unicode::{is_unicode_id_continue, is_unicode_id_start};
 
-pub(crate) use token::{BinOpPrecedence, Keyword, Token};
+pub(crate) use token::{BinOpPrecedence, Keyword, Token, TokenKind};
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum LexerError {
     UnexpectedChar,
     InvalidStringToKeywordConversion,
+    UnterminatedComment,
+    UnterminatedString,
+    UnterminatedTemplate,
 }
 
 impl fmt::Display for LexerError {
@@ -22,10 +25,52 @@ impl fmt::Display for LexerError {
             LexerError::InvalidStringToKeywordConversion => {
                 write!(f, "Unexpected attempt to convert a string to a keyword.")
             }
+            LexerError::UnterminatedComment => {
+                write!(f, "Unterminated multi-line comment in the input string.")
+            }
+            LexerError::UnterminatedString => {
+                write!(f, "Unterminated string literal in the input string.")
+            }
+            LexerError::UnterminatedTemplate => {
+                write!(f, "Unterminated template literal in the input string.")
+            }
         }
     }
 }
 
+/// A half-open range of byte offsets into the source text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Span {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl Span {
+    pub(crate) fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
+/// An error found while lexing, along with the span of the source text it was found in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LexerDiagnostic {
+    pub(crate) error: LexerError,
+    pub(crate) span: Span,
+}
+
+/// A line and column in the source text, both counted from 1, with the column counted in characters from the start of the line.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
 // 12.1 Unicode Format-Control Characters
 // https://262.ecma-international.org/16.0/#sec-unicode-format-control-characters
 // const ZWNJ: char = '\u{200C}'; // Used in IdentifierPart
@@ -124,21 +169,107 @@ pub(crate) struct Lexer<'a> {
     source: &'a str,
     chars: Vec<(usize, char)>,
     pos: usize,
+    /// The byte offset at which each line of the source text starts.
+    line_starts: Vec<usize>,
+    /// Whether the source text is lexed with the goal symbol Module, where HTML-like comments are not recognised.
+    module: bool,
+    /// The errors found so far, in the order of the source text, after each of which lexing carried on.
+    diagnostics: Vec<LexerDiagnostic>,
 }
 
 impl<'a> Lexer<'a> {
     pub(crate) fn new(input: &'a str) -> Self {
+        let chars = input.char_indices().collect::<Vec<_>>();
+
+        // NOTE: A CARRIAGE RETURN followed by a LINE FEED ends a single line.
+        let line_starts = std::iter::once(0)
+            .chain(
+                chars
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, (_, ch))| {
+                        is_char_line_terminator(*ch)
+                            && !(*ch == '\r'
+                                && chars.get(index + 1).is_some_and(|(_, next)| *next == '\n'))
+                    })
+                    .map(|(_, (offset, ch))| offset + ch.len_utf8()),
+            )
+            .collect();
+
         Self {
             source: input,
-            chars: input.char_indices().collect(),
+            chars,
             pos: 0,
+            line_starts,
+            module: false,
+            diagnostics: vec![],
+        }
+    }
+
+    /// Lexes the source text with the goal symbol Module rather than Script.
+    pub(crate) fn set_module_goal(&mut self) {
+        self.module = true;
+    }
+
+    /// The line and column of the byte offset in the source text.
+    pub(crate) fn source_position(&self, offset: usize) -> SourcePosition {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+
+        SourcePosition {
+            line,
+            column: self.source[line_start..offset].chars().count() + 1,
         }
     }
 
+    /// The source text of a line, counted from 1, without the line terminator which ends it.
+    pub(crate) fn line_text(&self, line: usize) -> &'a str {
+        let start = self.line_starts[line - 1];
+
+        self.source[start..]
+            .split(is_char_line_terminator)
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// The position of the next character to be lexed, which can later be restored with [`Lexer::rewind`].
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns to a position, forgetting the errors found after it, as they are found again when the source text after it is lexed again.
+    pub(crate) fn rewind(&mut self, position: usize) {
+        self.pos = position;
+
+        let offset = self.current_byte_pos();
+
+        self.diagnostics
+            .retain(|diagnostic| diagnostic.span.start < offset);
+    }
+
+    /// The errors found in the source text lexed so far.
+    pub(crate) fn diagnostics(&self) -> &[LexerDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Whether the source text between the byte offsets `start` and `end` contains a LineTerminator, for the grammar's [no LineTerminator here] restrictions.
+    pub(crate) fn has_line_terminator(&self, start: usize, end: usize) -> bool {
+        self.source[start..end].chars().any(is_char_line_terminator)
+    }
+
     fn error<T>(&self, error_type: LexerError) -> Result<T, LexerError> {
         Err(error_type)
     }
 
+    /// Records an error found in the source text from `start` up to the current position.
+    fn report(&mut self, error: LexerError, start: usize) {
+        let span = Span::new(start, self.current_byte_pos());
+
+        trace!(Lexer, Debug, "{error} at {}..{}", span.start, span.end);
+
+        self.diagnostics.push(LexerDiagnostic { error, span });
+    }
+
     fn current(&self) -> char {
         self.chars[self.pos].1
     }
@@ -200,8 +331,11 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Returns the character the given number of characters ahead, or a NUL character past the end of the source.
     fn peek_char(&self, n_chars: usize) -> char {
-        self.chars[self.pos + n_chars].1
+        self.chars
+            .get(self.pos + n_chars)
+            .map_or('\0', |(_, ch)| *ch)
     }
 
     // 12.2 White Space
@@ -209,18 +343,87 @@ impl<'a> Lexer<'a> {
 
     // 12.3 Line Terminators
     // https://262.ecma-international.org/16.0/#sec-line-terminators
-    fn js_skip_whitespace_and_line_terminators(&mut self) {
+
+    // 12.4 Comments
+    // https://262.ecma-international.org/16.0/#sec-comments
+    //
+    // NOTE: Comments are skipped along with white space and line terminators, as the parser finds the line terminators between two tokens, including those within a MultiLineComment, from the source text between their spans.
+    fn js_skip_whitespace_and_comments(&mut self) {
+        // 12.5 Hashbang Comments
+        // https://262.ecma-international.org/16.0/#sec-hashbang
+        // NOTE: A HashbangComment is only recognised at the start of the source text.
+        if self.pos == 0 && self.advance_if_2('#', '!') {
+            self.js_skip_single_line_comment();
+        }
+
+        // Whether a line terminator has been skipped since the previous token, after which an HTML close comment can begin.
+        let mut is_line_start = false;
+
         while !self.is_eof() {
+            let start = self.current_byte_pos();
             let ch = self.current();
 
-            if is_char_whitespace(ch) || is_char_line_terminator(ch) {
+            if is_char_whitespace(ch) {
+                self.advance();
+            } else if is_char_line_terminator(ch) {
                 self.advance();
+
+                is_line_start = true;
+            }
+            // SingleLineComment :: // SingleLineCommentChars opt
+            else if self.advance_if_2('/', '/') {
+                self.js_skip_single_line_comment();
+            }
+            // MultiLineComment :: /* MultiLineCommentChars opt */
+            else if self.advance_if_2('/', '*') {
+                match self.js_skip_multi_line_comment() {
+                    Ok(has_line_terminator) => is_line_start |= has_line_terminator,
+                    Err(error) => self.report(error, start),
+                }
+            }
+            // B.1.1 HTML-like Comments
+            // https://262.ecma-international.org/16.0/#sec-html-like-comments
+            // The syntax and semantics of B.1.1 is not applied when parsing source text using the goal symbol Module.
+            // SingleLineHTMLOpenComment :: <!-- SingleLineCommentChars opt
+            // SingleLineHTMLCloseComment :: LineTerminatorSequence HTMLCloseComment
+            else if !self.module
+                && ((ch == '<'
+                    && self.peek_char(1) == '!'
+                    && self.peek_char(2) == '-'
+                    && self.peek_char(3) == '-')
+                    || (is_line_start && self.advance_if_3('-', '-', '>')))
+            {
+                self.js_skip_single_line_comment();
             } else {
                 break;
             }
         }
     }
 
+    /// Skips the SingleLineCommentChars up to the next line terminator, which is not part of the comment.
+    fn js_skip_single_line_comment(&mut self) {
+        while !self.is_eof() && !is_char_line_terminator(self.current()) {
+            self.advance();
+        }
+    }
+
+    /// Skips the rest of a MultiLineComment after its opening `/*`, returning whether it contains a line terminator.
+    fn js_skip_multi_line_comment(&mut self) -> Result<bool, LexerError> {
+        let mut has_line_terminator = false;
+
+        while !self.is_eof() {
+            if self.advance_if_2('*', '/') {
+                return Ok(has_line_terminator);
+            }
+
+            has_line_terminator |= is_char_line_terminator(self.current());
+
+            self.advance();
+        }
+
+        self.error(LexerError::UnterminatedComment)
+    }
+
     // 12.7 Names and Keywords
     // https://262.ecma-international.org/16.0/#sec-names-and-keywords
     fn js_lex_identifier_name_or_keyword(&mut self) -> Result<Token<'a>, LexerError> {
@@ -457,7 +660,13 @@ impl<'a> Lexer<'a> {
                     Token::NullishCoalescingAssign
                 } else if self.advance_if('?') {
                     Token::NullishCoalescing
-                } else if self.advance_if('.') {
+                } else if !self.is_eof()
+                    && self.current() == '.'
+                    // OptionalChainingPunctuator :: ?. [lookahead ∉ DecimalDigit]
+                    && !self.peek_char(1).is_ascii_digit()
+                {
+                    self.advance();
+
                     Token::OptionalChaining
                 } else {
                     Token::Question
@@ -501,7 +710,7 @@ impl<'a> Lexer<'a> {
             self.advance();
         }
 
-        self.pos
+        self.current_byte_pos()
     }
 
     // 12.9.4 String Literals
@@ -513,13 +722,22 @@ impl<'a> Lexer<'a> {
 
         self.advance(); // Eat the opening quote.
 
-        while !self.is_eof() {
-            if self.current() == opening_quote_char {
-                self.advance(); // Eat the closing quote.
+        loop {
+            // NOTE: A LINE SEPARATOR or PARAGRAPH SEPARATOR can appear in a string literal, but a LINE FEED or CARRIAGE RETURN has to be escaped.
+            if self.is_eof() || matches!(self.current(), '\u{000A}' | '\u{000D}') {
+                return self.error(LexerError::UnterminatedString);
+            }
 
+            if self.advance_if(opening_quote_char) {
                 break;
             }
 
+            // NOTE: The character after a backslash is part of its escape sequence, so an escaped quote or line terminator does not end the string, and neither does the LINE FEED of an escaped CARRIAGE RETURN LINE FEED.
+            if self.advance_if('\\') && (self.is_eof() || self.advance_if_2('\u{000D}', '\u{000A}'))
+            {
+                continue;
+            }
+
             self.advance();
         }
 
@@ -527,30 +745,132 @@ impl<'a> Lexer<'a> {
             self.source_str(start, self.current_byte_pos()),
         ))
     }
+
+    // 12.9.6 Template Literal Lexical Components
+    // https://262.ecma-international.org/16.0/#prod-Template
+    fn js_lex_template(&mut self) -> Result<Token<'a>, LexerError> {
+        self.advance(); // Eat the opening '`'.
+
+        self.js_lex_template_characters(Token::TemplateNoSubstitution, Token::TemplateHead)
+    }
+
+    /// Lexes TemplateCharacters up to the '`' which ends the template, giving the `end` token, or the '${' which starts a substitution, giving the `substitution` token.
+    fn js_lex_template_characters(
+        &mut self,
+        end: fn(&'a str) -> Token<'a>,
+        substitution: fn(&'a str) -> Token<'a>,
+    ) -> Result<Token<'a>, LexerError> {
+        let start = self.current_byte_pos();
+
+        loop {
+            if self.is_eof() {
+                return self.error(LexerError::UnterminatedTemplate);
+            }
+
+            let characters_end = self.current_byte_pos();
+
+            if self.advance_if('`') {
+                return Ok(end(self.source_str(start, characters_end)));
+            }
+
+            if self.advance_if_2('$', '{') {
+                return Ok(substitution(self.source_str(start, characters_end)));
+            }
+
+            // NOTE: The character after a backslash is part of its escape sequence, so an escaped '`' or '$' does not end the TemplateCharacters.
+            if self.current() == '\\' {
+                self.advance();
+            }
+
+            if !self.is_eof() {
+                self.advance();
+            }
+        }
+    }
 }
 
-impl<'a> Iterator for Lexer<'a> {
-    type Item = Token<'a>;
+impl<'a> Lexer<'a> {
+    /// Lexes the next token along with the span of source text it was read from.
+    ///
+    /// An error in the source text is recorded in [`Lexer::diagnostics`] rather than ending the token stream, and lexing carries on from the character after the one it was found at.
+    pub(crate) fn next_token(&mut self) -> Option<(Token<'a>, Span)> {
+        loop {
+            if self.is_eof() {
+                return None;
+            }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.is_eof() {
-            return None;
+            self.js_skip_whitespace_and_comments();
+
+            let start = self.current_byte_pos();
+
+            if self.is_eof() {
+                return Some((Token::Eof, Span::new(start, start)));
+            }
+
+            let token = match self.current() {
+                '"' | '\'' => self.js_lex_string(),
+                '`' => self.js_lex_template(),
+                '0'..='9' => self.js_lex_number(),
+                ch if is_char_punctuator_start(ch) => self.js_lex_punctuator(),
+                ch if is_char_identifier_start(ch) => self.js_lex_identifier_name_or_keyword(),
+                _ => self.error(LexerError::UnexpectedChar),
+            };
+
+            match token {
+                Ok(token) => {
+                    let span = Span::new(start, self.current_byte_pos());
+
+                    trace!(Lexer, Trace, "{token} at {}..{}", span.start, span.end);
+
+                    return Some((token, span));
+                }
+                Err(error) => {
+                    if self.current_byte_pos() == start {
+                        self.advance();
+                    }
+
+                    self.report(error, start);
+                }
+            }
         }
+    }
+}
 
-        self.js_skip_whitespace_and_line_terminators();
+impl<'a> Lexer<'a> {
+    /// Lexes the '}' at the byte offset, which ends a substitution of a template, along with the TemplateCharacters after it as a TemplateMiddle or TemplateTail.
+    ///
+    /// NOTE: A '}' is lexed as a punctuator by [`Lexer::next_token`], as only the parser knows where the goal symbol is InputElementTemplateTail, so the source text after it is lexed again.
+    pub(crate) fn next_template_continuation(
+        &mut self,
+        offset: usize,
+    ) -> Option<(Token<'a>, Span)> {
+        let position = self.chars.partition_point(|(index, _)| *index < offset);
 
-        if self.is_eof() {
-            return Some(Token::Eof);
+        self.rewind(position);
+
+        self.advance(); // Eat the '}' token.
+
+        match self.js_lex_template_characters(Token::TemplateTail, Token::TemplateMiddle) {
+            Ok(token) => {
+                let span = Span::new(offset, self.current_byte_pos());
+
+                trace!(Lexer, Trace, "{token} at {}..{}", span.start, span.end);
+
+                Some((token, span))
+            }
+            Err(error) => {
+                self.report(error, offset);
+
+                None
+            }
         }
+    }
+}
 
-        let token = match self.current() {
-            '"' | '\'' => self.js_lex_string(),
-            '0'..='9' => self.js_lex_number(),
-            ch if is_char_punctuator_start(ch) => self.js_lex_punctuator(),
-            ch if is_char_identifier_start(ch) => self.js_lex_identifier_name_or_keyword(),
-            _ => self.error(LexerError::UnexpectedChar),
-        };
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Token<'a>;
 
-        token.ok()
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token().map(|(token, _)| token)
     }
 }
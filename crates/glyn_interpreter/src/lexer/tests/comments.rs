@@ -0,0 +1,105 @@
+#[test]
+fn single_line_comments() {
+    use crate::assert_lexer_eq;
+
+    assert_lexer_eq!(
+        "1 // + 2\n+ 3 //",
+        [Token::Int64("1"), Token::Plus, Token::Int64("3")]
+    );
+    assert_lexer_eq!("// 1\r\n2", [Token::Int64("2")]);
+}
+
+#[test]
+fn multi_line_comments() {
+    use crate::assert_lexer_eq;
+
+    assert_lexer_eq!(
+        "1 /* + 2 */ + /**/ 3",
+        [Token::Int64("1"), Token::Plus, Token::Int64("3")]
+    );
+    assert_lexer_eq!(
+        "1 /* * / \n */ + 2",
+        [Token::Int64("1"), Token::Plus, Token::Int64("2")]
+    );
+}
+
+#[test]
+fn unterminated_multi_line_comments() {
+    use crate::lexer::{Lexer, LexerDiagnostic, LexerError, Span, Token};
+
+    let mut lexer = Lexer::new("1 /* 2");
+
+    assert_eq!(lexer.next(), Some(Token::Int64("1")));
+    assert_eq!(lexer.next(), Some(Token::Eof));
+    assert_eq!(
+        lexer.diagnostics(),
+        [LexerDiagnostic {
+            error: LexerError::UnterminatedComment,
+            span: Span::new(2, 6),
+        }]
+    );
+}
+
+#[test]
+fn hashbang_comments() {
+    use crate::assert_lexer_eq;
+
+    assert_lexer_eq!("#!/usr/bin/env node\n1", [Token::Int64("1")]);
+}
+
+#[test]
+fn html_like_comments() {
+    use crate::assert_lexer_eq;
+
+    assert_lexer_eq!(
+        "1 <!-- + 2\n+ 3",
+        [Token::Int64("1"), Token::Plus, Token::Int64("3")]
+    );
+    assert_lexer_eq!(
+        "1\n--> + 2\n+ 3",
+        [Token::Int64("1"), Token::Plus, Token::Int64("3")]
+    );
+    assert_lexer_eq!(
+        "1 /*\n*/ --> + 2\n+ 3",
+        [Token::Int64("1"), Token::Plus, Token::Int64("3")]
+    );
+    assert_lexer_eq!(
+        "x --> 0",
+        [
+            Token::Ident("x"),
+            Token::Decrement,
+            Token::GreaterThan,
+            Token::Int64("0")
+        ]
+    );
+}
+
+#[test]
+fn html_like_comments_in_modules() {
+    use crate::lexer::{Lexer, Token};
+
+    let mut lexer = Lexer::new("x <!-- y");
+
+    lexer.set_module_goal();
+
+    assert_eq!(
+        lexer.collect::<Vec<_>>(),
+        vec![
+            Token::Ident("x"),
+            Token::LessThan,
+            Token::Not,
+            Token::Decrement,
+            Token::Ident("y")
+        ]
+    );
+}
+
+#[test]
+fn line_terminators_in_comments() {
+    use crate::lexer::Lexer;
+
+    let lexer = Lexer::new("a /*\n*/ b /* */ c");
+
+    assert!(lexer.has_line_terminator(1, 8));
+    assert!(!lexer.has_line_terminator(9, 16));
+}
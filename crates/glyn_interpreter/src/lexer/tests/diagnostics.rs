@@ -0,0 +1,44 @@
+#[test]
+fn lexing_continues_after_unexpected_characters() {
+    use crate::lexer::{Lexer, LexerDiagnostic, LexerError, Span, Token};
+
+    let mut lexer = Lexer::new("1 @ + ¬2");
+
+    assert_eq!(
+        lexer.by_ref().collect::<Vec<_>>(),
+        vec![Token::Int64("1"), Token::Plus, Token::Int64("2")]
+    );
+    assert_eq!(
+        lexer.diagnostics(),
+        [
+            LexerDiagnostic {
+                error: LexerError::UnexpectedChar,
+                span: Span::new(2, 3),
+            },
+            LexerDiagnostic {
+                error: LexerError::UnexpectedChar,
+                span: Span::new(6, 8),
+            },
+        ]
+    );
+}
+
+#[test]
+fn rewinding_forgets_the_errors_after_the_position() {
+    use crate::lexer::{Lexer, Token};
+
+    let mut lexer = Lexer::new("a @ b");
+
+    lexer.next();
+
+    let position = lexer.position();
+
+    assert_eq!(lexer.next(), Some(Token::Ident("b")));
+    assert_eq!(lexer.diagnostics().len(), 1);
+
+    lexer.rewind(position);
+
+    assert!(lexer.diagnostics().is_empty());
+    assert_eq!(lexer.next(), Some(Token::Ident("b")));
+    assert_eq!(lexer.diagnostics().len(), 1);
+}
@@ -4,4 +4,5 @@ use crate::assert_lexer_eq;
 fn identifiers() {
     assert_lexer_eq!("helloWorld", [Token::Ident("helloWorld")]);
     assert_lexer_eq!("HelloWorld", [Token::Ident("HelloWorld")]);
+    assert_lexer_eq!("print", [Token::Ident("print")]);
 }
@@ -1,7 +1,11 @@
+mod comments;
 mod common;
+mod diagnostics;
 mod identifiers;
 mod keywords;
 mod numbers;
+mod positions;
 mod strings;
+mod templates;
 mod terminals;
 mod whitespace;
@@ -0,0 +1,15 @@
+#[test]
+fn source_positions() {
+    use crate::lexer::{Lexer, SourcePosition};
+
+    let position = |input, offset| Lexer::new(input).source_position(offset);
+
+    assert_eq!(position("a + b", 4), SourcePosition { line: 1, column: 5 });
+    assert_eq!(position("a\nb", 2), SourcePosition { line: 2, column: 1 });
+    assert_eq!(position("a\r\nb", 3), SourcePosition { line: 2, column: 1 });
+    assert_eq!(position("a\r\rb", 3), SourcePosition { line: 3, column: 1 });
+    assert_eq!(
+        position("'é' +\u{2028}  x", 11),
+        SourcePosition { line: 2, column: 3 }
+    );
+}
@@ -13,3 +13,61 @@ fn strings() {
         [Token::String(r#""function if else""#)]
     );
 }
+
+#[test]
+fn escaped_quotes_and_line_terminators() {
+    assert_lexer_eq!(
+        r#""a\"b" 1"#,
+        [Token::String(r#""a\"b""#), Token::Int64("1")]
+    );
+    assert_lexer_eq!("'a\\'b'", [Token::String("'a\\'b'")]);
+    assert_lexer_eq!("\"a\\\nb\"", [Token::String("\"a\\\nb\"")]);
+    assert_lexer_eq!("\"a\\\r\nb\"", [Token::String("\"a\\\r\nb\"")]);
+    assert_lexer_eq!("\"a\u{2028}b\"", [Token::String("\"a\u{2028}b\"")]);
+}
+
+#[test]
+fn unterminated_strings() {
+    use crate::lexer::{Lexer, LexerDiagnostic, LexerError, Span, Token};
+
+    let mut lexer = Lexer::new("1 \"abc");
+
+    assert_eq!(lexer.next(), Some(Token::Int64("1")));
+    assert_eq!(lexer.next(), None);
+    assert_eq!(
+        lexer.diagnostics(),
+        [LexerDiagnostic {
+            error: LexerError::UnterminatedString,
+            span: Span::new(2, 6),
+        }]
+    );
+
+    let mut lexer = Lexer::new("'abc\\'\n1");
+
+    assert_eq!(lexer.next(), Some(Token::Int64("1")));
+    assert_eq!(
+        lexer.diagnostics(),
+        [LexerDiagnostic {
+            error: LexerError::UnterminatedString,
+            span: Span::new(0, 6),
+        }]
+    );
+
+    let mut lexer = Lexer::new("\"abc\r\"");
+
+    lexer.by_ref().for_each(drop);
+
+    assert_eq!(
+        lexer.diagnostics(),
+        [
+            LexerDiagnostic {
+                error: LexerError::UnterminatedString,
+                span: Span::new(0, 4),
+            },
+            LexerDiagnostic {
+                error: LexerError::UnterminatedString,
+                span: Span::new(5, 6),
+            }
+        ]
+    );
+}
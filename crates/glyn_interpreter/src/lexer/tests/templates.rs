@@ -0,0 +1,39 @@
+#[test]
+fn templates() {
+    use crate::assert_lexer_eq;
+
+    assert_lexer_eq!("``", [Token::TemplateNoSubstitution("")]);
+    assert_lexer_eq!(
+        r"`a\`b${c`",
+        [Token::TemplateHead(r"a\`b"), Token::Ident("c")]
+    );
+    assert_lexer_eq!("`a${b", [Token::TemplateHead("a"), Token::Ident("b")]);
+}
+
+#[test]
+fn template_continuations() {
+    use crate::lexer::{Lexer, Span, Token};
+
+    let mut lexer = Lexer::new("`a${b}c${d}e`");
+
+    assert_eq!(
+        lexer.next_token(),
+        Some((Token::TemplateHead("a"), Span::new(0, 4)))
+    );
+    assert_eq!(
+        lexer.next_token(),
+        Some((Token::Ident("b"), Span::new(4, 5)))
+    );
+    assert_eq!(
+        lexer.next_template_continuation(5),
+        Some((Token::TemplateMiddle("c"), Span::new(5, 9)))
+    );
+    assert_eq!(
+        lexer.next_token(),
+        Some((Token::Ident("d"), Span::new(9, 10)))
+    );
+    assert_eq!(
+        lexer.next_template_continuation(10),
+        Some((Token::TemplateTail("e"), Span::new(10, 13)))
+    );
+}
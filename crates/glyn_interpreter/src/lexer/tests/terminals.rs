@@ -8,3 +8,11 @@ fn terminals() {
     assert_lexer_eq!("}", [Token::RightBrace]);
     assert_lexer_eq!(";", [Token::Semicolon]);
 }
+
+#[test]
+fn optional_chaining() {
+    assert_lexer_eq!("?.", [Token::OptionalChaining]);
+    assert_lexer_eq!("?.[", [Token::OptionalChaining, Token::LeftBracket]);
+    assert_lexer_eq!("?.5", [Token::Question]);
+    assert_lexer_eq!("??", [Token::NullishCoalescing]);
+}
@@ -61,9 +61,6 @@ pub(crate) enum Keyword {
     Of,
     Set,
     Target,
-
-    // Utility
-    Print,
 }
 
 impl std::fmt::Display for Keyword {
@@ -122,7 +119,6 @@ impl std::fmt::Display for Keyword {
             Keyword::Of => write!(f, "of"),
             Keyword::Set => write!(f, "set"),
             Keyword::Target => write!(f, "target"),
-            Keyword::Print => write!(f, "print"),
         }
     }
 }
@@ -185,7 +181,6 @@ impl TryFrom<&str> for Keyword {
             "of" => Ok(Keyword::Of),
             "set" => Ok(Keyword::Set),
             "target" => Ok(Keyword::Target),
-            "print" => Ok(Keyword::Print),
             _ => Err(()),
         }
     }
@@ -265,10 +260,11 @@ pub(crate) enum Token<'a> {
     DivideAssign,
 
     // Template Literals
-    TemplateNoSubstitution,
-    TemplateHead,
-    TemplateMiddle,
-    TemplateTail,
+    // NOTE: Each template token holds the source text of its TemplateCharacters, without the delimiters around them.
+    TemplateNoSubstitution(&'a str),
+    TemplateHead(&'a str),
+    TemplateMiddle(&'a str),
+    TemplateTail(&'a str),
 
     // Utility
     Illegal,
@@ -298,16 +294,19 @@ impl<'a> Token<'a> {
     // 12.9.6 Template Literal Lexical Components
     // https://262.ecma-international.org/16.0/#sec-template-literal-lexical-components
     pub(crate) fn is_template_start(&self) -> bool {
-        matches!(self, Token::TemplateNoSubstitution | Token::TemplateHead)
+        matches!(
+            self,
+            Token::TemplateNoSubstitution(_) | Token::TemplateHead(_)
+        )
     }
 
     pub(crate) fn is_template_part(&self) -> bool {
         matches!(
             self,
-            Token::TemplateNoSubstitution
-                | Token::TemplateHead
-                | Token::TemplateMiddle
-                | Token::TemplateTail
+            Token::TemplateNoSubstitution(_)
+                | Token::TemplateHead(_)
+                | Token::TemplateMiddle(_)
+                | Token::TemplateTail(_)
         )
     }
 
@@ -364,6 +363,25 @@ impl<'a> Token<'a> {
         )
     }
 
+    // 13.1.1 Static Semantics: Early Errors
+    // https://262.ecma-international.org/16.0/#sec-identifiers-static-semantics-early-errors
+    pub(crate) fn is_strict_mode_reserved_word(&self) -> bool {
+        matches!(
+            self,
+            Token::Keyword(
+                Keyword::Implements
+                    | Keyword::Interface
+                    | Keyword::Let
+                    | Keyword::Package
+                    | Keyword::Private
+                    | Keyword::Protected
+                    | Keyword::Public
+                    | Keyword::Static
+                    | Keyword::Yield
+            )
+        )
+    }
+
     // 13.1 Identifiers
     // https://262.ecma-international.org/16.0/#prod-BindingIdentifier
     pub(crate) fn is_binding_identifier(&self) -> bool {
@@ -614,10 +632,10 @@ impl Display for Token<'_> {
             Token::DivideAssign => write!(f, "/="),
 
             // Template Literals
-            Token::TemplateNoSubstitution => write!(f, "`"),
-            Token::TemplateHead => write!(f, "`"),
-            Token::TemplateMiddle => write!(f, "${{"),
-            Token::TemplateTail => write!(f, "`"),
+            Token::TemplateNoSubstitution(value) => write!(f, "`{value}`"),
+            Token::TemplateHead(value) => write!(f, "`{value}${{"),
+            Token::TemplateMiddle(value) => write!(f, "}}{value}${{"),
+            Token::TemplateTail(value) => write!(f, "}}{value}`"),
 
             // Utility
             Token::Illegal => write!(f, "ILLEGAL"),
@@ -626,6 +644,62 @@ impl Display for Token<'_> {
     }
 }
 
+/// The kind of a token, which leaves out the source text of identifiers and literals so that it can outlive the source text, as the tokens of a parse error do.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum TokenKind {
+    Keyword(Keyword),
+    Identifier,
+    PrivateIdentifier,
+    String,
+    Number,
+    BigInt,
+    RegularExpression,
+    Template,
+    /// A punctuator, as it is written in the source text.
+    Punctuator(String),
+    Illegal,
+    Eof,
+}
+
+impl From<&Token<'_>> for TokenKind {
+    fn from(token: &Token<'_>) -> Self {
+        match token {
+            Token::Keyword(keyword) => TokenKind::Keyword(keyword.clone()),
+            Token::Ident(_) => TokenKind::Identifier,
+            Token::PrivateIdentifier(_) => TokenKind::PrivateIdentifier,
+            Token::String(_) => TokenKind::String,
+            Token::Int64(_) | Token::Float64(_) => TokenKind::Number,
+            Token::BigIntLiteral(_) => TokenKind::BigInt,
+            Token::RegularExpressionLiteral(_) => TokenKind::RegularExpression,
+            Token::TemplateNoSubstitution(_)
+            | Token::TemplateHead(_)
+            | Token::TemplateMiddle(_)
+            | Token::TemplateTail(_) => TokenKind::Template,
+            Token::Illegal => TokenKind::Illegal,
+            Token::Eof => TokenKind::Eof,
+            punctuator => TokenKind::Punctuator(punctuator.to_string()),
+        }
+    }
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            TokenKind::Keyword(keyword) => write!(f, "'{keyword}'"),
+            TokenKind::Identifier => write!(f, "identifier"),
+            TokenKind::PrivateIdentifier => write!(f, "private identifier"),
+            TokenKind::String => write!(f, "string"),
+            TokenKind::Number => write!(f, "number"),
+            TokenKind::BigInt => write!(f, "bigint"),
+            TokenKind::RegularExpression => write!(f, "regular expression"),
+            TokenKind::Template => write!(f, "template string"),
+            TokenKind::Punctuator(punctuator) => write!(f, "'{punctuator}'"),
+            TokenKind::Illegal => write!(f, "illegal token"),
+            TokenKind::Eof => write!(f, "end of input"),
+        }
+    }
+}
+
 /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Operator_precedence#table
 #[derive(Debug, PartialEq, PartialOrd)]
 pub(crate) enum BinOpPrecedence {
@@ -1,3 +1,13 @@
+#[cfg(feature = "debug")]
+#[macro_use]
+mod trace;
+
+/// Tracing compiles to nothing without the `debug` feature, so its arguments are never evaluated.
+#[cfg(not(feature = "debug"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {};
+}
+
 mod abstract_ops;
 mod codegen;
 mod eval_script;
@@ -8,6 +18,38 @@ mod runtime;
 mod value;
 mod vm;
 
-pub use eval_script::eval_script;
+pub use codegen::bytecode::disassembler::{
+    compile_script, disassemble, CompiledScript, DecodedInstruction,
+};
+pub use codegen::bytecode::json::script_to_bytecode_json;
+pub use codegen::bytecode::optimizer::OptimizationLevel;
+pub use codegen::warning::{Warning, WarningKind};
+pub use eval_script::{
+    eval_module, eval_script, eval_script_in_realm, EvalError, SyntaxDiagnostic,
+};
+pub use gc::HeapStats;
+pub use lexer::SourcePosition;
 pub use runtime::agent::JSAgent;
+pub use runtime::agent_builder::JSAgentBuilder;
+pub use runtime::class::{ClassMethod, ClassMethods, JSClass};
+pub use runtime::clock::{FixedClock, HostClock, SystemClock};
+pub use runtime::completion::ThrowCompletion;
+pub use runtime::console::{ConsoleSink, LogLevel, StdioSink, WriterSink};
+pub use runtime::debugger::{DebugContext, Debugger};
+pub use runtime::host_hooks::{HostHooks, RejectionOperation, UnhandledRejection};
+pub use runtime::native_function::{Args, NativeFunction};
+pub use runtime::profile::{OpcodeStats, Profile};
+pub use runtime::realm::JSRealm;
+pub use runtime::root::{Root, Rootable};
+pub use runtime::structured_clone::{structured_clone, Transferable};
+pub use runtime::watchpoint::{WatchCallback, WatchEvent, WatchTarget, WatchpointId};
+#[cfg(feature = "debug")]
+pub use trace::{set_trace_level, TraceLevel, TraceSubsystem};
+pub use value::big_int::JSBigInt;
+#[cfg(feature = "serde")]
+pub use value::interop::{from_js, to_js};
+pub use value::number::JSNumber;
+pub use value::object::ObjectAddr;
+pub use value::string::JSString;
+pub use value::symbol::JSSymbol;
 pub use value::JSValue;
@@ -1,7 +1,44 @@
+use crate::abstract_ops::function_operations::create_builtin_function;
+use crate::abstract_ops::object_operations::{call, define_property_or_throw};
+use crate::abstract_ops::ordinary::ordinary_object_create;
+use crate::abstract_ops::realm::initialize_host_defined_realm;
+use crate::codegen::bytecode::optimizer::OptimizationLevel;
+use crate::codegen::warning::Warning;
+use crate::eval_script::EvalError;
+use crate::gc::{Heap, HeapStats, Nursery};
+use crate::intrinsics::native_error_constructor::NativeErrorKind;
+use crate::lexer::SourcePosition;
+use crate::runtime::agent_builder::JSAgentBuilder;
+use crate::runtime::class::{define_class, JSClass};
+use crate::runtime::clock::{HostClock, SystemClock};
+use crate::runtime::completion::{throw_native_error, CompletionRecord, ThrowCompletion};
+use crate::runtime::console::{ConsoleSink, LogLevel, StdioSink};
+use crate::runtime::debugger::{DebugContext, Debugger};
 use crate::runtime::environment::Environment;
 use crate::runtime::execution_context::ExecutionContext;
-use crate::runtime::realm::RealmAddr;
+use crate::runtime::host_hooks::{DefaultHostHooks, HostHooks, UnhandledRejection};
+use crate::runtime::job::Job;
+use crate::runtime::module::{ModuleAddr, ModuleLoader};
+use crate::runtime::native_function::NativeFunction;
+use crate::runtime::profile::{Profile, Profiler};
+use crate::runtime::realm::{JSRealm, RealmAddr};
+use crate::runtime::root::{Root, Rootable, Roots};
+use crate::runtime::stack_trace::{CallFrame, DEFAULT_STACK_TRACE_LIMIT};
+use crate::runtime::timers::{self, Timers};
+use crate::runtime::watchpoint::{WatchEvent, WatchTarget, WatchpointId, Watchpoints};
+use crate::value::object::internal_slots::{HostData, InternalSlotName};
+use crate::value::object::property::{JSObjectPropDescriptor, JSObjectPropKey};
+use crate::value::object::shape::Shape;
+use crate::value::object::{ObjectAddr, ObjectData, ObjectMeta};
+use crate::value::string::JSString;
+use crate::value::JSValue;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::fmt::Display;
+use std::rc::Rc;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::Duration;
 
 /// 6.1.5.1 Well-Known Symbols
 /// https://262.ecma-international.org/16.0/#sec-well-known-symbols
@@ -44,20 +81,788 @@ pub(crate) const WELL_KNOWN_SYMBOLS_TO_PRIMITIVE: WellKnownSymbols = WellKnownSy
 pub(crate) const WELL_KNOWN_SYMBOLS_TO_STRING_TAG: WellKnownSymbols = WellKnownSymbols::ToStringTag;
 pub(crate) const WELL_KNOWN_SYMBOLS_UNSCOPABLES: WellKnownSymbols = WellKnownSymbols::Unscopables;
 
+/// 9.7 Agents
+/// https://262.ecma-international.org/16.0/#sec-agents
+///
+/// An agent holds all of the state of the scripts it evaluates, so agents can be created and run on different threads at the same time. The objects of an agent are reference counted without synchronization, which makes an agent confined to the thread that created it.
 #[derive(Default)]
 pub struct JSAgent {
     pub(crate) execution_contexts: Vec<ExecutionContext>,
     environment_records: Vec<Environment>,
+    pub(crate) warnings: Vec<Warning>,
+    pub(crate) watchpoints: Watchpoints,
+    /// The debugger set by the embedder, which is taken out of the agent while one of its callbacks is running.
+    debugger: Option<Box<dyn Debugger>>,
+    /// The lines on which a statement starting calls [`Debugger::on_breakpoint`].
+    breakpoints: BTreeSet<usize>,
+    /// The pending Jobs enqueued by HostEnqueuePromiseJob, in the order they must run.
+    jobs: VecDeque<Job>,
+    /// The modules loaded so far, keyed by their specifiers.
+    pub(crate) modules: HashMap<JSString, ModuleAddr>,
+    pub(crate) module_loader: Option<ModuleLoader>,
+    /// The clock set by the embedder, which is the system clock if none has been set.
+    clock: Option<Box<dyn HostClock>>,
+    /// The sink set by the embedder for the messages of the `console` object, which is stdout and stderr if none has been set.
+    console: Option<Box<dyn ConsoleSink>>,
+    /// The hooks set by the embedder, which customize nothing if none have been set.
+    host_hooks: Option<Box<dyn HostHooks>>,
+    /// The promises which were rejected while they had no handlers and have not been given one since, in the order they were rejected.
+    pub(crate) unhandled_rejections: Vec<ObjectAddr>,
+    /// The tasks enqueued by the embedder with [`JSAgent::enqueue_task`], in the order they must run.
+    tasks: VecDeque<Task>,
+    /// The timers scheduled by `setTimeout` and `setInterval`, which are run by [`JSAgent::run_due_timers`].
+    pub(crate) timers: Timers,
+    /// The times at which the timers started by `console.time` were started, keyed by their labels.
+    pub(crate) console_timers: HashMap<String, f64>,
+    /// The first internal VM error since evaluation last began, which is reported in place of the value it was thrown as.
+    pub(crate) internal_error: Option<String>,
+    /// The value most recently thrown by bytecode, along with the line and column of the statement it was thrown from and the stack trace of the calls it was thrown in, which are reported if the value is not caught.
+    pub(crate) thrown_at: Option<(JSValue, SourcePosition, String)>,
+    /// The frames of the scripts, modules and function bodies the VM is evaluating, from the outermost inwards.
+    pub(crate) call_frames: Vec<CallFrame>,
+    /// The number of call frames a stack trace records set by the embedder, which is [`DEFAULT_STACK_TRACE_LIMIT`] if none has been set.
+    stack_trace_limit: Option<usize>,
+    /// The maximum depth of the execution context stack set by the embedder, which is [`DEFAULT_MAX_CALL_DEPTH`] if none has been set.
+    max_call_depth: Option<usize>,
+    /// The number of instructions the VM can still execute, or None if evaluation is not metered.
+    fuel: Option<u64>,
+    /// Whether running out of fuel throws an error which scripts can catch, rather than aborting evaluation.
+    fuel_catchable: bool,
+    /// The level at which scripts and modules are optimized once they have been compiled.
+    pub(crate) optimization_level: OptimizationLevel,
+    /// Whether every script is strict mode code, as if it began with a Use Strict Directive.
+    pub(crate) strict_only: bool,
+    /// Whether the realms created from now on leave out the additional built-in properties of Annex B.
+    #[cfg(feature = "annex-b")]
+    annex_b_disabled: bool,
+    /// The values allocated by the agent, which are freed once they can no longer be reached.
+    pub(crate) heap: Heap,
+    /// The objects allocated most recently, whose allocations are reused once they are no longer referenced.
+    pub(crate) object_nursery: Nursery<ObjectData>,
+    /// The environments allocated most recently, whose allocations are reused once they are no longer referenced.
+    pub(crate) environment_nursery: Nursery<Environment>,
+    /// The shape of objects with no properties, which every object created by the agent starts out with.
+    pub(crate) empty_shape: Rc<Shape>,
+    /// Whether the heap grew beyond its limit since evaluation last began, which aborts the evaluation even once unwinding it has freed enough of the heap.
+    out_of_memory: bool,
+    /// The values rooted by the embedder.
+    roots: Roots,
+    /// The statistics being recorded, or None if the agent is not profiling.
+    profiler: Option<Box<Profiler>>,
 }
 
+/// Defines a property of the global object of the realm for a value defined by the embedder.
+fn define_global_property(realm: &RealmAddr, key: &JSObjectPropKey, value: JSValue) {
+    let global_object = realm.borrow().global_object.clone().unwrap();
+
+    // Every other data property described in clauses 19 through 28 and in Annex B.2 has the attributes { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true } unless otherwise specified.
+    let _ = define_property_or_throw(
+        &global_object,
+        key,
+        JSObjectPropDescriptor {
+            value: Some(value),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    );
+}
+
+/// A task enqueued by the embedder, which is run by [`JSAgent::run_until_idle`].
+type Task = Box<dyn FnOnce(&mut JSAgent) -> Result<(), EvalError>>;
+
+/// The default maximum depth of the execution context stack, which leaves enough of the 2 MiB stack of a spawned thread for a call to throw rather than overflow, even in a debug build.
+pub(crate) const DEFAULT_MAX_CALL_DEPTH: usize = 200;
+
 impl JSAgent {
+    /// Creates a builder which configures an agent before it evaluates anything.
+    pub fn builder() -> JSAgentBuilder {
+        JSAgentBuilder::default()
+    }
+
     pub(crate) fn new() -> Self {
         Self {
             execution_contexts: vec![],
             environment_records: vec![],
+            warnings: vec![],
+            watchpoints: Watchpoints::default(),
+            debugger: None,
+            breakpoints: BTreeSet::new(),
+            jobs: VecDeque::new(),
+            modules: HashMap::new(),
+            module_loader: None,
+            clock: None,
+            console: None,
+            host_hooks: None,
+            unhandled_rejections: vec![],
+            tasks: VecDeque::new(),
+            timers: Timers::default(),
+            console_timers: HashMap::new(),
+            internal_error: None,
+            thrown_at: None,
+            call_frames: vec![],
+            stack_trace_limit: None,
+            max_call_depth: None,
+            fuel: None,
+            fuel_catchable: false,
+            optimization_level: OptimizationLevel::None,
+            strict_only: false,
+            #[cfg(feature = "annex-b")]
+            annex_b_disabled: false,
+            heap: Heap::default(),
+            object_nursery: Nursery::default(),
+            environment_nursery: Nursery::default(),
+            empty_shape: Rc::default(),
+            out_of_memory: false,
+            profiler: None,
+            roots: Roots::default(),
+        }
+    }
+
+    /// Defines a global function named `name` which calls the native function, where `length` is the number of arguments it expects.
+    pub fn register_function(&mut self, name: &str, length: usize, function: impl NativeFunction) {
+        self.initialize_realm();
+
+        let realm = self.current_realm();
+
+        self.define_global_function(realm, name, length, function);
+    }
+
+    /// Defines a global function named `name` in a realm created with [`JSAgent::create_realm`], like [`JSAgent::register_function`] does in the realm of the agent.
+    pub fn register_function_in_realm(
+        &mut self,
+        realm: &JSRealm,
+        name: &str,
+        length: usize,
+        function: impl NativeFunction,
+    ) {
+        self.define_global_function(realm.0.clone(), name, length, function);
+    }
+
+    fn define_global_function(
+        &mut self,
+        realm: RealmAddr,
+        name: &str,
+        length: usize,
+        function: impl NativeFunction,
+    ) {
+        let key = JSObjectPropKey::from(JSString::from(name));
+
+        let function_obj = create_builtin_function(
+            self,
+            function,
+            length,
+            key.clone(),
+            vec![],
+            Some(realm.clone()),
+            None,
+            None,
+        );
+
+        define_global_property(&realm, &key, JSValue::from(function_obj));
+    }
+
+    /// Defines a global constructor named after the class, whose instances hold a value of the class and have its methods.
+    ///
+    /// Calling the constructor without `new` throws a TypeError, as does calling a method on an object which is not an instance of the class.
+    pub fn register_class<T: JSClass>(&mut self) {
+        self.initialize_realm();
+
+        let realm = self.current_realm();
+        let constructor = define_class::<T>(self, &realm);
+
+        define_global_property(
+            &realm,
+            &JSObjectPropKey::from(JSString::from(T::NAME)),
+            JSValue::from(constructor),
+        );
+    }
+
+    /// Creates an object which holds the Rust value, like a handle to a resource of the host, which scripts can pass around and host functions can read with [`JSAgent::host_data`].
+    pub fn create_host_object<T: Any>(&mut self, value: T) -> JSValue {
+        self.initialize_realm();
+
+        let object_prototype = self
+            .current_realm()
+            .borrow()
+            .intrinsics
+            .object_prototype
+            .clone();
+
+        let object = ordinary_object_create(
+            self,
+            object_prototype,
+            Some(vec![InternalSlotName::HostData]),
+        );
+
+        object
+            .data_mut()
+            .slots_mut()
+            .set_host_data(HostData::new(value));
+
+        JSValue::from(object)
+    }
+
+    /// Attaches the Rust value to the object, replacing any value attached to it before, and throws a TypeError if the value is not an object.
+    ///
+    /// The value is dropped along with the object. Any script values the Rust value holds are not traced by the garbage collector, so they are kept alive for as long as the object is.
+    pub fn set_host_data<T: Any>(
+        &mut self,
+        object: &JSValue,
+        value: T,
+    ) -> Result<(), ThrowCompletion> {
+        let JSValue::Object(object) = object else {
+            return type_error(&format!(
+                "Host data can only be attached to an object, but got {}",
+                object.preview()
+            ));
+        };
+
+        object
+            .data_mut()
+            .slots_mut()
+            .set_host_data(HostData::new(value));
+
+        Ok(())
+    }
+
+    /// The Rust value attached to the object, or None if the value is not an object, has nothing attached or has a value of another type attached.
+    ///
+    /// The value is shared with the object, so it can be borrowed while scripts run and kept after the object is gone.
+    pub fn host_data<T: Any>(&self, object: &JSValue) -> Option<Rc<RefCell<T>>> {
+        let JSValue::Object(object) = object else {
+            return None;
+        };
+
+        object.borrow().slots().host_data()?.downcast::<T>()
+    }
+
+    /// Creates the realm shared by every script the agent evaluates, unless it already exists.
+    pub(crate) fn initialize_realm(&mut self) {
+        if self.execution_contexts.is_empty() {
+            let _ = initialize_host_defined_realm(self);
+        }
+    }
+
+    /// Creates a new realm, with its own global object and intrinsics, in which scripts can be evaluated with [`eval_script_in_realm`](crate::eval_script_in_realm).
+    ///
+    /// The realm of [`eval_script`](crate::eval_script) is left as the realm of the agent, so functions registered with [`JSAgent::register_function`] are not defined in the new realm.
+    pub fn create_realm(&mut self) -> JSRealm {
+        // NOTE: Defining the default global bindings can't fail, as the global object of a new realm is an ordinary extensible object with no properties.
+        let realm = initialize_host_defined_realm(self).unwrap();
+
+        // NOTE: The execution context pushed for the new realm is popped, so that the running execution context is unchanged.
+        self.pop_execution_context();
+
+        JSRealm(realm)
+    }
+
+    /// Sets the callback which returns the source text of a module imported by another, given the specifier of the module and the specifier of the module importing it.
+    ///
+    /// Each specifier is only loaded once, and the module it is loaded as is shared by every module which imports it.
+    pub fn set_module_loader(
+        &mut self,
+        loader: impl FnMut(&str, &str) -> Result<String, String> + 'static,
+    ) {
+        self.module_loader = Some(Box::new(loader));
+    }
+
+    /// Sets the clock which Date objects read the current time and the local time zone from.
+    pub fn set_clock(&mut self, clock: impl HostClock + 'static) {
+        self.clock = Some(Box::new(clock));
+    }
+
+    pub(crate) fn clock(&self) -> &dyn HostClock {
+        self.clock.as_deref().unwrap_or(&SystemClock)
+    }
+
+    /// Sets the sink which the `console` object of every realm of the agent writes its messages to.
+    pub fn set_console(&mut self, sink: impl ConsoleSink + 'static) {
+        self.console = Some(Box::new(sink));
+    }
+
+    pub(crate) fn write_console(&mut self, level: LogLevel, message: &str) {
+        match &mut self.console {
+            Some(sink) => sink.write(level, message),
+            None => StdioSink.write(level, message),
+        }
+    }
+
+    /// Sets the hooks which customize the host-defined operations of the specification, replacing any hooks set before.
+    pub fn set_host_hooks(&mut self, hooks: impl HostHooks + 'static) {
+        self.host_hooks = Some(Box::new(hooks));
+    }
+
+    pub(crate) fn with_host_hooks<R>(&mut self, f: impl FnOnce(&mut dyn HostHooks) -> R) -> R {
+        match self.host_hooks.as_deref_mut() {
+            Some(hooks) => f(hooks),
+            None => f(&mut DefaultHostHooks),
         }
     }
 
+    /// Takes the promises which were rejected while they had no handlers and have not been given one since, in the order they were rejected.
+    ///
+    /// A promise can still be given a handler by a job which has not run yet, so this is best called once [`JSAgent::run_jobs`] has run every pending job. [`HostHooks::promise_rejection_tracker`] is called as soon as each promise is rejected or handled instead.
+    pub fn take_unhandled_rejections(&mut self) -> Vec<UnhandledRejection> {
+        std::mem::take(&mut self.unhandled_rejections)
+            .into_iter()
+            .map(|promise| {
+                let reason = promise.data().slots().promise_result();
+
+                UnhandledRejection {
+                    promise: JSValue::from(promise),
+                    reason,
+                }
+            })
+            .collect()
+    }
+
+    /// Sets the maximum number of execution contexts which can be on the stack at once, beyond which calling a function throws a RangeError rather than overflowing the native stack.
+    ///
+    /// Every function call, including calls to native functions, pushes an execution context, as does evaluating a script or a module. A limit higher than the default needs a native stack larger than the stack of a spawned thread.
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = Some(depth);
+    }
+
+    /// Sets the number of call frames recorded by the stack trace of an error, from the innermost call outwards, which is 10 by default.
+    pub fn set_stack_trace_limit(&mut self, limit: usize) {
+        self.stack_trace_limit = Some(limit);
+    }
+
+    pub(crate) fn stack_trace_limit(&self) -> usize {
+        self.stack_trace_limit.unwrap_or(DEFAULT_STACK_TRACE_LIMIT)
+    }
+
+    /// Sets the level at which the scripts and modules evaluated from now on are optimized once they have been compiled.
+    ///
+    /// Optimizing bytecode does not change what it evaluates to, but it may change how many instructions it executes, and so how much fuel it takes.
+    pub fn set_optimization_level(&mut self, level: OptimizationLevel) {
+        self.optimization_level = level;
+    }
+
+    /// Sets whether every script evaluated from now on is strict mode code, as if it began with a Use Strict Directive.
+    ///
+    /// Modules are always strict mode code, whether or not this is set.
+    pub fn set_strict_only(&mut self, strict_only: bool) {
+        self.strict_only = strict_only;
+    }
+
+    /// Sets whether the realms created from now on have the additional built-in properties of Annex B, such as `Object.prototype.__proto__`, which they have by default.
+    ///
+    /// The realm of [`eval_script`](crate::eval_script) is created when the first script is evaluated, so this must be set before then to apply to it.
+    #[cfg(feature = "annex-b")]
+    pub fn set_annex_b(&mut self, enabled: bool) {
+        self.annex_b_disabled = !enabled;
+    }
+
+    #[cfg(feature = "annex-b")]
+    pub(crate) fn annex_b(&self) -> bool {
+        !self.annex_b_disabled
+    }
+
+    /// Counts the execution contexts on the stack, which a native function can read to find how deeply it is nested in calls from scripts.
+    pub fn execution_context_depth(&self) -> usize {
+        self.execution_contexts.len()
+    }
+
+    /// Sets the number of instructions the VM can execute before evaluation runs out of fuel, or removes the limit if None.
+    ///
+    /// The fuel is shared by every script, module and job the agent evaluates until it is set again. By default, running out of fuel aborts evaluation with [`EvalError::FuelExhausted`], without running any catch or finally clauses.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.fuel = fuel;
+    }
+
+    /// The number of instructions the VM can still execute, or None if evaluation is not metered.
+    pub fn remaining_fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    /// Sets whether running out of fuel throws a RangeError which scripts can catch, rather than aborting evaluation.
+    ///
+    /// As a caught error could not be handled without executing further instructions, the limit is removed once the error has been thrown.
+    pub fn set_fuel_exhaustion_catchable(&mut self, catchable: bool) {
+        self.fuel_catchable = catchable;
+    }
+
+    /// Takes the fuel for executing one instruction, throwing a RangeError if there is none left.
+    pub(crate) fn consume_fuel(&mut self) -> CompletionRecord {
+        match self.fuel {
+            None => Ok(()),
+            Some(0) => {
+                if self.fuel_catchable {
+                    self.fuel = None;
+                }
+
+                range_error("fuel exhausted")
+            }
+            Some(fuel) => {
+                self.fuel = Some(fuel - 1);
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Sets the number of bytes the heap can grow to, or removes the limit if None.
+    ///
+    /// Objects, environments, the property lists of objects, strings and interned property keys are accounted for. Strings and interned property keys count towards the heap of the agent whose evaluation created them, even while other agents share them. Once an allocation takes the heap beyond the limit, garbage is collected, and if that is not enough evaluation is aborted with [`EvalError::OutOfMemory`], without running any catch or finally clauses.
+    pub fn set_heap_limit(&mut self, limit: Option<usize>) {
+        self.heap.set_limit(limit);
+    }
+
+    /// The number of bytes allocated on the heap of the agent which have not been freed.
+    pub fn heap_size(&self) -> usize {
+        self.heap.size()
+    }
+
+    /// Frees the objects and environments which can no longer be reached, including those which only reference each other, returning how many were freed.
+    ///
+    /// Garbage is also collected automatically during evaluation, each time the heap has doubled in size since it was last collected.
+    pub fn collect_garbage(&mut self) -> usize {
+        self.object_nursery.release();
+        self.environment_nursery.release();
+
+        self.heap.collect()
+    }
+
+    /// Forgets the errors of the previous evaluation, before a script, module or job is evaluated.
+    pub(crate) fn begin_evaluation(&mut self) {
+        self.internal_error = None;
+        self.thrown_at = None;
+        self.out_of_memory = false;
+    }
+
+    /// Collects garbage once the heap has grown enough since it was last collected, or has grown beyond its limit, throwing a RangeError if it is still beyond the limit afterwards.
+    pub(crate) fn check_heap_limit(&mut self) -> CompletionRecord {
+        if !self.out_of_memory && (self.heap.is_collection_due() || self.heap.is_over_limit()) {
+            self.collect_garbage();
+
+            self.out_of_memory = self.heap.is_over_limit();
+        }
+
+        if self.out_of_memory {
+            return range_error("out of memory");
+        }
+
+        Ok(())
+    }
+
+    /// Throws a RangeError if allocating the bytes would take the heap beyond its limit even once garbage has been collected, so that evaluation runs out of memory before a large allocation is made rather than after it.
+    pub(crate) fn check_allocation(&mut self, bytes: usize) -> CompletionRecord {
+        if !self.out_of_memory && self.heap.would_exceed_limit(bytes) {
+            self.collect_garbage();
+
+            self.out_of_memory = self.heap.would_exceed_limit(bytes);
+        }
+
+        if self.out_of_memory {
+            return range_error("out of memory");
+        }
+
+        Ok(())
+    }
+
+    /// The error evaluation must be aborted with, rather than the error being caught, if it has run out of fuel or memory.
+    pub(crate) fn abort_error(&self) -> Option<EvalError> {
+        if self.fuel == Some(0) && !self.fuel_catchable {
+            return Some(EvalError::FuelExhausted);
+        }
+
+        if self.out_of_memory {
+            return Some(EvalError::OutOfMemory);
+        }
+
+        None
+    }
+
+    /// Throws a RangeError if pushing another execution context would exceed the maximum call depth.
+    pub(crate) fn check_call_depth(&self) -> CompletionRecord {
+        let max_call_depth = self.max_call_depth.unwrap_or(DEFAULT_MAX_CALL_DEPTH);
+
+        if self.execution_contexts.len() >= max_call_depth {
+            return range_error("maximum call stack size exceeded");
+        }
+
+        Ok(())
+    }
+
+    /// Takes the warnings reported while compiling the scripts evaluated so far.
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Counts the objects allocated by the agent so far, and how many of those reused the allocation of a short-lived object which was no longer referenced.
+    pub fn heap_stats(&self) -> HeapStats {
+        self.object_nursery.stats()
+    }
+
+    /// Registers a callback which is invoked with the old and new values whenever a script writes to the target.
+    pub fn add_watchpoint(
+        &mut self,
+        target: WatchTarget,
+        callback: impl FnMut(&WatchEvent) + 'static,
+    ) -> WatchpointId {
+        self.watchpoints.add(target, Box::new(callback))
+    }
+
+    /// Removes a watchpoint, returning whether it was registered.
+    pub fn remove_watchpoint(&mut self, id: WatchpointId) -> bool {
+        self.watchpoints.remove(id)
+    }
+
+    /// Sets the debugger whose callbacks are called as scripts and modules are evaluated, replacing any debugger set before.
+    pub fn set_debugger(&mut self, debugger: impl Debugger + 'static) {
+        self.debugger = Some(Box::new(debugger));
+    }
+
+    /// Removes the debugger, returning whether one was set.
+    pub fn remove_debugger(&mut self) -> bool {
+        self.debugger.take().is_some()
+    }
+
+    /// Sets a breakpoint on the line, which calls [`Debugger::on_breakpoint`] before each statement starting on the line is evaluated, in every script and module the agent evaluates.
+    pub fn set_breakpoint(&mut self, line: usize) {
+        self.breakpoints.insert(line);
+    }
+
+    /// Removes the breakpoint on the line, returning whether one was set.
+    pub fn remove_breakpoint(&mut self, line: usize) -> bool {
+        self.breakpoints.remove(&line)
+    }
+
+    /// Whether a debugger is set, so that the work of reporting to it can be skipped otherwise.
+    pub(crate) fn has_debugger(&self) -> bool {
+        self.debugger.is_some()
+    }
+
+    /// Whether a breakpoint is set on the line.
+    pub(crate) fn has_breakpoint(&self, line: usize) -> bool {
+        self.breakpoints.contains(&line)
+    }
+
+    /// Calls the debugger, if one is set, with the context of the statement being evaluated.
+    pub(crate) fn notify_debugger(&mut self, f: impl FnOnce(&mut dyn Debugger, &DebugContext)) {
+        let Some(mut debugger) = self.debugger.take() else {
+            return;
+        };
+
+        f(debugger.as_mut(), &DebugContext::new(self));
+
+        self.debugger = Some(debugger);
+    }
+
+    /// Sets whether the agent records how many times each opcode is executed and how long it takes, along with how many times each function is called.
+    ///
+    /// Profiling slows evaluation down, so it is off by default. Turning it off discards the statistics which have not been taken.
+    pub fn set_profiling(&mut self, enabled: bool) {
+        if enabled {
+            self.profiler.get_or_insert_with(Box::default);
+        } else {
+            self.profiler = None;
+        }
+    }
+
+    /// Takes the statistics recorded since profiling was turned on or the profile was last taken, which is empty if the agent is not profiling.
+    pub fn take_profile(&mut self) -> Profile {
+        self.profiler
+            .as_mut()
+            .map_or_else(Profile::default, |profiler| profiler.take())
+    }
+
+    /// The profiler recording statistics, if the agent is profiling.
+    pub(crate) fn profiler(&mut self) -> Option<&mut Profiler> {
+        self.profiler.as_deref_mut()
+    }
+
+    /// Roots the value, keeping it and every value it references alive until it is unrooted, or the scope it was rooted in ends.
+    ///
+    /// A value held by the embedder is never collected either, but a root can be copied into host data structures and native functions without holding the value itself, and released all at once by a [`JSAgent::scope`].
+    pub fn root<T: Rootable>(&mut self, value: T) -> Root<T> {
+        self.roots.add(value)
+    }
+
+    /// The value of the root, or None if it has been released.
+    pub fn get_root<T: Rootable>(&self, root: Root<T>) -> Option<T> {
+        self.roots.get(root)
+    }
+
+    /// Releases the root, returning its value, or None if it had already been released.
+    pub fn unroot<T: Rootable>(&mut self, root: Root<T>) -> Option<T> {
+        self.roots.remove(root)
+    }
+
+    /// The number of roots which have not been released.
+    pub fn root_count(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Runs the closure in a scope, releasing every root added within it once it returns, similar to a handle scope in V8.
+    ///
+    /// A value which must outlive the scope can be returned from the closure, or rooted again once the scope has ended.
+    pub fn scope<R>(&mut self, f: impl FnOnce(&mut JSAgent) -> R) -> R {
+        let start = self.roots.begin_scope();
+
+        let result = f(self);
+
+        self.roots.end_scope(start);
+
+        result
+    }
+
+    /// 9.5.5 HostEnqueuePromiseJob ( job, realm )
+    /// https://262.ecma-international.org/16.0/#sec-hostenqueuepromisejob
+    pub(crate) fn host_enqueue_promise_job(&mut self, job: Job) {
+        // Jobs must run in the same order as the HostEnqueuePromiseJob invocations that scheduled them.
+        self.jobs.push_back(job);
+    }
+
+    /// Runs the pending promise jobs, including any jobs they enqueue, until none remain.
+    ///
+    /// Promise reactions are only run by this method, so it should be called after [`crate::eval_script`] to settle the promises a script created. If a job throws, the remaining jobs are left pending and the thrown value is reported to [`HostHooks::report_error`] and returned as an [`EvalError`].
+    pub fn run_jobs(&mut self) -> Result<(), EvalError> {
+        self.begin_evaluation();
+
+        while let Some(job) = self.jobs.pop_front() {
+            self.run_job(job)?;
+        }
+
+        Ok(())
+    }
+
+    /// Defines the global functions `setTimeout`, `setInterval`, `clearTimeout` and `clearInterval`, whose callbacks are run by [`JSAgent::run_due_timers`].
+    ///
+    /// A timer is due once the given number of milliseconds have passed on the clock of the agent, which is read when it is scheduled.
+    pub fn install_timers(&mut self) {
+        self.register_function("setTimeout", 1, timers::set_timeout);
+        self.register_function("setInterval", 1, timers::set_interval);
+        self.register_function("clearTimeout", 0, timers::clear_timer);
+        self.register_function("clearInterval", 0, timers::clear_timer);
+    }
+
+    /// Runs the callbacks of the timers which are due at the given time of the agent's clock, in the order they are due, and returns the number of callbacks which were run.
+    ///
+    /// The promise jobs are run after each callback, as an event loop performs a microtask checkpoint after each task. A timer which is scheduled by a callback is not run until the next call, even if it is already due. If a callback or job throws, the remaining timers are left pending and the error is reported and returned as it is by [`JSAgent::run_jobs`].
+    pub fn run_due_timers(&mut self, now: f64) -> Result<usize, EvalError> {
+        let mut count = 0;
+
+        for id in self.timers.due(now) {
+            // NOTE: A timer which was cleared by an earlier callback is skipped.
+            let Some(timer) = self.timers.take(id, now) else {
+                continue;
+            };
+
+            let job = Job::new(
+                move |agent| {
+                    call(
+                        agent,
+                        timer.callback,
+                        &JSValue::Undefined,
+                        Some(timer.arguments),
+                    )?;
+
+                    Ok(())
+                },
+                Some(timer.realm),
+            );
+
+            self.begin_evaluation();
+            self.run_job(job)?;
+            self.run_jobs()?;
+
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// The earliest time of the agent's clock at which a timer is due, or None if no timers are scheduled, so that an event loop knows how long it can wait for.
+    pub fn next_timer_due(&self) -> Option<f64> {
+        self.timers.next_due()
+    }
+
+    /// Enqueues a task which [`JSAgent::run_until_idle`] runs once the tasks enqueued before it have run, such as one which delivers the result of an I/O operation to a script.
+    pub fn enqueue_task(
+        &mut self,
+        task: impl FnOnce(&mut JSAgent) -> Result<(), EvalError> + 'static,
+    ) {
+        self.tasks.push_back(Box::new(task));
+    }
+
+    /// Runs an event loop until there are no promise jobs, tasks or timers left.
+    ///
+    /// Each task enqueued with [`JSAgent::enqueue_task`] is run in turn, followed by the timers which are due, with the promise jobs run after each of them. Once only timers which are not yet due are left, the thread sleeps until the earliest of them is due on the clock of the agent, so an interval which is never cleared keeps the loop running forever.
+    ///
+    /// On `wasm32-unknown-unknown`, where the thread can't sleep, this returns once only timers which are not yet due are left instead, and the host should call it again at the time [`JSAgent::next_timer_due`] returns, for example with `setTimeout`.
+    ///
+    /// If a task, callback or job fails, the remaining work is left pending and the error is returned.
+    pub fn run_until_idle(&mut self) -> Result<(), EvalError> {
+        // NOTE: The promise jobs of the script which was evaluated last run before any task, as they would at the end of the task which evaluated it.
+        self.run_jobs()?;
+
+        // NOTE: FinalizationRegistry is not implemented, so there are no cleanup jobs to run once the agent is idle.
+        loop {
+            if let Some(task) = self.tasks.pop_front() {
+                task(self)?;
+                self.run_jobs()?;
+
+                continue;
+            }
+
+            let Some(due) = self.next_timer_due() else {
+                return Ok(());
+            };
+
+            let now = self.clock().now();
+
+            #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+            if due > now {
+                return Ok(());
+            }
+
+            #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+            if due > now {
+                std::thread::sleep(Duration::from_secs_f64((due - now) / 1000.0));
+            }
+
+            self.run_due_timers(due.max(now))?;
+        }
+    }
+
+    fn run_job(&mut self, job: Job) -> Result<(), EvalError> {
+        // If realm is not null, each time job is invoked the implementation must perform implementation-defined steps such that execution is prepared to evaluate ECMAScript code at the time of job's invocation.
+        let pushed_context = job.realm.map(|realm| {
+            self.push_execution_context(ExecutionContext {
+                function: None,
+                realm,
+                script_or_module: None,
+                lexical_environment: None,
+                variable_environment: None,
+                private_environment: None,
+            })
+        });
+
+        let result = (job.callback)(self);
+
+        if pushed_context.is_some() {
+            self.pop_execution_context();
+        }
+
+        let error = match result {
+            Err(error) => Some(EvalError::from_throw_completion(self, error)),
+            // NOTE: A job which reacts to a promise rejects the promise with the error it throws, so running out of fuel or memory is reported here instead.
+            Ok(()) => self.abort_error(),
+        };
+
+        if let Some(error) = error {
+            self.with_host_hooks(|hooks| hooks.report_error(&error));
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn running_execution_context(&self) -> &ExecutionContext {
         debug_assert!(!self.execution_contexts.is_empty());
 
@@ -65,6 +870,12 @@ impl JSAgent {
         self.execution_contexts.last().unwrap()
     }
 
+    pub(crate) fn running_execution_context_mut(&mut self) -> &mut ExecutionContext {
+        debug_assert!(!self.execution_contexts.is_empty());
+
+        self.execution_contexts.last_mut().unwrap()
+    }
+
     pub(crate) fn current_realm(&self) -> RealmAddr {
         self.running_execution_context().realm.clone()
     }
@@ -78,18 +889,49 @@ impl JSAgent {
     }
 }
 
-pub(crate) fn type_error(message: &str) -> ! {
-    panic!("TypeError: {message:?}");
+/// Frees the cycles of values which only the agent could reach, like a realm and its intrinsics, which reference counting alone would leak once the agent is dropped.
+impl Drop for JSAgent {
+    fn drop(&mut self) {
+        // NOTE: The references the agent holds are dropped first, so that only the values referenced from outside the agent, like those still held by the embedder, are kept.
+        self.execution_contexts.clear();
+        self.environment_records.clear();
+        self.jobs.clear();
+        self.tasks.clear();
+        self.modules.clear();
+        self.module_loader = None;
+        self.debugger = None;
+        self.host_hooks = None;
+        self.unhandled_rejections.clear();
+        self.timers = Timers::default();
+        self.thrown_at = None;
+        self.roots = Roots::default();
+
+        // NOTE: Clearing a cycle can drop the last reference to a value held by a closure, which is only found to be unreachable by the next collection.
+        while self.collect_garbage() > 0 {}
+    }
+}
+
+pub(crate) fn type_error<T>(message: &str) -> CompletionRecord<T> {
+    throw_native_error(NativeErrorKind::Type, message.to_string())
+}
+
+/// Throws a TypeError whose message is followed by a preview of the offending value, so that the value which caused the failure can be told apart.
+pub(crate) fn type_error_for<T>(message: &str, value: &JSValue) -> CompletionRecord<T> {
+    type_error(&format!("{message}: {}", value.preview()))
+}
+
+pub(crate) fn reference_error<T>(message: &str) -> CompletionRecord<T> {
+    throw_native_error(NativeErrorKind::Reference, message.to_string())
 }
 
-pub(crate) fn reference_error(message: &str) -> ! {
-    panic!("ReferenceError: {message:?}");
+pub(crate) fn syntax_error<T>(message: &str) -> CompletionRecord<T> {
+    throw_native_error(NativeErrorKind::Syntax, message.to_string())
 }
 
-pub(crate) fn syntax_error(message: &str) -> ! {
-    panic!("SyntaxError: {message:?}");
+pub(crate) fn range_error<T>(message: &str) -> CompletionRecord<T> {
+    throw_native_error(NativeErrorKind::Range, message.to_string())
 }
 
-pub(crate) fn range_error(message: &str) -> ! {
-    panic!("RangeError: {message:?}");
+pub(crate) fn uri_error<T>(message: &str) -> CompletionRecord<T> {
+    throw_native_error(NativeErrorKind::Uri, message.to_string())
 }
@@ -0,0 +1,96 @@
+use crate::codegen::bytecode::optimizer::OptimizationLevel;
+use crate::runtime::agent::JSAgent;
+use crate::runtime::clock::HostClock;
+use crate::runtime::console::ConsoleSink;
+use crate::runtime::host_hooks::HostHooks;
+
+/// Configures a [`JSAgent`] before it evaluates anything, created with [`JSAgent::builder`].
+///
+/// Each setting is the same as calling the setter of the agent it is named after, so an agent can still be reconfigured once it has been built.
+#[derive(Default)]
+pub struct JSAgentBuilder {
+    agent: JSAgent,
+}
+
+impl JSAgentBuilder {
+    /// The maximum number of execution contexts which can be on the stack at once, as with [`JSAgent::set_max_call_depth`].
+    pub fn max_call_depth(mut self, depth: usize) -> Self {
+        self.agent.set_max_call_depth(depth);
+        self
+    }
+
+    /// The number of call frames recorded by the stack trace of an error, as with [`JSAgent::set_stack_trace_limit`].
+    pub fn stack_trace_limit(mut self, limit: usize) -> Self {
+        self.agent.set_stack_trace_limit(limit);
+        self
+    }
+
+    /// The number of bytes the heap can grow to, as with [`JSAgent::set_heap_limit`].
+    pub fn heap_limit(mut self, limit: usize) -> Self {
+        self.agent.set_heap_limit(Some(limit));
+        self
+    }
+
+    /// The number of instructions the VM can execute before evaluation runs out of fuel, as with [`JSAgent::set_fuel`].
+    pub fn fuel(mut self, fuel: u64) -> Self {
+        self.agent.set_fuel(Some(fuel));
+        self
+    }
+
+    /// Whether running out of fuel throws an error which scripts can catch, as with [`JSAgent::set_fuel_exhaustion_catchable`].
+    pub fn fuel_exhaustion_catchable(mut self, catchable: bool) -> Self {
+        self.agent.set_fuel_exhaustion_catchable(catchable);
+        self
+    }
+
+    /// The level at which scripts and modules are optimized once they have been compiled, as with [`JSAgent::set_optimization_level`].
+    pub fn optimization_level(mut self, level: OptimizationLevel) -> Self {
+        self.agent.set_optimization_level(level);
+        self
+    }
+
+    /// Whether every script is strict mode code, as with [`JSAgent::set_strict_only`].
+    pub fn strict_only(mut self, strict_only: bool) -> Self {
+        self.agent.set_strict_only(strict_only);
+        self
+    }
+
+    /// Whether realms have the additional built-in properties of Annex B, as with [`JSAgent::set_annex_b`].
+    #[cfg(feature = "annex-b")]
+    pub fn annex_b(mut self, enabled: bool) -> Self {
+        self.agent.set_annex_b(enabled);
+        self
+    }
+
+    /// The clock which Date objects read the current time and the local time zone from, as with [`JSAgent::set_clock`].
+    pub fn clock(mut self, clock: impl HostClock + 'static) -> Self {
+        self.agent.set_clock(clock);
+        self
+    }
+
+    /// The sink which the `console` object writes its messages to, as with [`JSAgent::set_console`].
+    pub fn console(mut self, sink: impl ConsoleSink + 'static) -> Self {
+        self.agent.set_console(sink);
+        self
+    }
+
+    /// The hooks which customize the host-defined operations of the specification, as with [`JSAgent::set_host_hooks`].
+    pub fn host_hooks(mut self, hooks: impl HostHooks + 'static) -> Self {
+        self.agent.set_host_hooks(hooks);
+        self
+    }
+
+    /// The callback which returns the source text of an imported module, as with [`JSAgent::set_module_loader`].
+    pub fn module_loader(
+        mut self,
+        loader: impl FnMut(&str, &str) -> Result<String, String> + 'static,
+    ) -> Self {
+        self.agent.set_module_loader(loader);
+        self
+    }
+
+    /// Returns the configured agent.
+    pub fn build(self) -> JSAgent {
+        self.agent
+    }
+}
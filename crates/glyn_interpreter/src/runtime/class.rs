@@ -0,0 +1,202 @@
+use crate::{
+    abstract_ops::{
+        function_operations::create_builtin_function,
+        object_operations::{define_property_or_throw, get},
+        ordinary::ordinary_object_create,
+    },
+    intrinsics::define_builtin_method,
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::{CompletionRecord, ThrowCompletion},
+        native_function::Args,
+        realm::RealmAddr,
+    },
+    value::{
+        object::{
+            internal_slots::{ConstructorKind, HostData, InternalSlotName},
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectMeta,
+        },
+        JSValue,
+    },
+};
+
+/// A Rust type which scripts can create instances of with `new` and call the methods of, registered with [`JSAgent::register_class`].
+///
+/// This is usually implemented with the `#[js_class]` attribute of the `glyn_macros` crate, which implements it for an `impl` block of the type.
+pub trait JSClass: Sized + 'static {
+    /// The name of the global constructor.
+    const NAME: &'static str;
+
+    /// The number of arguments the constructor expects.
+    const LENGTH: usize = 0;
+
+    /// Creates the value an instance holds from the arguments `new` was called with.
+    fn construct(agent: &mut JSAgent, args: Args) -> Result<Self, ThrowCompletion>;
+
+    /// Adds the methods which instances inherit from the prototype of the class.
+    fn define_methods(methods: &mut ClassMethods<Self>);
+}
+
+/// A method of a class, which is called with the value held by the instance it was called on.
+pub type ClassMethod<T> = fn(&mut T, &mut JSAgent, Args) -> Result<JSValue, ThrowCompletion>;
+
+/// The methods of the prototype of a class, in the order they are defined.
+pub struct ClassMethods<T> {
+    methods: Vec<(&'static str, usize, ClassMethod<T>)>,
+}
+
+impl<T: JSClass> ClassMethods<T> {
+    /// Adds a method named `name`, where `length` is the number of arguments it expects.
+    pub fn method(
+        &mut self,
+        name: &'static str,
+        length: usize,
+        method: ClassMethod<T>,
+    ) -> &mut Self {
+        self.methods.push((name, length, method));
+        self
+    }
+}
+
+/// Creates the constructor of the class and its prototype in the realm.
+pub(crate) fn define_class<T: JSClass>(agent: &mut JSAgent, realm: &RealmAddr) -> ObjectAddr {
+    let prototype = ordinary_object_create(
+        agent,
+        realm.borrow().intrinsics.object_prototype.clone(),
+        None,
+    );
+
+    let mut methods = ClassMethods { methods: vec![] };
+
+    T::define_methods(&mut methods);
+
+    let owner = format!("{}.prototype", T::NAME);
+
+    for (name, length, method) in methods.methods {
+        define_builtin_method(
+            agent,
+            realm,
+            &prototype,
+            &owner,
+            name,
+            length,
+            move |agent: &mut JSAgent, args: Args| call_method::<T>(agent, args, name, method),
+        );
+    }
+
+    let constructor = create_builtin_function(
+        agent,
+        construct::<T>,
+        T::LENGTH,
+        JSObjectPropKey::String(T::NAME.into()),
+        vec![InternalSlotName::ConstructorKind],
+        Some(realm.clone()),
+        None,
+        None,
+    );
+
+    constructor
+        .data_mut()
+        .slots_mut()
+        .set_constructor_kind(ConstructorKind::Base);
+
+    // NOTE: The prototype and constructor properties have the attributes of those of a class definition.
+    let _ = define_property_or_throw(
+        &constructor,
+        &JSObjectPropKey::String("prototype".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(prototype.clone())),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        },
+    );
+
+    let _ = define_property_or_throw(
+        &prototype,
+        &JSObjectPropKey::String("constructor".into()),
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(constructor.clone())),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..JSObjectPropDescriptor::default()
+        },
+    );
+
+    constructor
+}
+
+/// The steps of the constructor of a class, which create an instance holding the value returned by [`JSClass::construct`].
+fn construct<T: JSClass>(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+    let Some(new_target) = args.new_target() else {
+        return type_error(&format!(
+            "Class constructor {} cannot be invoked without 'new'",
+            T::NAME
+        ));
+    };
+
+    // NOTE: The prototype is read from NewTarget as GetPrototypeFromConstructor does, falling back to %Object.prototype% as the class has no intrinsic prototype.
+    let prototype = match get(
+        agent,
+        new_target,
+        &JSObjectPropKey::String("prototype".into()),
+    )? {
+        JSValue::Object(prototype) => Some(prototype),
+        _ => agent
+            .current_realm()
+            .borrow()
+            .intrinsics
+            .object_prototype
+            .clone(),
+    };
+
+    let value = T::construct(agent, args)?;
+
+    let instance = ordinary_object_create(agent, prototype, Some(vec![InternalSlotName::HostData]));
+
+    instance
+        .data_mut()
+        .slots_mut()
+        .set_host_data(HostData::new(value));
+
+    Ok(JSValue::from(instance))
+}
+
+/// The steps of a method of a class, which call the method with the value held by the this value.
+fn call_method<T: JSClass>(
+    agent: &mut JSAgent,
+    args: Args,
+    name: &str,
+    method: ClassMethod<T>,
+) -> CompletionRecord<JSValue> {
+    // NOTE: The host data is cloned out of the object, so that the object is not borrowed while the method runs.
+    let host_data = match args.this() {
+        JSValue::Object(object) => object.borrow().slots().host_data().cloned(),
+        _ => None,
+    };
+
+    let Some(value) = host_data.and_then(|host_data| host_data.downcast::<T>()) else {
+        return incompatible_receiver::<T>(name, args.this());
+    };
+
+    let Ok(mut value) = value.try_borrow_mut() else {
+        return type_error(&format!(
+            "{}.prototype.{name} cannot be called on a {} whose method is already running",
+            T::NAME,
+            T::NAME
+        ));
+    };
+
+    method(&mut value, agent, args)
+}
+
+fn incompatible_receiver<T: JSClass>(name: &str, this: &JSValue) -> CompletionRecord<JSValue> {
+    type_error(&format!(
+        "{}.prototype.{name} called on incompatible receiver: {}",
+        T::NAME,
+        this.preview()
+    ))
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// The source of the current time and of the local time zone, which the time values of Date objects are computed from.
+///
+/// Set one with [`crate::JSAgent::set_clock`] to make scripts which read the time deterministic, for example in tests.
+pub trait HostClock {
+    /// The current time, as the number of milliseconds since midnight at the beginning of 1 January 1970 UTC.
+    fn now(&self) -> f64;
+
+    /// The offset of local time from UTC in milliseconds, at the given time.
+    ///
+    /// The time is in UTC if `is_utc` is true, and in local time otherwise. Local time is UTC unless this is overridden.
+    fn local_tz_offset(&self, _time: f64, _is_utc: bool) -> f64 {
+        0.0
+    }
+}
+
+/// The clock of the system the agent runs on, whose local time is UTC.
+///
+/// On `wasm32-unknown-unknown`, which has no system clock, this is the clock of the JavaScript host the module runs in.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl HostClock for SystemClock {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    fn now(&self) -> f64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0.0, |duration| duration.as_millis() as f64)
+    }
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    fn now(&self) -> f64 {
+        js_sys::Date::now()
+    }
+}
+
+/// Measures how long something takes, like [`Instant`] does on targets which have a monotonic clock.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Stopwatch {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    started: Instant,
+
+    // NOTE: Instant panics on wasm32-unknown-unknown, so the time of the host is used, which only has millisecond precision.
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    started: f64,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+            started: Instant::now(),
+            #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+            started: js_sys::Date::now(),
+        }
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        return self.started.elapsed();
+
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        return Duration::from_secs_f64((js_sys::Date::now() - self.started).max(0.0) / 1000.0);
+    }
+}
+
+/// A clock which is stopped at a fixed time, in a time zone with a fixed offset from UTC.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FixedClock {
+    /// The time the clock is stopped at, in milliseconds since the epoch.
+    pub time: f64,
+
+    /// The offset of local time from UTC in milliseconds.
+    pub offset: f64,
+}
+
+impl HostClock for FixedClock {
+    fn now(&self) -> f64 {
+        self.time
+    }
+
+    fn local_tz_offset(&self, _time: f64, _is_utc: bool) -> f64 {
+        self.offset
+    }
+}
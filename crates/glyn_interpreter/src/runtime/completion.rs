@@ -1,4 +1,8 @@
-use crate::value::JSValue;
+use crate::{
+    intrinsics::native_error_constructor::{create_native_error, NativeErrorKind},
+    runtime::agent::JSAgent,
+    value::JSValue,
+};
 
 /// 6.2.4 The Completion Record Specification Type
 /// https://262.ecma-international.org/16.0/#sec-completion-record-specification-type
@@ -30,9 +34,54 @@ impl From<JSValue> for NormalCompletion {
 
 /// 6.2.4.2 ThrowCompletion ( value )
 /// https://262.ecma-international.org/16.0/#sec-throwcompletion
+///
+/// Holds the value which was thrown. An error raised by the engine is held as its kind and message until an agent reads the value, which creates the error object from the NativeError constructor of the current realm, so that it can be raised where there is no agent to allocate it with.
 #[derive(Debug)]
-pub struct ThrowCompletion(pub String);
+pub struct ThrowCompletion(pub(crate) Thrown);
+
+#[derive(Debug)]
+pub(crate) enum Thrown {
+    Value(JSValue),
+    Error(NativeErrorKind, String),
+}
+
+impl ThrowCompletion {
+    /// Throws a TypeError with the message, like `throw new TypeError(message)`.
+    pub fn type_error(message: impl Into<String>) -> Self {
+        ThrowCompletion(Thrown::Error(NativeErrorKind::Type, message.into()))
+    }
+
+    /// Throws a RangeError with the message, like `throw new RangeError(message)`.
+    pub fn range_error(message: impl Into<String>) -> Self {
+        ThrowCompletion(Thrown::Error(NativeErrorKind::Range, message.into()))
+    }
+
+    /// The value which was thrown, creating the error object of an error raised by the engine in the current realm of the agent.
+    pub fn into_value(self, agent: &mut JSAgent) -> JSValue {
+        match self.0 {
+            Thrown::Value(value) => value,
+            Thrown::Error(kind, message) => create_native_error(agent, kind, &message),
+        }
+    }
+
+    /// Creates the error object of an error raised by the engine, while the realm it is raised in is the current realm.
+    pub(crate) fn resolve(self, agent: &mut JSAgent) -> Self {
+        ThrowCompletion(Thrown::Value(self.into_value(agent)))
+    }
+}
+
+/// Lets a native function throw any value with `?`, which scripts can catch like a value thrown by a throw statement.
+impl From<JSValue> for ThrowCompletion {
+    fn from(value: JSValue) -> Self {
+        ThrowCompletion(Thrown::Value(value))
+    }
+}
 
 pub(crate) fn throw_completion<T>(message: &str) -> CompletionRecord<T> {
-    Err(ThrowCompletion(message.to_string()))
+    Err(ThrowCompletion::from(JSValue::from(message.to_string())))
+}
+
+/// Throws an error raised by the engine, whose error object is created once an agent reads the thrown value.
+pub(crate) fn throw_native_error<T>(kind: NativeErrorKind, message: String) -> CompletionRecord<T> {
+    Err(ThrowCompletion(Thrown::Error(kind, message)))
 }
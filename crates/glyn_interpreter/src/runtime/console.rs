@@ -0,0 +1,51 @@
+use std::io::{self, Write};
+
+/// The severity of a message written by the `console` object, from the logging function which wrote it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug,
+    /// Written by `console.info` and `console.timeEnd`.
+    Info,
+    Log,
+    Warn,
+    /// Written by `console.error` and by a failed `console.assert`.
+    Error,
+}
+
+/// The destination of the messages written by the `console` object of every realm of an agent.
+///
+/// Set one with [`crate::JSAgent::set_console`], which accepts any `FnMut(LogLevel, &str)` callback, or a writer wrapped in a [`WriterSink`].
+pub trait ConsoleSink {
+    /// Writes a message, which is a single line unless one of the values it shows is a string containing line breaks.
+    fn write(&mut self, level: LogLevel, message: &str);
+}
+
+impl<F: FnMut(LogLevel, &str)> ConsoleSink for F {
+    fn write(&mut self, level: LogLevel, message: &str) {
+        self(level, message);
+    }
+}
+
+/// The sink of the process the agent runs in, which writes warnings and errors to stderr and every other message to stdout.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdioSink;
+
+impl ConsoleSink for StdioSink {
+    fn write(&mut self, level: LogLevel, message: &str) {
+        // NOTE: A message which can't be written is dropped, as the console has no way to report it to the script.
+        let _ = match level {
+            LogLevel::Warn | LogLevel::Error => writeln!(io::stderr(), "{message}"),
+            LogLevel::Debug | LogLevel::Info | LogLevel::Log => writeln!(io::stdout(), "{message}"),
+        };
+    }
+}
+
+/// A sink which writes every message to a writer as a line of its own, whatever its level.
+#[derive(Debug, Default)]
+pub struct WriterSink<W: Write>(pub W);
+
+impl<W: Write> ConsoleSink for WriterSink<W> {
+    fn write(&mut self, _level: LogLevel, message: &str) {
+        let _ = writeln!(self.0, "{message}");
+    }
+}
@@ -0,0 +1,93 @@
+use std::{collections::HashSet, ops::Range};
+
+use crate::{
+    codegen::bytecode::generator::{find_span_entry, SpanEntry},
+    lexer::SourcePosition,
+    runtime::agent::JSAgent,
+    value::JSValue,
+};
+
+/// Callbacks which follow the evaluation of scripts and modules, installed on an agent with [`JSAgent::set_debugger`]. Every callback does nothing unless it is implemented.
+///
+/// Evaluation continues once a callback returns, so stepping is implemented by the debugger itself, for example by waiting for input in [`Debugger::on_statement`] while it is stepping, or in [`Debugger::on_breakpoint`] otherwise.
+pub trait Debugger {
+    /// Called before each statement or declaration is evaluated, including each time the body of a loop is evaluated again.
+    fn on_statement(&mut self, _context: &DebugContext) {}
+
+    /// Called before a statement or declaration which starts on a line with a breakpoint set by [`JSAgent::set_breakpoint`] is evaluated, ahead of [`Debugger::on_statement`].
+    fn on_breakpoint(&mut self, _context: &DebugContext) {}
+
+    /// Called when a function defined by a script is called with the arguments, before its body is evaluated. The context is the position of the call.
+    fn on_call(&mut self, _function_name: &str, _args: &[JSValue], _context: &DebugContext) {}
+
+    /// Called when the body of a function defined by a script returns the value, rather than throwing.
+    fn on_return(&mut self, _function_name: &str, _value: &JSValue, _context: &DebugContext) {}
+
+    /// Called when a script throws the value, before it is caught by any catch clause.
+    fn on_exception(&mut self, _value: &JSValue, _context: &DebugContext) {}
+}
+
+/// The state of the agent at the point a [`Debugger`] callback is called.
+pub struct DebugContext<'a> {
+    agent: &'a JSAgent,
+}
+
+impl<'a> DebugContext<'a> {
+    pub(crate) fn new(agent: &'a JSAgent) -> Self {
+        Self { agent }
+    }
+
+    /// The span entry of the statement being evaluated by the innermost call frame.
+    fn span_entry(&self) -> Option<&'a SpanEntry> {
+        let frame = self.agent.call_frames.last()?;
+
+        find_span_entry(&frame.spans, frame.offset)
+    }
+
+    /// The byte range in the source text of the statement being evaluated, if known.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span_entry()
+            .map(|entry| entry.span.start..entry.span.end)
+    }
+
+    /// The line and column of the statement being evaluated, if known.
+    pub fn position(&self) -> Option<SourcePosition> {
+        self.span_entry().map(|entry| entry.position)
+    }
+
+    /// The number of scripts, modules and function bodies being evaluated, which grows by one for each call a statement steps into.
+    pub fn call_depth(&self) -> usize {
+        self.agent.call_frames.len()
+    }
+
+    /// The initialized bindings which are in scope where the statement is being evaluated, from the innermost scope outwards and by name within each scope.
+    ///
+    /// A binding shadowed by a binding with the same name in an inner scope is left out, as are the properties of the global object and of the objects of with statements.
+    pub fn bindings(&self) -> Vec<(String, JSValue)> {
+        let mut bindings = vec![];
+        let mut names = HashSet::new();
+
+        let mut env = self
+            .agent
+            .execution_contexts
+            .last()
+            .and_then(|context| context.lexical_environment.clone());
+
+        while let Some(current) = env {
+            let mut scope = current.declarative_bindings();
+
+            scope.retain(|(name, _)| names.insert(name.clone()));
+            scope.sort_by_cached_key(|(name, _)| name.to_string());
+
+            bindings.extend(
+                scope
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value)),
+            );
+
+            env = current.outer();
+        }
+
+        bindings
+    }
+}
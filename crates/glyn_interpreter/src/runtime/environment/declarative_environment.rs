@@ -1,17 +1,20 @@
 use std::collections::HashMap;
 
 use crate::{
+    gc::{Trace, Tracer},
     runtime::{
+        agent::JSAgent,
         agent::{reference_error, type_error},
         completion::{throw_completion, CompletionRecord, ThrowCompletion},
         environment::{Environment, EnvironmentAddr, EnvironmentMethods},
     },
-    value::{object::ObjectAddr, string::JSString},
+    value::{atom::Atom, object::ObjectAddr},
     JSValue,
 };
 
 #[derive(Clone, Debug)]
 pub(crate) struct Binding {
+    name: Atom,
     value: Option<JSValue>,
     mutable: bool,
     deletable: bool,
@@ -26,109 +29,104 @@ pub(crate) struct DeclarativeEnvironment {
     /// https://262.ecma-international.org/16.0/#table-additional-fields-of-declarative-environment-records
     pub(crate) outer_env: Option<EnvironmentAddr>,
 
-    bindings: HashMap<JSString, Binding>,
+    /// The bindings in the order they were created, so that those the bytecode generator resolves statically can be addressed by their slot.
+    bindings: Vec<Binding>,
+
+    /// The slot of the binding for each name. A deleted binding keeps its slot, but its name is removed.
+    slots: HashMap<Atom, usize>,
 }
 
-impl DeclarativeEnvironment {
-    fn binding(&self, name: &JSString) -> &Binding {
-        self.bindings.get(name).unwrap()
+impl Trace for DeclarativeEnvironment {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.outer_env.trace(tracer);
+
+        for binding in &self.bindings {
+            binding.value.trace(tracer);
+        }
     }
+}
 
-    fn binding_mut(&mut self, name: &JSString) -> &mut Binding {
-        self.bindings.get_mut(name).unwrap()
+impl DeclarativeEnvironment {
+    fn slot(&self, name: &Atom) -> usize {
+        self.slots[&name]
     }
 
-    fn has_binding_impl(&self, name: &JSString) -> bool {
-        self.bindings.contains_key(name)
+    fn binding(&self, name: &Atom) -> &Binding {
+        &self.bindings[self.slot(name)]
     }
 
-    fn add_binding_impl(&mut self, name: &JSString, mutable: bool, deletable: bool, strict: bool) {
-        debug_assert!(!self.has_binding_impl(name));
+    fn binding_mut(&mut self, name: &Atom) -> &mut Binding {
+        let slot = self.slot(name);
 
-        self.bindings.insert(
-            name.clone(),
-            Binding {
-                mutable,
-                deletable,
-                strict,
-                value: None,
-            },
-        );
+        &mut self.bindings[slot]
     }
 
-    fn initialize_binding_impl(&mut self, name: &JSString, value: JSValue) {
-        debug_assert!(self.binding(name).value.is_none());
+    /// Takes the binding storage of a dead environment to grow into, if this environment has no bindings yet.
+    pub(crate) fn reuse_bindings_of(&mut self, dead: &mut DeclarativeEnvironment) {
+        if self.bindings.is_empty() {
+            std::mem::swap(&mut self.bindings, &mut dead.bindings);
+            std::mem::swap(&mut self.slots, &mut dead.slots);
 
-        self.binding_mut(&name).value = Some(value);
+            self.bindings.clear();
+            self.slots.clear();
+        }
     }
 
-    fn remove_binding_impl(&mut self, name: &JSString) {
-        self.bindings.remove(name);
+    pub(crate) fn has_binding_impl(&self, name: &Atom) -> bool {
+        self.slots.contains_key(&name)
     }
-}
 
-impl EnvironmentMethods for DeclarativeEnvironment {
-    /// 9.1.1.1.1 HasBinding ( N )
-    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-hasbinding-n
-    fn has_binding(&self, name: &JSString) -> CompletionRecord<bool> {
-        // 1. If envRec has a binding for N, return true.
-        // 2. Return false.
-        Ok(self.has_binding_impl(name))
-    }
+    fn add_binding_impl(&mut self, name: Atom, mutable: bool, deletable: bool, strict: bool) {
+        debug_assert!(!self.has_binding_impl(&name));
 
-    /// 9.1.1.1.2 CreateMutableBinding ( N, D )
-    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-createmutablebinding-n-d
-    fn create_mutable_binding(&mut self, name: &JSString, deletable: bool) -> CompletionRecord {
-        // 1. Assert: envRec does not already have a binding for N.
-        // 2. Create a mutable binding in envRec for N and record that it is uninitialized. If D is true, record that the newly created binding may be deleted by a subsequent DeleteBinding call.
-        self.add_binding_impl(name, true, deletable, true);
+        self.slots.insert(name.clone(), self.bindings.len());
 
-        // 3. Return unused.
-        Ok(())
+        self.bindings.push(Binding {
+            name,
+            mutable,
+            deletable,
+            strict,
+            value: None,
+        });
     }
 
-    /// 9.1.1.1.3 CreateImmutableBinding ( N, S )
-    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-createimmutablebinding-n-s
-    fn create_immutable_binding(&mut self, name: &JSString, strict: bool) -> CompletionRecord {
-        // 1. Assert: envRec does not already have a binding for N.
-        // Create an immutable binding in envRec for N and record that it is uninitialized. If S is true, record that the newly created binding is a strict binding.
-        self.add_binding_impl(name, false, false, strict);
+    fn initialize_binding_impl(&mut self, name: Atom, value: JSValue) {
+        debug_assert!(self.binding(&name).value.is_none());
 
-        // 3. Return unused.
-        Ok(())
+        self.binding_mut(&name).value = Some(value);
     }
 
-    /// 9.1.1.1.4 InitializeBinding ( N, V )
-    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-initializebinding-n-v
-    fn initialize_binding(&mut self, name: &JSString, value: JSValue) -> CompletionRecord {
-        // 1. Assert: envRec must have an uninitialized binding for N.
-        // 2. Set the bound value for N in envRec to V.
-        self.initialize_binding_impl(name, value);
-
-        // 3. Record that the binding for N in envRec has been initialized.
-        // Note: This is implicit in setting the value to Some(value)
+    fn remove_binding_impl(&mut self, name: Atom) {
+        if let Some(slot) = self.slots.remove(&name) {
+            self.bindings[slot].value = None;
+        }
+    }
 
-        // 4. Return unused.
-        Ok(())
+    /// The names and values of the bindings which have been initialized, in no particular order.
+    pub(crate) fn initialized_bindings(&self) -> Vec<(Atom, JSValue)> {
+        self.slots
+            .iter()
+            .filter_map(|(name, slot)| Some((name.clone(), self.bindings[*slot].value.clone()?)))
+            .collect()
     }
 
     /// 9.1.1.1.5 SetMutableBinding ( N, V, S )
     /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-setmutablebinding-n-v-s
-    fn set_mutable_binding(
+    pub(crate) fn set_mutable_binding_impl(
         &mut self,
-        name: &JSString,
+        name: Atom,
         value: JSValue,
-        mut strict: bool,
+        strict: bool,
     ) -> CompletionRecord {
         // 1. If envRec does not have a binding for N, then
         if !self.has_binding_impl(&name) {
             // a. If S is true, throw a ReferenceError exception.
             if strict {
-                reference_error(&format!("Property {name:?} is not defined"));
+                return reference_error(&format!("Property {name} is not defined"));
             }
 
             // b. Perform ! envRec.CreateMutableBinding(N, true).
-            self.add_binding_impl(name, true, true, true);
+            self.add_binding_impl(name.clone(), true, true, true);
 
             // c. Perform ! envRec.InitializeBinding(N, V).
             self.initialize_binding_impl(name, value);
@@ -137,28 +135,44 @@ impl EnvironmentMethods for DeclarativeEnvironment {
             return Ok(());
         }
 
+        self.set_mutable_binding_at(self.slot(&name), value, strict)
+    }
+
+    /// Steps 2 to 6 of SetMutableBinding, for the binding at the slot, which exists.
+    pub(crate) fn set_mutable_binding_at(
+        &mut self,
+        slot: usize,
+        value: JSValue,
+        mut strict: bool,
+    ) -> CompletionRecord {
+        let binding = &mut self.bindings[slot];
+
         // 2. If the binding for N in envRec is a strict binding, set S to true.
-        if self.binding(&name).strict {
+        if binding.strict {
             strict = true;
         }
 
         // 3. If the binding for N in envRec has not yet been initialized, then
-        if self.binding(&name).value.is_none() {
+        if binding.value.is_none() {
             // a. Throw a ReferenceError exception.
-            reference_error(&format!("Property {name:?} is not defined"));
+            return reference_error(&format!(
+                "Cannot access '{}' before initialization",
+                binding.name
+            ));
         }
         // 4. Else if the binding for N in envRec is a mutable binding, then
-        else if self.binding(&name).mutable {
+        else if binding.mutable {
             // a. Change its bound value to V.
-            self.binding_mut(&name).value = Some(value);
+            binding.value = Some(value);
         }
         // 5. Else,
         else {
             // a. Assert: This is an attempt to change the value of an immutable binding.
             // b. If S is true, throw a TypeError exception.
             if strict {
-                type_error(&format!(
-                    "Cannot change the value of an immutable property: {name:?}"
+                return type_error(&format!(
+                    "Assignment to constant variable '{}'",
+                    binding.name
                 ));
             }
         }
@@ -169,27 +183,115 @@ impl EnvironmentMethods for DeclarativeEnvironment {
 
     /// 9.1.1.1.6 GetBindingValue ( N, S )
     /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-getbindingvalue-n-s
-    fn get_binding_value(&self, name: &JSString, _strict: bool) -> CompletionRecord<JSValue> {
+    pub(crate) fn get_binding_value_impl(&self, name: Atom) -> CompletionRecord<JSValue> {
         // 1. Assert: envRec has a binding for N.
-        debug_assert!(self.has_binding_impl(name));
+        debug_assert!(self.has_binding_impl(&name));
+
+        self.get_binding_value_at(self.slot(&name))
+    }
+
+    /// Steps 2 and 3 of GetBindingValue, for the binding at the slot, which exists.
+    pub(crate) fn get_binding_value_at(&self, slot: usize) -> CompletionRecord<JSValue> {
+        let binding = &self.bindings[slot];
 
         // 2. If the binding for N in envRec is an uninitialized binding, throw a ReferenceError exception.
         // 3. Return the value currently bound to N in envRec.
-        if let Some(value) = &self.binding(name).value {
-            Ok(value.clone())
-        } else {
-            reference_error(&format!("Property {name:?} is not initialized"))
+        match &binding.value {
+            Some(value) => Ok(value.clone()),
+            None => reference_error(&format!(
+                "Cannot access '{}' before initialization",
+                binding.name
+            )),
         }
     }
 
+    /// The name of the binding at the slot, which exists.
+    pub(crate) fn binding_name_at(&self, slot: usize) -> Atom {
+        self.bindings[slot].name.clone()
+    }
+}
+
+impl EnvironmentMethods for DeclarativeEnvironment {
+    /// 9.1.1.1.1 HasBinding ( N )
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-hasbinding-n
+    fn has_binding(&self, _agent: &mut JSAgent, name: Atom) -> CompletionRecord<bool> {
+        // 1. If envRec has a binding for N, return true.
+        // 2. Return false.
+        Ok(self.has_binding_impl(&name))
+    }
+
+    /// 9.1.1.1.2 CreateMutableBinding ( N, D )
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-createmutablebinding-n-d
+    fn create_mutable_binding(&mut self, name: Atom, deletable: bool) -> CompletionRecord {
+        // 1. Assert: envRec does not already have a binding for N.
+        // 2. Create a mutable binding in envRec for N and record that it is uninitialized. If D is true, record that the newly created binding may be deleted by a subsequent DeleteBinding call.
+        self.add_binding_impl(name, true, deletable, true);
+
+        // 3. Return unused.
+        Ok(())
+    }
+
+    /// 9.1.1.1.3 CreateImmutableBinding ( N, S )
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-createimmutablebinding-n-s
+    fn create_immutable_binding(&mut self, name: Atom, strict: bool) -> CompletionRecord {
+        // 1. Assert: envRec does not already have a binding for N.
+        // Create an immutable binding in envRec for N and record that it is uninitialized. If S is true, record that the newly created binding is a strict binding.
+        self.add_binding_impl(name, false, false, strict);
+
+        // 3. Return unused.
+        Ok(())
+    }
+
+    /// 9.1.1.1.4 InitializeBinding ( N, V )
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-initializebinding-n-v
+    fn initialize_binding(
+        &mut self,
+        _agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+    ) -> CompletionRecord {
+        // 1. Assert: envRec must have an uninitialized binding for N.
+        // 2. Set the bound value for N in envRec to V.
+        self.initialize_binding_impl(name, value);
+
+        // 3. Record that the binding for N in envRec has been initialized.
+        // Note: This is implicit in setting the value to Some(value)
+
+        // 4. Return unused.
+        Ok(())
+    }
+
+    /// 9.1.1.1.5 SetMutableBinding ( N, V, S )
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-setmutablebinding-n-v-s
+    fn set_mutable_binding(
+        &mut self,
+        _agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+        strict: bool,
+    ) -> CompletionRecord {
+        self.set_mutable_binding_impl(name, value, strict)
+    }
+
+    /// 9.1.1.1.6 GetBindingValue ( N, S )
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-getbindingvalue-n-s
+    fn get_binding_value(
+        &self,
+        _agent: &mut JSAgent,
+        name: Atom,
+        _strict: bool,
+    ) -> CompletionRecord<JSValue> {
+        self.get_binding_value_impl(name)
+    }
+
     /// 9.1.1.1.7 DeleteBinding ( N )
     /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-deletebinding-n
-    fn delete_binding(&mut self, name: &JSString) -> CompletionRecord<bool> {
+    fn delete_binding(&mut self, name: Atom) -> CompletionRecord<bool> {
         // 1. Assert: envRec has a binding for N.
-        debug_assert!(self.has_binding_impl(name));
+        debug_assert!(self.has_binding_impl(&name));
 
         // 2. If the binding for N in envRec cannot be deleted, return false.
-        if !self.binding(name).deletable {
+        if !self.binding(&name).deletable {
             return Ok(false);
         }
 
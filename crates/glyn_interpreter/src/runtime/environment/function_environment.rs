@@ -1,6 +1,7 @@
 use crate::{
     runtime::{
         agent::reference_error,
+        agent::JSAgent,
         completion::{throw_completion, CompletionRecord, ThrowCompletion},
         environment::{
             declarative_environment::DeclarativeEnvironment, Environment, EnvironmentAddr,
@@ -8,8 +9,8 @@ use crate::{
         },
     },
     value::{
+        atom::Atom,
         object::{ObjectAddr, ObjectEssentialInternalMethods, ObjectKind, ObjectMeta},
-        string::JSString,
     },
     JSValue,
 };
@@ -48,36 +49,48 @@ pub(crate) struct FunctionEnvironment {
 }
 
 impl EnvironmentMethods for FunctionEnvironment {
-    fn has_binding(&self, name: &JSString) -> CompletionRecord<bool> {
-        self.decl_env.has_binding(name)
+    fn has_binding(&self, agent: &mut JSAgent, name: Atom) -> CompletionRecord<bool> {
+        self.decl_env.has_binding(agent, name)
     }
 
-    fn create_mutable_binding(&mut self, name: &JSString, deletable: bool) -> CompletionRecord {
+    fn create_mutable_binding(&mut self, name: Atom, deletable: bool) -> CompletionRecord {
         self.decl_env.create_mutable_binding(name, deletable)
     }
 
-    fn create_immutable_binding(&mut self, name: &JSString, strict: bool) -> CompletionRecord {
+    fn create_immutable_binding(&mut self, name: Atom, strict: bool) -> CompletionRecord {
         self.decl_env.create_immutable_binding(name, strict)
     }
 
-    fn initialize_binding(&mut self, name: &JSString, value: JSValue) -> CompletionRecord {
-        self.decl_env.initialize_binding(name, value)
+    fn initialize_binding(
+        &mut self,
+        agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+    ) -> CompletionRecord {
+        self.decl_env.initialize_binding(agent, name, value)
     }
 
     fn set_mutable_binding(
         &mut self,
-        name: &JSString,
+        agent: &mut JSAgent,
+        name: Atom,
         value: JSValue,
         strict: bool,
     ) -> CompletionRecord {
-        self.decl_env.set_mutable_binding(name, value, strict)
+        self.decl_env
+            .set_mutable_binding(agent, name, value, strict)
     }
 
-    fn get_binding_value(&self, name: &JSString, strict: bool) -> CompletionRecord<JSValue> {
-        self.decl_env.get_binding_value(name, strict)
+    fn get_binding_value(
+        &self,
+        agent: &mut JSAgent,
+        name: Atom,
+        strict: bool,
+    ) -> CompletionRecord<JSValue> {
+        self.decl_env.get_binding_value(agent, name, strict)
     }
 
-    fn delete_binding(&mut self, name: &JSString) -> CompletionRecord<bool> {
+    fn delete_binding(&mut self, name: Atom) -> CompletionRecord<bool> {
         self.decl_env.delete_binding(name)
     }
 
@@ -103,7 +116,7 @@ impl FunctionEnvironment {
 
         // 2. If envRec.[[ThisBindingStatus]] is initialized, throw a ReferenceError exception.
         if self.this_binding_status == ThisBindingStatus::Initialized {
-            reference_error("Cannot bind 'this' value multiple times");
+            return reference_error("Cannot bind 'this' value multiple times");
         }
 
         // 3. Set envRec.[[ThisValue]] to V.
@@ -147,7 +160,7 @@ impl FunctionEnvironment {
 
         // 2. If envRec.[[ThisBindingStatus]] is uninitialized, throw a ReferenceError exception.
         if self.this_binding_status == ThisBindingStatus::Uninitialized {
-            reference_error("Cannot get 'this' value which is uninitialized");
+            return reference_error("Cannot get 'this' value which is uninitialized");
         }
 
         // 3. Return envRec.[[ThisValue]].
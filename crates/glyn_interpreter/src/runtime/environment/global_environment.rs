@@ -5,6 +5,7 @@ use crate::{
     },
     runtime::{
         agent::type_error,
+        agent::JSAgent,
         completion::{throw_completion, CompletionRecord, ThrowCompletion},
         environment::{
             declarative_environment::DeclarativeEnvironment, object_environment::ObjectEnvironment,
@@ -12,11 +13,11 @@ use crate::{
         },
     },
     value::{
+        atom::Atom,
         object::{
             property::{JSObjectPropDescriptor, JSObjectPropKey},
             ObjectAddr, ObjectEssentialInternalMethods,
         },
-        string::JSString,
     },
     JSValue,
 };
@@ -42,25 +43,25 @@ pub(crate) struct GlobalEnvironment {
 impl EnvironmentMethods for GlobalEnvironment {
     /// 9.1.1.4.1 HasBinding ( N )
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records-hasbinding-n
-    fn has_binding(&self, name: &JSString) -> CompletionRecord<bool> {
+    fn has_binding(&self, agent: &mut JSAgent, name: Atom) -> CompletionRecord<bool> {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         // 2. If ! DclRec.HasBinding(N) is true, return true.
-        if self.declarative_record.has_binding(name)? {
+        if self.declarative_record.has_binding_impl(&name) {
             return Ok(true);
         }
 
         // 3. Let ObjRec be envRec.[[ObjectRecord]].
         // 4. Return ? ObjRec.HasBinding(N).
-        self.object_record.has_binding(name)
+        self.object_record.has_binding(agent, name)
     }
 
     /// 9.1.1.4.2 CreateMutableBinding ( N, D )
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records-createmutablebinding-n-d
-    fn create_mutable_binding(&mut self, name: &JSString, deletable: bool) -> CompletionRecord {
+    fn create_mutable_binding(&mut self, name: Atom, deletable: bool) -> CompletionRecord {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         // 2. If ! DclRec.HasBinding(N) is true, throw a TypeError exception.
-        if self.declarative_record.has_binding(&name)? {
-            type_error(&format!("Binding already exists for {name:?}"));
+        if self.declarative_record.has_binding_impl(&name) {
+            return type_error(&format!("Identifier '{name}' has already been declared"));
         }
 
         // 3. Return ! DclRec.CreateMutableBinding(N, D).
@@ -70,11 +71,11 @@ impl EnvironmentMethods for GlobalEnvironment {
 
     /// 9.1.1.4.3 CreateImmutableBinding ( N, S )
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records-createimmutablebinding-n-s
-    fn create_immutable_binding(&mut self, name: &JSString, strict: bool) -> CompletionRecord {
+    fn create_immutable_binding(&mut self, name: Atom, strict: bool) -> CompletionRecord {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         // 2. If ! DclRec.HasBinding(N) is true, throw a TypeError exception.
-        if self.declarative_record.has_binding(&name)? {
-            type_error(&format!("Binding already exists for {name:?}"));
+        if self.declarative_record.has_binding_impl(&name) {
+            return type_error(&format!("Identifier '{name}' has already been declared"));
         }
 
         // 3. Return ! DclRec.CreateImmutableBinding(N, S).
@@ -84,65 +85,81 @@ impl EnvironmentMethods for GlobalEnvironment {
 
     /// 9.1.1.4.4 InitializeBinding ( N, V )
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records-initializebinding-n-v
-    fn initialize_binding(&mut self, name: &JSString, value: JSValue) -> CompletionRecord {
+    fn initialize_binding(
+        &mut self,
+        agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+    ) -> CompletionRecord {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         // 2. If ! DclRec.HasBinding(N) is true, then
-        if self.declarative_record.has_binding(&name)? {
+        if self.declarative_record.has_binding_impl(&name) {
             // a. Return ! DclRec.InitializeBinding(N, V).
-            return self.declarative_record.initialize_binding(name, value);
+            return self
+                .declarative_record
+                .initialize_binding(agent, name, value);
         }
 
         // 3. Assert: If the binding exists, it must be in the Object Environment Record.
-        debug_assert!(self.object_record.has_binding(&name)?);
+        debug_assert!(self.object_record.has_binding(agent, name.clone())?);
 
         // 4. Let ObjRec be envRec.[[ObjectRecord]].
         // 5. Return ? ObjRec.InitializeBinding(N, V).
-        self.object_record.initialize_binding(name, value)
+        self.object_record.initialize_binding(agent, name, value)
     }
 
     /// 9.1.1.4.5 SetMutableBinding ( N, V, S )
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records-setmutablebinding-n-v-s
     fn set_mutable_binding(
         &mut self,
-        name: &JSString,
+        agent: &mut JSAgent,
+        name: Atom,
         value: JSValue,
         strict: bool,
     ) -> CompletionRecord {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         // 2. If ! DclRec.HasBinding(N) is true, then
-        if self.declarative_record.has_binding(&name)? {
+        if self.declarative_record.has_binding_impl(&name) {
             // a. Return ? DclRec.SetMutableBinding(N, V, S).
             return self
                 .declarative_record
-                .set_mutable_binding(name, value, strict);
+                .set_mutable_binding(agent, name, value, strict);
         }
 
         // 3. Let ObjRec be envRec.[[ObjectRecord]].
         // 4. Return ? ObjRec.SetMutableBinding(N, V, S).
-        self.object_record.set_mutable_binding(name, value, strict)
+        self.object_record
+            .set_mutable_binding(agent, name, value, strict)
     }
 
     /// 9.1.1.4.6 GetBindingValue ( N, S )
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records-getbindingvalue-n-s
-    fn get_binding_value(&self, name: &JSString, strict: bool) -> CompletionRecord<JSValue> {
+    fn get_binding_value(
+        &self,
+        agent: &mut JSAgent,
+        name: Atom,
+        strict: bool,
+    ) -> CompletionRecord<JSValue> {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         // 2. If ! DclRec.HasBinding(N) is true, then
-        if self.declarative_record.has_binding(name)? {
+        if self.declarative_record.has_binding_impl(&name) {
             // a. Return ? DclRec.GetBindingValue(N, S).
-            return self.declarative_record.get_binding_value(name, strict);
+            return self
+                .declarative_record
+                .get_binding_value(agent, name, strict);
         }
 
         // 3. Let ObjRec be envRec.[[ObjectRecord]].
         // 4. Return ? ObjRec.GetBindingValue(N, S).
-        self.object_record.get_binding_value(name, strict)
+        self.object_record.get_binding_value(agent, name, strict)
     }
 
     /// 9.1.1.4.7 DeleteBinding ( N )
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records-deletebinding-n
-    fn delete_binding(&mut self, name: &JSString) -> CompletionRecord<bool> {
+    fn delete_binding(&mut self, name: Atom) -> CompletionRecord<bool> {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         // 2. If ! DclRec.HasBinding(N) is true, then
-        if self.declarative_record.has_binding(name)? {
+        if self.declarative_record.has_binding_impl(&name) {
             // a. Return ! DclRec.DeleteBinding(N).
             return self.declarative_record.delete_binding(name);
         }
@@ -152,7 +169,7 @@ impl EnvironmentMethods for GlobalEnvironment {
         let global_object = self.object_record.binding_object.clone();
 
         // 5. Let existingProp be ? HasOwnProperty(globalObject, N).
-        let existing_prop = has_property(&global_object, &JSObjectPropKey::from(name))?;
+        let existing_prop = has_property(&global_object, &JSObjectPropKey::from(name.clone()))?;
 
         // 6. If existingProp is true, then
         if existing_prop {
@@ -196,17 +213,17 @@ impl GlobalEnvironment {
 
     /// 9.1.1.4.12 HasLexicalDeclaration ( envRec, N )
     /// https://262.ecma-international.org/16.0/#sec-haslexicaldeclaration
-    pub(crate) fn has_lexical_declaration(&self, name: &JSString) -> bool {
+    pub(crate) fn has_lexical_declaration(&self, name: &Atom) -> bool {
         // 1. Let DclRec be envRec.[[DeclarativeRecord]].
         let dcl_rec = &self.declarative_record;
 
         // 2. Return ! DclRec.HasBinding(N).
-        dcl_rec.has_binding(name).unwrap_or(false)
+        dcl_rec.has_binding_impl(name)
     }
 
     /// 9.1.1.4.13 HasRestrictedGlobalProperty ( envRec, N )
     /// https://262.ecma-international.org/16.0/#sec-hasrestrictedglobalproperty
-    pub(crate) fn has_restricted_global_property(&self, name: &JSString) -> CompletionRecord<bool> {
+    pub(crate) fn has_restricted_global_property(&self, name: Atom) -> CompletionRecord<bool> {
         // 1. Let ObjRec be envRec.[[ObjectRecord]].
         let obj_rec = &self.object_record;
 
@@ -232,7 +249,7 @@ impl GlobalEnvironment {
 
     /// 9.1.1.4.14 CanDeclareGlobalVar ( envRec, N )
     /// https://262.ecma-international.org/16.0/#sec-candeclareglobalvar
-    pub(crate) fn can_declare_global_var(&self, name: &JSString) -> CompletionRecord<bool> {
+    pub(crate) fn can_declare_global_var(&self, name: Atom) -> CompletionRecord<bool> {
         // 1. Let ObjRec be envRec.[[ObjectRecord]].
         let obj_rec = &self.object_record;
 
@@ -258,7 +275,7 @@ impl GlobalEnvironment {
 
     /// 9.1.1.4.15 CanDeclareGlobalFunction ( envRec, N )
     /// https://262.ecma-international.org/16.0/#sec-candeclareglobalfunction
-    pub(crate) fn can_declare_global_function(&self, name: &JSString) -> CompletionRecord<bool> {
+    pub(crate) fn can_declare_global_function(&self, name: Atom) -> CompletionRecord<bool> {
         // 1. Let ObjRec be envRec.[[ObjectRecord]].
         let obj_rec = &self.object_record;
 
@@ -294,7 +311,8 @@ impl GlobalEnvironment {
     /// https://262.ecma-international.org/16.0/#sec-createglobalvarbinding
     pub(crate) fn create_global_var_binding(
         &mut self,
-        name: &JSString,
+        agent: &mut JSAgent,
+        name: Atom,
         deletable: bool,
     ) -> CompletionRecord {
         // 1. Let ObjRec be envRec.[[ObjectRecord]].
@@ -304,7 +322,7 @@ impl GlobalEnvironment {
         let global_object = obj_rec.binding_object.clone();
 
         // 3. Let hasProperty be ? HasOwnProperty(globalObject, N).
-        let has_property = has_own_property(&global_object, &JSObjectPropKey::from(name))?;
+        let has_property = has_own_property(&global_object, &JSObjectPropKey::from(name.clone()))?;
 
         // 4. Let extensible be ? IsExtensible(globalObject).
         let extensible = is_extensible(&global_object);
@@ -312,10 +330,10 @@ impl GlobalEnvironment {
         // 5. If hasProperty is false and extensible is true, then
         if !has_property && extensible {
             // a. Perform ? ObjRec.CreateMutableBinding(N, D).
-            obj_rec.create_mutable_binding(name, deletable)?;
+            obj_rec.create_mutable_binding(name.clone(), deletable)?;
 
             // b. Perform ? ObjRec.InitializeBinding(N, undefined).
-            obj_rec.initialize_binding(name, JSValue::Undefined)?;
+            obj_rec.initialize_binding(agent, name, JSValue::Undefined)?;
         }
 
         // 6. Return unused.
@@ -326,7 +344,8 @@ impl GlobalEnvironment {
     /// https://262.ecma-international.org/16.0/#sec-createglobalfunctionbinding
     pub(crate) fn create_global_function_binding(
         &mut self,
-        name: JSString,
+        agent: &mut JSAgent,
+        name: Atom,
         value: JSValue,
         deletable: bool,
     ) -> CompletionRecord {
@@ -337,7 +356,8 @@ impl GlobalEnvironment {
         let global_object = obj_rec.binding_object.clone();
 
         // 3. Let existingProp be ? globalObject.[[GetOwnProperty]](N).
-        let existing_prop_opt = global_object.get_own_property(&JSObjectPropKey::from(&name))?;
+        let existing_prop_opt =
+            global_object.get_own_property(&JSObjectPropKey::from(name.clone()))?;
 
         // 4. If existingProp is undefined or existingProp.[[Configurable]] is true, then
         let desc = if existing_prop_opt.is_none()
@@ -363,10 +383,16 @@ impl GlobalEnvironment {
         };
 
         // 6. Perform ? DefinePropertyOrThrow(globalObject, N, desc).
-        define_property_or_throw(&global_object, &JSObjectPropKey::from(&name), desc)?;
+        define_property_or_throw(&global_object, &JSObjectPropKey::from(name.clone()), desc)?;
 
         // 7. Perform ? Set(globalObject, N, V, false).
-        set(&global_object, &JSObjectPropKey::from(&name), value, false)?;
+        set(
+            agent,
+            &global_object,
+            &JSObjectPropKey::from(name),
+            value,
+            false,
+        )?;
 
         // 8. Return unused.
         Ok(())
@@ -1,56 +1,70 @@
 pub(crate) mod declarative_environment;
 pub(crate) mod function_environment;
 pub(crate) mod global_environment;
+pub(crate) mod module_environment;
 pub(crate) mod object_environment;
 
 use std::ops::{Deref, DerefMut};
 
 use crate::{
-    gc::Gc,
+    gc::{Collect, Gc, Reclaim, Summarize, Trace, Tracer},
     runtime::{
+        agent::{reference_error, JSAgent},
         completion::CompletionRecord,
         environment::{
             declarative_environment::DeclarativeEnvironment,
             function_environment::FunctionEnvironment, global_environment::GlobalEnvironment,
-            object_environment::ObjectEnvironment,
+            module_environment::ModuleEnvironment, object_environment::ObjectEnvironment,
         },
+        module::ModuleAddr,
     },
-    value::{object::ObjectAddr, string::JSString, JSValue},
+    value::{atom::Atom, object::ObjectAddr, JSValue},
 };
 
 pub(crate) trait EnvironmentMethods {
     /// HasBinding ( N )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
-    fn has_binding(&self, name: &JSString) -> CompletionRecord<bool>;
+    fn has_binding(&self, agent: &mut JSAgent, name: Atom) -> CompletionRecord<bool>;
 
     /// CreateMutableBinding ( N, D )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
-    fn create_mutable_binding(&mut self, name: &JSString, deletable: bool) -> CompletionRecord;
+    fn create_mutable_binding(&mut self, name: Atom, deletable: bool) -> CompletionRecord;
 
     /// CreateImmutableBinding ( N, S )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
-    fn create_immutable_binding(&mut self, name: &JSString, strict: bool) -> CompletionRecord;
+    fn create_immutable_binding(&mut self, name: Atom, strict: bool) -> CompletionRecord;
 
     /// InitializeBinding ( N, V )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
-    fn initialize_binding(&mut self, name: &JSString, value: JSValue) -> CompletionRecord;
+    fn initialize_binding(
+        &mut self,
+        agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+    ) -> CompletionRecord;
 
     /// SetMutableBinding ( N, V, S )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
     fn set_mutable_binding(
         &mut self,
-        name: &JSString,
+        agent: &mut JSAgent,
+        name: Atom,
         value: JSValue,
         strict: bool,
     ) -> CompletionRecord;
 
     /// GetBindingValue ( N, S )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
-    fn get_binding_value(&self, name: &JSString, strict: bool) -> CompletionRecord<JSValue>;
+    fn get_binding_value(
+        &self,
+        agent: &mut JSAgent,
+        name: Atom,
+        strict: bool,
+    ) -> CompletionRecord<JSValue>;
 
     /// DeleteBinding ( N )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
-    fn delete_binding(&mut self, name: &JSString) -> CompletionRecord<bool>;
+    fn delete_binding(&mut self, name: Atom) -> CompletionRecord<bool>;
 
     /// HasThisBinding ( )
     /// https://262.ecma-international.org/16.0/#table-abstract-methods-of-environment-records
@@ -84,10 +98,84 @@ pub(crate) enum Environment {
     /// 9.1.1.4 Global Environment Records
     /// https://262.ecma-international.org/16.0/#sec-global-environment-records
     Global(GlobalEnvironment),
+
+    /// 9.1.1.5 Module Environment Records
+    /// https://262.ecma-international.org/16.0/#sec-module-environment-records
+    Module(ModuleEnvironment),
 }
 
 pub(crate) type EnvironmentAddr = Gc<Environment>;
 
+impl Trace for Environment {
+    fn trace(&self, tracer: &mut Tracer) {
+        match self {
+            Environment::Declarative(declarative_env) => declarative_env.trace(tracer),
+            Environment::Object(object_env) => object_env.trace(tracer),
+            Environment::Function(function_env) => {
+                function_env.outer_env.trace(tracer);
+                function_env.decl_env.trace(tracer);
+                function_env.this_value.trace(tracer);
+                function_env.function_object.trace(tracer);
+                function_env.new_target.trace(tracer);
+            }
+            Environment::Global(global_env) => {
+                global_env.outer_env.trace(tracer);
+                global_env.declarative_record.trace(tracer);
+                global_env.object_record.trace(tracer);
+                global_env.global_this_value.trace(tracer);
+            }
+            // NOTE: The modules which indirect bindings resolve through are roots, so they are not reported.
+            Environment::Module(module_env) => {
+                module_env.outer_env.trace(tracer);
+                module_env.decl_env.trace(tracer);
+            }
+        }
+    }
+}
+
+impl Collect for Environment {
+    fn clear(&mut self) {
+        *self = Environment::Declarative(DeclarativeEnvironment::default());
+    }
+}
+
+impl Summarize for Environment {
+    fn summarize(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Environment::Declarative(_) => "DeclarativeEnvironment",
+            Environment::Object(_) => "ObjectEnvironment",
+            Environment::Function(_) => "FunctionEnvironment",
+            Environment::Global(_) => "GlobalEnvironment",
+            Environment::Module(_) => "ModuleEnvironment",
+        })
+    }
+}
+
+impl Reclaim for Environment {
+    fn reclaim(&mut self, mut value: Self) {
+        // NOTE: A newly created environment has no bindings yet, so the binding map of the dead environment is kept to be grown into.
+        if let (Some(dead), Some(new)) = (
+            self.declarative_record_mut(),
+            value.declarative_record_mut(),
+        ) {
+            new.reuse_bindings_of(dead);
+        }
+
+        *self = value;
+    }
+}
+
+impl Environment {
+    /// The Declarative Environment Record which holds the bindings of a block or a function call, which are the environments allocated in the nursery.
+    fn declarative_record_mut(&mut self) -> Option<&mut DeclarativeEnvironment> {
+        match self {
+            Environment::Declarative(declarative_env) => Some(declarative_env),
+            Environment::Function(function_env) => Some(&mut function_env.decl_env),
+            _ => None,
+        }
+    }
+}
+
 impl EnvironmentAddr {
     pub(crate) fn outer(&self) -> Option<EnvironmentAddr> {
         match self.borrow().deref() {
@@ -95,21 +183,203 @@ impl EnvironmentAddr {
             Environment::Object(object_env) => object_env.outer_env.clone(),
             Environment::Function(function_env) => function_env.outer_env.clone(),
             Environment::Global(global_env) => global_env.outer_env.clone(),
+            Environment::Module(module_env) => module_env.outer_env.clone(),
+        }
+    }
+
+    /// The initialized bindings held by a Declarative Environment Record of the environment, in no particular order. The bindings held by an object and the indirect bindings of a module are not included.
+    pub(crate) fn declarative_bindings(&self) -> Vec<(Atom, JSValue)> {
+        match self.borrow().deref() {
+            Environment::Declarative(declarative_env) => declarative_env.initialized_bindings(),
+            Environment::Function(function_env) => function_env.decl_env.initialized_bindings(),
+            Environment::Global(global_env) => global_env.declarative_record.initialized_bindings(),
+            Environment::Module(module_env) => module_env.decl_env.initialized_bindings(),
+            Environment::Object(_) => vec![],
+        }
+    }
+
+    /// The environment the number of environments outwards from this one, which the bytecode generator counted when it resolved a binding statically.
+    pub(crate) fn outer_at(&self, depth: u32) -> EnvironmentAddr {
+        (0..depth).fold(self.clone(), |env, _| env.outer().unwrap())
+    }
+
+    /// GetBindingValue of the binding at the slot of a block's Declarative Environment Record, which the bytecode generator resolved statically.
+    pub(crate) fn get_binding_value_at(&self, slot: usize) -> CompletionRecord<JSValue> {
+        match self.borrow().deref() {
+            Environment::Declarative(declarative_env) => declarative_env.get_binding_value_at(slot),
+            // NOTE: Only the bindings of the Declarative Environment Records of blocks are resolved statically.
+            _ => unreachable!(),
+        }
+    }
+
+    /// SetMutableBinding of the binding at the slot of a block's Declarative Environment Record, which the bytecode generator resolved statically.
+    pub(crate) fn set_mutable_binding_at(
+        &self,
+        slot: usize,
+        value: JSValue,
+        strict: bool,
+    ) -> CompletionRecord {
+        match self.borrow_mut().deref_mut() {
+            Environment::Declarative(declarative_env) => {
+                declarative_env.set_mutable_binding_at(slot, value, strict)
+            }
+            // NOTE: Only the bindings of the Declarative Environment Records of blocks are resolved statically.
+            _ => unreachable!(),
+        }
+    }
+
+    /// The name of the binding at the slot of a block's Declarative Environment Record.
+    pub(crate) fn binding_name_at(&self, slot: usize) -> Atom {
+        match self.borrow().deref() {
+            Environment::Declarative(declarative_env) => declarative_env.binding_name_at(slot),
+            // NOTE: Only the bindings of the Declarative Environment Records of blocks are resolved statically.
+            _ => unreachable!(),
+        }
+    }
+
+    /// GetThisBinding ( )
+    /// https://262.ecma-international.org/16.0/#sec-function-environment-records-getthisbinding
+    /// https://262.ecma-international.org/16.0/#sec-global-environment-records-getthisbinding
+    /// https://262.ecma-international.org/16.0/#sec-module-environment-records-getthisbinding
+    pub(crate) fn get_this_binding(&self) -> CompletionRecord<JSValue> {
+        match self.borrow().deref() {
+            Environment::Function(function_env) => function_env.get_this_binding(),
+            Environment::Global(global_env) => Ok(global_env
+                .get_this_binding()
+                .map_or(JSValue::Undefined, JSValue::from)),
+            Environment::Module(module_env) => module_env.get_this_binding(),
+            // NOTE: Only Function, Global and Module Environment Records have a this binding.
+            _ => unreachable!(),
+        }
+    }
+
+    /// [[NewTarget]]
+    /// https://262.ecma-international.org/16.0/#table-additional-fields-of-function-environment-records
+    ///
+    /// The constructor the function was called with by [[Construct]], which is undefined if it was called by [[Call]].
+    pub(crate) fn new_target(&self) -> Option<ObjectAddr> {
+        match self.borrow().deref() {
+            Environment::Function(function_env) => function_env.new_target.clone(),
+            // NOTE: Only Function Environment Records have a [[NewTarget]] field, and it is an early error to evaluate new.target outside of a function.
+            _ => None,
+        }
+    }
+
+    /// GetSuperBase ( envRec )
+    /// https://262.ecma-international.org/16.0/#sec-getsuperbase
+    pub(crate) fn get_super_base(&self) -> Option<ObjectAddr> {
+        match self.borrow().deref() {
+            Environment::Function(function_env) => function_env.get_super_base(),
+            // NOTE: Only Function Environment Records have a super binding, and it is an early error to reference super outside of a method.
+            _ => None,
+        }
+    }
+
+    /// BindThisValue ( envRec, V )
+    /// https://262.ecma-international.org/16.0/#sec-bindthisvalue
+    pub(crate) fn bind_this_value(&self, value: JSValue) -> CompletionRecord {
+        match self.borrow_mut().deref_mut() {
+            Environment::Function(function_env) => function_env.bind_this_value(value),
+            // NOTE: Only Function Environment Records can bind a this value.
+            _ => unreachable!(),
+        }
+    }
+
+    /// The Object Environment Record which holds the binding for the name, if it is held by an object rather than by a Declarative Environment Record.
+    ///
+    /// Accessing a property of the binding object can evaluate a getter or setter, which may in turn access this environment, so the record is cloned out of the environment to be used once the borrow has been released.
+    fn object_record(&self, name: Atom) -> Option<ObjectEnvironment> {
+        match self.borrow().deref() {
+            Environment::Object(object_env) => Some(object_env.clone()),
+            // NOTE: A Global Environment Record defers to its [[ObjectRecord]] for every name its [[DeclarativeRecord]] has no binding for.
+            Environment::Global(global_env)
+                if !global_env.declarative_record.has_binding_impl(&name) =>
+            {
+                Some(global_env.object_record.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// The module and the name of the binding which an indirect binding of a Module Environment Record refers to, see [`ModuleEnvironment::indirect_binding`].
+    fn indirect_binding(&self, name: Atom) -> Option<(ModuleAddr, Atom)> {
+        match self.borrow().deref() {
+            Environment::Module(module_env) => module_env.indirect_binding(name),
+            _ => None,
+        }
+    }
+
+    /// GetBindingValue ( N, S ) of a Declarative, Function or Module Environment Record, which never evaluates user code and so can be performed without an agent.
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-getbindingvalue-n-s
+    /// https://262.ecma-international.org/16.0/#sec-module-environment-records-getbindingvalue-n-s
+    pub(crate) fn get_declarative_binding_value(&self, name: Atom) -> CompletionRecord<JSValue> {
+        // 3. If the binding for N is an indirect binding, then
+        if let Some((module, binding_name)) = self.indirect_binding(name.clone()) {
+            // a. Let M and N2 be the indirection values provided when this binding for N was created.
+            // b. Let targetEnv be M.[[Environment]].
+            let target_env = module.borrow().environment.clone();
+
+            // c. If targetEnv is empty, throw a ReferenceError exception.
+            let Some(target_env) = target_env else {
+                return reference_error(&format!(
+                    "Cannot access '{name}' before its module is linked"
+                ));
+            };
+
+            // d. Return ? targetEnv.GetBindingValue(N2, true).
+            return target_env.get_declarative_binding_value(binding_name);
+        }
+
+        match self.borrow().deref() {
+            Environment::Declarative(declarative_env) => {
+                declarative_env.get_binding_value_impl(name)
+            }
+            Environment::Function(function_env) => {
+                function_env.decl_env.get_binding_value_impl(name)
+            }
+            Environment::Module(module_env) => module_env.decl_env.get_binding_value_impl(name),
+            // NOTE: Object and Global Environment Records may hold bindings which are properties of an object.
+            _ => unreachable!(),
+        }
+    }
+
+    /// SetMutableBinding ( N, V, S ) of a Declarative or Function Environment Record, which never evaluates user code and so can be performed without an agent.
+    /// https://262.ecma-international.org/16.0/#sec-declarative-environment-records-setmutablebinding-n-v-s
+    pub(crate) fn set_declarative_mutable_binding(
+        &self,
+        name: Atom,
+        value: JSValue,
+        strict: bool,
+    ) -> CompletionRecord {
+        match self.borrow_mut().deref_mut() {
+            Environment::Declarative(declarative_env) => {
+                declarative_env.set_mutable_binding_impl(name, value, strict)
+            }
+            Environment::Function(function_env) => function_env
+                .decl_env
+                .set_mutable_binding_impl(name, value, strict),
+            // NOTE: Object and Global Environment Records may hold bindings which are properties of an object.
+            _ => unreachable!(),
         }
     }
 }
 
 impl EnvironmentMethods for EnvironmentAddr {
-    fn has_binding(&self, name: &JSString) -> CompletionRecord<bool> {
+    fn has_binding(&self, agent: &mut JSAgent, name: Atom) -> CompletionRecord<bool> {
+        if let Some(object_record) = self.object_record(name.clone()) {
+            return object_record.has_binding(agent, name);
+        }
+
         match self.borrow().deref() {
-            Environment::Declarative(declarative_env) => declarative_env.has_binding(name),
-            Environment::Object(object_env) => object_env.has_binding(name),
-            Environment::Function(function_env) => function_env.has_binding(name),
-            Environment::Global(global_env) => global_env.has_binding(name),
+            Environment::Declarative(declarative_env) => declarative_env.has_binding(agent, name),
+            Environment::Function(function_env) => function_env.has_binding(agent, name),
+            Environment::Global(global_env) => global_env.has_binding(agent, name),
+            Environment::Module(module_env) => module_env.has_binding(agent, name),
+            Environment::Object(_) => unreachable!(),
         }
     }
 
-    fn create_mutable_binding(&mut self, name: &JSString, deletable: bool) -> CompletionRecord {
+    fn create_mutable_binding(&mut self, name: Atom, deletable: bool) -> CompletionRecord {
         match self.borrow_mut().deref_mut() {
             Environment::Declarative(declarative_env) => {
                 declarative_env.create_mutable_binding(name, deletable)
@@ -119,10 +389,11 @@ impl EnvironmentMethods for EnvironmentAddr {
                 function_env.create_mutable_binding(name, deletable)
             }
             Environment::Global(global_env) => global_env.create_mutable_binding(name, deletable),
+            Environment::Module(module_env) => module_env.create_mutable_binding(name, deletable),
         }
     }
 
-    fn create_immutable_binding(&mut self, name: &JSString, strict: bool) -> CompletionRecord {
+    fn create_immutable_binding(&mut self, name: Atom, strict: bool) -> CompletionRecord {
         match self.borrow_mut().deref_mut() {
             Environment::Declarative(declarative_env) => {
                 declarative_env.create_immutable_binding(name, strict)
@@ -132,55 +403,95 @@ impl EnvironmentMethods for EnvironmentAddr {
                 function_env.create_immutable_binding(name, strict)
             }
             Environment::Global(global_env) => global_env.create_immutable_binding(name, strict),
+            Environment::Module(module_env) => module_env.create_immutable_binding(name, strict),
         }
     }
 
-    fn initialize_binding(&mut self, name: &JSString, value: JSValue) -> CompletionRecord {
+    fn initialize_binding(
+        &mut self,
+        agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+    ) -> CompletionRecord {
+        if let Some(mut object_record) = self.object_record(name.clone()) {
+            return object_record.initialize_binding(agent, name, value);
+        }
+
         match self.borrow_mut().deref_mut() {
             Environment::Declarative(declarative_env) => {
-                declarative_env.initialize_binding(name, value)
+                declarative_env.initialize_binding(agent, name, value)
+            }
+            Environment::Function(function_env) => {
+                function_env.initialize_binding(agent, name, value)
             }
-            Environment::Object(object_env) => object_env.initialize_binding(name, value),
-            Environment::Function(function_env) => function_env.initialize_binding(name, value),
-            Environment::Global(global_env) => global_env.initialize_binding(name, value),
+            Environment::Global(global_env) => global_env.initialize_binding(agent, name, value),
+            Environment::Module(module_env) => module_env.initialize_binding(agent, name, value),
+            Environment::Object(_) => unreachable!(),
         }
     }
 
     fn set_mutable_binding(
         &mut self,
-        name: &JSString,
+        agent: &mut JSAgent,
+        name: Atom,
         value: JSValue,
         strict: bool,
     ) -> CompletionRecord {
+        if let Some(mut object_record) = self.object_record(name.clone()) {
+            return object_record.set_mutable_binding(agent, name, value, strict);
+        }
+
         match self.borrow_mut().deref_mut() {
             Environment::Declarative(declarative_env) => {
-                declarative_env.set_mutable_binding(name, value, strict)
+                declarative_env.set_mutable_binding(agent, name, value, strict)
             }
-            Environment::Object(object_env) => object_env.set_mutable_binding(name, value, strict),
             Environment::Function(function_env) => {
-                function_env.set_mutable_binding(name, value, strict)
+                function_env.set_mutable_binding(agent, name, value, strict)
+            }
+            Environment::Global(global_env) => {
+                global_env.set_mutable_binding(agent, name, value, strict)
+            }
+            Environment::Module(module_env) => {
+                module_env.set_mutable_binding(agent, name, value, strict)
             }
-            Environment::Global(global_env) => global_env.set_mutable_binding(name, value, strict),
+            Environment::Object(_) => unreachable!(),
         }
     }
 
-    fn get_binding_value(&self, name: &JSString, strict: bool) -> CompletionRecord<JSValue> {
+    fn get_binding_value(
+        &self,
+        agent: &mut JSAgent,
+        name: Atom,
+        strict: bool,
+    ) -> CompletionRecord<JSValue> {
+        if let Some(object_record) = self.object_record(name.clone()) {
+            return object_record.get_binding_value(agent, name, strict);
+        }
+
+        if self.indirect_binding(name.clone()).is_some() {
+            return self.get_declarative_binding_value(name);
+        }
+
         match self.borrow().deref() {
             Environment::Declarative(declarative_env) => {
-                declarative_env.get_binding_value(name, strict)
+                declarative_env.get_binding_value(agent, name, strict)
+            }
+            Environment::Function(function_env) => {
+                function_env.get_binding_value(agent, name, strict)
             }
-            Environment::Object(object_env) => object_env.get_binding_value(name, strict),
-            Environment::Function(function_env) => function_env.get_binding_value(name, strict),
-            Environment::Global(global_env) => global_env.get_binding_value(name, strict),
+            Environment::Global(global_env) => global_env.get_binding_value(agent, name, strict),
+            Environment::Module(module_env) => module_env.get_binding_value(agent, name, strict),
+            Environment::Object(_) => unreachable!(),
         }
     }
 
-    fn delete_binding(&mut self, name: &JSString) -> CompletionRecord<bool> {
+    fn delete_binding(&mut self, name: Atom) -> CompletionRecord<bool> {
         match self.borrow_mut().deref_mut() {
             Environment::Declarative(declarative_env) => declarative_env.delete_binding(name),
             Environment::Object(object_env) => object_env.delete_binding(name),
             Environment::Function(function_env) => function_env.delete_binding(name),
             Environment::Global(global_env) => global_env.delete_binding(name),
+            Environment::Module(module_env) => module_env.delete_binding(name),
         }
     }
 
@@ -190,6 +501,7 @@ impl EnvironmentMethods for EnvironmentAddr {
             Environment::Object(object_env) => object_env.has_this_binding(),
             Environment::Function(function_env) => function_env.has_this_binding(),
             Environment::Global(global_env) => global_env.has_this_binding(),
+            Environment::Module(module_env) => module_env.has_this_binding(),
         }
     }
 
@@ -199,6 +511,7 @@ impl EnvironmentMethods for EnvironmentAddr {
             Environment::Object(object_env) => object_env.has_super_binding(),
             Environment::Function(function_env) => function_env.has_super_binding(),
             Environment::Global(global_env) => global_env.has_super_binding(),
+            Environment::Module(module_env) => module_env.has_super_binding(),
         }
     }
 
@@ -208,6 +521,7 @@ impl EnvironmentMethods for EnvironmentAddr {
             Environment::Object(object_env) => object_env.with_base_object(),
             Environment::Function(function_env) => function_env.with_base_object(),
             Environment::Global(global_env) => global_env.with_base_object(),
+            Environment::Module(module_env) => module_env.with_base_object(),
         }
     }
 }
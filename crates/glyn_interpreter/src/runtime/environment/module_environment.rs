@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use crate::{
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::{throw_completion, CompletionRecord, ThrowCompletion},
+        environment::{
+            declarative_environment::DeclarativeEnvironment, Environment, EnvironmentAddr,
+            EnvironmentMethods,
+        },
+        module::ModuleAddr,
+    },
+    value::{atom::Atom, object::ObjectAddr},
+    JSValue,
+};
+
+/// 9.1.1.5 Module Environment Records
+/// https://262.ecma-international.org/16.0/#sec-module-environment-records
+#[derive(Debug, Default)]
+pub(crate) struct ModuleEnvironment {
+    /// [[OuterEnv]]
+    pub(crate) outer_env: Option<EnvironmentAddr>,
+    pub(crate) decl_env: DeclarativeEnvironment,
+
+    /// The immutable indirect bindings created by CreateImportBinding, each of which refers to a binding of the module environment of the module it is imported from.
+    pub(crate) indirect_bindings: HashMap<Atom, (ModuleAddr, Atom)>,
+}
+
+impl ModuleEnvironment {
+    /// The module and the name of the binding which an indirect binding refers to, if the name is bound by an import.
+    ///
+    /// The binding may be held by this same environment, if a module imports from itself, so the target is cloned out of the environment to be read once the borrow has been released.
+    pub(crate) fn indirect_binding(&self, name: Atom) -> Option<(ModuleAddr, Atom)> {
+        self.indirect_bindings.get(&name).cloned()
+    }
+
+    /// 9.1.1.5.5 CreateImportBinding ( envRec, N, M, N2 )
+    /// https://262.ecma-international.org/16.0/#sec-createimportbinding
+    pub(crate) fn create_import_binding(
+        &mut self,
+        name: Atom,
+        module: ModuleAddr,
+        binding_name: Atom,
+    ) {
+        // 1. Assert: envRec does not already have a binding for N.
+        debug_assert!(!self.decl_env.has_binding_impl(&name));
+
+        // 2. Assert: When M.[[Environment]] is instantiated, it will have a direct binding for N2.
+        // 3. Create an immutable indirect binding in envRec for N that references M and N2 as its target binding and record that the binding is initialized.
+        self.indirect_bindings.insert(name, (module, binding_name));
+
+        // 4. Return unused.
+    }
+
+    /// 9.1.1.5.4 GetThisBinding ( )
+    /// https://262.ecma-international.org/16.0/#sec-module-environment-records-getthisbinding
+    pub(crate) fn get_this_binding(&self) -> CompletionRecord<JSValue> {
+        // 1. Return undefined.
+        Ok(JSValue::Undefined)
+    }
+}
+
+impl EnvironmentMethods for ModuleEnvironment {
+    fn has_binding(&self, agent: &mut JSAgent, name: Atom) -> CompletionRecord<bool> {
+        if self.indirect_bindings.contains_key(&name) {
+            return Ok(true);
+        }
+
+        self.decl_env.has_binding(agent, name)
+    }
+
+    fn create_mutable_binding(&mut self, name: Atom, deletable: bool) -> CompletionRecord {
+        self.decl_env.create_mutable_binding(name, deletable)
+    }
+
+    fn create_immutable_binding(&mut self, name: Atom, strict: bool) -> CompletionRecord {
+        self.decl_env.create_immutable_binding(name, strict)
+    }
+
+    fn initialize_binding(
+        &mut self,
+        agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+    ) -> CompletionRecord {
+        self.decl_env.initialize_binding(agent, name, value)
+    }
+
+    fn set_mutable_binding(
+        &mut self,
+        agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+        strict: bool,
+    ) -> CompletionRecord {
+        // NOTE: An indirect binding is immutable, and module code is strict mode code.
+        if self.indirect_bindings.contains_key(&name) {
+            return type_error(&format!("Assignment to imported binding '{name}'"));
+        }
+
+        self.decl_env
+            .set_mutable_binding(agent, name, value, strict)
+    }
+
+    /// 9.1.1.5.1 GetBindingValue ( N, S )
+    /// https://262.ecma-international.org/16.0/#sec-module-environment-records-getbindingvalue-n-s
+    fn get_binding_value(
+        &self,
+        agent: &mut JSAgent,
+        name: Atom,
+        strict: bool,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Assert: S is true.
+        // 2. Assert: envRec has a binding for N.
+        // 3. If the binding for N is an indirect binding, then
+        // NOTE: Indirect bindings are resolved by the Environment Record which holds this one, see [`ModuleEnvironment::indirect_binding`].
+        debug_assert!(!self.indirect_bindings.contains_key(&name));
+
+        // 4. If envRec's binding for N is an uninitialized binding, throw a ReferenceError exception.
+        // 5. Return the value currently bound to N in envRec.
+        self.decl_env.get_binding_value(agent, name, strict)
+    }
+
+    /// 9.1.1.5.2 DeleteBinding ( N )
+    /// https://262.ecma-international.org/16.0/#sec-module-environment-records-deletebinding-n
+    fn delete_binding(&mut self, _name: Atom) -> CompletionRecord<bool> {
+        // 1. Assert: This method is never invoked. See 13.5.1.1.
+        // NOTE: Deleting an unqualified identifier is an early error in strict mode code.
+        unreachable!()
+    }
+
+    /// 9.1.1.5.3 HasThisBinding ( )
+    /// https://262.ecma-international.org/16.0/#sec-module-environment-records-hasthisbinding
+    fn has_this_binding(&self) -> bool {
+        // 1. Return true.
+        true
+    }
+
+    fn has_super_binding(&self) -> bool {
+        self.decl_env.has_super_binding()
+    }
+
+    fn with_base_object(&self) -> Option<ObjectAddr> {
+        self.decl_env.with_base_object()
+    }
+}
+
+impl<'a> TryFrom<&'a mut Environment> for &'a mut ModuleEnvironment {
+    type Error = ThrowCompletion;
+
+    fn try_from(value: &'a mut Environment) -> Result<&'a mut ModuleEnvironment, Self::Error> {
+        match value {
+            Environment::Module(module_env) => Ok(module_env),
+            _ => {
+                throw_completion("Expected Environment::Module for conversion to ModuleEnvironment")
+            }
+        }
+    }
+}
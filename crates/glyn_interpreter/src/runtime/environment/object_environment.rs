@@ -3,17 +3,19 @@ use crate::{
         object_operations::{define_property_or_throw, get, has_property, set},
         type_conversion::to_boolean,
     },
+    gc::{Trace, Tracer},
     runtime::{
+        agent::JSAgent,
         agent::{reference_error, WELL_KNOWN_SYMBOLS_UNSCOPABLES},
         completion::{throw_completion, CompletionRecord, ThrowCompletion},
         environment::{Environment, EnvironmentAddr, EnvironmentMethods},
     },
     value::{
+        atom::Atom,
         object::{
             property::{JSObjectPropDescriptor, JSObjectPropKey},
             ObjectAddr, ObjectEssentialInternalMethods,
         },
-        string::JSString,
     },
     JSValue,
 };
@@ -32,15 +34,22 @@ pub(crate) struct ObjectEnvironment {
     pub(crate) is_with_environment: bool,
 }
 
+impl Trace for ObjectEnvironment {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.outer_env.trace(tracer);
+        self.binding_object.trace(tracer);
+    }
+}
+
 impl EnvironmentMethods for ObjectEnvironment {
     /// 9.1.1.2.1 HasBinding ( N )
     /// https://262.ecma-international.org/16.0/#sec-object-environment-records-hasbinding-n
-    fn has_binding(&self, name: &JSString) -> CompletionRecord<bool> {
+    fn has_binding(&self, agent: &mut JSAgent, name: Atom) -> CompletionRecord<bool> {
         // 1. Let bindingObject be envRec.[[BindingObject]].
         let binding_object = self.binding_object.clone();
 
         // 2. Let foundBinding be ? HasProperty(bindingObject, N).
-        let found_binding = has_property(&binding_object, &JSObjectPropKey::from(name))?;
+        let found_binding = has_property(&binding_object, &JSObjectPropKey::from(name.clone()))?;
 
         // 3. If foundBinding is false, return false.
         if !found_binding {
@@ -54,19 +63,15 @@ impl EnvironmentMethods for ObjectEnvironment {
 
         // 5. Let unscopables be ? Get(bindingObject, %Symbol.unscopables%).
         let unscopables = get(
+            agent,
             &binding_object,
             &JSObjectPropKey::from(WELL_KNOWN_SYMBOLS_UNSCOPABLES),
-            &JSValue::from(self.binding_object.clone()),
         )?;
 
         // 6. If unscopables is an Object, then
         if let Ok(unscopables_obj) = ObjectAddr::try_from(unscopables) {
             // a. Let blocked be ToBoolean(? Get(unscopables, N)).
-            let blocked = to_boolean(get(
-                &unscopables_obj,
-                &JSObjectPropKey::from(name),
-                &JSValue::from(unscopables_obj.clone()),
-            )?);
+            let blocked = to_boolean(get(agent, &unscopables_obj, &JSObjectPropKey::from(name))?);
 
             // b. If blocked is true, return false.
             if blocked {
@@ -80,7 +85,7 @@ impl EnvironmentMethods for ObjectEnvironment {
 
     /// 9.1.1.2.2 CreateMutableBinding ( N, D )
     /// https://262.ecma-international.org/16.0/#sec-object-environment-records-createmutablebinding-n-d
-    fn create_mutable_binding(&mut self, name: &JSString, deletable: bool) -> CompletionRecord {
+    fn create_mutable_binding(&mut self, name: Atom, deletable: bool) -> CompletionRecord {
         // 1. Let bindingObject be envRec.[[BindingObject]].
         let binding_object = self.binding_object.clone();
 
@@ -103,16 +108,21 @@ impl EnvironmentMethods for ObjectEnvironment {
 
     /// 9.1.1.2.3 CreateImmutableBinding ( N, S )
     /// https://262.ecma-international.org/16.0/#sec-object-environment-records-createimmutablebinding-n-s
-    fn create_immutable_binding(&mut self, name: &JSString, strict: bool) -> CompletionRecord {
+    fn create_immutable_binding(&mut self, name: Atom, strict: bool) -> CompletionRecord {
         // The CreateImmutableBinding concrete method of an Object Environment Record is never used within this specification.
         unreachable!()
     }
 
     /// 9.1.1.2.4 InitializeBinding ( N, V )
     /// https://262.ecma-international.org/16.0/#sec-object-environment-records-initializebinding-n-v
-    fn initialize_binding(&mut self, name: &JSString, value: JSValue) -> CompletionRecord {
+    fn initialize_binding(
+        &mut self,
+        agent: &mut JSAgent,
+        name: Atom,
+        value: JSValue,
+    ) -> CompletionRecord {
         // 1. Perform ? envRec.SetMutableBinding(N, V, false).
-        self.set_mutable_binding(name, value, false)?;
+        self.set_mutable_binding(agent, name, value, false)?;
 
         // 2. Return unused.
         Ok(())
@@ -122,8 +132,8 @@ impl EnvironmentMethods for ObjectEnvironment {
     /// https://262.ecma-international.org/16.0/#sec-object-environment-records-setmutablebinding-n-v-s
     fn set_mutable_binding(
         &mut self,
-
-        name: &JSString,
+        agent: &mut JSAgent,
+        name: Atom,
         value: JSValue,
         strict: bool,
     ) -> CompletionRecord {
@@ -131,15 +141,21 @@ impl EnvironmentMethods for ObjectEnvironment {
         let binding_object = self.binding_object.clone();
 
         // 2. Let stillExists be ? HasProperty(bindingObject, N).
-        let still_exists = has_property(&binding_object, &JSObjectPropKey::from(name))?;
+        let still_exists = has_property(&binding_object, &JSObjectPropKey::from(name.clone()))?;
 
         // 3. If stillExists is false and S is true, throw a ReferenceError exception.
         if !still_exists && strict {
-            reference_error(&format!("Property {name:?} is not defined"));
+            return reference_error(&format!("Property {name} is not defined"));
         }
 
         // 4. Perform ? Set(bindingObject, N, V, S).
-        set(&binding_object, &JSObjectPropKey::from(name), value, strict)?;
+        set(
+            agent,
+            &binding_object,
+            &JSObjectPropKey::from(name),
+            value,
+            strict,
+        )?;
 
         // 5. Return unused.
         Ok(())
@@ -147,34 +163,35 @@ impl EnvironmentMethods for ObjectEnvironment {
 
     /// 9.1.1.2.6 GetBindingValue ( N, S )
     /// https://262.ecma-international.org/16.0/#sec-object-environment-records-getbindingvalue-n-s
-    fn get_binding_value(&self, name: &JSString, strict: bool) -> CompletionRecord<JSValue> {
+    fn get_binding_value(
+        &self,
+        agent: &mut JSAgent,
+        name: Atom,
+        strict: bool,
+    ) -> CompletionRecord<JSValue> {
         // 1. Let bindingObject be envRec.[[BindingObject]].
         let binding_object = self.binding_object.clone();
 
         // 2. Let value be ? HasProperty(bindingObject, N).
-        let value = has_property(&binding_object, &JSObjectPropKey::from(name))?;
+        let value = has_property(&binding_object, &JSObjectPropKey::from(name.clone()))?;
 
         // 3. If value is false, then
         if !value {
             // a. If S is false, return undefined; otherwise throw a ReferenceError exception.
             if strict {
-                reference_error(&format!("Property {name:?} is not defined"));
+                return reference_error(&format!("Property {name} is not defined"));
             }
 
             return Ok(JSValue::Undefined);
         }
 
         // 4. Return ? Get(bindingObject, N).
-        get(
-            &binding_object,
-            &JSObjectPropKey::from(name),
-            &JSValue::from(self.binding_object.clone()),
-        )
+        get(agent, &binding_object, &JSObjectPropKey::from(name))
     }
 
     /// 9.1.1.2.7 DeleteBinding ( N )
     /// https://262.ecma-international.org/16.0/#sec-object-environment-records-deletebinding-n
-    fn delete_binding(&mut self, name: &JSString) -> CompletionRecord<bool> {
+    fn delete_binding(&mut self, name: Atom) -> CompletionRecord<bool> {
         // 1. Let bindingObject be envRec.[[BindingObject]].
         let binding_object = self.binding_object.clone();
 
@@ -1,17 +1,19 @@
+use crate::gc::{Trace, Tracer};
 use crate::runtime::environment::EnvironmentAddr;
+use crate::runtime::module::ModuleAddr;
 use crate::runtime::realm::RealmAddr;
 use crate::runtime::script::ScriptRecord;
 use crate::value::object::ObjectAddr;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) enum ScriptOrModule {
     Script(ScriptRecord),
-    Module,
+    Module(ModuleAddr),
 }
 
 /// 9.4 Execution Contexts
 /// https://262.ecma-international.org/16.0/#sec-execution-contexts
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct ExecutionContext {
     /// Function
     pub(crate) function: Option<ObjectAddr>,
@@ -31,3 +33,19 @@ pub(crate) struct ExecutionContext {
     /// PrivateEnvironment
     pub(crate) private_environment: Option<EnvironmentAddr>,
 }
+
+impl Trace for ExecutionContext {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.realm.trace(tracer);
+        self.function.trace(tracer);
+
+        // NOTE: Modules are roots, so only the realm of a script is reported.
+        if let Some(ScriptOrModule::Script(script)) = &self.script_or_module {
+            script.realm.trace(tracer);
+        }
+
+        self.lexical_environment.trace(tracer);
+        self.variable_environment.trace(tracer);
+        self.private_environment.trace(tracer);
+    }
+}
@@ -0,0 +1,36 @@
+use std::rc::Rc;
+
+use crate::{
+    codegen::bytecode::generator::ExecutableFunction,
+    gc::{Trace, Tracer},
+    runtime::execution_context::ExecutionContext,
+    vm::VMFrame,
+};
+
+/// [[GeneratorState]]
+/// https://262.ecma-international.org/16.0/#table-internal-slots-of-generator-instances
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// [[GeneratorContext]]
+/// https://262.ecma-international.org/16.0/#table-internal-slots-of-generator-instances
+///
+/// The execution context of a suspended generator, along with the code evaluation state it is resumed from.
+#[derive(Debug)]
+pub(crate) struct GeneratorContext {
+    pub(crate) execution_context: ExecutionContext,
+    pub(crate) code: Rc<ExecutableFunction>,
+    pub(crate) frame: VMFrame,
+}
+
+impl Trace for GeneratorContext {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.execution_context.trace(tracer);
+        self.frame.trace(tracer);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{eval_script::EvalError, value::JSValue};
+
+/// The host-defined operations of the specification which an embedder can customize, installed on an agent with [`crate::JSAgent::set_host_hooks`]. Every hook behaves as it does for a host which customizes nothing unless it is implemented.
+pub trait HostHooks {
+    /// 9.5.3 HostCallJobCallback ( jobCallback, V, argumentsList )
+    /// https://262.ecma-international.org/16.0/#sec-hostcalljobcallback
+    ///
+    /// Called before the callback of a promise job is called with the this value and the arguments, for example to prepare host state which the callback depends on.
+    fn call_job_callback(&mut self, _callback: &JSValue, _this_value: &JSValue, _args: &[JSValue]) {
+    }
+
+    /// 27.2.1.9 HostPromiseRejectionTracker ( promise, operation )
+    /// https://262.ecma-international.org/16.0/#sec-host-promise-rejection-tracker
+    ///
+    /// Called when a promise is rejected while it has no handlers, and again if a handler is added to it later.
+    fn promise_rejection_tracker(&mut self, _promise: &JSValue, _operation: RejectionOperation) {}
+
+    /// Resolves the specifier of a module imported by the module with the referrer specifier to the specifier which the module is loaded and identified by, which is the specifier unchanged unless this is implemented.
+    ///
+    /// Imports which resolve to the same specifier share one module, and the module loader is called with the resolved specifier.
+    fn resolve_module_specifier(&mut self, specifier: &str, _referrer: &str) -> String {
+        specifier.to_string()
+    }
+
+    /// Called with the error thrown by a job which [`crate::JSAgent::run_jobs`] runs, before it is returned.
+    fn report_error(&mut self, _error: &EvalError) {}
+}
+
+/// The operation HostPromiseRejectionTracker is called with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RejectionOperation {
+    /// A promise was rejected while it had no handlers.
+    Reject,
+    /// A handler was added to a rejected promise which had none.
+    Handle,
+}
+
+/// A promise which was rejected while it had no handlers, returned by [`crate::JSAgent::take_unhandled_rejections`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnhandledRejection {
+    /// The promise which was rejected.
+    pub promise: JSValue,
+    /// The value the promise was rejected with.
+    pub reason: JSValue,
+}
+
+/// The hooks of an agent on which none have been installed.
+pub(crate) struct DefaultHostHooks;
+
+impl HostHooks for DefaultHostHooks {}
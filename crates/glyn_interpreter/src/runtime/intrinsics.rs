@@ -1,3 +1,4 @@
+use crate::gc::{Trace, Tracer};
 use crate::value::object::ObjectAddr;
 
 /// 6.1.7.4 Well-Known Intrinsic Objects
@@ -257,3 +258,136 @@ pub(crate) struct Intrinsics {
     // %WeakSet.prototype%
     pub(crate) weak_set_prototype: Option<ObjectAddr>,
 }
+
+impl Trace for Intrinsics {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.aggregate_error.trace(tracer);
+        self.array.trace(tracer);
+        self.array_buffer.trace(tracer);
+        self.array_iterator_prototype.trace(tracer);
+        self.async_from_sync_iterator_prototype.trace(tracer);
+        self.async_function.trace(tracer);
+        self.async_generator_function.trace(tracer);
+        self.async_generator_prototype.trace(tracer);
+        self.async_iterator_prototype.trace(tracer);
+        self.atomics.trace(tracer);
+        self.big_int.trace(tracer);
+        self.big_int64_array.trace(tracer);
+        self.big_uint64_array.trace(tracer);
+        self.boolean.trace(tracer);
+        self.data_view.trace(tracer);
+        self.date.trace(tracer);
+        self.decode_uri.trace(tracer);
+        self.decode_uri_component.trace(tracer);
+        self.encode_uri.trace(tracer);
+        self.encode_uri_component.trace(tracer);
+        self.error.trace(tracer);
+        self.eval.trace(tracer);
+        self.eval_error.trace(tracer);
+        self.finalization_registry.trace(tracer);
+        self.float16_array.trace(tracer);
+        self.float32_array.trace(tracer);
+        self.float64_array.trace(tracer);
+        self.for_in_iterator_prototype.trace(tracer);
+        self.function.trace(tracer);
+        self.generator_function.trace(tracer);
+        self.generator_prototype.trace(tracer);
+        self.int8_array.trace(tracer);
+        self.int16_array.trace(tracer);
+        self.int32_array.trace(tracer);
+        self.is_finite.trace(tracer);
+        self.is_nan.trace(tracer);
+        self.iterator.trace(tracer);
+        self.iterator_helper_prototype.trace(tracer);
+        self.json.trace(tracer);
+        self.map.trace(tracer);
+        self.map_iterator_prototype.trace(tracer);
+        self.math.trace(tracer);
+        self.number.trace(tracer);
+        self.object.trace(tracer);
+        self.parse_float.trace(tracer);
+        self.parse_int.trace(tracer);
+        self.promise.trace(tracer);
+        self.proxy.trace(tracer);
+        self.range_error.trace(tracer);
+        self.reference_error.trace(tracer);
+        self.reflect.trace(tracer);
+        self.reg_exp.trace(tracer);
+        self.reg_exp_string_iterator_prototype.trace(tracer);
+        self.set.trace(tracer);
+        self.set_iterator_prototype.trace(tracer);
+        self.shared_array_buffer.trace(tracer);
+        self.string.trace(tracer);
+        self.string_iterator_prototype.trace(tracer);
+        self.symbol.trace(tracer);
+        self.syntax_error.trace(tracer);
+        self.throw_type_error.trace(tracer);
+        self.typed_array.trace(tracer);
+        self.type_error.trace(tracer);
+        self.uint8_array.trace(tracer);
+        self.uint8_clamped_array.trace(tracer);
+        self.uint16_array.trace(tracer);
+        self.uint32_array.trace(tracer);
+        self.uri_error.trace(tracer);
+        self.weak_map.trace(tracer);
+        self.weak_ref.trace(tracer);
+        self.weak_set.trace(tracer);
+        self.wrap_for_valid_iterator_prototype.trace(tracer);
+        self.aggregate_error_prototype.trace(tracer);
+        self.array_prototype_values.trace(tracer);
+        self.array_prototype.trace(tracer);
+        self.array_buffer_prototype.trace(tracer);
+        self.async_function_prototype.trace(tracer);
+        self.async_generator_function_prototype_prototype
+            .trace(tracer);
+        self.async_generator_function_prototype.trace(tracer);
+        self.big_int_prototype.trace(tracer);
+        self.big_int64_array_prototype.trace(tracer);
+        self.big_uint64_array_prototype.trace(tracer);
+        self.boolean_prototype.trace(tracer);
+        self.data_view_prototype.trace(tracer);
+        self.date_prototype.trace(tracer);
+        self.error_prototype.trace(tracer);
+        self.error_prototype_to_string.trace(tracer);
+        self.eval_error_prototype.trace(tracer);
+        self.finalization_registry_prototype.trace(tracer);
+        self.float32_array_prototype.trace(tracer);
+        self.float64_array_prototype.trace(tracer);
+        self.function_prototype.trace(tracer);
+        self.generator_function_prototype_prototype_next
+            .trace(tracer);
+        self.generator_function_prototype_prototype.trace(tracer);
+        self.generator_function_prototype.trace(tracer);
+        self.int16_array_prototype.trace(tracer);
+        self.int32_array_prototype.trace(tracer);
+        self.int8_array_prototype.trace(tracer);
+        self.iterator_prototype.trace(tracer);
+        self.json_parse.trace(tracer);
+        self.json_stringify.trace(tracer);
+        self.map_prototype.trace(tracer);
+        self.number_prototype.trace(tracer);
+        self.object_prototype_to_string.trace(tracer);
+        self.object_prototype_value_of.trace(tracer);
+        self.object_prototype.trace(tracer);
+        self.promise_prototype_then.trace(tracer);
+        self.promise_prototype.trace(tracer);
+        self.promise_resolve.trace(tracer);
+        self.range_error_prototype.trace(tracer);
+        self.reference_error_prototype.trace(tracer);
+        self.reg_exp_prototype.trace(tracer);
+        self.set_prototype.trace(tracer);
+        self.string_prototype.trace(tracer);
+        self.symbol_prototype.trace(tracer);
+        self.syntax_error_prototype.trace(tracer);
+        self.typed_array_prototype.trace(tracer);
+        self.type_error_prototype.trace(tracer);
+        self.uint16_array_prototype.trace(tracer);
+        self.uint32_array_prototype.trace(tracer);
+        self.uint8_array_prototype.trace(tracer);
+        self.uint8_clamped_array_prototype.trace(tracer);
+        self.uri_error_prototype.trace(tracer);
+        self.weak_map_prototype.trace(tracer);
+        self.weak_ref_prototype.trace(tracer);
+        self.weak_set_prototype.trace(tracer);
+    }
+}
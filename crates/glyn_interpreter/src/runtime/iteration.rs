@@ -0,0 +1,629 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    mem::size_of,
+};
+
+use crate::{
+    abstract_ops::{
+        object_operations::{create_array_from_list, get, length_of_array_like},
+        ordinary::ordinary_object_create,
+    },
+    gc::{Trace, Tracer},
+    runtime::{agent::JSAgent, completion::CompletionRecord},
+    value::{
+        atom::Atom,
+        object::{
+            internal_slots::InternalSlotName, property::JSObjectPropKey, ObjectAddr,
+            ObjectEssentialInternalMethods, ObjectMeta,
+        },
+        string::JSString,
+        JSValue,
+    },
+};
+
+/// 7.4.1 Iterator Records
+/// https://262.ecma-international.org/16.0/#sec-iterator-records
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct IteratorRecord {
+    /// [[Iterator]]
+    pub(crate) iterator: ObjectAddr,
+
+    /// [[NextMethod]]
+    pub(crate) next_method: JSValue,
+
+    /// [[Done]]
+    pub(crate) done: bool,
+}
+
+impl Trace for IteratorRecord {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.iterator.trace(tracer);
+        self.next_method.trace(tracer);
+    }
+}
+
+/// 14.7.5.6 ForIn/OfHeadEvaluation ( uninitializedBoundNames, expr, iterationKind )
+/// https://262.ecma-international.org/16.0/#sec-runtime-semantics-forinofheadevaluation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum IterationKind {
+    /// The keys of an object are enumerated by a for-in statement.
+    Enumerate,
+    /// The values of an iterable are iterated by a for-of statement, closing the iterator if the loop is exited early.
+    Iterate,
+}
+
+/// An ordered list whose entries keep their indices when other entries are removed.
+///
+/// Removing an entry leaves an empty slot behind rather than shifting the entries after it, so the indices of the other entries stay valid until the empty slots are removed by [`LiveList::compact`].
+#[derive(Debug)]
+pub(crate) struct LiveList<T> {
+    entries: Vec<Option<T>>,
+    len: usize,
+}
+
+impl<T> Default for LiveList<T> {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            len: 0,
+        }
+    }
+}
+
+impl<T> LiveList<T> {
+    /// The number of entries in the list, excluding empty slots.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The number of slots left empty by entries which have been removed since the empty slots were last removed.
+    pub(crate) fn empty_slots(&self) -> usize {
+        self.entries.len() - self.len
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&T> {
+        self.entries.get(index).and_then(Option::as_ref)
+    }
+
+    pub(crate) fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.entries.get_mut(index).and_then(Option::as_mut)
+    }
+
+    /// Appends the entry after every existing entry, including those which have been removed.
+    pub(crate) fn push(&mut self, value: T) {
+        self.compact();
+
+        self.append(value);
+    }
+
+    /// Appends the entry without removing the empty slots, returning its index, which stays valid until they are removed.
+    pub(crate) fn append(&mut self, value: T) -> usize {
+        self.entries.push(Some(value));
+
+        self.len += 1;
+
+        self.entries.len() - 1
+    }
+
+    /// Replaces the entry at the given index with an empty slot, returning it.
+    pub(crate) fn remove(&mut self, index: usize) -> Option<T> {
+        let value = self.entries.get_mut(index).and_then(Option::take);
+
+        if value.is_some() {
+            self.len -= 1;
+        }
+
+        value
+    }
+
+    /// Removes every entry and empty slot while keeping the allocated capacity.
+    pub(crate) fn reset(&mut self) {
+        self.entries.clear();
+
+        self.len = 0;
+    }
+
+    /// The bytes taken up by the buffer of the entries, including the empty slots.
+    pub(crate) fn size(&self) -> usize {
+        self.entries.capacity() * size_of::<Option<T>>()
+    }
+
+    /// Iterates over the entries of the list, skipping the empty slots.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        self.entries.iter().flatten()
+    }
+
+    /// Removes the empty slots, returning whether the indices of any entries shifted.
+    pub(crate) fn compact(&mut self) -> bool {
+        if self.len == self.entries.len() {
+            return false;
+        }
+
+        self.entries.retain(Option::is_some);
+
+        true
+    }
+}
+
+/// 14.7.5.10 For-In Iterator Objects
+/// https://262.ecma-international.org/16.0/#sec-for-in-iterator-objects
+///
+/// Implements EnumerateObjectProperties. The keys of each object on the prototype chain are snapshotted when the iterator reaches it, so properties added to an object after that point are not visited, while properties deleted before they are visited are skipped. A key is only visited once, even when it is shadowed further up the prototype chain, including by a non-enumerable property.
+#[derive(Debug)]
+pub(crate) struct ForInIterator {
+    /// [[Object]]
+    object: Option<ObjectAddr>,
+
+    /// [[ObjectWasVisited]]
+    object_was_visited: bool,
+
+    /// [[VisitedKeys]]
+    /// NOTE: The keys are only ever looked up, so a set avoids comparing each key with every key visited before it.
+    visited_keys: HashSet<Atom>,
+
+    /// [[RemainingKeys]]
+    remaining_keys: VecDeque<Atom>,
+}
+
+impl Trace for ForInIterator {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.object.trace(tracer);
+    }
+}
+
+impl ForInIterator {
+    /// 14.7.5.10.1 CreateForInIterator ( object )
+    /// https://262.ecma-international.org/16.0/#sec-createforiniterator
+    ///
+    /// The internal slots of a for-in iterator, which are held by the iterator object created by [`create_for_in_iterator`].
+    pub(crate) fn new(object: ObjectAddr) -> Self {
+        Self {
+            // 2. Set iterator.[[Object]] to object.
+            object: Some(object),
+            // 3. Set iterator.[[ObjectWasVisited]] to false.
+            object_was_visited: false,
+            // 4. Set iterator.[[VisitedKeys]] to a new empty List.
+            visited_keys: HashSet::new(),
+            // 5. Set iterator.[[RemainingKeys]] to a new empty List.
+            remaining_keys: VecDeque::new(),
+        }
+    }
+
+    /// 14.7.5.10.2.1 %ForInIteratorPrototype%.next ( )
+    /// https://262.ecma-international.org/16.0/#sec-%foriniteratorprototype%.next
+    ///
+    /// Returns the next key to be visited, or `None` once the iterator is done.
+    pub(crate) fn next(&mut self) -> CompletionRecord<Option<JSString>> {
+        // 4. Let object be O.[[Object]].
+        // 5. Repeat,
+        while let Some(object) = self.object.clone() {
+            // a. If O.[[ObjectWasVisited]] is false, then
+            if !self.object_was_visited {
+                // i. Let keys be ? object.[[OwnPropertyKeys]]().
+                let keys = object.own_property_keys();
+
+                // ii. For each element key of keys, do
+                for key in keys {
+                    // 1. If key is a String, then
+                    if let JSObjectPropKey::String(key) = key {
+                        // a. Append key to O.[[RemainingKeys]].
+                        self.remaining_keys.push_back(key);
+                    }
+                }
+
+                // iii. Set O.[[ObjectWasVisited]] to true.
+                self.object_was_visited = true;
+            }
+
+            // b. Repeat, while O.[[RemainingKeys]] is not empty,
+            // i. Let r be the first element of O.[[RemainingKeys]].
+            // ii. Remove the first element from O.[[RemainingKeys]].
+            while let Some(key) = self.remaining_keys.pop_front() {
+                // iii. If there does not exist an element v of O.[[VisitedKeys]] such that SameValue(r, v) is true, then
+                if self.visited_keys.contains(&key) {
+                    continue;
+                }
+
+                // 1. Let desc be ? object.[[GetOwnProperty]](r).
+                let desc = object.get_own_property(&JSObjectPropKey::from(key.clone()))?;
+
+                // 2. If desc is not undefined, then
+                if let Some(desc) = desc {
+                    // a. Append r to O.[[VisitedKeys]].
+                    self.visited_keys.insert(key.clone());
+
+                    // b. If desc.[[Enumerable]] is true, return CreateIteratorResultObject(r, false).
+                    if desc.enumerable == Some(true) {
+                        return Ok(Some(key.to_js_string()));
+                    }
+                }
+            }
+
+            // c. Set object to ? object.[[GetPrototypeOf]]().
+            // d. Set O.[[Object]] to object.
+            self.object = object.get_prototype_of();
+
+            // e. Set O.[[ObjectWasVisited]] to false.
+            self.object_was_visited = false;
+        }
+
+        // f. If object is null, return CreateIteratorResultObject(undefined, true).
+        Ok(None)
+    }
+}
+
+/// 14.7.5.10.1 CreateForInIterator ( object )
+/// https://262.ecma-international.org/16.0/#sec-createforiniterator
+pub(crate) fn create_for_in_iterator(agent: &mut JSAgent, object: ObjectAddr) -> ObjectAddr {
+    let prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .for_in_iterator_prototype
+        .clone();
+
+    // 1. Let iterator be OrdinaryObjectCreate(%ForInIteratorPrototype%, « [[Object]], [[ObjectWasVisited]], [[VisitedKeys]], [[RemainingKeys]] »).
+    let iterator = ordinary_object_create(
+        agent,
+        prototype,
+        Some(vec![InternalSlotName::ForInIterator]),
+    );
+
+    // 2. Set iterator.[[Object]] to object.
+    // 3. Set iterator.[[ObjectWasVisited]] to false.
+    // 4. Set iterator.[[VisitedKeys]] to a new empty List.
+    // 5. Set iterator.[[RemainingKeys]] to a new empty List.
+    iterator
+        .data_mut()
+        .slots_mut()
+        .set_for_in_iterator(ForInIterator::new(object));
+
+    // 6. Return iterator.
+    iterator
+}
+
+/// 23.1.5.1 CreateArrayIterator ( array, kind )
+/// https://262.ecma-international.org/16.0/#sec-createarrayiterator
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ArrayIteratorKind {
+    Key,
+    Value,
+    KeyValue,
+}
+
+/// 23.1.5.1 CreateArrayIterator ( array, kind )
+/// https://262.ecma-international.org/16.0/#sec-createarrayiterator
+///
+/// The variables captured by the closure of an Array Iterator, which are held in an internal slot of the iterator rather than by a suspended generator.
+#[derive(Clone, Debug)]
+pub(crate) struct ArrayIterator {
+    /// The array being iterated, which is cleared once the iterator is done.
+    array: Option<ObjectAddr>,
+    index: u64,
+    kind: ArrayIteratorKind,
+}
+
+impl Trace for ArrayIterator {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.array.trace(tracer);
+    }
+}
+
+impl ArrayIterator {
+    /// Returns the next result to be yielded, or `None` once the iterator is done.
+    pub(crate) fn next(&mut self, agent: &mut JSAgent) -> CompletionRecord<Option<JSValue>> {
+        let Some(array) = self.array.clone() else {
+            return Ok(None);
+        };
+
+        // b. Repeat,
+        // i. If array has a [[TypedArrayName]] internal slot, then
+        // ii. Else,
+        // 1. Let len be ? LengthOfArrayLike(array).
+        // NOTE: An abrupt completion completes the generator, so the iterator is done afterwards.
+        let len = length_of_array_like(agent, &array).inspect_err(|_| self.array = None)?;
+
+        // iii. If index ≥ len, return NormalCompletion(undefined).
+        if self.index >= len {
+            self.array = None;
+
+            return Ok(None);
+        }
+
+        // iv. Let indexNumber be 𝔽(index).
+        let index_number = JSValue::from(self.index as f64);
+
+        // viii. Set index to index + 1.
+        self.index += 1;
+
+        // v. If kind is key, then
+        // 1. Let result be indexNumber.
+        if self.kind == ArrayIteratorKind::Key {
+            return Ok(Some(index_number));
+        }
+
+        // vi. Else,
+        // 1. Let elementKey be ! ToString(indexNumber).
+        let element_key = JSObjectPropKey::String((self.index - 1).to_string().into());
+
+        // 2. Let elementValue be ? Get(array, elementKey).
+        let element_value = get(agent, &array, &element_key).inspect_err(|_| self.array = None)?;
+
+        // 3. If kind is value, then
+        // a. Let result be elementValue.
+        if self.kind == ArrayIteratorKind::Value {
+            return Ok(Some(element_value));
+        }
+
+        // 4. Else,
+        // a. Assert: kind is key+value.
+        // b. Let result be CreateArrayFromList(« indexNumber, elementValue »).
+        let result = create_array_from_list(agent, vec![index_number, element_value]);
+
+        // vii. Perform ? GeneratorYield(CreateIteratorResultObject(result, false)).
+        Ok(Some(JSValue::from(result)))
+    }
+}
+
+/// 23.1.5.1 CreateArrayIterator ( array, kind )
+/// https://262.ecma-international.org/16.0/#sec-createarrayiterator
+pub(crate) fn create_array_iterator(
+    agent: &mut JSAgent,
+    array: ObjectAddr,
+    kind: ArrayIteratorKind,
+) -> ObjectAddr {
+    let prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .array_iterator_prototype
+        .clone();
+
+    // 1. Let iterator be OrdinaryObjectCreate(%ArrayIteratorPrototype%, « [[GeneratorState]], [[GeneratorContext]], [[GeneratorBrand]] »).
+    let iterator = ordinary_object_create(
+        agent,
+        prototype,
+        Some(vec![InternalSlotName::ArrayIterator]),
+    );
+
+    // 2. Let closure be a new Abstract Closure with no parameters that captures kind and array and performs the following steps when called:
+    // a. Let index be 0.
+    // 3. Return CreateIteratorFromClosure(closure, "%ArrayIteratorPrototype%", %ArrayIteratorPrototype%).
+    iterator
+        .data_mut()
+        .slots_mut()
+        .set_array_iterator(ArrayIterator {
+            array: Some(array),
+            index: 0,
+            kind,
+        });
+
+    iterator
+}
+
+/// 22.1.3.36 String.prototype [ %Symbol.iterator% ] ( )
+/// https://262.ecma-international.org/16.0/#sec-string.prototype-%symbol.iterator%
+///
+/// The variables captured by the closure of a String Iterator, which are held in an internal slot of the iterator rather than by a suspended generator.
+#[derive(Clone, Debug)]
+pub(crate) struct StringIterator {
+    string: JSString,
+    /// The index of the code unit at which the next code point starts.
+    position: usize,
+}
+
+impl StringIterator {
+    /// Returns the next code point to be yielded, or `None` once the iterator is done.
+    pub(crate) fn next(&mut self) -> Option<JSString> {
+        // b. Let len be the length of s.
+        // c. Repeat, while position < len,
+        if self.position >= self.string.len() {
+            return None;
+        }
+
+        // i. Let cp be CodePointAt(s, position).
+        let (_, code_unit_count, _) = self.string.code_point_at(self.position);
+
+        // ii. Let nextIndex be position + cp.[[CodeUnitCount]].
+        let next_index = self.position + code_unit_count;
+
+        // iii. Let resultString be the substring of s from position to nextIndex.
+        let result_string = self.string.substring(self.position, next_index);
+
+        // iv. Set position to nextIndex.
+        self.position = next_index;
+
+        // v. Perform ? GeneratorYield(CreateIteratorResultObject(resultString, false)).
+        Some(result_string)
+    }
+}
+
+/// 22.1.3.36 String.prototype [ %Symbol.iterator% ] ( )
+/// https://262.ecma-international.org/16.0/#sec-string.prototype-%symbol.iterator%
+pub(crate) fn create_string_iterator(agent: &mut JSAgent, string: JSString) -> ObjectAddr {
+    let prototype = agent
+        .current_realm()
+        .borrow()
+        .intrinsics
+        .string_iterator_prototype
+        .clone();
+
+    let iterator = ordinary_object_create(
+        agent,
+        prototype,
+        Some(vec![InternalSlotName::StringIterator]),
+    );
+
+    // 3. Let closure be a new Abstract Closure with no parameters that captures s and performs the following steps when called:
+    // a. Let position be 0.
+    // 4. Return CreateIteratorFromClosure(closure, "%StringIteratorPrototype%", %StringIteratorPrototype%).
+    iterator
+        .data_mut()
+        .slots_mut()
+        .set_string_iterator(StringIterator {
+            string,
+            position: 0,
+        });
+
+    iterator
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        abstract_ops::{
+            object_operations::create_data_property_or_throw, ordinary::ordinary_object_create,
+        },
+        value::{object::property::JSObjectPropDescriptor, JSValue},
+    };
+
+    fn key(name: &str) -> JSObjectPropKey {
+        JSObjectPropKey::from(JSString::from(name))
+    }
+
+    fn create_object(
+        agent: &mut JSAgent,
+        prototype: Option<ObjectAddr>,
+        names: &[&str],
+    ) -> ObjectAddr {
+        let object = ordinary_object_create(agent, prototype, None);
+
+        for name in names {
+            create_data_property_or_throw(&object, &key(name), JSValue::Undefined).unwrap();
+        }
+
+        object
+    }
+
+    fn next(iterator: &mut ForInIterator) -> Option<String> {
+        iterator.next().unwrap().map(|key| key.to_string())
+    }
+
+    #[test]
+    fn live_list_keeps_indices_when_removing_entries() {
+        let mut list = LiveList::default();
+        let first = list.append(1);
+        let second = list.append(2);
+        let third = list.append(3);
+
+        assert_eq!(list.remove(second), Some(2));
+        assert_eq!(list.remove(second), None);
+        assert_eq!(list.get(first), Some(&1));
+        assert_eq!(list.get(third), Some(&3));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.empty_slots(), 1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3]);
+    }
+
+    #[test]
+    fn live_list_compacts_empty_slots() {
+        let mut list = LiveList::default();
+        list.push(1);
+        list.push(2);
+        list.remove(0);
+
+        assert!(list.compact());
+        assert!(!list.compact());
+        assert_eq!(list.get(0), Some(&2));
+
+        list.remove(0);
+        list.push(3);
+        assert_eq!(list.empty_slots(), 0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn for_in_order() {
+        let mut agent = JSAgent::default();
+
+        let object = create_object(&mut agent, None, &["b", "1", "a", "0"]);
+        let mut iterator = ForInIterator::new(object);
+
+        assert_eq!(next(&mut iterator).as_deref(), Some("0"));
+        assert_eq!(next(&mut iterator).as_deref(), Some("1"));
+        assert_eq!(next(&mut iterator).as_deref(), Some("b"));
+        assert_eq!(next(&mut iterator).as_deref(), Some("a"));
+        assert_eq!(next(&mut iterator), None);
+        assert_eq!(next(&mut iterator), None);
+    }
+
+    #[test]
+    fn for_in_skips_deleted_keys() {
+        let mut agent = JSAgent::default();
+
+        let object = create_object(&mut agent, None, &["a", "b", "c"]);
+        let mut iterator = ForInIterator::new(object.clone());
+
+        assert_eq!(next(&mut iterator).as_deref(), Some("a"));
+
+        object.delete(&key("b")).unwrap();
+        assert_eq!(next(&mut iterator).as_deref(), Some("c"));
+        assert_eq!(next(&mut iterator), None);
+    }
+
+    #[test]
+    fn for_in_does_not_visit_added_keys() {
+        let mut agent = JSAgent::default();
+
+        let prototype = create_object(&mut agent, None, &["p"]);
+        let object = create_object(&mut agent, Some(prototype.clone()), &["a"]);
+        let mut iterator = ForInIterator::new(object.clone());
+
+        assert_eq!(next(&mut iterator).as_deref(), Some("a"));
+
+        // The keys of the object have already been snapshotted, but those of its prototype have not.
+        create_data_property_or_throw(&object, &key("b"), JSValue::Undefined).unwrap();
+        create_data_property_or_throw(&prototype, &key("q"), JSValue::Undefined).unwrap();
+        assert_eq!(next(&mut iterator).as_deref(), Some("p"));
+        assert_eq!(next(&mut iterator).as_deref(), Some("q"));
+        assert_eq!(next(&mut iterator), None);
+    }
+
+    #[test]
+    fn for_in_shadowed_keys() {
+        let mut agent = JSAgent::default();
+
+        let prototype = create_object(&mut agent, None, &["a", "b", "c"]);
+        let object = create_object(&mut agent, Some(prototype), &["a"]);
+
+        // A non-enumerable property is not visited, but still shadows the enumerable property on the prototype.
+        object
+            .define_own_property(
+                &key("b"),
+                JSObjectPropDescriptor {
+                    value: Some(JSValue::Undefined),
+                    enumerable: Some(false),
+                    ..JSObjectPropDescriptor::default()
+                },
+            )
+            .unwrap();
+
+        let mut iterator = ForInIterator::new(object);
+
+        assert_eq!(next(&mut iterator).as_deref(), Some("a"));
+        assert_eq!(next(&mut iterator).as_deref(), Some("c"));
+        assert_eq!(next(&mut iterator), None);
+    }
+
+    #[test]
+    fn string_iterator_yields_code_points() {
+        let mut iterator = StringIterator {
+            string: JSString::from(vec![0x61, 0xD83D, 0xDE00, 0xDE00, 0xD83D]),
+            position: 0,
+        };
+
+        let values = std::iter::from_fn(|| iterator.next()).collect::<Vec<_>>();
+
+        assert_eq!(
+            values,
+            [
+                JSString::from("a"),
+                JSString::from("\u{1F600}"),
+                JSString::from(vec![0xDE00]),
+                JSString::from(vec![0xD83D]),
+            ]
+        );
+        assert_eq!(iterator.next(), None);
+    }
+}
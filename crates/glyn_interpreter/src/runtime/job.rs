@@ -0,0 +1,25 @@
+use crate::runtime::{agent::JSAgent, completion::CompletionRecord, realm::RealmAddr};
+
+/// 9.5 Jobs and Host Operations to Enqueue Jobs
+/// https://262.ecma-international.org/16.0/#sec-jobs
+///
+/// A Job is an Abstract Closure with no parameters that initiates an ECMAScript computation when no other ECMAScript computation is currently in progress, along with the realm it is evaluated in.
+pub(crate) struct Job {
+    /// [[Job]]
+    pub(crate) callback: Box<dyn FnOnce(&mut JSAgent) -> CompletionRecord>,
+
+    /// [[Realm]]
+    pub(crate) realm: Option<RealmAddr>,
+}
+
+impl Job {
+    pub(crate) fn new(
+        callback: impl FnOnce(&mut JSAgent) -> CompletionRecord + 'static,
+        realm: Option<RealmAddr>,
+    ) -> Self {
+        Self {
+            callback: Box::new(callback),
+            realm,
+        }
+    }
+}
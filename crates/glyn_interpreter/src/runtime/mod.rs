@@ -1,8 +1,26 @@
 pub(crate) mod agent;
+pub(crate) mod agent_builder;
+pub(crate) mod class;
+pub(crate) mod clock;
 pub(crate) mod completion;
+pub(crate) mod console;
+pub(crate) mod debugger;
 pub(crate) mod environment;
 pub(crate) mod execution_context;
+pub(crate) mod generator;
+pub(crate) mod host_hooks;
 pub(crate) mod intrinsics;
+pub(crate) mod iteration;
+pub(crate) mod job;
+pub(crate) mod module;
+pub(crate) mod native_function;
+pub(crate) mod profile;
+pub(crate) mod promise;
 pub(crate) mod realm;
 pub(crate) mod reference;
+pub(crate) mod root;
 pub(crate) mod script;
+pub(crate) mod stack_trace;
+pub(crate) mod structured_clone;
+pub(crate) mod timers;
+pub(crate) mod watchpoint;
@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use crate::{
+    codegen::{
+        bytecode::generator::ExecutableProgram,
+        parser::{ExportEntry, ImportEntry},
+    },
+    gc::{Collect, Gc, Summarize, Trace, Tracer},
+    runtime::{environment::EnvironmentAddr, realm::RealmAddr},
+    value::{atom::Atom, object::ObjectAddr, string::JSString, JSValue},
+};
+
+pub(crate) type ModuleAddr = Gc<ModuleRecord>;
+
+/// Returns the source text of the module with the specifier, which is requested by the module with the referrer specifier.
+pub(crate) type ModuleLoader = Box<dyn FnMut(&str, &str) -> Result<String, String>>;
+
+/// [[Status]]
+/// https://262.ecma-international.org/16.0/#table-cyclic-module-fields
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ModuleStatus {
+    New,
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+/// 16.2.1.6 Source Text Module Records
+/// https://262.ecma-international.org/16.0/#sec-source-text-module-records
+///
+/// The fields of Module Records and Cyclic Module Records are held together with those of Source Text Module Records, as source text modules are the only modules there are. Modules are evaluated synchronously, so the fields for top-level await are not held.
+#[derive(Debug)]
+pub(crate) struct ModuleRecord {
+    /// [[Realm]]
+    pub(crate) realm: RealmAddr,
+
+    /// [[Environment]]
+    pub(crate) environment: Option<EnvironmentAddr>,
+
+    /// [[Namespace]]
+    pub(crate) namespace: Option<ObjectAddr>,
+
+    /// The specifier the module was loaded with, which is passed to the module loader as the referrer of the modules it requests.
+    pub(crate) specifier: JSString,
+
+    /// [[Status]]
+    pub(crate) status: ModuleStatus,
+
+    /// [[EvaluationError]]
+    pub(crate) evaluation_error: Option<JSValue>,
+
+    /// [[DFSIndex]]
+    pub(crate) dfs_index: usize,
+
+    /// [[DFSAncestorIndex]]
+    pub(crate) dfs_ancestor_index: usize,
+
+    /// [[RequestedModules]]
+    pub(crate) requested_modules: Vec<JSString>,
+
+    /// [[LoadedModules]]
+    pub(crate) loaded_modules: Vec<(JSString, ModuleAddr)>,
+
+    /// [[ECMAScriptCode]]
+    pub(crate) ecmascript_code: Rc<ExecutableProgram>,
+
+    /// [[ImportEntries]]
+    pub(crate) import_entries: Vec<ImportEntry>,
+
+    /// [[LocalExportEntries]]
+    pub(crate) local_export_entries: Vec<ExportEntry>,
+
+    /// [[IndirectExportEntries]]
+    pub(crate) indirect_export_entries: Vec<ExportEntry>,
+
+    /// [[StarExportEntries]]
+    pub(crate) star_export_entries: Vec<ExportEntry>,
+
+    /// The bound names of the let and const declarations of the module along with whether each is a constant declaration, which are the LexicallyScopedDeclarations of the module other than its function declarations.
+    pub(crate) lexical_declarations: Vec<(Atom, bool)>,
+}
+
+/// NOTE: Modules are held by the module map of the agent, so they are roots, and the values they hold are not reported.
+impl Trace for ModuleRecord {
+    fn trace(&self, _tracer: &mut Tracer) {}
+}
+
+impl Collect for ModuleRecord {
+    fn clear(&mut self) {}
+}
+
+impl Summarize for ModuleRecord {
+    fn summarize(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Module")
+    }
+}
+
+/// ResolvedBinding Record
+/// https://262.ecma-international.org/16.0/#resolvedbinding-record
+#[derive(Clone, Debug)]
+pub(crate) struct ResolvedBinding {
+    /// [[Module]]
+    pub(crate) module: ModuleAddr,
+
+    /// [[BindingName]]
+    pub(crate) binding_name: BindingName,
+}
+
+/// [[BindingName]] of a ResolvedBinding Record.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum BindingName {
+    Name(Atom),
+    /// The binding is the module namespace object of the module.
+    Namespace,
+}
+
+/// The result of ResolveExport, which is a ResolvedBinding Record, null or ambiguous.
+#[derive(Clone, Debug)]
+pub(crate) enum ExportResolution {
+    Resolved(ResolvedBinding),
+    /// The export could not be found, or was found by a circular import request.
+    NotFound,
+    /// The export is provided by more than one `export *` declaration.
+    Ambiguous,
+}
@@ -0,0 +1,147 @@
+use crate::{
+    abstract_ops::testing_comparison::is_callable,
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::ThrowCompletion,
+    },
+    value::{object::ObjectAddr, string::JSString, JSValue},
+};
+
+/// A function implemented in Rust which can be called from scripts, see [`JSAgent::register_function`].
+///
+/// This is implemented for closures, so the function can capture state from the embedder, such as a counter or a handle to an application object. The captured state lives as long as the function object does.
+///
+/// A thrown error is returned as a [`ThrowCompletion`], which the getters of [`Args`] produce for missing or mistyped arguments, so they can be propagated with `?`. Any other value can be thrown by converting it into a [`ThrowCompletion`], which `?` does for a `Result` whose error is a [`JSValue`].
+pub trait NativeFunction:
+    Fn(&mut JSAgent, Args) -> Result<JSValue, ThrowCompletion> + 'static
+{
+}
+
+impl<F> NativeFunction for F where
+    F: Fn(&mut JSAgent, Args) -> Result<JSValue, ThrowCompletion> + 'static
+{
+}
+
+/// The this value and arguments list a native function was called with.
+///
+/// Missing arguments read as undefined, as they would for a function defined in a script. The typed getters instead throw a TypeError naming the function when an argument is missing or has the wrong type.
+#[derive(Clone, Copy, Debug)]
+pub struct Args<'a> {
+    function_name: &'a JSString,
+    this_value: &'a JSValue,
+    values: &'a [JSValue],
+    new_target: Option<&'a ObjectAddr>,
+}
+
+impl<'a> Args<'a> {
+    pub(crate) fn new(
+        function_name: &'a JSString,
+        this_value: &'a JSValue,
+        values: &'a [JSValue],
+        new_target: Option<&'a ObjectAddr>,
+    ) -> Self {
+        Self {
+            function_name,
+            this_value,
+            values,
+            new_target,
+        }
+    }
+
+    /// The this value of the call.
+    pub fn this(&self) -> &'a JSValue {
+        self.this_value
+    }
+
+    /// The name of the function, which errors about the call are reported with.
+    pub(crate) fn function_name(&self) -> &'a JSString {
+        self.function_name
+    }
+
+    /// The NewTarget value, which is only present when the function was called as a constructor.
+    pub(crate) fn new_target(&self) -> Option<&'a ObjectAddr> {
+        self.new_target
+    }
+
+    /// The number of arguments which were passed.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The argument at the given index, or undefined if it was not passed.
+    pub fn get(&self, index: usize) -> &'a JSValue {
+        self.values.get(index).unwrap_or(&JSValue::Undefined)
+    }
+
+    /// The arguments from the given index onwards, for functions which accept any number of arguments.
+    pub fn rest(&self, from: usize) -> &'a [JSValue] {
+        self.values.get(from..).unwrap_or_default()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a JSValue> {
+        self.values.iter()
+    }
+
+    /// The argument at the given index, which must be a Number.
+    pub fn get_f64(&self, index: usize) -> Result<f64, ThrowCompletion> {
+        match self.require(index)? {
+            JSValue::Number(number) => Ok(number.0),
+            value => self.invalid_type(index, "a number", value),
+        }
+    }
+
+    /// The argument at the given index, which must be a String, converted to UTF-8 with each lone surrogate replaced by U+FFFD (REPLACEMENT CHARACTER).
+    pub fn get_string(&self, index: usize) -> Result<String, ThrowCompletion> {
+        match self.require(index)? {
+            JSValue::String(string) => Ok(string.to_string()),
+            value => self.invalid_type(index, "a string", value),
+        }
+    }
+
+    /// The argument at the given index, which must be a Boolean.
+    pub fn get_bool(&self, index: usize) -> Result<bool, ThrowCompletion> {
+        match self.require(index)? {
+            JSValue::Bool(value) => Ok(*value),
+            value => self.invalid_type(index, "a boolean", value),
+        }
+    }
+
+    /// The argument at the given index, which must be callable.
+    pub fn get_function(&self, index: usize) -> Result<&'a JSValue, ThrowCompletion> {
+        match self.require(index)? {
+            value if is_callable(value) => Ok(value),
+            value => self.invalid_type(index, "a function", value),
+        }
+    }
+
+    fn require(&self, index: usize) -> Result<&'a JSValue, ThrowCompletion> {
+        match self.values.get(index) {
+            Some(value) => Ok(value),
+            None => type_error(&format!(
+                "{}: expected at least {} argument{}, but got {}",
+                self.function_name,
+                index + 1,
+                if index == 0 { "" } else { "s" },
+                self.values.len()
+            )),
+        }
+    }
+
+    fn invalid_type<T>(
+        &self,
+        index: usize,
+        expected: &str,
+        value: &JSValue,
+    ) -> Result<T, ThrowCompletion> {
+        type_error(&format!(
+            "{}: argument {} must be {expected}, but got {}",
+            self.function_name,
+            index + 1,
+            value.preview()
+        ))
+    }
+}
@@ -0,0 +1,83 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::{
+    codegen::bytecode::instruction::Instruction,
+    value::{
+        object::{ObjectAddr, ObjectMeta},
+        preview::function_name,
+    },
+};
+
+/// The statistics recorded while the agent was profiling, taken with [`JSAgent::take_profile`](crate::JSAgent::take_profile).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Profile {
+    /// The statistics of each opcode which was executed, keyed by its name, such as `BinAdd`.
+    pub opcodes: BTreeMap<String, OpcodeStats>,
+    /// The number of times each function was called or constructed, keyed by its name, which is `anonymous` for a function without one. Functions with the same name are counted together.
+    pub calls: BTreeMap<String, u64>,
+}
+
+impl Profile {
+    /// The total number of instructions which were executed.
+    pub fn instructions(&self) -> u64 {
+        self.opcodes.values().map(|stats| stats.count).sum()
+    }
+}
+
+/// The statistics of an opcode recorded while profiling.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OpcodeStats {
+    /// The number of times an instruction with the opcode was executed. An instruction following a Wide prefix is counted as its own opcode, rather than as Wide.
+    pub count: u64,
+    /// The time spent executing the instructions with the opcode, which includes the time spent in any functions they called.
+    pub time: Duration,
+}
+
+/// The statistics being recorded by an agent which is profiling, indexed by opcode so that recording an instruction does not hash its name.
+pub(crate) struct Profiler {
+    opcodes: Vec<OpcodeStats>,
+    calls: BTreeMap<String, u64>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self {
+            opcodes: vec![OpcodeStats::default(); u8::MAX as usize + 1],
+            calls: BTreeMap::new(),
+        }
+    }
+}
+
+impl Profiler {
+    pub(crate) fn record_instruction(&mut self, instruction: Instruction, time: Duration) {
+        let stats = &mut self.opcodes[instruction as usize];
+
+        stats.count += 1;
+        stats.time += time;
+    }
+
+    pub(crate) fn record_call(&mut self, function: &ObjectAddr) {
+        *self
+            .calls
+            .entry(function_name(&function.data()))
+            .or_default() += 1;
+    }
+
+    /// The statistics recorded so far, leaving the profiler empty.
+    pub(crate) fn take(&mut self) -> Profile {
+        let profiler = std::mem::take(self);
+
+        let opcodes = profiler
+            .opcodes
+            .into_iter()
+            .enumerate()
+            .filter(|(_, stats)| stats.count > 0)
+            .map(|(opcode, stats)| (Instruction::from(opcode as u8).to_string(), stats))
+            .collect();
+
+        Profile {
+            opcodes,
+            calls: profiler.calls,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    gc::{Trace, Tracer},
+    value::{object::ObjectAddr, JSValue},
+};
+
+/// [[PromiseState]]
+/// https://262.ecma-international.org/16.0/#table-internal-slots-of-promise-instances
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum PromiseState {
+    Pending,
+    Fulfilled,
+    Rejected,
+}
+
+/// 27.2.1.1 PromiseCapability Records
+/// https://262.ecma-international.org/16.0/#sec-promisecapability-records
+#[derive(Clone, Debug)]
+pub(crate) struct PromiseCapability {
+    /// [[Promise]]
+    pub(crate) promise: ObjectAddr,
+
+    /// [[Resolve]]
+    pub(crate) resolve: JSValue,
+
+    /// [[Reject]]
+    pub(crate) reject: JSValue,
+}
+
+/// [[Type]]
+/// https://262.ecma-international.org/16.0/#table-promisereaction-record-fields
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum PromiseReactionType {
+    Fulfill,
+    Reject,
+}
+
+/// 27.2.1.2 PromiseReaction Records
+/// https://262.ecma-international.org/16.0/#sec-promisereaction-records
+#[derive(Clone, Debug)]
+pub(crate) struct PromiseReaction {
+    /// [[Capability]]
+    pub(crate) capability: Option<PromiseCapability>,
+
+    /// [[Type]]
+    pub(crate) reaction_type: PromiseReactionType,
+
+    /// [[Handler]]
+    ///
+    /// NOTE: HostMakeJobCallback adds no host defined data, so the JobCallback Record is represented by its [[Callback]].
+    pub(crate) handler: Option<JSValue>,
+}
+
+impl Trace for PromiseCapability {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.promise.trace(tracer);
+        self.resolve.trace(tracer);
+        self.reject.trace(tracer);
+    }
+}
+
+impl Trace for PromiseReaction {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.capability.trace(tracer);
+        self.handler.trace(tracer);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::gc::Gc;
+use std::rc::Rc;
+
+use crate::codegen::bytecode::generator::TemplateSite;
+use crate::gc::{Collect, Gc, Summarize, Trace, Tracer};
 use crate::runtime::environment::EnvironmentAddr;
 use crate::runtime::intrinsics::Intrinsics;
 use crate::value::object::ObjectAddr;
@@ -17,4 +20,50 @@ pub(crate) struct Realm {
 
     /// [[GlobalEnv]]
     pub(crate) global_env: Option<EnvironmentAddr>,
+
+    /// [[TemplateMap]]
+    pub(crate) template_map: Vec<TemplateRecord>,
 }
+
+/// Table 26: Template Record Fields
+/// https://262.ecma-international.org/16.0/#table-templatemap-record-fields
+#[derive(Debug)]
+pub(crate) struct TemplateRecord {
+    /// [[Site]]
+    pub(crate) site: Rc<TemplateSite>,
+
+    /// [[Array]]
+    pub(crate) array: ObjectAddr,
+}
+
+impl Trace for Realm {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.intrinsics.trace(tracer);
+        self.global_object.trace(tracer);
+        self.global_env.trace(tracer);
+
+        self.template_map
+            .iter()
+            .for_each(|record| record.array.trace(tracer));
+    }
+}
+
+impl Collect for Realm {
+    fn clear(&mut self) {
+        *self = Realm::default();
+    }
+}
+
+impl Summarize for Realm {
+    fn summarize(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Realm")
+    }
+}
+
+/// A realm created by the embedder with [`JSAgent::create_realm`](crate::JSAgent::create_realm), which has its own global object and intrinsics.
+///
+/// Objects can be passed between realms, but each keeps the prototypes of the realm it was created in, so an object from another realm is not an `instanceof` the constructors of this one.
+///
+/// The realm is freed once the handle is dropped and none of its objects can be reached, which is the next time garbage is collected.
+#[derive(Clone, Debug)]
+pub struct JSRealm(pub(crate) RealmAddr);
@@ -1,6 +1,7 @@
 use crate::{
+    gc::{Trace, Tracer},
     runtime::environment::EnvironmentAddr,
-    value::{string::JSString, JSValue},
+    value::{atom::Atom, object::property::JSObjectPropKey, string::JSString, JSValue},
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -13,18 +14,26 @@ pub(crate) enum ReferenceBase {
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum ReferenceName {
     Value(JSValue),
+    /// The name of a binding, which is the referenced name of a reference whose base is an Environment Record or unresolvable.
+    Binding(Atom),
     PrivateName(String),
 }
 
-impl From<JSString> for ReferenceName {
-    fn from(value: JSString) -> Self {
-        ReferenceName::Value(JSValue::String(value))
+impl From<Atom> for ReferenceName {
+    fn from(value: Atom) -> Self {
+        ReferenceName::Binding(value)
     }
 }
 
-impl From<&JSString> for ReferenceName {
-    fn from(value: &JSString) -> Self {
-        ReferenceName::Value(JSValue::String(value.clone()))
+impl From<JSObjectPropKey> for ReferenceName {
+    fn from(value: JSObjectPropKey) -> Self {
+        match value {
+            JSObjectPropKey::String(name) => {
+                ReferenceName::Value(JSValue::from(JSString::from(name)))
+            }
+            JSObjectPropKey::Symbol(symbol) => ReferenceName::Value(JSValue::Symbol(symbol)),
+            JSObjectPropKey::PrivateName(name) => ReferenceName::PrivateName(name),
+        }
     }
 }
 
@@ -44,3 +53,19 @@ pub(crate) struct Reference {
     /// [[ThisValue]]
     pub(crate) this_value: Option<JSValue>,
 }
+
+impl Trace for Reference {
+    fn trace(&self, tracer: &mut Tracer) {
+        match &self.base {
+            ReferenceBase::Value(value) => value.trace(tracer),
+            ReferenceBase::Environment(env_addr) => env_addr.trace(tracer),
+            ReferenceBase::Unresolvable => {}
+        }
+
+        if let ReferenceName::Value(value) = &self.referenced_name {
+            value.trace(tracer);
+        }
+
+        self.this_value.trace(tracer);
+    }
+}
@@ -0,0 +1,100 @@
+use std::{any::Any, collections::BTreeMap, fmt, hash, marker::PhantomData};
+
+use crate::{runtime::realm::JSRealm, value::JSValue};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A value the embedder can root with [`JSAgent::root`](crate::JSAgent::root).
+pub trait Rootable: sealed::Sealed + Clone + 'static {}
+
+impl sealed::Sealed for JSValue {}
+impl Rootable for JSValue {}
+
+impl sealed::Sealed for JSRealm {}
+impl Rootable for JSRealm {}
+
+/// Identifies a value rooted by the embedder, which the agent keeps alive until it is unrooted or the scope it was rooted in ends.
+///
+/// A root is a plain id, so it can be copied into host data structures and native function closures without holding a reference to the value, and it is never reused for another value once released.
+pub struct Root<T> {
+    id: usize,
+    value_type: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Root<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Root<T> {}
+
+impl<T> PartialEq for Root<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T> Eq for Root<T> {}
+
+impl<T> hash::Hash for Root<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Root<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Root").field(&self.id).finish()
+    }
+}
+
+/// The values rooted by the embedder, which are referenced from outside the heap and so are roots of every collection.
+#[derive(Default)]
+pub(crate) struct Roots {
+    next_id: usize,
+    /// Keyed by id, which increases with each root, so the roots added within a scope are those with an id at least the next id when it began.
+    entries: BTreeMap<usize, Box<dyn Any>>,
+}
+
+impl Roots {
+    pub(crate) fn add<T: Rootable>(&mut self, value: T) -> Root<T> {
+        let id = self.next_id;
+
+        self.next_id += 1;
+
+        self.entries.insert(id, Box::new(value));
+
+        Root {
+            id,
+            value_type: PhantomData,
+        }
+    }
+
+    pub(crate) fn get<T: Rootable>(&self, root: Root<T>) -> Option<T> {
+        self.entries.get(&root.id)?.downcast_ref::<T>().cloned()
+    }
+
+    pub(crate) fn remove<T: Rootable>(&mut self, root: Root<T>) -> Option<T> {
+        self.entries
+            .remove(&root.id)
+            .and_then(|value| value.downcast::<T>().ok())
+            .map(|value| *value)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Begins a scope, returning the id it is ended with.
+    pub(crate) fn begin_scope(&self) -> usize {
+        self.next_id
+    }
+
+    /// Releases the roots added since the scope began, including those added by the scopes nested within it.
+    pub(crate) fn end_scope(&mut self, start: usize) {
+        self.entries.split_off(&start);
+    }
+}
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use crate::{codegen::bytecode::generator::ExecutableProgram, runtime::realm::RealmAddr};
 
 /// 16.1.4 Script Records
@@ -8,7 +10,7 @@ pub(crate) struct ScriptRecord {
     pub(crate) realm: RealmAddr,
 
     /// [[ECMAScriptCode]]
-    pub(crate) ecmascript_code: ExecutableProgram,
+    pub(crate) ecmascript_code: Rc<ExecutableProgram>,
 
     /// [[HostDefined]]
     pub(crate) host_defined: Option<()>,
@@ -0,0 +1,66 @@
+use std::{fmt::Write, rc::Rc};
+
+use crate::{
+    codegen::bytecode::generator::{find_span_entry, SpanEntry},
+    runtime::agent::JSAgent,
+    value::{
+        object::{ObjectAddr, ObjectMeta},
+        preview::function_name,
+    },
+};
+
+/// The default number of call frames a stack trace records, beyond which the frames of the outermost calls are left out.
+pub(crate) const DEFAULT_STACK_TRACE_LIMIT: usize = 10;
+
+/// The bytecode of a script, module or function body which the VM is evaluating, which a stack trace records the position of.
+#[derive(Debug)]
+pub(crate) struct CallFrame {
+    /// The function whose body is being evaluated, or None for a script or module.
+    pub(crate) function: Option<ObjectAddr>,
+    /// The span entries of the program being evaluated.
+    pub(crate) spans: Rc<[SpanEntry]>,
+    /// The offset of the instruction being executed.
+    pub(crate) offset: usize,
+}
+
+impl CallFrame {
+    /// The line of a stack trace for the frame, which is like `at f (2:3)` for a function and `at 2:3` for a script or module.
+    fn describe(&self) -> String {
+        let mut line = String::from("at ");
+
+        let position = find_span_entry(&self.spans, self.offset).map(|entry| entry.position);
+
+        match (&self.function, position) {
+            (Some(function), Some(position)) => {
+                let _ = write!(line, "{} ({position})", function_name(&function.data()));
+            }
+            (Some(function), None) => line.push_str(&function_name(&function.data())),
+            (None, Some(position)) => {
+                let _ = write!(line, "{position}");
+            }
+            (None, None) => line.push_str("<anonymous>"),
+        }
+
+        line
+    }
+}
+
+/// The stack trace of the calls being evaluated, with a line for each call frame from the innermost outwards, up to the limit set with [`JSAgent::set_stack_trace_limit`]. Each line starts with a line break, so that the trace can follow the line which describes the error.
+///
+/// ```text
+///
+///     at f (2:3)
+///     at 5:1
+/// ```
+pub(crate) fn capture_stack_trace(agent: &JSAgent) -> String {
+    agent
+        .call_frames
+        .iter()
+        .rev()
+        .take(agent.stack_trace_limit())
+        .fold(String::new(), |mut trace, frame| {
+            let _ = write!(trace, "\n    {}", frame.describe());
+
+            trace
+        })
+}
@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+
+use crate::{
+    abstract_ops::{
+        array_exotic_objects::array_create,
+        object_operations::{
+            create_data_property_or_throw, create_non_enumerable_data_property_or_throw, get,
+        },
+        ordinary::ordinary_object_create,
+        type_conversion::{to_object, to_string},
+    },
+    intrinsics::native_error_constructor::NativeErrorKind,
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::{CompletionRecord, ThrowCompletion},
+    },
+    value::{
+        atom::Atom,
+        object::{
+            internal_slots::InternalSlotName, property::JSObjectPropKey, ObjectAddr,
+            ObjectEssentialInternalMethods, ObjectKind, ObjectMeta,
+        },
+        string::JSString,
+        JSValue,
+    },
+};
+
+/// The names of the errors which keep their name when they are cloned, as any other name is cloned as "Error".
+const ERROR_NAMES: [&str; 7] = [
+    "Error",
+    "EvalError",
+    "RangeError",
+    "ReferenceError",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+];
+
+/// A value serialized by [`structured_clone`], which holds none of the objects of the agent it was serialized in, so it can be sent to another thread and deserialized in another agent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transferable {
+    value: Serialized,
+    /// The objects reachable from the value, which are referred to by their index so that an object reached more than once, including through a cycle, is only cloned once.
+    objects: Vec<SerializedObject>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Serialized {
+    Undefined,
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(Vec<u16>),
+    Object(usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum SerializedObject {
+    Boolean(bool),
+    Number(f64),
+    String(Vec<u16>),
+    Date(f64),
+    Error {
+        name: &'static str,
+        message: Option<Vec<u16>>,
+    },
+    Array {
+        length: u32,
+        properties: Vec<(Vec<u16>, Serialized)>,
+    },
+    Object {
+        properties: Vec<(Vec<u16>, Serialized)>,
+    },
+}
+
+/// StructuredSerialize ( value )
+/// https://html.spec.whatwg.org/multipage/structured-data.html#structuredserialize
+///
+/// Serializes primitives, plain objects, arrays, dates, errors and the Boolean, Number and String wrapper objects, along with the enumerable own properties of plain objects and arrays. Any other value, like a symbol or a function, throws a TypeError in place of a "DataCloneError" DOMException.
+pub fn structured_clone(
+    agent: &mut JSAgent,
+    value: &JSValue,
+) -> Result<Transferable, ThrowCompletion> {
+    let mut serializer = Serializer::default();
+
+    let value = serializer.serialize(agent, value)?;
+
+    Ok(Transferable {
+        value,
+        objects: serializer.objects,
+    })
+}
+
+#[derive(Default)]
+struct Serializer {
+    /// The memory of StructuredSerializeInternal, from each object which has been reached to its index.
+    memory: HashMap<ObjectAddr, usize>,
+    objects: Vec<SerializedObject>,
+}
+
+impl Serializer {
+    /// StructuredSerializeInternal ( value, forStorage [ , memory ] )
+    /// https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal
+    fn serialize(&mut self, agent: &mut JSAgent, value: &JSValue) -> CompletionRecord<Serialized> {
+        // 3. If Type(value) is Undefined, Null, Boolean, Number, BigInt, or String, then return { [[Type]]: "primitive", [[Value]]: value }.
+        // 4. If Type(value) is Symbol, then throw a "DataCloneError" DOMException.
+        // NOTE: BigInt values cannot be created yet, so they are not cloned either.
+        let object = match value {
+            JSValue::Undefined => return Ok(Serialized::Undefined),
+            JSValue::Null => return Ok(Serialized::Null),
+            JSValue::Bool(value) => return Ok(Serialized::Bool(*value)),
+            JSValue::Number(number) => return Ok(Serialized::Number(number.0)),
+            JSValue::String(string) => return Ok(Serialized::String(string.code_units().to_vec())),
+            JSValue::Symbol(_) | JSValue::BigInt(_) => {
+                return type_error(&format!("{} could not be cloned", value.preview()));
+            }
+            JSValue::Object(object) => object,
+        };
+
+        // 2. If memory[value] exists, then return memory[value].
+        if let Some(index) = self.memory.get(object) {
+            return Ok(Serialized::Object(*index));
+        }
+
+        // 5. Let serialized be an uninitialized value.
+        // 26. Set memory[value] to serialized.
+        // NOTE: The object is entered into the memory before its properties are serialized, so that a cycle refers back to it.
+        let index = self.objects.len();
+
+        self.memory.insert(object.clone(), index);
+        self.objects
+            .push(SerializedObject::Object { properties: vec![] });
+
+        let kind = object.kind();
+        let (boolean_data, number_data, string_data, date_value, is_error, slots_are_empty) = {
+            let data = object.borrow();
+            let slots = data.slots();
+
+            (
+                slots.boolean_data(),
+                slots.number_data(),
+                slots.string_data(),
+                slots.date_value(),
+                slots.contains(&InternalSlotName::ErrorData),
+                slots.is_empty(),
+            )
+        };
+
+        let serialized = if let Some(value) = boolean_data {
+            // 6. If value has a [[BooleanData]] internal slot, then set serialized to { [[Type]]: "Boolean", [[BooleanData]]: value.[[BooleanData]] }.
+            SerializedObject::Boolean(value)
+        } else if let Some(number) = number_data {
+            // 7. Otherwise, if value has a [[NumberData]] internal slot, then set serialized to { [[Type]]: "Number", [[NumberData]]: value.[[NumberData]] }.
+            SerializedObject::Number(number.0)
+        } else if let Some(string) = string_data {
+            // 10. Otherwise, if value has a [[StringData]] internal slot, then set serialized to { [[Type]]: "String", [[StringData]]: value.[[StringData]] }.
+            SerializedObject::String(string.code_units().to_vec())
+        } else if let Some(time) = date_value {
+            // 11. Otherwise, if value has a [[DateValue]] internal slot, then set serialized to { [[Type]]: "Date", [[DateValue]]: value.[[DateValue]] }.
+            SerializedObject::Date(time)
+        } else if is_error {
+            // 18. Otherwise, if value has an [[ErrorData]] internal slot and value is not a platform object, then:
+            self.serialize_error(agent, object)?
+        } else if kind == ObjectKind::Array {
+            // 19. Otherwise, if value is an Array exotic object, then:
+            // a. Let valueLenDescriptor be ? OrdinaryGetOwnProperty(value, "length").
+            // b. Let valueLen be valueLenDescriptor.[[Value]].
+            let length = get(agent, object, &JSObjectPropKey::String("length".into()))?;
+
+            // c. Set serialized to { [[Type]]: "Array", [[Length]]: valueLen, [[Properties]]: a new empty List }.
+            // d. Set deep to true.
+            SerializedObject::Array {
+                length: length.as_f64().unwrap_or_default() as u32,
+                properties: self.serialize_properties(agent, object)?,
+            }
+        } else if kind == ObjectKind::Ordinary && slots_are_empty {
+            // 24. Otherwise, if value is an exotic object and value is not the %Object.prototype% intrinsic object associated with any realm, then throw a "DataCloneError" DOMException.
+            // 25. Otherwise:
+            // a. Set serialized to { [[Type]]: "Object", [[Properties]]: a new empty List }.
+            // b. Set deep to true.
+            SerializedObject::Object {
+                properties: self.serialize_properties(agent, object)?,
+            }
+        } else {
+            // 21. Otherwise, if IsCallable(value) is true, then throw a "DataCloneError" DOMException.
+            // 22. Otherwise, if value has any internal slot other than [[Prototype]], [[Extensible]], or [[PrivateElements]], then throw a "DataCloneError" DOMException.
+            return type_error(&format!("{} could not be cloned", value.preview()));
+        };
+
+        self.objects[index] = serialized;
+
+        // 28. Return serialized.
+        Ok(Serialized::Object(index))
+    }
+
+    /// StructuredSerializeInternal ( value, forStorage [ , memory ] ), step 26
+    /// https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal
+    fn serialize_properties(
+        &mut self,
+        agent: &mut JSAgent,
+        object: &ObjectAddr,
+    ) -> CompletionRecord<Vec<(Vec<u16>, Serialized)>> {
+        let mut properties = vec![];
+
+        // 1. Let copiedProperties be a new empty List.
+        // 2. For each key in ! EnumerableOwnProperties(value, key):
+        for key in object.own_property_keys() {
+            let JSObjectPropKey::String(atom) = &key else {
+                continue;
+            };
+
+            // NOTE: A getter which ran for an earlier property may have deleted or hidden this one.
+            if object
+                .get_own_property(&key)?
+                .is_none_or(|desc| desc.enumerable != Some(true))
+            {
+                continue;
+            }
+
+            // a. If ! HasOwnProperty(value, key) is true, then:
+            // i. Let inputValue be ? value.[[Get]](key, value).
+            let input_value = get(agent, object, &key)?;
+
+            // ii. Let outputValue be ? StructuredSerializeInternal(inputValue, forStorage, memory).
+            let output_value = self.serialize(agent, &input_value)?;
+
+            // iii. Append { [[Key]]: key, [[Value]]: outputValue } to copiedProperties.
+            properties.push((
+                JSString::from(atom.clone()).code_units().to_vec(),
+                output_value,
+            ));
+        }
+
+        Ok(properties)
+    }
+
+    /// StructuredSerializeInternal ( value, forStorage [ , memory ] ), step 18
+    /// https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal
+    fn serialize_error(
+        &mut self,
+        agent: &mut JSAgent,
+        object: &ObjectAddr,
+    ) -> CompletionRecord<SerializedObject> {
+        // a. Let name be ? Get(value, "name").
+        let name = get(agent, object, &JSObjectPropKey::String("name".into()))?;
+
+        // b. If name is not one of "Error", "EvalError", "RangeError", "ReferenceError", "SyntaxError", "TypeError", or "URIError", then set name to "Error".
+        let name = ERROR_NAMES
+            .into_iter()
+            .find(|error_name| name.to_rust_string().as_deref() == Some(error_name))
+            .unwrap_or("Error");
+
+        // c. Let valueMessageDesc be ? value.[[GetOwnProperty]]("message").
+        let message_desc = object.get_own_property(&JSObjectPropKey::String("message".into()))?;
+
+        // d. Let message be undefined if IsDataDescriptor(valueMessageDesc) is false, and ? ToString(valueMessageDesc.[[Value]]) otherwise.
+        let message = match message_desc.and_then(|desc| desc.value) {
+            Some(value) => Some(to_string(agent, value)?.code_units().to_vec()),
+            None => None,
+        };
+
+        // e. Set serialized to { [[Type]]: "Error", [[Name]]: name, [[Message]]: message }.
+        Ok(SerializedObject::Error { name, message })
+    }
+}
+
+impl Transferable {
+    /// StructuredDeserialize ( serialized, targetRealm )
+    /// https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserialize
+    ///
+    /// Creates a copy of the serialized value in the realm of the agent, which need not be the agent it was serialized in.
+    pub fn deserialize(&self, agent: &mut JSAgent) -> JSValue {
+        agent.initialize_realm();
+
+        // NOTE: Every object is created before any properties are defined, so that a property can refer to an object which comes after it, as a cycle does.
+        let objects = self
+            .objects
+            .iter()
+            .map(|serialized| deserialize_object(agent, serialized))
+            .collect::<Vec<_>>();
+
+        for (serialized, object) in self.objects.iter().zip(&objects) {
+            let (SerializedObject::Array { properties, .. }
+            | SerializedObject::Object { properties }) = serialized
+            else {
+                continue;
+            };
+
+            for (key, value) in properties {
+                let key = JSObjectPropKey::String(Atom::from(JSString::from(key.as_slice())));
+
+                // NOTE: The object is a new extensible object, so creating a data property on it can't fail.
+                create_data_property_or_throw(object, &key, deserialize_value(value, &objects))
+                    .unwrap();
+            }
+        }
+
+        deserialize_value(&self.value, &objects)
+    }
+}
+
+fn deserialize_value(serialized: &Serialized, objects: &[ObjectAddr]) -> JSValue {
+    match serialized {
+        Serialized::Undefined => JSValue::Undefined,
+        Serialized::Null => JSValue::Null,
+        Serialized::Bool(value) => JSValue::Bool(*value),
+        Serialized::Number(number) => JSValue::from(*number),
+        Serialized::String(units) => JSValue::String(JSString::from(units.as_slice())),
+        Serialized::Object(index) => JSValue::from(objects[*index].clone()),
+    }
+}
+
+/// StructuredDeserialize ( serialized, targetRealm [ , memory ] ), steps 4 to 21
+/// https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserialize
+fn deserialize_object(agent: &mut JSAgent, serialized: &SerializedObject) -> ObjectAddr {
+    let realm = agent.current_realm();
+    let intrinsics = &realm.borrow().intrinsics;
+
+    // NOTE: Converting a Boolean, Number or String to an object can't fail.
+    match serialized {
+        // 4. Otherwise, if serialized.[[Type]] is "Boolean", then set value to a new Boolean object in targetRealm whose [[BooleanData]] internal slot value is serialized.[[BooleanData]].
+        SerializedObject::Boolean(value) => to_object(agent, &JSValue::Bool(*value)).unwrap(),
+        // 5. Otherwise, if serialized.[[Type]] is "Number", then set value to a new Number object in targetRealm whose [[NumberData]] internal slot value is serialized.[[NumberData]].
+        SerializedObject::Number(number) => to_object(agent, &JSValue::from(*number)).unwrap(),
+        // 8. Otherwise, if serialized.[[Type]] is "String", then set value to a new String object in targetRealm whose [[StringData]] internal slot value is serialized.[[StringData]].
+        SerializedObject::String(units) => {
+            to_object(agent, &JSValue::String(JSString::from(units.as_slice()))).unwrap()
+        }
+        // 9. Otherwise, if serialized.[[Type]] is "Date", then set value to a new Date object in targetRealm whose [[DateValue]] internal slot value is serialized.[[DateValue]].
+        SerializedObject::Date(time) => {
+            let date = ordinary_object_create(
+                agent,
+                intrinsics.date_prototype.clone(),
+                Some(vec![InternalSlotName::DateValue]),
+            );
+
+            date.data_mut().slots_mut().set_date_value(*time);
+
+            date
+        }
+        // 17. Otherwise, if serialized.[[Type]] is "Error", then:
+        SerializedObject::Error { name, message } => {
+            // a. Let prototype be %Error.prototype%.
+            // b-g. If serialized.[[Name]] is "EvalError", "RangeError", "ReferenceError", "SyntaxError", "TypeError" or "URIError", then set prototype to the %NativeError.prototype% of that name.
+            let prototype = match NativeErrorKind::from_name(name) {
+                Some(kind) => (kind.prototype())(intrinsics),
+                None => intrinsics.error_prototype.clone(),
+            };
+
+            // h. Let message be serialized.[[Message]].
+            // i. Set value to OrdinaryObjectCreate(prototype, « [[ErrorData]] »).
+            let error =
+                ordinary_object_create(agent, prototype, Some(vec![InternalSlotName::ErrorData]));
+
+            // j. If serialized.[[Message]] is not undefined, then perform ! OrdinaryDefineOwnProperty(value, "message", { [[Value]]: message, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+            if let Some(message) = message {
+                create_non_enumerable_data_property_or_throw(
+                    &error,
+                    &JSObjectPropKey::String("message".into()),
+                    JSValue::String(JSString::from(message.as_slice())),
+                );
+            }
+
+            error
+        }
+        // 13. Otherwise, if serialized.[[Type]] is "Array", then:
+        // a. Let outputProto be targetRealm.[[Intrinsics]].[[%Array.prototype%]].
+        // b. Set value to ! ArrayCreate(serialized.[[Length]], outputProto).
+        SerializedObject::Array { length, .. } => array_create(
+            agent,
+            u64::from(*length),
+            intrinsics.array_prototype.clone(),
+        )
+        .unwrap(),
+        // 14. Otherwise, if serialized.[[Type]] is "Object", then set value to a new Object in targetRealm.
+        SerializedObject::Object { .. } => {
+            ordinary_object_create(agent, intrinsics.object_prototype.clone(), None)
+        }
+    }
+}
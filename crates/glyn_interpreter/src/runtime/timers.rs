@@ -0,0 +1,140 @@
+use crate::{
+    abstract_ops::type_conversion::to_number,
+    runtime::{
+        agent::JSAgent, completion::CompletionRecord, native_function::Args, realm::RealmAddr,
+    },
+    value::JSValue,
+};
+use std::collections::BTreeMap;
+
+/// A callback scheduled by `setTimeout` or `setInterval`, along with the arguments it is called with.
+pub(crate) struct Timer {
+    pub(crate) callback: JSValue,
+    pub(crate) arguments: Vec<JSValue>,
+    /// The realm of the function which scheduled the timer, which the callback is run in.
+    pub(crate) realm: RealmAddr,
+    /// The time of the agent's clock at which the timer is due.
+    due: f64,
+    /// The delay the timer is scheduled again with once it has run, or None if it only runs once.
+    interval: Option<f64>,
+}
+
+/// The map of active timers of an agent, which the embedder runs with [`JSAgent::run_due_timers`].
+/// https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#map-of-active-timers
+#[derive(Default)]
+pub(crate) struct Timers {
+    /// The handle of the last timer which was scheduled, as handles are never reused.
+    last_id: u32,
+    timers: BTreeMap<u32, Timer>,
+}
+
+impl Timers {
+    fn schedule(&mut self, timer: Timer) -> u32 {
+        self.last_id += 1;
+
+        self.timers.insert(self.last_id, timer);
+
+        self.last_id
+    }
+
+    fn clear(&mut self, id: u32) {
+        self.timers.remove(&id);
+    }
+
+    /// The earliest time at which a timer is due, or None if there are no timers.
+    pub(crate) fn next_due(&self) -> Option<f64> {
+        self.timers.values().map(|timer| timer.due).reduce(f64::min)
+    }
+
+    /// The handles of the timers which are due at the given time, in the order they must run.
+    ///
+    /// Timers which are due at the same time run in the order they were scheduled.
+    pub(crate) fn due(&self, now: f64) -> Vec<u32> {
+        let mut due = self
+            .timers
+            .iter()
+            .filter(|(_, timer)| timer.due <= now)
+            .map(|(id, timer)| (timer.due, *id))
+            .collect::<Vec<_>>();
+
+        due.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+        due.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Takes the timer with the given handle so that it can be run, leaving an interval scheduled again at the given time.
+    pub(crate) fn take(&mut self, id: u32, now: f64) -> Option<Timer> {
+        let timer = self.timers.get_mut(&id)?;
+
+        let Some(interval) = timer.interval else {
+            return self.timers.remove(&id);
+        };
+
+        timer.due = now + interval;
+
+        Some(Timer {
+            callback: timer.callback.clone(),
+            arguments: timer.arguments.clone(),
+            realm: timer.realm.clone(),
+            due: now,
+            interval: None,
+        })
+    }
+}
+
+/// setTimeout(handler [ , timeout [ , ...arguments ] ])
+/// https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-settimeout
+pub(crate) fn set_timeout(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+    timer_initialization_steps(agent, args, false)
+}
+
+/// setInterval(handler [ , timeout [ , ...arguments ] ])
+/// https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-setinterval
+pub(crate) fn set_interval(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+    timer_initialization_steps(agent, args, true)
+}
+
+/// clearTimeout(id) and clearInterval(id)
+/// https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-cleartimeout
+pub(crate) fn clear_timer(agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+    // NOTE: The handle is converted like a WebIDL long, so a handle which was never returned clears nothing.
+    let id = to_number(agent, args.get(0).clone())?.0;
+
+    // The clearTimeout(id) and clearInterval(id) method steps are to remove this's map of active timers[id].
+    if id >= 1.0 && id <= f64::from(u32::MAX) && id.fract() == 0.0 {
+        agent.timers.clear(id as u32);
+    }
+
+    Ok(JSValue::Undefined)
+}
+
+/// 8.6 Timers: timer initialization steps
+/// https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timer-initialisation-steps
+fn timer_initialization_steps(
+    agent: &mut JSAgent,
+    args: Args,
+    repeat: bool,
+) -> CompletionRecord<JSValue> {
+    // NOTE: A string handler would have to be compiled like eval, which the agent doesn't support, so the handler must be a function.
+    let callback = args.get_function(0)?.clone();
+
+    // 4. If timeout is less than 0, then set timeout to 0.
+    let timeout = to_number(agent, args.get(1).clone())?.0;
+
+    let timeout = if timeout.is_finite() && timeout > 0.0 {
+        timeout.trunc()
+    } else {
+        0.0
+    };
+
+    let timer = Timer {
+        callback,
+        arguments: args.rest(2).to_vec(),
+        realm: agent.current_realm(),
+        due: agent.clock().now() + timeout,
+        interval: repeat.then_some(timeout),
+    };
+
+    // 12. Return handle.
+    Ok(JSValue::from(agent.timers.schedule(timer)))
+}
@@ -0,0 +1,110 @@
+use std::ops::Range;
+
+use crate::value::{
+    atom::Atom,
+    object::{property::JSObjectPropKey, ObjectAddr},
+    JSValue,
+};
+
+/// Identifies a registered watchpoint, so that it can be removed again.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WatchpointId(usize);
+
+/// The storage location observed by a watchpoint.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchTarget {
+    /// Every binding with the given name, whether it is global or local to a function.
+    Binding(String),
+    /// The property with the given key on an object. Watching a non-object value never triggers.
+    Property(JSValue, String),
+}
+
+/// A write observed by a watchpoint.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchEvent {
+    /// The value before the write, or undefined if the binding or property did not exist or was uninitialized.
+    pub old_value: JSValue,
+    pub new_value: JSValue,
+    /// The byte range of the statement in the source text which performed the write, if known.
+    pub span: Option<Range<usize>>,
+}
+
+pub type WatchCallback = Box<dyn FnMut(&WatchEvent)>;
+
+/// The location a write resolved to, used to match it against the registered watchpoints.
+pub(crate) enum WatchLocation {
+    Binding(Atom),
+    Property(ObjectAddr, JSObjectPropKey),
+}
+
+struct Watchpoint {
+    id: WatchpointId,
+    target: WatchTarget,
+    callback: WatchCallback,
+}
+
+impl Watchpoint {
+    fn matches(&self, location: &WatchLocation) -> bool {
+        match (&self.target, location) {
+            (WatchTarget::Binding(name), WatchLocation::Binding(binding_name)) => {
+                *binding_name == Atom::from(name.as_str())
+            }
+            (
+                WatchTarget::Property(JSValue::Object(object), key),
+                WatchLocation::Property(addr, prop_key),
+            ) => {
+                object == addr
+                    && matches!(prop_key, JSObjectPropKey::String(prop_key) if *prop_key == Atom::from(key.as_str()))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Watchpoints {
+    next_id: usize,
+    entries: Vec<Watchpoint>,
+}
+
+impl Watchpoints {
+    pub(crate) fn add(&mut self, target: WatchTarget, callback: WatchCallback) -> WatchpointId {
+        let id = WatchpointId(self.next_id);
+
+        self.next_id += 1;
+
+        self.entries.push(Watchpoint {
+            id,
+            target,
+            callback,
+        });
+
+        id
+    }
+
+    pub(crate) fn remove(&mut self, id: WatchpointId) -> bool {
+        let len = self.entries.len();
+
+        self.entries.retain(|watchpoint| watchpoint.id != id);
+
+        self.entries.len() != len
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn is_watched(&self, location: &WatchLocation) -> bool {
+        self.entries
+            .iter()
+            .any(|watchpoint| watchpoint.matches(location))
+    }
+
+    pub(crate) fn notify(&mut self, location: &WatchLocation, event: &WatchEvent) {
+        for watchpoint in self.entries.iter_mut() {
+            if watchpoint.matches(location) {
+                (watchpoint.callback)(event);
+            }
+        }
+    }
+}
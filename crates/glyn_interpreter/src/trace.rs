@@ -0,0 +1,233 @@
+//! Tracing of the internals of the interpreter, compiled in with the `debug` feature.
+//!
+//! Each subsystem is traced at its own level, so that diagnosing one layer does not flood the output with the others. The levels are read from the `GLYN_TRACE` environment variable the first time anything is traced, as a comma-separated list of `subsystem=level` directives. A directive without a subsystem sets the level of every subsystem, and later directives override earlier ones:
+//!
+//! ```text
+//! GLYN_TRACE=vm=trace
+//! GLYN_TRACE=debug,lexer=off
+//! ```
+//!
+//! The levels can also be changed at runtime with [`set_trace_level`]. Trace output is written to standard error.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Once,
+    },
+};
+
+/// Traces a message for a subsystem, if the level is enabled for it.
+///
+/// ```ignore
+/// trace!(Vm, Debug, "{instruction}");
+/// ```
+macro_rules! trace {
+    ($subsystem:ident, $level:ident, $($arg:tt)+) => {{
+        use $crate::trace::{TraceLevel, TraceSubsystem};
+
+        if $crate::trace::is_enabled(TraceSubsystem::$subsystem, TraceLevel::$level) {
+            $crate::trace::emit(
+                TraceSubsystem::$subsystem,
+                TraceLevel::$level,
+                format_args!($($arg)+),
+            );
+        }
+    }};
+}
+
+/// A layer of the interpreter which is traced independently of the others.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TraceSubsystem {
+    /// The tokens read from the source text.
+    Lexer,
+    /// The statements and functions recognised by the parser.
+    Parser,
+    /// The bytecode compiled for each script, function and parameter initializer.
+    Codegen,
+    /// The instructions executed by the virtual machine and the stack after each of them.
+    Vm,
+    /// The young values reclaimed or promoted by the nurseries.
+    Gc,
+}
+
+impl TraceSubsystem {
+    const ALL: [TraceSubsystem; 5] = [
+        TraceSubsystem::Lexer,
+        TraceSubsystem::Parser,
+        TraceSubsystem::Codegen,
+        TraceSubsystem::Vm,
+        TraceSubsystem::Gc,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            TraceSubsystem::Lexer => "lexer",
+            TraceSubsystem::Parser => "parser",
+            TraceSubsystem::Codegen => "codegen",
+            TraceSubsystem::Vm => "vm",
+            TraceSubsystem::Gc => "gc",
+        }
+    }
+}
+
+impl fmt::Display for TraceSubsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How much detail a subsystem traces. Each level includes the messages of the levels below it.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum TraceLevel {
+    Off,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl TraceLevel {
+    const ALL: [TraceLevel; 4] = [
+        TraceLevel::Off,
+        TraceLevel::Info,
+        TraceLevel::Debug,
+        TraceLevel::Trace,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            TraceLevel::Off => "off",
+            TraceLevel::Info => "info",
+            TraceLevel::Debug => "debug",
+            TraceLevel::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for TraceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The level of each subsystem, indexed by its discriminant.
+static LEVELS: [AtomicU8; TraceSubsystem::ALL.len()] =
+    [const { AtomicU8::new(TraceLevel::Off as u8) }; TraceSubsystem::ALL.len()];
+
+static LEVELS_FROM_ENV: Once = Once::new();
+
+fn levels() -> &'static [AtomicU8; TraceSubsystem::ALL.len()] {
+    LEVELS_FROM_ENV.call_once(|| {
+        let Ok(directives) = std::env::var("GLYN_TRACE") else {
+            return;
+        };
+
+        for (subsystem, level) in parse_directives(&directives) {
+            LEVELS[subsystem as usize].store(level as u8, Ordering::Relaxed);
+        }
+    });
+
+    &LEVELS
+}
+
+/// Sets the level a subsystem is traced at, overriding the level read from `GLYN_TRACE`.
+pub fn set_trace_level(subsystem: TraceSubsystem, level: TraceLevel) {
+    levels()[subsystem as usize].store(level as u8, Ordering::Relaxed);
+}
+
+pub(crate) fn is_enabled(subsystem: TraceSubsystem, level: TraceLevel) -> bool {
+    level != TraceLevel::Off && levels()[subsystem as usize].load(Ordering::Relaxed) >= level as u8
+}
+
+pub(crate) fn emit(subsystem: TraceSubsystem, level: TraceLevel, message: fmt::Arguments) {
+    eprintln!("[{level} {subsystem}] {message}");
+}
+
+/// Parses the directives of `GLYN_TRACE` into the level of each subsystem they set, ignoring any directive which names an unknown subsystem or level.
+fn parse_directives(directives: &str) -> Vec<(TraceSubsystem, TraceLevel)> {
+    let parse_level = |name: &str| {
+        TraceLevel::ALL
+            .into_iter()
+            .find(|level| level.name().eq_ignore_ascii_case(name.trim()))
+    };
+
+    let parse_subsystem = |name: &str| {
+        TraceSubsystem::ALL
+            .into_iter()
+            .find(|subsystem| subsystem.name().eq_ignore_ascii_case(name.trim()))
+    };
+
+    let mut levels = vec![];
+
+    for directive in directives.split(',').filter(|d| !d.trim().is_empty()) {
+        match directive.split_once('=') {
+            Some((subsystem, level)) => {
+                if let (Some(subsystem), Some(level)) =
+                    (parse_subsystem(subsystem), parse_level(level))
+                {
+                    levels.push((subsystem, level));
+                }
+            }
+            None => {
+                if let Some(level) = parse_level(directive) {
+                    levels.extend(
+                        TraceSubsystem::ALL
+                            .into_iter()
+                            .map(|subsystem| (subsystem, level)),
+                    );
+                }
+            }
+        }
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_subsystem_directives() {
+        assert_eq!(
+            parse_directives("vm=trace, GC=Debug"),
+            vec![
+                (TraceSubsystem::Vm, TraceLevel::Trace),
+                (TraceSubsystem::Gc, TraceLevel::Debug),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_global_directives_and_overrides() {
+        let levels = parse_directives("info,lexer=off");
+
+        assert_eq!(levels.len(), TraceSubsystem::ALL.len() + 1);
+        assert!(levels[..TraceSubsystem::ALL.len()]
+            .iter()
+            .all(|(_, level)| *level == TraceLevel::Info));
+        assert_eq!(
+            levels.last(),
+            Some(&(TraceSubsystem::Lexer, TraceLevel::Off))
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_directives() {
+        assert_eq!(parse_directives("vm=loud,heap=trace,,verbose"), vec![]);
+    }
+
+    #[test]
+    fn levels_include_the_levels_below_them() {
+        set_trace_level(TraceSubsystem::Codegen, TraceLevel::Debug);
+
+        assert!(is_enabled(TraceSubsystem::Codegen, TraceLevel::Info));
+        assert!(is_enabled(TraceSubsystem::Codegen, TraceLevel::Debug));
+        assert!(!is_enabled(TraceSubsystem::Codegen, TraceLevel::Trace));
+        assert!(!is_enabled(TraceSubsystem::Codegen, TraceLevel::Off));
+
+        set_trace_level(TraceSubsystem::Codegen, TraceLevel::Off);
+
+        assert!(!is_enabled(TraceSubsystem::Codegen, TraceLevel::Info));
+    }
+}
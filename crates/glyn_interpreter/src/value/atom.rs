@@ -0,0 +1,268 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::size_of,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    gc::{record_string_allocation, HeapSize},
+    runtime::{
+        completion::{throw_completion, ThrowCompletion},
+        reference::ReferenceName,
+    },
+    value::string::JSString,
+};
+
+thread_local! {
+    /// NOTE: Atoms are created and shown where there is no agent, like when a property key is built from a Rust string, and cannot be sent to another thread, so every agent on a thread shares the strings interned by each of them.
+    static ATOMS: RefCell<AtomTable> = RefCell::default();
+}
+
+/// The strings which are interned on the thread, each of which is removed from the table once the last atom naming it is dropped. Array indices are never interned, so indexing into arrays does not grow the table.
+#[derive(Default)]
+struct AtomTable {
+    entries: HashMap<JSString, Weak<AtomEntry>>,
+}
+
+impl AtomTable {
+    fn intern(&mut self, value: &JSString) -> Rc<AtomEntry> {
+        if let Some(entry) = self.entries.get(value).and_then(Weak::upgrade) {
+            return entry;
+        }
+
+        // NOTE: The code units are copied out of the string, so that interning a rope does not keep its halves alive.
+        let string = JSString::from(value.code_units());
+
+        // NOTE: The code units of the string are accounted for by the string itself, so only the entry and its place in the table are recorded here.
+        let entry = Rc::new(AtomEntry {
+            string: string.clone(),
+            heap_size: record_string_allocation(AtomEntry::SIZE),
+        });
+
+        self.entries.insert(string, Rc::downgrade(&entry));
+
+        entry
+    }
+}
+
+/// An interned string, which is shared by every atom naming it.
+struct AtomEntry {
+    string: JSString,
+    heap_size: Option<HeapSize>,
+}
+
+impl AtomEntry {
+    /// The bytes taken up by the allocation of an entry, including the reference counts, and by its place in the table.
+    const SIZE: usize =
+        size_of::<AtomEntry>() + 2 * size_of::<usize>() + size_of::<(JSString, Weak<AtomEntry>)>();
+}
+
+impl Drop for AtomEntry {
+    fn drop(&mut self) {
+        if let Some(heap_size) = &self.heap_size {
+            heap_size.record_free(AtomEntry::SIZE);
+        }
+
+        // NOTE: The table is already gone if the atom is dropped while the thread exits.
+        let _ = ATOMS.try_with(|atoms| {
+            if let Ok(mut atoms) = atoms.try_borrow_mut() {
+                atoms.entries.remove(&self.string);
+            }
+        });
+    }
+}
+
+#[derive(Clone)]
+enum AtomKind {
+    Index(u32),
+    Interned(Rc<AtomEntry>),
+}
+
+/// A string which is the name of a property or binding, interned so that comparing and hashing it compares and hashes a pointer rather than the characters of the string.
+///
+/// Two atoms are equal exactly when their strings are equal. The canonical string of an array index is held as the index itself rather than interned, so the elements of an array are found by their index without looking up a string.
+///
+/// NOTE: An interned atom shares its string with every other atom naming it, so it is neither Send nor Sync.
+#[derive(Clone)]
+pub(crate) struct Atom(AtomKind);
+
+impl PartialEq for Atom {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (AtomKind::Index(index), AtomKind::Index(other)) => index == other,
+            (AtomKind::Interned(entry), AtomKind::Interned(other)) => Rc::ptr_eq(entry, other),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Atom {}
+
+impl Hash for Atom {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.0 {
+            AtomKind::Index(index) => index.hash(state),
+            AtomKind::Interned(entry) => Rc::as_ptr(entry).hash(state),
+        }
+    }
+}
+
+impl Atom {
+    /// The array index the atom names, if its string is the canonical string of an integer in the inclusive interval from 0 to 2**32 - 2.
+    /// https://262.ecma-international.org/16.0/#array-index
+    pub(crate) fn as_array_index(&self) -> Option<u32> {
+        match &self.0 {
+            AtomKind::Index(index) => Some(*index),
+            AtomKind::Interned(_) => None,
+        }
+    }
+
+    pub(crate) fn to_js_string(&self) -> JSString {
+        match &self.0 {
+            AtomKind::Index(index) => JSString::from(index.to_string()),
+            AtomKind::Interned(entry) => entry.string.clone(),
+        }
+    }
+}
+
+/// Parses the string as an array index, if it is the canonical string of one.
+fn parse_array_index(value: &JSString) -> Option<u32> {
+    // NOTE: A canonical numeric string of an integer is its decimal digits without leading zeros, so any other string is not an array index.
+    let units = value.code_units();
+
+    if units.is_empty()
+        || units.len() > 10
+        || !units
+            .iter()
+            .all(|&unit| (b'0' as u16..=b'9' as u16).contains(&unit))
+        || (units[0] == b'0' as u16 && units.len() > 1)
+    {
+        return None;
+    }
+
+    let index = units.iter().fold(0u64, |index, &unit| {
+        index * 10 + (unit - b'0' as u16) as u64
+    });
+
+    u32::try_from(index).ok().filter(|&index| index != u32::MAX)
+}
+
+impl From<&str> for Atom {
+    fn from(value: &str) -> Self {
+        Atom::from(&JSString::from(value))
+    }
+}
+
+impl From<String> for Atom {
+    fn from(value: String) -> Self {
+        Atom::from(value.as_str())
+    }
+}
+
+impl From<&JSString> for Atom {
+    fn from(value: &JSString) -> Self {
+        match parse_array_index(value) {
+            Some(index) => Atom(AtomKind::Index(index)),
+            None => Atom(AtomKind::Interned(
+                ATOMS.with(|atoms| atoms.borrow_mut().intern(value)),
+            )),
+        }
+    }
+}
+
+impl From<JSString> for Atom {
+    fn from(value: JSString) -> Self {
+        Atom::from(&value)
+    }
+}
+
+impl From<u32> for Atom {
+    fn from(value: u32) -> Self {
+        match value {
+            u32::MAX => Atom::from(value.to_string()),
+            index => Atom(AtomKind::Index(index)),
+        }
+    }
+}
+
+impl From<Atom> for JSString {
+    fn from(value: Atom) -> Self {
+        value.to_js_string()
+    }
+}
+
+impl TryFrom<&ReferenceName> for Atom {
+    type Error = ThrowCompletion;
+
+    fn try_from(value: &ReferenceName) -> Result<Self, Self::Error> {
+        match value {
+            ReferenceName::Binding(name) => Ok(name.clone()),
+            _ => throw_completion("Expected a ReferenceName::Binding for conversion to Atom"),
+        }
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            AtomKind::Index(index) => write!(f, "{index}"),
+            AtomKind::Interned(entry) => write!(f, "{}", entry.string),
+        }
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Atom({:?})", self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_strings_are_the_same_atom() {
+        let name = String::from("name");
+
+        assert_eq!(Atom::from("name"), Atom::from(name));
+        assert_eq!(Atom::from("name"), Atom::from(&JSString::from("name")));
+        assert_ne!(Atom::from("name"), Atom::from("other"));
+        assert_eq!(Atom::from("name").to_js_string(), JSString::from("name"));
+        assert_eq!(format!("{:?}", Atom::from("name")), "Atom(\"name\")");
+    }
+
+    #[test]
+    fn array_indices_are_not_interned() {
+        assert_eq!(Atom::from("12").as_array_index(), Some(12));
+        assert_eq!(Atom::from(12), Atom::from("12"));
+        assert_eq!(Atom::from("4294967294").as_array_index(), Some(4294967294));
+        assert_eq!(Atom::from(u32::MAX), Atom::from("4294967295"));
+
+        for name in ["012", "", "-1", "1.5", "4294967295", "1e3"] {
+            assert_eq!(Atom::from(name).as_array_index(), None);
+            assert_eq!(Atom::from(name).to_string(), name);
+        }
+
+        assert_eq!(Atom::from("12").to_js_string(), JSString::from("12"));
+    }
+
+    #[test]
+    fn atoms_are_freed_with_their_last_name() {
+        let is_interned = |name: &str| {
+            ATOMS.with(|atoms| atoms.borrow().entries.contains_key(&JSString::from(name)))
+        };
+
+        let atom = Atom::from("atoms_are_freed_with_their_last_name");
+        let copy = atom.clone();
+
+        drop(atom);
+        assert!(is_interned("atoms_are_freed_with_their_last_name"));
+
+        drop(copy);
+        assert!(!is_interned("atoms_are_freed_with_their_last_name"));
+    }
+}
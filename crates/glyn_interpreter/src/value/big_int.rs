@@ -7,7 +7,7 @@ use crate::{
 /// 6.1.8 The BigInt Type
 /// https://262.ecma-international.org/16.0/#sec-ecmascript-language-types-bigint-type
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
-pub(crate) struct JSBigInt;
+pub struct JSBigInt;
 
 impl JSBigInt {
     pub(crate) fn is_zero(&self) -> bool {
@@ -27,7 +27,10 @@ impl TryFrom<JSValue> for JSBigInt {
     fn try_from(value: JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::BigInt(value) => Ok(value),
-            _ => throw_completion("Expected a JSValue::BigInt for conversion to JSBigInt"),
+            _ => throw_completion(&format!(
+                "Expected a JSValue::BigInt for conversion to JSBigInt, but got {}",
+                value.preview()
+            )),
         }
     }
 }
@@ -38,7 +41,10 @@ impl TryFrom<&JSValue> for JSBigInt {
     fn try_from(value: &JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::BigInt(value) => Ok(value.clone()),
-            _ => throw_completion("Expected a JSValue::BigInt for conversion to JSBigInt"),
+            _ => throw_completion(&format!(
+                "Expected a JSValue::BigInt for conversion to JSBigInt, but got {}",
+                value.preview()
+            )),
         }
     }
 }
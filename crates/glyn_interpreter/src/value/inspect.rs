@@ -0,0 +1,229 @@
+use std::fmt::Write;
+
+use crate::value::{
+    object::{
+        internal_slots::InternalSlotName, property::JSObjectPropKey, ObjectAddr, ObjectData,
+        ObjectKind,
+    },
+    preview::function_name,
+    symbol::JSSymbol,
+    JSValue,
+};
+
+/// The number of levels of nested objects shown, beyond which an object is shown as `[Object]` or `[Array]`.
+const MAX_DEPTH: usize = 2;
+
+impl JSValue {
+    /// A rendering of the value for a REPL or a console, like `{ a: 1, b: [ 'x', true ] }`, which shows the own enumerable properties of objects a few levels deep.
+    ///
+    /// Rendering the value never runs user code, so the value of an accessor property is shown as `[Getter]`, `[Setter]` or `[Getter/Setter]`. An error, which is an object with an [[ErrorData]] internal slot, is shown as its stack if it has an own "stack" property holding a string, and the holes of an array are shown as `<1 empty item>`.
+    pub fn inspect(&self) -> String {
+        let mut output = String::new();
+
+        write_value(&mut output, self, 0, &mut vec![]);
+
+        output
+    }
+}
+
+fn write_value(output: &mut String, value: &JSValue, depth: usize, seen: &mut Vec<ObjectAddr>) {
+    match value {
+        JSValue::Undefined => output.push_str("undefined"),
+        JSValue::Null => output.push_str("null"),
+        JSValue::Bool(value) => {
+            let _ = write!(output, "{value}");
+        }
+        JSValue::String(value) => write_string(output, &value.to_string()),
+        JSValue::Number(value) => {
+            let _ = write!(output, "{}", value.to_string(10));
+        }
+        // NOTE: BigInt values cannot be converted to strings yet.
+        JSValue::BigInt(_) => output.push_str("[BigInt]"),
+        JSValue::Symbol(symbol) => write_symbol(output, symbol),
+        JSValue::Object(object) => write_object(output, object, depth, seen),
+    }
+}
+
+fn write_symbol(output: &mut String, symbol: &JSSymbol) {
+    match &symbol.description {
+        Some(description) => {
+            let _ = write!(output, "Symbol({description})");
+        }
+        None => output.push_str("Symbol()"),
+    }
+}
+
+/// Writes the string in single quotes, escaping the characters which would not be read back as the same string.
+fn write_string(output: &mut String, value: &str) {
+    output.push('\'');
+
+    for c in value.chars() {
+        match c {
+            '\'' => output.push_str("\\'"),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+
+    output.push('\'');
+}
+
+fn write_object(
+    output: &mut String,
+    object: &ObjectAddr,
+    depth: usize,
+    seen: &mut Vec<ObjectAddr>,
+) {
+    // NOTE: The object may already be borrowed by the operation that is running, in which case only its type is shown.
+    let Ok(data) = object.try_borrow() else {
+        return output.push_str("[Object]");
+    };
+
+    let is_array = matches!(data.kind(), ObjectKind::Array);
+
+    if let ObjectKind::Function | ObjectKind::BuiltinFunction = data.kind() {
+        return match function_name(&data).as_str() {
+            "anonymous" => output.push_str("[Function (anonymous)]"),
+            name => {
+                let _ = write!(output, "[Function: {name}]");
+            }
+        };
+    }
+
+    if let Some(JSValue::String(stack)) = data
+        .slots()
+        .contains(&InternalSlotName::ErrorData)
+        .then(|| data.get_property(&JSObjectPropKey::String("stack".into())))
+        .flatten()
+        .and_then(|descriptor| descriptor.value.clone())
+    {
+        return output.push_str(&stack.to_string());
+    }
+
+    if seen.contains(object) {
+        return output.push_str("[Circular]");
+    }
+
+    if depth > MAX_DEPTH {
+        return output.push_str(if is_array { "[Array]" } else { "[Object]" });
+    }
+
+    seen.push(object.clone());
+
+    let entries = entries(&data, is_array, depth, seen);
+
+    seen.pop();
+
+    let (open, close) = if is_array { ('[', ']') } else { ('{', '}') };
+
+    if entries.is_empty() {
+        output.push(open);
+        output.push(close);
+    } else {
+        let _ = write!(output, "{open} {} {close}", entries.join(", "));
+    }
+}
+
+/// The rendering of each own enumerable property of the object, which leaves out the keys of the elements of an array, and shows each run of its holes as the number of elements missing.
+fn entries(
+    data: &ObjectData,
+    is_array: bool,
+    depth: usize,
+    seen: &mut Vec<ObjectAddr>,
+) -> Vec<String> {
+    let length = if is_array {
+        data.get_property(&JSObjectPropKey::String("length".into()))
+            .and_then(|descriptor| descriptor.value.as_ref().and_then(JSValue::as_f64))
+            .unwrap_or_default() as u32
+    } else {
+        0
+    };
+
+    let mut entries = vec![];
+    // The index after that of the last element shown, from which the elements missing before the next one shown are holes.
+    let mut next_index = 0;
+
+    for (key, descriptor) in data.keys().zip(data.values()) {
+        let mut entry = String::new();
+
+        let index = key.as_array_index().filter(|_| is_array);
+
+        // NOTE: The elements of an array come before its other properties, so the holes after its last element are shown before them.
+        push_holes(
+            &mut entries,
+            index.unwrap_or(length).saturating_sub(next_index),
+        );
+
+        next_index = index.map_or(next_index.max(length), |index| index + 1);
+
+        match key.as_ref() {
+            _ if index.is_some() => {}
+            JSObjectPropKey::String(key) => write_key(&mut entry, &key.to_string()),
+            JSObjectPropKey::Symbol(symbol) => {
+                entry.push('[');
+                write_symbol(&mut entry, symbol);
+                entry.push_str("]: ");
+            }
+            JSObjectPropKey::PrivateName(_) => continue,
+        }
+
+        if descriptor.enumerable != Some(true) {
+            continue;
+        }
+
+        let has_get = descriptor
+            .get
+            .as_ref()
+            .is_some_and(|get| !get.is_undefined());
+        let has_set = descriptor
+            .set
+            .as_ref()
+            .is_some_and(|set| !set.is_undefined());
+
+        match (&descriptor.value, has_get, has_set) {
+            (Some(value), _, _) => write_value(&mut entry, value, depth + 1, seen),
+            (None, true, true) => entry.push_str("[Getter/Setter]"),
+            (None, true, false) => entry.push_str("[Getter]"),
+            (None, false, _) => entry.push_str("[Setter]"),
+        }
+
+        entries.push(entry);
+    }
+
+    push_holes(&mut entries, length.saturating_sub(next_index));
+
+    entries
+}
+
+/// Adds an entry for a run of holes of an array, if there are any.
+fn push_holes(entries: &mut Vec<String>, count: u32) {
+    match count {
+        0 => {}
+        1 => entries.push(String::from("<1 empty item>")),
+        count => entries.push(format!("<{count} empty items>")),
+    }
+}
+
+/// Writes the key of a property followed by a colon, quoting it unless it is an identifier.
+fn write_key(output: &mut String, key: &str) {
+    let mut chars = key.chars();
+
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        output.push_str(key);
+    } else {
+        write_string(output, key);
+    }
+
+    output.push_str(": ");
+}
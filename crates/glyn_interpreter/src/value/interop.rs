@@ -0,0 +1,199 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    abstract_ops::{
+        array_exotic_objects::array_create,
+        object_operations::{create_data_property_or_throw, get},
+        ordinary::ordinary_object_create,
+    },
+    runtime::{
+        agent::{type_error, JSAgent},
+        completion::{CompletionRecord, ThrowCompletion},
+    },
+    value::{
+        atom::Atom,
+        object::{
+            property::JSObjectPropKey, ObjectAddr, ObjectEssentialInternalMethods, ObjectKind,
+            ObjectMeta,
+        },
+    },
+    JSValue,
+};
+
+impl JSValue {
+    /// Converts the value into JSON, reading the enumerable own string-keyed properties of objects and the elements of arrays with their getters.
+    ///
+    /// Undefined and numbers which are not finite become null, as they do in `JSON.stringify`, and integral numbers become integers so that they can be deserialized as Rust integers. Symbols, functions and objects which contain themselves throw a TypeError.
+    pub fn to_json(&self, agent: &mut JSAgent) -> Result<Value, ThrowCompletion> {
+        to_json(agent, self, &mut vec![])
+    }
+
+    /// Creates the value the JSON describes in the realm of the agent, with a new array or object for each array or object.
+    pub fn from_json(agent: &mut JSAgent, json: &Value) -> JSValue {
+        agent.initialize_realm();
+
+        from_json(agent, json)
+    }
+}
+
+/// Converts a Rust value into a script value by way of its JSON representation, as with [`JSValue::from_json`].
+pub fn to_js<T: Serialize>(agent: &mut JSAgent, value: &T) -> Result<JSValue, ThrowCompletion> {
+    match serde_json::to_value(value) {
+        Ok(json) => Ok(JSValue::from_json(agent, &json)),
+        Err(error) => type_error(&error.to_string()),
+    }
+}
+
+/// Converts a script value into a Rust value by way of its JSON representation, as with [`JSValue::to_json`].
+pub fn from_js<T: DeserializeOwned>(
+    agent: &mut JSAgent,
+    value: &JSValue,
+) -> Result<T, ThrowCompletion> {
+    let json = value.to_json(agent)?;
+
+    match serde_json::from_value(json) {
+        Ok(value) => Ok(value),
+        Err(error) => type_error(&error.to_string()),
+    }
+}
+
+/// The largest integer which a Number can hold exactly, which is 2**53 - 1.
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+fn to_json(
+    agent: &mut JSAgent,
+    value: &JSValue,
+    stack: &mut Vec<ObjectAddr>,
+) -> CompletionRecord<Value> {
+    let object = match value {
+        JSValue::Undefined | JSValue::Null => return Ok(Value::Null),
+        JSValue::Bool(value) => return Ok(Value::Bool(*value)),
+        JSValue::Number(number) => return Ok(number_to_json(number.0)),
+        JSValue::String(string) => return Ok(Value::String(string.to_string())),
+        JSValue::Object(object) if !object.is_callable() => object,
+        _ => return type_error(&format!("{} cannot be converted to JSON", value.preview())),
+    };
+
+    // NOTE: The objects being converted are tracked like the stack of SerializeJSONObject, so that a cycle throws rather than recursing forever.
+    if stack.contains(object) {
+        return type_error(&format!(
+            "{} cannot be converted to JSON, as it contains itself",
+            value.preview()
+        ));
+    }
+
+    stack.push(object.clone());
+
+    let json = if object.kind() == ObjectKind::Array {
+        array_to_json(agent, object, stack)
+    } else {
+        object_to_json(agent, object, stack)
+    };
+
+    stack.pop();
+
+    json
+}
+
+fn number_to_json(number: f64) -> Value {
+    // NOTE: -0 is converted to 0, as in JSON.stringify.
+    if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER {
+        return Value::from(number as i64);
+    }
+
+    Number::from_f64(number).map_or(Value::Null, Value::Number)
+}
+
+fn array_to_json(
+    agent: &mut JSAgent,
+    object: &ObjectAddr,
+    stack: &mut Vec<ObjectAddr>,
+) -> CompletionRecord<Value> {
+    let length = get(agent, object, &JSObjectPropKey::String("length".into()))?;
+    let length = length.as_f64().unwrap_or_default() as u32;
+
+    let mut elements = Vec::with_capacity(length as usize);
+
+    for index in 0..length {
+        let element = get(agent, object, &JSObjectPropKey::String(Atom::from(index)))?;
+
+        elements.push(to_json(agent, &element, stack)?);
+    }
+
+    Ok(Value::Array(elements))
+}
+
+fn object_to_json(
+    agent: &mut JSAgent,
+    object: &ObjectAddr,
+    stack: &mut Vec<ObjectAddr>,
+) -> CompletionRecord<Value> {
+    let mut properties = Map::new();
+
+    for key in object.own_property_keys() {
+        let JSObjectPropKey::String(atom) = &key else {
+            continue;
+        };
+
+        // NOTE: A getter which ran for an earlier property may have deleted or hidden this one.
+        if object
+            .get_own_property(&key)?
+            .is_none_or(|desc| desc.enumerable != Some(true))
+        {
+            continue;
+        }
+
+        let value = get(agent, object, &key)?;
+
+        properties.insert(atom.to_string(), to_json(agent, &value, stack)?);
+    }
+
+    Ok(Value::Object(properties))
+}
+
+fn from_json(agent: &mut JSAgent, json: &Value) -> JSValue {
+    match json {
+        Value::Null => JSValue::Null,
+        Value::Bool(value) => JSValue::Bool(*value),
+        Value::Number(number) => JSValue::from(number.as_f64().unwrap_or(f64::NAN)),
+        Value::String(string) => JSValue::from(string.clone()),
+        Value::Array(elements) => {
+            // NOTE: An empty array can always be created, and elements are added to it one by one.
+            let array = array_create(agent, 0, None).unwrap();
+
+            for (index, element) in elements.iter().enumerate() {
+                let element = from_json(agent, element);
+
+                create_data_property(&array, Atom::from(index as u32), element);
+            }
+
+            JSValue::from(array)
+        }
+        Value::Object(properties) => {
+            let object = ordinary_object_create(
+                agent,
+                agent
+                    .current_realm()
+                    .borrow()
+                    .intrinsics
+                    .object_prototype
+                    .clone(),
+                None,
+            );
+
+            for (key, value) in properties {
+                let value = from_json(agent, value);
+
+                create_data_property(&object, Atom::from(key.as_str()), value);
+            }
+
+            JSValue::from(object)
+        }
+    }
+}
+
+fn create_data_property(object: &ObjectAddr, key: Atom, value: JSValue) {
+    // NOTE: The object is a new extensible object, so creating a data property on it can't fail.
+    create_data_property_or_throw(object, &JSObjectPropKey::String(key), value).unwrap();
+}
@@ -1,15 +1,27 @@
+use crate::abstract_ops::type_conversion::to_string;
+use crate::gc::{Trace, Tracer};
+use crate::runtime::agent::JSAgent;
+use crate::runtime::completion::ThrowCompletion;
 use crate::value::big_int::JSBigInt;
 use crate::value::number::JSNumber;
 use crate::value::object::ObjectAddr;
 use crate::value::string::JSString;
 use crate::value::symbol::JSSymbol;
 
+pub(crate) mod atom;
 pub(crate) mod big_int;
+pub(crate) mod inspect;
+#[cfg(feature = "serde")]
+pub(crate) mod interop;
 pub(crate) mod number;
 pub(crate) mod object;
+pub(crate) mod preview;
 pub(crate) mod string;
 pub(crate) mod symbol;
 
+/// A value of one of the ECMAScript language types.
+///
+/// The payload of each variant is exported so that values can be matched on, but other than the `f64` of a [`JSNumber`] it is opaque, and is compared, printed and converted with the traits it implements.
 #[derive(Clone, Debug, PartialEq)]
 pub enum JSValue {
     /// 6.1.1 The Undefined Type
@@ -29,37 +41,83 @@ pub enum JSValue {
 }
 
 impl JSValue {
-    pub(crate) fn is_undefined(&self) -> bool {
+    /// The undefined value.
+    pub fn undefined() -> Self {
+        JSValue::Undefined
+    }
+
+    /// The null value.
+    pub fn null() -> Self {
+        JSValue::Null
+    }
+
+    pub fn is_undefined(&self) -> bool {
         self == &JSValue::Undefined
     }
 
-    pub(crate) fn is_null(&self) -> bool {
+    pub fn is_null(&self) -> bool {
         self == &JSValue::Null
     }
 
-    pub(crate) fn is_boolean(&self) -> bool {
+    pub fn is_boolean(&self) -> bool {
         matches!(self, JSValue::Bool(_))
     }
 
-    pub(crate) fn is_string(&self) -> bool {
+    pub fn is_string(&self) -> bool {
         matches!(self, JSValue::String(_))
     }
 
-    pub(crate) fn is_number(&self) -> bool {
+    pub fn is_number(&self) -> bool {
         matches!(self, JSValue::Number(_))
     }
 
-    pub(crate) fn is_big_int(&self) -> bool {
+    pub fn is_big_int(&self) -> bool {
         matches!(self, JSValue::BigInt(_))
     }
 
-    pub(crate) fn is_object(&self) -> bool {
+    pub fn is_object(&self) -> bool {
         matches!(self, JSValue::Object(_))
     }
 
-    pub(crate) fn is_symbol(&self) -> bool {
+    pub fn is_symbol(&self) -> bool {
         matches!(self, JSValue::Symbol(_))
     }
+
+    /// The value of a Number, or None for any other type of value.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JSValue::Number(number) => Some(number.0),
+            _ => None,
+        }
+    }
+
+    /// The contents of a String converted to UTF-8, with each lone surrogate replaced by U+FFFD (REPLACEMENT CHARACTER), or None for any other type of value.
+    ///
+    /// NOTE: There is no `as_str`, as a String is held as UTF-16 code units, often split across the pieces of a rope, so there is no UTF-8 text to borrow from it.
+    pub fn to_rust_string(&self) -> Option<String> {
+        match self {
+            JSValue::String(string) => Some(string.to_string()),
+            _ => None,
+        }
+    }
+
+    /// The value as a script would print it, which is the result of ToString for every type of value except a Symbol, which is shown as its descriptive string like `Symbol(tag)`.
+    ///
+    /// Converting an object calls its `toString` or `valueOf` method, so the conversion throws whatever those methods throw.
+    pub fn to_display_string(&self, agent: &mut JSAgent) -> Result<String, ThrowCompletion> {
+        match self {
+            JSValue::Symbol(symbol) => Ok(symbol.descriptive_string()),
+            value => Ok(to_string(agent, value.clone())?.to_string()),
+        }
+    }
+}
+
+impl Trace for JSValue {
+    fn trace(&self, tracer: &mut Tracer) {
+        if let JSValue::Object(object) = self {
+            object.trace(tracer);
+        }
+    }
 }
 
 impl JSValue {
@@ -110,6 +168,12 @@ impl From<JSNumber> for JSValue {
     }
 }
 
+impl From<&str> for JSValue {
+    fn from(value: &str) -> Self {
+        JSValue::String(JSString::from(value))
+    }
+}
+
 impl From<String> for JSValue {
     fn from(value: String) -> Self {
         JSValue::String(JSString::from(value))
@@ -42,6 +42,27 @@ impl JSNumber {
         self.0.is_infinite()
     }
 
+    /// 7.1.7 ToUint32 ( argument )
+    /// https://262.ecma-international.org/16.0/#sec-touint32
+    pub(crate) fn to_uint32(&self) -> u32 {
+        // 2. If number is not finite or number is either +0𝔽 or -0𝔽, return +0𝔽.
+        if !self.is_finite() {
+            return 0;
+        }
+
+        // 3. Let int be truncate(ℝ(number)).
+        // 4. Let int32bit be int modulo 2**32.
+        // 5. Return 𝔽(int32bit).
+        self.0.trunc().rem_euclid(2f64.powi(32)) as u32
+    }
+
+    /// 7.1.6 ToInt32 ( argument )
+    /// https://262.ecma-international.org/16.0/#sec-toint32
+    pub(crate) fn to_int32(&self) -> i32 {
+        // 5. If int32bit ≥ 2**31, return 𝔽(int32bit - 2**32); otherwise return 𝔽(int32bit).
+        self.to_uint32() as i32
+    }
+
     pub(crate) fn is_pos_infinite(&self) -> bool {
         self.0.is_infinite() && self.0 > 0.0
     }
@@ -84,7 +105,7 @@ impl JSNumber {
     /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-bitwiseNOT
     pub(crate) fn bitwise_not(self) -> Self {
         // 1. Let oldValue be ! ToInt32(x).
-        let old_value = self.0 as i32;
+        let old_value = self.to_int32();
 
         // 2. Return the result of applying bitwise complement to oldValue.
         // The mathematical value of the result is exactly representable as a 32-bit two's complement bit string.
@@ -94,7 +115,21 @@ impl JSNumber {
     /// 6.1.6.1.3 Number::exponentiate ( base, exponent )
     /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-exponentiate
     pub(crate) fn exponentiate(self, other: &Self) -> Self {
-        // Rust and JavaScript follow IEEE 754 for floating point arithmetic, so we can use the built-in powf method.
+        // 1. If exponent is NaN, return NaN.
+        if other.is_nan() {
+            return JSNumber::NAN;
+        }
+
+        // 9. If exponent is +∞𝔽, then
+        // b. If abs(ℝ(base)) = 1, return NaN.
+        // 10. If exponent is -∞𝔽, then
+        // b. If abs(ℝ(base)) = 1, return NaN.
+        // NOTE: IEEE 754 defines pow(±1, ±∞) as 1 rather than NaN.
+        if self.0.abs() == 1.0 && other.is_infinite() {
+            return JSNumber::NAN;
+        }
+
+        // Rust and JavaScript otherwise follow IEEE 754 for floating point arithmetic, so we can use the built-in powf method.
         JSNumber(self.0.powf(other.0))
     }
 
@@ -180,10 +215,10 @@ impl JSNumber {
     /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-leftShift
     pub(crate) fn left_shift(self, other: Self) -> Self {
         // 1. Let lnum be ! ToInt32(x).
-        let lnum = self.0 as i32;
+        let lnum = self.to_int32();
 
         // 2. Let rnum be ! ToUint32(y).
-        let rnum = other.0 as u32;
+        let rnum = other.to_uint32();
 
         // 3. Let shiftCount be ℝ(rnum) modulo 32.
         let shift_count = rnum % 32;
@@ -197,10 +232,10 @@ impl JSNumber {
     /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-signedRightShift
     pub(crate) fn signed_right_shift(self, other: Self) -> Self {
         // 1. Let lnum be ! ToInt32(x).
-        let lnum = self.0 as i32;
+        let lnum = self.to_int32();
 
         // 2. Let rnum be ! ToUint32(y).
-        let rnum = other.0 as u32;
+        let rnum = other.to_uint32();
 
         // 3. Let shiftCount be ℝ(rnum) modulo 32.
         let shift_count = rnum % 32;
@@ -214,10 +249,10 @@ impl JSNumber {
     /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-unsignedRightShift
     pub(crate) fn unsigned_right_shift(self, other: Self) -> Self {
         // 1. Let lnum be ! ToUint32(x).
-        let lnum = self.0 as u32;
+        let lnum = self.to_uint32();
 
         // 2. Let rnum be ! ToUint32(y).
-        let rnum = other.0 as u32;
+        let rnum = other.to_uint32();
 
         // 3. Let shiftCount be ℝ(rnum) modulo 32.
         let shift_count = rnum % 32;
@@ -232,10 +267,10 @@ impl JSNumber {
     pub(crate) fn bitwise_and(self, other: Self) -> Self {
         // 6.1.6.1.16 NumberBitwiseOp ( op, x, y )
         // 1. Let lnum be ! ToInt32(x).
-        let lnum = self.0 as i32;
+        let lnum = self.to_int32();
 
         // 2. Let rnum be ! ToInt32(y).
-        let rnum = other.0 as i32;
+        let rnum = other.to_int32();
 
         // 1. Return NumberBitwiseOp(&, x, y).
         JSNumber((lnum & rnum) as f64)
@@ -246,10 +281,10 @@ impl JSNumber {
     pub(crate) fn bitwise_xor(self, other: Self) -> Self {
         // 6.1.6.1.16 NumberBitwiseOp ( op, x, y )
         // 1. Let lnum be ! ToInt32(x).
-        let lnum = self.0 as i32;
+        let lnum = self.to_int32();
 
         // 2. Let rnum be ! ToInt32(y).
-        let rnum = other.0 as i32;
+        let rnum = other.to_int32();
 
         // 1. Return NumberBitwiseOp(^, x, y).
         JSNumber((lnum ^ rnum) as f64)
@@ -260,10 +295,10 @@ impl JSNumber {
     pub(crate) fn bitwise_or(self, other: Self) -> Self {
         // 6.1.6.1.16 NumberBitwiseOp ( op, x, y )
         // 1. Let lnum be ! ToInt32(x).
-        let lnum = self.0 as i32;
+        let lnum = self.to_int32();
 
         // 2. Let rnum be ! ToInt32(y).
-        let rnum = other.0 as i32;
+        let rnum = other.to_int32();
 
         // 1. Return NumberBitwiseOp(|, x, y).
         JSNumber((lnum | rnum) as f64)
@@ -313,7 +348,7 @@ impl JSNumber {
     /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-samevalue
     pub(crate) fn same_value(&self, y: &Self) -> bool {
         // 1. If x is NaN and y is NaN, return true.
-        if self.is_nan() || y.is_nan() {
+        if self.is_nan() && y.is_nan() {
             return true;
         }
 
@@ -327,6 +362,22 @@ impl JSNumber {
         self == y
     }
 
+    /// 6.1.6.1.15 Number::sameValueZero ( x, y )
+    /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-samevaluezero
+    pub(crate) fn same_value_zero(&self, y: &Self) -> bool {
+        // 1. If x is NaN and y is NaN, return true.
+        if self.is_nan() && y.is_nan() {
+            return true;
+        }
+
+        // 2. If x is +0𝔽 and y is -0𝔽, return true.
+        // 3. If x is -0𝔽 and y is +0𝔽, return true.
+        // 4. If x is y, return true.
+        // 5. Return false.
+        // NOTE: Zeros of either sign compare as equal in IEEE 754, so comparing the values covers steps 2 to 5.
+        self.0 == y.0
+    }
+
     /// 6.1.6.1.20 Number::toString ( x, radix )
     /// https://262.ecma-international.org/16.0/#sec-numeric-types-number-tostring
     pub(crate) fn to_string(&self, radix: u32) -> JSString {
@@ -342,7 +393,7 @@ impl JSNumber {
 
         // 3. If x < -0𝔽, return the string-concatenation of "-" and Number::toString(-x, radix).
         if self.lt(&JSNumber::ZERO) {
-            return format!("-{:?}", self.clone().unary_minus().to_string(radix)).into();
+            return JSString::from("-").concat(&self.clone().unary_minus().to_string(radix));
         }
 
         // 4. If x is +∞𝔽, return "Infinity".
@@ -350,60 +401,170 @@ impl JSNumber {
             return "Infinity".into();
         }
 
+        // NOTE: The digits of a radix other than 10 are those of the shortest fraction which rounds to x, which is how other engines approximate step 5 when the digits can't be found from Rust's formatting.
+        if radix != 10 {
+            return JSString::from(radix_digits(self.0, radix));
+        }
+
         // 5. Let n, k, and s be integers such that k ≥ 1, radix**(k - 1) ≤ s < radix**k,
         // 𝔽(s × radix**(n - k)) is x, and k is as small as possible.
-        // For simplicity, we'll use a more direct approach for common cases
+        // NOTE: Rust formats the shortest digits which round trip to the same value in scientific notation, which gives s as the digits of the mantissa and n - 1 as the exponent.
+        let scientific = format!("{:e}", self.0);
+
+        let (mantissa, exponent) = scientific.split_once('e').unwrap();
+
+        let digits = mantissa.replace('.', "");
+
+        let k = digits.len() as i32;
+        let n = exponent.parse::<i32>().unwrap() + 1;
+
         // 6. If radix ≠ 10 or n is in the inclusive interval from -5 to 21, then
-        // a. If n ≥ k, then
-        // i. Return the string-concatenation of:
-        // the code units of the k digits of the representation of s using radix radix
-        // n - k occurrences of the code unit 0x0030 (DIGIT ZERO)
-        // b. Else if n > 0, then
-        // i. Return the string-concatenation of:
-        // the code units of the most significant n digits of the representation of s using radix radix
-        // the code unit 0x002E (FULL STOP)
-        // the code units of the remaining k - n digits of the representation of s using radix radix
-        // c. Else,
-        // i. Assert: n ≤ 0.
-        // ii. Return the string-concatenation of:
-        // the code unit 0x0030 (DIGIT ZERO)
-        // the code unit 0x002E (FULL STOP)
-        // -n occurrences of the code unit 0x0030 (DIGIT ZERO)
-        // the code units of the k digits of the representation of s using radix radix
+        if (-5..=21).contains(&n) {
+            // a. If n ≥ k, then
+            if n >= k {
+                // i. Return the string-concatenation of:
+                // the code units of the k digits of the representation of s using radix radix
+                // n - k occurrences of the code unit 0x0030 (DIGIT ZERO)
+                return format!("{digits}{}", "0".repeat((n - k) as usize)).into();
+            }
+
+            // b. Else if n > 0, then
+            if n > 0 {
+                // i. Return the string-concatenation of:
+                // the code units of the most significant n digits of the representation of s using radix radix
+                // the code unit 0x002E (FULL STOP)
+                // the code units of the remaining k - n digits of the representation of s using radix radix
+                let (integer, fraction) = digits.split_at(n as usize);
+
+                return format!("{integer}.{fraction}").into();
+            }
+
+            // c. Else,
+            // i. Assert: n ≤ 0.
+            // ii. Return the string-concatenation of:
+            // the code unit 0x0030 (DIGIT ZERO)
+            // the code unit 0x002E (FULL STOP)
+            // -n occurrences of the code unit 0x0030 (DIGIT ZERO)
+            // the code units of the k digits of the representation of s using radix radix
+            return format!("0.{}{digits}", "0".repeat(-n as usize)).into();
+        }
+
         // 7. NOTE: In this case, the input will be represented using scientific E notation, such as 1.2e+3.
         // 8. Assert: radix is 10.
         // 9. If n < 0, then
         // a. Let exponentSign be the code unit 0x002D (HYPHEN-MINUS).
         // 10. Else,
         // a. Let exponentSign be the code unit 0x002B (PLUS SIGN).
+        let exponent_sign = if n < 0 { '-' } else { '+' };
+
         // 11. If k = 1, then
-        // a. Return the string-concatenation of:
-        // the code unit of the single digit of s
-        // the code unit 0x0065 (LATIN SMALL LETTER E)
-        // exponentSign
-        // the code units of the decimal representation of abs(n - 1).
+        if k == 1 {
+            // a. Return the string-concatenation of:
+            // the code unit of the single digit of s
+            // the code unit 0x0065 (LATIN SMALL LETTER E)
+            // exponentSign
+            // the code units of the decimal representation of abs(n - 1).
+            return format!("{digits}e{exponent_sign}{}", (n - 1).abs()).into();
+        }
+
         // 12. Return the string-concatenation of:
         // the code unit of the most significant digit of the decimal representation of s
         // the code unit 0x002E (FULL STOP)
         // the code units of the remaining k - 1 digits of the decimal representation of s
         // the code unit 0x0065 (LATIN SMALL LETTER E)
         // exponentSign
+        // the code units of the decimal representation of abs(n - 1).
+        let (first, rest) = digits.split_at(1);
 
-        // TODO Parse the above exactly
-
-        JSString::from(self.0.to_string())
+        format!("{first}.{rest}e{exponent_sign}{}", (n - 1).abs()).into()
     }
 }
 
-impl TryFrom<JSString> for JSNumber {
-    type Error = ThrowCompletion;
+/// The digits of a positive finite number in a radix other than 10, without an exponent.
+///
+/// The fraction has as many digits as tell the number apart from its neighbouring doubles, and the integer has zeros in place of the digits which are below its precision.
+fn radix_digits(value: f64, radix: u32) -> String {
+    let radix_f64 = radix as f64;
+    let digit = |digit: u32| char::from_digit(digit, radix).unwrap();
+
+    let mut integer = value.floor();
+    let mut fraction = value - integer;
+
+    // Half the distance to the next double, which a fraction digit must be larger than to be written.
+    let mut delta = (0.5 * (f64::from_bits(value.to_bits() + 1) - value)).max(f64::from_bits(1));
+
+    let mut fraction_digits = vec![];
+
+    if fraction >= delta {
+        loop {
+            fraction *= radix_f64;
+            delta *= radix_f64;
+
+            let fraction_digit = fraction as u32;
+
+            fraction_digits.push(fraction_digit);
+            fraction -= fraction_digit as f64;
+
+            // The digits are rounded half to even, carrying the rounding up into the digits before.
+            if (fraction > 0.5 || (fraction == 0.5 && fraction_digit & 1 == 1))
+                && fraction + delta > 1.0
+            {
+                loop {
+                    match fraction_digits.pop() {
+                        Some(previous) if previous + 1 < radix => {
+                            fraction_digits.push(previous + 1);
+
+                            break;
+                        }
+                        Some(_) => {}
+                        None => {
+                            integer += 1.0;
+
+                            break;
+                        }
+                    }
+                }
+
+                break;
+            }
+
+            if fraction < delta {
+                break;
+            }
+        }
+    }
+
+    let mut integer_digits = vec![];
+
+    // The digits below the precision of a double are zeros.
+    while integer / radix_f64 >= 2f64.powi(53) {
+        integer /= radix_f64;
+        integer_digits.push(0);
+    }
 
-    fn try_from(value: JSString) -> Result<Self, Self::Error> {
-        match value.0.parse::<f64>() {
-            Ok(number) => Ok(JSNumber(number)),
-            Err(_) => throw_completion(&format!("Invalid number conversion: {}", value.0)),
+    loop {
+        let remainder = integer % radix_f64;
+
+        integer_digits.push(remainder as u32);
+        integer = (integer - remainder) / radix_f64;
+
+        if integer <= 0.0 {
+            break;
         }
     }
+
+    let mut digits = integer_digits
+        .into_iter()
+        .rev()
+        .map(digit)
+        .collect::<String>();
+
+    if !fraction_digits.is_empty() {
+        digits.push('.');
+        digits.extend(fraction_digits.into_iter().map(digit));
+    }
+
+    digits
 }
 
 impl TryFrom<JSValue> for JSNumber {
@@ -412,7 +573,10 @@ impl TryFrom<JSValue> for JSNumber {
     fn try_from(value: JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::Number(number) => Ok(number),
-            _ => throw_completion("Expected JSValue::Number for conversion to JSNumber"),
+            _ => throw_completion(&format!(
+                "Expected JSValue::Number for conversion to JSNumber, but got {}",
+                value.preview()
+            )),
         }
     }
 }
@@ -423,7 +587,10 @@ impl TryFrom<&JSValue> for JSNumber {
     fn try_from(value: &JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::Number(number) => Ok(number.clone()),
-            _ => throw_completion("Expected JSValue::Number for conversion to JSNumber"),
+            _ => throw_completion(&format!(
+                "Expected JSValue::Number for conversion to JSNumber, but got {}",
+                value.preview()
+            )),
         }
     }
 }
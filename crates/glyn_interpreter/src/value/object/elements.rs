@@ -0,0 +1,264 @@
+use std::{borrow::Cow, collections::BTreeMap, mem::size_of};
+
+use crate::{
+    gc::{Trace, Tracer},
+    value::{object::property::JSObjectPropDescriptor, JSValue},
+};
+
+/// The own properties of an object whose keys are array indices, which are held apart from the other properties so that they are found by their index and kept in ascending index order.
+///
+/// The elements start out packed, holding the value of each element from index 0 without any holes, which is how array literals and appending to an array create them. Creating a hole, deleting an element other than the last, or giving an element attributes other than the defaults of an assignment, switches the elements to a map of their descriptors.
+#[derive(Debug)]
+pub(crate) enum Elements {
+    /// The values of the elements at indices 0 up to the length of the list, each a data property which is writable, enumerable and configurable.
+    Packed(Vec<JSValue>),
+    Sparse(BTreeMap<u32, JSObjectPropDescriptor>),
+}
+
+impl Default for Elements {
+    fn default() -> Self {
+        Elements::Packed(vec![])
+    }
+}
+
+/// Whether the descriptor is of an element which can be held packed, which is a data property whose attributes are all true.
+fn is_packable(descriptor: &JSObjectPropDescriptor) -> bool {
+    descriptor.value.is_some()
+        && descriptor.writable == Some(true)
+        && descriptor.enumerable == Some(true)
+        && descriptor.configurable == Some(true)
+}
+
+fn packed_descriptor(value: &JSValue) -> JSObjectPropDescriptor {
+    JSObjectPropDescriptor {
+        value: Some(value.clone()),
+        writable: Some(true),
+        enumerable: Some(true),
+        configurable: Some(true),
+        ..JSObjectPropDescriptor::default()
+    }
+}
+
+impl Elements {
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Elements::Packed(values) => values.len(),
+            Elements::Sparse(descriptors) => descriptors.len(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        match self {
+            Elements::Packed(values) => values.is_empty(),
+            Elements::Sparse(descriptors) => descriptors.is_empty(),
+        }
+    }
+
+    pub(crate) fn get(&self, index: u32) -> Option<Cow<'_, JSObjectPropDescriptor>> {
+        match self {
+            Elements::Packed(values) => values
+                .get(index as usize)
+                .map(|value| Cow::Owned(packed_descriptor(value))),
+            Elements::Sparse(descriptors) => descriptors.get(&index).map(Cow::Borrowed),
+        }
+    }
+
+    pub(crate) fn contains(&self, index: u32) -> bool {
+        match self {
+            Elements::Packed(values) => (index as usize) < values.len(),
+            Elements::Sparse(descriptors) => descriptors.contains_key(&index),
+        }
+    }
+
+    /// The indices of the elements in ascending order.
+    pub(crate) fn indices(&self) -> Box<dyn Iterator<Item = u32> + '_> {
+        match self {
+            Elements::Packed(values) => Box::new(0..values.len() as u32),
+            Elements::Sparse(descriptors) => Box::new(descriptors.keys().copied()),
+        }
+    }
+
+    /// Iterates over the elements in ascending index order.
+    pub(crate) fn iter(
+        &self,
+    ) -> Box<dyn Iterator<Item = (u32, Cow<'_, JSObjectPropDescriptor>)> + '_> {
+        match self {
+            Elements::Packed(values) => Box::new(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (index as u32, Cow::Owned(packed_descriptor(value)))),
+            ),
+            Elements::Sparse(descriptors) => Box::new(
+                descriptors
+                    .iter()
+                    .map(|(&index, descriptor)| (index, Cow::Borrowed(descriptor))),
+            ),
+        }
+    }
+
+    /// Replaces the element at the index, or adds it.
+    pub(crate) fn insert(&mut self, index: u32, descriptor: JSObjectPropDescriptor) {
+        if let Elements::Packed(values) = self {
+            let index = index as usize;
+
+            if is_packable(&descriptor) && index <= values.len() {
+                let value = descriptor.value.unwrap();
+
+                match values.get_mut(index) {
+                    Some(element) => *element = value,
+                    None => values.push(value),
+                }
+
+                return;
+            }
+
+            self.unpack();
+        }
+
+        if let Elements::Sparse(descriptors) = self {
+            descriptors.insert(index, descriptor);
+        }
+    }
+
+    /// Removes the element at the index, returning whether there was one.
+    pub(crate) fn remove(&mut self, index: u32) -> bool {
+        if !self.contains(index) {
+            return false;
+        }
+
+        if let Elements::Packed(values) = self {
+            if index as usize == values.len() - 1 {
+                values.pop();
+
+                return true;
+            }
+
+            self.unpack();
+        }
+
+        if let Elements::Sparse(descriptors) = self {
+            descriptors.remove(&index);
+        }
+
+        true
+    }
+
+    /// Removes every element, keeping the buffer of packed elements so that a new object can grow into it.
+    pub(crate) fn reset(&mut self) {
+        match self {
+            Elements::Packed(values) => values.clear(),
+            Elements::Sparse(_) => *self = Elements::default(),
+        }
+    }
+
+    fn unpack(&mut self) {
+        let Elements::Packed(values) = self else {
+            return;
+        };
+
+        // NOTE: The values are taken out so that replacing them does not record their buffer as freed, which the caller records along with the growth of the map.
+        let descriptors = std::mem::take(values)
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (index as u32, packed_descriptor(value)))
+            .collect();
+
+        *self = Elements::Sparse(descriptors);
+    }
+
+    /// The bytes taken up by the buffer of the elements.
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            Elements::Packed(values) => values.capacity() * size_of::<JSValue>(),
+            Elements::Sparse(descriptors) => {
+                descriptors.len() * size_of::<(u32, JSObjectPropDescriptor)>()
+            }
+        }
+    }
+}
+
+impl Trace for Elements {
+    fn trace(&self, tracer: &mut Tracer) {
+        match self {
+            Elements::Packed(values) => values.trace(tracer),
+            Elements::Sparse(descriptors) => descriptors
+                .values()
+                .for_each(|descriptor| descriptor.trace(tracer)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(values: &[f64]) -> Elements {
+        let mut elements = Elements::default();
+
+        for (index, value) in values.iter().enumerate() {
+            elements.insert(index as u32, packed_descriptor(&JSValue::from(*value)));
+        }
+
+        elements
+    }
+
+    fn values(elements: &Elements) -> Vec<(u32, Option<JSValue>)> {
+        elements
+            .iter()
+            .map(|(index, descriptor)| (index, descriptor.value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn appended_elements_stay_packed() {
+        let mut elements = elements(&[1.0, 2.0, 3.0]);
+
+        elements.insert(1, packed_descriptor(&JSValue::from(4.0)));
+
+        assert!(matches!(elements, Elements::Packed(_)));
+        assert_eq!(
+            elements.get(1).map(Cow::into_owned),
+            Some(packed_descriptor(&JSValue::from(4.0)))
+        );
+
+        assert!(elements.remove(2));
+        assert!(!elements.remove(2));
+        assert!(matches!(elements, Elements::Packed(_)));
+        assert_eq!(elements.indices().collect::<Vec<_>>(), vec![0, 1]);
+    }
+
+    #[test]
+    fn holes_and_attributes_switch_to_sparse() {
+        let mut holes = elements(&[1.0, 2.0, 3.0]);
+
+        assert!(holes.remove(1));
+        assert!(matches!(holes, Elements::Sparse(_)));
+        assert_eq!(
+            values(&holes),
+            vec![(0, Some(JSValue::from(1.0))), (2, Some(JSValue::from(3.0)))]
+        );
+
+        let mut gap = elements(&[1.0]);
+
+        gap.insert(3, packed_descriptor(&JSValue::from(2.0)));
+        gap.insert(2, packed_descriptor(&JSValue::from(3.0)));
+
+        assert!(matches!(gap, Elements::Sparse(_)));
+        assert_eq!(gap.indices().collect::<Vec<_>>(), vec![0, 2, 3]);
+
+        let mut read_only = elements(&[1.0, 2.0]);
+
+        read_only.insert(
+            0,
+            JSObjectPropDescriptor {
+                writable: Some(false),
+                ..packed_descriptor(&JSValue::from(1.0))
+            },
+        );
+
+        assert!(matches!(read_only, Elements::Sparse(_)));
+        assert_eq!(read_only.get(0).unwrap().writable, Some(false));
+        assert_eq!(read_only.get(1).unwrap().writable, Some(true));
+    }
+}
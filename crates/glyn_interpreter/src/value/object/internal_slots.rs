@@ -1,31 +1,167 @@
-use std::collections::HashMap;
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::{
-    runtime::{environment::EnvironmentAddr, realm::RealmAddr},
+    abstract_ops::{
+        arguments_exotic_objects::ParameterMap, module_namespace_exotic_objects::ModuleNamespace,
+    },
+    codegen::bytecode::generator::ExecutableFunction,
+    gc::{Trace, Tracer},
+    runtime::{
+        agent::JSAgent,
+        completion::CompletionRecord,
+        environment::EnvironmentAddr,
+        generator::{GeneratorContext, GeneratorState},
+        iteration::{ArrayIterator, ForInIterator, StringIterator},
+        native_function::{Args, NativeFunction},
+        promise::{PromiseReaction, PromiseReactionType, PromiseState},
+        realm::RealmAddr,
+    },
     value::object::ObjectAddr,
-    value::{string::JSString, JSValue},
+    value::{number::JSNumber, string::JSString, symbol::JSSymbol, JSValue},
 };
 
-pub(crate) type BehaviourFn = fn(Vec<JSValue>) -> JSValue;
+/// The steps of a built-in function object, called with the this value and the arguments list.
+///
+/// The steps are reference counted rather than being a plain function pointer, so that any state captured by a closure is owned by the function object.
+#[derive(Clone)]
+pub(crate) struct BehaviourFn(Rc<dyn NativeFunction>);
+
+impl BehaviourFn {
+    pub(crate) fn new(behaviour: impl NativeFunction) -> Self {
+        Self(Rc::new(behaviour))
+    }
+
+    pub(crate) fn call(&self, agent: &mut JSAgent, args: Args) -> CompletionRecord<JSValue> {
+        (self.0)(agent, args)
+    }
+}
+
+impl std::fmt::Debug for BehaviourFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("BehaviourFn")
+    }
+}
+
+/// The Rust value the embedder attached to an object, like an instance of a class registered with [`JSAgent::register_class`].
+///
+/// The value is held in a reference counted cell so that it can be borrowed while a host function runs without borrowing the object itself, as the function can run scripts which reach the object.
+#[derive(Clone)]
+pub(crate) struct HostData(Rc<dyn Any>);
+
+impl HostData {
+    pub(crate) fn new<T: Any>(value: T) -> Self {
+        Self(Rc::new(RefCell::new(value)))
+    }
+
+    /// The cell holding the value, or None if the value is of another type.
+    pub(crate) fn downcast<T: Any>(&self) -> Option<Rc<RefCell<T>>> {
+        self.0.clone().downcast::<RefCell<T>>().ok()
+    }
+}
+
+impl std::fmt::Debug for HostData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("HostData")
+    }
+}
+
+/// [[ConstructorKind]]
+/// https://262.ecma-international.org/16.0/#table-internal-slots-of-ecmascript-function-objects
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ConstructorKind {
+    Base,
+    // NOTE: Derived constructors need classes, which are not supported yet.
+}
+
+/// [[ThisMode]]
+/// https://262.ecma-international.org/16.0/#table-internal-slots-of-ecmascript-function-objects
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ThisMode {
+    Lexical,
+    Strict,
+    Global,
+}
 
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub(crate) enum InternalSlotName {
+    /// The state of the closure of an Array Iterator, see [`ArrayIterator`].
+    ArrayIterator,
     BehaviourFn,
+    BooleanData,
+    ConstructorKind,
+    DateValue,
+    ECMAScriptCode,
+    ErrorData,
+    /// [[Object]], [[ObjectWasVisited]], [[VisitedKeys]] and [[RemainingKeys]] of a for-in iterator, which are held together.
+    ForInIterator,
+    GeneratorBrand,
+    GeneratorContext,
+    GeneratorState,
     HomeObject,
+    /// The Rust value of an object created by the embedder, see [`HostData`].
+    HostData,
     InitialName,
+    /// [[Module]] and [[Exports]] of a module namespace object, which are held together, see [`ModuleNamespace`].
+    ModuleNamespace,
+    NumberData,
+    /// The formal parameters which the indices of a mapped arguments object alias, see [`ParameterMap`].
+    ParameterMap,
+    PromiseFulfillReactions,
+    PromiseIsHandled,
+    PromiseRejectReactions,
+    PromiseResult,
+    PromiseState,
+    /// The name a built-in function is reached by from the global object, like `Array.prototype.values`, which errors about the arguments it is called with are reported with.
+    QualifiedName,
     Realm,
     Environment,
+    Strict,
+    StringData,
+    /// The state of the closure of a String Iterator, see [`StringIterator`].
+    StringIterator,
+    SymbolData,
+    ThisMode,
 }
 
 #[derive(Debug)]
 pub(crate) enum InternalSlotValue {
+    ArrayIterator(ArrayIterator),
     BehaviourFn(BehaviourFn),
+    ConstructorKind(ConstructorKind),
+    ECMAScriptCode(Rc<ExecutableFunction>),
+    ForInIterator(ForInIterator),
+    GeneratorContext(Box<GeneratorContext>),
+    GeneratorState(GeneratorState),
+    HostData(HostData),
+    ModuleNamespace(ModuleNamespace),
+    ParameterMap(ParameterMap),
+    PromiseReactions(Vec<PromiseReaction>),
+    PromiseState(PromiseState),
     Realm(RealmAddr),
     Environment(EnvironmentAddr),
+    StringIterator(StringIterator),
+    ThisMode(ThisMode),
     Value(JSValue),
     NotSet,
 }
 
+impl Trace for InternalSlotValue {
+    fn trace(&self, tracer: &mut Tracer) {
+        match self {
+            InternalSlotValue::ArrayIterator(iterator) => iterator.trace(tracer),
+            InternalSlotValue::ForInIterator(iterator) => iterator.trace(tracer),
+            InternalSlotValue::GeneratorContext(context) => context.trace(tracer),
+            InternalSlotValue::ParameterMap(map) => map.trace(tracer),
+            InternalSlotValue::PromiseReactions(reactions) => reactions.trace(tracer),
+            InternalSlotValue::Realm(realm_addr) => realm_addr.trace(tracer),
+            InternalSlotValue::Environment(env_addr) => env_addr.trace(tracer),
+            InternalSlotValue::Value(value) => value.trace(tracer),
+            // NOTE: The state captured by a closure or held by a module namespace is shared, so it is not reported.
+            _ => {}
+        }
+    }
+}
+
 impl From<JSValue> for InternalSlotValue {
     fn from(value: JSValue) -> Self {
         InternalSlotValue::Value(value)
@@ -37,6 +173,12 @@ impl From<JSValue> for InternalSlotValue {
 #[derive(Debug, Default)]
 pub(crate) struct InternalSlots(HashMap<InternalSlotName, InternalSlotValue>);
 
+impl Trace for InternalSlots {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.0.values().for_each(|value| value.trace(tracer));
+    }
+}
+
 impl InternalSlots {
     fn new() -> Self {
         Self(HashMap::new())
@@ -50,6 +192,16 @@ impl InternalSlots {
         self.0.get(name)
     }
 
+    /// Whether the object has no internal slots other than [[Prototype]] and [[Extensible]], which are held by the object itself.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether the object has the internal slot, whatever its value, like the [[ErrorData]] slot which only marks an object as an Error instance.
+    pub(crate) fn contains(&self, name: &InternalSlotName) -> bool {
+        self.0.contains_key(name)
+    }
+
     pub(crate) fn realm(&self) -> Option<&RealmAddr> {
         match self.get(&InternalSlotName::Realm) {
             Some(InternalSlotValue::Realm(realm_addr)) => Some(&realm_addr),
@@ -64,6 +216,10 @@ impl InternalSlots {
         );
     }
 
+    pub(crate) fn has_initial_name(&self) -> bool {
+        self.0.contains_key(&InternalSlotName::InitialName)
+    }
+
     pub(crate) fn initial_name(&self) -> Option<JSString> {
         match self.get(&InternalSlotName::InitialName) {
             Some(InternalSlotValue::Value(JSValue::String(name))) => Some(name.clone()),
@@ -76,9 +232,23 @@ impl InternalSlots {
             .insert(InternalSlotName::InitialName, JSValue::String(name).into());
     }
 
+    pub(crate) fn qualified_name(&self) -> Option<JSString> {
+        match self.get(&InternalSlotName::QualifiedName) {
+            Some(InternalSlotValue::Value(JSValue::String(name))) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_qualified_name(&mut self, name: JSString) {
+        self.0.insert(
+            InternalSlotName::QualifiedName,
+            JSValue::String(name).into(),
+        );
+    }
+
     pub(crate) fn behaviour_fn(&self) -> Option<BehaviourFn> {
         match self.get(&InternalSlotName::BehaviourFn) {
-            Some(InternalSlotValue::BehaviourFn(func)) => Some(*func),
+            Some(InternalSlotValue::BehaviourFn(func)) => Some(func.clone()),
             _ => None,
         }
     }
@@ -104,6 +274,323 @@ impl InternalSlots {
         );
     }
 
+    pub(crate) fn ecmascript_code(&self) -> Option<Rc<ExecutableFunction>> {
+        match self.get(&InternalSlotName::ECMAScriptCode) {
+            Some(InternalSlotValue::ECMAScriptCode(code)) => Some(code.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_ecmascript_code(&mut self, code: Rc<ExecutableFunction>) {
+        self.0.insert(
+            InternalSlotName::ECMAScriptCode,
+            InternalSlotValue::ECMAScriptCode(code),
+        );
+    }
+
+    pub(crate) fn take_for_in_iterator(&mut self) -> Option<ForInIterator> {
+        match self.0.remove(&InternalSlotName::ForInIterator) {
+            Some(InternalSlotValue::ForInIterator(iterator)) => Some(iterator),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_for_in_iterator(&mut self, iterator: ForInIterator) {
+        self.0.insert(
+            InternalSlotName::ForInIterator,
+            InternalSlotValue::ForInIterator(iterator),
+        );
+    }
+
+    pub(crate) fn take_array_iterator(&mut self) -> Option<ArrayIterator> {
+        match self.0.remove(&InternalSlotName::ArrayIterator) {
+            Some(InternalSlotValue::ArrayIterator(iterator)) => Some(iterator),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_array_iterator(&mut self, iterator: ArrayIterator) {
+        self.0.insert(
+            InternalSlotName::ArrayIterator,
+            InternalSlotValue::ArrayIterator(iterator),
+        );
+    }
+
+    pub(crate) fn take_string_iterator(&mut self) -> Option<StringIterator> {
+        match self.0.remove(&InternalSlotName::StringIterator) {
+            Some(InternalSlotValue::StringIterator(iterator)) => Some(iterator),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_string_iterator(&mut self, iterator: StringIterator) {
+        self.0.insert(
+            InternalSlotName::StringIterator,
+            InternalSlotValue::StringIterator(iterator),
+        );
+    }
+
+    /// [[GeneratorState]]
+    pub(crate) fn generator_state(&self) -> Option<GeneratorState> {
+        match self.get(&InternalSlotName::GeneratorState) {
+            Some(InternalSlotValue::GeneratorState(state)) => Some(*state),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_generator_state(&mut self, state: GeneratorState) {
+        self.0.insert(
+            InternalSlotName::GeneratorState,
+            InternalSlotValue::GeneratorState(state),
+        );
+    }
+
+    /// [[GeneratorContext]]
+    pub(crate) fn take_generator_context(&mut self) -> Option<GeneratorContext> {
+        match self.0.remove(&InternalSlotName::GeneratorContext) {
+            Some(InternalSlotValue::GeneratorContext(context)) => Some(*context),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_generator_context(&mut self, context: GeneratorContext) {
+        self.0.insert(
+            InternalSlotName::GeneratorContext,
+            InternalSlotValue::GeneratorContext(Box::new(context)),
+        );
+    }
+
+    /// [[Module]] and [[Exports]]
+    pub(crate) fn module_namespace(&self) -> Option<&ModuleNamespace> {
+        match self.get(&InternalSlotName::ModuleNamespace) {
+            Some(InternalSlotValue::ModuleNamespace(namespace)) => Some(namespace),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_module_namespace(&mut self, namespace: ModuleNamespace) {
+        self.0.insert(
+            InternalSlotName::ModuleNamespace,
+            InternalSlotValue::ModuleNamespace(namespace),
+        );
+    }
+
+    /// [[ParameterMap]]
+    pub(crate) fn parameter_map(&self) -> Option<&ParameterMap> {
+        match self.get(&InternalSlotName::ParameterMap) {
+            Some(InternalSlotValue::ParameterMap(map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn parameter_map_mut(&mut self) -> Option<&mut ParameterMap> {
+        match self.0.get_mut(&InternalSlotName::ParameterMap) {
+            Some(InternalSlotValue::ParameterMap(map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_parameter_map(&mut self, map: ParameterMap) {
+        self.0.insert(
+            InternalSlotName::ParameterMap,
+            InternalSlotValue::ParameterMap(map),
+        );
+    }
+
+    /// [[PromiseState]]
+    pub(crate) fn promise_state(&self) -> Option<PromiseState> {
+        match self.get(&InternalSlotName::PromiseState) {
+            Some(InternalSlotValue::PromiseState(state)) => Some(*state),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_promise_state(&mut self, state: PromiseState) {
+        self.0.insert(
+            InternalSlotName::PromiseState,
+            InternalSlotValue::PromiseState(state),
+        );
+    }
+
+    /// [[PromiseResult]]
+    pub(crate) fn promise_result(&self) -> JSValue {
+        match self.get(&InternalSlotName::PromiseResult) {
+            Some(InternalSlotValue::Value(value)) => value.clone(),
+            _ => JSValue::Undefined,
+        }
+    }
+
+    pub(crate) fn set_promise_result(&mut self, value: JSValue) {
+        self.0.insert(
+            InternalSlotName::PromiseResult,
+            InternalSlotValue::Value(value),
+        );
+    }
+
+    /// [[PromiseFulfillReactions]] or [[PromiseRejectReactions]]
+    pub(crate) fn push_promise_reaction(&mut self, reaction: PromiseReaction) {
+        let name = match reaction.reaction_type {
+            PromiseReactionType::Fulfill => InternalSlotName::PromiseFulfillReactions,
+            PromiseReactionType::Reject => InternalSlotName::PromiseRejectReactions,
+        };
+
+        match self.0.get_mut(&name) {
+            Some(InternalSlotValue::PromiseReactions(reactions)) => reactions.push(reaction),
+            _ => {
+                self.0
+                    .insert(name, InternalSlotValue::PromiseReactions(vec![reaction]));
+            }
+        }
+    }
+
+    /// Takes the reactions of the given type, leaving both [[PromiseFulfillReactions]] and [[PromiseRejectReactions]] undefined.
+    pub(crate) fn take_promise_reactions(
+        &mut self,
+        reaction_type: PromiseReactionType,
+    ) -> Vec<PromiseReaction> {
+        let fulfill_reactions = self.0.remove(&InternalSlotName::PromiseFulfillReactions);
+        let reject_reactions = self.0.remove(&InternalSlotName::PromiseRejectReactions);
+
+        let reactions = match reaction_type {
+            PromiseReactionType::Fulfill => fulfill_reactions,
+            PromiseReactionType::Reject => reject_reactions,
+        };
+
+        match reactions {
+            Some(InternalSlotValue::PromiseReactions(reactions)) => reactions,
+            _ => vec![],
+        }
+    }
+
+    /// [[PromiseIsHandled]]
+    pub(crate) fn promise_is_handled(&self) -> bool {
+        matches!(
+            self.get(&InternalSlotName::PromiseIsHandled),
+            Some(InternalSlotValue::Value(JSValue::Bool(true)))
+        )
+    }
+
+    pub(crate) fn set_promise_is_handled(&mut self, is_handled: bool) {
+        self.0.insert(
+            InternalSlotName::PromiseIsHandled,
+            JSValue::Bool(is_handled).into(),
+        );
+    }
+
+    /// [[DateValue]]
+    pub(crate) fn date_value(&self) -> Option<f64> {
+        match self.get(&InternalSlotName::DateValue) {
+            Some(InternalSlotValue::Value(JSValue::Number(number))) => Some(number.0),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_date_value(&mut self, time_value: f64) {
+        self.0.insert(
+            InternalSlotName::DateValue,
+            JSValue::from(time_value).into(),
+        );
+    }
+
+    /// [[BooleanData]]
+    pub(crate) fn boolean_data(&self) -> Option<bool> {
+        match self.get(&InternalSlotName::BooleanData) {
+            Some(InternalSlotValue::Value(JSValue::Bool(value))) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_boolean_data(&mut self, value: bool) {
+        self.0
+            .insert(InternalSlotName::BooleanData, JSValue::Bool(value).into());
+    }
+
+    /// [[NumberData]]
+    pub(crate) fn number_data(&self) -> Option<JSNumber> {
+        match self.get(&InternalSlotName::NumberData) {
+            Some(InternalSlotValue::Value(JSValue::Number(number))) => Some(number.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_number_data(&mut self, number: JSNumber) {
+        self.0
+            .insert(InternalSlotName::NumberData, JSValue::Number(number).into());
+    }
+
+    /// [[SymbolData]]
+    pub(crate) fn symbol_data(&self) -> Option<JSSymbol> {
+        match self.get(&InternalSlotName::SymbolData) {
+            Some(InternalSlotValue::Value(JSValue::Symbol(symbol))) => Some(symbol.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_symbol_data(&mut self, symbol: JSSymbol) {
+        self.0
+            .insert(InternalSlotName::SymbolData, JSValue::Symbol(symbol).into());
+    }
+
+    /// [[StringData]]
+    pub(crate) fn string_data(&self) -> Option<JSString> {
+        match self.get(&InternalSlotName::StringData) {
+            Some(InternalSlotValue::Value(JSValue::String(string))) => Some(string.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_string_data(&mut self, string: JSString) {
+        self.0
+            .insert(InternalSlotName::StringData, JSValue::String(string).into());
+    }
+
+    pub(crate) fn host_data(&self) -> Option<&HostData> {
+        match self.get(&InternalSlotName::HostData) {
+            Some(InternalSlotValue::HostData(host_data)) => Some(host_data),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_host_data(&mut self, host_data: HostData) {
+        self.0.insert(
+            InternalSlotName::HostData,
+            InternalSlotValue::HostData(host_data),
+        );
+    }
+
+    pub(crate) fn constructor_kind(&self) -> Option<ConstructorKind> {
+        match self.get(&InternalSlotName::ConstructorKind) {
+            Some(InternalSlotValue::ConstructorKind(kind)) => Some(kind.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_constructor_kind(&mut self, kind: ConstructorKind) {
+        self.0.insert(
+            InternalSlotName::ConstructorKind,
+            InternalSlotValue::ConstructorKind(kind),
+        );
+    }
+
+    pub(crate) fn this_mode(&self) -> Option<ThisMode> {
+        match self.get(&InternalSlotName::ThisMode) {
+            Some(InternalSlotValue::ThisMode(mode)) => Some(mode.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_this_mode(&mut self, mode: ThisMode) {
+        self.0.insert(
+            InternalSlotName::ThisMode,
+            InternalSlotValue::ThisMode(mode),
+        );
+    }
+
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.0
+            .insert(InternalSlotName::Strict, JSValue::Bool(strict).into());
+    }
+
     pub(crate) fn home_object(&self) -> Option<ObjectAddr> {
         match self.get(&InternalSlotName::HomeObject) {
             Some(InternalSlotValue::Value(JSValue::Object(addr))) => Some(addr.clone()),
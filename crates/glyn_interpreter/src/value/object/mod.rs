@@ -1,17 +1,32 @@
+pub(crate) mod elements;
 pub(crate) mod internal_slots;
 pub(crate) mod property;
+pub(crate) mod shape;
 pub(crate) mod subtypes;
 
-use std::cell::RefMut;
+use std::{
+    borrow::Cow,
+    cell::{BorrowError, Ref, RefMut},
+    fmt::{self, Debug, Formatter},
+};
 
 use crate::{
-    gc::Gc,
-    runtime::completion::{throw_completion, CompletionRecord, ThrowCompletion},
+    gc::{Collect, Gc, HeapSize, Reclaim, Summarize, Trace, Tracer},
+    runtime::{
+        agent::JSAgent,
+        completion::{throw_completion, CompletionRecord, ThrowCompletion},
+    },
     value::{
         object::{
+            elements::Elements,
             internal_slots::InternalSlots,
             property::{JSObjectPropDescriptor, JSObjectPropKey},
-            subtypes::{FunctionObject, ImmutablePrototypeExoticObject, OrdinaryObject},
+            shape::PropertyStorage,
+            subtypes::{
+                ArgumentsExoticObject, ArrayExoticObject, BuiltinFunctionObject, FunctionObject,
+                ImmutablePrototypeExoticObject, ModuleNamespaceExoticObject, OrdinaryObject,
+                StringExoticObject,
+            },
         },
         JSValue,
     },
@@ -22,7 +37,12 @@ pub(crate) enum ObjectKind {
     #[default]
     Ordinary,
     Function,
+    BuiltinFunction,
+    Array,
+    String,
+    Arguments,
     ImmutablePrototype,
+    ModuleNamespace,
 }
 
 /// 6.1.7 The Object Type
@@ -37,16 +57,24 @@ pub(crate) struct ObjectData {
 
     kind: ObjectKind,
     slots: InternalSlots,
-    keys: Vec<JSObjectPropKey>,
-    values: Vec<JSObjectPropDescriptor>,
+    /// The own properties of the object whose keys are array indices, in ascending index order.
+    elements: Elements,
+    /// The other own properties of the object in order of creation.
+    properties: PropertyStorage,
+    /// The size of the heap the object was allocated on, which the buffers of its elements and properties are accounted to as they grow.
+    heap_size: HeapSize,
 }
 
 impl ObjectData {
-    pub(crate) fn new(kind: ObjectKind, slots: InternalSlots) -> Self {
+    pub(crate) fn new(agent: &JSAgent, kind: ObjectKind, slots: InternalSlots) -> Self {
         Self {
+            prototype: None,
+            extensible: true,
             kind,
             slots,
-            ..Self::default()
+            elements: Elements::default(),
+            properties: PropertyStorage::new(agent.empty_shape.clone()),
+            heap_size: agent.heap.size_handle(),
         }
     }
 
@@ -64,6 +92,11 @@ impl ObjectData {
         &self.kind
     }
 
+    /// Replaces the internal methods of the object with those of the given kind.
+    pub(crate) fn set_kind(&mut self, kind: ObjectKind) {
+        self.kind = kind;
+    }
+
     pub(crate) fn slots(&self) -> &InternalSlots {
         &self.slots
     }
@@ -72,61 +105,175 @@ impl ObjectData {
         &mut self.slots
     }
 
-    pub(crate) fn keys(&self) -> &[JSObjectPropKey] {
-        &self.keys
+    /// The number of own properties of the object.
+    pub(crate) fn property_count(&self) -> usize {
+        self.elements.len() + self.properties.len()
     }
 
-    pub(crate) fn values(&self) -> &[JSObjectPropDescriptor] {
-        &self.values
+    /// The keys of the own properties of the object, with the array indices in ascending index order followed by the other keys in order of creation.
+    pub(crate) fn keys(&self) -> impl Iterator<Item = Cow<'_, JSObjectPropKey>> {
+        self.element_indices()
+            .map(|index| Cow::Owned(JSObjectPropKey::String(index.into())))
+            .chain(self.named_keys().map(Cow::Borrowed))
     }
 
-    pub(crate) fn get_property(&self, index: usize) -> Option<&JSObjectPropDescriptor> {
-        self.values.get(index)
+    /// The own property descriptors of the object, in the order of [`ObjectData::keys`].
+    pub(crate) fn values(&self) -> impl Iterator<Item = Cow<'_, JSObjectPropDescriptor>> {
+        self.elements.iter().map(|(_, value)| value).chain(
+            self.properties
+                .iter()
+                .map(|(_, value)| Cow::Borrowed(value)),
+        )
     }
 
-    pub(crate) fn has_property(&self, key: &JSObjectPropKey) -> bool {
-        self.keys.iter().any(|k| k == key)
+    /// The array indices which are keys of own properties of the object, in ascending index order.
+    pub(crate) fn element_indices(&self) -> impl Iterator<Item = u32> + '_ {
+        self.elements.indices()
     }
 
-    pub(crate) fn set_property(
-        &mut self,
+    /// The own property keys of the object which are not array indices, in order of creation.
+    pub(crate) fn named_keys(&self) -> impl Iterator<Item = &JSObjectPropKey> {
+        self.properties.iter().map(|(key, _)| key)
+    }
+
+    pub(crate) fn get_property(
+        &self,
         key: &JSObjectPropKey,
-        value: JSObjectPropDescriptor,
-    ) -> usize {
-        self.keys.push(key.clone());
-        self.values.push(value);
+    ) -> Option<Cow<'_, JSObjectPropDescriptor>> {
+        match key.as_array_index() {
+            Some(index) => self.elements.get(index),
+            None => self.properties.get(key).map(Cow::Borrowed),
+        }
+    }
 
-        self.keys.len() - 1
+    pub(crate) fn has_property(&self, key: &JSObjectPropKey) -> bool {
+        match key.as_array_index() {
+            Some(index) => self.elements.contains(index),
+            None => self.properties.contains(key),
+        }
     }
 
-    pub(crate) fn delete_property(&mut self, index: usize) -> bool {
-        self.keys.remove(index);
-        self.values.remove(index);
+    /// Adds a property which the object does not have yet, after the existing properties so that insertion order is preserved.
+    pub(crate) fn insert_property(&mut self, key: &JSObjectPropKey, value: JSObjectPropDescriptor) {
+        debug_assert!(!self.has_property(key));
 
-        true
+        self.set_property(key, value);
     }
 
-    pub(crate) fn find_property_index(&self, key: &JSObjectPropKey) -> Option<usize> {
-        self.keys.iter().position(|k| k == key)
+    /// Replaces the attributes of a property which the object already has, which keeps the position of its key among the keys of the object.
+    pub(crate) fn update_property(&mut self, key: &JSObjectPropKey, value: JSObjectPropDescriptor) {
+        debug_assert!(self.has_property(key));
+
+        self.set_property(key, value);
+    }
+
+    fn set_property(&mut self, key: &JSObjectPropKey, value: JSObjectPropDescriptor) {
+        let size = self.buffer_size();
+
+        match key.as_array_index() {
+            Some(index) => self.elements.insert(index, value),
+            None => self.properties.insert(key, value),
+        }
+
+        self.heap_size.record_growth(size, self.buffer_size());
+    }
+
+    pub(crate) fn delete_property(&mut self, key: &JSObjectPropKey) -> bool {
+        let size = self.buffer_size();
+
+        let deleted = match key.as_array_index() {
+            Some(index) => self.elements.remove(index),
+            None => self.properties.remove(key),
+        };
+
+        self.heap_size.record_growth(size, self.buffer_size());
+
+        deleted
     }
 }
 
-impl Default for ObjectData {
-    fn default() -> Self {
-        Self {
-            prototype: None,
-            extensible: true,
-            kind: ObjectKind::Ordinary,
-            slots: InternalSlots::default(),
-            keys: vec![],
-            values: vec![],
+impl Trace for ObjectData {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.prototype.trace(tracer);
+        self.slots.trace(tracer);
+        self.elements.trace(tracer);
+        self.properties.trace(tracer);
+    }
+}
+
+impl Collect for ObjectData {
+    fn clear(&mut self) {
+        self.prototype = None;
+        self.slots = InternalSlots::default();
+        self.elements = Elements::default();
+        self.properties.clear();
+    }
+
+    fn buffer_size(&self) -> usize {
+        self.elements.size() + self.properties.size()
+    }
+}
+
+impl Summarize for ObjectData {
+    fn summarize(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Object({:?})", self.kind)
+    }
+}
+
+impl Reclaim for ObjectData {
+    fn reclaim(&mut self, value: Self) {
+        self.prototype = value.prototype;
+        self.extensible = value.extensible;
+        self.kind = value.kind;
+        self.slots = value.slots;
+        self.heap_size = value.heap_size;
+
+        // NOTE: A newly created object has no properties yet, so the property list and elements of the dead object are kept to be grown into.
+        self.properties.reclaim(value.properties);
+
+        if value.elements.is_empty() {
+            self.elements.reset();
+        } else {
+            self.elements = value.elements;
         }
     }
 }
 
-pub(crate) type ObjectAddr = Gc<ObjectData>;
+/// A reference to an object on the heap of an agent.
+///
+/// It is opaque to the embedder, so that the object cannot be borrowed across a call into the agent, where evaluating code borrows the object too.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct ObjectAddr(Gc<ObjectData>);
+
+impl Debug for ObjectAddr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Trace for ObjectAddr {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.0.trace(tracer);
+    }
+}
 
 impl ObjectAddr {
+    pub(crate) fn new(object: Gc<ObjectData>) -> Self {
+        ObjectAddr(object)
+    }
+
+    pub(crate) fn borrow(&self) -> Ref<'_, ObjectData> {
+        self.0.borrow()
+    }
+
+    pub(crate) fn borrow_mut(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+
+    pub(crate) fn try_borrow(&self) -> Result<Ref<'_, ObjectData>, BorrowError> {
+        self.0.try_borrow()
+    }
+
     pub(crate) fn kind(&self) -> ObjectKind {
         self.borrow().kind.clone()
     }
@@ -137,53 +284,96 @@ impl ObjectMeta for ObjectAddr {
         self.clone()
     }
 
-    fn data(&self) -> RefMut<ObjectData> {
+    fn data(&self) -> RefMut<'_, ObjectData> {
         self.borrow_mut()
     }
 
-    fn data_mut(&self) -> RefMut<ObjectData> {
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
         self.borrow_mut()
     }
+
+    fn is_callable(&self) -> bool {
+        matches!(
+            self.kind(),
+            ObjectKind::Function | ObjectKind::BuiltinFunction
+        )
+    }
+
+    /// Built-in functions which are constructors are marked by a [[ConstructorKind]] slot, like ECMAScript function objects are.
+    fn is_constructor(&self) -> bool {
+        matches!(
+            self.kind(),
+            ObjectKind::Function | ObjectKind::BuiltinFunction
+        ) && self.data().slots().constructor_kind().is_some()
+    }
 }
 
 impl ObjectEssentialInternalMethods for ObjectAddr {
     fn get_prototype_of(&self) -> Option<ObjectAddr> {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).get_prototype_of(),
+            ObjectKind::Array => ArrayExoticObject::from(self).get_prototype_of(),
+            ObjectKind::String => StringExoticObject::from(self).get_prototype_of(),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).get_prototype_of(),
             ObjectKind::Function => FunctionObject::from(self).get_prototype_of(),
+            ObjectKind::BuiltinFunction => BuiltinFunctionObject::from(self).get_prototype_of(),
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).get_prototype_of()
             }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).get_prototype_of()
+            }
         }
     }
 
     fn set_prototype_of(&self, prototype: Option<ObjectAddr>) -> bool {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).set_prototype_of(prototype),
+            ObjectKind::Array => ArrayExoticObject::from(self).set_prototype_of(prototype),
+            ObjectKind::String => StringExoticObject::from(self).set_prototype_of(prototype),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).set_prototype_of(prototype),
             ObjectKind::Function => FunctionObject::from(self).set_prototype_of(prototype),
+            ObjectKind::BuiltinFunction => {
+                BuiltinFunctionObject::from(self).set_prototype_of(prototype)
+            }
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).set_prototype_of(prototype)
             }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).set_prototype_of(prototype)
+            }
         }
     }
 
     fn is_extensible(&self) -> bool {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).is_extensible(),
+            ObjectKind::Array => ArrayExoticObject::from(self).is_extensible(),
+            ObjectKind::String => StringExoticObject::from(self).is_extensible(),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).is_extensible(),
             ObjectKind::Function => FunctionObject::from(self).is_extensible(),
+            ObjectKind::BuiltinFunction => BuiltinFunctionObject::from(self).is_extensible(),
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).is_extensible()
             }
+            ObjectKind::ModuleNamespace => ModuleNamespaceExoticObject::from(self).is_extensible(),
         }
     }
 
     fn prevent_extensions(&self) -> bool {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).prevent_extensions(),
+            ObjectKind::Array => ArrayExoticObject::from(self).prevent_extensions(),
+            ObjectKind::String => StringExoticObject::from(self).prevent_extensions(),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).prevent_extensions(),
             ObjectKind::Function => FunctionObject::from(self).prevent_extensions(),
+            ObjectKind::BuiltinFunction => BuiltinFunctionObject::from(self).prevent_extensions(),
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).prevent_extensions()
             }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).prevent_extensions()
+            }
         }
     }
 
@@ -193,10 +383,17 @@ impl ObjectEssentialInternalMethods for ObjectAddr {
     ) -> CompletionRecord<Option<JSObjectPropDescriptor>> {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).get_own_property(key),
+            ObjectKind::Array => ArrayExoticObject::from(self).get_own_property(key),
+            ObjectKind::String => StringExoticObject::from(self).get_own_property(key),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).get_own_property(key),
             ObjectKind::Function => FunctionObject::from(self).get_own_property(key),
+            ObjectKind::BuiltinFunction => BuiltinFunctionObject::from(self).get_own_property(key),
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).get_own_property(key)
             }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).get_own_property(key)
+            }
         }
     }
 
@@ -207,44 +404,90 @@ impl ObjectEssentialInternalMethods for ObjectAddr {
     ) -> CompletionRecord<bool> {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).define_own_property(key, descriptor),
+            ObjectKind::Array => ArrayExoticObject::from(self).define_own_property(key, descriptor),
+            ObjectKind::String => {
+                StringExoticObject::from(self).define_own_property(key, descriptor)
+            }
+            ObjectKind::Arguments => {
+                ArgumentsExoticObject::from(self).define_own_property(key, descriptor)
+            }
             ObjectKind::Function => FunctionObject::from(self).define_own_property(key, descriptor),
+            ObjectKind::BuiltinFunction => {
+                BuiltinFunctionObject::from(self).define_own_property(key, descriptor)
+            }
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).define_own_property(key, descriptor)
             }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).define_own_property(key, descriptor)
+            }
         }
     }
 
     fn has_property(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).has_property(key),
+            ObjectKind::Array => ArrayExoticObject::from(self).has_property(key),
+            ObjectKind::String => StringExoticObject::from(self).has_property(key),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).has_property(key),
             ObjectKind::Function => FunctionObject::from(self).has_property(key),
+            ObjectKind::BuiltinFunction => BuiltinFunctionObject::from(self).has_property(key),
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).has_property(key)
             }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).has_property(key)
+            }
         }
     }
 
-    fn get(&self, key: &JSObjectPropKey, receiver: &JSValue) -> CompletionRecord<JSValue> {
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
         match self.kind() {
-            ObjectKind::Ordinary => OrdinaryObject::from(self).get(key, receiver),
-            ObjectKind::Function => FunctionObject::from(self).get(key, receiver),
+            ObjectKind::Ordinary => OrdinaryObject::from(self).get(agent, key, receiver),
+            ObjectKind::Array => ArrayExoticObject::from(self).get(agent, key, receiver),
+            ObjectKind::String => StringExoticObject::from(self).get(agent, key, receiver),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).get(agent, key, receiver),
+            ObjectKind::Function => FunctionObject::from(self).get(agent, key, receiver),
+            ObjectKind::BuiltinFunction => {
+                BuiltinFunctionObject::from(self).get(agent, key, receiver)
+            }
             ObjectKind::ImmutablePrototype => {
-                ImmutablePrototypeExoticObject::from(self).get(key, receiver)
+                ImmutablePrototypeExoticObject::from(self).get(agent, key, receiver)
+            }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).get(agent, key, receiver)
             }
         }
     }
 
     fn set(
         &self,
+        agent: &mut JSAgent,
         key: &JSObjectPropKey,
         value: JSValue,
         receiver: JSValue,
     ) -> CompletionRecord<bool> {
         match self.kind() {
-            ObjectKind::Ordinary => OrdinaryObject::from(self).set(key, value, receiver),
-            ObjectKind::Function => FunctionObject::from(self).set(key, value, receiver),
+            ObjectKind::Ordinary => OrdinaryObject::from(self).set(agent, key, value, receiver),
+            ObjectKind::Array => ArrayExoticObject::from(self).set(agent, key, value, receiver),
+            ObjectKind::String => StringExoticObject::from(self).set(agent, key, value, receiver),
+            ObjectKind::Arguments => {
+                ArgumentsExoticObject::from(self).set(agent, key, value, receiver)
+            }
+            ObjectKind::Function => FunctionObject::from(self).set(agent, key, value, receiver),
+            ObjectKind::BuiltinFunction => {
+                BuiltinFunctionObject::from(self).set(agent, key, value, receiver)
+            }
             ObjectKind::ImmutablePrototype => {
-                ImmutablePrototypeExoticObject::from(self).set(key, value, receiver)
+                ImmutablePrototypeExoticObject::from(self).set(agent, key, value, receiver)
+            }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).set(agent, key, value, receiver)
             }
         }
     }
@@ -252,20 +495,66 @@ impl ObjectEssentialInternalMethods for ObjectAddr {
     fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).delete(key),
+            ObjectKind::Array => ArrayExoticObject::from(self).delete(key),
+            ObjectKind::String => StringExoticObject::from(self).delete(key),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).delete(key),
             ObjectKind::Function => FunctionObject::from(self).delete(key),
+            ObjectKind::BuiltinFunction => BuiltinFunctionObject::from(self).delete(key),
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).delete(key)
             }
+            ObjectKind::ModuleNamespace => ModuleNamespaceExoticObject::from(self).delete(key),
         }
     }
 
     fn own_property_keys(&self) -> Vec<JSObjectPropKey> {
         match self.kind() {
             ObjectKind::Ordinary => OrdinaryObject::from(self).own_property_keys(),
+            ObjectKind::Array => ArrayExoticObject::from(self).own_property_keys(),
+            ObjectKind::String => StringExoticObject::from(self).own_property_keys(),
+            ObjectKind::Arguments => ArgumentsExoticObject::from(self).own_property_keys(),
             ObjectKind::Function => FunctionObject::from(self).own_property_keys(),
+            ObjectKind::BuiltinFunction => BuiltinFunctionObject::from(self).own_property_keys(),
             ObjectKind::ImmutablePrototype => {
                 ImmutablePrototypeExoticObject::from(self).own_property_keys()
             }
+            ObjectKind::ModuleNamespace => {
+                ModuleNamespaceExoticObject::from(self).own_property_keys()
+            }
+        }
+    }
+}
+
+impl ObjectExtraInternalMethods for ObjectAddr {
+    fn call(
+        &self,
+        agent: &mut JSAgent,
+        this_value: &JSValue,
+        args: &[JSValue],
+    ) -> CompletionRecord<JSValue> {
+        match self.kind() {
+            ObjectKind::Function => FunctionObject::from(self).call(agent, this_value, args),
+            ObjectKind::BuiltinFunction => {
+                BuiltinFunctionObject::from(self).call(agent, this_value, args)
+            }
+            // NOTE: Callers are expected to check IsCallable before calling.
+            _ => unreachable!(),
+        }
+    }
+
+    fn construct(
+        &self,
+        agent: &mut JSAgent,
+        args: &[JSValue],
+        new_target: &ObjectAddr,
+    ) -> CompletionRecord<ObjectAddr> {
+        match self.kind() {
+            ObjectKind::Function => FunctionObject::from(self).construct(agent, args, new_target),
+            ObjectKind::BuiltinFunction => {
+                BuiltinFunctionObject::from(self).construct(agent, args, new_target)
+            }
+            // NOTE: Callers are expected to check IsConstructor before constructing.
+            _ => unreachable!(),
         }
     }
 }
@@ -276,7 +565,10 @@ impl TryFrom<JSValue> for ObjectAddr {
     fn try_from(value: JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::Object(obj) => Ok(obj),
-            _ => throw_completion("Expected JSValue::Object for conversion to ObjectAddr"),
+            _ => throw_completion(&format!(
+                "Expected JSValue::Object for conversion to ObjectAddr, but got {}",
+                value.preview()
+            )),
         }
     }
 }
@@ -287,7 +579,10 @@ impl TryFrom<&JSValue> for ObjectAddr {
     fn try_from(value: &JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::Object(obj) => Ok(obj.clone()),
-            _ => throw_completion("Expected JSValue::Object for conversion to ObjectAddr"),
+            _ => throw_completion(&format!(
+                "Expected JSValue::Object for conversion to ObjectAddr, but got {}",
+                value.preview()
+            )),
         }
     }
 }
@@ -304,18 +599,48 @@ impl From<&ObjectAddr> for FunctionObject {
     }
 }
 
+impl From<&ObjectAddr> for BuiltinFunctionObject {
+    fn from(value: &ObjectAddr) -> Self {
+        BuiltinFunctionObject(value.clone())
+    }
+}
+
+impl From<&ObjectAddr> for ArrayExoticObject {
+    fn from(value: &ObjectAddr) -> Self {
+        ArrayExoticObject(value.clone())
+    }
+}
+
+impl From<&ObjectAddr> for StringExoticObject {
+    fn from(value: &ObjectAddr) -> Self {
+        StringExoticObject(value.clone())
+    }
+}
+
+impl From<&ObjectAddr> for ArgumentsExoticObject {
+    fn from(value: &ObjectAddr) -> Self {
+        ArgumentsExoticObject(value.clone())
+    }
+}
+
 impl From<&ObjectAddr> for ImmutablePrototypeExoticObject {
     fn from(value: &ObjectAddr) -> Self {
         ImmutablePrototypeExoticObject(value.clone())
     }
 }
 
+impl From<&ObjectAddr> for ModuleNamespaceExoticObject {
+    fn from(value: &ObjectAddr) -> Self {
+        ModuleNamespaceExoticObject(value.clone())
+    }
+}
+
 pub(crate) trait ObjectMeta {
     fn addr(&self) -> ObjectAddr;
 
-    fn data(&self) -> RefMut<ObjectData>;
+    fn data(&self) -> RefMut<'_, ObjectData>;
 
-    fn data_mut(&self) -> RefMut<ObjectData>;
+    fn data_mut(&self) -> RefMut<'_, ObjectData>;
 
     fn has_ordinary_get_prototype_of(&self) -> bool {
         true
@@ -362,11 +687,17 @@ pub(crate) trait ObjectEssentialInternalMethods {
     fn has_property(&self, key: &JSObjectPropKey) -> CompletionRecord<bool>;
 
     /// [[Get]]
-    fn get(&self, key: &JSObjectPropKey, receiver: &JSValue) -> CompletionRecord<JSValue>;
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue>;
 
     /// [[Set]]
     fn set(
         &self,
+        agent: &mut JSAgent,
         key: &JSObjectPropKey,
         value: JSValue,
         receiver: JSValue,
@@ -383,12 +714,18 @@ pub(crate) trait ObjectEssentialInternalMethods {
 /// https://262.ecma-international.org/16.0/#table-additional-essential-internal-methods-of-function-objects
 pub(crate) trait ObjectExtraInternalMethods {
     /// [[Call]]
-    fn call(&self, this_value: &JSValue, args: &[JSValue]) -> CompletionRecord<JSValue>;
+    fn call(
+        &self,
+        agent: &mut JSAgent,
+        this_value: &JSValue,
+        args: &[JSValue],
+    ) -> CompletionRecord<JSValue>;
 
     /// [[Construct]]
     fn construct(
         &self,
+        agent: &mut JSAgent,
         args: &[JSValue],
-        new_target: &impl ObjectExtraInternalMethods,
+        new_target: &ObjectAddr,
     ) -> CompletionRecord<ObjectAddr>;
 }
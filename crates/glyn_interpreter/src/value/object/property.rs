@@ -1,13 +1,23 @@
 use crate::{
-    runtime::agent::WellKnownSymbols,
-    value::{number::JSNumber, string::JSString, symbol::JSSymbol, JSValue},
+    abstract_ops::{
+        object_operations::{create_data_property_or_throw, get, has_property},
+        ordinary::ordinary_object_create,
+        testing_comparison::is_callable,
+        type_conversion::to_boolean,
+    },
+    gc::{Trace, Tracer},
+    runtime::{
+        agent::{type_error, JSAgent, WellKnownSymbols},
+        completion::CompletionRecord,
+    },
+    value::{atom::Atom, object::ObjectAddr, string::JSString, symbol::JSSymbol, JSValue},
 };
 
 /// 6.1.7 The Object Type
 /// https://262.ecma-international.org/16.0/#sec-object-type
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) enum JSObjectPropKey {
-    String(JSString),
+    String(Atom),
     Symbol(JSSymbol),
     PrivateName(String),
 }
@@ -21,33 +31,32 @@ impl JSObjectPropKey {
         matches!(self, JSObjectPropKey::Symbol(_))
     }
 
-    pub(crate) fn is_array_index(&self) -> bool {
-        self.as_array_index().is_some()
-    }
-
     /// An array index is an integer index n such that CanonicalNumericIndexString(n) returns
     /// an integral Number in the inclusive interval from +0𝔽 to 𝔽(2****32 - 2).
     /// https://262.ecma-international.org/16.0/#sec-object-type
     pub(crate) fn as_array_index(&self) -> Option<u32> {
-        if let JSObjectPropKey::String(value) = self {
-            if let Ok(JSNumber(number)) = JSNumber::try_from(value.clone()) {
-                return Some(number as u32);
-            }
+        match self {
+            JSObjectPropKey::String(value) => value.as_array_index(),
+            _ => None,
         }
-
-        None
     }
 }
 
 impl From<JSString> for JSObjectPropKey {
     fn from(value: JSString) -> Self {
-        JSObjectPropKey::String(value)
+        JSObjectPropKey::String(Atom::from(value))
     }
 }
 
 impl From<&JSString> for JSObjectPropKey {
     fn from(value: &JSString) -> Self {
-        JSObjectPropKey::String(value.clone())
+        JSObjectPropKey::String(Atom::from(value))
+    }
+}
+
+impl From<Atom> for JSObjectPropKey {
+    fn from(value: Atom) -> Self {
+        JSObjectPropKey::String(value)
     }
 }
 
@@ -92,14 +101,36 @@ pub(crate) struct JSObjectPropDescriptor {
     pub(crate) configurable: Option<bool>,
 }
 
+impl Trace for JSObjectPropDescriptor {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.value.trace(tracer);
+        self.get.trace(tracer);
+        self.set.trace(tracer);
+    }
+}
+
 impl JSObjectPropDescriptor {
+    /// A fully populated Property Descriptor is either an accessor Property Descriptor or a data Property Descriptor that has all of the corresponding fields.
     pub(crate) fn is_fully_populated(&self) -> bool {
-        self.value.is_some()
-            && self.writable.is_some()
-            && self.get.is_some()
-            && self.set.is_some()
-            && self.enumerable.is_some()
-            && self.configurable.is_some()
+        let has_fields = if self.is_accessor_descriptor() {
+            self.get.is_some() && self.set.is_some()
+        } else {
+            self.value.is_some() && self.writable.is_some()
+        };
+
+        has_fields && self.enumerable.is_some() && self.configurable.is_some()
+    }
+
+    /// The attributes of a property with the attributes of `current` once each field of the descriptor has been set on it, which leaves the attributes the descriptor has no field for as they are.
+    pub(crate) fn merge(self, current: JSObjectPropDescriptor) -> Self {
+        JSObjectPropDescriptor {
+            value: self.value.or(current.value),
+            writable: self.writable.or(current.writable),
+            get: self.get.or(current.get),
+            set: self.set.or(current.set),
+            enumerable: self.enumerable.or(current.enumerable),
+            configurable: self.configurable.or(current.configurable),
+        }
     }
 
     pub(crate) fn is_empty(&self) -> bool {
@@ -137,9 +168,189 @@ impl JSObjectPropDescriptor {
     /// https://262.ecma-international.org/16.0/#sec-isgenericdescriptor
     pub(crate) fn is_generic_descriptor(&self) -> bool {
         // 1. If Desc is undefined, return false.
-        // 2. If Desc has a [[Value]] field, return true.
-        // 3. If Desc has a [[Writable]] field, return true.
-        // 4. Return false.
-        self.value.is_some() || self.writable.is_some()
+        // 2. If IsAccessorDescriptor(Desc) is true, return false.
+        // 3. If IsDataDescriptor(Desc) is true, return false.
+        // 4. Return true.
+        !self.is_accessor_descriptor() && !self.is_data_descriptor()
+    }
+}
+
+/// 6.2.6.4 FromPropertyDescriptor ( Desc )
+/// https://262.ecma-international.org/16.0/#sec-frompropertydescriptor
+pub(crate) fn from_property_descriptor(
+    agent: &mut JSAgent,
+    desc: Option<JSObjectPropDescriptor>,
+) -> JSValue {
+    // 1. If Desc is undefined, return undefined.
+    let Some(desc) = desc else {
+        return JSValue::Undefined;
+    };
+
+    // 2. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+    let obj = ordinary_object_create(
+        agent,
+        agent
+            .current_realm()
+            .borrow()
+            .intrinsics
+            .object_prototype
+            .clone(),
+        None,
+    );
+
+    // 3. Assert: obj is an extensible ordinary object with no own properties.
+    // 4. If Desc has a [[Value]] field, then
+    // a. Perform ! CreateDataPropertyOrThrow(obj, "value", Desc.[[Value]]).
+    // 5. If Desc has a [[Writable]] field, then
+    // a. Perform ! CreateDataPropertyOrThrow(obj, "writable", Desc.[[Writable]]).
+    // 6. If Desc has a [[Get]] field, then
+    // a. Perform ! CreateDataPropertyOrThrow(obj, "get", Desc.[[Get]]).
+    // 7. If Desc has a [[Set]] field, then
+    // a. Perform ! CreateDataPropertyOrThrow(obj, "set", Desc.[[Set]]).
+    // 8. If Desc has an [[Enumerable]] field, then
+    // a. Perform ! CreateDataPropertyOrThrow(obj, "enumerable", Desc.[[Enumerable]]).
+    // 9. If Desc has a [[Configurable]] field, then
+    // a. Perform ! CreateDataPropertyOrThrow(obj, "configurable", Desc.[[Configurable]]).
+    let fields = [
+        ("value", desc.value),
+        ("writable", desc.writable.map(JSValue::from)),
+        ("get", desc.get),
+        ("set", desc.set),
+        ("enumerable", desc.enumerable.map(JSValue::from)),
+        ("configurable", desc.configurable.map(JSValue::from)),
+    ];
+
+    for (name, value) in fields {
+        if let Some(value) = value {
+            let _ =
+                create_data_property_or_throw(&obj, &JSObjectPropKey::String(name.into()), value);
+        }
     }
+
+    // 10. Return obj.
+    JSValue::from(obj)
+}
+
+/// 6.2.6.5 ToPropertyDescriptor ( Obj )
+/// https://262.ecma-international.org/16.0/#sec-topropertydescriptor
+pub(crate) fn to_property_descriptor(
+    agent: &mut JSAgent,
+    obj: &JSValue,
+) -> CompletionRecord<JSObjectPropDescriptor> {
+    // 1. If Obj is not an Object, throw a TypeError exception.
+    let Ok(obj) = ObjectAddr::try_from(obj) else {
+        return type_error("Property description must be an object");
+    };
+
+    // Returns the value of the field of Obj with the given name, if Obj has the property.
+    let field = |agent: &mut JSAgent, name: &str| -> CompletionRecord<Option<JSValue>> {
+        let key = JSObjectPropKey::String(name.into());
+
+        if has_property(&obj, &key)? {
+            Ok(Some(get(agent, &obj, &key)?))
+        } else {
+            Ok(None)
+        }
+    };
+
+    // 2. Let desc be a new Property Descriptor that initially has no fields.
+    // 3. Let hasEnumerable be ? HasProperty(Obj, "enumerable").
+    // 4. If hasEnumerable is true, then
+    // a. Let enumerable be ToBoolean(? Get(Obj, "enumerable")).
+    // b. Set desc.[[Enumerable]] to enumerable.
+    let enumerable = field(agent, "enumerable")?.map(to_boolean);
+
+    // 5. Let hasConfigurable be ? HasProperty(Obj, "configurable").
+    // 6. If hasConfigurable is true, then
+    // a. Let configurable be ToBoolean(? Get(Obj, "configurable")).
+    // b. Set desc.[[Configurable]] to configurable.
+    let configurable = field(agent, "configurable")?.map(to_boolean);
+
+    // 7. Let hasValue be ? HasProperty(Obj, "value").
+    // 8. If hasValue is true, then
+    // a. Let value be ? Get(Obj, "value").
+    // b. Set desc.[[Value]] to value.
+    let value = field(agent, "value")?;
+
+    // 9. Let hasWritable be ? HasProperty(Obj, "writable").
+    // 10. If hasWritable is true, then
+    // a. Let writable be ToBoolean(? Get(Obj, "writable")).
+    // b. Set desc.[[Writable]] to writable.
+    let writable = field(agent, "writable")?.map(to_boolean);
+
+    // 11. Let hasGet be ? HasProperty(Obj, "get").
+    // 12. If hasGet is true, then
+    // a. Let getter be ? Get(Obj, "get").
+    let getter = field(agent, "get")?;
+
+    // b. If IsCallable(getter) is false and getter is not undefined, throw a TypeError exception.
+    if let Some(getter) = &getter {
+        if !is_callable(getter) && !getter.is_undefined() {
+            return type_error("Getter must be a function");
+        }
+    }
+
+    // c. Set desc.[[Get]] to getter.
+    // 13. Let hasSet be ? HasProperty(Obj, "set").
+    // 14. If hasSet is true, then
+    // a. Let setter be ? Get(Obj, "set").
+    let setter = field(agent, "set")?;
+
+    // b. If IsCallable(setter) is false and setter is not undefined, throw a TypeError exception.
+    if let Some(setter) = &setter {
+        if !is_callable(setter) && !setter.is_undefined() {
+            return type_error("Setter must be a function");
+        }
+    }
+
+    // c. Set desc.[[Set]] to setter.
+    let desc = JSObjectPropDescriptor {
+        value,
+        writable,
+        get: getter,
+        set: setter,
+        enumerable,
+        configurable,
+    };
+
+    // 15. If desc has a [[Get]] field or desc has a [[Set]] field, then
+    // a. If desc has a [[Value]] field or desc has a [[Writable]] field, throw a TypeError exception.
+    if desc.is_accessor_descriptor() && desc.is_data_descriptor() {
+        return type_error(
+            "Invalid property descriptor. Cannot both specify accessors and a value or writable attribute",
+        );
+    }
+
+    // 16. Return desc.
+    Ok(desc)
+}
+
+/// 6.2.6.6 CompletePropertyDescriptor ( Desc )
+/// https://262.ecma-international.org/16.0/#sec-completepropertydescriptor
+pub(crate) fn complete_property_descriptor(desc: &mut JSObjectPropDescriptor) {
+    // 1. Let like be the Record { [[Value]]: undefined, [[Writable]]: false, [[Get]]: undefined, [[Set]]: undefined, [[Enumerable]]: false, [[Configurable]]: false }.
+    // 2. If IsGenericDescriptor(Desc) is true or IsDataDescriptor(Desc) is true, then
+    if desc.is_generic_descriptor() || desc.is_data_descriptor() {
+        // a. If Desc does not have a [[Value]] field, set Desc.[[Value]] to like.[[Value]].
+        desc.value.get_or_insert(JSValue::Undefined);
+
+        // b. If Desc does not have a [[Writable]] field, set Desc.[[Writable]] to like.[[Writable]].
+        desc.writable.get_or_insert(false);
+    }
+    // 3. Else,
+    else {
+        // a. If Desc does not have a [[Get]] field, set Desc.[[Get]] to like.[[Get]].
+        desc.get.get_or_insert(JSValue::Undefined);
+
+        // b. If Desc does not have a [[Set]] field, set Desc.[[Set]] to like.[[Set]].
+        desc.set.get_or_insert(JSValue::Undefined);
+    }
+
+    // 4. If Desc does not have an [[Enumerable]] field, set Desc.[[Enumerable]] to like.[[Enumerable]].
+    desc.enumerable.get_or_insert(false);
+
+    // 5. If Desc does not have a [[Configurable]] field, set Desc.[[Configurable]] to like.[[Configurable]].
+    desc.configurable.get_or_insert(false);
+
+    // 6. Return unused.
 }
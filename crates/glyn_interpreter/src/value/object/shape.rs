@@ -0,0 +1,404 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    gc::{Trace, Tracer},
+    runtime::iteration::LiveList,
+    value::object::property::{JSObjectPropDescriptor, JSObjectPropKey},
+};
+
+/// The number of properties an object can have before it stops sharing a shape and switches to a dictionary.
+///
+/// Each shape holds the keys of every property before it, so the cost of a transition grows with the number of properties.
+const MAX_SHAPED_PROPERTIES: usize = 64;
+
+/// The keys of the properties of an object, in order of creation, which every object whose properties were created in the same order shares.
+///
+/// Each agent creates the shape of objects with no properties, which every object it creates starts out with, so the objects created by the same agent share the transitions from it.
+///
+/// The slot of each property is its index in the order of creation, so a property is found by looking up its slot in the shape rather than by comparing its key with the key of every other property.
+#[derive(Debug, Default)]
+pub(crate) struct Shape {
+    /// The shape this one is a transition from, which is kept alive so that objects which have not yet reached this shape share the transitions which lead to it.
+    _parent: Option<Rc<Shape>>,
+    keys: Vec<JSObjectPropKey>,
+    slots: HashMap<JSObjectPropKey, usize>,
+    /// The shapes which adding a property to an object of this shape leads to, which are dropped once no object has them.
+    transitions: RefCell<HashMap<JSObjectPropKey, Weak<Shape>>>,
+}
+
+impl Shape {
+    fn slot(&self, key: &JSObjectPropKey) -> Option<usize> {
+        self.slots.get(key).copied()
+    }
+
+    /// The shape of an object of this shape once the property has been added to it, which is shared with every other object the same property was added to.
+    fn transition(self: &Rc<Self>, key: &JSObjectPropKey) -> Rc<Shape> {
+        let mut transitions = self.transitions.borrow_mut();
+
+        if let Some(shape) = transitions.get(key).and_then(Weak::upgrade) {
+            return shape;
+        }
+
+        let mut keys = self.keys.clone();
+        let mut slots = self.slots.clone();
+
+        slots.insert(key.clone(), keys.len());
+        keys.push(key.clone());
+
+        let shape = Rc::new(Shape {
+            _parent: Some(self.clone()),
+            keys,
+            slots,
+            transitions: RefCell::default(),
+        });
+
+        transitions.insert(key.clone(), Rc::downgrade(&shape));
+
+        shape
+    }
+}
+
+/// The own properties of an object.
+///
+/// An object starts out sharing a shape with the other objects whose properties were created in the same order, with the descriptor of each property held in the slot the shape gives it. Deleting a property, or adding more than [`MAX_SHAPED_PROPERTIES`], switches the object to a dictionary of its own, as the shapes it would transition to are unlikely to be shared.
+#[derive(Debug)]
+pub(crate) enum PropertyStorage {
+    Shaped {
+        shape: Rc<Shape>,
+        slots: LiveList<JSObjectPropDescriptor>,
+    },
+    Dictionary {
+        /// The properties in order of creation. Deleting a property leaves an empty slot behind rather than shifting the indices of the others, until the empty slots outnumber the properties.
+        properties: LiveList<(JSObjectPropKey, JSObjectPropDescriptor)>,
+        indices: HashMap<JSObjectPropKey, usize>,
+    },
+}
+
+impl PropertyStorage {
+    /// The properties of an object which has none, whose shape is the shape of objects with no properties.
+    pub(crate) fn new(shape: Rc<Shape>) -> Self {
+        debug_assert!(shape.keys.is_empty());
+
+        PropertyStorage::Shaped {
+            shape,
+            slots: LiveList::default(),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            PropertyStorage::Shaped { slots, .. } => slots.len(),
+            PropertyStorage::Dictionary { properties, .. } => properties.len(),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &JSObjectPropKey) -> Option<&JSObjectPropDescriptor> {
+        match self {
+            PropertyStorage::Shaped { shape, slots } => slots.get(shape.slot(key)?),
+            PropertyStorage::Dictionary {
+                properties,
+                indices,
+            } => properties
+                .get(*indices.get(key)?)
+                .map(|(_, descriptor)| descriptor),
+        }
+    }
+
+    pub(crate) fn contains(&self, key: &JSObjectPropKey) -> bool {
+        match self {
+            PropertyStorage::Shaped { shape, .. } => shape.slot(key).is_some(),
+            PropertyStorage::Dictionary { indices, .. } => indices.contains_key(key),
+        }
+    }
+
+    /// Iterates over the properties in order of creation.
+    pub(crate) fn iter(
+        &self,
+    ) -> Box<dyn Iterator<Item = (&JSObjectPropKey, &JSObjectPropDescriptor)> + '_> {
+        match self {
+            PropertyStorage::Shaped { shape, slots } => {
+                Box::new(shape.keys.iter().zip(slots.iter()))
+            }
+            PropertyStorage::Dictionary { properties, .. } => {
+                Box::new(properties.iter().map(|(key, descriptor)| (key, descriptor)))
+            }
+        }
+    }
+
+    /// Replaces the property with the given key, or adds it after the existing properties.
+    pub(crate) fn insert(&mut self, key: &JSObjectPropKey, descriptor: JSObjectPropDescriptor) {
+        if let PropertyStorage::Shaped { shape, slots } = self {
+            if let Some(slot) = shape.slot(key).and_then(|slot| slots.get_mut(slot)) {
+                *slot = descriptor;
+
+                return;
+            }
+
+            if shape.keys.len() < MAX_SHAPED_PROPERTIES {
+                *shape = shape.transition(key);
+
+                slots.push(descriptor);
+
+                return;
+            }
+
+            self.convert_to_dictionary();
+        }
+
+        let PropertyStorage::Dictionary {
+            properties,
+            indices,
+        } = self
+        else {
+            unreachable!();
+        };
+
+        if let Some(property) = indices
+            .get(key)
+            .and_then(|&index| properties.get_mut(index))
+        {
+            property.1 = descriptor;
+
+            return;
+        }
+
+        // NOTE: The empty slots left by deleted properties are only removed once they outnumber the properties, as removing them shifts the indices of the properties after them, so that deleting and adding properties takes constant time on average.
+        if properties.empty_slots() > properties.len() && properties.compact() {
+            *indices = Self::index(properties);
+        }
+
+        let index = properties.append((key.clone(), descriptor));
+
+        indices.insert(key.clone(), index);
+    }
+
+    /// Removes the property with the given key, returning whether there was one.
+    pub(crate) fn remove(&mut self, key: &JSObjectPropKey) -> bool {
+        if !self.contains(key) {
+            return false;
+        }
+
+        self.convert_to_dictionary();
+
+        let PropertyStorage::Dictionary {
+            properties,
+            indices,
+        } = self
+        else {
+            unreachable!();
+        };
+
+        indices
+            .remove(key)
+            .and_then(|index| properties.remove(index))
+            .is_some()
+    }
+
+    /// Replaces the properties with those of a newly created object, keeping the buffer of a shaped object if the new object has no properties, so that it can grow into it.
+    pub(crate) fn reclaim(&mut self, storage: PropertyStorage) {
+        match (self, storage) {
+            (
+                PropertyStorage::Shaped { shape, slots },
+                PropertyStorage::Shaped {
+                    shape: empty_shape,
+                    slots: empty_slots,
+                },
+            ) if empty_slots.len() == 0 => {
+                *shape = empty_shape;
+
+                slots.reset();
+            }
+            (this, storage) => *this = storage,
+        }
+    }
+
+    /// Removes the descriptors of every property, which drops the references they hold.
+    pub(crate) fn clear(&mut self) {
+        match self {
+            PropertyStorage::Shaped { slots, .. } => slots.reset(),
+            PropertyStorage::Dictionary {
+                properties,
+                indices,
+            } => {
+                properties.reset();
+                indices.clear();
+            }
+        }
+    }
+
+    /// The bytes taken up by the buffer of the descriptors of the properties.
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            PropertyStorage::Shaped { slots, .. } => slots.size(),
+            PropertyStorage::Dictionary { properties, .. } => properties.size(),
+        }
+    }
+
+    fn convert_to_dictionary(&mut self) {
+        let PropertyStorage::Shaped { shape, slots } = self else {
+            return;
+        };
+
+        let mut properties = LiveList::default();
+
+        for (key, descriptor) in shape.keys.iter().zip(slots.iter()) {
+            properties.push((key.clone(), descriptor.clone()));
+        }
+
+        let indices = Self::index(&properties);
+
+        *self = PropertyStorage::Dictionary {
+            properties,
+            indices,
+        };
+    }
+
+    fn index(
+        properties: &LiveList<(JSObjectPropKey, JSObjectPropDescriptor)>,
+    ) -> HashMap<JSObjectPropKey, usize> {
+        properties
+            .iter()
+            .enumerate()
+            .map(|(index, (key, _))| (key.clone(), index))
+            .collect()
+    }
+}
+
+impl Trace for PropertyStorage {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.iter()
+            .for_each(|(_, descriptor)| descriptor.trace(tracer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::{string::JSString, JSValue};
+
+    fn key(name: &str) -> JSObjectPropKey {
+        JSObjectPropKey::from(JSString::from(name))
+    }
+
+    fn data(value: f64) -> JSObjectPropDescriptor {
+        JSObjectPropDescriptor {
+            value: Some(JSValue::from(value)),
+            ..JSObjectPropDescriptor::default()
+        }
+    }
+
+    fn storage(root: &Rc<Shape>, names: &[&str]) -> PropertyStorage {
+        let mut storage = PropertyStorage::new(root.clone());
+
+        for (index, name) in names.iter().enumerate() {
+            storage.insert(&key(name), data(index as f64));
+        }
+
+        storage
+    }
+
+    fn shape(storage: &PropertyStorage) -> &Rc<Shape> {
+        match storage {
+            PropertyStorage::Shaped { shape, .. } => shape,
+            PropertyStorage::Dictionary { .. } => panic!("Expected a shaped object"),
+        }
+    }
+
+    fn keys(storage: &PropertyStorage) -> Vec<JSObjectPropKey> {
+        storage.iter().map(|(key, _)| key.clone()).collect()
+    }
+
+    #[test]
+    fn objects_with_the_same_properties_share_a_shape() {
+        let root = Rc::default();
+
+        let a = storage(&root, &["x", "y"]);
+        let mut b = storage(&root, &["x", "y"]);
+        let c = storage(&root, &["y", "x"]);
+
+        assert!(Rc::ptr_eq(shape(&a), shape(&b)));
+        assert!(!Rc::ptr_eq(shape(&a), shape(&c)));
+
+        b.insert(&key("x"), data(2.0));
+
+        assert!(Rc::ptr_eq(shape(&a), shape(&b)));
+        assert_eq!(b.get(&key("x")), Some(&data(2.0)));
+        assert_eq!(a.get(&key("x")), Some(&data(0.0)));
+        assert_eq!(c.get(&key("x")), Some(&data(1.0)));
+        assert_eq!(c.get(&key("z")), None);
+    }
+
+    #[test]
+    fn deleting_a_property_switches_to_a_dictionary() {
+        let mut storage = storage(&Rc::default(), &["x", "y", "z"]);
+
+        assert!(!storage.remove(&key("w")));
+        assert!(matches!(storage, PropertyStorage::Shaped { .. }));
+
+        assert!(storage.remove(&key("y")));
+        assert!(matches!(storage, PropertyStorage::Dictionary { .. }));
+        assert!(!storage.remove(&key("y")));
+
+        storage.insert(&key("y"), data(3.0));
+        storage.insert(&key("x"), data(4.0));
+
+        assert_eq!(keys(&storage), vec![key("x"), key("z"), key("y")]);
+        assert_eq!(storage.get(&key("x")), Some(&data(4.0)));
+        assert_eq!(storage.get(&key("y")), Some(&data(3.0)));
+        assert_eq!(storage.get(&key("z")), Some(&data(2.0)));
+        assert_eq!(storage.len(), 3);
+    }
+
+    #[test]
+    fn deleted_properties_leave_empty_slots_until_they_outnumber_the_others() {
+        let mut storage = storage(&Rc::default(), &["a", "b", "c", "d"]);
+
+        assert!(storage.remove(&key("a")));
+        assert!(storage.remove(&key("b")));
+
+        storage.insert(&key("e"), data(4.0));
+
+        assert!(matches!(
+            &storage,
+            PropertyStorage::Dictionary { properties, .. } if properties.empty_slots() == 2
+        ));
+
+        assert!(storage.remove(&key("c")));
+        assert!(storage.remove(&key("d")));
+
+        storage.insert(&key("a"), data(5.0));
+
+        assert!(matches!(
+            &storage,
+            PropertyStorage::Dictionary { properties, .. } if properties.empty_slots() == 0
+        ));
+        assert_eq!(keys(&storage), vec![key("e"), key("a")]);
+        assert_eq!(storage.get(&key("e")), Some(&data(4.0)));
+        assert_eq!(storage.get(&key("a")), Some(&data(5.0)));
+        assert_eq!(storage.get(&key("c")), None);
+    }
+
+    #[test]
+    fn objects_with_many_properties_switch_to_a_dictionary() {
+        let names: Vec<String> = (0..=MAX_SHAPED_PROPERTIES)
+            .map(|index| format!("p{index}"))
+            .collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+
+        let storage = storage(&Rc::default(), &names);
+
+        assert!(matches!(storage, PropertyStorage::Dictionary { .. }));
+        assert_eq!(
+            keys(&storage),
+            names.iter().map(|name| key(name)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            storage.get(&key(names[MAX_SHAPED_PROPERTIES])),
+            Some(&data(MAX_SHAPED_PROPERTIES as f64))
+        );
+    }
+}
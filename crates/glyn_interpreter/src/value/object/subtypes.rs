@@ -2,19 +2,35 @@ use std::cell::RefMut;
 
 use crate::{
     abstract_ops::{
+        arguments_exotic_objects::{mapped_argument, unmap_argument},
+        array_exotic_objects::{array_length, array_set_length},
+        function_operations::{
+            builtin_call_or_construct, ordinary_call_bind_this, ordinary_call_evaluate_body,
+            prepare_for_ordinary_call,
+        },
         immutable_prototype_objects::set_immutable_prototype,
+        module_namespace_exotic_objects::{
+            exported_name, namespace_binding_value, namespace_exports,
+        },
         ordinary::{
+            is_compatible_property_descriptor, ordinary_create_from_constructor,
             ordinary_define_own_property, ordinary_delete, ordinary_get, ordinary_get_own_property,
             ordinary_get_prototype_of, ordinary_has_property, ordinary_is_extensible,
             ordinary_own_property_keys, ordinary_prevent_extensions, ordinary_set,
             ordinary_set_prototype_of,
         },
+        string_exotic_objects::string_get_own_property,
+        testing_comparison::same_value,
     },
-    runtime::completion::CompletionRecord,
-    value::object::{
-        property::{JSObjectPropDescriptor, JSObjectPropKey},
-        ObjectAddr, ObjectData, ObjectEssentialInternalMethods, ObjectExtraInternalMethods,
-        ObjectMeta,
+    runtime::{agent::JSAgent, completion::CompletionRecord},
+    value::{
+        object::{
+            internal_slots::ConstructorKind,
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectData, ObjectEssentialInternalMethods, ObjectExtraInternalMethods,
+            ObjectMeta,
+        },
+        preview::function_name,
     },
     JSValue,
 };
@@ -28,11 +44,11 @@ impl ObjectMeta for OrdinaryObject {
         self.0.clone()
     }
 
-    fn data(&self) -> RefMut<ObjectData> {
+    fn data(&self) -> RefMut<'_, ObjectData> {
         self.0.borrow_mut()
     }
 
-    fn data_mut(&self) -> RefMut<ObjectData> {
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
         self.0.borrow_mut()
     }
 }
@@ -98,21 +114,27 @@ impl ObjectEssentialInternalMethods for OrdinaryObject {
 
     /// 10.1.8 [[Get]] ( P, Receiver )
     /// https://262.ecma-international.org/16.0/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
-    fn get(&self, key: &JSObjectPropKey, receiver: &JSValue) -> CompletionRecord<JSValue> {
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
         // 1. Return OrdinaryGet(O, P, Receiver).
-        ordinary_get(self, key, receiver)
+        ordinary_get(agent, self, key, receiver)
     }
 
     /// 10.1.9 [[Set]] ( P, V, Receiver )
     /// https://262.ecma-international.org/16.0/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver
     fn set(
         &self,
+        agent: &mut JSAgent,
         key: &JSObjectPropKey,
         value: JSValue,
         receiver: JSValue,
     ) -> CompletionRecord<bool> {
         // 1. Return OrdinarySet(O, P, V, Receiver).
-        ordinary_set(self, key, value, receiver)
+        ordinary_set(agent, self, key, value, receiver)
     }
 
     /// 10.1.10 [[Delete]] ( P )
@@ -139,11 +161,11 @@ impl ObjectMeta for FunctionObject {
         self.0.clone()
     }
 
-    fn data(&self) -> RefMut<ObjectData> {
+    fn data(&self) -> RefMut<'_, ObjectData> {
         self.0.borrow_mut()
     }
 
-    fn data_mut(&self) -> RefMut<ObjectData> {
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
         self.0.borrow_mut()
     }
 }
@@ -184,17 +206,23 @@ impl ObjectEssentialInternalMethods for FunctionObject {
         ordinary_has_property(self, key)
     }
 
-    fn get(&self, key: &JSObjectPropKey, receiver: &JSValue) -> CompletionRecord<JSValue> {
-        ordinary_get(self, key, receiver)
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
+        ordinary_get(agent, self, key, receiver)
     }
 
     fn set(
         &self,
+        agent: &mut JSAgent,
         key: &JSObjectPropKey,
         value: JSValue,
         receiver: JSValue,
     ) -> CompletionRecord<bool> {
-        ordinary_set(self, key, value, receiver)
+        ordinary_set(agent, self, key, value, receiver)
     }
 
     fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
@@ -207,16 +235,704 @@ impl ObjectEssentialInternalMethods for FunctionObject {
 }
 
 impl ObjectExtraInternalMethods for FunctionObject {
-    fn call(&self, _this_value: &JSValue, _args: &[JSValue]) -> CompletionRecord<JSValue> {
-        todo!()
+    /// 10.2.1 [[Call]] ( thisArgument, argumentsList )
+    /// https://262.ecma-international.org/16.0/#sec-ecmascript-function-objects-call-thisargument-argumentslist
+    fn call(
+        &self,
+        agent: &mut JSAgent,
+        this_value: &JSValue,
+        args: &[JSValue],
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let callerContext be the running execution context.
+        // NOTE: The call throws rather than overflowing the native stack once the execution context stack is too deep.
+        agent.check_call_depth()?;
+
+        // 2. Let calleeContext be PrepareForOrdinaryCall(F, undefined).
+        prepare_for_ordinary_call(agent, self, None);
+
+        // 3. Assert: calleeContext is now the running execution context.
+        // 4. If F.[[IsClassConstructor]] is true, then
+        // NOTE: Class constructors are not supported yet.
+
+        // 5. Perform OrdinaryCallBindThis(F, calleeContext, thisArgument).
+        ordinary_call_bind_this(agent, self, this_value);
+
+        agent.notify_debugger(|debugger, context| {
+            debugger.on_call(&function_name(&self.data()), args, context)
+        });
+
+        // 6. Let result be Completion(OrdinaryCallEvaluateBody(F, argumentsList)).
+        let result = ordinary_call_evaluate_body(agent, self, args);
+
+        if let Ok(value) = &result {
+            agent.notify_debugger(|debugger, context| {
+                debugger.on_return(&function_name(&self.data()), value, context)
+            });
+        }
+
+        // 7. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+        agent.pop_execution_context();
+
+        // 8. If result is a return completion, return result.[[Value]].
+        // 9. ReturnIfAbrupt(result).
+        // 10. Return undefined.
+        // NOTE: Evaluating a body which completes without a return statement produces undefined.
+        result
+    }
+
+    /// 10.2.2 [[Construct]] ( argumentsList, newTarget )
+    /// https://262.ecma-international.org/16.0/#sec-ecmascript-function-objects-construct-argumentslist-newtarget
+    fn construct(
+        &self,
+        agent: &mut JSAgent,
+        args: &[JSValue],
+        new_target: &ObjectAddr,
+    ) -> CompletionRecord<ObjectAddr> {
+        // 1. Let callerContext be the running execution context.
+        // NOTE: The call throws rather than overflowing the native stack once the execution context stack is too deep.
+        agent.check_call_depth()?;
+
+        // 2. Let kind be F.[[ConstructorKind]].
+        // 3. If kind is base, then
+        // NOTE: Every constructor is a base constructor, as derived constructors need classes.
+        debug_assert_eq!(
+            self.data().slots().constructor_kind(),
+            Some(ConstructorKind::Base)
+        );
+
+        // a. Let thisArgument be ? OrdinaryCreateFromConstructor(newTarget, "%Object.prototype%").
+        let this_argument = ordinary_create_from_constructor(
+            agent,
+            new_target,
+            |intrinsics| intrinsics.object_prototype.clone(),
+            None,
+        )?;
+
+        // 4. Let calleeContext be PrepareForOrdinaryCall(F, newTarget).
+        prepare_for_ordinary_call(agent, self, Some(new_target.clone()));
+
+        // 5. Assert: calleeContext is now the running execution context.
+        // 6. If kind is base, then
+        // a. Perform OrdinaryCallBindThis(F, calleeContext, thisArgument).
+        ordinary_call_bind_this(agent, self, &JSValue::from(this_argument.clone()));
+
+        // b. Let initializeResult be Completion(InitializeInstanceElements(thisArgument, F)).
+        // c. If initializeResult is an abrupt completion, then
+        // NOTE: Class fields are not supported yet.
+
+        // 7. Let constructorEnv be the LexicalEnvironment of calleeContext.
+        // NOTE: Only derived constructors read their this binding from constructorEnv.
+
+        agent.notify_debugger(|debugger, context| {
+            debugger.on_call(&function_name(&self.data()), args, context)
+        });
+
+        // 8. Let result be Completion(OrdinaryCallEvaluateBody(F, argumentsList)).
+        let result = ordinary_call_evaluate_body(agent, self, args);
+
+        if let Ok(value) = &result {
+            agent.notify_debugger(|debugger, context| {
+                debugger.on_return(&function_name(&self.data()), value, context)
+            });
+        }
+
+        // 9. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+        agent.pop_execution_context();
+
+        // 10. If result is a return completion, then
+        let result = result?;
+
+        // a. If result.[[Value]] is an Object, return result.[[Value]].
+        if let JSValue::Object(object) = result {
+            return Ok(object);
+        }
+
+        // b. If kind is base, return thisArgument.
+        // NOTE: Steps 10.c to 14 only apply to derived constructors.
+        Ok(this_argument)
+    }
+}
+
+/// 10.3 Built-in Function Objects
+/// https://262.ecma-international.org/16.0/#sec-built-in-function-objects
+pub(crate) struct BuiltinFunctionObject(pub(crate) ObjectAddr);
+
+impl ObjectMeta for BuiltinFunctionObject {
+    fn addr(&self) -> ObjectAddr {
+        self.0.clone()
+    }
+
+    fn data(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+}
+
+impl ObjectEssentialInternalMethods for BuiltinFunctionObject {
+    fn get_prototype_of(&self) -> Option<ObjectAddr> {
+        ordinary_get_prototype_of(self)
+    }
+
+    fn set_prototype_of(&self, prototype: Option<ObjectAddr>) -> bool {
+        ordinary_set_prototype_of(self, prototype)
+    }
+
+    fn is_extensible(&self) -> bool {
+        ordinary_is_extensible(self)
+    }
+
+    fn prevent_extensions(&self) -> bool {
+        ordinary_prevent_extensions(self)
+    }
+
+    fn get_own_property(
+        &self,
+        key: &JSObjectPropKey,
+    ) -> CompletionRecord<Option<JSObjectPropDescriptor>> {
+        ordinary_get_own_property(self, key)
+    }
+
+    fn define_own_property(
+        &self,
+        key: &JSObjectPropKey,
+        descriptor: JSObjectPropDescriptor,
+    ) -> CompletionRecord<bool> {
+        ordinary_define_own_property(self, key, descriptor)
+    }
+
+    fn has_property(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        ordinary_has_property(self, key)
+    }
+
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
+        ordinary_get(agent, self, key, receiver)
+    }
+
+    fn set(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        value: JSValue,
+        receiver: JSValue,
+    ) -> CompletionRecord<bool> {
+        ordinary_set(agent, self, key, value, receiver)
+    }
+
+    fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        ordinary_delete(self, key)
+    }
+
+    fn own_property_keys(&self) -> Vec<JSObjectPropKey> {
+        ordinary_own_property_keys(self)
+    }
+}
+
+impl ObjectExtraInternalMethods for BuiltinFunctionObject {
+    /// 10.3.1 [[Call]] ( thisArgument, argumentsList )
+    /// https://262.ecma-international.org/16.0/#sec-built-in-function-objects-call-thisargument-argumentslist
+    fn call(
+        &self,
+        agent: &mut JSAgent,
+        this_value: &JSValue,
+        args: &[JSValue],
+    ) -> CompletionRecord<JSValue> {
+        // 1. Return ? BuiltinCallOrConstruct(F, thisArgument, argumentsList, undefined).
+        builtin_call_or_construct(agent, self, this_value, args, None)
     }
 
+    /// 10.3.2 [[Construct]] ( argumentsList, newTarget )
+    /// https://262.ecma-international.org/16.0/#sec-built-in-function-objects-construct-argumentslist-newtarget
     fn construct(
         &self,
-        _args: &[JSValue],
-        _new_target: &impl ObjectExtraInternalMethods,
+        agent: &mut JSAgent,
+        args: &[JSValue],
+        new_target: &ObjectAddr,
     ) -> CompletionRecord<ObjectAddr> {
-        todo!()
+        // 1. Let result be ? BuiltinCallOrConstruct(F, uninitialized, argumentsList, newTarget).
+        // NOTE: An uninitialized this value is represented by undefined, as built-in constructors do not read it.
+        let result =
+            builtin_call_or_construct(agent, self, &JSValue::Undefined, args, Some(new_target))?;
+
+        // 2. Assert: result is an Object.
+        // 3. Return result.
+        ObjectAddr::try_from(result)
+    }
+}
+
+/// 10.4.2 Array Exotic Objects
+/// https://262.ecma-international.org/16.0/#sec-array-exotic-objects
+pub(crate) struct ArrayExoticObject(pub(crate) ObjectAddr);
+
+impl ObjectMeta for ArrayExoticObject {
+    fn addr(&self) -> ObjectAddr {
+        self.0.clone()
+    }
+
+    fn data(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+}
+
+impl ObjectEssentialInternalMethods for ArrayExoticObject {
+    fn get_prototype_of(&self) -> Option<ObjectAddr> {
+        ordinary_get_prototype_of(self)
+    }
+
+    fn set_prototype_of(&self, prototype: Option<ObjectAddr>) -> bool {
+        ordinary_set_prototype_of(self, prototype)
+    }
+
+    fn is_extensible(&self) -> bool {
+        ordinary_is_extensible(self)
+    }
+
+    fn prevent_extensions(&self) -> bool {
+        ordinary_prevent_extensions(self)
+    }
+
+    fn get_own_property(
+        &self,
+        key: &JSObjectPropKey,
+    ) -> CompletionRecord<Option<JSObjectPropDescriptor>> {
+        ordinary_get_own_property(self, key)
+    }
+
+    /// 10.4.2.1 [[DefineOwnProperty]] ( P, Desc )
+    /// https://262.ecma-international.org/16.0/#sec-array-exotic-objects-defineownproperty-p-desc
+    fn define_own_property(
+        &self,
+        key: &JSObjectPropKey,
+        descriptor: JSObjectPropDescriptor,
+    ) -> CompletionRecord<bool> {
+        let length_key = JSObjectPropKey::String("length".into());
+
+        // 1. If P is "length", then
+        if *key == length_key {
+            // a. Return ? ArraySetLength(A, Desc).
+            return array_set_length(self, descriptor);
+        }
+
+        // 2. Else if P is an array index, then
+        if let Some(index) = key.as_array_index() {
+            // a. Let lengthDesc be OrdinaryGetOwnProperty(A, "length").
+            // b. Assert: IsDataDescriptor(lengthDesc) is true.
+            // c. Assert: lengthDesc.[[Configurable]] is false.
+            let mut length_desc = ordinary_get_own_property(self, &length_key)?.unwrap();
+
+            // d. Let length be lengthDesc.[[Value]].
+            // e. Assert: length is a non-negative integral Number.
+            let length = array_length(&length_desc);
+
+            // f. Let index be ! ToUint32(P).
+            // g. If index ≥ length and lengthDesc.[[Writable]] is false, return false.
+            if index >= length && length_desc.writable == Some(false) {
+                return Ok(false);
+            }
+
+            // h. Let succeeded be ! OrdinaryDefineOwnProperty(A, P, Desc).
+            // i. If succeeded is false, return false.
+            if !ordinary_define_own_property(self, key, descriptor)? {
+                return Ok(false);
+            }
+
+            // j. If index ≥ length, then
+            if index >= length {
+                // i. Set lengthDesc.[[Value]] to index + 1𝔽.
+                length_desc.value = Some(JSValue::from(index + 1));
+
+                // ii. Set succeeded to ! OrdinaryDefineOwnProperty(A, "length", lengthDesc).
+                let succeeded = ordinary_define_own_property(self, &length_key, length_desc)?;
+
+                // iii. Assert: succeeded is true.
+                debug_assert!(succeeded);
+            }
+
+            // k. Return true.
+            return Ok(true);
+        }
+
+        // 3. Return ? OrdinaryDefineOwnProperty(A, P, Desc).
+        ordinary_define_own_property(self, key, descriptor)
+    }
+
+    fn has_property(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        ordinary_has_property(self, key)
+    }
+
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
+        ordinary_get(agent, self, key, receiver)
+    }
+
+    fn set(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        value: JSValue,
+        receiver: JSValue,
+    ) -> CompletionRecord<bool> {
+        ordinary_set(agent, self, key, value, receiver)
+    }
+
+    fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        ordinary_delete(self, key)
+    }
+
+    fn own_property_keys(&self) -> Vec<JSObjectPropKey> {
+        ordinary_own_property_keys(self)
+    }
+}
+
+/// 10.4.3 String Exotic Objects
+/// https://262.ecma-international.org/16.0/#sec-string-exotic-objects
+pub(crate) struct StringExoticObject(pub(crate) ObjectAddr);
+
+impl ObjectMeta for StringExoticObject {
+    fn addr(&self) -> ObjectAddr {
+        self.0.clone()
+    }
+
+    fn data(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+}
+
+impl ObjectEssentialInternalMethods for StringExoticObject {
+    fn get_prototype_of(&self) -> Option<ObjectAddr> {
+        ordinary_get_prototype_of(self)
+    }
+
+    fn set_prototype_of(&self, prototype: Option<ObjectAddr>) -> bool {
+        ordinary_set_prototype_of(self, prototype)
+    }
+
+    fn is_extensible(&self) -> bool {
+        ordinary_is_extensible(self)
+    }
+
+    fn prevent_extensions(&self) -> bool {
+        ordinary_prevent_extensions(self)
+    }
+
+    /// 10.4.3.1 [[GetOwnProperty]] ( P )
+    /// https://262.ecma-international.org/16.0/#sec-string-exotic-objects-getownproperty-p
+    fn get_own_property(
+        &self,
+        key: &JSObjectPropKey,
+    ) -> CompletionRecord<Option<JSObjectPropDescriptor>> {
+        // 1. Let desc be OrdinaryGetOwnProperty(S, P).
+        let desc = ordinary_get_own_property(self, key)?;
+
+        // 2. If desc is not undefined, return desc.
+        if desc.is_some() {
+            return Ok(desc);
+        }
+
+        // 3. Return StringGetOwnProperty(S, P).
+        Ok(string_get_own_property(self, key))
+    }
+
+    /// 10.4.3.2 [[DefineOwnProperty]] ( P, Desc )
+    /// https://262.ecma-international.org/16.0/#sec-string-exotic-objects-defineownproperty-p-desc
+    fn define_own_property(
+        &self,
+        key: &JSObjectPropKey,
+        descriptor: JSObjectPropDescriptor,
+    ) -> CompletionRecord<bool> {
+        // 1. Let stringDesc be StringGetOwnProperty(S, P).
+        // 2. If stringDesc is not undefined, then
+        if let Some(string_desc) = string_get_own_property(self, key) {
+            // a. Let extensible be S.[[Extensible]].
+            let extensible = self.data().extensible;
+
+            // b. Return IsCompatiblePropertyDescriptor(extensible, Desc, stringDesc).
+            return Ok(is_compatible_property_descriptor(
+                extensible,
+                descriptor,
+                Some(string_desc),
+            ));
+        }
+
+        // 3. Return ! OrdinaryDefineOwnProperty(S, P, Desc).
+        ordinary_define_own_property(self, key, descriptor)
+    }
+
+    fn has_property(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        ordinary_has_property(self, key)
+    }
+
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
+        ordinary_get(agent, self, key, receiver)
+    }
+
+    fn set(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        value: JSValue,
+        receiver: JSValue,
+    ) -> CompletionRecord<bool> {
+        ordinary_set(agent, self, key, value, receiver)
+    }
+
+    fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        ordinary_delete(self, key)
+    }
+
+    /// 10.4.3.3 [[OwnPropertyKeys]] ( )
+    /// https://262.ecma-international.org/16.0/#sec-string-exotic-objects-ownpropertykeys
+    fn own_property_keys(&self) -> Vec<JSObjectPropKey> {
+        // 1. Let keys be a new empty List.
+        // 2. Let str be O.[[StringData]].
+        // 3. Assert: str is a String.
+        let str = self.data().slots().string_data().unwrap();
+
+        // 4. Let len be the length of str.
+        let len = str.len();
+
+        // 5. For each integer i such that 0 ≤ i < len, in ascending order, do
+        // a. Append ! ToString(𝔽(i)) to keys.
+        let mut keys = (0..len)
+            .map(|i| JSObjectPropKey::String(i.to_string().into()))
+            .collect::<Vec<_>>();
+
+        // 6. For each own property key P of O such that P is an array index and ! ToIntegerOrInfinity(P) ≥ len, in ascending numeric index order, do
+        // 7. For each own property key P of O such that P is a String and P is not an array index, in ascending chronological order of property creation, do
+        // 8. For each own property key P of O such that P is a Symbol, in ascending chronological order of property creation, do
+        // a. Append P to keys.
+        // NOTE: The own properties of a String object never include the indices below its length, so the remaining keys are in the order of OrdinaryOwnPropertyKeys.
+        keys.extend(ordinary_own_property_keys(self));
+
+        // 9. Return keys.
+        keys
+    }
+}
+
+/// 10.4.4 Arguments Exotic Objects
+/// https://262.ecma-international.org/16.0/#sec-arguments-exotic-objects
+pub(crate) struct ArgumentsExoticObject(pub(crate) ObjectAddr);
+
+impl ObjectMeta for ArgumentsExoticObject {
+    fn addr(&self) -> ObjectAddr {
+        self.0.clone()
+    }
+
+    fn data(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+}
+
+impl ObjectEssentialInternalMethods for ArgumentsExoticObject {
+    fn get_prototype_of(&self) -> Option<ObjectAddr> {
+        ordinary_get_prototype_of(self)
+    }
+
+    fn set_prototype_of(&self, prototype: Option<ObjectAddr>) -> bool {
+        ordinary_set_prototype_of(self, prototype)
+    }
+
+    fn is_extensible(&self) -> bool {
+        ordinary_is_extensible(self)
+    }
+
+    fn prevent_extensions(&self) -> bool {
+        ordinary_prevent_extensions(self)
+    }
+
+    /// 10.4.4.1 [[GetOwnProperty]] ( P )
+    /// https://262.ecma-international.org/16.0/#sec-arguments-exotic-objects-getownproperty-p
+    fn get_own_property(
+        &self,
+        key: &JSObjectPropKey,
+    ) -> CompletionRecord<Option<JSObjectPropDescriptor>> {
+        // 1. Let desc be OrdinaryGetOwnProperty(args, P).
+        // 2. If desc is undefined, return undefined.
+        let Some(mut desc) = ordinary_get_own_property(self, key)? else {
+            return Ok(None);
+        };
+
+        // 3. Let map be args.[[ParameterMap]].
+        // 4. Let isMapped be ! HasOwnProperty(map, P).
+        // 5. If isMapped is true, then
+        if let Some(mapped) = mapped_argument(self, key) {
+            // a. Set desc.[[Value]] to ! Get(map, P).
+            desc.value = Some(mapped.get()?);
+        }
+
+        // 6. Return desc.
+        Ok(Some(desc))
+    }
+
+    /// 10.4.4.2 [[DefineOwnProperty]] ( P, Desc )
+    /// https://262.ecma-international.org/16.0/#sec-arguments-exotic-objects-defineownproperty-p-desc
+    fn define_own_property(
+        &self,
+        key: &JSObjectPropKey,
+        descriptor: JSObjectPropDescriptor,
+    ) -> CompletionRecord<bool> {
+        // 1. Let map be args.[[ParameterMap]].
+        // 2. Let isMapped be ! HasOwnProperty(map, P).
+        let mapped = mapped_argument(self, key);
+
+        // 3. Let newArgDesc be Desc.
+        let mut new_arg_desc = descriptor.clone();
+
+        // 4. If isMapped is true and IsDataDescriptor(Desc) is true, then
+        if let Some(mapped) = &mapped {
+            // a. If Desc does not have a [[Value]] field, Desc has a [[Writable]] field, and Desc.[[Writable]] is false, then
+            if descriptor.is_data_descriptor()
+                && descriptor.value.is_none()
+                && descriptor.writable == Some(false)
+            {
+                // i. Set newArgDesc to a copy of Desc.
+                // ii. Set newArgDesc.[[Value]] to ! Get(map, P).
+                new_arg_desc.value = Some(mapped.get()?);
+            }
+        }
+
+        // 5. Let allowed be ! OrdinaryDefineOwnProperty(args, P, newArgDesc).
+        let allowed = ordinary_define_own_property(self, key, new_arg_desc)?;
+
+        // 6. If allowed is false, return false.
+        if !allowed {
+            return Ok(false);
+        }
+
+        // 7. If isMapped is true, then
+        if let Some(mapped) = mapped {
+            // a. If IsAccessorDescriptor(Desc) is true, then
+            if descriptor.is_accessor_descriptor() {
+                // i. Perform ! map.[[Delete]](P).
+                unmap_argument(self, key);
+            }
+            // b. Else,
+            else {
+                // i. If Desc has a [[Value]] field, then
+                if let Some(value) = descriptor.value {
+                    // 1. Assert: The following Set will succeed, since formal parameters mapped by arguments objects are always writable.
+                    // 2. Perform ! Set(map, P, Desc.[[Value]], false).
+                    mapped.set(value)?;
+                }
+
+                // ii. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, then
+                if descriptor.writable == Some(false) {
+                    // 1. Perform ! map.[[Delete]](P).
+                    unmap_argument(self, key);
+                }
+            }
+        }
+
+        // 8. Return true.
+        Ok(true)
+    }
+
+    fn has_property(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        ordinary_has_property(self, key)
+    }
+
+    /// 10.4.4.3 [[Get]] ( P, Receiver )
+    /// https://262.ecma-international.org/16.0/#sec-arguments-exotic-objects-get-p-receiver
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
+        // 1. Let map be args.[[ParameterMap]].
+        // 2. Let isMapped be ! HasOwnProperty(map, P).
+        match mapped_argument(self, key) {
+            // 3. If isMapped is false, then
+            // a. Return ? OrdinaryGet(args, P, Receiver).
+            None => ordinary_get(agent, self, key, receiver),
+            // 4. Else,
+            // a. Assert: map contains a formal parameter mapping for P.
+            // b. Return ! Get(map, P).
+            Some(mapped) => mapped.get(),
+        }
+    }
+
+    /// 10.4.4.4 [[Set]] ( P, V, Receiver )
+    /// https://262.ecma-international.org/16.0/#sec-arguments-exotic-objects-set-p-v-receiver
+    fn set(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        value: JSValue,
+        receiver: JSValue,
+    ) -> CompletionRecord<bool> {
+        // 1. If SameValue(args, Receiver) is false, then
+        // a. Let isMapped be false.
+        // 2. Else,
+        // a. Let map be args.[[ParameterMap]].
+        // b. Let isMapped be ! HasOwnProperty(map, P).
+        let mapped = if same_value(&JSValue::from(self.addr()), &receiver) {
+            mapped_argument(self, key)
+        } else {
+            None
+        };
+
+        // 3. If isMapped is true, then
+        if let Some(mapped) = mapped {
+            // a. Assert: The following Set will succeed, since formal parameters mapped by arguments objects are always writable.
+            // b. Perform ! Set(map, P, V, false).
+            mapped.set(value.clone())?;
+        }
+
+        // 4. Return ? OrdinarySet(args, P, V, Receiver).
+        ordinary_set(agent, self, key, value, receiver)
+    }
+
+    /// 10.4.4.5 [[Delete]] ( P )
+    /// https://262.ecma-international.org/16.0/#sec-arguments-exotic-objects-delete-p
+    fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        // 1. Let map be args.[[ParameterMap]].
+        // 2. Let isMapped be ! HasOwnProperty(map, P).
+        let is_mapped = mapped_argument(self, key).is_some();
+
+        // 3. Let result be ? OrdinaryDelete(args, P).
+        let result = ordinary_delete(self, key)?;
+
+        // 4. If result is true and isMapped is true, then
+        if result && is_mapped {
+            // a. Perform ! map.[[Delete]](P).
+            unmap_argument(self, key);
+        }
+
+        // 5. Return result.
+        Ok(result)
+    }
+
+    fn own_property_keys(&self) -> Vec<JSObjectPropKey> {
+        ordinary_own_property_keys(self)
     }
 }
 
@@ -231,11 +947,11 @@ impl ObjectMeta for ImmutablePrototypeExoticObject {
         self.0.clone()
     }
 
-    fn data(&self) -> RefMut<ObjectData> {
+    fn data(&self) -> RefMut<'_, ObjectData> {
         self.0.borrow_mut()
     }
 
-    fn data_mut(&self) -> RefMut<ObjectData> {
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
         self.0.borrow_mut()
     }
 }
@@ -279,17 +995,23 @@ impl ObjectEssentialInternalMethods for ImmutablePrototypeExoticObject {
         ordinary_has_property(self, key)
     }
 
-    fn get(&self, key: &JSObjectPropKey, receiver: &JSValue) -> CompletionRecord<JSValue> {
-        ordinary_get(self, key, receiver)
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
+        ordinary_get(agent, self, key, receiver)
     }
 
     fn set(
         &self,
+        agent: &mut JSAgent,
         key: &JSObjectPropKey,
         value: JSValue,
         receiver: JSValue,
     ) -> CompletionRecord<bool> {
-        ordinary_set(self, key, value, receiver)
+        ordinary_set(agent, self, key, value, receiver)
     }
 
     fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
@@ -300,3 +1022,198 @@ impl ObjectEssentialInternalMethods for ImmutablePrototypeExoticObject {
         ordinary_own_property_keys(self)
     }
 }
+
+/// 10.4.6 Module Namespace Exotic Objects
+/// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects
+pub(crate) struct ModuleNamespaceExoticObject(pub(crate) ObjectAddr);
+
+impl ObjectMeta for ModuleNamespaceExoticObject {
+    fn addr(&self) -> ObjectAddr {
+        self.0.clone()
+    }
+
+    fn data(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+
+    fn data_mut(&self) -> RefMut<'_, ObjectData> {
+        self.0.borrow_mut()
+    }
+}
+
+impl ObjectEssentialInternalMethods for ModuleNamespaceExoticObject {
+    /// 10.4.6.1 [[GetPrototypeOf]] ( )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-getprototypeof
+    fn get_prototype_of(&self) -> Option<ObjectAddr> {
+        // 1. Return null.
+        None
+    }
+
+    /// 10.4.6.2 [[SetPrototypeOf]] ( V )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-setprototypeof-v
+    fn set_prototype_of(&self, prototype: Option<ObjectAddr>) -> bool {
+        // 1. Return ! SetImmutablePrototype(O, V).
+        set_immutable_prototype(self, prototype)
+    }
+
+    /// 10.4.6.3 [[IsExtensible]] ( )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-isextensible
+    fn is_extensible(&self) -> bool {
+        // 1. Return false.
+        false
+    }
+
+    /// 10.4.6.4 [[PreventExtensions]] ( )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-preventextensions
+    fn prevent_extensions(&self) -> bool {
+        // 1. Return true.
+        true
+    }
+
+    /// 10.4.6.5 [[GetOwnProperty]] ( P )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-getownproperty-p
+    fn get_own_property(
+        &self,
+        key: &JSObjectPropKey,
+    ) -> CompletionRecord<Option<JSObjectPropDescriptor>> {
+        // 1. If P is a Symbol, return OrdinaryGetOwnProperty(O, P).
+        if key.is_symbol() {
+            return ordinary_get_own_property(self, key);
+        }
+
+        // 2. Let exports be O.[[Exports]].
+        // 3. If exports does not contain P, return undefined.
+        let Some(name) = exported_name(self, key) else {
+            return Ok(None);
+        };
+
+        // 4. Let value be ? O.[[Get]](P, O).
+        let value = namespace_binding_value(self, name)?;
+
+        // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
+        Ok(Some(JSObjectPropDescriptor {
+            value: Some(value),
+            writable: Some(true),
+            enumerable: Some(true),
+            configurable: Some(false),
+            ..JSObjectPropDescriptor::default()
+        }))
+    }
+
+    /// 10.4.6.6 [[DefineOwnProperty]] ( P, Desc )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-defineownproperty-p-desc
+    fn define_own_property(
+        &self,
+        key: &JSObjectPropKey,
+        descriptor: JSObjectPropDescriptor,
+    ) -> CompletionRecord<bool> {
+        // 1. If P is a Symbol, return ! OrdinaryDefineOwnProperty(O, P, Desc).
+        if key.is_symbol() {
+            return ordinary_define_own_property(self, key, descriptor);
+        }
+
+        // 2. Let current be ? O.[[GetOwnProperty]](P).
+        // 3. If current is undefined, return false.
+        let Some(current) = self.get_own_property(key)? else {
+            return Ok(false);
+        };
+
+        // 4. If Desc has a [[Configurable]] field and Desc.[[Configurable]] is true, return false.
+        // 5. If Desc has an [[Enumerable]] field and Desc.[[Enumerable]] is false, return false.
+        // 6. If IsAccessorDescriptor(Desc) is true, return false.
+        // 7. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, return false.
+        if descriptor.configurable == Some(true)
+            || descriptor.enumerable == Some(false)
+            || descriptor.is_accessor_descriptor()
+            || descriptor.writable == Some(false)
+        {
+            return Ok(false);
+        }
+
+        // 8. If Desc has a [[Value]] field, return SameValue(Desc.[[Value]], current.[[Value]]).
+        if let (Some(value), Some(current_value)) = (&descriptor.value, &current.value) {
+            return Ok(same_value(value, current_value));
+        }
+
+        // 9. Return true.
+        Ok(true)
+    }
+
+    /// 10.4.6.7 [[HasProperty]] ( P )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-hasproperty-p
+    fn has_property(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        // 1. If P is a Symbol, return ! OrdinaryHasProperty(O, P).
+        if key.is_symbol() {
+            return ordinary_has_property(self, key);
+        }
+
+        // 2. Let exports be O.[[Exports]].
+        // 3. If exports contains P, return true.
+        // 4. Return false.
+        Ok(exported_name(self, key).is_some())
+    }
+
+    /// 10.4.6.8 [[Get]] ( P, Receiver )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-get-p-receiver
+    fn get(
+        &self,
+        agent: &mut JSAgent,
+        key: &JSObjectPropKey,
+        receiver: &JSValue,
+    ) -> CompletionRecord<JSValue> {
+        // 1. If P is a Symbol, then
+        if key.is_symbol() {
+            // a. Return ! OrdinaryGet(O, P, Receiver).
+            return ordinary_get(agent, self, key, receiver);
+        }
+
+        // 2. Let exports be O.[[Exports]].
+        // 3. If exports does not contain P, return undefined.
+        let Some(name) = exported_name(self, key) else {
+            return Ok(JSValue::Undefined);
+        };
+
+        namespace_binding_value(self, name)
+    }
+
+    /// 10.4.6.9 [[Set]] ( P, V, Receiver )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-set-p-v-receiver
+    fn set(
+        &self,
+        _agent: &mut JSAgent,
+        _key: &JSObjectPropKey,
+        _value: JSValue,
+        _receiver: JSValue,
+    ) -> CompletionRecord<bool> {
+        // 1. Return false.
+        Ok(false)
+    }
+
+    /// 10.4.6.10 [[Delete]] ( P )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-delete-p
+    fn delete(&self, key: &JSObjectPropKey) -> CompletionRecord<bool> {
+        // 1. If P is a Symbol, then
+        if key.is_symbol() {
+            // a. Return ! OrdinaryDelete(O, P).
+            return ordinary_delete(self, key);
+        }
+
+        // 2. Let exports be O.[[Exports]].
+        // 3. If exports contains P, return false.
+        // 4. Return true.
+        Ok(exported_name(self, key).is_none())
+    }
+
+    /// 10.4.6.11 [[OwnPropertyKeys]] ( )
+    /// https://262.ecma-international.org/16.0/#sec-module-namespace-exotic-objects-ownpropertykeys
+    fn own_property_keys(&self) -> Vec<JSObjectPropKey> {
+        // 1. Let exports be O.[[Exports]].
+        // 2. Let symbolKeys be OrdinaryOwnPropertyKeys(O).
+        // 3. Return the list-concatenation of exports and symbolKeys.
+        namespace_exports(self)
+            .into_iter()
+            .map(JSObjectPropKey::from)
+            .chain(ordinary_own_property_keys(self))
+            .collect()
+    }
+}
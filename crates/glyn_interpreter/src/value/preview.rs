@@ -0,0 +1,280 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::value::{
+    object::{property::JSObjectPropKey, ObjectData, ObjectKind},
+    string::JSString,
+    JSValue,
+};
+
+/// The number of characters of a string shown before it is truncated.
+const MAX_STRING_LENGTH: usize = 24;
+
+/// The number of property keys or elements of an object shown before the rest are elided.
+const MAX_ENTRIES: usize = 3;
+
+/// A short description of a value for error messages, made up of its type name and a truncated rendering of the value, like `a string ("abc")` or `an object ({a, b, …})`.
+///
+/// Rendering the preview never runs user code, as only the own data properties of objects are read.
+pub(crate) struct ValuePreview<'a>(&'a JSValue);
+
+impl JSValue {
+    pub(crate) fn preview(&self) -> ValuePreview<'_> {
+        ValuePreview(self)
+    }
+
+    /// The type name of the value with its indefinite article, like `a number` or `an object`.
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            JSValue::Undefined => "undefined",
+            JSValue::Null => "null",
+            JSValue::Bool(_) => "a boolean",
+            JSValue::String(_) => "a string",
+            JSValue::Number(_) => "a number",
+            JSValue::BigInt(_) => "a bigint",
+            JSValue::Symbol(_) => "a symbol",
+            JSValue::Object(_) => "an object",
+        }
+    }
+}
+
+impl Display for ValuePreview<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            JSValue::Undefined | JSValue::Null => f.write_str(self.0.type_name()),
+            // NOTE: BigInt values cannot be converted to strings yet.
+            JSValue::BigInt(_) => f.write_str(self.0.type_name()),
+            JSValue::Object(object) => {
+                // NOTE: The object may already be borrowed by the operation that is failing, in which case only its type is shown.
+                let Ok(data) = object.try_borrow() else {
+                    return f.write_str(self.0.type_name());
+                };
+
+                match data.kind() {
+                    ObjectKind::Function | ObjectKind::BuiltinFunction => {
+                        write!(f, "a function ({})", function_name(&data))
+                    }
+                    ObjectKind::Array => {
+                        f.write_str("an array (")?;
+                        write_array(f, &data)?;
+                        f.write_char(')')
+                    }
+                    _ => {
+                        f.write_str("an object (")?;
+                        write_object(f, &data)?;
+                        f.write_char(')')
+                    }
+                }
+            }
+            value => {
+                write!(f, "{} (", value.type_name())?;
+                write_primitive(f, value)?;
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+/// Writes a value nested in an array, rendering objects without their contents.
+fn write_nested(f: &mut Formatter<'_>, value: &JSValue) -> fmt::Result {
+    let JSValue::Object(object) = value else {
+        return write_primitive(f, value);
+    };
+
+    match object.try_borrow().map(|data| data.kind().clone()) {
+        Ok(ObjectKind::Function | ObjectKind::BuiltinFunction) => f.write_str("function"),
+        Ok(ObjectKind::Array) => f.write_str("[…]"),
+        _ => f.write_str("{…}"),
+    }
+}
+
+fn write_primitive(f: &mut Formatter<'_>, value: &JSValue) -> fmt::Result {
+    match value {
+        JSValue::Undefined => f.write_str("undefined"),
+        JSValue::Null => f.write_str("null"),
+        JSValue::Bool(value) => write!(f, "{value}"),
+        JSValue::String(value) => {
+            f.write_char('"')?;
+
+            // NOTE: Only as many code units are read as can make up the characters shown and the one after them, so a long rope is not flattened.
+            let mut chars = char::decode_utf16(value.prefix(2 * MAX_STRING_LENGTH + 1));
+
+            for c in chars.by_ref().take(MAX_STRING_LENGTH) {
+                match c {
+                    Ok(c) => write!(f, "{}", c.escape_debug())?,
+                    Err(error) => write!(f, "\\u{{{:x}}}", error.unpaired_surrogate())?,
+                }
+            }
+
+            if chars.next().is_some() {
+                f.write_char('…')?;
+            }
+
+            f.write_char('"')
+        }
+        JSValue::Number(value) => write!(f, "{}", value.to_string(10)),
+        JSValue::BigInt(_) => f.write_str("bigint"),
+        JSValue::Symbol(symbol) => match &symbol.description {
+            Some(description) => write!(f, "Symbol({description})"),
+            None => f.write_str("Symbol()"),
+        },
+        JSValue::Object(_) => unreachable!(),
+    }
+}
+
+/// The value of the own "name" data property of a function, if it is a non-empty string.
+pub(crate) fn function_name(data: &ObjectData) -> String {
+    let name = data
+        .get_property(&JSObjectPropKey::from(JSString::from("name")))
+        .and_then(|descriptor| descriptor.value.clone());
+
+    match name {
+        Some(JSValue::String(name)) if !name.is_empty() => name.to_string(),
+        _ => "anonymous".to_string(),
+    }
+}
+
+fn write_array(f: &mut Formatter<'_>, data: &ObjectData) -> fmt::Result {
+    let mut elements = data.values().take(data.element_indices().count());
+
+    f.write_char('[')?;
+
+    for (index, descriptor) in elements.by_ref().take(MAX_ENTRIES).enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+
+        match &descriptor.value {
+            Some(value) => write_nested(f, value)?,
+            None => f.write_str("accessor")?,
+        }
+    }
+
+    if elements.next().is_some() {
+        f.write_str(", …")?;
+    }
+
+    f.write_char(']')
+}
+
+fn write_object(f: &mut Formatter<'_>, data: &ObjectData) -> fmt::Result {
+    let mut keys = data.keys().filter_map(|key| match key.into_owned() {
+        JSObjectPropKey::String(key) => Some(key),
+        _ => None,
+    });
+
+    f.write_char('{')?;
+
+    for (index, key) in keys.by_ref().take(MAX_ENTRIES).enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+
+        write!(f, "{key}")?;
+    }
+
+    if keys.next().is_some() {
+        f.write_str(", …")?;
+    }
+
+    f.write_char('}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        abstract_ops::{
+            object_operations::create_data_property_or_throw, ordinary::ordinary_object_create,
+        },
+        runtime::agent::JSAgent,
+        value::object::ObjectAddr,
+    };
+
+    fn key(name: &str) -> JSObjectPropKey {
+        JSObjectPropKey::from(JSString::from(name))
+    }
+
+    fn object_with_keys(agent: &mut JSAgent, names: &[&str]) -> JSValue {
+        let object = ordinary_object_create(agent, None, None);
+
+        for name in names {
+            create_data_property_or_throw(&object, &key(name), JSValue::Null).unwrap();
+        }
+
+        JSValue::from(object)
+    }
+
+    #[test]
+    fn previews_primitives() {
+        assert_eq!(JSValue::Undefined.preview().to_string(), "undefined");
+        assert_eq!(JSValue::Null.preview().to_string(), "null");
+        assert_eq!(
+            JSValue::from(true).preview().to_string(),
+            "a boolean (true)"
+        );
+        assert_eq!(JSValue::from(1.5).preview().to_string(), "a number (1.5)");
+        assert_eq!(
+            JSValue::from(f64::NAN).preview().to_string(),
+            "a number (NaN)"
+        );
+        assert_eq!(
+            JSValue::Symbol(String::from("tag").into())
+                .preview()
+                .to_string(),
+            "a symbol (Symbol(tag))"
+        );
+    }
+
+    #[test]
+    fn truncates_and_escapes_strings() {
+        assert_eq!(
+            JSValue::from(String::from("a\"b\n")).preview().to_string(),
+            "a string (\"a\\\"b\\n\")"
+        );
+        assert_eq!(
+            JSValue::from("x".repeat(MAX_STRING_LENGTH))
+                .preview()
+                .to_string(),
+            format!("a string (\"{}\")", "x".repeat(MAX_STRING_LENGTH))
+        );
+        assert_eq!(
+            JSValue::from("x".repeat(MAX_STRING_LENGTH + 1))
+                .preview()
+                .to_string(),
+            format!("a string (\"{}…\")", "x".repeat(MAX_STRING_LENGTH))
+        );
+    }
+
+    #[test]
+    fn elides_object_keys() {
+        let mut agent = JSAgent::default();
+
+        assert_eq!(
+            object_with_keys(&mut agent, &[]).preview().to_string(),
+            "an object ({})"
+        );
+        assert_eq!(
+            object_with_keys(&mut agent, &["a", "b", "c"])
+                .preview()
+                .to_string(),
+            "an object ({a, b, c})"
+        );
+        assert_eq!(
+            object_with_keys(&mut agent, &["a", "b", "c", "d"])
+                .preview()
+                .to_string(),
+            "an object ({a, b, c, …})"
+        );
+    }
+
+    #[test]
+    fn previews_objects_that_are_already_borrowed() {
+        let mut agent = JSAgent::default();
+
+        let object = object_with_keys(&mut agent, &["a"]);
+        let addr = ObjectAddr::try_from(&object).unwrap();
+        let _borrow = addr.borrow_mut();
+
+        assert_eq!(object.preview().to_string(), "an object");
+    }
+}
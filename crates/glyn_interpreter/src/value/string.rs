@@ -1,47 +1,413 @@
+use std::{
+    cell::{OnceCell, RefCell},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter,
+    mem::{size_of, size_of_val},
+    ops::Deref,
+    rc::Rc,
+};
+
 use crate::{
+    gc::{record_string_allocation, HeapSize},
     runtime::{
-        completion::{throw_completion, ThrowCompletion},
-        reference::ReferenceName,
+        agent::range_error,
+        completion::{throw_completion, CompletionRecord, ThrowCompletion},
     },
     JSValue,
 };
 
+/// The number of code units a string can hold without allocating, which is as many as fit in the space taken up by a string held on the heap.
+const INLINE_CAPACITY: usize = 11;
+
+/// The length in code units at which concatenating two strings creates a rope rather than copying both of them.
+const MIN_ROPE_LENGTH: usize = 32;
+
+/// The depth at which a rope is flattened before it is concatenated to, so that dropping a rope does not recurse too deeply.
+const MAX_ROPE_DEPTH: usize = 256;
+
+/// The maximum number of code units in a string, beyond which creating a string throws a RangeError rather than allocating it.
+pub(crate) const MAX_STRING_LENGTH: usize = (1 << 29) - 24;
+
 /// 6.1.4 The String Type
 /// https://262.ecma-international.org/16.0/#sec-ecmascript-language-types-string-type
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Hash)]
-pub(crate) struct JSString(pub(crate) String);
+///
+/// A sequence of UTF-16 code units, which may include lone surrogates. Short strings are held inline, and concatenating long strings creates a rope which is only copied into a single buffer once its code units are read.
+#[derive(Clone)]
+pub struct JSString(Repr);
 
-impl JSString {
-    pub(crate) fn len(&self) -> usize {
-        self.0.len()
+#[derive(Clone)]
+enum Repr {
+    Inline {
+        len: u8,
+        units: [u16; INLINE_CAPACITY],
+    },
+    Heap(HeapUnits),
+    Rope(Rc<Rope>),
+}
+
+/// The code units of a string which are held on the heap, which are shared by every string cloned from it.
+#[derive(Clone)]
+struct HeapUnits(Rc<UnitBuffer>);
+
+/// The code units of a string which are held on the heap, which are accounted for as allocated on the heap of the agent which created them until they are dropped.
+struct UnitBuffer {
+    units: Box<[u16]>,
+    heap_size: Option<HeapSize>,
+}
+
+impl HeapUnits {
+    fn new(units: impl Into<Box<[u16]>>) -> Self {
+        let units = units.into();
+        let heap_size = record_string_allocation(HeapUnits::size(&units));
+
+        HeapUnits(Rc::new(UnitBuffer { units, heap_size }))
+    }
+
+    /// The bytes taken up by the allocations, including the reference counts.
+    fn size(units: &[u16]) -> usize {
+        size_of_val(units) + size_of::<UnitBuffer>() + 2 * size_of::<usize>()
+    }
+}
+
+impl Deref for HeapUnits {
+    type Target = [u16];
+
+    fn deref(&self) -> &[u16] {
+        &self.0.units
     }
+}
 
-    pub(crate) fn utf16_len(&self) -> usize {
-        self.0.chars().count()
+impl Drop for UnitBuffer {
+    fn drop(&mut self) {
+        if let Some(heap_size) = &self.heap_size {
+            heap_size.record_free(HeapUnits::size(&self.units));
+        }
+    }
+}
+
+/// The concatenation of two strings.
+struct Rope {
+    /// The strings the rope concatenates, which are dropped once the rope is flattened.
+    halves: RefCell<Option<(JSString, JSString)>>,
+    len: usize,
+    depth: usize,
+    /// The code units of the rope, which are copied out of its halves the first time they are read.
+    flat: OnceCell<HeapUnits>,
+    heap_size: Option<HeapSize>,
+}
+
+impl Rope {
+    /// The bytes taken up by the allocation of a rope, including the reference counts, apart from its flattened code units which are accounted for separately.
+    const SIZE: usize = size_of::<Rope>() + 2 * size_of::<usize>();
+
+    fn flatten(&self) -> &HeapUnits {
+        self.flat.get_or_init(|| {
+            let (left, right) = self
+                .halves
+                .take()
+                .expect("a rope which is not flattened holds its halves");
+
+            let mut units = Vec::with_capacity(self.len);
+
+            for leaf in left.leaves().chain(right.leaves()) {
+                units.extend_from_slice(leaf.code_units());
+            }
+
+            // NOTE: The halves are dropped here, so a flattened rope does not hold its code units twice, though they stay alive while other strings share them.
+            HeapUnits::new(units)
+        })
+    }
+}
+
+impl Drop for Rope {
+    fn drop(&mut self) {
+        if let Some(heap_size) = &self.heap_size {
+            heap_size.record_free(Rope::SIZE);
+        }
     }
 }
 
 impl JSString {
+    /// The number of code units in the string.
+    pub(crate) fn len(&self) -> usize {
+        match &self.0 {
+            Repr::Inline { len, .. } => *len as usize,
+            Repr::Heap(units) => units.len(),
+            Repr::Rope(rope) => rope.len,
+        }
+    }
+
     pub(crate) fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.len() == 0
+    }
+
+    /// The code units of the string, which flattens a rope the first time they are read.
+    pub(crate) fn code_units(&self) -> &[u16] {
+        match &self.0 {
+            Repr::Inline { len, units } => &units[..*len as usize],
+            Repr::Heap(units) => units,
+            Repr::Rope(rope) => rope.flatten(),
+        }
+    }
+
+    /// The substring of the string from `start` up to but not including `end`, both of which are indices of code units.
+    pub(crate) fn substring(&self, start: usize, end: usize) -> JSString {
+        JSString::from(&self.code_units()[start..end])
+    }
+
+    /// The string-concatenation of the string and `other`.
+    pub(crate) fn concat(&self, other: &JSString) -> JSString {
+        if self.is_empty() {
+            return other.clone();
+        }
+
+        if other.is_empty() {
+            return self.clone();
+        }
+
+        let len = self.len() + other.len();
+
+        if len < MIN_ROPE_LENGTH {
+            let mut units = Vec::with_capacity(len);
+
+            units.extend_from_slice(self.code_units());
+            units.extend_from_slice(other.code_units());
+
+            return JSString::from(units);
+        }
+
+        let left = self.shallow();
+        let right = other.shallow();
+        let depth = left.depth().max(right.depth()) + 1;
+
+        JSString(Repr::Rope(Rc::new(Rope {
+            halves: RefCell::new(Some((left, right))),
+            len,
+            depth,
+            flat: OnceCell::new(),
+            heap_size: record_string_allocation(Rope::SIZE),
+        })))
+    }
+
+    /// The string-concatenation of the string and `other`, throwing a RangeError if it would be longer than [`MAX_STRING_LENGTH`].
+    pub(crate) fn try_concat(&self, other: &JSString) -> CompletionRecord<JSString> {
+        if self.len() + other.len() > MAX_STRING_LENGTH {
+            return range_error("Invalid string length");
+        }
+
+        Ok(self.concat(other))
+    }
+
+    /// The first code units of the string, up to `len` of them, which are read without flattening a rope.
+    pub(crate) fn prefix(&self, len: usize) -> Vec<u16> {
+        let mut units = Vec::with_capacity(len.min(self.len()));
+
+        for leaf in self.leaves() {
+            let rest = len - units.len();
+
+            if rest == 0 {
+                break;
+            }
+
+            let leaf_units = leaf.code_units();
+
+            units.extend_from_slice(&leaf_units[..rest.min(leaf_units.len())]);
+        }
+
+        units
+    }
+
+    /// The strings which are not ropes that make up the string, in order, which are the string itself unless it is a rope that has not been flattened.
+    fn leaves(&self) -> impl Iterator<Item = JSString> {
+        // NOTE: The halves are walked with a stack rather than by recursion, as a rope may be nested up to MAX_ROPE_DEPTH deep.
+        let mut pending = vec![self.clone()];
+
+        iter::from_fn(move || loop {
+            let string = pending.pop()?;
+
+            let Repr::Rope(rope) = &string.0 else {
+                return Some(string);
+            };
+
+            if let Some(flat) = rope.flat.get() {
+                return Some(JSString(Repr::Heap(flat.clone())));
+            }
+
+            let halves = rope.halves.borrow().clone();
+
+            if let Some((left, right)) = halves {
+                pending.push(right);
+                pending.push(left);
+            }
+        })
+    }
+
+    /// 11.1.4 Static Semantics: CodePointAt ( string, position )
+    /// https://262.ecma-international.org/16.0/#sec-codepointat
+    ///
+    /// Returns the code point, the number of code units it takes up, and whether it is an unpaired surrogate.
+    pub(crate) fn code_point_at(&self, position: usize) -> (u32, usize, bool) {
+        let units = self.code_units();
+
+        // 1. Let size be the length of string.
+        // 2. Assert: position ≥ 0 and position < size.
+        debug_assert!(position < units.len());
+
+        // 3. Let first be the code unit at index position within string.
+        let first = units[position];
+
+        // 4. Let cp be the code point whose numeric value is the numeric value of first.
+        let cp = first as u32;
+
+        // 5. If first is neither a leading surrogate nor a trailing surrogate, then
+        if !(0xD800..=0xDFFF).contains(&first) {
+            // a. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 1, [[IsUnpairedSurrogate]]: false }.
+            return (cp, 1, false);
+        }
+
+        // 6. If first is a trailing surrogate or position + 1 = size, then
+        if (0xDC00..=0xDFFF).contains(&first) || position + 1 == units.len() {
+            // a. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 1, [[IsUnpairedSurrogate]]: true }.
+            return (cp, 1, true);
+        }
+
+        // 7. Let second be the code unit at index position + 1 within string.
+        let second = units[position + 1];
+
+        // 8. If second is not a trailing surrogate, then
+        if !(0xDC00..=0xDFFF).contains(&second) {
+            // a. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 1, [[IsUnpairedSurrogate]]: true }.
+            return (cp, 1, true);
+        }
+
+        // 9. Set cp to UTF16SurrogatePairToCodePoint(first, second).
+        let cp = (first as u32 - 0xD800) * 0x400 + (second as u32 - 0xDC00) + 0x10000;
+
+        // 10. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 2, [[IsUnpairedSurrogate]]: false }.
+        (cp, 2, false)
+    }
+
+    fn depth(&self) -> usize {
+        match &self.0 {
+            Repr::Rope(rope) => rope.depth,
+            _ => 0,
+        }
+    }
+
+    /// The string itself, or its flattened code units if it is a rope too deep to be nested in another.
+    fn shallow(&self) -> JSString {
+        match &self.0 {
+            Repr::Rope(rope) if rope.depth >= MAX_ROPE_DEPTH => {
+                JSString(Repr::Heap(rope.flatten().clone()))
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+impl PartialEq for JSString {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.code_units() == other.code_units()
+    }
+}
+
+impl Eq for JSString {}
+
+impl PartialEq<str> for JSString {
+    fn eq(&self, other: &str) -> bool {
+        self.code_units().iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<&str> for JSString {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl Ord for JSString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code_units().cmp(other.code_units())
+    }
+}
+
+impl PartialOrd for JSString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for JSString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code_units().hash(state);
+    }
+}
+
+/// Writes the string as UTF-8, with each lone surrogate replaced by U+FFFD (REPLACEMENT CHARACTER).
+impl fmt::Display for JSString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        char::decode_utf16(self.code_units().iter().copied())
+            .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .try_for_each(|ch| fmt::Write::write_char(f, ch))
+    }
+}
+
+impl fmt::Debug for JSString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JSString({:?})", self.to_string())
+    }
+}
+
+impl From<&[u16]> for JSString {
+    fn from(value: &[u16]) -> Self {
+        if value.len() > INLINE_CAPACITY {
+            return JSString(Repr::Heap(HeapUnits::new(value)));
+        }
+
+        let mut units = [0; INLINE_CAPACITY];
+
+        units[..value.len()].copy_from_slice(value);
+
+        JSString(Repr::Inline {
+            len: value.len() as u8,
+            units,
+        })
+    }
+}
+
+impl From<Vec<u16>> for JSString {
+    fn from(value: Vec<u16>) -> Self {
+        if value.len() > INLINE_CAPACITY {
+            return JSString(Repr::Heap(HeapUnits::new(value)));
+        }
+
+        JSString::from(value.as_slice())
     }
 }
 
 impl From<String> for JSString {
     fn from(value: String) -> Self {
-        JSString(value)
+        JSString::from(value.as_str())
     }
 }
 
 impl From<&String> for JSString {
     fn from(value: &String) -> Self {
-        JSString(value.clone())
+        JSString::from(value.as_str())
     }
 }
 
 impl From<&str> for JSString {
     fn from(value: &str) -> Self {
-        JSString(value.to_string())
+        JSString::from(value.encode_utf16().collect::<Vec<_>>())
+    }
+}
+
+impl From<char> for JSString {
+    fn from(value: char) -> Self {
+        JSString::from(&*value.encode_utf16(&mut [0; 2]))
     }
 }
 
@@ -51,7 +417,10 @@ impl TryFrom<JSValue> for JSString {
     fn try_from(value: JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::String(value) => Ok(value),
-            _ => throw_completion("Expected a JSValue::String for conversion to JSString"),
+            _ => throw_completion(&format!(
+                "Expected a JSValue::String for conversion to JSString, but got {}",
+                value.preview()
+            )),
         }
     }
 }
@@ -62,20 +431,90 @@ impl TryFrom<&JSValue> for JSString {
     fn try_from(value: &JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::String(value) => Ok(value.clone()),
-            _ => throw_completion("Expected a JSValue::String for conversion to JSString"),
+            _ => throw_completion(&format!(
+                "Expected a JSValue::String for conversion to JSString, but got {}",
+                value.preview()
+            )),
         }
     }
 }
 
-impl TryFrom<&ReferenceName> for JSString {
-    type Error = ThrowCompletion;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    fn try_from(value: &ReferenceName) -> Result<Self, Self::Error> {
-        match value {
-            ReferenceName::Value(JSValue::String(value)) => Ok(value.clone()),
-            _ => throw_completion(
-                "Expected a ReferenceName::Value with JSValue::String for conversion to JSString",
-            ),
+    #[test]
+    fn short_strings_are_held_inline() {
+        assert!(matches!(
+            JSString::from("abc").0,
+            Repr::Inline { len: 3, .. }
+        ));
+        assert!(matches!(JSString::from("abcdefghijkl").0, Repr::Heap(_)));
+        assert!(std::mem::size_of::<JSString>() <= std::mem::size_of::<String>());
+
+        let concatenated = JSString::from("ab").concat(&JSString::from("c"));
+
+        assert!(matches!(concatenated.0, Repr::Inline { len: 3, .. }));
+        assert_eq!(concatenated, JSString::from("abc"));
+    }
+
+    #[test]
+    fn long_concatenations_are_ropes() {
+        let part = JSString::from("abcdefghijklmnopqrstuvwxyz0123456789");
+        let mut string = JSString::from("");
+        let mut expected = String::new();
+
+        for _ in 0..1000 {
+            string = string.concat(&part);
+            expected.push_str("abcdefghijklmnopqrstuvwxyz0123456789");
         }
+
+        assert!(matches!(string.0, Repr::Rope(_)));
+        assert!(string.depth() <= MAX_ROPE_DEPTH);
+        assert_eq!(string.len(), expected.len());
+        assert_eq!(string, JSString::from(expected.as_str()));
+        assert_eq!(string.to_string(), expected);
+        assert_eq!(string.code_units()[36], b'a' as u16);
+    }
+
+    #[test]
+    fn flattening_drops_the_halves() {
+        let left = JSString::from("abcdefghijklmnopqrstuvwxyz");
+        let rope = left.concat(&JSString::from("0123456789"));
+
+        let Repr::Heap(units) = &left.0 else {
+            panic!("expected a string held on the heap");
+        };
+
+        assert_eq!(Rc::strong_count(&units.0), 2);
+        assert_eq!(rope.prefix(3), JSString::from("abc").code_units());
+        assert_eq!(Rc::strong_count(&units.0), 2);
+
+        assert_eq!(rope.code_units()[26], b'0' as u16);
+        assert_eq!(Rc::strong_count(&units.0), 1);
+        assert_eq!(
+            rope.prefix(28),
+            JSString::from("abcdefghijklmnopqrstuvwxyz01").code_units()
+        );
+    }
+
+    #[test]
+    fn lone_surrogates_are_kept() {
+        let string = JSString::from("a\u{1F600}");
+        let high = string.substring(1, 2);
+
+        assert_eq!(string.len(), 3);
+        assert_eq!(string.code_point_at(1), (0x1F600, 2, false));
+        assert_eq!(string.code_point_at(2), (0xDE00, 1, true));
+        assert_eq!(high.code_units(), [0xD83D]);
+        assert_eq!(high.to_string(), "\u{FFFD}");
+        assert_eq!(
+            high.concat(&string.substring(2, 3)),
+            JSString::from("\u{1F600}")
+        );
+        assert_eq!(
+            JSString::from("\u{1F600}").cmp(&JSString::from("\u{FB00}")),
+            Ordering::Less
+        );
     }
 }
@@ -3,12 +3,27 @@ use crate::value::JSValue;
 
 /// 6.1.5 The Symbol Type
 /// https://262.ecma-international.org/16.0/#sec-ecmascript-language-types-symbol-type
-#[derive(Clone, Default, Debug, PartialEq)]
-pub(crate) struct JSSymbol {
+#[derive(Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct JSSymbol {
     /// [[Description]]
     pub(crate) description: Option<String>,
 }
 
+impl JSSymbol {
+    /// 20.4.3.3.1 SymbolDescriptiveString ( sym )
+    /// https://262.ecma-international.org/16.0/#sec-symboldescriptivestring
+    pub(crate) fn descriptive_string(&self) -> String {
+        // 1. Let desc be sym's [[Description]] value.
+        // 2. If desc is undefined, set desc to the empty String.
+        // 3. Assert: desc is a String.
+        // 4. Return the string-concatenation of "Symbol(", desc, and ")".
+        format!(
+            "Symbol({})",
+            self.description.as_deref().unwrap_or_default()
+        )
+    }
+}
+
 impl From<String> for JSSymbol {
     fn from(value: String) -> Self {
         Self {
@@ -23,7 +38,10 @@ impl TryFrom<JSValue> for JSSymbol {
     fn try_from(value: JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::Symbol(symbol) => Ok(symbol),
-            _ => throw_completion("Expected JSValue::Symbol for conversion to JSSymbol"),
+            _ => throw_completion(&format!(
+                "Expected JSValue::Symbol for conversion to JSSymbol, but got {}",
+                value.preview()
+            )),
         }
     }
 }
@@ -34,7 +52,10 @@ impl TryFrom<&JSValue> for JSSymbol {
     fn try_from(value: &JSValue) -> Result<Self, Self::Error> {
         match value {
             JSValue::Symbol(symbol) => Ok(symbol.clone()),
-            _ => throw_completion("Expected JSValue::Symbol for conversion to JSSymbol"),
+            _ => throw_completion(&format!(
+                "Expected JSValue::Symbol for conversion to JSSymbol, but got {}",
+                value.preview()
+            )),
         }
     }
 }
@@ -1,22 +1,114 @@
+use std::{ops::Range, rc::Rc};
+
 use crate::{
     abstract_ops::{
-        execution_contexts::resolve_binding,
-        reference_operations::initialize_referenced_binding,
+        array_exotic_objects::array_create,
+        environments::{new_declarative_environment, new_object_environment},
+        execution_contexts::{
+            get_new_target, get_this_environment, resolve_binding, resolve_this_binding,
+        },
+        function_definitions::{
+            accessor_method_definition_evaluation, instantiate_async_function_expression,
+            instantiate_function_object, instantiate_generator_function_expression,
+            instantiate_ordinary_function_expression, AccessorKind,
+        },
+        iterator_operations::{
+            create_iter_result_object, get_iterator, iterator_close, iterator_complete,
+            iterator_step_value, iterator_value,
+        },
+        object_operations::{
+            call, construct, create_array_from_list, create_data_property_or_throw,
+            define_property_or_throw, get, get_method, getv, has_property, length_of_array_like,
+            set, set_integrity_level, IntegrityLevel,
+        },
+        ordinary::ordinary_object_create,
+        reference_operations::{
+            get_this_value, get_value, initialize_referenced_binding, is_private_reference,
+            is_property_reference, is_super_reference, put_value,
+        },
         runtime_operations::{
             apply_numeric_binary_operator, apply_string_or_numeric_binary_operator,
+            instanceof_operator,
         },
-        testing_comparison::{is_less_than, is_loosely_equal, is_strictly_equal},
+        testing_comparison::{
+            is_callable, is_constructor, is_less_than, is_loosely_equal, is_strictly_equal,
+            same_value,
+        },
+        type_conversion::{to_number, to_numeric, to_object, to_property_key, to_string},
+    },
+    codegen::bytecode::{
+        generator::{find_span_entry, ExecutableProgram, GeneratorKind, SpanEntry},
+        instruction::Instruction,
     },
-    codegen::bytecode::{generator::ExecutableProgram, instruction::Instruction},
+    gc::charge_strings_to,
+    gc::{Trace, Tracer},
     lexer::Token,
-    runtime::{agent::JSAgent, environment::EnvironmentMethods, reference::Reference},
-    value::{number::JSNumber, string::JSString, JSValue},
+    runtime::{
+        agent::{reference_error, type_error, type_error_for, JSAgent},
+        clock::Stopwatch,
+        completion::ThrowCompletion,
+        environment::{EnvironmentAddr, EnvironmentMethods},
+        iteration::{create_for_in_iterator, IterationKind, IteratorRecord},
+        realm::TemplateRecord,
+        reference::{Reference, ReferenceBase, ReferenceName},
+        stack_trace::{capture_stack_trace, CallFrame},
+        watchpoint::{WatchEvent, WatchLocation},
+    },
+    value::{
+        atom::Atom,
+        number::JSNumber,
+        object::{
+            property::{JSObjectPropDescriptor, JSObjectPropKey},
+            ObjectAddr, ObjectEssentialInternalMethods,
+        },
+        JSValue,
+    },
 };
 
+#[cfg(feature = "annex-b")]
+use crate::runtime::environment::Environment;
+
 #[derive(Debug)]
 pub(crate) enum StackItem {
     JSValue(JSValue),
     Reference(Reference),
+    /// The iterator of an enclosing loop, which stays on the stack below the values produced by the loop body.
+    Iterator(IteratorRecord, IterationKind),
+    /// The completion of a try statement's block or catch clause, which is resumed once its finally clause has been evaluated.
+    Completion(Completion),
+    /// The iterator a yield* expression delegates to, which stays on the stack while the generator is suspended.
+    DelegateIterator(IteratorRecord),
+    /// A function and the this value it is called with, which an optional call resolves from its Reference Record before checking the function for undefined or null.
+    Callee(JSValue, JSValue),
+}
+
+/// 6.2.4 The Completion Record Specification Type
+/// https://262.ecma-international.org/16.0/#sec-completion-record-specification-type
+#[derive(Debug)]
+pub(crate) enum Completion {
+    Normal(JSValue),
+    Return(JSValue),
+    Throw(JSValue),
+    /// A break or continue completion, which continues at the offset of the code leading to its target once the finally clause it entered completes.
+    Jump(usize),
+}
+
+#[derive(Debug, PartialEq)]
+enum ExceptionHandlerKind {
+    Catch,
+    Finally,
+}
+
+/// The clause of an enclosing try statement which an abrupt completion is routed to.
+#[derive(Debug)]
+struct ExceptionHandler {
+    kind: ExceptionHandlerKind,
+    /// The offset of the first instruction of the clause.
+    target: usize,
+    /// The height of the stack when the try statement was entered, which the stack is unwound to.
+    stack_height: usize,
+    /// The LexicalEnvironment when the try statement was entered, which is restored when the clause is entered.
+    lexical_environment: Option<EnvironmentAddr>,
 }
 
 impl TryFrom<StackItem> for JSValue {
@@ -44,24 +136,78 @@ impl TryFrom<StackItem> for Reference {
 pub(crate) struct VM<'a> {
     agent: &'a mut JSAgent,
     stack: Vec<StackItem>,
+    handlers: Vec<ExceptionHandler>,
     program: &'a ExecutableProgram,
     ip: usize,
+    /// Whether the instruction being executed follows a Wide prefix, which widens its operand to four bytes.
+    wide: bool,
     running: bool,
+    /// Whether evaluation stopped at a yield or await rather than completing, so that the frame can be saved and resumed.
+    suspended: bool,
+}
+
+/// The state of a generator or async function body's evaluation which is saved when it yields or awaits, and restored when it is resumed.
+#[derive(Debug, Default)]
+pub(crate) struct VMFrame {
+    stack: Vec<StackItem>,
+    handlers: Vec<ExceptionHandler>,
+    ip: usize,
+}
+
+impl Trace for StackItem {
+    fn trace(&self, tracer: &mut Tracer) {
+        match self {
+            StackItem::JSValue(value) => value.trace(tracer),
+            StackItem::Reference(reference) => reference.trace(tracer),
+            StackItem::Iterator(iterator_record, _) => iterator_record.trace(tracer),
+            StackItem::Completion(
+                Completion::Normal(value) | Completion::Return(value) | Completion::Throw(value),
+            ) => value.trace(tracer),
+            StackItem::Completion(Completion::Jump(_)) => {}
+            StackItem::DelegateIterator(iterator_record) => iterator_record.trace(tracer),
+            StackItem::Callee(function, this_value) => {
+                function.trace(tracer);
+                this_value.trace(tracer);
+            }
+        }
+    }
+}
+
+/// NOTE: Only the frame of a suspended generator is held on the heap, as the frames of running code are roots.
+impl Trace for VMFrame {
+    fn trace(&self, tracer: &mut Tracer) {
+        self.stack.trace(tracer);
+
+        for handler in &self.handlers {
+            handler.lexical_environment.trace(tracer);
+        }
+    }
 }
 
+#[derive(Debug)]
 pub(crate) enum VMError {
-    BinOperationError,
-    InitializeMutableBindingError,
-    InitializeReferencedBindingError,
-    LessThanComparisonError,
-    LooselyEqualComparisonError,
-    ReferenceError,
     StackUnderflow,
+    ThrowCompletion(ThrowCompletion),
     UnaryOperationError,
     UnexpectedInstruction,
     UnexpectedStackItem,
 }
 
+impl From<ThrowCompletion> for VMError {
+    fn from(value: ThrowCompletion) -> Self {
+        VMError::ThrowCompletion(value)
+    }
+}
+
+impl From<VMError> for ThrowCompletion {
+    fn from(value: VMError) -> Self {
+        match value {
+            VMError::ThrowCompletion(throw_completion) => throw_completion,
+            error => ThrowCompletion::from(JSValue::from(format!("InternalError: {error:?}"))),
+        }
+    }
+}
+
 type VMResult<T = ()> = Result<T, VMError>;
 
 impl<'a> VM<'a> {
@@ -69,30 +215,261 @@ impl<'a> VM<'a> {
         Self {
             agent,
             stack: Vec::with_capacity(32),
+            handlers: vec![],
             program,
             ip: 0,
+            wide: false,
+            running: false,
+            suspended: false,
+        }
+    }
+
+    /// Restores a frame saved when a generator body yielded or an async function body awaited, along with the program it is evaluating.
+    pub(crate) fn resume(
+        agent: &'a mut JSAgent,
+        program: &'a ExecutableProgram,
+        frame: VMFrame,
+    ) -> Self {
+        Self {
+            agent,
+            stack: frame.stack,
+            handlers: frame.handlers,
+            program,
+            ip: frame.ip,
+            wide: false,
             running: false,
+            suspended: false,
         }
     }
 
+    /// The frame to resume from if evaluation stopped at a yield or await, or None if it completed.
+    pub(crate) fn into_suspended_frame(self) -> Option<VMFrame> {
+        self.suspended.then_some(VMFrame {
+            stack: self.stack,
+            handlers: self.handlers,
+            ip: self.ip,
+        })
+    }
+
     pub(crate) fn evaluate_script(&mut self) -> VMResult<JSValue> {
+        self.run()
+    }
+
+    /// Evaluates the body of a function, producing the value of its return statement or undefined if it completes without one.
+    pub(crate) fn evaluate_function_body(&mut self) -> VMResult<JSValue> {
+        self.run()
+    }
+
+    /// Continues evaluating a generator body which is suspended at a yield, or an async function body which is suspended at an await, with the completion it was resumed with as the result of the yield or await.
+    ///
+    /// Produces the iterator result object of the next yield, the value of the next await, or the value of the body's return statement.
+    pub(crate) fn resume_generator_body(&mut self, completion: Completion) -> VMResult<JSValue> {
+        self.running = true;
+
+        // NOTE: A yield* expression passes the completion on to the iterator it delegates to.
+        if matches!(self.stack.last(), Some(StackItem::DelegateIterator(_))) {
+            self.stack.push(StackItem::Completion(completion));
+
+            return self.execute();
+        }
+
+        let resumption = match completion {
+            Completion::Normal(value) => {
+                self.push_value(value);
+
+                Ok(())
+            }
+            Completion::Return(value) => {
+                self.push_value(value);
+
+                self.exec_return()
+            }
+            Completion::Throw(value) => Err(VMError::ThrowCompletion(ThrowCompletion::from(value))),
+            // NOTE: A generator is only ever resumed with a normal, return or throw completion.
+            Completion::Jump(_) => Err(VMError::UnexpectedStackItem),
+        };
+
+        if let Err(error) = resumption {
+            self.handle_error(error)?;
+        }
+
+        // NOTE: A return completion stops the evaluation unless a finally clause is entered.
+        self.execute()
+    }
+
+    fn run(&mut self) -> VMResult<JSValue> {
         self.running = true;
 
+        self.execute()
+    }
+
+    fn execute(&mut self) -> VMResult<JSValue> {
+        self.agent.call_frames.push(CallFrame {
+            function: self.agent.running_execution_context().function.clone(),
+            spans: self.program.spans.clone(),
+            offset: self.ip,
+        });
+
+        // NOTE: Strings are created where there is no agent to hand, so those created while the program runs are counted towards the heap of the agent running it, and those created outside of evaluation towards none.
+        let outer_string_heap = charge_strings_to(Some(self.agent.heap.size_handle()));
+
+        let result = self.execute_instructions();
+
+        charge_strings_to(outer_string_heap);
+
+        self.agent.call_frames.pop();
+
+        result
+    }
+
+    fn execute_instructions(&mut self) -> VMResult<JSValue> {
         while self.running && self.ip < self.program.instructions.len() {
-            self.instruction()?;
+            if let Err(error) = self.instruction() {
+                self.handle_error(error)?;
+            }
+        }
+
+        if self.stack.is_empty() {
+            return Ok(JSValue::Undefined);
+        }
+
+        self.pop_value()
+    }
+
+    /// Routes a thrown value to the innermost enclosing catch or finally clause, or returns the error if there is none.
+    fn handle_error(&mut self, error: VMError) -> VMResult {
+        let VMError::ThrowCompletion(error) = error else {
+            // NOTE: The error is thrown as a value from here on, so it is recorded for the embedder to tell it apart from a value thrown by the script.
+            self.agent
+                .internal_error
+                .get_or_insert_with(|| format!("{error:?}"));
+
+            return Err(error);
+        };
+
+        // NOTE: Once evaluation has run out of fuel or memory, the error propagates past every catch and finally clause to the embedder.
+        if self.agent.abort_error().is_some() {
+            return Err(VMError::ThrowCompletion(error));
+        }
+
+        // NOTE: An error raised by the engine is created here, while the frame it was raised in is still on the stack of calls.
+        let value = error.into_value(self.agent);
+
+        // NOTE: The value is recorded where it is first thrown, rather than at each call it propagates out of, and is recorded again if it is caught and thrown again.
+        if !self
+            .agent
+            .thrown_at
+            .as_ref()
+            .is_some_and(|(thrown, ..)| same_value(thrown, &value))
+        {
+            self.agent.thrown_at = self.current_span_entry().map(|entry| {
+                (
+                    value.clone(),
+                    entry.position,
+                    capture_stack_trace(self.agent),
+                )
+            });
+
+            self.agent
+                .notify_debugger(|debugger, context| debugger.on_exception(&value, context));
         }
 
-        let result = self.pop_value()?;
+        let handler = self.handlers.pop();
 
-        Ok(result)
+        // NOTE: The iterators of the for-of loops the thrown value propagates out of are closed, ignoring any error thrown while closing them.
+        let stack_height = handler.as_ref().map_or(0, |handler| handler.stack_height);
+
+        while let Some(iterator_record) = self.pop_open_iterator(stack_height) {
+            let _ = iterator_close::<()>(
+                self.agent,
+                &iterator_record,
+                Err(ThrowCompletion::from(value.clone())),
+            );
+        }
+
+        let Some(handler) = handler else {
+            return Err(VMError::ThrowCompletion(ThrowCompletion::from(value)));
+        };
+
+        let item = match handler.kind {
+            ExceptionHandlerKind::Catch => {
+                self.agent.thrown_at = None;
+
+                StackItem::JSValue(value)
+            }
+            ExceptionHandlerKind::Finally => StackItem::Completion(Completion::Throw(value)),
+        };
+
+        self.enter_handler(handler, item);
+
+        Ok(())
+    }
+
+    /// Removes the innermost for-of iterator above the given stack height which is not yet done, along with everything above it on the stack.
+    fn pop_open_iterator(&mut self, stack_height: usize) -> Option<IteratorRecord> {
+        let position = self.stack[stack_height.min(self.stack.len())..]
+            .iter()
+            .rposition(|item| {
+                matches!(item, StackItem::Iterator(iterator_record, IterationKind::Iterate) if !iterator_record.done)
+            })?
+            + stack_height;
+
+        self.stack.truncate(position + 1);
+
+        let Some(StackItem::Iterator(iterator_record, _)) = self.stack.pop() else {
+            unreachable!()
+        };
+
+        Some(iterator_record)
+    }
+
+    /// Unwinds the stack and the LexicalEnvironment to where they were when the try statement was entered, and continues at the clause with the item on top of the stack.
+    fn enter_handler(&mut self, handler: ExceptionHandler, item: StackItem) {
+        self.stack.truncate(handler.stack_height);
+        self.stack.push(item);
+
+        self.agent
+            .running_execution_context_mut()
+            .lexical_environment = handler.lexical_environment;
+
+        self.ip = handler.target;
     }
 
     fn instruction(&mut self) -> VMResult {
-        let instruction = self.program.instructions[self.ip].into();
+        self.agent.consume_fuel()?;
+        self.agent.check_heap_limit()?;
+
+        // NOTE: The offset is recorded before the instruction runs, so that a stack trace captured by any code it calls knows where the call was made from.
+        if let Some(frame) = self.agent.call_frames.last_mut() {
+            frame.offset = self.ip;
+        }
+
+        if self.agent.has_debugger() {
+            self.notify_statement();
+        }
+
+        let mut instruction = self.program.instructions[self.ip].into();
+
+        trace!(Vm, Debug, "{:04}  {instruction}", self.ip);
 
         self.ip += 1;
 
-        match instruction {
+        // NOTE: A Wide prefix is executed along with the instruction it widens, so that the two take up a single step.
+        self.wide = matches!(instruction, Instruction::Wide);
+
+        if self.wide {
+            instruction = self.program.instructions[self.ip].into();
+
+            self.ip += 1;
+        }
+
+        let started = self.agent.profiler().is_some().then(Stopwatch::start);
+
+        let result = match instruction {
+            Instruction::AppendElement => self.exec_append_element(),
+            Instruction::AppendElision => self.exec_append_elision(),
+            Instruction::ArraySpread => self.exec_array_spread(),
+            Instruction::Await => self.exec_await(),
             Instruction::BinAdd => self.exec_bin_add(),
             Instruction::BinDivide => self.exec_numeric_bin_op(Token::Divide),
             Instruction::BinExponent => self.exec_numeric_bin_op(Token::Exponent),
@@ -108,39 +485,94 @@ impl<'a> VM<'a> {
             }
             Instruction::BitXor => self.exec_numeric_bin_op(Token::BitXor),
             Instruction::Call => self.exec_call(),
+            Instruction::CollectArguments => self.exec_collect_arguments(),
             Instruction::Const => self.exec_const(),
+            Instruction::CreateArray => self.exec_create_array(),
+            Instruction::CreateClosure => self.exec_create_closure(),
+            Instruction::CreateForInIterator => self.exec_create_for_in_iterator(),
+            Instruction::CreateImmutableBinding => self.exec_create_immutable_binding(),
             Instruction::CreateMutableBinding => self.exec_create_mutable_binding(),
+            Instruction::CreateObject => self.exec_create_object(),
+            Instruction::DefineGetter => self.exec_define_accessor(AccessorKind::Get),
+            Instruction::DefineProperty => self.exec_define_property(),
+            Instruction::DefineSetter => self.exec_define_accessor(AccessorKind::Set),
+            Instruction::EndFinally => self.exec_end_finally(),
+            Instruction::EnterFinally => self.exec_enter_finally(),
+            Instruction::Delete => self.exec_delete(),
             Instruction::Equal => self.exec_loosely_equal(true),
+            Instruction::False => self.exec_boolean_literal(false),
+            Instruction::GetCallee => self.exec_get_callee(),
+            Instruction::GetIterator => self.exec_get_iterator(),
+            Instruction::GetScopedLocal => self.exec_get_scoped_local(),
+            Instruction::GetTemplateObject => self.exec_get_template_object(),
+            Instruction::GetValue => self.exec_get_value(),
             Instruction::GreaterThan => self.exec_greater_than(),
             Instruction::GreaterThanOrEqual => self.exec_greater_than_or_equal(),
+            Instruction::In => self.exec_in(),
             Instruction::InitializeReferencedBinding => self.exec_initialize_referenced_binding(),
+            Instruction::InstanceOf => self.exec_instance_of(),
+            Instruction::InstantiateBlockFunctions => self.exec_instantiate_block_functions(),
+            Instruction::IteratorClose => self.exec_iterator_close(),
+            Instruction::IteratorRest => self.exec_iterator_rest(),
+            Instruction::IteratorStepElement => self.exec_iterator_step_element(),
+            Instruction::IteratorStepValue => self.exec_iterator_step_value(),
+            Instruction::Jump => self.exec_jump(),
+            Instruction::JumpIfNotUndefined => self.exec_jump_if_not_undefined(),
+            Instruction::JumpIfNullish => self.exec_jump_if_nullish(),
+            Instruction::JumpThroughFinally => self.exec_jump_through_finally(),
             Instruction::LessThan => self.exec_less_than(),
             Instruction::LessThanOrEqual => self.exec_less_than_or_equal(),
             Instruction::Minus => self.exec_unary_minus(),
+            Instruction::New => self.exec_new(),
+            Instruction::NewTarget => self.exec_new_target(),
             Instruction::NotEqual => self.exec_loosely_equal(false),
-            Instruction::Plus => Ok(()), // No-op,
+            Instruction::Null => self.exec_null(),
+            Instruction::Plus => self.exec_unary_plus(),
+            Instruction::Pop => self.exec_pop(),
+            Instruction::PopCompletion => self.exec_pop_completion(),
+            Instruction::PopEnvironment => self.exec_pop_environment(),
+            Instruction::PopExceptionHandler => self.exec_pop_exception_handler(),
+            Instruction::PropertyAccess => self.exec_property_access(),
+            Instruction::PushCatchHandler => {
+                self.exec_push_exception_handler(ExceptionHandlerKind::Catch)
+            }
+            Instruction::PushDeclarativeEnvironment => self.exec_push_declarative_environment(),
+            Instruction::PushFinallyHandler => {
+                self.exec_push_exception_handler(ExceptionHandlerKind::Finally)
+            }
+            Instruction::PushWithEnvironment => self.exec_push_with_environment(),
+            Instruction::PutValue => self.exec_put_value(),
             Instruction::ResolveBinding => self.exec_resolve_binding(),
+            Instruction::Return => self.exec_return(),
+            Instruction::SetPrototype => self.exec_set_prototype(),
+            Instruction::SetScopedLocal => self.exec_set_scoped_local(),
+            #[cfg(feature = "annex-b")]
+            Instruction::SetVariableBinding => self.exec_set_variable_binding(),
+            Instruction::SpreadCall => self.exec_spread_call(),
+            Instruction::SpreadNew => self.exec_spread_new(),
             Instruction::StrictEqual => self.exec_strictly_equal(true),
             Instruction::StrictNotEqual => self.exec_strictly_equal(false),
+            Instruction::SuperProperty => self.exec_super_property(),
+            Instruction::Swap => self.exec_swap(),
+            Instruction::This => self.exec_this(),
+            Instruction::Throw => self.exec_throw(),
+            Instruction::ToString => self.exec_to_string(),
+            Instruction::True => self.exec_boolean_literal(true),
+            Instruction::TypeOf => self.exec_typeof(),
             Instruction::Undefined => self.exec_undefined(),
-            Instruction::Halt => {
-                self.running = false;
-
-                Ok(())
-            }
-            _ => return Err(VMError::UnexpectedInstruction),
-        }?;
+            Instruction::Yield => self.exec_yield(),
+            Instruction::YieldDelegate => self.exec_yield_delegate(),
+            _ => Err(VMError::UnexpectedInstruction),
+        };
 
-        #[cfg(feature = "debug")]
-        {
-            println!("{}", instruction);
-            println!(
-                "Constants: {:?} | Identifiers: {:?} | Stack: {:?}",
-                self.program.constants, self.program.identifiers, self.stack
-            );
-            println!();
+        if let (Some(started), Some(profiler)) = (started, self.agent.profiler()) {
+            profiler.record_instruction(instruction, started.elapsed());
         }
 
+        result?;
+
+        trace!(Vm, Trace, "stack: {:?}", self.stack);
+
         Ok(())
     }
 
@@ -152,12 +584,119 @@ impl<'a> VM<'a> {
         value
     }
 
-    fn get_constant(&mut self, index: u8) -> JSValue {
+    fn read_u32(&mut self) -> u32 {
+        u32::from_le_bytes([
+            self.read_byte(),
+            self.read_byte(),
+            self.read_byte(),
+            self.read_byte(),
+        ])
+    }
+
+    /// Reads the operand of the instruction, which is four bytes if the instruction follows a Wide prefix and otherwise a single byte.
+    fn read_operand(&mut self) -> u32 {
+        if self.wide {
+            self.read_u32()
+        } else {
+            self.read_byte() as u32
+        }
+    }
+
+    fn get_constant(&mut self, index: u32) -> JSValue {
         self.program.constants[index as usize].clone()
     }
 
-    fn get_identifier(&self, index: u8) -> &JSString {
-        &self.program.identifiers[index as usize]
+    fn get_identifier(&self, index: u32) -> Atom {
+        self.program.identifiers[index as usize].clone()
+    }
+
+    /// Resolves the location a write through the reference targets if it is observed by a watchpoint, along with the value it holds before the write.
+    fn watched_location(
+        &mut self,
+        reference: &Reference,
+    ) -> VMResult<Option<(WatchLocation, JSValue)>> {
+        if self.agent.watchpoints.is_empty() {
+            return Ok(None);
+        }
+
+        let location = match (&reference.base, &reference.referenced_name) {
+            // NOTE: Object keys are skipped, as converting them to a property key may call into user code.
+            (ReferenceBase::Value(JSValue::Object(object)), ReferenceName::Value(name))
+                if !name.is_object() =>
+            {
+                let key = to_property_key(self.agent, name.clone())?;
+
+                WatchLocation::Property(object.clone(), key)
+            }
+            (ReferenceBase::Environment(_) | ReferenceBase::Unresolvable, name) => {
+                WatchLocation::Binding(Atom::try_from(name)?)
+            }
+            _ => return Ok(None),
+        };
+
+        if !self.agent.watchpoints.is_watched(&location) {
+            return Ok(None);
+        }
+
+        let old_value = match (&reference.base, &location) {
+            (ReferenceBase::Environment(env), WatchLocation::Binding(name)) => env
+                .get_binding_value(self.agent, name.clone(), false)
+                .unwrap_or(JSValue::Undefined),
+            (_, WatchLocation::Property(object, key)) => object
+                .get_own_property(key)?
+                .and_then(|descriptor| descriptor.value)
+                .unwrap_or(JSValue::Undefined),
+            _ => JSValue::Undefined,
+        };
+
+        Ok(Some((location, old_value)))
+    }
+
+    fn notify_watchpoints(
+        &mut self,
+        location: WatchLocation,
+        old_value: JSValue,
+        new_value: JSValue,
+    ) {
+        let event = WatchEvent {
+            old_value,
+            new_value,
+            span: self.current_span(),
+        };
+
+        self.agent.watchpoints.notify(&location, &event);
+    }
+
+    /// Reports the statement or declaration which starts at the instruction about to be executed to the debugger, if there is one.
+    fn notify_statement(&mut self) {
+        let Some(entry) = find_span_entry(&self.program.spans, self.ip) else {
+            return;
+        };
+
+        if entry.offset != self.ip {
+            return;
+        }
+
+        let breakpoint = self.agent.has_breakpoint(entry.position.line);
+
+        self.agent.notify_debugger(|debugger, context| {
+            if breakpoint {
+                debugger.on_breakpoint(context);
+            }
+
+            debugger.on_statement(context);
+        });
+    }
+
+    /// The span entry of the statement which the current instruction was compiled from.
+    fn current_span_entry(&self) -> Option<&SpanEntry> {
+        self.program.span_entry(self.ip.saturating_sub(1))
+    }
+
+    /// The byte range of the statement in the source text which the current instruction was compiled from.
+    fn current_span(&self) -> Option<Range<usize>> {
+        self.current_span_entry()
+            .map(|entry| entry.span.start..entry.span.end)
     }
 
     fn push_value(&mut self, value: JSValue) {
@@ -182,8 +721,27 @@ impl<'a> VM<'a> {
             .and_then(|item| item.try_into())
     }
 
+    fn peek_value(&self) -> VMResult<&JSValue> {
+        match self.stack.last() {
+            Some(StackItem::JSValue(value)) => Ok(value),
+            Some(_) => Err(VMError::UnexpectedStackItem),
+            None => Err(VMError::StackUnderflow),
+        }
+    }
+
+    /// Pops the given number of values, returning them in the order in which they were pushed.
+    fn pop_values(&mut self, count: u32) -> VMResult<Vec<JSValue>> {
+        let mut values = (0..count)
+            .map(|_| self.pop_value())
+            .collect::<VMResult<Vec<_>>>()?;
+
+        values.reverse();
+
+        Ok(values)
+    }
+
     fn exec_const(&mut self) -> VMResult {
-        let index = self.read_byte();
+        let index = self.read_operand();
 
         let value = self.get_constant(index);
 
@@ -193,151 +751,1196 @@ impl<'a> VM<'a> {
     }
 
     fn exec_create_mutable_binding(&mut self) -> VMResult {
-        let binding_index = self.read_byte();
-        // TODO Ensure that the identifier correctly gets added to the environment at the correct depth.
-        let _scope_depth = self.read_byte();
+        let binding_index = self.read_operand();
 
         let binding_name = self.get_identifier(binding_index);
 
+        // 14.2.3 BlockDeclarationInstantiation ( code, env )
+        // ii. Else,
+        // 1. Perform ! env.CreateMutableBinding(dn, false).
         self.agent
             .running_execution_context()
             .lexical_environment
             .clone()
             .unwrap()
-            .create_mutable_binding(binding_name, true)
-            .map_err(|_| VMError::InitializeMutableBindingError)?;
+            .create_mutable_binding(binding_name, false)?;
 
         Ok(())
     }
 
-    fn exec_bin_add(&mut self) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+    fn exec_create_immutable_binding(&mut self) -> VMResult {
+        let binding_index = self.read_operand();
 
-        let result = apply_string_or_numeric_binary_operator(a, b)
-            .map_err(|_| VMError::BinOperationError)?;
+        let binding_name = self.get_identifier(binding_index);
 
-        self.push_value(result);
+        // 14.2.3 BlockDeclarationInstantiation ( code, env )
+        // i. If IsConstantDeclaration of d is true, then
+        // 1. Perform ! env.CreateImmutableBinding(dn, true).
+        self.agent
+            .running_execution_context()
+            .lexical_environment
+            .clone()
+            .unwrap()
+            .create_immutable_binding(binding_name, true)?;
 
         Ok(())
     }
 
-    fn exec_numeric_bin_op(&mut self, operator: Token) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+    /// 14.2.3 BlockDeclarationInstantiation ( code, env )
+    /// https://262.ecma-international.org/16.0/#sec-blockdeclarationinstantiation
+    fn exec_instantiate_block_functions(&mut self) -> VMResult {
+        let block_index = self.read_operand();
 
-        let result = apply_numeric_binary_operator(a, operator, b)
-            .map_err(|_| VMError::BinOperationError)?;
+        let mut env = self
+            .agent
+            .running_execution_context()
+            .lexical_environment
+            .clone()
+            .unwrap();
 
-        self.push_value(result);
+        let mut declared_function_names = vec![];
 
-        Ok(())
-    }
+        // 3. For each element d of declarations, do
+        // NOTE: If there are multiple function declarations for the same name, the last declaration is used.
+        for function_index in self.program.block_function_declarations[block_index as usize]
+            .iter()
+            .rev()
+        {
+            let function = self.program.functions[*function_index].clone();
 
-    /// 13.3.6.2 EvaluateCall ( func, ref, arguments, tailPosition )
-    /// https://262.ecma-international.org/16.0/#sec-evaluatecall
-    fn exec_call(&mut self) -> VMResult {
-        let args_length = self.read_byte();
+            // b. If d is either a FunctionDeclaration, a GeneratorDeclaration, an AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
+            // i. Let fn be the sole element of the BoundNames of d.
+            let function_name = function.name.clone().unwrap();
 
-        Ok(())
-    }
+            if declared_function_names.contains(&function_name) {
+                continue;
+            }
 
-    /// 13.5.5.1 Runtime Semantics: Evaluation
-    /// https://262.ecma-international.org/16.0/#sec-unary-minus-operator-runtime-semantics-evaluation
-    /// UnaryExpression : - UnaryExpression
-    fn exec_unary_minus(&mut self) -> VMResult {
-        let value = self.pop_value()?;
+            declared_function_names.push(function_name.clone());
 
-        let number = JSNumber::try_from(value)
-            .map_err(|_| VMError::UnaryOperationError)?
-            .unary_minus();
+            // ii. Let fo be InstantiateFunctionObject of d with arguments env and privateEnv.
+            let function_obj = instantiate_function_object(self.agent, function, env.clone());
 
-        self.push_value(JSValue::Number(number));
+            // iii. Perform ! env.InitializeBinding(fn, fo).
+            env.initialize_binding(self.agent, function_name, JSValue::from(function_obj))?;
+        }
 
         Ok(())
     }
 
-    /// 13.10.1 Runtime Semantics: Evaluation
-    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
-    /// RelationalExpression : RelationalExpression < ShiftExpression
-    fn exec_less_than(&mut self) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+    /// B.3.2.1 Changes to FunctionDeclarationInstantiation
+    /// https://262.ecma-international.org/16.0/#sec-web-compat-functiondeclarationinstantiation
+    #[cfg(feature = "annex-b")]
+    fn exec_set_variable_binding(&mut self) -> VMResult {
+        let binding_index = self.read_operand();
 
-        // 5. Let r be ? IsLessThan(lval, rval, true).
-        let result = is_less_than(a, b, true)
-            .map_err(|_| VMError::LessThanComparisonError)?
-            // 6. If r is undefined, return false. Otherwise, return r.
-            .unwrap_or(false);
+        let binding_name = self.get_identifier(binding_index);
 
-        self.push_value(JSValue::from(result));
+        // iii. Let fobj be ! benv.GetBindingValue(F, false).
+        let function_obj = self.pop_value()?;
 
-        Ok(())
-    }
+        if !self.agent.annex_b() {
+            return Ok(());
+        }
 
-    /// 13.10.1 Runtime Semantics: Evaluation
-    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
-    /// RelationalExpression : RelationalExpression > ShiftExpression
-    fn exec_greater_than(&mut self) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+        // i. Let fenv be the running execution context's VariableEnvironment.
+        let mut fenv = self
+            .agent
+            .running_execution_context()
+            .variable_environment
+            .clone()
+            .unwrap();
 
-        // 5. Let r be ? IsLessThan(rval, lval, false).
-        let result = is_less_than(b, a, false)
-            .map_err(|_| VMError::LessThanComparisonError)?
-            // 6. If r is undefined, return false. Otherwise, return r.
-            .unwrap_or(false);
+        // B.3.2.2 Changes to GlobalDeclarationInstantiation
+        // i. If env.HasLexicalDeclaration(F) is false, then
+        // NOTE: A lexical declaration of an earlier script prevents the var scoped binding from being created, in which case the function is not copied to it.
+        if matches!(&*fenv.borrow(), Environment::Global(global_env) if global_env.has_lexical_declaration(&binding_name))
+        {
+            return Ok(());
+        }
 
-        self.push_value(JSValue::from(result));
+        // iv. Perform ? fenv.SetMutableBinding(F, fobj, false).
+        fenv.set_mutable_binding(self.agent, binding_name, function_obj, false)?;
 
         Ok(())
     }
 
-    /// 13.10.1 Runtime Semantics: Evaluation
-    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
-    /// RelationalExpression : RelationalExpression <= ShiftExpression
-    fn exec_less_than_or_equal(&mut self) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+    /// 14.2.2 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-block-runtime-semantics-evaluation
+    fn exec_push_declarative_environment(&mut self) -> VMResult {
+        // 1. Let oldEnv be the running execution context's LexicalEnvironment.
+        let old_env = self
+            .agent
+            .running_execution_context()
+            .lexical_environment
+            .clone();
 
-        // 5. Let r be ? IsLessThan(rval, lval, false).
-        let result = !is_less_than(b, a, false)
-            .map_err(|_| VMError::LessThanComparisonError)?
-            // 6. If r is either true or undefined, return false. Otherwise, return true.
-            .unwrap_or(true);
+        // 2. Let blockEnv be NewDeclarativeEnvironment(oldEnv).
+        let block_env = new_declarative_environment(self.agent, old_env);
 
-        self.push_value(JSValue::from(result));
+        // 4. Set the running execution context's LexicalEnvironment to blockEnv.
+        self.agent
+            .running_execution_context_mut()
+            .lexical_environment = Some(block_env);
 
         Ok(())
     }
 
-    /// 13.10.1 Runtime Semantics: Evaluation
-    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
-    /// RelationalExpression : RelationalExpression >= ShiftExpression
-    fn exec_greater_than_or_equal(&mut self) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+    /// 14.11.2 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-with-statement-runtime-semantics-evaluation
+    /// WithStatement : with ( Expression ) Statement
+    fn exec_push_with_environment(&mut self) -> VMResult {
+        // 1. Let val be ? Evaluation of Expression.
+        // 2. Let obj be ? ToObject(? GetValue(val)).
+        let value = self.pop_value()?;
 
-        // 5. Let r be ? IsLessThan(lval, rval, true).
-        let result = !is_less_than(a, b, true)
-            .map_err(|_| VMError::LessThanComparisonError)?
-            // 6. If r is either true or undefined, return false. Otherwise, return true.
-            .unwrap_or(true);
+        let obj = to_object(self.agent, &value)?;
 
-        self.push_value(JSValue::from(result));
+        // 3. Let oldEnv be the running execution context's LexicalEnvironment.
+        let old_env = self
+            .agent
+            .running_execution_context()
+            .lexical_environment
+            .clone();
+
+        // 4. Let newEnv be NewObjectEnvironment(obj, true, oldEnv).
+        let new_env = new_object_environment(self.agent, &obj, true, old_env);
+
+        // 5. Set the running execution context's LexicalEnvironment to newEnv.
+        self.agent
+            .running_execution_context_mut()
+            .lexical_environment = Some(new_env);
 
         Ok(())
     }
 
-    /// 13.11.1 Runtime Semantics: Evaluation
-    /// https://262.ecma-international.org/16.0/#sec-equality-operators-runtime-semantics-evaluation
-    /// EqualityExpression : EqualityExpression == RelationalExpression
-    /// EqualityExpression : EqualityExpression != RelationalExpression
+    fn exec_pop_environment(&mut self) -> VMResult {
+        let env = self
+            .agent
+            .running_execution_context()
+            .lexical_environment
+            .clone()
+            .unwrap();
+
+        // 6. Set the running execution context's LexicalEnvironment to oldEnv.
+        self.agent
+            .running_execution_context_mut()
+            .lexical_environment = env.outer();
+
+        Ok(())
+    }
+
+    fn exec_bin_add(&mut self) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        let result = apply_string_or_numeric_binary_operator(self.agent, lval, rval)?;
+
+        self.push_value(result);
+
+        Ok(())
+    }
+
+    fn exec_numeric_bin_op(&mut self, operator: Token) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        let result = apply_numeric_binary_operator(self.agent, lval, operator, rval)?;
+
+        self.push_value(result);
+
+        Ok(())
+    }
+
+    fn exec_call(&mut self) -> VMResult {
+        let args_length = self.read_operand();
+
+        let arg_list = self.pop_values(args_length)?;
+
+        self.evaluate_call(arg_list)
+    }
+
+    fn exec_spread_call(&mut self) -> VMResult {
+        let arg_list = self.pop_argument_list()?;
+
+        self.evaluate_call(arg_list)
+    }
+
+    /// 13.3.8.1 Runtime Semantics: ArgumentListEvaluation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-argumentlistevaluation
+    ///
+    /// Collects the arguments evaluated before the first spread argument of a call into an array, which the remaining arguments are appended to.
+    fn exec_collect_arguments(&mut self) -> VMResult {
+        let args_length = self.read_operand();
+
+        let arg_list = self.pop_values(args_length)?;
+
+        let array = create_array_from_list(self.agent, arg_list);
+
+        self.push_value(JSValue::from(array));
+
+        Ok(())
+    }
+
+    /// Pops the array which the arguments of a call with a spread argument were collected into, returning its elements.
+    fn pop_argument_list(&mut self) -> VMResult<Vec<JSValue>> {
+        let array = ObjectAddr::try_from(self.pop_value()?)?;
+
+        // NOTE: The array is only reachable from the stack, so reading its elements cannot run user code.
+        let len = length_of_array_like(self.agent, &array)?;
+
+        let arg_list = (0..len)
+            .map(|index| {
+                get(
+                    self.agent,
+                    &array,
+                    &JSObjectPropKey::String(index.to_string().into()),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(arg_list)
+    }
+
+    /// 13.3.6.2 EvaluateCall ( func, ref, arguments, tailPosition )
+    /// https://262.ecma-international.org/16.0/#sec-evaluatecall
+    fn evaluate_call(&mut self, arg_list: Vec<JSValue>) -> VMResult {
+        let callee = self.stack.pop().ok_or(VMError::StackUnderflow)?;
+
+        let (func, this_value) = self.resolve_callee(callee)?;
+
+        // 3. If func is not an Object, throw a TypeError exception.
+        if !func.is_object() {
+            return Err(type_error_for::<()>("Value is not a function", &func)
+                .unwrap_err()
+                .into());
+        }
+
+        // 4. If IsCallable(func) is false, throw a TypeError exception.
+        // 5. If tailPosition is true, perform PrepareForTailCall().
+        // 6. Return ? Call(func, thisValue, argList).
+        let result = call(self.agent, func, &this_value, Some(arg_list))?;
+
+        self.push_value(result);
+
+        Ok(())
+    }
+
+    /// Resolves the function a call evaluates and the this value it is called with.
+    fn resolve_callee(&mut self, callee: StackItem) -> VMResult<(JSValue, JSValue)> {
+        match callee {
+            // 13.3.6.1 Runtime Semantics: Evaluation
+            // CallExpression : CoverCallExpressionAndAsyncArrowHead
+            StackItem::Reference(reference) => {
+                // 3. Let func be ? GetValue(ref).
+                let func = get_value(self.agent, reference.clone())?;
+
+                // 1. If ref is a Reference Record, then
+                let this_value = if is_property_reference(&reference) {
+                    // a. If IsPropertyReference(ref) is true, then
+                    // i. Let thisValue be GetThisValue(ref).
+                    get_this_value(&reference)
+                } else if let ReferenceBase::Environment(ref_env) = &reference.base {
+                    // b. Else,
+                    // i. Let refEnv be ref.[[Base]].
+                    // ii. Assert: refEnv is an Environment Record.
+                    // iii. Let thisValue be refEnv.WithBaseObject().
+                    ref_env
+                        .with_base_object()
+                        .map_or(JSValue::Undefined, JSValue::from)
+                } else {
+                    JSValue::Undefined
+                };
+
+                Ok((func, this_value))
+            }
+            // 2. Else,
+            // a. Let thisValue be undefined.
+            StackItem::JSValue(func) => Ok((func, JSValue::Undefined)),
+            StackItem::Callee(func, this_value) => Ok((func, this_value)),
+            StackItem::Iterator(..) | StackItem::Completion(_) | StackItem::DelegateIterator(_) => {
+                Err(VMError::UnexpectedStackItem)
+            }
+        }
+    }
+
+    /// 13.3.9.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-optional-chaining-evaluation
+    ///
+    /// Resolves the callee of an optional call, so that its value can be checked for undefined or null without evaluating its Reference Record again when it is called.
+    fn exec_get_callee(&mut self) -> VMResult {
+        // 1. Let baseReference be ? Evaluation of MemberExpression.
+        let base_reference = self.stack.pop().ok_or(VMError::StackUnderflow)?;
+
+        // 2. Let baseValue be ? GetValue(baseReference).
+        let (func, this_value) = self.resolve_callee(base_reference)?;
+
+        self.stack.push(StackItem::Callee(func, this_value));
+
+        Ok(())
+    }
+
+    fn exec_new(&mut self) -> VMResult {
+        let args_length = self.read_operand();
+
+        // 4. Else,
+        // a. Let argList be ? ArgumentListEvaluation of arguments.
+        let arg_list = self.pop_values(args_length)?;
+
+        self.evaluate_new(arg_list)
+    }
+
+    fn exec_spread_new(&mut self) -> VMResult {
+        // 4. Else,
+        // a. Let argList be ? ArgumentListEvaluation of arguments.
+        let arg_list = self.pop_argument_list()?;
+
+        self.evaluate_new(arg_list)
+    }
+
+    /// 13.3.5.1.1 EvaluateNew ( constructExpr, arguments )
+    /// https://262.ecma-international.org/16.0/#sec-evaluatenew
+    fn evaluate_new(&mut self, arg_list: Vec<JSValue>) -> VMResult {
+        // 1. Let ref be ? Evaluation of constructExpr.
+        // 2. Let constructor be ? GetValue(ref).
+        let constructor = self.pop_value()?;
+
+        // 5. If IsConstructor(constructor) is false, throw a TypeError exception.
+        if !is_constructor(constructor.clone()) {
+            return Err(
+                type_error_for::<()>("Value is not a constructor", &constructor)
+                    .unwrap_err()
+                    .into(),
+            );
+        }
+
+        // 6. Return ? Construct(constructor, argList).
+        let result = construct(
+            self.agent,
+            &ObjectAddr::try_from(constructor)?,
+            Some(arg_list),
+            None,
+        )?;
+
+        self.push_value(JSValue::from(result));
+
+        Ok(())
+    }
+
+    /// 15.2.6 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-function-definitions-runtime-semantics-evaluation
+    /// FunctionExpression : function BindingIdentifier_opt ( FormalParameters ) { FunctionBody }
+    /// 15.5.5 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-generator-function-definitions-runtime-semantics-evaluation
+    /// GeneratorExpression : function * BindingIdentifier_opt ( FormalParameters ) { GeneratorBody }
+    fn exec_create_closure(&mut self) -> VMResult {
+        let function_index = self.read_operand();
+
+        let function = self.program.functions[function_index as usize].clone();
+
+        let closure = match function.generator_kind {
+            // 1. Return InstantiateAsyncFunctionExpression of AsyncFunctionExpression.
+            GeneratorKind::NonGenerator if function.is_async => {
+                instantiate_async_function_expression(self.agent, function)
+            }
+            // 1. Return InstantiateOrdinaryFunctionExpression of FunctionExpression.
+            GeneratorKind::NonGenerator => {
+                instantiate_ordinary_function_expression(self.agent, function)
+            }
+            // 1. Return InstantiateGeneratorFunctionExpression of GeneratorExpression.
+            GeneratorKind::Sync => instantiate_generator_function_expression(self.agent, function),
+        };
+
+        self.push_value(JSValue::from(closure));
+
+        Ok(())
+    }
+
+    /// 13.2.4.2 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-array-initializer-runtime-semantics-evaluation
+    fn exec_create_array(&mut self) -> VMResult {
+        // 1. Let array be ! ArrayCreate(0).
+        let array = array_create(self.agent, 0, None)?;
+
+        self.push_value(JSValue::from(array));
+
+        Ok(())
+    }
+
+    /// 13.2.4.1 Runtime Semantics: ArrayAccumulation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-arrayaccumulation
+    /// ElementList : AssignmentExpression
+    fn exec_append_element(&mut self) -> VMResult {
+        // 1. Let initResult be ? Evaluation of AssignmentExpression.
+        // 2. Let initValue be ? GetValue(initResult).
+        let init_value = self.pop_value()?;
+
+        let array = ObjectAddr::try_from(self.peek_value()?)?;
+
+        // NOTE: The next index is the length of the array, which each element and elision accumulated so far has extended.
+        let next_index = length_of_array_like(self.agent, &array)?;
+
+        // 3. Perform ! CreateDataPropertyOrThrow(array, ! ToString(𝔽(nextIndex)), initValue).
+        create_data_property_or_throw(
+            &array,
+            &JSObjectPropKey::String(next_index.to_string().into()),
+            init_value,
+        )?;
+
+        // 4. Return nextIndex + 1.
+        Ok(())
+    }
+
+    /// 13.2.4.1 Runtime Semantics: ArrayAccumulation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-arrayaccumulation
+    /// SpreadElement : ... AssignmentExpression
+    fn exec_array_spread(&mut self) -> VMResult {
+        // 1. Let spreadRef be ? Evaluation of AssignmentExpression.
+        // 2. Let spreadObj be ? GetValue(spreadRef).
+        let spread_obj = self.pop_value()?;
+
+        let array = ObjectAddr::try_from(self.peek_value()?)?;
+
+        let mut next_index = length_of_array_like(self.agent, &array)?;
+
+        // 3. Let iteratorRecord be ? GetIterator(spreadObj, sync).
+        let mut iterator_record = get_iterator(self.agent, &spread_obj)?;
+
+        // 4. Repeat,
+        // a. Let next be ? IteratorStepValue(iteratorRecord).
+        // b. If next is done, return nextIndex.
+        while let Some(next) = iterator_step_value(self.agent, &mut iterator_record)? {
+            // c. Perform ! CreateDataPropertyOrThrow(array, ! ToString(𝔽(nextIndex)), next).
+            create_data_property_or_throw(
+                &array,
+                &JSObjectPropKey::String(next_index.to_string().into()),
+                next,
+            )?;
+
+            // d. Set nextIndex to nextIndex + 1.
+            next_index += 1;
+        }
+
+        Ok(())
+    }
+
+    /// 13.2.4.1 Runtime Semantics: ArrayAccumulation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-arrayaccumulation
+    /// Elision : ,
+    fn exec_append_elision(&mut self) -> VMResult {
+        let array = ObjectAddr::try_from(self.peek_value()?)?;
+
+        let next_index = length_of_array_like(self.agent, &array)?;
+
+        // 1. Let len be nextIndex + 1.
+        // 2. Perform ? Set(array, "length", 𝔽(len), true).
+        // NOTE: Elisions within the list are only observable through the length of the array, so the length is extended for each of them.
+        set(
+            self.agent,
+            &array,
+            &JSObjectPropKey::String("length".into()),
+            JSValue::from((next_index + 1) as f64),
+            true,
+        )?;
+
+        // 3. Return len.
+        Ok(())
+    }
+
+    /// 13.2.5.4 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-object-initializer-runtime-semantics-evaluation
+    /// ObjectLiteral : { PropertyDefinitionList }
+    fn exec_create_object(&mut self) -> VMResult {
+        // 1. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+        let object_prototype = self
+            .agent
+            .current_realm()
+            .borrow()
+            .intrinsics
+            .object_prototype
+            .clone();
+
+        let obj = ordinary_object_create(self.agent, object_prototype, None);
+
+        self.push_value(JSValue::from(obj));
+
+        Ok(())
+    }
+
+    /// 13.2.5.5 Runtime Semantics: PropertyDefinitionEvaluation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-propertydefinitionevaluation
+    /// PropertyDefinition : PropertyName : AssignmentExpression
+    fn exec_define_property(&mut self) -> VMResult {
+        // 3. Let exprValueRef be ? Evaluation of AssignmentExpression.
+        // 4. Let propValue be ? GetValue(exprValueRef).
+        let prop_value = self.pop_value()?;
+
+        // 1. Let propKey be ? Evaluation of PropertyName.
+        let prop_key = self.pop_value()?;
+
+        let prop_key = to_property_key(self.agent, prop_key)?;
+
+        let object = ObjectAddr::try_from(self.peek_value()?)?;
+
+        // 5. If isProtoSetter is true, then
+        // NOTE: Handled by the SetPrototype instruction.
+        // 6. Assert: object is an ordinary, extensible object with no non-configurable properties.
+        // 7. If IsAnonymousFunctionDefinition(AssignmentExpression) is true and isProtoSetter is false, then
+        // TODO: Name anonymous functions after their property key.
+        // 8. Perform ! CreateDataPropertyOrThrow(object, propKey, propValue).
+        create_data_property_or_throw(&object, &prop_key, prop_value)?;
+
+        // 9. Return unused.
+        Ok(())
+    }
+
+    /// 13.2.5.5 Runtime Semantics: PropertyDefinitionEvaluation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-propertydefinitionevaluation
+    /// PropertyDefinition : MethodDefinition
+    fn exec_define_accessor(&mut self, kind: AccessorKind) -> VMResult {
+        let function_index = self.read_operand();
+
+        let function = self.program.functions[function_index as usize].clone();
+
+        let prop_key = self.pop_value()?;
+
+        let prop_key = to_property_key(self.agent, prop_key)?;
+
+        let object = ObjectAddr::try_from(self.peek_value()?)?;
+
+        // 1. Perform ? MethodDefinitionEvaluation of MethodDefinition with arguments object and true.
+        accessor_method_definition_evaluation(self.agent, &object, prop_key, function, kind, true)?;
+
+        // 2. Return unused.
+        Ok(())
+    }
+
+    /// 13.2.5.5 Runtime Semantics: PropertyDefinitionEvaluation
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-propertydefinitionevaluation
+    /// PropertyDefinition : __proto__ : AssignmentExpression
+    fn exec_set_prototype(&mut self) -> VMResult {
+        let prop_value = self.pop_value()?;
+
+        let object = ObjectAddr::try_from(self.peek_value()?)?;
+
+        // 5. If isProtoSetter is true, then
+        match prop_value {
+            // a. If propValue is an Object or propValue is null, then
+            // i. Perform ! object.[[SetPrototypeOf]](propValue).
+            JSValue::Object(prototype) => {
+                object.set_prototype_of(Some(prototype));
+            }
+            JSValue::Null => {
+                object.set_prototype_of(None);
+            }
+            _ => {}
+        }
+
+        // b. Return unused.
+        Ok(())
+    }
+
+    /// 6.2.5.5 GetValue ( V )
+    /// https://262.ecma-international.org/16.0/#sec-getvalue
+    fn exec_get_value(&mut self) -> VMResult {
+        // 1. If V is not a Reference Record, return V.
+        // NOTE: A short-circuited OptionalChain leaves undefined in place of the Reference Record the rest of the chain evaluates to.
+        if matches!(self.stack.last(), Some(StackItem::JSValue(_))) {
+            return Ok(());
+        }
+
+        let reference = self.pop_reference()?;
+
+        let value = get_value(self.agent, reference)?;
+
+        self.push_value(value);
+
+        Ok(())
+    }
+
+    /// 13.15.2 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-assignment-operators-runtime-semantics-evaluation
+    /// AssignmentExpression : LeftHandSideExpression = AssignmentExpression
+    fn exec_put_value(&mut self) -> VMResult {
+        // c. Let rref be ? Evaluation of AssignmentExpression.
+        // d. Let rval be ? GetValue(rref).
+        let rval = self.pop_value()?;
+
+        // a. Let lref be ? Evaluation of LeftHandSideExpression.
+        let lref = self.pop_reference()?;
+
+        let watched_location = self.watched_location(&lref)?;
+
+        // e. Perform ? PutValue(lref, rval).
+        put_value(self.agent, lref, rval.clone())?;
+
+        if let Some((location, old_value)) = watched_location {
+            self.notify_watchpoints(location, old_value, rval.clone());
+        }
+
+        // f. Return rval.
+        self.push_value(rval);
+
+        Ok(())
+    }
+
+    /// 13.5.1.2 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-delete-operator-runtime-semantics-evaluation
+    fn exec_delete(&mut self) -> VMResult {
+        // 1. Let ref be ? Evaluation of UnaryExpression.
+        let reference = match self.stack.pop().ok_or(VMError::StackUnderflow)? {
+            StackItem::Reference(reference) => reference,
+            // 2. If ref is not a Reference Record, return true.
+            StackItem::JSValue(_)
+            | StackItem::Iterator(..)
+            | StackItem::Completion(_)
+            | StackItem::DelegateIterator(_)
+            | StackItem::Callee(..) => {
+                self.push_value(JSValue::from(true));
+
+                return Ok(());
+            }
+        };
+
+        let delete_status = match &reference.base {
+            // 3. If IsUnresolvableReference(ref) is true, then
+            ReferenceBase::Unresolvable => {
+                // a. Assert: ref.[[Strict]] is false.
+                debug_assert!(!reference.strict);
+
+                // b. Return true.
+                true
+            }
+            // 4. If IsPropertyReference(ref) is true, then
+            ReferenceBase::Value(base) => {
+                // a. Assert: IsPrivateReference(ref) is false.
+                // NOTE: It is an early error to delete a private reference.
+                debug_assert!(!is_private_reference(&reference));
+
+                // b. If IsSuperReference(ref) is true, throw a ReferenceError exception.
+                if is_super_reference(&reference) {
+                    return Err(reference_error::<()>("Unsupported reference to 'super'")
+                        .unwrap_err()
+                        .into());
+                }
+
+                // c. Let baseObj be ? ToObject(ref.[[Base]]).
+                let base_obj = to_object(self.agent, base)?;
+
+                // d. If ref.[[ReferencedName]] is neither a String nor a Symbol, then
+                // i. Set ref.[[ReferencedName]] to ? ToPropertyKey(ref.[[ReferencedName]]).
+                let ReferenceName::Value(referenced_name) = &reference.referenced_name else {
+                    unreachable!()
+                };
+
+                let key = to_property_key(self.agent, referenced_name.clone())?;
+
+                // e. Let deleteStatus be ? baseObj.[[Delete]](ref.[[ReferencedName]]).
+                let delete_status = base_obj.delete(&key)?;
+
+                // f. If deleteStatus is false and ref.[[Strict]] is true, throw a TypeError exception.
+                if !delete_status && reference.strict {
+                    return Err(type_error::<()>("Cannot delete non-configurable property")
+                        .unwrap_err()
+                        .into());
+                }
+
+                // g. Return deleteStatus.
+                delete_status
+            }
+            // 5. Else,
+            ReferenceBase::Environment(env) => {
+                // a. Let base be ref.[[Base]].
+                // b. Assert: base is an Environment Record.
+                // c. Return ? base.DeleteBinding(ref.[[ReferencedName]]).
+                env.clone()
+                    .delete_binding(Atom::try_from(&reference.referenced_name)?)?
+            }
+        };
+
+        self.push_value(JSValue::from(delete_status));
+
+        Ok(())
+    }
+
+    /// 13.3.3 EvaluatePropertyAccessWithExpressionKey ( baseValue, expression, strict )
+    /// https://262.ecma-international.org/16.0/#sec-evaluate-property-access-with-expression-key
+    fn exec_property_access(&mut self) -> VMResult {
+        // 1. Let propertyNameReference be ? Evaluation of expression.
+        // 2. Let propertyNameValue be ? GetValue(propertyNameReference).
+        let property_name_value = self.pop_value()?;
+
+        let base_value = self.pop_value()?;
+
+        // 3. NOTE: In most cases, ToPropertyKey will be performed on propertyNameValue immediately after this step. However, in the case of a[b] = c, it will not be performed until evaluation of c.
+        // 4. Return the Reference Record { [[Base]]: baseValue, [[ReferencedName]]: propertyNameValue, [[Strict]]: strict, [[ThisValue]]: empty }.
+        self.push_reference(Reference {
+            base: ReferenceBase::Value(base_value),
+            referenced_name: ReferenceName::Value(property_name_value),
+            strict: self.program.strict,
+            this_value: None,
+        });
+
+        Ok(())
+    }
+
+    /// 13.3.7.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-super-keyword-runtime-semantics-evaluation
+    /// SuperProperty : super [ Expression ]
+    /// SuperProperty : super . IdentifierName
+    fn exec_super_property(&mut self) -> VMResult {
+        // 1. Let env be GetThisEnvironment().
+        let env = get_this_environment(self.agent);
+
+        // 2. Let actualThis be ? env.GetThisBinding().
+        // NOTE: GetThisBinding can only throw in a derived constructor, which is not supported, so it is not observable that the property name is evaluated first.
+        let actual_this = env.get_this_binding()?;
+
+        // 3. Let propertyNameReference be ? Evaluation of Expression.
+        // 4. Let propertyNameValue be ? GetValue(propertyNameReference).
+        let property_name_value = self.pop_value()?;
+
+        // 5. Let propertyKey be ? ToPropertyKey(propertyNameValue).
+        let property_key = to_property_key(self.agent, property_name_value)?;
+
+        // 6. If the source text matched by this SuperProperty is strict mode code, let strict be true; else let strict be false.
+        // 7. Return MakeSuperPropertyReference(actualThis, propertyKey, strict).
+        // 13.3.7.3 MakeSuperPropertyReference ( actualThis, propertyKey, strict )
+        // 1. Let env be GetThisEnvironment().
+        // 2. Assert: env.HasSuperBinding() is true.
+        debug_assert!(env.has_super_binding());
+
+        // 3. Let baseValue be ? env.GetSuperBase().
+        // NOTE: The home object of a method always exists, so the base is only missing when its prototype is null.
+        let base_value = env.get_super_base().map_or(JSValue::Null, JSValue::from);
+
+        // 4. Return the Reference Record { [[Base]]: baseValue, [[ReferencedName]]: propertyKey, [[Strict]]: strict, [[ThisValue]]: actualThis }.
+        self.push_reference(Reference {
+            base: ReferenceBase::Value(base_value),
+            referenced_name: ReferenceName::from(property_key),
+            strict: self.program.strict,
+            this_value: Some(actual_this),
+        });
+
+        Ok(())
+    }
+
+    /// 15.2.6 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-return-statement-runtime-semantics-evaluation
+    /// ReturnStatement : return Expression ;
+    fn exec_return(&mut self) -> VMResult {
+        // 1. Let exprRef be ? Evaluation of Expression.
+        // 2. Let exprValue be ? GetValue(exprRef).
+        // 3. If GetGeneratorKind() is async, set exprValue to ? Await(exprValue).
+        // 4. Return ReturnCompletion(exprValue).
+        // NOTE: The finally clauses of enclosing try statements are evaluated before returning, and may replace the completion.
+        let finally_index = self
+            .handlers
+            .iter()
+            .rposition(|handler| handler.kind == ExceptionHandlerKind::Finally);
+
+        // NOTE: The iterators of the for-of loops the return exits before reaching the finally clause are closed, and an error thrown while closing one replaces the completion.
+        let stack_height = finally_index.map_or(0, |index| self.handlers[index].stack_height);
+
+        let value = self.pop_value()?;
+
+        while let Some(iterator_record) = self.pop_open_iterator(stack_height) {
+            let iterator_height = self.stack.len();
+
+            // NOTE: The catch clauses of try statements within the loop body are exited along with the loop.
+            self.handlers
+                .retain(|handler| handler.stack_height <= iterator_height);
+
+            iterator_close(self.agent, &iterator_record, Ok(()))?;
+        }
+
+        if let Some(index) = finally_index {
+            self.handlers.truncate(index + 1);
+
+            let handler = self.handlers.pop().unwrap();
+
+            self.enter_handler(handler, StackItem::Completion(Completion::Return(value)));
+
+            return Ok(());
+        }
+
+        // NOTE: The return value is left on top of the stack for the caller.
+        self.push_value(value);
+
+        self.running = false;
+
+        Ok(())
+    }
+
+    /// 13.2.1.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-this-keyword-runtime-semantics-evaluation
+    /// PrimaryExpression : this
+    fn exec_this(&mut self) -> VMResult {
+        // 1. Return ? ResolveThisBinding().
+        let this_value = resolve_this_binding(self.agent)?;
+
+        self.push_value(this_value);
+
+        Ok(())
+    }
+
+    /// 13.2.8.4 GetTemplateObject ( templateLiteral )
+    /// https://262.ecma-international.org/16.0/#sec-gettemplateobject
+    fn exec_get_template_object(&mut self) -> VMResult {
+        let template_index = self.read_operand();
+
+        let site = self.program.templates[template_index as usize].clone();
+
+        // 1. Let realm be the current Realm Record.
+        let realm = self.agent.current_realm();
+
+        // 2. Let templateRegistry be realm.[[TemplateMap]].
+        // 3. For each element e of templateRegistry, do
+        // a. If e.[[Site]] is the same Parse Node as templateLiteral, then
+        // i. Return e.[[Array]].
+        let existing = realm
+            .borrow()
+            .template_map
+            .iter()
+            .find(|record| Rc::ptr_eq(&record.site, &site))
+            .map(|record| record.array.clone());
+
+        if let Some(array) = existing {
+            self.push_value(JSValue::from(array));
+
+            return Ok(());
+        }
+
+        // 4. Let rawStrings be the TemplateStrings of templateLiteral with argument true.
+        // 5. Assert: rawStrings is a List of Strings.
+        // 6. Let cookedStrings be the TemplateStrings of templateLiteral with argument false.
+        // 7. Let count be the number of elements in the List cookedStrings.
+        let count = site.cooked.len();
+
+        // 8. Assert: count ≤ 2**32 - 1.
+        debug_assert!(count <= u32::MAX as usize);
+
+        // 9. Let template be ! ArrayCreate(count).
+        let template = array_create(self.agent, count as u64, None).unwrap();
+
+        // 10. Let rawObj be ! ArrayCreate(count).
+        let raw_obj = array_create(self.agent, count as u64, None).unwrap();
+
+        // 11. Let index be 0.
+        // 12. Repeat, while index < count,
+        for (index, (cooked_value, raw_value)) in site.cooked.iter().zip(&site.raw).enumerate() {
+            // a. Let prop be ! ToString(𝔽(index)).
+            let prop = JSObjectPropKey::String(index.to_string().into());
+
+            // b. Let cookedValue be cookedStrings[index].
+            let cooked_value = cooked_value
+                .clone()
+                .map_or(JSValue::Undefined, JSValue::from);
+
+            // c. Perform ! DefinePropertyOrThrow(template, prop, PropertyDescriptor { [[Value]]: cookedValue, [[Writable]]: false, [[Enumerable]]: true, [[Configurable]]: false }).
+            define_property_or_throw(
+                &template,
+                &prop,
+                JSObjectPropDescriptor {
+                    value: Some(cooked_value),
+                    writable: Some(false),
+                    enumerable: Some(true),
+                    configurable: Some(false),
+                    ..JSObjectPropDescriptor::default()
+                },
+            )
+            .unwrap();
+
+            // d. Let rawValue be the String value rawStrings[index].
+            // e. Perform ! DefinePropertyOrThrow(rawObj, prop, PropertyDescriptor { [[Value]]: rawValue, [[Writable]]: false, [[Enumerable]]: true, [[Configurable]]: false }).
+            define_property_or_throw(
+                &raw_obj,
+                &prop,
+                JSObjectPropDescriptor {
+                    value: Some(JSValue::from(raw_value.clone())),
+                    writable: Some(false),
+                    enumerable: Some(true),
+                    configurable: Some(false),
+                    ..JSObjectPropDescriptor::default()
+                },
+            )
+            .unwrap();
+
+            // f. Set index to index + 1.
+        }
+
+        // 13. Perform ! SetIntegrityLevel(rawObj, frozen).
+        set_integrity_level(&raw_obj, IntegrityLevel::Frozen).unwrap();
+
+        // 14. Perform ! DefinePropertyOrThrow(template, "raw", PropertyDescriptor { [[Value]]: rawObj, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+        define_property_or_throw(
+            &template,
+            &JSObjectPropKey::String("raw".into()),
+            JSObjectPropDescriptor {
+                value: Some(JSValue::from(raw_obj)),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..JSObjectPropDescriptor::default()
+            },
+        )
+        .unwrap();
+
+        // 15. Perform ! SetIntegrityLevel(template, frozen).
+        set_integrity_level(&template, IntegrityLevel::Frozen).unwrap();
+
+        // 16. Append the Template Record { [[Site]]: templateLiteral, [[Array]]: template } to realm.[[TemplateMap]].
+        realm.borrow_mut().template_map.push(TemplateRecord {
+            site,
+            array: template.clone(),
+        });
+
+        // 17. Return template.
+        self.push_value(JSValue::from(template));
+
+        Ok(())
+    }
+
+    /// 13.2.8.6 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-template-literals-runtime-semantics-evaluation
+    /// SubstitutionTemplate : TemplateHead Expression TemplateSpans
+    fn exec_to_string(&mut self) -> VMResult {
+        // 3. Let sub be ? GetValue(subRef).
+        let sub = self.pop_value()?;
+
+        // 4. Let middle be ? ToString(sub).
+        let middle = to_string(self.agent, sub)?;
+
+        self.push_value(JSValue::from(middle));
+
+        Ok(())
+    }
+
+    /// 13.3.12.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-meta-properties-runtime-semantics-evaluation
+    /// NewTarget : new . target
+    fn exec_new_target(&mut self) -> VMResult {
+        // 1. Return GetNewTarget().
+        let new_target = get_new_target(self.agent);
+
+        self.push_value(new_target);
+
+        Ok(())
+    }
+
+    /// 13.5.3.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-typeof-operator-runtime-semantics-evaluation
+    /// UnaryExpression : typeof UnaryExpression
+    fn exec_typeof(&mut self) -> VMResult {
+        // 1. Let val be ? Evaluation of UnaryExpression.
+        let value = match self.stack.pop().ok_or(VMError::StackUnderflow)? {
+            // 2. If val is a Reference Record, then
+            StackItem::Reference(reference) => {
+                // a. If IsUnresolvableReference(val) is true, return "undefined".
+                if reference.base == ReferenceBase::Unresolvable {
+                    self.push_value(JSValue::from("undefined".to_string()));
+
+                    return Ok(());
+                }
+
+                // 3. Set val to ? GetValue(val).
+                get_value(self.agent, reference)?
+            }
+            StackItem::JSValue(value) => value,
+            StackItem::Iterator(..)
+            | StackItem::Completion(_)
+            | StackItem::DelegateIterator(_)
+            | StackItem::Callee(..) => return Err(VMError::UnexpectedStackItem),
+        };
+
+        let type_name = match value {
+            // 4. If val is undefined, return "undefined".
+            JSValue::Undefined => "undefined",
+            // 5. If val is null, return "object".
+            JSValue::Null => "object",
+            // 6. If val is a String, return "string".
+            JSValue::String(_) => "string",
+            // 7. If val is a Symbol, return "symbol".
+            JSValue::Symbol(_) => "symbol",
+            // 8. If val is a Boolean, return "boolean".
+            JSValue::Bool(_) => "boolean",
+            // 9. If val is a Number, return "number".
+            JSValue::Number(_) => "number",
+            // 10. If val is a BigInt, return "bigint".
+            JSValue::BigInt(_) => "bigint",
+            // 11. Assert: val is an Object.
+            // 12. NOTE: This step is replaced in section B.3.6.3.
+            // 13. If val has a [[Call]] internal slot, return "function".
+            JSValue::Object(_) if is_callable(&value) => "function",
+            // 14. Return "object".
+            JSValue::Object(_) => "object",
+        };
+
+        self.push_value(JSValue::from(type_name.to_string()));
+
+        Ok(())
+    }
+
+    /// 13.5.5.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-unary-minus-operator-runtime-semantics-evaluation
+    /// UnaryExpression : - UnaryExpression
+    fn exec_unary_minus(&mut self) -> VMResult {
+        // 1. Let expr be ? Evaluation of UnaryExpression.
+        // 2. Let oldValue be ? ToNumeric(? GetValue(expr)).
+        let expr = self.pop_value()?;
+
+        let old_value = to_numeric(self.agent, expr)?;
+
+        // 3. If oldValue is a Number, then
+        // a. Return Number::unaryMinus(oldValue).
+        // 4. Else,
+        // a. Assert: oldValue is a BigInt.
+        // b. Return BigInt::unaryMinus(oldValue).
+        // TODO: Implement BigInt::unaryMinus.
+        let number = JSNumber::try_from(old_value)
+            .map_err(|_| VMError::UnaryOperationError)?
+            .unary_minus();
+
+        self.push_value(JSValue::Number(number));
+
+        Ok(())
+    }
+
+    /// 13.5.4.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-unary-plus-operator-runtime-semantics-evaluation
+    /// UnaryExpression : + UnaryExpression
+    fn exec_unary_plus(&mut self) -> VMResult {
+        // 1. Let expr be ? Evaluation of UnaryExpression.
+        // 2. Return ? ToNumber(? GetValue(expr)).
+        let expr = self.pop_value()?;
+
+        let number = to_number(self.agent, expr)?;
+
+        self.push_value(JSValue::Number(number));
+
+        Ok(())
+    }
+
+    /// 13.10.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
+    /// RelationalExpression : RelationalExpression < ShiftExpression
+    fn exec_less_than(&mut self) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        // 5. Let r be ? IsLessThan(lval, rval, true).
+        let result = is_less_than(self.agent, lval, rval, true)?
+            // 6. If r is undefined, return false. Otherwise, return r.
+            .unwrap_or(false);
+
+        self.push_value(JSValue::from(result));
+
+        Ok(())
+    }
+
+    /// 13.10.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
+    /// RelationalExpression : RelationalExpression > ShiftExpression
+    fn exec_greater_than(&mut self) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        // 5. Let r be ? IsLessThan(rval, lval, false).
+        let result = is_less_than(self.agent, rval, lval, false)?
+            // 6. If r is undefined, return false. Otherwise, return r.
+            .unwrap_or(false);
+
+        self.push_value(JSValue::from(result));
+
+        Ok(())
+    }
+
+    /// 13.10.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
+    /// RelationalExpression : RelationalExpression <= ShiftExpression
+    fn exec_less_than_or_equal(&mut self) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        // 5. Let r be ? IsLessThan(rval, lval, false).
+        let result = !is_less_than(self.agent, rval, lval, false)?
+            // 6. If r is either true or undefined, return false. Otherwise, return true.
+            .unwrap_or(true);
+
+        self.push_value(JSValue::from(result));
+
+        Ok(())
+    }
+
+    /// 13.10.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
+    /// RelationalExpression : RelationalExpression >= ShiftExpression
+    fn exec_greater_than_or_equal(&mut self) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        // 5. Let r be ? IsLessThan(lval, rval, true).
+        let result = !is_less_than(self.agent, lval, rval, true)?
+            // 6. If r is either true or undefined, return false. Otherwise, return true.
+            .unwrap_or(true);
+
+        self.push_value(JSValue::from(result));
+
+        Ok(())
+    }
+
+    /// 13.10.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
+    /// RelationalExpression : RelationalExpression instanceof ShiftExpression
+    fn exec_instance_of(&mut self) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        // 5. Return ? InstanceofOperator(lval, rval).
+        let result = instanceof_operator(self.agent, lval, rval)?;
+
+        self.push_value(JSValue::from(result));
+
+        Ok(())
+    }
+
+    /// 13.10.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-relational-operators-runtime-semantics-evaluation
+    /// RelationalExpression : RelationalExpression in ShiftExpression
+    fn exec_in(&mut self) -> VMResult {
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
+
+        // 5. If rval is not an Object, throw a TypeError exception.
+        let JSValue::Object(rval) = rval else {
+            return Err(type_error_for::<()>(
+                "Cannot use 'in' operator to search for a key in a non-object",
+                &rval,
+            )
+            .unwrap_err()
+            .into());
+        };
+
+        // 6. Return ? HasProperty(rval, ? ToPropertyKey(lval)).
+        let key = to_property_key(self.agent, lval)?;
+
+        let result = has_property(&rval, &key)?;
+
+        self.push_value(JSValue::from(result));
+
+        Ok(())
+    }
+
+    /// 13.11.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-equality-operators-runtime-semantics-evaluation
+    /// EqualityExpression : EqualityExpression == RelationalExpression
+    /// EqualityExpression : EqualityExpression != RelationalExpression
     fn exec_loosely_equal(&mut self, check_equal: bool) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
 
-        let result = is_loosely_equal(a, b).map_err(|_| VMError::LooselyEqualComparisonError)?;
+        // 5. Let r be ? IsLooselyEqual(rval, lval).
+        let result = is_loosely_equal(self.agent, rval, lval)?;
 
         self.push_value(JSValue::from(if check_equal { result } else { !result }));
 
@@ -349,11 +1952,11 @@ impl<'a> VM<'a> {
     /// EqualityExpression : EqualityExpression === RelationalExpression
     /// EqualityExpression : EqualityExpression !== RelationalExpression
     fn exec_strictly_equal(&mut self, check_equal: bool) -> VMResult {
-        let a = self.pop_value()?;
-        let b = self.pop_value()?;
+        let rval = self.pop_value()?;
+        let lval = self.pop_value()?;
 
         // 5. Return IsStrictlyEqual(rval, lval).
-        let result = is_strictly_equal(&a, &b);
+        let result = is_strictly_equal(&rval, &lval);
 
         self.push_value(JSValue::from(if check_equal { result } else { !result }));
 
@@ -361,31 +1964,112 @@ impl<'a> VM<'a> {
     }
 
     fn exec_resolve_binding(&mut self) -> VMResult {
-        let index = self.read_byte();
+        let index = self.read_operand();
 
         let value = self.get_identifier(index);
 
-        let binding = resolve_binding(
-            self.agent,
-            value,
-            self.agent
-                .running_execution_context()
-                .lexical_environment
-                .clone(),
-        )
-        .map_err(|_| VMError::ReferenceError)?;
+        let env = self
+            .agent
+            .running_execution_context()
+            .lexical_environment
+            .clone();
+
+        let binding = resolve_binding(self.agent, value, env, self.program.strict)?;
 
         self.push_reference(binding);
 
         Ok(())
     }
 
+    /// The environment holding the binding of an identifier reference which the bytecode generator resolved statically, along with the slot of the binding.
+    fn scoped_environment(&mut self) -> (EnvironmentAddr, usize) {
+        let depth = self.read_operand();
+        let slot = self.read_operand();
+
+        let env = self
+            .agent
+            .running_execution_context()
+            .lexical_environment
+            .clone()
+            .unwrap()
+            .outer_at(depth);
+
+        (env, slot as usize)
+    }
+
+    /// 6.2.5.5 GetValue ( V )
+    /// https://262.ecma-international.org/16.0/#sec-getvalue
+    ///
+    /// GetValue of an identifier reference which the bytecode generator resolved statically to the binding at a slot of a block's environment, so that the environment chain is not searched by name.
+    fn exec_get_scoped_local(&mut self) -> VMResult {
+        let (env, slot) = self.scoped_environment();
+
+        // 3. If IsPropertyReference(V) is true, then
+        // 4. Else,
+        // a. Let base be V.[[Base]].
+        // b. Assert: base is an Environment Record.
+        // c. Return ? base.GetBindingValue(V.[[ReferencedName]], V.[[Strict]]).
+        let value = env.get_binding_value_at(slot)?;
+
+        self.push_value(value);
+
+        Ok(())
+    }
+
+    /// 13.15.2 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-assignment-operators-runtime-semantics-evaluation
+    /// AssignmentExpression : LeftHandSideExpression = AssignmentExpression
+    ///
+    /// An assignment to an identifier reference which the bytecode generator resolved statically to the binding at a slot of a block's environment, so that the environment chain is not searched by name.
+    fn exec_set_scoped_local(&mut self) -> VMResult {
+        let (env, slot) = self.scoped_environment();
+
+        // c. Let rref be ? Evaluation of AssignmentExpression.
+        // d. Let rval be ? GetValue(rref).
+        let rval = self.pop_value()?;
+
+        let watched_location = if self.agent.watchpoints.is_empty() {
+            None
+        } else {
+            let lref = Reference {
+                base: ReferenceBase::Environment(env.clone()),
+                referenced_name: ReferenceName::from(env.binding_name_at(slot)),
+                strict: self.program.strict,
+                this_value: None,
+            };
+
+            self.watched_location(&lref)?
+        };
+
+        // e. Perform ? PutValue(lref, rval).
+        // 6.2.5.6 PutValue ( V, W )
+        // 4. Else,
+        // a. Let base be V.[[Base]].
+        // b. Assert: base is an Environment Record.
+        // c. Return ? base.SetMutableBinding(V.[[ReferencedName]], W, V.[[Strict]]).
+        env.set_mutable_binding_at(slot, rval.clone(), self.program.strict)?;
+
+        if let Some((location, old_value)) = watched_location {
+            self.notify_watchpoints(location, old_value, rval.clone());
+        }
+
+        // f. Return rval.
+        self.push_value(rval);
+
+        Ok(())
+    }
+
     fn exec_initialize_referenced_binding(&mut self) -> VMResult {
         let value = self.pop_value()?;
         let reference = self.pop_reference()?;
 
-        initialize_referenced_binding(reference, value)
-            .map_err(|_| VMError::InitializeReferencedBindingError)?;
+        let watched_location = self.watched_location(&reference)?;
+
+        initialize_referenced_binding(self.agent, reference, value.clone())?;
+
+        if let Some((location, old_value)) = watched_location {
+            self.notify_watchpoints(location, old_value, value);
+        }
 
         Ok(())
     }
@@ -395,4 +2079,623 @@ impl<'a> VM<'a> {
 
         Ok(())
     }
+
+    /// 13.2.3.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-literals-runtime-semantics-evaluation
+    /// Literal : NullLiteral
+    fn exec_null(&mut self) -> VMResult {
+        // 1. Return null.
+        self.push_value(JSValue::Null);
+
+        Ok(())
+    }
+
+    /// 13.2.3.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-literals-runtime-semantics-evaluation
+    /// Literal : BooleanLiteral
+    fn exec_boolean_literal(&mut self, value: bool) -> VMResult {
+        // 1. If BooleanLiteral is the token false, return false.
+        // 2. If BooleanLiteral is the token true, return true.
+        self.push_value(JSValue::Bool(value));
+
+        Ok(())
+    }
+
+    fn exec_pop(&mut self) -> VMResult {
+        self.stack.pop().ok_or(VMError::StackUnderflow)?;
+
+        Ok(())
+    }
+
+    fn exec_swap(&mut self) -> VMResult {
+        let length = self.stack.len();
+
+        if length < 2 {
+            return Err(VMError::StackUnderflow);
+        }
+
+        self.stack.swap(length - 1, length - 2);
+
+        Ok(())
+    }
+
+    fn exec_jump(&mut self) -> VMResult {
+        self.ip = self.read_u32() as usize;
+
+        Ok(())
+    }
+
+    /// 13.3.9.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-optional-chaining-evaluation
+    fn exec_jump_if_nullish(&mut self) -> VMResult {
+        let target = self.read_u32();
+
+        let base_value = match self.stack.last() {
+            Some(StackItem::JSValue(value) | StackItem::Callee(value, _)) => value,
+            Some(_) => return Err(VMError::UnexpectedStackItem),
+            None => return Err(VMError::StackUnderflow),
+        };
+
+        // 3. If baseValue is either undefined or null, then
+        if base_value.is_undefined() || base_value.is_null() {
+            self.stack.pop();
+
+            // a. Return undefined.
+            self.push_value(JSValue::Undefined);
+
+            self.ip = target as usize;
+        }
+
+        Ok(())
+    }
+
+    /// 14.7.5.6 ForIn/OfHeadEvaluation ( uninitializedBoundNames, expr, iterationKind )
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-forinofheadevaluation
+    fn exec_create_for_in_iterator(&mut self) -> VMResult {
+        let exit = self.read_u32();
+
+        // 5. Let exprValue be ? GetValue(exprRef).
+        let expr_value = self.pop_value()?;
+
+        // 6. If iterationKind is enumerate, then
+        // a. If exprValue is either undefined or null, then
+        if matches!(expr_value, JSValue::Undefined | JSValue::Null) {
+            // i. Return Completion Record { [[Type]]: break, [[Value]]: empty, [[Target]]: empty }.
+            // NOTE: The loop completes without evaluating its body, so its value is undefined.
+            self.push_value(JSValue::Undefined);
+
+            self.ip = exit as usize;
+
+            return Ok(());
+        }
+
+        // b. Let obj be ! ToObject(exprValue).
+        let obj = to_object(self.agent, &expr_value)?;
+
+        // c. Let iterator be EnumerateObjectProperties(obj).
+        let iterator = create_for_in_iterator(self.agent, obj);
+
+        // d. Let nextMethod be ! GetV(iterator, "next").
+        let next_method = getv(
+            self.agent,
+            &JSValue::from(iterator.clone()),
+            &JSObjectPropKey::String("next".into()),
+        )?;
+
+        // e. Return the Iterator Record { [[Iterator]]: iterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        self.stack.push(StackItem::Iterator(
+            IteratorRecord {
+                iterator,
+                next_method,
+                done: false,
+            },
+            IterationKind::Enumerate,
+        ));
+
+        // 14.7.5.7 ForIn/OfBodyEvaluation ( lhs, stmt, iteratorRecord, iterationKind, lhsKind, labelSet [ , iteratorKind ] )
+        // 3. Let V be undefined.
+        self.push_value(JSValue::Undefined);
+
+        Ok(())
+    }
+
+    /// 8.6.3 Runtime Semantics: IteratorBindingInitialization
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-iteratorbindinginitialization
+    fn exec_jump_if_not_undefined(&mut self) -> VMResult {
+        let target = self.read_u32();
+
+        let value = match self.stack.last() {
+            Some(StackItem::JSValue(value)) => value,
+            Some(_) => return Err(VMError::UnexpectedStackItem),
+            None => return Err(VMError::StackUnderflow),
+        };
+
+        // 3. If Initializer is present and v is undefined, then
+        if value.is_undefined() {
+            self.stack.pop();
+        } else {
+            self.ip = target as usize;
+        }
+
+        Ok(())
+    }
+
+    /// 8.6.3 Runtime Semantics: IteratorBindingInitialization
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-iteratorbindinginitialization
+    /// SingleNameBinding : BindingIdentifier Initializer
+    fn exec_iterator_step_element(&mut self) -> VMResult {
+        let agent = &mut *self.agent;
+
+        let iterator_record = innermost_iterator(&mut self.stack)?;
+
+        // 2. Let v be undefined.
+        // 3. If iteratorRecord.[[Done]] is false, then
+        // a. Let next be ? IteratorStepValue(iteratorRecord).
+        // b. If next is not done, then
+        // i. Set v to next.
+        let value = if iterator_record.done {
+            None
+        } else {
+            iterator_step_value(agent, iterator_record)?
+        };
+
+        self.push_value(value.unwrap_or(JSValue::Undefined));
+
+        Ok(())
+    }
+
+    /// 8.6.3 Runtime Semantics: IteratorBindingInitialization
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-iteratorbindinginitialization
+    /// BindingRestElement : ... BindingIdentifier
+    fn exec_iterator_rest(&mut self) -> VMResult {
+        let agent = &mut *self.agent;
+
+        let iterator_record = innermost_iterator(&mut self.stack)?;
+
+        let mut values = vec![];
+
+        // 4. Repeat,
+        // a. Let next be done.
+        // b. If iteratorRecord.[[Done]] is false, then
+        // i. Set next to ? IteratorStepValue(iteratorRecord).
+        while !iterator_record.done {
+            match iterator_step_value(agent, iterator_record)? {
+                // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), next).
+                // e. Set n to n + 1.
+                Some(next) => values.push(next),
+                // c. If next is done, then
+                None => break,
+            }
+        }
+
+        // 2. Let A be ! ArrayCreate(0).
+        // c. If next is done, then
+        // i. If environment is undefined, return ? PutValue(lhs, A).
+        // ii. Return ? InitializeReferencedBinding(lhs, A).
+        let array = create_array_from_list(self.agent, values);
+
+        self.push_value(JSValue::from(array));
+
+        Ok(())
+    }
+
+    /// 14.7.5.6 ForIn/OfHeadEvaluation ( uninitializedBoundNames, expr, iterationKind )
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-forinofheadevaluation
+    fn exec_get_iterator(&mut self) -> VMResult {
+        // 5. Let exprValue be ? GetValue(exprRef).
+        let expr_value = self.pop_value()?;
+
+        // 7. Else,
+        // a. Assert: iterationKind is either iterate or async-iterate.
+        // b. If iterationKind is async-iterate, let iteratorKind be async.
+        // c. Else, let iteratorKind be sync.
+        // d. Return ? GetIterator(exprValue, iteratorKind).
+        let iterator_record = get_iterator(self.agent, &expr_value)?;
+
+        self.stack
+            .push(StackItem::Iterator(iterator_record, IterationKind::Iterate));
+
+        // 14.7.5.7 ForIn/OfBodyEvaluation ( lhs, stmt, iteratorRecord, iterationKind, lhsKind, labelSet [ , iteratorKind ] )
+        // 3. Let V be undefined.
+        self.push_value(JSValue::Undefined);
+
+        Ok(())
+    }
+
+    /// 14.7.5.7 ForIn/OfBodyEvaluation ( lhs, stmt, iteratorRecord, iterationKind, lhsKind, labelSet [ , iteratorKind ] )
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-forin-div-ofbodyevaluation-lhs-stmt-iterator-lhskind-labelset
+    ///
+    /// Expects the iterator record and the value of the loop to be on the stack, followed by any values produced by the previous evaluation of the loop body. Pushes the next value of the iterator, or jumps to the end of the loop leaving only the value of the loop once the iterator is done.
+    fn exec_iterator_step_value(&mut self) -> VMResult {
+        let exit = self.read_u32();
+
+        let position = self
+            .stack
+            .iter()
+            .rposition(|item| matches!(item, StackItem::Iterator(..)))
+            .ok_or(VMError::UnexpectedStackItem)?;
+
+        // o. If result.[[Value]] is not empty, set V to result.[[Value]].
+        if self.stack.len() > position + 2 {
+            let value = self.pop_value()?;
+
+            self.stack.truncate(position + 1);
+
+            self.push_value(value);
+        }
+
+        let StackItem::Iterator(iterator_record, _) = &mut self.stack[position] else {
+            unreachable!()
+        };
+
+        // a. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        // c. If nextResult is not an Object, throw a TypeError exception.
+        // d. Let done be ? IteratorComplete(nextResult).
+        // f. Let nextValue be ? IteratorValue(nextResult).
+        match iterator_step_value(self.agent, iterator_record)? {
+            Some(next_value) => self.push_value(next_value),
+            // e. If done is true, return V.
+            None => {
+                self.stack.remove(position);
+
+                self.ip = exit as usize;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 14.7.5.7 ForIn/OfBodyEvaluation ( lhs, stmt, iteratorRecord, iterationKind, lhsKind, labelSet [ , iteratorKind ] )
+    /// https://262.ecma-international.org/16.0/#sec-runtime-semantics-forin-div-ofbodyevaluation-lhs-stmt-iterator-lhskind-labelset
+    ///
+    /// Leaves the innermost for-in or for-of loop, as a break or continue statement targets a statement outside of it.
+    fn exec_iterator_close(&mut self) -> VMResult {
+        let position = self
+            .stack
+            .iter()
+            .rposition(|item| matches!(item, StackItem::Iterator(..)))
+            .ok_or(VMError::UnexpectedStackItem)?;
+
+        self.stack.truncate(position + 1);
+
+        let Some(StackItem::Iterator(iterator_record, iteration_kind)) = self.stack.pop() else {
+            unreachable!()
+        };
+
+        // l. Let result be Completion(Evaluation of stmt).
+        // n. If LoopContinues(result, labelSet) is false, then
+        // i. If iterationKind is enumerate, then
+        // 1. Return ? UpdateEmpty(result, V).
+        // ii. Else,
+        // 1. Assert: iterationKind is iterate.
+        // 2. Set status to Completion(UpdateEmpty(result, V)).
+        // 3. If iteratorKind is async, return ? AsyncIteratorClose(iteratorRecord, status).
+        // 4. Return ? IteratorClose(iteratorRecord, status).
+        if iteration_kind == IterationKind::Iterate && !iterator_record.done {
+            iterator_close(self.agent, &iterator_record, Ok(()))?;
+        }
+
+        Ok(())
+    }
+
+    /// 14.14.1 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-throw-statement-runtime-semantics-evaluation
+    fn exec_throw(&mut self) -> VMResult {
+        // 1. Let exprRef be ? Evaluation of Expression.
+        // 2. Let exprValue be ? GetValue(exprRef).
+        let expr_value = self.pop_value()?;
+
+        // 3. Return ThrowCompletion(exprValue).
+        Err(VMError::ThrowCompletion(ThrowCompletion::from(expr_value)))
+    }
+
+    fn exec_push_exception_handler(&mut self, kind: ExceptionHandlerKind) -> VMResult {
+        let target = self.read_u32() as usize;
+
+        self.handlers.push(ExceptionHandler {
+            kind,
+            target,
+            stack_height: self.stack.len(),
+            lexical_environment: self
+                .agent
+                .running_execution_context()
+                .lexical_environment
+                .clone(),
+        });
+
+        Ok(())
+    }
+
+    fn exec_pop_exception_handler(&mut self) -> VMResult {
+        self.handlers.pop();
+
+        Ok(())
+    }
+
+    /// 14.15.3 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-try-statement-runtime-semantics-evaluation
+    ///
+    /// Enters the finally clause after the block or catch clause completed normally, keeping the value they produced as the completion to resume with.
+    fn exec_enter_finally(&mut self) -> VMResult {
+        let handler = self.handlers.pop().ok_or(VMError::UnexpectedInstruction)?;
+
+        let value = if self.stack.len() > handler.stack_height {
+            self.pop_value()?
+        } else {
+            JSValue::Undefined
+        };
+
+        self.stack.truncate(handler.stack_height);
+
+        self.stack
+            .push(StackItem::Completion(Completion::Normal(value)));
+
+        Ok(())
+    }
+
+    /// 14.15.3 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-try-statement-runtime-semantics-evaluation
+    ///
+    /// Resumes the completion of the block or catch clause once the finally clause has completed normally, discarding the values produced by the finally clause.
+    fn exec_end_finally(&mut self) -> VMResult {
+        let position = self
+            .stack
+            .iter()
+            .rposition(|item| matches!(item, StackItem::Completion(_)))
+            .ok_or(VMError::UnexpectedStackItem)?;
+
+        self.stack.truncate(position + 1);
+
+        let Some(StackItem::Completion(completion)) = self.stack.pop() else {
+            unreachable!()
+        };
+
+        // 4. If F is a normal completion, set F to C.
+        match completion {
+            Completion::Normal(value) => {
+                self.push_value(value);
+
+                Ok(())
+            }
+            Completion::Return(value) => {
+                self.push_value(value);
+
+                self.exec_return()
+            }
+            Completion::Throw(value) => Err(VMError::ThrowCompletion(ThrowCompletion::from(value))),
+            Completion::Jump(target) => {
+                self.ip = target;
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Enters the finally clause of the innermost try statement with a break or continue completion, which continues at the target once the finally clause completes normally.
+    fn exec_jump_through_finally(&mut self) -> VMResult {
+        let target = self.read_u32() as usize;
+
+        let handler = self.handlers.pop().ok_or(VMError::UnexpectedInstruction)?;
+
+        self.enter_handler(handler, StackItem::Completion(Completion::Jump(target)));
+
+        Ok(())
+    }
+
+    /// Discards the completion of the innermost try statement whose finally clause is being evaluated, as a break or continue statement leaves the finally clause.
+    fn exec_pop_completion(&mut self) -> VMResult {
+        let position = self
+            .stack
+            .iter()
+            .rposition(|item| matches!(item, StackItem::Completion(_)))
+            .ok_or(VMError::UnexpectedStackItem)?;
+
+        self.stack.truncate(position);
+
+        Ok(())
+    }
+
+    /// 15.5.5 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-generator-function-definitions-runtime-semantics-evaluation
+    /// YieldExpression : yield AssignmentExpression
+    fn exec_yield(&mut self) -> VMResult {
+        let value = self.pop_value()?;
+
+        // 27.5.3.7 Yield ( value )
+        // 1. Let generatorKind be GetGeneratorKind().
+        // 2. If generatorKind is async, return ? AsyncGeneratorYield(? Await(value)).
+        // 3. Otherwise, return ? GeneratorYield(CreateIteratorResultObject(value, false)).
+        let iterator_result = create_iter_result_object(self.agent, value, false);
+
+        self.generator_yield(JSValue::from(iterator_result));
+
+        Ok(())
+    }
+
+    /// 15.5.5 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-generator-function-definitions-runtime-semantics-evaluation
+    /// YieldExpression : yield * AssignmentExpression
+    ///
+    /// Expects the value to delegate to on top of the stack when first evaluated, and the completion the generator was resumed with each time it is evaluated again. Pushes the value of the expression once the iterator is done, or yields its result and evaluates this instruction again when resumed.
+    fn exec_yield_delegate(&mut self) -> VMResult {
+        let received = match self.stack.pop().ok_or(VMError::StackUnderflow)? {
+            StackItem::Completion(received) => received,
+            StackItem::JSValue(value) => {
+                // 5. Let iteratorRecord be ? GetIterator(value, generatorKind).
+                let iterator_record = get_iterator(self.agent, &value)?;
+
+                self.stack
+                    .push(StackItem::DelegateIterator(iterator_record));
+
+                // 6. Let received be NormalCompletion(undefined).
+                Completion::Normal(JSValue::Undefined)
+            }
+            _ => return Err(VMError::UnexpectedStackItem),
+        };
+
+        // 4. Let iterator be iteratorRecord.[[Iterator]].
+        let Some(StackItem::DelegateIterator(iterator_record)) = self.stack.last() else {
+            return Err(VMError::UnexpectedStackItem);
+        };
+
+        let iterator = JSValue::from(iterator_record.iterator.clone());
+        let next_method = iterator_record.next_method.clone();
+
+        // 7. Repeat,
+        let (inner_result, is_return) = match received {
+            // a. If received is a normal completion, then
+            Completion::Normal(value) => {
+                // i. Let innerResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]], « received.[[Value]] »).
+                (
+                    call(self.agent, next_method, &iterator, Some(vec![value]))?,
+                    false,
+                )
+            }
+            // b. Else if received is a throw completion, then
+            Completion::Throw(value) => {
+                // i. Let throw be ? GetMethod(iterator, "throw").
+                let throw = get_method(
+                    self.agent,
+                    &iterator,
+                    &JSObjectPropKey::String("throw".into()),
+                )?;
+
+                // ii. If throw is not undefined, then
+                // 1. Let innerResult be ? Call(throw, iterator, « received.[[Value]] »).
+                let Some(throw) = throw else {
+                    // iii. Else,
+                    // 1. NOTE: If iterator does not have a throw method, this throw is going to terminate the yield* loop. But first we need to give iterator a chance to clean up.
+                    // 2. Let closeCompletion be NormalCompletion(empty).
+                    // 3. If generatorKind is async, perform ? AsyncIteratorClose(iteratorRecord, closeCompletion).
+                    // 4. Else, perform ? IteratorClose(iteratorRecord, closeCompletion).
+                    let Some(StackItem::DelegateIterator(iterator_record)) = self.stack.pop()
+                    else {
+                        unreachable!()
+                    };
+
+                    iterator_close(self.agent, &iterator_record, Ok(()))?;
+
+                    // 5. NOTE: The next step throws a TypeError to indicate that there was a yield* protocol violation: iterator does not have a throw method.
+                    // 6. Throw a TypeError exception.
+                    return Err(
+                        type_error::<()>("The iterator does not provide a 'throw' method")
+                            .unwrap_err()
+                            .into(),
+                    );
+                };
+
+                (
+                    call(self.agent, throw, &iterator, Some(vec![value]))?,
+                    false,
+                )
+            }
+            // c. Else,
+            Completion::Return(value) => {
+                // i. Assert: received is a return completion.
+                // ii. Let return be ? GetMethod(iterator, "return").
+                let return_method = get_method(
+                    self.agent,
+                    &iterator,
+                    &JSObjectPropKey::String("return".into()),
+                )?;
+
+                // iii. If return is undefined, then
+                let Some(return_method) = return_method else {
+                    // 1. Set value to received.[[Value]].
+                    // 2. If generatorKind is async, then
+                    // a. Set value to ? Await(value).
+                    // 3. Return ReturnCompletion(value).
+                    self.stack.pop();
+
+                    self.push_value(value);
+
+                    return self.exec_return();
+                };
+
+                // iv. Let innerReturnResult be ? Call(return, iterator, « received.[[Value]] »).
+                (
+                    call(self.agent, return_method, &iterator, Some(vec![value]))?,
+                    true,
+                )
+            }
+            Completion::Jump(_) => return Err(VMError::UnexpectedStackItem),
+        };
+
+        // NOTE: The remaining steps are the same for each kind of completion, except that the value of a done result is returned when the generator was resumed with a return completion.
+        // iii. If innerResult is not an Object, throw a TypeError exception.
+        let JSValue::Object(inner_result) = inner_result else {
+            return Err(
+                type_error_for::<()>("Iterator result is not an object", &inner_result)
+                    .unwrap_err()
+                    .into(),
+            );
+        };
+
+        // iv. Let done be ? IteratorComplete(innerResult).
+        let done = iterator_complete(self.agent, &inner_result)?;
+
+        // v. If done is true, then
+        if done {
+            // 1. Return ? IteratorValue(innerResult).
+            let value = iterator_value(self.agent, &inner_result)?;
+
+            self.stack.pop();
+
+            self.push_value(value);
+
+            if is_return {
+                return self.exec_return();
+            }
+
+            return Ok(());
+        }
+
+        // vi. If generatorKind is async, set received to Completion(AsyncGeneratorYield(? IteratorValue(innerResult))).
+        // vii. Else, set received to Completion(GeneratorYield(innerResult)).
+        self.ip -= 1;
+
+        self.generator_yield(JSValue::from(inner_result));
+
+        Ok(())
+    }
+
+    /// 15.8.5 Runtime Semantics: Evaluation
+    /// https://262.ecma-international.org/16.0/#sec-async-function-definitions-runtime-semantics-evaluation
+    /// AwaitExpression : await UnaryExpression
+    ///
+    /// Suspends the evaluation of the async function body, leaving the value on top of the stack for AsyncFunctionStart to await. When resumed, the completion the awaited promise settled with becomes the result of the await.
+    fn exec_await(&mut self) -> VMResult {
+        // 1. Let exprRef be ? Evaluation of UnaryExpression.
+        // 2. Let value be ? GetValue(exprRef).
+        self.peek_value()?;
+
+        // 3. Return ? Await(value).
+        self.running = false;
+        self.suspended = true;
+
+        Ok(())
+    }
+
+    /// 27.5.3.6 GeneratorYield ( iteratorResult )
+    /// https://262.ecma-international.org/16.0/#sec-generatoryield
+    ///
+    /// Suspends the evaluation of the generator body, leaving the iterator result on top of the stack for the caller of the generator. When resumed, the completion the generator was resumed with becomes the result of the yield.
+    fn generator_yield(&mut self, iterator_result: JSValue) {
+        self.push_value(iterator_result);
+
+        self.running = false;
+        self.suspended = true;
+    }
+}
+
+/// The innermost iterator on the stack, which is the iterator of the array binding pattern being initialized.
+fn innermost_iterator(stack: &mut [StackItem]) -> VMResult<&mut IteratorRecord> {
+    match stack
+        .iter_mut()
+        .rev()
+        .find(|item| matches!(item, StackItem::Iterator(..)))
+    {
+        Some(StackItem::Iterator(iterator_record, _)) => Ok(iterator_record),
+        _ => Err(VMError::UnexpectedStackItem),
+    }
 }
@@ -0,0 +1,150 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn object_literal_accessors() {
+    assert_script_eq!(
+        "let o = { a: 1, get x() { return this.a + 1; } }; o.x",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "let o = { a: 1, set x(v) { this.a = v * 2; } }; o.x = 5; o.a",
+        JSValue::from(10.0)
+    );
+    assert_script_eq!(
+        "let o = { get x() { return 1; } }; o.x = 2; o.x",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!("let o = { set x(v) {} }; o.x", JSValue::Undefined);
+    assert_script_eq!(
+        "let k = 'y'; let o = { get [k + 1]() { return 3; } }; o.y1",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let o = { get 'a b'() { return 4; }, get 5() { return 5; } }; o['a b'] + o[5]",
+        JSValue::from(9.0)
+    );
+    assert_script_throws!(
+        "'use strict'; let o = { get x() { return 1; } }; o.x = 2;",
+        "TypeError"
+    );
+}
+
+#[test]
+fn get_and_set_remain_property_names() {
+    assert_script_eq!(
+        "let o = { get: 1, set: 2 }; o.get + o.set",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!("let get = 1; let o = { get }; o.get", JSValue::from(1.0));
+    assert_script_eq!(
+        "let o = { get get() { return 6; } }; o.get",
+        JSValue::from(6.0)
+    );
+}
+
+#[test]
+fn accessor_function_names() {
+    assert_script_eq!(
+        "Object.getOwnPropertyDescriptor({ get x() {} }, 'x').get.name",
+        JSValue::from("get x")
+    );
+    assert_script_eq!(
+        "Object.getOwnPropertyDescriptor({ set x(v) {} }, 'x').set.name",
+        JSValue::from("set x")
+    );
+}
+
+#[test]
+fn accessor_parameters() {
+    assert_script_throws!(
+        "({ get x(a) {} })",
+        "SyntaxError: Getter must not have any formal parameters"
+    );
+    assert_script_throws!(
+        "({ set x() {} })",
+        "SyntaxError: Setter must have exactly one formal parameter"
+    );
+    assert_script_throws!(
+        "({ set x(a, b) {} })",
+        "SyntaxError: Setter must have exactly one formal parameter"
+    );
+    assert_script_throws!(
+        "({ set x(...a) {} })",
+        "SyntaxError: Setter must have exactly one formal parameter"
+    );
+    assert_script_eq!(
+        "let o = { set x(v = 3) { this.a = v; } }; o.x = undefined; o.a",
+        JSValue::from(3.0)
+    );
+}
+
+#[test]
+fn define_property_accessors() {
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { get: function () { return 7; } }); o.x",
+        JSValue::from(7.0)
+    );
+    assert_script_eq!(
+        "let o = { a: 1 }; Object.defineProperty(o, 'x', { get: function () { return this.a; }, set: function (v) { this.a = v * 2; } }); o.x = 5; o.x",
+        JSValue::from(10.0)
+    );
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { get: undefined }); o.x",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { value: 1 }) === o",
+        JSValue::Bool(true)
+    );
+    assert_script_eq!(
+        "let o = Object.defineProperty({}, 'x', { value: 1 }); o.x = 2; o.x",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!(
+        "Object.defineProperty({}, 'x', { get: function () {}, value: 1 })",
+        "TypeError: Invalid property descriptor"
+    );
+    assert_script_throws!(
+        "Object.defineProperty({}, 'x', { set: function (v) {}, writable: true })",
+        "TypeError: Invalid property descriptor"
+    );
+    assert_script_throws!(
+        "Object.defineProperty({}, 'x', { get: 1 })",
+        "TypeError: Getter must be a function"
+    );
+    assert_script_throws!(
+        "Object.defineProperty({}, 'x', 1)",
+        "TypeError: Property description must be an object"
+    );
+    assert_script_throws!("Object.defineProperty(1, 'x', {})", "TypeError");
+}
+
+#[test]
+fn get_own_property_descriptor() {
+    assert_script_eq!(
+        "let d = Object.getOwnPropertyDescriptor({ a: 1 }, 'a'); d.value + ',' + d.writable + ',' + d.enumerable + ',' + d.configurable",
+        JSValue::from("1,true,true,true")
+    );
+    assert_script_eq!(
+        "let d = Object.getOwnPropertyDescriptor({ get x() {} }, 'x'); typeof d.get + ',' + d.set + ',' + ('value' in d)",
+        JSValue::from("function,undefined,false")
+    );
+    assert_script_eq!(
+        "Object.getOwnPropertyDescriptor({}, 'a')",
+        JSValue::Undefined
+    );
+}
+
+#[test]
+fn object_constructor() {
+    assert_script_eq!("typeof Object()", JSValue::from("object"));
+    assert_script_eq!("new Object() instanceof Object", JSValue::Bool(true));
+    assert_script_eq!("let o = {}; Object(o) === o", JSValue::Bool(true));
+    assert_script_eq!(
+        "Object.prototype.constructor === Object",
+        JSValue::Bool(true)
+    );
+    assert_script_eq!("Object.length", JSValue::from(1.0));
+}
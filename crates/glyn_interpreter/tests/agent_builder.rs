@@ -0,0 +1,78 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glyn_interpreter::{
+    eval_script, ConsoleSink, EvalError, FixedClock, JSAgent, JSValue, LogLevel,
+};
+
+#[derive(Clone, Default)]
+struct RecordingSink(Rc<RefCell<Vec<String>>>);
+
+impl ConsoleSink for RecordingSink {
+    fn write(&mut self, _level: LogLevel, message: &str) {
+        self.0.borrow_mut().push(message.to_string());
+    }
+}
+
+#[test]
+fn builders_configure_the_agent() {
+    let sink = RecordingSink::default();
+
+    let mut agent = JSAgent::builder()
+        .clock(FixedClock {
+            time: 1000.0,
+            offset: 0.0,
+        })
+        .console(sink.clone())
+        .stack_trace_limit(1)
+        .build();
+
+    assert_eq!(
+        eval_script(&mut agent, "console.log('hi'); Date.now()"),
+        Ok(JSValue::from(1000.0))
+    );
+    assert_eq!(*sink.0.borrow(), vec!["hi".to_string()]);
+}
+
+#[test]
+fn builders_limit_call_depth_and_fuel() {
+    let mut agent = JSAgent::builder().max_call_depth(10).build();
+
+    // NOTE: The realm and the script each take up an execution context of their own.
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let depth = 0; function f() { depth = depth + 1; f(); } try { f(); } catch (e) {} depth"
+        ),
+        Ok(JSValue::from(8.0))
+    );
+
+    let mut agent = JSAgent::builder().fuel(100).build();
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let a = [0]; for (let x of a) { a[a.length] = x; }"
+        ),
+        Err(EvalError::FuelExhausted)
+    );
+}
+
+#[test]
+fn strict_only_agents_evaluate_scripts_as_strict_mode_code() {
+    let source = "function f() { return this; } typeof f()";
+
+    let mut agent = JSAgent::builder().strict_only(true).build();
+
+    assert_eq!(
+        eval_script(&mut agent, source),
+        Ok(JSValue::from("undefined"))
+    );
+    assert!(eval_script(&mut agent, "undeclared = 1")
+        .unwrap_err()
+        .to_string()
+        .starts_with("ReferenceError"));
+
+    let mut agent = JSAgent::default();
+
+    assert_eq!(eval_script(&mut agent, source), Ok(JSValue::from("object")));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "annex-b")]
+
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn define_getter_and_lookup_getter() {
+    assert_script_eq!(
+        "let o = {}; o.__defineGetter__('x', function () { return 7; }); let d = Object.getOwnPropertyDescriptor(o, 'x'); o.x + '' + d.enumerable + d.configurable + (o.__lookupGetter__('x') === d.get)",
+        JSValue::from("7truetruetrue")
+    );
+    assert_script_throws!(
+        "({}).__defineGetter__('x', 1)",
+        "TypeError: Object.prototype.__defineGetter__: argument 2 must be a function, but got a number (1)"
+    );
+}
+
+#[test]
+fn define_setter_and_lookup_setter_follow_the_prototype_chain() {
+    assert_script_eq!(
+        "let p = {}; p.__defineSetter__('y', function (v) { this.z = v; }); let o = {}; o.__proto__ = p; o.y = 3; o.z + '' + (o.__lookupSetter__('y') === p.__lookupSetter__('y')) + o.__lookupGetter__('y') + ({ a: 1 }).__lookupGetter__('a')",
+        JSValue::from("3trueundefinedundefined")
+    );
+}
+
+#[test]
+fn proto_accessor_gets_and_sets_the_prototype() {
+    assert_script_eq!(
+        "let o = {}; let p = { q: 1 }; o.__proto__ = p; (o.__proto__ === p) + '' + o.q + ({}.__proto__ === Object.prototype)",
+        JSValue::from("true1true")
+    );
+    assert_script_eq!(
+        "let o = {}; o.__proto__ = 1; o.__proto__ === Object.prototype",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "let o = {}; o.__proto__ = null; typeof o.toString",
+        JSValue::from("undefined")
+    );
+    assert_script_throws!(
+        "Object.prototype.__proto__ = {}",
+        "TypeError: Object.prototype.__proto__: cannot set the prototype"
+    );
+}
+
+#[test]
+fn annex_b_properties_can_be_left_out() {
+    let mut agent = glyn_interpreter::JSAgent::builder().annex_b(false).build();
+
+    assert_eq!(
+        glyn_interpreter::eval_script(
+            &mut agent,
+            "typeof ({}).__lookupGetter__ + typeof ({}).__proto__"
+        ),
+        Ok(JSValue::from("undefinedundefined"))
+    );
+}
+
+#[test]
+fn block_function_declarations_are_var_scoped_in_sloppy_mode() {
+    assert_script_eq!(
+        "let r = typeof g; { function g() { return 1 } } r + typeof g + g()",
+        JSValue::from("undefinedfunction1")
+    );
+    assert_script_eq!(
+        "function o() { let r = typeof g; { function g() {} } return r + typeof g } o()",
+        JSValue::from("undefinedfunction")
+    );
+    assert_script_eq!(
+        "{ function f() { return 1 } function f() { return 2 } f() }",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "function o(g) { { function g() {} } return typeof g } o(3)",
+        JSValue::from("number")
+    );
+    assert_script_eq!(
+        "function o() { { let g = 1; { function g() {} } } return typeof g } o()",
+        JSValue::from("undefined")
+    );
+    assert_script_eq!(
+        "\"use strict\"; { function g() {} } typeof g",
+        JSValue::from("undefined")
+    );
+    assert_script_eq!("{ function* g() {} } typeof g", JSValue::from("undefined"));
+}
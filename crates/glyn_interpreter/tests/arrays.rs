@@ -0,0 +1,206 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn array_constructor_creates_arrays() {
+    assert_script_eq!("new Array(3).length", JSValue::from(3));
+    assert_script_eq!("Array(1, 2).length + Array(1, 2)[1]", JSValue::from(4));
+    assert_script_eq!("Array('x').length + Array('x')[0]", JSValue::from("1x"));
+    assert_script_eq!("[].constructor === Array", JSValue::from(true));
+    assert_script_throws!("Array(-1)", "RangeError: Invalid array length");
+    assert_script_throws!("new Array(3 / 2)", "RangeError: Invalid array length");
+}
+
+#[test]
+fn array_from_copies_iterables_and_array_likes() {
+    assert_script_eq!(
+        "Array.from('abc', function (x, i) { return x + i; }).join('')",
+        JSValue::from("a0b1c2")
+    );
+    assert_script_eq!(
+        "const a = Array.from({ length: 2, 0: 'a', 1: 'b' }); a.length + a[0] + a[1]",
+        JSValue::from("2ab")
+    );
+    assert_script_eq!(
+        "Array.from({ length: 3 }, function (v, i) { return this.base + i; }, { base: 10 })[2]",
+        JSValue::from(12)
+    );
+    assert_script_eq!(
+        "function C(n) { this.n = n; } C.from = Array.from; const a = C.from({ length: 1, 0: 'x' }); a.n + a[0] + (a instanceof C)",
+        JSValue::from("1xtrue")
+    );
+    assert_script_throws!(
+        "Array.from([], 1)",
+        "TypeError: Array.from: the mapper is not a function"
+    );
+    assert_script_throws!("Array.from(null)", "TypeError: ");
+}
+
+#[test]
+fn array_from_closes_iterators_when_the_mapper_throws() {
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield 2; } finally { log = 'closed'; } } try { Array.from(g(), function () { throw 1; }); } catch (e) { log = log + e; } log",
+        JSValue::from("closed1")
+    );
+}
+
+#[test]
+fn array_of_creates_arrays_from_its_arguments() {
+    assert_script_eq!(
+        "Array.of(7).length + Array.of(1, 2, 3)[2]",
+        JSValue::from(4)
+    );
+    assert_script_eq!("Array.of().length", JSValue::from(0));
+    assert_script_eq!(
+        "function C(n) { this.n = n; } C.of = Array.of; const a = C.of(1, 2); a.n + '' + a.length + (a instanceof C)",
+        JSValue::from("22true")
+    );
+}
+
+#[test]
+fn array_is_array_detects_array_exotic_objects() {
+    assert_script_eq!(
+        "Array.isArray([]) + '' + Array.isArray(new Array()) + Array.isArray({ length: 0 }) + Array.isArray('a')",
+        JSValue::from("truetruefalsefalse")
+    );
+}
+
+#[test]
+fn array_sort_orders_elements() {
+    assert_script_eq!("[3, 1, 10, 2].sort().join()", JSValue::from("1,10,2,3"));
+    assert_script_eq!(
+        "[3, 1, 10, 2].sort(function (a, b) { return a - b; }).join()",
+        JSValue::from("1,2,3,10")
+    );
+    assert_script_eq!(
+        "const a = [{ k: 1, v: 'a' }, { k: 0, v: 'b' }, { k: 1, v: 'c' }, { k: 0, v: 'd' }, { k: 0, v: 'e' }]; a.sort(function (x, y) { return x.k - y.k; }); a[0].v + a[1].v + a[2].v + a[3].v + a[4].v",
+        JSValue::from("bdeac")
+    );
+    assert_script_eq!(
+        "[2, 1, 3].sort(function () { return NaN; }).join()",
+        JSValue::from("2,1,3")
+    );
+}
+
+#[test]
+fn array_sort_moves_undefined_and_holes_to_the_end() {
+    assert_script_eq!(
+        "const a = [undefined, 3, , 1]; a.sort(); a.length + '' + a[0] + a[1] + a[2] + (2 in a) + (3 in a)",
+        JSValue::from("413undefinedtruefalse")
+    );
+    assert_script_eq!(
+        "const o = { length: 3, 0: 'c', 2: 'a' }; o.sort = Array.prototype.sort; o.sort(); o[0] + o[1] + (2 in o) + o.length",
+        JSValue::from("acfalse3")
+    );
+}
+
+#[test]
+fn array_sort_stops_at_abrupt_comparisons() {
+    assert_script_eq!(
+        "let calls = 0; try { [3, 2, 1].sort(function () { calls = calls + 1; throw 1; }); } catch (e) {} calls",
+        JSValue::from(1)
+    );
+    assert_script_throws!(
+        "[1].sort(1)",
+        "TypeError: Array.prototype.sort: argument 1 must be a function, but got a number (1)"
+    );
+    assert_script_throws!(
+        "[1].toSorted(null)",
+        "TypeError: Array.prototype.toSorted: argument 1 must be a function, but got null"
+    );
+}
+
+#[test]
+fn array_to_sorted_returns_a_sorted_copy() {
+    assert_script_eq!(
+        "const a = ['b', 'a']; a.toSorted().join() + a.join()",
+        JSValue::from("a,bb,a")
+    );
+    assert_script_eq!(
+        "const b = [undefined, 3, , 1].toSorted(); b.length + '' + b[0] + b[1] + b[2] + b[3] + (3 in b)",
+        JSValue::from("413undefinedundefinedtrue")
+    );
+}
+
+#[test]
+fn array_at_and_with_use_relative_indices() {
+    assert_script_eq!(
+        "[1, 2, 3].at(-1) + [1, 2, 3].at(0) + ('' + [1].at(5))",
+        JSValue::from("4undefined")
+    );
+    assert_script_eq!(
+        "const a = [1, 2, 3]; a.with(-1, 9).join() + ';' + a.join()",
+        JSValue::from("1,2,9;1,2,3")
+    );
+    assert_script_throws!("[1].with(1, 2)", "RangeError: Invalid array index");
+}
+
+#[test]
+fn array_copy_within_and_fill_write_ranges() {
+    assert_script_eq!(
+        "[1, 2, 3, 4, 5].copyWithin(0, 3).join()",
+        JSValue::from("4,5,3,4,5")
+    );
+    assert_script_eq!(
+        "[1, 2, 3, 4, 5].copyWithin(1, 0, 3).join()",
+        JSValue::from("1,1,2,3,5")
+    );
+    assert_script_eq!(
+        "[1, 2, 3, 4, 5].copyWithin(-2, -4, -3).join()",
+        JSValue::from("1,2,3,2,5")
+    );
+    assert_script_eq!(
+        "[1, 2, 3].fill(0, 1).join() + ';' + [1, 2, 3].fill(9, -1).join()",
+        JSValue::from("1,0,0;1,2,9")
+    );
+}
+
+#[test]
+fn array_find_last_and_last_index_of_search_backwards() {
+    assert_script_eq!(
+        "[1, 2, 3, 4].findLast(function (x) { return x % 2 === 1; }) + ';' + [1, 2, 3].findLastIndex(function (x) { return x > 5; })",
+        JSValue::from("3;-1")
+    );
+    assert_script_eq!(
+        "[1, 2, 1, 3].lastIndexOf(1) + ';' + [1, 2, 1].lastIndexOf(1, -2) + ';' + [1].lastIndexOf(2)",
+        JSValue::from("2;0;-1")
+    );
+    assert_script_throws!(
+        "[].findLast(1)",
+        "TypeError: Array.prototype.findLast: argument 1 must be a function, but got a number (1)"
+    );
+}
+
+#[test]
+fn array_flat_and_flat_map_flatten_nested_arrays() {
+    assert_script_eq!(
+        "[1, [2, [3, [4]]]].flat().length + ';' + [1, [2, [3, [4]]]].flat(Infinity).join()",
+        JSValue::from("3;1,2,3,4")
+    );
+    assert_script_eq!(
+        "[1, 2].flatMap(function (x) { return [x, x * 2]; }).join()",
+        JSValue::from("1,2,2,4")
+    );
+    assert_script_eq!("[, 1].flat().length", JSValue::from(1));
+    assert_script_throws!(
+        "const a = [1, [2]]; a.constructor = 1; a.flat()",
+        "TypeError: Array species is not a constructor"
+    );
+}
+
+#[test]
+fn array_reverse_and_change_by_copy_methods() {
+    assert_script_eq!(
+        "const a = [1, 2, , 4]; a.reverse(); a.join() + (1 in a) + (2 in a)",
+        JSValue::from("4,,2,1falsetrue")
+    );
+    assert_script_eq!(
+        "const a = [1, 2, 3]; a.toReversed().join() + ';' + a.join()",
+        JSValue::from("3,2,1;1,2,3")
+    );
+    assert_script_eq!(
+        "[1, 2, 3, 4].toSpliced(1, 2, 'a', 'b', 'c').join() + ';' + [1, 2, 3].toSpliced(1).join() + ';' + [1, 2].toSpliced().join()",
+        JSValue::from("1,a,b,c,4;1;1,2")
+    );
+}
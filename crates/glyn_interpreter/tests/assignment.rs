@@ -0,0 +1,124 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn assignment_yields_the_assigned_value() {
+    assert_script_eq!("let x; x = 1", JSValue::from(1.0));
+    assert_script_eq!("let x; (x = 3) + 1", JSValue::from(4.0));
+    assert_script_eq!("let b; let a = (b = 2); a + b", JSValue::from(4.0));
+    assert_script_eq!(
+        "function f() { let x; return x = 9; } f()",
+        JSValue::from(9.0)
+    );
+    assert_script_eq!("let s; [s = 'a', s][1]", JSValue::from("a"));
+}
+
+#[test]
+fn chained_assignment() {
+    assert_script_eq!("let x; let y; x = y = 5; x + y", JSValue::from(10.0));
+    assert_script_eq!(
+        "let x; let y; let z; x = y = z = 'v'; x + y + z",
+        JSValue::from("vvv")
+    );
+    assert_script_eq!("let x = 1; let y = 2; x = y = x; y", JSValue::from(1.0));
+}
+
+#[test]
+fn property_targets() {
+    assert_script_eq!("let o = {}; o['k'] = 4", JSValue::from(4.0));
+    assert_script_eq!("let o = {}; let v = o.p = 3; v + o.p", JSValue::from(6.0));
+    assert_script_eq!(
+        "let a; let o = {}; a = o.q = o.r = 7; a + o.q + o.r",
+        JSValue::from(21.0)
+    );
+    assert_script_eq!(
+        "let o = { inner: {} }; let x; x = o.inner.p = o.p = 'n'; x + o.inner.p + o.p",
+        JSValue::from("nnn")
+    );
+    assert_script_eq!("let a = []; a[0] = a[1] = 2; a.length", JSValue::from(2.0));
+}
+
+#[test]
+fn evaluates_the_target_before_the_value() {
+    assert_script_eq!(
+        "let log = ''; let o = {};
+         function k() { log = log + 'k'; return 'p'; }
+         function v() { log = log + 'v'; return 1; }
+         o[k()] = v(); log",
+        JSValue::from("kv")
+    );
+    assert_script_eq!(
+        "let a = [0, 0]; let i = 0; a[i] = i = 1; a[0] + ',' + a[1] + ',' + i",
+        JSValue::from("1,0,1")
+    );
+}
+
+#[test]
+fn invalid_targets() {
+    assert_script_throws!("1 = 2", "SyntaxError: Invalid left-hand side in assignment");
+    assert_script_throws!(
+        "let x; (x = 1) = 2",
+        "SyntaxError: Invalid left-hand side in assignment"
+    );
+    assert_script_throws!(
+        "const c = 1; let x; x = c = 2",
+        "TypeError: Assignment to constant variable"
+    );
+}
+
+#[test]
+fn writable_properties_are_written() {
+    assert_script_eq!(
+        "let o = { x: 1 }; o.x = 2; o.x + ',' + Object.getOwnPropertyDescriptor(o, 'x').writable",
+        JSValue::from("2,true")
+    );
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { value: 1, writable: true }); o.x = 2; o.x",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn non_writable_properties_are_not_written() {
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { value: 1 }); o.x = 2; o.x",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!(
+        "'use strict'; let o = {}; Object.defineProperty(o, 'x', { value: 1 }); o.x = 2;",
+        "TypeError: Cannot assign to read only property"
+    );
+}
+
+#[test]
+fn inherited_properties_are_shadowed_on_the_receiver() {
+    assert_script_eq!(
+        "function F() {} F.prototype.x = 1; let o = new F(); o.x = 2; o.x + ',' + F.prototype.x",
+        JSValue::from("2,1")
+    );
+    assert_script_eq!(
+        "function F() {} Object.defineProperty(F.prototype, 'x', { value: 1 }); let o = new F(); o.x = 2; o.x + ',' + Object.getOwnPropertyDescriptor(o, 'x')",
+        JSValue::from("1,undefined")
+    );
+}
+
+#[test]
+fn accessor_properties_are_written_through_their_setter() {
+    assert_script_eq!(
+        "let o = { set x(v) { this.y = v; } }; o.x = 2; o.y + ',' + o.x",
+        JSValue::from("2,undefined")
+    );
+    assert_script_eq!(
+        "let receiver; function F() {} Object.defineProperty(F.prototype, 'x', { set: function (v) { receiver = this; } }); let o = new F(); o.x = 2; (receiver === o) + ',' + Object.getOwnPropertyDescriptor(o, 'x')",
+        JSValue::from("true,undefined")
+    );
+    assert_script_eq!(
+        "let o = { get x() { return 1; } }; o.x = 2; o.x",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!(
+        "'use strict'; let o = { get x() { return 1; } }; o.x = 2;",
+        "TypeError: Cannot assign to read only property"
+    );
+}
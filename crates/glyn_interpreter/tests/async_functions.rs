@@ -0,0 +1,147 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn async_functions_return_promises() {
+    assert_script_eq!(
+        "async function f() { return 1; } f() instanceof Promise",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "let f = async function () {}; f() instanceof Promise",
+        JSValue::from(true)
+    );
+    assert_script_throws!("async function f() {} new f()", "TypeError");
+    assert_eq!(
+        settle!(
+            "let result;
+             async function f(a, b) { return a + b; }
+             f(1, 2).then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from(3.0)
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             let f = async function named() { return named === f; };
+             f().then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from(true)
+    );
+}
+
+#[test]
+fn await_suspends_until_the_promise_settles() {
+    assert_eq!(
+        settle!(
+            "let log = '';
+             async function f() { log = log + 'a'; await null; log = log + 'c'; }
+             f();
+             log = log + 'b';",
+            "log"
+        ),
+        JSValue::from("abc")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             async function f() {
+               let x = await Promise.resolve(2);
+               let y = await 3;
+               let z = await { then: function (resolve) { resolve(4); } };
+               return x + y + z;
+             }
+             f().then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from(9.0)
+    );
+    assert_eq!(
+        settle!(
+            "let log = '';
+             async function f(name) { log = log + name + 1; await null; log = log + name + 2; }
+             f('a');
+             f('b');",
+            "log"
+        ),
+        JSValue::from("a1b1a2b2")
+    );
+    assert_eq!(
+        settle!(
+            "let sum = 0;
+             async function f() { for (let v of [1, 2, 3]) { sum = sum + await v; } }
+             f();",
+            "sum"
+        ),
+        JSValue::from(6.0)
+    );
+}
+
+#[test]
+fn rejections_are_thrown_at_the_await() {
+    assert_eq!(
+        settle!(
+            "let result;
+             async function f() { await Promise.reject('bad'); result = 'skipped'; }
+             f().catch(function (e) { result = e; });",
+            "result"
+        ),
+        JSValue::from("bad")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             async function f() {
+               try { await Promise.reject('bad'); } catch (e) { return 'caught ' + e; }
+             }
+             f().then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from("caught bad")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             async function f() { throw 'sync'; }
+             let p = f();
+             result = 'returned';
+             p.catch(function (e) { result = result + ' ' + e; });",
+            "result"
+        ),
+        JSValue::from("returned sync")
+    );
+    assert_eq!(
+        settle!(
+            "let log = '';
+             async function f() {
+               try { await null; return 'try'; } finally { log = log + 'finally,'; }
+             }
+             f().then(function (v) { log = log + v; });",
+            "log"
+        ),
+        JSValue::from("finally,try")
+    );
+}
+
+#[test]
+fn await_and_async_as_identifiers() {
+    assert_script_eq!("let await = 1; await", JSValue::from(1.0));
+    assert_script_eq!("let async = 2; async", JSValue::from(2.0));
+    assert_script_eq!("let async = 3; async\nfunction f() {}", JSValue::from(3.0));
+    assert_script_eq!(
+        "async function f() { function g() { let await = 4; return await; } return g(); } f() instanceof Promise",
+        JSValue::from(true)
+    );
+    assert_script_throws!(
+        "async function f() { let await = 1; }",
+        "SyntaxError: Unexpected token"
+    );
+    assert_script_throws!(
+        "async function f(await) {}",
+        "SyntaxError: Unexpected token"
+    );
+    assert_script_throws!("async function* f() {}", "SyntaxError: Unexpected token");
+}
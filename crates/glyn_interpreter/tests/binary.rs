@@ -34,6 +34,18 @@ fn operator_precedence() {
     assert_script_eq!("2 << 7 >> 1 ** 5 / 2", JSValue::Number(256.into()));
 }
 
+#[test]
+fn constant_folding() {
+    assert_script_eq!("let z = 0; 1 / -0 === 1 / -z", JSValue::Bool(true));
+    assert_script_eq!("let n = 0 / 0; n === n", JSValue::Bool(false));
+    assert_script_eq!("0 / 0 === 0 / 0", JSValue::Bool(false));
+    assert_script_eq!("'a' + 1 + 2", JSValue::from(String::from("a12")));
+    assert_script_eq!("1 + 2 + 'a'", JSValue::from(String::from("3a")));
+    assert_script_eq!("'b' < 'a' + 'c'", JSValue::Bool(false));
+    assert_script_eq!("null === null", JSValue::Bool(true));
+    assert_script_eq!("-(2 ** 2) + 1", JSValue::Number((-3).into()));
+}
+
 #[test]
 fn relational() {
     assert_script_eq!("3 > 2", JSValue::Bool(true));
@@ -45,3 +57,63 @@ fn relational() {
     assert_script_eq!("4 <= 5", JSValue::Bool(true));
     assert_script_eq!("4 <= 4", JSValue::Bool(true));
 }
+
+#[test]
+fn addition_converts_operands_to_primitives() {
+    assert_script_eq!("let one = 1; one + '2'", JSValue::from(String::from("12")));
+    assert_script_eq!(
+        "let t = true; t + '1'",
+        JSValue::from(String::from("true1"))
+    );
+    assert_script_eq!("let n = null; n + 1", JSValue::Number(1.into()));
+    assert_script_eq!(
+        "let o = {}; o + ''",
+        JSValue::from(String::from("[object Object]"))
+    );
+    assert_script_eq!("[] + {}", JSValue::from(String::from("[object Object]")));
+    assert_script_eq!(
+        "[1, [2, null], undefined] + 3",
+        JSValue::from(String::from("1,2,,3"))
+    );
+    assert_script_eq!(
+        "({ valueOf: function () { return 2; }, toString: function () { return 'T'; } }) + ''",
+        JSValue::from(String::from("2"))
+    );
+    assert_script_eq!(
+        "({ toString: function () { return 'T'; } }) + 1",
+        JSValue::from(String::from("T1"))
+    );
+    assert_script_eq!(
+        "let a = [1, 2]; a.join = 0; a + ''",
+        JSValue::from(String::from("[object Array]"))
+    );
+    assert_script_throws!(
+        "({ valueOf: function () { return {}; }, toString: function () { return {}; } }) + 1",
+        "TypeError: Cannot convert object to primitive value"
+    );
+}
+
+#[test]
+fn exponentiation() {
+    assert_script_eq!("let two = 2; two ** 3 ** 2", JSValue::Number(512.into()));
+    assert_script_eq!("let two = 2; (two ** 3) ** 2", JSValue::Number(64.into()));
+    assert_script_eq!("let two = 2; two ** 3 * 2", JSValue::Number(16.into()));
+    assert_script_eq!("let two = 2; 2 * two ** 3", JSValue::Number(16.into()));
+    assert_script_eq!("let two = 2; (-two) ** 2", JSValue::Number(4.into()));
+    assert_script_eq!("let two = 2; -(two ** 2)", JSValue::Number((-4).into()));
+    assert_script_eq!("let two = 2; two ** -1", JSValue::Number(0.5.into()));
+
+    for source in [
+        "-2 ** 2",
+        "let a = 2; +a ** 2",
+        "typeof 2 ** 2",
+        "let a = {}; delete a.b ** 2",
+        "2 ** -2 ** 2",
+        "async function f() { return await 2 ** 2; }",
+    ] {
+        assert_script_throws!(
+            source,
+            "SyntaxError: Unary operator used immediately before exponentiation expression"
+        );
+    }
+}
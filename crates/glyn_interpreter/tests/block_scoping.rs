@@ -0,0 +1,169 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+
+mod common;
+
+#[test]
+fn block_scoped_bindings() {
+    assert_script_eq!("let x = 1; { let x = 2; } x", JSValue::from(1.0));
+    assert_script_eq!("let x = 1; { let x = 2; x }", JSValue::from(2.0));
+    assert_script_eq!("let x = 1; { x = 2; } x", JSValue::from(2.0));
+    assert_script_eq!(
+        "let x = 1; { let y = x + 1; { let x = y * 2; x } }",
+        JSValue::from(4.0)
+    );
+    assert_script_throws!("{ let y = 1; } y", "ReferenceError");
+}
+
+#[test]
+fn multiple_bindings() {
+    assert_script_eq!("let a = 1, b = a + 1, c; b", JSValue::from(2.0));
+    assert_script_eq!("let a = 1, b = 2, c; c", JSValue::Undefined);
+    assert_script_eq!(
+        "const a = 1, b = { c: 2, d: 3 }, e = 4; e",
+        JSValue::from(4.0)
+    );
+}
+
+#[test]
+fn temporal_dead_zone() {
+    assert_script_throws!("x; let x = 1;", "ReferenceError");
+    assert_script_throws!("let x = 1; { x; let x = 2; }", "ReferenceError");
+    assert_script_throws!("let x = x;", "ReferenceError");
+    assert_script_throws!("x = 1; let x;", "ReferenceError");
+    assert_script_throws!(
+        "function f() { return x } f(); let x = 1;",
+        "ReferenceError"
+    );
+    assert_script_eq!(
+        "function f() { return x } let x = 1; f()",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!(
+        "function f() { { g(); let y = 1; function g() { return y } } } f()",
+        "ReferenceError"
+    );
+}
+
+#[test]
+fn temporal_dead_zone_error_message() {
+    assert_script_throws!(
+        "x; let x = 1;",
+        "ReferenceError: Cannot access 'x' before initialization"
+    );
+    assert_script_throws!(
+        "x = 1; let x;",
+        "ReferenceError: Cannot access 'x' before initialization"
+    );
+    assert_script_throws!(
+        "function f() { return y } f(); const y = 1;",
+        "ReferenceError: Cannot access 'y' before initialization"
+    );
+}
+
+#[test]
+fn closures_capture_block_environment() {
+    assert_script_eq!(
+        "let f; { let x = 1; f = function () { return x }; } let x = 2; f()",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn redeclaration() {
+    assert_script_throws!(
+        "let x; let x;",
+        "SyntaxError: Identifier has already been declared"
+    );
+    assert_script_throws!(
+        "{ const x = 1; let x; }",
+        "SyntaxError: Identifier has already been declared"
+    );
+    assert_script_throws!(
+        "function f(a) { let a; }",
+        "SyntaxError: Identifier has already been declared"
+    );
+    assert_script_eq!(
+        "let x = 1; { let x = 2; } { let x = 3; x }",
+        JSValue::from(3.0)
+    );
+}
+
+#[test]
+fn redeclaration_by_a_later_script() {
+    let mut agent = JSAgent::default();
+
+    eval_script(&mut agent, "let x = 1; function f() {}").unwrap();
+
+    for source in ["let x = 2;", "const f = 1;", "let undefined;"] {
+        let name = source.split([' ', ';']).nth(1).unwrap();
+
+        assert_eq!(
+            eval_script(&mut agent, source).unwrap_err().to_string(),
+            format!("SyntaxError: Identifier '{name}' has already been declared")
+        );
+    }
+
+    assert_eq!(eval_script(&mut agent, "x"), Ok(JSValue::from(1.0)));
+}
+
+#[test]
+fn const_declarations() {
+    assert_script_eq!("const x = 1; x", JSValue::from(1.0));
+    assert_script_throws!(
+        "const x;",
+        "SyntaxError: Missing initializer in const declaration"
+    );
+    assert_script_throws!("x; const x = 1;", "ReferenceError");
+}
+
+#[test]
+fn bindings_of_enclosing_blocks_are_read_and_assigned() {
+    assert_script_eq!(
+        "{ let a = 1; { let b = 2; { a = a + b; b = a * 10; } b + a } }",
+        JSValue::from(33.0)
+    );
+    assert_script_eq!(
+        "let r = ''; for (const v of ['a', 'b']) { let w = v; { w = w + v; } r = r + w; } r",
+        JSValue::from("aabb")
+    );
+    assert_script_eq!(
+        "try { throw 1; } catch (e) { { e = e + 1; } e }",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "{ let t = 1; with ({ t: 2 }) { t = 3; } t }",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!("{ { a; } let a = 1; }", "ReferenceError");
+    assert_script_throws!("{ { a = 2; } let a = 1; }", "ReferenceError");
+}
+
+#[test]
+fn block_function_declarations() {
+    assert_script_eq!(
+        "{ let x = 1; function g() { return x } g() }",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f() { const x = 3; { const y = 4; { function g() { return x + y } return g(); } } } f()",
+        JSValue::from(7.0)
+    );
+    assert_script_eq!("{ g(); function g() { return 1 } }", JSValue::from(1.0));
+    assert_script_eq!(
+        "{ let a = 1; `${a}`; function g() { return a } g() }",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "\"use strict\"; { function g() {} } typeof g",
+        JSValue::from("undefined")
+    );
+    assert_script_eq!("let f = 1; { function f() {} } f", JSValue::from(1.0));
+    assert_script_throws!(
+        "{ let f; function f() {} }",
+        "SyntaxError: Identifier has already been declared"
+    );
+    assert_script_throws!(
+        "\"use strict\"; { function f() {} function f() {} }",
+        "SyntaxError: Identifier has already been declared"
+    );
+}
@@ -0,0 +1,23 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn boolean_prototype_methods() {
+    assert_script_eq!(
+        "true.toString() + false.valueOf()",
+        JSValue::from("truefalse")
+    );
+    assert_script_throws!(
+        "let valueOf = true.valueOf; valueOf()",
+        "TypeError: Boolean.prototype.valueOf called on an incompatible receiver"
+    );
+}
+
+#[test]
+fn boolean_objects() {
+    assert_script_eq!(
+        "let b = Object(false); b.tag = ({}).toString; b.tag() + b.valueOf() + b",
+        JSValue::from("[object Boolean]falsefalse")
+    );
+}
@@ -0,0 +1,39 @@
+use glyn_interpreter::script_to_bytecode_json;
+
+#[test]
+fn bytecode_json_schema() {
+    let json = script_to_bytecode_json("let x = 1;").unwrap();
+
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"version":1,"#,
+            r#""instructions":["#,
+            r#"{"offset":0,"opcode":"CreateMutableBinding","operands":[0]},"#,
+            r#"{"offset":2,"opcode":"ResolveBinding","operands":[0]},"#,
+            r#"{"offset":4,"opcode":"Const","operands":[0]},"#,
+            r#"{"offset":6,"opcode":"InitializeReferencedBinding","operands":[]}],"#,
+            r#""constants":[{"type":"number","value":1}],"#,
+            r#""identifiers":["x"],"#,
+            r#""spans":[{"offset":2,"start":0,"end":10}],"#,
+            r#""functions":[]}"#
+        )
+    );
+}
+
+#[test]
+fn bytecode_json_spans() {
+    let json = script_to_bytecode_json("1 + 2;\n  3").unwrap();
+
+    assert!(json
+        .contains(r#""spans":[{"offset":0,"start":0,"end":6},{"offset":2,"start":9,"end":10}]"#));
+}
+
+#[test]
+fn bytecode_json_folded_constants() {
+    let json = script_to_bytecode_json("let x = 'a' + 'b'; x + -2 * 3 + 'ab';").unwrap();
+
+    assert!(json
+        .contains(r#""constants":[{"type":"string","value":"ab"},{"type":"number","value":-6}]"#));
+    assert!(!json.contains("BinMultiply"));
+}
@@ -0,0 +1,119 @@
+use glyn_interpreter::{eval_script, Args, EvalError, JSAgent, JSValue, ThrowCompletion};
+
+mod common;
+
+fn register_depth(agent: &mut JSAgent) {
+    agent.register_function("depth", 0, |agent: &mut JSAgent, _: Args| {
+        Ok(JSValue::from(agent.execution_context_depth() as f64))
+    });
+}
+
+fn register_evaluate(agent: &mut JSAgent) {
+    agent.register_function("evaluate", 1, |agent: &mut JSAgent, args: Args| {
+        let source = args.get(0).to_rust_string().unwrap_or_default();
+
+        eval_script(agent, &source).map_err(|error| match error {
+            EvalError::UncaughtException { value, .. } => ThrowCompletion::from(value),
+            error => ThrowCompletion::from(JSValue::from(error.to_string())),
+        })
+    });
+}
+
+#[test]
+fn unbounded_recursion_throws() {
+    assert_script_throws!(
+        "function f() { return f(); } f()",
+        "RangeError: maximum call stack size exceeded"
+    );
+    assert_script_throws!(
+        "function F() { return new F(); } new F()",
+        "RangeError: maximum call stack size exceeded"
+    );
+    assert_script_throws!(
+        "let o = { get x() { return o.x; } }; o.x",
+        "RangeError: maximum call stack size exceeded"
+    );
+    assert_script_eq!(
+        "function f() { return f(); } let error; try { f(); } catch (e) { error = e; } (error instanceof RangeError) + \": \" + error.message",
+        JSValue::from("true: maximum call stack size exceeded")
+    );
+}
+
+#[test]
+fn stack_is_unwound_after_overflow() {
+    let mut agent = JSAgent::default();
+
+    register_depth(&mut agent);
+
+    let before = eval_script(&mut agent, "depth()");
+
+    assert!(eval_script(&mut agent, "function f() { return f(); } f()").is_err());
+    assert_eq!(eval_script(&mut agent, "depth()"), before);
+    assert_eq!(
+        eval_script(&mut agent, "function g() { return depth(); } g() - depth()"),
+        Ok(JSValue::from(1.0))
+    );
+}
+
+#[test]
+fn max_call_depth_is_configurable() {
+    let count_calls = |max_call_depth| {
+        let mut agent = JSAgent::default();
+
+        agent.set_max_call_depth(max_call_depth);
+
+        eval_script(
+            &mut agent,
+            "let count = 0; function f() { count = count + 1; return f(); } try { f(); } catch (e) {} count",
+        )
+        .unwrap()
+        .as_f64()
+        .unwrap()
+    };
+
+    assert_eq!(count_calls(30) - count_calls(20), 10.0);
+
+    let mut agent = JSAgent::default();
+
+    agent.set_max_call_depth(2);
+
+    assert!(eval_script(&mut agent, "1 + 1").is_ok());
+    assert_eq!(
+        eval_script(&mut agent, "function f() {} f()").map_err(|error| error.to_string()),
+        Err(String::from(
+            "RangeError: maximum call stack size exceeded\n    at 1:17"
+        ))
+    );
+}
+
+#[test]
+fn native_functions_can_reenter_the_agent() {
+    let mut agent = JSAgent::default();
+
+    register_depth(&mut agent);
+    register_evaluate(&mut agent);
+
+    assert_eq!(
+        eval_script(&mut agent, "evaluate('1 + 1') + 1"),
+        Ok(JSValue::from(3.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "evaluate('depth()') - depth()"),
+        Ok(JSValue::from(2.0))
+    );
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let caught; try { evaluate('throw 7;'); } catch (e) { caught = e; } caught"
+        ),
+        Ok(JSValue::from(7.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "function f() { return evaluate('f()'); } f()")
+            .map_err(|error| error.to_string()),
+        Err(String::from(
+            "RangeError: maximum call stack size exceeded\n    at f (1:16)\n    at 1:42"
+        ))
+    );
+    assert_eq!(eval_script(&mut agent, "depth()"), Ok(JSValue::from(3.0)));
+}
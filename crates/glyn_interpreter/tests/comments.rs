@@ -0,0 +1,32 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn comments() {
+    assert_script_eq!(
+        "#!/usr/bin/env glyn\n// one\nlet a = 1; /* two */ let b = /* three\n */ 2;\na + b // four",
+        JSValue::from(3.0)
+    );
+    assert_script_throws!(
+        "1 + 2 /* three",
+        "SyntaxError: Unterminated multi-line comment"
+    );
+}
+
+#[test]
+fn html_like_comments() {
+    assert_script_eq!(
+        "let a = 1; <!-- a = 2\n--> a = 3\n /*\n*/ --> a = 4\na",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn multi_line_comments_are_line_terminators() {
+    assert_script_eq!("async /* */ function f() {} 1", JSValue::from(1.0));
+    assert_script_throws!(
+        "async /*\n*/ function f() {}",
+        "ReferenceError: async is not defined"
+    );
+}
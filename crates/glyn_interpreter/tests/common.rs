@@ -11,3 +11,61 @@ macro_rules! assert_script_eq {
         }
     };
 }
+
+#[macro_export]
+macro_rules! assert_script_throws {
+    ($source: expr, $expected: expr) => {
+        let mut agent = glyn_interpreter::JSAgent::default();
+
+        let completion_record = glyn_interpreter::eval_script(&mut agent, $source);
+
+        match completion_record {
+            Ok(result) => panic!("Expected script to throw, but it evaluated to: {result:?}"),
+            Err(err) => assert!(
+                err.to_string().starts_with($expected),
+                "Expected error starting with {:?}, got {err:?}",
+                $expected
+            ),
+        }
+    };
+}
+
+/// Evaluates the script, runs the promise jobs it enqueued and then evaluates the expression.
+#[macro_export]
+macro_rules! settle {
+    ($source: expr, $expression: expr $(,)?) => {{
+        let mut agent = glyn_interpreter::JSAgent::default();
+
+        glyn_interpreter::eval_script(&mut agent, $source).expect("script should evaluate");
+        agent.run_jobs().expect("jobs should run");
+
+        glyn_interpreter::eval_script(&mut agent, $expression).expect("expression should evaluate")
+    }};
+}
+
+/// An agent with timers installed, the clock of which is stopped at 0, which evaluates the script.
+#[macro_export]
+macro_rules! agent_with_timers {
+    ($source: expr $(,)?) => {{
+        let mut agent = glyn_interpreter::JSAgent::builder()
+            .clock(glyn_interpreter::FixedClock {
+                time: 0.0,
+                offset: 0.0,
+            })
+            .build();
+
+        agent.install_timers();
+
+        glyn_interpreter::eval_script(&mut agent, $source).unwrap();
+
+        agent
+    }};
+}
+
+/// The value of the global `log` binding the scripts of the timer and event loop tests append to.
+#[macro_export]
+macro_rules! log {
+    ($agent: expr $(,)?) => {
+        glyn_interpreter::eval_script($agent, "log;").unwrap()
+    };
+}
@@ -24,3 +24,54 @@ fn loosely_equal() {
     assert_script_eq!("+0 == -0", JSValue::Bool(true));
     // TODO: Add more tests for different types.
 }
+
+/// The values compared by the matrices below, which are the zeros and NaN along with values of other types that they must not equal.
+const VALUES: [&str; 6] = ["0", "-0", "NaN", "1", "'0'", "undefined"];
+
+#[test]
+fn same_value_distinguishes_zeros_and_equates_nans() {
+    for (i, x) in VALUES.iter().enumerate() {
+        for (j, y) in VALUES.iter().enumerate() {
+            assert_script_eq!(&format!("Object.is({x}, {y})"), JSValue::Bool(i == j));
+        }
+    }
+}
+
+#[test]
+fn same_value_zero_equates_zeros_and_nans() {
+    for (i, x) in VALUES.iter().enumerate() {
+        for (j, y) in VALUES.iter().enumerate() {
+            let zeros = i < 2 && j < 2;
+
+            assert_script_eq!(
+                &format!("[{y}].includes({x})"),
+                JSValue::Bool(i == j || zeros)
+            );
+        }
+    }
+}
+
+#[test]
+fn strict_equality_equates_zeros_but_not_nans() {
+    for (i, x) in VALUES.iter().enumerate() {
+        for (j, y) in VALUES.iter().enumerate() {
+            let zeros = i < 2 && j < 2;
+            let nan = *x == "NaN";
+
+            assert_script_eq!(
+                &format!("{x} === {y}"),
+                JSValue::Bool((i == j && !nan) || zeros)
+            );
+        }
+    }
+}
+
+#[test]
+fn includes_searches_from_the_index() {
+    assert_script_eq!("[1, 2, 3].includes(1, 1)", JSValue::Bool(false));
+    assert_script_eq!("[1, 2, 3].includes(3, -1)", JSValue::Bool(true));
+    assert_script_eq!("[1, 2, 3].includes(1, -10)", JSValue::Bool(true));
+    assert_script_eq!("[1, 2, 3].includes(1, Infinity)", JSValue::Bool(false));
+    assert_script_eq!("[].includes(undefined)", JSValue::Bool(false));
+    assert_script_eq!("[, 1].includes(undefined)", JSValue::Bool(true));
+}
@@ -0,0 +1,185 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use glyn_interpreter::{
+    eval_script, eval_script_in_realm, ConsoleSink, HostClock, JSAgent, JSValue, LogLevel,
+    WriterSink,
+};
+
+type Messages = Rc<RefCell<Vec<(LogLevel, String)>>>;
+
+/// An agent whose console records each message along with its level.
+fn recording_agent() -> (JSAgent, Messages) {
+    let mut agent = JSAgent::default();
+    let messages = Messages::default();
+    let sink = messages.clone();
+
+    agent.set_console(move |level: LogLevel, message: &str| {
+        sink.borrow_mut().push((level, message.to_string()));
+    });
+
+    (agent, messages)
+}
+
+fn messages_of(source: &str) -> Vec<(LogLevel, String)> {
+    let (mut agent, messages) = recording_agent();
+
+    eval_script(&mut agent, source).unwrap();
+
+    messages.take()
+}
+
+/// A clock which advances by a millisecond and a half each time it is read.
+#[derive(Default)]
+struct TickingClock {
+    time: Cell<f64>,
+}
+
+impl HostClock for TickingClock {
+    fn now(&self) -> f64 {
+        self.time.set(self.time.get() + 1.5);
+        self.time.get()
+    }
+}
+
+#[test]
+fn logging_functions_write_at_their_levels() {
+    assert_eq!(
+        messages_of(
+            "console.log('log', 1); console.info('info'); console.debug('debug'); console.warn('warn'); console.error('error');"
+        ),
+        vec![
+            (LogLevel::Log, String::from("log 1")),
+            (LogLevel::Info, String::from("info")),
+            (LogLevel::Debug, String::from("debug")),
+            (LogLevel::Warn, String::from("warn")),
+            (LogLevel::Error, String::from("error")),
+        ]
+    );
+}
+
+#[test]
+fn values_are_inspected() {
+    assert_eq!(
+        messages_of(
+            "console.log('a', 'b c', undefined, null, true, 1, { x: ['y'] }, function f() {});"
+        ),
+        vec![(
+            LogLevel::Log,
+            String::from("a b c undefined null true 1 { x: [ 'y' ] } [Function: f]")
+        )]
+    );
+    assert_eq!(messages_of("console.log();"), vec![]);
+}
+
+#[test]
+fn format_specifiers_are_replaced_by_the_values_after_them() {
+    assert_eq!(
+        messages_of(
+            "console.log('%s is %d', 'x', 5); console.log('%i|%d|%f', '42.9px', 'a', '1.5e1'); console.log('%o and %O', { a: 1 }, ['b']); console.log('%cstyled', 'color: red');"
+        ),
+        vec![
+            (LogLevel::Log, String::from("x is 5")),
+            (LogLevel::Log, String::from("42|NaN|15")),
+            (LogLevel::Log, String::from("{ a: 1 } and [ 'b' ]")),
+            (LogLevel::Log, String::from("styled")),
+        ]
+    );
+    assert_eq!(
+        messages_of(
+            "console.log('%s and %s', 'a'); console.log('%s', 'a', 'b', 1); console.log('100%', 1); console.log('%s');"
+        ),
+        vec![
+            (LogLevel::Log, String::from("a and %s")),
+            (LogLevel::Log, String::from("a b 1")),
+            (LogLevel::Log, String::from("100% 1")),
+            (LogLevel::Log, String::from("%s")),
+        ]
+    );
+}
+
+#[test]
+fn assert_writes_only_when_the_condition_is_falsy() {
+    assert_eq!(
+        messages_of(
+            "console.assert(true, 'no'); console.assert(0); console.assert('', 'message', 1); console.assert(null, { a: 1 });"
+        ),
+        vec![
+            (LogLevel::Error, String::from("Assertion failed")),
+            (LogLevel::Error, String::from("Assertion failed: message 1")),
+            (LogLevel::Error, String::from("Assertion failed { a: 1 }")),
+        ]
+    );
+}
+
+#[test]
+fn timers_measure_the_time_of_the_clock() {
+    let (mut agent, messages) = recording_agent();
+
+    agent.set_clock(TickingClock::default());
+
+    eval_script(
+        &mut agent,
+        "console.time(); console.time('t'); console.time('t'); console.timeEnd('t'); console.timeEnd(); console.timeEnd();",
+    )
+    .unwrap();
+
+    assert_eq!(
+        messages.take(),
+        vec![
+            (
+                LogLevel::Warn,
+                String::from("Warning: Label 't' already exists for console.time()")
+            ),
+            (LogLevel::Info, String::from("t: 1.5ms")),
+            (LogLevel::Info, String::from("default: 4.5ms")),
+            (
+                LogLevel::Warn,
+                String::from("Warning: No such label 'default' for console.timeEnd()")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn the_sink_is_shared_by_every_realm() {
+    let (mut agent, messages) = recording_agent();
+    let realm = agent.create_realm();
+
+    eval_script(&mut agent, "console.log('agent');").unwrap();
+    eval_script_in_realm(&mut agent, &realm, "console.log('realm');").unwrap();
+
+    assert_eq!(
+        messages.take(),
+        vec![
+            (LogLevel::Log, String::from("agent")),
+            (LogLevel::Log, String::from("realm")),
+        ]
+    );
+}
+
+#[test]
+fn writer_sinks_write_a_line_per_message() {
+    let mut sink = WriterSink(Vec::new());
+
+    sink.write(LogLevel::Log, "one");
+    sink.write(LogLevel::Error, "two");
+
+    assert_eq!(String::from_utf8(sink.0).unwrap(), "one\ntwo\n");
+}
+
+#[test]
+fn console_is_a_non_enumerable_global() {
+    let (mut agent, _) = recording_agent();
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "Object.getOwnPropertyDescriptor(globalThis, 'console').enumerable"
+        )
+        .unwrap(),
+        JSValue::from(false)
+    );
+}
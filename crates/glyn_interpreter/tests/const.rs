@@ -0,0 +1,90 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+
+mod common;
+
+#[test]
+fn const_declarations() {
+    assert_script_eq!("const x = 1; x", JSValue::from(1.0));
+    assert_script_eq!("const x = 1; { const x = 2; } x", JSValue::from(1.0));
+    assert_script_eq!("const o = {}; o.a = 1; o.a", JSValue::from(1.0));
+}
+
+#[test]
+fn assignment_to_const_throws_when_it_runs() {
+    let mut agent = JSAgent::default();
+
+    assert_eq!(
+        eval_script(&mut agent, "const c = 1; function f() { c = 2 } 1"),
+        Ok(JSValue::from(1.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "f()").map_err(|error| error.to_string()),
+        Err(String::from(
+            "TypeError: Assignment to constant variable 'c'\n    at f (1:29)\n    at 1:1"
+        ))
+    );
+    assert_eq!(eval_script(&mut agent, "c"), Ok(JSValue::from(1.0)));
+
+    assert_script_throws!(
+        "const x = 1; x = 2;",
+        "TypeError: Assignment to constant variable"
+    );
+    assert_script_throws!(
+        "const x = 1; { x = 2; }",
+        "TypeError: Assignment to constant variable"
+    );
+    assert_script_eq!(
+        "const c = 1; let error; try { c = 2; } catch (e) { error = e; } \"\" + error",
+        JSValue::from("TypeError: Assignment to constant variable 'c'")
+    );
+    assert_script_eq!("const x = 1; try {} catch { x = 2; } x", JSValue::from(1.0));
+    assert_script_throws!(
+        "const x = 1; try { throw 0; } catch { x = 2; }",
+        "TypeError: Assignment to constant variable"
+    );
+    assert_script_eq!("const x = 1; l: { break l; x = 2; } x", JSValue::from(1.0));
+    assert_script_throws!(
+        "const x = 1; let o = { get y() { x = 2; } }; o.y",
+        "TypeError: Assignment to constant variable"
+    );
+    assert_script_eq!("function f() { const x = 1; x = 2; } 1", JSValue::from(1.0));
+}
+
+#[test]
+fn assignment_to_const_before_its_declaration_is_in_the_temporal_dead_zone() {
+    assert_script_throws!(
+        "x = 2; const x = 1;",
+        "ReferenceError: Cannot access 'x' before initialization"
+    );
+    assert_script_throws!(
+        "let a = 1; { a = 2; const a = 3; }",
+        "ReferenceError: Cannot access 'a' before initialization"
+    );
+}
+
+#[test]
+fn assignment_to_shadowed_const() {
+    assert_script_eq!("const x = 1; { let x = 2; x = 3; x }", JSValue::from(3.0));
+    assert_script_eq!(
+        "const x = 1; function f(x) { x = 2; return x } f(0)",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!("let x = 1; { const x = 2; } x = 3; x", JSValue::from(3.0));
+}
+
+#[test]
+fn assignment_to_const_throws_at_runtime() {
+    let mut agent = JSAgent::default();
+
+    assert_eq!(
+        eval_script(&mut agent, "const x = 1;"),
+        Ok(JSValue::Undefined)
+    );
+    assert_eq!(
+        eval_script(&mut agent, "x = 2").map_err(|error| error.to_string()),
+        Err(String::from(
+            "TypeError: Assignment to constant variable 'x'\n    at 1:1"
+        ))
+    );
+    assert_eq!(eval_script(&mut agent, "x"), Ok(JSValue::from(1.0)));
+}
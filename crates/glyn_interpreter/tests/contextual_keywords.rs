@@ -0,0 +1,175 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+/// Words which the lexer classifies as keywords but which are not reserved words, along with `print`, which is an ordinary identifier.
+const CONTEXTUAL_KEYWORDS: [&str; 18] = [
+    "as",
+    "async",
+    "await",
+    "from",
+    "get",
+    "implements",
+    "interface",
+    "let",
+    "of",
+    "package",
+    "print",
+    "private",
+    "protected",
+    "public",
+    "set",
+    "static",
+    "target",
+    "yield",
+];
+
+#[test]
+fn contextual_keywords_as_identifiers() {
+    for keyword in CONTEXTUAL_KEYWORDS {
+        assert_script_eq!(&format!("{keyword} = 1; {keyword}"), JSValue::from(1.0));
+        assert_script_eq!(
+            &format!("function {keyword}() {{ return 2; }} {keyword}()"),
+            JSValue::from(2.0)
+        );
+        assert_script_eq!(
+            &format!("function f({keyword}) {{ return {keyword}; }} f(3)"),
+            JSValue::from(3.0)
+        );
+        assert_script_eq!(
+            &format!("let o = {{}}; with (o) {{ o.{keyword} = 5; {keyword} }}"),
+            JSValue::from(5.0)
+        );
+
+        // NOTE: A for-of statement's LeftHandSideExpression may not start with `let` or `async of`.
+        if keyword != "let" && keyword != "async" {
+            assert_script_eq!(
+                &format!("let s = 0; for ({keyword} of [4]) {{ s = s + {keyword}; }} s"),
+                JSValue::from(4.0)
+            );
+        }
+
+        if keyword != "let" {
+            assert_script_eq!(&format!("let {keyword} = 6; {keyword}"), JSValue::from(6.0));
+            assert_script_eq!(
+                &format!("let s = 0; for (const {keyword} of [7]) {{ s = s + {keyword}; }} s"),
+                JSValue::from(7.0)
+            );
+        }
+    }
+}
+
+#[test]
+fn contextual_keywords_as_property_names() {
+    for keyword in CONTEXTUAL_KEYWORDS {
+        assert_script_eq!(
+            &format!("let o = {{ {keyword}: 1 }}; o.{keyword}"),
+            JSValue::from(1.0)
+        );
+        assert_script_eq!(
+            &format!("let o = {{}}; o.{keyword} = 2; o['{keyword}']"),
+            JSValue::from(2.0)
+        );
+        assert_script_eq!(
+            &format!("'use strict'; let o = {{ {keyword}: 3 }}; o.{keyword}"),
+            JSValue::from(3.0)
+        );
+    }
+
+    // NOTE: Reserved words are also valid property names.
+    assert_script_eq!(
+        "let o = { if: 1, class: 2, new: 3 }; o.if + o.class + o.new",
+        JSValue::from(6.0)
+    );
+}
+
+#[test]
+fn contextual_keywords_as_method_names() {
+    for keyword in CONTEXTUAL_KEYWORDS {
+        assert_script_eq!(
+            &format!(
+                "let o = {{ {keyword}: function () {{ return this === o; }} }}; o.{keyword}()"
+            ),
+            JSValue::from(true)
+        );
+        assert_script_eq!(
+            &format!("let o = {{ {keyword}: function () {{ return 1; }} }}; o['{keyword}']()"),
+            JSValue::from(1.0)
+        );
+    }
+}
+
+#[test]
+fn contextual_keywords_in_their_contexts() {
+    assert_script_eq!(
+        "let of = [1, 2]; let s = 0; for (let x of of) { s = s + x; } s",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let s = 0; for (let of of [1, 2]) { s = s + of; } s",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "function* g() { let o = { yield: 1 }; return o.yield; } g().next().value",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "async function f() { let o = { await: 1 }; return o.await; } 1",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!("for (let of [1]) {}", "SyntaxError: Unexpected token");
+    assert_script_throws!("for (async of [1]) {}", "SyntaxError: Unexpected token");
+    assert_script_eq!(
+        "let async = { a: 1 }; let s = ''; for (async in async) { s = s + async; } s",
+        JSValue::from(String::from("a"))
+    );
+    assert_script_throws!(
+        "let let = 1",
+        "SyntaxError: let is disallowed as a lexically bound name"
+    );
+    assert_script_throws!(
+        "const let = 1",
+        "SyntaxError: let is disallowed as a lexically bound name"
+    );
+    assert_script_throws!(
+        "for (let let of [1]) {}",
+        "SyntaxError: let is disallowed as a lexically bound name"
+    );
+}
+
+#[test]
+fn strict_mode_reserved_words() {
+    for keyword in [
+        "implements",
+        "interface",
+        "let",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "static",
+        "yield",
+    ] {
+        assert_script_throws!(
+            &format!("'use strict'; {keyword} = 1"),
+            "SyntaxError: Unexpected strict mode reserved word"
+        );
+        assert_script_throws!(
+            &format!("'use strict'; function f({keyword}) {{}}"),
+            "SyntaxError: Unexpected strict mode reserved word"
+        );
+        assert_script_throws!(
+            &format!("function f() {{ 'use strict'; return {keyword}; }}"),
+            "SyntaxError: Unexpected strict mode reserved word"
+        );
+    }
+
+    for keyword in [
+        "as", "async", "await", "from", "get", "of", "print", "set", "target",
+    ] {
+        assert_script_eq!(
+            &format!("'use strict'; let {keyword} = 1; {keyword}"),
+            JSValue::from(1.0)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+// Generates the expression corpus which is evaluated by `tests/expression_corpus.rs`, with the
+// expected values computed by a reference engine:
+//
+//     node tests/corpus/generate_expressions.js > tests/corpus/expressions.txt
+//
+// The expressions are built from a seeded pseudo-random generator, so the output is stable. Only
+// operators the interpreter supports are used; extend OPERATORS as further ones are integrated.
+
+const SEED = 0x676c796e;
+const COUNT = 500;
+
+// Binary operators grouped by precedence, from loosest to tightest.
+const OPERATORS = [
+  ["|"],
+  ["^"],
+  ["&"],
+  ["==", "!=", "===", "!=="],
+  ["<", ">", "<=", ">="],
+  ["<<", ">>", ">>>"],
+  ["+", "-"],
+  ["*", "/", "%"],
+  ["**"],
+];
+
+const UNARY_OPERATORS = ["-", "+"];
+
+const OPERANDS = [
+  "0", "1", "2", "3", "4", "5", "7", "8", "10", "16", "31", "32", "100", "255", "1024",
+  "'1'", "'2'", "'10'", "'a'", "'b'", "''",
+];
+
+let state = SEED;
+
+function random(n) {
+  // xorshift32
+  state ^= state << 13;
+  state ^= state >>> 17;
+  state ^= state << 5;
+  state >>>= 0;
+
+  return state % n;
+}
+
+function pick(list) {
+  return list[random(list.length)];
+}
+
+function operand() {
+  const value = pick(OPERANDS);
+
+  return random(6) === 0 ? `${pick(UNARY_OPERATORS)}${value}` : value;
+}
+
+function expression(depth) {
+  if (depth === 0 || random(4) === 0) {
+    return operand();
+  }
+
+  const left = expression(depth - 1);
+  const operator = pick(pick(OPERATORS));
+  const right = expression(depth - 1);
+
+  const source = `${left} ${operator} ${right}`;
+
+  return depth > 1 && random(5) === 0 ? `(${source})` : source;
+}
+
+function format(value) {
+  switch (typeof value) {
+    case "number":
+      return Object.is(value, -0) ? "-0" : String(value);
+    case "string":
+      return JSON.stringify(value);
+    case "boolean":
+      return String(value);
+    default:
+      throw new Error(`Unexpected value ${value}`);
+  }
+}
+
+const seen = new Set();
+
+console.log("# Generated by generate_expressions.js, do not edit by hand.");
+console.log("# Each line is an expression and its expected value, separated by ' => '.");
+
+let count = 0;
+
+while (count < COUNT) {
+  const source = expression(1 + random(3));
+
+  if (seen.has(source)) {
+    continue;
+  }
+
+  seen.add(source);
+
+  let value;
+
+  try {
+    value = (0, eval)(source);
+  } catch (error) {
+    // A unary expression cannot be the base of an exponentiation, which is an early error.
+    if (error instanceof SyntaxError) {
+      continue;
+    }
+
+    throw error;
+  }
+
+  console.log(`${source} => ${format(value)}`);
+
+  count += 1;
+}
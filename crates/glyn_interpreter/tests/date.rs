@@ -0,0 +1,306 @@
+use glyn_interpreter::{eval_script, FixedClock, JSAgent, JSValue};
+
+/// 2024-03-15T12:34:56.789Z, a Friday.
+const NOW: f64 = 1710506096789.0;
+
+const HOUR: f64 = 60.0 * 60.0 * 1000.0;
+
+/// Evaluates the script with the clock stopped at `NOW`, in a time zone the offset of which from UTC is given in hours.
+fn eval_at(source: &str, offset: f64) -> Result<JSValue, String> {
+    let mut agent = JSAgent::default();
+
+    agent.set_clock(FixedClock {
+        time: NOW,
+        offset: offset * HOUR,
+    });
+
+    eval_script(&mut agent, source).map_err(|error| error.to_string())
+}
+
+fn eval(source: &str) -> Result<JSValue, String> {
+    eval_at(source, 0.0)
+}
+
+fn assert_nan(result: Result<JSValue, String>) {
+    assert!(
+        matches!(result, Ok(JSValue::Number(ref number)) if number.0.is_nan()),
+        "Expected NaN, got {result:?}"
+    );
+}
+
+#[test]
+fn current_time() {
+    assert_eq!(eval("Date.now();"), Ok(JSValue::from(NOW)));
+    assert_eq!(eval("new Date().getTime();"), Ok(JSValue::from(NOW)));
+    assert_eq!(
+        eval("Date();"),
+        Ok(JSValue::from("Fri Mar 15 2024 12:34:56 GMT+0000"))
+    );
+    assert_eq!(eval("typeof Date(2000, 1);"), Ok(JSValue::from("string")));
+}
+
+#[test]
+fn constructor_arguments() {
+    assert_eq!(eval("new Date(0).getTime();"), Ok(JSValue::from(0.0)));
+    assert_eq!(
+        eval("new Date(2000, 0).getTime();"),
+        Ok(JSValue::from(946684800000.0))
+    );
+    assert_eq!(
+        eval("new Date(99, 11, 31, 23, 59, 59, 999).toISOString();"),
+        Ok(JSValue::from("1999-12-31T23:59:59.999Z"))
+    );
+    assert_eq!(
+        eval("new Date(2024, 13, 1).toISOString();"),
+        Ok(JSValue::from("2025-02-01T00:00:00.000Z"))
+    );
+    assert_eq!(
+        eval("let d = new Date(5); new Date(d).getTime();"),
+        Ok(JSValue::from(5.0))
+    );
+    assert_eq!(
+        eval("new Date('2000-01-01').getTime();"),
+        Ok(JSValue::from(946684800000.0))
+    );
+    assert_eq!(
+        eval("new Date(0) instanceof Date;"),
+        Ok(JSValue::Bool(true))
+    );
+    assert_eq!(eval("Date.length;"), Ok(JSValue::from(7.0)));
+    assert_eq!(
+        eval("Date.prototype.constructor === Date;"),
+        Ok(JSValue::Bool(true))
+    );
+}
+
+#[test]
+fn getters() {
+    assert_eq!(
+        eval(
+            "let d = new Date(); d.getFullYear() + ',' + d.getMonth() + ',' + d.getDate() + ',' + d.getDay() + ',' + d.getHours() + ',' + d.getMinutes() + ',' + d.getSeconds() + ',' + d.getMilliseconds();"
+        ),
+        Ok(JSValue::from("2024,2,15,5,12,34,56,789"))
+    );
+    assert_eq!(
+        eval(
+            "let d = new Date(); d.getUTCFullYear() + ',' + d.getUTCMonth() + ',' + d.getUTCDate() + ',' + d.getUTCDay() + ',' + d.getUTCHours() + ',' + d.getUTCMinutes() + ',' + d.getUTCSeconds() + ',' + d.getUTCMilliseconds();"
+        ),
+        Ok(JSValue::from("2024,2,15,5,12,34,56,789"))
+    );
+    assert_eq!(
+        eval("new Date(-1).getUTCFullYear();"),
+        Ok(JSValue::from(1969.0))
+    );
+    assert_eq!(
+        eval("new Date(Date.UTC(2000, 1, 29)).getUTCDate();"),
+        Ok(JSValue::from(29.0))
+    );
+    assert_nan(eval("new Date(0 / 0).getDate();"));
+}
+
+#[test]
+fn setters() {
+    assert_eq!(
+        eval("let d = new Date(0); d.setUTCHours(25, 30); d.toISOString();"),
+        Ok(JSValue::from("1970-01-02T01:30:00.000Z"))
+    );
+    assert_eq!(
+        eval("let d = new Date(0); d.setUTCMinutes(1, 2, 3); d.toISOString();"),
+        Ok(JSValue::from("1970-01-01T00:01:02.003Z"))
+    );
+    assert_eq!(
+        eval("let d = new Date(0); d.setMilliseconds(-1); d.toISOString();"),
+        Ok(JSValue::from("1969-12-31T23:59:59.999Z"))
+    );
+    assert_eq!(
+        eval("let d = new Date(2024, 0, 31); d.setMonth(1); d.toISOString();"),
+        Ok(JSValue::from("2024-03-02T00:00:00.000Z"))
+    );
+    assert_eq!(
+        eval("let d = new Date(2024, 0, 31); d.setDate(0); d.toISOString();"),
+        Ok(JSValue::from("2023-12-31T00:00:00.000Z"))
+    );
+    assert_eq!(
+        eval("let d = new Date(0 / 0); d.setFullYear(2000, 5); d.toISOString();"),
+        Ok(JSValue::from("2000-06-01T00:00:00.000Z"))
+    );
+    assert_nan(eval("let d = new Date(0 / 0); d.setHours(1);"));
+    assert_nan(eval(
+        "let d = new Date(0); d.setTime(8640000000000000 + 1);",
+    ));
+    assert_eq!(
+        eval("let d = new Date(0); d.setTime('10'); d.valueOf();"),
+        Ok(JSValue::from(10.0))
+    );
+}
+
+#[test]
+fn string_representations() {
+    assert_eq!(
+        eval("new Date().toString();"),
+        Ok(JSValue::from("Fri Mar 15 2024 12:34:56 GMT+0000"))
+    );
+    assert_eq!(
+        eval("new Date().toDateString();"),
+        Ok(JSValue::from("Fri Mar 15 2024"))
+    );
+    assert_eq!(
+        eval("new Date().toTimeString();"),
+        Ok(JSValue::from("12:34:56 GMT+0000"))
+    );
+    assert_eq!(
+        eval("new Date().toUTCString();"),
+        Ok(JSValue::from("Fri, 15 Mar 2024 12:34:56 GMT"))
+    );
+    assert_eq!(
+        eval("new Date().toISOString();"),
+        Ok(JSValue::from("2024-03-15T12:34:56.789Z"))
+    );
+    assert_eq!(
+        eval("new Date().toJSON();"),
+        Ok(JSValue::from("2024-03-15T12:34:56.789Z"))
+    );
+    assert_eq!(eval("new Date(0 / 0).toJSON();"), Ok(JSValue::Null));
+    assert_eq!(
+        eval("new Date(0 / 0).toString();"),
+        Ok(JSValue::from("Invalid Date"))
+    );
+    assert_eq!(
+        eval("new Date(-62198755200000).toISOString();"),
+        Ok(JSValue::from("-000001-01-01T00:00:00.000Z"))
+    );
+    assert_eq!(
+        eval("new Date(8640000000000000).toISOString();"),
+        Ok(JSValue::from("+275760-09-13T00:00:00.000Z"))
+    );
+    assert_eq!(
+        eval("new Date(-62198755200000).toUTCString();"),
+        Ok(JSValue::from("Fri, 01 Jan -0001 00:00:00 GMT"))
+    );
+}
+
+#[test]
+fn invalid_dates() {
+    assert_nan(eval("new Date(8640000000000000 + 1).getTime();"));
+    assert_nan(eval("new Date('not a date').getTime();"));
+    assert_nan(eval("new Date(2000, 0 / 0).getTime();"));
+    assert!(eval("new Date(0 / 0).toISOString();")
+        .unwrap_err()
+        .starts_with("RangeError: Invalid time value"));
+    assert!(eval("let o = { f: Date.prototype.getTime }; o.f();")
+        .unwrap_err()
+        .starts_with("TypeError: Date.prototype.getTime called on an incompatible receiver"));
+    assert!(eval("Date.prototype.getTime();")
+        .unwrap_err()
+        .starts_with("TypeError"));
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        eval("Date.parse('2024-03-15T12:34:56.789Z');"),
+        Ok(JSValue::from(NOW))
+    );
+    assert_eq!(
+        eval("Date.parse('2024-03-15T14:34:56.789+02:00');"),
+        Ok(JSValue::from(NOW))
+    );
+    assert_eq!(eval("Date.parse('1970');"), Ok(JSValue::from(0.0)));
+    assert_eq!(eval("Date.parse('1970-01');"), Ok(JSValue::from(0.0)));
+    assert_eq!(
+        eval("Date.parse('+002000-01-01T00:00Z');"),
+        Ok(JSValue::from(946684800000.0))
+    );
+    assert_nan(eval("Date.parse('-000000-01-01');"));
+    assert_nan(eval("Date.parse('2000-13-01');"));
+    assert_nan(eval("Date.parse('2000-02-30');"));
+    assert_nan(eval("Date.parse('');"));
+}
+
+#[test]
+fn parse_round_trips() {
+    for method in ["toString", "toUTCString", "toISOString"] {
+        assert_eq!(
+            eval(&format!(
+                "let d = new Date(); d.setMilliseconds(0); Date.parse(d.{method}()) === d.getTime();"
+            )),
+            Ok(JSValue::Bool(true)),
+            "{method}"
+        );
+        assert_eq!(
+            eval_at(
+                &format!(
+                    "let d = new Date(); d.setMilliseconds(0); Date.parse(d.{method}()) === d.getTime();"
+                ),
+                -5.5
+            ),
+            Ok(JSValue::Bool(true)),
+            "{method}"
+        );
+    }
+}
+
+#[test]
+fn utc() {
+    assert_eq!(eval("Date.UTC(1970);"), Ok(JSValue::from(0.0)));
+    assert_eq!(
+        eval("Date.UTC(2024, 2, 15, 12, 34, 56, 789);"),
+        Ok(JSValue::from(NOW))
+    );
+    assert_eq!(eval("Date.UTC(0);"), eval("Date.UTC(1900);"));
+    assert_nan(eval("Date.UTC();"));
+    assert_nan(eval("Date.UTC(275761);"));
+}
+
+#[test]
+fn local_time_zone() {
+    assert_eq!(
+        eval_at("new Date().getHours();", 2.0),
+        Ok(JSValue::from(14.0))
+    );
+    assert_eq!(
+        eval_at("new Date().getTimezoneOffset();", -5.5),
+        Ok(JSValue::from(330.0))
+    );
+    assert_eq!(
+        eval_at("new Date().toString();", -5.5),
+        Ok(JSValue::from("Fri Mar 15 2024 07:04:56 GMT-0530"))
+    );
+    assert_eq!(
+        eval_at("new Date(2024, 0).toISOString();", 1.0),
+        Ok(JSValue::from("2023-12-31T23:00:00.000Z"))
+    );
+    assert_eq!(
+        eval_at("Date.parse('2024-01-01T00:00');", 1.0),
+        Ok(JSValue::from(1704063600000.0))
+    );
+    assert_eq!(
+        eval_at("Date.parse('2024-01-01');", 1.0),
+        Ok(JSValue::from(1704067200000.0))
+    );
+    assert_eq!(
+        eval_at(
+            "let d = new Date(2024, 0, 1); d.setHours(0, 0, 0, 0); d.getDate();",
+            1.0
+        ),
+        Ok(JSValue::from(1.0))
+    );
+}
+
+#[test]
+fn to_primitive() {
+    assert_eq!(
+        eval("new Date(2000, 0, 2) - new Date(2000, 0, 1);"),
+        Ok(JSValue::from(86400000.0))
+    );
+    assert_eq!(eval("+new Date(7);"), Ok(JSValue::from(7.0)));
+    assert_eq!(
+        eval("new Date(0) + 1;"),
+        Ok(JSValue::from("Thu Jan 01 1970 00:00:00 GMT+00001"))
+    );
+    assert_eq!(eval("new Date(0) < new Date(1);"), Ok(JSValue::Bool(true)));
+    assert_eq!(
+        eval("new Date(0) == new Date(0).toString();"),
+        Ok(JSValue::Bool(true))
+    );
+}
@@ -0,0 +1,151 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glyn_interpreter::{eval_script, DebugContext, Debugger, JSAgent, JSValue};
+
+/// Records each callback as a line, like `statement 2:1`.
+#[derive(Default)]
+struct Recorder {
+    events: Rc<RefCell<Vec<String>>>,
+}
+
+impl Recorder {
+    fn record(&self, event: &str, context: &DebugContext) {
+        let position = context
+            .position()
+            .map_or(String::from("?"), |position| position.to_string());
+
+        self.events.borrow_mut().push(format!("{event} {position}"));
+    }
+}
+
+impl Debugger for Recorder {
+    fn on_statement(&mut self, context: &DebugContext) {
+        self.record("statement", context);
+    }
+
+    fn on_breakpoint(&mut self, context: &DebugContext) {
+        let bindings = context
+            .bindings()
+            .into_iter()
+            .map(|(name, value)| format!("{name}={value:?}"))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.record(&format!("breakpoint [{bindings}]"), context);
+    }
+
+    fn on_call(&mut self, function_name: &str, args: &[JSValue], context: &DebugContext) {
+        self.record(&format!("call {function_name} {args:?}"), context);
+    }
+
+    fn on_return(&mut self, function_name: &str, value: &JSValue, context: &DebugContext) {
+        self.record(&format!("return {function_name} {value:?}"), context);
+    }
+
+    fn on_exception(&mut self, value: &JSValue, context: &DebugContext) {
+        self.record(&format!("exception {value:?}"), context);
+    }
+}
+
+fn debug(source: &str, breakpoints: &[usize]) -> Vec<String> {
+    let mut agent = JSAgent::default();
+    let recorder = Recorder::default();
+    let events = recorder.events.clone();
+
+    agent.set_debugger(recorder);
+
+    for line in breakpoints {
+        agent.set_breakpoint(*line);
+    }
+
+    let _ = eval_script(&mut agent, source);
+
+    events.take()
+}
+
+#[test]
+fn statements_are_reported_before_they_are_evaluated() {
+    assert_eq!(
+        debug("let a = 1;\nlet b = 2;\na + b", &[]),
+        vec!["statement 1:1", "statement 2:1", "statement 3:1"]
+    );
+    assert_eq!(
+        debug("for (let x of [1, 2]) {\n  x;\n}", &[]),
+        vec![
+            "statement 1:1",
+            "statement 1:23",
+            "statement 2:3",
+            "statement 1:23",
+            "statement 2:3"
+        ]
+    );
+}
+
+#[test]
+fn breakpoints_report_the_bindings_in_scope() {
+    assert_eq!(
+        debug("let a = 1;\nlet b = 2;\na + b", &[2]),
+        vec![
+            "statement 1:1",
+            "breakpoint [a=Number(JSNumber(1.0))] 2:1",
+            "statement 2:1",
+            "statement 3:1"
+        ]
+    );
+    assert_eq!(
+        debug(
+            "let x = 1;\nlet z = 0;\nfunction f(y) {\n  let x = 2;\n  return x + y;\n}\nf(3);",
+            &[5]
+        )
+        .into_iter()
+        .filter(|event| event.starts_with("breakpoint"))
+        .collect::<Vec<_>>(),
+        vec!["breakpoint [x=Number(JSNumber(2.0)),y=Number(JSNumber(3.0)),z=Number(JSNumber(0.0))] 5:3"]
+    );
+
+    let mut agent = JSAgent::default();
+
+    agent.set_breakpoint(1);
+
+    assert!(agent.remove_breakpoint(1));
+    assert!(!agent.remove_breakpoint(1));
+}
+
+#[test]
+fn calls_and_returns_are_reported_at_the_call() {
+    assert_eq!(
+        debug("function f(x) { return x + 1; }\nf(1)", &[]),
+        vec![
+            "statement 2:1",
+            "call f [Number(JSNumber(1.0))] 2:1",
+            "statement 1:17",
+            "return f Number(JSNumber(2.0)) 2:1"
+        ]
+    );
+}
+
+#[test]
+fn exceptions_are_reported_where_they_are_thrown() {
+    assert_eq!(
+        debug("try {\n  throw 1;\n} catch (e) {}", &[]),
+        vec![
+            "statement 1:1",
+            "statement 2:3",
+            "exception Number(JSNumber(1.0)) 2:3"
+        ]
+    );
+    assert_eq!(
+        debug("function f() {\n  throw 2;\n}\nf();", &[])
+            .into_iter()
+            .filter(|event| event.starts_with("exception"))
+            .collect::<Vec<_>>(),
+        vec!["exception Number(JSNumber(2.0)) 2:3"]
+    );
+
+    let mut agent = JSAgent::default();
+
+    agent.set_debugger(Recorder::default());
+
+    assert!(agent.remove_debugger());
+    assert!(!agent.remove_debugger());
+}
@@ -0,0 +1,94 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn array_binding_patterns_bind_the_values_of_iterables() {
+    assert_script_eq!("let [a, b] = [1, 2]; a + b", JSValue::from(3));
+    assert_script_eq!(
+        "const [a, , b = 5, ...c] = [1, 2, undefined, 4, 6]; '' + a + b + c.length + c[1]",
+        JSValue::from("1526")
+    );
+    assert_script_eq!(
+        "let [[a, b], [c = 3] = []] = [[1, 2]]; a + b + c",
+        JSValue::from(6)
+    );
+    assert_script_eq!(
+        "let [a = 10, b] = []; a + '' + b",
+        JSValue::from("10undefined")
+    );
+    assert_script_eq!(
+        "function f() { let [a, [b, ...c]] = [1, 'xyz']; { const [d] = c; return a + b + d + c.length; } } f()",
+        JSValue::from("1xy2")
+    );
+    assert_script_eq!(
+        "let s = ''; for (const [k, v] of [[1, 2], [3, 4]]) { s = s + k + v; } s",
+        JSValue::from("1234")
+    );
+    assert_script_throws!("let [a] = 1;", "TypeError: ");
+    assert_script_throws!(
+        "const [a] = [1]; a = 2;",
+        "TypeError: Assignment to constant variable"
+    );
+}
+
+#[test]
+fn array_binding_patterns_close_iterators_they_do_not_exhaust() {
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield 2; } finally { log = log + 'closed'; } } let [a] = g(); log + a",
+        JSValue::from("closed1")
+    );
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield 2; } finally { log = log + 'closed'; } } let [...a] = g(); log + a.length",
+        JSValue::from("closed2")
+    );
+    assert_script_eq!(
+        "let calls = 0; function* g() { yield 1; } const it = g(); it.return = function () { calls = calls + 1; return {}; }; let [a, b] = it; calls",
+        JSValue::from(0)
+    );
+    assert_script_eq!(
+        "let calls = 0; function* g() { yield 1; yield 2; } const it = g(); it.return = function () { calls = calls + 1; return {}; }; let [a] = it; calls",
+        JSValue::from(1)
+    );
+}
+
+#[test]
+fn array_binding_patterns_close_iterators_on_abrupt_completions() {
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield undefined; yield 3; } finally { log = log + 'closed'; } } try { let [a, b = (function () { throw 1; })()] = g(); } catch (e) { log = log + e; } log",
+        JSValue::from("closed1")
+    );
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield 2; } finally { log = log + 'closed'; } } try { let [[a]] = g(); } catch (e) { log = log + e; } log",
+        JSValue::from("closedTypeError: Value is not iterable: a number (1)")
+    );
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield [1]; yield [2]; } finally { log = log + 'closed'; } } for (const [x] of g()) { break; } log",
+        JSValue::from("closed")
+    );
+    assert_script_eq!(
+        "let log = ''; function* source() { try { yield undefined; yield 2; } finally { log = log + 'closed'; } } function* g() { let [a = yield 1] = source(); } const it = g(); it.next(); it.return(5); log",
+        JSValue::from("closed")
+    );
+}
+
+#[test]
+fn array_binding_patterns_have_early_errors() {
+    assert_script_throws!(
+        "let [a, a] = [1];",
+        "SyntaxError: Identifier has already been declared"
+    );
+    assert_script_throws!(
+        "for (const [a, a] of []) ;",
+        "SyntaxError: Identifier has already been declared"
+    );
+    assert_script_throws!(
+        "let [a];",
+        "SyntaxError: Missing initializer in destructuring declaration"
+    );
+    assert_script_throws!(
+        "let [let] = [1];",
+        "SyntaxError: let is disallowed as a lexically bound name"
+    );
+    assert_script_throws!("let [...a, b] = [];", "SyntaxError: ");
+}
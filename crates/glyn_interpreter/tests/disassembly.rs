@@ -0,0 +1,146 @@
+use glyn_interpreter::{
+    compile_script, disassemble, DecodedInstruction, OptimizationLevel, SourcePosition,
+};
+
+fn instruction(
+    offset: usize,
+    opcode: &str,
+    operands: &[u32],
+    source: Option<(std::ops::Range<usize>, usize, usize)>,
+) -> DecodedInstruction {
+    DecodedInstruction {
+        offset,
+        opcode: String::from(opcode),
+        operands: operands.to_vec(),
+        source_span: source.clone().map(|(span, _, _)| span),
+        source_position: source.map(|(_, line, column)| SourcePosition { line, column }),
+    }
+}
+
+#[test]
+fn instructions_are_decoded_with_their_source_spans() {
+    let script = compile_script("let x = 1;\nx", OptimizationLevel::None).unwrap();
+
+    assert_eq!(
+        script.instructions().collect::<Vec<_>>(),
+        vec![
+            instruction(0, "CreateMutableBinding", &[0], None),
+            instruction(2, "ResolveBinding", &[0], Some((0..10, 1, 1))),
+            instruction(4, "Const", &[0], Some((0..10, 1, 1))),
+            instruction(6, "InitializeReferencedBinding", &[], Some((0..10, 1, 1))),
+            instruction(7, "ResolveBinding", &[0], Some((11..12, 2, 1))),
+            instruction(9, "GetValue", &[], Some((11..12, 2, 1))),
+        ]
+    );
+}
+
+#[test]
+fn wide_operands_and_jump_targets_are_decoded_as_values() {
+    let declarations = (0..300)
+        .map(|index| format!("let v{index} = {index};"))
+        .collect::<String>();
+
+    let script = compile_script(&declarations, OptimizationLevel::None).unwrap();
+
+    let last = script
+        .instructions()
+        .filter(|instruction| instruction.opcode == "Const")
+        .last()
+        .unwrap();
+
+    assert_eq!(last.operands, vec![299]);
+    assert!(script
+        .instructions()
+        .all(|instruction| instruction.opcode != "Wide"));
+
+    let script =
+        compile_script("try { throw 1; } catch (e) { e; }", OptimizationLevel::None).unwrap();
+
+    let offsets = script
+        .instructions()
+        .map(|instruction| instruction.offset as u32)
+        .collect::<Vec<_>>();
+
+    let handler = script
+        .instructions()
+        .find(|instruction| instruction.opcode == "PushCatchHandler")
+        .unwrap();
+
+    assert!(offsets.contains(&handler.operands[0]));
+}
+
+#[test]
+fn bindings_of_blocks_are_resolved_to_their_depth_and_slot() {
+    let opcodes_of = |source: &str| {
+        compile_script(source, OptimizationLevel::None)
+            .unwrap()
+            .instructions()
+            .filter(|instruction| instruction.opcode.contains("Scoped"))
+            .map(|instruction| (instruction.opcode, instruction.operands))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        opcodes_of("{ let a = 1; const b = 2; { a = b; } }"),
+        vec![
+            (String::from("GetScopedLocal"), vec![1, 1]),
+            (String::from("SetScopedLocal"), vec![1, 0]),
+        ]
+    );
+    assert_eq!(
+        opcodes_of("for (const v of []) { v; }"),
+        vec![(String::from("GetScopedLocal"), vec![1, 0])]
+    );
+
+    // Bindings of the script, of an enclosing function, or which may be properties of the object of a with statement are resolved by name.
+    assert_eq!(opcodes_of("let a = 1; { a = a; }"), vec![]);
+    assert_eq!(opcodes_of("{ let a; (function () { a; }); }"), vec![]);
+    assert_eq!(opcodes_of("{ let a; with ({}) { a; } }"), vec![]);
+
+    let declarations = (0..300)
+        .map(|index| format!("let v{index};"))
+        .collect::<String>();
+
+    assert_eq!(
+        opcodes_of(&format!("{{ {declarations} v299; }}")),
+        vec![(String::from("GetScopedLocal"), vec![0, 299])]
+    );
+}
+
+#[test]
+fn functions_and_listings() {
+    let script =
+        compile_script("function f(a) { return a; } f(1)", OptimizationLevel::None).unwrap();
+
+    let functions = script.functions().collect::<Vec<_>>();
+
+    assert_eq!(functions.len(), 1);
+    assert_eq!(functions[0].0.as_deref(), Some("f"));
+    assert_eq!(
+        functions[0].1.instructions().next().unwrap().opcode,
+        "ResolveBinding"
+    );
+
+    let listing = disassemble(&script);
+
+    assert!(listing.contains("; 1:29\n0000  ResolveBinding 0\n"));
+    assert!(listing.contains("function f\n; 1:17\n0000  ResolveBinding 0\n"));
+    assert!(listing.contains("\n\nfunction f\n"));
+    assert!(listing.contains("identifiers: [\"a\"]"));
+}
+
+#[test]
+fn compiled_scripts_are_optimized_at_the_given_level() {
+    let source = "function f() { return 1; 2; }";
+
+    let count = |level| {
+        let script = compile_script(source, level).unwrap();
+        let (_, function) = script.functions().next().unwrap();
+
+        function.instructions().count()
+    };
+
+    assert_eq!(count(OptimizationLevel::None), 5);
+    assert_eq!(count(OptimizationLevel::Peephole), 2);
+    assert!(compile_script("let x = ;", OptimizationLevel::None).is_err());
+}
@@ -0,0 +1,69 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn type_errors_preview_the_offending_value() {
+    assert_script_throws!(
+        "let x = 1; x()",
+        "TypeError: Value is not a function: a number (1)"
+    );
+    assert_script_throws!(
+        "let o = { a: 'b' }; o.a()",
+        "TypeError: Value is not a function: a string (\"b\")"
+    );
+    assert_script_throws!(
+        "function f() {} new (f())",
+        "TypeError: Value is not a constructor: undefined"
+    );
+    assert_script_throws!(
+        "new (async function named() {})",
+        "TypeError: Value is not a constructor: a function (named)"
+    );
+    assert_script_throws!(
+        "for (let x of { a: 1 }) {}",
+        "TypeError: Value is not iterable: an object ({a})"
+    );
+    assert_script_throws!(
+        "1 instanceof null",
+        "TypeError: Right-hand side of 'instanceof' is not an object: null"
+    );
+    assert_script_throws!(
+        "'a' in 'abc'",
+        "TypeError: Cannot use 'in' operator to search for a key in a non-object: a string (\"abc\")"
+    );
+}
+
+#[test]
+fn builtin_argument_errors_name_the_builtin() {
+    assert_script_throws!(
+        "new Promise(true)",
+        "TypeError: Promise: argument 1 must be a function, but got a boolean (true)"
+    );
+    assert_script_throws!(
+        "new Promise()",
+        "TypeError: Promise: expected at least 1 argument, but got 0"
+    );
+}
+
+#[test]
+fn previews_are_truncated() {
+    assert_script_throws!(
+        "'a very long string that keeps going and going'()",
+        "TypeError: Value is not a function: a string (\"a very long string that …\")"
+    );
+    assert_script_throws!(
+        "let o = { a: 1, b: 2, c: 3, d: 4 }; o()",
+        "TypeError: Function cannot be called: an object ({a, b, c, …})"
+    );
+    assert_script_throws!(
+        "let f = function () {}; new [1, 'two', {}, f]",
+        "TypeError: Value is not a constructor: an array ([1, \"two\", {…}, …])"
+    );
+    assert_script_eq!(
+        "let message; try { [[], function () {}]() } catch (e) { message = e; } \"\" + message",
+        JSValue::from(String::from(
+            "TypeError: Function cannot be called: an array ([[…], function])"
+        ))
+    );
+}
@@ -0,0 +1,101 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+
+mod common;
+
+#[test]
+fn error_constructor() {
+    assert_script_eq!(
+        "let e = new Error('boom'); e.message",
+        JSValue::from("boom")
+    );
+    assert_script_eq!("let e = Error(); e.message", JSValue::from(""));
+    assert_script_eq!("let e = Error(1); e.message", JSValue::from("1"));
+    assert_script_eq!("Error('x') instanceof Error", JSValue::from(true));
+    assert_script_eq!(
+        "let e = Error('x', { cause: 2 }); e.cause",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "let e = Error('x', {}); Object.getOwnPropertyDescriptor(e, 'cause')",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "let e = Error('x'); Object.getOwnPropertyDescriptor(e, 'message').enumerable",
+        JSValue::from(false)
+    );
+    assert_script_eq!(
+        "let e = Error('x'); Object.getOwnPropertyDescriptor(e, 'stack').enumerable",
+        JSValue::from(false)
+    );
+}
+
+#[test]
+fn error_prototype_to_string() {
+    assert_script_eq!("'' + new Error('boom')", JSValue::from("Error: boom"));
+    assert_script_eq!("'' + new Error()", JSValue::from("Error"));
+    assert_script_eq!(
+        "let e = Error('boom'); e.name = 'Custom'; '' + e",
+        JSValue::from("Custom: boom")
+    );
+    assert_script_eq!(
+        "let e = Error('boom'); e.name = ''; '' + e",
+        JSValue::from("boom")
+    );
+    assert_script_eq!(
+        "let o = { message: 'm', toString: Error.prototype.toString }; '' + o",
+        JSValue::from("Error: m")
+    );
+    assert_script_throws!(
+        "let f = Error.prototype.toString; f()",
+        "TypeError: Error.prototype.toString called on a non-object"
+    );
+    assert_script_throws!("throw new Error('boom');", "Error: boom");
+}
+
+#[test]
+fn stack_traces_record_each_call_frame() {
+    assert_script_eq!(
+        "function inner() {\n  return new Error('boom');\n}\nfunction outer() { return inner(); }\nouter().stack",
+        JSValue::from("Error: boom\n    at inner (2:3)\n    at outer (4:20)\n    at 5:1")
+    );
+    assert_script_eq!(
+        "let e = Error('x'); e.stack",
+        JSValue::from("Error: x\n    at 1:1")
+    );
+    assert_script_eq!(
+        "let o = { message: 'm' };\nfunction f() { Error.captureStackTrace(o); }\nf();\no.stack",
+        JSValue::from("Error: m\n    at f (2:16)\n    at 3:1")
+    );
+    assert_script_throws!(
+        "Error.captureStackTrace(1)",
+        "TypeError: Error.captureStackTrace: argument 1 must be an object, but got a number (1)"
+    );
+}
+
+#[test]
+fn stack_trace_limit_is_configurable() {
+    let stack = |limit: Option<usize>| {
+        let mut agent = JSAgent::default();
+
+        if let Some(limit) = limit {
+            agent.set_stack_trace_limit(limit);
+        }
+
+        eval_script(
+            &mut agent,
+            "function f() { return Error(); }\nfunction g() { return f(); }\nfunction h() { return g(); }\nh().stack",
+        )
+    };
+
+    assert_eq!(
+        stack(None),
+        Ok(JSValue::from(
+            "Error\n    at f (1:16)\n    at g (2:16)\n    at h (3:16)\n    at 4:1"
+        ))
+    );
+    assert_eq!(
+        stack(Some(2)),
+        Ok(JSValue::from("Error\n    at f (1:16)\n    at g (2:16)"))
+    );
+    assert_eq!(stack(Some(0)), Ok(JSValue::from("Error")));
+}
@@ -0,0 +1,377 @@
+use glyn_interpreter::{
+    eval_script, script_to_bytecode_json, EvalError, JSAgent, JSValue, SourcePosition,
+    SyntaxDiagnostic,
+};
+
+fn eval(source: &str) -> Result<JSValue, EvalError> {
+    eval_script(&mut JSAgent::default(), source)
+}
+
+#[test]
+fn syntax_errors_carry_their_span() {
+    assert_eq!(
+        eval("let x = 1;\nx = ) 2;"),
+        Err(EvalError::SyntaxError(vec![SyntaxDiagnostic {
+            message: String::from("Unexpected token ')'"),
+            start: 15,
+            end: 16,
+        }]))
+    );
+    assert_eq!(
+        eval("1 +;"),
+        Err(EvalError::SyntaxError(vec![SyntaxDiagnostic {
+            message: String::from("Unexpected token ';'"),
+            start: 3,
+            end: 4,
+        }]))
+    );
+    assert_eq!(
+        script_to_bytecode_json("1 +;").map_err(|error| error.to_string()),
+        Err(String::from("SyntaxError: Unexpected token ';'"))
+    );
+}
+
+#[test]
+fn unsupported_syntax_is_a_syntax_error() {
+    let syntax_error = |message: &str, start, end| {
+        Err(EvalError::SyntaxError(vec![SyntaxDiagnostic {
+            message: String::from(message),
+            start,
+            end,
+        }]))
+    };
+
+    assert_eq!(
+        eval("let {a} = {a: 1};"),
+        syntax_error("Object binding patterns are not supported", 4, 5)
+    );
+    assert_eq!(
+        eval("1; super.x;"),
+        syntax_error("'super' keyword unexpected here", 3, 8)
+    );
+    assert_eq!(
+        eval("super[0];"),
+        syntax_error("'super' keyword unexpected here", 0, 5)
+    );
+    assert_eq!(
+        eval("super();"),
+        syntax_error("'super' keyword unexpected here", 0, 5)
+    );
+    assert_eq!(
+        eval("import.meta;"),
+        syntax_error("import.meta is not supported", 0, 6)
+    );
+    assert_eq!(
+        eval("import('./a.js');"),
+        syntax_error("import() is not supported", 0, 6)
+    );
+}
+
+#[test]
+fn unexpected_tokens_name_what_was_expected() {
+    let message = |source| eval(source).map_err(|error| error.to_string());
+
+    assert_eq!(
+        message("(1"),
+        Err(String::from(
+            "SyntaxError: Unexpected end of input, expected ')'"
+        ))
+    );
+    assert_eq!(
+        message("try {} catch (e {}"),
+        Err(String::from(
+            "SyntaxError: Unexpected token '{', expected ')'"
+        ))
+    );
+    assert_eq!(
+        message("(1 2)"),
+        Err(String::from("SyntaxError: Unexpected number, expected ')'"))
+    );
+}
+
+#[test]
+fn syntax_errors_render_the_source_they_were_found_in() {
+    let source = "let x = 1;\nx = ) 2;";
+
+    assert_eq!(
+        eval(source).unwrap_err().render(source),
+        "SyntaxError: Unexpected token ')'\n --> 2:5\n  |\n2 | x = ) 2;\n  |     ^"
+    );
+
+    let source = "let a = 1 @;\na = 'é' +";
+
+    assert_eq!(
+        eval(source).unwrap_err().render(source),
+        [
+            "SyntaxError: Unexpected character in the input string.",
+            " --> 1:11",
+            "  |",
+            "1 | let a = 1 @;",
+            "  |           ^",
+            "SyntaxError: Unexpected end of input",
+            " --> 2:10",
+            "  |",
+            "2 | a = 'é' +",
+            "  |          ^",
+        ]
+        .join("\n")
+    );
+    assert_eq!(
+        eval("throw 1;").unwrap_err().render("throw 1;"),
+        "1\n    at 1:1"
+    );
+}
+
+#[test]
+fn every_syntax_error_is_reported() {
+    let syntax_error = |message: &str, start, end| SyntaxDiagnostic {
+        message: String::from(message),
+        start,
+        end,
+    };
+
+    assert_eq!(
+        eval("let a = 1 @;\na = ; /*"),
+        Err(EvalError::SyntaxError(vec![
+            syntax_error("Unexpected character in the input string.", 10, 11),
+            syntax_error("Unexpected token ';'", 17, 18),
+            syntax_error(
+                "Unterminated multi-line comment in the input string.",
+                19,
+                21
+            ),
+        ]))
+    );
+    assert_eq!(
+        eval("1 ¬ + 2").map_err(|error| error.to_string()),
+        Err(String::from(
+            "SyntaxError: Unexpected character in the input string."
+        ))
+    );
+}
+
+#[test]
+fn parsing_carries_on_after_a_statement_with_a_syntax_error() {
+    let messages = |source| match eval(source) {
+        Err(EvalError::SyntaxError(diagnostics)) => diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect::<Vec<_>>(),
+        result => panic!("Expected a syntax error, got {result:?}"),
+    };
+
+    assert_eq!(
+        messages("let a = ; let b = ) 2; let c = 3 +"),
+        vec![
+            "Unexpected token ';'",
+            "Unexpected token ')'",
+            "Unexpected end of input",
+        ]
+    );
+    assert_eq!(
+        messages("function f() { return ( }\nlet g = 1;\ng = ]\ng = 2;"),
+        vec!["Unexpected token '}'", "Unexpected token ']'"]
+    );
+    assert_eq!(
+        messages("{ let a = ; }\nlet a = 1;"),
+        vec!["Unexpected token ';'"]
+    );
+    assert_eq!(
+        messages("try { 1 + } catch (e) {}"),
+        vec!["Unexpected token '}'"]
+    );
+    assert_eq!(
+        messages("for (const x of [1]) { let y = ) }"),
+        vec!["Unexpected token ')'"]
+    );
+    assert_eq!(
+        messages("function f() { let a = ) 1; { 1 + } let b = ] }\nlet c = ;"),
+        vec![
+            "Unexpected token ')'",
+            "Unexpected token '}'",
+            "Unexpected token ']'",
+            "Unexpected token ';'",
+        ]
+    );
+}
+
+#[test]
+fn uncaught_exceptions_carry_the_thrown_value() {
+    assert_eq!(
+        eval("throw 1 + 1;"),
+        Err(EvalError::UncaughtException {
+            value: JSValue::from(2.0),
+            message: String::from("2"),
+            position: Some(SourcePosition { line: 1, column: 1 }),
+            stack: String::from("\n    at 1:1"),
+        })
+    );
+    assert!(matches!(
+        eval("throw { toString: function () { return 'custom'; } };"),
+        Err(EvalError::UncaughtException { value, message, .. })
+            if value.is_object() && message == "custom"
+    ));
+    assert_eq!(
+        eval("undefined.x").map_err(|error| error.to_string()),
+        Err(String::from(
+            "TypeError: Cannot convert undefined to object\n    at 1:1"
+        ))
+    );
+}
+
+#[test]
+fn uncaught_engine_errors_are_error_instances() {
+    let mut agent = JSAgent::default();
+
+    let Err(EvalError::UncaughtException { value, message, .. }) =
+        eval_script(&mut agent, "function f() {\n  null.x;\n}\nf();")
+    else {
+        panic!("Expected an uncaught exception");
+    };
+
+    assert!(value.is_object());
+    assert_eq!(message, "TypeError: Cannot convert null to object");
+
+    agent.register_function("thrown", 0, move |_, _| Ok(value.clone()));
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let e = thrown(); [e instanceof TypeError, e.name, e.message, e.stack].join('|');"
+        ),
+        Ok(JSValue::from(
+            "true|TypeError|Cannot convert null to object|TypeError: Cannot convert null to object\n    at f (2:3)\n    at 4:1"
+        ))
+    );
+}
+
+#[test]
+fn uncaught_exceptions_carry_the_stack_trace_they_were_thrown_with() {
+    let stack = |source| match eval(source) {
+        Err(EvalError::UncaughtException { stack, .. }) => stack,
+        result => panic!("Expected an uncaught exception, got {result:?}"),
+    };
+
+    assert_eq!(
+        stack("function g() {\n  throw 1;\n}\nfunction f() {\n  g();\n}\nf();"),
+        "\n    at g (2:3)\n    at f (5:3)\n    at 7:1"
+    );
+    assert_eq!(
+        stack("function f() {\n  null.x;\n}\ntry {\n  f();\n} catch (e) {\n  throw e;\n}"),
+        "\n    at 7:3"
+    );
+    assert_eq!(
+        eval("function f() {\n  throw new Error('x');\n}\nf();").map_err(|error| error.to_string()),
+        Err(String::from("Error: x\n    at f (2:3)\n    at 4:1"))
+    );
+}
+
+#[test]
+fn errors_do_not_poison_the_agent() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "let a = ;").is_err());
+    assert!(eval_script(&mut agent, "throw 'x';").is_err());
+    assert_eq!(
+        eval_script(&mut agent, "let a = 1; a"),
+        Ok(JSValue::from(1.0))
+    );
+}
+
+#[test]
+fn uncaught_exceptions_carry_the_position_they_were_thrown_from() {
+    let position = |source| match eval(source) {
+        Err(EvalError::UncaughtException { position, .. }) => position,
+        result => panic!("Expected an uncaught exception, got {result:?}"),
+    };
+
+    assert_eq!(
+        position("let a = 1;\nfunction f() {\n  throw a;\n}\n\nf();"),
+        Some(SourcePosition { line: 3, column: 3 })
+    );
+    assert_eq!(
+        position("try {\n  throw 1;\n} catch (e) {\n    throw e;\n}"),
+        Some(SourcePosition { line: 4, column: 5 })
+    );
+    assert_eq!(
+        position("try {\n  throw 1;\n} finally {\n  2;\n}"),
+        Some(SourcePosition { line: 2, column: 3 })
+    );
+    assert_eq!(
+        eval("1;\r\n  null.x;").map_err(|error| error.to_string()),
+        Err(String::from(
+            "TypeError: Cannot convert null to object\n    at 2:3"
+        ))
+    );
+}
+
+#[test]
+fn deeply_nested_code_is_a_syntax_error() {
+    let nested =
+        |open: &str, close: &str, depth| format!("{}1{}", open.repeat(depth), close.repeat(depth));
+
+    for (open, close) in [
+        ("(", ")"),
+        ("[", "]"),
+        ("{", "}"),
+        ("x = { a: ", " }"),
+        ("function f() { ", " }"),
+        ("`${", "}`"),
+        ("- ", ""),
+        ("a ** ", ""),
+    ] {
+        match eval(&nested(open, close, 1_000)) {
+            Err(EvalError::SyntaxError(diagnostics)) => {
+                assert_eq!(
+                    diagnostics[0].message, "Maximum nesting depth exceeded",
+                    "nesting {open:?}"
+                )
+            }
+            result => panic!("Expected a SyntaxError nesting {open:?}, got {result:?}"),
+        }
+    }
+
+    assert_eq!(eval(&nested("(", ")", 50)), Ok(JSValue::from(1.0)));
+    assert_eq!(eval(&nested("[", "][0]", 50)), Ok(JSValue::from(1.0)));
+}
+
+#[test]
+fn code_nested_within_its_limit_is_parsed_on_a_small_stack() {
+    let nested =
+        |open: &str, close: &str, depth| format!("{}1{}", open.repeat(depth), close.repeat(depth));
+
+    // NOTE: A spawned thread has a 2 MiB stack, which the parser outgrows well before the nesting limit in a debug build.
+    std::thread::spawn(move || {
+        assert_eq!(eval(&nested("1 + (", ")", 900)), Ok(JSValue::from(901.0)));
+        assert_eq!(
+            eval(&nested("({ a: ", " }).a", 400)),
+            Ok(JSValue::from(1.0))
+        );
+        assert_eq!(eval(&nested("[", "][0]", 900)), Ok(JSValue::from(1.0)));
+        assert_eq!(eval(&nested("{ ", " }", 900)), Ok(JSValue::from(1.0)));
+        assert_eq!(eval(&nested("1 ** ", "", 900)), Ok(JSValue::from(1.0)));
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn deeply_nested_binding_patterns_are_a_syntax_error() {
+    let pattern = |depth| format!("{}a{}", "[".repeat(depth), "]".repeat(depth));
+
+    match eval(&format!("let {} = 1;", pattern(20_000))) {
+        Err(EvalError::SyntaxError(diagnostics)) => {
+            assert_eq!(diagnostics[0].message, "Maximum nesting depth exceeded")
+        }
+        result => panic!("Expected a SyntaxError, got {result:?}"),
+    }
+
+    assert_eq!(
+        eval(&format!(
+            "let {} = {}; a",
+            pattern(20),
+            pattern(20).replace('a', "1")
+        )),
+        Ok(JSValue::from(1.0))
+    );
+}
@@ -0,0 +1,68 @@
+use glyn_interpreter::{eval_script, JSValue};
+
+mod common;
+
+#[test]
+fn promise_jobs_tasks_and_timers_run_in_order() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        setTimeout(function () {
+            log = log + "c";
+            Promise.resolve().then(function () { log = log + "d"; });
+        }, 2);
+        setTimeout(function () { log = log + "e"; }, 1);
+        Promise.resolve().then(function () { log = log + "a"; });
+        "#,
+    );
+
+    agent.enqueue_task(|agent| {
+        eval_script(agent, "log = log + \"b\";")?;
+
+        Ok(())
+    });
+
+    assert_eq!(agent.run_until_idle(), Ok(()));
+    assert_eq!(log!(&mut agent), JSValue::from("abecd"));
+    assert_eq!(agent.next_timer_due(), None);
+}
+
+#[test]
+fn tasks_can_be_enqueued_by_native_functions() {
+    let mut agent = agent_with_timers!("let log = \"\";");
+
+    agent.register_function("later", 0, |agent, _args| {
+        agent.enqueue_task(|agent| {
+            eval_script(agent, "log = log + \"b\";")?;
+
+            Ok(())
+        });
+
+        Ok(JSValue::Undefined)
+    });
+
+    eval_script(
+        &mut agent,
+        "setTimeout(function () { later(); log = log + \"a\"; }, 0);",
+    )
+    .unwrap();
+
+    assert_eq!(agent.run_until_idle(), Ok(()));
+    assert_eq!(log!(&mut agent), JSValue::from("ab"));
+}
+
+#[test]
+fn errors_stop_the_loop() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        setTimeout(function () { throw "boom"; }, 0);
+        setTimeout(function () { log = log + "a"; }, 0);
+        "#,
+    );
+
+    assert!(agent.run_until_idle().is_err());
+    assert_eq!(log!(&mut agent), JSValue::from(""));
+    assert_eq!(agent.run_until_idle(), Ok(()));
+    assert_eq!(log!(&mut agent), JSValue::from("a"));
+}
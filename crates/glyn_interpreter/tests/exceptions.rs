@@ -0,0 +1,186 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn throw_statement() {
+    assert_script_throws!("throw 'oops'", "oops");
+    assert_script_throws!("throw 1 + 1", "2");
+    assert_script_throws!("function f() { throw 'inner' } f(); 1", "inner");
+}
+
+#[test]
+fn catch_binding() {
+    assert_script_eq!("try { throw 1 } catch (e) { e + 1 }", JSValue::from(2.0));
+    assert_script_eq!(
+        "let o = { a: 1 }; try { throw o } catch (e) { e === o }",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "try { missing } catch (e) { \"\" + e }",
+        JSValue::from("ReferenceError: missing is not defined")
+    );
+    assert_script_eq!(
+        "function f() { throw 'inner' } try { f() } catch (e) { e }",
+        JSValue::from("inner")
+    );
+    assert_script_eq!(
+        "let e = 1; try { throw 2 } catch (e) { e = 3 } e",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!(
+        "try {} catch (e) { let e; }",
+        "SyntaxError: Identifier has already been declared"
+    );
+    assert_script_throws!("try { throw 1 } catch (e) {} e", "ReferenceError");
+}
+
+#[test]
+fn optional_catch_binding() {
+    assert_script_eq!("try { throw 1 } catch { 2 }", JSValue::from(2.0));
+    assert_script_eq!(
+        "let x = 1; try { x = 2 } catch { x = 3 } x",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "let x = 1; try { throw x } catch { x = x + 1 } x",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn missing_clauses() {
+    assert_script_throws!("try {}", "SyntaxError: Missing catch or finally after try");
+    assert_script_throws!(
+        "try {} 1",
+        "SyntaxError: Missing catch or finally after try"
+    );
+}
+
+#[test]
+fn completion_value() {
+    assert_script_eq!("try { 1 } catch {}", JSValue::from(1.0));
+    assert_script_eq!("1; try {} catch {}", JSValue::Undefined);
+    assert_script_eq!("1; try { throw 2 } catch {}", JSValue::Undefined);
+    assert_script_eq!("try { 1 } finally { 2 }", JSValue::from(1.0));
+    assert_script_eq!(
+        "try { throw 1 } catch { 2 } finally { 3 }",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn finally_runs_after_normal_and_throw_completions() {
+    assert_script_eq!(
+        "let s = ''; try { s = s + 'a' } finally { s = s + 'b' } s",
+        JSValue::from("ab")
+    );
+    assert_script_eq!(
+        "let s = ''; try { try { throw 'x' } finally { s = s + 'f' } } catch (e) { s = s + e } s",
+        JSValue::from("fx")
+    );
+    assert_script_eq!(
+        "let s = ''; try { throw 'x' } catch (e) { s = s + e } finally { s = s + 'f' } s",
+        JSValue::from("xf")
+    );
+}
+
+#[test]
+fn abrupt_catch_and_finally() {
+    // The throw completion of the finally clause replaces the one from the catch clause.
+    assert_script_throws!("try { throw 1 } catch { throw 2 } finally { throw 3 }", "3");
+    // A normal completion of the finally clause resumes the throw completion of the catch clause.
+    assert_script_throws!("try { throw 1 } catch { throw 2 } finally { 3 }", "2");
+    assert_script_eq!(
+        "let s = ''; try { try { throw 1 } catch (e) { throw e + 1 } finally { s = 'f' } } catch (e) { s + e }",
+        JSValue::from("f2")
+    );
+    assert_script_eq!(
+        "try { try { throw 1 } catch { throw 2 } finally { throw 3 } } catch (e) { e }",
+        JSValue::from(3.0)
+    );
+}
+
+#[test]
+fn return_through_finally() {
+    assert_script_eq!(
+        "function f() { try { return 1 } finally { 2 } } f()",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f() { try { return 1 } finally { return 2 } } f()",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "function f() { try { throw 1 } catch { return 'catch' } finally { return 'finally' } } f()",
+        JSValue::from("finally")
+    );
+    assert_script_eq!(
+        "let s = ''; function f() { try { try { return 'r' } finally { s = s + 'a' } } finally { s = s + 'b' } } f() + s",
+        JSValue::from("rab")
+    );
+    assert_script_eq!(
+        "function f() { try { return 1 } finally { throw 2 } } try { f() } catch (e) { e }",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "function f() { try { throw 1 } finally { return 2 } } f()",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn unwinds_environments_and_loops() {
+    assert_script_eq!(
+        "let x = 1; try { let x = 2; { let x = 3; throw x } } catch (e) { x + e }",
+        JSValue::from(4.0)
+    );
+    assert_script_eq!(
+        "let s = ''; for (let k in { a: 1, b: 2, c: 3 }) { try { if_missing } catch { s = s + k } } s",
+        JSValue::from("abc")
+    );
+    assert_script_eq!(
+        "let s = ''; try { for (let k in { a: 1, b: 2 }) { s = s + k; throw s } } catch (e) { e }",
+        JSValue::from("a")
+    );
+}
+
+#[test]
+fn engine_errors_are_native_error_instances() {
+    assert_script_eq!(
+        "try { missing } catch (e) { typeof e + ' ' + (e instanceof ReferenceError) + ' ' + (e instanceof Error) }",
+        JSValue::from("object true true")
+    );
+    assert_script_eq!(
+        "try { null.x } catch (e) { e.name + ': ' + e.message + ' ' + (e.constructor === TypeError) }",
+        JSValue::from("TypeError: Cannot convert null to object true")
+    );
+    assert_script_eq!(
+        "try { new Array(-1) } catch (e) { (e instanceof RangeError) + ' ' + (e instanceof TypeError) }",
+        JSValue::from("true false")
+    );
+    assert_script_eq!(
+        "try { missing } catch (e) { typeof e.stack }",
+        JSValue::from("string")
+    );
+}
+
+#[test]
+fn native_error_constructors() {
+    assert_script_eq!(
+        "let e = new SyntaxError('bad'); e.name + ': ' + e.message + ' ' + (e instanceof Error)",
+        JSValue::from("SyntaxError: bad true")
+    );
+    assert_script_eq!(
+        "let e = URIError('uri'); (e instanceof URIError) + ' ' + e",
+        JSValue::from("true URIError: uri")
+    );
+    assert_script_eq!(
+        "(EvalError.prototype instanceof Error) + ' ' + EvalError.length + ' ' + EvalError.prototype.message.length",
+        JSValue::from("true 1 0")
+    );
+    assert_script_eq!(
+        "new RangeError('r', { cause: 1 }).cause",
+        JSValue::from(1.0)
+    );
+}
@@ -0,0 +1,70 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+
+/// Expressions with the values a reference engine evaluates them to, generated by `corpus/generate_expressions.js`.
+const CORPUS: &str = include_str!("corpus/expressions.txt");
+
+fn parse_expected(expected: &str) -> JSValue {
+    match expected {
+        "true" => JSValue::Bool(true),
+        "false" => JSValue::Bool(false),
+        "NaN" => JSValue::Number(f64::NAN.into()),
+        "Infinity" => JSValue::Number(f64::INFINITY.into()),
+        "-Infinity" => JSValue::Number(f64::NEG_INFINITY.into()),
+        string if string.starts_with('"') => JSValue::from(string.trim_matches('"').to_string()),
+        number => JSValue::Number(
+            number
+                .parse::<f64>()
+                .unwrap_or_else(|_| panic!("Invalid expected value {number:?}"))
+                .into(),
+        ),
+    }
+}
+
+/// Numbers are compared by their bits, so that NaN matches itself and the sign of zero is checked.
+///
+/// Exponentiation is implementation-approximated, so a result which is not an integer may differ from the reference engine in its last bits.
+fn same_value(actual: &JSValue, expected: &JSValue) -> bool {
+    match (actual, expected) {
+        (JSValue::Number(actual), JSValue::Number(expected)) => {
+            let (actual, expected) = (actual.0, expected.0);
+
+            (actual.is_nan() && expected.is_nan())
+                || actual.to_bits() == expected.to_bits()
+                || (expected.fract() != 0.0
+                    && ((actual - expected) / expected).abs() <= 4.0 * f64::EPSILON)
+        }
+        _ => actual == expected,
+    }
+}
+
+#[test]
+fn expression_corpus() {
+    let mut failures = vec![];
+
+    let cases = CORPUS
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+    for case in cases {
+        let (source, expected) = case
+            .rsplit_once(" => ")
+            .unwrap_or_else(|| panic!("Invalid corpus line {case:?}"));
+
+        let expected = parse_expected(expected);
+
+        let mut agent = JSAgent::default();
+
+        match eval_script(&mut agent, source) {
+            Ok(actual) if same_value(&actual, &expected) => {}
+            Ok(actual) => failures.push(format!("{source}: expected {expected:?}, got {actual:?}")),
+            Err(err) => failures.push(format!("{source}: expected {expected:?}, threw {err:?}")),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} expressions evaluated incorrectly:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,109 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn enumerates_own_keys() {
+    assert_script_eq!(
+        "let s = ''; for (let k in { a: 1, b: 2, c: 3 }) s = s + k; s",
+        JSValue::from("abc")
+    );
+    assert_script_eq!(
+        "let o = { a: 1, b: 2 }; let t = 0; for (const k in o) { t = t + o[k]; } t",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let s = ''; for (let k in { b: 1, 2: 1, a: 1, 1: 1 }) s = s + k; s",
+        JSValue::from("12ba")
+    );
+}
+
+#[test]
+fn assignment_targets() {
+    assert_script_eq!("let k; for (k in { a: 1, b: 2 }) {} k", JSValue::from("b"));
+    assert_script_eq!(
+        "let o = {}; for (o.p in { x: 1 }) {} o.p",
+        JSValue::from("x")
+    );
+    assert_script_eq!(
+        "let o = { q: {} }; for (o['q'].r in { y: 1 }) {} o.q.r",
+        JSValue::from("y")
+    );
+    assert_script_throws!(
+        "for (1 in {}) {}",
+        "SyntaxError: Invalid left-hand side in assignment"
+    );
+    assert_script_throws!(
+        "const k = 1; for (k in { a: 1 }) {}",
+        "TypeError: Assignment to constant variable"
+    );
+    assert_script_eq!("const k = 1; for (k in {}) {} k", JSValue::from(1.0));
+}
+
+#[test]
+fn walks_the_prototype_chain() {
+    assert_script_eq!(
+        "let p = { a: 1, b: 2 }; let o = { __proto__: p, b: 3, c: 4 }; let s = ''; for (let k in o) s = s + k; s",
+        JSValue::from("bca")
+    );
+    assert_script_eq!(
+        "function f() {} let s = ''; for (let k in f) s = s + k; s",
+        JSValue::from("")
+    );
+}
+
+#[test]
+fn mutation_during_enumeration() {
+    assert_script_eq!(
+        "let o = { a: 1, b: 2, c: 3 }; let s = ''; for (let k in o) { delete o.b; s = s + k; } s",
+        JSValue::from("ac")
+    );
+    assert_script_eq!(
+        "let o = { a: 1, b: 2 }; let s = ''; for (let k in o) { o.c = 3; s = s + k; } s",
+        JSValue::from("ab")
+    );
+}
+
+#[test]
+fn nullish_objects_are_not_enumerated() {
+    assert_script_eq!(
+        "let s = 1; for (let k in {}.missing) s = 2; s",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn completion_value() {
+    assert_script_eq!("for (let k in { a: 1, b: 2 }) k", JSValue::from("b"));
+    assert_script_eq!("1; for (let k in {}) 2", JSValue::Undefined);
+    assert_script_eq!("1; for (let k in { a: 1 }) {}", JSValue::Undefined);
+}
+
+#[test]
+fn per_iteration_bindings() {
+    assert_script_eq!(
+        "let fs = {}; for (let k in { a: 1, b: 2 }) fs[k] = function () { return k }; fs.a() + fs.b()",
+        JSValue::from("ab")
+    );
+    assert_script_throws!("for (let k in k) {}", "ReferenceError");
+    assert_script_throws!(
+        "for (const k in { a: 1 }) k = 2",
+        "TypeError: Assignment to constant variable"
+    );
+    assert_script_eq!(
+        "let k = 1; for (let k in { a: 1 }) {} k",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn nested_loops() {
+    assert_script_eq!(
+        "let s = ''; for (let a in { x: 1, y: 1 }) for (let b in { p: 1, q: 1 }) s = s + a + b; s",
+        JSValue::from("xpxqypyq")
+    );
+    assert_script_eq!(
+        "function keys(o) { let s = ''; for (let k in o) { s = s + k; } return s } keys({ a: 1 }) + keys({ b: 1, c: 1 })",
+        JSValue::from("abc")
+    );
+}
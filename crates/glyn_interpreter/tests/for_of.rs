@@ -0,0 +1,165 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn array_literals() {
+    assert_script_eq!("[1, 2, 3].length", JSValue::from(3.0));
+    assert_script_eq!("[1, 2, 3][1]", JSValue::from(2.0));
+    assert_script_eq!("[].length", JSValue::from(0.0));
+    assert_script_eq!("[1, , 3].length", JSValue::from(3.0));
+    assert_script_eq!("[1, ,].length", JSValue::from(2.0));
+    assert_script_eq!("[, , ].length", JSValue::from(2.0));
+    assert_script_eq!("1 in [1, , 3]", JSValue::from(false));
+    assert_script_eq!("let a = [1, 2]; a[5] = 6; a.length", JSValue::from(6.0));
+    assert_script_eq!("let a = [1, 2, 3]; a.length = 1; a[2]", JSValue::Undefined);
+    assert_script_throws!("let a = []; a.length = -1", "RangeError");
+}
+
+#[test]
+fn iterates_arrays() {
+    assert_script_eq!(
+        "let t = 0; for (let x of [1, 2, 3]) t = t + x; t",
+        JSValue::from(6.0)
+    );
+    assert_script_eq!(
+        "let s = ''; for (const x of ['a', , 'c']) s = s + x; s",
+        JSValue::from("aundefinedc")
+    );
+    assert_script_eq!(
+        "let n = 0; for (let x of []) n = n + 1; n",
+        JSValue::from(0.0)
+    );
+    assert_script_eq!(
+        "let a = [1, 2]; let s = ''; for (let x of a) { s = s + x; a.length = 1; } s",
+        JSValue::from("1")
+    );
+}
+
+#[test]
+fn iterates_array_iterators() {
+    assert_script_eq!(
+        "let s = ''; for (let k of ['a', 'b'].keys()) s = s + k; s",
+        JSValue::from("01")
+    );
+    assert_script_eq!(
+        "let s = ''; for (let e of ['a', 'b'].entries()) s = s + e[0] + e[1]; s",
+        JSValue::from("0a1b")
+    );
+    assert_script_eq!(
+        "let s = ''; for (let v of ['a', 'b'].values()) s = s + v; s",
+        JSValue::from("ab")
+    );
+}
+
+#[test]
+fn iterates_strings() {
+    assert_script_eq!(
+        "let s = ''; for (let c of 'abc') s = c + s; s",
+        JSValue::from("cba")
+    );
+    assert_script_eq!(
+        "let n = 0; for (let c of '') n = n + 1; n",
+        JSValue::from(0.0)
+    );
+    assert_script_eq!("'abc'[1]", JSValue::from("b"));
+    assert_script_eq!("'abc'.length", JSValue::from(3.0));
+}
+
+#[test]
+fn assignment_targets() {
+    assert_script_eq!("let x; for (x of [1, 2]) {} x", JSValue::from(2.0));
+    assert_script_eq!("let o = {}; for (o.p of [3]) {} o.p", JSValue::from(3.0));
+    assert_script_throws!(
+        "for (1 of []) {}",
+        "SyntaxError: Invalid left-hand side in assignment"
+    );
+    assert_script_throws!(
+        "const k = 1; for (k of [1]) {}",
+        "TypeError: Assignment to constant variable"
+    );
+    assert_script_eq!("const k = 1; for (k of []) {} k", JSValue::from(1.0));
+    assert_script_throws!(
+        "for (const k of [1, 2]) { k = 3; }",
+        "TypeError: Assignment to constant variable"
+    );
+}
+
+#[test]
+fn throws_for_non_iterables() {
+    assert_script_throws!("for (let x of {}) {}", "TypeError: Value is not iterable");
+    assert_script_throws!("let u; for (let x of u) {}", "TypeError");
+}
+
+#[test]
+fn iterates_user_iterators() {
+    assert_script_eq!(
+        "let it = [].values(); let i = 0;
+         it.next = function () { i = i + 1; return { value: i, done: i > 3 }; };
+         let t = 0; for (let x of it) t = t + x; t",
+        JSValue::from(6.0)
+    );
+    assert_script_throws!(
+        "let it = [].values(); it.next = function () { return 1; }; for (let x of it) {}",
+        "TypeError: Iterator result is not an object"
+    );
+}
+
+#[test]
+fn closes_iterators_on_abrupt_completion() {
+    assert_script_eq!(
+        "let it = [1, 2, 3].values(); let closed = 0;
+         it['return'] = function () { closed = closed + 1; return {}; };
+         try { for (let x of it) throw x; } catch (e) {}
+         closed",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "let it = [1, 2, 3].values(); let closed = 0;
+         it['return'] = function () { closed = closed + 1; return {}; };
+         function f() { for (let x of it) { return x; } }
+         f() + closed",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "let it = [1, 2, 3].values(); let closed = 0;
+         it['return'] = function () { closed = closed + 1; return {}; };
+         for (let x of it) {}
+         closed",
+        JSValue::from(0.0)
+    );
+    assert_script_eq!(
+        "let it = [1, 2].values(); let closed = 0;
+         it['return'] = function () { closed = closed + 1; return {}; };
+         function f() { for (let x of it) { try { return x; } catch (e) {} } }
+         f() + closed",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn close_errors() {
+    assert_script_throws!(
+        "let it = [1].values(); it['return'] = function () { throw 'closing'; };
+         function f() { for (let x of it) return x; }
+         f()",
+        "closing"
+    );
+    assert_script_throws!(
+        "let it = [1].values(); it['return'] = function () { throw 'closing'; };
+         for (let x of it) throw 'body';",
+        "body"
+    );
+    assert_script_throws!(
+        "let it = [1].values(); it['return'] = function () { return 1; };
+         function f() { for (let x of it) return x; }
+         f()",
+        "TypeError: Result of the iterator return method is not an object"
+    );
+    assert_script_eq!(
+        "let it = [1].values(); it['return'] = function () { throw 'closing'; };
+         function f() { try { for (let x of it) return x; } catch (e) { return e; } }
+         f()",
+        JSValue::from("closing")
+    );
+}
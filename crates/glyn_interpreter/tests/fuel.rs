@@ -0,0 +1,105 @@
+use glyn_interpreter::{eval_script, EvalError, JSAgent, JSValue};
+
+const INFINITE_LOOP: &str = "let a = [0]; for (let x of a) { a[a.length] = x; }";
+
+#[test]
+fn fuel_is_unlimited_by_default() {
+    let mut agent = JSAgent::default();
+
+    assert_eq!(agent.remaining_fuel(), None);
+    assert_eq!(eval_script(&mut agent, "1 + 1"), Ok(JSValue::from(2.0)));
+    assert_eq!(agent.remaining_fuel(), None);
+}
+
+#[test]
+fn instructions_consume_fuel() {
+    let mut agent = JSAgent::default();
+
+    agent.set_fuel(Some(1000));
+
+    assert_eq!(eval_script(&mut agent, "1 + 1"), Ok(JSValue::from(2.0)));
+
+    let after_one_script = agent.remaining_fuel().unwrap();
+
+    assert!(after_one_script < 1000);
+
+    assert_eq!(eval_script(&mut agent, "1 + 1"), Ok(JSValue::from(2.0)));
+    assert_eq!(
+        agent.remaining_fuel(),
+        Some(after_one_script - (1000 - after_one_script))
+    );
+
+    agent.set_fuel(None);
+
+    assert_eq!(agent.remaining_fuel(), None);
+}
+
+#[test]
+fn running_out_of_fuel_aborts_evaluation() {
+    let mut agent = JSAgent::default();
+
+    agent.set_fuel(Some(10_000));
+
+    assert_eq!(
+        eval_script(&mut agent, INFINITE_LOOP),
+        Err(EvalError::FuelExhausted)
+    );
+    assert_eq!(agent.remaining_fuel(), Some(0));
+
+    agent.set_fuel(Some(10_000));
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "globalThis.handled = false; function f() { let a = [0]; for (let x of a) { a[a.length] = x; } } try { f(); } catch (e) { globalThis.handled = true; } finally { globalThis.handled = true; }"
+        ),
+        Err(EvalError::FuelExhausted)
+    );
+    assert_eq!(
+        eval_script(&mut agent, "1").map_err(|error| error.to_string()),
+        Err(String::from("RangeError: fuel exhausted"))
+    );
+
+    agent.set_fuel(None);
+
+    assert_eq!(eval_script(&mut agent, "handled"), Ok(JSValue::Bool(false)));
+}
+
+#[test]
+fn running_out_of_fuel_can_be_made_catchable() {
+    let mut agent = JSAgent::default();
+
+    agent.set_fuel(Some(10_000));
+    agent.set_fuel_exhaustion_catchable(true);
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let error; try { let a = [0]; for (let x of a) { a[a.length] = x; } } catch (e) { error = e; } \"\" + error"
+        ),
+        Ok(JSValue::from("RangeError: fuel exhausted"))
+    );
+    assert_eq!(agent.remaining_fuel(), None);
+
+    agent.set_fuel(Some(10_000));
+
+    assert_eq!(
+        eval_script(&mut agent, INFINITE_LOOP).map_err(|error| error.to_string()),
+        Err(String::from("RangeError: fuel exhausted\n    at 1:33"))
+    );
+}
+
+#[test]
+fn jobs_consume_fuel() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(
+        &mut agent,
+        "Promise.resolve().then(function () { let a = [0]; for (let x of a) { a[a.length] = x; } }); 1"
+    )
+    .is_ok());
+
+    agent.set_fuel(Some(10_000));
+
+    assert_eq!(agent.run_jobs(), Err(EvalError::FuelExhausted));
+}
@@ -0,0 +1,172 @@
+use std::{cell::Cell, rc::Rc};
+
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+
+const CYCLES: &str = "
+    (function () {
+        let d = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        for (let w of d) { for (let x of d) { for (let y of d) { for (let z of d) {
+            let a = {};
+            let b = { a: a };
+            a.b = b;
+        } } } }
+    })();
+";
+
+#[test]
+fn unreachable_cycles_are_collected() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    let before = agent.heap_size();
+
+    assert!(eval_script(
+        &mut agent,
+        "function f() { let a = {}; let b = { a: a }; a.b = b; a.self = a; } f(); f();"
+    )
+    .is_ok());
+
+    let grown = agent.heap_size();
+
+    assert!(agent.collect_garbage() >= 4);
+    assert!(agent.heap_size() < grown);
+    assert!(agent.heap_size() - before < grown - before);
+}
+
+#[test]
+fn closures_which_capture_themselves_are_collected() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(
+        &mut agent,
+        "function f() { let g = function () { return g; }; return 1; } f()"
+    )
+    .is_ok());
+
+    // NOTE: The function object refers to the environment it was created in, which has a binding of the function object.
+    assert!(agent.collect_garbage() >= 2);
+    assert_eq!(agent.collect_garbage(), 0);
+}
+
+#[test]
+fn reachable_values_are_not_collected() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(
+        &mut agent,
+        "
+            globalThis.o = { n: 1 };
+            o.self = o;
+            let counter = (function () { let n = 0; return function () { n = n + 1; return n; }; })();
+            function* g() { let x = { n: 2 }; x.self = x; yield 1; yield x.self.n; }
+            globalThis.it = g();
+            it.next();
+            globalThis.result = 0;
+            new Promise(function (resolve) { globalThis.resolve = resolve; }).then(function (v) { result = v; });
+        "
+    )
+    .is_ok());
+
+    agent.collect_garbage();
+
+    assert_eq!(
+        eval_script(&mut agent, "o.self.self.n"),
+        Ok(JSValue::from(1.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "counter() + counter()"),
+        Ok(JSValue::from(3.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "it.next().value"),
+        Ok(JSValue::from(2.0))
+    );
+    assert!(eval_script(&mut agent, "resolve(4);").is_ok());
+    assert!(agent.run_jobs().is_ok());
+    assert_eq!(eval_script(&mut agent, "result"), Ok(JSValue::from(4.0)));
+}
+
+#[test]
+fn garbage_is_collected_during_evaluation() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    let before = agent.heap_size();
+
+    assert!(eval_script(&mut agent, CYCLES).is_ok());
+
+    // NOTE: Each of the 10000 cycles would take up hundreds of bytes if none were collected.
+    assert!(
+        agent.heap_size() - before < 2 * 1024 * 1024,
+        "Expected the cycles to be collected, but the heap grew by {} bytes",
+        agent.heap_size() - before
+    );
+}
+
+#[test]
+fn cycles_are_collected_before_running_out_of_memory() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    agent.set_heap_limit(Some(agent.heap_size() + 512 * 1024));
+
+    assert!(eval_script(&mut agent, CYCLES).is_ok());
+    assert!(eval_script(&mut agent, CYCLES).is_ok());
+
+    agent.set_heap_limit(None);
+}
+
+#[test]
+fn long_chains_of_objects_are_freed() {
+    let mut agent = JSAgent::default();
+
+    // NOTE: Each object references the one created before it, so freeing the first frees a chain of 30000 objects.
+    let chain = "
+        for (let v of [0, 1, 2]) { for (let w of d) { for (let x of d) { for (let y of d) { for (let z of d) {
+            o = { next: o };
+        } } } } }
+    ";
+
+    assert!(eval_script(
+        &mut agent,
+        &format!("let d = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]; let o = null; {chain} o = null;")
+    )
+    .is_ok());
+
+    assert_eq!(eval_script(&mut agent, "o"), Ok(JSValue::Null));
+}
+
+/// Counts the objects it was attached to which have been freed.
+struct Freed(Rc<Cell<usize>>);
+
+impl Drop for Freed {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn dropping_an_agent_frees_its_cycles() {
+    let freed = Rc::new(Cell::new(0));
+
+    for _ in 0..20 {
+        let mut agent = JSAgent::default();
+
+        let object = eval_script(
+            &mut agent,
+            "globalThis.o = { f: function () { return o; } }; o.self = o; o",
+        )
+        .unwrap();
+
+        agent.set_host_data(&object, Freed(freed.clone())).unwrap();
+
+        drop(object);
+        drop(agent);
+    }
+
+    // NOTE: Each object is reachable from the global object, which is only freed once the cycle between the realm and its intrinsics is broken.
+    assert_eq!(freed.get(), 20);
+}
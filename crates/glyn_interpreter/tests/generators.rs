@@ -0,0 +1,224 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn yields_values_in_order() {
+    assert_script_eq!(
+        "function* g() { yield 1; yield 2; } let it = g(); it.next().value + it.next().value",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "function* g() { yield 1; } let it = g(); it.next(); it.next().done",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "function* g() { yield; } g().next().value",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "function* g() { return 5; } let r = g().next(); r.value + ' ' + r.done",
+        JSValue::from("5 true")
+    );
+    assert_script_eq!(
+        "function* g() { yield 1; } let it = g(); it.next(); it.next(); it.next().done",
+        JSValue::from(true)
+    );
+}
+
+#[test]
+fn body_runs_lazily() {
+    assert_script_eq!(
+        "let n = 0; function* g() { n = n + 1; yield n; } let it = g(); n",
+        JSValue::from(0.0)
+    );
+    assert_script_eq!(
+        "let n = 0; function* g() { n = n + 1; yield n; } let it = g(); it.next(); n",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn next_passes_values_into_yield() {
+    assert_script_eq!(
+        "function* g() { let a = yield 1; let b = yield a * 2; return a + b; }
+         let it = g(); it.next('ignored'); it.next(10); it.next(5).value",
+        JSValue::from(15.0)
+    );
+}
+
+#[test]
+fn keeps_state_across_yields() {
+    assert_script_eq!(
+        "function* g(x) { let y = x; try { yield y; y = y + 1; yield y; } finally { y = 0; } }
+         let it = g(4); it.next().value + it.next().value",
+        JSValue::from(9.0)
+    );
+    assert_script_eq!(
+        "function* g() { for (let x of [1, 2, 3]) yield x * 10; }
+         let s = 0; for (let v of g()) s = s + v; s",
+        JSValue::from(60.0)
+    );
+}
+
+#[test]
+fn generator_expressions() {
+    assert_script_eq!(
+        "let g = function* () { yield 'a'; }; g().next().value",
+        JSValue::from("a")
+    );
+    assert_script_eq!(
+        "let g = function* named() { yield named.name; }; g().next().value",
+        JSValue::from("named")
+    );
+    assert_script_eq!(
+        "let o = { g: function* () { yield this.v; }, v: 7 }; o.g().next().value",
+        JSValue::from(7.0)
+    );
+}
+
+#[test]
+fn iterated_by_for_of() {
+    assert_script_eq!(
+        "function* g() { yield 'x'; yield 'y'; return 'z'; }
+         let s = ''; for (let v of g()) s = s + v; s",
+        JSValue::from("xy")
+    );
+    assert_script_eq!(
+        "function* g() { try { yield 1; yield 2; } finally { log = log + 'closed'; } }
+         let log = ''; function f() { for (let v of g()) { log = log + v; return; } } f(); log",
+        JSValue::from("1closed")
+    );
+}
+
+#[test]
+fn return_completes_the_generator() {
+    assert_script_eq!(
+        "function* g() { yield 1; yield 2; } let it = g(); it.next();
+         let r = it.return(9); r.value + ' ' + r.done + ' ' + it.next().done",
+        JSValue::from("9 true true")
+    );
+    assert_script_eq!(
+        "function* g() { yield 1; } let it = g(); let r = it.return(3); r.value + ' ' + it.next().done",
+        JSValue::from("3 true")
+    );
+    assert_script_eq!(
+        "function* g() { try { yield 1; } finally { yield 'cleanup'; } }
+         let it = g(); it.next(); let r = it.return(2); r.value + ' ' + r.done + ' ' + it.next().value",
+        JSValue::from("cleanup false 2")
+    );
+    assert_script_eq!(
+        "function* g() { try { yield 1; } finally { return 'overridden'; } }
+         let it = g(); it.next(); it.return(2).value",
+        JSValue::from("overridden")
+    );
+}
+
+#[test]
+fn throw_resumes_with_an_exception() {
+    assert_script_eq!(
+        "function* g() { try { yield 1; } catch (e) { yield 'caught ' + e; } }
+         let it = g(); it.next(); it.throw('boom').value",
+        JSValue::from("caught boom")
+    );
+    assert_script_throws!(
+        "function* g() { yield 1; } let it = g(); it.next(); it.throw('uncaught')",
+        "uncaught"
+    );
+    assert_script_eq!(
+        "function* g() { yield 1; } let it = g();
+         try { it.throw('early'); } catch (e) { e + ' ' + it.next().done }",
+        JSValue::from("early true")
+    );
+    assert_script_eq!(
+        "function* g() { throw 'inside'; } let it = g();
+         try { it.next(); } catch (e) { e + ' ' + it.next().done }",
+        JSValue::from("inside true")
+    );
+}
+
+#[test]
+fn rejects_reentrancy_and_incompatible_receivers() {
+    assert_script_throws!(
+        "let it; function* g() { it.next(); yield 1; } it = g(); it.next()",
+        "TypeError"
+    );
+    assert_script_throws!("function* g() {} g().next.call({})", "TypeError");
+}
+
+#[test]
+fn yield_star_delegates() {
+    assert_script_eq!(
+        "function* inner() { yield 1; yield 2; return 'r'; }
+         function* outer() { let v = yield* inner(); yield v; }
+         let s = ''; for (let x of outer()) s = s + x; s",
+        JSValue::from("12r")
+    );
+    assert_script_eq!(
+        "function* g() { yield* 'ab'; yield* [1, 2]; }
+         let s = ''; for (let x of g()) s = s + x; s",
+        JSValue::from("ab12")
+    );
+    assert_script_eq!(
+        "function* inner() { let a = yield 1; return a; }
+         function* outer() { return yield* inner(); }
+         let it = outer(); it.next(); it.next('sent').value",
+        JSValue::from("sent")
+    );
+    assert_script_eq!(
+        "function* g() { yield* yield 1; } let it = g(); it.next().value + it.next(['x']).value",
+        JSValue::from("1x")
+    );
+    assert_script_throws!(
+        "function* g() { yield* {}; } g().next()",
+        "TypeError: Value is not iterable"
+    );
+}
+
+#[test]
+fn yield_star_forwards_throw_and_return() {
+    assert_script_eq!(
+        "function* inner() { try { yield 1; } catch (e) { yield 'inner caught ' + e; } }
+         function* outer() { yield* inner(); }
+         let it = outer(); it.next(); it.throw('x').value",
+        JSValue::from("inner caught x")
+    );
+    assert_script_eq!(
+        "let log = '';
+         function* inner() { try { yield 1; } finally { log = log + 'inner closed'; } }
+         function* outer() { yield* inner(); }
+         let it = outer(); it.next(); let r = it.return(4); log + ' ' + r.value + ' ' + r.done",
+        JSValue::from("inner closed 4 true")
+    );
+    assert_script_eq!(
+        "let closed = 0; let inner = [1, 2].values();
+         inner['return'] = function () { closed = closed + 1; return {}; };
+         function* g() { yield* inner; }
+         let it = g(); it.next(); try { it.throw('x'); } catch (e) { closed + ' ' + e }",
+        JSValue::from("1 TypeError: The iterator does not provide a 'throw' method")
+    );
+}
+
+#[test]
+fn generator_functions_are_not_constructors() {
+    assert_script_throws!("function* g() {} new g()", "TypeError");
+    assert_script_eq!("function* g() {} g() instanceof g", JSValue::from(true));
+    assert_script_eq!(
+        "function* g() {} g.prototype.constructor === g",
+        JSValue::from(false)
+    );
+}
+
+#[test]
+fn yield_is_an_identifier_outside_generators() {
+    assert_script_eq!("let yield = 3; yield", JSValue::from(3.0));
+    assert_script_throws!(
+        "function* g() { let yield = 1; }",
+        "SyntaxError: Unexpected token"
+    );
+    assert_script_throws!(
+        "function* g() { yield = 1; }",
+        "SyntaxError: Unexpected token"
+    );
+    assert_script_throws!("function* () {}", "SyntaxError: Unexpected token");
+}
@@ -0,0 +1,106 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn value_properties() {
+    assert_script_eq!("undefined", JSValue::Undefined);
+    assert_script_eq!("Infinity", JSValue::from(f64::INFINITY));
+    assert_script_eq!("NaN !== NaN", JSValue::Bool(true));
+    assert_script_eq!("globalThis === this", JSValue::Bool(true));
+    assert_script_eq!("globalThis.isNaN === isNaN", JSValue::Bool(true));
+    assert_script_eq!(
+        "undefined = 1; NaN = 1; Infinity = 1; undefined",
+        JSValue::Undefined
+    );
+    assert_script_throws!("'use strict'; undefined = 1;", "TypeError");
+    assert_script_eq!("globalThis = 1; globalThis", JSValue::from(1.0));
+}
+
+#[test]
+fn is_nan_and_is_finite() {
+    assert_script_eq!("isNaN(NaN)", JSValue::Bool(true));
+    assert_script_eq!("isNaN('abc')", JSValue::Bool(true));
+    assert_script_eq!("isNaN(' 12 ')", JSValue::Bool(false));
+    assert_script_eq!("isNaN()", JSValue::Bool(true));
+    assert_script_eq!("isFinite('12')", JSValue::Bool(true));
+    assert_script_eq!("isFinite(-Infinity)", JSValue::Bool(false));
+    assert_script_eq!("isFinite(null)", JSValue::Bool(true));
+    assert_script_eq!("isNaN.length + isFinite.length", JSValue::from(2.0));
+}
+
+#[test]
+fn parse_int() {
+    assert_script_eq!("parseInt('  42px')", JSValue::from(42.0));
+    assert_script_eq!("parseInt('-0x1F')", JSValue::from(-31.0));
+    assert_script_eq!("parseInt('0x1F', 16)", JSValue::from(31.0));
+    assert_script_eq!("parseInt('0x1F', 10)", JSValue::from(0.0));
+    assert_script_eq!("parseInt('08')", JSValue::from(8.0));
+    assert_script_eq!("parseInt('z', 36)", JSValue::from(35.0));
+    assert_script_eq!("parseInt('101', 2)", JSValue::from(5.0));
+    assert_script_eq!("parseInt('1e3')", JSValue::from(1.0));
+    assert_script_eq!("1 / parseInt('-0')", JSValue::from(f64::NEG_INFINITY));
+    assert_script_eq!("isNaN(parseInt('10', 1))", JSValue::Bool(true));
+    assert_script_eq!("isNaN(parseInt('10', 37))", JSValue::Bool(true));
+    assert_script_eq!("isNaN(parseInt(''))", JSValue::Bool(true));
+    assert_script_eq!("isNaN(parseInt('0x'))", JSValue::Bool(true));
+    assert_script_eq!(
+        "parseInt('9007199254740993')",
+        JSValue::from(9007199254740992.0)
+    );
+    assert_script_eq!(
+        "parseInt('11111111111111111111111111111111111111111111111111111111', 2)",
+        JSValue::from(72057594037927936.0)
+    );
+    assert_script_eq!("parseInt.length", JSValue::from(2.0));
+}
+
+#[test]
+fn parse_float() {
+    assert_script_eq!("parseFloat('  2.75abc')", JSValue::from(2.75));
+    assert_script_eq!("parseFloat('.5e-3x')", JSValue::from(0.0005));
+    assert_script_eq!("parseFloat('1.e')", JSValue::from(1.0));
+    assert_script_eq!("parseFloat('1e+')", JSValue::from(1.0));
+    assert_script_eq!("parseFloat('-Infinityx')", JSValue::from(f64::NEG_INFINITY));
+    assert_script_eq!("parseFloat('0x10')", JSValue::from(0.0));
+    assert_script_eq!("1 / parseFloat('-0')", JSValue::from(f64::NEG_INFINITY));
+    assert_script_eq!("isNaN(parseFloat('e5'))", JSValue::Bool(true));
+    assert_script_eq!("isNaN(parseFloat('inf'))", JSValue::Bool(true));
+    assert_script_eq!("isNaN(parseFloat('.'))", JSValue::Bool(true));
+}
+
+#[test]
+fn encode_uri() {
+    assert_script_eq!(
+        "encodeURIComponent('a b&c/d?é')",
+        JSValue::from("a%20b%26c%2Fd%3F%C3%A9")
+    );
+    assert_script_eq!(
+        "encodeURI('http://x.y/a b?q=1&r=é#f')",
+        JSValue::from("http://x.y/a%20b?q=1&r=%C3%A9#f")
+    );
+    assert_script_eq!(
+        "encodeURIComponent(\"-_.!~*'()\")",
+        JSValue::from("-_.!~*'()")
+    );
+}
+
+#[test]
+fn decode_uri() {
+    assert_script_eq!(
+        "decodeURIComponent('%E2%82%AC%20%41%2f')",
+        JSValue::from("€ A/")
+    );
+    assert_script_eq!("decodeURI('%2F%41%23%20')", JSValue::from("%2FA%23 "));
+    assert_script_eq!(
+        "decodeURIComponent(encodeURIComponent('a+b é'))",
+        JSValue::from("a+b é")
+    );
+    assert_script_throws!("decodeURI('%')", "URIError");
+    assert_script_throws!("decodeURI('%E0%A4%A')", "URIError");
+    assert_script_throws!("decodeURI('%G0')", "URIError");
+    assert_script_throws!("decodeURIComponent('%80')", "URIError");
+    assert_script_throws!("decodeURIComponent('%C0%80')", "URIError");
+    assert_script_throws!("decodeURIComponent('%ED%A0%80')", "URIError");
+    assert_script_throws!("decodeURIComponent('%E2%82')", "URIError");
+}
@@ -0,0 +1,190 @@
+use glyn_interpreter::{eval_script, EvalError, JSAgent, JSValue};
+
+#[test]
+fn iterator_results_are_reclaimed() {
+    let mut agent = JSAgent::default();
+
+    let source = "
+        let a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let s = 0;
+        for (let x of a) { for (let y of a) { for (let z of a) { s = s + 1; } } }
+        s
+    ";
+
+    assert_eq!(eval_script(&mut agent, source), Ok(JSValue::from(1000.0)));
+
+    let stats = agent.heap_stats();
+
+    // NOTE: Each step of an iterator allocates an iterator result object which is dropped as soon as the loop has read it.
+    assert!(stats.allocated > 1000);
+    assert!(
+        stats.reclaimed * 2 > stats.allocated,
+        "Expected most allocations to be reclaimed, got {stats:?}"
+    );
+}
+
+#[test]
+fn reachable_objects_are_not_reclaimed() {
+    let mut agent = JSAgent::default();
+
+    let source = "
+        let a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let kept = [];
+        let n = 0;
+        for (let x of a) { for (let y of a) { for (let z of a) { kept[n] = { n: n }; n = n + 1; } } }
+        let s = 0;
+        for (let o of kept) { s = s + o.n; }
+        s
+    ";
+
+    assert_eq!(eval_script(&mut agent, source), Ok(JSValue::from(499500.0)));
+}
+
+const UNBOUNDED_GROWTH: &str = "let a = [0]; for (let x of a) { a[a.length] = { x: x }; }";
+
+#[test]
+fn environments_captured_by_closures_are_not_reclaimed() {
+    let mut agent = JSAgent::default();
+
+    // NOTE: Each iteration creates the environments of its block and of the call, and the closures keep those of their iteration alive while many more are created.
+    let source = "
+        let a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let getters = [];
+        let n = 0;
+        function capture(value) { return function () { return value; }; }
+        for (let x of a) { for (let y of a) { for (let z of a) { let m = n; getters[n] = capture(m); n = n + 1; } } }
+        let s = 0;
+        for (let get of getters) { s = s + get(); }
+        s
+    ";
+
+    assert_eq!(eval_script(&mut agent, source), Ok(JSValue::from(499500.0)));
+}
+
+#[test]
+fn heap_size_is_accounted() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    let before = agent.heap_size();
+
+    assert!(eval_script(
+        &mut agent,
+        "let kept = []; for (let x of [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]) { kept[x] = { x: x }; }"
+    )
+    .is_ok());
+
+    assert!(agent.heap_size() > before);
+}
+
+#[test]
+fn exceeding_the_heap_limit_aborts_evaluation() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "globalThis.handled = false;").is_ok());
+
+    agent.set_heap_limit(Some(agent.heap_size() + 1_000_000));
+
+    assert_eq!(
+        eval_script(&mut agent, UNBOUNDED_GROWTH),
+        Err(EvalError::OutOfMemory)
+    );
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "try { let b = [0]; for (let x of b) { b[b.length] = { x: x }; } } catch (e) { globalThis.handled = true; } finally { globalThis.handled = true; }"
+        )
+        .map_err(|error| error.to_string()),
+        Err(String::from("RangeError: out of memory"))
+    );
+
+    agent.set_heap_limit(None);
+
+    assert_eq!(eval_script(&mut agent, "handled"), Ok(JSValue::Bool(false)));
+}
+
+#[test]
+fn unreferenced_objects_are_freed_before_running_out_of_memory() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    agent.set_heap_limit(Some(agent.heap_size() + 100_000));
+
+    let source = "
+        let a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let s = 0;
+        for (let x of a) { for (let y of a) { for (let z of a) { let o = { x: x, y: y, z: z }; s = s + o.z; } } }
+        s
+    ";
+
+    assert_eq!(eval_script(&mut agent, source), Ok(JSValue::from(4500.0)));
+}
+
+#[test]
+fn strings_are_accounted() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    let before = agent.heap_size();
+
+    assert!(eval_script(
+        &mut agent,
+        "let s = 'abcdefghijklmnopqrstuvwxyz'; s = s + s + s + s; s = s + s + s + s; s[0]"
+    )
+    .is_ok());
+
+    assert!(agent.heap_size() > before + 400 * 2);
+}
+
+#[test]
+fn concatenating_beyond_the_heap_limit_runs_out_of_memory() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    agent.set_heap_limit(Some(4 * 1024 * 1024));
+
+    let source = format!("let s = 'x'; {} s.length", "s = s + s; ".repeat(24));
+
+    assert_eq!(
+        eval_script(&mut agent, &source),
+        Err(EvalError::OutOfMemory)
+    );
+
+    agent.set_heap_limit(None);
+
+    assert_eq!(
+        eval_script(&mut agent, "s.length"),
+        Ok(JSValue::from(1048576.0))
+    );
+}
+
+#[test]
+fn strings_of_other_agents_are_not_accounted() {
+    let mut other = JSAgent::default();
+
+    // NOTE: The keys are interned on the thread, so while they are shared by every agent on it they only count towards the heap of the agent which created them.
+    let source = "
+        let a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let key = 'key';
+        for (let x of a) { key = key + key; }
+        let o = {};
+        let n = 0;
+        for (let x of a) { for (let y of a) { for (let z of a) { o[key + n] = n; n = n + 1; } } }
+        n
+    ";
+
+    assert_eq!(eval_script(&mut other, source), Ok(JSValue::from(1000.0)));
+
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    agent.set_heap_limit(Some(4_000_000));
+
+    assert_eq!(eval_script(&mut agent, "1 + 1"), Ok(JSValue::from(2.0)));
+    assert!(agent.heap_size() < other.heap_size());
+}
@@ -0,0 +1,106 @@
+use glyn_interpreter::{eval_script, structured_clone, JSAgent, JSValue};
+
+struct File {
+    path: String,
+    reads: u32,
+}
+
+/// An agent in which `open(path)` creates a host object holding a `File`, and `read(file)` counts a read of it.
+fn agent() -> JSAgent {
+    let mut agent = JSAgent::default();
+
+    agent.register_function("open", 1, |agent, args| {
+        let path = args.get_string(0)?;
+
+        Ok(agent.create_host_object(File { path, reads: 0 }))
+    });
+
+    agent.register_function("read", 1, |agent, args| {
+        let Some(file) = agent.host_data::<File>(args.get(0)) else {
+            return Ok(JSValue::Undefined);
+        };
+
+        let mut file = file.borrow_mut();
+
+        file.reads += 1;
+
+        Ok(JSValue::from(format!("{}:{}", file.path, file.reads)))
+    });
+
+    agent
+}
+
+#[test]
+fn host_objects_hold_rust_values() {
+    let mut agent = agent();
+
+    let file = eval_script(
+        &mut agent,
+        "let f = open(\"a.txt\"); read(f); read(f) + \",\" + read({}) + \",\" + (typeof f); f;",
+    )
+    .unwrap();
+
+    let file = agent.host_data::<File>(&file).unwrap();
+
+    assert_eq!(file.borrow().path, "a.txt");
+    assert_eq!(file.borrow().reads, 2);
+    assert!(agent.host_data::<String>(&JSValue::Undefined).is_none());
+}
+
+#[test]
+fn host_data_can_be_attached_to_any_object() {
+    let mut agent = agent();
+
+    let object = eval_script(&mut agent, "({ name: \"o\" });").unwrap();
+
+    agent.set_host_data(&object, 1u8).unwrap();
+
+    assert!(agent.host_data::<File>(&object).is_none());
+    assert_eq!(agent.host_data::<u8>(&object).map(|v| *v.borrow()), Some(1));
+
+    // NOTE: Attaching another value replaces the first.
+    agent
+        .set_host_data(
+            &object,
+            File {
+                path: String::from("b.txt"),
+                reads: 0,
+            },
+        )
+        .unwrap();
+
+    assert!(agent.host_data::<u8>(&object).is_none());
+
+    agent.register_function("object", 0, move |_, _| Ok(object.clone()));
+
+    assert_eq!(
+        eval_script(&mut agent, "object().name + read(object());"),
+        Ok(JSValue::from(String::from("ob.txt:1")))
+    );
+}
+
+#[test]
+fn host_data_cannot_be_attached_to_primitives() {
+    let mut agent = agent();
+
+    let error = agent
+        .set_host_data(&JSValue::from(1.0), ())
+        .unwrap_err()
+        .into_value(&mut agent);
+
+    assert_eq!(
+        error.to_display_string(&mut agent).ok(),
+        Some(String::from(
+            "TypeError: Host data can only be attached to an object, but got a number (1)"
+        ))
+    );
+}
+
+#[test]
+fn host_objects_cannot_be_cloned() {
+    let mut agent = agent();
+
+    let file = eval_script(&mut agent, "open(\"a.txt\");").unwrap();
+
+    assert!(structured_clone(&mut agent, &file).is_err());
+}
@@ -0,0 +1,123 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glyn_interpreter::{
+    eval_module, eval_script, EvalError, HostHooks, JSAgent, JSValue, RejectionOperation,
+};
+
+/// Hooks which record a line for each time they are called.
+#[derive(Clone, Default)]
+struct RecordingHooks(Rc<RefCell<Vec<String>>>);
+
+impl RecordingHooks {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.0.borrow_mut())
+    }
+}
+
+impl HostHooks for RecordingHooks {
+    fn call_job_callback(&mut self, _callback: &JSValue, _this_value: &JSValue, args: &[JSValue]) {
+        self.0.borrow_mut().push(format!("call {args:?}"));
+    }
+
+    fn promise_rejection_tracker(&mut self, _promise: &JSValue, operation: RejectionOperation) {
+        self.0.borrow_mut().push(format!("{operation:?}"));
+    }
+
+    fn resolve_module_specifier(&mut self, specifier: &str, _referrer: &str) -> String {
+        specifier.trim_start_matches("./").to_string()
+    }
+
+    fn report_error(&mut self, error: &EvalError) {
+        self.0.borrow_mut().push(format!("report {error}"));
+    }
+}
+
+fn agent() -> (JSAgent, RecordingHooks) {
+    let hooks = RecordingHooks::default();
+    let agent = JSAgent::builder().host_hooks(hooks.clone()).build();
+
+    (agent, hooks)
+}
+
+#[test]
+fn job_callbacks_are_reported_before_they_are_called() {
+    let (mut agent, hooks) = agent();
+
+    eval_script(
+        &mut agent,
+        "Promise.resolve(1).then(function (x) { return x; })",
+    )
+    .unwrap();
+
+    assert!(hooks.take().is_empty());
+
+    agent.run_jobs().unwrap();
+
+    assert_eq!(hooks.take(), vec!["call [Number(JSNumber(1.0))]"]);
+}
+
+#[test]
+fn rejections_without_handlers_are_tracked() {
+    let (mut agent, hooks) = agent();
+
+    eval_script(&mut agent, "let p = Promise.reject(1);").unwrap();
+
+    assert_eq!(hooks.take(), vec!["Reject"]);
+
+    eval_script(
+        &mut agent,
+        "p.then(undefined, function () {}); p.then(undefined, function () {});",
+    )
+    .unwrap();
+
+    assert_eq!(hooks.take(), vec!["Handle"]);
+
+    eval_script(
+        &mut agent,
+        "new Promise(function (resolve, reject) { Promise.resolve().then(function () { reject(2); }); }).then(undefined, function () {});",
+    )
+    .unwrap();
+    agent.run_jobs().unwrap();
+
+    assert!(!hooks.take().contains(&String::from("Reject")));
+}
+
+#[test]
+fn module_specifiers_are_resolved_before_they_are_loaded() {
+    let (mut agent, _) = agent();
+    let loaded = Rc::new(RefCell::new(Vec::new()));
+
+    let push_loaded = Rc::clone(&loaded);
+    agent.set_module_loader(move |specifier, _| {
+        push_loaded.borrow_mut().push(specifier.to_string());
+
+        Ok(String::from("export let x = 1;"))
+    });
+
+    assert!(eval_module(
+        &mut agent,
+        "main",
+        "import { x } from './a'; import { x as y } from 'a';"
+    )
+    .is_ok());
+    assert_eq!(*loaded.borrow(), vec!["a"]);
+}
+
+#[test]
+fn errors_thrown_by_jobs_are_reported() {
+    let (mut agent, hooks) = agent();
+
+    eval_script(
+        &mut agent,
+        "Promise.resolve().then(function () { let a = [0]; for (let x of a) { a[a.length] = x; } });",
+    )
+    .unwrap();
+
+    agent.set_fuel(Some(1000));
+
+    assert_eq!(agent.run_jobs(), Err(EvalError::FuelExhausted));
+    assert_eq!(
+        hooks.take().last().map(String::as_str),
+        Some("report RangeError: fuel exhausted")
+    );
+}
@@ -0,0 +1,124 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn break_and_continue_leave_loops() {
+    assert_script_eq!(
+        "let s = ''; for (const x of [1, 2, 3]) { s = s + x; break; } s",
+        JSValue::from("1")
+    );
+    assert_script_eq!(
+        "let s = ''; for (const x of [1, 2, 3]) { s = s + x; continue; s = s + 'no'; } s",
+        JSValue::from("123")
+    );
+    assert_script_eq!(
+        "let s = ''; for (const x in { a: 1, b: 2 }) { let y = x; { let z = y; s = s + z; break; } } s",
+        JSValue::from("a")
+    );
+    assert_script_eq!(
+        "let s = ''; for (const x of [1, 2]) { for (const y of [3, 4]) { s = s + y; break; } s = s + x; } s",
+        JSValue::from("3132")
+    );
+}
+
+#[test]
+fn labelled_statements_are_targeted_by_their_labels() {
+    assert_script_eq!(
+        "let s = ''; a: { s = s + 'a'; break a; s = s + 'b'; } s",
+        JSValue::from("a")
+    );
+    assert_script_eq!(
+        "let s = ''; outer: for (const x of [1, 2, 3]) { for (const y of [1, 2]) { s = s + x + y + ','; continue outer; } } s",
+        JSValue::from("11,21,31,")
+    );
+    assert_script_eq!(
+        "let s = ''; a: b: for (const x of [1, 2]) { for (const y of [1, 2]) { s = s + x + y; break a; } } s",
+        JSValue::from("11")
+    );
+    assert_script_eq!("a: { } b: { } a: { } 1", JSValue::from(1));
+    assert_script_eq!("a: function f() { return 2; } f()", JSValue::from(2));
+}
+
+#[test]
+fn break_and_continue_close_iterators() {
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield 2; } finally { log = 'closed'; } } for (const x of g()) { break; } log",
+        JSValue::from("closed")
+    );
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield 2; } finally { log = log + 'closed'; } } a: for (const y of [1]) { for (const x of g()) { continue a; } } log",
+        JSValue::from("closed")
+    );
+    assert_script_eq!(
+        "let log = ''; function* g() { try { yield 1; yield 2; } finally { log = log + 'closed'; } } for (const x of g()) { continue; } log",
+        JSValue::from("closed")
+    );
+}
+
+#[test]
+fn break_and_continue_evaluate_finally_clauses() {
+    assert_script_eq!(
+        "let s = ''; for (const x of [1, 2, 3]) { try { s = s + x; break; } finally { s = s + 'f'; } } s",
+        JSValue::from("1f")
+    );
+    assert_script_eq!(
+        "let s = ''; l: for (const x of [1, 2]) { try { try { s = s + x; continue l; } finally { s = s + 'i'; } } finally { s = s + 'o'; } } s",
+        JSValue::from("1io2io")
+    );
+    assert_script_eq!(
+        "let s = ''; for (const x of [1, 2, 3]) { try { s = s + x; } finally { s = s + 'f'; break; } } s",
+        JSValue::from("1f")
+    );
+    assert_script_eq!(
+        "let s = ''; for (const x of [1, 2]) { try { throw x; } catch (e) { let q = e; s = s + q; continue; } } s",
+        JSValue::from("12")
+    );
+    assert_script_eq!(
+        "(function () { a: { try { return 1; } finally { break a; } } return 2; })()",
+        JSValue::from(2)
+    );
+}
+
+#[test]
+fn labels_have_early_errors() {
+    assert_script_throws!("a: a: ;", "SyntaxError: Label has already been declared");
+    assert_script_throws!(
+        "a: { for (const x of []) { a: ; } }",
+        "SyntaxError: Label has already been declared"
+    );
+    assert_script_throws!(
+        "a: { for (const x of []) { continue a; } }",
+        "SyntaxError: Illegal continue statement: label does not denote an iteration statement"
+    );
+    assert_script_throws!(
+        "for (const x of []) { a: { continue a; } }",
+        "SyntaxError: Illegal continue statement: label does not denote an iteration statement"
+    );
+    assert_script_throws!("a: { } break a;", "SyntaxError: Undefined label");
+    assert_script_throws!(
+        "a: for (const x of []) { (function () { break a; }); }",
+        "SyntaxError: Undefined label"
+    );
+    assert_script_throws!("a: { break; }", "SyntaxError: Illegal break statement");
+    assert_script_throws!(
+        "continue;",
+        "SyntaxError: Illegal continue statement: no surrounding iteration statement"
+    );
+    assert_script_throws!(
+        "'use strict'; a: function f() {}",
+        "SyntaxError: Functions can only be labelled"
+    );
+    assert_script_throws!(
+        "a: function* g() {}",
+        "SyntaxError: Functions can only be labelled"
+    );
+}
+
+#[test]
+fn labels_are_not_read_across_line_terminators() {
+    assert_script_eq!(
+        "let s = ''; a: for (const x of [1, 2]) { s = s + x; break\na; } s",
+        JSValue::from("1")
+    );
+}
@@ -0,0 +1,65 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+fn list(count: usize, item: impl Fn(usize) -> String) -> Vec<String> {
+    (0..count).map(item).collect()
+}
+
+#[test]
+fn programs_with_many_constants_and_identifiers() {
+    let declarations = list(300, |index| format!("let v{index} = {index};")).join(" ");
+
+    assert_script_eq!(
+        &format!("{declarations} v0 + v255 + v256 + v299"),
+        JSValue::from(810.0)
+    );
+
+    let strings = list(300, |index| format!("'s{index}'")).join(" + ");
+
+    assert_script_eq!(
+        &format!("let s = {strings}; s.length"),
+        JSValue::from(1090.0)
+    );
+}
+
+#[test]
+fn calls_with_many_arguments() {
+    let arguments = list(300, |index| index.to_string()).join(", ");
+
+    assert_script_eq!(
+        &format!("(function (...args) {{ return args.length + args[299]; }})({arguments})"),
+        JSValue::from(599.0)
+    );
+    assert_script_eq!(
+        &format!("let f = function (a) {{ return a; }}; f({arguments}) + [{arguments}].length"),
+        JSValue::from(300.0)
+    );
+}
+
+#[test]
+fn programs_with_many_functions() {
+    let functions = list(300, |index| {
+        format!("function f{index}() {{ return {index}; }}")
+    })
+    .join(" ");
+
+    assert_script_eq!(
+        &format!("{functions} f0() + f256() + f299()"),
+        JSValue::from(555.0)
+    );
+}
+
+#[test]
+fn jumps_over_long_bodies() {
+    let statements = list(10000, |_| String::from("n = n + 1;")).join(" ");
+
+    assert_script_eq!(
+        &format!("let n = 0; for (let x of [1, 2]) {{ {statements} }} n"),
+        JSValue::from(20000.0)
+    );
+    assert_script_eq!(
+        &format!("let n = 0; try {{ {statements} throw n; }} catch (e) {{ n = e + 1; }} n"),
+        JSValue::from(10001.0)
+    );
+}
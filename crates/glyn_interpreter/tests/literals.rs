@@ -0,0 +1,28 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn boolean_literals() {
+    assert_script_eq!("true", JSValue::Bool(true));
+    assert_script_eq!("false", JSValue::Bool(false));
+    assert_script_eq!("true === true", JSValue::Bool(true));
+    assert_script_eq!("true == 1", JSValue::Bool(true));
+    assert_script_eq!("false + 1", JSValue::from(1.0));
+    assert_script_eq!("let t = true; t", JSValue::Bool(true));
+}
+
+#[test]
+fn null_literal() {
+    assert_script_eq!("null", JSValue::Null);
+    assert_script_eq!("null === null", JSValue::Bool(true));
+    assert_script_eq!("let u; null == u", JSValue::Bool(true));
+    assert_script_eq!("null + 1", JSValue::from(1.0));
+    assert_script_eq!("let o = { p: null }; o.p", JSValue::Null);
+}
+
+#[test]
+fn discarded_values() {
+    assert_script_eq!("true; null; false", JSValue::Bool(false));
+    assert_script_eq!("[true, false, null].length", JSValue::from(3.0));
+}
@@ -0,0 +1,362 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use glyn_interpreter::{eval_module, EvalError, JSAgent, JSValue, SourcePosition};
+
+/// Creates an agent which loads modules from the sources, along with the values passed to `report`.
+fn agent(sources: &[(&str, &str)]) -> (JSAgent, Rc<RefCell<Vec<JSValue>>>) {
+    let mut agent = JSAgent::default();
+    let reported = Rc::new(RefCell::new(Vec::new()));
+
+    let push_reported = Rc::clone(&reported);
+    agent.register_function("report", 1, move |_, args| {
+        push_reported.borrow_mut().push(args.get(0).clone());
+
+        Ok(JSValue::Undefined)
+    });
+
+    let sources: HashMap<String, String> = sources
+        .iter()
+        .map(|(specifier, source)| (specifier.to_string(), source.to_string()))
+        .collect();
+
+    agent.set_module_loader(move |specifier, _| {
+        sources
+            .get(specifier)
+            .cloned()
+            .ok_or_else(|| String::from("not found"))
+    });
+
+    (agent, reported)
+}
+
+/// Evaluates the main module, returning the values it and the modules it imports passed to `report`.
+fn run(main: &str, sources: &[(&str, &str)]) -> Result<Vec<JSValue>, String> {
+    let (mut agent, reported) = agent(sources);
+
+    eval_module(&mut agent, "main", main).map_err(|error| error.to_string())?;
+
+    let reported = reported.borrow().clone();
+
+    Ok(reported)
+}
+
+fn assert_throws(main: &str, sources: &[(&str, &str)], expected: &str) {
+    match run(main, sources) {
+        Ok(reported) => panic!("Expected module to throw, but it reported: {reported:?}"),
+        Err(err) => assert!(
+            err.starts_with(expected),
+            "Expected error starting with {expected:?}, got {err:?}"
+        ),
+    }
+}
+
+#[test]
+fn named_imports_and_exports() {
+    let sources = [(
+        "math",
+        "export const one = 1; export function add(a, b) { return a + b; } let two = 2; export { two, two as deux };",
+    )];
+
+    assert_eq!(
+        run(
+            "import { one, add, deux as d } from 'math'; report(add(one, d));",
+            &sources
+        ),
+        Ok(vec![JSValue::from(3.0)])
+    );
+    assert_eq!(
+        run("import { 'two' as t } from 'math'; report(t);", &sources),
+        Ok(vec![JSValue::from(2.0)])
+    );
+    assert_eq!(
+        run("import 'math'; report(typeof one);", &sources),
+        Ok(vec![JSValue::from("undefined")])
+    );
+}
+
+#[test]
+fn default_exports() {
+    assert_eq!(
+        run(
+            "import value from 'a'; import f, { x } from 'b'; report(value); report(f()); report(x);",
+            &[
+                ("a", "export default 1 + 2;"),
+                ("b", "export default function f() { return 'f'; } export const x = f.name;"),
+            ]
+        ),
+        Ok(vec![JSValue::from(3.0), JSValue::from("f"), JSValue::from("f")])
+    );
+    assert_eq!(
+        run(
+            "import { default as g } from 'a'; report(g());",
+            &[("a", "export default function () { return 4; }")]
+        ),
+        Ok(vec![JSValue::from(4.0)])
+    );
+}
+
+#[test]
+fn namespace_objects() {
+    let sources = [("a", "export let b = 2; export let a = 1; export default 3;")];
+
+    assert_eq!(
+        run(
+            "import * as ns from 'a'; let keys = ''; for (let k in ns) keys = keys + k; report(keys); report(ns.a + ns.b + ns.default);",
+            &sources
+        ),
+        Ok(vec![JSValue::from("abdefault"), JSValue::from(6.0)])
+    );
+    assert_eq!(
+        run(
+            "import * as ns from 'a'; report(ns.missing); report('a' in ns); report(delete ns.missing);",
+            &sources
+        ),
+        Ok(vec![JSValue::Undefined, JSValue::Bool(true), JSValue::Bool(true)])
+    );
+    assert_throws("import * as ns from 'a'; ns.a = 5;", &sources, "TypeError");
+    assert_throws(
+        "import * as ns from 'a'; delete ns.a;",
+        &sources,
+        "TypeError",
+    );
+}
+
+#[test]
+fn re_exports() {
+    let sources = [
+        (
+            "a",
+            "export const x = 1; export const y = 2; export default 3;",
+        ),
+        (
+            "b",
+            "export * from 'a'; export { x as z } from 'a'; export * as all from 'a';",
+        ),
+        ("c", "import { y } from 'a'; export { y as w };"),
+    ];
+
+    assert_eq!(
+        run(
+            "import { x, y, z, all } from 'b'; import { w } from 'c'; report(x + y + z + w + all.default);",
+            &sources
+        ),
+        Ok(vec![JSValue::from(9.0)])
+    );
+    assert_eq!(
+        run(
+            "import * as b from 'b'; let keys = ''; for (let k in b) keys = keys + k; report(keys);",
+            &sources
+        ),
+        Ok(vec![JSValue::from("allxyz")])
+    );
+}
+
+#[test]
+fn live_bindings() {
+    assert_eq!(
+        run(
+            "import { count, increment } from 'counter'; report(count); increment(); report(count);",
+            &[(
+                "counter",
+                "export let count = 0; export function increment() { count = count + 1; }"
+            )]
+        ),
+        Ok(vec![JSValue::from(0.0), JSValue::from(1.0)])
+    );
+}
+
+#[test]
+fn evaluation_order() {
+    assert_eq!(
+        run(
+            "import 'a'; import 'b'; report('main');",
+            &[
+                ("a", "import 'c'; report('a');"),
+                ("b", "import 'c'; report('b');"),
+                ("c", "report('c');"),
+            ]
+        ),
+        Ok(vec![
+            JSValue::from("c"),
+            JSValue::from("a"),
+            JSValue::from("b"),
+            JSValue::from("main")
+        ])
+    );
+}
+
+#[test]
+fn cycles() {
+    assert_eq!(
+        run(
+            "import { a } from 'a'; report(a());",
+            &[
+                (
+                    "a",
+                    "import { b } from 'b'; export function a() { return 'a' + b(); } report('a');"
+                ),
+                (
+                    "b",
+                    "import { a } from 'a'; export function b() { return 'b'; } report(typeof a);"
+                ),
+            ]
+        ),
+        Ok(vec![
+            JSValue::from("function"),
+            JSValue::from("a"),
+            JSValue::from("ab")
+        ])
+    );
+    assert_throws(
+        "import 'a';",
+        &[
+            ("a", "import { b } from 'b'; export const a = 1;"),
+            ("b", "import { a } from 'a'; report(a); export const b = 2;"),
+        ],
+        "ReferenceError",
+    );
+}
+
+#[test]
+fn module_code() {
+    assert_eq!(run("report(this);", &[]), Ok(vec![JSValue::Undefined]));
+    assert_throws("x = 1;", &[], "ReferenceError");
+    assert_throws(
+        "import { x } from 'a'; x = 2;",
+        &[("a", "export let x = 1;")],
+        "TypeError: Assignment to imported binding 'x'",
+    );
+}
+
+#[test]
+fn early_errors() {
+    assert_throws(
+        "const a = 1; export { a, a };",
+        &[],
+        "SyntaxError: Duplicate export of a name",
+    );
+    assert_throws(
+        "export default 1; export default 2;",
+        &[],
+        "SyntaxError: Duplicate export of a name",
+    );
+    assert_throws(
+        "export { a };",
+        &[],
+        "SyntaxError: Export of a name which is not declared",
+    );
+    assert_throws(
+        "import { a } from 'x'; let a = 1;",
+        &[],
+        "SyntaxError: Identifier has already been declared",
+    );
+}
+
+#[test]
+fn resolution_errors() {
+    assert_throws(
+        "import { missing } from 'a';",
+        &[("a", "export const x = 1;")],
+        "SyntaxError: The requested module 'a' does not provide an export named 'missing'",
+    );
+    assert_throws(
+        "import { x } from 'c';",
+        &[
+            ("a", "export const x = 1;"),
+            ("b", "export const x = 2;"),
+            ("c", "export * from 'a'; export * from 'b';"),
+        ],
+        "SyntaxError: The requested module 'c' contains conflicting star exports for name 'x'",
+    );
+    assert_throws(
+        "import 'missing';",
+        &[],
+        "TypeError: Cannot load module 'missing': not found",
+    );
+    assert_throws("import 'a';", &[("a", "export const = 1;")], "SyntaxError");
+}
+
+#[test]
+fn evaluation_errors() {
+    let (mut agent, reported) = agent(&[("a", "report('a'); throw 'failed';")]);
+
+    assert_eq!(
+        eval_module(&mut agent, "main", "import 'a';"),
+        Err(EvalError::UncaughtException {
+            value: JSValue::from("failed"),
+            message: String::from("failed"),
+            position: Some(SourcePosition {
+                line: 1,
+                column: 14
+            }),
+            stack: String::from("\n    at 1:14"),
+        })
+    );
+    assert_eq!(
+        eval_module(&mut agent, "other", "import 'a';").map_err(|error| error.to_string()),
+        Err(String::from("failed"))
+    );
+    assert_eq!(*reported.borrow(), vec![JSValue::from("a")]);
+    assert_eq!(
+        eval_module(&mut agent, "main", "").map_err(|error| error.to_string()),
+        Err(String::from(
+            "TypeError: Module 'main' has already been loaded"
+        ))
+    );
+}
+
+#[test]
+fn html_like_comments_are_not_comments() {
+    assert_eq!(
+        run("report(1); // <!-- 2\n/* --> 3 */", &[]),
+        Ok(vec![JSValue::from(1.0)])
+    );
+    assert_throws("report(1) <!-- 2", &[], "SyntaxError");
+    assert_throws("report(1);\n--> 2", &[], "SyntaxError");
+}
+
+#[test]
+fn every_syntax_error_of_the_main_module_is_reported() {
+    let mut agent = JSAgent::default();
+
+    assert_eq!(
+        eval_module(
+            &mut agent,
+            "main",
+            "import { } from;\nexport let a = ;\nreport(a);"
+        )
+        .map_err(|error| error.to_string()),
+        Err(String::from(
+            "SyntaxError: Unexpected token ';'\nSyntaxError: Unexpected token ';'"
+        ))
+    );
+}
+
+#[test]
+fn import_meta_and_import_calls_are_not_supported() {
+    assert_throws(
+        "report(import.meta);",
+        &[],
+        "SyntaxError: import.meta is not supported",
+    );
+    assert_throws(
+        "import.meta;",
+        &[],
+        "SyntaxError: import.meta is not supported",
+    );
+    assert_throws(
+        "import('a');",
+        &[("a", "")],
+        "SyntaxError: import() is not supported",
+    );
+    assert_throws(
+        "export function f() { return import('a'); }",
+        &[("a", "")],
+        "SyntaxError: import() is not supported",
+    );
+    assert_throws(
+        "import 'a';",
+        &[("a", "import.meta;")],
+        "SyntaxError: import.meta is not supported",
+    );
+}
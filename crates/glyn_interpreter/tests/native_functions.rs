@@ -0,0 +1,197 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use glyn_interpreter::{eval_script, Args, EvalError, JSAgent, JSValue, ThrowCompletion};
+
+fn add(_: &mut JSAgent, args: Args) -> Result<JSValue, ThrowCompletion> {
+    Ok(JSValue::from(args.get_f64(0)? + args.get_f64(1)?))
+}
+
+fn sum(_: &mut JSAgent, args: Args) -> Result<JSValue, ThrowCompletion> {
+    let mut total = args.get_f64(0)?;
+
+    for index in 1..args.len() {
+        total += args.get_f64(index)?;
+    }
+
+    Ok(JSValue::from(total))
+}
+
+fn repeat(_: &mut JSAgent, args: Args) -> Result<JSValue, ThrowCompletion> {
+    let string = args.get_string(0)?;
+    let count = args.get_f64(1)? as usize;
+    let separator = if args.get_bool(2)? { " " } else { "" };
+
+    Ok(JSValue::from(vec![string; count].join(separator)))
+}
+
+fn count(_: &mut JSAgent, args: Args) -> Result<JSValue, ThrowCompletion> {
+    Ok(JSValue::from(args.rest(1).len() as f64))
+}
+
+fn this(_: &mut JSAgent, args: Args) -> Result<JSValue, ThrowCompletion> {
+    Ok(args.this().clone())
+}
+
+fn agent() -> JSAgent {
+    let mut agent = JSAgent::default();
+
+    agent.register_function("add", 2, add);
+    agent.register_function("sum", 1, sum);
+    agent.register_function("repeat", 3, repeat);
+    agent.register_function("count", 0, count);
+    agent.register_function("self", 0, this);
+
+    agent
+}
+
+fn eval(source: &str) -> Result<JSValue, String> {
+    eval_script(&mut agent(), source).map_err(|error| error.to_string())
+}
+
+#[test]
+fn typed_arguments() {
+    assert_eq!(eval("add(1, 2)"), Ok(JSValue::from(3.0)));
+    assert_eq!(
+        eval("repeat('ab', 3, 1 === 1)"),
+        Ok(JSValue::from(String::from("ab ab ab")))
+    );
+}
+
+#[test]
+fn variadic_arguments() {
+    assert_eq!(eval("sum(1)"), Ok(JSValue::from(1.0)));
+    assert_eq!(eval("sum(1, 2, 3, 4)"), Ok(JSValue::from(10.0)));
+    assert_eq!(eval("count()"), Ok(JSValue::from(0.0)));
+    assert_eq!(eval("count(1, 2, 3)"), Ok(JSValue::from(2.0)));
+}
+
+#[test]
+fn this_value() {
+    assert_eq!(
+        eval("let o = { self: self }; o.self() === o"),
+        Ok(JSValue::from(true))
+    );
+}
+
+#[test]
+fn missing_arguments() {
+    assert_eq!(
+        eval("add(1)"),
+        Err(String::from(
+            "TypeError: add: expected at least 2 arguments, but got 1\n    at 1:1"
+        ))
+    );
+    assert_eq!(
+        eval("sum()"),
+        Err(String::from(
+            "TypeError: sum: expected at least 1 argument, but got 0\n    at 1:1"
+        ))
+    );
+}
+
+#[test]
+fn mistyped_arguments() {
+    assert_eq!(
+        eval("add(1, 'a')"),
+        Err(String::from(
+            "TypeError: add: argument 2 must be a number, but got a string (\"a\")\n    at 1:1"
+        ))
+    );
+    assert_eq!(
+        eval("sum(1, 2, {})"),
+        Err(String::from(
+            "TypeError: sum: argument 3 must be a number, but got an object ({})\n    at 1:1"
+        ))
+    );
+    assert_eq!(
+        eval("repeat('a', 1, 0)"),
+        Err(String::from(
+            "TypeError: repeat: argument 3 must be a boolean, but got a number (0)\n    at 1:1"
+        ))
+    );
+}
+
+#[test]
+fn function_properties() {
+    assert_eq!(eval("add.name"), Ok(JSValue::from(String::from("add"))));
+    assert_eq!(eval("add.length"), Ok(JSValue::from(2.0)));
+    assert_eq!(eval("add.name = 'x'; add = 1; add"), Ok(JSValue::from(1.0)));
+}
+
+#[test]
+fn closures_keep_state_between_calls() {
+    let mut agent = JSAgent::default();
+    let count = Cell::new(0.0);
+
+    agent.register_function("next", 0, move |_, _| {
+        count.set(count.get() + 1.0);
+
+        Ok(JSValue::from(count.get()))
+    });
+
+    assert_eq!(
+        eval_script(&mut agent, "next(); next(); next()"),
+        Ok(JSValue::from(3.0))
+    );
+    assert_eq!(eval_script(&mut agent, "next()"), Ok(JSValue::from(4.0)));
+}
+
+#[test]
+fn closures_share_state_with_the_host() {
+    let mut agent = JSAgent::default();
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let push_log = Rc::clone(&log);
+    agent.register_function("log", 1, move |_, args| {
+        push_log.borrow_mut().push(args.get_string(0)?);
+
+        Ok(JSValue::Undefined)
+    });
+
+    let size_log = Rc::clone(&log);
+    agent.register_function("size", 0, move |_, _| {
+        Ok(JSValue::from(size_log.borrow().len() as f64))
+    });
+
+    assert_eq!(
+        eval_script(&mut agent, "log('a'); log('b'); size()"),
+        Ok(JSValue::from(2.0))
+    );
+    assert_eq!(*log.borrow(), vec![String::from("a"), String::from("b")]);
+    assert_eq!(
+        eval_script(&mut agent, "log(1)").map_err(|error| error.to_string()),
+        Err(String::from(
+            "TypeError: log: argument 1 must be a string, but got a number (1)\n    at 1:1"
+        ))
+    );
+}
+
+#[test]
+fn thrown_values_can_be_caught() {
+    let mut agent = JSAgent::default();
+
+    agent.register_function("fail", 1, |_, args| {
+        let result: Result<JSValue, JSValue> = Err(args.get(0).clone());
+
+        Ok(result?)
+    });
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let caught; try { fail({ code: 7 }); } catch (e) { caught = e.code; } caught"
+        ),
+        Ok(JSValue::from(7.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "try { fail('x'); } catch (e) { e + 'y'; }"),
+        Ok(JSValue::from("xy"))
+    );
+    assert!(matches!(
+        eval_script(&mut agent, "fail({ code: 7 })"),
+        Err(EvalError::UncaughtException { value, .. }) if value.is_object()
+    ));
+}
@@ -0,0 +1,48 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn new_target_is_the_constructor_when_constructing() {
+    assert_script_eq!(
+        "function F() { return new.target; } new F() === F",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "function F() { this.name = new.target.name; } new F().name",
+        JSValue::from("F")
+    );
+    assert_script_eq!(
+        "function G() { this.n = 1; } function F() { return new new.target.g(); } F.g = G; new F().n",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function F(a = new.target) { this.a = a === F; } new F().a",
+        JSValue::from(true)
+    );
+}
+
+#[test]
+fn new_target_is_undefined_when_calling() {
+    assert_script_eq!(
+        "function f() { return new.target; } f()",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "function F() { let g = function () { return new.target; }; this.r = g(); } new F().r",
+        JSValue::Undefined
+    );
+}
+
+#[test]
+fn new_target_outside_of_a_function_is_an_early_error() {
+    assert_script_throws!(
+        "new.target",
+        "SyntaxError: new.target expression is not allowed here"
+    );
+    assert_script_throws!(
+        "function f() {} new.target",
+        "SyntaxError: new.target expression is not allowed here"
+    );
+    assert_script_eq!("let o = { target: 1 }; o.target", JSValue::from(1.0));
+}
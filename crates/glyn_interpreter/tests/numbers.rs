@@ -0,0 +1,148 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn to_string_in_radix() {
+    assert_script_eq!("(255).toString(16)", JSValue::from("ff"));
+    assert_script_eq!("(255).toString(2)", JSValue::from("11111111"));
+    assert_script_eq!("(-255).toString(36)", JSValue::from("-73"));
+    assert_script_eq!("(1 / 2).toString(2)", JSValue::from("0.1"));
+    assert_script_eq!("(1 / 3).toString(3)", JSValue::from("0.1"));
+    assert_script_eq!(
+        "(1 / 10).toString(2)",
+        JSValue::from("0.0001100110011001100110011001100110011001100110011001101")
+    );
+    assert_script_eq!(
+        "(2 ** 53 + 2).toString(2)",
+        JSValue::from("100000000000000000000000000000000000000000000000000010")
+    );
+    assert_script_eq!(
+        "(10 ** 21).toString(16)",
+        JSValue::from("3635c9adc5dea00000")
+    );
+}
+
+#[test]
+fn to_string_in_radix_of_special_values() {
+    assert_script_eq!("(-0).toString(2)", JSValue::from("0"));
+    assert_script_eq!("NaN.toString(16)", JSValue::from("NaN"));
+    assert_script_eq!("(1 / 0).toString(2)", JSValue::from("Infinity"));
+    assert_script_eq!("(-1 / 0).toString(2)", JSValue::from("-Infinity"));
+}
+
+#[test]
+fn to_string_defaults_to_radix_10() {
+    assert_script_eq!("(255).toString()", JSValue::from("255"));
+    assert_script_eq!("(255).toString(undefined)", JSValue::from("255"));
+    assert_script_eq!("(1 / 3).toString(10)", JSValue::from("0.3333333333333333"));
+    assert_script_eq!("(10 ** 21).toString()", JSValue::from("1e+21"));
+}
+
+#[test]
+fn to_string_radix_must_be_between_2_and_36() {
+    assert_script_throws!(
+        "(1).toString(1)",
+        "RangeError: toString() radix must be between 2 and 36"
+    );
+    assert_script_throws!(
+        "(1).toString(37)",
+        "RangeError: toString() radix must be between 2 and 36"
+    );
+}
+
+#[test]
+fn number_methods_require_a_number() {
+    assert_script_throws!(
+        "let toString = (1).toString; toString()",
+        "TypeError: Number.prototype.toString called on an incompatible receiver"
+    );
+}
+
+#[test]
+fn number_objects() {
+    assert_script_eq!("Object(2).toString(2)", JSValue::from("10"));
+    assert_script_eq!("Object(2) + 1", JSValue::from(3.0));
+    assert_script_eq!(
+        "let n = Object(2); n.tag = ({}).toString; n.tag()",
+        JSValue::from("[object Number]")
+    );
+}
+
+#[test]
+fn strings_are_converted_to_numbers() {
+    assert_script_eq!("+'12'", JSValue::from(12.0));
+    assert_script_eq!("+'-1.5e1'", JSValue::from(-15.0));
+    assert_script_eq!("+'.5' + +'5.'", JSValue::from(5.5));
+    assert_script_eq!("+'0x1F' + +'0o17' + +'0b11'", JSValue::from(49.0));
+    assert_script_eq!("+'Infinity' === 1 / 0", JSValue::Bool(true));
+    assert_script_eq!("+'-Infinity' === -1 / 0", JSValue::Bool(true));
+    assert_script_eq!("+''", JSValue::from(0.0));
+    assert_script_eq!("+'\t\u{A0}\u{FEFF}'", JSValue::from(0.0));
+    assert_script_eq!("+'\t 12 \u{FEFF}'", JSValue::from(12.0));
+}
+
+#[test]
+fn strings_which_are_not_numeric_literals_are_nan() {
+    for source in [
+        "'-0x10'",
+        "'0x'",
+        "'0xG'",
+        "'0b102'",
+        "'infinity'",
+        "'inf'",
+        "'1e'",
+        "'.'",
+        "'1_0'",
+        "'12abc'",
+    ] {
+        assert_script_eq!(&format!("isNaN(+{source})"), JSValue::Bool(true));
+    }
+}
+
+#[test]
+fn non_decimal_strings_are_rounded_once() {
+    assert_script_eq!("+'0x20000000000001' === 2 ** 53", JSValue::Bool(true));
+    assert_script_eq!("+'0x20000000000003' === 2 ** 53 + 4", JSValue::Bool(true));
+    assert_script_eq!("+'0x200000000000018' === 2 ** 57 + 32", JSValue::Bool(true));
+}
+
+#[test]
+fn to_fixed_rounds_ties_up() {
+    assert_script_eq!("(123456 / 1000).toFixed(2)", JSValue::from("123.46"));
+    assert_script_eq!(
+        "(5 / 10).toFixed(0) + (25 / 10).toFixed(0)",
+        JSValue::from("13")
+    );
+    assert_script_eq!("(-1 / 3).toFixed(3)", JSValue::from("-0.333"));
+    assert_script_eq!(
+        "(0).toFixed(2) + ';' + (1 / 1000).toFixed(1)",
+        JSValue::from("0.00;0.0")
+    );
+    assert_script_eq!("(10 ** 21).toFixed(2)", JSValue::from("1e+21"));
+    assert_script_throws!(
+        "(1).toFixed(101)",
+        "RangeError: toFixed() digits argument must be between 0 and 100"
+    );
+}
+
+#[test]
+fn to_exponential_and_to_precision() {
+    assert_script_eq!(
+        "(123456).toExponential(2) + ';' + (123456).toExponential() + ';' + (0).toExponential(2)",
+        JSValue::from("1.23e+5;1.23456e+5;0.00e+0")
+    );
+    assert_script_eq!(
+        "(123456).toPrecision(2) + ';' + (1 / 8).toPrecision(2) + ';' + (99999).toPrecision(2)",
+        JSValue::from("1.2e+5;0.13;1.0e+5")
+    );
+    assert_script_eq!(
+        "(1 / 1000000000).toPrecision(3) + ';' + (1 / 3).toPrecision()",
+        JSValue::from("1.00e-9;0.3333333333333333")
+    );
+    assert_script_eq!("NaN.toPrecision(0)", JSValue::from("NaN"));
+    assert_script_throws!(
+        "(1).toPrecision(0)",
+        "RangeError: toPrecision() argument must be between 1 and 100"
+    );
+}
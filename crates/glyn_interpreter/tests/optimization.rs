@@ -0,0 +1,40 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue, OptimizationLevel};
+
+const FUEL: u64 = 1_000_000;
+
+/// Evaluates the script with its bytecode optimized at the level, returning its result along with the fuel it took.
+fn evaluate(level: OptimizationLevel, source: &str) -> (Result<JSValue, String>, u64) {
+    let mut agent = JSAgent::default();
+
+    agent.set_optimization_level(level);
+    agent.set_fuel(Some(FUEL));
+
+    let result = eval_script(&mut agent, source).map_err(|error| error.to_string());
+
+    (result, FUEL - agent.remaining_fuel().unwrap())
+}
+
+#[test]
+fn optimized_scripts_evaluate_to_the_same_values() {
+    let scripts = [
+        "function f() { return 1; 2; 3; } f()",
+        "function f() { throw 1; return 2; } let n = 0; try { f(); } catch (e) { n = e; } n",
+        "let n = 0; try { throw 1; n = 5; } catch (e) { n = e; } finally { n = n + 1; } n",
+        "let n = 0; try { try { n = 1; } catch (e) { n = 2; } } catch (e) { n = 3; } n",
+        "function g() { try { return 1; } finally { globalThis.done = true; } } g() + globalThis.done",
+        "let n = 0; for (let x of [1, 2]) { for (let y of [3, 4]) { try { n = n + x * y; } catch (e) {} } } n",
+        "let n = ''; for (let k in { a: 1, b: 2 }) { try { n = n + k; } finally { n = n + ','; } } n",
+        "function* g() { yield 1; return 2; yield 3; } let n = 0; for (let x of g()) { n = n + x; } n",
+        "let o = null; o?.a?.b",
+        "function f(a = 1 + 2) { return a; throw a; } f()",
+        "undefinedBinding",
+    ];
+
+    for source in scripts {
+        let (unoptimized, unoptimized_fuel) = evaluate(OptimizationLevel::None, source);
+        let (optimized, optimized_fuel) = evaluate(OptimizationLevel::Peephole, source);
+
+        assert_eq!(optimized, unoptimized, "{source}");
+        assert!(optimized_fuel <= unoptimized_fuel, "{source}");
+    }
+}
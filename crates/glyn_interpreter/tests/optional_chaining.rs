@@ -0,0 +1,119 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn optional_property_access() {
+    assert_script_eq!("let o = { a: 1 }; o?.a", JSValue::from(1.0));
+    assert_script_eq!("let o = { a: 1 }; o?.['a']", JSValue::from(1.0));
+    assert_script_eq!("let o = null; o?.a", JSValue::Undefined);
+    assert_script_eq!("let o; o?.['a']", JSValue::Undefined);
+    assert_script_eq!("let o = { a: { b: 2 } }; o.a?.b", JSValue::from(2.0));
+    assert_script_eq!("let o = { a: null }; o.a?.b", JSValue::Undefined);
+    assert_script_eq!("let o = {}; o?.a", JSValue::Undefined);
+    assert_script_eq!("'abc'?.length", JSValue::from(3.0));
+}
+
+#[test]
+fn optional_call() {
+    assert_script_eq!("function f() { return 1; } f?.()", JSValue::from(1.0));
+    assert_script_eq!("let f = null; f?.()", JSValue::Undefined);
+    assert_script_eq!(
+        "let o = { x: 1, f: function () { return this.x; } }; o.f?.()",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "let o = { x: 1, f: function () { return this.x; } }; o?.f()",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!("let o = {}; o.f?.()", JSValue::Undefined);
+    assert_script_eq!(
+        "function f(a, b) { return a + b; } f?.(...[1, 2])",
+        JSValue::from(3.0)
+    );
+    assert_script_throws!("let o = {}; o?.f.a()", "TypeError");
+}
+
+#[test]
+fn optional_call_this_value() {
+    assert_script_eq!(
+        "let o = { x: 1, f: function () { return this.x; } }; let r; with (o) { r = f?.(); } r",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "let o = { x: 1, f: function () { return this; } }; o['f']?.() === o",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "let o = { a: { x: 2, f: function () { return this.x; } } }; o?.a.f?.()",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn optional_chain_short_circuits() {
+    assert_script_eq!("let o = null; o?.a.b.c", JSValue::Undefined);
+    assert_script_eq!("let o = null; o?.a.b()", JSValue::Undefined);
+    assert_script_eq!("let o = null; o?.a['b'].c()", JSValue::Undefined);
+    assert_script_eq!("let o = { a: null }; o?.a?.b.c", JSValue::Undefined);
+    assert_script_eq!(
+        "let n = 0; function f() { n = n + 1; return 'a'; } let o = null; o?.[f()].b[f()]; n",
+        JSValue::from(0.0)
+    );
+    assert_script_eq!(
+        "let n = 0; function f() { n = n + 1; } let o = null; o?.a(f(), f()); n",
+        JSValue::from(0.0)
+    );
+    assert_script_throws!("let o = null; (o?.a).b", "TypeError");
+}
+
+#[test]
+fn delete_optional_chain() {
+    assert_script_eq!("let o = null; delete o?.a", JSValue::from(true));
+    assert_script_eq!("let o = { a: 1 }; delete o?.a; o.a", JSValue::Undefined);
+    assert_script_eq!(
+        "let o = { a: { b: 1 } }; delete o?.a.b; o.a.b",
+        JSValue::Undefined
+    );
+    assert_script_eq!("let o = { a: 1 }; delete o?.a", JSValue::from(true));
+    assert_script_eq!(
+        "let n = 0; function f() { n = n + 1; } let o; delete o?.[f()]; n",
+        JSValue::from(0.0)
+    );
+}
+
+#[test]
+fn typeof_optional_chain() {
+    assert_script_eq!(
+        "let o = null; typeof o?.a",
+        JSValue::from(String::from("undefined"))
+    );
+    assert_script_eq!(
+        "let o = { a: 1 }; typeof o?.a",
+        JSValue::from(String::from("number"))
+    );
+}
+
+#[test]
+fn optional_chain_early_errors() {
+    assert_script_throws!(
+        "let o = {}; o?.a = 1",
+        "SyntaxError: Invalid left-hand side in assignment"
+    );
+    assert_script_throws!(
+        "let o = {}; o?.a.b = 1",
+        "SyntaxError: Invalid left-hand side in assignment"
+    );
+    assert_script_throws!(
+        "let o = {}; for (o?.a in {}) {}",
+        "SyntaxError: Invalid left-hand side in assignment"
+    );
+    assert_script_throws!(
+        "function F() {} new F?.()",
+        "SyntaxError: Invalid optional chain from new expression"
+    );
+    assert_script_eq!(
+        "function F() { this.a = 1; } new F()?.a",
+        JSValue::from(1.0)
+    );
+}
@@ -0,0 +1,183 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn default_parameters() {
+    assert_script_eq!("function f(a = 1) { return a; } f()", JSValue::from(1.0));
+    assert_script_eq!("function f(a = 1) { return a; } f(2)", JSValue::from(2.0));
+    assert_script_eq!(
+        "let u; function f(a = 1) { return a; } f(u)",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!("function f(a = 1) { return a; } f(null)", JSValue::Null);
+    assert_script_eq!(
+        "function f(a, b = a + 1) { return a + b; } f(1)",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let o = { x: 2 }; o.f = function (a = this.x) { return a; }; o.f()",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "let n = 0; function f(a = n = n + 1) { return a; } f(); f(5); f(); n",
+        JSValue::from(2.0)
+    );
+    assert_script_throws!("function f(a = b, b) { return a; } f()", "ReferenceError");
+}
+
+#[test]
+fn default_parameters_have_their_own_scope() {
+    assert_script_eq!(
+        "let x = 'outer'; function f(g = function () { return x; }) { let x = 'inner'; return g(); } f()",
+        JSValue::from(String::from("outer"))
+    );
+    assert_script_eq!(
+        "function f(a, g = function () { return a; }) { a = 2; return g(); } f(1)",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn rest_parameters() {
+    assert_script_eq!(
+        "function f(...rest) { return rest.length; } f(1, 2, 3)",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "function f(a, ...rest) { return rest[0] + rest[1]; } f(1, 2, 3)",
+        JSValue::from(5.0)
+    );
+    assert_script_eq!(
+        "function f(a, b, ...rest) { return rest.length; } f(1)",
+        JSValue::from(0.0)
+    );
+}
+
+#[test]
+fn function_length() {
+    assert_script_eq!("function f(a, b) {} f.length", JSValue::from(2.0));
+    assert_script_eq!("function f(a, b = 1, c) {} f.length", JSValue::from(1.0));
+    assert_script_eq!("function f(a, ...rest) {} f.length", JSValue::from(1.0));
+    assert_script_eq!("function f(...rest) {} f.length", JSValue::from(0.0));
+}
+
+#[test]
+fn mapped_arguments_object() {
+    assert_script_eq!(
+        "function f() { return arguments.length; } f(1, 2, 3)",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "function f(a) { return arguments[1]; } f(1, 2)",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "function f(a) { a = 2; return arguments[0]; } f(1)",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "function f(a) { arguments[0] = 2; return a; } f(1)",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "function f(a, a) { arguments[1] = 3; return a; } f(1, 2)",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "function f(a) { arguments[0] = 2; return a; } f()",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "function f(a) { delete arguments[0]; arguments[0] = 2; return a; } f(1)",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f() { return arguments.callee === f; } f()",
+        JSValue::from(true)
+    );
+}
+
+#[test]
+fn unmapped_arguments_object() {
+    assert_script_eq!(
+        "function f(a) { 'use strict'; a = 2; return arguments[0]; } f(1)",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f(a = 0) { a = 2; return arguments[0]; } f(1)",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f(a, ...rest) { arguments[0] = 2; return a; } f(1)",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f(a = 0) { return arguments.length; } f()",
+        JSValue::from(0.0)
+    );
+    assert_script_throws!(
+        "function f() { 'use strict'; return arguments.callee; } f()",
+        "TypeError"
+    );
+}
+
+#[test]
+fn arguments_binding() {
+    assert_script_eq!(
+        "function f(arguments) { return arguments; } f(1)",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f() { function arguments() { return 1; } return arguments(); } f()",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "function f() { let arguments = 2; return arguments; } f()",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "function f() { function g() { return arguments[0]; } return g(2); } f(1)",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn iterating_arguments() {
+    assert_script_eq!(
+        "function f() { let s = 0; for (let x of arguments) { s = s + x; } return s; } f(1, 2, 3)",
+        JSValue::from(6.0)
+    );
+}
+
+#[test]
+fn parameter_early_errors() {
+    assert_script_throws!(
+        "function f(...a = []) {}",
+        "SyntaxError: Rest parameter may not have a default initializer"
+    );
+    assert_script_throws!(
+        "function f(...a, b) {}",
+        "SyntaxError: Rest parameter must be last formal parameter"
+    );
+    assert_script_throws!(
+        "function f(a = 1) { 'use strict'; }",
+        "SyntaxError: Illegal 'use strict' directive in function with non-simple parameter list"
+    );
+    assert_script_throws!(
+        "function f(a, a = 1) {}",
+        "SyntaxError: Duplicate parameter name not allowed in this context"
+    );
+    assert_script_throws!(
+        "function f(a, ...a) {}",
+        "SyntaxError: Duplicate parameter name not allowed in this context"
+    );
+    assert_script_throws!(
+        "async function f(a = await 1) {}",
+        "SyntaxError: Illegal await-expression in formal parameters"
+    );
+    assert_script_throws!(
+        "function* g(a = yield) {}",
+        "SyntaxError: Illegal yield-expression in formal parameters"
+    );
+}
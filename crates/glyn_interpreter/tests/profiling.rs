@@ -0,0 +1,46 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+
+#[test]
+fn profiles_count_opcodes_and_calls() {
+    let mut agent = JSAgent::default();
+
+    agent.set_profiling(true);
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "function f(x) { return x + 1; }\nlet o = {};\nObject.defineProperty(o, 'a', { value: 1 });\nf(1) + f(2)"
+        ),
+        Ok(JSValue::from(5.0))
+    );
+
+    let profile = agent.take_profile();
+
+    assert_eq!(profile.opcodes["BinAdd"].count, 3);
+    assert_eq!(profile.calls["f"], 2);
+    assert_eq!(profile.calls["defineProperty"], 1);
+    assert!(profile.instructions() > profile.opcodes["BinAdd"].count);
+    assert!(!profile.opcodes.contains_key("Wide"));
+
+    // Taking the profile leaves it empty, while profiling continues.
+    assert_eq!(agent.take_profile().instructions(), 0);
+
+    eval_script(&mut agent, "let a = 1;\na + 2").unwrap();
+
+    assert_eq!(agent.take_profile().opcodes["BinAdd"].count, 1);
+}
+
+#[test]
+fn profiling_is_off_by_default() {
+    let mut agent = JSAgent::default();
+
+    eval_script(&mut agent, "function f() {}\nf();").unwrap();
+
+    assert_eq!(agent.take_profile(), Default::default());
+
+    agent.set_profiling(true);
+    eval_script(&mut agent, "f();").unwrap();
+    agent.set_profiling(false);
+
+    assert_eq!(agent.take_profile(), Default::default());
+}
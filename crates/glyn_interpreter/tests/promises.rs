@@ -0,0 +1,294 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+
+mod common;
+
+#[test]
+fn promise_constructor() {
+    assert_script_eq!("Promise.length", JSValue::from(1.0));
+    assert_script_eq!(
+        "Promise.prototype.constructor === Promise",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "new Promise(function () {}) instanceof Promise",
+        JSValue::from(true)
+    );
+    assert_script_throws!("Promise(function () {})", "TypeError");
+    assert_script_throws!(
+        "new Promise(1)",
+        "TypeError: Promise: argument 1 must be a function"
+    );
+    assert_script_throws!("Promise.prototype.then.call({})", "TypeError");
+}
+
+#[test]
+fn reactions_run_as_jobs() {
+    assert_eq!(
+        settle!(
+            "let log = '';
+             Promise.resolve(1).then(function (v) { log = log + 'then' + v; });
+             log = log + 'sync';",
+            "log"
+        ),
+        JSValue::from("syncthen1")
+    );
+    assert_eq!(
+        settle!(
+            "let log = '';
+             let p = new Promise(function (resolve) { log = log + 'executor'; resolve(2); });
+             p.then(function (v) { log = log + v; });",
+            "log"
+        ),
+        JSValue::from("executor2")
+    );
+}
+
+#[test]
+fn chains_and_adopts_thenables() {
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.resolve(1)
+               .then(function (v) { return v + 1; })
+               .then(function (v) { return Promise.resolve(v * 10); })
+               .then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from(20.0)
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             let thenable = { then: function (resolve) { resolve('adopted'); } };
+             Promise.resolve(thenable).then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from("adopted")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             let p = new Promise(function (resolve) { resolve(1); resolve(2); });
+             p.then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn rejections() {
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.reject('no').catch(function (e) { result = e; });",
+            "result"
+        ),
+        JSValue::from("no")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             new Promise(function () { throw 'thrown'; })
+               .then(function () { result = 'fulfilled'; }, function (e) { result = e; });",
+            "result"
+        ),
+        JSValue::from("thrown")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.resolve(1)
+               .then(function () { throw 'later'; })
+               .then(function () { result = 'skipped'; })
+               .catch(function (e) { result = e; });",
+            "result"
+        ),
+        JSValue::from("later")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             let p = Promise.resolve(1);
+             let q = p.then(function () { return q; });
+             q.catch(function (e) { result = \"\" + e; });",
+            "result"
+        ),
+        JSValue::from("TypeError: Chaining cycle detected for promise")
+    );
+}
+
+#[test]
+fn finally() {
+    assert_eq!(
+        settle!(
+            "let log = '';
+             Promise.resolve('value')
+               .finally(function () { log = log + 'finally,'; return 'ignored'; })
+               .then(function (v) { log = log + v; });",
+            "log"
+        ),
+        JSValue::from("finally,value")
+    );
+    assert_eq!(
+        settle!(
+            "let log = '';
+             Promise.reject('reason')
+               .finally(function () { log = log + 'finally,'; })
+               .catch(function (e) { log = log + e; });",
+            "log"
+        ),
+        JSValue::from("finally,reason")
+    );
+}
+
+#[test]
+fn combinators() {
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.all([1, Promise.resolve(2), { then: function (r) { r(3); } }])
+               .then(function (v) { result = v.length + ':' + v[0] + v[1] + v[2]; });",
+            "result"
+        ),
+        JSValue::from("3:123")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.all([1, Promise.reject('bad')]).catch(function (e) { result = e; });",
+            "result"
+        ),
+        JSValue::from("bad")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.all([]).then(function (v) { result = v.length; });",
+            "result"
+        ),
+        JSValue::from(0.0)
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.allSettled([Promise.resolve(1), Promise.reject(2)]).then(function (v) {
+               result = v[0].status + v[0].value + v[1].status + v[1].reason;
+             });",
+            "result"
+        ),
+        JSValue::from("fulfilled1rejected2")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.any([Promise.reject(1), Promise.resolve(2)]).then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from(2.0)
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.any([Promise.reject(1), Promise.reject(2)]).catch(function (e) {
+               result = e.name + e.errors.length + e.errors[1];
+             });",
+            "result"
+        ),
+        JSValue::from("AggregateError22")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.race([new Promise(function () {}), Promise.resolve('first')])
+               .then(function (v) { result = v; });",
+            "result"
+        ),
+        JSValue::from("first")
+    );
+    assert_eq!(
+        settle!(
+            "let result;
+             Promise.all({}).catch(function (e) { result = \"\" + e; });",
+            "result"
+        ),
+        JSValue::from("TypeError: Value is not iterable: an object ({})")
+    );
+}
+
+#[test]
+fn run_jobs_drains_jobs_enqueued_by_jobs() {
+    let mut agent = JSAgent::default();
+
+    eval_script(
+        &mut agent,
+        "let count = 0;
+         function tick() { count = count + 1; }
+         Promise.resolve().then(tick).then(tick).then(tick).then(tick).then(tick);",
+    )
+    .unwrap();
+
+    assert_eq!(eval_script(&mut agent, "count"), Ok(JSValue::from(0.0)));
+
+    agent.run_jobs().unwrap();
+
+    assert_eq!(eval_script(&mut agent, "count"), Ok(JSValue::from(5.0)));
+    assert_eq!(agent.run_jobs(), Ok(()));
+}
+
+/// Evaluates the script, runs the promise jobs it enqueued and then returns the reasons of the promises left rejected without handlers.
+fn unhandled_rejections(source: &str) -> Vec<JSValue> {
+    let mut agent = JSAgent::default();
+
+    eval_script(&mut agent, source).expect("script should evaluate");
+    agent.run_jobs().expect("jobs should run");
+
+    agent
+        .take_unhandled_rejections()
+        .into_iter()
+        .map(|rejection| rejection.reason)
+        .collect()
+}
+
+#[test]
+fn rejections_without_handlers_are_unhandled() {
+    assert_eq!(
+        unhandled_rejections("Promise.reject(1); Promise.reject(2).then(function () {});"),
+        vec![JSValue::from(1.0), JSValue::from(2.0)]
+    );
+    assert_eq!(
+        unhandled_rejections(
+            "Promise.resolve().then(function () { throw 'thrown'; }); Promise.resolve(1);"
+        ),
+        vec![JSValue::from("thrown")]
+    );
+}
+
+#[test]
+fn rejections_handled_later_are_not_unhandled() {
+    assert_eq!(
+        unhandled_rejections(
+            "let p = Promise.reject(1); Promise.resolve().then(function () { p.then(undefined, function () {}); });"
+        ),
+        vec![]
+    );
+    assert_eq!(
+        unhandled_rejections(
+            "Promise.reject(1).then(undefined, function (e) { return e; }); async function f() { try { await Promise.reject(2); } catch (e) {} } f();"
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn unhandled_rejections_are_only_taken_once() {
+    let mut agent = JSAgent::default();
+
+    eval_script(&mut agent, "let p = Promise.reject(1);").unwrap();
+
+    let rejections = agent.take_unhandled_rejections();
+
+    assert_eq!(rejections.len(), 1);
+    assert_eq!(rejections[0].promise, eval_script(&mut agent, "p").unwrap());
+    assert!(agent.take_unhandled_rejections().is_empty());
+}
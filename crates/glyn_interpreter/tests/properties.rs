@@ -0,0 +1,230 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn objects_created_alike_have_independent_properties() {
+    assert_script_eq!(
+        "let a = { x: 1, y: 2 }; let b = { x: 3, y: 4 }; a.x = 5; a.x + b.x + a.y + b.y",
+        JSValue::from(14.0)
+    );
+    assert_script_eq!(
+        "let a = {}; a.x = 1; let b = {}; b.x = 2; b.y = 3; a.y",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "let a = { x: 1 }; let b = { x: 2 }; Object.defineProperty(a, 'x', { writable: false }); b.x = 3; a.x = 4; a.x + b.x",
+        JSValue::from(4.0)
+    );
+}
+
+#[test]
+fn deleted_properties_keep_the_order_of_creation() {
+    assert_script_eq!(
+        "let o = { a: 1, b: 2, c: 3 }; delete o.b; o.b = 4; o.d = 5; let s = ''; for (let k in o) { s = s + k + o[k]; } s",
+        JSValue::from("a1c3b4d5")
+    );
+    assert_script_eq!(
+        "let o = { a: 1, b: 2 }; delete o.a; delete o.b; o.a = 3; let s = ''; for (let k in o) { s = s + k; } s + ('b' in o)",
+        JSValue::from("afalse")
+    );
+    assert_script_eq!(
+        "let o = { a: 1 }; delete o.missing; o.b = 2; let s = ''; for (let k in o) { s = s + k; } s",
+        JSValue::from("ab")
+    );
+}
+
+#[test]
+fn objects_with_many_properties() {
+    assert_script_eq!(
+        "
+            let o = {};
+            let d = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            for (let x of d) { for (let y of d) { o['p' + x + y] = x * 10 + y; } }
+            delete o.p50;
+            o.p50 = 1;
+            let s = 0;
+            let last;
+            for (let k in o) { s = s + o[k]; last = k; }
+            s + ',' + o.p99 + ',' + last
+        ",
+        JSValue::from("4901,99,p50")
+    );
+}
+
+#[test]
+fn array_indices_come_first_in_ascending_order() {
+    assert_script_eq!(
+        "let o = { b: 1, 2: 2, a: 3, 1: 4, '01': 5 }; let s = ''; for (let k in o) { s = s + k + ','; } s",
+        JSValue::from("1,2,b,a,01,")
+    );
+    assert_script_eq!(
+        "let a = [1, 2, 3]; a[5] = 6; a.x = 7; a[4] = 5; let s = ''; for (let k in a) { s = s + k; } s",
+        JSValue::from("01245x")
+    );
+    assert_script_eq!(
+        "let a = []; a[4294967294] = 1; a[4294967295] = 2; a.length",
+        JSValue::from(4294967295.0)
+    );
+}
+
+#[test]
+fn elements_with_holes_and_attributes() {
+    assert_script_eq!(
+        "let a = [1, 2, 3]; delete a[1]; a.length + ',' + (1 in a) + ',' + a[2]",
+        JSValue::from("3,false,3")
+    );
+    assert_script_eq!(
+        "let a = [1, 2, 3]; delete a[2]; a[2] = 4; a[3] = 5; let s = 0; for (let x of a) { s = s + x; } s",
+        JSValue::from(12.0)
+    );
+    assert_script_eq!(
+        "let a = [1, 2]; Object.defineProperty(a, 0, { writable: false }); a[0] = 5; a[1] = 6; a[0] + a[1]",
+        JSValue::from(7.0)
+    );
+    assert_script_eq!(
+        "let a = [1, 2]; Object.defineProperty(a, '1', { get: function () { return 9; } }); a[1]",
+        JSValue::from(9.0)
+    );
+    assert_script_eq!(
+        "let a = [1, 2, 3, 4]; a.length = 2; a[2] + ',' + a.length + ',' + (3 in a)",
+        JSValue::from("undefined,2,false")
+    );
+}
+
+#[test]
+fn defined_properties_take_the_default_value_of_each_attribute_not_given() {
+    let describe = "function describe(o, key) { let d = Object.getOwnPropertyDescriptor(o, key); let s = ''; for (let k in d) { s = s + k + '=' + d[k] + ','; } return s; }";
+
+    for (descriptor, expected) in [
+        (
+            "{}",
+            "value=undefined,writable=false,enumerable=false,configurable=false,",
+        ),
+        (
+            "{ enumerable: true }",
+            "value=undefined,writable=false,enumerable=true,configurable=false,",
+        ),
+        (
+            "{ value: 1 }",
+            "value=1,writable=false,enumerable=false,configurable=false,",
+        ),
+        (
+            "{ writable: true, configurable: true }",
+            "value=undefined,writable=true,enumerable=false,configurable=true,",
+        ),
+        (
+            "{ get: undefined }",
+            "get=undefined,set=undefined,enumerable=false,configurable=false,",
+        ),
+    ] {
+        assert_script_eq!(
+            &format!("{describe} let o = {{}}; Object.defineProperty(o, 'x', {descriptor}); describe(o, 'x')"),
+            JSValue::from(expected)
+        );
+    }
+
+    assert_script_eq!(
+        &format!("{describe} let o = {{}}; o.x = 1; describe(o, 'x')"),
+        JSValue::from("value=1,writable=true,enumerable=true,configurable=true,")
+    );
+    assert_script_eq!(
+        &format!(
+            "{describe} let o = {{ x: 1 }}; Object.defineProperty(o, 'x', {{}}); describe(o, 'x')"
+        ),
+        JSValue::from("value=1,writable=true,enumerable=true,configurable=true,")
+    );
+}
+
+#[test]
+fn redefining_a_property_sets_only_the_attributes_given() {
+    let describe = "function describe(o, key) { let d = Object.getOwnPropertyDescriptor(o, key); return d.value + ',' + d.writable + ',' + d.get + ',' + d.enumerable + ',' + d.configurable; }";
+
+    assert_script_eq!(
+        &format!("{describe} let o = {{ x: 1 }}; Object.defineProperty(o, 'x', {{ enumerable: false }}); describe(o, 'x')"),
+        JSValue::from("1,true,undefined,false,true")
+    );
+    assert_script_eq!(
+        &format!("{describe} let o = {{ x: 1 }}; Object.defineProperty(o, 'x', {{ value: 2, writable: false }}); Object.defineProperty(o, 'x', {{ configurable: false }}); describe(o, 'x')"),
+        JSValue::from("2,false,undefined,true,false")
+    );
+    assert_script_eq!(
+        &format!("{describe} let a = [1, 2]; Object.defineProperty(a, 0, {{ enumerable: false }}); Object.defineProperty(a, 0, {{ value: 3 }}); describe(a, 0) + ';' + describe(a, 1)"),
+        JSValue::from("3,true,undefined,false,true;2,true,undefined,true,true")
+    );
+}
+
+#[test]
+fn redefining_a_property_as_another_kind_keeps_its_enumerable_and_configurable_attributes() {
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { value: 1, enumerable: true, configurable: true }); Object.defineProperty(o, 'x', { get: function () { return 2; } }); let d = Object.getOwnPropertyDescriptor(o, 'x'); o.x + ',' + d.set + ',' + d.enumerable + ',' + d.configurable + ',' + ('value' in d) + ',' + ('writable' in d)",
+        JSValue::from("2,undefined,true,true,false,false")
+    );
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { get: function () { return 1; }, configurable: true }); Object.defineProperty(o, 'x', { value: 2 }); let d = Object.getOwnPropertyDescriptor(o, 'x'); d.value + ',' + d.writable + ',' + d.enumerable + ',' + ('get' in d)",
+        JSValue::from("2,false,false,false")
+    );
+}
+
+#[test]
+fn redefining_a_property_keeps_the_order_of_the_keys() {
+    assert_script_eq!(
+        "let o = { a: 1, b: 2, c: 3 }; Object.defineProperty(o, 'a', { value: 4 }); Object.defineProperty(o, 'b', { get: function () { return 5; } }); let s = ''; for (let k in o) { s = s + k + o[k]; } s",
+        JSValue::from("a4b5c3")
+    );
+}
+
+#[test]
+fn non_configurable_properties_cannot_be_redefined() {
+    assert_script_throws!(
+        "let o = {}; Object.defineProperty(o, 'x', { value: 1 }); Object.defineProperty(o, 'x', { enumerable: true });",
+        "TypeError"
+    );
+    assert_script_throws!(
+        "let o = {}; Object.defineProperty(o, 'x', { value: 1 }); Object.defineProperty(o, 'x', { get: function () {} });",
+        "TypeError"
+    );
+    assert_script_eq!(
+        "let o = {}; Object.defineProperty(o, 'x', { value: 1 }); Object.defineProperty(o, 'x', { value: 1, writable: false }); o.x",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn keys_are_the_same_however_they_are_written() {
+    assert_script_eq!(
+        "let o = {}; o[1] = 1; o['1'] = 2; o['01'] = 3; o[2 - 1] = o[1] + o['01']; let s = ''; for (let k in o) { s = s + k + o[k] + ','; } s",
+        JSValue::from("15,013,")
+    );
+    assert_script_eq!(
+        "let o = {}; o[4294967295] = 1; o['4294967295'] = o['4294967295'] + 1; o[4294967295]",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "let o = { x: 1 }; let key = 'x'; o[key] = o.x + 1; let f = function (x) { return x + o['x']; }; f(3)",
+        JSValue::from(5.0)
+    );
+}
+
+#[test]
+fn object_prototype_to_string_tags() {
+    assert_script_eq!("({}).toString()", JSValue::from("[object Object]"));
+    assert_script_eq!(
+        "let toString = ({}).toString; let f = function () {}; f.toString = toString; f.toString()",
+        JSValue::from("[object Function]")
+    );
+    assert_script_eq!(
+        "let toString = ({}).toString; let e = new Error('x'); e.toString = toString; e.toString()",
+        JSValue::from("[object Error]")
+    );
+    assert_script_eq!(
+        "let toString = ({}).toString; let d = new Date(0); d.toString = toString; d.toString()",
+        JSValue::from("[object Date]")
+    );
+    assert_script_eq!(
+        "function f() { arguments.toString = ({}).toString; return arguments.toString(); } f()",
+        JSValue::from("[object Arguments]")
+    );
+    assert_script_eq!("[1, 2].toString()", JSValue::from("1,2"));
+    assert_script_eq!("let o = {}; o.valueOf() === o", JSValue::Bool(true));
+}
@@ -0,0 +1,196 @@
+use glyn_interpreter::{eval_script, eval_script_in_realm, Args, JSAgent, JSValue};
+
+#[test]
+fn realms_have_separate_globals() {
+    let mut agent = JSAgent::default();
+    let realm = agent.create_realm();
+
+    assert!(eval_script(&mut agent, "globalThis.a = 1; globalThis.b = 2;").is_ok());
+    assert!(eval_script_in_realm(&mut agent, &realm, "globalThis.a = 3;").is_ok());
+
+    assert_eq!(eval_script(&mut agent, "a"), Ok(JSValue::from(1.0)));
+    assert_eq!(
+        eval_script_in_realm(&mut agent, &realm, "a"),
+        Ok(JSValue::from(3.0))
+    );
+    assert_eq!(
+        eval_script_in_realm(&mut agent, &realm, "typeof b"),
+        Ok(JSValue::from("undefined"))
+    );
+    assert_eq!(
+        eval_script_in_realm(&mut agent, &realm, "let c = 4; c + a"),
+        Ok(JSValue::from(7.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "typeof c"),
+        Ok(JSValue::from("undefined"))
+    );
+}
+
+#[test]
+fn realms_can_be_created_before_the_agent_evaluates_scripts() {
+    let mut agent = JSAgent::default();
+    let first = agent.create_realm();
+    let second = agent.create_realm();
+
+    assert!(eval_script_in_realm(&mut agent, &first, "globalThis.a = 1;").is_ok());
+
+    assert_eq!(
+        eval_script_in_realm(&mut agent, &second, "typeof a"),
+        Ok(JSValue::from("undefined"))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "typeof a"),
+        Ok(JSValue::from("undefined"))
+    );
+}
+
+#[test]
+fn realms_have_separate_intrinsics() {
+    let mut agent = JSAgent::default();
+    let realm = agent.create_realm();
+
+    let other_object = eval_script_in_realm(&mut agent, &realm, "Object").unwrap();
+
+    agent.register_function("otherObject", 0, move |_: &mut JSAgent, _: Args| {
+        Ok(other_object.clone())
+    });
+
+    assert_eq!(
+        eval_script(&mut agent, "otherObject() === Object"),
+        Ok(JSValue::Bool(false))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "({}) instanceof otherObject()"),
+        Ok(JSValue::Bool(false))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "new (otherObject())() instanceof Object"),
+        Ok(JSValue::Bool(false))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "new (otherObject())() instanceof otherObject()"),
+        Ok(JSValue::Bool(true))
+    );
+}
+
+#[test]
+fn functions_use_the_realm_they_were_created_in() {
+    let mut agent = JSAgent::default();
+    let realm = agent.create_realm();
+
+    let make = eval_script_in_realm(
+        &mut agent,
+        &realm,
+        "let name = 'other'; (function () { return { name: name }; })",
+    )
+    .unwrap();
+
+    agent.register_function("otherMake", 0, move |_: &mut JSAgent, _: Args| {
+        Ok(make.clone())
+    });
+
+    assert_eq!(
+        eval_script(&mut agent, "let name = 'main'; otherMake()().name"),
+        Ok(JSValue::from("other"))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "otherMake()() instanceof Object"),
+        Ok(JSValue::Bool(false))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "otherMake() instanceof Object"),
+        Ok(JSValue::Bool(false))
+    );
+    assert_eq!(
+        eval_script_in_realm(&mut agent, &realm, "({}) instanceof Object"),
+        Ok(JSValue::Bool(true))
+    );
+}
+
+#[test]
+fn errors_in_a_realm_do_not_affect_the_agent() {
+    let mut agent = JSAgent::default();
+    let realm = agent.create_realm();
+
+    assert!(eval_script_in_realm(&mut agent, &realm, "throw 1;").is_err());
+    assert!(eval_script_in_realm(&mut agent, &realm, "let = ;").is_err());
+
+    assert_eq!(eval_script(&mut agent, "1 + 1"), Ok(JSValue::from(2.0)));
+    assert_eq!(
+        eval_script_in_realm(&mut agent, &realm, "2 + 2"),
+        Ok(JSValue::from(4.0))
+    );
+}
+
+#[test]
+fn dropped_realms_are_freed() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "1").is_ok());
+
+    agent.collect_garbage();
+
+    let before = agent.heap_size();
+
+    for _ in 0..10 {
+        let realm = agent.create_realm();
+
+        assert!(eval_script_in_realm(
+            &mut agent,
+            &realm,
+            "globalThis.o = { f: function () { return o; } };"
+        )
+        .is_ok());
+    }
+
+    let grown = agent.heap_size();
+
+    assert!(agent.collect_garbage() > 0);
+    assert!(
+        agent.heap_size() - before < (grown - before) / 10,
+        "Expected the realms to be freed, but the heap grew by {} bytes",
+        agent.heap_size() - before
+    );
+}
+
+#[test]
+fn errors_are_created_from_the_constructors_of_the_realm_they_are_thrown_in() {
+    let mut agent = JSAgent::default();
+    let realm = agent.create_realm();
+
+    let other = eval_script_in_realm(
+        &mut agent,
+        &realm,
+        "({ TypeError: TypeError, RangeError: RangeError, Array: Array, read: function (o) { return o.x; } })",
+    )
+    .unwrap();
+
+    agent.register_function(
+        "other",
+        0,
+        move |_: &mut JSAgent, _: Args| Ok(other.clone()),
+    );
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let e; try { other().read(null); } catch (error) { e = error; } (e instanceof other().TypeError) + ' ' + (e instanceof TypeError)"
+        ),
+        Ok(JSValue::from("true false"))
+    );
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let f; try { new (other().Array)(-1); } catch (error) { f = error; } (f instanceof other().RangeError) + ' ' + (f instanceof RangeError)"
+        ),
+        Ok(JSValue::from("true false"))
+    );
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let g; try { null.x; } catch (error) { g = error; } (g instanceof other().TypeError) + ' ' + (g instanceof TypeError)"
+        ),
+        Ok(JSValue::from("false true"))
+    );
+}
@@ -0,0 +1,147 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn typeof_unresolvable_references() {
+    assert_script_eq!("typeof x", JSValue::from("undefined"));
+    assert_script_eq!("'use strict'; typeof x", JSValue::from("undefined"));
+    assert_script_throws!("typeof x.y", "ReferenceError: x is not defined");
+    assert_script_throws!("let x = typeof y; y", "ReferenceError: y is not defined");
+}
+
+#[test]
+fn typeof_values() {
+    assert_script_eq!(
+        "typeof 1 + ',' + typeof 'a' + ',' + typeof true + ',' + typeof null",
+        JSValue::from("number,string,boolean,object")
+    );
+    assert_script_eq!(
+        "typeof {} + ',' + typeof [] + ',' + typeof function () {} + ',' + typeof Promise",
+        JSValue::from("object,object,function,function")
+    );
+    assert_script_eq!(
+        "let x; let o = { p: 1 }; typeof x + typeof o.p + typeof o.q",
+        JSValue::from("undefinednumberundefined")
+    );
+    assert_script_throws!("typeof x; let x = 1;", "ReferenceError");
+}
+
+#[test]
+fn with_statement_resolves_properties_of_the_object() {
+    assert_script_eq!(
+        "let o = { x: 1 }; let y = 2; let r; with (o) { r = x + y; } r",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!("let o = { x: 1 }; with (o) x = 2; o.x", JSValue::from(2.0));
+    assert_script_eq!(
+        "const x = 1; let o = { x: 1 }; with (o) { x = 2; } o.x + x",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let o = {}; with (o) { typeof x }",
+        JSValue::from("undefined")
+    );
+    assert_script_eq!(
+        "let o = { x: 1 }; with (o) {} typeof x",
+        JSValue::from("undefined")
+    );
+    assert_script_throws!("with (o) {}", "ReferenceError: o is not defined");
+    assert_script_throws!("with (null) {}", "TypeError");
+}
+
+#[test]
+fn with_statement_calls_use_the_object_as_this() {
+    assert_script_eq!(
+        "let o = { name: 'o', f: function () { return this.name; } }; let r; with (o) { r = f(); } r",
+        JSValue::from("o")
+    );
+    assert_script_eq!(
+        "let o = { x: 1, f: function () { return x; } }; let x = 2; let r; with (o) { r = f(); } r",
+        JSValue::from(2.0)
+    );
+}
+
+#[test]
+fn with_statement_environment_is_restored() {
+    assert_script_eq!(
+        "let o = { x: 1 }; let x = 2; let r; try { with (o) { throw x; } } catch (e) { r = e + x; } r",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let o = { x: 1 }; let x = 2; function f() { with (o) { return x; } } f() + x",
+        JSValue::from(3.0)
+    );
+}
+
+#[test]
+fn with_statement_completion_value() {
+    assert_script_eq!("1; with ({}) {}", JSValue::Undefined);
+    assert_script_eq!("1; with ({ x: 2 }) { x }", JSValue::from(2.0));
+    assert_script_eq!("1; with ({ x: 2 }) x;", JSValue::from(2.0));
+}
+
+#[test]
+fn with_statement_respects_array_unscopables() {
+    assert_script_eq!(
+        "let keys = 'outer'; let r; with ([]) { r = keys; } r",
+        JSValue::from("outer")
+    );
+    assert_script_eq!(
+        "let values = 'outer'; let r; with ([1]) { r = values + length; } r",
+        JSValue::from("outer1")
+    );
+    assert_script_eq!(
+        "let r; with ([]) { r = typeof entries; } r",
+        JSValue::from("undefined")
+    );
+    assert_script_eq!(
+        "let a = [1, 2]; typeof a.keys + typeof a.values + typeof a.entries",
+        JSValue::from("functionfunctionfunction")
+    );
+}
+
+#[test]
+fn unresolvable_references() {
+    assert_script_throws!("x", "ReferenceError: x is not defined");
+    assert_script_throws!("'use strict'; x = 1", "ReferenceError: x is not defined");
+    assert_script_eq!("x = 1; globalThis.x", JSValue::from(1.0));
+    assert_script_eq!(
+        "x = 1; Object.getOwnPropertyDescriptor(globalThis, 'x').configurable",
+        JSValue::from(true)
+    );
+    assert_script_eq!("delete x", JSValue::from(true));
+    assert_script_eq!("x = 1; delete x; typeof x", JSValue::from("undefined"));
+}
+
+#[test]
+fn property_references_of_primitives() {
+    assert_script_eq!("'ab'.length", JSValue::from(2.0));
+    assert_script_eq!("let s = 'ab'; s.y = 1; s.y", JSValue::Undefined);
+    assert_script_throws!(
+        "'use strict'; let s = 'ab'; s.y = 1",
+        "TypeError: Cannot assign to read only property"
+    );
+    assert_script_eq!(
+        "Object.defineProperty(Object.prototype, 'self', { get: function () { 'use strict'; return typeof this; } }); 'a'.self",
+        JSValue::from("string")
+    );
+    assert_script_eq!(
+        "Object.defineProperty(Object.prototype, 'p', { set: function (v) { 'use strict'; globalThis.seen = typeof this; } }); (1).p = 2; seen",
+        JSValue::from("number")
+    );
+}
+
+#[test]
+fn delete_references() {
+    assert_script_eq!("delete 1", JSValue::from(true));
+    assert_script_eq!(
+        "let o = { a: 1 }; delete o.a; 'a' in o",
+        JSValue::from(false)
+    );
+    assert_script_eq!("delete Object.prototype", JSValue::from(false));
+    assert_script_throws!(
+        "'use strict'; delete Object.prototype",
+        "TypeError: Cannot delete non-configurable property"
+    );
+}
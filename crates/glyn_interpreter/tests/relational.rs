@@ -0,0 +1,49 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn in_operator() {
+    assert_script_eq!("let o = { a: 1 }; 'a' in o", JSValue::Bool(true));
+    assert_script_eq!("let o = { a: 1 }; 'b' in o", JSValue::Bool(false));
+    assert_script_eq!("let o = { 1: 1 }; 1 in o", JSValue::Bool(true));
+    assert_script_eq!(
+        "let p = { a: 1 }; let o = { __proto__: p }; 'a' in o",
+        JSValue::Bool(true)
+    );
+    assert_script_eq!("let a = 1; let o = { a }; 'a' in o", JSValue::Bool(true));
+    assert_script_eq!("let o = { ['a' + 'b']: 1 }; 'ab' in o", JSValue::Bool(true));
+}
+
+#[test]
+fn in_operator_throws_for_non_object() {
+    assert_script_throws!("'a' in 1", "TypeError");
+    assert_script_throws!("'length' in 'abc'", "TypeError");
+}
+
+#[test]
+fn instanceof_operator() {
+    assert_script_eq!(
+        "function F() {} let f = new F(); f instanceof F",
+        JSValue::Bool(true)
+    );
+    assert_script_eq!(
+        "function F() {} function G() {} let f = new F(); f instanceof G",
+        JSValue::Bool(false)
+    );
+    assert_script_eq!(
+        "function F() {} let o = { __proto__: F.prototype }; o instanceof F",
+        JSValue::Bool(true)
+    );
+    assert_script_eq!(
+        "function F() {} let o = { __proto__: { __proto__: F.prototype } }; o instanceof F",
+        JSValue::Bool(true)
+    );
+    assert_script_eq!("function F() {} 1 instanceof F", JSValue::Bool(false));
+}
+
+#[test]
+fn instanceof_operator_throws_for_non_callable() {
+    assert_script_throws!("let o = {}; o instanceof o", "TypeError");
+    assert_script_throws!("let o = {}; o instanceof 1", "TypeError");
+}
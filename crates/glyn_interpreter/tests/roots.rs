@@ -0,0 +1,116 @@
+use glyn_interpreter::{eval_script, eval_script_in_realm, Args, JSAgent, JSValue};
+
+#[test]
+fn rooted_values_survive_collection() {
+    let mut agent = JSAgent::default();
+
+    let value = eval_script(
+        &mut agent,
+        "(function () { let o = { n: 1 }; o.self = o; return o; })()",
+    )
+    .unwrap();
+
+    let root = agent.root(value);
+
+    agent.collect_garbage();
+
+    agent.register_function("rooted", 0, move |agent: &mut JSAgent, _: Args| {
+        Ok(agent.get_root(root).unwrap_or(JSValue::Undefined))
+    });
+
+    assert_eq!(
+        eval_script(&mut agent, "rooted().self.self.n"),
+        Ok(JSValue::from(1.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "rooted() === rooted()"),
+        Ok(JSValue::Bool(true))
+    );
+}
+
+#[test]
+fn unrooted_values_are_released() {
+    let mut agent = JSAgent::default();
+
+    let root = agent.root(JSValue::from(1.0));
+
+    assert_eq!(agent.root_count(), 1);
+    assert_eq!(agent.get_root(root), Some(JSValue::from(1.0)));
+    assert_eq!(agent.unroot(root), Some(JSValue::from(1.0)));
+    assert_eq!(agent.root_count(), 0);
+    assert_eq!(agent.get_root(root), None);
+    assert_eq!(agent.unroot(root), None);
+
+    let next = agent.root(JSValue::from(2.0));
+
+    assert_ne!(root, next);
+    assert_eq!(agent.get_root(root), None);
+}
+
+#[test]
+fn scopes_release_their_roots() {
+    let mut agent = JSAgent::default();
+
+    let outer = agent.root(JSValue::from("outer"));
+
+    let (inner, nested, result) = agent.scope(|agent| {
+        let inner = agent.root(JSValue::from("inner"));
+
+        let nested = agent.scope(|agent| {
+            let nested = agent.root(JSValue::from("nested"));
+
+            assert_eq!(agent.root_count(), 3);
+
+            nested
+        });
+
+        assert_eq!(agent.root_count(), 2);
+        assert_eq!(agent.get_root(nested), None);
+
+        (inner, nested, agent.get_root(inner))
+    });
+
+    assert_eq!(result, Some(JSValue::from("inner")));
+    assert_eq!(agent.get_root(inner), None);
+    assert_eq!(agent.get_root(nested), None);
+    assert_eq!(agent.get_root(outer), Some(JSValue::from("outer")));
+    assert_eq!(agent.root_count(), 1);
+}
+
+#[test]
+fn values_released_by_a_scope_can_be_collected() {
+    let mut agent = JSAgent::default();
+
+    agent.scope(|agent| {
+        let value = eval_script(
+            agent,
+            "(function () { let o = {}; o.self = o; return o; })()",
+        )
+        .unwrap();
+
+        agent.root(value);
+        agent.collect_garbage();
+    });
+
+    assert!(agent.collect_garbage() >= 1);
+}
+
+#[test]
+fn realms_can_be_rooted() {
+    let mut agent = JSAgent::default();
+
+    let realm = agent.create_realm();
+    let root = agent.root(realm);
+
+    let realm = agent.get_root(root).unwrap();
+
+    assert!(eval_script_in_realm(&mut agent, &realm, "globalThis.a = 1;").is_ok());
+    assert_eq!(
+        eval_script_in_realm(&mut agent, &realm, "a"),
+        Ok(JSValue::from(1.0))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "typeof a"),
+        Ok(JSValue::from("undefined"))
+    );
+}
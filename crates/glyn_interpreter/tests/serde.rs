@@ -0,0 +1,84 @@
+#![cfg(feature = "serde")]
+
+use glyn_interpreter::{eval_script, from_js, to_js, JSAgent, JSValue};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Point {
+    x: i32,
+    y: i32,
+    label: Option<String>,
+}
+
+#[test]
+fn values_convert_to_json() {
+    let mut agent = JSAgent::default();
+
+    let value = eval_script(
+        &mut agent,
+        "({ a: 1, b: [true, null, undefined, \"s\"], c: 3 / 2, d: 1 / 0, e: { f: -0 } });",
+    )
+    .unwrap();
+
+    assert_eq!(
+        value.to_json(&mut agent).ok(),
+        Some(json!({ "a": 1, "b": [true, null, null, "s"], "c": 1.5, "d": null, "e": { "f": 0 } }))
+    );
+}
+
+#[test]
+fn json_converts_to_values() {
+    let mut agent = JSAgent::default();
+
+    let value = JSValue::from_json(&mut agent, &json!({ "list": [1, "two", { "three": 3 }] }));
+
+    agent.register_function("value", 0, move |_, _| Ok(value.clone()));
+
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "let v = value(); \"\" + v.list.length + v.list[1] + v.list[2].three;"
+        ),
+        Ok(JSValue::from(String::from("3two3")))
+    );
+}
+
+#[test]
+fn rust_values_cross_the_boundary() {
+    let mut agent = JSAgent::default();
+
+    let point = Point {
+        x: 1,
+        y: -2,
+        label: None,
+    };
+
+    let value = to_js(&mut agent, &point).unwrap();
+
+    assert_eq!(from_js::<Point>(&mut agent, &value).ok(), Some(point));
+
+    let value = eval_script(&mut agent, "({ x: 3, y: 4, label: \"p\", extra: [] });").unwrap();
+
+    assert_eq!(
+        from_js::<Point>(&mut agent, &value).ok(),
+        Some(Point {
+            x: 3,
+            y: 4,
+            label: Some(String::from("p")),
+        })
+    );
+}
+
+#[test]
+fn unconvertible_values_throw() {
+    let mut agent = JSAgent::default();
+
+    let function = eval_script(&mut agent, "(function f() {});").unwrap();
+    let cycle = eval_script(&mut agent, "let o = {}; o.o = o; o;").unwrap();
+    let mistyped = eval_script(&mut agent, "({ x: \"1\", y: 2 });").unwrap();
+
+    assert!(function.to_json(&mut agent).is_err());
+    assert!(cycle.to_json(&mut agent).is_err());
+    assert!(from_js::<Point>(&mut agent, &mistyped).is_err());
+}
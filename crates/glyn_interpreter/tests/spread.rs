@@ -0,0 +1,83 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn spread_arguments() {
+    assert_script_eq!(
+        "function f(a, b, c) { return a + b + c; } f(...[1, 2, 3])",
+        JSValue::from(6.0)
+    );
+    assert_script_eq!(
+        "function f(a, b, c, d) { return '' + a + b + c + d; } f(1, ...[2, 3], 4)",
+        JSValue::from(String::from("1234"))
+    );
+    assert_script_eq!(
+        "function f() { return arguments.length; } f(...[], ...[1, 2], 3, ...'ab')",
+        JSValue::from(5.0)
+    );
+    assert_script_eq!(
+        "function f(...rest) { return rest[2]; } f(...'abc')",
+        JSValue::from(String::from("c"))
+    );
+    assert_script_eq!(
+        "function* g() { yield 1; yield 2; } function f(a, b) { return a + b; } f(...g())",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let o = { x: 1, f: function (a) { return this.x + a; } }; o.f(...[2])",
+        JSValue::from(3.0)
+    );
+}
+
+#[test]
+fn spread_arguments_evaluation_order() {
+    assert_script_eq!(
+        "let s = ''; function log(x) { s = s + x; return [x]; } function f() {} f(log(1), ...log(2), log(3)); s",
+        JSValue::from(String::from("123"))
+    );
+    assert_script_eq!(
+        "let s = ''; function* g() { s = s + 'a'; yield 1; s = s + 'b'; } function f() { s = s + 'c'; } f(...g()); s",
+        JSValue::from(String::from("abc"))
+    );
+}
+
+#[test]
+fn spread_new_arguments() {
+    assert_script_eq!(
+        "function P(a, b) { this.sum = a + b; } new P(...[1, 2]).sum",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "function P(a, b, c) { this.v = '' + a + b + c; } new P(1, ...[2, 3]).v",
+        JSValue::from(String::from("123"))
+    );
+}
+
+#[test]
+fn spread_elements() {
+    assert_script_eq!("[...[1, 2], 3].length", JSValue::from(3.0));
+    assert_script_eq!("[0, ...[1, 2], 3][2]", JSValue::from(2.0));
+    assert_script_eq!("[...'abc'][1]", JSValue::from(String::from("b")));
+    assert_script_eq!("[...[]].length", JSValue::from(0.0));
+    assert_script_eq!("[, ...[1]][1]", JSValue::from(1.0));
+    assert_script_eq!("[...[1], , 2].length", JSValue::from(3.0));
+    assert_script_eq!(
+        "function* g() { yield 1; yield 2; } let a = [...g(), ...g()]; a[3]",
+        JSValue::from(2.0)
+    );
+    assert_script_eq!(
+        "let a = [1, 2]; let b = [...a]; b[0] = 3; a[0]",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn spread_non_iterable() {
+    assert_script_throws!("[...{}]", "TypeError: Value is not iterable");
+    assert_script_throws!(
+        "function f() {} f(...{})",
+        "TypeError: Value is not iterable"
+    );
+    assert_script_throws!("function f() {} f(...null)", "TypeError");
+}
@@ -0,0 +1,90 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn assignment_to_undeclared_name() {
+    assert_script_eq!("x = 1; x", JSValue::from(1.0));
+    assert_script_throws!("'use strict'; x = 1", "ReferenceError");
+    assert_script_throws!("function f() { 'use strict'; x = 1 } f()", "ReferenceError");
+    assert_script_throws!("'use strict'; function f() { x = 1 } f()", "ReferenceError");
+    assert_script_eq!("function f() { x = 1 } f(); x", JSValue::from(1.0));
+}
+
+#[test]
+fn directive_prologue() {
+    assert_script_eq!("'use strict'", JSValue::from(String::from("use strict")));
+    assert_script_eq!("'use strict' + 1; x = 1; x", JSValue::from(1.0));
+    assert_script_throws!("'foo'; \"use strict\"; x = 1", "ReferenceError");
+    // A Use Strict Directive must be part of the Directive Prologue.
+    assert_script_eq!("let y = 0; 'use strict'; x = 1; x", JSValue::from(1.0));
+}
+
+#[test]
+fn this_in_strict_function() {
+    assert_script_eq!(
+        "'use strict'; function f() { return this } f()",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "let o = {}; function f() { 'use strict'; return this } o.f = f; o.f() === o",
+        JSValue::from(true)
+    );
+}
+
+#[test]
+fn delete() {
+    assert_script_eq!("let o = { a: 1 }; delete o.a", JSValue::from(true));
+    assert_script_eq!(
+        "let o = { a: 1 }; delete o['a']; 'a' in o",
+        JSValue::from(false)
+    );
+    assert_script_eq!("let x = 1; delete x", JSValue::from(false));
+    assert_script_eq!("delete 1", JSValue::from(true));
+    assert_script_throws!(
+        "'use strict'; let x = 1; delete x",
+        "SyntaxError: Delete of an unqualified identifier in strict mode"
+    );
+    assert_script_eq!(
+        "'use strict'; let o = { a: 1 }; delete o.a",
+        JSValue::from(true)
+    );
+}
+
+#[test]
+fn duplicate_parameters() {
+    assert_script_eq!("function f(a, a) { return a } f(1, 2)", JSValue::from(2.0));
+    assert_script_throws!(
+        "'use strict'; function f(a, a) {}",
+        "SyntaxError: Duplicate parameter name not allowed in this context"
+    );
+    assert_script_throws!(
+        "function f(a, a) { 'use strict' }",
+        "SyntaxError: Duplicate parameter name not allowed in this context"
+    );
+}
+
+#[test]
+fn legacy_octal() {
+    assert_script_eq!("010", JSValue::from(8.0));
+    assert_script_throws!(
+        "'use strict'; 010",
+        "SyntaxError: Octal literals are not allowed in strict mode"
+    );
+    assert_script_throws!(
+        "function f() { 'use strict'; return 010 }",
+        "SyntaxError: Octal literals are not allowed in strict mode"
+    );
+    assert_script_throws!(
+        "'use strict'; '\\01'",
+        "SyntaxError: Octal escape sequences are not allowed in strict mode"
+    );
+}
+
+#[test]
+fn with_statement() {
+    assert_script_throws!(
+        "'use strict'; with ({}) {}",
+        "SyntaxError: Strict mode code may not include a with statement"
+    );
+}
@@ -0,0 +1,76 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn strings_are_sequences_of_code_units() {
+    assert_script_eq!("'a😀b'.length", JSValue::from(4.0));
+    assert_script_eq!("let s = 'a😀b'; s[1] + s[2] === '😀'", JSValue::Bool(true));
+    assert_script_eq!("let s = 'a😀b'; s[1] === '😀'", JSValue::Bool(false));
+    assert_script_eq!("let s = 'a😀b'; s[3] + s[4]", JSValue::from("bundefined"));
+    assert_script_eq!(
+        "let n = 0; for (let c of 'a😀b' + '😀'[0]) { n = n + 1; } n",
+        JSValue::from(4.0)
+    );
+}
+
+#[test]
+fn strings_compare_by_code_unit() {
+    assert_script_eq!("'😀' < 'ﬀ'", JSValue::Bool(true));
+    assert_script_eq!("'ab' < 'abc'", JSValue::Bool(true));
+    assert_script_eq!("'b' < 'abc'", JSValue::Bool(false));
+}
+
+#[test]
+fn long_concatenations() {
+    assert_script_eq!(
+        "
+            let s = '';
+            let d = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            for (let x of d) { for (let y of d) { for (let z of d) { s = s + x + y + z; } } }
+            s.length + ',' + s[0] + s[1] + s[2997] + s[2998] + s[2999] + ',' + (s === s + '')
+        ",
+        JSValue::from("3000,00999,true")
+    );
+    assert_script_eq!(
+        "
+            let s = 'abcdefghijklmnopqrstuvwxyz0123456789';
+            let t = s + s;
+            let o = {};
+            o[t] = 1;
+            o[s + 'abcdefghijklmnopqrstuvwxyz' + '0123456789']
+        ",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn strings_longer_than_the_maximum_length_throw() {
+    let doublings = |count: usize| format!("let s = 'x'; {}", "s = s + s; ".repeat(count));
+
+    assert_script_eq!(
+        &format!("{} s.length", doublings(28)),
+        JSValue::from(268435456.0)
+    );
+    assert_script_throws!(&doublings(30), "RangeError: Invalid string length");
+    assert_script_eq!(
+        &format!(
+            "let error; try {{ {} }} catch (e) {{ error = e; }} \"\" + error",
+            doublings(30)
+        ),
+        JSValue::from("RangeError: Invalid string length")
+    );
+}
+
+#[test]
+fn lone_surrogates() {
+    assert_script_eq!("encodeURIComponent('a😀')", JSValue::from("a%F0%9F%98%80"));
+    assert_script_throws!("encodeURIComponent('😀'[0])", "URIError");
+    assert_script_throws!("encodeURI('a' + '😀'[1])", "URIError");
+}
+
+#[test]
+fn unterminated_strings_are_a_syntax_error() {
+    assert_script_throws!("\"abc", "SyntaxError: Unterminated string literal");
+    assert_script_throws!("'abc\n'", "SyntaxError: Unterminated string literal");
+}
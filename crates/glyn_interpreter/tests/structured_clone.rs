@@ -0,0 +1,156 @@
+use std::thread;
+
+use glyn_interpreter::{eval_script, structured_clone, JSAgent, JSValue, Transferable};
+
+/// Clones the completion value of the script.
+fn clone(source: &str) -> Result<Transferable, String> {
+    let mut agent = JSAgent::default();
+
+    let value = eval_script(&mut agent, source).unwrap();
+
+    structured_clone(&mut agent, &value).map_err(|error| {
+        let error = error.into_value(&mut agent);
+
+        error.to_display_string(&mut agent).unwrap_or_default()
+    })
+}
+
+/// Evaluates the script in a new agent in which `received()` returns the deserialized value.
+fn receive(transferable: Transferable, source: &str) -> JSValue {
+    let mut agent = JSAgent::default();
+
+    agent.register_function("received", 0, move |agent, _args| {
+        Ok(transferable.deserialize(agent))
+    });
+
+    eval_script(&mut agent, source).unwrap()
+}
+
+#[test]
+fn primitives() {
+    assert_eq!(
+        receive(clone("\"abc\";").unwrap(), "received();"),
+        JSValue::from("abc")
+    );
+    assert_eq!(
+        receive(clone("-0;").unwrap(), "1 / received();"),
+        JSValue::from(f64::NEG_INFINITY)
+    );
+    assert_eq!(
+        receive(clone("null;").unwrap(), "received();"),
+        JSValue::Null
+    );
+}
+
+#[test]
+fn objects_and_arrays() {
+    let transferable = clone("({ a: 1, b: [\"x\", { c: true }], d: undefined });").unwrap();
+
+    assert_eq!(
+        receive(
+            transferable,
+            r#"
+            let v = received();
+            "" + v.a + v.b.length + v.b[0] + v.b[1].c + ("d" in v) + Array.isArray(v.b);
+            "#
+        ),
+        JSValue::from("12xtruetruetrue")
+    );
+}
+
+#[test]
+fn only_enumerable_own_string_properties_are_cloned() {
+    let transferable = clone(
+        r#"
+        function P() {}
+        P.prototype.inherited = 1;
+        let o = new P();
+        Object.defineProperty(o, "hidden", { value: 2, enumerable: false });
+        Object.defineProperty(o, "computed", { get: function () { return 3; }, enumerable: true });
+        o;
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        receive(
+            transferable,
+            r#"
+            let v = received();
+            "" + ("inherited" in v) + ("hidden" in v) + v.computed;
+            "#
+        ),
+        JSValue::from("falsefalse3")
+    );
+}
+
+#[test]
+fn shared_and_cyclic_references_are_kept() {
+    let transferable = clone(
+        r#"
+        let shared = {};
+        let o = { first: shared, second: shared };
+        o.self = o;
+        o;
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        receive(
+            transferable,
+            r#"
+            let v = received();
+            "" + (v.first === v.second) + (v.self === v);
+            "#
+        ),
+        JSValue::from("truetrue")
+    );
+}
+
+#[test]
+fn dates_errors_and_wrappers() {
+    let transferable = clone(
+        r#"
+        let e = new Error("boom");
+        e.name = "TypeError";
+        [new Date(86400000), e, Object(true), Object(2), Object("s")];
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        receive(
+            transferable,
+            r#"
+            let v = received();
+            "" + v[0].getTime() + v[1].name + v[1].message + (v[1] instanceof TypeError) + v[2].valueOf() + v[3].valueOf() + v[4].length + v[4][0];
+            "#
+        ),
+        JSValue::from("86400000TypeErrorboomtruetrue21s")
+    );
+}
+
+#[test]
+fn uncloneable_values_throw() {
+    assert_eq!(
+        clone("(function f() {});"),
+        Err(String::from(
+            "TypeError: a function (f) could not be cloned"
+        ))
+    );
+    assert!(clone("(function* () {})();").is_err());
+}
+
+#[test]
+fn values_can_be_sent_between_threads() {
+    let transferable = clone("({ list: [1, 2, 3] });").unwrap();
+
+    let result = thread::spawn(move || {
+        receive(transferable, "received().list.length;") == JSValue::from(3.0)
+    })
+    .join()
+    .unwrap();
+
+    assert!(result);
+}
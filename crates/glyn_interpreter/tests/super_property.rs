@@ -0,0 +1,88 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn super_property_gets_from_the_home_object_prototype() {
+    assert_script_eq!(
+        "let p = { x: 1 }; let o = { __proto__: p, x: 2, get y() { return super.x; } }; o.y",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "let p = { x: 1 }; let o = { __proto__: p, get y() { let k = 'x'; return super[k]; } }; o.y",
+        JSValue::from(1.0)
+    );
+    assert_script_eq!(
+        "let o = { get y() { return super.x; } }; o.y",
+        JSValue::Undefined
+    );
+    assert_script_eq!(
+        "let o = { get y() { return super.toString === Object.prototype.toString; } }; o.y",
+        JSValue::from(true)
+    );
+}
+
+#[test]
+fn super_property_uses_the_this_value() {
+    assert_script_eq!(
+        "let p = { get w() { return this.v; } }; let o = { __proto__: p, v: 3, get y() { return super.w; } }; o.y",
+        JSValue::from(3.0)
+    );
+    assert_script_eq!(
+        "let p = { get w() { return this.v; } }; let o = { __proto__: p, get y() { return super.w; } }; let c = { __proto__: o, v: 4 }; c.y",
+        JSValue::from(4.0)
+    );
+    assert_script_eq!(
+        "let p = { f: function () { return this.v; } }; let o = { __proto__: p, v: 5, get y() { return super.f(); } }; o.y",
+        JSValue::from(5.0)
+    );
+}
+
+#[test]
+fn super_property_puts_on_the_this_value() {
+    assert_script_eq!(
+        "let p = { x: 1 }; let o = { __proto__: p, set y(v) { super.x = v; } }; o.y = 2; o.x + ',' + p.x + ',' + Object.getOwnPropertyDescriptor(o, 'x').value",
+        JSValue::from("2,1,2")
+    );
+    assert_script_eq!(
+        "let p = { set x(v) { this.s = v; } }; let o = { __proto__: p, set y(v) { super.x = v; } }; o.y = 6; o.s",
+        JSValue::from(6.0)
+    );
+    assert_script_eq!(
+        "let p = { x: 1 }; let o = { __proto__: p, get y() { return (super.x = super.x + 2); } }; o.y + ',' + o.x + ',' + p.x",
+        JSValue::from("3,3,1")
+    );
+}
+
+#[test]
+fn deleting_a_super_property_throws() {
+    assert_script_throws!(
+        "let p = { x: 1 }; let o = { __proto__: p, get y() { return delete super.x; } }; o.y",
+        "ReferenceError"
+    );
+    assert_script_eq!(
+        "let p = { x: 1 }; let o = { __proto__: p, get y() { try { delete super.x; } catch (e) {} return p.x; } }; o.y",
+        JSValue::from(1.0)
+    );
+}
+
+#[test]
+fn super_property_with_a_null_prototype_throws() {
+    assert_script_throws!(
+        "let o = { __proto__: null, get y() { return super.x; } }; o.y",
+        "TypeError"
+    );
+}
+
+#[test]
+fn super_property_outside_of_a_method_is_an_early_error() {
+    assert_script_throws!(
+        "function f() { return super.x; }",
+        "SyntaxError: 'super' keyword unexpected here"
+    );
+    assert_script_throws!(
+        "let o = { get y() { return function () { return super.x; }; } };",
+        "SyntaxError: 'super' keyword unexpected here"
+    );
+    assert_script_throws!("super.x", "SyntaxError: 'super' keyword unexpected here");
+}
@@ -0,0 +1,90 @@
+use glyn_interpreter::JSValue;
+
+mod common;
+
+#[test]
+fn templates_concatenate_their_substitutions() {
+    assert_script_eq!("`abc`", JSValue::from("abc"));
+    assert_script_eq!("`a${1}b${'c'}d`", JSValue::from("a1bcd"));
+    assert_script_eq!("`${ {a: 2}.a }${`${3}`}`", JSValue::from("23"));
+    assert_script_eq!(
+        "let o = { toString: function () { return 'x'; }, valueOf: function () { return 'y'; } }; `${o}`",
+        JSValue::from("x")
+    );
+    assert_script_throws!(
+        "let o = { toString: function () { return {}; }, valueOf: function () { return {}; } }; `${o}`",
+        "TypeError: "
+    );
+}
+
+#[test]
+fn templates_process_their_escape_sequences() {
+    assert_script_eq!(r"`\x41B\u{43}\0\``", JSValue::from("ABC\0`"));
+    assert_script_eq!("`a\\\nb\r\nc`", JSValue::from("ab\nc"));
+    assert_script_throws!(
+        r"`\unicode`",
+        "SyntaxError: Invalid escape sequence in template"
+    );
+    assert_script_throws!(r"`\01`", "SyntaxError: Invalid escape sequence in template");
+    assert_script_throws!("`a${1}", "SyntaxError: ");
+}
+
+#[test]
+fn tags_are_called_with_the_strings_and_substitutions() {
+    assert_script_eq!(
+        "function tag(strings, a, b) { return strings.length + strings[0] + a + strings[1] + b + strings[2]; } tag`x${1}y${2}z`",
+        JSValue::from("3x1y2z")
+    );
+    assert_script_eq!(
+        "function tag(strings) { return strings.raw[0] + '|' + strings[0]; } tag`a\\tb`",
+        JSValue::from("a\\tb|a\tb")
+    );
+    assert_script_eq!(
+        "function tag(strings) { return strings.raw[0]; } tag`a\r\nb\rc`",
+        JSValue::from("a\nb\nc")
+    );
+    assert_script_eq!(
+        "let o = {}; o.tag = function () { return this === o; }; o.tag`x`",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "function f() { return function (strings, a) { return a; }; } f()`${1}`",
+        JSValue::from(1.0)
+    );
+    assert_script_throws!(
+        "let o = {}; o?.tag`x`",
+        "SyntaxError: Tagged template cannot be used in optional chain"
+    );
+}
+
+#[test]
+fn invalid_escape_sequences_of_tagged_templates_are_undefined() {
+    assert_script_eq!(
+        r"function tag(strings) { return (strings[0] === undefined) + strings.raw[0]; } tag`\unicode`",
+        JSValue::from(r"true\unicode")
+    );
+}
+
+#[test]
+fn template_objects_are_frozen_and_cached_per_site() {
+    assert_script_eq!(
+        "function tag(strings) { return strings; } function f() { return tag`x`; } f() === f()",
+        JSValue::from(true)
+    );
+    assert_script_eq!(
+        "function tag(strings) { return strings; } tag`x` === tag`x`",
+        JSValue::from(false)
+    );
+    assert_script_eq!(
+        "function tag(strings) { let raw = Object.getOwnPropertyDescriptor(strings, 'raw'); return raw.writable + ' ' + raw.enumerable; } tag`x`",
+        JSValue::from("false false")
+    );
+    assert_script_throws!(
+        "'use strict'; function tag(strings) { strings[0] = 'y'; } tag`x`",
+        "TypeError: "
+    );
+    assert_script_throws!(
+        "'use strict'; function tag(strings) { strings.raw.x = 'y'; } tag`x`",
+        "TypeError: "
+    );
+}
@@ -0,0 +1,21 @@
+function assert(mustBeTrue, message) {
+  assert.sameValue(mustBeTrue, true, message);
+}
+
+assert.sameValue = function (actual, expected, message) {
+  ({
+    true: function () {},
+    false: function () {
+      throw new Test262Error('Expected SameValue(' + actual + ', ' + expected + ') to be true. ' + message);
+    }
+  })[actual === expected]();
+};
+
+assert.notSameValue = function (actual, unexpected, message) {
+  ({
+    true: function () {
+      throw new Test262Error('Expected SameValue(' + actual + ', ' + unexpected + ') to be false. ' + message);
+    },
+    false: function () {}
+  })[actual === unexpected]();
+};
@@ -0,0 +1,10 @@
+function $DONE(error) {
+  ({
+    true: function () {
+      print('Test262:AsyncTestComplete');
+    },
+    false: function () {
+      print('Test262:AsyncTestFailure:' + error);
+    }
+  })[error === undefined]();
+}
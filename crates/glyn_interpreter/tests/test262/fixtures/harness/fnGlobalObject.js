@@ -0,0 +1,3 @@
+function fnGlobalObject() {
+  return globalThis;
+}
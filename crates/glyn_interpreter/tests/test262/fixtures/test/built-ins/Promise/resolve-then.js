@@ -0,0 +1,9 @@
+/*---
+esid: sec-promise.resolve
+description: The reactions of a resolved promise are called with its value
+flags: [async]
+---*/
+
+Promise.resolve(1).then(function (value) {
+  assert.sameValue(value, 1, 'value');
+}).then($DONE, $DONE);
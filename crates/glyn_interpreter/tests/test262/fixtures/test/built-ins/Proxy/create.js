@@ -0,0 +1,7 @@
+/*---
+esid: sec-proxy-target-handler
+description: A proxy can be created
+features: [Proxy]
+---*/
+
+new Proxy({}, {});
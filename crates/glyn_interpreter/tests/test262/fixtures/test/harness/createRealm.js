@@ -0,0 +1,10 @@
+/*---
+description: $262.createRealm creates a realm with its own global object
+includes: [fnGlobalObject.js]
+---*/
+
+let other = $262.createRealm();
+
+assert.sameValue(other.evalScript('1 + 1'), 2, 'evalScript');
+assert.notSameValue(other.global, fnGlobalObject(), 'global');
+assert.sameValue($262.global, fnGlobalObject(), '$262.global');
@@ -0,0 +1,9 @@
+/*---
+description: A raw test is evaluated without the harness
+flags: [raw]
+negative:
+  phase: runtime
+  type: ReferenceError
+---*/
+
+assert;
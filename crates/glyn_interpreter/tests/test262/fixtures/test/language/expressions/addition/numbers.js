@@ -0,0 +1,6 @@
+/*---
+esid: sec-addition-operator-plus
+description: Adds two numbers
+---*/
+
+assert.sameValue(1 + 2, 3, '1 + 2');
@@ -0,0 +1,9 @@
+/*---
+esid: sec-property-accessors
+description: Accessing a property of undefined throws a TypeError
+negative:
+  phase: runtime
+  type: TypeError
+---*/
+
+undefined.x;
@@ -0,0 +1,12 @@
+/*---
+esid: sec-ordinarycallbindthis
+description: The this value of a function called without a base is undefined in strict mode code
+flags:
+  - onlyStrict
+---*/
+
+function f() {
+  return this;
+}
+
+assert.sameValue(f(), undefined, 'this');
@@ -0,0 +1,7 @@
+/*---
+esid: sec-exports
+description: A module can export a binding
+flags: [module]
+---*/
+
+export let x = 1;
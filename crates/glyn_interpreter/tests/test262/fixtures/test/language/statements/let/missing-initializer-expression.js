@@ -0,0 +1,11 @@
+/*---
+esid: sec-let-and-const-declarations
+description: An initializer must have an expression
+negative:
+  phase: parse
+  type: SyntaxError
+---*/
+
+$DONOTEVALUATE();
+
+let x = ;
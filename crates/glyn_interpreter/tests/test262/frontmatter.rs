@@ -0,0 +1,129 @@
+/// The phase in which a negative test expects an error to be thrown.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Phase {
+    Parse,
+    Resolution,
+    Runtime,
+}
+
+/// The error a negative test expects, from the `negative` key of its frontmatter.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Negative {
+    pub(crate) phase: Phase,
+    /// The name of the constructor of the expected error, such as `SyntaxError`.
+    pub(crate) error_type: String,
+}
+
+/// The metadata of a test262 test, read from the YAML between `/*---` and `---*/`.
+///
+/// Only the keys which decide how the test is run are read, and only the subset of YAML which test262 uses for them: inline lists like `[a, b]`, block lists of `- a` items, and the nested keys of `negative`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Frontmatter {
+    pub(crate) flags: Vec<String>,
+    pub(crate) includes: Vec<String>,
+    pub(crate) features: Vec<String>,
+    pub(crate) negative: Option<Negative>,
+}
+
+impl Frontmatter {
+    pub(crate) fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+}
+
+/// Parses the frontmatter of the test source, or returns an error if it has none or it is malformed.
+pub(crate) fn parse_frontmatter(source: &str) -> Result<Frontmatter, String> {
+    let start = source
+        .find("/*---")
+        .ok_or_else(|| String::from("missing frontmatter"))?;
+
+    let end = source[start..]
+        .find("---*/")
+        .ok_or_else(|| String::from("unterminated frontmatter"))?;
+
+    let yaml = &source[start + "/*---".len()..start + end];
+
+    let mut frontmatter = Frontmatter::default();
+
+    // The top level key whose indented lines are being read.
+    let mut key = "";
+    let mut phase = None;
+    let mut error_type = None;
+
+    for line in yaml.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if !line.starts_with(char::is_whitespace) {
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("invalid frontmatter line {line:?}"))?;
+
+            key = name.trim();
+
+            if let Some(list) = list_mut(&mut frontmatter, key) {
+                list.extend(parse_inline_list(value.trim()));
+            }
+
+            continue;
+        }
+
+        let line = line.trim();
+
+        if key == "negative" {
+            match line.split_once(':') {
+                Some(("phase", value)) => phase = Some(parse_phase(value.trim())?),
+                Some(("type", value)) => error_type = Some(value.trim().to_string()),
+                _ => {}
+            }
+        } else if let Some(item) = line.strip_prefix("- ") {
+            if let Some(list) = list_mut(&mut frontmatter, key) {
+                list.push(item.trim().to_string());
+            }
+        }
+    }
+
+    frontmatter.negative = match (phase, error_type) {
+        (Some(phase), Some(error_type)) => Some(Negative { phase, error_type }),
+        (None, None) => None,
+        _ => return Err(String::from("negative requires both a phase and a type")),
+    };
+
+    Ok(frontmatter)
+}
+
+/// The list of the frontmatter which the key holds, if it is one the runner reads.
+fn list_mut<'a>(frontmatter: &'a mut Frontmatter, key: &str) -> Option<&'a mut Vec<String>> {
+    match key {
+        "flags" => Some(&mut frontmatter.flags),
+        "includes" => Some(&mut frontmatter.includes),
+        "features" => Some(&mut frontmatter.features),
+        _ => None,
+    }
+}
+
+/// The items of a list like `[a, b]`, which is empty for any other value.
+fn parse_inline_list(value: &str) -> Vec<String> {
+    value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+        .map(|items| {
+            items
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_phase(value: &str) -> Result<Phase, String> {
+    match value {
+        "parse" => Ok(Phase::Parse),
+        "resolution" => Ok(Phase::Resolution),
+        "runtime" => Ok(Phase::Runtime),
+        _ => Err(format!("unknown negative phase {value:?}")),
+    }
+}
@@ -0,0 +1,71 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glyn_interpreter::{
+    eval_script_in_realm, Args, EvalError, JSAgent, JSRealm, JSValue, ThrowCompletion,
+};
+
+/// The lines a test printed with `print`, shared by every realm the test creates.
+pub(crate) type Output = Rc<RefCell<Vec<String>>>;
+
+/// Defines the `$262` object, which the `createRealm` and `evalScript` methods forward to the host functions of the realm.
+const HOST_OBJECT: &str = "
+globalThis.$262 = {
+  global: globalThis,
+  createRealm: function () { return $262createRealm(); },
+  evalScript: function (source) { return $262evalScript(source); },
+  agent: {}
+};
+$262";
+
+/// Defines the host functions which the test262 harness requires in the realm, which are `print` and the `$262` object, returning the `$262` object.
+pub(crate) fn install_harness(
+    agent: &mut JSAgent,
+    realm: &JSRealm,
+    output: &Output,
+) -> Result<JSValue, EvalError> {
+    let print_output = output.clone();
+
+    agent.register_function_in_realm(realm, "print", 1, move |agent: &mut JSAgent, args: Args| {
+        let line = args.get(0).to_display_string(agent)?;
+
+        print_output.borrow_mut().push(line);
+
+        Ok(JSValue::Undefined)
+    });
+
+    let realm_output = output.clone();
+
+    agent.register_function_in_realm(
+        realm,
+        "$262createRealm",
+        0,
+        move |agent: &mut JSAgent, _: Args| {
+            let realm = agent.create_realm();
+
+            install_harness(agent, &realm, &realm_output).map_err(rethrow)
+        },
+    );
+
+    let script_realm = realm.clone();
+
+    agent.register_function_in_realm(
+        realm,
+        "$262evalScript",
+        1,
+        move |agent: &mut JSAgent, args: Args| {
+            let source = args.get_string(0)?;
+
+            eval_script_in_realm(agent, &script_realm, &source).map_err(rethrow)
+        },
+    );
+
+    eval_script_in_realm(agent, realm, HOST_OBJECT)
+}
+
+/// Throws the error of a nested evaluation into the script which started it.
+fn rethrow(error: EvalError) -> ThrowCompletion {
+    match error {
+        EvalError::UncaughtException { value, .. } => ThrowCompletion::from(value),
+        error => ThrowCompletion::from(JSValue::from(error.to_string())),
+    }
+}
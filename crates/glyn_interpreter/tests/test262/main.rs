@@ -0,0 +1,95 @@
+//! A runner for the test262 conformance suite, which reads the frontmatter of each test to decide how it is run and whether it is expected to throw.
+//!
+//! The suite is run from a checkout of test262 with `TEST262_DIR=path/to/test262 cargo test --test test262 -- --ignored --nocapture`, optionally with `TEST262_FILTER` set to run only the tests whose paths contain it. Otherwise only the fixtures alongside the runner are run, whose harness files are small stand-ins for those of test262 written in the subset of the language the engine supports so far.
+
+mod frontmatter;
+mod harness;
+mod runner;
+
+use std::env;
+
+use frontmatter::{parse_frontmatter, Frontmatter, Negative, Phase};
+use runner::{Outcome, Runner};
+
+fn fixtures() -> Runner {
+    Runner::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/test262/fixtures"
+    ))
+}
+
+#[test]
+fn fixtures_are_run() {
+    let summary = fixtures().run_all(None);
+
+    assert_eq!(summary.failed, vec![], "{}", summary.report());
+    assert_eq!((summary.passed, summary.skipped), (7, 2));
+
+    let summary = fixtures().run_all(Some("language/expressions"));
+
+    assert_eq!(summary.report(), "2 passed, 0 failed, 0 skipped");
+}
+
+#[test]
+#[ignore = "requires a checkout of test262 at TEST262_DIR"]
+fn test262() {
+    let root = env::var("TEST262_DIR").expect("TEST262_DIR must be the root of a test262 checkout");
+    let filter = env::var("TEST262_FILTER").ok();
+
+    println!("{}", Runner::new(root).run_all(filter.as_deref()).report());
+}
+
+#[test]
+fn frontmatter_is_parsed() {
+    assert_eq!(
+        parse_frontmatter(
+            "/*---\ndescription: |\n  flags: [module]\nflags: [onlyStrict, async]\nincludes:\n  - compareArray.js\nfeatures: [Symbol]\nnegative:\n  phase: runtime\n  type: TypeError\n---*/\n"
+        ),
+        Ok(Frontmatter {
+            flags: vec![String::from("onlyStrict"), String::from("async")],
+            includes: vec![String::from("compareArray.js")],
+            features: vec![String::from("Symbol")],
+            negative: Some(Negative {
+                phase: Phase::Runtime,
+                error_type: String::from("TypeError"),
+            }),
+        })
+    );
+    assert_eq!(
+        parse_frontmatter("/*---\n---*/"),
+        Ok(Frontmatter::default())
+    );
+    assert!(parse_frontmatter("1;").is_err());
+    assert!(parse_frontmatter("/*---\nnegative:\n  phase: parse\n---*/").is_err());
+    assert!(parse_frontmatter("/*---\nnegative:\n  phase: later\n  type: Error\n---*/").is_err());
+}
+
+#[test]
+fn failures_are_reported() {
+    let runner = fixtures();
+
+    assert!(matches!(
+        runner.run_test("/*---\n---*/\nassert.sameValue(1, 2, 'one');"),
+        Outcome::Fail(reason) if reason.starts_with("Test262Error: Expected SameValue(1, 2) to be true. one")
+    ));
+    assert_eq!(
+        runner.run_test("/*---\nnegative:\n  phase: runtime\n  type: TypeError\n---*/\n1;"),
+        Outcome::Fail(String::from(
+            "expected a TypeError to be thrown, but the test completed (in sloppy mode)"
+        ))
+    );
+    assert_eq!(
+        runner.run_test("/*---\nflags: [async]\n---*/\n1;"),
+        Outcome::Fail(String::from("$DONE was not called (in sloppy mode)"))
+    );
+    assert_eq!(
+        runner.run_test("/*---\nflags: [async]\n---*/\n$DONE('nope');"),
+        Outcome::Fail(String::from(
+            "Test262:AsyncTestFailure:nope (in sloppy mode)"
+        ))
+    );
+    assert!(matches!(
+        runner.run_test("/*---\nincludes: [missing.js]\n---*/\n"),
+        Outcome::Fail(reason) if reason.starts_with("cannot read the harness file missing.js")
+    ));
+}
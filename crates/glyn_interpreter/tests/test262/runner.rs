@@ -0,0 +1,284 @@
+use std::{
+    fmt::Write,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use glyn_interpreter::{eval_script_in_realm, EvalError, JSAgent};
+
+use crate::{
+    frontmatter::{parse_frontmatter, Frontmatter, Phase},
+    harness::{install_harness, Output},
+};
+
+/// The features of test262 which the engine does not support yet, whose tests are skipped rather than failed.
+const UNSUPPORTED_FEATURES: &[&str] = &[
+    "Atomics",
+    "FinalizationRegistry",
+    "Proxy",
+    "Reflect",
+    "SharedArrayBuffer",
+    "Symbol",
+    "Temporal",
+    "TypedArray",
+    "WeakRef",
+    "arrow-function",
+    "class",
+];
+
+/// The number of instructions a test can execute before it fails, so that a test which never finishes does not stall the run.
+const FUEL: u64 = 10_000_000;
+
+/// The result of running a test.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Outcome {
+    Pass,
+    Fail(String),
+    Skip(String),
+}
+
+/// The outcomes of the tests which were run.
+#[derive(Debug, Default)]
+pub(crate) struct Summary {
+    pub(crate) passed: usize,
+    /// The path of each test which failed, along with why it failed.
+    pub(crate) failed: Vec<(String, String)>,
+    pub(crate) skipped: usize,
+}
+
+impl Summary {
+    fn record(&mut self, name: String, outcome: Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Fail(reason) => self.failed.push((name, reason)),
+            Outcome::Skip(_) => self.skipped += 1,
+        }
+    }
+
+    /// A report of the failures followed by the count of each outcome, like `12 passed, 3 failed, 4 skipped`.
+    pub(crate) fn report(&self) -> String {
+        let mut report = String::new();
+
+        for (name, reason) in &self.failed {
+            let _ = writeln!(report, "FAIL {name}: {reason}");
+        }
+
+        let _ = write!(
+            report,
+            "{} passed, {} failed, {} skipped",
+            self.passed,
+            self.failed.len(),
+            self.skipped
+        );
+
+        report
+    }
+}
+
+/// Runs the tests of a directory laid out like test262, with the harness files in `harness` and the tests in `test`.
+pub(crate) struct Runner {
+    root: PathBuf,
+}
+
+impl Runner {
+    pub(crate) fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Runs every test whose path relative to the `test` directory contains the filter, in order of their paths.
+    pub(crate) fn run_all(&self, filter: Option<&str>) -> Summary {
+        let test_dir = self.root.join("test");
+
+        let mut paths = vec![];
+
+        collect_tests(&test_dir, &mut paths);
+
+        paths.sort();
+
+        let mut summary = Summary::default();
+
+        // NOTE: A test which panics is failed rather than ending the run, so the message of the panic is recorded instead of being printed.
+        let hook = panic::take_hook();
+
+        panic::set_hook(Box::new(|_| {}));
+
+        for path in paths {
+            let name = path
+                .strip_prefix(&test_dir)
+                .unwrap_or(&path)
+                .display()
+                .to_string();
+
+            if filter.is_some_and(|filter| !name.contains(filter)) {
+                continue;
+            }
+
+            let outcome = match fs::read_to_string(&path) {
+                Ok(source) => self.run_test(&source),
+                Err(error) => Outcome::Fail(format!("cannot read the test: {error}")),
+            };
+
+            summary.record(name, outcome);
+        }
+
+        panic::set_hook(hook);
+
+        summary
+    }
+
+    /// Runs the test with the source, in sloppy mode, strict mode or both as its flags require.
+    pub(crate) fn run_test(&self, source: &str) -> Outcome {
+        let frontmatter = match parse_frontmatter(source) {
+            Ok(frontmatter) => frontmatter,
+            Err(error) => return Outcome::Fail(error),
+        };
+
+        if frontmatter.has_flag("module") {
+            return Outcome::Skip(String::from("modules are not run yet"));
+        }
+
+        if let Some(feature) = frontmatter
+            .features
+            .iter()
+            .find(|feature| UNSUPPORTED_FEATURES.contains(&feature.as_str()))
+        {
+            return Outcome::Skip(format!("{feature} is not supported"));
+        }
+
+        let harness = match self.harness_source(&frontmatter) {
+            Ok(harness) => harness,
+            Err(error) => return Outcome::Fail(error),
+        };
+
+        let modes: &[bool] = if frontmatter.has_flag("onlyStrict") {
+            &[true]
+        } else if frontmatter.has_flag("noStrict") || frontmatter.has_flag("raw") {
+            &[false]
+        } else {
+            &[false, true]
+        };
+
+        for &strict in modes {
+            let prefix = if strict { "'use strict';\n" } else { "" };
+            let source = format!("{prefix}{harness}{source}");
+
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| run_script(&frontmatter, &source)))
+                    .unwrap_or_else(|payload| {
+                        let message = payload
+                            .downcast_ref::<&str>()
+                            .map(|message| message.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_default();
+
+                        Err(format!("panicked: {message}"))
+                    });
+
+            if let Err(reason) = result {
+                let mode = if strict { "strict" } else { "sloppy" };
+
+                return Outcome::Fail(format!("{reason} (in {mode} mode)"));
+            }
+        }
+
+        Outcome::Pass
+    }
+
+    /// The source of the harness files the test includes, which are `assert.js` and `sta.js` along with those listed in its frontmatter, unless it is a raw test.
+    fn harness_source(&self, frontmatter: &Frontmatter) -> Result<String, String> {
+        if frontmatter.has_flag("raw") {
+            return Ok(String::new());
+        }
+
+        let mut includes = vec!["assert.js", "sta.js"];
+
+        if frontmatter.has_flag("async") {
+            includes.push("doneprintHandle.js");
+        }
+
+        includes.extend(frontmatter.includes.iter().map(String::as_str));
+
+        includes
+            .iter()
+            .try_fold(String::new(), |mut harness, include| {
+                let path = self.root.join("harness").join(include);
+
+                let source = fs::read_to_string(&path)
+                    .map_err(|error| format!("cannot read the harness file {include}: {error}"))?;
+
+                harness.push_str(&source);
+                harness.push('\n');
+
+                Ok(harness)
+            })
+    }
+}
+
+/// Evaluates the source of a test in a new agent, then checks the result against what the frontmatter expects.
+fn run_script(frontmatter: &Frontmatter, source: &str) -> Result<(), String> {
+    let mut agent = JSAgent::default();
+
+    agent.set_fuel(Some(FUEL));
+
+    let realm = agent.create_realm();
+    let output = Output::default();
+
+    install_harness(&mut agent, &realm, &output)
+        .map_err(|error| format!("cannot install the harness: {error}"))?;
+
+    let result = eval_script_in_realm(&mut agent, &realm, source).and_then(|_| agent.run_jobs());
+
+    match (&frontmatter.negative, result) {
+        (None, Ok(())) if frontmatter.has_flag("async") => {
+            let output = output.borrow();
+
+            match output.iter().find(|line| line.starts_with("Test262:Async")) {
+                Some(line) if line == "Test262:AsyncTestComplete" => Ok(()),
+                Some(line) => Err(line.clone()),
+                None => Err(String::from("$DONE was not called")),
+            }
+        }
+        (None, Ok(())) => Ok(()),
+        (None, Err(error)) => Err(error.to_string()),
+        (Some(negative), Ok(())) => Err(format!(
+            "expected a {} to be thrown, but the test completed",
+            negative.error_type
+        )),
+        (Some(negative), Err(error)) => {
+            let thrown_type = match &error {
+                EvalError::SyntaxError(_) if negative.phase == Phase::Parse => "SyntaxError",
+                EvalError::UncaughtException { message, .. } if negative.phase != Phase::Parse => {
+                    message.split(':').next().unwrap_or_default()
+                }
+                _ => "",
+            };
+
+            if thrown_type == negative.error_type {
+                Ok(())
+            } else {
+                Err(format!(
+                    "expected a {} to be thrown, but got {error}",
+                    negative.error_type
+                ))
+            }
+        }
+    }
+}
+
+/// Adds the paths of the test files in the directory and its subdirectories, leaving out the fixtures which tests import.
+fn collect_tests(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_tests(&path, paths);
+        } else if path.extension().is_some_and(|extension| extension == "js")
+            && !path.to_string_lossy().contains("_FIXTURE")
+        {
+            paths.push(path);
+        }
+    }
+}
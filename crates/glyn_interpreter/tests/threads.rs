@@ -0,0 +1,57 @@
+use std::{
+    sync::{Arc, Barrier},
+    thread,
+};
+
+use glyn_interpreter::{eval_script, Args, JSAgent, JSValue};
+
+const THREADS: usize = 8;
+
+#[test]
+fn agents_on_multiple_threads() {
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let handles = (0..THREADS)
+        .map(|index| {
+            let barrier = barrier.clone();
+
+            thread::spawn(move || {
+                let mut agent = JSAgent::default();
+
+                agent.register_function("offset", 0, move |_: &mut JSAgent, _: Args| {
+                    Ok(JSValue::from(index as f64))
+                });
+
+                // NOTE: Every thread evaluates its scripts at the same time, so any state shared between agents would be contended.
+                barrier.wait();
+
+                let source = "
+                    let a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+                    function* g() { for (let x of a) { yield x; } }
+                    let s = 0;
+                    for (let x of a) { for (let y of g()) { s = s + y; } }
+                    s + offset()
+                ";
+
+                // NOTE: Values are confined to the thread of their agent, so the result is compared before the thread ends.
+                let result = eval_script(&mut agent, source)
+                    .map(|value| value == JSValue::from(450.0 + index as f64))
+                    .map_err(|error| error.to_string());
+
+                (result, agent.heap_stats())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let results = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+
+    for (result, stats) in &results {
+        assert_eq!(*result, Ok(true));
+
+        // NOTE: Each agent allocates from its own nursery, so the same script makes the same allocations on every thread.
+        assert_eq!(*stats, results[0].1);
+    }
+}
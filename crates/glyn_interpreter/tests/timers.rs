@@ -0,0 +1,132 @@
+use glyn_interpreter::{eval_script, JSValue};
+
+mod common;
+
+#[test]
+fn timers_run_once_they_are_due_in_order() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        setTimeout(function () { log = log + "c"; }, 20);
+        setTimeout(function () { log = log + "a"; }, 10);
+        setTimeout(function () { log = log + "b"; }, 10);
+        "#,
+    );
+
+    assert_eq!(agent.next_timer_due(), Some(10.0));
+    assert_eq!(agent.run_due_timers(5.0), Ok(0));
+    assert_eq!(agent.run_due_timers(10.0), Ok(2));
+    assert_eq!(log!(&mut agent), JSValue::from("ab"));
+    assert_eq!(agent.run_due_timers(30.0), Ok(1));
+    assert_eq!(log!(&mut agent), JSValue::from("abc"));
+    assert_eq!(agent.next_timer_due(), None);
+}
+
+#[test]
+fn timers_are_passed_their_arguments() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        setTimeout(function (a, b) { log = a + b; }, -5, "x", "y");
+        "#,
+    );
+
+    assert_eq!(agent.run_due_timers(0.0), Ok(1));
+    assert_eq!(log!(&mut agent), JSValue::from("xy"));
+}
+
+#[test]
+fn cleared_timers_do_not_run() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        let second = 0;
+        setTimeout(function () { log = log + "a"; clearTimeout(second); }, 10);
+        second = setTimeout(function () { log = log + "b"; }, 10);
+        clearTimeout(setTimeout(function () { log = log + "c"; }, 10));
+        clearTimeout(1000);
+        "#,
+    );
+
+    assert_eq!(agent.run_due_timers(10.0), Ok(1));
+    assert_eq!(log!(&mut agent), JSValue::from("a"));
+}
+
+#[test]
+fn intervals_repeat_until_cleared() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        let id = setInterval(function () {
+            log = log + "i";
+        }, 10);
+        setTimeout(function () {
+            clearInterval(id);
+        }, 25);
+        "#,
+    );
+
+    assert_eq!(agent.run_due_timers(10.0), Ok(1));
+    assert_eq!(agent.next_timer_due(), Some(20.0));
+    assert_eq!(agent.run_due_timers(20.0), Ok(1));
+    assert_eq!(agent.run_due_timers(30.0), Ok(1));
+    assert_eq!(agent.next_timer_due(), None);
+    assert_eq!(log!(&mut agent), JSValue::from("ii"));
+}
+
+#[test]
+fn timers_scheduled_by_callbacks_wait_for_the_next_run() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        setTimeout(function () {
+            log = log + "a";
+            setTimeout(function () { log = log + "b"; }, 0);
+        }, 0);
+        "#,
+    );
+
+    assert_eq!(agent.run_due_timers(0.0), Ok(1));
+    assert_eq!(agent.run_due_timers(0.0), Ok(1));
+    assert_eq!(log!(&mut agent), JSValue::from("ab"));
+}
+
+#[test]
+fn promise_jobs_run_after_each_timer() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        setTimeout(function () {
+            log = log + "a";
+            Promise.resolve().then(function () { log = log + "p"; });
+        }, 0);
+        setTimeout(function () { log = log + "b"; }, 0);
+        "#,
+    );
+
+    assert_eq!(agent.run_due_timers(0.0), Ok(2));
+    assert_eq!(log!(&mut agent), JSValue::from("apb"));
+}
+
+#[test]
+fn errors_leave_the_remaining_timers_pending() {
+    let mut agent = agent_with_timers!(
+        r#"
+        let log = "";
+        setTimeout(function () { throw "boom"; }, 0);
+        setTimeout(function () { log = log + "b"; }, 0);
+        "#,
+    );
+
+    assert!(agent.run_due_timers(0.0).is_err());
+    assert_eq!(agent.run_due_timers(0.0), Ok(1));
+    assert_eq!(log!(&mut agent), JSValue::from("b"));
+}
+
+#[test]
+fn handlers_must_be_functions() {
+    let mut agent = agent_with_timers!("let log = \"\";");
+
+    assert!(eval_script(&mut agent, "setTimeout(\"log = 1\", 0);").is_err());
+    assert_eq!(agent.next_timer_due(), None);
+}
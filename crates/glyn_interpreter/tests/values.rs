@@ -0,0 +1,169 @@
+use glyn_interpreter::{eval_script, Args, JSAgent, JSNumber, JSString, JSValue, ObjectAddr};
+
+fn display(agent: &mut JSAgent, source: &str) -> String {
+    let value = eval_script(agent, source).unwrap();
+
+    value.to_display_string(agent).unwrap()
+}
+
+#[test]
+fn construction() {
+    let mut agent = JSAgent::default();
+
+    assert!(JSValue::undefined().is_undefined());
+    assert!(JSValue::null().is_null());
+    assert_eq!(
+        JSValue::from("abc").to_rust_string().as_deref(),
+        Some("abc")
+    );
+    assert_eq!(JSValue::from(1.5).as_f64(), Some(1.5));
+    assert_eq!(JSValue::from("abc").as_f64(), None);
+    assert_eq!(JSValue::from(1.5).to_rust_string(), None);
+
+    assert_eq!(eval_script(&mut agent, "'abc'"), Ok(JSValue::from("abc")));
+}
+
+#[test]
+fn inspection() {
+    let mut agent = JSAgent::default();
+
+    assert!(eval_script(&mut agent, "({})").unwrap().is_object());
+    assert!(eval_script(&mut agent, "[]").unwrap().is_object());
+    assert!(!eval_script(&mut agent, "'{}'").unwrap().is_object());
+    assert!(eval_script(&mut agent, "1 + 1").unwrap().is_number());
+    assert!(eval_script(&mut agent, "'a' + 1").unwrap().is_string());
+    assert!(eval_script(&mut agent, "1 === 1").unwrap().is_boolean());
+}
+
+#[test]
+fn payloads_can_be_named() {
+    let mut agent = JSAgent::default();
+
+    let string: Option<JSString> = match eval_script(&mut agent, "'a' + 'b'").unwrap() {
+        JSValue::String(string) => Some(string),
+        _ => None,
+    };
+    let number: Option<JSNumber> = match eval_script(&mut agent, "1 + 1").unwrap() {
+        JSValue::Number(number) => Some(number),
+        _ => None,
+    };
+    let object: Option<ObjectAddr> = match eval_script(&mut agent, "({})").unwrap() {
+        JSValue::Object(object) => Some(object),
+        _ => None,
+    };
+
+    assert_eq!(
+        string.map(|string| string.to_string()).as_deref(),
+        Some("ab")
+    );
+    assert_eq!(number.map(|number| number.0), Some(2.0));
+    assert!(object.is_some());
+}
+
+#[test]
+fn display_strings() {
+    let mut agent = JSAgent::default();
+
+    assert_eq!(display(&mut agent, "undefined"), "undefined");
+    assert_eq!(display(&mut agent, "null"), "null");
+    assert_eq!(display(&mut agent, "1 / 2"), "0.5");
+    assert_eq!(display(&mut agent, "-1 / 0"), "-Infinity");
+    assert_eq!(display(&mut agent, "'abc'"), "abc");
+    assert_eq!(display(&mut agent, "1 === 1"), "true");
+    assert_eq!(
+        display(
+            &mut agent,
+            "({ toString: function () { return 'custom'; } })"
+        ),
+        "custom"
+    );
+
+    let throwing =
+        eval_script(&mut agent, "({ toString: function () { throw 'nope'; } })").unwrap();
+
+    let error = throwing.to_display_string(&mut agent).unwrap_err();
+
+    assert_eq!(
+        error.into_value(&mut agent).to_rust_string().as_deref(),
+        Some("nope")
+    );
+}
+
+#[test]
+fn inspected_renderings() {
+    let mut agent = JSAgent::default();
+
+    let mut inspect = |source: &str| eval_script(&mut agent, source).unwrap().inspect();
+
+    assert_eq!(inspect("undefined"), "undefined");
+    assert_eq!(inspect("1 / 2"), "0.5");
+    assert_eq!(inspect("'a\\b'"), "'a\\\\b'");
+    assert_eq!(inspect("({})"), "{}");
+    assert_eq!(
+        inspect("({ a: 1, 'b-c': [1, 'x', true], d: { e: null } })"),
+        "{ a: 1, 'b-c': [ 1, 'x', true ], d: { e: null } }"
+    );
+    assert_eq!(
+        inspect("({ a: { b: { c: { d: 1 } } } })"),
+        "{ a: { b: { c: [Object] } } }"
+    );
+    assert_eq!(
+        inspect("function f() {}\n({ f: f, g: function () {} })"),
+        "{ f: [Function: f], g: [Function (anonymous)] }"
+    );
+    assert_eq!(inspect("({ get a() { return 1; } })"), "{ a: [Getter] }");
+    assert_eq!(inspect("let o = {}; o.self = o; o"), "{ self: [Circular] }");
+    assert_eq!(inspect("new Error('boom')"), "Error: boom\n    at 1:1");
+    assert_eq!(
+        inspect("({ stack: 'not an error', a: 1 })"),
+        "{ stack: 'not an error', a: 1 }"
+    );
+    assert_eq!(inspect("[1, , 3]"), "[ 1, <1 empty item>, 3 ]");
+    assert_eq!(
+        inspect("[, , 1, , , ]"),
+        "[ <2 empty items>, 1, <2 empty items> ]"
+    );
+    assert_eq!(
+        inspect("let a = [1]; a.length = 3; a.x = 2; a"),
+        "[ 1, <2 empty items>, x: 2 ]"
+    );
+    assert_eq!(inspect("[, ]"), "[ <1 empty item> ]");
+}
+
+#[test]
+fn values_passed_to_native_functions() {
+    let mut agent = JSAgent::default();
+
+    agent.register_function("describe", 1, |agent: &mut JSAgent, args: Args| {
+        let value = args.get(0);
+
+        let kind = if value.is_undefined() {
+            "undefined"
+        } else if value.is_object() {
+            "object"
+        } else {
+            "primitive"
+        };
+
+        Ok(JSValue::from(format!(
+            "{kind}:{}",
+            value.to_display_string(agent)?
+        )))
+    });
+
+    assert_eq!(
+        eval_script(&mut agent, "describe()"),
+        Ok(JSValue::from("undefined:undefined"))
+    );
+    assert_eq!(
+        eval_script(
+            &mut agent,
+            "describe({ toString: function () { return 'o'; } })"
+        ),
+        Ok(JSValue::from("object:o"))
+    );
+    assert_eq!(
+        eval_script(&mut agent, "describe(2)"),
+        Ok(JSValue::from("primitive:2"))
+    );
+}
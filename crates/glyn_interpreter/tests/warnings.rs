@@ -0,0 +1,70 @@
+use glyn_interpreter::{eval_script, JSAgent, JSValue, Warning, WarningKind};
+
+fn eval_warnings(source: &str) -> Vec<Warning> {
+    let mut agent = JSAgent::default();
+
+    let _ = eval_script(&mut agent, source);
+
+    agent.take_warnings()
+}
+
+#[test]
+fn no_warnings() {
+    assert_eq!(eval_warnings("let x = 10; x + 0"), vec![]);
+}
+
+#[test]
+fn legacy_octal_literal() {
+    assert_eq!(
+        eval_warnings("let x = 017;"),
+        vec![Warning {
+            kind: WarningKind::LegacyOctalLiteral,
+            start: 8,
+            end: 11,
+        }]
+    );
+
+    let mut agent = JSAgent::default();
+
+    assert_eq!(eval_script(&mut agent, "017"), Ok(JSValue::from(15.0)));
+    assert_eq!(eval_script(&mut agent, "019"), Ok(JSValue::from(19.0)));
+    assert_eq!(agent.take_warnings().len(), 2);
+    assert_eq!(agent.take_warnings(), vec![]);
+}
+
+#[test]
+fn legacy_octal_escape_sequence() {
+    assert_eq!(
+        eval_warnings("let x = '\\01';"),
+        vec![Warning {
+            kind: WarningKind::LegacyOctalEscapeSequence,
+            start: 8,
+            end: 13,
+        }]
+    );
+    assert_eq!(eval_warnings("let x = '\\0';"), vec![]);
+}
+
+#[test]
+fn with_statement() {
+    let warnings = eval_warnings("with (o) {}");
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::WithStatement);
+    assert_eq!(warnings[0].start, 0);
+}
+
+#[test]
+fn const_assignment() {
+    let warnings = eval_warnings("const c = 1; function f() { c = 2; }");
+
+    assert_eq!(
+        warnings,
+        vec![Warning {
+            kind: WarningKind::ConstAssignment,
+            start: 28,
+            end: 31,
+        }]
+    );
+    assert_eq!(eval_warnings("const c = 1; { let c = 2; c = 3; }"), vec![]);
+}
@@ -0,0 +1,103 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glyn_interpreter::{eval_script, JSAgent, JSValue, WatchEvent, WatchTarget};
+
+fn record(agent: &mut JSAgent, target: WatchTarget) -> Rc<RefCell<Vec<WatchEvent>>> {
+    let events = Rc::new(RefCell::new(vec![]));
+
+    let recorded_events = events.clone();
+
+    agent.add_watchpoint(target, move |event| {
+        recorded_events.borrow_mut().push(event.clone())
+    });
+
+    events
+}
+
+#[test]
+fn binding_writes() {
+    let mut agent = JSAgent::default();
+
+    let events = record(&mut agent, WatchTarget::Binding("x".into()));
+
+    let source = "let x = 1; let y = 2; x = x + y;";
+
+    assert!(eval_script(&mut agent, source).is_ok());
+
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            WatchEvent {
+                old_value: JSValue::Undefined,
+                new_value: JSValue::from(1.0),
+                span: Some(0..10),
+            },
+            WatchEvent {
+                old_value: JSValue::from(1.0),
+                new_value: JSValue::from(3.0),
+                span: Some(22..32),
+            },
+        ]
+    );
+}
+
+#[test]
+fn local_binding_writes() {
+    let mut agent = JSAgent::default();
+
+    let events = record(&mut agent, WatchTarget::Binding("a".into()));
+
+    assert!(eval_script(&mut agent, "function f(a) { a = 2; } f(1);").is_ok());
+
+    assert_eq!(events.borrow().len(), 1);
+    assert_eq!(events.borrow()[0].old_value, JSValue::from(1.0));
+    assert_eq!(events.borrow()[0].new_value, JSValue::from(2.0));
+    assert_eq!(events.borrow()[0].span, Some(16..22));
+}
+
+#[test]
+fn property_writes() {
+    let mut agent = JSAgent::default();
+
+    let object = eval_script(&mut agent, "let o = { a: 1, b: 2 }; o").unwrap();
+
+    let events = record(&mut agent, WatchTarget::Property(object, "a".into()));
+
+    assert!(eval_script(&mut agent, "o.b = 3; o.a = 4; o['a'] = 5;").is_ok());
+
+    let new_values = events
+        .borrow()
+        .iter()
+        .map(|event| (event.old_value.clone(), event.new_value.clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        new_values,
+        vec![
+            (JSValue::from(1.0), JSValue::from(4.0)),
+            (JSValue::from(4.0), JSValue::from(5.0)),
+        ]
+    );
+}
+
+#[test]
+fn remove_watchpoint() {
+    let mut agent = JSAgent::default();
+
+    let events = Rc::new(RefCell::new(0));
+
+    let count = events.clone();
+
+    let id = agent.add_watchpoint(WatchTarget::Binding("x".into()), move |_| {
+        *count.borrow_mut() += 1
+    });
+
+    assert!(eval_script(&mut agent, "let x = 1;").is_ok());
+
+    assert!(agent.remove_watchpoint(id));
+    assert!(!agent.remove_watchpoint(id));
+
+    assert!(eval_script(&mut agent, "x = 2;").is_ok());
+
+    assert_eq!(*events.borrow(), 1);
+}
@@ -0,0 +1,210 @@
+//! Attributes which expose a Rust type to scripts as a class, by implementing `glyn_interpreter::JSClass` for it.
+//!
+//! ```ignore
+//! struct Counter {
+//!     count: f64,
+//! }
+//!
+//! #[js_class]
+//! impl Counter {
+//!     #[js_method(constructor, length = 1)]
+//!     fn new(_agent: &mut JSAgent, args: Args) -> Result<Self, ThrowCompletion> {
+//!         Ok(Counter { count: args.get_f64(0)? })
+//!     }
+//!
+//!     #[js_method]
+//!     fn increment(&mut self, _agent: &mut JSAgent, _args: Args) -> Result<f64, ThrowCompletion> {
+//!         self.count += 1.0;
+//!         Ok(self.count)
+//!     }
+//! }
+//!
+//! agent.register_class::<Counter>();
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, parse_macro_input, spanned::Spanned, Attribute, Error, ImplItem,
+    ItemImpl, LitInt, LitStr, Type,
+};
+
+/// Implements `JSClass` for the type of an `impl` block, exposing the functions marked with `#[js_method]` to scripts.
+///
+/// The class is named after the type unless it is given a name with `#[js_class(name = "...")]`.
+///
+/// The function marked with `#[js_method(constructor)]` creates the value of each instance from the arguments `new` is called with, and must take a `&mut JSAgent` and `Args` and return `Result<Self, ThrowCompletion>`. Every other marked function is a method of the prototype, which must take `&self` or `&mut self` along with a `&mut JSAgent` and `Args`, and return `Result<T, ThrowCompletion>` for any `T` which a `JSValue` can be created from. A method is named after its function in lower camel case unless it is given a name, and the number of arguments it expects is given with `length`.
+#[proc_macro_attribute]
+pub fn js_class(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut name = None;
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("expected `name = \"...\"`"))
+        }
+    });
+
+    parse_macro_input!(attr with parser);
+
+    let item = parse_macro_input!(item as ItemImpl);
+
+    expand_class(item, name)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Marks a function of a `#[js_class]` impl block as the constructor or a method of the class, see [`macro@js_class`].
+#[proc_macro_attribute]
+pub fn js_method(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = proc_macro2::TokenStream::from(item);
+
+    Error::new(
+        item.span(),
+        "#[js_method] can only be used in a #[js_class] impl block",
+    )
+    .into_compile_error()
+    .into()
+}
+
+/// The arguments of a `#[js_method]` attribute.
+#[derive(Default)]
+struct MethodOptions {
+    constructor: bool,
+    name: Option<String>,
+    length: usize,
+}
+
+impl MethodOptions {
+    fn parse(attr: &Attribute) -> syn::Result<Self> {
+        let mut options = MethodOptions::default();
+
+        // NOTE: A bare `#[js_method]` has no arguments to parse.
+        if matches!(attr.meta, syn::Meta::Path(_)) {
+            return Ok(options);
+        }
+
+        attr.parse_nested_meta(|meta: ParseNestedMeta| {
+            if meta.path.is_ident("constructor") {
+                options.constructor = true;
+            } else if meta.path.is_ident("name") {
+                options.name = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("length") {
+                options.length = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+            } else {
+                return Err(meta.error("expected `constructor`, `name = \"...\"` or `length = N`"));
+            }
+
+            Ok(())
+        })?;
+
+        Ok(options)
+    }
+}
+
+fn expand_class(mut item: ItemImpl, name: Option<String>) -> syn::Result<proc_macro2::TokenStream> {
+    let self_ty = item.self_ty.clone();
+
+    let name = match (name, &*self_ty) {
+        (Some(name), _) => name,
+        (None, Type::Path(path)) => path.path.segments.last().unwrap().ident.to_string(),
+        (None, ty) => {
+            return Err(Error::new(
+                ty.span(),
+                "the class must be given a name with #[js_class(name = \"...\")]",
+            ))
+        }
+    };
+
+    let mut constructor = None;
+    let mut methods = vec![];
+
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(function) = impl_item else {
+            continue;
+        };
+
+        let Some(index) = function
+            .attrs
+            .iter()
+            .position(|attr| attr.path().is_ident("js_method"))
+        else {
+            continue;
+        };
+
+        // NOTE: The attribute is removed, as it is not a macro of its own within the impl block.
+        let attr = function.attrs.remove(index);
+        let options = MethodOptions::parse(&attr)?;
+        let ident = function.sig.ident.clone();
+
+        if options.constructor {
+            if constructor.is_some() {
+                return Err(Error::new(attr.span(), "a class has only one constructor"));
+            }
+
+            constructor = Some((ident, options.length));
+        } else {
+            let name = options
+                .name
+                .unwrap_or_else(|| lower_camel_case(&ident.to_string()));
+            let length = options.length;
+
+            methods.push(quote! {
+                methods.method(#name, #length, |this, agent, args| {
+                    <#self_ty>::#ident(this, agent, args).map(::glyn_interpreter::JSValue::from)
+                });
+            });
+        }
+    }
+
+    let Some((constructor, length)) = constructor else {
+        return Err(Error::new(
+            Span::call_site(),
+            "a #[js_class] impl block needs a function marked with #[js_method(constructor)]",
+        ));
+    };
+
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    Ok(quote! {
+        #item
+
+        impl #impl_generics ::glyn_interpreter::JSClass for #self_ty #where_clause {
+            const NAME: &'static str = #name;
+            const LENGTH: usize = #length;
+
+            fn construct(
+                agent: &mut ::glyn_interpreter::JSAgent,
+                args: ::glyn_interpreter::Args,
+            ) -> ::std::result::Result<Self, ::glyn_interpreter::ThrowCompletion> {
+                <#self_ty>::#constructor(agent, args)
+            }
+
+            fn define_methods(methods: &mut ::glyn_interpreter::ClassMethods<Self>) {
+                #(#methods)*
+            }
+        }
+    })
+}
+
+/// Converts the name of a function like `get_count` into the name of a method like `getCount`.
+fn lower_camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut capitalize = false;
+
+    for ch in name.trim_start_matches('_').chars() {
+        if ch == '_' {
+            capitalize = true;
+        } else if capitalize {
+            result.extend(ch.to_uppercase());
+            capitalize = false;
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,119 @@
+use glyn_interpreter::{eval_script, Args, JSAgent, JSValue, ThrowCompletion};
+use glyn_macros::js_class;
+
+struct Counter {
+    count: f64,
+    step: f64,
+}
+
+#[js_class]
+impl Counter {
+    #[js_method(constructor, length = 1)]
+    fn new(_agent: &mut JSAgent, args: Args) -> Result<Self, ThrowCompletion> {
+        Ok(Counter {
+            count: 0.0,
+            step: args.get_f64(0)?,
+        })
+    }
+
+    #[js_method]
+    fn increment(&mut self, _agent: &mut JSAgent, _args: Args) -> Result<f64, ThrowCompletion> {
+        self.count += self.step;
+
+        Ok(self.count)
+    }
+
+    #[js_method(length = 1)]
+    fn is_above(&self, _agent: &mut JSAgent, args: Args) -> Result<bool, ThrowCompletion> {
+        Ok(self.count > args.get_f64(0)?)
+    }
+
+    #[js_method(name = "valueOf")]
+    fn value(&self, _agent: &mut JSAgent, _args: Args) -> Result<JSValue, ThrowCompletion> {
+        Ok(JSValue::from(self.count))
+    }
+
+    /// Not exposed to scripts, as it is not marked with `#[js_method]`.
+    #[allow(dead_code)]
+    fn reset(&mut self) {
+        self.count = 0.0;
+    }
+}
+
+struct Label(String);
+
+#[js_class(name = "Tag")]
+impl Label {
+    #[js_method(constructor)]
+    fn create(_agent: &mut JSAgent, args: Args) -> Result<Self, ThrowCompletion> {
+        Ok(Label(args.get_string(0)?))
+    }
+
+    #[js_method]
+    fn text(&self, _agent: &mut JSAgent, _args: Args) -> Result<String, ThrowCompletion> {
+        Ok(self.0.clone())
+    }
+}
+
+fn agent() -> JSAgent {
+    let mut agent = JSAgent::default();
+
+    agent.register_class::<Counter>();
+    agent.register_class::<Label>();
+
+    agent
+}
+
+fn eval(source: &str) -> Result<JSValue, String> {
+    eval_script(&mut agent(), source).map_err(|error| error.to_string())
+}
+
+#[test]
+fn instances_hold_their_own_state() {
+    assert_eq!(
+        eval(
+            r#"
+            let a = new Counter(2);
+            let b = new Counter(10);
+            a.increment();
+            a.increment();
+            b.increment();
+            "" + a.valueOf() + "," + b.valueOf() + "," + a.isAbove(3) + "," + (a instanceof Counter);
+            "#
+        ),
+        Ok(JSValue::from(String::from("4,10,true,true")))
+    );
+}
+
+#[test]
+fn classes_are_named_and_methods_are_inherited() {
+    assert_eq!(
+        eval(
+            r#"
+            let tag = new Tag("t");
+            "" + Counter.name + Counter.length + Tag.name + tag.text() + ("text" in Tag.prototype) + ("reset" in Counter.prototype);
+            "#
+        ),
+        Ok(JSValue::from(String::from("Counter1Tagttruefalse")))
+    );
+}
+
+#[test]
+fn misuse_throws_type_errors() {
+    assert!(eval("Counter(1);")
+        .unwrap_err()
+        .contains("Class constructor Counter cannot be invoked without 'new'"));
+    assert!(eval("new Counter();")
+        .unwrap_err()
+        .contains("Counter: expected at least 1 argument, but got 0"));
+    assert!(
+        eval("let f = new Counter(1).increment; let o = { f: f }; o.f();")
+            .unwrap_err()
+            .contains("Counter.prototype.increment called on incompatible receiver")
+    );
+    assert!(
+        eval("let t = new Tag(\"t\"); t.increment = new Counter(1).increment; t.increment();")
+            .unwrap_err()
+            .contains("incompatible receiver")
+    );
+}
@@ -0,0 +1,77 @@
+//! Bindings which let JavaScript hosts such as browsers evaluate scripts in glyn, built for `wasm32-unknown-unknown` with `wasm-pack build crates/glyn_wasm`.
+//!
+//! ```js
+//! import { Glyn } from "glyn_wasm";
+//!
+//! const glyn = new Glyn();
+//!
+//! glyn.evalScript("let x = 20;");
+//! glyn.evalScript("x * 2 + 2;"); // 42
+//! ```
+//!
+//! Each `Glyn` is a separate agent, so scripts evaluated in one can't reach the globals of another or of the host.
+
+use glyn_interpreter::{eval_script, JSAgent, JSValue};
+use wasm_bindgen::prelude::*;
+
+/// An agent with a realm which the scripts it evaluates share, like the global scope of a page, and in which `setTimeout` and `setInterval` are defined.
+#[wasm_bindgen]
+pub struct Glyn {
+    agent: JSAgent,
+}
+
+#[wasm_bindgen]
+impl Glyn {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Glyn {
+        let mut agent = JSAgent::default();
+
+        agent.install_timers();
+
+        Glyn { agent }
+    }
+
+    /// Evaluates the script and returns its completion value, or throws the message of the error it failed with.
+    ///
+    /// Undefined, null, booleans, numbers and strings are returned as they are, and any other value is returned as the string it is shown as in a REPL.
+    #[wasm_bindgen(js_name = evalScript)]
+    pub fn eval_script(&mut self, source: &str) -> Result<JsValue, JsError> {
+        match eval_script(&mut self.agent, source) {
+            Ok(value) => Ok(to_host_value(&value)),
+            Err(error) => Err(JsError::new(&error.render(source))),
+        }
+    }
+
+    /// Runs the promise jobs, tasks and timers which are due, returning the time of the earliest timer still to run, if any, at which it should be called again.
+    #[wasm_bindgen(js_name = runUntilIdle)]
+    pub fn run_until_idle(&mut self) -> Result<Option<f64>, JsError> {
+        match self.agent.run_until_idle() {
+            Ok(()) => Ok(self.agent.next_timer_due()),
+            Err(error) => Err(JsError::new(&error.to_string())),
+        }
+    }
+}
+
+impl Default for Glyn {
+    fn default() -> Self {
+        Glyn::new()
+    }
+}
+
+/// Converts a value of the agent into a value of the host, which can't share objects with the agent.
+pub fn to_host_value(value: &JSValue) -> JsValue {
+    if let Some(number) = value.as_f64() {
+        return JsValue::from_f64(number);
+    }
+
+    if let Some(string) = value.to_rust_string() {
+        return JsValue::from_str(&string);
+    }
+
+    match value {
+        JSValue::Undefined => JsValue::UNDEFINED,
+        JSValue::Null => JsValue::NULL,
+        JSValue::Bool(value) => JsValue::from_bool(*value),
+        _ => JsValue::from_str(&value.inspect()),
+    }
+}
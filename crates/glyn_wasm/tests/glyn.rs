@@ -0,0 +1,41 @@
+#![cfg(target_arch = "wasm32")]
+
+use glyn_wasm::Glyn;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn scripts_share_the_realm_of_the_agent() {
+    let mut glyn = Glyn::new();
+
+    glyn.eval_script("let x = 20;").unwrap();
+
+    assert_eq!(glyn.eval_script("x * 2 + 2;").unwrap().as_f64(), Some(42.0));
+    assert_eq!(
+        glyn.eval_script("\"a\" + x;").unwrap().as_string(),
+        Some(String::from("a20"))
+    );
+    assert_eq!(
+        glyn.eval_script("({ a: [1] });").unwrap().as_string(),
+        Some(String::from("{ a: [ 1 ] }"))
+    );
+}
+
+#[wasm_bindgen_test]
+fn errors_are_thrown() {
+    let mut glyn = Glyn::new();
+
+    assert!(glyn.eval_script("throw 1;").is_err());
+    assert!(glyn.eval_script("let;").is_err());
+}
+
+#[wasm_bindgen_test]
+fn timers_run_on_the_host_clock() {
+    let mut glyn = Glyn::new();
+
+    glyn.eval_script("let done = false; setTimeout(function () { done = true; }, 0);")
+        .unwrap();
+
+    assert_eq!(glyn.run_until_idle().unwrap(), None);
+    assert_eq!(glyn.eval_script("done;").unwrap(), JsValue::TRUE);
+}